@@ -12,6 +12,10 @@ the bidirectional /v1/dispatch-gateway channel:
      as {type:"dispatch_result", ...}, and waits for the next reply.
   4. When orchestrator replies {type:"final", ...} bootstrap prints content and exits.
 
+With SEAL enabled, attestation also negotiates a per-execution payload key
+(X25519 + HKDF-SHA256 + AES-256-GCM): the prompt is sent as `encrypted_prompt`
+and the final reply arrives as `encrypted_content`.
+
 DESIGN CONSTRAINTS (DO NOT VIOLATE):
   - stdlib-only: no third-party imports (Ultra-Thin Client, ADR-040 §Design Principles)
   - All policy enforcement is server-side; bootstrap.py is a trusted executor
@@ -38,7 +42,14 @@ SEAL_GATEWAY_URL = os.environ.get(
 
 if SEAL_ENABLED:
     try:
+        from cryptography.hazmat.primitives import hashes
         from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+        from cryptography.hazmat.primitives.asymmetric.x25519 import (
+            X25519PrivateKey,
+            X25519PublicKey,
+        )
+        from cryptography.hazmat.primitives.ciphers.aead import AESGCM
+        from cryptography.hazmat.primitives.kdf.hkdf import HKDF
         from cryptography.hazmat.primitives.serialization import Encoding, PublicFormat
     except ImportError:
         print(
@@ -54,7 +65,10 @@ class SealPolicyViolation(Exception):
 
 class SealClient:
     """SEAL attestation client for agent bootstrap. Performs Ed25519 key generation,
-    attestation handshake with the orchestrator, and SEAL-wrapped tool invocation."""
+    attestation handshake with the orchestrator, SEAL-wrapped tool invocation, and
+    encryption of the execution's prompt and final output."""
+
+    PAYLOAD_KEY_INFO = b"aegis-payload-v1"
 
     def __init__(
         self,
@@ -75,6 +89,8 @@ class SealClient:
         self._public_key_b64 = base64.b64encode(
             self._private_key.public_key().public_bytes(Encoding.Raw, PublicFormat.Raw)
         ).decode("utf-8")
+        self._payload_private_key = X25519PrivateKey.generate()
+        self._payload_aead = None
         self.security_token = None
         self.session_id = None
 
@@ -87,6 +103,11 @@ class SealClient:
                 "security_context": self.security_context,
                 "agent_id": self.agent_id,
                 "execution_id": self.execution_id,
+                "payload_public_key": base64.b64encode(
+                    self._payload_private_key.public_key().public_bytes(
+                        Encoding.Raw, PublicFormat.Raw
+                    )
+                ).decode("utf-8"),
             }
         ).encode("utf-8")
 
@@ -102,6 +123,44 @@ class SealClient:
         self.security_token = data["security_token"]
         self.session_id = data.get("session_id")
 
+        # Older orchestrators do not negotiate a payload key; payloads then
+        # travel in plaintext as before.
+        orchestrator_key = data.get("payload_public_key")
+        if orchestrator_key:
+            shared = self._payload_private_key.exchange(
+                X25519PublicKey.from_public_bytes(base64.b64decode(orchestrator_key))
+            )
+            key = HKDF(
+                algorithm=hashes.SHA256(),
+                length=32,
+                salt=self.execution_id.encode("utf-8"),
+                info=self.PAYLOAD_KEY_INFO,
+            ).derive(shared)
+            self._payload_aead = AESGCM(key)
+
+    @property
+    def encrypts_payloads(self) -> bool:
+        return self._payload_aead is not None
+
+    def encrypt_payload(self, plaintext: str) -> dict:
+        """Seal a payload for the orchestrator, bound to this execution."""
+        nonce = os.urandom(12)
+        ciphertext = self._payload_aead.encrypt(
+            nonce, plaintext.encode("utf-8"), self.execution_id.encode("utf-8")
+        )
+        return {
+            "nonce": base64.b64encode(nonce).decode("utf-8"),
+            "ciphertext": base64.b64encode(ciphertext).decode("utf-8"),
+        }
+
+    def decrypt_payload(self, payload: dict) -> str:
+        """Open a payload sealed by the orchestrator for this execution."""
+        return self._payload_aead.decrypt(
+            base64.b64decode(payload["nonce"]),
+            base64.b64decode(payload["ciphertext"]),
+            self.execution_id.encode("utf-8"),
+        ).decode("utf-8")
+
     def call_tool(self, tool_name: str, arguments: dict) -> dict:
         """Invoke a tool through the SEAL gateway with a signed envelope."""
         if self.security_token is None:
@@ -458,18 +517,19 @@ def main():
     # -- Dispatch loop (ADR-040) ----------------------------------------------
     # Send the initial generate request; the response may be a dispatch command
     # (type="dispatch") or the final LLM output (type="final").
-    msg = post_json(
-        {
-            "type": "generate",
-            "agent_id": agent_id,
-            "execution_id": execution_id,
-            "iteration_number": iteration_number,
-            "model_alias": model_alias,
-            "prompt": final_prompt,
-            "messages": [],
-        },
-        timeout=llm_timeout_seconds,
-    )
+    generate = {
+        "type": "generate",
+        "agent_id": agent_id,
+        "execution_id": execution_id,
+        "iteration_number": iteration_number,
+        "model_alias": model_alias,
+        "prompt": final_prompt,
+        "messages": [],
+    }
+    if seal_client is not None and seal_client.encrypts_payloads:
+        generate["prompt"] = ""
+        generate["encrypted_prompt"] = seal_client.encrypt_payload(final_prompt)
+    msg = post_json(generate, timeout=llm_timeout_seconds)
 
     # Execute dispatch commands until the orchestrator issues type="final".
     while msg.get("type") == "dispatch":
@@ -483,7 +543,10 @@ def main():
         msg = post_json(result)
 
     # type="final" — print the LLM response to stdout and exit cleanly.
-    print(msg.get("content", ""))
+    if "encrypted_content" in msg and seal_client is not None:
+        print(seal_client.decrypt_payload(msg["encrypted_content"]))
+    else:
+        print(msg.get("content", ""))
 
 
 if __name__ == "__main__":
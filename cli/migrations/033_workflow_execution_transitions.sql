@@ -0,0 +1,22 @@
+-- Compact FSM transition history for workflow executions.
+--
+-- Temporal's own event history is subject to namespace retention, after which
+-- the audit trail of which states a workflow visited is gone. Rows here are
+-- written by the TemporalEventListener on WorkflowStateEntered /
+-- WorkflowStateExited callbacks and are kept for the lifetime of the
+-- workflow execution record.
+
+CREATE TABLE IF NOT EXISTS workflow_execution_transitions (
+    execution_id UUID NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    sequence_number BIGINT NOT NULL,
+    state_name TEXT NOT NULL,
+    triggered_by TEXT,
+    entered_at TIMESTAMPTZ NOT NULL,
+    exited_at TIMESTAMPTZ,
+    output_summary TEXT,
+    PRIMARY KEY (execution_id, sequence_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_workflow_execution_transitions_open
+    ON workflow_execution_transitions(execution_id, state_name)
+    WHERE exited_at IS NULL;
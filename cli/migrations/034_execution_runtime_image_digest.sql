@@ -0,0 +1,7 @@
+-- Provenance for orchestrator-built runtime images (spec.runtime.packages).
+--
+-- Records the content-addressed image ID each execution ran on so that the
+-- exact dependency set behind a result can be reproduced after the agent's
+-- manifest has moved on. NULL for executions on registry-pulled images.
+
+ALTER TABLE executions ADD COLUMN IF NOT EXISTS runtime_image_digest TEXT;
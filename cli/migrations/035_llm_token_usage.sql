@@ -0,0 +1,39 @@
+-- Per-call LLM token usage accounting.
+--
+-- `llm_token_usage` holds one row per recorded LlmInteraction that reported
+-- token counts; `/v1/usage` rolls it up by execution, agent, day or tenant.
+-- `llm_token_usage_daily` is rebuilt for each finished UTC day by the nightly
+-- aggregation job so dashboards read a small summary instead of raw rows.
+
+CREATE TABLE IF NOT EXISTS llm_token_usage (
+    id BIGSERIAL PRIMARY KEY,
+    execution_id UUID NOT NULL,
+    tenant_id TEXT NOT NULL,
+    agent_id UUID NOT NULL,
+    iteration_number SMALLINT NOT NULL,
+    provider TEXT NOT NULL,
+    model TEXT NOT NULL,
+    input_tokens BIGINT NOT NULL,
+    output_tokens BIGINT NOT NULL,
+    recorded_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_llm_token_usage_tenant_recorded
+    ON llm_token_usage(tenant_id, recorded_at);
+CREATE INDEX IF NOT EXISTS idx_llm_token_usage_execution
+    ON llm_token_usage(execution_id);
+CREATE INDEX IF NOT EXISTS idx_llm_token_usage_recorded
+    ON llm_token_usage(recorded_at);
+
+CREATE TABLE IF NOT EXISTS llm_token_usage_daily (
+    day DATE NOT NULL,
+    tenant_id TEXT NOT NULL,
+    agent_id UUID NOT NULL,
+    model TEXT NOT NULL,
+    input_tokens BIGINT NOT NULL,
+    output_tokens BIGINT NOT NULL,
+    calls BIGINT NOT NULL,
+    aggregated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (day, tenant_id, agent_id, model)
+);
+CREATE INDEX IF NOT EXISTS idx_llm_token_usage_daily_tenant_day
+    ON llm_token_usage_daily(tenant_id, day);
@@ -0,0 +1,13 @@
+-- Composite indexes for `/v1/executions?filter=...`.
+--
+-- Filter expressions always scope to a tenant and order by `started_at`, so
+-- the single-column indexes from 001 leave Postgres sorting whole tenants.
+-- These cover the common shapes: recent executions by status, and time
+-- windows over started/completed timestamps.
+
+CREATE INDEX IF NOT EXISTS idx_executions_tenant_status_started
+    ON executions(tenant_id, status, started_at DESC);
+CREATE INDEX IF NOT EXISTS idx_executions_tenant_started
+    ON executions(tenant_id, started_at DESC);
+CREATE INDEX IF NOT EXISTS idx_executions_tenant_completed
+    ON executions(tenant_id, completed_at DESC);
@@ -0,0 +1,8 @@
+-- Key/value labels on executions (`customer=acme`, `release-check`).
+--
+-- Set at submit time or via `PATCH /v1/executions/:id` and inherited by
+-- child executions. The GIN index serves the `@>` / `?` operators used by
+-- `label.KEY=VALUE` and `label=KEY` filter expressions.
+
+ALTER TABLE executions ADD COLUMN IF NOT EXISTS labels JSONB NOT NULL DEFAULT '{}'::jsonb;
+CREATE INDEX IF NOT EXISTS idx_executions_labels ON executions USING GIN (labels);
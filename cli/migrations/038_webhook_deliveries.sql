@@ -0,0 +1,42 @@
+-- Outbound webhook delivery log and dead letters.
+--
+-- `webhook_delivery_attempts` holds one row per HTTP attempt made by the
+-- shared outbound webhook dispatcher; `/v1/outbound-webhooks/deliveries`
+-- lists it per destination URL. Deliveries that exhaust their retries, or
+-- are rejected with a non-retryable status, land in `webhook_dead_letters`
+-- with their body so they can be inspected and replayed.
+
+CREATE TABLE IF NOT EXISTS webhook_delivery_attempts (
+    id BIGSERIAL PRIMARY KEY,
+    delivery_id UUID NOT NULL,
+    tenant_id TEXT NOT NULL,
+    destination TEXT NOT NULL,
+    source TEXT NOT NULL,
+    attempt INTEGER NOT NULL,
+    status_code INTEGER,
+    error TEXT,
+    outcome TEXT NOT NULL,
+    duration_ms BIGINT NOT NULL,
+    attempted_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_attempts_destination
+    ON webhook_delivery_attempts(tenant_id, destination, attempted_at DESC);
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_attempts_tenant
+    ON webhook_delivery_attempts(tenant_id, attempted_at DESC);
+CREATE INDEX IF NOT EXISTS idx_webhook_delivery_attempts_delivery
+    ON webhook_delivery_attempts(delivery_id);
+
+CREATE TABLE IF NOT EXISTS webhook_dead_letters (
+    delivery_id UUID PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    destination TEXT NOT NULL,
+    source TEXT NOT NULL,
+    method TEXT NOT NULL,
+    content_type TEXT NOT NULL,
+    body TEXT NOT NULL,
+    attempts INTEGER NOT NULL,
+    last_error TEXT NOT NULL,
+    dead_lettered_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX IF NOT EXISTS idx_webhook_dead_letters_tenant
+    ON webhook_dead_letters(tenant_id, dead_lettered_at DESC);
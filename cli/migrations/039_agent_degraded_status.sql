@@ -0,0 +1,10 @@
+-- `degraded` agent status.
+--
+-- Set when an agent's `spec.healthcheck` smoke test fails with
+-- `on_failure: degrade` (`aegis agent deploy --verify`). Degraded agents stay
+-- runnable and count towards the tenant's active-agent quota; a passing
+-- healthcheck returns them to `active`.
+
+ALTER TABLE agents DROP CONSTRAINT IF EXISTS agents_status_check;
+ALTER TABLE agents ADD CONSTRAINT agents_status_check
+    CHECK (status IN ('active', 'paused', 'archived', 'degraded'));
@@ -0,0 +1,15 @@
+-- Failover lease for active/standby daemon pairs (spec.high_availability).
+--
+-- One row per lease name. The daemon whose `holder` owns an unexpired row is
+-- active; the standby polls until `expires_at` passes and then takes the row
+-- over, bumping `epoch`. Renewals are conditional on `(holder, epoch)`, so
+-- `epoch` doubles as the fencing token.
+
+CREATE TABLE IF NOT EXISTS daemon_leases (
+    name TEXT PRIMARY KEY,
+    holder TEXT NOT NULL,
+    epoch BIGINT NOT NULL,
+    acquired_at TIMESTAMPTZ NOT NULL,
+    renewed_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
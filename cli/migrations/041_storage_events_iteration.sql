@@ -0,0 +1,7 @@
+-- Supervisor iteration that was running when a storage event happened.
+--
+-- Stamped by the FSAL from the NFS volume registry, which the supervisor
+-- updates at the start of each iteration. NULL for workflow steps, user
+-- browsing and events recorded before this column existed.
+
+ALTER TABLE storage_events ADD COLUMN IF NOT EXISTS iteration_number SMALLINT;
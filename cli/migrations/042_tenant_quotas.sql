@@ -0,0 +1,14 @@
+-- Per-tenant quota overrides managed through `/v1/quotas`.
+--
+-- One row per tenant. A NULL column keeps the tier default for that limit;
+-- `monthly_token_budget` has no tier default, so NULL means no budget.
+
+CREATE TABLE IF NOT EXISTS tenant_quotas (
+    tenant_id TEXT PRIMARY KEY,
+    max_agents BIGINT,
+    max_concurrent_executions BIGINT,
+    max_volume_bytes BIGINT,
+    monthly_token_budget BIGINT,
+    updated_by TEXT NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,21 @@
+-- Network flows recorded for executions that requested flow capture.
+--
+-- One row per connection (TCP) or DNS query (UDP) opened from the agent's
+-- network namespace. Only addresses, ports and the TLS SNI / DNS name are
+-- kept; payloads are never captured.
+
+CREATE TABLE IF NOT EXISTS execution_network_flows (
+    id BIGSERIAL PRIMARY KEY,
+    execution_id UUID NOT NULL,
+    iteration_number SMALLINT,
+    protocol TEXT NOT NULL,
+    source_address TEXT NOT NULL,
+    source_port INTEGER NOT NULL,
+    destination_address TEXT NOT NULL,
+    destination_port INTEGER NOT NULL,
+    server_name TEXT,
+    dns_query TEXT,
+    first_seen TIMESTAMPTZ NOT NULL
+);
+CREATE INDEX IF NOT EXISTS idx_execution_network_flows_execution
+    ON execution_network_flows(execution_id, first_seen);
@@ -0,0 +1,9 @@
+-- Blackboard snapshot per workflow state transition.
+--
+-- The Temporal worker reports the blackboard as it stood when a state was
+-- entered; the TemporalEventListener stores it on the matching transition row
+-- so a run can be stepped through and diffed after the fact. NULL for
+-- transitions recorded before this column existed or by workers that do not
+-- report a snapshot. JSONB values of this size are compressed by TOAST.
+
+ALTER TABLE workflow_execution_transitions ADD COLUMN IF NOT EXISTS blackboard JSONB;
@@ -0,0 +1,21 @@
+-- Shared execution queue (spec.execution_queue, backend: postgres).
+--
+-- One row per execution waiting to be started by a worker daemon. Workers
+-- claim the oldest row with `available_at <= NOW()` using
+-- FOR UPDATE SKIP LOCKED, push `available_at` out by the visibility timeout
+-- and stamp a fresh `claim_token`. Acking deletes the row; a worker that dies
+-- mid-claim leaves it to reappear once `available_at` passes.
+
+CREATE TABLE IF NOT EXISTS execution_task_queue (
+    execution_id UUID PRIMARY KEY,
+    tenant_id TEXT NOT NULL,
+    task JSONB NOT NULL,
+    enqueued_at TIMESTAMPTZ NOT NULL,
+    available_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    claimed_by TEXT,
+    claim_token UUID
+);
+
+CREATE INDEX IF NOT EXISTS idx_execution_task_queue_available
+    ON execution_task_queue(available_at, enqueued_at);
@@ -0,0 +1,21 @@
+-- Monthly per-tenant usage summaries behind `/v1/reports/usage`.
+--
+-- Rebuilt every hour for the current month and once more after a month has
+-- ended, from `executions` (count and trajectory tool calls),
+-- `llm_token_usage` and `volumes`. `storage_bytes` is the peak allocated
+-- volume size sampled while the month was current; the final pass keeps it.
+
+CREATE TABLE IF NOT EXISTS usage_monthly (
+    month DATE NOT NULL,
+    tenant_id TEXT NOT NULL,
+    executions BIGINT NOT NULL,
+    tool_calls BIGINT NOT NULL,
+    input_tokens BIGINT NOT NULL,
+    output_tokens BIGINT NOT NULL,
+    llm_calls BIGINT NOT NULL,
+    storage_bytes BIGINT NOT NULL,
+    aggregated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (month, tenant_id)
+);
+CREATE INDEX IF NOT EXISTS idx_usage_monthly_tenant_month
+    ON usage_monthly(tenant_id, month);
@@ -0,0 +1,15 @@
+-- Typed handoff artifacts produced by workflow states.
+--
+-- A state that declares `outputs:` in its manifest hands each declared output
+-- to downstream states when it exits. The TemporalEventListener validates the
+-- exited state's output against the declarations and stores one row per
+-- output; a state visited again replaces its earlier handoffs.
+
+CREATE TABLE IF NOT EXISTS workflow_handoffs (
+    execution_id UUID NOT NULL REFERENCES workflow_executions(id) ON DELETE CASCADE,
+    state_name TEXT NOT NULL,
+    output_name TEXT NOT NULL,
+    value JSONB NOT NULL,
+    produced_at TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (execution_id, state_name, output_name)
+);
@@ -0,0 +1,21 @@
+-- Audit trail of agent manifest updates.
+--
+-- Every `PATCH /v1/agents/{id}` records the fields it changed (`changes` is a
+-- JSON array of {path, before, after}), who made the update and, for tenants
+-- with the `manifest_change_reason` feature flag, why. Served newest first by
+-- `GET /v1/agents/{id}/changes`.
+
+CREATE TABLE IF NOT EXISTS agent_manifest_changes (
+    id UUID PRIMARY KEY,
+    agent_id UUID NOT NULL REFERENCES agents(id) ON DELETE CASCADE,
+    tenant_id TEXT NOT NULL,
+    old_version TEXT NOT NULL,
+    new_version TEXT NOT NULL,
+    changes JSONB NOT NULL,
+    reason TEXT,
+    changed_by TEXT,
+    changed_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_agent_manifest_changes_agent
+    ON agent_manifest_changes(agent_id, changed_at DESC);
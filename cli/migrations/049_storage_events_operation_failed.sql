@@ -0,0 +1,18 @@
+-- Storage backend failures in the file-level audit trail.
+--
+-- The FSAL records an `OperationFailed` storage event when the backend
+-- fails a read, write, listing, create, delete or rename. Its
+-- `operation_details` carry the operation, a machine-readable `cause`
+-- (`backend_unavailable`, `corrupted` or `io`) and the error message.
+
+ALTER TABLE storage_events DROP CONSTRAINT IF EXISTS storage_events_type_check;
+
+ALTER TABLE storage_events
+    ADD CONSTRAINT storage_events_type_check CHECK (
+        event_type IN (
+            'FileOpened', 'FileRead', 'FileWritten', 'FileClosed',
+            'DirectoryListed', 'FileCreated', 'FileDeleted',
+            'PathTraversalBlocked', 'FilesystemPolicyViolation',
+            'QuotaExceeded', 'UnauthorizedVolumeAccess', 'OperationFailed'
+        )
+    );
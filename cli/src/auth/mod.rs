@@ -5,10 +5,12 @@ pub(crate) mod env;
 mod profile;
 mod refresh;
 
-pub use profile::{load_store, save_store, AegisProfile};
+pub use profile::{load_store, save_store, AegisProfile, RemoteHost};
 
 use anyhow::Result;
 
+use crate::exit_code::{CliError, ExitCode};
+
 /// Returns the bearer key for the current invocation.
 ///
 /// Precedence:
@@ -28,7 +30,7 @@ pub async fn require_key() -> Result<String> {
     let profile = store
         .profiles
         .get(&profile_name)
-        .ok_or_else(|| anyhow::anyhow!("Not authenticated. Run 'aegis auth login'."))?
+        .ok_or_else(|| CliError::new(ExitCode::Auth, "Not authenticated. Run 'aegis auth login'."))?
         .clone();
 
     if chrono::Utc::now() < profile.expires_at {
@@ -3,7 +3,7 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::PathBuf;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,10 +19,54 @@ pub struct AegisProfile {
     pub scopes: Vec<String>,
 }
 
+/// A named SSH host running an AEGIS daemon, used by `aegis --remote <alias>`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RemoteHost {
+    /// SSH destination, e.g. "ops@edge-07.example.com"
+    pub destination: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssh_port: Option<u16>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity_file: Option<PathBuf>,
+    /// Daemon HTTP API port on the remote host
+    #[serde(default = "default_remote_api_port")]
+    pub api_port: u16,
+    /// Daemon gRPC port on the remote host, forwarded alongside the HTTP API
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub grpc_port: Option<u16>,
+    /// `aegis` binary on the remote host, for commands run over SSH exec
+    #[serde(default = "default_remote_command")]
+    pub command: String,
+}
+
+fn default_remote_api_port() -> u16 {
+    8088
+}
+
+fn default_remote_command() -> String {
+    "aegis".to_string()
+}
+
+impl RemoteHost {
+    pub fn new(destination: impl Into<String>) -> Self {
+        Self {
+            destination: destination.into(),
+            ssh_port: None,
+            identity_file: None,
+            api_port: default_remote_api_port(),
+            grpc_port: None,
+            command: default_remote_command(),
+        }
+    }
+}
+
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct AuthStore {
     pub active_profile: String,
     pub profiles: HashMap<String, AegisProfile>,
+    /// Remote host aliases, keyed by alias
+    #[serde(default)]
+    pub remotes: BTreeMap<String, RemoteHost>,
 }
 
 fn auth_store_path() -> PathBuf {
@@ -3,7 +3,8 @@
 //! Agent
 //!
 //! Provides agent functionality for the system.
-//! Includes list/deploy/show/remove/logs and generate operations.
+//! Includes list/deploy/render/show/remove/logs, generate and the local
+//! `shell` REPL.
 //!
 //! # Architecture
 //!
@@ -14,11 +15,31 @@ use anyhow::{Context, Result};
 use clap::Subcommand;
 use colored::Colorize;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::io::AsyncBufReadExt;
 use uuid::Uuid;
 
+use aegis_orchestrator_core::application::agent_shell::{
+    diff_turn, AgentShell, ShellEvent, ShellFixture, ShellTurn,
+};
+use aegis_orchestrator_core::domain::deploy_gate::{scan_manifest_for_secrets, SecretFinding};
+use aegis_orchestrator_core::domain::llm::LLMProvider;
+use aegis_orchestrator_core::domain::node_config::NodeConfigManifest;
+use aegis_orchestrator_core::infrastructure::agent_manifest_parser::{
+    AgentManifestParser, ManifestChain,
+};
+use aegis_orchestrator_core::infrastructure::llm::registry::AliasedProvider;
+use aegis_orchestrator_core::infrastructure::llm::replay::{
+    RecordingLLMProvider, ReplayLLMProvider,
+};
+use aegis_orchestrator_core::infrastructure::llm::ProviderRegistry;
+use aegis_orchestrator_sdk::{AgentManifest, ValidatorSpec};
+
 use crate::commands::builtins;
 use crate::daemon::{check_daemon_running, DaemonClient, DaemonStatus};
+use crate::exit_code::{CliError, ExitCode};
 use crate::output::{render_serialized, structured_output_unsupported, OutputFormat};
 
 const AGENT_GENERATOR_NAME: &str = builtins::AGENT_GENERATOR_AGENT_NAME;
@@ -43,6 +64,19 @@ pub enum AgentCommand {
         /// and version is already deployed.
         #[arg(long)]
         force: bool,
+
+        /// Run `spec.healthcheck` after deploying. Fails the command when the
+        /// check fails with `on_failure: fail`; with `on_failure: degrade`
+        /// the agent is marked Degraded instead.
+        #[arg(long, conflicts_with = "validate_only")]
+        verify: bool,
+    },
+
+    /// Show the effective manifest after resolving `extends` (YAML)
+    Render {
+        /// Path to agent manifest YAML file
+        #[arg(value_name = "MANIFEST")]
+        manifest: PathBuf,
     },
 
     /// Show agent configuration (YAML)
@@ -121,6 +155,25 @@ pub enum AgentCommand {
         wait: bool,
     },
 
+    /// Try a manifest locally: send inputs and watch each iteration's
+    /// prompt, response and validation verdict. Runs without the daemon;
+    /// LLM calls go to the providers in the node config. Type `:save PATH`
+    /// to keep the session as a regression fixture.
+    Shell {
+        /// Path to agent manifest YAML file
+        #[arg(value_name = "MANIFEST")]
+        manifest: PathBuf,
+
+        /// Answer LLM calls from a saved session instead of the providers
+        #[arg(long, value_name = "FIXTURE")]
+        replay: Option<PathBuf>,
+
+        /// Re-run every turn of the `--replay` fixture without prompting and
+        /// fail if any output, prompt or verdict differs
+        #[arg(long, requires = "replay")]
+        check: bool,
+    },
+
     /// Generate an agent from natural-language input
     Generate {
         /// Natural-language intent for the agent to create
@@ -140,6 +193,22 @@ pub async fn handle_command(
     port: u16,
     output_format: OutputFormat,
 ) -> Result<()> {
+    // Rendering only needs the daemon for `agent:` bases.
+    if let AgentCommand::Render { manifest } = &command {
+        return render_agent(manifest, host, port, output_format).await;
+    }
+    if let AgentCommand::Shell {
+        manifest,
+        replay,
+        check,
+    } = &command
+    {
+        if output_format.is_structured() {
+            return structured_output_unsupported("aegis agent shell", output_format);
+        }
+        return shell_agent(manifest, replay.as_deref(), *check, config_path, host, port).await;
+    }
+
     // Agents are currently managed via the daemon.
     // Embedded mode may later support direct repository access.
 
@@ -152,7 +221,7 @@ pub async fn handle_command(
                 format!("⚠ Daemon is running (PID: {pid}) but unhealthy: {error}").yellow()
             );
             println!("Run 'aegis daemon status' for more info.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
         _ => {
             println!(
@@ -160,7 +229,7 @@ pub async fn handle_command(
                 "Agent management requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -173,7 +242,21 @@ pub async fn handle_command(
             manifest,
             validate_only,
             force,
-        } => deploy_agent(manifest, validate_only, force, client, output_format).await,
+            verify,
+        } => {
+            deploy_agent(
+                manifest,
+                validate_only,
+                force,
+                verify,
+                client,
+                output_format,
+            )
+            .await
+        }
+        AgentCommand::Render { .. } | AgentCommand::Shell { .. } => {
+            unreachable!("handled before the daemon check")
+        }
         AgentCommand::Show { agent_id } => show_agent(agent_id, client, output_format).await,
         AgentCommand::Remove { agent_id } => remove_agent(agent_id, client, output_format).await,
         AgentCommand::Logs {
@@ -235,6 +318,11 @@ struct AgentDeployOutput {
     validate_only: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     runtime: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verification: Option<crate::daemon::client::AgentVerifyReport>,
+    /// Credential-looking strings found in the manifest before upload.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    secret_findings: Vec<SecretFinding>,
 }
 
 #[derive(Serialize)]
@@ -325,21 +413,29 @@ async fn deploy_agent(
     manifest: PathBuf,
     validate_only: bool,
     force: bool,
+    verify: bool,
     client: DaemonClient,
     output_format: OutputFormat,
 ) -> Result<()> {
-    let manifest_content = tokio::fs::read_to_string(&manifest)
-        .await
-        .with_context(|| format!("Failed to read manifest: {manifest:?}"))?;
-
-    // Parse with SDK types (now using core domain re-exports)
-    let agent_manifest: aegis_orchestrator_sdk::AgentManifest =
-        serde_yaml::from_str(&manifest_content).context("Failed to parse manifest YAML")?;
+    // Resolves `extends` and runs domain validation (DNS labels, timeouts, etc.)
+    let chain = AgentManifestParser::load_chain(&manifest)?;
+    let agent_manifest = resolve_manifest_chain(chain, Some(&client)).await?;
 
-    // Use domain validation (comprehensive checks including DNS labels, timeouts, etc.)
-    agent_manifest
-        .validate()
-        .map_err(|e| anyhow::anyhow!("Manifest validation failed: {e}"))?;
+    // The daemon's deploy gates decide whether these block the deploy; warn
+    // locally either way so the key can be rotated.
+    let secret_findings = scan_manifest_for_secrets(&agent_manifest);
+    if !output_format.is_structured() {
+        for finding in &secret_findings {
+            println!(
+                "{}",
+                format!(
+                    "⚠ Possible {} at {} ({}); use a secretRef instead",
+                    finding.kind, finding.location, finding.redacted
+                )
+                .yellow()
+            );
+        }
+    }
 
     if validate_only {
         let runtime = format!(
@@ -366,6 +462,8 @@ async fn deploy_agent(
                     version: agent_manifest.metadata.version.clone(),
                     validate_only: true,
                     runtime: Some(runtime.clone()),
+                    verification: None,
+                    secret_findings,
                 },
             );
         }
@@ -382,6 +480,13 @@ async fn deploy_agent(
         return Ok(());
     }
 
+    if verify && agent_manifest.spec.healthcheck.is_none() {
+        anyhow::bail!(
+            "--verify requires spec.healthcheck in {}",
+            manifest.display()
+        );
+    }
+
     if !output_format.is_structured() {
         println!("Deploying agent: {}", agent_manifest.metadata.name.bold());
     }
@@ -405,8 +510,21 @@ async fn deploy_agent(
     ));
     let agent_id = client.deploy_agent(agent_manifest, force, None).await?;
 
+    if verify && !output_format.is_structured() {
+        println!("{}", format!("✓ Agent deployed: {agent_id}").green());
+        println!("Running healthcheck...");
+    }
+    let verification = if verify {
+        Some(client.verify_agent(agent_id).await?)
+    } else {
+        None
+    };
+    let failed = verification
+        .as_ref()
+        .is_some_and(|report| !report.passed && report.on_failure == "fail");
+
     if output_format.is_structured() {
-        return render_serialized(
+        render_serialized(
             output_format,
             &AgentDeployOutput {
                 agent_id: Some(agent_id),
@@ -414,15 +532,108 @@ async fn deploy_agent(
                 version,
                 validate_only: false,
                 runtime,
+                verification,
+                secret_findings,
             },
-        );
+        )?;
+    } else if let Some(report) = &verification {
+        let reason = report.reason.as_deref().unwrap_or("unknown");
+        if report.passed {
+            println!(
+                "{}",
+                format!("✓ Healthcheck passed (execution {})", report.execution_id).green()
+            );
+        } else if failed {
+            println!(
+                "{}",
+                format!(
+                    "✗ Healthcheck failed (execution {}): {reason}",
+                    report.execution_id
+                )
+                .red()
+            );
+        } else {
+            println!(
+                "{}",
+                format!(
+                    "⚠ Healthcheck failed (execution {}): {reason}; agent status is now {}",
+                    report.execution_id, report.agent_status
+                )
+                .yellow()
+            );
+        }
+    } else {
+        println!("{}", format!("✓ Agent deployed: {agent_id}").green());
     }
 
-    println!("{}", format!("✓ Agent deployed: {agent_id}").green());
+    if failed {
+        anyhow::bail!("Healthcheck failed for agent {agent_id}");
+    }
 
     Ok(())
 }
 
+async fn render_agent(
+    manifest: &Path,
+    host: &str,
+    port: u16,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let agent_manifest = load_manifest(manifest, host, port).await?;
+
+    if output_format.is_structured() {
+        return render_serialized(output_format, &agent_manifest);
+    }
+
+    print!("{}", AgentManifestParser::to_yaml(&agent_manifest)?);
+    Ok(())
+}
+
+/// Load and resolve a manifest file, contacting the daemon only when it
+/// extends a deployed agent.
+async fn load_manifest(manifest: &Path, host: &str, port: u16) -> Result<AgentManifest> {
+    let chain = AgentManifestParser::load_chain(manifest)?;
+    let client = match &chain.agent_base {
+        None => None,
+        Some(name) => {
+            if !matches!(
+                check_daemon_running(host, port).await,
+                Ok(DaemonStatus::Running { .. })
+            ) {
+                anyhow::bail!(
+                    "Manifest extends deployed agent '{name}'; run 'aegis daemon start' first"
+                );
+            }
+            let auth_key = crate::auth::require_key().await?;
+            Some(DaemonClient::new(host, port)?.with_auth(auth_key))
+        }
+    };
+    resolve_manifest_chain(chain, client.as_ref()).await
+}
+
+/// Merge a loaded `extends` chain into the effective manifest, fetching an
+/// `agent:` base from the daemon.
+async fn resolve_manifest_chain(
+    chain: ManifestChain,
+    client: Option<&DaemonClient>,
+) -> Result<AgentManifest> {
+    let agent_base = match (&chain.agent_base, client) {
+        (None, _) => None,
+        (Some(name), Some(client)) => {
+            let agent_id = client
+                .lookup_agent(name)
+                .await?
+                .with_context(|| format!("Base agent '{name}' is not deployed"))?;
+            let base = client.get_agent(agent_id).await?;
+            Some(serde_yaml::to_value(base).context("Failed to serialize base manifest")?)
+        }
+        (Some(name), None) => {
+            anyhow::bail!("Manifest extends deployed agent '{name}', which requires the daemon")
+        }
+    };
+    AgentManifestParser::from_value(chain.merge(agent_base))
+}
+
 async fn logs_agent(
     agent_id_str: String,
     follow: bool,
@@ -442,7 +653,11 @@ async fn logs_agent(
         match client.lookup_agent(&agent_id_str).await? {
             Some(id) => id,
             None => {
-                anyhow::bail!("Agent '{agent_id_str}' not found");
+                return Err(CliError::new(
+                    ExitCode::NotFound,
+                    format!("Agent '{agent_id_str}' not found"),
+                )
+                .into());
             }
         }
     };
@@ -515,7 +730,11 @@ async fn run_agent(
     } else {
         match client.lookup_agent(&agent).await? {
             Some(id) => id,
-            None => anyhow::bail!("Agent '{agent}' not found"),
+            None => {
+                return Err(
+                    CliError::new(ExitCode::NotFound, format!("Agent '{agent}' not found")).into(),
+                )
+            }
         }
     };
 
@@ -546,6 +765,9 @@ async fn run_agent(
             None,
             version.as_deref(),
             attachment_refs,
+            Default::default(),
+            false,
+            &[],
         )
         .await
         .context("Failed to start agent execution")?;
@@ -646,6 +868,9 @@ async fn generate_agent(
             None,
             None,
             Vec::new(),
+            Default::default(),
+            false,
+            &[],
         )
         .await
         .context("Failed to start agent generation execution")?;
@@ -677,3 +902,217 @@ async fn generate_agent(
 
     Ok(())
 }
+
+async fn shell_agent(
+    manifest: &Path,
+    replay: Option<&Path>,
+    check: bool,
+    config_path: Option<PathBuf>,
+    host: &str,
+    port: u16,
+) -> Result<()> {
+    let agent_manifest = load_manifest(manifest, host, port).await?;
+    let fixture = match replay {
+        Some(path) => {
+            let text = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read fixture {}", path.display()))?;
+            Some(
+                serde_yaml::from_str::<ShellFixture>(&text)
+                    .with_context(|| format!("Invalid fixture {}", path.display()))?,
+            )
+        }
+        None => None,
+    };
+
+    let llm: Arc<dyn LLMProvider> = match &fixture {
+        Some(fixture) => Arc::new(ReplayLLMProvider::new(fixture.exchanges.clone())),
+        None => {
+            let config = NodeConfigManifest::load_or_default(config_path)
+                .context("Failed to load configuration")?;
+            let registry = ProviderRegistry::from_config(&config)
+                .context("Failed to initialize LLM providers")?;
+            Arc::new(AliasedProvider::new(
+                Arc::new(registry),
+                agent_manifest.spec.runtime.model.clone(),
+            ))
+        }
+    };
+    let recorder = Arc::new(RecordingLLMProvider::new(llm));
+    let shell = AgentShell::new(agent_manifest, recorder.clone());
+
+    for spec in shell.skipped_validators() {
+        let name = match spec {
+            ValidatorSpec::Semantic { judge_agent, .. } => format!("semantic ({judge_agent})"),
+            ValidatorSpec::MultiJudge { judges, .. } => {
+                format!("multi_judge ({})", judges.join(", "))
+            }
+            ValidatorSpec::Plugin { plugin, .. } => format!("plugin ({plugin})"),
+            _ => "validator".to_string(),
+        };
+        println!(
+            "{}",
+            format!(
+                "⚠ Skipping {name} validator: judge agents and plugins need a running orchestrator"
+            )
+            .yellow()
+        );
+    }
+
+    match fixture {
+        Some(fixture) if check => check_shell_fixture(&shell, &fixture).await,
+        _ => run_shell(&shell, &recorder).await,
+    }
+}
+
+/// Replay every turn of `fixture` and report the ones that differ.
+async fn check_shell_fixture(shell: &AgentShell, fixture: &ShellFixture) -> Result<()> {
+    let mut failed = 0;
+    for (n, expected) in fixture.turns.iter().enumerate() {
+        let actual = shell.send(expected.input.clone(), |_| {}).await?;
+        let diffs = diff_turn(expected, &actual);
+        if diffs.is_empty() {
+            println!("{}", format!("✓ Turn {}", n + 1).green());
+        } else {
+            failed += 1;
+            println!("{}", format!("✗ Turn {}", n + 1).red());
+            for diff in diffs {
+                println!("    {diff}");
+            }
+        }
+    }
+    if failed > 0 {
+        return Err(CliError::new(
+            ExitCode::Failure,
+            format!(
+                "{failed} of {} turns differ from the fixture",
+                fixture.turns.len()
+            ),
+        )
+        .into());
+    }
+    println!(
+        "{}",
+        format!("✓ All {} turns match the fixture", fixture.turns.len()).green()
+    );
+    Ok(())
+}
+
+const SHELL_HELP: &str = "\
+Type an input and press Enter. Input that parses as JSON is sent as-is,
+anything else as a string.
+
+  :save PATH   write the session so far as a fixture (replay with --replay)
+  :help        show this help
+  :quit        leave the shell (also Ctrl-D)";
+
+async fn run_shell(shell: &AgentShell, recorder: &RecordingLLMProvider) -> Result<()> {
+    let manifest = shell.manifest();
+    println!(
+        "{}",
+        format!(
+            "Agent shell: {} v{}. Type :help for commands.",
+            manifest.metadata.name, manifest.metadata.version
+        )
+        .bold()
+    );
+
+    let mut turns: Vec<ShellTurn> = Vec::new();
+    let mut lines = tokio::io::BufReader::new(tokio::io::stdin()).lines();
+    loop {
+        print!("{} ", ">".cyan().bold());
+        std::io::stdout().flush()?;
+        let Some(line) = lines.next_line().await? else {
+            println!();
+            break;
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(command) = line.strip_prefix(':') {
+            let (command, arg) = command.split_once(' ').unwrap_or((command, ""));
+            match command {
+                "quit" | "q" | "exit" => break,
+                "help" => println!("{SHELL_HELP}"),
+                "save" if !arg.trim().is_empty() => {
+                    let fixture = ShellFixture {
+                        agent: manifest.metadata.name.clone(),
+                        version: manifest.metadata.version.clone(),
+                        turns: turns.clone(),
+                        exchanges: recorder.exchanges(),
+                    };
+                    let path = Path::new(arg.trim());
+                    std::fs::write(path, serde_yaml::to_string(&fixture)?)
+                        .with_context(|| format!("Failed to write {}", path.display()))?;
+                    println!(
+                        "{}",
+                        format!("✓ Saved {} turns to {}", turns.len(), path.display()).green()
+                    );
+                }
+                "save" => println!("{}", "Usage: :save PATH".yellow()),
+                other => println!("{}", format!("Unknown command ':{other}'").yellow()),
+            }
+            continue;
+        }
+
+        let input = serde_json::from_str(line)
+            .unwrap_or_else(|_| serde_json::Value::String(line.to_string()));
+        match shell.send(input, print_shell_event).await {
+            Ok(turn) => {
+                match (&turn.output, &turn.error) {
+                    (Some(_), _) => println!(
+                        "{}",
+                        format!("✓ Accepted after {} iteration(s)", turn.iterations.len()).green()
+                    ),
+                    (None, error) => println!(
+                        "{}",
+                        format!("✗ {}", error.as_deref().unwrap_or("Execution failed")).red()
+                    ),
+                }
+                turns.push(turn);
+            }
+            Err(e) => println!("{}", format!("✗ {e}").red()),
+        }
+    }
+    Ok(())
+}
+
+fn print_shell_event(event: &ShellEvent) {
+    match event {
+        ShellEvent::Prompt { iteration, prompt } => {
+            println!("{}", format!("── Iteration {iteration} · prompt").dimmed());
+            println!("{}", prompt.dimmed());
+        }
+        ShellEvent::Response { iteration, output } => {
+            println!("{}", format!("── Iteration {iteration} · response").cyan());
+            println!("{output}");
+        }
+        ShellEvent::Failed { iteration, error } => {
+            println!(
+                "{}",
+                format!("✗ Iteration {iteration} failed: {error}").red()
+            );
+        }
+        ShellEvent::Verdict { iteration, verdict } => {
+            let score = verdict
+                .score
+                .map(|s| format!(" (score {s:.2})"))
+                .unwrap_or_default();
+            if verdict.passed {
+                println!(
+                    "{}",
+                    format!("✓ Iteration {iteration} passed validation{score}").green()
+                );
+            } else {
+                println!(
+                    "{}",
+                    format!("✗ Iteration {iteration} failed validation{score}").yellow()
+                );
+            }
+            if let Some(reasoning) = verdict.reasoning.as_deref().filter(|r| !r.is_empty()) {
+                println!("  {}", reasoning.dimmed());
+            }
+        }
+    }
+}
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: AGPL-3.0
 //! Configuration management commands
 //!
-//! Commands: show, validate, generate
+//! Commands: show, validate, generate, registry (add/list/remove)
 //!
 //! # Architecture
 //!
@@ -13,9 +13,9 @@ use anyhow::{Context, Result};
 use clap::Subcommand;
 use colored::Colorize;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
-use aegis_orchestrator_core::domain::node_config::NodeConfigManifest;
+use aegis_orchestrator_core::domain::node_config::{NodeConfigManifest, RegistryCredentials};
 
 use crate::output::{render_serialized, OutputFormat};
 
@@ -45,6 +45,52 @@ pub enum ConfigCommand {
         #[arg(long)]
         examples: bool,
     },
+
+    /// Manage private container registry credentials (spec.registry_credentials)
+    Registry {
+        #[command(subcommand)]
+        command: RegistryCommand,
+    },
+}
+
+/// Subcommands for `aegis config registry`.
+///
+/// Edits `spec.registry_credentials` in the config file selected by `--config`
+/// or discovery. The daemon picks up changes on restart.
+#[derive(Subcommand)]
+pub enum RegistryCommand {
+    /// Add or replace credentials for a registry host prefix
+    Add {
+        /// Registry host prefix matched against image references (e.g. ghcr.io)
+        #[arg(value_name = "REGISTRY")]
+        registry: String,
+
+        /// Registry username
+        #[arg(short, long)]
+        username: String,
+
+        /// Read the password from this environment variable on the daemon host
+        #[arg(long, value_name = "VAR", group = "password_source")]
+        password_env: Option<String>,
+
+        /// Read the password from the secrets provider (engine/path or engine/path#field)
+        #[arg(long, value_name = "REF", group = "password_source")]
+        password_secret: Option<String>,
+
+        /// Store the password in plaintext in the config file (not recommended)
+        #[arg(long, value_name = "PASSWORD", group = "password_source")]
+        password: Option<String>,
+    },
+
+    /// List configured registries (passwords are never printed)
+    List,
+
+    /// Remove credentials for a registry host prefix
+    Remove {
+        /// Registry host prefix to remove
+        #[arg(value_name = "REGISTRY")]
+        registry: String,
+    },
 }
 
 pub async fn handle_command(
@@ -56,6 +102,7 @@ pub async fn handle_command(
         ConfigCommand::Show { paths } => show(config_override, paths, output_format).await,
         ConfigCommand::Validate { file } => validate(file.or(config_override), output_format).await,
         ConfigCommand::Generate { out, examples } => generate(out, examples, output_format).await,
+        ConfigCommand::Registry { command } => registry(command, config_override, output_format),
     }
 }
 
@@ -79,6 +126,21 @@ struct ConfigGenerateOutput {
     examples: bool,
 }
 
+#[derive(Serialize)]
+struct RegistryEntryOutput {
+    registry: String,
+    username: String,
+    /// `env`, `secret`, or `plaintext`
+    password_source: &'static str,
+}
+
+#[derive(Serialize)]
+struct RegistryChangeOutput {
+    path: String,
+    registry: String,
+    action: &'static str,
+}
+
 async fn show(
     config_override: Option<PathBuf>,
     show_paths: bool,
@@ -255,3 +317,250 @@ async fn generate(output: PathBuf, with_examples: bool, output_format: OutputFor
 
     Ok(())
 }
+
+fn registry(
+    command: RegistryCommand,
+    config_override: Option<PathBuf>,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let path = config_override
+        .or_else(NodeConfigManifest::discover_config)
+        .context(
+            "No configuration file found; pass --config or run `aegis config generate` first",
+        )?;
+
+    match command {
+        RegistryCommand::Add {
+            registry,
+            username,
+            password_env,
+            password_secret,
+            password,
+        } => {
+            let password = match (password_env, password_secret, password) {
+                (Some(var), _, _) => format!("env:{var}"),
+                (_, Some(reference), _) => format!("secret:{reference}"),
+                (_, _, Some(plaintext)) => plaintext,
+                _ => anyhow::bail!(
+                    "one of --password-env, --password-secret, or --password is required"
+                ),
+            };
+            let cred = RegistryCredentials {
+                registry,
+                username,
+                password,
+            };
+            cred.validate()
+                .map_err(|e| anyhow::anyhow!("Invalid registry credentials: {e}"))?;
+            let replaced = upsert_registry_credentials(&path, &cred)?;
+
+            if output_format.is_structured() {
+                return render_serialized(
+                    output_format,
+                    &RegistryChangeOutput {
+                        path: path.display().to_string(),
+                        registry: cred.registry,
+                        action: if replaced { "replaced" } else { "added" },
+                    },
+                );
+            }
+            if cred.is_plaintext() {
+                eprintln!(
+                    "{} password stored in plaintext; prefer --password-env or --password-secret",
+                    "warning:".yellow().bold()
+                );
+            }
+            println!(
+                "{}",
+                format!(
+                    "✓ Registry credentials {} for {} in {}",
+                    if replaced { "replaced" } else { "added" },
+                    cred.registry,
+                    path.display()
+                )
+                .green()
+            );
+            println!("  Restart the daemon to apply.");
+            Ok(())
+        }
+        RegistryCommand::List => {
+            let config = NodeConfigManifest::from_yaml_file(&path)
+                .with_context(|| format!("Failed to load configuration from {path:?}"))?;
+            let entries: Vec<RegistryEntryOutput> = config
+                .spec
+                .registry_credentials
+                .iter()
+                .map(|cred| RegistryEntryOutput {
+                    registry: cred.registry.clone(),
+                    username: cred.username.clone(),
+                    password_source: password_source(cred),
+                })
+                .collect();
+
+            if output_format.is_structured() {
+                return render_serialized(output_format, &entries);
+            }
+            if entries.is_empty() {
+                println!("No registry credentials configured in {}", path.display());
+                return Ok(());
+            }
+            println!("{}", "Registry credentials:".bold());
+            for entry in entries {
+                println!(
+                    "  {} (user: {}, password: {})",
+                    entry.registry.bold(),
+                    entry.username,
+                    entry.password_source
+                );
+            }
+            Ok(())
+        }
+        RegistryCommand::Remove { registry } => {
+            if !remove_registry_credentials(&path, &registry)? {
+                anyhow::bail!("No registry credentials for '{registry}' in {path:?}");
+            }
+            if output_format.is_structured() {
+                return render_serialized(
+                    output_format,
+                    &RegistryChangeOutput {
+                        path: path.display().to_string(),
+                        registry,
+                        action: "removed",
+                    },
+                );
+            }
+            println!(
+                "{}",
+                format!("✓ Registry credentials removed for {registry}").green()
+            );
+            Ok(())
+        }
+    }
+}
+
+fn password_source(cred: &RegistryCredentials) -> &'static str {
+    if cred.password.starts_with("env:") {
+        "env"
+    } else if cred.secret_reference().is_some() {
+        "secret"
+    } else {
+        "plaintext"
+    }
+}
+
+/// Read the config file as a YAML value so unrelated keys round-trip untouched.
+fn read_config_value(path: &Path) -> Result<serde_yaml::Value> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read configuration from {path:?}"))?;
+    serde_yaml::from_str(&raw).with_context(|| format!("Failed to parse {path:?}"))
+}
+
+fn write_config_value(path: &Path, value: &serde_yaml::Value) -> Result<()> {
+    let updated = serde_yaml::to_string(value).context("Failed to serialize updated config")?;
+    std::fs::write(path, updated).with_context(|| format!("Failed to write {path:?}"))
+}
+
+fn registry_entries(value: &mut serde_yaml::Value) -> Result<&mut Vec<serde_yaml::Value>> {
+    let spec = value
+        .get_mut("spec")
+        .and_then(serde_yaml::Value::as_mapping_mut)
+        .context("Configuration has no 'spec' mapping")?;
+    let entries = spec
+        .entry(serde_yaml::Value::from("registry_credentials"))
+        .or_insert_with(|| serde_yaml::Value::Sequence(Vec::new()));
+    if entries.is_null() {
+        *entries = serde_yaml::Value::Sequence(Vec::new());
+    }
+    entries
+        .as_sequence_mut()
+        .context("spec.registry_credentials must be a list")
+}
+
+fn entry_registry(entry: &serde_yaml::Value) -> Option<&str> {
+    entry.get("registry").and_then(serde_yaml::Value::as_str)
+}
+
+/// Insert `cred`, replacing any entry for the same registry in place so the
+/// prefix-match priority order is preserved. Returns `true` when replaced.
+fn upsert_registry_credentials(path: &Path, cred: &RegistryCredentials) -> Result<bool> {
+    let mut value = read_config_value(path)?;
+    let entries = registry_entries(&mut value)?;
+    let new_entry = serde_yaml::to_value(cred).context("Failed to serialize registry entry")?;
+    let replaced = match entries
+        .iter_mut()
+        .find(|entry| entry_registry(entry) == Some(cred.registry.as_str()))
+    {
+        Some(existing) => {
+            *existing = new_entry;
+            true
+        }
+        None => {
+            entries.push(new_entry);
+            false
+        }
+    };
+    write_config_value(path, &value)?;
+    Ok(replaced)
+}
+
+/// Remove the entry for `registry`. Returns `false` when none existed.
+fn remove_registry_credentials(path: &Path, registry: &str) -> Result<bool> {
+    let mut value = read_config_value(path)?;
+    let entries = registry_entries(&mut value)?;
+    let before = entries.len();
+    entries.retain(|entry| entry_registry(entry) != Some(registry));
+    if entries.len() == before {
+        return Ok(false);
+    }
+    write_config_value(path, &value)?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cred(registry: &str, password: &str) -> RegistryCredentials {
+        RegistryCredentials {
+            registry: registry.to_string(),
+            username: "robot".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    #[test]
+    fn registry_add_replace_and_remove_round_trip() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let path = dir.path().join("aegis-config.yaml");
+        std::fs::write(&path, include_str!("../../templates/config-minimal.yaml"))
+            .expect("write config");
+
+        assert!(!upsert_registry_credentials(&path, &cred("ghcr.io", "env:GHCR_TOKEN")).unwrap());
+        assert!(!upsert_registry_credentials(&path, &cred("quay.io", "env:QUAY_TOKEN")).unwrap());
+        assert!(upsert_registry_credentials(
+            &path,
+            &cred("ghcr.io", "secret:kv/registries/ghcr#token")
+        )
+        .unwrap());
+
+        let config = NodeConfigManifest::from_yaml_file(&path).unwrap();
+        let registries: Vec<_> = config
+            .spec
+            .registry_credentials
+            .iter()
+            .map(|c| (c.registry.as_str(), c.password.as_str()))
+            .collect();
+        assert_eq!(
+            registries,
+            [
+                ("ghcr.io", "secret:kv/registries/ghcr#token"),
+                ("quay.io", "env:QUAY_TOKEN")
+            ]
+        );
+
+        assert!(remove_registry_credentials(&path, "ghcr.io").unwrap());
+        assert!(!remove_registry_credentials(&path, "ghcr.io").unwrap());
+        let config = NodeConfigManifest::from_yaml_file(&path).unwrap();
+        assert_eq!(config.spec.registry_credentials.len(), 1);
+    }
+}
@@ -17,6 +17,7 @@ use serde::Serialize;
 use std::path::PathBuf;
 
 use crate::daemon::{check_daemon_running, DaemonClient, DaemonStatus};
+use crate::exit_code::{CliError, ExitCode};
 use crate::output::{render_serialized, OutputFormat};
 
 /// Top-level `aegis credential` subcommands.
@@ -140,7 +141,7 @@ pub async fn handle_command(
                 format!("⚠ Daemon is running (PID: {pid}) but unhealthy: {error}").yellow()
             );
             println!("Run 'aegis daemon status' for more info.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
         _ => {
             println!(
@@ -148,7 +149,7 @@ pub async fn handle_command(
                 "Credential management requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: AGPL-3.0
 //! Daemon lifecycle management commands
 //!
-//! Commands: start, stop, status, install, uninstall
+//! Commands: start, stop, status, install, uninstall, gc, migrate
 //!
 //! # Architecture
 //!
@@ -16,14 +16,40 @@ use serde::Serialize;
 use std::path::PathBuf;
 use tracing::{info, warn};
 
-use crate::daemon::{check_daemon_running, stop_daemon, DaemonStatus};
+use crate::daemon::migrations::{self, MigrationPlan};
+use crate::daemon::recovery::{self, RecoveryReport};
+use crate::daemon::{check_daemon_running, stop_daemon, DaemonClient, DaemonStatus};
 use crate::output::{render_serialized, structured_output_unsupported, OutputFormat};
-use aegis_orchestrator_core::domain::node_config::NodeConfigManifest;
+use aegis_orchestrator_core::domain::node_config::{
+    resolve_env_value, MigrateMode, NodeConfigManifest,
+};
+use aegis_orchestrator_core::infrastructure::runtime::connect_container_runtime;
+use aegis_orchestrator_core::infrastructure::runtime_gc::RuntimeGarbageCollector;
 
 #[derive(Subcommand)]
 pub enum DaemonCommand {
     /// Start the daemon (if not already running)
-    Start,
+    ///
+    /// Recovers from an unclean exit first: removes a stale PID file and
+    /// refuses to start on a port held by something other than the daemon.
+    Start {
+        /// Also remove agent containers left running by the previous daemon
+        #[arg(long)]
+        reap_orphans: bool,
+
+        /// Startup migration mode: check, apply or skip
+        /// (default: spec.database.migrations.mode)
+        #[arg(long, value_name = "MODE")]
+        migrate: Option<MigrateMode>,
+    },
+
+    /// Apply pending database migrations, running the configured
+    /// pre-migration backup first
+    Migrate {
+        /// Only report applied and pending migrations; change nothing
+        #[arg(long)]
+        plan: bool,
+    },
 
     /// Stop the daemon gracefully
     Stop {
@@ -39,6 +65,18 @@ pub enum DaemonCommand {
     /// Check daemon status
     Status,
 
+    /// Put this node into maintenance: reject new executions, let running
+    /// ones finish and, in multi-node setups, requeue queued executions to
+    /// other nodes
+    Cordon {
+        /// Why the node is cordoned; returned by `GET /v1/node/maintenance`
+        #[arg(long)]
+        reason: Option<String>,
+    },
+
+    /// Take this node out of maintenance and accept new executions again
+    Uncordon,
+
     /// Install daemon as system service
     Install {
         /// Binary path (default: current executable)
@@ -52,6 +90,21 @@ pub enum DaemonCommand {
 
     /// Uninstall system service
     Uninstall,
+
+    /// Remove exited containers and old runtime images per spec.runtime.gc
+    Gc {
+        /// List what would be removed without removing anything
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Override spec.runtime.gc.keep_images_per_agent
+        #[arg(long, value_name = "N")]
+        keep_images: Option<usize>,
+
+        /// Override spec.runtime.gc.exited_container_max_age_seconds
+        #[arg(long, value_name = "SECONDS")]
+        max_container_age: Option<u64>,
+    },
 }
 
 pub async fn handle_command(
@@ -62,11 +115,27 @@ pub async fn handle_command(
     output_format: OutputFormat,
 ) -> Result<()> {
     match command {
-        DaemonCommand::Start => start(config_path, host, port, output_format).await,
+        DaemonCommand::Start {
+            reap_orphans,
+            migrate,
+        } => {
+            start(
+                config_path,
+                host,
+                port,
+                reap_orphans,
+                migrate,
+                output_format,
+            )
+            .await
+        }
+        DaemonCommand::Migrate { plan } => migrate(config_path, plan, output_format).await,
         DaemonCommand::Stop { force, timeout } => {
             stop(force, timeout, host, port, output_format).await
         }
         DaemonCommand::Status => status(host, port, output_format).await,
+        DaemonCommand::Cordon { reason } => cordon(reason, host, port, output_format).await,
+        DaemonCommand::Uncordon => uncordon(host, port, output_format).await,
         DaemonCommand::Install { binary_path, user } => {
             if output_format.is_structured() {
                 structured_output_unsupported("aegis daemon install", output_format)
@@ -81,6 +150,20 @@ pub async fn handle_command(
                 uninstall().await
             }
         }
+        DaemonCommand::Gc {
+            dry_run,
+            keep_images,
+            max_container_age,
+        } => {
+            gc(
+                config_path,
+                dry_run,
+                keep_images,
+                max_container_age,
+                output_format,
+            )
+            .await
+        }
     }
 }
 
@@ -90,6 +173,8 @@ struct DaemonStartOutput {
     pid: u32,
     stdout_log: String,
     stderr_log: String,
+    #[serde(skip_serializing_if = "RecoveryReport::is_empty")]
+    recovery: RecoveryReport,
 }
 
 #[derive(Serialize)]
@@ -112,10 +197,176 @@ struct DaemonStatusOutput {
     error: Option<String>,
 }
 
+/// Run one garbage-collection pass against the local container engine.
+///
+/// Talks to the engine directly rather than through the daemon, so it also
+/// works while the daemon is stopped.
+async fn gc(
+    config_path: Option<PathBuf>,
+    dry_run: bool,
+    keep_images: Option<usize>,
+    max_container_age: Option<u64>,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let config =
+        NodeConfigManifest::load_or_default(config_path).context("Failed to load configuration")?;
+    let mut gc_config = config.spec.runtime.gc.clone();
+    if let Some(keep) = keep_images {
+        gc_config.keep_images_per_agent = keep;
+    }
+    if let Some(age) = max_container_age {
+        gc_config.exited_container_max_age_seconds = age;
+    }
+
+    let docker = connect_container_runtime(config.spec.runtime.container_socket_path.as_deref())
+        .context("Failed to connect to container runtime")?;
+    let report = RuntimeGarbageCollector::new(docker, gc_config)
+        .run(dry_run)
+        .await
+        .context("Garbage collection failed")?;
+
+    if output_format.is_structured() {
+        return render_serialized(output_format, &report);
+    }
+
+    let verb = if dry_run { "Would remove" } else { "Removed" };
+    println!(
+        "{} {verb} {} container(s) and {} image(s), {:.1} MiB reclaimable",
+        if dry_run {
+            "→".dimmed()
+        } else {
+            "✓".green()
+        },
+        report.containers_removed.len(),
+        report.images_removed.len(),
+        report.bytes_reclaimed as f64 / (1024.0 * 1024.0)
+    );
+    for id in &report.containers_removed {
+        println!("  container {}", short_id(id));
+    }
+    for id in &report.images_removed {
+        println!("  image     {}", short_id(id));
+    }
+    if !report.skipped.is_empty() {
+        println!(
+            "  {} {} object(s) skipped (still in use)",
+            "!".yellow(),
+            report.skipped.len()
+        );
+    }
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct DaemonMigrateOutput {
+    status: &'static str,
+    plan: MigrationPlan,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    backup_file: Option<PathBuf>,
+}
+
+/// Report or apply pending migrations against `spec.database`.
+///
+/// Connects to the database directly, so it works while the daemon is
+/// stopped; a running daemon is unaffected until restarted.
+async fn migrate(
+    config_path: Option<PathBuf>,
+    plan_only: bool,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let config =
+        NodeConfigManifest::load_or_default(config_path).context("Failed to load configuration")?;
+    let db_config = config
+        .spec
+        .database
+        .as_ref()
+        .context("spec.database not configured in aegis-config.yaml")?;
+    let database_url =
+        resolve_env_value(&db_config.url).context("Failed to resolve spec.database.url")?;
+    let pool = sqlx::postgres::PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .context("Failed to connect to database")?;
+
+    let plan = migrations::plan(&pool).await?;
+    let (status, backup_file) = if plan_only {
+        ("planned", None)
+    } else if plan.has_conflicts() {
+        if !output_format.is_structured() {
+            print_migration_plan(&plan);
+        }
+        anyhow::bail!("Migration history conflicts with this binary; nothing was applied");
+    } else if plan.is_up_to_date() {
+        ("up_to_date", None)
+    } else {
+        let backup = migrations::apply(&pool, &database_url, db_config, &plan).await?;
+        ("applied", backup)
+    };
+
+    if output_format.is_structured() {
+        return render_serialized(
+            output_format,
+            &DaemonMigrateOutput {
+                status,
+                plan,
+                backup_file,
+            },
+        );
+    }
+    print_migration_plan(&plan);
+    if let Some(file) = &backup_file {
+        println!("{} Backup written to {}", "✓".green(), file.display());
+    }
+    match status {
+        "applied" => println!(
+            "{} {} migration(s) applied",
+            "✓".green(),
+            plan.pending.len()
+        ),
+        "up_to_date" => println!("{} Database schema is up to date", "✓".green()),
+        _ => {}
+    }
+    Ok(())
+}
+
+fn print_migration_plan(plan: &MigrationPlan) {
+    println!(
+        "Applied: {} (current version: {})",
+        plan.applied,
+        plan.current_version
+            .map_or_else(|| "none".to_string(), |v| v.to_string())
+    );
+    if plan.pending.is_empty() {
+        println!("Pending: none");
+    } else {
+        println!("Pending: {}", plan.pending.len());
+        for migration in &plan.pending {
+            println!("  {:>4}  {}", migration.version, migration.description);
+        }
+    }
+    for (label, versions) in [
+        ("Checksum mismatch", &plan.checksum_mismatches),
+        ("Incomplete", &plan.dirty),
+        ("Unknown to this binary", &plan.unknown),
+    ] {
+        if !versions.is_empty() {
+            println!("{} {label}: {versions:?}", "!".yellow());
+        }
+    }
+}
+
+fn short_id(id: &str) -> &str {
+    let id = id.strip_prefix("sha256:").unwrap_or(id);
+    &id[..id.len().min(12)]
+}
+
 async fn start(
     config_path: Option<PathBuf>,
     host: &str,
     port: u16,
+    reap_orphans: bool,
+    migrate_mode: Option<MigrateMode>,
     output_format: OutputFormat,
 ) -> Result<()> {
     // 1. Validation: Load config to check for existence and validity
@@ -135,6 +386,11 @@ async fn start(
         println!("         Please check your config file or use --config <path>.");
     }
 
+    let mut recovery = RecoveryReport {
+        stale_pid_file: recovery::clear_stale_pid_file()?,
+        ..Default::default()
+    };
+
     info!("Checking if daemon is already running...");
 
     match check_daemon_running(host, port).await {
@@ -147,6 +403,7 @@ async fn start(
                         pid,
                         stdout_log: std::env::temp_dir().join("aegis.out").display().to_string(),
                         stderr_log: std::env::temp_dir().join("aegis.err").display().to_string(),
+                        recovery,
                     },
                 );
             }
@@ -158,6 +415,7 @@ async fn start(
             return Ok(());
         }
         Ok(DaemonStatus::Stopped) => {
+            recovery::ensure_port_free(host, port)?;
             info!("Daemon not running, starting...");
         }
         Ok(DaemonStatus::Unhealthy { pid, error }) => {
@@ -172,6 +430,13 @@ async fn start(
         }
     }
 
+    if reap_orphans {
+        let docker =
+            connect_container_runtime(config.spec.runtime.container_socket_path.as_deref())
+                .context("Failed to connect to container runtime")?;
+        recovery::reap_orphaned_containers(&docker, &mut recovery).await?;
+    }
+
     // Re-exec self with --daemon flag
     let current_exe = std::env::current_exe().context("Failed to get current executable path")?;
 
@@ -179,6 +444,9 @@ async fn start(
     cmd.arg("--daemon");
     cmd.arg("--host").arg(host);
     cmd.arg("--port").arg(port.to_string());
+    if let Some(mode) = migrate_mode {
+        cmd.arg("--migrate").arg(mode.to_string());
+    }
 
     if let Some(config) = config_path {
         cmd.arg("--config").arg(config);
@@ -214,10 +482,12 @@ async fn start(
                 pid: child.id(),
                 stdout_log: stdout_path.display().to_string(),
                 stderr_log: stderr_path.display().to_string(),
+                recovery,
             },
         );
     }
 
+    print_recovery_report(&recovery);
     println!("Redirecting logs to: {}", stdout_path.display());
     println!(
         "{}",
@@ -228,6 +498,35 @@ async fn start(
     Ok(())
 }
 
+fn print_recovery_report(report: &RecoveryReport) {
+    if let Some(stale) = &report.stale_pid_file {
+        let reason = match stale.reason {
+            "pid_reused" => "PID now belongs to another process",
+            "unreadable" => "PID file was unreadable",
+            _ => "process no longer running",
+        };
+        println!(
+            "{} Removed stale PID file (PID {}: {reason})",
+            "✓".green(),
+            stale.pid
+        );
+    }
+    if !report.reaped_containers.is_empty() {
+        println!(
+            "{} Removed {} orphaned agent container(s) from the previous run",
+            "✓".green(),
+            report.reaped_containers.len()
+        );
+    }
+    for id in &report.reap_failures {
+        println!(
+            "{} Could not remove orphaned container {}",
+            "!".yellow(),
+            short_id(id)
+        );
+    }
+}
+
 async fn stop(
     force: bool,
     timeout: u64,
@@ -339,6 +638,55 @@ async fn status(host: &str, port: u16, output_format: OutputFormat) -> Result<()
     Ok(())
 }
 
+async fn cordon(
+    reason: Option<String>,
+    host: &str,
+    port: u16,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let client = maintenance_client(host, port).await?;
+    let outcome = client.cordon_node(reason.as_deref()).await?;
+    if output_format.is_structured() {
+        return render_serialized(output_format, &outcome);
+    }
+
+    println!(
+        "{}",
+        "✓ Node cordoned: new executions are rejected, running ones will finish".green()
+    );
+    let requeued = outcome["requeued"].as_array().map(Vec::len).unwrap_or(0);
+    if requeued > 0 {
+        println!("  Requeued {requeued} queued execution(s) to other nodes");
+    }
+    let queued = outcome["queued_executions"].as_u64().unwrap_or(0);
+    if queued > 0 {
+        println!("  {queued} execution(s) still queued on this node");
+    }
+    Ok(())
+}
+
+async fn uncordon(host: &str, port: u16, output_format: OutputFormat) -> Result<()> {
+    let client = maintenance_client(host, port).await?;
+    let status = client.uncordon_node().await?;
+    if output_format.is_structured() {
+        return render_serialized(output_format, &status);
+    }
+
+    println!("{}", "✓ Node uncordoned: accepting new executions".green());
+    Ok(())
+}
+
+async fn maintenance_client(host: &str, port: u16) -> Result<DaemonClient> {
+    if !matches!(
+        check_daemon_running(host, port).await?,
+        DaemonStatus::Running { .. }
+    ) {
+        anyhow::bail!("The daemon is not running. Run 'aegis daemon start' first.");
+    }
+    let auth_key = crate::auth::require_key().await?;
+    Ok(DaemonClient::new(host, port)?.with_auth(auth_key))
+}
+
 async fn install(_binary_path: Option<PathBuf>, _user: Option<String>) -> Result<()> {
     #[cfg(unix)]
     {
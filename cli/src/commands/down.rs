@@ -20,9 +20,10 @@ use std::thread;
 use anyhow::{bail, Context, Result};
 use clap::Args;
 use colored::Colorize;
-use dialoguer::Confirm;
 use indicatif::{ProgressBar, ProgressStyle};
 
+use crate::util::prompt::confirm;
+
 /// Arguments for `aegis down`
 #[derive(Args)]
 pub struct DownArgs {
@@ -65,10 +66,10 @@ pub async fn run(args: DownArgs) -> Result<()> {
             "⚠".yellow().bold()
         );
         if !args.yes {
-            let confirmed = Confirm::new()
-                .with_prompt("This will permanently destroy local AEGIS data volumes. Continue?")
-                .default(false)
-                .interact()?;
+            let confirmed = confirm(
+                "This will permanently destroy local AEGIS data volumes. Continue?",
+                false,
+            )?;
             if !confirmed {
                 println!("  Aborted — no changes made.");
                 return Ok(());
@@ -21,6 +21,7 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::auth::load_store;
+use crate::exit_code::CliError;
 
 /// Canonical tenant header consumed by `tenant_context_middleware` per
 /// ADR-100/-111. Centralized so the value cannot drift between the request
@@ -158,7 +159,7 @@ impl EdgeApiClient {
                 .text()
                 .await
                 .unwrap_or_else(|_| String::from("<unreadable response body>"));
-            Err(anyhow!("{status}: {body}"))
+            Err(CliError::from_status(status, format!("{status}: {body}")).into())
         }
     }
 
@@ -181,7 +182,7 @@ impl EdgeApiClient {
         let bytes = resp.bytes().await.context("read body")?;
         if !status.is_success() {
             let body = String::from_utf8_lossy(&bytes);
-            return Err(anyhow!("{status}: {body}"));
+            return Err(CliError::from_status(status, format!("{status}: {body}")).into());
         }
         serde_json::from_slice(&bytes)
             .with_context(|| format!("decode response: {}", String::from_utf8_lossy(&bytes)))
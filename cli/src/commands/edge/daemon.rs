@@ -164,6 +164,7 @@ pub async fn run(args: DaemonArgs) -> Result<()> {
                             max_response_size: None,
                             rate_limit: None,
                             max_concurrent: None,
+                            conditions: cap.conditions.clone(),
                         })
                         .collect(),
                     deny_list: def.deny_list.clone(),
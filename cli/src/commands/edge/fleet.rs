@@ -7,6 +7,7 @@ use serde::{Deserialize, Serialize};
 
 use super::client::EdgeApiClient;
 use super::selector;
+use crate::exit_code::CliError;
 use crate::output::OutputFormat;
 
 #[derive(Debug, Subcommand)]
@@ -109,7 +110,7 @@ pub async fn run(cmd: FleetCommand, output: OutputFormat) -> Result<()> {
                     .text()
                     .await
                     .unwrap_or_else(|_| String::from("<unreadable response body>"));
-                return Err(anyhow::anyhow!("{status}: {txt}"));
+                return Err(CliError::from_status(status, format!("{status}: {txt}")).into());
             }
             // Read SSE-style stream of `event:` / `data:` frames; print
             // every `data:` payload on its own line.
@@ -145,7 +146,7 @@ pub async fn run(cmd: FleetCommand, output: OutputFormat) -> Result<()> {
                     .text()
                     .await
                     .unwrap_or_else(|_| String::from("<unreadable response body>"));
-                return Err(anyhow::anyhow!("{status}: {txt}"));
+                return Err(CliError::from_status(status, format!("{status}: {txt}")).into());
             }
         }
         FleetCommand::Runs {} => {
@@ -17,6 +17,7 @@ use std::time::Duration;
 
 use super::client::EdgeApiClient;
 use super::grpc;
+use crate::util::prompt::require_interactive;
 
 #[derive(Debug, Subcommand)]
 pub enum KeysCommand {
@@ -64,6 +65,7 @@ async fn rotate(keep_old: &str, force: bool) -> Result<()> {
     }
 
     if !force {
+        require_interactive("Key rotation confirmation", "pass --force")?;
         let proceed = Confirm::new()
             .with_prompt(
                 "Rotate edge daemon keypair? This is destructive if the \
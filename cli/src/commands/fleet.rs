@@ -0,0 +1,269 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Fleet commands spanning every configured orchestrator node
+//!
+//! The fleet is the daemon at `--host`/`--port` plus every remote host alias
+//! (`aegis remote add`), each reached through its own SSH tunnel.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Interface / Presentation Layer
+//! - **Purpose:** Implements aegis fleet status
+
+use anyhow::{anyhow, Result};
+use clap::Subcommand;
+use colored::Colorize;
+use futures::future::join_all;
+use serde::Serialize;
+use std::time::Duration;
+
+use crate::auth::RemoteHost;
+use crate::daemon::DaemonClient;
+use crate::output::{render_serialized, OutputFormat};
+use crate::remote::SshTunnel;
+
+/// Upper bound on executions counted per node.
+const RUNNING_EXECUTIONS_LIMIT: usize = 1000;
+
+#[derive(Debug, Subcommand)]
+pub enum FleetCommand {
+    /// Show health, version, running executions and degraded subsystems of
+    /// every node
+    Status {
+        /// Per-node timeout in seconds, including opening the SSH tunnel
+        #[arg(long, default_value = "30")]
+        timeout: u64,
+
+        /// Only query remote host aliases, not the daemon at --host/--port
+        #[arg(long)]
+        remotes_only: bool,
+    },
+}
+
+#[derive(Debug, Serialize)]
+struct FleetNodeRow {
+    node: String,
+    endpoint: String,
+    /// `ready`, `degraded` or `unreachable`
+    health: String,
+    version: Option<String>,
+    uptime_seconds: Option<u64>,
+    running_executions: Option<usize>,
+    degraded: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl FleetNodeRow {
+    fn unreachable(node: String, endpoint: String, error: String) -> Self {
+        Self {
+            node,
+            endpoint,
+            health: "unreachable".to_string(),
+            version: None,
+            uptime_seconds: None,
+            running_executions: None,
+            degraded: Vec::new(),
+            error: Some(error),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct FleetStatusOutput {
+    nodes: Vec<FleetNodeRow>,
+}
+
+pub async fn handle_command(
+    command: FleetCommand,
+    host: &str,
+    port: u16,
+    output_format: OutputFormat,
+) -> Result<()> {
+    match command {
+        FleetCommand::Status {
+            timeout,
+            remotes_only,
+        } => status(host, port, timeout, remotes_only, output_format).await,
+    }
+}
+
+async fn status(
+    host: &str,
+    port: u16,
+    timeout_secs: u64,
+    remotes_only: bool,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let remotes = crate::auth::load_store()?.remotes;
+    if remotes.is_empty() && remotes_only {
+        anyhow::bail!("No remotes configured. Add one with 'aegis remote add'.");
+    }
+    // Nodes without a valid key still report health; only counts need auth.
+    let auth_key = crate::auth::require_key().await.ok();
+    let timeout = Duration::from_secs(timeout_secs);
+
+    let local = async {
+        if remotes_only {
+            return None;
+        }
+        let endpoint = format!("{host}:{port}");
+        let query = query_node(
+            "local".to_string(),
+            host.to_string(),
+            port,
+            endpoint.clone(),
+            auth_key.clone(),
+        );
+        Some(with_timeout("local".to_string(), endpoint, timeout, query).await)
+    };
+    let remote_rows = join_all(remotes.into_iter().map(|(name, remote)| {
+        let endpoint = format!("{}:{}", remote.destination, remote.api_port);
+        let query = query_remote(name.clone(), remote, auth_key.clone());
+        with_timeout(name, endpoint, timeout, query)
+    }));
+    let (local, remote_rows) = futures::join!(local, remote_rows);
+    let rows: Vec<FleetNodeRow> = local.into_iter().chain(remote_rows).collect();
+
+    if output_format.is_structured() {
+        render_serialized(output_format, &FleetStatusOutput { nodes: rows })?;
+    } else {
+        print_fleet_table(&rows);
+    }
+
+    let unhealthy = rows.iter().filter(|row| row.health != "ready").count();
+    if unhealthy > 0 {
+        return Err(anyhow!(
+            "{unhealthy} of {} fleet nodes are not ready",
+            rows.len()
+        ));
+    }
+    Ok(())
+}
+
+async fn with_timeout(
+    node: String,
+    endpoint: String,
+    timeout: Duration,
+    query: impl std::future::Future<Output = FleetNodeRow>,
+) -> FleetNodeRow {
+    tokio::time::timeout(timeout, query)
+        .await
+        .unwrap_or_else(|_| {
+            FleetNodeRow::unreachable(
+                node,
+                endpoint,
+                format!("timed out after {}s", timeout.as_secs()),
+            )
+        })
+}
+
+async fn query_remote(name: String, remote: RemoteHost, auth_key: Option<String>) -> FleetNodeRow {
+    let endpoint = format!("{}:{}", remote.destination, remote.api_port);
+    match SshTunnel::open(&remote).await {
+        Ok(tunnel) => {
+            // Keep the tunnel open until the queries through it finish.
+            let row = query_node(
+                name,
+                "127.0.0.1".to_string(),
+                tunnel.api_port,
+                endpoint,
+                auth_key,
+            )
+            .await;
+            drop(tunnel);
+            row
+        }
+        Err(e) => FleetNodeRow::unreachable(name, endpoint, e.to_string()),
+    }
+}
+
+async fn query_node(
+    node: String,
+    host: String,
+    port: u16,
+    endpoint: String,
+    auth_key: Option<String>,
+) -> FleetNodeRow {
+    let client = match DaemonClient::new(&host, port) {
+        Ok(client) => client,
+        Err(e) => return FleetNodeRow::unreachable(node, endpoint, e.to_string()),
+    };
+    let readiness = match client.readiness().await {
+        Ok(readiness) => readiness,
+        Err(e) => return FleetNodeRow::unreachable(node, endpoint, e.to_string()),
+    };
+
+    let mut error = None;
+    let running_executions = match auth_key {
+        Some(key) => match client
+            .with_auth(key)
+            .list_executions(None, RUNNING_EXECUTIONS_LIMIT, Some("status=running"))
+            .await
+        {
+            Ok(executions) => Some(executions.len()),
+            Err(e) => {
+                error = Some(e.to_string());
+                None
+            }
+        },
+        None => None,
+    };
+
+    FleetNodeRow {
+        node,
+        endpoint,
+        health: readiness.status,
+        version: readiness.version,
+        uptime_seconds: readiness.uptime_seconds,
+        running_executions,
+        degraded: readiness
+            .dependencies
+            .into_iter()
+            .filter(|(_, ready)| !ready)
+            .map(|(name, _)| name)
+            .collect(),
+        error,
+    }
+}
+
+fn print_fleet_table(rows: &[FleetNodeRow]) {
+    println!();
+    println!(
+        "{:<16} {:<12} {:<10} {:<10} {:<24} {}",
+        "NODE".bold(),
+        "HEALTH".bold(),
+        "VERSION".bold(),
+        "RUNNING".bold(),
+        "DEGRADED".bold(),
+        "ENDPOINT".bold()
+    );
+    println!("{}", "-".repeat(100));
+
+    for row in rows {
+        let health = match row.health.as_str() {
+            "ready" => row.health.green(),
+            "degraded" => row.health.yellow(),
+            _ => row.health.red(),
+        };
+        let degraded = if row.degraded.is_empty() {
+            "-".to_string()
+        } else {
+            row.degraded.join(",")
+        };
+        println!(
+            "{:<16} {:<12} {:<10} {:<10} {:<24} {}",
+            row.node.as_str(),
+            health,
+            row.version.as_deref().unwrap_or("n/a"),
+            row.running_executions
+                .map_or_else(|| "n/a".to_string(), |count| count.to_string()),
+            degraded,
+            row.endpoint.cyan()
+        );
+        if let Some(error) = &row.error {
+            println!("  {}", error.dimmed());
+        }
+    }
+    println!();
+}
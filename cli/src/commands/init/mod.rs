@@ -42,6 +42,7 @@ use self::download::fetch_stack;
 use self::prereqs::PrereqChecker;
 use self::verify::HealthChecker;
 use super::update::{run_database_migrations, sync_builtins};
+use crate::util::prompt::require_interactive;
 
 /// Arguments for `aegis init`
 #[derive(Args)]
@@ -99,6 +100,10 @@ pub async fn run(args: InitArgs) -> Result<()> {
     );
     eprintln!();
 
+    if !args.yes {
+        require_interactive("The setup wizard", "pass --yes to accept the defaults")?;
+    }
+
     // ─── Step 1: Select components ────────────────────────────────────────────
     print_step(1, 8, "Select components");
     let selector = ComponentSelector::new(args.yes);
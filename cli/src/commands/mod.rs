@@ -15,15 +15,18 @@ pub mod credential;
 pub mod daemon;
 pub mod down;
 pub mod edge;
+pub mod fleet;
 pub mod fuse_daemon;
 pub mod init;
 pub mod node;
+pub mod remote;
 pub mod restart;
 pub mod secret;
 pub mod status;
 pub mod task;
 pub mod uninstall;
 pub mod up;
+pub mod volume;
 pub mod workflow;
 
 pub use self::agent::AgentCommand;
@@ -31,15 +34,18 @@ pub use self::config::ConfigCommand;
 pub use self::credential::CredentialCommand;
 pub use self::daemon::DaemonCommand;
 pub use self::down::DownArgs;
+pub use self::fleet::FleetCommand;
 pub use self::fuse_daemon::FuseDaemonCommand;
 pub use self::init::InitArgs;
 pub use self::node::NodeCommand;
+pub use self::remote::RemoteCommand;
 pub use self::restart::RestartArgs;
 pub use self::secret::SecretCommand;
 pub use self::status::StatusArgs;
 pub use self::task::TaskCommand;
 pub use self::uninstall::UninstallArgs;
 pub use self::up::UpArgs;
+pub use self::volume::VolumeCommand;
 pub use self::workflow::WorkflowCommand;
 pub mod update;
 pub use self::update::UpdateCommand;
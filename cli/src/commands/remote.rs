@@ -0,0 +1,127 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Remote host alias commands for `aegis --remote <alias>`
+//!
+//! Aliases live in the profiles file (`~/.aegis/auth.json`) next to the auth
+//! profiles.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Interface / Presentation Layer
+//! - **Purpose:** Implements aegis remote add/list/remove commands
+
+use anyhow::Result;
+use clap::Subcommand;
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use crate::auth::{self, RemoteHost};
+use crate::exit_code::{CliError, ExitCode};
+use crate::output::{render_serialized, OutputFormat};
+
+#[derive(Debug, Subcommand)]
+pub enum RemoteCommand {
+    /// Save (or replace) a remote host alias.
+    Add {
+        /// Alias used with `--remote`.
+        name: String,
+        /// SSH destination, e.g. `ops@edge-07.example.com`.
+        destination: String,
+        /// SSH port on the remote host.
+        #[arg(long)]
+        ssh_port: Option<u16>,
+        /// SSH identity file.
+        #[arg(long, value_name = "FILE")]
+        identity: Option<PathBuf>,
+        /// Daemon HTTP API port on the remote host.
+        #[arg(long, default_value = "8088")]
+        api_port: u16,
+        /// Daemon gRPC port on the remote host to forward as well.
+        #[arg(long)]
+        grpc_port: Option<u16>,
+        /// `aegis` binary on the remote host for `daemon`/`up`/`down`/`restart`.
+        #[arg(long, default_value = "aegis")]
+        command: String,
+    },
+    /// List remote host aliases.
+    List,
+    /// Remove a remote host alias.
+    Remove {
+        /// Alias to remove.
+        name: String,
+    },
+}
+
+#[derive(Serialize)]
+struct RemoteListOutput {
+    remotes: BTreeMap<String, RemoteHost>,
+}
+
+pub async fn handle_command(command: RemoteCommand, output_format: OutputFormat) -> Result<()> {
+    match command {
+        RemoteCommand::Add {
+            name,
+            destination,
+            ssh_port,
+            identity,
+            api_port,
+            grpc_port,
+            command,
+        } => {
+            if name.contains('@') {
+                anyhow::bail!("Remote alias '{name}' must not contain '@'");
+            }
+            let remote = RemoteHost {
+                ssh_port,
+                identity_file: identity,
+                api_port,
+                grpc_port,
+                command,
+                ..RemoteHost::new(destination)
+            };
+            let mut store = auth::load_store()?;
+            store.remotes.insert(name.clone(), remote);
+            auth::save_store(&store)?;
+            println!("{} Saved remote '{}'.", "✓".green(), name.cyan());
+            Ok(())
+        }
+        RemoteCommand::List => {
+            let remotes = auth::load_store()?.remotes;
+            if output_format.is_structured() {
+                return render_serialized(output_format, &RemoteListOutput { remotes });
+            }
+            if remotes.is_empty() {
+                println!("No remotes configured. Add one with 'aegis remote add'.");
+                return Ok(());
+            }
+            for (name, remote) in &remotes {
+                let ssh_port = remote
+                    .ssh_port
+                    .map(|port| format!(":{port}"))
+                    .unwrap_or_default();
+                println!(
+                    "{:16} {}{ssh_port} (api {})",
+                    name.bold(),
+                    remote.destination,
+                    remote.api_port
+                );
+            }
+            Ok(())
+        }
+        RemoteCommand::Remove { name } => {
+            let mut store = auth::load_store()?;
+            if store.remotes.remove(&name).is_none() {
+                return Err(CliError::new(
+                    ExitCode::NotFound,
+                    format!("Remote '{name}' not found."),
+                )
+                .into());
+            }
+            auth::save_store(&store)?;
+            println!("{} Removed remote '{}'.", "✓".green(), name.cyan());
+            Ok(())
+        }
+    }
+}
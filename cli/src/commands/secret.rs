@@ -16,6 +16,7 @@ use serde::Serialize;
 use std::path::PathBuf;
 
 use crate::daemon::{check_daemon_running, DaemonClient, DaemonStatus};
+use crate::exit_code::{CliError, ExitCode};
 use crate::output::{render_serialized, OutputFormat};
 
 #[derive(Subcommand)]
@@ -76,7 +77,7 @@ pub async fn handle_command(
                 format!("⚠ Daemon is running (PID: {pid}) but unhealthy: {error}").yellow()
             );
             println!("Run 'aegis daemon status' for more info.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
         _ => {
             println!(
@@ -84,7 +85,7 @@ pub async fn handle_command(
                 "Secret management requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
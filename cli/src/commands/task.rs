@@ -2,24 +2,43 @@
 // SPDX-License-Identifier: AGPL-3.0
 //! Agent task operations commands
 //!
-//! Commands: deploy, execute, status, logs, cancel
+//! Commands: deploy, execute, status, attachments, logs, cancel, requeue
+//!
+//! `cancel --filter` and `requeue --filter` act on every execution matching a
+//! filter expression: they preview the matches, ask for confirmation (skip
+//! with `--yes`) and follow the daemon's batched progress to the end.
+//!
+//! `execute --wait` on a terminal follows the execution live (a spinner per
+//! iteration, judge scores as they arrive, then a summary table); otherwise it
+//! polls quietly and prints the final status.
 //!
 //! # Architecture
 //!
 //! - **Layer:** Interface / Presentation Layer
 //! - **Purpose:** Implements internal responsibilities for task
 
+use aegis_orchestrator_core::domain::events::CorrelatedActivityEvent;
+use aegis_orchestrator_core::domain::execution::OUTPUT_ATTACHMENT_DIR;
+use aegis_orchestrator_core::domain::node_config::NodeConfigManifest;
 use anyhow::{Context, Result};
 use clap::Subcommand;
 use colored::Colorize;
+use indicatif::{ProgressBar, ProgressStyle};
 use serde::Serialize;
+use serde_json::Value;
+use std::collections::{BTreeMap, HashMap};
+use std::io::IsTerminal;
+use std::ops::ControlFlow;
 use std::path::PathBuf;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 use tracing::info;
 use uuid::Uuid;
 
+use crate::daemon::client::canonical_event_type;
 use crate::daemon::{check_daemon_running, DaemonClient, DaemonStatus};
+use crate::exit_code::{CliError, ExitCode};
 use crate::output::{render_serialized, structured_output_unsupported, OutputFormat};
+use crate::util::prompt::confirm;
 
 #[derive(Subcommand)]
 pub enum TaskCommand {
@@ -55,11 +74,28 @@ pub enum TaskCommand {
         #[arg(long = "attachment", value_name = "VOLUME_ID:PATH")]
         attachment: Vec<String>,
 
+        /// Upload a local file with the request and attach it. Repeatable;
+        /// combines with `--attachments`/`--attachment`.
+        #[arg(long = "file", value_name = "PATH")]
+        files: Vec<PathBuf>,
+
         /// Target a specific agent version (default: latest)
         #[arg(long, value_name = "VERSION")]
         version: Option<String>,
 
-        /// Wait for execution to complete
+        /// Label the execution, e.g. `--label customer=acme --label release-check`.
+        /// Repeatable; child executions inherit labels.
+        #[arg(long = "label", value_name = "KEY[=VALUE]", value_parser = parse_label)]
+        labels: Vec<(String, String)>,
+
+        /// Record the connections the agent opens (addresses, ports, TLS SNI
+        /// and DNS names; no payloads). Requires the tenant's
+        /// `network_flow_capture` feature flag.
+        #[arg(long)]
+        network_flows: bool,
+
+        /// Wait for execution to complete, showing live iteration progress
+        /// and a summary when stdout is a terminal
         #[arg(short, long)]
         wait: bool,
 
@@ -73,6 +109,34 @@ pub enum TaskCommand {
         /// Execution ID
         #[arg(value_name = "EXECUTION_ID")]
         execution_id: Uuid,
+
+        /// Also show which files each iteration read, wrote, created or deleted
+        #[arg(long)]
+        files: bool,
+
+        /// Also summarize the network flows recorded for the execution
+        #[arg(long)]
+        network: bool,
+    },
+
+    /// Explain an execution: one annotated timeline of iterations, LLM and
+    /// tool calls, validation, Cortex injections and storage violations
+    Explain {
+        /// Execution ID
+        #[arg(value_name = "EXECUTION_ID")]
+        execution_id: Uuid,
+    },
+
+    /// List an execution's output attachments (files the agent wrote under
+    /// /workspace/outputs/)
+    Attachments {
+        /// Execution ID
+        #[arg(value_name = "EXECUTION_ID")]
+        execution_id: Uuid,
+
+        /// Download the attachments into this directory
+        #[arg(long, value_name = "DIR")]
+        download: Option<PathBuf>,
     },
 
     /// Stream execution logs
@@ -94,15 +158,50 @@ pub enum TaskCommand {
         verbose: bool,
     },
 
-    /// Cancel running execution
+    /// Cancel a running execution, or every pending or running execution
+    /// matching --filter
     Cancel {
         /// Execution ID
-        #[arg(value_name = "EXECUTION_ID")]
-        execution_id: Uuid,
+        #[arg(
+            value_name = "EXECUTION_ID",
+            required_unless_present = "filter",
+            conflicts_with = "filter"
+        )]
+        execution_id: Option<Uuid>,
 
         /// Force kill without graceful shutdown
         #[arg(short, long)]
         force: bool,
+
+        /// Filter expression selecting the executions, e.g. 'agent=foo'
+        /// (same syntax as `task list --filter`)
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// List the executions that would be cancelled and stop
+        #[arg(long, requires = "filter")]
+        dry_run: bool,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long, requires = "filter")]
+        yes: bool,
+    },
+
+    /// Start every finished execution matching --filter again, as a new
+    /// execution with the same agent and input
+    Requeue {
+        /// Filter expression selecting the executions, e.g.
+        /// 'status=failed AND ended>-1h'
+        #[arg(long)]
+        filter: String,
+
+        /// List the executions that would be requeued and stop
+        #[arg(long)]
+        dry_run: bool,
+
+        /// Skip the confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
     },
 
     /// Remove an execution
@@ -112,6 +211,29 @@ pub enum TaskCommand {
         execution_id: Uuid,
     },
 
+    /// Add, change or remove execution labels
+    Label {
+        /// Execution ID
+        #[arg(value_name = "EXECUTION_ID")]
+        execution_id: Uuid,
+
+        /// `KEY=VALUE` or `KEY` to set a label, `KEY-` to remove it
+        #[arg(value_name = "CHANGE", required = true)]
+        changes: Vec<String>,
+    },
+
+    /// Send guidance to a running execution; it is appended to the prompt of
+    /// the next iteration
+    Guide {
+        /// Execution ID
+        #[arg(value_name = "EXECUTION_ID")]
+        execution_id: Uuid,
+
+        /// Instructions for the agent
+        #[arg(value_name = "GUIDANCE")]
+        guidance: String,
+    },
+
     /// List recent executions
     List {
         /// Show only for specific agent
@@ -121,11 +243,22 @@ pub enum TaskCommand {
         /// Maximum number of results
         #[arg(short, long, default_value = "20")]
         limit: usize,
+
+        /// Filter expression, e.g. 'status=failed AND agent=foo AND started>-24h'
+        /// (fields: status, agent, started, ended, label, label.KEY)
+        #[arg(long)]
+        filter: Option<String>,
+
+        /// Only executions with this label (`KEY=VALUE`) or label key (`KEY`).
+        /// Repeatable; combined with --filter using AND.
+        #[arg(long = "label", value_name = "KEY[=VALUE]", value_parser = parse_label)]
+        labels: Vec<(String, String)>,
     },
 }
 
 pub async fn handle_command(
     command: TaskCommand,
+    config_path: Option<PathBuf>,
     host: &str,
     port: u16,
     output_format: OutputFormat,
@@ -144,11 +277,13 @@ pub async fn handle_command(
             info!("Delegating to daemon API");
             let auth_key = crate::auth::require_key().await?;
             let client = DaemonClient::new(host, port)?.with_auth(auth_key);
-            handle_command_daemon(command, client, output_format).await
-        }
-        _ => {
-            anyhow::bail!("Daemon is not running. Start it with 'aegis-orchestrator daemon start'.")
+            handle_command_daemon(command, config_path, client, output_format).await
         }
+        _ => Err(CliError::new(
+            ExitCode::DaemonUnavailable,
+            "Daemon is not running. Start it with 'aegis-orchestrator daemon start'.",
+        )
+        .into()),
     }
 }
 
@@ -168,6 +303,18 @@ struct TaskMutationOutput {
     status: &'static str,
 }
 
+#[derive(Serialize)]
+struct TaskLabelOutput {
+    execution_id: Uuid,
+    labels: BTreeMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct TaskGuideOutput {
+    execution_id: Uuid,
+    iteration_number: u8,
+}
+
 #[derive(Serialize)]
 struct TaskListOutput {
     count: usize,
@@ -176,6 +323,7 @@ struct TaskListOutput {
 
 async fn handle_command_daemon(
     command: TaskCommand,
+    config_path: Option<PathBuf>,
     client: DaemonClient,
     output_format: OutputFormat,
 ) -> Result<()> {
@@ -187,7 +335,10 @@ async fn handle_command_daemon(
             context,
             attachments,
             attachment,
+            files,
             version,
+            labels,
+            network_flows,
             wait,
             follow,
         } => {
@@ -198,17 +349,30 @@ async fn handle_command_daemon(
                 context,
                 attachments,
                 attachment,
+                files,
                 version,
+                labels.into_iter().collect(),
+                network_flows,
                 wait,
                 follow,
+                config_path,
                 client,
                 output_format,
             )
             .await
         }
-        TaskCommand::Status { execution_id } => {
-            status_daemon(execution_id, client, output_format).await
+        TaskCommand::Status {
+            execution_id,
+            files,
+            network,
+        } => status_daemon(execution_id, files, network, client, output_format).await,
+        TaskCommand::Explain { execution_id } => {
+            explain_daemon(execution_id, client, output_format).await
         }
+        TaskCommand::Attachments {
+            execution_id,
+            download,
+        } => attachments_daemon(execution_id, download, client, output_format).await,
         TaskCommand::Logs {
             execution_id,
             follow,
@@ -222,14 +386,43 @@ async fn handle_command_daemon(
             }
         }
         TaskCommand::Cancel {
-            execution_id,
+            execution_id: Some(execution_id),
             force,
+            ..
         } => cancel_daemon(execution_id, force, client, output_format).await,
+        TaskCommand::Cancel {
+            filter,
+            dry_run,
+            yes,
+            ..
+        } => {
+            let filter = filter.unwrap_or_default();
+            bulk_daemon("cancel", &filter, dry_run, yes, client, output_format).await
+        }
+        TaskCommand::Requeue {
+            filter,
+            dry_run,
+            yes,
+        } => bulk_daemon("requeue", &filter, dry_run, yes, client, output_format).await,
         TaskCommand::Remove { execution_id } => {
             remove_daemon(execution_id, client, output_format).await
         }
-        TaskCommand::List { agent_id, limit } => {
-            list_daemon(agent_id, limit, client, output_format).await
+        TaskCommand::Label {
+            execution_id,
+            changes,
+        } => label_daemon(execution_id, changes, client, output_format).await,
+        TaskCommand::Guide {
+            execution_id,
+            guidance,
+        } => guide_daemon(execution_id, guidance, client, output_format).await,
+        TaskCommand::List {
+            agent_id,
+            limit,
+            filter,
+            labels,
+        } => {
+            let filter = with_label_clauses(filter, &labels);
+            list_daemon(agent_id, limit, filter, client, output_format).await
         }
     }
 }
@@ -243,9 +436,13 @@ async fn execute_daemon(
     context: Option<String>,
     attachments: Option<String>,
     attachment_shorthand: Vec<String>,
+    files: Vec<PathBuf>,
     version: Option<String>,
+    labels: BTreeMap<String, String>,
+    network_flows: bool,
     wait: bool,
     follow: bool,
+    config_path: Option<PathBuf>,
     client: DaemonClient,
     output_format: OutputFormat,
 ) -> Result<()> {
@@ -277,7 +474,11 @@ async fn execute_daemon(
                     }
                 }
             } else {
-                anyhow::bail!("Agent '{agent}' not found and not a valid manifest path.");
+                return Err(CliError::new(
+                    ExitCode::NotFound,
+                    format!("Agent '{agent}' not found and not a valid manifest path."),
+                )
+                .into());
             }
         }
     };
@@ -305,19 +506,17 @@ async fn execute_daemon(
             context_overrides,
             version.as_deref(),
             attachment_refs,
+            labels,
+            network_flows,
+            &files,
         )
         .await?;
 
     if follow {
         logs_daemon(execution_id, true, false, false, client).await?;
     } else if wait {
-        if !output_format.is_structured() {
-            println!("{}", format!("✓ Execution started: {execution_id}").green());
-            println!("Waiting for completion...");
-        }
-        let final_execution =
-            wait_for_execution_completion(execution_id, &client, output_format).await?;
         if output_format.is_structured() {
+            let final_execution = wait_for_execution_completion(execution_id, &client).await?;
             return render_serialized(
                 output_format,
                 &TaskExecuteOutput {
@@ -329,6 +528,17 @@ async fn execute_daemon(
                 },
             );
         }
+        println!("{}", format!("✓ Execution started: {execution_id}").green());
+        if std::io::stdout().is_terminal() {
+            watch_execution(execution_id, config_path, &client).await?;
+        } else {
+            println!("Waiting for completion...");
+            let execution = wait_for_execution_completion(execution_id, &client).await?;
+            println!(
+                "Execution {} finished with status: {}",
+                execution.id, execution.status
+            );
+        }
     } else if output_format.is_structured() {
         return render_serialized(
             output_format,
@@ -349,13 +559,35 @@ async fn execute_daemon(
 
 async fn status_daemon(
     execution_id: Uuid,
+    files: bool,
+    network: bool,
     client: DaemonClient,
     output_format: OutputFormat,
 ) -> Result<()> {
     let execution = client.get_execution(execution_id).await?;
+    let file_activity = if files {
+        Some(client.get_execution_file_activity(execution_id).await?)
+    } else {
+        None
+    };
+    let network_flows = if network {
+        Some(client.get_execution_network_flows(execution_id).await?)
+    } else {
+        None
+    };
 
     if output_format.is_structured() {
-        return render_serialized(output_format, &execution);
+        if file_activity.is_none() && network_flows.is_none() {
+            return render_serialized(output_format, &execution);
+        }
+        let mut report = serde_json::json!({ "execution": execution });
+        if let Some(activity) = &file_activity {
+            report["file_activity"] = activity["iterations"].clone();
+        }
+        if let Some(flows) = &network_flows {
+            report["network_flows"] = flows["summary"].clone();
+        }
+        return render_serialized(output_format, &report);
     }
 
     println!("Execution {execution_id}");
@@ -367,7 +599,194 @@ async fn status_daemon(
     if let Some(ended) = execution.ended_at {
         println!("  Ended: {ended}");
     }
+    if let Some(activity) = file_activity {
+        print_file_activity(&activity);
+    }
+    if let Some(flows) = network_flows {
+        print_network_flows(&flows["summary"]);
+    }
+
+    Ok(())
+}
+
+/// Summary from `GET /v1/executions/{id}/network-flows`.
+fn print_network_flows(summary: &Value) {
+    println!("  Network flows:");
+    let destinations = summary["destinations"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    if destinations.is_empty() {
+        println!("    {}", "none recorded".dimmed());
+        return;
+    }
+    for destination in &destinations {
+        let names: Vec<&str> = destination["server_names"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        let endpoint = format!(
+            "{} {}:{}",
+            destination["protocol"].as_str().unwrap_or_default(),
+            destination["address"].as_str().unwrap_or_default(),
+            destination["port"].as_u64().unwrap_or_default()
+        );
+        let detail = match (names.is_empty(), destination["flows"].as_u64().unwrap_or(0)) {
+            (true, 1) => String::new(),
+            (true, flows) => format!("{flows} flows"),
+            (false, 1) => names.join(", "),
+            (false, flows) => format!("{} ({flows} flows)", names.join(", ")),
+        };
+        println!("    {endpoint} {}", detail.dimmed());
+    }
+    let queries: Vec<&str> = summary["dns_queries"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(Value::as_str)
+        .collect();
+    if !queries.is_empty() {
+        println!("    DNS: {}", queries.join(", "));
+    }
+}
+
+/// Per-iteration file activity from `GET /v1/executions/{id}/file-activity`.
+fn print_file_activity(activity: &Value) {
+    let iterations = activity["iterations"]
+        .as_array()
+        .cloned()
+        .unwrap_or_default();
+    println!("  File activity:");
+    if iterations.is_empty() {
+        println!("    {}", "none recorded".dimmed());
+    }
+    for iteration in &iterations {
+        match iteration["iteration_number"].as_u64() {
+            Some(number) => println!("    {}", format!("Iteration {number}").bold()),
+            None => println!("    {}", "Unattributed".bold()),
+        }
+        for file in iteration["files"].as_array().into_iter().flatten() {
+            println!(
+                "      {} {}",
+                file["path"].as_str().unwrap_or_default(),
+                file_activity_summary(file).dimmed()
+            );
+        }
+    }
+}
+
+fn file_activity_summary(file: &Value) -> String {
+    let count = |key: &str| file[key].as_u64().unwrap_or(0);
+    let mut parts = Vec::new();
+    if file["created"].as_bool().unwrap_or(false) {
+        parts.push("created".to_string());
+    }
+    if count("writes") > 0 {
+        parts.push(format!(
+            "{} writes ({} bytes)",
+            count("writes"),
+            count("bytes_written")
+        ));
+    }
+    if count("reads") > 0 {
+        parts.push(format!(
+            "{} reads ({} bytes)",
+            count("reads"),
+            count("bytes_read")
+        ));
+    }
+    if file["deleted"].as_bool().unwrap_or(false) {
+        parts.push("deleted".to_string());
+    }
+    for operation in file["denied"].as_array().into_iter().flatten() {
+        parts.push(format!("{} denied", operation.as_str().unwrap_or_default()));
+    }
+    parts.join(", ")
+}
+
+async fn explain_daemon(
+    execution_id: Uuid,
+    client: DaemonClient,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let mut explanation = client.explain_execution(execution_id).await?;
+    if output_format.is_structured() {
+        if let Some(body) = explanation.as_object_mut() {
+            body.remove("text");
+        }
+        return render_serialized(output_format, &explanation);
+    }
+
+    print!("{}", explanation["text"].as_str().unwrap_or_default());
+    Ok(())
+}
+
+async fn attachments_daemon(
+    execution_id: Uuid,
+    download: Option<PathBuf>,
+    client: DaemonClient,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let attachments = client.list_execution_attachments(execution_id).await?;
+
+    if let Some(dir) = &download {
+        for attachment in &attachments {
+            let relative = attachment
+                .path
+                .strip_prefix(OUTPUT_ATTACHMENT_DIR)
+                .unwrap_or(&attachment.path)
+                .trim_start_matches('/');
+            let relative = std::path::Path::new(relative);
+            if !relative
+                .components()
+                .all(|c| matches!(c, std::path::Component::Normal(_)))
+            {
+                anyhow::bail!(
+                    "Refusing to download attachment with unsafe path '{}'",
+                    attachment.path
+                );
+            }
+            let target = dir.join(relative);
+            if let Some(parent) = target.parent() {
+                tokio::fs::create_dir_all(parent)
+                    .await
+                    .with_context(|| format!("Failed to create {}", parent.display()))?;
+            }
+            let data = client
+                .download_execution_file(execution_id, &attachment.path)
+                .await?;
+            tokio::fs::write(&target, data)
+                .await
+                .with_context(|| format!("Failed to write {}", target.display()))?;
+        }
+    }
 
+    if output_format.is_structured() {
+        return render_serialized(output_format, &attachments);
+    }
+    if attachments.is_empty() {
+        println!("No output attachments for execution {execution_id}");
+        return Ok(());
+    }
+    for attachment in &attachments {
+        println!(
+            "{}  {}  {} bytes",
+            attachment.path, attachment.mime_type, attachment.size
+        );
+    }
+    if let Some(dir) = download {
+        println!(
+            "{}",
+            format!(
+                "✓ Downloaded {} file(s) to {}",
+                attachments.len(),
+                dir.display()
+            )
+            .green()
+        );
+    }
     Ok(())
 }
 
@@ -407,13 +826,129 @@ async fn cancel_daemon(
     Ok(())
 }
 
+#[derive(Serialize)]
+struct TaskBulkPreviewOutput {
+    action: &'static str,
+    dry_run: bool,
+    count: usize,
+    executions: Vec<crate::daemon::client::ExecutionInfo>,
+}
+
+/// Interval between progress polls of a bulk operation.
+const BULK_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Preview a bulk `cancel` / `requeue`, confirm it, start it and follow its
+/// progress until the daemon has processed every matching execution.
+async fn bulk_daemon(
+    action: &'static str,
+    filter: &str,
+    dry_run: bool,
+    yes: bool,
+    client: DaemonClient,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let (verb, done) = match action {
+        "cancel" => ("Cancel", "cancelled"),
+        _ => ("Requeue", "requeued"),
+    };
+    let executions = client.preview_bulk_executions(action, filter).await?;
+    if dry_run || executions.is_empty() {
+        if output_format.is_structured() {
+            return render_serialized(
+                output_format,
+                &TaskBulkPreviewOutput {
+                    action,
+                    dry_run,
+                    count: executions.len(),
+                    executions,
+                },
+            );
+        }
+        if executions.is_empty() {
+            println!("{}", format!("No executions to {action}").yellow());
+            return Ok(());
+        }
+        println!("Would {action} {} executions:", executions.len());
+        for exec in &executions {
+            println!(
+                "  {} - Agent: {} - {}",
+                exec.id,
+                exec.agent_id,
+                format_status(&exec.status)
+            );
+        }
+        return Ok(());
+    }
+
+    if !yes && !confirm(&format!("{verb} {} executions?", executions.len()), false)? {
+        println!("  Aborted — no changes made.");
+        return Ok(());
+    }
+
+    let mut operation = client.start_bulk_executions(action, filter).await?;
+    let progress = (!output_format.is_structured() && std::io::stdout().is_terminal()).then(|| {
+        let bar = ProgressBar::new(operation.total as u64);
+        bar.set_style(
+            ProgressStyle::with_template("  {bar:30.cyan} {pos}/{len} {msg}")
+                .unwrap()
+                .progress_chars("=> "),
+        );
+        bar
+    });
+    while operation.state != "completed" {
+        tokio::time::sleep(BULK_POLL_INTERVAL).await;
+        operation = client.get_bulk_operation(operation.id).await?;
+        if let Some(bar) = &progress {
+            bar.set_position(operation.processed as u64);
+            bar.set_message(format!("{} failed", operation.failures.len()));
+        }
+    }
+    if let Some(bar) = progress {
+        bar.finish_and_clear();
+    }
+
+    if output_format.is_structured() {
+        return render_serialized(output_format, &operation);
+    }
+    println!(
+        "{}",
+        format!(
+            "✓ {} of {} executions {done}",
+            operation.succeeded, operation.total
+        )
+        .green()
+    );
+    for requeued in &operation.requeued {
+        println!("  {} → {}", requeued.from, requeued.to);
+    }
+    for failure in &operation.failures {
+        println!(
+            "  {} {}: {}",
+            "✗".red(),
+            failure.execution_id,
+            failure.error
+        );
+    }
+    if !operation.failures.is_empty() {
+        anyhow::bail!(
+            "{} of {} executions could not be {done}",
+            operation.failures.len(),
+            operation.total
+        );
+    }
+    Ok(())
+}
+
 async fn list_daemon(
     agent_id: Option<Uuid>,
     limit: usize,
+    filter: Option<String>,
     client: DaemonClient,
     output_format: OutputFormat,
 ) -> Result<()> {
-    let executions = client.list_executions(agent_id, limit).await?;
+    let executions = client
+        .list_executions(agent_id, limit, filter.as_deref())
+        .await?;
 
     if output_format.is_structured() {
         return render_serialized(
@@ -443,7 +978,101 @@ async fn list_daemon(
     Ok(())
 }
 
+async fn label_daemon(
+    execution_id: Uuid,
+    changes: Vec<String>,
+    client: DaemonClient,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let mut patch = BTreeMap::new();
+    for change in changes {
+        match change.strip_suffix('-') {
+            Some(key) if !change.contains('=') => {
+                patch.insert(key.to_string(), None);
+            }
+            _ => {
+                let (key, value) = parse_label(&change).map_err(|e| anyhow::anyhow!(e))?;
+                patch.insert(key, Some(value));
+            }
+        }
+    }
+
+    let labels = client.update_execution_labels(execution_id, &patch).await?;
+
+    if output_format.is_structured() {
+        return render_serialized(
+            output_format,
+            &TaskLabelOutput {
+                execution_id,
+                labels,
+            },
+        );
+    }
+    println!(
+        "{}",
+        format!("✓ Execution {execution_id} labels updated").green()
+    );
+    for (key, value) in &labels {
+        println!("  {key}={value}");
+    }
+    Ok(())
+}
+
+async fn guide_daemon(
+    execution_id: Uuid,
+    guidance: String,
+    client: DaemonClient,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let iteration_number = client
+        .add_execution_guidance(execution_id, &guidance)
+        .await?;
+    if output_format.is_structured() {
+        return render_serialized(
+            output_format,
+            &TaskGuideOutput {
+                execution_id,
+                iteration_number,
+            },
+        );
+    }
+    println!(
+        "{}",
+        format!("✓ Guidance queued for iteration {iteration_number} of execution {execution_id}")
+            .green()
+    );
+    Ok(())
+}
+
 // Helpers
+
+/// Parse `KEY=VALUE` (or a bare `KEY`, meaning an empty value).
+fn parse_label(raw: &str) -> std::result::Result<(String, String), String> {
+    let (key, value) = raw.split_once('=').unwrap_or((raw, ""));
+    if key.is_empty() {
+        return Err(format!("invalid label '{raw}': expected KEY=VALUE or KEY"));
+    }
+    Ok((key.to_string(), value.to_string()))
+}
+
+/// AND `--label` selectors onto a `--filter` expression.
+fn with_label_clauses(filter: Option<String>, labels: &[(String, String)]) -> Option<String> {
+    let clauses = filter
+        .into_iter()
+        .filter(|f| !f.trim().is_empty())
+        .chain(labels.iter().map(|(key, value)| {
+            if value.is_empty() {
+                format!("label={key}")
+            } else if value.contains('"') {
+                format!("label.{key}='{value}'")
+            } else {
+                format!("label.{key}=\"{value}\"")
+            }
+        }))
+        .collect::<Vec<_>>();
+    (!clauses.is_empty()).then(|| clauses.join(" AND "))
+}
+
 async fn parse_input(input: Option<String>) -> Result<serde_json::Value> {
     match input {
         None => Ok(serde_json::json!({})),
@@ -500,7 +1129,6 @@ fn parse_json_or_yaml(input: &str) -> Result<serde_json::Value> {
 async fn wait_for_execution_completion(
     execution_id: Uuid,
     client: &DaemonClient,
-    output_format: OutputFormat,
 ) -> Result<crate::daemon::client::ExecutionInfo> {
     const MAX_POLLS: u32 = 300;
     const POLL_INTERVAL: Duration = Duration::from_secs(1);
@@ -512,12 +1140,6 @@ async fn wait_for_execution_completion(
             normalized.as_str(),
             "completed" | "failed" | "cancelled" | "canceled"
         ) {
-            if !output_format.is_structured() {
-                println!(
-                    "Execution {} finished with status: {}",
-                    execution.id, execution.status
-                );
-            }
             return Ok(execution);
         }
         tokio::time::sleep(POLL_INTERVAL).await;
@@ -526,6 +1148,317 @@ async fn wait_for_execution_completion(
     anyhow::bail!("Timed out waiting for execution {execution_id} to finish");
 }
 
+// Interactive progress
+
+/// What one activity event changed about a watched execution.
+#[derive(Debug, PartialEq)]
+enum ProgressUpdate {
+    IterationStarted {
+        number: u8,
+        action: String,
+    },
+    IterationCompleted {
+        number: u8,
+    },
+    IterationFailed {
+        number: u8,
+        error: String,
+    },
+    JudgeScore {
+        score: f64,
+        confidence: f64,
+        judges: usize,
+    },
+    Finished,
+}
+
+/// Running totals of a watched execution, folded from its activity stream.
+#[derive(Debug, Default)]
+struct ExecutionProgress {
+    iterations: u8,
+    input_tokens: u64,
+    output_tokens: u64,
+    /// Total tokens per model, for the cost estimate.
+    tokens_by_model: BTreeMap<String, u64>,
+    last_score: Option<f64>,
+}
+
+impl ExecutionProgress {
+    fn observe(&mut self, event: &CorrelatedActivityEvent) -> Option<ProgressUpdate> {
+        let iteration = event.iteration.or_else(|| {
+            event_detail(&event.details, "iteration_number")
+                .and_then(Value::as_u64)
+                .map(|number| number as u8)
+        });
+
+        match canonical_event_type(&event.event_type).as_str() {
+            "iteration_started" => {
+                let number = iteration.unwrap_or(self.iterations + 1);
+                self.iterations = self.iterations.max(number);
+                let action = event_detail(&event.details, "action")
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string();
+                Some(ProgressUpdate::IterationStarted { number, action })
+            }
+            "iteration_completed" => Some(ProgressUpdate::IterationCompleted {
+                number: iteration.unwrap_or(self.iterations),
+            }),
+            "iteration_failed" => {
+                let error = event_detail(&event.details, "error")
+                    .and_then(|error| error.get("message").or(Some(error)))
+                    .and_then(Value::as_str)
+                    .unwrap_or(&event.message)
+                    .to_string();
+                Some(ProgressUpdate::IterationFailed {
+                    number: iteration.unwrap_or(self.iterations),
+                    error,
+                })
+            }
+            "llm_interaction" => {
+                let tokens = |key: &str| {
+                    event_detail(&event.details, key)
+                        .and_then(Value::as_u64)
+                        .unwrap_or(0)
+                };
+                let (input, output) = (tokens("input_tokens"), tokens("output_tokens"));
+                self.input_tokens += input;
+                self.output_tokens += output;
+                if let Some(model) = event_detail(&event.details, "model").and_then(Value::as_str) {
+                    *self.tokens_by_model.entry(model.to_string()).or_default() += input + output;
+                }
+                None
+            }
+            "gradient_validation_performed" => {
+                let score = event_detail(&event.details, "score").and_then(Value::as_f64)?;
+                self.last_score = Some(score);
+                Some(ProgressUpdate::JudgeScore {
+                    score,
+                    confidence: event_detail(&event.details, "confidence")
+                        .and_then(Value::as_f64)
+                        .unwrap_or_default(),
+                    judges: 1,
+                })
+            }
+            "multi_judge_consensus" => {
+                let score = event_detail(&event.details, "final_score").and_then(Value::as_f64)?;
+                self.last_score = Some(score);
+                Some(ProgressUpdate::JudgeScore {
+                    score,
+                    confidence: event_detail(&event.details, "confidence")
+                        .and_then(Value::as_f64)
+                        .unwrap_or_default(),
+                    judges: event_detail(&event.details, "judge_scores")
+                        .and_then(Value::as_array)
+                        .map_or(0, Vec::len),
+                })
+            }
+            "execution_completed"
+            | "execution_failed"
+            | "execution_cancelled"
+            | "execution_timed_out" => Some(ProgressUpdate::Finished),
+            _ => None,
+        }
+    }
+
+    /// Cost at the `cost_per_1k_tokens` of each model, or `None` when a model
+    /// that consumed tokens has no configured price.
+    fn estimated_cost(&self, prices: &HashMap<String, f64>) -> Option<f64> {
+        self.tokens_by_model
+            .iter()
+            .map(|(model, tokens)| {
+                prices
+                    .get(model)
+                    .map(|price| *tokens as f64 / 1000.0 * price)
+            })
+            .sum()
+    }
+}
+
+/// Find `key` in event details, which nest the payload under its variant
+/// names (e.g. `{"Validation": {"GradientValidationPerformed": {...}}}`).
+fn event_detail<'a>(details: &'a Value, key: &str) -> Option<&'a Value> {
+    let object = details.as_object()?;
+    object
+        .get(key)
+        .or_else(|| object.values().find_map(|value| event_detail(value, key)))
+}
+
+/// Watch an execution on a terminal: a spinner per iteration, judge scores as
+/// they arrive, and a summary table once it finishes.
+async fn watch_execution(
+    execution_id: Uuid,
+    config_path: Option<PathBuf>,
+    client: &DaemonClient,
+) -> Result<()> {
+    let started = Instant::now();
+    let mut progress = ExecutionProgress::default();
+    let mut spinner: Option<ProgressBar> = None;
+
+    let watched = client
+        .watch_execution_events(execution_id, |event| {
+            match progress.observe(event) {
+                Some(ProgressUpdate::IterationStarted { number, action }) => {
+                    if let Some(previous) = spinner.take() {
+                        previous.finish_and_clear();
+                    }
+                    spinner = Some(iteration_spinner(number, &action));
+                }
+                Some(ProgressUpdate::IterationCompleted { number }) => {
+                    if let Some(done) = spinner.take() {
+                        done.finish_and_clear();
+                        println!(
+                            "  {} Iteration {number} completed ({:.1}s)",
+                            "✓".green(),
+                            done.elapsed().as_secs_f64()
+                        );
+                    }
+                }
+                Some(ProgressUpdate::IterationFailed { number, error }) => {
+                    if let Some(failed) = spinner.take() {
+                        failed.finish_and_clear();
+                    }
+                    println!(
+                        "  {}",
+                        format!("✗ Iteration {number} failed: {error}").red()
+                    );
+                }
+                Some(ProgressUpdate::JudgeScore {
+                    score,
+                    confidence,
+                    judges,
+                }) => {
+                    let judged_by = if judges > 1 {
+                        format!("{judges} judges")
+                    } else {
+                        "judge".to_string()
+                    };
+                    let line = format!(
+                        "    {} {score:.2} (confidence {confidence:.2}, {judged_by})",
+                        "score".magenta().bold()
+                    );
+                    match &spinner {
+                        Some(spinner) => spinner.println(line),
+                        None => println!("{line}"),
+                    }
+                }
+                Some(ProgressUpdate::Finished) => return ControlFlow::Break(()),
+                None => {}
+            }
+            ControlFlow::Continue(())
+        })
+        .await;
+
+    if let Some(spinner) = spinner.take() {
+        spinner.finish_and_clear();
+    }
+    if let Err(e) = watched {
+        println!(
+            "{}",
+            format!("⚠ Lost the execution event stream ({e}); waiting for completion...").yellow()
+        );
+    }
+
+    let execution = wait_for_execution_completion(execution_id, client).await?;
+    let duration = execution_duration(&execution).unwrap_or_else(|| started.elapsed());
+    let cost = progress.estimated_cost(&model_prices(config_path));
+
+    println!();
+    println!("{}", "Execution summary".bold());
+    println!("  {:<12} {}", "Status", format_status(&execution.status));
+    for (label, value) in summary_rows(&progress, duration, cost) {
+        println!("  {label:<12} {value}");
+    }
+    Ok(())
+}
+
+fn iteration_spinner(number: u8, action: &str) -> ProgressBar {
+    let spinner = ProgressBar::new_spinner();
+    spinner.set_style(
+        ProgressStyle::with_template("  {spinner:.cyan} {msg} {elapsed:.dim}")
+            .unwrap()
+            .tick_chars("⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏"),
+    );
+    spinner.enable_steady_tick(Duration::from_millis(80));
+    if action.is_empty() {
+        spinner.set_message(format!("Iteration {number}"));
+    } else {
+        spinner.set_message(format!("Iteration {number}: {action}"));
+    }
+    spinner
+}
+
+fn summary_rows(
+    progress: &ExecutionProgress,
+    duration: Duration,
+    cost: Option<f64>,
+) -> Vec<(&'static str, String)> {
+    let mut rows = vec![
+        ("Duration", format_duration(duration)),
+        ("Iterations", progress.iterations.to_string()),
+    ];
+    if let Some(score) = progress.last_score {
+        rows.push(("Judge score", format!("{score:.2}")));
+    }
+    rows.push((
+        "Tokens",
+        format!(
+            "{} ({} in / {} out)",
+            progress.input_tokens + progress.output_tokens,
+            progress.input_tokens,
+            progress.output_tokens
+        ),
+    ));
+    rows.push((
+        "Cost",
+        match cost {
+            Some(cost) => format!("${cost:.4} (estimated)"),
+            None => "n/a (model price not configured)".to_string(),
+        },
+    ));
+    rows
+}
+
+fn execution_duration(execution: &crate::daemon::client::ExecutionInfo) -> Option<Duration> {
+    let parse = |timestamp: &Option<String>| {
+        chrono::DateTime::parse_from_rfc3339(timestamp.as_deref()?).ok()
+    };
+    (parse(&execution.ended_at)? - parse(&execution.started_at)?)
+        .to_std()
+        .ok()
+}
+
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    if secs >= 3600 {
+        format!("{}h {}m", secs / 3600, secs % 3600 / 60)
+    } else if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{:.1}s", duration.as_secs_f64())
+    }
+}
+
+/// `cost_per_1k_tokens` by model name and alias from the local node config.
+/// Empty when no config can be loaded, which leaves the cost unestimated.
+fn model_prices(config_path: Option<PathBuf>) -> HashMap<String, f64> {
+    let Ok(config) = NodeConfigManifest::load_or_default(config_path) else {
+        return HashMap::new();
+    };
+    config
+        .spec
+        .llm_providers
+        .iter()
+        .flat_map(|provider| &provider.models)
+        .flat_map(|model| {
+            [
+                (model.model.clone(), model.cost_per_1k_tokens),
+                (model.alias.clone(), model.cost_per_1k_tokens),
+            ]
+        })
+        .collect()
+}
+
 fn format_status(status: &str) -> colored::ColoredString {
     match status {
         "running" => "running".yellow(),
@@ -575,6 +1508,158 @@ mod tests {
         let _ = tokio::fs::remove_file(path).await;
     }
 
+    #[test]
+    fn label_selectors_extend_filter_expression() {
+        assert_eq!(with_label_clauses(None, &[]), None);
+        let labels = vec![
+            ("customer".to_string(), "acme".to_string()),
+            ("release-check".to_string(), String::new()),
+        ];
+        assert_eq!(
+            with_label_clauses(Some("status=failed".to_string()), &labels).as_deref(),
+            Some("status=failed AND label.customer=\"acme\" AND label=release-check")
+        );
+        assert_eq!(
+            parse_label("team=infra=core").unwrap(),
+            ("team".to_string(), "infra=core".to_string())
+        );
+        assert!(parse_label("=x").is_err());
+    }
+
+    fn activity(
+        event_type: &str,
+        iteration: Option<u8>,
+        details: Value,
+    ) -> CorrelatedActivityEvent {
+        CorrelatedActivityEvent {
+            event_type: event_type.to_string(),
+            category: "execution".to_string(),
+            timestamp: chrono::Utc::now(),
+            execution_id: None,
+            agent_id: None,
+            iteration,
+            stage: None,
+            message: String::new(),
+            details,
+        }
+    }
+
+    #[test]
+    fn progress_folds_iterations_scores_and_tokens() {
+        let mut progress = ExecutionProgress::default();
+
+        assert_eq!(
+            progress.observe(&activity(
+                "iteration_started",
+                Some(1),
+                serde_json::json!({ "type": "execution", "IterationStarted": { "action": "write tests" } }),
+            )),
+            Some(ProgressUpdate::IterationStarted {
+                number: 1,
+                action: "write tests".to_string()
+            })
+        );
+        for (model, input, output) in [("gpt-4o", 1200, 300), ("gpt-4o", 800, 200)] {
+            let details = serde_json::json!({
+                "type": "execution",
+                "LlmInteraction": { "model": model, "input_tokens": input, "output_tokens": output }
+            });
+            assert_eq!(
+                progress.observe(&activity("llm_interaction", Some(1), details)),
+                None
+            );
+        }
+        assert_eq!(
+            progress.observe(&activity(
+                "gradient_validation_performed",
+                Some(1),
+                serde_json::json!({
+                    "type": "execution",
+                    "Validation": { "GradientValidationPerformed": { "score": 0.82, "confidence": 0.9 } }
+                }),
+            )),
+            Some(ProgressUpdate::JudgeScore {
+                score: 0.82,
+                confidence: 0.9,
+                judges: 1
+            })
+        );
+        assert_eq!(
+            progress.observe(&activity("IterationCompleted", Some(1), Value::Null)),
+            Some(ProgressUpdate::IterationCompleted { number: 1 })
+        );
+        assert_eq!(
+            progress.observe(&activity("execution_completed", None, Value::Null)),
+            Some(ProgressUpdate::Finished)
+        );
+
+        assert_eq!(progress.iterations, 1);
+        assert_eq!((progress.input_tokens, progress.output_tokens), (2000, 500));
+        assert_eq!(progress.last_score, Some(0.82));
+
+        let prices = HashMap::from([("gpt-4o".to_string(), 0.01)]);
+        let cost = progress.estimated_cost(&prices).unwrap();
+        assert!((cost - 0.025).abs() < 1e-9, "unexpected cost {cost}");
+        assert_eq!(progress.estimated_cost(&HashMap::new()), None);
+    }
+
+    #[test]
+    fn summary_rows_report_duration_tokens_and_cost() {
+        let progress = ExecutionProgress {
+            iterations: 3,
+            input_tokens: 1000,
+            output_tokens: 250,
+            ..Default::default()
+        };
+        let rows = summary_rows(&progress, Duration::from_secs(72), None);
+        assert_eq!(
+            rows,
+            vec![
+                ("Duration", "1m 12s".to_string()),
+                ("Iterations", "3".to_string()),
+                ("Tokens", "1250 (1000 in / 250 out)".to_string()),
+                ("Cost", "n/a (model price not configured)".to_string()),
+            ]
+        );
+        assert_eq!(format_duration(Duration::from_millis(4200)), "4.2s");
+    }
+
+    #[test]
+    fn cancel_takes_an_execution_id_or_a_filter() {
+        use clap::Parser;
+
+        #[derive(Parser)]
+        struct TaskTestCli {
+            #[command(subcommand)]
+            command: TaskCommand,
+        }
+
+        let cli = TaskTestCli::try_parse_from(["task", "cancel", "--filter", "agent=foo", "-y"])
+            .expect("filtered cancel should parse");
+        match cli.command {
+            TaskCommand::Cancel {
+                execution_id,
+                filter,
+                yes,
+                ..
+            } => {
+                assert_eq!(execution_id, None);
+                assert_eq!(filter.as_deref(), Some("agent=foo"));
+                assert!(yes);
+            }
+            _ => panic!("expected cancel command"),
+        }
+
+        assert!(TaskTestCli::try_parse_from(["task", "cancel"]).is_err());
+        assert!(TaskTestCli::try_parse_from([
+            "task",
+            "cancel",
+            "00000000-0000-0000-0000-000000000000",
+            "--dry-run",
+        ])
+        .is_err());
+    }
+
     #[tokio::test]
     async fn parse_object_input_rejects_scalar_values() {
         let err = parse_object_input(Some("hello".to_string()), "context override")
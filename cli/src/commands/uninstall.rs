@@ -16,7 +16,8 @@ use std::path::{Path, PathBuf};
 use anyhow::Result;
 use clap::Args;
 use colored::Colorize;
-use dialoguer::Confirm;
+
+use crate::util::prompt::confirm;
 
 /// Arguments for `aegis uninstall`
 #[derive(Args)]
@@ -58,13 +59,13 @@ pub async fn run(args: UninstallArgs) -> Result<()> {
     println!();
 
     if !args.yes {
-        let confirmed = Confirm::new()
-            .with_prompt(format!(
+        let confirmed = confirm(
+            &format!(
                 "Delete {} and all its contents? This cannot be undone",
                 dir.display()
-            ))
-            .default(false)
-            .interact()?;
+            ),
+            false,
+        )?;
 
         if !confirmed {
             println!("  Aborted — nothing was changed.");
@@ -20,10 +20,10 @@ use std::path::{Path, PathBuf};
 use anyhow::Result;
 use clap::Args;
 use colored::Colorize;
-use dialoguer::Confirm;
 
 use super::init::{self, compose::ComposeRunner, InitArgs};
 use super::update::{persist_image_tag, refresh_compose, resolve_image_tag};
+use crate::util::prompt::confirm;
 
 /// Arguments for `aegis up`
 #[derive(Args)]
@@ -79,12 +79,7 @@ pub async fn run(args: UpArgs) -> Result<()> {
         let advanced_override = if args.yes {
             Some(false)
         } else {
-            Some(
-                Confirm::new()
-                    .with_prompt("Run advanced configuration walkthrough?")
-                    .default(false)
-                    .interact()?,
-            )
+            Some(confirm("Run advanced configuration walkthrough?", false)?)
         };
 
         let initial_tag = resolve_image_tag(&config_file_path, args.tag.as_deref());
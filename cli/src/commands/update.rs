@@ -209,13 +209,6 @@ pub(crate) async fn run_database_migrations(
     let database_url =
         resolve_env_value(&db_config.url).context("Failed to resolve spec.database.url")?;
 
-    // ADR-117 SEV-2 #2.1: the orchestrator binary lives in the `cli` crate;
-    // `sqlx::migrate!` resolves its argument relative to the crate root, so
-    // the canonical location for migrations is `cli/migrations/` (NOT
-    // `orchestrator/core/migrations/`). All ADR-117 edge migrations
-    // (029_edge_mode.sql, ...) live here.
-    static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
-
     if dry_run {
         println!(
             "  {} would connect to database and run pending migrations",
@@ -224,7 +217,7 @@ pub(crate) async fn run_database_migrations(
         println!(
             "  {} total migrations available: {}",
             "→".dimmed(),
-            MIGRATOR.iter().count()
+            crate::daemon::migrations::MIGRATOR.iter().count()
         );
         return Ok(());
     }
@@ -235,27 +228,21 @@ pub(crate) async fn run_database_migrations(
         .await
         .context("Failed to connect to database")?;
 
-    let applied_count = sqlx::query("SELECT version FROM _sqlx_migrations")
-        .fetch_all(&pool)
-        .await
-        .map(|r| r.len())
-        .unwrap_or(0);
-
-    let total = MIGRATOR.iter().count();
-    let pending = total.saturating_sub(applied_count);
-
-    if pending == 0 {
+    let plan = crate::daemon::migrations::plan(&pool).await?;
+    if plan.is_up_to_date() {
         println!(
             "  {} Database schema is up to date ({} migrations applied)",
             "✓".green(),
-            applied_count
+            plan.applied
         );
     } else {
+        let pending = plan.pending.len();
         println!("  Applying {pending} pending migration(s)...");
-        MIGRATOR
-            .run(&pool)
-            .await
-            .context("Failed to apply migrations")?;
+        if let Some(file) =
+            crate::daemon::migrations::apply(&pool, &database_url, db_config, &plan).await?
+        {
+            println!("  {} Backup written to {}", "✓".green(), file.display());
+        }
         println!("  {} {} migration(s) applied", "✓".green(), pending);
     }
 
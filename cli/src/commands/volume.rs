@@ -0,0 +1,224 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Volume commands for the AEGIS CLI
+//!
+//! `aegis volume search` finds files across every volume the caller can
+//! browse (their own volumes plus the tenant's execution and workflow
+//! workspaces): by filename from the daemon's per-tenant index, or by content
+//! with `--content`, which runs a size- and time-bounded grep on the daemon.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Interface / Presentation Layer
+//! - **Purpose:** Implements `aegis volume` subcommands (search)
+
+use anyhow::Result;
+use clap::Subcommand;
+use colored::Colorize;
+use std::path::PathBuf;
+use uuid::Uuid;
+
+use crate::daemon::{check_daemon_running, DaemonClient, DaemonStatus};
+use crate::exit_code::{CliError, ExitCode};
+use crate::output::{render_serialized, OutputFormat};
+
+#[derive(Subcommand)]
+pub enum VolumeCommand {
+    /// Find files by name, or by content with --content
+    Search {
+        /// Filename substring (case-insensitive), or the text to grep for
+        /// with --content
+        #[arg(value_name = "QUERY")]
+        query: String,
+
+        /// Search file contents instead of names
+        #[arg(long)]
+        content: bool,
+
+        /// Treat QUERY as a regular expression (with --content)
+        #[arg(long, requires = "content")]
+        regex: bool,
+
+        /// Case-insensitive content match (with --content)
+        #[arg(short = 'i', long, requires = "content")]
+        ignore_case: bool,
+
+        /// Only search this volume (with --content)
+        #[arg(long, value_name = "VOLUME_ID", requires = "content")]
+        volume: Option<Uuid>,
+
+        /// Only search files under this path prefix (with --content)
+        #[arg(long, value_name = "PREFIX", requires = "content")]
+        path: Option<String>,
+
+        /// Maximum number of results
+        #[arg(long, default_value = "100")]
+        limit: usize,
+
+        /// Rebuild the daemon's filename index before searching
+        #[arg(long)]
+        refresh: bool,
+    },
+}
+
+pub async fn handle_command(
+    command: VolumeCommand,
+    _config_path: Option<PathBuf>,
+    host: &str,
+    port: u16,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let daemon_status = check_daemon_running(host, port).await;
+    match daemon_status {
+        Ok(DaemonStatus::Running { .. }) => {}
+        Ok(DaemonStatus::Unhealthy { pid, error }) => {
+            println!(
+                "{}",
+                format!("⚠ Daemon is running (PID: {pid}) but unhealthy: {error}").yellow()
+            );
+            println!("Run 'aegis daemon status' for more info.");
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
+        }
+        _ => {
+            println!(
+                "{}",
+                "Volume commands require the daemon to be running.".red()
+            );
+            println!("Run 'aegis daemon start' to start the daemon.");
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
+        }
+    }
+
+    let auth_key = crate::auth::require_key().await?;
+    let client = DaemonClient::new(host, port)?.with_auth(auth_key);
+
+    match command {
+        VolumeCommand::Search {
+            query,
+            content: false,
+            limit,
+            refresh,
+            ..
+        } => search_names(&query, limit, refresh, &client, output_format).await,
+        VolumeCommand::Search {
+            query,
+            content: true,
+            regex,
+            ignore_case,
+            volume,
+            path,
+            limit,
+            refresh,
+        } => {
+            if refresh {
+                // The grep endpoint scans indexed files; rebuild the index
+                // through the name search first.
+                client.search_volume_files(&query, 1, true).await?;
+            }
+            let request = serde_json::json!({
+                "pattern": query,
+                "regex": regex,
+                "ignore_case": ignore_case,
+                "volume_id": volume,
+                "path_prefix": path,
+                "max_matches": limit,
+            });
+            grep(request, &client, output_format).await
+        }
+    }
+}
+
+async fn search_names(
+    query: &str,
+    limit: usize,
+    refresh: bool,
+    client: &DaemonClient,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let body = client.search_volume_files(query, limit, refresh).await?;
+
+    if output_format.is_structured() {
+        return render_serialized(output_format, &body);
+    }
+
+    let matches = body["matches"].as_array().cloned().unwrap_or_default();
+    if matches.is_empty() {
+        println!("{}", format!("No files matching '{query}'").yellow());
+    }
+    for m in &matches {
+        println!(
+            "{}  {}:{}  {}",
+            m["volume_id"].as_str().unwrap_or_default().dimmed(),
+            m["volume_name"].as_str().unwrap_or_default().cyan(),
+            m["path"].as_str().unwrap_or_default(),
+            format_size(m["size_bytes"].as_u64().unwrap_or(0)).dimmed()
+        );
+    }
+    if body["truncated"].as_bool().unwrap_or(false) {
+        println!(
+            "{}",
+            format!("… more than {limit} matches; narrow the query or raise --limit").yellow()
+        );
+    }
+    if let Some(indexed_at) = body["indexed_at"].as_str() {
+        println!(
+            "{}",
+            format!("Index built at {indexed_at} (use --refresh to rebuild)").dimmed()
+        );
+    }
+    Ok(())
+}
+
+async fn grep(
+    request: serde_json::Value,
+    client: &DaemonClient,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let body = client.grep_volumes(request).await?;
+
+    if output_format.is_structured() {
+        return render_serialized(output_format, &body);
+    }
+
+    let matches = body["matches"].as_array().cloned().unwrap_or_default();
+    if matches.is_empty() {
+        println!("{}", "No matches".yellow());
+    }
+    for m in &matches {
+        println!(
+            "{}:{}:{}: {}",
+            m["volume_name"].as_str().unwrap_or_default().cyan(),
+            m["path"].as_str().unwrap_or_default(),
+            m["line_number"].as_u64().unwrap_or(0).to_string().green(),
+            m["line"].as_str().unwrap_or_default()
+        );
+    }
+    println!(
+        "{}",
+        format!(
+            "Scanned {} files ({})",
+            body["files_scanned"].as_u64().unwrap_or(0),
+            format_size(body["bytes_scanned"].as_u64().unwrap_or(0))
+        )
+        .dimmed()
+    );
+    if let Some(stopped_by) = body["stopped_by"].as_str() {
+        println!(
+            "{}",
+            format!("Search stopped early ({stopped_by}); results are partial").yellow()
+        );
+    }
+    Ok(())
+}
+
+fn format_size(bytes: u64) -> String {
+    const KIB: u64 = 1024;
+    const MIB: u64 = 1024 * KIB;
+    if bytes >= MIB {
+        format!("{:.1} MiB", bytes as f64 / MIB as f64)
+    } else if bytes >= KIB {
+        format!("{:.1} KiB", bytes as f64 / KIB as f64)
+    } else {
+        format!("{bytes} B")
+    }
+}
@@ -13,13 +13,17 @@
 //! - `aegis workflow list` - List registered workflows
 //! - `aegis workflow describe <name>` - Show workflow details
 //! - `aegis workflow logs <execution_id>` - Stream workflow execution logs
+//! - `aegis workflow executions diff <execution_id> <from> <to>` - Diff blackboard snapshots between two transitions
 //! - `aegis workflow generate --input <text>` - Generate a workflow from natural language
+//! - `aegis workflow import --from <langgraph|crewai> <file>` - Draft a workflow and agents from another framework
 //!
 //! # Architecture
 //!
 //! - **Layer:** Interface / Presentation Layer
 //! - **Purpose:** Implements internal responsibilities for workflow
 
+use aegis_orchestrator_core::domain::workflow::{diff_blackboards, BlackboardChange};
+use aegis_orchestrator_core::infrastructure::workflow_import::{import_definition, ImportFormat};
 use anyhow::{Context, Result};
 use clap::Subcommand;
 use colored::Colorize;
@@ -30,7 +34,9 @@ use uuid::Uuid;
 
 use crate::commands::builtins;
 use crate::daemon::{check_daemon_running, DaemonClient, DaemonStatus};
+use crate::exit_code::{CliError, ExitCode};
 use crate::output::{render_serialized, structured_output_unsupported, OutputFormat};
+use crate::util::prompt::require_interactive;
 
 const WORKFLOW_GENERATOR_WORKFLOW_NAME: &str = builtins::WORKFLOW_GENERATOR_WORKFLOW_NAME;
 
@@ -164,6 +170,29 @@ pub enum WorkflowCommand {
         follow: bool,
     },
 
+    /// Draft a workflow and its agents from a LangGraph or CrewAI definition
+    Import {
+        /// Path to the exported JSON definition
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// Source framework (langgraph, crewai)
+        #[arg(long, value_name = "FORMAT")]
+        from: ImportFormat,
+
+        /// Workflow name (default: the file name)
+        #[arg(long, value_name = "NAME")]
+        name: Option<String>,
+
+        /// Directory the drafts are written to
+        #[arg(long, short = 'o', value_name = "DIR", default_value = ".")]
+        output_dir: PathBuf,
+
+        /// Overwrite existing draft files
+        #[arg(long, short = 'f')]
+        force: bool,
+    },
+
     /// Check workflow execution status
     Status {
         /// Execution ID
@@ -249,6 +278,21 @@ pub enum ExecutionsCommand {
         #[arg(value_name = "EXECUTION_ID")]
         execution_id: Uuid,
     },
+
+    /// Diff the blackboard between two state transitions
+    Diff {
+        /// Execution ID
+        #[arg(value_name = "EXECUTION_ID")]
+        execution_id: Uuid,
+
+        /// Transition index to diff from (0-based, as listed in `executions get`)
+        #[arg(value_name = "FROM")]
+        from: usize,
+
+        /// Transition index to diff to
+        #[arg(value_name = "TO")]
+        to: usize,
+    },
 }
 
 pub async fn handle_command(
@@ -335,6 +379,13 @@ pub async fn handle_command(
             )
             .await
         }
+        WorkflowCommand::Import {
+            file,
+            from,
+            name,
+            output_dir,
+            force,
+        } => import_workflow(file, from, name, output_dir, force, output_format).await,
         WorkflowCommand::Promote { name_or_id, to } => {
             change_workflow_scope(name_or_id, to, "promote", host, port, output_format).await
         }
@@ -375,6 +426,11 @@ async fn handle_executions_command(
         ExecutionsCommand::Get { execution_id } => {
             get_workflow_execution(execution_id, host, port, output_format).await
         }
+        ExecutionsCommand::Diff {
+            execution_id,
+            from,
+            to,
+        } => diff_workflow_transitions(execution_id, from, to, host, port, output_format).await,
     }
 }
 
@@ -399,6 +455,8 @@ struct WorkflowDeployOutput {
     name: String,
     file: String,
     force: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    warnings: Vec<String>,
 }
 
 #[derive(Serialize)]
@@ -434,6 +492,14 @@ struct WorkflowExecutionsOutput {
     executions: Vec<crate::daemon::client::WorkflowExecutionInfo>,
 }
 
+#[derive(Serialize)]
+struct WorkflowTransitionDiffOutput {
+    execution_id: Uuid,
+    from: serde_json::Value,
+    to: serde_json::Value,
+    changes: Vec<BlackboardChange>,
+}
+
 #[derive(Serialize)]
 struct WorkflowDeleteOutput {
     name: String,
@@ -455,6 +521,15 @@ struct WorkflowGenerateOutput {
     generated_agents_root: String,
 }
 
+#[derive(Serialize)]
+struct WorkflowImportOutput {
+    name: String,
+    from: &'static str,
+    workflow_file: String,
+    agent_files: Vec<String>,
+    warnings: Vec<String>,
+}
+
 /// Validate a workflow manifest file
 async fn validate_workflow(file: PathBuf, output_format: OutputFormat) -> Result<()> {
     use aegis_orchestrator_core::infrastructure::workflow_parser::WorkflowParser;
@@ -511,6 +586,99 @@ async fn validate_workflow(file: PathBuf, output_format: OutputFormat) -> Result
     Ok(())
 }
 
+/// Write workflow and agent drafts translated from an external framework
+async fn import_workflow(
+    file: PathBuf,
+    from: ImportFormat,
+    name: Option<String>,
+    output_dir: PathBuf,
+    force: bool,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let source = std::fs::read_to_string(&file)
+        .with_context(|| format!("Failed to read {}", file.display()))?;
+    let name = name.unwrap_or_else(|| {
+        file.file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    });
+    let drafts = import_definition(from, &source, &name)
+        .with_context(|| format!("Failed to import {}", file.display()))?;
+
+    let workflow_path = output_dir.join(format!("{}.yaml", drafts.workflow_name));
+    let agents_dir = output_dir.join("agents");
+    let agent_paths: Vec<PathBuf> = drafts
+        .agents
+        .iter()
+        .map(|agent| agents_dir.join(format!("{}.yaml", agent.name)))
+        .collect();
+    if !force {
+        if let Some(existing) = std::iter::once(&workflow_path)
+            .chain(&agent_paths)
+            .find(|path| path.exists())
+        {
+            anyhow::bail!(
+                "{} already exists; pass --force to overwrite",
+                existing.display()
+            );
+        }
+    }
+
+    std::fs::create_dir_all(&agents_dir)
+        .with_context(|| format!("Failed to create {}", agents_dir.display()))?;
+    std::fs::write(&workflow_path, &drafts.workflow_yaml)
+        .with_context(|| format!("Failed to write {}", workflow_path.display()))?;
+    for (agent, path) in drafts.agents.iter().zip(&agent_paths) {
+        std::fs::write(path, &agent.yaml)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    if output_format.is_structured() {
+        return render_serialized(
+            output_format,
+            &WorkflowImportOutput {
+                name: drafts.workflow_name,
+                from: from.as_str(),
+                workflow_file: workflow_path.display().to_string(),
+                agent_files: agent_paths
+                    .iter()
+                    .map(|path| path.display().to_string())
+                    .collect(),
+                warnings: drafts.warnings,
+            },
+        );
+    }
+
+    println!(
+        "{}",
+        format!("✓ Imported {} definition as drafts", from.as_str())
+            .green()
+            .bold()
+    );
+    println!();
+    println!("  Workflow: {}", workflow_path.display());
+    for path in &agent_paths {
+        println!("  Agent:    {}", path.display());
+    }
+    if !drafts.warnings.is_empty() {
+        println!();
+        println!(
+            "{}",
+            format!("⚠ {} item(s) need review:", drafts.warnings.len()).yellow()
+        );
+        for warning in &drafts.warnings {
+            println!("{}", format!("  - {warning}").yellow());
+        }
+    }
+    println!();
+    println!(
+        "Deploy the agents, then: aegis workflow deploy {}",
+        workflow_path.display()
+    );
+
+    Ok(())
+}
+
 /// Deploy a workflow to the registry
 async fn deploy_workflow(
     file: PathBuf,
@@ -530,7 +698,7 @@ async fn deploy_workflow(
                 format!("⚠ Daemon is running (PID: {pid}) but unhealthy: {error}").yellow()
             );
             println!("Run 'aegis daemon status' for more info.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
         _ => {
             println!(
@@ -538,7 +706,7 @@ async fn deploy_workflow(
                 "Workflow deployment requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -560,6 +728,14 @@ async fn deploy_workflow(
         WorkflowParser::parse_file(&file).context("Failed to parse workflow manifest")?;
 
     let workflow_name = workflow.metadata.name.clone();
+    use aegis_orchestrator_core::infrastructure::workflow_template_engine::WorkflowTemplateEngine;
+    // The daemon registers these too, but rendering them will fail at runtime.
+    let warnings: Vec<String> = WorkflowTemplateEngine::undeclared_references(&workflow)
+        .into_iter()
+        .map(|(template, key)| {
+            format!("Template '{template}' references undeclared blackboard variable '{key}'")
+        })
+        .collect();
 
     // Deploy via daemon API
     let auth_key = crate::auth::require_key().await?;
@@ -576,6 +752,7 @@ async fn deploy_workflow(
                 name: workflow_name,
                 file: file.display().to_string(),
                 force,
+                warnings,
             },
         );
     }
@@ -584,6 +761,9 @@ async fn deploy_workflow(
     println!();
     println!("  Name: {workflow_name}");
     println!("  Run:  aegis workflow run {workflow_name}");
+    for warning in &warnings {
+        println!("{}", format!("⚠ {warning}").yellow());
+    }
 
     Ok(())
 }
@@ -605,7 +785,7 @@ async fn run_workflow(
                 "Workflow execution requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -817,7 +997,7 @@ async fn generate_workflow(
                 format!("⚠ Daemon is running (PID: {pid}) but unhealthy: {error}").yellow()
             );
             println!("Run 'aegis daemon status' for more info.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
         _ => {
             println!(
@@ -825,7 +1005,7 @@ async fn generate_workflow(
                 "Workflow generation requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -922,7 +1102,7 @@ async fn list_workflows(
                 "Listing workflows requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -1028,7 +1208,7 @@ async fn change_workflow_scope(
                 "Workflow scope changes require the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -1098,7 +1278,7 @@ async fn list_workflow_executions(
                 "Listing workflow executions requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -1198,7 +1378,7 @@ async fn describe_workflow(
                 "Describing workflows requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -1242,7 +1422,7 @@ async fn stream_workflow_logs(
                 "Streaming logs requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -1303,7 +1483,7 @@ async fn get_workflow_execution(
                 "Workflow execution status requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -1338,6 +1518,86 @@ async fn get_workflow_execution(
     Ok(())
 }
 
+async fn diff_workflow_transitions(
+    execution_id: Uuid,
+    from: usize,
+    to: usize,
+    host: &str,
+    port: u16,
+    output_format: OutputFormat,
+) -> Result<()> {
+    let daemon_status = check_daemon_running(host, port).await;
+    match daemon_status {
+        Ok(DaemonStatus::Running { .. }) => {}
+        _ => {
+            println!(
+                "{}",
+                "Workflow transition diff requires the daemon to be running.".red()
+            );
+            println!("Run 'aegis daemon start' to start the daemon.");
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
+        }
+    }
+
+    let auth_key = crate::auth::require_key().await?;
+    let client = DaemonClient::new(host, port)?.with_auth(auth_key);
+    let from_snapshot = client
+        .get_workflow_transition_blackboard(execution_id, from)
+        .await
+        .with_context(|| format!("Failed to get blackboard for transition {from}"))?;
+    let to_snapshot = client
+        .get_workflow_transition_blackboard(execution_id, to)
+        .await
+        .with_context(|| format!("Failed to get blackboard for transition {to}"))?;
+    let changes = diff_blackboards(&from_snapshot["blackboard"], &to_snapshot["blackboard"]);
+    let describe = |snapshot: &serde_json::Value, index: usize| {
+        format!(
+            "#{index} {} ({})",
+            snapshot["state_name"].as_str().unwrap_or("unknown"),
+            snapshot["entered_at"].as_str().unwrap_or("-")
+        )
+    };
+    let (from_label, to_label) = (describe(&from_snapshot, from), describe(&to_snapshot, to));
+
+    if output_format.is_structured() {
+        return render_serialized(
+            output_format,
+            &WorkflowTransitionDiffOutput {
+                execution_id,
+                from: from_snapshot,
+                to: to_snapshot,
+                changes,
+            },
+        );
+    }
+
+    println!("Blackboard diff for workflow execution {execution_id}");
+    println!("  From: {from_label}");
+    println!("  To:   {to_label}");
+    println!();
+    if changes.is_empty() {
+        println!("{}", "No blackboard changes.".dimmed());
+        return Ok(());
+    }
+    for change in &changes {
+        let path = if change.path.is_empty() {
+            "(root)"
+        } else {
+            change.path.as_str()
+        };
+        match (&change.before, &change.after) {
+            (None, Some(after)) => println!("{} {path} = {after}", "+".green()),
+            (Some(before), None) => println!("{} {path} = {before}", "-".red()),
+            (Some(before), Some(after)) => {
+                println!("{} {path}: {before} -> {after}", "~".yellow())
+            }
+            (None, None) => {}
+        }
+    }
+
+    Ok(())
+}
+
 async fn signal_workflow_execution(
     execution_id: Uuid,
     response: String,
@@ -1447,7 +1707,7 @@ async fn delete_workflow(
                 "Deleting workflows requires the daemon to be running.".red()
             );
             println!("Run 'aegis daemon start' to start the daemon.");
-            return Ok(());
+            return Err(CliError::reported(ExitCode::DaemonUnavailable).into());
         }
     }
 
@@ -1458,6 +1718,7 @@ async fn delete_workflow(
     }
 
     if !skip_confirmation {
+        require_interactive("Delete confirmation", "pass --yes")?;
         let name_for_prompt = name.clone();
         let confirmed = tokio::task::spawn_blocking(move || {
             use std::io::{self, Write};
@@ -11,13 +11,18 @@ use anyhow::{Context, Result};
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::BTreeMap;
+use std::ops::ControlFlow;
 use tokio_stream::StreamExt;
 use tracing::info;
 use uuid::Uuid;
 
 use aegis_orchestrator_core::domain::events::CorrelatedActivityEvent;
+use aegis_orchestrator_core::presentation::api_version::{API_VERSION, API_VERSION_HEADER};
 use aegis_orchestrator_sdk::AgentManifest;
 
+use crate::exit_code::{http_error, CliError, ExitCode};
+
 #[derive(Deserialize)]
 #[serde(untagged)]
 enum WorkflowListResponse {
@@ -34,8 +39,16 @@ pub struct DaemonClient {
 
 impl DaemonClient {
     pub fn new(host: &str, port: u16) -> Result<Self> {
+        // Pin the contract this CLI was built against so an incompatible
+        // daemon rejects requests up front instead of mis-parsing them.
+        let mut headers = reqwest::header::HeaderMap::new();
+        headers.insert(
+            API_VERSION_HEADER,
+            reqwest::header::HeaderValue::from_static(API_VERSION),
+        );
         let client = Client::builder()
             // No global timeout for CLI client as we need long-lived streams
+            .default_headers(headers)
             .build()
             .context("Failed to create HTTP client")?;
 
@@ -97,8 +110,7 @@ impl DaemonClient {
             .context("Failed to deploy agent")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to deploy agent: {error_text}");
+            return Err(http_error(response, "Failed to deploy agent").await);
         }
 
         #[derive(Deserialize)]
@@ -114,6 +126,29 @@ impl DaemonClient {
         Ok(deploy_response.agent_id)
     }
 
+    /// Run the agent's `spec.healthcheck`. Blocks until the smoke execution
+    /// finishes or hits its timeout.
+    pub async fn verify_agent(&self, agent_id: Uuid) -> Result<AgentVerifyReport> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                format!("{}/v1/agents/{}/verify", self.base_url, agent_id),
+            )
+            .send()
+            .await
+            .context("Failed to verify agent")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to verify agent").await);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse verify response")
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub async fn execute_agent(
         &self,
         agent_id: Uuid,
@@ -122,6 +157,9 @@ impl DaemonClient {
         context_overrides: Option<serde_json::Value>,
         version: Option<&str>,
         attachments: Vec<aegis_orchestrator_core::domain::execution::AttachmentRef>,
+        labels: BTreeMap<String, String>,
+        network_flow_capture: bool,
+        files: &[std::path::PathBuf],
     ) -> Result<Uuid> {
         #[derive(Serialize)]
         struct ExecuteRequest {
@@ -132,6 +170,10 @@ impl DaemonClient {
             context_overrides: Option<serde_json::Value>,
             #[serde(skip_serializing_if = "Vec::is_empty")]
             attachments: Vec<aegis_orchestrator_core::domain::execution::AttachmentRef>,
+            #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+            labels: BTreeMap<String, String>,
+            #[serde(skip_serializing_if = "std::ops::Not::not")]
+            network_flow_capture: bool,
         }
 
         let mut url = format!("{}/v1/agents/{}/execute", self.base_url, agent_id);
@@ -139,21 +181,44 @@ impl DaemonClient {
             url.push_str(&format!("?version={ver}"));
         }
 
-        let response = self
-            .request(reqwest::Method::POST, url)
-            .json(&ExecuteRequest {
-                input,
-                intent,
-                context_overrides,
-                attachments,
-            })
-            .send()
-            .await
-            .context("Failed to execute agent")?;
+        let request = ExecuteRequest {
+            input,
+            intent,
+            context_overrides,
+            attachments,
+            labels,
+            network_flow_capture,
+        };
+        let builder = self.request(reqwest::Method::POST, url);
+        // Local files are uploaded in the same request; the daemon stores
+        // them and adds them to the attachments.
+        let builder = if files.is_empty() {
+            builder.json(&request)
+        } else {
+            let mut form = reqwest::multipart::Form::new().part(
+                "request",
+                reqwest::multipart::Part::text(serde_json::to_string(&request)?)
+                    .mime_str("application/json")?,
+            );
+            for path in files {
+                let data = tokio::fs::read(path)
+                    .await
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                let name = path
+                    .file_name()
+                    .map(|n| n.to_string_lossy().into_owned())
+                    .unwrap_or_else(|| "attachment".to_string());
+                form = form.part(
+                    "file",
+                    reqwest::multipart::Part::bytes(data).file_name(name),
+                );
+            }
+            builder.multipart(form)
+        };
+        let response = builder.send().await.context("Failed to execute agent")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to execute agent: {error_text}");
+            return Err(http_error(response, "Failed to execute agent").await);
         }
 
         #[derive(Deserialize)]
@@ -180,8 +245,7 @@ impl DaemonClient {
             .context("Failed to get execution")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to get execution: {error_text}");
+            return Err(http_error(response, "Failed to get execution").await);
         }
 
         response
@@ -190,6 +254,252 @@ impl DaemonClient {
             .context("Failed to parse execution response")
     }
 
+    /// Annotated timeline of an execution, with its plain-text rendering
+    /// under `text`.
+    pub async fn explain_execution(&self, execution_id: Uuid) -> Result<Value> {
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                format!(
+                    "{}/v1/executions/{}/explain?text=true",
+                    self.base_url, execution_id
+                ),
+            )
+            .send()
+            .await
+            .context("Failed to explain execution")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to explain execution").await);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse execution explanation")
+    }
+
+    /// Per-iteration file activity of an execution.
+    pub async fn get_execution_file_activity(&self, execution_id: Uuid) -> Result<Value> {
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                format!(
+                    "{}/v1/executions/{}/file-activity",
+                    self.base_url, execution_id
+                ),
+            )
+            .send()
+            .await
+            .context("Failed to get execution file activity")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to get execution file activity").await);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse file activity response")
+    }
+
+    /// Network flows recorded for an execution, with their summary.
+    pub async fn get_execution_network_flows(&self, execution_id: Uuid) -> Result<Value> {
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                format!(
+                    "{}/v1/executions/{}/network-flows",
+                    self.base_url, execution_id
+                ),
+            )
+            .send()
+            .await
+            .context("Failed to get execution network flows")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to get execution network flows").await);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse network flows response")
+    }
+
+    /// Output attachments of an execution
+    /// (`GET /v1/executions/{id}/attachments`).
+    pub async fn list_execution_attachments(
+        &self,
+        execution_id: Uuid,
+    ) -> Result<Vec<aegis_orchestrator_core::domain::execution::AttachmentRef>> {
+        #[derive(Deserialize)]
+        struct AttachmentsResponse {
+            attachments: Vec<aegis_orchestrator_core::domain::execution::AttachmentRef>,
+        }
+
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                format!(
+                    "{}/v1/executions/{}/attachments",
+                    self.base_url, execution_id
+                ),
+            )
+            .send()
+            .await
+            .context("Failed to list execution attachments")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to list execution attachments").await);
+        }
+
+        let body: AttachmentsResponse = response
+            .json()
+            .await
+            .context("Failed to parse attachments response")?;
+        Ok(body.attachments)
+    }
+
+    /// Download a file from an execution's workspace.
+    pub async fn download_execution_file(&self, execution_id: Uuid, path: &str) -> Result<Vec<u8>> {
+        let encoded_path = path
+            .trim_start_matches('/')
+            .split('/')
+            .map(|segment| {
+                percent_encoding::utf8_percent_encode(segment, percent_encoding::NON_ALPHANUMERIC)
+                    .to_string()
+            })
+            .collect::<Vec<_>>()
+            .join("/");
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                format!(
+                    "{}/v1/executions/{}/files/{}",
+                    self.base_url, execution_id, encoded_path
+                ),
+            )
+            .send()
+            .await
+            .with_context(|| format!("Failed to download {path}"))?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, &format!("Failed to download {path}")).await);
+        }
+
+        response
+            .bytes()
+            .await
+            .map(|bytes| bytes.to_vec())
+            .with_context(|| format!("Failed to read {path}"))
+    }
+
+    /// Apply a label merge patch (`None` removes a key); returns the
+    /// resulting labels.
+    pub async fn update_execution_labels(
+        &self,
+        execution_id: Uuid,
+        patch: &BTreeMap<String, Option<String>>,
+    ) -> Result<BTreeMap<String, String>> {
+        let response = self
+            .request(
+                reqwest::Method::PATCH,
+                format!("{}/v1/executions/{}", self.base_url, execution_id),
+            )
+            .json(&serde_json::json!({ "labels": patch }))
+            .send()
+            .await
+            .context("Failed to update execution labels")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to update execution labels").await);
+        }
+
+        #[derive(Deserialize)]
+        struct UpdateResponse {
+            labels: BTreeMap<String, String>,
+        }
+
+        let update: UpdateResponse = response
+            .json()
+            .await
+            .context("Failed to parse label update response")?;
+        Ok(update.labels)
+    }
+
+    /// Queue operator guidance for the next iteration of a running execution;
+    /// returns the iteration number it will be applied to.
+    pub async fn add_execution_guidance(&self, execution_id: Uuid, guidance: &str) -> Result<u8> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                format!("{}/v1/executions/{}/guidance", self.base_url, execution_id),
+            )
+            .json(&serde_json::json!({ "guidance": guidance }))
+            .send()
+            .await
+            .context("Failed to add execution guidance")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to add execution guidance").await);
+        }
+
+        #[derive(Deserialize)]
+        struct GuidanceResponse {
+            iteration_number: u8,
+        }
+
+        let added: GuidanceResponse = response
+            .json()
+            .await
+            .context("Failed to parse guidance response")?;
+        Ok(added.iteration_number)
+    }
+
+    /// Put the daemon's node into maintenance (`POST /v1/node/cordon`).
+    pub async fn cordon_node(&self, reason: Option<&str>) -> Result<serde_json::Value> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                format!("{}/v1/node/cordon", self.base_url),
+            )
+            .json(&serde_json::json!({ "reason": reason }))
+            .send()
+            .await
+            .context("Failed to cordon node")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to cordon node").await);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse cordon response")
+    }
+
+    /// Take the daemon's node out of maintenance (`POST /v1/node/uncordon`).
+    pub async fn uncordon_node(&self) -> Result<serde_json::Value> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                format!("{}/v1/node/uncordon", self.base_url),
+            )
+            .send()
+            .await
+            .context("Failed to uncordon node")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to uncordon node").await);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse uncordon response")
+    }
+
     pub async fn cancel_execution(&self, execution_id: Uuid) -> Result<()> {
         let response = self
             .request(
@@ -201,32 +511,126 @@ impl DaemonClient {
             .context("Failed to cancel execution")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to cancel execution: {error_text}");
+            return Err(http_error(response, "Failed to cancel execution").await);
         }
 
         Ok(())
     }
 
+    /// `POST /v1/executions/bulk/{action}` with `dry_run`: the executions a
+    /// bulk `cancel` or `requeue` would apply to.
+    pub async fn preview_bulk_executions(
+        &self,
+        action: &str,
+        filter: &str,
+    ) -> Result<Vec<ExecutionInfo>> {
+        #[derive(Deserialize)]
+        struct PreviewResponse {
+            executions: Vec<ExecutionInfo>,
+        }
+
+        let preview: PreviewResponse = self.bulk_executions(action, filter, true).await?;
+        Ok(preview.executions)
+    }
+
+    /// `POST /v1/executions/bulk/{action}`: start a bulk `cancel` or
+    /// `requeue` in the background.
+    pub async fn start_bulk_executions(
+        &self,
+        action: &str,
+        filter: &str,
+    ) -> Result<BulkOperationInfo> {
+        self.bulk_executions(action, filter, false).await
+    }
+
+    async fn bulk_executions<T: serde::de::DeserializeOwned>(
+        &self,
+        action: &str,
+        filter: &str,
+        dry_run: bool,
+    ) -> Result<T> {
+        let response = self
+            .request(
+                reqwest::Method::POST,
+                format!("{}/v1/executions/bulk/{action}", self.base_url),
+            )
+            .json(&serde_json::json!({ "filter": filter, "dry_run": dry_run }))
+            .send()
+            .await
+            .with_context(|| format!("Failed to {action} executions"))?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, &format!("Failed to {action} executions")).await);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse bulk operation response")
+    }
+
+    /// `GET /v1/executions/bulk/{operation_id}`: progress of a bulk operation.
+    pub async fn get_bulk_operation(&self, operation_id: Uuid) -> Result<BulkOperationInfo> {
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                format!("{}/v1/executions/bulk/{operation_id}", self.base_url),
+            )
+            .send()
+            .await
+            .context("Failed to get bulk operation")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to get bulk operation").await);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse bulk operation response")
+    }
+
+    /// `GET /health/ready`: overall readiness, daemon version and the
+    /// readiness of each dependency.
+    pub async fn readiness(&self) -> Result<ReadinessInfo> {
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                format!("{}/health/ready", self.base_url),
+            )
+            .send()
+            .await
+            .context("Failed to query daemon readiness")?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Readiness probe failed: HTTP {}", response.status());
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse readiness response")
+    }
+
     pub async fn list_executions(
         &self,
         agent_id: Option<Uuid>,
         limit: usize,
+        filter: Option<&str>,
     ) -> Result<Vec<ExecutionInfo>> {
         let mut url = format!("{}/v1/executions?limit={}", self.base_url, limit);
         if let Some(aid) = agent_id {
             url.push_str(&format!("&agent_id={aid}"));
         }
 
-        let response = self
-            .request(reqwest::Method::GET, &url)
-            .send()
-            .await
-            .context("Failed to list executions")?;
+        let mut request = self.request(reqwest::Method::GET, &url);
+        if let Some(filter) = filter {
+            request = request.query(&[("filter", filter)]);
+        }
+        let response = request.send().await.context("Failed to list executions")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to list executions: {error_text}");
+            return Err(http_error(response, "Failed to list executions").await);
         }
 
         response
@@ -259,13 +663,60 @@ impl DaemonClient {
             .context("Failed to connect to event stream")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to stream logs: {error_text}");
+            return Err(http_error(response, "Failed to stream logs").await);
         }
 
         stream_correlated_events(response, errors_only, verbose).await
     }
 
+    /// Follow the activity stream of an execution, handing every event to
+    /// `on_event` until it returns [`ControlFlow::Break`] or the daemon
+    /// closes the stream.
+    pub async fn watch_execution_events<F>(&self, execution_id: Uuid, mut on_event: F) -> Result<()>
+    where
+        F: FnMut(&CorrelatedActivityEvent) -> ControlFlow<()>,
+    {
+        let url = format!(
+            "{}/v1/executions/{}/events?follow=true",
+            self.base_url, execution_id
+        );
+        let response = self
+            .request(reqwest::Method::GET, &url)
+            .send()
+            .await
+            .context("Failed to connect to event stream")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to watch execution").await);
+        }
+
+        let mut stream = response.bytes_stream();
+        // SSE lines may be split across chunks; only complete lines are parsed.
+        let mut pending = String::new();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.context("Failed to read event stream chunk")?;
+            pending.push_str(&String::from_utf8_lossy(&chunk));
+
+            while let Some(newline) = pending.find('\n') {
+                let line: String = pending.drain(..=newline).collect();
+                let line = line.trim_end();
+                if line == "event: stream_closed" {
+                    return Ok(());
+                }
+                let Some(json_str) = line.strip_prefix("data: ") else {
+                    continue;
+                };
+                if let Ok(event) = serde_json::from_str::<CorrelatedActivityEvent>(json_str) {
+                    if on_event(&event).is_break() {
+                        return Ok(());
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn stream_agent_logs(
         &self,
         agent_id: Uuid,
@@ -290,8 +741,7 @@ impl DaemonClient {
             .context("Failed to connect to agent event stream")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to stream agent logs: {error_text}");
+            return Err(http_error(response, "Failed to stream agent logs").await);
         }
 
         stream_correlated_events(response, errors_only, verbose).await
@@ -308,8 +758,7 @@ impl DaemonClient {
             .context("Failed to delete execution")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to delete execution: {error_text}");
+            return Err(http_error(response, "Failed to delete execution").await);
         }
 
         Ok(())
@@ -323,8 +772,7 @@ impl DaemonClient {
             .context("Failed to list agents")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to list agents: {error_text}");
+            return Err(http_error(response, "Failed to list agents").await);
         }
 
         response
@@ -344,8 +792,7 @@ impl DaemonClient {
             .context("Failed to get agent")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to get agent: {error_text}");
+            return Err(http_error(response, "Failed to get agent").await);
         }
 
         #[derive(Debug, Deserialize)]
@@ -371,8 +818,7 @@ impl DaemonClient {
             .context("Failed to delete agent")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to delete agent: {error_text}");
+            return Err(http_error(response, "Failed to delete agent").await);
         }
 
         Ok(())
@@ -392,8 +838,7 @@ impl DaemonClient {
         }
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to lookup agent: {error_text}");
+            return Err(http_error(response, "Failed to lookup agent").await);
         }
 
         #[derive(Deserialize)]
@@ -436,8 +881,7 @@ impl DaemonClient {
             .context("Failed to write secret")?;
 
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to write secret at '{path}': {err}");
+            return Err(http_error(response, &format!("Failed to write secret at '{path}'")).await);
         }
         Ok(())
     }
@@ -451,11 +895,12 @@ impl DaemonClient {
             .context("Failed to read secret")?;
 
         if response.status() == reqwest::StatusCode::NOT_FOUND {
-            anyhow::bail!("Secret not found at '{path}'");
+            return Err(
+                CliError::new(ExitCode::NotFound, format!("Secret not found at '{path}'")).into(),
+            );
         }
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to read secret at '{path}': {err}");
+            return Err(http_error(response, &format!("Failed to read secret at '{path}'")).await);
         }
         response
             .json()
@@ -476,8 +921,7 @@ impl DaemonClient {
             return Ok(None);
         }
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to list secrets: {err}");
+            return Err(http_error(response, "Failed to list secrets").await);
         }
         let body = response
             .json()
@@ -495,8 +939,9 @@ impl DaemonClient {
             .context("Failed to delete secret")?;
 
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to delete secret at '{path}': {err}");
+            return Err(
+                http_error(response, &format!("Failed to delete secret at '{path}'")).await,
+            );
         }
         Ok(())
     }
@@ -533,8 +978,7 @@ impl DaemonClient {
             .context("Failed to store API key")?;
 
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to store API key: {err}");
+            return Err(http_error(response, "Failed to store API key").await);
         }
         response
             .json()
@@ -565,8 +1009,7 @@ impl DaemonClient {
             .context("Failed to initiate OAuth flow")?;
 
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to initiate OAuth: {err}");
+            return Err(http_error(response, "Failed to initiate OAuth").await);
         }
         response
             .json()
@@ -583,8 +1026,7 @@ impl DaemonClient {
             .context("Failed to list credentials")?;
 
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to list credentials: {err}");
+            return Err(http_error(response, "Failed to list credentials").await);
         }
         response
             .json()
@@ -601,11 +1043,12 @@ impl DaemonClient {
             .context("Failed to get credential")?;
 
         if response.status() == reqwest::StatusCode::NOT_FOUND {
-            anyhow::bail!("Credential not found: {id}");
+            return Err(
+                CliError::new(ExitCode::NotFound, format!("Credential not found: {id}")).into(),
+            );
         }
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to get credential '{id}': {err}");
+            return Err(http_error(response, &format!("Failed to get credential '{id}'")).await);
         }
         response
             .json()
@@ -622,8 +1065,7 @@ impl DaemonClient {
             .context("Failed to delete credential")?;
 
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to delete credential '{id}': {err}");
+            return Err(http_error(response, &format!("Failed to delete credential '{id}'")).await);
         }
         Ok(())
     }
@@ -657,8 +1099,11 @@ impl DaemonClient {
             .context("Failed to add credential grant")?;
 
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to add grant for credential '{binding_id}': {err}");
+            return Err(http_error(
+                response,
+                &format!("Failed to add grant for credential '{binding_id}'"),
+            )
+            .await);
         }
         response
             .json()
@@ -675,8 +1120,11 @@ impl DaemonClient {
             .context("Failed to list credential grants")?;
 
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to list grants for credential '{binding_id}': {err}");
+            return Err(http_error(
+                response,
+                &format!("Failed to list grants for credential '{binding_id}'"),
+            )
+            .await);
         }
         response
             .json()
@@ -696,10 +1144,11 @@ impl DaemonClient {
             .context("Failed to revoke credential grant")?;
 
         if !response.status().is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!(
-                "Failed to revoke grant '{grant_id}' from credential '{binding_id}': {err}"
-            );
+            return Err(http_error(
+                response,
+                &format!("Failed to revoke grant '{grant_id}' from credential '{binding_id}'"),
+            )
+            .await);
         }
         Ok(())
     }
@@ -719,16 +1168,60 @@ impl DaemonClient {
             .send()
             .await
             .context("Failed to stat attachment file")?;
-        let status = response.status();
-        if !status.is_success() {
-            let err = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to stat {volume_id}:{path}: {status} {err}");
+        if !response.status().is_success() {
+            return Err(http_error(response, &format!("Failed to stat {volume_id}:{path}")).await);
         }
         response
             .json()
             .await
             .context("Failed to parse stat response")
     }
+
+    /// Filename search across the caller's volumes (`GET /v1/volumes/search`).
+    pub async fn search_volume_files(
+        &self,
+        name: &str,
+        limit: usize,
+        refresh: bool,
+    ) -> Result<serde_json::Value> {
+        let url = format!("{}/v1/volumes/search", self.base_url);
+        let response = self
+            .request(reqwest::Method::GET, url)
+            .query(&[
+                ("name", name.to_string()),
+                ("limit", limit.to_string()),
+                ("refresh", refresh.to_string()),
+            ])
+            .send()
+            .await
+            .context("Failed to search volumes")?;
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to search volumes").await);
+        }
+        response
+            .json()
+            .await
+            .context("Failed to parse volume search response")
+    }
+
+    /// Bounded content search across the caller's volumes
+    /// (`POST /v1/volumes/grep`).
+    pub async fn grep_volumes(&self, request: serde_json::Value) -> Result<serde_json::Value> {
+        let url = format!("{}/v1/volumes/grep", self.base_url);
+        let response = self
+            .request(reqwest::Method::POST, url)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to grep volumes")?;
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to grep volumes").await);
+        }
+        response
+            .json()
+            .await
+            .context("Failed to parse volume grep response")
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -747,6 +1240,49 @@ pub struct ExecutionInfo {
     pub status: String,
     pub started_at: Option<String>,
     pub ended_at: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+/// Progress of a bulk cancel or requeue.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BulkOperationInfo {
+    pub id: Uuid,
+    pub action: String,
+    pub filter: String,
+    /// `running` or `completed`.
+    pub state: String,
+    pub total: usize,
+    pub processed: usize,
+    pub succeeded: usize,
+    #[serde(default)]
+    pub failures: Vec<BulkFailureInfo>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub requeued: Vec<RequeuedExecutionInfo>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BulkFailureInfo {
+    pub execution_id: Uuid,
+    pub error: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RequeuedExecutionInfo {
+    pub from: Uuid,
+    pub to: Uuid,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReadinessInfo {
+    pub status: String,
+    /// Absent on daemons that predate version reporting.
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub uptime_seconds: Option<u64>,
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, bool>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -758,6 +1294,17 @@ pub struct AgentInfo {
     pub status: String,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AgentVerifyReport {
+    pub execution_id: Uuid,
+    pub passed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// `fail` or `degrade`, from `spec.healthcheck.on_failure`.
+    pub on_failure: String,
+    pub agent_status: String,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct WorkflowExecutionInfo {
     pub execution_id: Uuid,
@@ -827,6 +1374,11 @@ async fn stream_correlated_events(
         let text = String::from_utf8_lossy(&chunk);
 
         for line in text.lines() {
+            // The daemon closes followed streams whose execution was deleted.
+            if line == "event: stream_closed" {
+                info!("Event stream closed by daemon: execution no longer exists");
+                return Ok(());
+            }
             if let Some(json_str) = line.strip_prefix("data: ") {
                 if let Ok(event) = serde_json::from_str::<CorrelatedActivityEvent>(json_str) {
                     if errors_only && !is_error_event(&event) {
@@ -960,6 +1512,7 @@ fn is_error_event(event: &CorrelatedActivityEvent) -> bool {
             | "path_traversal_blocked"
             | "quota_exceeded"
             | "unauthorized_volume_access"
+            | "storage_operation_failed"
             | "policy_violation"
             | "invocation_failed"
             | "policy_violation_blocked"
@@ -976,21 +1529,26 @@ fn is_error_event(event: &CorrelatedActivityEvent) -> bool {
     )
 }
 
-fn extract_iteration_error_message(event: &CorrelatedActivityEvent) -> String {
-    if let Some(msg) = event
+/// Payload field of an activity event. Live bus events carry their fields
+/// inside a versioned envelope's `payload`; history events carry them flat.
+fn detail<'a>(event: &'a CorrelatedActivityEvent, key: &str) -> Option<&'a Value> {
+    event
         .details
-        .get("error")
+        .get(key)
+        .or_else(|| event.details.get("payload")?.get(key))
+}
+
+fn extract_iteration_error_message(event: &CorrelatedActivityEvent) -> String {
+    if let Some(msg) = detail(event, "error")
         .and_then(|e| e.get("message"))
         .and_then(Value::as_str)
     {
         return msg.to_string();
     }
 
-    if let Some(msg) = event
-        .details
-        .get("error")
+    if let Some(msg) = detail(event, "error")
         .and_then(Value::as_str)
-        .or_else(|| event.details.get("reason").and_then(Value::as_str))
+        .or_else(|| detail(event, "reason").and_then(Value::as_str))
     {
         return msg.to_string();
     }
@@ -1021,14 +1579,10 @@ fn format_event(event: &CorrelatedActivityEvent, verbose: bool) -> String {
 
     match event_type.as_str() {
         "console_output" => {
-            let stream = event
-                .details
-                .get("stream")
+            let stream = detail(event, "stream")
                 .and_then(Value::as_str)
                 .unwrap_or("stdout");
-            let content = event
-                .details
-                .get("output")
+            let content = detail(event, "output")
                 .and_then(Value::as_str)
                 .unwrap_or(&event.message);
             let prefix = match stream {
@@ -1039,19 +1593,13 @@ fn format_event(event: &CorrelatedActivityEvent, verbose: bool) -> String {
             format!("{prefix} {}", content.trim_end())
         }
         "llm_interaction" if verbose => {
-            let model = event
-                .details
-                .get("model")
+            let model = detail(event, "model")
                 .and_then(Value::as_str)
                 .unwrap_or("unknown");
-            let prompt = event
-                .details
-                .get("prompt")
+            let prompt = detail(event, "prompt")
                 .and_then(Value::as_str)
                 .unwrap_or("");
-            let response = event
-                .details
-                .get("response")
+            let response = detail(event, "response")
                 .and_then(Value::as_str)
                 .unwrap_or("");
             format!(
@@ -1063,9 +1611,7 @@ fn format_event(event: &CorrelatedActivityEvent, verbose: bool) -> String {
             )
         }
         "llm_interaction" => {
-            let model = event
-                .details
-                .get("model")
+            let model = detail(event, "model")
                 .and_then(Value::as_str)
                 .unwrap_or("unknown");
             format!("{header} {category} {} [{model}]", "LLM".purple())
@@ -1097,7 +1643,7 @@ fn format_event(event: &CorrelatedActivityEvent, verbose: bool) -> String {
     }
 }
 
-fn canonical_event_type(event_type: &str) -> String {
+pub(crate) fn canonical_event_type(event_type: &str) -> String {
     if event_type.bytes().any(|byte| byte.is_ascii_uppercase()) {
         let mut canonical = String::with_capacity(event_type.len() + 4);
         for (index, ch) in event_type.chars().enumerate() {
@@ -1173,8 +1719,7 @@ impl DaemonClient {
             .context("Failed to deploy workflow")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to deploy workflow: {error_text}");
+            return Err(http_error(response, "Failed to deploy workflow").await);
         }
 
         Ok(())
@@ -1215,8 +1760,7 @@ impl DaemonClient {
             .context("Failed to run workflow")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to run workflow: {error_text}");
+            return Err(http_error(response, "Failed to run workflow").await);
         }
 
         #[derive(Deserialize)]
@@ -1244,8 +1788,7 @@ impl DaemonClient {
             .context("Failed to list workflows")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to list workflows: {error_text}");
+            return Err(http_error(response, "Failed to list workflows").await);
         }
 
         let list_response: WorkflowListResponse = response
@@ -1288,8 +1831,7 @@ impl DaemonClient {
             .context("Failed to list workflows")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to list workflows: {error_text}");
+            return Err(http_error(response, "Failed to list workflows").await);
         }
 
         let list_response: WorkflowListResponse = response
@@ -1322,8 +1864,7 @@ impl DaemonClient {
             .context("Failed to change workflow scope")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to change workflow scope: {error_text}");
+            return Err(http_error(response, "Failed to change workflow scope").await);
         }
 
         let result: serde_json::Value = response
@@ -1352,8 +1893,7 @@ impl DaemonClient {
             .context("Failed to list workflow executions")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to list workflow executions: {error_text}");
+            return Err(http_error(response, "Failed to list workflow executions").await);
         }
 
         let executions: Vec<WorkflowExecutionInfo> = response
@@ -1376,8 +1916,7 @@ impl DaemonClient {
             .context("Failed to describe workflow")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to describe workflow: {error_text}");
+            return Err(http_error(response, "Failed to describe workflow").await);
         }
 
         let value = response
@@ -1400,8 +1939,7 @@ impl DaemonClient {
             .context("Failed to delete workflow")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to delete workflow: {error_text}");
+            return Err(http_error(response, "Failed to delete workflow").await);
         }
 
         Ok(())
@@ -1421,8 +1959,7 @@ impl DaemonClient {
             .context("Failed to get workflow execution")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to get workflow execution: {error_text}");
+            return Err(http_error(response, "Failed to get workflow execution").await);
         }
 
         response
@@ -1431,6 +1968,35 @@ impl DaemonClient {
             .context("Failed to parse workflow execution response")
     }
 
+    /// Blackboard snapshot of the `index`-th transition of a workflow execution
+    /// (`GET /v1/workflows/executions/{id}/states/{index}/blackboard`).
+    pub async fn get_workflow_transition_blackboard(
+        &self,
+        execution_id: Uuid,
+        index: usize,
+    ) -> Result<Value> {
+        let response = self
+            .request(
+                reqwest::Method::GET,
+                format!(
+                    "{}/v1/workflows/executions/{}/states/{}/blackboard",
+                    self.base_url, execution_id, index
+                ),
+            )
+            .send()
+            .await
+            .context("Failed to get transition blackboard")?;
+
+        if !response.status().is_success() {
+            return Err(http_error(response, "Failed to get transition blackboard").await);
+        }
+
+        response
+            .json()
+            .await
+            .context("Failed to parse transition blackboard response")
+    }
+
     pub async fn signal_workflow_execution(
         &self,
         execution_id: Uuid,
@@ -1450,8 +2016,7 @@ impl DaemonClient {
             .context("Failed to signal workflow execution")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to signal workflow execution: {error_text}");
+            return Err(http_error(response, "Failed to signal workflow execution").await);
         }
 
         Ok(())
@@ -1471,8 +2036,7 @@ impl DaemonClient {
             .context("Failed to cancel workflow execution")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to cancel workflow execution: {error_text}");
+            return Err(http_error(response, "Failed to cancel workflow execution").await);
         }
 
         Ok(())
@@ -1489,8 +2053,7 @@ impl DaemonClient {
             .context("Failed to remove workflow execution")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to remove workflow execution: {error_text}");
+            return Err(http_error(response, "Failed to remove workflow execution").await);
         }
 
         Ok(())
@@ -1514,8 +2077,7 @@ impl DaemonClient {
             .context("Failed to connect to workflow log stream")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to stream workflow logs: {error_text}");
+            return Err(http_error(response, "Failed to stream workflow logs").await);
         }
 
         stream_workflow_events(response, options).await
@@ -1539,8 +2101,7 @@ impl DaemonClient {
             .context("Failed to get workflow logs")?;
 
         if !response.status().is_success() {
-            let error_text = response.text().await.unwrap_or_default();
-            anyhow::bail!("Failed to get workflow logs: {error_text}");
+            return Err(http_error(response, "Failed to get workflow logs").await);
         }
 
         let payload: WorkflowLogsResponse = response
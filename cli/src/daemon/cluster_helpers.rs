@@ -203,6 +203,10 @@ pub(crate) async fn load_cluster_nodes(state: &AppState) -> Vec<ClusterNodeView>
             .map(|peer| cluster_node_view(&peer))
             .collect()
     } else {
-        vec![cluster_node_view(&fallback_cluster_node(&state.config))]
+        let mut node = fallback_cluster_node(&state.config);
+        if state.node_maintenance.is_cordoned() {
+            node.status = NodePeerStatus::Draining;
+        }
+        vec![cluster_node_view(&node)]
     }
 }
@@ -845,6 +845,7 @@ mod tests {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             }],
             deny_list: vec![],
             metadata: SecurityContextMetadata {
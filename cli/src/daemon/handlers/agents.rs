@@ -4,8 +4,8 @@
 
 use std::sync::Arc;
 
-use axum::extract::{Extension, Path, Query, State};
-use axum::http::{HeaderMap, StatusCode};
+use axum::extract::{Extension, FromRequest, Multipart, Path, Query, Request, State};
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::response::sse::{Event, Sse};
 use axum::response::IntoResponse;
 use axum::Json;
@@ -13,14 +13,22 @@ use futures::StreamExt;
 use uuid::Uuid;
 
 use aegis_orchestrator_core::application::agent::AgentLifecycleService;
-use aegis_orchestrator_core::application::execution::ExecutionService;
+use aegis_orchestrator_core::application::attachment_store::AttachmentUpload;
+use aegis_orchestrator_core::application::cluster::NodeCordonedError;
+use aegis_orchestrator_core::application::concurrency_group::ConcurrencyError;
+use aegis_orchestrator_core::application::execution::{ExecutionService, NetworkFlowCaptureDenied};
 use aegis_orchestrator_core::application::scope_requester::ScopeChangeRequester;
 use aegis_orchestrator_core::domain::agent::{AgentId, AgentScope};
 use aegis_orchestrator_core::domain::execution::ExecutionInput;
-use aegis_orchestrator_core::domain::iam::{IdentityKind, UserIdentity};
+use aegis_orchestrator_core::domain::iam::{IdentityKind, UserIdentity, ZaruTier};
+use aegis_orchestrator_core::domain::manifest_change::{ManifestChangeError, ManifestUpdate};
 use aegis_orchestrator_core::domain::tenant::TenantId;
 use aegis_orchestrator_core::presentation::keycloak_auth::ScopeGuard;
 
+use crate::daemon::handlers::volumes::{
+    content_length_exceeds_cap, payload_too_large_response, read_body_capped,
+    resolve_max_file_size_bytes,
+};
 use crate::daemon::handlers::{
     is_operator, tenant_id_from_identity, tenant_id_from_request, TENANT_DELEGATION_HEADER,
 };
@@ -46,8 +54,23 @@ pub(crate) struct ExecuteRequest {
     /// verbatim so the dispatch path matches the SEAL JSON-RPC invoke shape.
     #[serde(default)]
     attachments: Vec<aegis_orchestrator_core::domain::execution::AttachmentRef>,
+    /// Execution labels; see `validate_labels`.
+    #[serde(default)]
+    labels: std::collections::BTreeMap<String, String>,
+    /// Record the execution's network flows. Requires the tenant's
+    /// `network_flow_capture` feature flag.
+    #[serde(default)]
+    network_flow_capture: bool,
 }
 
+/// Multipart part of an execute request that carries the [`ExecuteRequest`]
+/// JSON.
+const EXECUTE_REQUEST_PART: &str = "request";
+
+/// Largest JSON execute request. Multipart requests are bounded by the
+/// caller's upload cap instead.
+const MAX_EXECUTE_JSON_BYTES: u64 = 2 * 1024 * 1024;
+
 #[derive(serde::Deserialize, Default)]
 pub(crate) struct ExecuteAgentQuery {
     version: Option<String>,
@@ -78,6 +101,22 @@ pub(crate) async fn deploy_agent_handler(
         tenant_id
     };
 
+    let gate_report = state.agent_service.check_deploy_gates(
+        &effective_tenant_id,
+        &manifest,
+        identity.as_ref().map(|e| &e.0),
+    );
+    if gate_report.is_rejected() {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(serde_json::json!({
+                "error": gate_report.rejection_message(),
+                "findings": gate_report.findings,
+            })),
+        ));
+    }
+    let warnings: Vec<_> = gate_report.warnings().collect();
+
     match state
         .agent_service
         .deploy_agent_for_tenant(
@@ -89,7 +128,10 @@ pub(crate) async fn deploy_agent_handler(
         )
         .await
     {
-        Ok(id) => Ok((StatusCode::OK, Json(serde_json::json!({"agent_id": id.0})))),
+        Ok(id) => Ok((
+            StatusCode::OK,
+            Json(serde_json::json!({"agent_id": id.0, "warnings": warnings})),
+        )),
         Err(e) => Ok((
             StatusCode::INTERNAL_SERVER_ERROR,
             Json(serde_json::json!({"error": e.to_string()})),
@@ -97,6 +139,15 @@ pub(crate) async fn deploy_agent_handler(
     }
 }
 
+/// `POST /v1/agents/{agent_id}/execute`
+///
+/// The body is either a JSON [`ExecuteRequest`] or `multipart/form-data`
+/// (ADR-113): the [`EXECUTE_REQUEST_PART`] part carries the same JSON and
+/// every part with a filename is an attachment. Uploaded files are written
+/// to a new attachment volume owned by the caller and appended to
+/// `attachments`, so the agent finds them in `input.attachments` next to
+/// any refs the caller supplied. Uploads share the caller's per-tier file
+/// size cap.
 pub(crate) async fn execute_agent_handler(
     State(state): State<Arc<AppState>>,
     scope_guard: ScopeGuard,
@@ -104,13 +155,49 @@ pub(crate) async fn execute_agent_handler(
     headers: HeaderMap,
     Path(agent_id): Path<Uuid>,
     Query(query): Query<ExecuteAgentQuery>,
-    Json(request): Json<ExecuteRequest>,
+    http_request: Request,
 ) -> Result<impl IntoResponse, (axum::http::StatusCode, axum::Json<serde_json::Value>)> {
     scope_guard.require("agent:execute")?;
+    let identity_ref = identity.as_ref().map(|e| &e.0);
     let delegation = headers
         .get(TENANT_DELEGATION_HEADER)
         .and_then(|v| v.to_str().ok());
-    let tenant_id = tenant_id_from_request(identity.as_ref().map(|e| &e.0), delegation);
+    let tenant_id = tenant_id_from_request(identity_ref, delegation);
+
+    let is_multipart = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|ct| {
+            ct.trim_start()
+                .to_ascii_lowercase()
+                .starts_with("multipart/")
+        });
+    let (mut request, uploads, max_file_size) = if is_multipart {
+        let max_file_size =
+            resolve_max_file_size_bytes(&user_tier(identity_ref)).ok_or_else(|| {
+                (
+                    StatusCode::FORBIDDEN,
+                    Json(serde_json::json!({
+                        "error": "no upload size limit configured for caller tier"
+                    })),
+                )
+            })?;
+        if content_length_exceeds_cap(&headers, max_file_size) {
+            return Err(payload_too_large_response(max_file_size));
+        }
+        let (request, uploads) =
+            read_multipart_execute_request(http_request, max_file_size).await?;
+        (request, uploads, max_file_size)
+    } else {
+        let body = read_body_capped(http_request.into_body(), MAX_EXECUTE_JSON_BYTES).await?;
+        let Json(request) = Json::<ExecuteRequest>::from_bytes(&body).map_err(|e| {
+            (
+                e.status(),
+                Json(serde_json::json!({"error": e.body_text()})),
+            )
+        })?;
+        (request, Vec::new(), 0)
+    };
 
     // If a version query parameter is provided, verify the agent's manifest version matches
     if let Some(ref requested_version) = query.version {
@@ -141,12 +228,49 @@ pub(crate) async fn execute_agent_handler(
         }
     }
 
+    if let Err(e) = aegis_orchestrator_core::domain::execution::validate_labels(&request.labels) {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(serde_json::json!({"error": e.to_string()})),
+        ));
+    }
+
+    if !uploads.is_empty() {
+        let files: Vec<_> = uploads
+            .iter()
+            .map(|upload| AttachmentUpload {
+                filename: &upload.filename,
+                declared_mime_type: upload.content_type.as_deref(),
+                data: &upload.data,
+            })
+            .collect();
+        let stored = state
+            .attachment_store
+            .store(
+                &tenant_id,
+                &user_sub(identity_ref),
+                "execution-attachments",
+                &files,
+                max_file_size,
+            )
+            .await
+            .map_err(|e| {
+                (
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    Json(serde_json::json!({"error": e.to_string()})),
+                )
+            })?;
+        request.attachments.extend(stored);
+    }
+
     let input = ExecutionInput {
         intent: request.intent,
         input: serde_json::json!({
             "input": request.input,
             "context_overrides": request.context_overrides,
             "tenant_id": tenant_id.to_string(),
+            "labels": request.labels,
+            "network_flow_capture": request.network_flow_capture,
         }),
         workspace_volume_id: None,
         workspace_volume_mount_path: None,
@@ -177,7 +301,13 @@ pub(crate) async fn execute_agent_handler(
         )),
         Err(e) => {
             let error_str = e.to_string();
-            let status = if error_str.contains("InvalidExecutionInput") {
+            let status = if e.downcast_ref::<ConcurrencyError>().is_some() {
+                StatusCode::CONFLICT
+            } else if e.downcast_ref::<NodeCordonedError>().is_some() {
+                StatusCode::SERVICE_UNAVAILABLE
+            } else if e.downcast_ref::<NetworkFlowCaptureDenied>().is_some() {
+                StatusCode::FORBIDDEN
+            } else if error_str.contains("InvalidExecutionInput") {
                 StatusCode::UNPROCESSABLE_ENTITY
             } else {
                 StatusCode::INTERNAL_SERVER_ERROR
@@ -187,6 +317,140 @@ pub(crate) async fn execute_agent_handler(
     }
 }
 
+/// A file part of a multipart execute request.
+struct UploadedFile {
+    filename: String,
+    content_type: Option<String>,
+    data: Vec<u8>,
+}
+
+/// Read a multipart execute body. The running size of all parts is held to
+/// `max_bytes` while streaming, as for volume uploads.
+async fn read_multipart_execute_request(
+    http_request: Request,
+    max_bytes: u64,
+) -> Result<(ExecuteRequest, Vec<UploadedFile>), (StatusCode, Json<serde_json::Value>)> {
+    let bad_request = |message: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": message })),
+        )
+    };
+    let mut multipart = Multipart::from_request(http_request, &())
+        .await
+        .map_err(|e| bad_request(format!("multipart init error: {e}")))?;
+
+    let mut request = None;
+    let mut uploads = Vec::new();
+    let mut total_bytes: u64 = 0;
+    while let Some(mut field) = multipart
+        .next_field()
+        .await
+        .map_err(|e| bad_request(format!("multipart parse error: {e}")))?
+    {
+        let name = field.name().map(str::to_string);
+        let filename = field.file_name().map(str::to_string);
+        let content_type = field.content_type().map(str::to_string);
+        let mut data = Vec::new();
+        while let Some(chunk) = field
+            .chunk()
+            .await
+            .map_err(|e| bad_request(format!("multipart read error: {e}")))?
+        {
+            total_bytes = total_bytes.saturating_add(chunk.len() as u64);
+            if total_bytes > max_bytes {
+                return Err(payload_too_large_response(max_bytes));
+            }
+            data.extend_from_slice(&chunk);
+        }
+
+        match (filename, name.as_deref()) {
+            (Some(filename), _) => uploads.push(UploadedFile {
+                filename,
+                content_type,
+                data,
+            }),
+            (None, Some(EXECUTE_REQUEST_PART)) => {
+                let Json(parsed) = Json::<ExecuteRequest>::from_bytes(&data).map_err(|e| {
+                    let message =
+                        format!("invalid '{EXECUTE_REQUEST_PART}' part: {}", e.body_text());
+                    (e.status(), Json(serde_json::json!({ "error": message })))
+                })?;
+                request = Some(parsed);
+            }
+            // Browsers may add unrelated form fields; ignore them.
+            (None, _) => {}
+        }
+    }
+
+    let request = request.ok_or_else(|| {
+        (
+            StatusCode::UNPROCESSABLE_ENTITY,
+            Json(serde_json::json!({
+                "error": format!("multipart body has no '{EXECUTE_REQUEST_PART}' part")
+            })),
+        )
+    })?;
+    Ok((request, uploads))
+}
+
+fn user_tier(identity: Option<&UserIdentity>) -> ZaruTier {
+    match identity.map(|i| &i.identity_kind) {
+        Some(IdentityKind::ConsumerUser { zaru_tier, .. }) => zaru_tier.clone(),
+        _ => ZaruTier::Enterprise,
+    }
+}
+
+fn user_sub(identity: Option<&UserIdentity>) -> String {
+    identity
+        .map(|i| i.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string())
+}
+
+/// `POST /v1/agents/{agent_id}/verify` — run the agent's `spec.healthcheck`.
+///
+/// Blocks until the smoke execution finishes (bounded by
+/// `spec.healthcheck.timeout_seconds`) and returns the report. A failed check
+/// is still `200`; callers inspect `passed`.
+pub(crate) async fn verify_agent_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(agent_id): Path<Uuid>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    use aegis_orchestrator_core::application::agent_healthcheck::HealthCheckError;
+
+    scope_guard.require("agent:execute")?;
+    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|e| &e.0));
+    let security_context_name = identity
+        .as_ref()
+        .map(|ext| ext.0.to_security_context_name())
+        .unwrap_or_else(|| "aegis-system-operator".to_string());
+
+    match state
+        .agent_healthcheck_service
+        .verify(
+            &tenant_id,
+            AgentId(agent_id),
+            security_context_name,
+            identity.as_ref().map(|ext| &ext.0),
+        )
+        .await
+    {
+        Ok(report) => Ok((StatusCode::OK, Json(serde_json::json!(report)))),
+        Err(e) => {
+            let status = match e {
+                HealthCheckError::AgentNotFound(_) => StatusCode::NOT_FOUND,
+                HealthCheckError::NotDefined(_) => StatusCode::UNPROCESSABLE_ENTITY,
+                HealthCheckError::Repository(_) | HealthCheckError::Execution(_) => {
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+            };
+            Ok((status, Json(serde_json::json!({"error": e.to_string()}))))
+        }
+    }
+}
+
 pub(crate) async fn stream_agent_events_handler(
     State(state): State<Arc<AppState>>,
     scope_guard: ScopeGuard,
@@ -542,15 +806,52 @@ pub(crate) async fn lookup_agent_handler(
 }
 
 /// PATCH /v1/agents/:id - Update agent manifest with scope authorization check
+///
+/// The body is the whole new manifest, or an RFC 7386 merge patch against the
+/// stored one when sent as `application/merge-patch+json`. A top-level
+/// `change_reason` string is taken out of the body and recorded with the
+/// change; tenants with the `manifest_change_reason` flag must send one.
 pub(crate) async fn update_agent_handler(
     State(state): State<Arc<AppState>>,
     scope_guard: ScopeGuard,
     identity: Option<Extension<UserIdentity>>,
     headers: HeaderMap,
     Path(agent_id): Path<Uuid>,
-    Json(manifest): Json<aegis_orchestrator_sdk::AgentManifest>,
+    Json(mut body): Json<serde_json::Value>,
 ) -> Result<impl IntoResponse, (axum::http::StatusCode, axum::Json<serde_json::Value>)> {
     scope_guard.require("agent:deploy")?;
+    let reason = match body
+        .as_object_mut()
+        .and_then(|fields| fields.remove("change_reason"))
+    {
+        None => None,
+        Some(serde_json::Value::String(reason)) => Some(reason),
+        Some(_) => {
+            return Ok((
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({"error": "change_reason must be a string"})),
+            )
+                .into_response())
+        }
+    };
+    let is_merge_patch = headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/merge-patch+json"));
+    let update = if is_merge_patch {
+        ManifestUpdate::MergePatch(body)
+    } else {
+        match serde_json::from_value(body) {
+            Ok(manifest) => ManifestUpdate::Replace(Box::new(manifest)),
+            Err(e) => {
+                return Ok((
+                    StatusCode::UNPROCESSABLE_ENTITY,
+                    Json(serde_json::json!({"error": format!("Invalid manifest: {e}")})),
+                )
+                    .into_response())
+            }
+        }
+    };
     let delegation = headers
         .get(TENANT_DELEGATION_HEADER)
         .and_then(|v| v.to_str().ok());
@@ -585,17 +886,30 @@ pub(crate) async fn update_agent_handler(
 
             match state
                 .agent_service
-                .update_agent_for_tenant(&tenant_id, aid, manifest)
+                .change_agent_manifest_for_tenant(
+                    &tenant_id,
+                    aid,
+                    update,
+                    reason,
+                    identity.as_ref().map(|e| &e.0),
+                )
                 .await
             {
-                Ok(_) => Ok(
-                    (StatusCode::OK, Json(serde_json::json!({"success": true}))).into_response()
-                ),
-                Err(e) => Ok((
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(serde_json::json!({"error": e.to_string()})),
+                Ok(change) => Ok((
+                    StatusCode::OK,
+                    Json(serde_json::json!({"success": true, "change": change})),
                 )
                     .into_response()),
+                Err(e) => {
+                    let status = match e.downcast_ref::<ManifestChangeError>() {
+                        Some(ManifestChangeError::ReasonRequired) => StatusCode::BAD_REQUEST,
+                        Some(ManifestChangeError::InvalidManifest(_)) => {
+                            StatusCode::UNPROCESSABLE_ENTITY
+                        }
+                        None => StatusCode::INTERNAL_SERVER_ERROR,
+                    };
+                    Ok((status, Json(serde_json::json!({"error": e.to_string()}))).into_response())
+                }
             }
         }
         Err(_) => Ok((
@@ -606,6 +920,32 @@ pub(crate) async fn update_agent_handler(
     }
 }
 
+/// GET /v1/agents/:id/changes - Manifest change history, newest first
+pub(crate) async fn list_agent_manifest_changes_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    headers: HeaderMap,
+    Path(agent_id): Path<Uuid>,
+) -> Result<impl IntoResponse, (axum::http::StatusCode, axum::Json<serde_json::Value>)> {
+    scope_guard.require("agent:read")?;
+    let delegation = headers
+        .get(TENANT_DELEGATION_HEADER)
+        .and_then(|v| v.to_str().ok());
+    let tenant_id = tenant_id_from_request(identity.as_ref().map(|e| &e.0), delegation);
+    match state
+        .agent_service
+        .list_manifest_changes_for_tenant(&tenant_id, AgentId(agent_id))
+        .await
+    {
+        Ok(changes) => Ok((StatusCode::OK, Json(serde_json::json!(changes)))),
+        Err(e) => Ok((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )),
+    }
+}
+
 /// POST /v1/agents/:id/scope - Change agent scope (promote/demote)
 pub(crate) async fn update_agent_scope_handler(
     State(state): State<Arc<AppState>>,
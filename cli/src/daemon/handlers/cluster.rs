@@ -1,12 +1,14 @@
 // Copyright (c) 2026 100monkeys.ai
 // SPDX-License-Identifier: AGPL-3.0
-//! Cluster status and node handlers.
+//! Cluster status, node and node maintenance (cordon / uncordon) handlers.
 
 use std::sync::Arc;
 
-use axum::extract::{Query, State};
+use axum::extract::{Extension, Query, State};
+use axum::http::StatusCode;
 use axum::Json;
 
+use aegis_orchestrator_core::domain::iam::UserIdentity;
 use aegis_orchestrator_core::presentation::keycloak_auth::ScopeGuard;
 
 use crate::daemon::cluster_helpers::{cluster_status_view, load_cluster_nodes};
@@ -40,3 +42,66 @@ pub(crate) async fn cluster_nodes_handler(
         "items": nodes.into_iter().take(limit).collect::<Vec<_>>(),
     })))
 }
+
+#[derive(serde::Deserialize, Default)]
+pub(crate) struct CordonRequest {
+    #[serde(default)]
+    reason: Option<String>,
+}
+
+/// `GET /v1/node/maintenance` — this node's cordon state.
+pub(crate) async fn node_maintenance_status_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+) -> Result<impl axum::response::IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    scope_guard.require("node:read")?;
+    Ok(Json(serde_json::json!(state.node_maintenance.status())))
+}
+
+/// `POST /v1/node/cordon` — stop accepting new executions on this node.
+pub(crate) async fn cordon_node_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    request: Option<Json<CordonRequest>>,
+) -> Result<impl axum::response::IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    scope_guard.require("node:drain")?;
+    let reason = request
+        .and_then(|Json(request)| request.reason)
+        .filter(|reason| !reason.trim().is_empty());
+    let cordoned_by = identity
+        .as_ref()
+        .map(|ext| ext.0.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+
+    state
+        .node_maintenance
+        .cordon(reason, cordoned_by)
+        .await
+        .map(|outcome| Json(serde_json::json!(outcome)))
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e.to_string()})),
+            )
+        })
+}
+
+/// `POST /v1/node/uncordon` — accept new executions again.
+pub(crate) async fn uncordon_node_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+) -> Result<impl axum::response::IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    scope_guard.require("node:drain")?;
+    state
+        .node_maintenance
+        .uncordon()
+        .await
+        .map(|status| Json(serde_json::json!(status)))
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                Json(serde_json::json!({"error": e.to_string()})),
+            )
+        })
+}
@@ -11,6 +11,8 @@ use axum::Json;
 use uuid::Uuid;
 
 use aegis_orchestrator_core::application::execution::ExecutionService;
+use aegis_orchestrator_core::domain::dispatch::AgentMessage;
+use aegis_orchestrator_core::domain::execution::ExecutionId;
 use aegis_orchestrator_core::domain::iam::{IdentityKind, UserIdentity, ZaruTier};
 use aegis_orchestrator_core::infrastructure::TemporalEventPayload;
 
@@ -18,14 +20,61 @@ use tracing::Instrument;
 
 use crate::daemon::state::AppState;
 
+/// Replace an `encrypted_prompt` with its plaintext using the payload key
+/// negotiated at attestation. Executions that negotiated a key must not send
+/// plaintext prompts.
+fn decrypt_prompt(
+    state: &AppState,
+    agent_msg: &mut AgentMessage,
+) -> Result<(), (StatusCode, Json<serde_json::Value>)> {
+    let AgentMessage::Generate {
+        execution_id,
+        prompt,
+        encrypted_prompt,
+        ..
+    } = agent_msg
+    else {
+        return Ok(());
+    };
+    let bad_request = |message: String| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": message })),
+        )
+    };
+    let key = ExecutionId::from_string(execution_id)
+        .ok()
+        .and_then(|id| state.payload_keys.get(id).ok());
+
+    match (encrypted_prompt.take(), key) {
+        (Some(sealed), Some(key)) => {
+            *prompt = key
+                .decrypt(&sealed)
+                .map_err(|e| bad_request(e.to_string()))?;
+            Ok(())
+        }
+        (Some(_), None) => Err(bad_request(format!(
+            "No payload key negotiated for execution {execution_id}"
+        ))),
+        (None, Some(_)) => Err(bad_request(format!(
+            "Execution {execution_id} negotiated payload encryption; send encrypted_prompt"
+        ))),
+        (None, None) => Ok(()),
+    }
+}
+
 pub(crate) async fn dispatch_gateway_handler(
     State(state): State<Arc<AppState>>,
-    Json(agent_msg): Json<aegis_orchestrator_core::domain::dispatch::AgentMessage>,
+    Json(mut agent_msg): Json<AgentMessage>,
 ) -> impl IntoResponse {
-    use aegis_orchestrator_core::domain::dispatch::{AgentMessage, OrchestratorMessage};
+    use aegis_orchestrator_core::domain::dispatch::OrchestratorMessage;
 
     let started_at = std::time::Instant::now();
 
+    if let Err(response) = decrypt_prompt(&state, &mut agent_msg) {
+        return response;
+    }
+
     let (exec_id_opt, iteration_number, prompt_opt, model_opt) = match &agent_msg {
         AgentMessage::Generate {
             execution_id,
@@ -123,6 +172,8 @@ pub(crate) async fn dispatch_gateway_handler(
             content,
             tool_calls_executed,
             trajectory,
+            input_tokens,
+            output_tokens,
             ..
         }) => {
             // Publish LlmInteraction event for observability
@@ -139,8 +190,8 @@ pub(crate) async fn dispatch_gateway_handler(
                             iteration_number,
                             provider: "orchestrator".to_string(),
                             model: model_alias.clone(),
-                            input_tokens: None,
-                            output_tokens: None,
+                            input_tokens: Some(input_tokens),
+                            output_tokens: Some(output_tokens),
                             prompt: prompt.clone(),
                             response: content.clone(),
                             timestamp: chrono::Utc::now(),
@@ -153,6 +204,8 @@ pub(crate) async fn dispatch_gateway_handler(
                         prompt: prompt.clone(),
                         response: content.clone(),
                         timestamp: chrono::Utc::now(),
+                        input_tokens: Some(input_tokens),
+                        output_tokens: Some(output_tokens),
                     };
                     let _ = state
                         .execution_service
@@ -179,13 +232,24 @@ pub(crate) async fn dispatch_gateway_handler(
                 }
             }
 
-            (
-                StatusCode::OK,
-                Json(serde_json::json!({
+            // The final reply ends this bootstrap run, so its payload key is
+            // spent; the next iteration attests again.
+            let payload_key = exec_id_opt.and_then(|id| {
+                let key = state.payload_keys.get(ExecutionId(id)).ok();
+                state.payload_keys.remove(ExecutionId(id));
+                key
+            });
+            let body = match payload_key {
+                Some(key) => serde_json::json!({
+                    "encrypted_content": key.encrypt(&content),
+                    "tool_calls_executed": tool_calls_executed,
+                }),
+                None => serde_json::json!({
                     "content": content,
                     "tool_calls_executed": tool_calls_executed,
-                })),
-            )
+                }),
+            };
+            (StatusCode::OK, Json(body))
         }
         Ok(OrchestratorMessage::Dispatch {
             dispatch_id,
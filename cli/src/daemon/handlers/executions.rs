@@ -1,7 +1,9 @@
 // Copyright (c) 2026 100monkeys.ai
 // SPDX-License-Identifier: AGPL-3.0
-//! Execution handlers: get, cancel, list, delete, stream events, file retrieval.
+//! Execution handlers: get, cancel, list, label, delete, stream events, file retrieval,
+//! per-iteration file activity, bulk cancel / requeue by filter.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use axum::extract::{Extension, Path, State};
@@ -11,10 +13,22 @@ use axum::response::IntoResponse;
 use futures::StreamExt;
 use uuid::Uuid;
 
+use aegis_orchestrator_core::application::agent::AgentLifecycleService;
+use aegis_orchestrator_core::application::bulk_execution::BulkAction;
+use aegis_orchestrator_core::application::execution_explain::explain_execution;
+use aegis_orchestrator_core::application::execution_file_activity::file_activity_for_execution;
 use aegis_orchestrator_core::application::file_operations_service::FileOperationsError;
 use aegis_orchestrator_core::domain::agent::AgentId;
-use aegis_orchestrator_core::domain::execution::ExecutionId;
+use aegis_orchestrator_core::domain::events::ExecutionEvent;
+use aegis_orchestrator_core::domain::execution::{validate_labels, ExecutionId};
+use aegis_orchestrator_core::domain::execution_query::{
+    AgentSelector, CompareOp, ExecutionPredicate, ExecutionQuery,
+};
+use aegis_orchestrator_core::domain::guidance::{OperatorGuidance, MAX_GUIDANCE_CHARS};
 use aegis_orchestrator_core::domain::iam::UserIdentity;
+use aegis_orchestrator_core::domain::network_flow::NetworkFlowSummary;
+use aegis_orchestrator_core::domain::repository::RepositoryError;
+use aegis_orchestrator_core::domain::tenant::TenantId;
 use aegis_orchestrator_core::presentation::keycloak_auth::ScopeGuard;
 
 use crate::daemon::handlers::{is_operator, tenant_id_from_identity};
@@ -22,11 +36,18 @@ use crate::daemon::state::AppState;
 
 pub(crate) use crate::daemon::handlers::DEFAULT_MAX_EXECUTION_LIST_LIMIT;
 
+/// Interval between `heartbeat` events on followed execution streams. Well
+/// under common load balancer idle timeouts (60s).
+const STREAM_HEARTBEAT_INTERVAL: std::time::Duration = std::time::Duration::from_secs(15);
+
 #[derive(serde::Deserialize)]
 pub(crate) struct ListExecutionsQuery {
     pub(crate) agent_id: Option<Uuid>,
     pub(crate) workflow_name: Option<String>,
     pub(crate) limit: Option<usize>,
+    /// Filter expression, e.g. `status=failed AND started>-24h`. See
+    /// [`ExecutionQuery`] for the grammar.
+    pub(crate) filter: Option<String>,
 }
 
 pub(crate) async fn get_execution_handler(
@@ -67,6 +88,7 @@ pub(crate) async fn get_execution_handler(
                 "agent_id": exec.agent_id.0,
                 "status": format!("{:?}", exec.status),
                 "tenant_id": exec.tenant_id.as_str(),
+                "labels": exec.labels,
             })),
         )),
         // Audit 002 §4.37.6 — collapse not-found / not-visible to 404 instead
@@ -125,12 +147,64 @@ pub(crate) async fn stream_events_handler(
     let tenant_id = tenant_id_from_identity(identity.as_ref().map(|identity| &identity.0));
     let activity_service = state.correlated_activity_stream_service.clone();
 
+    let execution_repo = state.execution_repo.clone();
+    // Lets clients spot duplicated subscriptions to the same execution.
+    let stream_id = Uuid::new_v4();
+
     let stream = async_stream::stream! {
+        yield Ok::<_, anyhow::Error>(
+            Event::default()
+                .event("stream_open")
+                .data(serde_json::json!({ "stream_id": stream_id, "execution_id": exec_id.0 }).to_string()),
+        );
         if follow {
             let mut activity_stream = activity_service.stream_execution_activity(&tenant_id, exec_id, verbose).await?;
-            while let Some(activity) = activity_stream.next().await {
-                let payload = serde_json::to_string(&activity?)?;
-                yield Ok::<_, anyhow::Error>(Event::default().data(payload));
+            let mut heartbeat = tokio::time::interval(STREAM_HEARTBEAT_INTERVAL);
+            heartbeat.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            heartbeat.tick().await;
+            loop {
+                let next = tokio::select! {
+                    activity = activity_stream.next() => futures::future::Either::Left(activity),
+                    _ = heartbeat.tick() => futures::future::Either::Right(()),
+                };
+                match next {
+                    futures::future::Either::Left(Some(activity)) => {
+                        let payload = serde_json::to_string(&activity?)?;
+                        yield Ok::<_, anyhow::Error>(Event::default().data(payload));
+                    }
+                    futures::future::Either::Left(None) => break,
+                    futures::future::Either::Right(()) => {
+                        match execution_repo.find_by_id_for_tenant(&tenant_id, exec_id).await {
+                            Ok(Some(execution)) => {
+                                yield Ok(Event::default().event("heartbeat").data(
+                                    serde_json::json!({
+                                        "stream_id": stream_id,
+                                        "execution_id": exec_id.0,
+                                        "status": format!("{:?}", execution.status),
+                                        "timestamp": chrono::Utc::now(),
+                                    })
+                                    .to_string(),
+                                ));
+                            }
+                            // The execution was deleted while followed: nothing
+                            // more will ever arrive, so close instead of idling.
+                            Ok(None) => {
+                                yield Ok(Event::default().event("stream_closed").data(
+                                    serde_json::json!({
+                                        "stream_id": stream_id,
+                                        "execution_id": exec_id.0,
+                                        "reason": "execution_deleted",
+                                    })
+                                    .to_string(),
+                                ));
+                                break;
+                            }
+                            Err(e) => {
+                                tracing::warn!(%execution_id, error = %e, "Heartbeat status lookup failed");
+                            }
+                        }
+                    }
+                }
             }
         } else {
             for activity in activity_service.execution_history(&tenant_id, exec_id, verbose).await? {
@@ -203,6 +277,37 @@ pub(crate) async fn list_executions_handler(
     let identity_ref = identity.as_ref().map(|identity| &identity.0);
     let tenant_id = tenant_id_from_identity(identity_ref);
 
+    if let Some(filter) = query.filter.as_deref().filter(|f| !f.trim().is_empty()) {
+        if query.workflow_name.is_some() {
+            return Err((
+                StatusCode::BAD_REQUEST,
+                axum::Json(
+                    serde_json::json!({"error": "filter cannot be combined with workflow_name"}),
+                ),
+            ));
+        }
+        let mut execution_query = parse_execution_filter(&state, &tenant_id, filter).await?;
+        if let Some(agent_id) = agent_id {
+            execution_query.predicates.push(ExecutionPredicate::Agent {
+                op: CompareOp::Eq,
+                agent: AgentSelector::Id(agent_id),
+            });
+        }
+
+        return match state
+            .execution_repo
+            .find_by_query_for_tenant(&tenant_id, &execution_query, limit)
+            .await
+        {
+            Ok(executions) => {
+                let json_executions: Vec<serde_json::Value> =
+                    executions.iter().map(execution_summary_json).collect();
+                Ok(axum::Json(serde_json::json!(json_executions)))
+            }
+            Err(e) => Ok(axum::Json(serde_json::json!({"error": e.to_string()}))),
+        };
+    }
+
     // Resolve workflow_name to a WorkflowId if provided
     let workflow_id = if let Some(ref wf_name) = query.workflow_name {
         match state
@@ -244,25 +349,368 @@ pub(crate) async fn list_executions_handler(
 
     match executions_result {
         Ok(executions) => {
-            let json_executions: Vec<serde_json::Value> = executions
-                .into_iter()
-                .map(|exec| {
-                    serde_json::json!({
-                        "id": exec.id.0,
-                        "agent_id": exec.agent_id.0,
-                        "status": format!("{:?}", exec.status),
-                        "started_at": exec.started_at,
-                        "ended_at": exec.ended_at,
-                        "tenant_id": exec.tenant_id.as_str(),
-                    })
-                })
-                .collect();
+            let json_executions: Vec<serde_json::Value> =
+                executions.iter().map(execution_summary_json).collect();
             Ok(axum::Json(serde_json::json!(json_executions)))
         }
         Err(e) => Ok(axum::Json(serde_json::json!({"error": e.to_string()}))),
     }
 }
 
+/// Parse a filter expression and resolve the agent names it mentions.
+async fn parse_execution_filter(
+    state: &AppState,
+    tenant_id: &TenantId,
+    filter: &str,
+) -> Result<ExecutionQuery, (StatusCode, axum::Json<serde_json::Value>)> {
+    let mut execution_query = ExecutionQuery::parse(filter, chrono::Utc::now()).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            axum::Json(serde_json::json!({"error": format!("Invalid filter: {e}")})),
+        )
+    })?;
+    for name in execution_query.unresolved_agent_names() {
+        match state
+            .agent_service
+            .lookup_agent_visible_for_tenant(tenant_id, &name)
+            .await
+        {
+            Ok(Some(id)) => execution_query.resolve_agent_name(&name, id),
+            Ok(None) => {
+                return Err((
+                    StatusCode::BAD_REQUEST,
+                    axum::Json(serde_json::json!({"error": format!("Agent '{}' not found", name)})),
+                ));
+            }
+            Err(e) => {
+                return Err((
+                    StatusCode::INTERNAL_SERVER_ERROR,
+                    axum::Json(serde_json::json!({"error": e.to_string()})),
+                ));
+            }
+        }
+    }
+    Ok(execution_query)
+}
+
+#[derive(serde::Deserialize)]
+pub(crate) struct BulkExecutionRequest {
+    /// Filter expression selecting the executions; required so that an
+    /// empty request can never match everything.
+    filter: String,
+    /// List the executions the operation would apply to without touching them.
+    #[serde(default)]
+    dry_run: bool,
+}
+
+/// POST /v1/executions/bulk/cancel
+pub(crate) async fn bulk_cancel_executions_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    axum::Json(request): axum::Json<BulkExecutionRequest>,
+) -> Result<
+    impl axum::response::IntoResponse,
+    (axum::http::StatusCode, axum::Json<serde_json::Value>),
+> {
+    scope_guard.require("execution:cancel")?;
+    bulk_execution_operation(&state, identity, BulkAction::Cancel, request).await
+}
+
+/// POST /v1/executions/bulk/requeue
+pub(crate) async fn bulk_requeue_executions_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    axum::Json(request): axum::Json<BulkExecutionRequest>,
+) -> Result<
+    impl axum::response::IntoResponse,
+    (axum::http::StatusCode, axum::Json<serde_json::Value>),
+> {
+    scope_guard.require("agent:execute")?;
+    bulk_execution_operation(&state, identity, BulkAction::Requeue, request).await
+}
+
+/// Dry runs answer `200` with the matching executions; real runs start the
+/// operation and answer `202` with its initial progress.
+async fn bulk_execution_operation(
+    state: &AppState,
+    identity: Option<Extension<UserIdentity>>,
+    action: BulkAction,
+    request: BulkExecutionRequest,
+) -> Result<(StatusCode, axum::Json<serde_json::Value>), (StatusCode, axum::Json<serde_json::Value>)>
+{
+    let identity = identity.map(|Extension(identity)| identity);
+    let tenant_id = tenant_id_from_identity(identity.as_ref());
+    let filter = request.filter.trim();
+    if filter.is_empty() {
+        return Err((
+            StatusCode::BAD_REQUEST,
+            axum::Json(serde_json::json!({"error": "filter is required"})),
+        ));
+    }
+    let execution_query = parse_execution_filter(state, &tenant_id, filter).await?;
+    let repository_error = |e: RepositoryError| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            axum::Json(serde_json::json!({"error": e.to_string()})),
+        )
+    };
+
+    if request.dry_run {
+        let executions = state
+            .bulk_execution_service
+            .matching(&tenant_id, action, &execution_query)
+            .await
+            .map_err(repository_error)?;
+        return Ok((
+            StatusCode::OK,
+            axum::Json(serde_json::json!({
+                "dry_run": true,
+                "action": action,
+                "total": executions.len(),
+                "executions": executions.iter().map(execution_summary_json).collect::<Vec<_>>(),
+            })),
+        ));
+    }
+
+    let operation = state
+        .bulk_execution_service
+        .start(
+            &tenant_id,
+            action,
+            filter.to_string(),
+            &execution_query,
+            identity,
+        )
+        .await
+        .map_err(repository_error)?;
+    Ok((
+        StatusCode::ACCEPTED,
+        axum::Json(serde_json::json!(operation)),
+    ))
+}
+
+/// GET /v1/executions/bulk/:operation_id
+pub(crate) async fn get_bulk_execution_operation_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(operation_id): Path<Uuid>,
+) -> Result<
+    impl axum::response::IntoResponse,
+    (axum::http::StatusCode, axum::Json<serde_json::Value>),
+> {
+    scope_guard.require("execution:read")?;
+    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|identity| &identity.0));
+    state
+        .bulk_execution_service
+        .get_for_tenant(&tenant_id, operation_id)
+        .map(axum::Json)
+        .ok_or((
+            StatusCode::NOT_FOUND,
+            axum::Json(serde_json::json!({"error": "Bulk operation not found"})),
+        ))
+}
+
+/// Row shape returned by `GET /v1/executions`.
+fn execution_summary_json(
+    exec: &aegis_orchestrator_core::domain::execution::Execution,
+) -> serde_json::Value {
+    serde_json::json!({
+        "id": exec.id.0,
+        "agent_id": exec.agent_id.0,
+        "status": format!("{:?}", exec.status),
+        "started_at": exec.started_at,
+        "ended_at": exec.ended_at,
+        "tenant_id": exec.tenant_id.as_str(),
+        "labels": exec.labels,
+    })
+}
+
+#[derive(serde::Deserialize)]
+pub(crate) struct UpdateExecutionRequest {
+    /// Merge patch: a string sets the label, `null` removes it.
+    labels: BTreeMap<String, Option<String>>,
+}
+
+/// PATCH /v1/executions/:execution_id
+///
+/// Add, change or remove labels on an execution. Returns the resulting
+/// label set.
+pub(crate) async fn update_execution_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(execution_id): Path<Uuid>,
+    axum::Json(request): axum::Json<UpdateExecutionRequest>,
+) -> Result<
+    impl axum::response::IntoResponse,
+    (axum::http::StatusCode, axum::Json<serde_json::Value>),
+> {
+    scope_guard.require("execution:label")?;
+    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|e| &e.0));
+    let execution_id = ExecutionId(execution_id);
+
+    let mut labels = match state
+        .execution_repo
+        .find_by_id_for_tenant(&tenant_id, execution_id)
+        .await
+    {
+        Ok(Some(execution)) => execution.labels,
+        Ok(None) => {
+            return Ok((
+                StatusCode::NOT_FOUND,
+                axum::Json(serde_json::json!({"error": "Execution not found"})),
+            ))
+        }
+        Err(e) => {
+            return Ok((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                axum::Json(serde_json::json!({"error": e.to_string()})),
+            ))
+        }
+    };
+    for (key, value) in request.labels {
+        match value {
+            Some(value) => labels.insert(key, value),
+            None => labels.remove(&key),
+        };
+    }
+    if let Err(e) = validate_labels(&labels) {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            axum::Json(serde_json::json!({"error": e.to_string()})),
+        ));
+    }
+
+    match state
+        .execution_repo
+        .update_labels_for_tenant(&tenant_id, execution_id, &labels)
+        .await
+    {
+        Ok(()) => Ok((
+            StatusCode::OK,
+            axum::Json(serde_json::json!({"id": execution_id.0, "labels": labels})),
+        )),
+        Err(RepositoryError::NotFound(_)) => Ok((
+            StatusCode::NOT_FOUND,
+            axum::Json(serde_json::json!({"error": "Execution not found"})),
+        )),
+        Err(e) => Ok((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            axum::Json(serde_json::json!({"error": e.to_string()})),
+        )),
+    }
+}
+
+#[derive(serde::Deserialize)]
+pub(crate) struct AddGuidanceRequest {
+    guidance: String,
+}
+
+/// POST /v1/executions/:execution_id/guidance
+///
+/// Queue operator guidance for a running execution. The text is appended to
+/// the prompt of the next iteration the supervisor starts and recorded as an
+/// `OperatorGuidanceAdded` event attributed to the caller.
+pub(crate) async fn add_execution_guidance_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(execution_id): Path<Uuid>,
+    axum::Json(request): axum::Json<AddGuidanceRequest>,
+) -> Result<
+    impl axum::response::IntoResponse,
+    (axum::http::StatusCode, axum::Json<serde_json::Value>),
+> {
+    scope_guard.require("execution:guide")?;
+    let identity_ref = identity.as_ref().map(|e| &e.0);
+    let tenant_id = tenant_id_from_identity(identity_ref);
+    let execution_id = ExecutionId(execution_id);
+
+    let guidance = request.guidance.trim().to_string();
+    if guidance.is_empty() || guidance.chars().count() > MAX_GUIDANCE_CHARS {
+        return Ok((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            axum::Json(serde_json::json!({
+                "error": format!("guidance must be 1-{MAX_GUIDANCE_CHARS} characters")
+            })),
+        ));
+    }
+
+    let execution = match state
+        .execution_repo
+        .find_by_id_for_tenant(&tenant_id, execution_id)
+        .await
+    {
+        Ok(Some(execution)) => execution,
+        Ok(None) => {
+            return Ok((
+                StatusCode::NOT_FOUND,
+                axum::Json(serde_json::json!({"error": "Execution not found"})),
+            ))
+        }
+        Err(e) => {
+            return Ok((
+                StatusCode::INTERNAL_SERVER_ERROR,
+                axum::Json(serde_json::json!({"error": e.to_string()})),
+            ))
+        }
+    };
+    if execution.status.is_terminal() {
+        let error = format!(
+            "Execution is {:?}; guidance only applies to running executions",
+            execution.status
+        );
+        return Ok((
+            StatusCode::CONFLICT,
+            axum::Json(serde_json::json!({ "error": error })),
+        ));
+    }
+    let next_iteration = execution.iterations().len() + 1;
+    if next_iteration > execution.max_iterations as usize {
+        return Ok((
+            StatusCode::CONFLICT,
+            axum::Json(serde_json::json!({
+                "error": "Execution has no iterations left to apply guidance to"
+            })),
+        ));
+    }
+
+    let submitted_by = identity_ref
+        .map(|identity| identity.sub.clone())
+        .unwrap_or_else(|| "anonymous".to_string());
+    let submitted_at = chrono::Utc::now();
+    state.guidance_queue.enqueue(
+        execution_id,
+        OperatorGuidance {
+            guidance: guidance.clone(),
+            submitted_by: submitted_by.clone(),
+            submitted_at,
+        },
+    );
+    state
+        .event_bus
+        .publish_execution_event(ExecutionEvent::OperatorGuidanceAdded {
+            execution_id,
+            agent_id: execution.agent_id,
+            iteration_number: next_iteration as u8,
+            guidance,
+            submitted_by: submitted_by.clone(),
+            timestamp: submitted_at,
+        });
+
+    Ok((
+        StatusCode::ACCEPTED,
+        axum::Json(serde_json::json!({
+            "execution_id": execution_id.0,
+            "iteration_number": next_iteration,
+            "submitted_by": submitted_by,
+        })),
+    ))
+}
+
 /// GET /v1/executions/:execution_id/files/*path
 ///
 /// Read a single file from a completed execution's workspace volume post-mortem.
@@ -284,12 +732,15 @@ pub(crate) async fn get_execution_file_handler(
 
     state
         .file_operations_service
-        .read_file_for_execution(ExecutionId(execution_id), &tenant_id, normalized)
+        .read_file_for_execution_stream(ExecutionId(execution_id), &tenant_id, normalized)
         .await
         .map(|content| {
             (
-                [(axum::http::header::CONTENT_TYPE, content.content_type)],
-                content.data,
+                [
+                    (axum::http::header::CONTENT_TYPE, content.content_type),
+                    (axum::http::header::CONTENT_LENGTH, content.size.to_string()),
+                ],
+                axum::body::Body::from_stream(content.chunks),
             )
                 .into_response()
         })
@@ -305,3 +756,185 @@ pub(crate) async fn get_execution_file_handler(
             (status, axum::Json(serde_json::json!({"error": message})))
         })
 }
+
+/// `GET /v1/executions/{execution_id}/attachments` — the execution's output
+/// attachments: files the agent wrote under `/workspace/outputs/`. Each
+/// `path` can be downloaded from `/v1/executions/{execution_id}/files/{path}`.
+pub(crate) async fn list_execution_attachments_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(execution_id): Path<Uuid>,
+) -> Result<impl IntoResponse, (StatusCode, axum::Json<serde_json::Value>)> {
+    scope_guard.require("execution:read")?;
+    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|identity| &identity.0));
+
+    let attachments = state
+        .file_operations_service
+        .list_execution_outputs(ExecutionId(execution_id), &tenant_id)
+        .await
+        .map_err(|e| {
+            let status = match &e {
+                FileOperationsError::NotFound(_) => StatusCode::NOT_FOUND,
+                FileOperationsError::Unauthorized => StatusCode::FORBIDDEN,
+                _ => StatusCode::INTERNAL_SERVER_ERROR,
+            };
+            (
+                status,
+                axum::Json(serde_json::json!({"error": e.to_string()})),
+            )
+        })?;
+    Ok(axum::Json(serde_json::json!({
+        "execution_id": execution_id,
+        "attachments": attachments,
+    })))
+}
+
+/// Files each iteration read, wrote, created or deleted, from the storage
+/// audit trail.
+pub(crate) async fn get_execution_file_activity_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(execution_id): Path<Uuid>,
+) -> Result<impl IntoResponse, (StatusCode, axum::Json<serde_json::Value>)> {
+    scope_guard.require("execution:read")?;
+    let identity_ref = identity.as_ref().map(|identity| &identity.0);
+    let tenant_id = tenant_id_from_identity(identity_ref);
+    let execution_id = ExecutionId(execution_id);
+
+    if !is_operator(identity_ref)
+        && state
+            .execution_service
+            .get_execution_for_tenant(&tenant_id, execution_id)
+            .await
+            .is_err()
+    {
+        return Err((
+            StatusCode::NOT_FOUND,
+            axum::Json(serde_json::json!({"error": "Execution not found"})),
+        ));
+    }
+
+    let iterations = file_activity_for_execution(state.storage_event_repo.as_ref(), execution_id)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                axum::Json(serde_json::json!({"error": e.to_string()})),
+            )
+        })?;
+    Ok(axum::Json(serde_json::json!({
+        "execution_id": execution_id.0,
+        "iterations": iterations,
+    })))
+}
+
+/// Network flows recorded for an execution that requested flow capture,
+/// with the summary shown in the execution report. Empty when capture was
+/// not requested.
+pub(crate) async fn get_execution_network_flows_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(execution_id): Path<Uuid>,
+) -> Result<impl IntoResponse, (StatusCode, axum::Json<serde_json::Value>)> {
+    scope_guard.require("execution:read")?;
+    let identity_ref = identity.as_ref().map(|identity| &identity.0);
+    let tenant_id = tenant_id_from_identity(identity_ref);
+    let execution_id = ExecutionId(execution_id);
+
+    if !is_operator(identity_ref)
+        && state
+            .execution_service
+            .get_execution_for_tenant(&tenant_id, execution_id)
+            .await
+            .is_err()
+    {
+        return Err((
+            StatusCode::NOT_FOUND,
+            axum::Json(serde_json::json!({"error": "Execution not found"})),
+        ));
+    }
+
+    let flows = state
+        .network_flow_repo
+        .find_by_execution(execution_id, None)
+        .await
+        .map_err(|e| {
+            (
+                StatusCode::INTERNAL_SERVER_ERROR,
+                axum::Json(serde_json::json!({"error": e.to_string()})),
+            )
+        })?;
+    Ok(axum::Json(serde_json::json!({
+        "execution_id": execution_id.0,
+        "summary": NetworkFlowSummary::summarize(&flows),
+        "flows": flows,
+    })))
+}
+
+#[derive(serde::Deserialize)]
+pub(crate) struct ExplainQuery {
+    /// Include the plain-text rendering as `text`.
+    #[serde(default)]
+    pub(crate) text: bool,
+}
+
+/// GET /v1/executions/:execution_id/explain
+///
+/// One chronological, annotated timeline of everything persisted about the
+/// execution: iterations, LLM and tool calls, validation, Cortex injections,
+/// operator guidance and storage violations.
+pub(crate) async fn explain_execution_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(execution_id): Path<Uuid>,
+    axum::extract::Query(query): axum::extract::Query<ExplainQuery>,
+) -> Result<impl IntoResponse, (StatusCode, axum::Json<serde_json::Value>)> {
+    scope_guard.require("execution:read")?;
+    let identity_ref = identity.as_ref().map(|identity| &identity.0);
+    let tenant_id = tenant_id_from_identity(identity_ref);
+    let execution_id = ExecutionId(execution_id);
+
+    let execution = if is_operator(identity_ref) {
+        state
+            .execution_repo
+            .find_by_id_unscoped(execution_id)
+            .await
+            .ok()
+            .flatten()
+    } else {
+        state
+            .execution_service
+            .get_execution_for_tenant(&tenant_id, execution_id)
+            .await
+            .ok()
+    };
+    let Some(execution) = execution else {
+        return Err((
+            StatusCode::NOT_FOUND,
+            axum::Json(serde_json::json!({"error": "Execution not found"})),
+        ));
+    };
+
+    let explanation = explain_execution(
+        &execution,
+        state.workflow_execution_repo.as_ref(),
+        state.storage_event_repo.as_ref(),
+    )
+    .await
+    .map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            axum::Json(serde_json::json!({"error": e.to_string()})),
+        )
+    })?;
+
+    let mut body = serde_json::to_value(&explanation).unwrap_or(serde_json::json!({}));
+    if query.text {
+        body["text"] = serde_json::Value::String(explanation.render_text());
+    }
+    Ok(axum::Json(body))
+}
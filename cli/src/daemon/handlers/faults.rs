@@ -0,0 +1,95 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Admin handlers: fault injection rules for resilience testing.
+//!
+//! Compiled only with the `fault-injection` feature. Every route is
+//! operator-restricted.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+use uuid::Uuid;
+
+use aegis_orchestrator_core::domain::iam::UserIdentity;
+use aegis_orchestrator_core::infrastructure::fault_injection::FaultRuleSpec;
+
+use crate::daemon::handlers::is_operator;
+use crate::daemon::state::AppState;
+
+fn operator_required_response() -> axum::response::Response {
+    (
+        StatusCode::FORBIDDEN,
+        Json(serde_json::json!({
+            "error": "operator_required",
+            "message": "Fault injection affects every tenant and is operator-restricted.",
+        })),
+    )
+        .into_response()
+}
+
+/// `GET /v1/admin/faults`
+pub(crate) async fn list_faults_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+) -> axum::response::Response {
+    if !is_operator(identity.as_ref().map(|e| &e.0)) {
+        return operator_required_response();
+    }
+    let rules = state.fault_injector.list();
+    let count = rules.len();
+    Json(serde_json::json!({ "rules": rules, "count": count })).into_response()
+}
+
+/// `POST /v1/admin/faults`
+pub(crate) async fn add_fault_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+    Json(spec): Json<FaultRuleSpec>,
+) -> axum::response::Response {
+    if !is_operator(identity.as_ref().map(|e| &e.0)) {
+        return operator_required_response();
+    }
+    match state.fault_injector.add(spec) {
+        Ok(rule) => (StatusCode::CREATED, Json(serde_json::json!(rule))).into_response(),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": e })),
+        )
+            .into_response(),
+    }
+}
+
+/// `DELETE /v1/admin/faults` — remove every rule.
+pub(crate) async fn clear_faults_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+) -> axum::response::Response {
+    if !is_operator(identity.as_ref().map(|e| &e.0)) {
+        return operator_required_response();
+    }
+    let removed = state.fault_injector.clear();
+    Json(serde_json::json!({ "status": "cleared", "removed": removed })).into_response()
+}
+
+/// `DELETE /v1/admin/faults/{id}`
+pub(crate) async fn remove_fault_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+    Path(id): Path<Uuid>,
+) -> axum::response::Response {
+    if !is_operator(identity.as_ref().map(|e| &e.0)) {
+        return operator_required_response();
+    }
+    if state.fault_injector.remove(id) {
+        Json(serde_json::json!({ "status": "deleted", "id": id.to_string() })).into_response()
+    } else {
+        (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "Fault rule not found" })),
+        )
+            .into_response()
+    }
+}
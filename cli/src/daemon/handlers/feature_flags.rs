@@ -0,0 +1,103 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Admin handlers: runtime feature flag overrides.
+//!
+//! Every route is operator-restricted; overrides apply to every tenant on
+//! this node until cleared or the daemon restarts.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::{Extension, Json};
+
+use aegis_orchestrator_core::application::feature_flags::FeatureFlagOverride;
+use aegis_orchestrator_core::domain::iam::UserIdentity;
+use aegis_orchestrator_core::domain::tenant::TenantId;
+
+use crate::daemon::handlers::is_operator;
+use crate::daemon::state::AppState;
+
+fn operator_required_response() -> axum::response::Response {
+    (
+        StatusCode::FORBIDDEN,
+        Json(serde_json::json!({
+            "error": "operator_required",
+            "message": "Feature flags affect every tenant and are operator-restricted.",
+        })),
+    )
+        .into_response()
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct FeatureFlagsQuery {
+    /// Also report each flag's value for this tenant.
+    tenant_id: Option<String>,
+}
+
+/// `GET /v1/admin/feature-flags`
+pub(crate) async fn list_feature_flags_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+    Query(query): Query<FeatureFlagsQuery>,
+) -> axum::response::Response {
+    if !is_operator(identity.as_ref().map(|e| &e.0)) {
+        return operator_required_response();
+    }
+    let tenant_id = match query.tenant_id.map(TenantId::new).transpose() {
+        Ok(tenant_id) => tenant_id,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "error": e.to_string() })),
+            )
+                .into_response()
+        }
+    };
+    let flags = state.feature_flags.states(tenant_id.as_ref());
+    let count = flags.len();
+    Json(serde_json::json!({ "flags": flags, "count": count })).into_response()
+}
+
+/// `PUT /v1/admin/feature-flags/{name}`
+pub(crate) async fn set_feature_flag_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+    Path(name): Path<String>,
+    Json(value): Json<FeatureFlagOverride>,
+) -> axum::response::Response {
+    let identity_ref = identity.as_ref().map(|e| &e.0);
+    if !is_operator(identity_ref) {
+        return operator_required_response();
+    }
+    let updated_by = identity_ref.map_or("unknown", |i| i.sub.as_str());
+    match state.feature_flags.set_override(&name, value, updated_by) {
+        Ok(stored) => Json(serde_json::json!({ "name": name, "override": stored })).into_response(),
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": e.to_string() })),
+        )
+            .into_response(),
+    }
+}
+
+/// `DELETE /v1/admin/feature-flags/{name}` — revert to the configured value.
+pub(crate) async fn clear_feature_flag_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+    Path(name): Path<String>,
+) -> axum::response::Response {
+    if !is_operator(identity.as_ref().map(|e| &e.0)) {
+        return operator_required_response();
+    }
+    if state.feature_flags.clear_override(&name) {
+        Json(serde_json::json!({ "status": "cleared", "name": name })).into_response()
+    } else {
+        (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "No override set for this flag" })),
+        )
+            .into_response()
+    }
+}
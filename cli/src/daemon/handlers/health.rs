@@ -37,11 +37,13 @@ pub(crate) async fn readiness_handler(
 
     Json(serde_json::json!({
         "status": if temporal_ready && database_ready { "ready" } else { "degraded" },
+        "version": env!("CARGO_PKG_VERSION"),
         "uptime_seconds": state.start_time.elapsed().as_secs(),
         "dependencies": {
             "database": database_ready,
             "temporal": temporal_ready,
             "cluster_repository": state.cluster_repo.is_some(),
-        }
+        },
+        "feature_flags": state.feature_flags.summary(),
     }))
 }
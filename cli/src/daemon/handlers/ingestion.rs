@@ -0,0 +1,72 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Slack Events API Receiver (BC-8)
+//!
+//! `POST /v1/webhooks/slack/events` — the Request URL of the Slack app
+//! configured in `spec.ingestion.slack`. Authenticated by Slack's signing
+//! secret rather than IAM; see
+//! [`aegis_orchestrator_core::infrastructure::ingestion::slack`].
+
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::IntoResponse;
+use axum::Json;
+use serde_json::json;
+use tracing::warn;
+
+use aegis_orchestrator_core::infrastructure::ingestion::slack::{
+    SlackEventOutcome, SLACK_SIGNATURE_HEADER, SLACK_TIMESTAMP_HEADER,
+};
+
+use crate::daemon::state::AppState;
+
+/// `POST /v1/webhooks/slack/events`
+pub(crate) async fn slack_events_handler(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> axum::response::Response {
+    let Some(adapter) = state.slack_ingestion.as_ref() else {
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(json!({ "error": "Slack ingestion is not configured" })),
+        )
+            .into_response();
+    };
+
+    let header = |name: &str| {
+        headers
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+    };
+    if let Err(e) = adapter.verify(
+        header(SLACK_TIMESTAMP_HEADER),
+        header(SLACK_SIGNATURE_HEADER),
+        &body,
+    ) {
+        warn!(error = %e, "Rejected Slack event");
+        return (
+            StatusCode::UNAUTHORIZED,
+            Json(json!({ "error": e.to_string() })),
+        )
+            .into_response();
+    }
+
+    match adapter.handle(&body) {
+        Ok(SlackEventOutcome::Challenge(challenge)) => {
+            Json(json!({ "challenge": challenge })).into_response()
+        }
+        Ok(SlackEventOutcome::Accepted | SlackEventOutcome::Ignored) => {
+            StatusCode::OK.into_response()
+        }
+        Err(e) => (
+            StatusCode::BAD_REQUEST,
+            Json(json!({ "error": format!("Invalid Slack event: {e}") })),
+        )
+            .into_response(),
+    }
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! API metadata handlers.
+
+use axum::Json;
+
+use aegis_orchestrator_core::infrastructure::event_schema::{event_schemas, EventSchema};
+use aegis_orchestrator_core::presentation::api_version::{
+    handshake, DeprecatedEndpoint, HandshakeRequest, HandshakeResponse, API_VERSION,
+    DEPRECATED_ENDPOINTS, SERVER_FEATURES, SUPPORTED_API_VERSIONS,
+};
+
+/// Manifest `apiVersion` accepted for agents, workflows and node config.
+const MANIFEST_API_VERSION: &str = "100monkeys.ai/v1";
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct VersionView {
+    pub(crate) api_version: &'static str,
+    pub(crate) supported_api_versions: &'static [&'static str],
+    pub(crate) server_version: &'static str,
+    pub(crate) manifest_api_version: &'static str,
+    /// Latest database migration bundled with this build.
+    pub(crate) schema_version: Option<i64>,
+    pub(crate) features: &'static [&'static str],
+    pub(crate) deprecations: &'static [DeprecatedEndpoint],
+}
+
+/// `GET /v1/meta/version` — contract versions for SDK compatibility checks.
+pub(crate) async fn version_handler() -> Json<VersionView> {
+    Json(VersionView {
+        api_version: API_VERSION,
+        supported_api_versions: SUPPORTED_API_VERSIONS,
+        server_version: env!("CARGO_PKG_VERSION"),
+        manifest_api_version: MANIFEST_API_VERSION,
+        schema_version: crate::daemon::migrations::MIGRATOR
+            .iter()
+            .map(|migration| migration.version)
+            .max(),
+        features: SERVER_FEATURES,
+        deprecations: DEPRECATED_ENDPOINTS,
+    })
+}
+
+/// `POST /v1/meta/handshake` — tell an SDK whether it can be served and
+/// which features it may use.
+pub(crate) async fn handshake_handler(
+    Json(request): Json<HandshakeRequest>,
+) -> Json<HandshakeResponse> {
+    let response = handshake(&request, env!("CARGO_PKG_VERSION"));
+    if let Some(reason) = &response.reason {
+        tracing::info!(
+            client_version = %request.client_version,
+            reason = %reason,
+            "Rejected incompatible SDK handshake"
+        );
+    }
+    Json(response)
+}
+
+#[derive(Debug, serde::Serialize)]
+pub(crate) struct EventSchemasView {
+    pub(crate) event_types: Vec<EventSchema>,
+    pub(crate) count: usize,
+}
+
+/// `GET /v1/schemas/events` — every event type carried in event envelopes,
+/// with its payload schema version and fields.
+pub(crate) async fn event_schemas_handler() -> Json<EventSchemasView> {
+    let event_types = event_schemas();
+    Json(EventSchemasView {
+        count: event_types.len(),
+        event_types,
+    })
+}
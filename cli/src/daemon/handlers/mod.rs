@@ -20,14 +20,24 @@ pub(crate) mod cortex;
 pub(crate) mod credentials;
 pub(crate) mod dispatch;
 pub(crate) mod executions;
+#[cfg(feature = "fault-injection")]
+pub(crate) mod faults;
+pub(crate) mod feature_flags;
 pub(crate) mod git_repo;
 pub(crate) mod health;
+pub(crate) mod ingestion;
+pub(crate) mod meta;
 pub(crate) mod observability;
+pub(crate) mod outbound_webhooks;
+pub(crate) mod plugins;
+pub(crate) mod quotas;
 pub(crate) mod script;
 pub(crate) mod seal;
+pub(crate) mod security_contexts;
 pub(crate) mod stimulus;
 pub(crate) mod swarms;
 pub(crate) mod tenant_provisioning;
+pub(crate) mod usage;
 pub(crate) mod volumes;
 pub(crate) mod workflow_executions;
 pub(crate) mod workflows;
@@ -0,0 +1,97 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Outbound webhook handlers: the per-destination delivery-attempt log and
+//! dead letters under `/v1/outbound-webhooks`.
+
+use std::sync::Arc;
+
+use axum::extract::{Extension, Query, State};
+use axum::http::StatusCode;
+use axum::Json;
+
+use aegis_orchestrator_core::domain::iam::UserIdentity;
+use aegis_orchestrator_core::presentation::keycloak_auth::ScopeGuard;
+
+use crate::daemon::handlers::tenant_id_from_identity;
+use crate::daemon::state::AppState;
+
+const DEFAULT_LIMIT: usize = 100;
+const MAX_LIMIT: usize = 1000;
+
+type HandlerError = (StatusCode, Json<serde_json::Value>);
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct DeliveryQueryParams {
+    /// Destination URL; all destinations when omitted.
+    destination: Option<String>,
+    limit: Option<usize>,
+}
+
+fn deliveries_unavailable() -> HandlerError {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(serde_json::json!({
+            "error": "deliveries_unavailable",
+            "message": "The outbound webhook delivery log requires a configured database.",
+        })),
+    )
+}
+
+fn internal(e: impl std::fmt::Display) -> HandlerError {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(serde_json::json!({ "error": e.to_string() })),
+    )
+}
+
+/// GET /v1/outbound-webhooks/deliveries — delivery attempts of the caller's
+/// tenant, newest first, optionally for one `destination` URL.
+pub(crate) async fn list_deliveries_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Query(params): Query<DeliveryQueryParams>,
+) -> Result<Json<serde_json::Value>, HandlerError> {
+    scope_guard.require("execution:list")?;
+    let repo = state
+        .webhook_delivery_repo
+        .as_ref()
+        .ok_or_else(deliveries_unavailable)?;
+
+    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|e| &e.0));
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let items = repo
+        .list_attempts(&tenant_id, params.destination.as_deref(), limit)
+        .await
+        .map_err(internal)?;
+    Ok(Json(serde_json::json!({
+        "destination": params.destination,
+        "items": items,
+    })))
+}
+
+/// GET /v1/outbound-webhooks/dead-letters — deliveries of the caller's tenant
+/// that failed every attempt, newest first, optionally for one `destination`.
+pub(crate) async fn list_dead_letters_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Query(params): Query<DeliveryQueryParams>,
+) -> Result<Json<serde_json::Value>, HandlerError> {
+    scope_guard.require("execution:list")?;
+    let repo = state
+        .webhook_delivery_repo
+        .as_ref()
+        .ok_or_else(deliveries_unavailable)?;
+
+    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|e| &e.0));
+    let limit = params.limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT);
+    let items = repo
+        .list_dead_letters(&tenant_id, params.destination.as_deref(), limit)
+        .await
+        .map_err(internal)?;
+    Ok(Json(serde_json::json!({
+        "destination": params.destination,
+        "items": items,
+    })))
+}
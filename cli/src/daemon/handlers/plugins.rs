@@ -0,0 +1,78 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # WASM Plugin Handlers
+//!
+//! | Endpoint | Scope | Notes |
+//! |---|---|---|
+//! | `POST /v1/plugins` | `workflow:deploy` | Install a component (raw body); returns its digest |
+//! | `POST /v1/plugins/{digest}/evaluate` | `workflow:run` | Run a `condition: plugin` transition for the Temporal worker |
+//!
+//! Both return `503` when `spec.plugins` is not configured.
+
+use std::sync::Arc;
+
+use axum::body::Bytes;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::Json;
+use serde_json::json;
+
+use aegis_orchestrator_core::domain::plugin::{PluginDigest, PluginError, PluginHost};
+use aegis_orchestrator_core::presentation::keycloak_auth::ScopeGuard;
+
+use crate::daemon::state::AppState;
+
+/// Upper bound on an uploaded component.
+pub(crate) const MAX_PLUGIN_BYTES: usize = 32 * 1024 * 1024;
+
+fn plugin_host(
+    state: &AppState,
+) -> Result<Arc<dyn PluginHost>, (StatusCode, Json<serde_json::Value>)> {
+    state.plugin_host.clone().ok_or((
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(json!({"error": "plugins are not configured (spec.plugins)"})),
+    ))
+}
+
+fn plugin_error_response(e: PluginError) -> (StatusCode, Json<serde_json::Value>) {
+    let status = match &e {
+        PluginError::InvalidDigest(_) | PluginError::InvalidComponent(_) => StatusCode::BAD_REQUEST,
+        PluginError::NotFound(_) => StatusCode::NOT_FOUND,
+        PluginError::FuelExhausted { .. } | PluginError::Failed { .. } => {
+            StatusCode::UNPROCESSABLE_ENTITY
+        }
+        PluginError::Storage(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (status, Json(json!({ "error": e.to_string() })))
+}
+
+/// `POST /v1/plugins` — install a WASM component.
+pub(crate) async fn upload_plugin_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    body: Bytes,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    scope_guard.require("workflow:deploy")?;
+    let host = plugin_host(&state)?;
+    let digest = host.install(&body).await.map_err(plugin_error_response)?;
+    Ok((StatusCode::CREATED, Json(json!({ "digest": digest }))))
+}
+
+/// `POST /v1/plugins/{digest}/evaluate` — decide a plugin transition. The body
+/// is the transition context the worker would give a `custom` expression.
+pub(crate) async fn evaluate_plugin_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    Path(digest): Path<String>,
+    Json(context): Json<serde_json::Value>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    scope_guard.require("workflow:run")?;
+    let host = plugin_host(&state)?;
+    let digest = PluginDigest::parse(&digest).map_err(plugin_error_response)?;
+    let result = host
+        .evaluate(&digest, &context)
+        .await
+        .map_err(plugin_error_response)?;
+    Ok(Json(json!({ "result": result })))
+}
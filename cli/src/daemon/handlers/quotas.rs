@@ -0,0 +1,160 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Tenant quota handlers: `/v1/quotas` (ADR-056).
+//!
+//! Tenants may read their own limits and usage; listing every override and
+//! changing limits is operator-restricted.
+
+use std::sync::Arc;
+
+use axum::extract::{Extension, Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+
+use aegis_orchestrator_core::application::tenant_quota::{QuotaError, TenantQuotaService};
+use aegis_orchestrator_core::domain::iam::UserIdentity;
+use aegis_orchestrator_core::domain::quota::QuotaLimits;
+use aegis_orchestrator_core::domain::tenant::TenantId;
+
+use crate::daemon::handlers::{is_operator, tenant_id_from_identity};
+use crate::daemon::state::AppState;
+
+type HandlerError = (StatusCode, Json<serde_json::Value>);
+
+fn quotas_unavailable() -> HandlerError {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(serde_json::json!({
+            "error": "quotas_unavailable",
+            "message": "Tenant quotas require a configured database.",
+        })),
+    )
+}
+
+fn operator_required() -> HandlerError {
+    (
+        StatusCode::FORBIDDEN,
+        Json(serde_json::json!({
+            "error": "operator_required",
+            "message": "Changing or listing tenant quotas is operator-restricted.",
+        })),
+    )
+}
+
+fn quota_error(e: QuotaError) -> HandlerError {
+    let status = match e {
+        QuotaError::TenantNotFound(_) => StatusCode::NOT_FOUND,
+        QuotaError::Exceeded { .. } => StatusCode::TOO_MANY_REQUESTS,
+        QuotaError::Repository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (status, Json(serde_json::json!({ "error": e.to_string() })))
+}
+
+fn parse_tenant(tenant_id: String) -> Result<TenantId, HandlerError> {
+    TenantId::new(tenant_id).map_err(|e| {
+        (
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": e.to_string() })),
+        )
+    })
+}
+
+fn quota_service(state: &AppState) -> Result<&Arc<TenantQuotaService>, HandlerError> {
+    state.quota_service.as_ref().ok_or_else(quotas_unavailable)
+}
+
+/// GET /v1/quotas — every stored per-tenant override. Operator only.
+pub(crate) async fn list_quotas_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+) -> Result<Json<serde_json::Value>, HandlerError> {
+    if !is_operator(identity.as_ref().map(|e| &e.0)) {
+        return Err(operator_required());
+    }
+    let definitions = quota_service(&state)?
+        .list_definitions()
+        .await
+        .map_err(quota_error)?;
+    let count = definitions.len();
+    Ok(Json(serde_json::json!({
+        "quotas": definitions,
+        "count": count,
+    })))
+}
+
+/// GET /v1/quotas/{tenant_id} — effective limits, current usage and stored
+/// overrides of one tenant. Tenants may read their own.
+pub(crate) async fn get_quota_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+    Path(tenant_id): Path<String>,
+) -> Result<Json<serde_json::Value>, HandlerError> {
+    let identity_ref = identity.as_ref().map(|e| &e.0);
+    let tenant_id = parse_tenant(tenant_id)?;
+    if !is_operator(identity_ref) && tenant_id != tenant_id_from_identity(identity_ref) {
+        return Err(operator_required());
+    }
+    let service = quota_service(&state)?;
+    let limits = service
+        .effective_quotas(&tenant_id)
+        .await
+        .map_err(quota_error)?;
+    let usage = service.usage(&tenant_id).await.map_err(quota_error)?;
+    let overrides = service.definition(&tenant_id).await.map_err(quota_error)?;
+    Ok(Json(serde_json::json!({
+        "tenant_id": tenant_id.as_str(),
+        "limits": limits,
+        "usage": usage,
+        "overrides": overrides,
+    })))
+}
+
+/// PUT /v1/quotas/{tenant_id} — replace the tenant's overrides. Omitted or
+/// `null` limits revert to the tier default. Operator only.
+pub(crate) async fn set_quota_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+    Path(tenant_id): Path<String>,
+    Json(limits): Json<QuotaLimits>,
+) -> Result<Json<serde_json::Value>, HandlerError> {
+    let identity_ref = identity.as_ref().map(|e| &e.0);
+    if !is_operator(identity_ref) {
+        return Err(operator_required());
+    }
+    let tenant_id = parse_tenant(tenant_id)?;
+    let updated_by = identity_ref.map_or("unknown", |i| i.sub.as_str());
+    let definition = quota_service(&state)?
+        .set_definition(&tenant_id, limits, updated_by)
+        .await
+        .map_err(quota_error)?;
+    Ok(Json(serde_json::json!({ "quota": definition })))
+}
+
+/// DELETE /v1/quotas/{tenant_id} — drop the tenant's overrides so its tier
+/// defaults apply again. Operator only.
+pub(crate) async fn delete_quota_handler(
+    State(state): State<Arc<AppState>>,
+    identity: Option<Extension<UserIdentity>>,
+    Path(tenant_id): Path<String>,
+) -> Result<Json<serde_json::Value>, HandlerError> {
+    let identity_ref = identity.as_ref().map(|e| &e.0);
+    if !is_operator(identity_ref) {
+        return Err(operator_required());
+    }
+    let tenant_id = parse_tenant(tenant_id)?;
+    let updated_by = identity_ref.map_or("unknown", |i| i.sub.as_str());
+    let cleared = quota_service(&state)?
+        .clear_definition(&tenant_id, updated_by)
+        .await
+        .map_err(quota_error)?;
+    if !cleared {
+        return Err((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({ "error": "No quota overrides set for this tenant" })),
+        ));
+    }
+    Ok(Json(serde_json::json!({
+        "status": "cleared",
+        "tenant_id": tenant_id.as_str(),
+    })))
+}
@@ -6,7 +6,8 @@ use std::sync::Arc;
 
 use axum::extract::{Query, State};
 use axum::http::{HeaderMap, StatusCode};
-use axum::response::IntoResponse;
+use axum::response::sse::{Event, Sse};
+use axum::response::{IntoResponse, Response};
 use axum::Json;
 
 use aegis_orchestrator_core::application::execution::ExecutionService;
@@ -15,6 +16,8 @@ use aegis_orchestrator_core::domain::iam::{
 };
 use aegis_orchestrator_core::domain::shared_kernel::ExecutionId;
 use aegis_orchestrator_core::domain::tenant::TenantId;
+use aegis_orchestrator_core::infrastructure::seal::envelope::SealEnvelope;
+use aegis_orchestrator_core::infrastructure::seal::stream::DEFAULT_STREAM_CHUNK_BYTES;
 
 use crate::daemon::handlers::api_keys::hash_key;
 use crate::daemon::state::AppState;
@@ -33,6 +36,8 @@ pub struct HttpAttestationRequest {
     pub zaru_tier: Option<String>,
     pub tenant_id: Option<String>,
     pub task_summary: Option<String>,
+    /// Base64 X25519 public key for payload encryption key negotiation.
+    pub payload_public_key: Option<String>,
 }
 
 #[derive(serde::Deserialize)]
@@ -49,6 +54,12 @@ pub(crate) struct SealToolsQuery {
     security_context: Option<String>,
 }
 
+#[derive(serde::Deserialize, Default)]
+pub(crate) struct SealStreamQuery {
+    /// Maximum chunk size per response frame, in bytes.
+    chunk_bytes: Option<usize>,
+}
+
 /// Errors that may arise while resolving the canonical attestation tenant.
 ///
 /// The handler maps these onto HTTP status codes; the variants are exposed for
@@ -533,6 +544,7 @@ pub(crate) async fn attest_seal_handler(
             tenant_id,
             realm,
             task_summary: request.task_summary.clone(),
+            payload_public_key: request.payload_public_key.clone(),
         };
 
     let tenant_for_log = internal_req.tenant_id.as_str().to_string();
@@ -550,6 +562,8 @@ pub(crate) async fn attest_seal_handler(
                     "security_token": res.security_token,
                     "expires_at": res.expires_at,
                     "session_id": res.session_id,
+                    "payload_public_key": res.payload_public_key,
+                    "response_signing_key": state.tool_invocation_service.response_signing_key(),
                 })),
             )
                 .into_response()
@@ -572,29 +586,36 @@ pub(crate) async fn attest_seal_handler(
     }
 }
 
-pub(crate) async fn invoke_seal_handler(
-    State(state): State<Arc<AppState>>,
-    Json(request): Json<HttpSealEnvelope>,
-) -> impl IntoResponse {
+fn seal_envelope_from_http(request: HttpSealEnvelope) -> Result<SealEnvelope, Response> {
     let (protocol, timestamp) = match (request.protocol, request.timestamp) {
         (Some(p), Some(t)) => (p, t),
         _ => {
-            return (
+            return Err((
                 StatusCode::BAD_REQUEST,
                 Json(serde_json::json!({
                     "error": "SEAL envelope requires both 'protocol' and 'timestamp' fields"
                 })),
             )
-                .into_response();
+                .into_response());
         }
     };
 
-    let envelope = aegis_orchestrator_core::infrastructure::seal::envelope::SealEnvelope {
+    Ok(SealEnvelope {
         protocol,
         security_token: request.security_token,
         signature: request.signature,
         payload: request.payload,
         timestamp,
+    })
+}
+
+pub(crate) async fn invoke_seal_handler(
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<HttpSealEnvelope>,
+) -> impl IntoResponse {
+    let envelope = match seal_envelope_from_http(request) {
+        Ok(envelope) => envelope,
+        Err(response) => return response,
     };
 
     // The ToolInvocationService is responsible for validating the security_token
@@ -611,6 +632,52 @@ pub(crate) async fn invoke_seal_handler(
     }
 }
 
+/// Same as [`invoke_seal_handler`], but returns the tool result as a
+/// server-sent event stream of signed `frame` events (`seal-stream/v1`).
+/// Agents verify each frame against the `response_signing_key` returned at
+/// attestation and reassemble the result once the final frame arrives.
+pub(crate) async fn invoke_seal_stream_handler(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<SealStreamQuery>,
+    Json(request): Json<HttpSealEnvelope>,
+) -> impl IntoResponse {
+    let envelope = match seal_envelope_from_http(request) {
+        Ok(envelope) => envelope,
+        Err(response) => return response,
+    };
+    let chunk_bytes = query
+        .chunk_bytes
+        .filter(|bytes| *bytes > 0)
+        .unwrap_or(DEFAULT_STREAM_CHUNK_BYTES);
+
+    let frames = match state
+        .tool_invocation_service
+        .invoke_tool_streaming(&envelope, chunk_bytes)
+        .await
+    {
+        Ok(frames) => frames,
+        Err(e) => {
+            return (
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({
+                    "error": e.to_string()
+                })),
+            )
+                .into_response();
+        }
+    };
+
+    let stream = async_stream::stream! {
+        for frame in frames {
+            let data = serde_json::to_string(&frame).unwrap_or_default();
+            yield Ok::<_, anyhow::Error>(Event::default().event("frame").data(data));
+        }
+    };
+    Sse::new(stream)
+        .keep_alive(axum::response::sse::KeepAlive::default())
+        .into_response()
+}
+
 pub(crate) async fn list_seal_tools_handler(
     State(state): State<Arc<AppState>>,
     Query(query): Query<SealToolsQuery>,
@@ -640,6 +707,7 @@ pub(crate) async fn list_seal_tools_handler(
                 "protocol": "seal/v1",
                 "attestation_endpoint": "/v1/seal/attest",
                 "invoke_endpoint": "/v1/seal/invoke",
+                "stream_endpoint": "/v1/seal/invoke/stream",
                 "security_context": security_context,
                 "tools": tools,
             })),
@@ -684,6 +752,7 @@ mod attest_tenant_resolution_tests {
             zaru_tier: None,
             tenant_id: None,
             task_summary: None,
+            payload_public_key: None,
         }
     }
 
@@ -0,0 +1,60 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Security context handlers: dry-run policy evaluation.
+
+use std::sync::Arc;
+
+use axum::extract::{Extension, Path, State};
+use axum::http::StatusCode;
+use axum::Json;
+use serde_json::json;
+
+use aegis_orchestrator_core::application::policy_simulation::{
+    PolicySimulationError, SimulatedToolCall,
+};
+use aegis_orchestrator_core::domain::iam::UserIdentity;
+use aegis_orchestrator_core::domain::security_context::validate_context_ownership;
+use aegis_orchestrator_core::presentation::keycloak_auth::ScopeGuard;
+
+use crate::daemon::handlers::{is_operator, tenant_id_from_identity};
+use crate::daemon::state::AppState;
+
+/// POST /v1/security-contexts/:name/simulate
+///
+/// Evaluates a hypothetical tool call against the named security context and
+/// the caller's rate limits. Nothing is executed and no rate-limit counter
+/// is incremented.
+pub(crate) async fn simulate_security_context_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(name): Path<String>,
+    Json(call): Json<SimulatedToolCall>,
+) -> Result<Json<serde_json::Value>, (StatusCode, Json<serde_json::Value>)> {
+    scope_guard.require("agent:read")?;
+    let identity_ref = identity.as_ref().map(|e| &e.0);
+    let tenant_id = tenant_id_from_identity(identity_ref);
+
+    if !is_operator(identity_ref) {
+        if let Some(identity) = identity_ref {
+            validate_context_ownership(&name, &tenant_id, &identity.realm_kind())
+                .map_err(|e| (StatusCode::FORBIDDEN, Json(json!({ "error": e }))))?;
+        }
+    }
+
+    let simulation = state
+        .policy_simulation_service
+        .simulate(&tenant_id, identity_ref, &name, &call)
+        .await
+        .map_err(|e| {
+            let status = match e {
+                PolicySimulationError::NotFound(_) => StatusCode::NOT_FOUND,
+                PolicySimulationError::Repository(_) | PolicySimulationError::RateLimit(_) => {
+                    StatusCode::INTERNAL_SERVER_ERROR
+                }
+            };
+            (status, Json(json!({ "error": e.to_string() })))
+        })?;
+
+    Ok(Json(serde_json::to_value(simulation).unwrap_or(json!({}))))
+}
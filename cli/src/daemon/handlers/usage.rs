@@ -0,0 +1,213 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Token usage handlers: `/v1/usage` rollups, `/v1/usage/daily` summaries and
+//! the `/v1/reports/usage` monthly report.
+
+use std::sync::Arc;
+
+use axum::extract::{Extension, Query, State};
+use axum::http::{header, StatusCode};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use uuid::Uuid;
+
+use aegis_orchestrator_core::domain::agent::AgentId;
+use aegis_orchestrator_core::domain::execution::ExecutionId;
+use aegis_orchestrator_core::domain::iam::UserIdentity;
+use aegis_orchestrator_core::domain::token_usage::{UsageGroupBy, UsageQuery};
+use aegis_orchestrator_core::domain::usage_report::{month_of, parse_month, to_csv};
+use aegis_orchestrator_core::presentation::keycloak_auth::ScopeGuard;
+
+use crate::daemon::handlers::{is_operator, tenant_id_from_identity};
+use crate::daemon::state::AppState;
+
+/// Default lookback when `from` is omitted.
+const DEFAULT_USAGE_WINDOW_DAYS: i64 = 30;
+
+type HandlerError = (StatusCode, Json<serde_json::Value>);
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct UsageQueryParams {
+    #[serde(default)]
+    group_by: UsageGroupBy,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+    agent_id: Option<Uuid>,
+    execution_id: Option<Uuid>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct DailyUsageParams {
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct UsageReportParams {
+    /// `YYYY-MM`; defaults to the current month.
+    month: Option<String>,
+    /// `json` (default) or `csv`.
+    format: Option<String>,
+    /// Every tenant instead of the caller's. Operator only.
+    #[serde(default)]
+    all_tenants: bool,
+}
+
+fn usage_unavailable() -> HandlerError {
+    (
+        StatusCode::SERVICE_UNAVAILABLE,
+        Json(serde_json::json!({
+            "error": "usage_unavailable",
+            "message": "Token usage accounting requires a configured database.",
+        })),
+    )
+}
+
+fn internal(e: impl std::fmt::Display) -> HandlerError {
+    (
+        StatusCode::INTERNAL_SERVER_ERROR,
+        Json(serde_json::json!({ "error": e.to_string() })),
+    )
+}
+
+fn bad_request(message: &str) -> HandlerError {
+    (
+        StatusCode::BAD_REQUEST,
+        Json(serde_json::json!({ "error": message })),
+    )
+}
+
+/// GET /v1/usage — token usage of the caller's tenant rolled up by
+/// `group_by` (`execution`, `agent`, `day` or `tenant`) over `[from, to)`.
+pub(crate) async fn usage_rollup_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Query(params): Query<UsageQueryParams>,
+) -> Result<Json<serde_json::Value>, HandlerError> {
+    scope_guard.require("execution:list")?;
+    let repo = state
+        .token_usage_repo
+        .as_ref()
+        .ok_or_else(usage_unavailable)?;
+
+    let to = params.to.unwrap_or_else(Utc::now);
+    let from = params
+        .from
+        .unwrap_or(to - Duration::days(DEFAULT_USAGE_WINDOW_DAYS));
+    if from >= to {
+        return Err(bad_request("'from' must be earlier than 'to'"));
+    }
+    let query = UsageQuery {
+        tenant_id: tenant_id_from_identity(identity.as_ref().map(|e| &e.0)),
+        group_by: params.group_by,
+        from,
+        to,
+        agent_id: params.agent_id.map(AgentId),
+        execution_id: params.execution_id.map(ExecutionId),
+    };
+    let items = repo.rollup(&query).await.map_err(internal)?;
+    Ok(Json(serde_json::json!({
+        "group_by": query.group_by,
+        "from": from,
+        "to": to,
+        "items": items,
+    })))
+}
+
+/// GET /v1/usage/daily — nightly per-agent/model summary rows of the caller's
+/// tenant for `from <= day <= to`. The current day appears after it ends.
+pub(crate) async fn usage_daily_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Query(params): Query<DailyUsageParams>,
+) -> Result<Json<serde_json::Value>, HandlerError> {
+    scope_guard.require("execution:list")?;
+    let repo = state
+        .token_usage_repo
+        .as_ref()
+        .ok_or_else(usage_unavailable)?;
+
+    let to = params.to.unwrap_or_else(|| Utc::now().date_naive());
+    let from = params
+        .from
+        .unwrap_or(to - Duration::days(DEFAULT_USAGE_WINDOW_DAYS));
+    if from > to {
+        return Err(bad_request("'from' must not be later than 'to'"));
+    }
+    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|e| &e.0));
+    let items = repo
+        .daily_summary(&tenant_id, from, to)
+        .await
+        .map_err(internal)?;
+    Ok(Json(serde_json::json!({
+        "from": from,
+        "to": to,
+        "items": items,
+    })))
+}
+
+/// GET /v1/reports/usage — monthly executions, tool calls, tokens and
+/// storage of the caller's tenant (or, for operators with `all_tenants`,
+/// every tenant) as JSON or CSV.
+pub(crate) async fn usage_report_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Query(params): Query<UsageReportParams>,
+) -> Result<Response, HandlerError> {
+    scope_guard.require("execution:list")?;
+    let repo = state
+        .usage_report_repo
+        .as_ref()
+        .ok_or_else(usage_unavailable)?;
+
+    let month = match params.month.as_deref() {
+        Some(month) => parse_month(month).ok_or_else(|| bad_request("'month' must be YYYY-MM"))?,
+        None => month_of(Utc::now().date_naive()),
+    };
+    let identity = identity.as_ref().map(|e| &e.0);
+    let tenant_id = if params.all_tenants {
+        if !is_operator(identity) {
+            return Err((
+                StatusCode::FORBIDDEN,
+                Json(serde_json::json!({
+                    "error": "operator_required",
+                    "message": "Reports across all tenants are operator-restricted.",
+                })),
+            ));
+        }
+        None
+    } else {
+        Some(tenant_id_from_identity(identity))
+    };
+    let rows = repo
+        .monthly_summary(month, tenant_id.as_ref())
+        .await
+        .map_err(internal)?;
+
+    match params.format.as_deref().unwrap_or("json") {
+        "json" => Ok(Json(serde_json::json!({
+            "month": month.format("%Y-%m").to_string(),
+            "items": rows,
+        }))
+        .into_response()),
+        "csv" => {
+            let disposition = format!(
+                "attachment; filename=\"usage-{}.csv\"",
+                month.format("%Y-%m")
+            );
+            Ok((
+                [
+                    (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                    (header::CONTENT_DISPOSITION, disposition),
+                ],
+                to_csv(&rows),
+            )
+                .into_response())
+        }
+        _ => Err(bad_request("'format' must be json or csv")),
+    }
+}
@@ -12,9 +12,12 @@ use futures::StreamExt;
 use sha2::Digest;
 use uuid::Uuid;
 
-use aegis_orchestrator_core::application::file_operations_service::FileOperationsError;
+use aegis_orchestrator_core::application::file_operations_service::{
+    FileOperationsError, MAX_BROWSE_FILE_BYTES,
+};
 use aegis_orchestrator_core::application::user_volume_service::UserVolumeError;
 use aegis_orchestrator_core::application::volume_manager::CreateUserVolumeCommand;
+use aegis_orchestrator_core::application::volume_search_service::{GrepRequest, VolumeSearchError};
 use aegis_orchestrator_core::domain::iam::{IdentityKind, UserIdentity, ZaruTier};
 use aegis_orchestrator_core::domain::volume::VolumeId;
 use aegis_orchestrator_core::presentation::keycloak_auth::ScopeGuard;
@@ -42,6 +45,35 @@ pub(crate) struct FilePathQuery {
     pub path: String,
 }
 
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct VolumeSearchQuery {
+    pub name: String,
+    #[serde(default = "default_search_limit")]
+    pub limit: usize,
+    /// Rebuild the tenant's filename index before searching.
+    #[serde(default)]
+    pub refresh: bool,
+}
+
+fn default_search_limit() -> usize {
+    100
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub(crate) struct VolumeGrepRequest {
+    pub pattern: String,
+    #[serde(default)]
+    pub regex: bool,
+    #[serde(default)]
+    pub ignore_case: bool,
+    #[serde(default)]
+    pub volume_id: Option<Uuid>,
+    #[serde(default)]
+    pub path_prefix: Option<String>,
+    #[serde(default)]
+    pub max_matches: Option<usize>,
+}
+
 #[derive(Debug, serde::Deserialize)]
 pub(crate) struct MovePathRequest {
     pub from: String,
@@ -77,6 +109,16 @@ fn file_ops_error_response(e: FileOperationsError) -> (StatusCode, Json<serde_js
     (status, Json(serde_json::json!({"error": message})))
 }
 
+fn volume_search_error_response(e: VolumeSearchError) -> (StatusCode, Json<serde_json::Value>) {
+    let status = match &e {
+        VolumeSearchError::InvalidPattern(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        VolumeSearchError::VolumeNotFound(_) => StatusCode::NOT_FOUND,
+        VolumeSearchError::Unauthorized => StatusCode::FORBIDDEN,
+        VolumeSearchError::Repository(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    (status, Json(serde_json::json!({"error": e.to_string()})))
+}
+
 fn user_tier(identity: Option<&UserIdentity>) -> ZaruTier {
     match identity.map(|i| &i.identity_kind) {
         Some(IdentityKind::ConsumerUser { zaru_tier, .. }) => zaru_tier.clone(),
@@ -277,6 +319,9 @@ pub(crate) async fn delete_volume(
 }
 
 /// GET /v1/volumes/:id/files  (list directory)
+///
+/// Read-only browse of the caller's own volumes and of execution/workflow
+/// workspaces in their tenant. Listings are audit-logged by the FSAL.
 pub(crate) async fn list_files(
     State(state): State<Arc<AppState>>,
     scope_guard: ScopeGuard,
@@ -292,7 +337,7 @@ pub(crate) async fn list_files(
 
     state
         .file_operations_service
-        .list_directory(&vol_id, &tenant_id, &owner, &params.path)
+        .browse_directory(&vol_id, &tenant_id, &owner, &params.path)
         .await
         .map(|entries| Json(serde_json::to_value(entries).unwrap_or(serde_json::json!([]))))
         .map_err(file_ops_error_response)
@@ -327,6 +372,100 @@ pub(crate) async fn stat_file(
         .map_err(file_ops_error_response)
 }
 
+/// GET /v1/volumes/:id/files/content
+///
+/// Read-only file view for the volume browser. Works on the caller's own
+/// volumes and on execution/workflow workspaces in their tenant; files over
+/// `MAX_BROWSE_FILE_BYTES` are rejected (use `/files/download` instead).
+pub(crate) async fn get_file_content(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(id): Path<Uuid>,
+    Query(params): Query<FilePathQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    scope_guard.require("volume:read")?;
+    let identity_ref = identity.as_ref().map(|e| &e.0);
+    let tenant_id = tenant_id_from_identity(identity_ref);
+    let owner = user_sub(identity_ref);
+    let vol_id = VolumeId(id);
+
+    let content = state
+        .file_operations_service
+        .browse_file(
+            &vol_id,
+            &tenant_id,
+            &owner,
+            &params.path,
+            MAX_BROWSE_FILE_BYTES,
+        )
+        .await
+        .map_err(file_ops_error_response)?;
+
+    Ok(([(header::CONTENT_TYPE, content.content_type)], content.data).into_response())
+}
+
+/// GET /v1/volumes/search?name=...
+///
+/// Filename search across every volume the caller can browse, served from a
+/// per-tenant index (`refresh=true` rebuilds it first).
+pub(crate) async fn search_files(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Query(params): Query<VolumeSearchQuery>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    scope_guard.require("volume:read")?;
+    let identity_ref = identity.as_ref().map(|e| &e.0);
+    let tenant_id = tenant_id_from_identity(identity_ref);
+    let owner = user_sub(identity_ref);
+
+    state
+        .volume_search_service
+        .search_names(
+            &tenant_id,
+            &owner,
+            &params.name,
+            params.limit,
+            params.refresh,
+        )
+        .await
+        .map(|result| Json(serde_json::to_value(result).unwrap_or(serde_json::json!({}))))
+        .map_err(volume_search_error_response)
+}
+
+/// POST /v1/volumes/grep
+///
+/// Bounded content search over the caller's browsable volumes. Size, match
+/// and time limits are enforced server-side; a partial result comes back
+/// with `truncated: true` and the limit that stopped it.
+pub(crate) async fn grep_files(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Json(body): Json<VolumeGrepRequest>,
+) -> Result<impl IntoResponse, (StatusCode, Json<serde_json::Value>)> {
+    scope_guard.require("volume:read")?;
+    let identity_ref = identity.as_ref().map(|e| &e.0);
+    let tenant_id = tenant_id_from_identity(identity_ref);
+    let owner = user_sub(identity_ref);
+
+    let request = GrepRequest {
+        pattern: body.pattern,
+        regex: body.regex,
+        case_insensitive: body.ignore_case,
+        volume_id: body.volume_id.map(VolumeId),
+        path_prefix: body.path_prefix,
+        max_matches: body.max_matches,
+    };
+    state
+        .volume_search_service
+        .grep(&tenant_id, &owner, &request)
+        .await
+        .map(|result| Json(serde_json::to_value(result).unwrap_or(serde_json::json!({}))))
+        .map_err(volume_search_error_response)
+}
+
 /// GET /v1/volumes/:id/files/download
 pub(crate) async fn download_file(
     State(state): State<Arc<AppState>>,
@@ -343,13 +482,16 @@ pub(crate) async fn download_file(
 
     let content = state
         .file_operations_service
-        .read_file(&vol_id, &tenant_id, &owner, &params.path)
+        .read_file_stream(&vol_id, &tenant_id, &owner, &params.path)
         .await
         .map_err(file_ops_error_response)?;
 
     Ok((
-        [(axum::http::header::CONTENT_TYPE, content.content_type)],
-        content.data,
+        [
+            (header::CONTENT_TYPE, content.content_type),
+            (header::CONTENT_LENGTH, content.size.to_string()),
+        ],
+        axum::body::Body::from_stream(content.chunks),
     )
         .into_response())
 }
@@ -506,7 +648,7 @@ fn decode_x_filename(raw: &str) -> String {
 /// Resolve the per-file upload cap for a tier from the canonical
 /// `StorageTierLimits` table. An unknown tier returns `None` so the caller
 /// fails closed rather than admitting the upload under an unbounded cap.
-fn resolve_max_file_size_bytes(tier: &ZaruTier) -> Option<u64> {
+pub(crate) fn resolve_max_file_size_bytes(tier: &ZaruTier) -> Option<u64> {
     aegis_orchestrator_core::domain::volume::StorageTierLimits::default()
         .limits
         .get(tier)
@@ -549,7 +691,7 @@ fn chat_attachments_volume_size_for_tier(tier: &ZaruTier) -> Option<u64> {
 /// rejected because its running byte count exceeded the per-tier cap. We emit
 /// this from the streaming path the *moment* the cap is crossed — the
 /// half-buffered prefix is immediately dropped.
-fn payload_too_large_response(cap: u64) -> (StatusCode, Json<serde_json::Value>) {
+pub(crate) fn payload_too_large_response(cap: u64) -> (StatusCode, Json<serde_json::Value>) {
     (
         StatusCode::PAYLOAD_TOO_LARGE,
         Json(serde_json::json!({
@@ -597,7 +739,7 @@ pub(crate) async fn read_body_capped(
 /// and may be omitted entirely, so the streaming size check below is the
 /// authoritative gate. The fast-fail path saves us from accepting any body
 /// frames when the caller has already declared they will exceed the cap.
-fn content_length_exceeds_cap(headers: &axum::http::HeaderMap, cap: u64) -> bool {
+pub(crate) fn content_length_exceeds_cap(headers: &axum::http::HeaderMap, cap: u64) -> bool {
     headers
         .get(axum::http::header::CONTENT_LENGTH)
         .and_then(|v| v.to_str().ok())
@@ -17,7 +17,11 @@ use aegis_orchestrator_core::domain::events::WorkflowEvent;
 use aegis_orchestrator_core::domain::execution::ExecutionId;
 use aegis_orchestrator_core::domain::iam::UserIdentity;
 use aegis_orchestrator_core::domain::node_config::{resolve_env_value, NodeConfigManifest};
+use aegis_orchestrator_core::domain::repository::RepositoryError;
 use aegis_orchestrator_core::domain::tenant::TenantId;
+use aegis_orchestrator_core::infrastructure::temporal_client::{
+    validate_handler_name, TemporalClient, WorkflowSignal, HUMAN_INPUT_SIGNAL, WORKFLOW_STATE_QUERY,
+};
 use aegis_orchestrator_core::infrastructure::temporal_proto::temporal::api::common::v1::WorkflowExecution as TemporalWorkflowExecution;
 use aegis_orchestrator_core::infrastructure::temporal_proto::temporal::api::workflowservice::v1::{
     DeleteWorkflowExecutionRequest, RequestCancelWorkflowExecutionRequest,
@@ -93,6 +97,15 @@ pub(crate) fn workflow_event_message(event: &WorkflowEvent) -> String {
         WorkflowEvent::WorkflowStateExited { state_name, .. } => {
             format!("Exited workflow state {state_name}")
         }
+        WorkflowEvent::WorkflowStateStuck {
+            state_name,
+            expected_seconds,
+            action,
+            ..
+        } => format!(
+            "Workflow state {state_name} exceeded {expected_seconds}s (action: {})",
+            action.as_str()
+        ),
         WorkflowEvent::WorkflowIterationStarted {
             iteration_number, ..
         } => format!("Workflow iteration {iteration_number} started"),
@@ -165,6 +178,7 @@ pub(crate) fn workflow_event_type_name(event: &WorkflowEvent) -> &'static str {
         WorkflowEvent::WorkflowExecutionStarted { .. } => "WorkflowExecutionStarted",
         WorkflowEvent::WorkflowStateEntered { .. } => "WorkflowStateEntered",
         WorkflowEvent::WorkflowStateExited { .. } => "WorkflowStateExited",
+        WorkflowEvent::WorkflowStateStuck { .. } => "WorkflowStateStuck",
         WorkflowEvent::WorkflowIterationStarted { .. } => "WorkflowIterationStarted",
         WorkflowEvent::WorkflowIterationCompleted { .. } => "WorkflowIterationCompleted",
         WorkflowEvent::WorkflowIterationFailed { .. } => "WorkflowIterationFailed",
@@ -238,6 +252,18 @@ pub(crate) fn workflow_event_view_from_domain(
             exited_at.to_rfc3339(),
             serde_json::to_value(event).unwrap_or(serde_json::Value::Null),
         ),
+        WorkflowEvent::WorkflowStateStuck {
+            execution_id,
+            state_name,
+            detected_at,
+            ..
+        } => (
+            execution_id.0,
+            Some(state_name.clone()),
+            None,
+            detected_at.to_rfc3339(),
+            serde_json::to_value(event).unwrap_or(serde_json::Value::Null),
+        ),
         WorkflowEvent::WorkflowIterationStarted {
             execution_id,
             iteration_number,
@@ -491,6 +517,10 @@ pub(crate) async fn workflow_execution_temporal_linkage(
 }
 
 /// GET /v1/workflows/executions - List workflow executions (paginated, newest first)
+///
+/// `?stuck=true` lists only running executions that have overstayed their
+/// current state (see `WorkflowWatchdog`), with `state_entered_at`,
+/// `stuck_for_seconds` and `expected_seconds` added to each entry.
 pub(crate) async fn list_workflow_executions_handler(
     State(state): State<Arc<AppState>>,
     scope_guard: ScopeGuard,
@@ -512,41 +542,76 @@ pub(crate) async fn list_workflow_executions_handler(
         .get("workflow_id")
         .and_then(|value| Uuid::parse_str(value).ok())
         .map(aegis_orchestrator_core::domain::workflow::WorkflowId);
+    let stuck_only = params.get("stuck").is_some_and(|value| value == "true");
 
     // Operator cross-tenant aggregation (ADR-097): when no workflow_id
     // filter is supplied, return executions across every tenant — each
     // carries its own `tenant_id` in the projection.
-    let repo_result = if is_operator(identity_ref) && workflow_id.is_none() {
+    let repo_result = if stuck_only {
+        let now = chrono::Utc::now();
+        let scope = (!is_operator(identity_ref)).then_some(&tenant_id);
         state
-            .workflow_execution_repo
-            .list_paginated_all(limit, offset)
-            .await
-    } else if let Some(workflow_id) = workflow_id {
-        state
-            .workflow_execution_repo
-            .find_by_workflow_for_tenant(&tenant_id, workflow_id, limit, offset)
+            .workflow_watchdog
+            .find_stuck(scope, now)
             .await
+            .map(|stuck| {
+                stuck
+                    .into_iter()
+                    .filter(|s| {
+                        workflow_id.is_none() || workflow_id == Some(s.execution.workflow_id)
+                    })
+                    .skip(offset)
+                    .take(limit)
+                    .map(|s| {
+                        let details = serde_json::json!({
+                            "current_state": s.state_name,
+                            "state_entered_at": s.entered_at,
+                            "stuck_for_seconds": s.stuck_for(now).as_secs(),
+                            "expected_seconds": s.expected.as_secs(),
+                        });
+                        (s.execution, Some(details))
+                    })
+                    .collect::<Vec<_>>()
+            })
     } else {
-        state
-            .workflow_execution_repo
-            .list_paginated_for_tenant(&tenant_id, limit, offset)
-            .await
+        let executions = if is_operator(identity_ref) && workflow_id.is_none() {
+            state
+                .workflow_execution_repo
+                .list_paginated_all(limit, offset)
+                .await
+        } else if let Some(workflow_id) = workflow_id {
+            state
+                .workflow_execution_repo
+                .find_by_workflow_for_tenant(&tenant_id, workflow_id, limit, offset)
+                .await
+        } else {
+            state
+                .workflow_execution_repo
+                .list_paginated_for_tenant(&tenant_id, limit, offset)
+                .await
+        };
+        executions.map(|executions| {
+            executions
+                .into_iter()
+                .map(|e| (e, None))
+                .collect::<Vec<_>>()
+        })
     };
 
     match repo_result {
         Ok(executions) => {
             let workflow_ids: Vec<_> = executions
                 .iter()
-                .map(|execution| execution.workflow_id)
+                .map(|(execution, _)| execution.workflow_id)
                 .collect();
             let workflow_name_map =
                 workflow_name_map_for_ids(state.workflow_repo.clone(), &tenant_id, &workflow_ids)
                     .await;
             let list: Vec<serde_json::Value> = executions
                 .iter()
-                .map(|e| {
+                .map(|(e, stuck)| {
                     let workflow_name = workflow_name_map.get(&e.workflow_id.0).cloned();
-                    serde_json::json!({
+                    let mut view = serde_json::json!({
                         "execution_id": e.id.0,
                         "workflow_id": e.workflow_id.0,
                         "workflow_name": workflow_name,
@@ -555,7 +620,13 @@ pub(crate) async fn list_workflow_executions_handler(
                         "started_at": e.started_at,
                         "last_transition_at": e.last_transition_at,
                         "tenant_id": e.tenant_id.as_str(),
-                    })
+                    });
+                    if let (Some(view), Some(serde_json::Value::Object(stuck))) =
+                        (view.as_object_mut(), stuck)
+                    {
+                        view.extend(stuck.clone());
+                    }
+                    view
                 })
                 .collect();
             Ok((StatusCode::OK, Json(list)).into_response())
@@ -621,6 +692,24 @@ pub(crate) async fn get_workflow_execution_handler(
             }
         };
 
+    // Served from our own repository so the transition audit trail survives
+    // Temporal history retention and Temporal outages.
+    let transitions = match state
+        .workflow_execution_repo
+        .find_transitions_for_tenant(&tenant_id, execution.id)
+        .await
+    {
+        Ok(transitions) => transitions,
+        Err(error) => {
+            tracing::warn!(
+                "Failed to load transition history for workflow execution {}: {}",
+                execution_id,
+                error
+            );
+            Vec::new()
+        }
+    };
+
     Ok((
         StatusCode::OK,
         Json(serde_json::json!({
@@ -633,6 +722,7 @@ pub(crate) async fn get_workflow_execution_handler(
             "last_transition_at": execution.last_transition_at,
             "blackboard": execution.blackboard.to_json(),
             "state_outputs": execution.state_outputs,
+            "transitions": transitions,
             "temporal_workflow_id": temporal_linkage.as_ref().map(|linkage| linkage.temporal_workflow_id.clone()),
             "temporal_run_id": temporal_linkage.as_ref().map(|linkage| linkage.temporal_run_id.clone()),
         })),
@@ -640,12 +730,255 @@ pub(crate) async fn get_workflow_execution_handler(
         .into_response())
 }
 
+/// GET /v1/workflows/executions/:execution_id/states/:index/blackboard - Blackboard
+/// snapshot taken when the execution entered its `index`-th state (0-based, in
+/// transition history order)
+pub(crate) async fn get_workflow_transition_blackboard_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path((execution_id, index)): Path<(Uuid, usize)>,
+) -> Result<impl IntoResponse, (axum::http::StatusCode, axum::Json<serde_json::Value>)> {
+    scope_guard.require("workflow:read")?;
+    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|identity| &identity.0));
+    let internal_error = |e: RepositoryError| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+    };
+
+    let transitions = state
+        .workflow_execution_repo
+        .find_transitions_for_tenant(&tenant_id, ExecutionId(execution_id))
+        .await
+        .map_err(internal_error)?;
+    let Some(transition) = transitions.get(index) else {
+        return Ok((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({
+                "error": "transition not found",
+                "transitions": transitions.len(),
+            })),
+        )
+            .into_response());
+    };
+    let Some(blackboard) = state
+        .workflow_execution_repo
+        .find_transition_blackboard_for_tenant(
+            &tenant_id,
+            ExecutionId(execution_id),
+            transition.sequence,
+        )
+        .await
+        .map_err(internal_error)?
+    else {
+        return Ok((
+            StatusCode::NOT_FOUND,
+            Json(
+                serde_json::json!({"error": "no blackboard snapshot recorded for this transition"}),
+            ),
+        )
+            .into_response());
+    };
+
+    Ok((
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "execution_id": execution_id,
+            "index": index,
+            "sequence": transition.sequence,
+            "state_name": transition.state_name,
+            "entered_at": transition.entered_at,
+            "blackboard": blackboard,
+        })),
+    )
+        .into_response())
+}
+
+/// GET /v1/workflows/executions/:execution_id/handoffs - Typed handoffs the
+/// execution's states have produced so far
+pub(crate) async fn get_workflow_handoffs_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(execution_id): Path<Uuid>,
+) -> Result<impl IntoResponse, (axum::http::StatusCode, axum::Json<serde_json::Value>)> {
+    scope_guard.require("workflow:read")?;
+    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|identity| &identity.0));
+    let internal_error = |e: RepositoryError| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": e.to_string()})),
+        )
+    };
+
+    let execution = state
+        .workflow_execution_repo
+        .find_by_id_for_tenant(&tenant_id, ExecutionId(execution_id))
+        .await
+        .map_err(internal_error)?;
+    if execution.is_none() {
+        return Ok((
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "workflow execution not found"})),
+        )
+            .into_response());
+    }
+    let handoffs = state
+        .workflow_execution_repo
+        .find_handoffs_for_tenant(&tenant_id, ExecutionId(execution_id))
+        .await
+        .map_err(internal_error)?;
+
+    Ok((
+        StatusCode::OK,
+        Json(serde_json::json!({
+            "execution_id": execution_id,
+            "handoffs": handoffs,
+        })),
+    )
+        .into_response())
+}
+
 #[derive(serde::Deserialize)]
 pub(crate) struct WorkflowSignalRequest {
-    response: String,
+    /// Legacy shorthand for `{"signal": "humanInput", "payload": "<text>"}`.
+    #[serde(default)]
+    response: Option<String>,
+    /// Signal name registered by the workflow worker. Defaults to `humanInput`.
+    #[serde(default)]
+    signal: Option<String>,
+    #[serde(default)]
+    payload: Option<serde_json::Value>,
+}
+
+impl WorkflowSignalRequest {
+    fn into_signal(self) -> anyhow::Result<WorkflowSignal> {
+        match (self.signal, self.payload, self.response) {
+            (None, None, Some(response)) => Ok(WorkflowSignal::HumanInput(response)),
+            (None, None, None) => {
+                anyhow::bail!("request must include either 'response' or 'signal'")
+            }
+            (name, payload, response) => {
+                let name = name.unwrap_or_else(|| HUMAN_INPUT_SIGNAL.to_string());
+                let payload = payload
+                    .or(response.map(serde_json::Value::String))
+                    .unwrap_or(serde_json::Value::Null);
+                WorkflowSignal::from_parts(&name, payload)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Deserialize, Default)]
+pub(crate) struct WorkflowQueryParams {
+    /// Query handler name. Defaults to `workflowState`.
+    #[serde(default)]
+    name: Option<String>,
+}
+
+/// Resolve `execution_id` to a workflow execution owned by the caller's tenant.
+///
+/// Audit 002 §4.7: returns 404 on mismatch — never 403 — to avoid leaking the
+/// existence of another tenant's execution.
+async fn authorize_workflow_execution(
+    state: &AppState,
+    identity: Option<&UserIdentity>,
+    execution_id: &str,
+) -> Result<Uuid, axum::response::Response> {
+    let not_found = || {
+        (
+            StatusCode::NOT_FOUND,
+            Json(serde_json::json!({"error": "workflow execution not found"})),
+        )
+            .into_response()
+    };
+    let tenant_id = tenant_id_from_identity(identity);
+    let exec_uuid = Uuid::parse_str(execution_id).map_err(|_| not_found())?;
+    match state
+        .workflow_execution_repo
+        .find_by_id_for_tenant(&tenant_id, ExecutionId(exec_uuid))
+        .await
+    {
+        Ok(Some(_)) => Ok(exec_uuid),
+        Ok(None) => Err(not_found()),
+        Err(error) => Err((
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({"error": error.to_string()})),
+        )
+            .into_response()),
+    }
+}
+
+/// Reject blackboard updates that break the workflow's declared
+/// `spec.variables` before they reach the worker.
+async fn check_blackboard_update(
+    state: &AppState,
+    tenant_id: &TenantId,
+    execution_id: ExecutionId,
+    updates: &serde_json::Map<String, serde_json::Value>,
+) -> Result<(), axum::response::Response> {
+    let internal = |error: String| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Json(serde_json::json!({ "error": error })),
+        )
+            .into_response()
+    };
+    let Some(execution) = state
+        .workflow_execution_repo
+        .find_by_id_for_tenant(tenant_id, execution_id)
+        .await
+        .map_err(|error| internal(error.to_string()))?
+    else {
+        return Ok(());
+    };
+    let Some(workflow) = state
+        .workflow_repo
+        .find_by_id_for_tenant(tenant_id, execution.workflow_id)
+        .await
+        .map_err(|error| internal(error.to_string()))?
+    else {
+        return Ok(());
+    };
+
+    for (key, value) in updates {
+        workflow
+            .spec
+            .check_blackboard_write(key, value)
+            .map_err(|error| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    Json(serde_json::json!({ "error": error.to_string() })),
+                )
+                    .into_response()
+            })?;
+    }
+    Ok(())
+}
+
+async fn connected_temporal_client(
+    state: &AppState,
+) -> Result<Arc<TemporalClient>, axum::response::Response> {
+    let guard = state.temporal_client_container.read().await;
+    guard.as_ref().cloned().ok_or_else(|| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(serde_json::json!({
+                "error": "Temporal client not yet connected"
+            })),
+        )
+            .into_response()
+    })
 }
 
 /// POST /v1/workflows/executions/:execution_id/signal
+///
+/// Body is either `{"response": "<text>"}` (human input) or
+/// `{"signal": "<name>", "payload": <json>}`. `blackboardUpdate` merges the
+/// payload object into the workflow blackboard; other names are forwarded to
+/// the worker unchanged.
 pub(crate) async fn signal_workflow_execution_handler(
     State(state): State<Arc<AppState>>,
     scope_guard: ScopeGuard,
@@ -656,65 +989,47 @@ pub(crate) async fn signal_workflow_execution_handler(
     scope_guard.require("workflow:signal")?;
     // Audit 002 §4.7: tenant-scoped ownership check before forwarding the
     // signal. Cross-tenant signal injection (any caller with workflow:signal
-    // and a known execution UUID) was previously possible. Return 404 on
-    // mismatch — never 403 — to avoid leaking the existence of another
-    // tenant's execution.
-    let tenant_id = tenant_id_from_identity(identity.as_ref().map(|identity| &identity.0));
-    let exec_uuid = match Uuid::parse_str(&execution_id) {
-        Ok(uuid) => uuid,
-        Err(_) => {
-            return Ok((
-                StatusCode::NOT_FOUND,
-                Json(serde_json::json!({"error": "workflow execution not found"})),
-            )
-                .into_response());
-        }
-    };
-    match state
-        .workflow_execution_repo
-        .find_by_id_for_tenant(&tenant_id, ExecutionId(exec_uuid))
-        .await
+    // and a known execution UUID) was previously possible.
+    let exec_uuid = match authorize_workflow_execution(
+        &state,
+        identity.as_ref().map(|identity| &identity.0),
+        &execution_id,
+    )
+    .await
     {
-        Ok(Some(_)) => {}
-        Ok(None) => {
+        Ok(exec_uuid) => exec_uuid,
+        Err(response) => return Ok(response),
+    };
+    let signal = match request.into_signal() {
+        Ok(signal) => signal,
+        Err(e) => {
             return Ok((
-                StatusCode::NOT_FOUND,
-                Json(serde_json::json!({"error": "workflow execution not found"})),
+                StatusCode::BAD_REQUEST,
+                Json(serde_json::json!({ "error": e.to_string() })),
             )
                 .into_response());
         }
-        Err(error) => {
-            return Ok((
-                StatusCode::INTERNAL_SERVER_ERROR,
-                Json(serde_json::json!({"error": error.to_string()})),
-            )
-                .into_response());
+    };
+    if let WorkflowSignal::BlackboardUpdate(updates) = &signal {
+        let tenant_id = tenant_id_from_identity(identity.as_ref().map(|identity| &identity.0));
+        if let Err(response) =
+            check_blackboard_update(&state, &tenant_id, ExecutionId(exec_uuid), updates).await
+        {
+            return Ok(response);
         }
     }
-    let guard = state.temporal_client_container.read().await;
-    let client = match guard.as_ref() {
-        Some(c) => c.clone(),
-        None => {
-            return Ok((
-                StatusCode::SERVICE_UNAVAILABLE,
-                Json(serde_json::json!({
-                    "error": "Temporal client not yet connected"
-                })),
-            )
-                .into_response());
-        }
+    let client = match connected_temporal_client(&state).await {
+        Ok(client) => client,
+        Err(response) => return Ok(response),
     };
-    drop(guard);
 
-    match client
-        .send_human_signal(&execution_id, request.response)
-        .await
-    {
+    match client.signal_workflow(&execution_id, &signal).await {
         Ok(()) => Ok((
             StatusCode::ACCEPTED,
             Json(serde_json::json!({
                 "status": "signal_sent",
-                "execution_id": execution_id
+                "execution_id": execution_id,
+                "signal": signal.name(),
             })),
         )
             .into_response()),
@@ -726,6 +1041,63 @@ pub(crate) async fn signal_workflow_execution_handler(
     }
 }
 
+/// GET /v1/workflows/executions/:execution_id/query - Run a worker query handler
+///
+/// Defaults to `workflowState`, which returns the live `current_state`,
+/// `blackboard`, and `state_outputs` held by the running workflow.
+pub(crate) async fn query_workflow_execution_handler(
+    State(state): State<Arc<AppState>>,
+    scope_guard: ScopeGuard,
+    identity: Option<Extension<UserIdentity>>,
+    Path(execution_id): Path<String>,
+    Query(params): Query<WorkflowQueryParams>,
+) -> Result<impl IntoResponse, (axum::http::StatusCode, axum::Json<serde_json::Value>)> {
+    scope_guard.require("workflow:read")?;
+    if let Err(response) = authorize_workflow_execution(
+        &state,
+        identity.as_ref().map(|identity| &identity.0),
+        &execution_id,
+    )
+    .await
+    {
+        return Ok(response);
+    }
+    let query_type = params
+        .name
+        .unwrap_or_else(|| WORKFLOW_STATE_QUERY.to_string());
+    if let Err(e) = validate_handler_name(&query_type) {
+        return Ok((
+            StatusCode::BAD_REQUEST,
+            Json(serde_json::json!({ "error": e.to_string() })),
+        )
+            .into_response());
+    }
+    let client = match connected_temporal_client(&state).await {
+        Ok(client) => client,
+        Err(response) => return Ok(response),
+    };
+
+    match client
+        .query_workflow(&execution_id, &query_type, None)
+        .await
+    {
+        Ok(result) => Ok((
+            StatusCode::OK,
+            Json(serde_json::json!({
+                "execution_id": execution_id,
+                "query": query_type,
+                "result": result,
+            })),
+        )
+            .into_response()),
+        Err(e) => Ok((
+            StatusCode::BAD_GATEWAY,
+            Json(serde_json::json!({ "error": e.to_string() })),
+        )
+            .into_response()),
+    }
+}
+
 /// GET /v1/workflows/executions/:execution_id/logs - List workflow log events
 pub(crate) async fn get_workflow_logs_handler(
     State(state): State<Arc<AppState>>,
@@ -1118,3 +1490,40 @@ pub(crate) async fn remove_workflow_execution_handler(
             .into_response()),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aegis_orchestrator_core::infrastructure::temporal_client::BLACKBOARD_UPDATE_SIGNAL;
+
+    fn parse(body: serde_json::Value) -> anyhow::Result<WorkflowSignal> {
+        serde_json::from_value::<WorkflowSignalRequest>(body)
+            .unwrap()
+            .into_signal()
+    }
+
+    #[test]
+    fn legacy_response_body_maps_to_human_input() {
+        let signal = parse(serde_json::json!({ "response": "approved" })).unwrap();
+        assert_eq!(signal, WorkflowSignal::HumanInput("approved".to_string()));
+    }
+
+    #[test]
+    fn named_signal_body_maps_to_blackboard_update() {
+        let signal = parse(serde_json::json!({
+            "signal": BLACKBOARD_UPDATE_SIGNAL,
+            "payload": { "dataset_ready": true }
+        }))
+        .unwrap();
+        assert_eq!(signal.name(), BLACKBOARD_UPDATE_SIGNAL);
+        assert_eq!(
+            signal.payload(),
+            serde_json::json!({ "dataset_ready": true })
+        );
+    }
+
+    #[test]
+    fn empty_signal_body_is_rejected() {
+        assert!(parse(serde_json::json!({})).is_err());
+    }
+}
@@ -12,6 +12,7 @@ use chrono::Utc;
 use tracing::warn;
 use uuid::Uuid;
 
+use aegis_orchestrator_core::application::concurrency_group::ConcurrencyError;
 use aegis_orchestrator_core::application::register_workflow::RegisterWorkflowUseCase;
 use aegis_orchestrator_core::application::start_workflow_execution::{
     StartWorkflowExecutionRequest, StartWorkflowExecutionUseCase,
@@ -94,7 +95,9 @@ pub(crate) async fn execute_temporal_workflow_handler(
         Ok(res) => Ok((StatusCode::OK, Json(res)).into_response()),
         Err(e) => {
             let error_str = e.to_string();
-            let status = if error_str.contains("InvalidExecutionInput") {
+            let status = if e.downcast_ref::<ConcurrencyError>().is_some() {
+                StatusCode::CONFLICT
+            } else if error_str.contains("InvalidExecutionInput") {
                 StatusCode::UNPROCESSABLE_ENTITY
             } else {
                 StatusCode::BAD_REQUEST
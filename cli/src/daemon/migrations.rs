@@ -0,0 +1,391 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Schema migration planning, the pre-migration backup hook and the startup
+//! migration policy.
+//!
+//! The daemon consults `spec.database.migrations.mode` (or `--migrate`) at
+//! startup:
+//!
+//! - `apply` runs the configured `pg_dump` backup, then applies pending
+//!   migrations.
+//! - `check` refuses to start while migrations are pending or the applied
+//!   history disagrees with the binary, without touching the schema.
+//! - `skip` starts without looking.
+//!
+//! `aegis daemon migrate --plan` prints the same [`MigrationPlan`] without
+//! applying anything.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Interface / Presentation Layer
+//! - **Purpose:** Implements internal responsibilities for migrations
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+use tracing::info;
+
+use aegis_orchestrator_core::domain::node_config::{
+    DatabaseConfig, MigrateMode, MigrationBackupConfig,
+};
+
+/// Migrations embedded in this binary.
+///
+/// ADR-117 SEV-2 #2.1: the orchestrator binary lives in the `cli` crate;
+/// `sqlx::migrate!` resolves its argument relative to the crate root, so the
+/// canonical location for migrations is `cli/migrations/` (NOT
+/// `orchestrator/core/migrations/`).
+pub(crate) static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
+
+/// A migration known to the binary but not yet applied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PendingMigration {
+    pub version: i64,
+    pub description: String,
+}
+
+/// Applied and pending migrations of a database, compared with this binary.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MigrationPlan {
+    /// Highest applied version, if any.
+    pub current_version: Option<i64>,
+    pub applied: usize,
+    pub pending: Vec<PendingMigration>,
+    /// Applied versions whose checksum differs from the binary's copy.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub checksum_mismatches: Vec<i64>,
+    /// Applied versions that did not complete (`success = false`).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub dirty: Vec<i64>,
+    /// Applied versions this binary does not know, e.g. after a downgrade.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub unknown: Vec<i64>,
+}
+
+impl MigrationPlan {
+    pub fn is_up_to_date(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Whether `sqlx` would refuse to run this plan.
+    pub fn has_conflicts(&self) -> bool {
+        !self.checksum_mismatches.is_empty() || !self.dirty.is_empty() || !self.unknown.is_empty()
+    }
+}
+
+struct AppliedMigration {
+    checksum: Vec<u8>,
+    success: bool,
+}
+
+/// Compare the database's `_sqlx_migrations` history with the binary.
+pub async fn plan(pool: &PgPool) -> Result<MigrationPlan> {
+    let history_exists: bool =
+        sqlx::query_scalar("SELECT to_regclass('_sqlx_migrations') IS NOT NULL")
+            .fetch_one(pool)
+            .await
+            .context("Failed to inspect migration history")?;
+    let applied: HashMap<i64, AppliedMigration> = if history_exists {
+        sqlx::query("SELECT version, checksum, success FROM _sqlx_migrations")
+            .fetch_all(pool)
+            .await
+            .context("Failed to read _sqlx_migrations")?
+            .iter()
+            .map(|row| {
+                (
+                    row.get("version"),
+                    AppliedMigration {
+                        checksum: row.get("checksum"),
+                        success: row.get("success"),
+                    },
+                )
+            })
+            .collect()
+    } else {
+        HashMap::new()
+    };
+    Ok(compare(&applied))
+}
+
+fn compare(applied: &HashMap<i64, AppliedMigration>) -> MigrationPlan {
+    let mut plan = MigrationPlan {
+        current_version: applied.keys().copied().max(),
+        applied: applied.len(),
+        ..MigrationPlan::default()
+    };
+    let known = MIGRATOR
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration());
+    for migration in known {
+        match applied.get(&migration.version) {
+            None => plan.pending.push(PendingMigration {
+                version: migration.version,
+                description: migration.description.to_string(),
+            }),
+            Some(row) => {
+                if !row.success {
+                    plan.dirty.push(migration.version);
+                } else if row.checksum != migration.checksum.as_ref() {
+                    plan.checksum_mismatches.push(migration.version);
+                }
+            }
+        }
+    }
+    plan.unknown = applied
+        .keys()
+        .copied()
+        .filter(|version| MIGRATOR.iter().all(|m| m.version != *version))
+        .collect();
+    plan.pending.sort_by_key(|m| m.version);
+    plan.dirty.sort_unstable();
+    plan.checksum_mismatches.sort_unstable();
+    plan.unknown.sort_unstable();
+    plan
+}
+
+/// Apply the startup policy `mode` to `pool`.
+pub async fn migrate_on_startup(
+    pool: &PgPool,
+    database_url: &str,
+    db_config: &DatabaseConfig,
+    mode: MigrateMode,
+) -> Result<()> {
+    if MIGRATOR.iter().next().is_none() {
+        anyhow::bail!("CRITICAL: No migrations found in binary! Check build process.");
+    }
+    if mode == MigrateMode::Skip {
+        info!("Skipping database migration check (migrate mode: skip)");
+        return Ok(());
+    }
+
+    let plan = plan(pool).await?;
+    info!(
+        applied = plan.applied,
+        pending = plan.pending.len(),
+        current_version = ?plan.current_version,
+        "Database migration status"
+    );
+    if plan.has_conflicts() {
+        anyhow::bail!(
+            "Migration history conflicts with this binary (checksum mismatches: {:?}, \
+             incomplete: {:?}, unknown: {:?}). Run `aegis daemon migrate --plan` for details.",
+            plan.checksum_mismatches,
+            plan.dirty,
+            plan.unknown
+        );
+    }
+    if plan.is_up_to_date() {
+        info!("Database is up to date");
+        return Ok(());
+    }
+
+    match mode {
+        MigrateMode::Check => anyhow::bail!(
+            "{} pending migration(s) (next: {}) and migrate mode is 'check'. \
+             Apply them with `aegis daemon migrate` or start with --migrate=apply.",
+            plan.pending.len(),
+            plan.pending[0].version
+        ),
+        MigrateMode::Apply => apply(pool, database_url, db_config, &plan)
+            .await
+            .map(|_| ()),
+        MigrateMode::Skip => Ok(()),
+    }
+}
+
+/// Run the backup hook, if configured, then apply every pending migration.
+/// Returns the path of the dump that was written.
+pub async fn apply(
+    pool: &PgPool,
+    database_url: &str,
+    db_config: &DatabaseConfig,
+    plan: &MigrationPlan,
+) -> Result<Option<PathBuf>> {
+    let backup = match &db_config.migrations.backup {
+        Some(backup) if !plan.is_up_to_date() => {
+            Some(run_backup(backup, database_url, plan.current_version).await?)
+        }
+        _ => None,
+    };
+    info!(
+        pending = plan.pending.len(),
+        "Applying pending migrations..."
+    );
+    MIGRATOR
+        .run(pool)
+        .await
+        .context("Failed to apply migrations")?;
+    info!("Database migrations applied successfully");
+    Ok(backup)
+}
+
+/// Dump the database with `pg_dump` before it is migrated.
+pub async fn run_backup(
+    backup: &MigrationBackupConfig,
+    database_url: &str,
+    current_version: Option<i64>,
+) -> Result<PathBuf> {
+    std::fs::create_dir_all(&backup.output_dir).with_context(|| {
+        format!(
+            "Failed to create backup directory {}",
+            backup.output_dir.display()
+        )
+    })?;
+    let file = backup
+        .output_dir
+        .join(backup_file_name(chrono::Utc::now(), current_version));
+
+    // Keep the password out of the process list; libpq reads PGPASSWORD.
+    let (dbname, password) = split_password(database_url);
+    let mut command = tokio::process::Command::new(&backup.pg_dump_path);
+    command
+        .arg("--format=custom")
+        .arg("--file")
+        .arg(&file)
+        .args(&backup.extra_args)
+        .arg("--dbname")
+        .arg(dbname)
+        .kill_on_drop(true);
+    if let Some(password) = password {
+        command.env("PGPASSWORD", password);
+    }
+
+    info!(file = %file.display(), "Running pre-migration backup");
+    let output = command
+        .output()
+        .await
+        .with_context(|| format!("Failed to run {}", backup.pg_dump_path))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Pre-migration backup failed ({}): {}",
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    info!(file = %file.display(), "Pre-migration backup written");
+    Ok(file)
+}
+
+fn backup_file_name(at: chrono::DateTime<chrono::Utc>, current_version: Option<i64>) -> String {
+    format!(
+        "aegis-{}-pre-{}.dump",
+        at.format("%Y%m%dT%H%M%SZ"),
+        current_version.map_or_else(|| "empty".to_string(), |v| format!("v{v:03}"))
+    )
+}
+
+/// Split the password out of a `postgres://` URL. Other connection strings
+/// are passed through unchanged.
+fn split_password(database_url: &str) -> (String, Option<String>) {
+    match reqwest::Url::parse(database_url) {
+        Ok(mut url) => {
+            let password = url.password().map(percent_decode);
+            if password.is_some() && url.set_password(None).is_ok() {
+                return (url.to_string(), password);
+            }
+            (database_url.to_string(), None)
+        }
+        Err(_) => (database_url.to_string(), None),
+    }
+}
+
+fn percent_decode(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let escaped = (bytes[i] == b'%' && i + 2 < bytes.len())
+            .then(|| std::str::from_utf8(&bytes[i + 1..i + 3]).ok())
+            .flatten()
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                out.push(byte);
+                i += 3;
+            }
+            None => {
+                out.push(bytes[i]);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn fresh_database_has_every_migration_pending() {
+        let plan = compare(&HashMap::new());
+        assert_eq!(plan.current_version, None);
+        assert_eq!(plan.pending.len(), MIGRATOR.iter().count());
+        assert!(!plan.has_conflicts());
+    }
+
+    #[test]
+    fn plan_reports_pending_mismatched_and_unknown_versions() {
+        let mut applied: HashMap<i64, AppliedMigration> = MIGRATOR
+            .iter()
+            .map(|m| {
+                (
+                    m.version,
+                    AppliedMigration {
+                        checksum: m.checksum.to_vec(),
+                        success: true,
+                    },
+                )
+            })
+            .collect();
+        let latest = MIGRATOR.iter().map(|m| m.version).max().unwrap();
+        let first = MIGRATOR.iter().map(|m| m.version).min().unwrap();
+        applied.remove(&latest);
+        applied.get_mut(&first).unwrap().checksum = vec![0];
+        applied.insert(
+            9_999,
+            AppliedMigration {
+                checksum: Vec::new(),
+                success: true,
+            },
+        );
+
+        let plan = compare(&applied);
+        assert_eq!(
+            plan.pending.iter().map(|m| m.version).collect::<Vec<_>>(),
+            vec![latest]
+        );
+        assert_eq!(plan.checksum_mismatches, vec![first]);
+        assert_eq!(plan.unknown, vec![9_999]);
+        assert!(plan.has_conflicts());
+    }
+
+    #[test]
+    fn backup_file_is_named_after_time_and_version() {
+        let at = chrono::Utc.with_ymd_and_hms(2026, 3, 4, 5, 6, 7).unwrap();
+        assert_eq!(
+            backup_file_name(at, Some(37)),
+            "aegis-20260304T050607Z-pre-v037.dump"
+        );
+        assert_eq!(
+            backup_file_name(at, None),
+            "aegis-20260304T050607Z-pre-empty.dump"
+        );
+    }
+
+    #[test]
+    fn password_is_moved_out_of_the_connection_url() {
+        let (url, password) = split_password("postgres://aegis:p%40ss@db:5432/aegis");
+        assert_eq!(url, "postgres://aegis@db:5432/aegis");
+        assert_eq!(password.as_deref(), Some("p@ss"));
+
+        let (url, password) = split_password("host=db user=aegis");
+        assert_eq!(url, "host=db user=aegis");
+        assert!(password.is_none());
+    }
+}
@@ -33,8 +33,10 @@ pub mod handlers;
 #[cfg(unix)]
 pub mod install;
 pub(crate) mod log_sanitize;
+pub mod migrations;
 pub mod operator_read_models;
 pub(crate) mod ports;
+pub mod recovery;
 pub mod relay_server;
 pub(crate) mod router;
 pub mod server;
@@ -83,14 +85,14 @@ pub async fn check_daemon_running(host: &str, port: u16) -> Result<DaemonStatus>
         Err(e) => {
             // HTTP failed. Check if local PID exists to determine if it SHOULD be running.
             if let Some(pid) = local_pid {
-                if process_exists(pid) {
+                if recovery::is_daemon_process(pid, &pid_file) {
                     // PID exists, Process exists, but HTTP failed -> Unhealthy
                     Ok(DaemonStatus::Unhealthy {
                         pid,
                         error: e.to_string(),
                     })
                 } else {
-                    // Stale PID file
+                    // Stale PID file: process gone or PID reused
                     let _ = tokio::fs::remove_file(&pid_file).await;
                     Ok(DaemonStatus::Stopped)
                 }
@@ -144,6 +146,12 @@ pub async fn stop_daemon(_force: bool, _timeout_secs: u64) -> Result<()> {
         .parse::<u32>()
         .context("Invalid PID")?;
 
+    // Never signal a process that merely inherited a crashed daemon's PID.
+    if !recovery::is_daemon_process(pid, &pid_file) {
+        let _ = tokio::fs::remove_file(&pid_file).await;
+        anyhow::bail!("PID file named process {pid}, which is not a running daemon; removed it");
+    }
+
     info!("Sending SIGTERM to process {}", pid);
 
     #[cfg(unix)]
@@ -12,10 +12,12 @@ use uuid::Uuid;
 
 use aegis_orchestrator_core::{
     domain::node_config::{resolve_env_value, NodeConfigManifest},
+    infrastructure::temporal_client::{WorkflowSignal, RETRY_STATE_SIGNAL},
     infrastructure::temporal_proto::temporal::api::{
         common::v1::WorkflowExecution as TemporalWorkflowExecution,
         workflowservice::v1::{
             DeleteWorkflowExecutionRequest, RequestCancelWorkflowExecutionRequest,
+            TerminateWorkflowExecutionRequest,
         },
     },
 };
@@ -131,6 +133,59 @@ impl aegis_orchestrator_core::application::ports::WorkflowExecutionControlPort
         }
         Ok(())
     }
+
+    async fn terminate_workflow_execution(
+        &self,
+        _tenant_id: &aegis_orchestrator_core::domain::tenant::TenantId,
+        execution_id: aegis_orchestrator_core::domain::execution::ExecutionId,
+        reason: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let namespace = temporal_namespace(&self.config)
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { e.to_string().into() })?;
+        let mut client = connect_temporal_workflow_client(&self.config)
+            .await
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { e.to_string().into() })?;
+        let request = TerminateWorkflowExecutionRequest {
+            namespace,
+            workflow_execution: Some(TemporalWorkflowExecution {
+                workflow_id: execution_id.0.to_string(),
+                run_id: String::new(),
+            }),
+            reason: reason.to_string(),
+            identity: "aegis-daemon".to_string(),
+            ..Default::default()
+        };
+        client
+            .terminate_workflow_execution(request)
+            .await
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { e.to_string().into() })?;
+        Ok(())
+    }
+
+    async fn retry_workflow_state(
+        &self,
+        _tenant_id: &aegis_orchestrator_core::domain::tenant::TenantId,
+        execution_id: aegis_orchestrator_core::domain::execution::ExecutionId,
+        state_name: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let guard = self.temporal_client_container.read().await;
+        let client = guard
+            .as_ref()
+            .ok_or_else(|| -> Box<dyn std::error::Error + Send + Sync> {
+                "Temporal client not yet connected".into()
+            })?
+            .clone();
+        drop(guard);
+        let signal = WorkflowSignal::Custom {
+            name: RETRY_STATE_SIGNAL.to_string(),
+            payload: serde_json::json!({ "state": state_name }),
+        };
+        client
+            .signal_workflow(&execution_id.0.to_string(), &signal)
+            .await
+            .map_err(|e| -> Box<dyn std::error::Error + Send + Sync> { e.to_string().into() })?;
+        Ok(())
+    }
 }
 
 /// Adapts the daemon's execution repository into the `AgentActivityPort`
@@ -0,0 +1,260 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Startup recovery after an unclean daemon exit.
+//!
+//! `aegis daemon start` runs these checks before spawning a new daemon:
+//!
+//! - A PID file whose process is gone, or whose PID now belongs to another
+//!   process, is removed. On Linux the process identity is verified from
+//!   `/proc/<pid>/cmdline` (an `aegis ... --daemon` invocation) and its start
+//!   time (not later than the PID file was written); elsewhere only liveness
+//!   is checked.
+//! - An API port that is bound but not answered by a healthy daemon is
+//!   reported instead of spawning a daemon that would fail to bind.
+//! - With `--reap-orphans`, managed agent and sidecar containers left behind
+//!   by the previous daemon are removed.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Interface / Presentation Layer
+//! - **Purpose:** Implements internal responsibilities for recovery
+
+use anyhow::{Context, Result};
+use bollard::query_parameters::{ListContainersOptionsBuilder, RemoveContainerOptions};
+use bollard::Docker;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
+use std::time::SystemTime;
+use tracing::{info, warn};
+
+use super::get_pid_file_path;
+
+/// Slack between the daemon process starting and it writing the PID file.
+#[cfg(target_os = "linux")]
+const PID_FILE_START_SLACK_SECS: u64 = 2;
+
+/// A PID file removed because it no longer names a running daemon.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StalePidFile {
+    pub pid: u32,
+    /// `process_exited`, `pid_reused` or `unreadable`
+    pub reason: &'static str,
+}
+
+/// What startup recovery cleaned up.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RecoveryReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stale_pid_file: Option<StalePidFile>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reaped_containers: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reap_failures: Vec<String>,
+}
+
+impl RecoveryReport {
+    pub fn is_empty(&self) -> bool {
+        self.stale_pid_file.is_none()
+            && self.reaped_containers.is_empty()
+            && self.reap_failures.is_empty()
+    }
+}
+
+/// Remove the PID file if it does not name a live daemon process.
+pub fn clear_stale_pid_file() -> Result<Option<StalePidFile>> {
+    let pid_file = get_pid_file_path();
+    let Ok(content) = std::fs::read_to_string(&pid_file) else {
+        return Ok(None);
+    };
+
+    let stale = match content.trim().parse::<u32>() {
+        Err(_) => Some(StalePidFile {
+            pid: 0,
+            reason: "unreadable",
+        }),
+        Ok(pid) => stale_reason(pid, &pid_file).map(|reason| StalePidFile { pid, reason }),
+    };
+
+    if let Some(stale) = &stale {
+        std::fs::remove_file(&pid_file)
+            .with_context(|| format!("Failed to remove stale PID file: {pid_file:?}"))?;
+        info!(
+            pid = stale.pid,
+            reason = stale.reason,
+            "Removed stale daemon PID file {:?}",
+            pid_file
+        );
+    }
+    Ok(stale)
+}
+
+/// Whether the PID file's `pid` is a running daemon started before the file
+/// was written.
+pub(crate) fn is_daemon_process(pid: u32, pid_file: &Path) -> bool {
+    stale_reason(pid, pid_file).is_none()
+}
+
+fn stale_reason(pid: u32, pid_file: &Path) -> Option<&'static str> {
+    if !super::process_exists(pid) {
+        return Some("process_exited");
+    }
+    let written_at = std::fs::metadata(pid_file)
+        .and_then(|metadata| metadata.modified())
+        .ok();
+    (!process_identity_matches(pid, written_at)).then_some("pid_reused")
+}
+
+#[cfg(target_os = "linux")]
+fn process_identity_matches(pid: u32, pid_file_written: Option<SystemTime>) -> bool {
+    // Unreadable /proc entries (e.g. hidepid) give no evidence either way.
+    if let Ok(cmdline) = std::fs::read(format!("/proc/{pid}/cmdline")) {
+        if !cmdline_is_daemon(&cmdline) {
+            return false;
+        }
+    }
+    match (process_started_at(pid), pid_file_written) {
+        (Some(started), Some(written)) => {
+            started <= written + std::time::Duration::from_secs(PID_FILE_START_SLACK_SECS)
+        }
+        _ => true,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+fn process_identity_matches(_pid: u32, _pid_file_written: Option<SystemTime>) -> bool {
+    true
+}
+
+/// Whether a NUL-separated `/proc/<pid>/cmdline` is an `aegis --daemon` run.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn cmdline_is_daemon(cmdline: &[u8]) -> bool {
+    let mut args = cmdline
+        .split(|byte| *byte == 0)
+        .map(String::from_utf8_lossy);
+    let Some(program) = args.next() else {
+        return false;
+    };
+    let program_is_aegis = Path::new(program.as_ref())
+        .file_name()
+        .is_some_and(|name| name.to_string_lossy().contains("aegis"));
+    program_is_aegis && args.any(|arg| arg == "--daemon")
+}
+
+#[cfg(target_os = "linux")]
+fn process_started_at(pid: u32) -> Option<SystemTime> {
+    let stat = std::fs::read_to_string(format!("/proc/{pid}/stat")).ok()?;
+    let start_ticks = start_ticks_from_stat(&stat)?;
+    let boot_time = std::fs::read_to_string("/proc/stat")
+        .ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("btime "))?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    // SAFETY: `sysconf` has no preconditions and only reads a constant.
+    let ticks_per_second = u64::try_from(unsafe { libc::sysconf(libc::_SC_CLK_TCK) })
+        .ok()
+        .filter(|ticks| *ticks > 0)?;
+    let started = boot_time + start_ticks / ticks_per_second;
+    Some(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(started))
+}
+
+/// Field 22 (`starttime`, clock ticks after boot) of `/proc/<pid>/stat`.
+/// Parsed after the last `)` because the command name may contain spaces.
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn start_ticks_from_stat(stat: &str) -> Option<u64> {
+    let (_, rest) = stat.rsplit_once(')')?;
+    rest.split_whitespace().nth(19)?.parse().ok()
+}
+
+/// Fail when `host:port` is already bound, since no healthy daemon answered
+/// on it and a new daemon could not bind it either.
+pub fn ensure_port_free(host: &str, port: u16) -> Result<()> {
+    match std::net::TcpListener::bind((host, port)) {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::AddrInUse => anyhow::bail!(
+            "Port {port} on {host} is in use, but no healthy AEGIS daemon answers on it. \
+             Stop the process holding it (e.g. `lsof -i :{port}`) or start with --port."
+        ),
+        // Not a local address; the daemon's own bind reports any problem.
+        Err(_) => Ok(()),
+    }
+}
+
+/// Remove managed agent and sidecar containers left by a previous daemon.
+/// Containers retained for debugging (`aegis.keep_container_on_failure`)
+/// are kept.
+pub async fn reap_orphaned_containers(docker: &Docker, report: &mut RecoveryReport) -> Result<()> {
+    let filters = HashMap::from([(
+        "label".to_string(),
+        vec![
+            "aegis.managed=true".to_string(),
+            "aegis.execution_id".to_string(),
+        ],
+    )]);
+    let containers = docker
+        .list_containers(Some(
+            ListContainersOptionsBuilder::new()
+                .all(true)
+                .filters(&filters)
+                .build(),
+        ))
+        .await
+        .context("Failed to list managed containers")?;
+
+    for container in containers {
+        let Some(id) = container.id else { continue };
+        let debug_retain = container
+            .labels
+            .as_ref()
+            .and_then(|labels| labels.get("aegis.keep_container_on_failure"))
+            .is_some_and(|value| value.eq_ignore_ascii_case("true"));
+        if debug_retain {
+            continue;
+        }
+        let options = RemoveContainerOptions {
+            force: true,
+            v: true,
+            ..Default::default()
+        };
+        match docker.remove_container(&id, Some(options)).await {
+            Ok(()) => report.reaped_containers.push(id),
+            Err(e) => {
+                warn!(container_id = %id, error = %e, "Failed to reap orphaned container");
+                report.reap_failures.push(id);
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cmdline_identifies_daemon_invocations() {
+        assert!(cmdline_is_daemon(
+            b"/usr/local/bin/aegis\0--daemon\0--port\08088\0"
+        ));
+        assert!(!cmdline_is_daemon(b"/usr/local/bin/aegis\0task\0list\0"));
+        assert!(!cmdline_is_daemon(b"/usr/bin/python3\0--daemon\0"));
+        assert!(!cmdline_is_daemon(b""));
+    }
+
+    #[test]
+    fn start_ticks_skip_command_names_with_spaces() {
+        let stat = "4242 (aegis worker) S 1 4242 4242 0 -1 4194560 1 0 0 0 3 1 0 0 20 0 9 0 123456 1000 50";
+        assert_eq!(start_ticks_from_stat(stat), Some(123456));
+        assert_eq!(start_ticks_from_stat("garbage"), None);
+    }
+
+    #[test]
+    fn pid_of_exited_process_is_stale() {
+        let path = std::env::temp_dir().join(format!("aegis-recovery-{}.pid", std::process::id()));
+        std::fs::write(&path, "2147483646").unwrap();
+        assert_eq!(stale_reason(2_147_483_646, &path), Some("process_exited"));
+        let _ = std::fs::remove_file(path);
+    }
+}
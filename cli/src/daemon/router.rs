@@ -19,8 +19,9 @@ use crate::daemon::handlers::admin::{
 };
 use crate::daemon::handlers::agents::{
     delete_agent_handler, deploy_agent_handler, execute_agent_handler, get_agent_handler,
-    list_agent_versions_handler, list_agents_handler, lookup_agent_handler,
-    stream_agent_events_handler, update_agent_handler, update_agent_scope_handler,
+    list_agent_manifest_changes_handler, list_agent_versions_handler, list_agents_handler,
+    lookup_agent_handler, stream_agent_events_handler, update_agent_handler,
+    update_agent_scope_handler, verify_agent_handler,
 };
 use crate::daemon::handlers::api_keys::{
     create_api_key_handler, list_api_keys_handler, revoke_api_key_handler, validate_api_key_handler,
@@ -41,7 +42,10 @@ use crate::daemon::handlers::canvas::{
     terminate_session_handler as canvas_terminate_session_handler,
     update_session_handler as canvas_update_session_handler,
 };
-use crate::daemon::handlers::cluster::{cluster_nodes_handler, cluster_status_handler};
+use crate::daemon::handlers::cluster::{
+    cluster_nodes_handler, cluster_status_handler, cordon_node_handler,
+    node_maintenance_status_handler, uncordon_node_handler,
+};
 use crate::daemon::handlers::colony::{
     accept_invitation, cancel_invitation, create_invitation, create_team, delete_team,
     get_saml_config, get_subscription, list_invitations, list_members, list_teams, remove_member,
@@ -59,31 +63,59 @@ use crate::daemon::handlers::credentials::{
 };
 use crate::daemon::handlers::dispatch::{dispatch_gateway_handler, temporal_events_handler};
 use crate::daemon::handlers::executions::{
-    cancel_execution_handler, delete_execution_handler, get_execution_file_handler,
-    get_execution_handler, list_executions_handler, stream_events_handler,
+    add_execution_guidance_handler, bulk_cancel_executions_handler,
+    bulk_requeue_executions_handler, cancel_execution_handler, delete_execution_handler,
+    explain_execution_handler, get_bulk_execution_operation_handler,
+    get_execution_file_activity_handler, get_execution_file_handler, get_execution_handler,
+    get_execution_network_flows_handler, list_execution_attachments_handler,
+    list_executions_handler, stream_events_handler, update_execution_handler,
+};
+#[cfg(feature = "fault-injection")]
+use crate::daemon::handlers::faults::{
+    add_fault_handler, clear_faults_handler, list_faults_handler, remove_fault_handler,
+};
+use crate::daemon::handlers::feature_flags::{
+    clear_feature_flag_handler, list_feature_flags_handler, set_feature_flag_handler,
 };
 use crate::daemon::handlers::git_repo::{
     commit_git_repo, create_git_repo, delete_git_repo, diff_git_repo, get_git_repo, list_git_repos,
     push_git_repo, refresh_git_repo, webhook_git_repo,
 };
 use crate::daemon::handlers::health::{health_handler, readiness_handler};
+use crate::daemon::handlers::ingestion::slack_events_handler;
+use crate::daemon::handlers::meta::{event_schemas_handler, handshake_handler, version_handler};
 use crate::daemon::handlers::observability::{
     dashboard_summary_handler, get_stimulus_handler, list_security_incidents_handler,
     list_stimuli_handler, list_storage_violations_handler,
 };
+use crate::daemon::handlers::outbound_webhooks::{
+    list_dead_letters_handler, list_deliveries_handler,
+};
+use crate::daemon::handlers::plugins::{
+    evaluate_plugin_handler, upload_plugin_handler, MAX_PLUGIN_BYTES,
+};
+use crate::daemon::handlers::quotas::{
+    delete_quota_handler, get_quota_handler, list_quotas_handler, set_quota_handler,
+};
 use crate::daemon::handlers::script::{
     create_script, delete_script, get_script, list_scripts, update_script,
 };
 use crate::daemon::handlers::seal::{
-    attest_seal_handler, invoke_seal_handler, list_seal_tools_handler,
+    attest_seal_handler, invoke_seal_handler, invoke_seal_stream_handler, list_seal_tools_handler,
 };
+use crate::daemon::handlers::security_contexts::simulate_security_context_handler;
 use crate::daemon::handlers::stimulus::{ingest_stimulus_handler, webhook_handler};
 use crate::daemon::handlers::swarms::{get_swarm_handler, list_swarms_handler};
 use crate::daemon::handlers::tenant_provisioning::keycloak_event_handler;
+use crate::daemon::handlers::usage::{
+    usage_daily_handler, usage_report_handler, usage_rollup_handler,
+};
 use crate::daemon::handlers::volumes;
 use crate::daemon::handlers::workflow_executions::{
-    cancel_workflow_execution_handler, get_workflow_execution_handler, get_workflow_logs_handler,
-    list_workflow_executions_handler, remove_workflow_execution_handler,
+    cancel_workflow_execution_handler, get_workflow_execution_handler,
+    get_workflow_handoffs_handler, get_workflow_logs_handler,
+    get_workflow_transition_blackboard_handler, list_workflow_executions_handler,
+    query_workflow_execution_handler, remove_workflow_execution_handler,
     signal_workflow_execution_handler, stream_workflow_logs_handler,
 };
 use crate::daemon::handlers::workflows::{
@@ -102,12 +134,24 @@ pub(crate) fn create_router(
         .route("/health", get(health_handler))
         .route("/health/live", get(health_handler))
         .route("/health/ready", get(readiness_handler))
-        .route("/v1/agents/{agent_id}/execute", post(execute_agent_handler))
+        .route("/v1/meta/version", get(version_handler))
+        .route("/v1/meta/handshake", post(handshake_handler))
+        .route("/v1/schemas/events", get(event_schemas_handler))
+        // Multipart uploads are held to the caller's tier cap by the handler.
+        .route(
+            "/v1/agents/{agent_id}/execute",
+            post(execute_agent_handler).layer(DefaultBodyLimit::disable()),
+        )
+        .route("/v1/agents/{agent_id}/verify", post(verify_agent_handler))
         .route("/v1/executions/{execution_id}", get(get_execution_handler))
         .route(
             "/v1/executions/{execution_id}/cancel",
             post(cancel_execution_handler),
         )
+        .route(
+            "/v1/executions/{execution_id}/guidance",
+            post(add_execution_guidance_handler),
+        )
         .route(
             "/v1/executions/{execution_id}/events",
             get(stream_events_handler),
@@ -116,20 +160,63 @@ pub(crate) fn create_router(
             "/v1/executions/{execution_id}/files/{*path}",
             get(get_execution_file_handler),
         )
+        .route(
+            "/v1/executions/{execution_id}/attachments",
+            get(list_execution_attachments_handler),
+        )
+        .route(
+            "/v1/executions/{execution_id}/file-activity",
+            get(get_execution_file_activity_handler),
+        )
+        .route(
+            "/v1/executions/{execution_id}/network-flows",
+            get(get_execution_network_flows_handler),
+        )
+        .route(
+            "/v1/executions/{execution_id}/explain",
+            get(explain_execution_handler),
+        )
         .route(
             "/v1/agents/{agent_id}/events",
             get(stream_agent_events_handler),
         )
         .route("/v1/executions", get(list_executions_handler))
+        .route(
+            "/v1/executions/bulk/cancel",
+            post(bulk_cancel_executions_handler),
+        )
+        .route(
+            "/v1/executions/bulk/requeue",
+            post(bulk_requeue_executions_handler),
+        )
+        .route(
+            "/v1/executions/bulk/{operation_id}",
+            get(get_bulk_execution_operation_handler),
+        )
+        .route("/v1/usage", get(usage_rollup_handler))
+        .route("/v1/usage/daily", get(usage_daily_handler))
+        .route("/v1/reports/usage", get(usage_report_handler))
+        .route(
+            "/v1/outbound-webhooks/deliveries",
+            get(list_deliveries_handler),
+        )
+        .route(
+            "/v1/outbound-webhooks/dead-letters",
+            get(list_dead_letters_handler),
+        )
         .route(
             "/v1/executions/{execution_id}",
-            delete(delete_execution_handler),
+            delete(delete_execution_handler).patch(update_execution_handler),
         )
         .route(
             "/v1/agents",
             post(deploy_agent_handler).get(list_agents_handler),
         )
         .route("/v1/agents/{id}/versions", get(list_agent_versions_handler))
+        .route(
+            "/v1/agents/{id}/changes",
+            get(list_agent_manifest_changes_handler),
+        )
         .route(
             "/v1/agents/{id}",
             get(get_agent_handler)
@@ -189,10 +276,30 @@ pub(crate) fn create_router(
             "/v1/workflows/executions/{execution_id}/signal",
             post(signal_workflow_execution_handler),
         )
+        .route(
+            "/v1/workflows/executions/{execution_id}/query",
+            get(query_workflow_execution_handler),
+        )
         .route(
             "/v1/workflows/executions/{execution_id}/cancel",
             post(cancel_workflow_execution_handler),
         )
+        .route(
+            "/v1/workflows/executions/{execution_id}/states/{index}/blackboard",
+            get(get_workflow_transition_blackboard_handler),
+        )
+        .route(
+            "/v1/workflows/executions/{execution_id}/handoffs",
+            get(get_workflow_handoffs_handler),
+        )
+        .route(
+            "/v1/plugins",
+            post(upload_plugin_handler).layer(DefaultBodyLimit::max(MAX_PLUGIN_BYTES)),
+        )
+        .route(
+            "/v1/plugins/{digest}/evaluate",
+            post(evaluate_plugin_handler),
+        )
         .route("/v1/temporal-events", post(temporal_events_handler))
         .route("/v1/human-approvals", get(list_pending_approvals_handler))
         .route(
@@ -209,9 +316,17 @@ pub(crate) fn create_router(
         )
         .route("/v1/seal/attest", post(attest_seal_handler))
         .route("/v1/seal/invoke", post(invoke_seal_handler))
+        .route("/v1/seal/invoke/stream", post(invoke_seal_stream_handler))
         .route("/v1/seal/tools", get(list_seal_tools_handler))
+        .route(
+            "/v1/security-contexts/{name}/simulate",
+            post(simulate_security_context_handler),
+        )
         .route("/v1/cluster/status", get(cluster_status_handler))
         .route("/v1/cluster/nodes", get(cluster_nodes_handler))
+        .route("/v1/node/maintenance", get(node_maintenance_status_handler))
+        .route("/v1/node/cordon", post(cordon_node_handler))
+        .route("/v1/node/uncordon", post(uncordon_node_handler))
         .route("/v1/swarms", get(list_swarms_handler))
         .route("/v1/swarms/{swarm_id}", get(get_swarm_handler))
         .route(
@@ -244,6 +359,19 @@ pub(crate) fn create_router(
             "/v1/admin/rate-limits/usage",
             get(get_rate_limit_usage_handler),
         )
+        // Tenant quota management (ADR-056)
+        .route("/v1/quotas", get(list_quotas_handler))
+        .route(
+            "/v1/quotas/{tenant_id}",
+            get(get_quota_handler)
+                .put(set_quota_handler)
+                .delete(delete_quota_handler),
+        )
+        .route("/v1/admin/feature-flags", get(list_feature_flags_handler))
+        .route(
+            "/v1/admin/feature-flags/{name}",
+            put(set_feature_flag_handler).delete(clear_feature_flag_handler),
+        )
         .route(
             "/v1/user/rate-limits/usage",
             get(get_user_rate_limit_usage_handler),
@@ -258,6 +386,12 @@ pub(crate) fn create_router(
         .route("/v1/api-keys/{id}", delete(revoke_api_key_handler))
         // Keycloak webhook for tenant provisioning (ADR-097)
         .route("/v1/webhooks/keycloak", post(keycloak_event_handler))
+        // BC-8 Slack Events API ingestion — Slack signing-secret
+        // authenticated, exempt from Keycloak JWT via "/v1/webhooks".
+        .route(
+            "/v1/webhooks/slack/events",
+            post(slack_events_handler).layer(DefaultBodyLimit::max(MAX_WEBHOOK_BODY_BYTES)),
+        )
         // BC-8 Webhook stimulus ingestion (ADR-021).
         // Audit 002 §4.21: cap pre-auth body reads at
         // MAX_WEBHOOK_BODY_BYTES to prevent memory DoS.
@@ -305,13 +439,16 @@ pub(crate) fn create_router(
                 .delete(delete_secret_handler),
         )
         // User volume management (Gap 079)
-        // Note: /v1/volumes/quota MUST be registered before /v1/volumes/{id} to avoid
-        // axum routing ambiguity — "quota" would otherwise be matched as an id segment.
+        // Note: /v1/volumes/quota, /search and /grep MUST be registered before
+        // /v1/volumes/{id} to avoid axum routing ambiguity — "quota" would
+        // otherwise be matched as an id segment.
         .route(
             "/v1/volumes",
             post(volumes::create_volume).get(volumes::list_volumes),
         )
         .route("/v1/volumes/quota", get(volumes::get_quota))
+        .route("/v1/volumes/search", get(volumes::search_files))
+        .route("/v1/volumes/grep", post(volumes::grep_files))
         .route(
             "/v1/volumes/{id}",
             get(volumes::get_volume)
@@ -326,6 +463,10 @@ pub(crate) fn create_router(
             "/v1/volumes/{id}/files/download",
             get(volumes::download_file),
         )
+        .route(
+            "/v1/volumes/{id}/files/content",
+            get(volumes::get_file_content),
+        )
         .route("/v1/volumes/{id}/files/stat", get(volumes::stat_file))
         .route("/v1/volumes/{id}/files/upload", post(volumes::upload_file))
         .route("/v1/volumes/{id}/files/mkdir", post(volumes::mkdir))
@@ -422,6 +563,20 @@ pub(crate) fn create_router(
         router
     };
 
+    // Fault injection rules (`fault-injection` builds only).
+    #[cfg(feature = "fault-injection")]
+    let router = router.merge(
+        Router::new()
+            .route(
+                "/v1/admin/faults",
+                get(list_faults_handler)
+                    .post(add_fault_handler)
+                    .delete(clear_faults_handler),
+            )
+            .route("/v1/admin/faults/{id}", delete(remove_fault_handler))
+            .with_state(app_state.clone()),
+    );
+
     // Tenant-context middleware (ADR-056, ADR-111 §Tenant-Context Header
     // Extension) — inserts the resolved TenantId into request extensions and
     // enforces consumer team-switch authorization via MembershipRepository.
@@ -436,12 +591,24 @@ pub(crate) fn create_router(
         aegis_orchestrator_core::presentation::tenant_middleware::tenant_context_middleware,
     ));
 
-    if let Some(iam_service) = iam_service {
+    let router = if let Some(iam_service) = iam_service {
         router.layer(middleware::from_fn_with_state(
             iam_service,
             aegis_orchestrator_core::presentation::keycloak_auth::iam_auth_middleware,
         ))
     } else {
         router
-    }
+    };
+
+    // Outermost so unsupported versions are rejected before auth and every
+    // response, including auth failures, carries the version headers.
+    let router = router.layer(middleware::from_fn(
+        aegis_orchestrator_core::presentation::api_version::api_version_middleware,
+    ));
+
+    // Outermost: every error response leaves as problem+json with an
+    // `AEG-xxxx` code, whichever layer or handler produced it.
+    router.layer(middleware::from_fn(
+        aegis_orchestrator_core::presentation::problem::problem_details_middleware,
+    ))
 }
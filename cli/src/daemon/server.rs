@@ -97,9 +97,12 @@ use aegis_orchestrator_core::domain::rate_limit::{RateLimitEnforcer, RateLimitPo
 use aegis_orchestrator_core::{
     application::{
         agent::AgentLifecycleService,
+        cluster::{DaemonFailover, LeaseLost},
+        concurrency_group::ConcurrencyGroupService,
         execution::ExecutionService,
         execution::StandardExecutionService,
         lifecycle::StandardAgentLifecycleService,
+        lock_service::{InMemoryLockService, LockService},
         register_workflow::{RegisterWorkflowUseCase, StandardRegisterWorkflowUseCase},
         start_workflow_execution::StandardStartWorkflowExecutionUseCase,
         validation_service::ValidationService,
@@ -111,12 +114,16 @@ use aegis_orchestrator_core::{
             NodeClusterRepository, NodeId, NodeRole,
         },
         iam::IdentityProvider,
-        node_config::{resolve_env_value, IamConfig, IamRealmConfig, NodeConfigManifest},
+        node_config::{
+            resolve_env_value, IamConfig, IamRealmConfig, MigrateMode, NodeConfigManifest,
+        },
         repository::AgentRepository,
         runtime_registry::StandardRuntimeRegistry,
         supervisor::Supervisor,
     },
     infrastructure::{
+        cluster::PgDaemonLeaseRepository,
+        db::DatabasePools,
         event_bus::EventBus,
         iam::StandardIamService,
         llm::registry::ProviderRegistry,
@@ -188,7 +195,12 @@ fn temporal_connection_max_retries(raw_value: Option<i32>) -> i32 {
 // cluster_role_to_string, node_status_to_string, cluster_node_view, fallback_cluster_node,
 // cluster_status_view, load_cluster_nodes moved to cluster_helpers.rs
 
-pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()> {
+/// Run the daemon. `migrate_override` replaces `spec.database.migrations.mode`.
+pub async fn start_daemon(
+    config_path: Option<PathBuf>,
+    port: u16,
+    migrate_override: Option<MigrateMode>,
+) -> Result<()> {
     // Write PID file
     let pid = std::process::id();
     write_pid_file(pid)?;
@@ -285,6 +297,11 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         .as_ref()
         .map(|db| db.max_connections)
         .unwrap_or(5);
+    let replica_config = config
+        .spec
+        .database
+        .as_ref()
+        .and_then(|db| db.read_replica.clone());
 
     // Store pool separately for later volume repo initialization
     let db_pool: Option<PgPool> = if let Some(url) = database_url.as_ref() {
@@ -297,42 +314,15 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             Ok(db_pool) => {
                 info!("Connected to PostgreSQL");
 
-                // Check migration status
-                static MIGRATOR: sqlx::migrate::Migrator = sqlx::migrate!("./migrations");
-
-                let total_known = MIGRATOR.iter().count();
-                if total_known == 0 {
-                    return Err(anyhow::anyhow!(
-                        "CRITICAL: No migrations found in binary! Check build process."
-                    ));
-                }
-
-                // Check applied migrations
-                let applied_result = sqlx::query("SELECT version FROM _sqlx_migrations")
-                    .fetch_all(&db_pool)
-                    .await;
-
-                let applied_count = match applied_result {
-                    Ok(rows) => rows.len(),
-                    Err(_) => 0,
-                };
-
-                info!(
-                    applied = applied_count,
-                    total = total_known,
-                    "Database migration status"
-                );
-
-                if applied_count < total_known {
-                    info!("Applying pending migrations...");
-                    match MIGRATOR.run(&db_pool).await {
-                        Ok(_) => info!("Database migrations applied successfully"),
-                        Err(e) => {
-                            return Err(anyhow::anyhow!("Failed to apply migrations: {e}"));
-                        }
-                    }
-                } else {
-                    info!("Database is up to date");
+                if let Some(db_config) = config.spec.database.as_ref() {
+                    let migrate_mode = migrate_override.unwrap_or(db_config.migrations.mode);
+                    crate::daemon::migrations::migrate_on_startup(
+                        &db_pool,
+                        url,
+                        db_config,
+                        migrate_mode,
+                    )
+                    .await?;
                 }
 
                 Some(db_pool)
@@ -359,12 +349,51 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         None
     };
 
+    // Optional read replica for listing and event queries. A replica that
+    // cannot be reached at startup is skipped rather than failing the daemon.
+    let db_pools: Option<DatabasePools> = match (db_pool.as_ref(), replica_config) {
+        (Some(primary), Some(replica)) => match resolve_env_value(&replica.url) {
+            Ok(url) => match sqlx::postgres::PgPoolOptions::new()
+                .max_connections(replica.max_connections)
+                .connect(&url)
+                .await
+            {
+                Ok(replica_pool) => {
+                    info!(
+                        max_lag_seconds = replica.max_lag_seconds,
+                        "Connected to PostgreSQL read replica"
+                    );
+                    let pools = DatabasePools::with_replica(
+                        primary.clone(),
+                        replica_pool,
+                        std::time::Duration::from_secs(replica.max_lag_seconds),
+                    );
+                    pools.refresh_replica_lag().await;
+                    pools.spawn_lag_monitor(std::time::Duration::from_secs(
+                        replica.lag_check_interval_seconds.max(1),
+                    ));
+                    Some(pools)
+                }
+                Err(e) => {
+                    warn!(error = %e, "Failed to connect to read replica; all reads use the primary");
+                    Some(DatabasePools::primary_only(primary.clone()))
+                }
+            },
+            Err(e) => {
+                warn!(error = %e, "Failed to resolve read replica URL; all reads use the primary");
+                Some(DatabasePools::primary_only(primary.clone()))
+            }
+        },
+        (Some(primary), None) => Some(DatabasePools::primary_only(primary.clone())),
+        (None, _) => None,
+    };
+
     let (agent_repo, workflow_repo, execution_repo, workflow_execution_repo): RepositoryTuple =
-        if let Some(db_pool) = db_pool.as_ref() {
+        if let (Some(db_pool), Some(pools)) = (db_pool.as_ref(), db_pools.as_ref()) {
             (
             Arc::new(aegis_orchestrator_core::infrastructure::repositories::postgres_agent::PostgresAgentRepository::new(db_pool.clone())),
             Arc::new(aegis_orchestrator_core::infrastructure::repositories::postgres_workflow::PostgresWorkflowRepository::new_with_pool(db_pool.clone())),
-            Arc::new(aegis_orchestrator_core::infrastructure::repositories::postgres_execution::PostgresExecutionRepository::new(db_pool.clone())),
+            Arc::new(aegis_orchestrator_core::infrastructure::repositories::postgres_execution::PostgresExecutionRepository::with_pools(pools.clone())),
             Arc::new(aegis_orchestrator_core::infrastructure::repositories::postgres_workflow_execution::PostgresWorkflowExecutionRepository::new(db_pool.clone())),
         )
         } else {
@@ -378,6 +407,51 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             )
         };
 
+    // spec.high_availability: stand by until this daemon holds the failover
+    // lease, then fail the executions the previous active left unfinished.
+    // Nothing below binds a port until the lease is ours.
+    let failover: Option<Arc<DaemonFailover>> = match config
+        .spec
+        .high_availability
+        .as_ref()
+        .filter(|ha| ha.enabled)
+    {
+        Some(ha) => {
+            let pool = db_pool.as_ref().ok_or_else(|| {
+                anyhow::anyhow!("spec.high_availability requires a reachable spec.database")
+            })?;
+            let failover = Arc::new(DaemonFailover::new(
+                Arc::new(PgDaemonLeaseRepository::new(pool.clone())),
+                ha.clone(),
+                &config.spec.node.id,
+            ));
+            info!(
+                lease = %ha.lease_name,
+                holder = %failover.holder(),
+                "High availability enabled; waiting for the failover lease"
+            );
+            tokio::select! {
+                _ = failover.wait_until_active() => {}
+                _ = shutdown_signal() => {
+                    info!("Shutdown requested while standing by");
+                    return Ok(());
+                }
+            }
+            failover
+                .reconcile_executions(execution_repo.as_ref())
+                .await
+                .context("Failed to reconcile executions after taking over the failover lease")?;
+            Some(failover)
+        }
+        None => None,
+    };
+    let (lease_lost_tx, lease_lost_rx) = tokio::sync::oneshot::channel::<LeaseLost>();
+    if let Some(failover) = failover.clone() {
+        tokio::spawn(async move {
+            let _ = lease_lost_tx.send(failover.hold().await);
+        });
+    }
+
     let cluster_repo: Option<Arc<dyn NodeClusterRepository>> = None;
 
     // ── ADR-060: Load effective config by merging database layers over bootstrap YAML ──
@@ -444,8 +518,13 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
 
     let event_bus = Arc::new(EventBus::new(100));
     let operator_read_model = OperatorReadModelStore::spawn_collector(event_bus.clone());
-    let swarm_service =
-        Arc::new(StandardSwarmService::new().with_execution_repository(execution_repo.clone()));
+    // Shared by swarm resource locks and manifest concurrency groups.
+    let lock_service: Arc<dyn LockService> = Arc::new(InMemoryLockService::new());
+    let swarm_service = Arc::new(
+        StandardSwarmService::new()
+            .with_execution_repository(execution_repo.clone())
+            .with_lock_service(lock_service.clone()),
+    );
     swarm_service.start_gc_task();
     let iam_service: Option<Arc<dyn IdentityProvider>> = match config.spec.iam.as_ref() {
         Some(iam) => {
@@ -525,10 +604,11 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         .await;
     }
 
-    info!("Initializing LLM registry...");
-    let llm_registry = Arc::new(
-        ProviderRegistry::from_config(&config).context("Failed to initialize LLM providers")?,
-    );
+    #[cfg(feature = "fault-injection")]
+    let fault_injector =
+        Arc::new(aegis_orchestrator_core::infrastructure::fault_injection::FaultInjector::new());
+    #[cfg(feature = "fault-injection")]
+    warn!("Fault injection is compiled in; /v1/admin/faults can degrade LLM, storage and Temporal calls");
 
     info!("Initializing Docker runtime...");
 
@@ -680,6 +760,21 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
                 aegis_orchestrator_core::infrastructure::storage::create_storage_provider(
                     aegis_orchestrator_core::infrastructure::storage::StorageBackend::SeaweedFS {
                         filer_url: filer_url.clone(),
+                        write_behind: {
+                            let write_behind = storage_config
+                                .seaweedfs
+                                .as_ref()
+                                .map(|s| s.write_behind.clone())
+                                .unwrap_or_default();
+                            write_behind.enabled.then(|| {
+                                aegis_orchestrator_core::infrastructure::storage::WriteBehindConfig {
+                                    flush_bytes: write_behind.flush_bytes,
+                                    flush_interval: std::time::Duration::from_millis(
+                                        write_behind.flush_interval_ms,
+                                    ),
+                                }
+                            })
+                        },
                     },
                 )?
             }
@@ -711,16 +806,67 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             }
             other => return Err(anyhow::anyhow!("Unsupported storage backend: {other}")),
         };
+    #[cfg(feature = "fault-injection")]
+    let storage_provider: Arc<dyn aegis_orchestrator_core::domain::storage::StorageProvider> =
+        Arc::new(
+            aegis_orchestrator_core::infrastructure::fault_injection::FaultInjectingStorageProvider::new(
+                storage_provider,
+                fault_injector.clone(),
+            ),
+        );
 
-    let volume_service = Arc::new(
+    // Tenant quotas (ADR-056): tier defaults plus per-tenant overrides managed
+    // through `/v1/quotas`. Enforced on agent deploy, execution start and
+    // volume creation only when `spec.quotas` is enabled.
+    let quota_service: Option<
+        Arc<aegis_orchestrator_core::application::tenant_quota::TenantQuotaService>,
+    > = db_pool.as_ref().map(|pool| {
+        Arc::new(
+            aegis_orchestrator_core::application::tenant_quota::TenantQuotaService::new(
+                Arc::new(
+                    aegis_orchestrator_core::infrastructure::repositories::postgres_tenant::PostgresTenantRepository::new(pool.clone()),
+                ),
+                Arc::new(
+                    aegis_orchestrator_core::infrastructure::repositories::PostgresBillingRepository::new(
+                        pool.clone(),
+                    ),
+                ),
+                agent_repo.clone(),
+                execution_repo.clone(),
+                event_bus.clone(),
+            )
+            .with_quota_repository(Arc::new(
+                aegis_orchestrator_core::infrastructure::repositories::PostgresQuotaRepository::new(
+                    pool.clone(),
+                ),
+            ))
+            .with_volume_repository(volume_repo.clone())
+            .with_token_usage_repository(Arc::new(
+                aegis_orchestrator_core::infrastructure::repositories::PostgresTokenUsageRepository::new(
+                    pool.clone(),
+                ),
+            )),
+        )
+    });
+    let enforced_quota_service = quota_service
+        .clone()
+        .filter(|_| config.spec.quotas.as_ref().is_some_and(|cfg| cfg.enabled));
+    if enforced_quota_service.is_some() {
+        info!("Tenant quota enforcement enabled");
+    }
+
+    let mut volume_service_builder =
         aegis_orchestrator_core::application::volume_manager::StandardVolumeService::new(
             volume_repo.clone(),
             storage_provider.clone(),
             event_bus.clone(),
             filer_url,
             storage_config.backend.clone(),
-        )?,
-    );
+        )?;
+    if let Some(quota_service) = enforced_quota_service.clone() {
+        volume_service_builder = volume_service_builder.with_quota_service(quota_service);
+    }
+    let volume_service = Arc::new(volume_service_builder);
 
     info!(mode = %storage_config.backend, "Volume service initialized");
 
@@ -749,8 +895,8 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
     info!("Initializing Storage Event Persister...");
     let storage_event_repo: Arc<
         dyn aegis_orchestrator_core::domain::repository::StorageEventRepository,
-    > = if let Some(db_pool) = db_pool.as_ref() {
-        Arc::new(aegis_orchestrator_core::infrastructure::repositories::postgres_storage_event::PostgresStorageEventRepository::new(db_pool.clone()))
+    > = if let Some(pools) = db_pools.as_ref() {
+        Arc::new(aegis_orchestrator_core::infrastructure::repositories::postgres_storage_event::PostgresStorageEventRepository::with_pools(pools.clone()))
     } else {
         warn!("Storage event persistence disabled (no database pool available)");
         Arc::new(
@@ -815,6 +961,27 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
     }
     info!(port = nfs_bind_port, "NFS Server Gateway started");
 
+    // Restart the gateway when its server task dies. Running iterations are
+    // told through the supervisor's storage transport subscription, since
+    // their mounts do not survive the restart.
+    let nfs_watchdog_gateway = nfs_gateway.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(5));
+        loop {
+            interval.tick().await;
+            match nfs_watchdog_gateway.ensure_running().await {
+                Ok(Some(generation)) => {
+                    warn!(
+                        generation,
+                        "NFS Server Gateway stopped unexpectedly and was restarted"
+                    )
+                }
+                Ok(None) => {}
+                Err(e) => error!(error = %e, "NFS Server Gateway restart failed"),
+            }
+        }
+    });
+
     // ─── Create FUSE FSAL Daemon (ADR-107) ───────────────────────────────────
     // Shares the same FSAL instance as the NFS gateway. The FUSE daemon provides
     // an alternative volume transport using host-local FUSE mountpoints + bind
@@ -832,25 +999,94 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         Some(Arc::new(daemon))
     };
 
-    let runtime = Arc::new(
-        ContainerRuntime::new(aegis_orchestrator_core::infrastructure::runtime::ContainerRuntimeConfig {
-            bootstrap_script: config.spec.runtime.bootstrap_script.clone(),
-            socket_path: config.spec.runtime.container_socket_path.clone(),
-            network_mode: network_mode.clone(),
-            orchestrator_url,
-            nfs_server_host: nfs_server_host.clone(),
-            nfs_port: config.spec.runtime.nfs_port,
-            nfs_mountport: config.spec.runtime.nfs_mountport,
-            event_bus: event_bus.clone(),
-            credential_resolver: Arc::new(
-                aegis_orchestrator_core::infrastructure::image_manager::NodeConfigCredentialResolver::new(
-                    config.spec.registry_credentials.clone(),
+    // Secrets manager: initialize from `spec.secrets.backend`, otherwise use an in-memory store for local development/testing.
+    let secrets_manager: Arc<aegis_orchestrator_core::infrastructure::secrets_manager::SecretsManager> =
+        match config.spec.secrets.as_ref().and_then(|s| s.backend.as_ref()) {
+            Some(secret_backend_config) => {
+                match aegis_orchestrator_core::infrastructure::secrets_manager::SecretsManager::from_config(
+                    secret_backend_config,
+                    event_bus.clone(),
+                ).await {
+                    Ok(manager) => {
+                        info!("OpenBao secrets manager initialized");
+                        Arc::new(manager)
+                    }
+                    Err(e) => {
+                        return Err(anyhow::anyhow!("Failed to initialize OpenBao secrets manager: {e}"));
+                    }
+                }
+            }
+            None => {
+                warn!("No spec.secrets.backend configured; using in-memory secret store (development/testing only, not production-safe)");
+                Arc::new(aegis_orchestrator_core::infrastructure::secrets_manager::SecretsManager::from_store(
+                    Arc::new(aegis_orchestrator_core::infrastructure::secrets_manager::TestSecretStore::new()),
+                    event_bus.clone(),
+                ))
+            }
+        };
+
+    // Tenant LLM layers (spec.tenant_llm) read `secret:` API keys, so the
+    // registry is built once the secrets manager is up.
+    info!("Initializing LLM registry...");
+    let llm_registry = ProviderRegistry::from_config(&config)
+        .context("Failed to initialize LLM providers")?
+        .with_tenant_configs(&config.spec.tenant_llm, &secrets_manager)
+        .await
+        .context("Failed to initialize tenant LLM providers")?;
+    #[cfg(feature = "fault-injection")]
+    let llm_registry = llm_registry.with_fault_injector(fault_injector.clone());
+    let llm_registry = Arc::new(llm_registry);
+
+    // Registry credentials (spec.registry_credentials, ADR-045) shared by every
+    // image pull path; `secret:` passwords are read through the secrets manager.
+    let registry_credential_resolver: Arc<
+        dyn aegis_orchestrator_core::infrastructure::image_manager::CredentialResolver,
+    > = Arc::new(
+        aegis_orchestrator_core::infrastructure::image_manager::SecretsCredentialResolver::new(
+            config.spec.registry_credentials.clone(),
+            secrets_manager.clone(),
+        ),
+    );
+
+    // Supply-chain gate (spec.image_verification): refuse unsigned agent images
+    // before container creation.
+    let image_verifier: Option<
+        Arc<dyn aegis_orchestrator_core::infrastructure::image_verifier::ImageSignatureVerifier>,
+    > = match config.spec.image_verification.clone() {
+        Some(verification) if verification.enabled => {
+            info!(
+                trust_roots = verification.trust_roots.len(),
+                require_trust_root = verification.require_trust_root,
+                "Image signature verification enabled"
+            );
+            Some(Arc::new(
+                aegis_orchestrator_core::infrastructure::image_verifier::CosignImageVerifier::new(
+                    verification,
                 ),
-            ),
-            fuse_daemon: fuse_daemon.clone(),
-            fuse_mount_prefix: fuse_mount_prefix.clone(),
-            fuse_mount_client: fuse_mount_client.clone(),
-        })
+            ))
+        }
+        _ => None,
+    };
+
+    let runtime = Arc::new(
+        ContainerRuntime::new(
+            aegis_orchestrator_core::infrastructure::runtime::ContainerRuntimeConfig {
+                bootstrap_script: config.spec.runtime.bootstrap_script.clone(),
+                socket_path: config.spec.runtime.container_socket_path.clone(),
+                endpoint: None,
+                network_mode: network_mode.clone(),
+                orchestrator_url: orchestrator_url.clone(),
+                nfs_server_host: nfs_server_host.clone(),
+                nfs_port: config.spec.runtime.nfs_port,
+                nfs_mountport: config.spec.runtime.nfs_mountport,
+                event_bus: event_bus.clone(),
+                credential_resolver: registry_credential_resolver.clone(),
+                image_verifier: image_verifier.clone(),
+                fuse_daemon: fuse_daemon.clone(),
+                fuse_mount_prefix: fuse_mount_prefix.clone(),
+                fuse_mount_client: fuse_mount_client.clone(),
+            },
+        )
         .await
         .context("Failed to initialize Docker runtime")?,
     );
@@ -867,11 +1103,121 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         );
     }
 
+    // Additional container engines (spec.runtime.endpoints). Remote engines
+    // cannot bind-mount this host's FUSE mountpoints, so they always use NFS
+    // volume mounts against `nfs_server_host`.
+    let mut container_runtimes = vec![runtime.clone()];
+    let mut placement_endpoints = vec![
+        aegis_orchestrator_core::infrastructure::runtime_placement::RuntimeEndpoint {
+            name: "local".to_string(),
+            runtime: runtime.clone(),
+        },
+    ];
+    for endpoint in &config.spec.runtime.endpoints {
+        let remote = Arc::new(
+            ContainerRuntime::new(
+                aegis_orchestrator_core::infrastructure::runtime::ContainerRuntimeConfig {
+                    bootstrap_script: config.spec.runtime.bootstrap_script.clone(),
+                    socket_path: None,
+                    endpoint: Some(endpoint.clone()),
+                    network_mode: network_mode.clone(),
+                    orchestrator_url: orchestrator_url.clone(),
+                    nfs_server_host: nfs_server_host.clone(),
+                    nfs_port: config.spec.runtime.nfs_port,
+                    nfs_mountport: config.spec.runtime.nfs_mountport,
+                    event_bus: event_bus.clone(),
+                    credential_resolver: registry_credential_resolver.clone(),
+                    image_verifier: image_verifier.clone(),
+                    fuse_daemon: None,
+                    fuse_mount_prefix: fuse_mount_prefix.clone(),
+                    fuse_mount_client: None,
+                },
+            )
+            .await
+            .with_context(|| {
+                format!(
+                    "Failed to initialize container endpoint '{}'",
+                    endpoint.name
+                )
+            })?,
+        );
+        remote
+            .healthcheck()
+            .await
+            .with_context(|| format!("Container endpoint '{}' is not reachable", endpoint.name))?;
+        info!(
+            endpoint = %endpoint.name,
+            platforms = ?remote.platforms(),
+            "Container endpoint connected"
+        );
+        container_runtimes.push(remote.clone());
+        placement_endpoints.push(
+            aegis_orchestrator_core::infrastructure::runtime_placement::RuntimeEndpoint {
+                name: endpoint.name.clone(),
+                runtime: remote,
+            },
+        );
+    }
+    let agent_runtime: Arc<dyn aegis_orchestrator_core::domain::runtime::AgentRuntime> =
+        if let Some(kubernetes) = config.spec.runtime.kubernetes.clone() {
+            Arc::new(
+                aegis_orchestrator_core::infrastructure::runtime_kubernetes::K8sRuntime::new(
+                    aegis_orchestrator_core::infrastructure::runtime_kubernetes::K8sRuntimeConfig {
+                        kubernetes,
+                        bootstrap_script: config.spec.runtime.bootstrap_script.clone(),
+                        orchestrator_url: orchestrator_url.clone(),
+                        nfs_server_host: nfs_server_host.clone(),
+                        nfs_port: config.spec.runtime.nfs_port,
+                        nfs_mountport: config.spec.runtime.nfs_mountport,
+                        image_verifier: image_verifier.clone(),
+                    },
+                )
+                .await
+                .context("Failed to initialize Kubernetes runtime")?,
+            )
+        } else if let Some(nomad) = config.spec.runtime.nomad.clone() {
+            Arc::new(
+                aegis_orchestrator_core::infrastructure::runtime_nomad::NomadRuntime::new(
+                    aegis_orchestrator_core::infrastructure::runtime_nomad::NomadDriverConfig {
+                        nomad,
+                        bootstrap_script: config.spec.runtime.bootstrap_script.clone(),
+                        orchestrator_url: orchestrator_url.clone(),
+                        nfs_server_host: nfs_server_host.clone(),
+                        nfs_port: config.spec.runtime.nfs_port,
+                        nfs_mountport: config.spec.runtime.nfs_mountport,
+                        image_verifier: image_verifier.clone(),
+                    },
+                )
+                .await
+                .context("Failed to initialize Nomad runtime")?,
+            )
+        } else if placement_endpoints.len() > 1 {
+            Arc::new(
+                aegis_orchestrator_core::infrastructure::runtime_placement::PlacementRuntime::new(
+                    placement_endpoints,
+                ),
+            )
+        } else {
+            runtime.clone()
+        };
+
+    let guidance_queue = Arc::new(
+        aegis_orchestrator_core::infrastructure::guidance_queue::InMemoryGuidanceQueue::new(),
+    );
+    // Create human input service — workflow approval gates and
+    // `approval_mode: per_iteration` agents share its pending-request list.
+    let human_input_service =
+        Arc::new(aegis_orchestrator_core::infrastructure::HumanInputService::new());
     let supervisor = Arc::new(
-        Supervisor::new(runtime.clone()).with_execution_repository(execution_repo.clone()),
+        Supervisor::new(agent_runtime)
+            .with_execution_repository(execution_repo.clone())
+            .with_guidance_queue(guidance_queue.clone())
+            .with_approval_gate(human_input_service.clone())
+            .with_iteration_tracker(Arc::new(nfs_gateway.volume_registry().clone()))
+            .with_storage_transport(nfs_gateway.clone()),
     );
 
-    let agent_container_reaper_runtime = runtime.clone();
+    let agent_container_reaper_runtimes = container_runtimes;
     let agent_container_reaper_execution_repo = execution_repo.clone();
     tokio::spawn(async move {
         let mut interval = tokio::time::interval(std::time::Duration::from_secs(300));
@@ -890,29 +1236,57 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
                 tracing::info!("Running startup orphan container cleanup");
                 first_run = false;
             }
-            match cleanup_orphaned_agent_containers(
-                agent_container_reaper_runtime.clone(),
-                agent_container_reaper_execution_repo.clone(),
-                &mut attempt_states,
-            )
-            .await
-            {
-                Ok(count) => {
-                    if count > 0 {
-                        tracing::info!(
-                            "Agent container cleanup: {} orphaned container(s) deleted",
-                            count
-                        );
+            for reaper_runtime in &agent_container_reaper_runtimes {
+                match cleanup_orphaned_agent_containers(
+                    reaper_runtime.clone(),
+                    agent_container_reaper_execution_repo.clone(),
+                    &mut attempt_states,
+                )
+                .await
+                {
+                    Ok(count) => {
+                        if count > 0 {
+                            tracing::info!(
+                                "Agent container cleanup: {} orphaned container(s) deleted",
+                                count
+                            );
+                        }
+                    }
+                    Err(e) => {
+                        tracing::error!("Agent container cleanup failed: {}", e);
                     }
-                }
-                Err(e) => {
-                    tracing::error!("Agent container cleanup failed: {}", e);
                 }
             }
         }
     });
     info!("Agent container cleanup background task spawned (interval: 5 minutes)");
 
+    // Node-local GC (spec.runtime.gc): exited containers and surplus built images.
+    let gc_config = config.spec.runtime.gc.clone();
+    if gc_config.enabled {
+        let gc_docker =
+            connect_container_runtime(config.spec.runtime.container_socket_path.as_deref())
+                .context("Failed to connect container runtime for runtime GC")?;
+        let interval_seconds = gc_config.interval_seconds;
+        let collector =
+            aegis_orchestrator_core::infrastructure::runtime_gc::RuntimeGarbageCollector::new(
+                gc_docker, gc_config,
+            );
+        tokio::spawn(async move {
+            let mut interval =
+                tokio::time::interval(std::time::Duration::from_secs(interval_seconds));
+            // Skip the immediate first tick; the orphan reaper owns startup cleanup.
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                if let Err(e) = collector.run(false).await {
+                    tracing::error!("Runtime GC pass failed: {}", e);
+                }
+            }
+        });
+        info!(interval_seconds, "Runtime GC background task spawned");
+    }
+
     // Initialize security context repository early — needed by StandardAgentLifecycleService (ADR-102).
     let security_context_repo: Arc<
         dyn aegis_orchestrator_core::domain::security_context::repository::SecurityContextRepository,
@@ -936,6 +1310,7 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
                         max_response_size: None,
                         rate_limit: None,
                         max_concurrent: None,
+                        conditions: cap.conditions.clone(),
                     })
                     .collect();
 
@@ -960,11 +1335,27 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         Arc::new(repo)
     };
 
-    let agent_service = Arc::new(StandardAgentLifecycleService::new(
+    let feature_flags = Arc::new(
+        aegis_orchestrator_core::application::feature_flags::FeatureFlagService::new(
+            config.spec.feature_flags.clone().unwrap_or_default(),
+        ),
+    );
+
+    let mut agent_service_builder = StandardAgentLifecycleService::new(
         agent_repo.clone(),
         event_bus.clone(),
         security_context_repo.clone(),
-    ));
+    )
+    .with_deploy_gates(
+        aegis_orchestrator_core::domain::deploy_gate::DeployGates::from_config(
+            &config.spec.deploy_gates.clone().unwrap_or_default(),
+        ),
+    )
+    .with_feature_flags(feature_flags.clone());
+    if let Some(quota_service) = enforced_quota_service.clone() {
+        agent_service_builder = agent_service_builder.with_quota_service(quota_service);
+    }
+    let agent_service = Arc::new(agent_service_builder);
 
     // Load StandardRuntime registry (ADR-043 / ADR-060)
     // Try database-backed merged registry first, fall back to file-based.
@@ -1129,6 +1520,12 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             temporal_connection_max_retries,
         )
         .await?;
+        #[cfg(feature = "fault-injection")]
+        let client = Arc::new(
+            (*client)
+                .clone()
+                .with_fault_injector(fault_injector.clone()),
+        );
 
         let mut lock = temporal_client_container_clone.write().await;
         *lock = Some(client.clone());
@@ -1190,7 +1587,10 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         aegis_orchestrator_core::infrastructure::seal::middleware::SealMiddleware::with_rate_limiting(
             rate_limit_enforcer.clone(),
             rate_limit_resolver.clone(),
-        ),
+        )
+        .with_stream_signer(Arc::new(
+            aegis_orchestrator_core::infrastructure::seal::stream::SealStreamSigner::generate(),
+        )),
     );
     let tool_registry =
         Arc::new(aegis_orchestrator_core::infrastructure::tool_router::InMemoryToolRegistry::new());
@@ -1417,6 +1817,32 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             execution_service_builder.with_rate_limiting(enforcer.clone(), resolver.clone());
     }
 
+    if let Some(quota_service) = enforced_quota_service.clone() {
+        execution_service_builder = execution_service_builder.with_quota_service(quota_service);
+    }
+
+    // Enforce `spec.concurrency` groups for agents and workflows on the shared lock service.
+    let concurrency_groups = Arc::new(
+        ConcurrencyGroupService::new(
+            lock_service.clone(),
+            execution_repo.clone(),
+            workflow_execution_repo.clone(),
+        )
+        .with_workflow_control(Arc::new(DaemonWorkflowExecutionControl {
+            config: config.clone(),
+            temporal_client_container: temporal_client_container.clone(),
+        })),
+    );
+    concurrency_groups.spawn_release_listener(&event_bus);
+    execution_service_builder =
+        execution_service_builder.with_concurrency_groups(concurrency_groups.clone());
+
+    // `aegis daemon cordon`: rejects new executions and tracks queued ones.
+    let node_maintenance =
+        Arc::new(aegis_orchestrator_core::application::cluster::NodeMaintenanceService::new());
+    execution_service_builder =
+        execution_service_builder.with_node_maintenance(node_maintenance.clone());
+
     // Wire swarm cascade cancellation so parent execution cancel propagates to child swarms (BC-6).
     execution_service_builder = execution_service_builder
         .with_swarm_cancellation(swarm_service.clone()
@@ -1428,9 +1854,237 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
     execution_service_builder = execution_service_builder
         .with_seal_session_precreation(seal_gateway_client, token_issuer.clone());
 
+    // Build per-agent-version runtime images for manifests declaring
+    // `spec.runtime.packages`; base-image pulls reuse the registry credential resolver.
+    let docker_for_image_builds =
+        connect_container_runtime(config.spec.runtime.container_socket_path.as_deref())
+            .context("Failed to connect container runtime for runtime image builds")?;
+    execution_service_builder = execution_service_builder.with_runtime_image_builder(Arc::new(
+        aegis_orchestrator_core::infrastructure::runtime_image_builder::DockerRuntimeImageBuilder::new(
+            docker_for_image_builds,
+            registry_credential_resolver.clone(),
+        ),
+    ));
+
+    // Resolve `spec.runtime.env` secretRefs from the tenant's secrets namespace (ADR-034).
+    execution_service_builder =
+        execution_service_builder.with_secrets_manager(secrets_manager.clone());
+
+    // Outbound webhook attempt log and dead letters behind
+    // `/v1/outbound-webhooks/*`.
+    let webhook_delivery_repo: Option<
+        Arc<dyn aegis_orchestrator_core::domain::outbound_webhook::WebhookDeliveryRepository>,
+    > = db_pool.as_ref().map(|pool| {
+        Arc::new(
+            aegis_orchestrator_core::infrastructure::repositories::PostgresWebhookDeliveryRepository::new(
+                pool.clone(),
+            ),
+        ) as Arc<dyn aegis_orchestrator_core::domain::outbound_webhook::WebhookDeliveryRepository>
+    });
+
+    // Per-call token usage accounting behind `/v1/usage`, plus the nightly
+    // job that folds each finished UTC day into `llm_token_usage_daily`.
+    let token_usage_repo: Option<
+        Arc<dyn aegis_orchestrator_core::domain::token_usage::TokenUsageRepository>,
+    > = db_pool.as_ref().map(|pool| {
+        Arc::new(
+            aegis_orchestrator_core::infrastructure::repositories::PostgresTokenUsageRepository::new(
+                pool.clone(),
+            ),
+        ) as Arc<dyn aegis_orchestrator_core::domain::token_usage::TokenUsageRepository>
+    });
+    if let Some(repo) = token_usage_repo.clone() {
+        execution_service_builder =
+            execution_service_builder.with_token_usage_repository(repo.clone());
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+            let mut last_aggregated: Option<chrono::NaiveDate> = None;
+            loop {
+                interval.tick().await;
+                // Hourly check so a restart never skips a day; each day is
+                // aggregated once, after it has ended.
+                let yesterday = chrono::Utc::now().date_naive() - chrono::Duration::days(1);
+                if last_aggregated == Some(yesterday) {
+                    continue;
+                }
+                match repo.aggregate_day(yesterday).await {
+                    Ok(rows) => {
+                        info!(day = %yesterday, rows, "Token usage daily aggregation complete");
+                        last_aggregated = Some(yesterday);
+                    }
+                    Err(e) => tracing::error!("Token usage daily aggregation failed: {}", e),
+                }
+            }
+        });
+        info!("Token usage daily aggregation background task spawned");
+    }
+
+    // Monthly per-tenant usage summaries behind `/v1/reports/usage`. The
+    // hourly job keeps the current month to date and closes out the
+    // previous month once after it ends.
+    let usage_report_repo: Option<
+        Arc<dyn aegis_orchestrator_core::domain::usage_report::UsageReportRepository>,
+    > = db_pool.as_ref().map(|pool| {
+        Arc::new(
+            aegis_orchestrator_core::infrastructure::repositories::PostgresUsageReportRepository::new(
+                pool.clone(),
+            ),
+        ) as Arc<dyn aegis_orchestrator_core::domain::usage_report::UsageReportRepository>
+    });
+    if let Some(repo) = usage_report_repo.clone() {
+        tokio::spawn(async move {
+            use aegis_orchestrator_core::domain::usage_report::month_of;
+            let mut interval = tokio::time::interval(std::time::Duration::from_secs(3600));
+            let mut closed_month: Option<chrono::NaiveDate> = None;
+            loop {
+                interval.tick().await;
+                let current = month_of(chrono::Utc::now().date_naive());
+                let previous = month_of(current - chrono::Duration::days(1));
+                if closed_month != Some(previous) {
+                    match repo.aggregate_month(previous).await {
+                        Ok(rows) => {
+                            info!(month = %previous, rows, "Monthly usage rollup closed");
+                            closed_month = Some(previous);
+                        }
+                        Err(e) => tracing::error!("Monthly usage rollup failed: {}", e),
+                    }
+                }
+                if let Err(e) = repo.aggregate_month(current).await {
+                    tracing::error!("Monthly usage rollup failed: {}", e);
+                }
+            }
+        });
+        info!("Monthly usage rollup background task spawned");
+    }
+
+    execution_service_builder = execution_service_builder.with_feature_flags(feature_flags.clone());
+
+    // Flows recorded for executions that request network flow capture,
+    // served by `/v1/executions/{id}/network-flows`.
+    let network_flow_repo: Arc<
+        dyn aegis_orchestrator_core::domain::network_flow::NetworkFlowRepository,
+    > = if let Some(pool) = db_pool.as_ref() {
+        Arc::new(
+            aegis_orchestrator_core::infrastructure::repositories::PostgresNetworkFlowRepository::new(
+                pool.clone(),
+            ),
+        )
+    } else {
+        Arc::new(
+            aegis_orchestrator_core::infrastructure::repositories::InMemoryNetworkFlowRepository::new(),
+        )
+    };
+    execution_service_builder =
+        execution_service_builder.with_network_flow_repository(network_flow_repo.clone());
+    if config.spec.network_flow_capture.is_some() {
+        info!("Network flow capture available to tenants with the network_flow_capture flag");
+    }
+
+    // Shared execution queue: `start_execution` enqueues and every daemon
+    // configured to consume claims from the same backend.
+    let execution_queue: Option<(
+        Arc<dyn aegis_orchestrator_core::domain::task_queue::TaskQueue>,
+        aegis_orchestrator_core::domain::node_config::ExecutionQueueConfig,
+    )> = match config.spec.execution_queue.as_ref() {
+        Some(queue_config) => {
+            use aegis_orchestrator_core::domain::node_config::ExecutionQueueBackend;
+            let queue: Arc<dyn aegis_orchestrator_core::domain::task_queue::TaskQueue> =
+                match queue_config.backend {
+                    ExecutionQueueBackend::Postgres => {
+                        let pool = db_pool.as_ref().ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "spec.execution_queue requires a reachable spec.database"
+                            )
+                        })?;
+                        Arc::new(
+                            aegis_orchestrator_core::infrastructure::task_queue::PostgresTaskQueue::new(
+                                pool.clone(),
+                                queue_config.visibility_timeout(),
+                            ),
+                        )
+                    }
+                    #[cfg(feature = "redis-task-queue")]
+                    ExecutionQueueBackend::Redis => {
+                        let url = queue_config.redis_url.as_deref().ok_or_else(|| {
+                            anyhow::anyhow!(
+                                "spec.execution_queue.redis_url is required for the redis backend"
+                            )
+                        })?;
+                        Arc::new(
+                            aegis_orchestrator_core::infrastructure::task_queue::RedisTaskQueue::connect(
+                                &resolve_env_value(url)?,
+                                queue_config.redis_stream.clone(),
+                                queue_config.visibility_timeout(),
+                            )
+                            .await
+                            .context("Failed to connect to the execution queue Redis")?,
+                        )
+                    }
+                    #[cfg(not(feature = "redis-task-queue"))]
+                    ExecutionQueueBackend::Redis => {
+                        anyhow::bail!(
+                            "spec.execution_queue.backend is redis but this build lacks the redis-task-queue feature"
+                        );
+                    }
+                };
+            execution_service_builder = execution_service_builder.with_task_queue(queue.clone());
+            info!(
+                backend = queue.backend(),
+                consume = queue_config.consume,
+                "Execution queue enabled"
+            );
+            Some((queue, queue_config.clone()))
+        }
+        None => None,
+    };
+
+    // WASM plugins for `type: plugin` validators and `condition: plugin`
+    // transitions, installed through `/v1/plugins`.
+    let plugin_host: Option<Arc<dyn aegis_orchestrator_core::domain::plugin::PluginHost>> =
+        match config.spec.plugins.as_ref() {
+            #[cfg(feature = "wasm-plugins")]
+            Some(plugins_config) => {
+                let host: Arc<dyn aegis_orchestrator_core::domain::plugin::PluginHost> = Arc::new(
+                    aegis_orchestrator_core::infrastructure::wasm_plugin_host::WasmPluginHost::new(
+                        plugins_config,
+                    )
+                    .context("Failed to initialize the WASM plugin host")?,
+                );
+                execution_service_builder =
+                    execution_service_builder.with_plugin_host(host.clone());
+                info!(directory = %plugins_config.directory, "WASM plugins enabled");
+                Some(host)
+            }
+            #[cfg(not(feature = "wasm-plugins"))]
+            Some(_) => {
+                anyhow::bail!("spec.plugins is set but this build lacks the wasm-plugins feature");
+            }
+            None => None,
+        };
+
     let execution_service = Arc::new(execution_service_builder);
     // Wire the self-reference so judge agents can be spawned as child executions (ADR-016).
     execution_service.set_child_execution_service(execution_service.clone());
+    concurrency_groups.set_execution_service(execution_service.clone());
+
+    // Kept alive for the daemon's lifetime; dropping it stops the worker.
+    let _execution_queue_shutdown = execution_queue
+        .filter(|(_, queue_config)| queue_config.consume)
+        .map(|(queue, queue_config)| {
+            let worker =
+                aegis_orchestrator_core::application::execution_queue::ExecutionQueueWorker::new(
+                    queue,
+                    execution_service.clone(),
+                    queue_config,
+                    config.spec.node.id.clone(),
+                )
+                .with_node_maintenance(node_maintenance.clone());
+            let (shutdown_tx, shutdown_rx) = tokio::sync::watch::channel(false);
+            tokio::spawn(async move {
+                worker.run(shutdown_rx).await;
+            });
+            shutdown_tx
+        });
 
     let validation_service = Arc::new(ValidationService::new(
         event_bus.clone(),
@@ -1438,19 +2092,61 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         agent_service.clone(),
     ));
 
-    // Create human input service
-    let human_input_service =
-        Arc::new(aegis_orchestrator_core::infrastructure::HumanInputService::new());
+    let agent_healthcheck_service = Arc::new(
+        aegis_orchestrator_core::application::agent_healthcheck::AgentHealthCheckService::new(
+            agent_repo.clone(),
+            execution_service.clone(),
+        ),
+    );
+
+    let bulk_execution_service = Arc::new(
+        aegis_orchestrator_core::application::bulk_execution::BulkExecutionService::new(
+            execution_service.clone(),
+            execution_repo.clone(),
+        ),
+    );
 
     // Legacy WorkflowEngine removed as part of Temporal migration
 
-    let temporal_event_listener = Arc::new(TemporalEventListener::new(
-        event_bus.clone(),
-        workflow_execution_repo.clone(),
-    ));
+    let temporal_event_listener = Arc::new(
+        TemporalEventListener::new(event_bus.clone(), workflow_execution_repo.clone())
+            .with_workflow_repository(workflow_repo.clone()),
+    );
 
     info!("Temporal event listener initialized");
 
+    // Stuck-state detection for running workflow executions (spec.workflow_watchdog).
+    let watchdog_config = config.spec.workflow_watchdog.clone();
+    let workflow_watchdog = Arc::new(
+        aegis_orchestrator_core::application::workflow_watchdog::WorkflowWatchdog::new(
+            workflow_execution_repo.clone(),
+            workflow_repo.clone(),
+            event_bus.clone(),
+            watchdog_config.clone().unwrap_or_default(),
+        )
+        .with_workflow_control(Arc::new(DaemonWorkflowExecutionControl {
+            config: config.clone(),
+            temporal_client_container: temporal_client_container.clone(),
+        })),
+    );
+    if let Some(watchdog_config) = watchdog_config.filter(|c| c.enabled) {
+        let watchdog = workflow_watchdog.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(watchdog.check_interval());
+            loop {
+                interval.tick().await;
+                if let Err(e) = watchdog.sweep().await {
+                    tracing::error!("Workflow watchdog sweep failed: {}", e);
+                }
+            }
+        });
+        info!(
+            check_interval_seconds = watchdog_config.check_interval_seconds,
+            on_stuck = watchdog_config.on_stuck.as_str(),
+            "Workflow watchdog background task spawned"
+        );
+    }
+
     let register_workflow_use_case = Arc::new(StandardRegisterWorkflowUseCase::new(
         workflow_repo.clone(),
         workflow_engine_container.clone(),
@@ -1470,7 +2166,7 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         {
             uc = uc.with_rate_limiting(enforcer.clone(), resolver.clone());
         }
-        Arc::new(uc)
+        Arc::new(uc.with_concurrency_groups(concurrency_groups.clone()))
     };
 
     // --- Initialize SEAL / Tool Routing Services ---
@@ -1484,6 +2180,10 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         aegis_orchestrator_core::infrastructure::seal::session_repository::InMemorySealSessionRepository::new(),
     );
 
+    let payload_keys = Arc::new(
+        aegis_orchestrator_core::infrastructure::seal::payload_crypto::PayloadKeyRegistry::new(),
+    );
+
     // Application Services — token_issuer was created earlier (ADR-088 §A8) and shared with ExecutionService.
     let mut attestation_service_builder =
         aegis_orchestrator_core::application::attestation_service::AttestationServiceImpl::new(
@@ -1492,7 +2192,8 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             token_issuer,
         )
         .with_gateway_client(attestation_gateway_client)
-        .with_agent_manifest_tools(execution_service.clone(), agent_service.clone());
+        .with_agent_manifest_tools(execution_service.clone(), agent_service.clone())
+        .with_payload_keys(payload_keys.clone());
 
     match aegis_orchestrator_core::infrastructure::docker::BollardContainerVerifier::new(
         config.spec.runtime.container_socket_path.as_deref(),
@@ -1510,32 +2211,6 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         dyn aegis_orchestrator_core::infrastructure::seal::attestation::AttestationService,
     > = Arc::new(attestation_service_builder);
 
-    // Secrets manager: initialize from `spec.secrets.backend`, otherwise use an in-memory store for local development/testing.
-    let secrets_manager: Arc<aegis_orchestrator_core::infrastructure::secrets_manager::SecretsManager> =
-        match config.spec.secrets.as_ref().and_then(|s| s.backend.as_ref()) {
-            Some(secret_backend_config) => {
-                match aegis_orchestrator_core::infrastructure::secrets_manager::SecretsManager::from_config(
-                    secret_backend_config,
-                    event_bus.clone(),
-                ).await {
-                    Ok(manager) => {
-                        info!("OpenBao secrets manager initialized");
-                        Arc::new(manager)
-                    }
-                    Err(e) => {
-                        return Err(anyhow::anyhow!("Failed to initialize OpenBao secrets manager: {e}"));
-                    }
-                }
-            }
-            None => {
-                warn!("No spec.secrets.backend configured; using in-memory secret store (development/testing only, not production-safe)");
-                Arc::new(aegis_orchestrator_core::infrastructure::secrets_manager::SecretsManager::from_store(
-                    Arc::new(aegis_orchestrator_core::infrastructure::secrets_manager::TestSecretStore::new()),
-                    event_bus.clone(),
-                ))
-            }
-        };
-
     // ─── Credential Management Service (BC-11, ADR-078) ────────────────────────
     let credential_service: Option<Arc<dyn CredentialManagementService>> = match db_pool.as_ref() {
         None => None,
@@ -1571,19 +2246,12 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
     let docker_for_steps =
         connect_container_runtime(config.spec.runtime.container_socket_path.as_deref())
             .context("Failed to connect container runtime for ContainerStepRunner (ADR-050)")?;
-    let step_credential_resolver: Arc<
-        dyn aegis_orchestrator_core::infrastructure::image_manager::CredentialResolver,
-    > = Arc::new(
-        aegis_orchestrator_core::infrastructure::image_manager::NodeConfigCredentialResolver::new(
-            config.spec.registry_credentials.clone(),
-        ),
-    );
     let step_image_manager: Arc<
         dyn aegis_orchestrator_core::infrastructure::image_manager::DockerImageManager,
     > = Arc::new(
         aegis_orchestrator_core::infrastructure::image_manager::StandardDockerImageManager::new(
             docker_for_steps.clone(),
-            step_credential_resolver,
+            registry_credential_resolver.clone(),
         ),
     );
     let container_step_runner: Arc<
@@ -1637,6 +2305,20 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             nfs_gateway.fsal().clone(),
         ),
     );
+    let volume_search_service = Arc::new(
+        aegis_orchestrator_core::application::volume_search_service::VolumeSearchService::new(
+            nfs_gateway.fsal().clone(),
+        ),
+    );
+    let mut policy_simulation_service =
+        aegis_orchestrator_core::application::policy_simulation::PolicySimulationService::new(
+            security_context_repo.clone(),
+        );
+    if let (Some(ref enforcer), Some(ref resolver)) = (&rate_limit_enforcer, &rate_limit_resolver) {
+        policy_simulation_service =
+            policy_simulation_service.with_rate_limits(enforcer.clone(), resolver.clone());
+    }
+    let policy_simulation_service = Arc::new(policy_simulation_service);
 
     let mut tool_invocation_service_builder =
         aegis_orchestrator_core::application::tool_invocation_service::ToolInvocationService::new(
@@ -1684,6 +2366,16 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             tool_invocation_service_builder.with_discovery_service(disc_svc.clone());
     }
 
+    // Tool result cache for repeated identical calls (spec.tool_cache).
+    if let Some(cache_config) = config.spec.tool_cache.clone().filter(|cache| cache.enabled) {
+        tool_invocation_service_builder =
+            tool_invocation_service_builder.with_result_cache(Arc::new(
+                aegis_orchestrator_core::application::tool_invocation_service::ToolResultCache::new(
+                    cache_config,
+                ),
+            ));
+    }
+
     // ADR-117: build edge components and wire the four-step pre-routing hook
     // when a Postgres pool is available. The same components feed the
     // `/v1/edge/*` REST surface (constructed below) — we collect them here
@@ -1772,13 +2464,33 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             aegis_orchestrator_core::application::inner_loop_service::InnerLoopService::new(
                 tool_invocation_service.clone(),
                 execution_service.clone(),
-                llm_registry,
+                llm_registry.clone(),
             );
         if let (Some(ref enforcer), Some(ref resolver)) =
             (&rate_limit_enforcer, &rate_limit_resolver)
         {
             ils = ils.with_rate_limiting(enforcer.clone(), resolver.clone());
         }
+        ils = ils.with_feature_flags(feature_flags.clone());
+        if let Some(routing) = config
+            .spec
+            .llm_selection
+            .routing
+            .clone()
+            .filter(|r| r.enabled)
+        {
+            info!(rules = routing.rules.len(), "Model routing enabled");
+            ils = ils.with_model_router(Arc::new(
+                aegis_orchestrator_core::application::model_router::ModelRouter::new(
+                    routing,
+                    Arc::new(
+                        aegis_orchestrator_core::domain::model_routing::HeuristicTaskClassifier,
+                    ),
+                    &llm_registry,
+                    event_bus.clone(),
+                ),
+            ));
+        }
         Arc::new(ils)
     };
 
@@ -2261,6 +2973,63 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             },
         );
 
+    // Shared dispatcher for calls to user-provided URLs: signs payloads,
+    // retries with backoff and logs every attempt.
+    let webhook_dispatcher = Arc::new(
+        aegis_orchestrator_core::infrastructure::outbound_webhook::OutboundWebhookDispatcher::new(
+            Arc::new(
+                aegis_orchestrator_core::infrastructure::outbound_webhook::EnvOutboundWebhookSecretProvider,
+            ),
+            webhook_delivery_repo.clone(),
+        ),
+    );
+
+    // Files uploaded with a dispatch (multipart execute, inbound messages).
+    let attachment_store = Arc::new(
+        aegis_orchestrator_core::application::attachment_store::AttachmentStore::new(
+            volume_service.clone(),
+            file_operations_service.clone(),
+        ),
+    );
+
+    // Email/chat ingestion (spec.ingestion): IMAP pollers run in the
+    // background; the Slack receiver is served over HTTP.
+    let ingestion_config = config.spec.ingestion.clone();
+    let ingestion_service = ingestion_config.as_ref().map(|ingestion| {
+        Arc::new(
+            aegis_orchestrator_core::application::ingestion::IngestionService::new(
+                execution_service.clone(),
+                attachment_store.clone(),
+                webhook_dispatcher.clone(),
+                ingestion,
+            ),
+        )
+    });
+    let mut slack_ingestion = None;
+    if let (Some(service), Some(ingestion)) = (&ingestion_service, &ingestion_config) {
+        use aegis_orchestrator_core::infrastructure::ingestion::{
+            ImapPoller, SlackIngestionAdapter,
+        };
+        for imap in &ingestion.imap {
+            match ImapPoller::new(imap, service.clone()) {
+                Ok(poller) => {
+                    tokio::spawn(poller.run());
+                }
+                Err(e) => tracing::error!(source = %imap.name, "IMAP ingestion disabled: {e}"),
+            }
+        }
+        if let Some(slack) = &ingestion.slack {
+            match SlackIngestionAdapter::new(slack, ingestion.max_attachment_bytes, service.clone())
+            {
+                Ok(adapter) => {
+                    info!(source = %slack.name, "Slack ingestion enabled");
+                    slack_ingestion = Some(Arc::new(adapter));
+                }
+                Err(e) => tracing::error!(source = %slack.name, "Slack ingestion disabled: {e}"),
+            }
+        }
+    }
+
     let app_state = AppState {
         agent_service: agent_service.clone(),
         execution_service: execution_service.clone(),
@@ -2283,6 +3052,7 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         agent_scope_service,
         temporal_client_container: temporal_client_container.clone(),
         storage_event_repo: storage_event_repo.clone(),
+        network_flow_repo,
         tool_invocation_service: tool_invocation_service.clone(),
         attestation_service: attestation_service.clone(),
         swarm_service: swarm_service.clone(),
@@ -2309,9 +3079,16 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
         stimulus_service: None,
         user_volume_service,
         file_operations_service,
+        attachment_store,
+        volume_search_service,
+        policy_simulation_service,
+        feature_flags,
+        ingestion_service,
+        slack_ingestion,
         git_repo_service,
         canvas_service,
         script_service,
+        plugin_host,
         team_service,
         team_repo: team_repo_opt.clone(),
         membership_repo: membership_repo_opt.clone(),
@@ -2339,6 +3116,18 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
             .as_ref()
             .and_then(|cfg| resolve_env_value(&cfg.internal_secret).ok()),
         edge_api: edge_api_state,
+        token_usage_repo,
+        usage_report_repo,
+        quota_service,
+        webhook_delivery_repo: webhook_delivery_repo.clone(),
+        payload_keys,
+        guidance_queue,
+        agent_healthcheck_service,
+        bulk_execution_service,
+        node_maintenance: node_maintenance.clone(),
+        workflow_watchdog,
+        #[cfg(feature = "fault-injection")]
+        fault_injector,
     };
 
     info!("Building router...");
@@ -2388,15 +3177,32 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
     let volume_service_for_grpc: Arc<
         dyn aegis_orchestrator_core::application::volume_manager::VolumeService,
     > = volume_service.clone();
-    let output_handler_service: Arc<
-        dyn aegis_orchestrator_core::application::output_handler_service::OutputHandlerService,
-    > = Arc::new(
+    let mut output_handler_builder =
         aegis_orchestrator_core::application::output_handler_service::StandardOutputHandlerService::new(
             execution_service.clone(),
             agent_service.clone(),
             event_bus.clone(),
-        ),
-    );
+        )
+        .with_webhook_dispatcher(webhook_dispatcher);
+    // Flagged outputs wait in the same pending-request list as workflow
+    // approval gates.
+    if let Some(moderation) = config.spec.moderation.as_ref() {
+        let providers =
+            aegis_orchestrator_core::infrastructure::moderation::build_moderation_providers(
+                moderation,
+            )
+            .context("Invalid spec.moderation")?;
+        info!(providers = providers.len(), "Output moderation enabled");
+        output_handler_builder = output_handler_builder.with_moderation(Arc::new(
+            aegis_orchestrator_core::application::output_moderation::OutputModerationService::new(
+                providers, moderation,
+            )
+            .with_approvals(human_input_service.clone()),
+        ));
+    }
+    let output_handler_service: Arc<
+        dyn aegis_orchestrator_core::application::output_handler_service::OutputHandlerService,
+    > = Arc::new(output_handler_builder);
     let grpc_auth = match (&iam_service, config.spec.grpc_auth.clone()) {
         (Some(iam), Some(grpc_auth)) if grpc_auth.enabled => Some(
             aegis_orchestrator_core::presentation::grpc::auth_interceptor::GrpcIamAuthInterceptor::new(
@@ -2562,6 +3368,7 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
                     cluster_repo.clone(),
                 ),
             );
+            node_maintenance.set_cluster_registry(cluster_repo.clone(), controller_node_id);
             let router: Arc<dyn aegis_orchestrator_core::domain::cluster::NodeRouter> =
                 Arc::new(RoundRobinNodeRouter::new());
             let route_uc = Arc::new(
@@ -2947,6 +3754,7 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
                                         token_refresh_margin,
                                         signing_key,
                                         enrolment_token,
+                                        node_maintenance.clone(),
                                     );
 
                                     let (shutdown_tx, shutdown_rx) =
@@ -3175,11 +3983,24 @@ pub async fn start_daemon(config_path: Option<PathBuf>, port: u16) -> Result<()>
     info!(address = %addr, "Daemon listening");
 
     axum::serve(listener, app)
-        .with_graceful_shutdown(shutdown_signal())
+        .with_graceful_shutdown(async move {
+            tokio::select! {
+                _ = shutdown_signal() => {}
+                Ok(lost) = lease_lost_rx => {
+                    error!(
+                        reason = ?lost,
+                        "Lost the failover lease; shutting down so only one daemon is active"
+                    );
+                }
+            }
+        })
         .await
         .context("HTTP server failed")?;
 
     info!("Daemon shutting down");
+    if let Some(failover) = &failover {
+        failover.release().await;
+    }
 
     Ok(())
 }
@@ -6,12 +6,15 @@ use std::sync::Arc;
 
 use aegis_orchestrator_core::{
     application::{
-        canvas_service::CanvasService, credential_service::CredentialManagementService,
-        execution::StandardExecutionService, file_operations_service::FileOperationsService,
-        lifecycle::StandardAgentLifecycleService,
+        attachment_store::AttachmentStore, canvas_service::CanvasService,
+        credential_service::CredentialManagementService, execution::StandardExecutionService,
+        feature_flags::FeatureFlagService, file_operations_service::FileOperationsService,
+        ingestion::IngestionService, lifecycle::StandardAgentLifecycleService,
+        policy_simulation::PolicySimulationService,
         register_workflow::StandardRegisterWorkflowUseCase,
         start_workflow_execution::StandardStartWorkflowExecutionUseCase, stimulus::StimulusService,
-        user_volume_service::UserVolumeService, CorrelatedActivityStreamService,
+        user_volume_service::UserVolumeService, volume_search_service::VolumeSearchService,
+        CorrelatedActivityStreamService,
     },
     domain::{
         cluster::NodeClusterRepository,
@@ -24,7 +27,8 @@ use aegis_orchestrator_core::{
     },
     infrastructure::{
         event_bus::EventBus, iam::keycloak_admin_client::KeycloakAdminClient,
-        secrets_manager::SecretsManager, temporal_client::TemporalClient, TemporalEventListener,
+        ingestion::SlackIngestionAdapter, secrets_manager::SecretsManager,
+        temporal_client::TemporalClient, TemporalEventListener,
     },
     presentation::webhook_guard::WebhookSecretProvider,
 };
@@ -55,6 +59,9 @@ pub(crate) struct AppState {
         Arc<aegis_orchestrator_core::application::agent_scope::AgentScopeService>,
     pub(crate) temporal_client_container: Arc<tokio::sync::RwLock<Option<Arc<TemporalClient>>>>,
     pub(crate) storage_event_repo: Arc<dyn StorageEventRepository>,
+    /// Flows recorded by per-execution network flow capture.
+    pub(crate) network_flow_repo:
+        Arc<dyn aegis_orchestrator_core::domain::network_flow::NetworkFlowRepository>,
     pub(crate) tool_invocation_service:
         Arc<aegis_orchestrator_core::application::tool_invocation_service::ToolInvocationService>,
     pub(crate) attestation_service:
@@ -86,6 +93,20 @@ pub(crate) struct AppState {
     pub(crate) stimulus_service: Option<Arc<dyn StimulusService>>,
     pub(crate) user_volume_service: Arc<UserVolumeService>,
     pub(crate) file_operations_service: Arc<FileOperationsService>,
+    /// Stores files uploaded with `POST /v1/agents/{id}/execute`.
+    pub(crate) attachment_store: Arc<AttachmentStore>,
+    /// Filename index and bounded grep behind `/v1/volumes/search` and
+    /// `/v1/volumes/grep`.
+    pub(crate) volume_search_service: Arc<VolumeSearchService>,
+    /// Dry-run evaluation behind `/v1/security-contexts/{name}/simulate`.
+    pub(crate) policy_simulation_service: Arc<PolicySimulationService>,
+    /// Static and runtime feature flags; overrides via `/v1/admin/feature-flags`.
+    pub(crate) feature_flags: Arc<FeatureFlagService>,
+    /// Email/chat message ingestion (`spec.ingestion`). `None` when unconfigured.
+    pub(crate) ingestion_service: Option<Arc<IngestionService>>,
+    /// Receiver behind `/v1/webhooks/slack/events`. `None` unless
+    /// `spec.ingestion.slack` is set.
+    pub(crate) slack_ingestion: Option<Arc<SlackIngestionAdapter>>,
     /// BC-7 Git Repository Binding service (ADR-081). Optional until
     /// the surrounding infrastructure (git2-backed executor, volume
     /// service, OpenBao) is wired in at startup.
@@ -98,6 +119,8 @@ pub(crate) struct AppState {
     /// Postgres pool is available and migration 021 has been applied.
     pub(crate) script_service:
         Option<Arc<aegis_orchestrator_core::application::script_service::ScriptService>>,
+    /// WASM plugin host. `None` unless `spec.plugins` is configured.
+    pub(crate) plugin_host: Option<Arc<dyn aegis_orchestrator_core::domain::plugin::PluginHost>>,
     /// Team tenancy service (ADR-111). Optional until a Postgres pool, a
     /// `BillingConfig`, and an `invitation_hmac_key` are all configured.
     #[allow(dead_code)] // handlers land in Phase 2
@@ -142,4 +165,46 @@ pub(crate) struct AppState {
     /// dispatcher hooks. `None` on deployments where a Postgres pool is not
     /// available or the controller is configured without edge enrollment.
     pub(crate) edge_api: Option<aegis_orchestrator_core::api::rest::edge::EdgeApiState>,
+    /// Token usage store backing `/v1/usage`. `None` without a Postgres pool.
+    pub(crate) token_usage_repo:
+        Option<Arc<dyn aegis_orchestrator_core::domain::token_usage::TokenUsageRepository>>,
+    /// Monthly per-tenant summaries behind `/v1/reports/usage`. `None`
+    /// without a Postgres pool.
+    pub(crate) usage_report_repo:
+        Option<Arc<dyn aegis_orchestrator_core::domain::usage_report::UsageReportRepository>>,
+    /// Tenant quota limits and usage behind `/v1/quotas`. `None` without a
+    /// Postgres pool.
+    pub(crate) quota_service:
+        Option<Arc<aegis_orchestrator_core::application::tenant_quota::TenantQuotaService>>,
+    /// Outbound webhook attempt log and dead letters behind
+    /// `/v1/outbound-webhooks/*`. `None` without a Postgres pool.
+    pub(crate) webhook_delivery_repo: Option<
+        Arc<dyn aegis_orchestrator_core::domain::outbound_webhook::WebhookDeliveryRepository>,
+    >,
+    /// Per-execution payload encryption keys negotiated during SEAL
+    /// attestation; the dispatch gateway decrypts prompts and encrypts
+    /// final outputs with them.
+    pub(crate) payload_keys:
+        Arc<aegis_orchestrator_core::infrastructure::seal::payload_crypto::PayloadKeyRegistry>,
+    /// Operator guidance queued by `POST /v1/executions/:id/guidance`,
+    /// drained by the supervisor before each iteration.
+    pub(crate) guidance_queue: Arc<dyn aegis_orchestrator_core::domain::guidance::GuidanceQueue>,
+    /// Runs `spec.healthcheck` smoke tests for `POST /v1/agents/:id/verify`.
+    pub(crate) agent_healthcheck_service:
+        Arc<aegis_orchestrator_core::application::agent_healthcheck::AgentHealthCheckService>,
+    /// Bulk cancel / requeue behind `/v1/executions/bulk/*`.
+    pub(crate) bulk_execution_service:
+        Arc<aegis_orchestrator_core::application::bulk_execution::BulkExecutionService>,
+    /// Cordon state for `aegis daemon cordon` / `uncordon`; shared with the
+    /// execution service, which rejects new executions while cordoned.
+    pub(crate) node_maintenance:
+        Arc<aegis_orchestrator_core::application::cluster::NodeMaintenanceService>,
+    /// Stuck-state detection behind `GET /v1/workflows/executions?stuck=true`.
+    pub(crate) workflow_watchdog:
+        Arc<aegis_orchestrator_core::application::workflow_watchdog::WorkflowWatchdog>,
+    /// Fault rules behind `/v1/admin/faults`, shared with the wrapped LLM,
+    /// storage and Temporal adapters.
+    #[cfg(feature = "fault-injection")]
+    pub(crate) fault_injector:
+        Arc<aegis_orchestrator_core::infrastructure::fault_injection::FaultInjector>,
 }
@@ -28,6 +28,7 @@ use anyhow::{Context, Result};
 use chrono::Utc;
 use tracing;
 
+use aegis_orchestrator_core::application::cluster::NodeMaintenanceService;
 use aegis_orchestrator_core::domain::cluster::NodeId;
 use aegis_orchestrator_core::infrastructure::aegis_cluster_proto::{
     node_command::Command, NodeCapabilities, NodeCommand,
@@ -52,6 +53,8 @@ pub struct WorkerLifecycle {
     /// `AttestNode` call (and on every re-attestation). Sourced from
     /// `spec.cluster.controller.token` in the node config.
     enrolment_token: String,
+    /// Reported as Draining in heartbeats while the node is cordoned.
+    node_maintenance: Arc<NodeMaintenanceService>,
 }
 
 impl WorkerLifecycle {
@@ -66,6 +69,7 @@ impl WorkerLifecycle {
         token_refresh_margin: Duration,
         signing_key: Arc<ed25519_dalek::SigningKey>,
         enrolment_token: String,
+        node_maintenance: Arc<NodeMaintenanceService>,
     ) -> Self {
         Self {
             client,
@@ -77,6 +81,7 @@ impl WorkerLifecycle {
             token_refresh_margin,
             signing_key,
             enrolment_token,
+            node_maintenance,
         }
     }
 
@@ -154,7 +159,8 @@ impl WorkerLifecycle {
                         }
                     }
 
-                    match self.client.heartbeat(0.0, 0).await {
+                    let draining = self.node_maintenance.is_cordoned();
+                    match self.client.heartbeat(0.0, 0, draining).await {
                         Ok(commands) => {
                             for cmd in commands {
                                 self.process_command(cmd).await;
@@ -268,6 +274,7 @@ mod tests {
             margin,
             signing_key,
             "test-enrolment-token".to_string(),
+            Arc::new(NodeMaintenanceService::new()),
         );
 
         // The field is accessible without an underscore prefix — this would fail
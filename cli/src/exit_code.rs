@@ -0,0 +1,175 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Process exit codes for the `aegis` CLI.
+//!
+//! Every command exits with one of these codes so scripts can tell failures
+//! apart without parsing output. The numbers are stable; new codes are only
+//! ever added.
+//!
+//! | Code | Meaning |
+//! |---|---|
+//! | 0 | Success |
+//! | 1 | Unclassified failure |
+//! | 2 | Usage error: bad arguments, or a prompt was needed without a terminal (pass `--yes`) |
+//! | 3 | Not authenticated, or the credentials were rejected (HTTP 401) |
+//! | 4 | Resource not found (HTTP 404) |
+//! | 5 | Conflict with existing state (HTTP 409) |
+//! | 6 | Request rejected as invalid (HTTP 400, 422) |
+//! | 7 | Permission denied (HTTP 403) |
+//! | 8 | Timed out |
+//! | 9 | Rate limited (HTTP 429) |
+//! | 10 | Server error (HTTP 5xx) |
+//! | 11 | Daemon not running or unhealthy |
+//! | 12 | Network error: connection refused, DNS, TLS |
+//!
+//! Commands attach a code by returning a [`CliError`]; errors from the
+//! daemon client carry the HTTP status and are classified by
+//! [`exit_code_for`]. Anything else exits 1.
+
+use std::fmt;
+
+use reqwest::StatusCode;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExitCode {
+    Failure = 1,
+    Usage = 2,
+    Auth = 3,
+    NotFound = 4,
+    Conflict = 5,
+    Invalid = 6,
+    Forbidden = 7,
+    Timeout = 8,
+    RateLimited = 9,
+    Server = 10,
+    DaemonUnavailable = 11,
+    Network = 12,
+}
+
+impl ExitCode {
+    pub fn as_i32(self) -> i32 {
+        self as i32
+    }
+
+    pub fn from_status(status: StatusCode) -> Self {
+        match status {
+            StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Self::Invalid,
+            StatusCode::UNAUTHORIZED => Self::Auth,
+            StatusCode::FORBIDDEN => Self::Forbidden,
+            StatusCode::NOT_FOUND => Self::NotFound,
+            StatusCode::CONFLICT => Self::Conflict,
+            StatusCode::REQUEST_TIMEOUT | StatusCode::GATEWAY_TIMEOUT => Self::Timeout,
+            StatusCode::TOO_MANY_REQUESTS => Self::RateLimited,
+            s if s.is_server_error() => Self::Server,
+            _ => Self::Failure,
+        }
+    }
+}
+
+/// An error with a known exit code.
+#[derive(Debug)]
+pub struct CliError {
+    pub code: ExitCode,
+    /// `None` when the command already told the user what went wrong.
+    message: Option<String>,
+}
+
+impl CliError {
+    pub fn new(code: ExitCode, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: Some(message.into()),
+        }
+    }
+
+    /// Exit with `code` without printing anything further.
+    pub fn reported(code: ExitCode) -> Self {
+        Self {
+            code,
+            message: None,
+        }
+    }
+
+    pub fn from_status(status: StatusCode, message: impl Into<String>) -> Self {
+        Self::new(ExitCode::from_status(status), message)
+    }
+
+    pub fn is_reported(&self) -> bool {
+        self.message.is_none()
+    }
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.message {
+            Some(message) => f.write_str(message),
+            None => write!(f, "exit code {}", self.code.as_i32()),
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// Turn a failed daemon response into an error carrying its status.
+pub async fn http_error(response: reqwest::Response, context: &str) -> anyhow::Error {
+    let status = response.status();
+    let error_text = response.text().await.unwrap_or_default();
+    CliError::from_status(status, format!("{context}: {error_text}")).into()
+}
+
+/// Exit code for an error returned by a command.
+pub fn exit_code_for(err: &anyhow::Error) -> ExitCode {
+    for cause in err.chain() {
+        if let Some(cli) = cause.downcast_ref::<CliError>() {
+            return cli.code;
+        }
+        if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+            if e.is_timeout() {
+                return ExitCode::Timeout;
+            }
+            if let Some(status) = e.status() {
+                return ExitCode::from_status(status);
+            }
+            if e.is_connect() || e.is_request() {
+                return ExitCode::Network;
+            }
+        }
+    }
+    ExitCode::Failure
+}
+
+/// Whether the error has already been shown to the user.
+pub fn is_reported(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        cause
+            .downcast_ref::<CliError>()
+            .is_some_and(CliError::is_reported)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn classifies_through_context() {
+        let err = Err::<(), _>(CliError::from_status(
+            StatusCode::NOT_FOUND,
+            "Failed to get agent: not found",
+        ))
+        .context("Loading agent")
+        .unwrap_err();
+        assert_eq!(exit_code_for(&err), ExitCode::NotFound);
+        assert_eq!(
+            err.root_cause().to_string(),
+            "Failed to get agent: not found"
+        );
+
+        assert_eq!(
+            ExitCode::from_status(StatusCode::SERVICE_UNAVAILABLE),
+            ExitCode::Server
+        );
+        assert_eq!(exit_code_for(&anyhow::anyhow!("boom")), ExitCode::Failure);
+    }
+}
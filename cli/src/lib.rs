@@ -10,5 +10,7 @@
 pub mod auth;
 pub mod commands;
 pub mod daemon;
+pub mod exit_code;
 pub mod output;
+pub mod remote;
 pub mod util;
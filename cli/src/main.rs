@@ -23,6 +23,15 @@
 //! - `aegis down [--volumes]` - Stop the Docker Compose stack
 //! - `aegis restart [--profile <name>]` - Restart the Docker Compose services
 //! - `aegis uninstall [-y]` - Stop stack and remove the ~/.aegis directory
+//! - `aegis remote add|list|remove` - Host aliases for `--remote`
+//! - `aegis fleet status` - Health of the local daemon and every remote
+//!
+//! `--remote <alias|user@host>` runs any command against the daemon on
+//! another host over SSH.
+//!
+//! Exit codes are stable and documented in [`exit_code`]. No command waits
+//! for input without a terminal: prompts fail with exit code 2 unless `--yes`
+//! skips them.
 //!
 //! See the architecture documentation for details.
 //!
@@ -31,7 +40,7 @@
 //! - **Layer:** Interface / Presentation Layer
 //! - **Purpose:** Implements internal responsibilities for main
 
-use aegis_orchestrator_core::domain::node_config::{LoggingConfig, OtlpProtocol};
+use aegis_orchestrator_core::domain::node_config::{LoggingConfig, MigrateMode, OtlpProtocol};
 use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use colored::Colorize;
@@ -45,14 +54,16 @@ use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, Layer};
 mod auth;
 mod commands;
 mod daemon;
+mod exit_code;
 mod output;
+mod remote;
 mod util;
 
 use commands::auth::AuthCommand;
 use commands::{
-    AgentCommand, ConfigCommand, CredentialCommand, DaemonCommand, DownArgs, FuseDaemonCommand,
-    InitArgs, NodeCommand, RestartArgs, SecretCommand, StatusArgs, TaskCommand, UninstallArgs,
-    UpArgs, WorkflowCommand,
+    AgentCommand, ConfigCommand, CredentialCommand, DaemonCommand, DownArgs, FleetCommand,
+    FuseDaemonCommand, InitArgs, NodeCommand, RemoteCommand, RestartArgs, SecretCommand,
+    StatusArgs, TaskCommand, UninstallArgs, UpArgs, VolumeCommand, WorkflowCommand,
 };
 use output::{structured_output_unsupported, OutputFormat};
 
@@ -65,6 +76,11 @@ struct Cli {
     #[arg(long)]
     daemon: bool,
 
+    /// Startup migration mode for --daemon: check, apply or skip
+    /// (default: spec.database.migrations.mode)
+    #[arg(long, env = "AEGIS_MIGRATE", value_name = "MODE")]
+    migrate: Option<MigrateMode>,
+
     /// Path to configuration file (overrides discovery)
     #[arg(
         short,
@@ -91,6 +107,11 @@ struct Cli {
     #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
     output: OutputFormat,
 
+    /// Manage the daemon on another host over SSH: a saved alias
+    /// (see `aegis remote`) or `user@host[:port]`
+    #[arg(long, global = true, env = "AEGIS_REMOTE", value_name = "TARGET")]
+    remote: Option<String>,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
@@ -142,6 +163,14 @@ enum Commands {
         #[command(subcommand)]
         command: WorkflowCommand,
     },
+
+    /// Search files across volumes
+    #[command(name = "volume")]
+    Volume {
+        #[command(subcommand)]
+        command: VolumeCommand,
+    },
+
     /// Manage secrets in the OpenBao-backed secret store
     #[command(name = "secret")]
     Secret {
@@ -156,6 +185,20 @@ enum Commands {
         command: CredentialCommand,
     },
 
+    /// Query every configured orchestrator node at once
+    #[command(name = "fleet")]
+    Fleet {
+        #[command(subcommand)]
+        command: FleetCommand,
+    },
+
+    /// Manage remote host aliases for `--remote`
+    #[command(name = "remote")]
+    Remote {
+        #[command(subcommand)]
+        command: RemoteCommand,
+    },
+
     /// Authenticate with an AEGIS environment.
     #[command(name = "auth")]
     Auth {
@@ -221,8 +264,17 @@ enum Commands {
 }
 
 #[tokio::main]
-async fn main() -> Result<()> {
-    let cli = Cli::parse();
+async fn main() {
+    if let Err(err) = run().await {
+        if !exit_code::is_reported(&err) {
+            eprintln!("Error: {err:?}");
+        }
+        std::process::exit(exit_code::exit_code_for(&err).as_i32());
+    }
+}
+
+async fn run() -> Result<()> {
+    let mut cli = Cli::parse();
 
     // Load config first to initialize logging properly
     let config =
@@ -253,9 +305,49 @@ async fn main() -> Result<()> {
     // Handle daemon mode (background service)
     if cli.daemon {
         info!("Starting AEGIS Agent Host in daemon mode");
-        return daemon::start_daemon(cli.config, cli.port).await;
+        return daemon::start_daemon(cli.config, cli.port, cli.migrate).await;
     }
 
+    // With --remote, host-management commands re-run on the remote host and
+    // everything else talks to its API through an SSH tunnel.
+    let _tunnel = match cli.remote.as_deref() {
+        Some(target)
+            if !matches!(
+                cli.command,
+                Some(Commands::Remote { .. } | Commands::Fleet { .. })
+            ) =>
+        {
+            let remote = remote::resolve(target)?;
+            if matches!(
+                cli.command,
+                Some(
+                    Commands::Daemon { .. }
+                        | Commands::Up { .. }
+                        | Commands::Down { .. }
+                        | Commands::Restart { .. }
+                )
+            ) {
+                return remote::exec(&remote, &remote::forwarded_args(std::env::args())).await;
+            }
+            let tunnel = remote::SshTunnel::open(&remote).await?;
+            info!(
+                remote = %remote.destination,
+                local_port = tunnel.api_port,
+                "Forwarding remote daemon API over SSH"
+            );
+            if let Some(grpc_port) = tunnel.grpc_port {
+                info!(
+                    local_port = grpc_port,
+                    "Forwarding remote gRPC API over SSH"
+                );
+            }
+            cli.host = "127.0.0.1".to_string();
+            cli.port = tunnel.api_port;
+            Some(tunnel)
+        }
+        _ => None,
+    };
+
     // Handle commands in CLI mode
     let res = match cli.command {
         Some(Commands::Daemon { command }) => {
@@ -263,7 +355,8 @@ async fn main() -> Result<()> {
                 .await
         }
         Some(Commands::Task { command }) => {
-            commands::task::handle_command(command, &cli.host, cli.port, cli.output).await
+            commands::task::handle_command(command, cli.config, &cli.host, cli.port, cli.output)
+                .await
         }
         Some(Commands::Node { command }) => {
             commands::node::handle_command(command, cli.config, &cli.host, cli.port, cli.output)
@@ -281,6 +374,10 @@ async fn main() -> Result<()> {
             commands::workflow::handle_command(command, cli.config, &cli.host, cli.port, cli.output)
                 .await
         }
+        Some(Commands::Volume { command }) => {
+            commands::volume::handle_command(command, cli.config, &cli.host, cli.port, cli.output)
+                .await
+        }
         Some(Commands::Secret { command }) => {
             commands::secret::handle_command(command, cli.config, &cli.host, cli.port, cli.output)
                 .await
@@ -291,6 +388,12 @@ async fn main() -> Result<()> {
             )
             .await
         }
+        Some(Commands::Fleet { command }) => {
+            commands::fleet::handle_command(command, &cli.host, cli.port, cli.output).await
+        }
+        Some(Commands::Remote { command }) => {
+            commands::remote::handle_command(command, cli.output).await
+        }
         Some(Commands::Auth { command }) => {
             commands::auth::handle_command(command, cli.output).await
         }
@@ -341,7 +444,7 @@ async fn main() -> Result<()> {
         None => {
             // No command provided - show help
             eprintln!("{}", "No command specified. Use --help for usage.".yellow());
-            std::process::exit(1);
+            std::process::exit(exit_code::ExitCode::Usage.as_i32());
         }
     };
 
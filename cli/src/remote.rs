@@ -0,0 +1,253 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Remote daemon management over SSH (`aegis --remote <TARGET> ...`)
+//!
+//! API commands run against an SSH local forward of the remote daemon's HTTP
+//! (and, when configured, gRPC) port, so the rest of the CLI talks to
+//! `127.0.0.1` unchanged. Commands that manage the remote host itself
+//! (`daemon`, `up`, `down`, `restart`) are re-run there over SSH exec instead.
+//!
+//! `TARGET` is either an alias saved with `aegis remote add` or a
+//! `user@host[:ssh_port]` destination.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Interface / Presentation Layer
+//! - **Purpose:** Implements internal responsibilities for remote
+
+use anyhow::{Context, Result};
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::io::AsyncReadExt;
+use tokio::net::TcpStream;
+use tokio::process::{Child, Command};
+
+use crate::auth::{load_store, RemoteHost};
+
+/// How long to wait for SSH to authenticate and open the forward.
+const TUNNEL_READY_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Resolve `--remote` to a host: a saved alias first, then a
+/// `user@host[:ssh_port]` destination.
+pub fn resolve(target: &str) -> Result<RemoteHost> {
+    if !target.contains('@') {
+        if let Some(remote) = load_store()?.remotes.remove(target) {
+            return Ok(remote);
+        }
+    }
+    parse_destination(target)
+}
+
+fn parse_destination(target: &str) -> Result<RemoteHost> {
+    let (destination, ssh_port) = match target.rsplit_once(':') {
+        Some((destination, port)) => {
+            let port = port
+                .parse::<u16>()
+                .with_context(|| format!("Invalid SSH port in remote '{target}'"))?;
+            (destination, Some(port))
+        }
+        None => (target, None),
+    };
+    let host = destination.rsplit('@').next().unwrap_or_default();
+    if host.is_empty() || destination.starts_with('@') {
+        anyhow::bail!(
+            "Unknown remote '{target}': expected a saved alias or user@host[:port] (see 'aegis remote list')"
+        );
+    }
+    Ok(RemoteHost {
+        ssh_port,
+        ..RemoteHost::new(destination)
+    })
+}
+
+/// `ssh` options shared by tunnels and exec.
+fn ssh_options(remote: &RemoteHost) -> Vec<String> {
+    let mut args = Vec::new();
+    if let Some(port) = remote.ssh_port {
+        args.extend(["-p".to_string(), port.to_string()]);
+    }
+    if let Some(identity) = &remote.identity_file {
+        args.extend(["-i".to_string(), identity.display().to_string()]);
+    }
+    args
+}
+
+/// An `ssh -N -L` forward of the remote daemon's API ports. The SSH process
+/// is killed when the tunnel is dropped.
+pub struct SshTunnel {
+    _child: Child,
+    /// Local port forwarded to the remote HTTP API
+    pub api_port: u16,
+    /// Local port forwarded to the remote gRPC API, when configured
+    pub grpc_port: Option<u16>,
+}
+
+impl SshTunnel {
+    pub async fn open(remote: &RemoteHost) -> Result<Self> {
+        let api_port = free_local_port()?;
+        let grpc_port = remote.grpc_port.map(|_| free_local_port()).transpose()?;
+
+        let mut command = Command::new("ssh");
+        command
+            .args(ssh_options(remote))
+            .args(["-N", "-o", "ExitOnForwardFailure=yes"])
+            .arg("-L")
+            .arg(format!(
+                "127.0.0.1:{api_port}:127.0.0.1:{}",
+                remote.api_port
+            ));
+        if let (Some(local), Some(remote_port)) = (grpc_port, remote.grpc_port) {
+            command
+                .arg("-L")
+                .arg(format!("127.0.0.1:{local}:127.0.0.1:{remote_port}"));
+        }
+        let mut child = command
+            .arg(&remote.destination)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .context("Failed to spawn `ssh` — is OpenSSH installed?")?;
+
+        // ssh only binds the local end once it has authenticated.
+        let deadline = tokio::time::Instant::now() + TUNNEL_READY_TIMEOUT;
+        loop {
+            if let Some(status) = child.try_wait()? {
+                let mut stderr = String::new();
+                if let Some(mut pipe) = child.stderr.take() {
+                    let _ = pipe.read_to_string(&mut stderr).await;
+                }
+                anyhow::bail!(
+                    "SSH tunnel to {} exited ({status}): {}",
+                    remote.destination,
+                    stderr.trim()
+                );
+            }
+            if TcpStream::connect(("127.0.0.1", api_port)).await.is_ok() {
+                break;
+            }
+            if tokio::time::Instant::now() >= deadline {
+                anyhow::bail!(
+                    "Timed out opening SSH tunnel to {} after {}s",
+                    remote.destination,
+                    TUNNEL_READY_TIMEOUT.as_secs()
+                );
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        Ok(Self {
+            _child: child,
+            api_port,
+            grpc_port,
+        })
+    }
+}
+
+fn free_local_port() -> Result<u16> {
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0))
+        .context("Failed to reserve a local port for the SSH tunnel")?;
+    Ok(listener.local_addr()?.port())
+}
+
+/// Run `aegis <args>` on the remote host over SSH, with inherited stdio.
+pub async fn exec(remote: &RemoteHost, args: &[String]) -> Result<()> {
+    use std::io::IsTerminal;
+
+    let mut command = Command::new("ssh");
+    command.args(ssh_options(remote));
+    if std::io::stdin().is_terminal() {
+        // Interactive commands (e.g. `up` running `init`) need a remote TTY.
+        command.arg("-t");
+    }
+    let status = command
+        .arg(&remote.destination)
+        .arg("--")
+        .arg(&remote.command)
+        .args(args.iter().map(|arg| shell_quote(arg)))
+        .status()
+        .await
+        .context("Failed to spawn `ssh` — is OpenSSH installed?")?;
+
+    if !status.success() {
+        anyhow::bail!(
+            "`{} {}` failed on {} ({status})",
+            remote.command,
+            args.join(" "),
+            remote.destination
+        );
+    }
+    Ok(())
+}
+
+/// The CLI arguments to replay on the remote host: everything after the
+/// program name except `--remote`.
+pub fn forwarded_args(args: impl IntoIterator<Item = String>) -> Vec<String> {
+    let mut forwarded = Vec::new();
+    let mut args = args.into_iter().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--remote" {
+            args.next();
+        } else if !arg.starts_with("--remote=") {
+            forwarded.push(arg);
+        }
+    }
+    forwarded
+}
+
+/// Quote an argument for the remote login shell, which re-splits the command.
+fn shell_quote(arg: &str) -> String {
+    if !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./=:,@%+".contains(c))
+    {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_destination_with_optional_port() {
+        let remote = parse_destination("ops@edge-07:2222").unwrap();
+        assert_eq!(remote.destination, "ops@edge-07");
+        assert_eq!(remote.ssh_port, Some(2222));
+        assert_eq!(remote.api_port, 8088);
+
+        let remote = parse_destination("ops@edge-07").unwrap();
+        assert_eq!(remote.ssh_port, None);
+
+        assert!(parse_destination("ops@edge-07:ssh").is_err());
+        assert!(parse_destination("@edge-07").is_err());
+        assert!(parse_destination("ops@").is_err());
+    }
+
+    #[test]
+    fn forwarded_args_strip_remote_flag() {
+        let argv = [
+            "aegis",
+            "--remote",
+            "edge",
+            "daemon",
+            "stop",
+            "--remote=x",
+            "-f",
+        ]
+        .map(String::from);
+        assert_eq!(forwarded_args(argv), ["daemon", "stop", "-f"]);
+    }
+
+    #[test]
+    fn shell_quote_escapes_metacharacters() {
+        assert_eq!(shell_quote("--timeout=30"), "--timeout=30");
+        assert_eq!(shell_quote("a b"), "'a b'");
+        assert_eq!(shell_quote("it's"), r"'it'\''s'");
+        assert_eq!(shell_quote(""), "''");
+    }
+}
@@ -3,3 +3,4 @@
 //! CLI utility helpers shared across commands.
 
 pub mod attachments;
+pub mod prompt;
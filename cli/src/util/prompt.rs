@@ -0,0 +1,43 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Prompts that never block a script.
+//!
+//! Without a terminal on stdin, or with `AEGIS_NON_INTERACTIVE` set, a
+//! prompt fails immediately with exit code 2 instead of waiting for input.
+//! Commands that prompt take `--yes` to skip the prompt altogether.
+
+use std::io::IsTerminal;
+
+use anyhow::Result;
+use dialoguer::Confirm;
+
+use crate::exit_code::{CliError, ExitCode};
+
+pub fn is_interactive() -> bool {
+    let forced_off = std::env::var("AEGIS_NON_INTERACTIVE")
+        .is_ok_and(|v| !v.is_empty() && v != "0" && !v.eq_ignore_ascii_case("false"));
+    !forced_off && std::io::stdin().is_terminal()
+}
+
+/// Fail with a usage error unless a user can answer a prompt. `what` names
+/// the prompt; `hint` says how to avoid it, e.g. "pass --yes".
+pub fn require_interactive(what: &str, hint: &str) -> Result<()> {
+    if is_interactive() {
+        Ok(())
+    } else {
+        Err(CliError::new(
+            ExitCode::Usage,
+            format!("{what} needs an interactive terminal; {hint}"),
+        )
+        .into())
+    }
+}
+
+/// Ask a yes/no question. Callers skip this entirely under `--yes`.
+pub fn confirm(prompt: &str, default: bool) -> Result<bool> {
+    require_interactive(&format!("Confirmation ({prompt})"), "pass --yes")?;
+    Ok(Confirm::new()
+        .with_prompt(prompt)
+        .default(default)
+        .interact()?)
+}
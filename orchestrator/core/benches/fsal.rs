@@ -0,0 +1,139 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # FSAL Hot-Path Benchmarks (ADR-036)
+//!
+//! Measures the per-operation overhead `AegisFSAL` adds on top of the storage
+//! provider: authorization, path canonicalization, policy enforcement, quota
+//! check and audit-event publication. The storage backend is the in-memory
+//! `TestStorageProvider`, so the numbers isolate orchestrator cost from I/O.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use chrono::Utc;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use parking_lot::RwLock;
+
+use aegis_orchestrator_core::domain::events::StorageEvent;
+use aegis_orchestrator_core::domain::execution::ExecutionId;
+use aegis_orchestrator_core::domain::fsal::{
+    AegisFSAL, AegisFileHandle, EventPublisher, FsalAccessPolicy,
+};
+use aegis_orchestrator_core::domain::repository::VolumeRepository;
+use aegis_orchestrator_core::domain::storage::StorageProvider;
+use aegis_orchestrator_core::domain::volume::{
+    FilerEndpoint, StorageClass, TenantId, Volume, VolumeBackend, VolumeId, VolumeOwnership,
+    VolumeStatus,
+};
+use aegis_orchestrator_core::infrastructure::nfs::{decode_file_handle, encode_file_handle};
+use aegis_orchestrator_core::infrastructure::repositories::InMemoryVolumeRepository;
+use aegis_orchestrator_core::infrastructure::storage::TestStorageProvider;
+
+const REMOTE_PATH: &str = "/aegis/volumes/bench/v1";
+
+struct NoopPublisher;
+
+#[async_trait]
+impl EventPublisher for NoopPublisher {
+    async fn publish_storage_event(&self, _: StorageEvent) {}
+}
+
+fn runtime() -> tokio::runtime::Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("benchmark runtime")
+}
+
+async fn fsal_with_execution_volume() -> (AegisFSAL, AegisFileHandle) {
+    let execution_id = ExecutionId::new();
+    let volume = Volume {
+        id: VolumeId::new(),
+        name: "bench-vol".to_string(),
+        tenant_id: TenantId::consumer(),
+        storage_class: StorageClass::persistent(),
+        backend: VolumeBackend::SeaweedFS {
+            filer_endpoint: FilerEndpoint::new("http://localhost:8888").unwrap(),
+            remote_path: REMOTE_PATH.to_string(),
+        },
+        size_limit_bytes: u64::MAX,
+        status: VolumeStatus::Available,
+        ownership: VolumeOwnership::execution(execution_id),
+        created_at: Utc::now(),
+        attached_at: None,
+        detached_at: None,
+        expires_at: None,
+        host_node_id: None,
+    };
+
+    let repo = Arc::new(InMemoryVolumeRepository::new());
+    repo.save(&volume).await.unwrap();
+    let storage = Arc::new(TestStorageProvider::new());
+    storage.create_directory(REMOTE_PATH).await.unwrap();
+
+    let fsal = AegisFSAL::new(
+        storage,
+        repo,
+        Arc::new(RwLock::new(HashMap::new())),
+        Arc::new(NoopPublisher),
+    );
+    let handle = AegisFileHandle::new(execution_id, volume.id, "/workspace/data.bin");
+    (fsal, handle)
+}
+
+fn bench_fsal_io(c: &mut Criterion) {
+    let rt = runtime();
+    let (fsal, handle) = rt.block_on(fsal_with_execution_volume());
+    let policy = FsalAccessPolicy::default();
+
+    let mut group = c.benchmark_group("fsal");
+    for size in [4 * 1024usize, 64 * 1024] {
+        let payload = vec![0xA5u8; size];
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::new("read", size), &size, |b, &size| {
+            b.to_async(&rt).iter(|| async {
+                fsal.read(&handle, "/workspace/data.bin", &policy, 0, size)
+                    .await
+                    .unwrap()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("write", size), &payload, |b, payload| {
+            b.to_async(&rt).iter(|| async {
+                fsal.write(&handle, "/workspace/data.bin", &policy, 0, payload)
+                    .await
+                    .unwrap()
+            })
+        });
+    }
+    group.bench_function("read_policy_denied", |b| {
+        b.to_async(&rt).iter(|| async {
+            fsal.read(&handle, "/etc/passwd", &policy, 0, 16)
+                .await
+                .unwrap_err()
+        })
+    });
+    group.finish();
+}
+
+fn bench_file_handle_codec(c: &mut Criterion) {
+    let handle = AegisFileHandle::new(
+        ExecutionId::new(),
+        VolumeId::new(),
+        "/workspace/src/main.rs",
+    );
+    let bytes = encode_file_handle(&handle).unwrap();
+
+    let mut group = c.benchmark_group("nfs_file_handle");
+    group.bench_function("encode", |b| {
+        b.iter(|| encode_file_handle(black_box(&handle)).unwrap())
+    });
+    group.bench_function("decode", |b| {
+        b.iter(|| decode_file_handle(black_box(&bytes)).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_fsal_io, bench_file_handle_codec);
+criterion_main!(benches);
@@ -0,0 +1,74 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # NFS FileHandle Table Benchmarks (ADR-036)
+//!
+//! Every NFSv3 LOOKUP, GETATTR, READ and WRITE resolves a `fileid3` through
+//! [`FileHandleTable`]. These benchmarks cover the three access patterns the
+//! NFS adapter uses: first registration, re-registration of a known path, and
+//! `fileid3` lookups against a warm table.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+use aegis_orchestrator_core::domain::execution::ExecutionId;
+use aegis_orchestrator_core::domain::fsal::AegisFileHandle;
+use aegis_orchestrator_core::domain::volume::VolumeId;
+use aegis_orchestrator_core::infrastructure::nfs::FileHandleTable;
+
+fn handles(count: usize) -> Vec<(AegisFileHandle, String)> {
+    let execution_id = ExecutionId::new();
+    let volume_id = VolumeId::new();
+    (0..count)
+        .map(|i| {
+            let path = format!("/workspace/src/module_{i}.rs");
+            (AegisFileHandle::new(execution_id, volume_id, &path), path)
+        })
+        .collect()
+}
+
+fn warm_table(entries: &[(AegisFileHandle, String)]) -> (FileHandleTable, Vec<u64>) {
+    let table = FileHandleTable::new();
+    let ids = entries
+        .iter()
+        .map(|(handle, path)| table.register(handle.clone(), path.clone()))
+        .collect();
+    (table, ids)
+}
+
+fn bench_handle_table(c: &mut Criterion) {
+    let mut group = c.benchmark_group("nfs_handle_table");
+    for size in [1_000usize, 100_000] {
+        let entries = handles(size);
+        let (table, ids) = warm_table(&entries);
+        let (probe_handle, probe_path) = entries[size / 2].clone();
+        let probe_id = ids[size / 2];
+
+        group.bench_with_input(BenchmarkId::new("lookup", size), &probe_id, |b, &id| {
+            b.iter(|| table.lookup(black_box(id)).unwrap())
+        });
+        group.bench_with_input(
+            BenchmarkId::new("register_existing", size),
+            &(probe_handle, probe_path),
+            |b, (handle, path)| b.iter(|| table.register(handle.clone(), path.clone())),
+        );
+    }
+
+    let fresh = handles(1_000);
+    group.bench_function("register_new_1000", |b| {
+        b.iter_batched(
+            FileHandleTable::new,
+            |table| {
+                for (handle, path) in &fresh {
+                    black_box(table.register(handle.clone(), path.clone()));
+                }
+                table
+            },
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_handle_table);
+criterion_main!(benches);
@@ -0,0 +1,80 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Prompt Template Rendering Benchmarks
+//!
+//! Every supervisor iteration renders the agent's prompt template before the
+//! LLM call. These benchmarks cover the default template, a manifest-style
+//! template with refinement context and custom variables, and the
+//! `render_simple` helper used by workflow state inputs.
+
+use std::collections::HashMap;
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use serde_json::json;
+
+use aegis_orchestrator_core::infrastructure::prompt_template_engine::{
+    render_simple, PromptContext, PromptTemplateEngine,
+};
+
+const MANIFEST_TEMPLATE: &str = "\
+You are {{agent_name}}.
+{{#if instruction}}Task: {{instruction}}
+{{/if}}{{#if iteration_number}}Iteration: {{iteration_number}}
+{{/if}}{{#if previous_error}}The previous attempt failed:
+{{previous_error}}
+{{/if}}{{#if context}}Context:
+{{context}}
+{{/if}}Input: {{input}}";
+
+fn refinement_context() -> PromptContext {
+    PromptContext::new()
+        .instruction("Implement the requested change and make the test suite pass.")
+        .input(json!({
+            "repository": "example/service",
+            "files": ["src/lib.rs", "src/handlers.rs", "tests/api.rs"],
+            "constraints": { "max_lines": 400, "language": "rust" }
+        }))
+        .iteration_number(3)
+        .previous_error("error[E0308]: mismatched types\n --> src/handlers.rs:42:17")
+        .context("## src/lib.rs\n".repeat(64))
+        .extra("agent_name", json!("coder-v1"))
+}
+
+fn bench_prompt_rendering(c: &mut Criterion) {
+    let engine = PromptTemplateEngine::new();
+    let context = refinement_context();
+    let vars: HashMap<String, String> = [
+        ("task".to_string(), "summarise the diff".to_string()),
+        ("state".to_string(), "REVIEW".to_string()),
+    ]
+    .into_iter()
+    .collect();
+
+    let mut group = c.benchmark_group("prompt_rendering");
+    group.bench_function("default_template", |b| {
+        b.iter(|| {
+            engine
+                .render(
+                    PromptTemplateEngine::default_template(),
+                    black_box(&context),
+                )
+                .unwrap()
+        })
+    });
+    group.bench_function("manifest_template", |b| {
+        b.iter(|| {
+            engine
+                .render(MANIFEST_TEMPLATE, black_box(&context))
+                .unwrap()
+        })
+    });
+    group.bench_function("render_simple", |b| {
+        b.iter(|| render_simple("Run {{task}} in state {{state}}", black_box(&vars)).unwrap())
+    });
+    group.bench_function("engine_construction", |b| b.iter(PromptTemplateEngine::new));
+    group.finish();
+}
+
+criterion_group!(benches, bench_prompt_rendering);
+criterion_main!(benches);
@@ -0,0 +1,143 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Workflow Tick and Event Bus Benchmarks (ADR-058)
+//!
+//! A workflow "tick" is the in-orchestrator work done for one FSM transition
+//! reported by the Temporal worker: map the entered/exited payloads to domain
+//! events, apply them to the `WorkflowExecution` aggregate, and fan them out
+//! on the `EventBus`. The `event_bus` group measures raw publish throughput
+//! with and without subscribers attached.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use serde_json::json;
+
+use aegis_orchestrator_core::domain::events::WorkflowEvent;
+use aegis_orchestrator_core::domain::execution::ExecutionId;
+use aegis_orchestrator_core::domain::workflow::{StateName, WorkflowExecution};
+use aegis_orchestrator_core::infrastructure::event_bus::EventBus;
+use aegis_orchestrator_core::infrastructure::temporal_event_listener::{
+    TemporalEventMapper, TemporalEventPayload,
+};
+use aegis_orchestrator_core::infrastructure::workflow_parser::WorkflowParser;
+
+const WORKFLOW_YAML: &str = r#"
+apiVersion: 100monkeys.ai/v1
+kind: Workflow
+metadata:
+  name: bench-workflow
+  version: "1.0.0"
+spec:
+  initial_state: START
+  states:
+    START:
+      kind: Agent
+      agent: coder-v1
+      input: "{{input}}"
+      transitions:
+        - condition: always
+          target: REVIEW
+    REVIEW:
+      kind: Agent
+      agent: reviewer-v1
+      input: "{{START.output}}"
+      transitions:
+        - condition: always
+          target: END
+    END:
+      kind: System
+      command: echo "done"
+      transitions: []
+"#;
+
+fn payload(event_type: &str, execution_id: &ExecutionId, state: &str) -> TemporalEventPayload {
+    TemporalEventPayload {
+        event_type: event_type.to_string(),
+        execution_id: execution_id.0.to_string(),
+        state_name: Some(state.to_string()),
+        output: (event_type == "WorkflowStateExited")
+            .then(|| json!({ "summary": "ok", "files_changed": 3 })),
+        timestamp: "2026-01-01T00:00:00Z".to_string(),
+        ..Default::default()
+    }
+}
+
+fn tick(
+    execution: &mut WorkflowExecution,
+    bus: &EventBus,
+    entered: &TemporalEventPayload,
+    exited: &TemporalEventPayload,
+) {
+    let entered = TemporalEventMapper::to_domain_event(entered).unwrap();
+    if let WorkflowEvent::WorkflowStateEntered { state_name, .. } = &entered {
+        execution.transition_to(StateName::new(state_name.as_str()).unwrap());
+    }
+    bus.publish_workflow_event(entered);
+
+    let exited = TemporalEventMapper::to_domain_event(exited).unwrap();
+    if let WorkflowEvent::WorkflowStateExited {
+        state_name, output, ..
+    } = &exited
+    {
+        execution.record_state_output(StateName::new(state_name.as_str()).unwrap(), output.clone());
+    }
+    bus.publish_workflow_event(exited);
+}
+
+fn bench_workflow_tick(c: &mut Criterion) {
+    let workflow = WorkflowParser::parse_yaml(WORKFLOW_YAML).unwrap();
+    let execution_id = ExecutionId::new();
+    let mut execution = WorkflowExecution::new(&workflow, execution_id, json!({ "task": "ship" }));
+    let entered = payload("WorkflowStateEntered", &execution_id, "REVIEW");
+    let exited = payload("WorkflowStateExited", &execution_id, "REVIEW");
+
+    let bus = EventBus::with_default_capacity();
+    let mut receiver = bus.subscribe();
+
+    let mut group = c.benchmark_group("workflow_tick");
+    group.bench_function("map_event", |b| {
+        b.iter(|| TemporalEventMapper::to_domain_event(black_box(&exited)).unwrap())
+    });
+    group.bench_function("transition", |b| {
+        b.iter(|| {
+            tick(&mut execution, &bus, &entered, &exited);
+            while receiver.try_recv().is_ok() {}
+        })
+    });
+    group.finish();
+}
+
+fn bench_event_bus(c: &mut Criterion) {
+    const BATCH: u64 = 1_000;
+    let event = WorkflowEvent::WorkflowStateEntered {
+        execution_id: ExecutionId::new(),
+        state_name: "REVIEW".to_string(),
+        entered_at: chrono::Utc::now(),
+    };
+
+    let mut group = c.benchmark_group("event_bus");
+    group.throughput(Throughput::Elements(BATCH));
+    for subscribers in [0usize, 1, 8] {
+        let bus = EventBus::new(BATCH as usize);
+        let mut receivers: Vec<_> = (0..subscribers).map(|_| bus.subscribe()).collect();
+        group.bench_with_input(
+            BenchmarkId::new("publish", subscribers),
+            &subscribers,
+            |b, _| {
+                b.iter(|| {
+                    for _ in 0..BATCH {
+                        bus.publish_workflow_event(event.clone());
+                    }
+                    for receiver in &mut receivers {
+                        while receiver.try_recv().is_ok() {}
+                    }
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_workflow_tick, bench_event_bus);
+criterion_main!(benches);
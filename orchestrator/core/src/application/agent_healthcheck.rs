@@ -0,0 +1,214 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # AgentHealthCheckService — BC-1 Agent Lifecycle
+//!
+//! Runs an agent's `spec.healthcheck` smoke test: one execution with the
+//! canned input, polled to completion and checked against
+//! [`HealthCheckExpectation`](crate::domain::agent::HealthCheckExpectation).
+//! Backs `POST /v1/agents/{id}/verify` and `aegis agent deploy --verify`.
+//!
+//! A failing check with `on_failure: degrade` marks the agent
+//! [`AgentStatus::Degraded`]; a passing check returns a degraded agent to
+//! `Active`. With `on_failure: fail` the agent is left untouched and the
+//! caller decides what to do with the failed report.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::application::execution::ExecutionService;
+use crate::domain::agent::{AgentId, AgentStatus, HealthCheckFailureAction};
+use crate::domain::execution::{ExecutionId, ExecutionInput, ExecutionStatus};
+use crate::domain::iam::UserIdentity;
+use crate::domain::repository::{AgentRepository, RepositoryError};
+use crate::domain::tenant::TenantId;
+
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+#[derive(Debug, thiserror::Error)]
+pub enum HealthCheckError {
+    #[error("agent not found: {0}")]
+    AgentNotFound(AgentId),
+    #[error("agent '{0}' does not define spec.healthcheck")]
+    NotDefined(String),
+    #[error("repository error: {0}")]
+    Repository(String),
+    #[error("failed to start healthcheck execution: {0}")]
+    Execution(String),
+}
+
+impl From<RepositoryError> for HealthCheckError {
+    fn from(e: RepositoryError) -> Self {
+        HealthCheckError::Repository(e.to_string())
+    }
+}
+
+/// Outcome of one healthcheck run.
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthCheckReport {
+    pub agent_id: AgentId,
+    pub execution_id: ExecutionId,
+    pub passed: bool,
+    /// Why the check failed; `None` when it passed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub on_failure: HealthCheckFailureAction,
+    /// Agent status after the run.
+    pub agent_status: AgentStatus,
+}
+
+pub struct AgentHealthCheckService {
+    agent_repository: Arc<dyn AgentRepository>,
+    execution_service: Arc<dyn ExecutionService>,
+    poll_interval: Duration,
+}
+
+impl AgentHealthCheckService {
+    pub fn new(
+        agent_repository: Arc<dyn AgentRepository>,
+        execution_service: Arc<dyn ExecutionService>,
+    ) -> Self {
+        Self {
+            agent_repository,
+            execution_service,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+        }
+    }
+
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Run the agent's healthcheck and apply `on_failure`. Returns `Err` only
+    /// when the check could not be run; a failing check is `Ok` with
+    /// `passed: false`.
+    pub async fn verify(
+        &self,
+        tenant_id: &TenantId,
+        agent_id: AgentId,
+        security_context_name: String,
+        identity: Option<&UserIdentity>,
+    ) -> Result<HealthCheckReport, HealthCheckError> {
+        let agent = self
+            .agent_repository
+            .find_by_id_for_tenant(tenant_id, agent_id)
+            .await?
+            .ok_or(HealthCheckError::AgentNotFound(agent_id))?;
+        let check = agent
+            .manifest
+            .spec
+            .healthcheck
+            .clone()
+            .ok_or_else(|| HealthCheckError::NotDefined(agent.name.clone()))?;
+
+        let input = ExecutionInput {
+            intent: Some(check.input.clone()),
+            input: serde_json::json!({
+                "input": check.input_data.clone().unwrap_or(serde_json::Value::Null),
+                "tenant_id": tenant_id.to_string(),
+                "labels": { "healthcheck": "true" },
+            }),
+            workspace_volume_id: None,
+            workspace_volume_mount_path: None,
+            workspace_remote_path: None,
+            workflow_execution_id: None,
+            attachments: Vec::new(),
+        };
+        let execution_id = self
+            .execution_service
+            .start_execution(agent_id, input, security_context_name, identity)
+            .await
+            .map_err(|e| HealthCheckError::Execution(e.to_string()))?;
+
+        let result = self
+            .await_outcome(tenant_id, execution_id, check.timeout_seconds)
+            .await
+            .and_then(|(status, output)| check.expect.evaluate(&status, output.as_deref()));
+
+        let agent_status = self
+            .apply_outcome(tenant_id, agent_id, result.is_ok(), check.on_failure)
+            .await?;
+
+        Ok(HealthCheckReport {
+            agent_id,
+            execution_id,
+            passed: result.is_ok(),
+            reason: result.err(),
+            on_failure: check.on_failure,
+            agent_status,
+        })
+    }
+
+    /// Poll until the execution is terminal. On timeout the execution is
+    /// cancelled and the check fails.
+    async fn await_outcome(
+        &self,
+        tenant_id: &TenantId,
+        execution_id: ExecutionId,
+        timeout_seconds: u64,
+    ) -> Result<(ExecutionStatus, Option<String>), String> {
+        let deadline = tokio::time::Instant::now() + Duration::from_secs(timeout_seconds);
+        loop {
+            let execution = self
+                .execution_service
+                .get_execution_for_tenant(tenant_id, execution_id)
+                .await
+                .map_err(|e| format!("failed to read healthcheck execution: {e}"))?;
+            if execution.status.is_terminal() {
+                let output = execution.current_iteration().and_then(|i| i.output.clone());
+                return Ok((execution.status, output));
+            }
+            if tokio::time::Instant::now() >= deadline {
+                if let Err(e) = self
+                    .execution_service
+                    .cancel_execution_for_tenant(tenant_id, execution_id)
+                    .await
+                {
+                    tracing::warn!(
+                        %execution_id,
+                        error = %e,
+                        "Failed to cancel timed-out healthcheck execution"
+                    );
+                }
+                return Err(format!("timed out after {timeout_seconds}s"));
+            }
+            tokio::time::sleep(self.poll_interval).await;
+        }
+    }
+
+    /// Re-read the agent (the run may have taken minutes) and move it into
+    /// or out of `Degraded`. Paused and archived agents are left alone.
+    async fn apply_outcome(
+        &self,
+        tenant_id: &TenantId,
+        agent_id: AgentId,
+        passed: bool,
+        on_failure: HealthCheckFailureAction,
+    ) -> Result<AgentStatus, HealthCheckError> {
+        let mut agent = self
+            .agent_repository
+            .find_by_id_for_tenant(tenant_id, agent_id)
+            .await?
+            .ok_or(HealthCheckError::AgentNotFound(agent_id))?;
+        let changed = if passed && agent.status == AgentStatus::Degraded {
+            agent.resume();
+            true
+        } else if !passed
+            && on_failure == HealthCheckFailureAction::Degrade
+            && agent.status == AgentStatus::Active
+        {
+            agent.degrade();
+            true
+        } else {
+            false
+        };
+        if changed {
+            self.agent_repository
+                .save_for_tenant(tenant_id, &agent)
+                .await?;
+        }
+        Ok(agent.status)
+    }
+}
@@ -209,6 +209,11 @@ mod tests {
                     version: Some("3.11".to_string()),
                     image: None,
                     image_pull_policy: crate::domain::agent::ImagePullPolicy::IfNotPresent,
+                    base_image: None,
+                    packages: Vec::new(),
+                    env: Vec::new(),
+                    sidecars: Vec::new(),
+                    platform: None,
                     isolation: "docker".to_string(),
                     model: "default".to_string(),
                     temperature: None,
@@ -225,6 +230,8 @@ mod tests {
                 input_schema: None,
                 security_context: None,
                 output_handler: None,
+                concurrency: None,
+                healthcheck: None,
             },
         };
 
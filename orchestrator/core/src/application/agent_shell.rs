@@ -0,0 +1,546 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Agent Shell
+//!
+//! Runs an agent manifest in-process for prompt development
+//! (`aegis agent shell`): no daemon, no container, no database. Each input
+//! goes through the real [`Supervisor`] loop so iteration, history and
+//! validation behave as in a deployed execution, with two substitutions:
+//!
+//! - [`ShellRuntime`] stands in for the container. Each iteration is a single
+//!   LLM call on the prompt the bootstrap would send (the previous attempts
+//!   prefix followed by the rendered task prompt); tool calls are not run.
+//! - Only validators that run in-process (`exit_code`, `json_schema`,
+//!   `regex`) are applied; `semantic` and `multi_judge` need judge agents and
+//!   are reported as skipped.
+//!
+//! Every turn is recorded as a [`ShellTurn`]. A session together with its
+//! LLM exchanges is a [`ShellFixture`]; replaying one through a
+//! [`ReplayLLMProvider`](crate::infrastructure::llm::replay::ReplayLLMProvider)
+//! and comparing outputs turns it into a regression test.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Local prompt/response/validation loop over one manifest
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+use tokio_util::sync::CancellationToken;
+
+use crate::application::execution::DEFAULT_PROMPT_TEMPLATE;
+use crate::application::validation_service::build_local_validation_pipeline;
+use crate::domain::agent::{AgentManifest, ValidatorSpec};
+use crate::domain::execution::{ExecutionId, ExecutionInput};
+use crate::domain::llm::{GenerationOptions, LLMProvider};
+use crate::domain::runtime::{
+    AgentRuntime, InstanceId, InstanceStatus, ResourceLimits, RuntimeConfig, RuntimeError,
+    TaskInput, TaskOutput,
+};
+use crate::domain::supervisor::{Supervisor, SupervisorObserver};
+use crate::domain::tenant::TenantId;
+use crate::domain::validation::{ValidationPipeline, ValidationResults};
+use crate::infrastructure::llm::replay::LlmExchange;
+use crate::infrastructure::prompt_template_engine::{PromptContext, PromptTemplateEngine};
+
+#[derive(Debug, Error)]
+pub enum AgentShellError {
+    #[error("failed to render prompt: {0}")]
+    PromptRender(String),
+}
+
+/// Something that happened during a turn, in the order it happened.
+#[derive(Debug, Clone)]
+pub enum ShellEvent {
+    /// The exact prompt sent to the LLM.
+    Prompt {
+        iteration: u8,
+        prompt: String,
+    },
+    Response {
+        iteration: u8,
+        output: String,
+    },
+    Failed {
+        iteration: u8,
+        error: String,
+    },
+    Verdict {
+        iteration: u8,
+        verdict: ShellVerdict,
+    },
+}
+
+/// Validation outcome of one iteration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShellVerdict {
+    pub passed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reasoning: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ShellIteration {
+    pub iteration: u8,
+    pub prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verdict: Option<ShellVerdict>,
+}
+
+/// One input and everything the supervisor did with it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShellTurn {
+    pub input: serde_json::Value,
+    pub iterations: Vec<ShellIteration>,
+    /// Accepted output; `None` when the loop failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A saved shell session: the turns as seen and the LLM exchanges that
+/// produced them.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShellFixture {
+    pub agent: String,
+    pub version: String,
+    pub turns: Vec<ShellTurn>,
+    pub exchanges: Vec<LlmExchange>,
+}
+
+/// Differences between a replayed turn and the fixture's expectation.
+pub fn diff_turn(expected: &ShellTurn, actual: &ShellTurn) -> Vec<String> {
+    let mut diffs = Vec::new();
+    if expected.output != actual.output {
+        diffs.push(format!(
+            "output: expected {:?}, got {:?}",
+            expected.output, actual.output
+        ));
+    }
+    if expected.iterations.len() != actual.iterations.len() {
+        diffs.push(format!(
+            "iterations: expected {}, got {}",
+            expected.iterations.len(),
+            actual.iterations.len()
+        ));
+    }
+    for (want, got) in expected.iterations.iter().zip(&actual.iterations) {
+        if want.prompt != got.prompt {
+            diffs.push(format!("iteration {}: prompt changed", want.iteration));
+        }
+        let passed = |i: &ShellIteration| i.verdict.as_ref().map(|v| v.passed);
+        if passed(want) != passed(got) {
+            diffs.push(format!(
+                "iteration {}: verdict expected {:?}, got {:?}",
+                want.iteration,
+                passed(want),
+                passed(got)
+            ));
+        }
+    }
+    diffs
+}
+
+type EventSink = Arc<dyn Fn(&ShellEvent) + Send + Sync>;
+
+/// Local session over one manifest.
+pub struct AgentShell {
+    manifest: AgentManifest,
+    llm: Arc<dyn LLMProvider>,
+    pipeline: Option<Arc<ValidationPipeline>>,
+    skipped_validators: Vec<ValidatorSpec>,
+}
+
+impl AgentShell {
+    pub fn new(manifest: AgentManifest, llm: Arc<dyn LLMProvider>) -> Self {
+        let validators = manifest
+            .spec
+            .execution
+            .as_ref()
+            .and_then(|e| e.validation.as_deref())
+            .unwrap_or_default();
+        let (pipeline, skipped) = build_local_validation_pipeline(validators);
+        let pipeline = (skipped.len() < validators.len()).then(|| Arc::new(pipeline));
+        let skipped_validators = skipped.into_iter().cloned().collect();
+        Self {
+            manifest,
+            llm,
+            pipeline,
+            skipped_validators,
+        }
+    }
+
+    pub fn manifest(&self) -> &AgentManifest {
+        &self.manifest
+    }
+
+    /// Validators the shell cannot run (judge agents and plugins).
+    pub fn skipped_validators(&self) -> &[ValidatorSpec] {
+        &self.skipped_validators
+    }
+
+    /// Render the first-iteration prompt for `input` the way the execution
+    /// service does for a dispatch with `input` and no intent.
+    pub fn render_prompt(&self, input: &serde_json::Value) -> Result<String, AgentShellError> {
+        let task = self.manifest.spec.task.as_ref();
+        let template = task
+            .and_then(|t| t.prompt_template.as_deref())
+            .filter(|s| !s.is_empty())
+            .unwrap_or(DEFAULT_PROMPT_TEMPLATE);
+        let instruction = task
+            .and_then(|t| t.instruction.as_ref())
+            .or(self.manifest.metadata.description.as_ref())
+            .map(String::as_str)
+            .unwrap_or("");
+        let context = PromptContext::new()
+            .instruction(instruction)
+            .input(input.clone())
+            .iteration_number(1);
+        PromptTemplateEngine::new()
+            .render(template, &context)
+            .map_err(|e| AgentShellError::PromptRender(e.to_string()))
+    }
+
+    /// Run one input through the supervisor loop, reporting each
+    /// [`ShellEvent`] to `on_event` as it happens.
+    pub async fn send(
+        &self,
+        input: serde_json::Value,
+        on_event: impl Fn(&ShellEvent) + Send + Sync + 'static,
+    ) -> Result<ShellTurn, AgentShellError> {
+        let prompt = self.render_prompt(&input)?;
+        let recorder = Arc::new(TurnRecorder::new(Arc::new(on_event)));
+        let runtime = Arc::new(ShellRuntime::new(self.llm.clone(), recorder.clone()));
+        // Same default as `StandardExecutionService` for manifests without
+        // `spec.execution`.
+        let max_iterations = self
+            .manifest
+            .spec
+            .execution
+            .as_ref()
+            .map_or(3, |e| e.max_retries);
+        let strategy = self.manifest.spec.execution.clone().unwrap_or_default();
+
+        let config = RuntimeConfig {
+            language: self
+                .manifest
+                .spec
+                .runtime
+                .language
+                .clone()
+                .unwrap_or_default(),
+            version: self
+                .manifest
+                .spec
+                .runtime
+                .version
+                .clone()
+                .unwrap_or_default(),
+            isolation: "shell".to_string(),
+            env: HashMap::new(),
+            image_pull_policy: self.manifest.spec.runtime.image_pull_policy.clone(),
+            container_uid: 1000,
+            container_gid: 1000,
+            resources: ResourceLimits {
+                cpu_millis: None,
+                memory_bytes: None,
+                disk_bytes: None,
+                timeout_seconds: None,
+            },
+            execution: strategy,
+            volumes: Vec::new(),
+            keep_container_on_failure: false,
+            image: "shell".to_string(),
+            bootstrap_path: None,
+            execution_id: ExecutionId::new(),
+            tenant_id: TenantId::default(),
+            sidecars: Vec::new(),
+            platform: None,
+            network_policy: None,
+            network_flow_capture: None,
+        };
+        let execution_input = ExecutionInput {
+            intent: Some(prompt),
+            input: input.clone(),
+            workspace_volume_id: None,
+            workspace_volume_mount_path: None,
+            workspace_remote_path: None,
+            workflow_execution_id: None,
+            attachments: Vec::new(),
+        };
+
+        let result = Supervisor::new(runtime)
+            .run_loop(
+                config,
+                execution_input,
+                max_iterations,
+                recorder.clone(),
+                CancellationToken::new(),
+                self.pipeline.clone(),
+            )
+            .await;
+        let (output, error) = match result {
+            Ok(output) => (Some(output), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        Ok(ShellTurn {
+            input,
+            iterations: recorder.iterations(),
+            output,
+            error,
+        })
+    }
+}
+
+/// Collects [`ShellIteration`]s from the runtime (prompts) and the
+/// supervisor (responses, verdicts) and forwards each event.
+struct TurnRecorder {
+    sink: EventSink,
+    iterations: Mutex<Vec<ShellIteration>>,
+}
+
+impl TurnRecorder {
+    fn new(sink: EventSink) -> Self {
+        Self {
+            sink,
+            iterations: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn iterations(&self) -> Vec<ShellIteration> {
+        self.iterations.lock().expect("iterations poisoned").clone()
+    }
+
+    fn emit(&self, event: ShellEvent) {
+        {
+            let mut iterations = self.iterations.lock().expect("iterations poisoned");
+            let iteration = match &event {
+                ShellEvent::Prompt { iteration, .. }
+                | ShellEvent::Response { iteration, .. }
+                | ShellEvent::Failed { iteration, .. }
+                | ShellEvent::Verdict { iteration, .. } => *iteration,
+            };
+            if iterations.last().map(|i| i.iteration) != Some(iteration) {
+                iterations.push(ShellIteration {
+                    iteration,
+                    ..ShellIteration::default()
+                });
+            }
+            let current = iterations.last_mut().expect("pushed above");
+            match &event {
+                ShellEvent::Prompt { prompt, .. } => current.prompt = prompt.clone(),
+                ShellEvent::Response { output, .. } => current.output = Some(output.clone()),
+                ShellEvent::Failed { error, .. } => current.error = Some(error.clone()),
+                ShellEvent::Verdict { verdict, .. } => current.verdict = Some(verdict.clone()),
+            }
+        }
+        (self.sink)(&event);
+    }
+}
+
+#[async_trait]
+impl SupervisorObserver for TurnRecorder {
+    async fn on_iteration_start(&self, _iteration: u8, _prompt: &str) {}
+
+    async fn on_console_output(&self, _iteration: u8, _stream: &str, _content: &str) {}
+
+    async fn on_iteration_complete(&self, iteration: u8, result: &str, _exit_code: i64) {
+        self.emit(ShellEvent::Response {
+            iteration,
+            output: result.to_string(),
+        });
+    }
+
+    async fn on_iteration_fail(&self, iteration: u8, error: &str) {
+        self.emit(ShellEvent::Failed {
+            iteration,
+            error: error.to_string(),
+        });
+    }
+
+    async fn on_instance_spawned(&self, _iteration: u8, _instance_id: &InstanceId) {}
+
+    async fn on_instance_terminated(&self, _iteration: u8, _instance_id: &InstanceId) {}
+
+    async fn on_validation_complete(
+        &self,
+        iteration: u8,
+        results: &ValidationResults,
+        passed: bool,
+    ) {
+        self.emit(ShellEvent::Verdict {
+            iteration,
+            verdict: ShellVerdict {
+                passed,
+                score: results.gradient.as_ref().map(|g| g.score),
+                reasoning: results.gradient.as_ref().map(|g| g.reasoning.clone()),
+            },
+        });
+    }
+}
+
+/// [`AgentRuntime`] that answers each iteration with one LLM call.
+///
+/// The prompt is assembled like `bootstrap.py` does it: the
+/// `AEGIS_ITERATION_HISTORY` the supervisor passes is rendered as a
+/// "Previous Attempts" block in front of the task prompt.
+struct ShellRuntime {
+    llm: Arc<dyn LLMProvider>,
+    recorder: Arc<TurnRecorder>,
+    next_instance: AtomicU64,
+    env: Mutex<HashMap<InstanceId, HashMap<String, String>>>,
+}
+
+impl ShellRuntime {
+    fn new(llm: Arc<dyn LLMProvider>, recorder: Arc<TurnRecorder>) -> Self {
+        Self {
+            llm,
+            recorder,
+            next_instance: AtomicU64::new(0),
+            env: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl AgentRuntime for ShellRuntime {
+    async fn spawn(&self, config: RuntimeConfig) -> Result<InstanceId, RuntimeError> {
+        let n = self.next_instance.fetch_add(1, Ordering::Relaxed);
+        let id = InstanceId::new(format!("shell-{n}"));
+        self.env
+            .lock()
+            .expect("env poisoned")
+            .insert(id.clone(), config.env);
+        Ok(id)
+    }
+
+    async fn execute(&self, id: &InstanceId, input: TaskInput) -> Result<TaskOutput, RuntimeError> {
+        let env = self
+            .env
+            .lock()
+            .expect("env poisoned")
+            .get(id)
+            .cloned()
+            .ok_or_else(|| RuntimeError::InstanceNotFound(id.0.clone()))?;
+        let iteration = env
+            .get("AEGIS_ITERATION")
+            .and_then(|n| n.parse().ok())
+            .unwrap_or(1);
+        let prompt = match env.get("AEGIS_ITERATION_HISTORY") {
+            Some(history) => format!("{}{}", history_context(history), input.prompt),
+            None => input.prompt,
+        };
+        self.recorder.emit(ShellEvent::Prompt {
+            iteration,
+            prompt: prompt.clone(),
+        });
+        let response = self
+            .llm
+            .generate(&prompt, &GenerationOptions::default())
+            .await
+            .map_err(|e| RuntimeError::ExecutionFailed(e.to_string()))?;
+        Ok(TaskOutput {
+            result: serde_json::Value::String(response.text),
+            logs: Vec::new(),
+            tool_calls: Vec::new(),
+            exit_code: 0,
+            trajectory: Vec::new(),
+        })
+    }
+
+    async fn terminate(&self, id: &InstanceId) -> Result<(), RuntimeError> {
+        self.env.lock().expect("env poisoned").remove(id);
+        Ok(())
+    }
+
+    async fn status(&self, id: &InstanceId) -> Result<InstanceStatus, RuntimeError> {
+        let running = self.env.lock().expect("env poisoned").contains_key(id);
+        Ok(InstanceStatus {
+            id: id.clone(),
+            state: if running { "running" } else { "exited" }.to_string(),
+            uptime_seconds: 0,
+            memory_usage_mb: 0,
+            cpu_usage_percent: 0.0,
+        })
+    }
+}
+
+/// Port of `build_history_context` in `assets/bootstrap.py`.
+fn history_context(history_json: &str) -> String {
+    let Ok(history) = serde_json::from_str::<Vec<serde_json::Value>>(history_json) else {
+        return String::new();
+    };
+    if history.is_empty() {
+        return String::new();
+    }
+    // Outputs may be double-encoded by `Value::to_string()`.
+    let field = |item: &serde_json::Value, key: &str| -> Option<String> {
+        let value = match item.get(key)? {
+            serde_json::Value::String(s) if s.is_empty() => return None,
+            serde_json::Value::String(s) => s.clone(),
+            serde_json::Value::Null => return None,
+            other => other.to_string(),
+        };
+        if value.starts_with('"') && value.ends_with('"') {
+            if let Ok(inner) = serde_json::from_str::<String>(&value) {
+                return Some(inner);
+            }
+        }
+        Some(value)
+    };
+
+    let mut ctx = String::from("\n\n# Previous Attempts:\n");
+    for item in &history {
+        let iteration = item
+            .get("iteration")
+            .map_or_else(|| "?".to_string(), ToString::to_string);
+        ctx.push_str(&format!("\n## Iteration {iteration}:\n"));
+        if let Some(output) = field(item, "output") {
+            ctx.push_str(&format!("Output:\n{output}\n"));
+        }
+        if let Some(error) = field(item, "error") {
+            ctx.push_str(&format!("Error:\n{error}\n"));
+        }
+        if let Some(feedback) = field(item, "feedback") {
+            ctx.push_str(&format!("Feedback:\n{feedback}\n"));
+        } else if let Some(reason) = field(item, "validation_reason") {
+            ctx.push_str(&format!("Validation Failed:\n{reason}\n"));
+        }
+    }
+    ctx.push_str("\n# Current Attempt:\n");
+    ctx
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_context_matches_bootstrap_layout() {
+        let history = serde_json::json!([
+            {"iteration": 1, "output": "\"draft\"", "error": null, "feedback": "too short"},
+            {"iteration": 2, "output": "", "error": "boom", "validation_reason": "no match"},
+        ]);
+        assert_eq!(
+            history_context(&history.to_string()),
+            "\n\n# Previous Attempts:\n\
+             \n## Iteration 1:\nOutput:\ndraft\nFeedback:\ntoo short\n\
+             \n## Iteration 2:\nError:\nboom\nValidation Failed:\nno match\n\
+             \n# Current Attempt:\n"
+        );
+        assert_eq!(history_context("[]"), "");
+        assert_eq!(history_context("not json"), "");
+    }
+}
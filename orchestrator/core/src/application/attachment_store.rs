@@ -0,0 +1,162 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Attachment Store (ADR-113)
+//!
+//! Writes files that arrive together with a dispatch — multipart uploads on
+//! `POST /v1/agents/{id}/execute`, email and Slack attachments — to a fresh
+//! ephemeral volume and returns the [`AttachmentRef`]s to put on
+//! [`ExecutionInput::attachments`](crate::domain::execution::ExecutionInput).
+//!
+//! The volume is `Persistent`-owned by the uploader so the agent can read
+//! the files with `aegis.attachment.read` and the uploader can browse them
+//! like any other volume. It expires after [`ATTACHMENT_VOLUME_TTL_HOURS`].
+//! MIME types are content-sniffed; the declared type is only a fallback for
+//! content `infer` cannot classify.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Dispatch-time file uploads → `AttachmentRef`s
+
+use std::sync::Arc;
+
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::application::file_operations_service::{FileOperationsError, FileOperationsService};
+use crate::application::volume_manager::VolumeService;
+use crate::domain::execution::AttachmentRef;
+use crate::domain::shared_kernel::TenantId;
+use crate::domain::volume::{StorageClass, VolumeOwnership};
+
+/// Attachment volumes expire after a week.
+pub const ATTACHMENT_VOLUME_TTL_HOURS: i64 = 24 * 7;
+
+#[derive(Debug, Error)]
+pub enum AttachmentStoreError {
+    #[error("failed to create attachment volume: {0}")]
+    Volume(String),
+
+    #[error("failed to write attachment '{name}': {source}")]
+    Write {
+        name: String,
+        #[source]
+        source: FileOperationsError,
+    },
+}
+
+/// A file to store, as received.
+#[derive(Debug, Clone, Copy)]
+pub struct AttachmentUpload<'a> {
+    pub filename: &'a str,
+    /// MIME type declared by the sender.
+    pub declared_mime_type: Option<&'a str>,
+    pub data: &'a [u8],
+}
+
+pub struct AttachmentStore {
+    volume_service: Arc<dyn VolumeService>,
+    file_operations: Arc<FileOperationsService>,
+}
+
+impl AttachmentStore {
+    pub fn new(
+        volume_service: Arc<dyn VolumeService>,
+        file_operations: Arc<FileOperationsService>,
+    ) -> Self {
+        Self {
+            volume_service,
+            file_operations,
+        }
+    }
+
+    /// Store `uploads` in a new volume named `{name_prefix}-{uuid}` owned by
+    /// `owner`. Returns no refs, and creates no volume, when `uploads` is
+    /// empty. Each file must fit in `max_file_bytes`.
+    pub async fn store(
+        &self,
+        tenant_id: &TenantId,
+        owner: &str,
+        name_prefix: &str,
+        uploads: &[AttachmentUpload<'_>],
+        max_file_bytes: u64,
+    ) -> Result<Vec<AttachmentRef>, AttachmentStoreError> {
+        if uploads.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let total_bytes: u64 = uploads.iter().map(|u| u.data.len() as u64).sum();
+        let volume_id = self
+            .volume_service
+            .create_volume(
+                format!("{name_prefix}-{}", uuid::Uuid::new_v4()),
+                tenant_id.clone(),
+                StorageClass::ephemeral_hours(ATTACHMENT_VOLUME_TTL_HOURS),
+                total_bytes.div_ceil(1024 * 1024) + 1,
+                VolumeOwnership::persistent(owner),
+            )
+            .await
+            .map_err(|e| AttachmentStoreError::Volume(e.to_string()))?;
+
+        let mut refs = Vec::with_capacity(uploads.len());
+        for (index, upload) in uploads.iter().enumerate() {
+            let path = AttachmentRef::storage_path(index, upload.filename);
+            self.file_operations
+                .write_file(
+                    &volume_id,
+                    tenant_id,
+                    owner,
+                    &path,
+                    upload.data,
+                    max_file_bytes,
+                )
+                .await
+                .map_err(|source| AttachmentStoreError::Write {
+                    name: upload.filename.to_string(),
+                    source,
+                })?;
+            refs.push(AttachmentRef {
+                volume_id,
+                path,
+                name: upload.filename.to_string(),
+                mime_type: attachment_mime_type(upload.data, upload.declared_mime_type),
+                size: upload.data.len() as u64,
+                sha256: Some(hex::encode(Sha256::digest(upload.data))),
+            });
+        }
+        Ok(refs)
+    }
+}
+
+/// Content-sniffed MIME type, falling back to the declared one and then to
+/// `application/octet-stream`.
+pub fn attachment_mime_type(data: &[u8], declared: Option<&str>) -> String {
+    infer::get(data)
+        .map(|kind| kind.mime_type())
+        .or(declared.filter(|mime| !mime.trim().is_empty()))
+        .unwrap_or("application/octet-stream")
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sniffed_mime_type_wins_over_declared() {
+        let png = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+        assert_eq!(attachment_mime_type(png, Some("text/plain")), "image/png");
+        assert_eq!(
+            attachment_mime_type(b"hello", Some("text/plain")),
+            "text/plain"
+        );
+        assert_eq!(
+            attachment_mime_type(b"hello", Some(" ")),
+            "application/octet-stream"
+        );
+        assert_eq!(
+            attachment_mime_type(b"hello", None),
+            "application/octet-stream"
+        );
+    }
+}
@@ -16,10 +16,11 @@
 //!   1. Resolve SecurityContext for execution (loads from registry)
 //!   2. Build ContextClaims (agent/exec IDs, 1hr expiry)
 //!   3. SecurityTokenIssuer.issue()  →  RS256 JWT signed by orchestrator key
+//!   3b. Negotiate a payload encryption key when the agent offers one
 //!   4. Create SealSession  (stores session + public key)
 //!   5. SealSessionRepository.save(session)
 //!   ──────────────────────────────────────────────────────────────────────────
-//!   AttestationResponse { security_token: JWT }
+//!   AttestationResponse { security_token: JWT, payload_public_key }
 //!   ▼
 //! Agent container  ← uses JWT in every subsequent SealEnvelope
 //! ```
@@ -57,6 +58,7 @@ use crate::infrastructure::seal::attestation::{
 use crate::infrastructure::seal::envelope::{
     normalize_public_key_bytes, AudienceClaim, ContextClaims,
 };
+use crate::infrastructure::seal::payload_crypto::PayloadKeyRegistry;
 use crate::infrastructure::seal::signature::SecurityTokenIssuer;
 
 /// Concrete implementation of the SEAL attestation ceremony.
@@ -78,6 +80,7 @@ pub struct AttestationServiceImpl {
     execution_service: Option<Arc<dyn ExecutionService>>,
     agent_lifecycle: Option<Arc<dyn AgentLifecycleService>>,
     container_verifier: Option<Arc<dyn ContainerVerificationPort>>,
+    payload_keys: Option<Arc<PayloadKeyRegistry>>,
 }
 
 impl AttestationServiceImpl {
@@ -94,6 +97,7 @@ impl AttestationServiceImpl {
             execution_service: None,
             agent_lifecycle: None,
             container_verifier: None,
+            payload_keys: None,
         }
     }
 
@@ -109,6 +113,14 @@ impl AttestationServiceImpl {
         self
     }
 
+    /// Wire in the registry that holds per-execution payload encryption keys.
+    /// Without it, agents offering a `payload_public_key` fall back to
+    /// plaintext dispatch payloads.
+    pub fn with_payload_keys(mut self, payload_keys: Arc<PayloadKeyRegistry>) -> Self {
+        self.payload_keys = Some(payload_keys);
+        self
+    }
+
     /// Wire in execution and agent lifecycle services so that attestation can
     /// derive `allowed_tool_patterns` from the agent manifest's `spec.tools`.
     pub fn with_agent_manifest_tools(
@@ -250,6 +262,21 @@ impl AttestationServiceImpl {
         // 3. Issue Token
         let token = self.token_issuer.issue(&mut claims)?;
 
+        // 3b. Negotiate the execution's payload encryption key. Re-attesting
+        // without offering one drops any key left by an earlier attestation.
+        let payload_public_key = match (&request.payload_public_key, &self.payload_keys) {
+            (Some(agent_key), Some(payload_keys)) => Some(
+                payload_keys
+                    .negotiate(execution_id, agent_key)
+                    .map_err(|e| anyhow::anyhow!("Payload key negotiation failed: {e}"))?,
+            ),
+            (None, Some(payload_keys)) => {
+                payload_keys.remove(execution_id);
+                None
+            }
+            _ => None,
+        };
+
         // 4. Create and persist SEAL Session
         let public_key_bytes = normalize_public_key_bytes(&request.public_key_pem)?;
         let public_key_b64 = STANDARD.encode(&public_key_bytes);
@@ -407,6 +434,7 @@ impl AttestationServiceImpl {
             security_token: token,
             expires_at,
             session_id,
+            payload_public_key,
         })
     }
 }
@@ -468,6 +496,7 @@ mod tests {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             }],
             deny_list: vec![],
             metadata: SecurityContextMetadata {
@@ -478,6 +507,59 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn attest_negotiates_payload_key_when_offered() {
+        let security_context_repo = Arc::new(InMemorySecurityContextRepository::new());
+        security_context_repo
+            .save(test_context("zaru-pro"))
+            .await
+            .unwrap();
+        let issuer =
+            Arc::new(SecurityTokenIssuer::new(TEST_RSA_PRIVATE_PEM, "aegis-orchestrator").unwrap());
+        let payload_keys = Arc::new(PayloadKeyRegistry::new());
+        let service = AttestationServiceImpl::new(
+            security_context_repo,
+            Arc::new(InMemorySealSessionRepository::new()),
+            issuer,
+        )
+        .with_payload_keys(payload_keys.clone());
+
+        let execution_id = ExecutionId::new();
+        let agent_secret = x25519_dalek::EphemeralSecret::random_from_rng(rand_core::OsRng);
+        let agent_public = x25519_dalek::PublicKey::from(&agent_secret);
+        let request = |payload_public_key| AttestationRequest {
+            agent_id: Some(AgentId::new().0.to_string()),
+            execution_id: Some(execution_id.0.to_string()),
+            container_id: None,
+            public_key_pem: STANDARD.encode(
+                SigningKey::from_bytes(&[9u8; 32])
+                    .verifying_key()
+                    .as_bytes(),
+            ),
+            security_context: Some("zaru-pro".to_string()),
+            principal_subject: None,
+            user_id: None,
+            workload_id: None,
+            zaru_tier: None,
+            tenant_id: TenantId::consumer(),
+            realm: RealmKind::Consumer,
+            task_summary: None,
+            payload_public_key,
+        };
+
+        let response = service
+            .attest(request(Some(STANDARD.encode(agent_public.as_bytes()))))
+            .await
+            .unwrap();
+        assert!(response.payload_public_key.is_some());
+        assert!(payload_keys.get(execution_id).is_ok());
+
+        // Re-attesting without a payload key drops the stale one.
+        let response = service.attest(request(None)).await.unwrap();
+        assert!(response.payload_public_key.is_none());
+        assert!(payload_keys.get(execution_id).is_err());
+    }
+
     #[tokio::test]
     async fn attest_accepts_explicit_security_context_without_agent_or_execution_ids() {
         let security_context_repo = Arc::new(InMemorySecurityContextRepository::new());
@@ -512,6 +594,7 @@ mod tests {
                 tenant_id: TenantId::consumer(),
                 realm: RealmKind::Consumer,
                 task_summary: None,
+                payload_public_key: None,
             })
             .await
             .unwrap();
@@ -556,6 +639,7 @@ mod tests {
                 tenant_id: TenantId::consumer(),
                 realm: RealmKind::Consumer,
                 task_summary: None,
+                payload_public_key: None,
             })
             .await;
 
@@ -589,6 +673,7 @@ mod tests {
                 tenant_id: TenantId::system(),
                 realm: RealmKind::System,
                 task_summary: None,
+                payload_public_key: None,
             })
             .await;
 
@@ -627,6 +712,7 @@ mod tests {
                 tenant_id: TenantId::consumer(),
                 realm: RealmKind::Consumer,
                 task_summary: None,
+                payload_public_key: None,
             })
             .await;
 
@@ -669,6 +755,7 @@ mod tests {
                     slug: "other-corp".to_string(),
                 },
                 task_summary: None,
+                payload_public_key: None,
             })
             .await;
 
@@ -711,6 +798,7 @@ mod tests {
                     slug: "acme-corp".to_string(),
                 },
                 task_summary: None,
+                payload_public_key: None,
             })
             .await;
 
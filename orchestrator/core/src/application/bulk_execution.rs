@@ -0,0 +1,310 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Bulk Execution Operations
+//!
+//! Incident tooling: cancel or requeue every execution matching a filter
+//! expression ([`ExecutionQuery`]), e.g. `agent=foo AND status=running` or
+//! `status=failed AND ended>-1h`.
+//!
+//! - **cancel** applies to matching executions that are still pending or
+//!   running;
+//! - **requeue** applies to matching executions that have finished and
+//!   starts each again as a new execution with the same agent, input and
+//!   security context.
+//!
+//! [`BulkExecutionService::matching`] is the dry-run preview.
+//! [`BulkExecutionService::start`] resolves the matching set once, then works
+//! through it in batches of [`BULK_BATCH_SIZE`] in the background; the
+//! returned [`BulkOperation`] is polled for progress. Operations are held in
+//! memory by the daemon that runs them and dropped an hour after they finish.
+
+use std::sync::Arc;
+
+use chrono::{DateTime, Duration, Utc};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::application::cluster::node_maintenance::RequeuedExecution;
+use crate::application::execution::ExecutionService;
+use crate::domain::execution::{Execution, ExecutionId, ExecutionStatus};
+use crate::domain::execution_query::ExecutionQuery;
+use crate::domain::iam::UserIdentity;
+use crate::domain::repository::{ExecutionRepository, RepositoryError};
+use crate::domain::tenant::TenantId;
+
+/// Executions processed concurrently per batch.
+pub const BULK_BATCH_SIZE: usize = 25;
+
+/// Upper bound on the executions a single operation covers. Narrow the
+/// filter to go further.
+pub const MAX_BULK_EXECUTIONS: usize = 1000;
+
+/// How long a finished operation stays available for polling.
+const FINISHED_OPERATION_RETENTION_HOURS: i64 = 1;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkAction {
+    Cancel,
+    Requeue,
+}
+
+impl BulkAction {
+    /// Whether the action applies to an execution in `status`.
+    pub fn applies_to(&self, status: &ExecutionStatus) -> bool {
+        match self {
+            BulkAction::Cancel => !status.is_terminal(),
+            BulkAction::Requeue => status.is_terminal(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkOperationState {
+    Running,
+    Completed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkFailure {
+    pub execution_id: ExecutionId,
+    pub error: String,
+}
+
+/// Progress of a bulk operation.
+#[derive(Debug, Clone, Serialize)]
+pub struct BulkOperation {
+    pub id: Uuid,
+    #[serde(skip)]
+    pub tenant_id: TenantId,
+    pub action: BulkAction,
+    pub filter: String,
+    pub state: BulkOperationState,
+    /// Executions the filter matched when the operation started.
+    pub total: usize,
+    pub processed: usize,
+    pub succeeded: usize,
+    pub failures: Vec<BulkFailure>,
+    /// Original and new execution ids (requeue only).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub requeued: Vec<RequeuedExecution>,
+    pub started_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl BulkOperation {
+    fn new(tenant_id: TenantId, action: BulkAction, filter: String, total: usize) -> Self {
+        let now = Utc::now();
+        let done = total == 0;
+        Self {
+            id: Uuid::new_v4(),
+            tenant_id,
+            action,
+            filter,
+            state: if done {
+                BulkOperationState::Completed
+            } else {
+                BulkOperationState::Running
+            },
+            total,
+            processed: 0,
+            succeeded: 0,
+            failures: Vec::new(),
+            requeued: Vec::new(),
+            started_at: now,
+            completed_at: done.then_some(now),
+        }
+    }
+
+    /// Fold the outcome of one execution into the progress counters.
+    fn record(&mut self, execution_id: ExecutionId, outcome: anyhow::Result<Option<ExecutionId>>) {
+        self.processed += 1;
+        match outcome {
+            Ok(requeued_as) => {
+                self.succeeded += 1;
+                if let Some(to) = requeued_as {
+                    self.requeued.push(RequeuedExecution {
+                        from: execution_id,
+                        to,
+                    });
+                }
+            }
+            Err(e) => self.failures.push(BulkFailure {
+                execution_id,
+                error: e.to_string(),
+            }),
+        }
+        if self.processed >= self.total {
+            self.state = BulkOperationState::Completed;
+            self.completed_at = Some(Utc::now());
+        }
+    }
+}
+
+pub struct BulkExecutionService {
+    execution_service: Arc<dyn ExecutionService>,
+    execution_repo: Arc<dyn ExecutionRepository>,
+    operations: Arc<DashMap<Uuid, BulkOperation>>,
+}
+
+impl BulkExecutionService {
+    pub fn new(
+        execution_service: Arc<dyn ExecutionService>,
+        execution_repo: Arc<dyn ExecutionRepository>,
+    ) -> Self {
+        Self {
+            execution_service,
+            execution_repo,
+            operations: Arc::new(DashMap::new()),
+        }
+    }
+
+    /// The tenant's executions `action` would apply to, newest first. Agent
+    /// names in `query` must already be resolved.
+    pub async fn matching(
+        &self,
+        tenant_id: &TenantId,
+        action: BulkAction,
+        query: &ExecutionQuery,
+    ) -> Result<Vec<Execution>, RepositoryError> {
+        let mut executions = self
+            .execution_repo
+            .find_by_query_for_tenant(tenant_id, query, MAX_BULK_EXECUTIONS)
+            .await?;
+        executions.retain(|execution| action.applies_to(&execution.status));
+        Ok(executions)
+    }
+
+    /// Start `action` on every matching execution in the background and
+    /// return the operation to poll. Requeued executions are started as
+    /// `identity`.
+    pub async fn start(
+        &self,
+        tenant_id: &TenantId,
+        action: BulkAction,
+        filter: String,
+        query: &ExecutionQuery,
+        identity: Option<UserIdentity>,
+    ) -> Result<BulkOperation, RepositoryError> {
+        let executions = self.matching(tenant_id, action, query).await?;
+        let cutoff = Utc::now() - Duration::hours(FINISHED_OPERATION_RETENTION_HOURS);
+        self.operations
+            .retain(|_, op| op.completed_at.is_none_or(|at| at > cutoff));
+
+        let operation = BulkOperation::new(tenant_id.clone(), action, filter, executions.len());
+        self.operations.insert(operation.id, operation.clone());
+        metrics::counter!("aegis_bulk_execution_operations_total", "action" => match action {
+            BulkAction::Cancel => "cancel",
+            BulkAction::Requeue => "requeue",
+        })
+        .increment(1);
+        tracing::info!(
+            operation_id = %operation.id,
+            action = ?action,
+            filter = %operation.filter,
+            total = executions.len(),
+            "Starting bulk execution operation"
+        );
+
+        tokio::spawn(Self::run(
+            self.execution_service.clone(),
+            self.operations.clone(),
+            operation.id,
+            tenant_id.clone(),
+            action,
+            executions,
+            identity,
+        ));
+        Ok(operation)
+    }
+
+    /// Current progress of one of the tenant's operations.
+    pub fn get_for_tenant(&self, tenant_id: &TenantId, id: Uuid) -> Option<BulkOperation> {
+        self.operations
+            .get(&id)
+            .filter(|op| op.tenant_id == *tenant_id)
+            .map(|op| op.clone())
+    }
+
+    async fn run(
+        execution_service: Arc<dyn ExecutionService>,
+        operations: Arc<DashMap<Uuid, BulkOperation>>,
+        id: Uuid,
+        tenant_id: TenantId,
+        action: BulkAction,
+        executions: Vec<Execution>,
+        identity: Option<UserIdentity>,
+    ) {
+        for batch in executions.chunks(BULK_BATCH_SIZE) {
+            let outcomes = futures::future::join_all(batch.iter().map(|execution| async {
+                match action {
+                    BulkAction::Cancel => execution_service
+                        .cancel_execution_for_tenant(&tenant_id, execution.id)
+                        .await
+                        .map(|_| None),
+                    BulkAction::Requeue => execution_service
+                        .start_execution(
+                            execution.agent_id,
+                            execution.input.clone(),
+                            execution.security_context_name.clone(),
+                            identity.as_ref(),
+                        )
+                        .await
+                        .map(Some),
+                }
+            }))
+            .await;
+
+            if let Some(mut operation) = operations.get_mut(&id) {
+                for (execution, outcome) in batch.iter().zip(outcomes) {
+                    operation.record(execution.id, outcome);
+                }
+            }
+        }
+
+        if let Some(operation) = operations.get(&id) {
+            tracing::info!(
+                operation_id = %id,
+                succeeded = operation.succeeded,
+                failed = operation.failures.len(),
+                "Bulk execution operation finished"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operation_completes_once_every_execution_is_recorded() {
+        assert!(BulkAction::Cancel.applies_to(&ExecutionStatus::Running));
+        assert!(!BulkAction::Cancel.applies_to(&ExecutionStatus::Failed));
+        assert!(BulkAction::Requeue.applies_to(&ExecutionStatus::Failed));
+        assert!(!BulkAction::Requeue.applies_to(&ExecutionStatus::Pending));
+
+        let empty = BulkOperation::new(TenantId::default(), BulkAction::Cancel, String::new(), 0);
+        assert_eq!(empty.state, BulkOperationState::Completed);
+
+        let mut operation = BulkOperation::new(
+            TenantId::default(),
+            BulkAction::Requeue,
+            "status=failed".to_string(),
+            2,
+        );
+        let (first, second, requeued_as) =
+            (ExecutionId::new(), ExecutionId::new(), ExecutionId::new());
+        operation.record(first, Ok(Some(requeued_as)));
+        assert_eq!(operation.state, BulkOperationState::Running);
+        operation.record(second, Err(anyhow::anyhow!("agent not found")));
+
+        assert_eq!(operation.state, BulkOperationState::Completed);
+        assert_eq!((operation.processed, operation.succeeded), (2, 1));
+        assert_eq!(operation.failures[0].execution_id, second);
+        assert_eq!(operation.requeued[0].to, requeued_as);
+    }
+}
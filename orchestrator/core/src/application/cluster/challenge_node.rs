@@ -362,6 +362,9 @@ mod tests {
         async fn start_drain(&self, _: &NodeId) -> anyhow::Result<()> {
             Ok(())
         }
+        async fn end_drain(&self, _: &NodeId) -> anyhow::Result<()> {
+            Ok(())
+        }
         async fn deregister(&self, _: &NodeId, _: &str) -> anyhow::Result<()> {
             Ok(())
         }
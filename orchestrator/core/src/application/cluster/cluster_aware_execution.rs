@@ -292,6 +292,15 @@ impl ExecutionService for ClusterAwareExecutionService {
         self.inner.get_execution_unscoped(id).await
     }
 
+    async fn wait_for_terminal(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+        timeout: std::time::Duration,
+    ) -> Result<Execution> {
+        self.inner.wait_for_terminal(tenant_id, id, timeout).await
+    }
+
     async fn get_iterations_for_tenant(
         &self,
         tenant_id: &TenantId,
@@ -0,0 +1,393 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Daemon Failover (spec.high_availability)
+//!
+//! Active/standby pairs of daemons sharing one Postgres database. Both run
+//! the same config; the lease in `daemon_leases` decides which is active.
+//!
+//! - At startup each daemon calls [`DaemonFailover::wait_until_active`]
+//!   before binding NFS, HTTP or gRPC. The first to take the lease proceeds;
+//!   the other stands by, polling every `renew_interval_seconds` and logging
+//!   which daemon is active.
+//! - The active renews through [`DaemonFailover::hold`]. When a renewal is
+//!   refused (the lease has passed to the standby) or renewals keep failing
+//!   for long enough that the standby may already have taken over, `hold`
+//!   returns and the daemon must shut down: this is the fence against
+//!   split-brain.
+//! - A daemon that becomes active fails the executions still pending or
+//!   running in the database ([`DaemonFailover::reconcile_executions`]); they
+//!   were driven by the previous active's in-process supervisor and will not
+//!   progress on their own.
+//!
+//! On graceful shutdown the active releases the lease so the standby takes
+//! over on its next poll instead of waiting out the TTL.
+
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
+
+use uuid::Uuid;
+
+use crate::domain::cluster::{DaemonLease, DaemonLeaseRepository};
+use crate::domain::node_config::HighAvailabilityConfig;
+use crate::domain::repository::ExecutionRepository;
+
+/// Executions reconciled per repository round-trip.
+const RECONCILE_BATCH: usize = 500;
+
+/// Why [`DaemonFailover::hold`] stopped holding the lease.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeaseLost {
+    /// Another daemon now holds the lease.
+    TakenOver,
+    /// Renewals failed for too long to be sure the lease is still ours.
+    RenewalTimedOut,
+    /// This daemon released the lease on shutdown.
+    Released,
+}
+
+pub struct DaemonFailover {
+    repository: Arc<dyn DaemonLeaseRepository>,
+    config: HighAvailabilityConfig,
+    /// Unique per process, so a restarted daemon never renews the lease of
+    /// its previous incarnation.
+    holder: String,
+    lease: RwLock<Option<DaemonLease>>,
+}
+
+impl DaemonFailover {
+    pub fn new(
+        repository: Arc<dyn DaemonLeaseRepository>,
+        config: HighAvailabilityConfig,
+        node_id: &str,
+    ) -> Self {
+        Self {
+            repository,
+            config,
+            holder: format!("{node_id}/{}", Uuid::new_v4()),
+            lease: RwLock::new(None),
+        }
+    }
+
+    pub fn holder(&self) -> &str {
+        &self.holder
+    }
+
+    /// The lease while this daemon is active.
+    pub fn lease(&self) -> Option<DaemonLease> {
+        self.lease.read().unwrap().clone()
+    }
+
+    /// Block until this daemon holds the lease.
+    pub async fn wait_until_active(&self) -> DaemonLease {
+        let name = &self.config.lease_name;
+        let mut active_holder: Option<String> = None;
+        loop {
+            match self
+                .repository
+                .try_acquire(name, &self.holder, self.config.lease_ttl())
+                .await
+            {
+                Ok(Some(lease)) => {
+                    tracing::info!(
+                        lease = %name,
+                        holder = %self.holder,
+                        epoch = lease.epoch,
+                        "Acquired failover lease; this daemon is now active"
+                    );
+                    *self.lease.write().unwrap() = Some(lease.clone());
+                    return lease;
+                }
+                Ok(None) => {
+                    if let Ok(Some(current)) = self.repository.current(name).await {
+                        if active_holder.as_deref() != Some(current.holder.as_str()) {
+                            tracing::info!(
+                                lease = %name,
+                                active = %current.holder,
+                                expires_at = %current.expires_at,
+                                "Standing by; another daemon holds the failover lease"
+                            );
+                            active_holder = Some(current.holder);
+                        }
+                    }
+                }
+                Err(e) => tracing::warn!(
+                    lease = %name,
+                    error = %e,
+                    "Failed to reach the lease store; retrying"
+                ),
+            }
+            tokio::time::sleep(self.config.renew_interval()).await;
+        }
+    }
+
+    /// Renew the lease until it is lost. The caller must stop serving as
+    /// soon as this returns.
+    pub async fn hold(&self) -> LeaseLost {
+        let Some(lease) = self.lease() else {
+            return LeaseLost::TakenOver;
+        };
+        let ttl = self.config.lease_ttl();
+        let interval = self.config.renew_interval();
+        // Give up one interval before the lease could expire, so this daemon
+        // is down before the standby can take over.
+        let fence_after = ttl.saturating_sub(interval);
+        let mut last_renewed = Instant::now();
+
+        loop {
+            tokio::time::sleep(interval).await;
+            if self.lease().is_none() {
+                return LeaseLost::Released;
+            }
+            let attempt = Instant::now();
+            // A hung database connection counts as a failed renewal.
+            let renewed = tokio::time::timeout(
+                interval,
+                self.repository
+                    .renew(&lease.name, &self.holder, lease.epoch, ttl),
+            )
+            .await
+            .unwrap_or_else(|_| Err(anyhow::anyhow!("renewal timed out after {interval:?}")));
+            match renewed {
+                Ok(true) => last_renewed = attempt,
+                Ok(false) => {
+                    tracing::error!(
+                        lease = %lease.name,
+                        epoch = lease.epoch,
+                        "Failover lease was taken over by another daemon"
+                    );
+                    *self.lease.write().unwrap() = None;
+                    return LeaseLost::TakenOver;
+                }
+                Err(e) => {
+                    tracing::warn!(lease = %lease.name, error = %e, "Failed to renew failover lease");
+                    if last_renewed.elapsed() >= fence_after {
+                        tracing::error!(
+                            lease = %lease.name,
+                            "Could not renew failover lease before it expires"
+                        );
+                        *self.lease.write().unwrap() = None;
+                        return LeaseLost::RenewalTimedOut;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Give up the lease so the standby can take over immediately.
+    pub async fn release(&self) {
+        let Some(lease) = self.lease.write().unwrap().take() else {
+            return;
+        };
+        if let Err(e) = self
+            .repository
+            .release(&lease.name, &self.holder, lease.epoch)
+            .await
+        {
+            tracing::warn!(lease = %lease.name, error = %e, "Failed to release failover lease");
+        }
+    }
+
+    /// Fail every execution still pending or running. Call once, right
+    /// after becoming active and before accepting new work. Returns how many
+    /// executions were failed.
+    pub async fn reconcile_executions(
+        &self,
+        executions: &dyn ExecutionRepository,
+    ) -> anyhow::Result<usize> {
+        let mut reconciled = 0;
+        loop {
+            let batch = executions.find_unfinished_all(RECONCILE_BATCH).await?;
+            if batch.is_empty() {
+                break;
+            }
+            let mut saved = 0;
+            for mut execution in batch {
+                execution.fail(
+                    "Interrupted by daemon failover: the previously active daemon stopped \
+                     before this execution finished"
+                        .to_string(),
+                );
+                match executions
+                    .save_for_tenant(&execution.tenant_id, &execution)
+                    .await
+                {
+                    Ok(()) => saved += 1,
+                    Err(e) => tracing::warn!(
+                        execution_id = %execution.id,
+                        error = %e,
+                        "Failed to reconcile execution after failover"
+                    ),
+                }
+            }
+            reconciled += saved;
+            if saved == 0 {
+                break;
+            }
+        }
+        if reconciled > 0 {
+            tracing::info!(
+                count = reconciled,
+                "Failed executions left unfinished by the previous active daemon"
+            );
+        }
+        Ok(reconciled)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::agent::AgentId;
+    use crate::domain::execution::{Execution, ExecutionInput, ExecutionStatus};
+    use crate::infrastructure::repositories::InMemoryExecutionRepository;
+    use chrono::Utc;
+    use std::sync::Mutex;
+    use std::time::Duration;
+
+    #[derive(Default)]
+    struct InMemoryLeases {
+        leases: Mutex<Option<DaemonLease>>,
+    }
+
+    #[async_trait::async_trait]
+    impl DaemonLeaseRepository for InMemoryLeases {
+        async fn try_acquire(
+            &self,
+            name: &str,
+            holder: &str,
+            ttl: Duration,
+        ) -> anyhow::Result<Option<DaemonLease>> {
+            let mut lease = self.leases.lock().unwrap();
+            let now = Utc::now();
+            let epoch = match lease.as_ref() {
+                Some(l) if l.expires_at > now && l.holder != holder => return Ok(None),
+                Some(l) => l.epoch + 1,
+                None => 1,
+            };
+            *lease = Some(DaemonLease {
+                name: name.to_string(),
+                holder: holder.to_string(),
+                epoch,
+                acquired_at: now,
+                renewed_at: now,
+                expires_at: now + chrono::Duration::from_std(ttl).unwrap(),
+            });
+            Ok(lease.clone())
+        }
+
+        async fn renew(
+            &self,
+            _name: &str,
+            holder: &str,
+            epoch: i64,
+            ttl: Duration,
+        ) -> anyhow::Result<bool> {
+            let mut lease = self.leases.lock().unwrap();
+            match lease.as_mut() {
+                Some(l) if l.holder == holder && l.epoch == epoch => {
+                    l.expires_at = Utc::now() + chrono::Duration::from_std(ttl).unwrap();
+                    Ok(true)
+                }
+                _ => Ok(false),
+            }
+        }
+
+        async fn release(&self, _name: &str, holder: &str, epoch: i64) -> anyhow::Result<()> {
+            if let Some(l) = self.leases.lock().unwrap().as_mut() {
+                if l.holder == holder && l.epoch == epoch {
+                    l.expires_at = Utc::now();
+                }
+            }
+            Ok(())
+        }
+
+        async fn current(&self, _name: &str) -> anyhow::Result<Option<DaemonLease>> {
+            Ok(self.leases.lock().unwrap().clone())
+        }
+    }
+
+    fn config() -> HighAvailabilityConfig {
+        HighAvailabilityConfig {
+            enabled: true,
+            lease_name: "aegis-primary".to_string(),
+            lease_ttl_seconds: 15,
+            renew_interval_seconds: 5,
+        }
+    }
+
+    #[tokio::test]
+    async fn standby_takes_over_released_lease_and_fences_old_active() {
+        let leases = Arc::new(InMemoryLeases::default());
+        let active = DaemonFailover::new(leases.clone(), config(), "node-a");
+        let standby = DaemonFailover::new(leases.clone(), config(), "node-b");
+
+        let first = active.wait_until_active().await;
+        assert!(leases
+            .try_acquire("aegis-primary", standby.holder(), Duration::from_secs(15))
+            .await
+            .unwrap()
+            .is_none());
+
+        active.release().await;
+        let second = standby.wait_until_active().await;
+        assert_eq!(second.epoch, first.epoch + 1);
+        assert!(active.lease().is_none());
+
+        // The old epoch can no longer be renewed.
+        assert!(!leases
+            .renew(
+                "aegis-primary",
+                active.holder(),
+                first.epoch,
+                Duration::from_secs(15)
+            )
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn reconcile_fails_unfinished_executions() {
+        let executions = InMemoryExecutionRepository::new();
+        let mut ids = Vec::new();
+        for status in [ExecutionStatus::Running, ExecutionStatus::Completed] {
+            let mut execution = Execution::new(
+                AgentId::new(),
+                ExecutionInput {
+                    intent: Some("failover".to_string()),
+                    input: serde_json::json!({}),
+                    workspace_volume_id: None,
+                    workspace_volume_mount_path: None,
+                    workspace_remote_path: None,
+                    workflow_execution_id: None,
+                    attachments: Vec::new(),
+                },
+                5,
+                "default".to_string(),
+            );
+            execution.status = status;
+            executions
+                .save_for_tenant(&execution.tenant_id, &execution)
+                .await
+                .unwrap();
+            ids.push((execution.tenant_id.clone(), execution.id));
+        }
+
+        let failover = DaemonFailover::new(Arc::new(InMemoryLeases::default()), config(), "node-b");
+        assert_eq!(failover.reconcile_executions(&executions).await.unwrap(), 1);
+
+        let (tenant, running) = &ids[0];
+        let reconciled = executions
+            .find_by_id_for_tenant(tenant, *running)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(reconciled.status, ExecutionStatus::Failed);
+        let (tenant, completed) = &ids[1];
+        let untouched = executions
+            .find_by_id_for_tenant(tenant, *completed)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(untouched.status, ExecutionStatus::Completed);
+    }
+}
@@ -180,6 +180,9 @@ mod tests {
         async fn start_drain(&self, _: &NodeId) -> anyhow::Result<()> {
             unimplemented!()
         }
+        async fn end_drain(&self, _: &NodeId) -> anyhow::Result<()> {
+            unimplemented!()
+        }
         async fn deregister(&self, _: &NodeId, _: &str) -> anyhow::Result<()> {
             unimplemented!()
         }
@@ -46,14 +46,20 @@ impl HeartbeatUseCase {
             .record_heartbeat(&req.node_id, snapshot)
             .await?;
 
-        // 2. The single-node Phase 1 baseline has no command queue; only synthesize a drain
-        // command when the repository already marks the peer as draining.
-        let mut pending_commands = Vec::new();
+        // 2. The node's reported status carries its maintenance state: a
+        // cordoned node reports Draining until it is uncordoned. Mirror that
+        // into the registry so routing skips the node while it is cordoned.
+        let pending_commands = Vec::new();
 
         if let Some(peer) = self.cluster_repo.find_peer(&req.node_id).await? {
-            if peer.status == NodePeerStatus::Draining && req.status == NodePeerStatus::Active {
-                // Controller wants to drain, but node still thinks it's active
-                pending_commands.push(NodeCommand::Drain(true));
+            match (peer.status, req.status) {
+                (NodePeerStatus::Active, NodePeerStatus::Draining) => {
+                    self.cluster_repo.start_drain(&req.node_id).await?;
+                }
+                (NodePeerStatus::Draining, NodePeerStatus::Active) => {
+                    self.cluster_repo.end_drain(&req.node_id).await?;
+                }
+                _ => {}
             }
         }
 
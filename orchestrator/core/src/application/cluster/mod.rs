@@ -4,9 +4,11 @@
 pub mod attest_node;
 pub mod challenge_node;
 pub mod cluster_aware_execution;
+pub mod daemon_failover;
 pub mod forward_execution;
 pub mod health_sweeper;
 pub mod heartbeat;
+pub mod node_maintenance;
 pub mod push_config;
 pub mod register_node;
 pub mod route_execution;
@@ -15,9 +17,11 @@ pub mod sync_config;
 pub use attest_node::*;
 pub use challenge_node::*;
 pub use cluster_aware_execution::{ClusterAwareExecutionService, ClusterForwardingConfig};
+pub use daemon_failover::{DaemonFailover, LeaseLost};
 pub use forward_execution::*;
 pub use health_sweeper::HealthSweeper;
 pub use heartbeat::*;
+pub use node_maintenance::{NodeCordonedError, NodeMaintenanceService};
 pub use push_config::PushConfigUseCase;
 pub use register_node::*;
 pub use route_execution::*;
@@ -0,0 +1,267 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Node Maintenance (cordon / uncordon)
+//!
+//! `aegis daemon cordon` puts this node into maintenance before it is patched:
+//!
+//! - new root executions are rejected with [`NodeCordonedError`]; child
+//!   executions of work already running (judges, sub-agents) still start, so
+//!   running executions finish normally;
+//! - the node's peer record in the cluster registry moves to
+//!   [`NodePeerStatus::Draining`](crate::domain::cluster::NodePeerStatus), so
+//!   the controller stops routing to it. Controllers and hybrids update the
+//!   registry directly; workers report the state on their next heartbeat;
+//! - executions still queued behind a concurrency group are requeued through
+//!   the requeue target (the cluster-routing execution service) when one is
+//!   installed, i.e. in multi-node setups. Each is started elsewhere first and
+//!   only then cancelled here, so a failed hand-off leaves it queued locally.
+//!
+//! `aegis daemon uncordon` reverses the first two. Maintenance state is held
+//! in memory and does not survive a daemon restart.
+
+use std::sync::{Arc, OnceLock, RwLock};
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use serde::Serialize;
+
+use crate::application::execution::ExecutionService;
+use crate::domain::agent::AgentId;
+use crate::domain::cluster::{NodeClusterRepository, NodeId};
+use crate::domain::execution::{ExecutionId, ExecutionInput};
+use crate::domain::tenant::TenantId;
+
+#[derive(Debug, thiserror::Error)]
+#[error("node is cordoned for maintenance and is not accepting new executions")]
+pub struct NodeCordonedError;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CordonState {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub cordoned_by: String,
+    pub cordoned_at: DateTime<Utc>,
+}
+
+/// What a queued execution needs to be started again on another node.
+#[derive(Debug, Clone)]
+pub struct QueuedExecution {
+    pub tenant_id: TenantId,
+    pub agent_id: AgentId,
+    pub input: ExecutionInput,
+    pub security_context_name: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct MaintenanceStatus {
+    pub cordoned: bool,
+    #[serde(flatten)]
+    pub cordon: Option<CordonState>,
+    /// Executions waiting for a concurrency group on this node.
+    pub queued_executions: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RequeuedExecution {
+    pub from: ExecutionId,
+    pub to: ExecutionId,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CordonOutcome {
+    #[serde(flatten)]
+    pub status: MaintenanceStatus,
+    pub requeued: Vec<RequeuedExecution>,
+}
+
+#[derive(Default)]
+pub struct NodeMaintenanceService {
+    cordon: RwLock<Option<CordonState>>,
+    queued: DashMap<ExecutionId, QueuedExecution>,
+    /// This node's entry in the cluster registry. Set once at the
+    /// composition root on nodes that own the registry.
+    registry: OnceLock<(Arc<dyn NodeClusterRepository>, NodeId)>,
+    /// Cluster-routing execution service used to move queued executions.
+    requeue_target: OnceLock<Arc<dyn ExecutionService>>,
+}
+
+impl NodeMaintenanceService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mirror cordon state into `node_id`'s peer status in the registry.
+    pub fn set_cluster_registry(
+        &self,
+        repository: Arc<dyn NodeClusterRepository>,
+        node_id: NodeId,
+    ) {
+        let _ = self.registry.set((repository, node_id));
+    }
+
+    /// Wire the execution service that places requeued executions on other nodes.
+    pub fn set_requeue_target(&self, service: Arc<dyn ExecutionService>) {
+        let _ = self.requeue_target.set(service);
+    }
+
+    pub fn is_cordoned(&self) -> bool {
+        self.cordon.read().unwrap().is_some()
+    }
+
+    /// Called before a new root execution is admitted.
+    pub fn ensure_schedulable(&self) -> Result<(), NodeCordonedError> {
+        if self.is_cordoned() {
+            Err(NodeCordonedError)
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Record an execution that is waiting for its concurrency group.
+    pub fn track_queued(&self, execution_id: ExecutionId, queued: QueuedExecution) {
+        self.queued.insert(execution_id, queued);
+    }
+
+    /// Forget a queued execution once it is admitted, cancelled or requeued.
+    pub fn untrack_queued(&self, execution_id: ExecutionId) {
+        self.queued.remove(&execution_id);
+    }
+
+    pub fn status(&self) -> MaintenanceStatus {
+        let cordon = self.cordon.read().unwrap().clone();
+        MaintenanceStatus {
+            cordoned: cordon.is_some(),
+            cordon,
+            queued_executions: self.queued.len(),
+        }
+    }
+
+    /// Enter maintenance. Cordoning an already cordoned node keeps the
+    /// original reason and timestamp but retries the registry update and
+    /// requeue.
+    pub async fn cordon(
+        &self,
+        reason: Option<String>,
+        cordoned_by: String,
+    ) -> anyhow::Result<CordonOutcome> {
+        {
+            let mut cordon = self.cordon.write().unwrap();
+            if cordon.is_none() {
+                *cordon = Some(CordonState {
+                    reason,
+                    cordoned_by,
+                    cordoned_at: Utc::now(),
+                });
+            }
+        }
+        tracing::info!("Node cordoned for maintenance; rejecting new executions");
+
+        if let Some((repository, node_id)) = self.registry.get() {
+            repository.start_drain(node_id).await?;
+        }
+
+        let requeued = self.requeue_queued().await;
+        Ok(CordonOutcome {
+            status: self.status(),
+            requeued,
+        })
+    }
+
+    /// Leave maintenance and accept new executions again.
+    pub async fn uncordon(&self) -> anyhow::Result<MaintenanceStatus> {
+        *self.cordon.write().unwrap() = None;
+        tracing::info!("Node uncordoned; accepting new executions");
+
+        if let Some((repository, node_id)) = self.registry.get() {
+            repository.end_drain(node_id).await?;
+        }
+        Ok(self.status())
+    }
+
+    async fn requeue_queued(&self) -> Vec<RequeuedExecution> {
+        let Some(target) = self.requeue_target.get() else {
+            return Vec::new();
+        };
+
+        let pending: Vec<(ExecutionId, QueuedExecution)> = self
+            .queued
+            .iter()
+            .map(|entry| (*entry.key(), entry.value().clone()))
+            .collect();
+
+        let mut requeued = Vec::new();
+        for (execution_id, queued) in pending {
+            let started = target
+                .start_execution(
+                    queued.agent_id,
+                    queued.input,
+                    queued.security_context_name,
+                    None,
+                )
+                .await;
+            let new_id = match started {
+                Ok(new_id) => new_id,
+                Err(e) => {
+                    tracing::warn!(
+                        %execution_id,
+                        error = %e,
+                        "Failed to requeue queued execution; leaving it queued on this node"
+                    );
+                    continue;
+                }
+            };
+            if let Err(e) = target
+                .cancel_execution_for_tenant(&queued.tenant_id, execution_id)
+                .await
+            {
+                tracing::warn!(
+                    %execution_id,
+                    requeued_as = %new_id,
+                    error = %e,
+                    "Failed to cancel requeued execution on this node"
+                );
+            }
+            self.untrack_queued(execution_id);
+            tracing::info!(%execution_id, requeued_as = %new_id, "Requeued execution off cordoned node");
+            requeued.push(RequeuedExecution {
+                from: execution_id,
+                to: new_id,
+            });
+        }
+        requeued
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn cordon_rejects_new_executions_until_uncordoned() {
+        let service = NodeMaintenanceService::new();
+        assert!(service.ensure_schedulable().is_ok());
+
+        let outcome = service
+            .cordon(Some("kernel patch".to_string()), "ops".to_string())
+            .await
+            .unwrap();
+        assert!(outcome.status.cordoned);
+        assert!(outcome.requeued.is_empty());
+        assert!(service.ensure_schedulable().is_err());
+
+        // A second cordon keeps the original reason.
+        service
+            .cordon(None, "someone-else".to_string())
+            .await
+            .unwrap();
+        let status = service.status();
+        assert_eq!(
+            status.cordon.as_ref().and_then(|c| c.reason.as_deref()),
+            Some("kernel patch")
+        );
+
+        let status = service.uncordon().await.unwrap();
+        assert!(!status.cordoned);
+        assert!(service.ensure_schedulable().is_ok());
+    }
+}
@@ -165,6 +165,9 @@ mod tests {
         async fn start_drain(&self, _node_id: &NodeId) -> anyhow::Result<()> {
             Ok(())
         }
+        async fn end_drain(&self, _node_id: &NodeId) -> anyhow::Result<()> {
+            Ok(())
+        }
         async fn deregister(&self, _node_id: &NodeId, _reason: &str) -> anyhow::Result<()> {
             Ok(())
         }
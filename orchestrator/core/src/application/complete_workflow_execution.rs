@@ -304,9 +304,12 @@ mod tests {
             WorkflowSpec {
                 initial_state: StateName::new("START").unwrap(),
                 context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
                 states,
                 storage: Default::default(),
                 max_total_transitions: None,
+                concurrency: None,
             },
         )
         .unwrap()
@@ -0,0 +1,459 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Concurrency Group Service
+//!
+//! Enforces manifest `spec.concurrency` groups
+//! ([`crate::domain::concurrency::ConcurrencySpec`]) for agent and workflow
+//! executions on top of the shared [`LockService`].
+//!
+//! An execution that declares a group must be admitted before it runs:
+//!
+//! | Policy | Group free | Group held |
+//! |---|---|---|
+//! | `reject` | admitted | [`ConcurrencyError::GroupBusy`] |
+//! | `queue` | admitted | queued behind earlier executions (FIFO) |
+//! | `cancel_in_progress` | admitted | holder is cancelled, then queued |
+//!
+//! The group is released when the holder emits a terminal execution or
+//! workflow event (see [`ConcurrencyGroupService::spawn_release_listener`]).
+//! A holder that is already terminal in its repository — a lost event or a
+//! crashed start — is reclaimed the next time the group is contended.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Implements internal responsibilities for concurrency group
+
+use crate::application::execution::ExecutionService;
+use crate::application::execution_completion::terminal_execution_id;
+use crate::application::lock_service::{
+    LockAcquisition, LockError, LockHolder, LockService, LockWaiter,
+};
+use crate::application::ports::WorkflowExecutionControlPort;
+use crate::domain::concurrency::{ConcurrencyPolicy, ConcurrencySpec};
+use crate::domain::events::WorkflowEvent;
+use crate::domain::execution::ExecutionId;
+use crate::domain::repository::{ExecutionRepository, WorkflowExecutionRepository};
+use crate::domain::tenant::TenantId;
+use crate::infrastructure::event_bus::{DomainEvent, EventBus, EventBusError, SubscriptionOptions};
+use std::sync::{Arc, OnceLock};
+use tracing::{info, warn};
+
+/// Lock-service resource name for a concurrency group.
+pub fn concurrency_group_resource(group: &str) -> String {
+    format!("concurrency-group/{group}")
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ConcurrencyError {
+    #[error("concurrency group '{group}' is busy: execution {holder} is in progress")]
+    GroupBusy { group: String, holder: ExecutionId },
+    #[error(transparent)]
+    Lock(#[from] LockError),
+}
+
+/// Result of [`ConcurrencyGroupService::admit`].
+pub enum ConcurrencyAdmission {
+    /// The group was free; the execution may run now.
+    Admitted,
+    /// The execution must wait for [`LockWaiter::acquired`] before running.
+    Queued(LockWaiter),
+}
+
+pub struct ConcurrencyGroupService {
+    locks: Arc<dyn LockService>,
+    execution_repository: Arc<dyn ExecutionRepository>,
+    workflow_execution_repository: Arc<dyn WorkflowExecutionRepository>,
+    /// Cancels agent holders under `cancel_in_progress`. Set once at the
+    /// composition root because the execution service itself depends on this
+    /// service.
+    execution_service: OnceLock<Arc<dyn ExecutionService>>,
+    /// Cancels workflow holders under `cancel_in_progress`.
+    workflow_control: Option<Arc<dyn WorkflowExecutionControlPort>>,
+}
+
+impl ConcurrencyGroupService {
+    pub fn new(
+        locks: Arc<dyn LockService>,
+        execution_repository: Arc<dyn ExecutionRepository>,
+        workflow_execution_repository: Arc<dyn WorkflowExecutionRepository>,
+    ) -> Self {
+        Self {
+            locks,
+            execution_repository,
+            workflow_execution_repository,
+            execution_service: OnceLock::new(),
+            workflow_control: None,
+        }
+    }
+
+    /// Attach the port used to cancel in-progress workflow executions.
+    pub fn with_workflow_control(mut self, port: Arc<dyn WorkflowExecutionControlPort>) -> Self {
+        self.workflow_control = Some(port);
+        self
+    }
+
+    /// Wire the execution service used to cancel in-progress agent executions.
+    pub fn set_execution_service(&self, service: Arc<dyn ExecutionService>) {
+        let _ = self.execution_service.set(service);
+    }
+
+    /// Admit `holder` into the group described by `spec`.
+    pub async fn admit(
+        &self,
+        tenant_id: &TenantId,
+        spec: &ConcurrencySpec,
+        holder: LockHolder,
+    ) -> Result<ConcurrencyAdmission, ConcurrencyError> {
+        let resource = concurrency_group_resource(&spec.group);
+
+        if spec.policy == ConcurrencyPolicy::Reject {
+            let mut reclaimed = false;
+            loop {
+                let current = match self
+                    .locks
+                    .try_acquire(tenant_id, &resource, holder, None)
+                    .await
+                {
+                    Ok(_) => {
+                        record_admission(spec.policy, "admitted");
+                        return Ok(ConcurrencyAdmission::Admitted);
+                    }
+                    Err(LockError::Held {
+                        holder: current, ..
+                    }) => current,
+                    Err(e) => return Err(e.into()),
+                };
+                // Retry once: the first contention may be a stale holder.
+                if !reclaimed && self.reclaim_if_stale(tenant_id, current).await {
+                    reclaimed = true;
+                    continue;
+                }
+                record_admission(spec.policy, "rejected");
+                return Err(ConcurrencyError::GroupBusy {
+                    group: spec.group.clone(),
+                    holder: current.execution_id,
+                });
+            }
+        }
+
+        let (current, waiter) = match self
+            .locks
+            .acquire_or_enqueue(tenant_id, &resource, holder, None)
+            .await
+        {
+            LockAcquisition::Acquired(_) => {
+                record_admission(spec.policy, "admitted");
+                return Ok(ConcurrencyAdmission::Admitted);
+            }
+            LockAcquisition::Queued { current, waiter } => (current, waiter),
+        };
+        record_admission(spec.policy, "queued");
+
+        if !self.reclaim_if_stale(tenant_id, current).await
+            && spec.policy == ConcurrencyPolicy::CancelInProgress
+        {
+            self.cancel_holder(tenant_id, current).await;
+        }
+        info!(
+            group = %spec.group,
+            execution_id = %holder.execution_id,
+            blocked_by = %current.execution_id,
+            policy = spec.policy.as_str(),
+            "Execution queued behind concurrency group holder"
+        );
+        Ok(ConcurrencyAdmission::Queued(waiter))
+    }
+
+    /// Release every group held or awaited by `execution_id`.
+    pub async fn release_execution(&self, execution_id: ExecutionId) {
+        self.locks.release_execution(execution_id).await;
+    }
+
+    /// Guard that releases `execution_id`'s groups if a start path bails out
+    /// after admission, before the execution could emit a terminal event.
+    pub fn admission_guard(self: &Arc<Self>, execution_id: ExecutionId) -> AdmissionGuard {
+        AdmissionGuard {
+            service: Some(Arc::clone(self)),
+            execution_id,
+        }
+    }
+
+    /// Spawn the bus subscription that releases groups when their holder
+    /// reaches a terminal state.
+    pub fn spawn_release_listener(
+        self: &Arc<Self>,
+        event_bus: &EventBus,
+    ) -> tokio::task::JoinHandle<()> {
+        let mut receiver =
+            event_bus.subscribe_with(SubscriptionOptions::named("concurrency_group_release"));
+        let service = Arc::clone(self);
+        tokio::spawn(async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => {
+                        if let Some(execution_id) = released_execution_id(&event) {
+                            service.release_execution(execution_id).await;
+                        }
+                    }
+                    Err(EventBusError::Lagged(n)) => {
+                        // Missed releases are reclaimed on the next contention.
+                        warn!(
+                            lagged = n,
+                            "Concurrency group release listener lagged, continuing"
+                        );
+                    }
+                    Err(e) => {
+                        warn!(error = %e, "Concurrency group release listener stopping");
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Release the group if `holder` already finished. Returns `true` when the
+    /// holder was stale.
+    async fn reclaim_if_stale(&self, tenant_id: &TenantId, holder: LockHolder) -> bool {
+        let terminal = match holder.agent_id {
+            Some(_) => self
+                .execution_repository
+                .find_by_id_for_tenant(tenant_id, holder.execution_id)
+                .await
+                .map(|execution| execution.is_none_or(|e| e.status.is_terminal())),
+            None => self
+                .workflow_execution_repository
+                .find_by_id_for_tenant(tenant_id, holder.execution_id)
+                .await
+                .map(|execution| execution.is_none_or(|e| e.status.is_terminal())),
+        };
+        match terminal {
+            Ok(true) => {
+                warn!(
+                    execution_id = %holder.execution_id,
+                    "Reclaiming concurrency group from finished execution"
+                );
+                self.locks.release_execution(holder.execution_id).await;
+                true
+            }
+            Ok(false) => false,
+            Err(e) => {
+                warn!(
+                    execution_id = %holder.execution_id,
+                    error = %e,
+                    "Failed to check concurrency group holder status"
+                );
+                false
+            }
+        }
+    }
+
+    async fn cancel_holder(&self, tenant_id: &TenantId, holder: LockHolder) {
+        let result = match holder.agent_id {
+            Some(_) => match self.execution_service.get() {
+                Some(service) => service
+                    .cancel_execution_for_tenant(tenant_id, holder.execution_id)
+                    .await
+                    .map_err(|e| e.to_string()),
+                None => Err("execution service not wired".to_string()),
+            },
+            None => match &self.workflow_control {
+                Some(port) => port
+                    .cancel_workflow_execution(tenant_id, holder.execution_id)
+                    .await
+                    .map_err(|e| e.to_string()),
+                None => Err("workflow control port not wired".to_string()),
+            },
+        };
+        match result {
+            Ok(()) => info!(
+                execution_id = %holder.execution_id,
+                "Cancelled in-progress concurrency group holder"
+            ),
+            Err(e) => warn!(
+                execution_id = %holder.execution_id,
+                error = %e,
+                "Failed to cancel in-progress concurrency group holder; waiting for it instead"
+            ),
+        }
+    }
+}
+
+/// Returned by [`ConcurrencyGroupService::admission_guard`]. Dropping the
+/// guard without calling [`AdmissionGuard::disarm`] releases the groups.
+pub struct AdmissionGuard {
+    service: Option<Arc<ConcurrencyGroupService>>,
+    execution_id: ExecutionId,
+}
+
+impl AdmissionGuard {
+    /// Hand release over to the execution's terminal event.
+    pub fn disarm(mut self) {
+        self.service = None;
+    }
+}
+
+impl Drop for AdmissionGuard {
+    fn drop(&mut self) {
+        let Some(service) = self.service.take() else {
+            return;
+        };
+        let execution_id = self.execution_id;
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move { service.release_execution(execution_id).await });
+        }
+    }
+}
+
+fn record_admission(policy: ConcurrencyPolicy, outcome: &'static str) {
+    metrics::counter!(
+        "aegis_concurrency_group_admissions_total",
+        "policy" => policy.as_str(),
+        "outcome" => outcome
+    )
+    .increment(1);
+}
+
+fn released_execution_id(event: &DomainEvent) -> Option<ExecutionId> {
+    match event {
+        DomainEvent::Execution(event) => terminal_execution_id(event),
+        DomainEvent::Workflow(
+            WorkflowEvent::WorkflowExecutionCompleted { execution_id, .. }
+            | WorkflowEvent::WorkflowExecutionFailed { execution_id, .. }
+            | WorkflowEvent::WorkflowExecutionCancelled { execution_id, .. },
+        ) => Some(*execution_id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::lock_service::InMemoryLockService;
+    use crate::domain::agent::AgentId;
+    use crate::domain::execution::{Execution, ExecutionInput};
+    use crate::infrastructure::repositories::{
+        InMemoryExecutionRepository, InMemoryWorkflowExecutionRepository,
+    };
+
+    fn tenant() -> TenantId {
+        TenantId::new("tenant-a").unwrap()
+    }
+
+    fn spec(policy: ConcurrencyPolicy) -> ConcurrencySpec {
+        ConcurrencySpec {
+            group: "deploy-prod".to_string(),
+            policy,
+        }
+    }
+
+    async fn running_execution(repo: &InMemoryExecutionRepository) -> LockHolder {
+        let mut execution = Execution::new(
+            AgentId::new(),
+            ExecutionInput {
+                intent: None,
+                input: serde_json::json!({}),
+                workspace_volume_id: None,
+                workspace_volume_mount_path: None,
+                workspace_remote_path: None,
+                workflow_execution_id: None,
+                attachments: Vec::new(),
+            },
+            3,
+            "default".to_string(),
+        );
+        execution.start();
+        repo.save_for_tenant(&tenant(), &execution).await.unwrap();
+        LockHolder::agent(execution.id, execution.agent_id)
+    }
+
+    fn service(repo: Arc<InMemoryExecutionRepository>) -> ConcurrencyGroupService {
+        ConcurrencyGroupService::new(
+            Arc::new(InMemoryLockService::new()),
+            repo,
+            Arc::new(InMemoryWorkflowExecutionRepository::new()),
+        )
+    }
+
+    #[tokio::test]
+    async fn reject_policy_fails_while_group_is_held() {
+        let repo = Arc::new(InMemoryExecutionRepository::new());
+        let groups = service(repo.clone());
+        let first = running_execution(&repo).await;
+        let second = running_execution(&repo).await;
+
+        assert!(matches!(
+            groups
+                .admit(&tenant(), &spec(ConcurrencyPolicy::Reject), first)
+                .await,
+            Ok(ConcurrencyAdmission::Admitted)
+        ));
+        match groups
+            .admit(&tenant(), &spec(ConcurrencyPolicy::Reject), second)
+            .await
+        {
+            Err(ConcurrencyError::GroupBusy { holder, .. }) => {
+                assert_eq!(holder, first.execution_id)
+            }
+            _ => panic!("expected GroupBusy"),
+        }
+
+        groups.release_execution(first.execution_id).await;
+        assert!(matches!(
+            groups
+                .admit(&tenant(), &spec(ConcurrencyPolicy::Reject), second)
+                .await,
+            Ok(ConcurrencyAdmission::Admitted)
+        ));
+    }
+
+    #[tokio::test]
+    async fn queue_policy_waits_for_release() {
+        let repo = Arc::new(InMemoryExecutionRepository::new());
+        let groups = service(repo.clone());
+        let first = running_execution(&repo).await;
+        let second = running_execution(&repo).await;
+
+        groups
+            .admit(&tenant(), &spec(ConcurrencyPolicy::Queue), first)
+            .await
+            .unwrap();
+        let Ok(ConcurrencyAdmission::Queued(waiter)) = groups
+            .admit(&tenant(), &spec(ConcurrencyPolicy::Queue), second)
+            .await
+        else {
+            panic!("expected queued admission");
+        };
+
+        groups.release_execution(first.execution_id).await;
+        assert_eq!(waiter.acquired().await.unwrap().holder, second);
+    }
+
+    #[tokio::test]
+    async fn finished_holders_are_reclaimed_on_contention() {
+        let repo = Arc::new(InMemoryExecutionRepository::new());
+        let groups = service(repo.clone());
+        let first = running_execution(&repo).await;
+        groups
+            .admit(&tenant(), &spec(ConcurrencyPolicy::Reject), first)
+            .await
+            .unwrap();
+
+        // The holder finished but its release event was never observed.
+        let mut execution = repo
+            .find_by_id_for_tenant(&tenant(), first.execution_id)
+            .await
+            .unwrap()
+            .unwrap();
+        execution.complete();
+        repo.save_for_tenant(&tenant(), &execution).await.unwrap();
+
+        let second = running_execution(&repo).await;
+        assert!(matches!(
+            groups
+                .admit(&tenant(), &spec(ConcurrencyPolicy::Reject), second)
+                .await,
+            Ok(ConcurrencyAdmission::Admitted)
+        ));
+    }
+}
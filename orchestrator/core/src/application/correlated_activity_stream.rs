@@ -10,6 +10,7 @@ use crate::domain::repository::{ExecutionRepository, WorkflowExecutionRepository
 use crate::domain::tenant::TenantId;
 use crate::infrastructure::event_bus::DomainEvent;
 use crate::infrastructure::event_bus::{EventBus, EventBusError};
+use crate::infrastructure::event_schema::EventEnvelope;
 use anyhow::{anyhow, Result};
 use chrono::{DateTime, Utc};
 use futures::{Stream, StreamExt};
@@ -282,8 +283,8 @@ fn execution_to_history(execution: &Execution) -> Vec<CorrelatedActivityEvent> {
                     iteration_number: iteration.number,
                     provider: interaction.provider.clone(),
                     model: interaction.model.clone(),
-                    input_tokens: None,
-                    output_tokens: None,
+                    input_tokens: interaction.input_tokens,
+                    output_tokens: interaction.output_tokens,
                     prompt: interaction.prompt.clone(),
                     response: interaction.response.clone(),
                     timestamp: interaction.timestamp,
@@ -497,7 +498,7 @@ pub fn normalize_domain_event(
         iteration: event.iteration_number(),
         stage: event.stage().map(ToOwned::to_owned),
         message: event_message(event),
-        details: serde_json::to_value(event).unwrap_or(Value::Null),
+        details: serde_json::to_value(EventEnvelope::new(event)).unwrap_or(Value::Null),
     }
 }
 
@@ -556,6 +557,28 @@ fn event_message(event: &DomainEvent) -> String {
         }) => format!(
             "LLM call failed on iteration {iteration_number} via {provider}/{model}: {error_class:?} - {message}"
         ),
+        DomainEvent::Execution(ExecutionEvent::ModelRouted {
+            iteration_number,
+            requested_alias,
+            routed_alias,
+            rule,
+            ..
+        }) => match rule {
+            Some(rule) => format!(
+                "Model routed on iteration {iteration_number}: {requested_alias} -> {routed_alias} (rule {rule})"
+            ),
+            None => format!(
+                "Model routed on iteration {iteration_number}: kept {requested_alias} (no rule matched)"
+            ),
+        },
+        DomainEvent::Execution(ExecutionEvent::OperatorGuidanceAdded {
+            iteration_number,
+            submitted_by,
+            guidance,
+            ..
+        }) => format!(
+            "Operator guidance from {submitted_by} for iteration {iteration_number}: {guidance}"
+        ),
         DomainEvent::Execution(ExecutionEvent::InstanceSpawned {
             iteration_number,
             instance_id,
@@ -600,6 +623,11 @@ fn event_message(event: &DomainEvent) -> String {
         DomainEvent::Workflow(WorkflowEvent::WorkflowStateExited { state_name, .. }) => {
             format!("Exited workflow state {state_name}")
         }
+        DomainEvent::Workflow(WorkflowEvent::WorkflowStateStuck {
+            state_name,
+            expected_seconds,
+            ..
+        }) => format!("Workflow state {state_name} stuck for over {expected_seconds}s"),
         DomainEvent::Workflow(WorkflowEvent::WorkflowIterationStarted {
             iteration_number, ..
         }) => format!("Workflow iteration {iteration_number} started"),
@@ -725,6 +753,12 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn find_active_all(
+            &self,
+        ) -> std::result::Result<Vec<WorkflowExecution>, RepositoryError> {
+            Ok(vec![])
+        }
+
         async fn find_by_workflow_for_tenant(
             &self,
             _tenant_id: &crate::domain::tenant::TenantId,
@@ -789,6 +823,33 @@ mod tests {
         ) -> std::result::Result<Vec<WorkflowExecutionEventRecord>, RepositoryError> {
             Ok(vec![])
         }
+
+        async fn append_transition(
+            &self,
+            _execution_id: ExecutionId,
+            _record: &crate::domain::workflow::WorkflowTransitionRecord,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn close_transition(
+            &self,
+            _execution_id: ExecutionId,
+            _state_name: &str,
+            _exited_at: chrono::DateTime<chrono::Utc>,
+            _output_summary: Option<String>,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_transitions_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _id: ExecutionId,
+        ) -> Result<Vec<crate::domain::workflow::WorkflowTransitionRecord>, RepositoryError>
+        {
+            Ok(vec![])
+        }
     }
 
     #[tokio::test]
@@ -822,6 +883,8 @@ mod tests {
                     prompt: "hello".to_string(),
                     response: "world".to_string(),
                     timestamp: Utc::now(),
+                    input_tokens: None,
+                    output_tokens: None,
                 },
             )
             .unwrap();
@@ -846,6 +909,7 @@ mod tests {
         event_bus.publish_storage_event(StorageEvent::FileOpened {
             execution_id: Some(execution_id),
             workflow_execution_id: None,
+            iteration_number: None,
             volume_id: crate::domain::volume::VolumeId::new(),
             path: "/workspace/file.rs".to_string(),
             open_mode: "read".to_string(),
@@ -911,6 +975,7 @@ mod tests {
         event_bus.publish_storage_event(StorageEvent::FilesystemPolicyViolation {
             execution_id: Some(execution_id),
             workflow_execution_id: None,
+            iteration_number: None,
             volume_id: crate::domain::volume::VolumeId::new(),
             operation: "write".to_string(),
             path: "/workspace/secret.txt".to_string(),
@@ -1054,6 +1119,7 @@ mod tests {
         event_bus.publish_storage_event(StorageEvent::FilesystemPolicyViolation {
             execution_id: Some(exec_b_id),
             workflow_execution_id: None,
+            iteration_number: None,
             volume_id: crate::domain::volume::VolumeId::new(),
             operation: "write".to_string(),
             path: "/workspace/tenant-b-secret.txt".to_string(),
@@ -1065,6 +1131,7 @@ mod tests {
         event_bus.publish_storage_event(StorageEvent::FileOpened {
             execution_id: None,
             workflow_execution_id: None,
+            iteration_number: None,
             volume_id: crate::domain::volume::VolumeId::new(),
             path: "/system/global.log".to_string(),
             open_mode: "read".to_string(),
@@ -1079,6 +1146,7 @@ mod tests {
         event_bus.publish_storage_event(StorageEvent::FileOpened {
             execution_id: Some(exec_a_id),
             workflow_execution_id: None,
+            iteration_number: None,
             volume_id: crate::domain::volume::VolumeId::new(),
             path: "/workspace/tenant-a-allowed.txt".to_string(),
             open_mode: "read".to_string(),
@@ -37,28 +37,37 @@
 //! See Also: ADR-005 (Iterative Execution Strategy), ADR-036 (NFS Server Gateway)
 
 use crate::application::agent::AgentLifecycleService;
+use crate::application::execution_completion::ExecutionCompletionWatcher;
+use crate::application::feature_flags::{flags, FeatureFlagService};
+use crate::application::file_operations_service::FileOperationsService;
 use crate::application::nfs_gateway::{NfsGatewayService, VolumeRegistration};
 use crate::application::ports::{
     CortexPatternPort, StoreTrajectoryPatternCommand, TrajectoryStepCommand,
 };
+use crate::application::runtime_env::{EnvRenderScope, RuntimeEnvRenderer};
 use crate::application::validation_service::build_validation_pipeline;
 use crate::application::volume_manager::VolumeService;
-use crate::domain::agent::AgentId;
+use crate::domain::agent::{AgentId, EventVerbosity, VolumeSpec};
 use crate::domain::events::ExecutionEvent;
 use crate::domain::execution::{
     Execution, ExecutionError, ExecutionId, ExecutionInput, ExecutionStatus, Iteration,
 };
 use crate::domain::fsal::FsalAccessPolicy;
 use crate::domain::iam::UserIdentity;
+use crate::domain::network_flow::{NetworkFlow, NetworkFlowRepository};
 use crate::domain::node_config::resolve_env_value;
+use crate::domain::plugin::PluginHost;
 use crate::domain::repository::ExecutionRepository;
-use crate::domain::runtime::RuntimeError;
+use crate::domain::runtime::{NetworkFlowCapture, RuntimeError};
+use crate::domain::secrets::SecretMasker;
 use crate::domain::supervisor::{Supervisor, SupervisorObserver};
+use crate::domain::task_queue::{ExecutionTask, TaskQueue};
 use crate::domain::volume::{
     AccessMode, FilerEndpoint, TenantId, VolumeId, VolumeMount, VolumeOwnership,
 };
 use crate::infrastructure::event_bus::{DomainEvent, EventBus, EventBusError};
 use crate::infrastructure::prompt_template_engine::{PromptContext, PromptTemplateEngine};
+use crate::infrastructure::secrets_manager::SecretsManager;
 use anyhow::{anyhow, Context, Result};
 use async_trait::async_trait;
 use chrono::Utc;
@@ -100,8 +109,10 @@ pub trait ExecutionService: Send + Sync {
         identity: Option<&UserIdentity>,
     ) -> Result<ExecutionId>;
 
-    /// Start an execution with a pre-assigned ID (used for cluster forwarding).
-    /// The execution_id is imported from the originating node to preserve tracing correlation.
+    /// Start an execution with a pre-assigned ID (used for cluster forwarding
+    /// and by the execution queue worker). The execution_id is imported from the
+    /// originating node to preserve tracing correlation. Never goes through the
+    /// execution queue.
     async fn start_execution_with_id(
         &self,
         execution_id: ExecutionId,
@@ -275,8 +286,52 @@ pub trait ExecutionService: Send + Sync {
     ) -> Result<()> {
         Ok(())
     }
+
+    /// Wait until the execution reaches a terminal state or `timeout` elapses.
+    ///
+    /// Returns the latest snapshot either way; callers check
+    /// `execution.status.is_terminal()` to detect the timeout case.
+    ///
+    /// The default implementation re-reads the execution on a short fixed
+    /// interval. `StandardExecutionService` overrides it with an event-driven
+    /// [`ExecutionCompletionWatcher`] so waiters wake on the terminal event
+    /// instead of polling the repository.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the execution does not exist within the given tenant.
+    async fn wait_for_terminal(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+        timeout: std::time::Duration,
+    ) -> Result<Execution> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let execution = self.get_execution_for_tenant(tenant_id, id).await?;
+            let now = tokio::time::Instant::now();
+            if execution.status.is_terminal() || now >= deadline {
+                return Ok(execution);
+            }
+            tokio::time::sleep((deadline - now).min(DEFAULT_TERMINAL_POLL_INTERVAL)).await;
+        }
+    }
 }
 
+/// Re-read interval for the polling fallback in
+/// [`ExecutionService::wait_for_terminal`].
+const DEFAULT_TERMINAL_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(250);
+
+/// Prompt layout used when the manifest sets no `spec.task.prompt_template`.
+pub const DEFAULT_PROMPT_TEMPLATE: &str =
+    "{{instruction}}{{#if intent}}\n\nTask: {{intent}}{{/if}}\n\nUser: {{input}}\nAgent:";
+
+/// An execution requested network flow capture that its tenant or this node
+/// does not allow.
+#[derive(Debug, thiserror::Error)]
+#[error("network flow capture unavailable: {0}")]
+pub struct NetworkFlowCaptureDenied(pub String);
+
 pub struct StandardExecutionService {
     agent_service: Arc<dyn AgentLifecycleService>,
     volume_service: Arc<dyn VolumeService>,
@@ -296,6 +351,9 @@ pub struct StandardExecutionService {
     /// Self-reference used by judge validators to spawn child executions (ADR-016, ADR-039).
     /// Set once at composition root via `set_child_execution_service()`.
     child_executor: std::sync::OnceLock<Arc<dyn ExecutionService>>,
+    /// Event-driven completion watcher backing `wait_for_terminal`. Created on
+    /// first use so the bus subscription is only held when something waits.
+    completion_watcher: std::sync::OnceLock<Arc<ExecutionCompletionWatcher>>,
     /// Optional ToolRouter used to validate that an agent's requested tools actually exist
     /// before spawning the container (Safety & Polish).
     tool_router: Option<Arc<crate::infrastructure::tool_router::ToolRouter>>,
@@ -305,6 +363,8 @@ pub struct StandardExecutionService {
     rate_limit_enforcer: Option<Arc<dyn crate::domain::rate_limit::RateLimitEnforcer>>,
     /// Optional rate limit policy resolver for resolving tier/tenant/user policies (ADR-072).
     rate_limit_resolver: Option<Arc<dyn crate::domain::rate_limit::RateLimitPolicyResolver>>,
+    /// Resolves `spec.runtime.env` templates and `secretRef`s for each execution.
+    runtime_env: RuntimeEnvRenderer,
     /// Optional swarm cancellation port for cascade-cancelling child swarms (BC-6).
     swarm_cancellation: Option<Arc<dyn crate::application::ports::SwarmCancellationPort>>,
     /// SEAL gateway client for pre-creating sessions before container spawn (ADR-088 §A8).
@@ -316,6 +376,26 @@ pub struct StandardExecutionService {
         Option<Arc<dyn crate::application::output_handler_service::OutputHandlerService>>,
     /// Optional quota enforcement service (ADR-056). Checks concurrent execution limits.
     quota_service: Option<Arc<crate::application::tenant_quota::TenantQuotaService>>,
+    /// Optional builder for agents that declare `spec.runtime.packages` (ADR-043).
+    /// Required for such agents; executions will fail without it.
+    runtime_image_builder:
+        Option<Arc<dyn crate::infrastructure::runtime_image_builder::RuntimeImageBuilder>>,
+    /// Optional per-call token usage store backing `/v1/usage`.
+    token_usage_repository: Option<Arc<dyn crate::domain::token_usage::TokenUsageRepository>>,
+    /// Optional enforcement of manifest `spec.concurrency` groups.
+    concurrency_groups: Option<Arc<crate::application::concurrency_group::ConcurrencyGroupService>>,
+    /// Optional node maintenance state; a cordoned node rejects new executions.
+    node_maintenance: Option<Arc<crate::application::cluster::NodeMaintenanceService>>,
+    /// Optional feature flags; gates per-tenant network flow capture.
+    feature_flags: Option<Arc<FeatureFlagService>>,
+    /// Optional store for captured network flows. Required before an
+    /// execution may request capture.
+    network_flow_repository: Option<Arc<dyn NetworkFlowRepository>>,
+    /// Optional shared execution queue. When set, `start_execution` enqueues
+    /// instead of starting in-process (see [`ExecutionTask`]).
+    task_queue: Option<Arc<dyn TaskQueue>>,
+    /// Optional WASM plugin host for `type: plugin` validators.
+    plugin_host: Option<Arc<dyn PluginHost>>,
 }
 
 impl StandardExecutionService {
@@ -339,6 +419,66 @@ impl StandardExecutionService {
         Self::resolve_tenant_from_payload(&input.input)
     }
 
+    /// Labels requested at submit time, carried in the payload envelope
+    /// next to `tenant_id`. Absent or `null` means no labels.
+    fn resolve_labels_from_payload(
+        payload: &serde_json::Value,
+    ) -> Result<std::collections::BTreeMap<String, String>> {
+        let labels = match payload.get("labels") {
+            None | Some(serde_json::Value::Null) => return Ok(Default::default()),
+            Some(value) => serde_json::from_value(value.clone())
+                .map_err(|e| anyhow!("Invalid `labels` in execution payload: {e}"))?,
+        };
+        crate::domain::execution::validate_labels(&labels)?;
+        Ok(labels)
+    }
+
+    /// Network flow capture requested at submit time with
+    /// `"network_flow_capture": true` in the payload envelope. Refused unless
+    /// the tenant has the `network_flow_capture` flag, the node configures a
+    /// capture image and flows can be stored.
+    fn resolve_network_flow_capture(
+        &self,
+        payload: &serde_json::Value,
+        tenant_id: &TenantId,
+    ) -> Result<Option<NetworkFlowCapture>> {
+        match payload.get("network_flow_capture") {
+            None | Some(JsonValue::Null) | Some(JsonValue::Bool(false)) => return Ok(None),
+            Some(JsonValue::Bool(true)) => {}
+            Some(other) => {
+                return Err(anyhow!(
+                    "Invalid `network_flow_capture` in execution payload: expected a boolean, got {other}"
+                ))
+            }
+        }
+        let allowed = self
+            .feature_flags
+            .as_ref()
+            .is_some_and(|f| f.is_enabled(flags::NETWORK_FLOW_CAPTURE, Some(tenant_id)));
+        if !allowed {
+            return Err(NetworkFlowCaptureDenied(format!(
+                "the `{}` feature flag is not enabled for tenant '{tenant_id}'",
+                flags::NETWORK_FLOW_CAPTURE
+            ))
+            .into());
+        }
+        let Some(capture) = &self.config.spec.network_flow_capture else {
+            return Err(NetworkFlowCaptureDenied(
+                "this node has no `spec.network_flow_capture` configured".to_string(),
+            )
+            .into());
+        };
+        if self.network_flow_repository.is_none() {
+            return Err(
+                NetworkFlowCaptureDenied("this node has no flow log store".to_string()).into(),
+            );
+        }
+        Ok(Some(NetworkFlowCapture {
+            image: capture.image.clone(),
+            image_pull_policy: capture.image_pull_policy,
+        }))
+    }
+
     fn is_workspace_mount(path: &str) -> bool {
         path == "/workspace" || path.starts_with("/workspace/")
     }
@@ -584,15 +724,25 @@ impl StandardExecutionService {
             runtime_registry: None,
             cancellation_tokens: Arc::new(dashmap::DashMap::new()),
             child_executor: std::sync::OnceLock::new(),
+            completion_watcher: std::sync::OnceLock::new(),
             tool_router: None,
             cortex_client: None,
             rate_limit_enforcer: None,
             rate_limit_resolver: None,
+            runtime_env: RuntimeEnvRenderer::new(None),
             swarm_cancellation: None,
             seal_gateway_client: None,
             token_issuer: None,
             output_handler_service: None,
             quota_service: None,
+            runtime_image_builder: None,
+            token_usage_repository: None,
+            concurrency_groups: None,
+            node_maintenance: None,
+            feature_flags: None,
+            network_flow_repository: None,
+            task_queue: None,
+            plugin_host: None,
         }
     }
 
@@ -695,6 +845,118 @@ impl StandardExecutionService {
         self.output_handler_service = Some(service);
         self
     }
+
+    /// Attach the secrets manager used to resolve `spec.runtime.env` `secretRef`s
+    /// (ADR-034). Without it, executions of agents using `secretRef` fail to start.
+    pub fn with_secrets_manager(mut self, secrets_manager: Arc<SecretsManager>) -> Self {
+        self.runtime_env = RuntimeEnvRenderer::new(Some(secrets_manager));
+        self
+    }
+
+    /// Attach a runtime image builder for agents that declare `spec.runtime.packages`.
+    pub fn with_runtime_image_builder(
+        mut self,
+        builder: Arc<dyn crate::infrastructure::runtime_image_builder::RuntimeImageBuilder>,
+    ) -> Self {
+        self.runtime_image_builder = Some(builder);
+        self
+    }
+
+    /// Attach a token usage store; every recorded LLM interaction that reports
+    /// token counts is written to it.
+    pub fn with_token_usage_repository(
+        mut self,
+        repository: Arc<dyn crate::domain::token_usage::TokenUsageRepository>,
+    ) -> Self {
+        self.token_usage_repository = Some(repository);
+        self
+    }
+
+    /// Enforce `spec.concurrency` groups declared by agent manifests.
+    pub fn with_concurrency_groups(
+        mut self,
+        service: Arc<crate::application::concurrency_group::ConcurrencyGroupService>,
+    ) -> Self {
+        self.concurrency_groups = Some(service);
+        self
+    }
+
+    /// Reject new executions while the node is cordoned and track queued
+    /// executions so cordoning can requeue them.
+    pub fn with_node_maintenance(
+        mut self,
+        service: Arc<crate::application::cluster::NodeMaintenanceService>,
+    ) -> Self {
+        self.node_maintenance = Some(service);
+        self
+    }
+
+    pub fn with_feature_flags(mut self, flags: Arc<FeatureFlagService>) -> Self {
+        self.feature_flags = Some(flags);
+        self
+    }
+
+    /// Attach the store for flows recorded by network flow capture.
+    pub fn with_network_flow_repository(
+        mut self,
+        repository: Arc<dyn NetworkFlowRepository>,
+    ) -> Self {
+        self.network_flow_repository = Some(repository);
+        self
+    }
+
+    /// Route `start_execution` through a shared queue; a worker started with
+    /// this service as its executor runs the queued executions.
+    pub fn with_task_queue(mut self, queue: Arc<dyn TaskQueue>) -> Self {
+        self.task_queue = Some(queue);
+        self
+    }
+
+    /// Run `type: plugin` validators on `host`. Without it they fail.
+    pub fn with_plugin_host(mut self, host: Arc<dyn PluginHost>) -> Self {
+        self.plugin_host = Some(host);
+        self
+    }
+
+    /// Validate what can be checked without starting the execution, then
+    /// hand it to the shared queue. Quotas, rate limits and cordoning apply
+    /// when a worker starts it.
+    async fn enqueue_execution(
+        &self,
+        queue: &Arc<dyn TaskQueue>,
+        agent_id: AgentId,
+        input: ExecutionInput,
+        security_context_name: String,
+        identity: Option<&UserIdentity>,
+    ) -> Result<ExecutionId> {
+        let tenant_id = Self::resolve_tenant_from_input(&input)?;
+        Self::resolve_labels_from_payload(&input.input)?;
+        self.resolve_network_flow_capture(&input.input, &tenant_id)?;
+
+        let task = ExecutionTask {
+            execution_id: ExecutionId::new(),
+            tenant_id,
+            agent_id,
+            input,
+            security_context_name,
+            identity: identity.cloned(),
+            enqueued_at: Utc::now(),
+        };
+        queue
+            .enqueue(&task)
+            .await
+            .context("Failed to enqueue execution")?;
+        metrics::counter!("aegis_execution_queue_enqueued_total", "backend" => queue.backend())
+            .increment(1);
+        tracing::info!(
+            execution_id = %task.execution_id,
+            agent_id = %agent_id,
+            tenant_id = %task.tenant_id,
+            backend = queue.backend(),
+            "Execution queued"
+        );
+        Ok(task.execution_id)
+    }
 }
 
 #[cfg(test)]
@@ -889,6 +1151,11 @@ mod tests {
                     version: None,
                     image: Some(format!("ghcr.io/example/{name}:latest")),
                     image_pull_policy: ImagePullPolicy::IfNotPresent,
+                    base_image: None,
+                    packages: Vec::new(),
+                    env: Vec::new(),
+                    sidecars: Vec::new(),
+                    platform: None,
                     isolation: "docker".to_string(),
                     model: "default".to_string(),
                     temperature: None,
@@ -923,6 +1190,8 @@ mod tests {
                 input_schema: None,
                 security_context: None,
                 output_handler: None,
+                concurrency: None,
+                healthcheck: None,
             },
         })
     }
@@ -1440,6 +1709,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn resolve_labels_from_payload_reads_and_validates_labels() {
+        let payload = serde_json::json!({ "tenant_id": "acme-corp" });
+        assert!(
+            StandardExecutionService::resolve_labels_from_payload(&payload)
+                .unwrap()
+                .is_empty()
+        );
+
+        let payload = serde_json::json!({ "labels": { "customer": "acme" } });
+        let labels = StandardExecutionService::resolve_labels_from_payload(&payload).unwrap();
+        assert_eq!(labels.get("customer").map(String::as_str), Some("acme"));
+
+        let payload = serde_json::json!({ "labels": { "bad key": "x" } });
+        assert!(StandardExecutionService::resolve_labels_from_payload(&payload).is_err());
+    }
+
     #[test]
     fn resolve_tenant_from_payload_rejects_legacy_tenant_alias() {
         // The legacy `tenant` key is not accepted; only canonical `tenant_id`.
@@ -2002,6 +2288,10 @@ struct ExecutionMonitor {
     tenant_id: TenantId,
     repository: Arc<dyn ExecutionRepository>,
     event_bus: Arc<EventBus>,
+    /// Masks `secretRef` env values in everything the agent prints or returns.
+    masker: SecretMasker,
+    /// Store for flows reported by network flow capture.
+    network_flows: Option<Arc<dyn NetworkFlowRepository>>,
 }
 
 #[async_trait]
@@ -2045,19 +2335,20 @@ impl SupervisorObserver for ExecutionMonitor {
                 agent_id: self.agent_id,
                 iteration_number: iteration,
                 stream: stream.to_string(),
-                content: content.to_string(),
+                content: self.masker.mask(content),
                 timestamp: now,
             });
     }
 
     async fn on_iteration_complete(&self, iteration: u8, output: &str, _exit_code: i64) {
         let now = Utc::now();
+        let output = self.masker.mask(output);
         if let Ok(Some(mut exec)) = self
             .repository
             .find_by_id_for_tenant(&self.tenant_id, self.execution_id)
             .await
         {
-            exec.complete_iteration(output.to_string());
+            exec.complete_iteration(output.clone());
             let _ = self
                 .repository
                 .save_for_tenant(&self.tenant_id, &exec)
@@ -2068,7 +2359,7 @@ impl SupervisorObserver for ExecutionMonitor {
                 execution_id: self.execution_id,
                 agent_id: self.agent_id,
                 iteration_number: iteration,
-                output: output.to_string(),
+                output,
                 completed_at: now,
             });
     }
@@ -2077,7 +2368,7 @@ impl SupervisorObserver for ExecutionMonitor {
         let now = Utc::now();
         // Map string error to IterationError
         let iter_error = crate::domain::execution::IterationError {
-            message: error.to_string(),
+            message: self.masker.mask(error),
             details: None,
         };
 
@@ -2201,9 +2492,102 @@ impl SupervisorObserver for ExecutionMonitor {
             }
         }
     }
+
+    async fn on_storage_transport_interrupted(
+        &self,
+        iteration: u8,
+        interruption: &crate::domain::supervisor::StorageTransportInterruption,
+    ) {
+        metrics::counter!(
+            "aegis_storage_transport_interruptions_total",
+            "transport" => interruption.transport.clone()
+        )
+        .increment(1);
+        self.event_bus
+            .publish_execution_event(ExecutionEvent::StorageTransportInterrupted {
+                execution_id: self.execution_id,
+                agent_id: self.agent_id,
+                iteration_number: iteration,
+                transport: interruption.transport.clone(),
+                generation: interruption.generation,
+                reason: interruption.reason.clone(),
+                interrupted_at: Utc::now(),
+            });
+    }
+
+    async fn on_network_flows(&self, iteration: u8, flows: &[NetworkFlow]) {
+        let Some(repository) = &self.network_flows else {
+            return;
+        };
+        let flows: Vec<NetworkFlow> = flows
+            .iter()
+            .cloned()
+            .map(|mut flow| {
+                flow.iteration_number = Some(iteration);
+                flow
+            })
+            .collect();
+        metrics::counter!("aegis_network_flows_recorded_total").increment(flows.len() as u64);
+        if let Err(e) = repository.append(self.execution_id, &flows).await {
+            tracing::warn!(
+                "Failed to store network flows for execution {} iteration {}: {}",
+                self.execution_id,
+                iteration,
+                e
+            );
+        }
+    }
 }
 
 impl StandardExecutionService {
+    /// Swap the resolved StandardRuntime image for the agent's built runtime
+    /// image when the manifest declares `spec.runtime.packages`.
+    ///
+    /// Returns the built image digest for provenance, or `None` when the agent
+    /// runs on the resolved image as-is.
+    async fn apply_built_runtime_image(
+        &self,
+        agent: &crate::domain::agent::Agent,
+        runtime_config: &mut crate::domain::runtime::RuntimeConfig,
+    ) -> Result<Option<String>> {
+        let runtime = &agent.manifest.spec.runtime;
+        if !runtime.requires_image_build() {
+            return Ok(None);
+        }
+        let Some(builder) = self.runtime_image_builder.as_ref() else {
+            return Err(anyhow!(
+                "Agent '{}' declares spec.runtime.packages but no runtime image builder is \
+                 configured. Call `.with_runtime_image_builder()` when building \
+                 StandardExecutionService.",
+                agent.manifest.metadata.name
+            ));
+        };
+
+        let spec = crate::domain::runtime_image::RuntimeImageSpec::from_runtime(
+            &agent.manifest.metadata.name,
+            &agent.manifest.metadata.version,
+            runtime,
+            &runtime_config.image,
+        )
+        .map_err(|e| anyhow!("Invalid spec.runtime.packages: {e}"))?;
+        let built = builder
+            .ensure_built(&spec)
+            .await
+            .context("Failed to build agent runtime image")?;
+
+        tracing::info!(
+            agent = %agent.manifest.metadata.name,
+            image = %built.tag,
+            digest = %built.digest,
+            reused = built.reused,
+            "Using built runtime image"
+        );
+        runtime_config.image = built.tag;
+        // The tag only exists in the local daemon; never try to pull it.
+        runtime_config.image_pull_policy = crate::domain::agent::ImagePullPolicy::Never;
+        Ok(Some(built.digest))
+    }
+
     /// Extract structured user input from `ExecutionInput.input` (ADR-092).
     ///
     /// Returns a `serde_json::Value` preserving the original structure so that
@@ -2348,8 +2732,6 @@ impl StandardExecutionService {
         let mut rendered_prompt: Option<String> = None;
 
         if has_input {
-            const DEFAULT_PROMPT_TEMPLATE: &str = "{{instruction}}{{#if intent}}\n\nTask: {{intent}}{{/if}}\n\nUser: {{input}}\nAgent:";
-
             let task_spec = agent
                 .manifest
                 .spec
@@ -2466,13 +2848,25 @@ impl StandardExecutionService {
         identity: Option<&UserIdentity>,
     ) -> Result<ExecutionId> {
         let tenant_id = Self::resolve_tenant_from_input(&input)?;
+        let labels = Self::resolve_labels_from_payload(&input.input)?;
+        let network_flow_capture = self.resolve_network_flow_capture(&input.input, &tenant_id)?;
+
+        // A cordoned node takes no new work; running executions finish.
+        if let Some(maintenance) = &self.node_maintenance {
+            maintenance.ensure_schedulable()?;
+        }
 
-        // 0a. Quota check (ADR-056): enforce per-tenant concurrent execution limit.
+        // 0a. Quota check (ADR-056): enforce per-tenant concurrent execution
+        // limit and monthly token budget.
         if let Some(quota_svc) = &self.quota_service {
             quota_svc
                 .check_execution_quota(&tenant_id)
                 .await
                 .map_err(|e| anyhow!("quota_exceeded:{}", e))?;
+            quota_svc
+                .check_token_budget(&tenant_id)
+                .await
+                .map_err(|e| anyhow!("quota_exceeded:{}", e))?;
         }
 
         // 0. Rate limit check (ADR-072): enforce AgentExecution quota at tenant scope
@@ -2655,6 +3049,12 @@ impl StandardExecutionService {
             }
         }
 
+        // Executions that may queue behind a concurrency group keep the
+        // caller's input so cordoning the node can start them elsewhere.
+        let requeue_input = (self.node_maintenance.is_some()
+            && agent.manifest.spec.concurrency.is_some())
+        .then(|| input.clone());
+
         // Extract workspace volume fields before input is consumed by prepare_execution_input.
         let workspace_volume_id = input.workspace_volume_id;
         let workspace_volume_mount_path = input.workspace_volume_mount_path.clone();
@@ -2696,6 +3096,26 @@ impl StandardExecutionService {
 
         // Persist the initiating user's sub for later recovery by the dispatch gateway.
         execution.initiating_user_sub = identity.map(|id| id.sub.clone());
+        execution.labels = labels;
+
+        // 2.5 Concurrency group admission. `reject` fails here before anything is
+        // persisted; `queue` and `cancel_in_progress` defer the supervisor loop
+        // until the group is handed to this execution.
+        let mut queued_lock = None;
+        let mut admission_guard = None;
+        if let (Some(groups), Some(concurrency)) =
+            (&self.concurrency_groups, &agent.manifest.spec.concurrency)
+        {
+            use crate::application::concurrency_group::ConcurrencyAdmission;
+            let holder =
+                crate::application::lock_service::LockHolder::agent(execution_id, agent_id);
+            if let ConcurrencyAdmission::Queued(waiter) =
+                groups.admit(&tenant_id, concurrency, holder).await?
+            {
+                queued_lock = Some(waiter);
+            }
+            admission_guard = Some(groups.admission_guard(execution_id));
+        }
 
         // 3. Save initial state
         self.repository
@@ -2813,6 +3233,19 @@ impl StandardExecutionService {
 
         // 4. Spawn Runtime
         let mut env = agent.manifest.spec.env.clone();
+        let rendered_env = self
+            .runtime_env
+            .render(
+                &agent.manifest.spec.runtime.env,
+                &EnvRenderScope {
+                    execution_id,
+                    agent_id,
+                    tenant_id: &tenant_id,
+                    input: &persisted_input,
+                },
+            )
+            .await?;
+        env.extend(rendered_env.vars);
 
         // Inject instruction from default task if available
         if let Some(task_spec) = &agent.manifest.spec.task {
@@ -2899,7 +3332,20 @@ impl StandardExecutionService {
             "Checking for volumes in agent manifest: {} volume(s) specified",
             agent.manifest.spec.volumes.len()
         );
-        let volume_mounts = if !agent.manifest.spec.volumes.is_empty() {
+        // Manifests without volumes get the node's default scratch workspace
+        // (spec.storage.default_workspace) unless a workflow supplied one.
+        let default_workspace =
+            if agent.manifest.spec.volumes.is_empty() && workspace_volume_id.is_none() {
+                self.default_workspace_spec()
+            } else {
+                None
+            };
+        let volume_specs = match &default_workspace {
+            Some(spec) => std::slice::from_ref(spec),
+            None => agent.manifest.spec.volumes.as_slice(),
+        };
+        let mut default_workspace_volume_id = None;
+        let volume_mounts = if !volume_specs.is_empty() {
             tracing::info!("Creating volumes for execution {}", execution_id.0);
 
             // Get storage config from node config
@@ -2918,22 +3364,22 @@ impl StandardExecutionService {
                 .create_volumes_for_execution(
                     execution_id,
                     tenant_id.clone(),
-                    &agent.manifest.spec.volumes,
+                    volume_specs,
                     &storage_config.backend,
                 )
                 .await?;
 
             tracing::info!("Successfully created {} volume(s)", volumes.len());
+            if default_workspace.is_some() {
+                default_workspace_volume_id = volumes.first().map(|volume| volume.id);
+            }
 
             // Build VolumeMount objects from created volumes
             volumes
                 .iter()
                 .map(|volume| {
                     // Find corresponding spec to get mount_path and access_mode
-                    let spec = agent
-                        .manifest
-                        .spec
-                        .volumes
+                    let spec = volume_specs
                         .iter()
                         .find(|s| s.name == volume.name)
                         .expect("Volume spec not found for created volume");
@@ -3067,7 +3513,7 @@ impl StandardExecutionService {
                 .context("Failed to persist workspace volume to DB")?;
         }
 
-        let runtime_config = crate::domain::runtime::RuntimeConfig {
+        let mut runtime_config = crate::domain::runtime::RuntimeConfig {
             language: agent
                 .manifest
                 .spec
@@ -3145,9 +3591,32 @@ impl StandardExecutionService {
                 .and_then(|a| a.bootstrap_path.clone()),
             // Attach execution_id so ContainerRuntime can correlate image events (ADR-045).
             execution_id,
+            tenant_id: tenant_id.clone(),
+            sidecars: agent
+                .manifest
+                .spec
+                .runtime
+                .sidecars
+                .iter()
+                .map(crate::domain::runtime::SidecarConfig::from_spec)
+                .collect(),
+            platform: agent.manifest.spec.runtime.platform.clone(),
+            network_policy: agent
+                .manifest
+                .spec
+                .security
+                .as_ref()
+                .map(|security| security.network.clone()),
+            network_flow_capture,
         };
+        execution.runtime_image_digest = self
+            .apply_built_runtime_image(&agent, &mut runtime_config)
+            .await?;
 
-        execution.start();
+        // Queued executions stay pending until their concurrency group is free.
+        if queued_lock.is_none() {
+            execution.start();
+        }
         self.repository
             .save_for_tenant(&tenant_id, &execution)
             .await?;
@@ -3159,12 +3628,24 @@ impl StandardExecutionService {
         let exec_input = runtime_input;
         let tenant_id_for_task = tenant_id.clone();
 
+        event_bus.set_execution_verbosity(
+            execution_id,
+            agent
+                .manifest
+                .spec
+                .advanced
+                .as_ref()
+                .map_or(EventVerbosity::Full, |advanced| advanced.event_verbosity),
+        );
+        let output_masker = rendered_env.masker;
         let monitor = Arc::new(ExecutionMonitor {
             execution_id,
             agent_id,
             tenant_id: tenant_id_for_task.clone(),
             repository: repository.clone(),
             event_bus: event_bus.clone(),
+            masker: output_masker.clone(),
+            network_flows: self.network_flow_repository.clone(),
         });
 
         // Build gradient validation pipeline from manifest config (ADR-017).
@@ -3184,6 +3665,7 @@ impl StandardExecutionService {
                     self.agent_service.clone(),
                     child_svc,
                     self.event_bus.clone(),
+                    self.plugin_host.clone(),
                     execution_id,
                     tenant_id.clone(),
                 ))
@@ -3206,17 +3688,86 @@ impl StandardExecutionService {
         // caller's per-call intent, not the rendered LLM prompt.
         let intent_for_handler = persisted_input.intent.clone();
 
+        // The default workspace is deleted once the execution is terminal
+        // unless the manifest asks to keep it for debugging or the agent
+        // left output attachments in it.
+        let keep_workspace = agent
+            .manifest
+            .spec
+            .advanced
+            .as_ref()
+            .is_some_and(|a| a.keep_workspace);
+        let workspace_cleanup =
+            default_workspace_volume_id
+                .filter(|_| !keep_workspace)
+                .map(|volume_id| WorkspaceCleanup {
+                    volume_service: self.volume_service.clone(),
+                    volume_id,
+                    execution_id,
+                    tenant_id: tenant_id.clone(),
+                    file_operations: self
+                        .nfs_gateway
+                        .as_ref()
+                        .map(|gw| FileOperationsService::new(gw.fsal().clone())),
+                });
+
+        // From here the group is released by the execution's terminal event.
+        if let Some(guard) = admission_guard {
+            guard.disarm();
+        }
+
+        if let (Some(maintenance), Some(input), true) =
+            (&self.node_maintenance, requeue_input, queued_lock.is_some())
+        {
+            maintenance.track_queued(
+                execution_id,
+                crate::application::cluster::node_maintenance::QueuedExecution {
+                    tenant_id: tenant_id.clone(),
+                    agent_id,
+                    input,
+                    security_context_name: seal_security_context.clone(),
+                },
+            );
+        }
+        let node_maintenance = self.node_maintenance.clone();
+
         tokio::spawn(async move {
-            let result = supervisor
-                .run_loop(
-                    runtime_config,
-                    exec_input,
-                    max_retries as u32,
-                    monitor,
-                    cancellation_token,
-                    validation_pipeline,
-                )
-                .await;
+            let admitted = match queued_lock {
+                None => true,
+                Some(waiter) => {
+                    let granted = tokio::select! {
+                        granted = waiter.acquired() => granted.is_ok(),
+                        _ = cancellation_token.cancelled() => false,
+                    };
+                    if granted {
+                        if let Ok(Some(mut exec)) = repository
+                            .find_by_id_for_tenant(&tenant_id_for_task, execution_id)
+                            .await
+                        {
+                            exec.start();
+                            let _ = repository.save_for_tenant(&tenant_id_for_task, &exec).await;
+                        }
+                    }
+                    if let Some(maintenance) = &node_maintenance {
+                        maintenance.untrack_queued(execution_id);
+                    }
+                    granted
+                }
+            };
+            let result = if admitted {
+                supervisor
+                    .run_loop(
+                        runtime_config,
+                        exec_input,
+                        max_retries as u32,
+                        monitor,
+                        cancellation_token,
+                        validation_pipeline,
+                    )
+                    .await
+            } else {
+                Err(RuntimeError::Cancelled)
+            };
 
             let duration = Utc::now() - start_time;
             let duration_seconds = duration.num_milliseconds() as f64 / 1000.0;
@@ -3227,6 +3778,7 @@ impl StandardExecutionService {
 
             match result {
                 Ok(final_output) => {
+                    let final_output = output_masker.mask(&final_output);
                     metrics::counter!(
                         "aegis_executions_total",
                         "kind" => "root",
@@ -3291,6 +3843,7 @@ impl StandardExecutionService {
                                             failed_at: Utc::now(),
                                         },
                                     );
+                                    release_default_workspace(workspace_cleanup).await;
                                     return;
                                 }
                                 Err(e) => {
@@ -3394,24 +3947,103 @@ impl StandardExecutionService {
                         .find_by_id_for_tenant(&tenant_id_for_task, execution_id)
                         .await
                     {
-                        exec.fail(e.to_string());
+                        let reason = output_masker.mask(&e.to_string());
+                        exec.fail(reason.clone());
                         let total_iterations = exec.iterations().len() as u8;
                         let _ = repository.save_for_tenant(&tenant_id_for_task, &exec).await;
 
                         event_bus.publish_execution_event(ExecutionEvent::ExecutionFailed {
                             execution_id,
                             agent_id,
-                            reason: e.to_string(),
+                            reason,
                             total_iterations,
                             failed_at: Utc::now(),
                         });
                     }
                 }
             }
+
+            release_default_workspace(workspace_cleanup).await;
         });
 
         Ok(execution_id)
     }
+
+    /// Volume spec for the node's default `/workspace`, or `None` when storage
+    /// is not configured or `spec.storage.default_workspace.enabled` is false.
+    fn default_workspace_spec(&self) -> Option<VolumeSpec> {
+        let workspace = &self.config.spec.storage.as_ref()?.default_workspace;
+        workspace.enabled.then(|| VolumeSpec {
+            name: DEFAULT_WORKSPACE_VOLUME_NAME.to_string(),
+            storage_class: "ephemeral".to_string(),
+            volume_type: "seaweedfs".to_string(),
+            provider: None,
+            config: None,
+            mount_path: "/workspace".to_string(),
+            access_mode: "read-write".to_string(),
+            size_limit: workspace.size_limit.clone(),
+            ttl_hours: Some(workspace.ttl_hours),
+        })
+    }
+}
+
+/// Name of the auto-provisioned per-execution workspace volume.
+const DEFAULT_WORKSPACE_VOLUME_NAME: &str = "workspace";
+
+/// An auto-provisioned workspace to delete when its execution ends.
+struct WorkspaceCleanup {
+    volume_service: Arc<dyn VolumeService>,
+    volume_id: VolumeId,
+    execution_id: ExecutionId,
+    tenant_id: TenantId,
+    /// Used to look for output attachments; `None` without an NFS gateway.
+    file_operations: Option<FileOperationsService>,
+}
+
+/// Delete an auto-provisioned workspace. A workspace holding output
+/// attachments is kept so they can be downloaded until its TTL expires.
+/// Failures are logged only: the volume is ephemeral and the TTL sweep
+/// reclaims it.
+async fn release_default_workspace(cleanup: Option<WorkspaceCleanup>) {
+    let Some(cleanup) = cleanup else {
+        return;
+    };
+    let WorkspaceCleanup {
+        volume_service,
+        volume_id,
+        execution_id,
+        tenant_id,
+        file_operations,
+    } = cleanup;
+    if let Some(file_operations) = file_operations {
+        match file_operations
+            .list_execution_outputs(execution_id, &tenant_id)
+            .await
+        {
+            Ok(outputs) if !outputs.is_empty() => {
+                tracing::info!(
+                    %execution_id,
+                    volume_id = %volume_id,
+                    outputs = outputs.len(),
+                    "Keeping default workspace with output attachments until its TTL"
+                );
+                return;
+            }
+            Ok(_) => {}
+            Err(e) => tracing::warn!(
+                %execution_id,
+                error = %e,
+                "Could not list output attachments; deleting workspace"
+            ),
+        }
+    }
+    if let Err(e) = volume_service.delete_volume(volume_id).await {
+        tracing::warn!(
+            volume_id = %volume_id,
+            error = %e,
+            "Failed to delete default workspace volume; it will expire with its TTL"
+        );
+    }
 }
 
 #[async_trait]
@@ -3423,6 +4055,11 @@ impl ExecutionService for StandardExecutionService {
         security_context_name: String,
         identity: Option<&UserIdentity>,
     ) -> Result<ExecutionId> {
+        if let Some(queue) = &self.task_queue {
+            return self
+                .enqueue_execution(queue, agent_id, input, security_context_name, identity)
+                .await;
+        }
         self.do_start_execution(None, agent_id, input, security_context_name, identity)
             .await
     }
@@ -3621,6 +4258,19 @@ impl ExecutionService for StandardExecutionService {
 
         // 5. Build runtime config.
         let mut env = agent.manifest.spec.env.clone();
+        let rendered_env = self
+            .runtime_env
+            .render(
+                &agent.manifest.spec.runtime.env,
+                &EnvRenderScope {
+                    execution_id: child_execution_id,
+                    agent_id,
+                    tenant_id: &tenant_id,
+                    input: &persisted_input,
+                },
+            )
+            .await?;
+        env.extend(rendered_env.vars);
         if let Some(task_spec) = &agent.manifest.spec.task {
             if let Some(instr) = &task_spec.instruction {
                 env.insert("AEGIS_AGENT_INSTRUCTION".to_string(), instr.clone());
@@ -3871,7 +4521,7 @@ impl ExecutionService for StandardExecutionService {
             merged
         };
 
-        let runtime_config = crate::domain::runtime::RuntimeConfig {
+        let mut runtime_config = crate::domain::runtime::RuntimeConfig {
             language: agent
                 .manifest
                 .spec
@@ -3905,7 +4555,27 @@ impl ExecutionService for StandardExecutionService {
                 .as_ref()
                 .and_then(|a| a.bootstrap_path.clone()),
             execution_id: child_execution_id,
+            tenant_id: tenant_id.clone(),
+            sidecars: agent
+                .manifest
+                .spec
+                .runtime
+                .sidecars
+                .iter()
+                .map(crate::domain::runtime::SidecarConfig::from_spec)
+                .collect(),
+            platform: agent.manifest.spec.runtime.platform.clone(),
+            network_policy: agent
+                .manifest
+                .spec
+                .security
+                .as_ref()
+                .map(|security| security.network.clone()),
+            network_flow_capture: None,
         };
+        child_execution.runtime_image_digest = self
+            .apply_built_runtime_image(&agent, &mut runtime_config)
+            .await?;
 
         child_execution.start();
         self.repository
@@ -3927,12 +4597,24 @@ impl ExecutionService for StandardExecutionService {
         let supervisor = self.supervisor.clone();
         let repository = self.repository.clone();
         let event_bus = self.event_bus.clone();
+        event_bus.set_execution_verbosity(
+            child_execution_id,
+            agent
+                .manifest
+                .spec
+                .advanced
+                .as_ref()
+                .map_or(EventVerbosity::Full, |advanced| advanced.event_verbosity),
+        );
+        let output_masker = rendered_env.masker;
         let monitor = Arc::new(ExecutionMonitor {
             execution_id: child_execution_id,
             agent_id,
             tenant_id: tenant_id.clone(),
             repository: repository.clone(),
             event_bus: event_bus.clone(),
+            masker: output_masker.clone(),
+            network_flows: self.network_flow_repository.clone(),
         });
 
         // Judge agents may declare their own (nested) validation steps.
@@ -3954,6 +4636,7 @@ impl ExecutionService for StandardExecutionService {
                     self.agent_service.clone(),
                     child_svc,
                     self.event_bus.clone(),
+                    self.plugin_host.clone(),
                     child_execution_id,
                     tenant_id.clone(),
                 ))
@@ -3984,6 +4667,7 @@ impl ExecutionService for StandardExecutionService {
 
             match result {
                 Ok(final_output) => {
+                    let final_output = output_masker.mask(&final_output);
                     if let Ok(Some(mut exec)) = repository
                         .find_by_id_for_tenant(&tenant_id_for_task, child_execution_id)
                         .await
@@ -4054,13 +4738,14 @@ impl ExecutionService for StandardExecutionService {
                         .find_by_id_for_tenant(&tenant_id_for_task, child_execution_id)
                         .await
                     {
-                        exec.fail(e.to_string());
+                        let reason = output_masker.mask(&e.to_string());
+                        exec.fail(reason.clone());
                         let total_iterations = exec.iterations().len() as u8;
                         let _ = repository.save_for_tenant(&tenant_id_for_task, &exec).await;
                         event_bus.publish_execution_event(ExecutionEvent::ExecutionFailed {
                             execution_id: child_execution_id,
                             agent_id,
-                            reason: e.to_string(),
+                            reason,
                             total_iterations,
                             failed_at: Utc::now(),
                         });
@@ -4088,6 +4773,20 @@ impl ExecutionService for StandardExecutionService {
     ) -> Result<()> {
         if let Some(mut exec) = self.repository.find_by_id_unscoped(execution_id).await? {
             let tenant_id = exec.tenant_id.clone();
+            let usage = match (interaction.input_tokens, interaction.output_tokens) {
+                (None, None) => None,
+                (input, output) => Some(crate::domain::token_usage::TokenUsageRecord {
+                    execution_id,
+                    tenant_id: tenant_id.clone(),
+                    agent_id: exec.agent_id,
+                    iteration_number: iteration,
+                    provider: interaction.provider.clone(),
+                    model: interaction.model.clone(),
+                    input_tokens: input.unwrap_or_default(),
+                    output_tokens: output.unwrap_or_default(),
+                    recorded_at: interaction.timestamp,
+                }),
+            };
             if let Err(e) = exec.add_llm_interaction(iteration, interaction) {
                 tracing::warn!(
                     "Failed to record LLM interaction for execution {} iteration {}: {}",
@@ -4097,6 +4796,17 @@ impl ExecutionService for StandardExecutionService {
                 );
             } else {
                 self.repository.save_for_tenant(&tenant_id, &exec).await?;
+                if let (Some(repo), Some(usage)) = (&self.token_usage_repository, usage) {
+                    // Accounting must never fail the iteration.
+                    if let Err(e) = repo.record(&usage).await {
+                        tracing::warn!(
+                            "Failed to record token usage for execution {} iteration {}: {}",
+                            execution_id.0,
+                            iteration,
+                            e
+                        );
+                    }
+                }
             }
         }
         Ok(())
@@ -4136,6 +4846,22 @@ impl ExecutionService for StandardExecutionService {
         }
         Ok(())
     }
+
+    async fn wait_for_terminal(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+        timeout: std::time::Duration,
+    ) -> Result<Execution> {
+        let watcher = self
+            .completion_watcher
+            .get_or_init(|| ExecutionCompletionWatcher::spawn(self.event_bus.clone()));
+        watcher
+            .wait(id, timeout, move || {
+                StandardExecutionService::get_execution_for_tenant(self, tenant_id, id)
+            })
+            .await
+    }
 }
 
 impl StandardExecutionService {
@@ -0,0 +1,295 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Execution Completion Watcher
+//!
+//! Event-driven replacement for the per-caller `get_execution` + `sleep`
+//! polling loops that used to wait on child executions (output-handler agents,
+//! RouterAgent classification, inner-loop judges, `aegis.task.wait`).
+//!
+//! A single background task subscribes to the [`EventBus`] and resolves
+//! one-shot waiters the moment an execution emits a terminal
+//! [`ExecutionEvent`]. Waiters cost a map entry and a oneshot channel instead of
+//! a dedicated tick loop, so thousands of concurrent waits per node add no
+//! steady-state load on the execution repository.
+//!
+//! # Correctness
+//!
+//! The watcher only signals that *something changed*; the authoritative state
+//! is always re-read from the repository. Waiters register **before** reading
+//! the persisted status, so a completion that races the read is never missed.
+//! If the bus lags or the terminal event is published before the repository
+//! write lands, a bounded safety re-check ([`SAFETY_RECHECK_INTERVAL`]) picks
+//! it up.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Bridge in-memory `EventBus` terminal events → awaiting callers
+
+use crate::domain::events::ExecutionEvent;
+use crate::domain::execution::{Execution, ExecutionId};
+use crate::infrastructure::event_bus::{DomainEvent, EventBus, EventBusError, SubscriptionOptions};
+use anyhow::Result;
+use dashmap::DashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use tracing::{debug, warn};
+
+/// Upper bound between repository re-reads while waiting. Only matters when a
+/// terminal event is lost or reordered; the common path wakes on the event.
+pub const SAFETY_RECHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Queue depth for the watcher's bus subscription. Every event is inspected,
+/// so this is sized well above the default to ride out publish bursts.
+const WATCHER_QUEUE_CAPACITY: usize = 8192;
+
+/// Resolves waiters when executions reach a terminal state.
+pub struct ExecutionCompletionWatcher {
+    waiters: DashMap<ExecutionId, Vec<oneshot::Sender<()>>>,
+}
+
+impl ExecutionCompletionWatcher {
+    /// Create the watcher and spawn its bus subscription task.
+    ///
+    /// The task holds only a weak reference and exits once the watcher is
+    /// dropped or the bus closes.
+    pub fn spawn(event_bus: Arc<EventBus>) -> Arc<Self> {
+        let watcher = Arc::new(Self {
+            waiters: DashMap::new(),
+        });
+        let mut receiver = event_bus.subscribe_with(
+            SubscriptionOptions::named("execution_completion_watcher")
+                .with_capacity(WATCHER_QUEUE_CAPACITY),
+        );
+        let weak = Arc::downgrade(&watcher);
+
+        tokio::spawn(async move {
+            loop {
+                let result = receiver.recv().await;
+                let Some(watcher) = weak.upgrade() else {
+                    break;
+                };
+                match result {
+                    Ok(DomainEvent::Execution(event)) => {
+                        if let Some(execution_id) = terminal_execution_id(&event) {
+                            watcher.notify(execution_id);
+                        }
+                    }
+                    Ok(_) => {}
+                    Err(EventBusError::Lagged(n)) => {
+                        // A dropped terminal event would strand its waiter
+                        // until the safety re-check; wake everyone now instead.
+                        warn!(
+                            lagged_events = n,
+                            "execution completion watcher lagged; re-checking all waiters"
+                        );
+                        watcher.notify_all();
+                    }
+                    Err(e) => {
+                        debug!(error = %e, "execution completion watcher stopping");
+                        watcher.notify_all();
+                        break;
+                    }
+                }
+            }
+        });
+
+        watcher
+    }
+
+    /// Register interest in `execution_id`. The receiver fires on the next
+    /// terminal event for that execution (or on a bus lag).
+    pub fn register(&self, execution_id: ExecutionId) -> oneshot::Receiver<()> {
+        let (tx, rx) = oneshot::channel();
+        let mut entry = self.waiters.entry(execution_id).or_default();
+        entry.retain(|sender| !sender.is_closed());
+        entry.push(tx);
+        rx
+    }
+
+    /// Wait until `load` returns a terminal execution or `timeout` elapses.
+    ///
+    /// Returns the last loaded snapshot either way; callers distinguish the
+    /// timeout case via `execution.status.is_terminal()`.
+    pub async fn wait<F, Fut>(
+        &self,
+        execution_id: ExecutionId,
+        timeout: Duration,
+        mut load: F,
+    ) -> Result<Execution>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<Execution>>,
+    {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            let signal = self.register(execution_id);
+            let execution = match load().await {
+                Ok(execution) => execution,
+                Err(e) => {
+                    drop(signal);
+                    self.prune(execution_id);
+                    return Err(e);
+                }
+            };
+
+            let now = tokio::time::Instant::now();
+            if execution.status.is_terminal() || now >= deadline {
+                drop(signal);
+                self.prune(execution_id);
+                return Ok(execution);
+            }
+
+            let recheck = (deadline - now).min(SAFETY_RECHECK_INTERVAL);
+            let _ = tokio::time::timeout(recheck, signal).await;
+        }
+    }
+
+    /// Number of executions with at least one live waiter.
+    pub fn pending_waiters(&self) -> usize {
+        self.waiters.len()
+    }
+
+    fn notify(&self, execution_id: ExecutionId) {
+        if let Some((_, senders)) = self.waiters.remove(&execution_id) {
+            for sender in senders {
+                let _ = sender.send(());
+            }
+        }
+    }
+
+    fn notify_all(&self) {
+        let ids: Vec<ExecutionId> = self.waiters.iter().map(|e| *e.key()).collect();
+        for id in ids {
+            self.notify(id);
+        }
+    }
+
+    /// Drop abandoned senders so executions that were already terminal when
+    /// first checked do not leave entries behind.
+    fn prune(&self, execution_id: ExecutionId) {
+        self.waiters.remove_if_mut(&execution_id, |_, senders| {
+            senders.retain(|sender| !sender.is_closed());
+            senders.is_empty()
+        });
+    }
+}
+
+pub(crate) fn terminal_execution_id(event: &ExecutionEvent) -> Option<ExecutionId> {
+    match event {
+        ExecutionEvent::ExecutionCompleted { execution_id, .. }
+        | ExecutionEvent::ExecutionFailed { execution_id, .. }
+        | ExecutionEvent::ExecutionCancelled { execution_id, .. }
+        | ExecutionEvent::ExecutionTimedOut { execution_id, .. } => Some(*execution_id),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::agent::AgentId;
+    use crate::domain::execution::ExecutionInput;
+    use chrono::Utc;
+    use std::sync::Mutex;
+
+    fn running_execution() -> Execution {
+        let mut execution = Execution::new(
+            AgentId::new(),
+            ExecutionInput {
+                intent: None,
+                input: serde_json::json!({}),
+                workspace_volume_id: None,
+                workspace_volume_mount_path: None,
+                workspace_remote_path: None,
+                workflow_execution_id: None,
+                attachments: Vec::new(),
+            },
+            1,
+            "aegis-system-operator".to_string(),
+        );
+        execution.start();
+        execution
+    }
+
+    #[tokio::test]
+    async fn wakes_on_terminal_event_without_polling() {
+        let event_bus = Arc::new(EventBus::new(64));
+        let watcher = ExecutionCompletionWatcher::spawn(event_bus.clone());
+        let execution = Arc::new(Mutex::new(running_execution()));
+        let execution_id = execution.lock().unwrap().id;
+        let loads = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let waiter = {
+            let watcher = watcher.clone();
+            let execution = execution.clone();
+            let loads = loads.clone();
+            tokio::spawn(async move {
+                watcher
+                    .wait(execution_id, Duration::from_secs(30), || {
+                        loads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                        let snapshot = execution.lock().unwrap().clone();
+                        async move { Ok(snapshot) }
+                    })
+                    .await
+            })
+        };
+
+        while watcher.pending_waiters() == 0 {
+            tokio::task::yield_now().await;
+        }
+        execution.lock().unwrap().complete();
+        event_bus.publish_execution_event(ExecutionEvent::ExecutionCompleted {
+            execution_id,
+            agent_id: AgentId::new(),
+            final_output: "done".to_string(),
+            total_iterations: 1,
+            completed_at: Utc::now(),
+        });
+
+        let result = tokio::time::timeout(Duration::from_secs(2), waiter)
+            .await
+            .expect("waiter should wake on the terminal event")
+            .unwrap()
+            .unwrap();
+        assert!(result.status.is_terminal());
+        assert_eq!(loads.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(watcher.pending_waiters(), 0);
+    }
+
+    #[tokio::test]
+    async fn already_terminal_execution_returns_immediately() {
+        let watcher = ExecutionCompletionWatcher::spawn(Arc::new(EventBus::new(8)));
+        let mut execution = running_execution();
+        execution.complete();
+        let execution_id = execution.id;
+
+        let result = watcher
+            .wait(execution_id, Duration::from_secs(30), || {
+                let snapshot = execution.clone();
+                async move { Ok(snapshot) }
+            })
+            .await
+            .unwrap();
+        assert!(result.status.is_terminal());
+        assert_eq!(watcher.pending_waiters(), 0);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn timeout_returns_last_snapshot() {
+        let watcher = ExecutionCompletionWatcher::spawn(Arc::new(EventBus::new(8)));
+        let execution = running_execution();
+        let execution_id = execution.id;
+
+        let result = watcher
+            .wait(execution_id, Duration::from_secs(12), || {
+                let snapshot = execution.clone();
+                async move { Ok(snapshot) }
+            })
+            .await
+            .unwrap();
+        assert!(!result.status.is_terminal());
+    }
+}
@@ -30,7 +30,7 @@
 use crate::domain::events::ExecutionEvent;
 use crate::domain::execution::ExecutionId;
 use crate::domain::repository::WorkflowExecutionRepository;
-use crate::infrastructure::event_bus::{DomainEvent, EventBus, EventBusError};
+use crate::infrastructure::event_bus::{DomainEvent, EventBus, EventBusError, SubscriptionOptions};
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
@@ -76,7 +76,9 @@ impl ExecutionEventPersister {
         info!("ExecutionEvent persister started (audit trail enabled)");
 
         tokio::spawn(async move {
-            let mut receiver = self.event_bus.subscribe();
+            let mut receiver = self
+                .event_bus
+                .subscribe_with(SubscriptionOptions::critical("execution_event_persister"));
             loop {
                 match receiver.recv().await {
                     Ok(DomainEvent::Execution(event)) => {
@@ -160,8 +162,12 @@ fn execution_id_for(event: &ExecutionEvent) -> ExecutionId {
         | ExecutionEvent::ConsoleOutput { execution_id, .. }
         | ExecutionEvent::LlmInteraction { execution_id, .. }
         | ExecutionEvent::LlmCallFailed { execution_id, .. }
+        | ExecutionEvent::ModelRouted { execution_id, .. }
+        | ExecutionEvent::OperatorGuidanceAdded { execution_id, .. }
         | ExecutionEvent::InstanceSpawned { execution_id, .. }
-        | ExecutionEvent::InstanceTerminated { execution_id, .. } => *execution_id,
+        | ExecutionEvent::InstanceTerminated { execution_id, .. }
+        | ExecutionEvent::StorageTransportInterrupted { execution_id, .. }
+        | ExecutionEvent::OutputModerated { execution_id, .. } => *execution_id,
         // Variants not enumerated above use serde to extract the field. This
         // is robust against new variants and is only used as a last resort —
         // the common path matches above with no allocation.
@@ -204,11 +210,20 @@ fn iteration_number_for(event: &ExecutionEvent) -> Option<u8> {
         | ExecutionEvent::LlmCallFailed {
             iteration_number, ..
         }
+        | ExecutionEvent::ModelRouted {
+            iteration_number, ..
+        }
+        | ExecutionEvent::OperatorGuidanceAdded {
+            iteration_number, ..
+        }
         | ExecutionEvent::InstanceSpawned {
             iteration_number, ..
         }
         | ExecutionEvent::InstanceTerminated {
             iteration_number, ..
+        }
+        | ExecutionEvent::StorageTransportInterrupted {
+            iteration_number, ..
         } => Some(*iteration_number),
         _ => None,
     }
@@ -228,8 +243,12 @@ fn event_type_str(event: &ExecutionEvent) -> &'static str {
         ExecutionEvent::ConsoleOutput { .. } => "ConsoleOutput",
         ExecutionEvent::LlmInteraction { .. } => "LlmInteraction",
         ExecutionEvent::LlmCallFailed { .. } => "LlmCallFailed",
+        ExecutionEvent::ModelRouted { .. } => "ModelRouted",
+        ExecutionEvent::OperatorGuidanceAdded { .. } => "OperatorGuidanceAdded",
         ExecutionEvent::InstanceSpawned { .. } => "InstanceSpawned",
         ExecutionEvent::InstanceTerminated { .. } => "InstanceTerminated",
+        ExecutionEvent::StorageTransportInterrupted { .. } => "StorageTransportInterrupted",
+        ExecutionEvent::OutputModerated { .. } => "OutputModerated",
         _ => "ExecutionEvent",
     }
 }
@@ -295,6 +314,9 @@ mod tests {
         ) -> Result<Vec<WorkflowExecution>, RepositoryError> {
             Ok(vec![])
         }
+        async fn find_active_all(&self) -> Result<Vec<WorkflowExecution>, RepositoryError> {
+            Ok(vec![])
+        }
         async fn find_by_workflow_for_tenant(
             &self,
             _tenant_id: &TenantId,
@@ -368,6 +390,33 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn append_transition(
+            &self,
+            _execution_id: ExecutionId,
+            _record: &crate::domain::workflow::WorkflowTransitionRecord,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn close_transition(
+            &self,
+            _execution_id: ExecutionId,
+            _state_name: &str,
+            _exited_at: chrono::DateTime<chrono::Utc>,
+            _output_summary: Option<String>,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_transitions_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _id: ExecutionId,
+        ) -> Result<Vec<crate::domain::workflow::WorkflowTransitionRecord>, RepositoryError>
+        {
+            Ok(vec![])
+        }
+
         async fn find_tenant_id_by_execution(
             &self,
             _id: ExecutionId,
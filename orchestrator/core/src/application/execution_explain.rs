@@ -0,0 +1,801 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Execution explain report.
+//!
+//! Merges everything persisted about one execution into a single
+//! chronological narrative for triage: the aggregate's iterations, LLM calls,
+//! tool trajectory and validation results, the `execution_events` audit
+//! trail (Cortex pattern injections, operator guidance, LLM failures,
+//! timeouts) and storage policy violations. Each entry carries a severity,
+//! and [`ExecutionExplanation::findings`] lists the entries most likely to
+//! explain the outcome.
+//!
+//! Tool calls are not timestamped in the trajectory; they are placed at the
+//! start of their iteration, in call order.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Backs `GET /v1/executions/{id}/explain`
+
+use std::fmt::Write as _;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use crate::domain::agent::AgentId;
+use crate::domain::events::{ExecutionEvent, StorageEvent};
+use crate::domain::execution::{Execution, ExecutionId, ExecutionStatus, IterationStatus};
+use crate::domain::repository::{
+    RepositoryError, StorageEventRepository, WorkflowExecutionRepository,
+};
+
+/// Audit-trail events read per report.
+pub const EXPLAIN_EVENT_LIMIT: usize = 2000;
+/// Storage events read per report.
+pub const EXPLAIN_STORAGE_EVENT_LIMIT: usize = 5000;
+
+/// Characters kept from outputs, stderr and prompts in entry summaries.
+const SUMMARY_CHARS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExplainSource {
+    Execution,
+    Llm,
+    Tool,
+    Validation,
+    Cortex,
+    Operator,
+    Storage,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExplainSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExplainEntry {
+    pub timestamp: DateTime<Utc>,
+    pub iteration_number: Option<u8>,
+    pub source: ExplainSource,
+    pub severity: ExplainSeverity,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Value::is_null")]
+    pub details: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExecutionExplanation {
+    pub execution_id: ExecutionId,
+    pub agent_id: AgentId,
+    pub status: ExecutionStatus,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub iterations: usize,
+    pub max_iterations: u8,
+    pub error: Option<String>,
+    /// One-line annotations on what most likely decided the outcome.
+    pub findings: Vec<String>,
+    pub entries: Vec<ExplainEntry>,
+}
+
+/// Load every persisted source for `execution` and build its explanation.
+pub async fn explain_execution(
+    execution: &Execution,
+    events: &dyn WorkflowExecutionRepository,
+    storage_events: &dyn StorageEventRepository,
+) -> Result<ExecutionExplanation, RepositoryError> {
+    // Temporal-listener records in the same table are workflow events and
+    // don't deserialize as `ExecutionEvent`; they are covered elsewhere.
+    let audit: Vec<ExecutionEvent> = events
+        .find_events_by_execution(execution.id, EXPLAIN_EVENT_LIMIT, 0)
+        .await?
+        .into_iter()
+        .filter_map(|record| serde_json::from_value(record.payload).ok())
+        .collect();
+    let storage = storage_events
+        .find_by_execution(execution.id, Some(EXPLAIN_STORAGE_EVENT_LIMIT))
+        .await?;
+    Ok(build_explanation(execution, &audit, &storage))
+}
+
+pub fn build_explanation(
+    execution: &Execution,
+    audit: &[ExecutionEvent],
+    storage: &[StorageEvent],
+) -> ExecutionExplanation {
+    let mut entries = Vec::new();
+    let mut push = |timestamp, iteration_number, source, severity, summary: String, details| {
+        entries.push(ExplainEntry {
+            timestamp,
+            iteration_number,
+            source,
+            severity,
+            summary,
+            details,
+        })
+    };
+
+    push(
+        execution.started_at,
+        None,
+        ExplainSource::Execution,
+        ExplainSeverity::Info,
+        format!(
+            "Execution started (security context '{}', max {} iterations)",
+            execution.security_context_name, execution.max_iterations
+        ),
+        Value::Null,
+    );
+
+    for iteration in execution.iterations() {
+        let n = Some(iteration.number);
+        let ended = iteration.ended_at.unwrap_or(iteration.started_at);
+        push(
+            iteration.started_at,
+            n,
+            ExplainSource::Execution,
+            ExplainSeverity::Info,
+            format!(
+                "Iteration {} started: {}",
+                iteration.number, iteration.action
+            ),
+            Value::Null,
+        );
+
+        for llm in &iteration.llm_interactions {
+            push(
+                llm.timestamp,
+                n,
+                ExplainSource::Llm,
+                ExplainSeverity::Info,
+                format!(
+                    "LLM call to {}/{} ({} in, {} out tokens)",
+                    llm.provider,
+                    llm.model,
+                    tokens(llm.input_tokens),
+                    tokens(llm.output_tokens)
+                ),
+                json!({ "response": truncate(&llm.response) }),
+            );
+        }
+
+        for step in iteration.trajectory.iter().flatten() {
+            let (severity, outcome) = match &step.error {
+                Some(error) => (ExplainSeverity::Warning, format!("failed: {error}")),
+                None => (ExplainSeverity::Info, step.status.clone()),
+            };
+            push(
+                iteration.started_at,
+                n,
+                ExplainSource::Tool,
+                severity,
+                format!("Tool {} {}", step.tool_name, outcome),
+                json!({ "arguments": truncate(&step.arguments_json) }),
+            );
+        }
+
+        for tool in &iteration.policy_violations {
+            push(
+                ended,
+                n,
+                ExplainSource::Tool,
+                ExplainSeverity::Warning,
+                format!("Tool {tool} blocked by security context policy"),
+                Value::Null,
+            );
+        }
+
+        if let Some(results) = &iteration.validation_results {
+            if let Some(system) = &results.system {
+                push(
+                    ended,
+                    n,
+                    ExplainSource::Validation,
+                    if system.success {
+                        ExplainSeverity::Info
+                    } else {
+                        ExplainSeverity::Error
+                    },
+                    format!("System validation exited with code {}", system.exit_code),
+                    json!({ "stderr": truncate(&system.stderr) }),
+                );
+            }
+            if let Some(output) = results.output.as_ref().filter(|o| !o.success) {
+                push(
+                    ended,
+                    n,
+                    ExplainSource::Validation,
+                    ExplainSeverity::Error,
+                    format!(
+                        "Output validation failed: {}",
+                        output.error.as_deref().unwrap_or("no reason given")
+                    ),
+                    Value::Null,
+                );
+            }
+            if let Some(semantic) = &results.semantic {
+                push(
+                    ended,
+                    n,
+                    ExplainSource::Validation,
+                    if semantic.success {
+                        ExplainSeverity::Info
+                    } else {
+                        ExplainSeverity::Error
+                    },
+                    format!("Semantic validation scored {:.2}", semantic.score),
+                    json!({ "reasoning": truncate(&semantic.reasoning) }),
+                );
+            }
+            if let Some(gradient) = &results.gradient {
+                push(
+                    ended,
+                    n,
+                    ExplainSource::Validation,
+                    ExplainSeverity::Info,
+                    format!(
+                        "Judge scored {:.2} (confidence {:.2})",
+                        gradient.score, gradient.confidence
+                    ),
+                    json!({ "reasoning": truncate(&gradient.reasoning) }),
+                );
+            }
+            if let Some(consensus) = &results.consensus {
+                push(
+                    ended,
+                    n,
+                    ExplainSource::Validation,
+                    ExplainSeverity::Info,
+                    format!(
+                        "{} judges reached {:.2} (confidence {:.2})",
+                        consensus.individual_results.len(),
+                        consensus.final_score,
+                        consensus.consensus_confidence
+                    ),
+                    Value::Null,
+                );
+            }
+        }
+
+        match iteration.status {
+            IterationStatus::Success => push(
+                ended,
+                n,
+                ExplainSource::Execution,
+                ExplainSeverity::Info,
+                format!("Iteration {} succeeded", iteration.number),
+                json!({ "output": iteration.output.as_deref().map(truncate) }),
+            ),
+            IterationStatus::Failed => push(
+                ended,
+                n,
+                ExplainSource::Execution,
+                ExplainSeverity::Error,
+                format!(
+                    "Iteration {} failed: {}",
+                    iteration.number,
+                    iteration
+                        .error
+                        .as_ref()
+                        .map_or("no error recorded", |e| e.message.as_str())
+                ),
+                json!({ "details": iteration.error.as_ref().and_then(|e| e.details.clone()) }),
+            ),
+            IterationStatus::Running | IterationStatus::Refining => {}
+        }
+    }
+
+    // The aggregate already covers iterations, LLM calls and validation;
+    // the audit trail adds what only exists there.
+    for event in audit {
+        match event {
+            ExecutionEvent::RefinementApplied {
+                iteration_number,
+                code_diff,
+                applied_at,
+                cortex_pattern_id,
+                cortex_pattern_category,
+                cortex_success_score,
+                cortex_solution_approach,
+                ..
+            } => match cortex_pattern_id {
+                Some(pattern_id) => push(
+                    *applied_at,
+                    Some(*iteration_number),
+                    ExplainSource::Cortex,
+                    ExplainSeverity::Info,
+                    format!(
+                        "Cortex pattern {pattern_id} ({}) injected into refinement{}",
+                        cortex_pattern_category
+                            .as_deref()
+                            .unwrap_or("uncategorized"),
+                        cortex_success_score
+                            .map(|score| format!(", past success {score:.2}"))
+                            .unwrap_or_default()
+                    ),
+                    json!({
+                        "file_path": code_diff.file_path,
+                        "solution_approach": cortex_solution_approach,
+                    }),
+                ),
+                None => push(
+                    *applied_at,
+                    Some(*iteration_number),
+                    ExplainSource::Execution,
+                    ExplainSeverity::Info,
+                    format!("Refinement applied to {}", code_diff.file_path),
+                    Value::Null,
+                ),
+            },
+            ExecutionEvent::OperatorGuidanceAdded {
+                iteration_number,
+                guidance,
+                submitted_by,
+                timestamp,
+                ..
+            } => push(
+                *timestamp,
+                Some(*iteration_number),
+                ExplainSource::Operator,
+                ExplainSeverity::Info,
+                format!("Guidance from {submitted_by}: {}", truncate(guidance)),
+                Value::Null,
+            ),
+            ExecutionEvent::ModelRouted {
+                iteration_number,
+                requested_alias,
+                routed_alias,
+                rule,
+                timestamp,
+                ..
+            } if requested_alias != routed_alias => push(
+                *timestamp,
+                Some(*iteration_number),
+                ExplainSource::Llm,
+                ExplainSeverity::Info,
+                format!(
+                    "Routed {requested_alias} to {routed_alias}{}",
+                    rule.as_deref()
+                        .map(|rule| format!(" (rule {rule})"))
+                        .unwrap_or_default()
+                ),
+                Value::Null,
+            ),
+            ExecutionEvent::LlmCallFailed {
+                iteration_number,
+                provider,
+                model,
+                error_class,
+                message,
+                attempts,
+                fallback_attempted,
+                timestamp,
+                ..
+            } => push(
+                *timestamp,
+                Some(*iteration_number),
+                ExplainSource::Llm,
+                ExplainSeverity::Error,
+                format!(
+                    "LLM call to {provider}/{model} failed after {attempts} attempt(s): {message}"
+                ),
+                json!({
+                    "error_class": error_class,
+                    "fallback_attempted": fallback_attempted,
+                }),
+            ),
+            ExecutionEvent::ExecutionTimedOut {
+                timeout_seconds,
+                timed_out_at,
+                ..
+            } => push(
+                *timed_out_at,
+                None,
+                ExplainSource::Execution,
+                ExplainSeverity::Error,
+                format!("Execution timed out after {timeout_seconds}s"),
+                Value::Null,
+            ),
+            ExecutionEvent::ChildExecutionSpawned {
+                child_execution_id,
+                child_agent_id,
+                spawned_at,
+                ..
+            } => push(
+                *spawned_at,
+                None,
+                ExplainSource::Execution,
+                ExplainSeverity::Info,
+                format!("Spawned child execution {child_execution_id} (agent {child_agent_id})"),
+                Value::Null,
+            ),
+            ExecutionEvent::ChildExecutionCompleted {
+                child_execution_id,
+                outcome,
+                completed_at,
+                ..
+            } => push(
+                *completed_at,
+                None,
+                ExplainSource::Execution,
+                ExplainSeverity::Info,
+                format!("Child execution {child_execution_id} {outcome}"),
+                Value::Null,
+            ),
+            ExecutionEvent::StorageTransportInterrupted {
+                iteration_number,
+                transport,
+                generation,
+                reason,
+                interrupted_at,
+                ..
+            } => push(
+                *interrupted_at,
+                Some(*iteration_number),
+                ExplainSource::Storage,
+                ExplainSeverity::Warning,
+                format!("Storage transport {transport} restarted ({reason}); iteration abandoned on stale mounts"),
+                json!({ "generation": generation }),
+            ),
+            ExecutionEvent::OutputModerated {
+                handler_type,
+                action,
+                findings,
+                delivered,
+                reviewed_by,
+                moderated_at,
+                ..
+            } => {
+                let verdict = crate::domain::moderation::ModerationVerdict::new(findings.clone());
+                let (severity, disposition) = match (delivered, reviewed_by) {
+                    (false, _) => (ExplainSeverity::Warning, "withheld".to_string()),
+                    (true, Some(approver)) => {
+                        (ExplainSeverity::Info, format!("released by {approver}"))
+                    }
+                    (true, None) => (ExplainSeverity::Info, "delivered".to_string()),
+                };
+                push(
+                    *moderated_at,
+                    None,
+                    ExplainSource::Execution,
+                    severity,
+                    format!(
+                        "Moderation verdict `{}` before {handler_type} handler, {disposition}: {}",
+                        action.as_str(),
+                        verdict.summary()
+                    ),
+                    serde_json::to_value(findings).unwrap_or(Value::Null),
+                )
+            }
+            _ => {}
+        }
+    }
+
+    for event in storage {
+        match event {
+            StorageEvent::FilesystemPolicyViolation {
+                iteration_number,
+                operation,
+                path,
+                policy_rule,
+                violated_at,
+                ..
+            } => push(
+                *violated_at,
+                *iteration_number,
+                ExplainSource::Storage,
+                ExplainSeverity::Warning,
+                format!("Filesystem policy denied {operation} on {path} ({policy_rule})"),
+                Value::Null,
+            ),
+            StorageEvent::PathTraversalBlocked {
+                attempted_path,
+                blocked_at,
+                ..
+            } => push(
+                *blocked_at,
+                None,
+                ExplainSource::Storage,
+                ExplainSeverity::Error,
+                format!("Path traversal blocked: {attempted_path}"),
+                Value::Null,
+            ),
+            StorageEvent::QuotaExceeded {
+                requested_bytes,
+                available_bytes,
+                exceeded_at,
+                ..
+            } => push(
+                *exceeded_at,
+                None,
+                ExplainSource::Storage,
+                ExplainSeverity::Error,
+                format!(
+                    "Volume quota exceeded: requested {requested_bytes} bytes, {available_bytes} available"
+                ),
+                Value::Null,
+            ),
+            StorageEvent::UnauthorizedVolumeAccess {
+                volume_id,
+                attempted_at,
+                ..
+            } => push(
+                *attempted_at,
+                None,
+                ExplainSource::Storage,
+                ExplainSeverity::Error,
+                format!("Unauthorized access to volume {volume_id}"),
+                Value::Null,
+            ),
+            StorageEvent::OperationFailed {
+                iteration_number,
+                operation,
+                path,
+                cause,
+                error,
+                failed_at,
+                ..
+            } => push(
+                *failed_at,
+                *iteration_number,
+                ExplainSource::Storage,
+                ExplainSeverity::Error,
+                format!("Storage {operation} on {path} failed ({cause}): {error}"),
+                Value::Null,
+            ),
+            _ => {}
+        }
+    }
+
+    let ended_at = execution.ended_at.unwrap_or(Utc::now());
+    match execution.status {
+        ExecutionStatus::Completed => push(
+            ended_at,
+            None,
+            ExplainSource::Execution,
+            ExplainSeverity::Info,
+            "Execution completed".to_string(),
+            Value::Null,
+        ),
+        ExecutionStatus::Failed | ExecutionStatus::Cancelled => push(
+            ended_at,
+            None,
+            ExplainSource::Execution,
+            ExplainSeverity::Error,
+            format!(
+                "Execution {}: {}",
+                if execution.status == ExecutionStatus::Failed {
+                    "failed"
+                } else {
+                    "cancelled"
+                },
+                execution.error.as_deref().unwrap_or("no reason recorded")
+            ),
+            Value::Null,
+        ),
+        ExecutionStatus::Pending | ExecutionStatus::Running => {}
+    }
+
+    // Stable: entries with equal timestamps keep their source order.
+    entries.sort_by_key(|entry| entry.timestamp);
+    let findings = findings(execution, &entries);
+
+    ExecutionExplanation {
+        execution_id: execution.id,
+        agent_id: execution.agent_id,
+        status: execution.status.clone(),
+        started_at: execution.started_at,
+        ended_at: execution.ended_at,
+        iterations: execution.iterations().len(),
+        max_iterations: execution.max_iterations,
+        error: execution.error.clone(),
+        findings,
+        entries,
+    }
+}
+
+fn findings(execution: &Execution, entries: &[ExplainEntry]) -> Vec<String> {
+    let mut findings = Vec::new();
+    if execution.status == ExecutionStatus::Failed
+        && execution.iterations().len() >= execution.max_iterations as usize
+    {
+        findings.push(format!(
+            "Used all {} iterations without passing validation",
+            execution.max_iterations
+        ));
+    }
+    // The last error of each source is the most specific one.
+    for source in [
+        ExplainSource::Llm,
+        ExplainSource::Validation,
+        ExplainSource::Storage,
+        ExplainSource::Execution,
+    ] {
+        if let Some(entry) = entries
+            .iter()
+            .rev()
+            .find(|e| e.source == source && e.severity == ExplainSeverity::Error)
+        {
+            findings.push(entry.summary.clone());
+        }
+    }
+    let blocked = entries
+        .iter()
+        .filter(|e| e.source == ExplainSource::Tool && e.severity == ExplainSeverity::Warning)
+        .count();
+    if blocked > 0 {
+        findings.push(format!("{blocked} tool call(s) failed or were blocked"));
+    }
+    let patterns = entries
+        .iter()
+        .filter(|e| e.source == ExplainSource::Cortex)
+        .count();
+    if patterns > 0 {
+        findings.push(format!("{patterns} Cortex pattern(s) injected"));
+    }
+    findings
+}
+
+impl ExecutionExplanation {
+    /// Plain-text rendering for terminals and tickets.
+    pub fn render_text(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "Execution {} ({:?}, {}/{} iterations)",
+            self.execution_id, self.status, self.iterations, self.max_iterations
+        );
+        if !self.findings.is_empty() {
+            let _ = writeln!(out, "\nFindings:");
+            for finding in &self.findings {
+                let _ = writeln!(out, "  - {finding}");
+            }
+        }
+        let _ = writeln!(out, "\nTimeline:");
+        for entry in &self.entries {
+            let marker = match entry.severity {
+                ExplainSeverity::Info => " ",
+                ExplainSeverity::Warning => "!",
+                ExplainSeverity::Error => "x",
+            };
+            let iteration = entry
+                .iteration_number
+                .map(|n| format!("#{n}"))
+                .unwrap_or_default();
+            let _ = writeln!(
+                out,
+                "  {marker} {} {iteration:>3} [{:?}] {}",
+                entry.timestamp.format("%H:%M:%S%.3f"),
+                entry.source,
+                entry.summary
+            );
+        }
+        out
+    }
+}
+
+fn tokens(count: Option<u32>) -> String {
+    count.map_or_else(|| "?".to_string(), |n| n.to_string())
+}
+
+fn truncate(text: &str) -> String {
+    match text.char_indices().nth(SUMMARY_CHARS) {
+        Some((end, _)) => format!("{}…", &text[..end]),
+        None => text.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::execution::{CodeDiff, ExecutionInput, IterationError};
+    use crate::domain::validation::{SystemValidationResult, ValidationResults};
+    use crate::domain::volume::VolumeId;
+    use chrono::Duration;
+
+    fn failed_execution() -> Execution {
+        let mut execution = Execution::new(
+            AgentId::new(),
+            ExecutionInput {
+                intent: Some("fix the build".to_string()),
+                input: json!({}),
+                workspace_volume_id: None,
+                workspace_volume_mount_path: None,
+                workspace_remote_path: None,
+                workflow_execution_id: None,
+                attachments: Vec::new(),
+            },
+            1,
+            "default".to_string(),
+        );
+        execution.start();
+        execution.start_iteration("generate".to_string()).unwrap();
+        execution.add_policy_violation("cmd.run".to_string());
+        execution
+            .store_validation_results(
+                1,
+                ValidationResults {
+                    system: Some(SystemValidationResult {
+                        success: false,
+                        exit_code: 2,
+                        stdout: String::new(),
+                        stderr: "error[E0425]".to_string(),
+                    }),
+                    output: None,
+                    semantic: None,
+                    gradient: None,
+                    consensus: None,
+                },
+            )
+            .unwrap();
+        execution.fail_iteration(IterationError {
+            message: "tests failed".to_string(),
+            details: None,
+        });
+        execution.fail("max iterations reached".to_string());
+        execution
+    }
+
+    #[test]
+    fn merges_sources_chronologically_with_findings() {
+        let execution = failed_execution();
+        let audit = vec![ExecutionEvent::RefinementApplied {
+            execution_id: execution.id,
+            agent_id: execution.agent_id,
+            iteration_number: 1,
+            code_diff: CodeDiff {
+                file_path: "src/lib.rs".to_string(),
+                diff: String::new(),
+            },
+            applied_at: execution.started_at + Duration::milliseconds(1),
+            cortex_pattern_id: Some("p-42".to_string()),
+            cortex_pattern_category: Some("build".to_string()),
+            cortex_success_score: Some(0.8),
+            cortex_solution_approach: None,
+        }];
+        let storage = vec![StorageEvent::FilesystemPolicyViolation {
+            execution_id: Some(execution.id),
+            workflow_execution_id: None,
+            iteration_number: Some(1),
+            volume_id: VolumeId::new(),
+            operation: "write".to_string(),
+            path: "/etc/passwd".to_string(),
+            policy_rule: "write_allowlist".to_string(),
+            violated_at: execution.started_at + Duration::milliseconds(2),
+            caller_node_id: None,
+            host_node_id: None,
+        }];
+
+        let explanation = build_explanation(&execution, &audit, &storage);
+        assert!(explanation
+            .entries
+            .windows(2)
+            .all(|pair| pair[0].timestamp <= pair[1].timestamp));
+        for source in [
+            ExplainSource::Cortex,
+            ExplainSource::Storage,
+            ExplainSource::Validation,
+            ExplainSource::Tool,
+        ] {
+            assert!(explanation.entries.iter().any(|e| e.source == source));
+        }
+        assert_eq!(
+            explanation.findings,
+            vec![
+                "Used all 1 iterations without passing validation".to_string(),
+                "System validation exited with code 2".to_string(),
+                "Execution failed: max iterations reached".to_string(),
+                "1 tool call(s) failed or were blocked".to_string(),
+                "1 Cortex pattern(s) injected".to_string(),
+            ]
+        );
+        assert!(explanation.render_text().contains("Findings:"));
+    }
+}
@@ -0,0 +1,179 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Per-iteration file activity for the execution report.
+//!
+//! Groups an execution's persisted `StorageEvent`s by the supervisor
+//! iteration that was running when they happened (see
+//! `StorageEvent::iteration_number`), so a bad file can be traced back to the
+//! iteration that wrote it. Opens, closes and directory listings are left
+//! out; they say nothing about what changed.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Builds the `file_activity` section of the execution report
+
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::events::StorageEvent;
+use crate::domain::execution::ExecutionId;
+use crate::domain::repository::{RepositoryError, StorageEventRepository};
+
+/// Storage events read per report.
+pub const FILE_ACTIVITY_EVENT_LIMIT: usize = 5000;
+
+/// What one iteration did to one file.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct FileActivity {
+    pub volume_id: Uuid,
+    pub path: String,
+    pub reads: u32,
+    pub bytes_read: u64,
+    pub writes: u32,
+    pub bytes_written: u64,
+    pub created: bool,
+    pub deleted: bool,
+    /// Operations on this file refused by the filesystem policy.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub denied: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct IterationFileActivity {
+    /// `None` collects operations that could not be attributed to an
+    /// iteration (recorded before iteration tracking, or outside the loop).
+    pub iteration_number: Option<u8>,
+    pub files: Vec<FileActivity>,
+}
+
+/// Load the execution's storage events and group them by iteration.
+pub async fn file_activity_for_execution(
+    repository: &dyn StorageEventRepository,
+    execution_id: ExecutionId,
+) -> Result<Vec<IterationFileActivity>, RepositoryError> {
+    let events = repository
+        .find_by_execution(execution_id, Some(FILE_ACTIVITY_EVENT_LIMIT))
+        .await?;
+    Ok(group_by_iteration(&events))
+}
+
+/// Iterations in ascending order with unattributed activity first; files
+/// within an iteration are ordered by volume and path.
+pub fn group_by_iteration(events: &[StorageEvent]) -> Vec<IterationFileActivity> {
+    let mut iterations: BTreeMap<Option<u8>, BTreeMap<(Uuid, String), FileActivity>> =
+        BTreeMap::new();
+
+    for event in events {
+        let (volume_id, path) = match event {
+            StorageEvent::FileRead {
+                volume_id, path, ..
+            }
+            | StorageEvent::FileWritten {
+                volume_id, path, ..
+            }
+            | StorageEvent::FileCreated {
+                volume_id, path, ..
+            }
+            | StorageEvent::FileDeleted {
+                volume_id, path, ..
+            }
+            | StorageEvent::FilesystemPolicyViolation {
+                volume_id, path, ..
+            } => (volume_id.0, path.clone()),
+            _ => continue,
+        };
+        let file = iterations
+            .entry(event.iteration_number())
+            .or_default()
+            .entry((volume_id, path.clone()))
+            .or_insert_with(|| FileActivity {
+                volume_id,
+                path,
+                ..Default::default()
+            });
+
+        match event {
+            StorageEvent::FileRead { bytes_read, .. } => {
+                file.reads += 1;
+                file.bytes_read += bytes_read;
+            }
+            StorageEvent::FileWritten { bytes_written, .. } => {
+                file.writes += 1;
+                file.bytes_written += bytes_written;
+            }
+            StorageEvent::FileCreated { .. } => file.created = true,
+            StorageEvent::FileDeleted { .. } => file.deleted = true,
+            StorageEvent::FilesystemPolicyViolation { operation, .. } => {
+                if !file.denied.contains(operation) {
+                    file.denied.push(operation.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    iterations
+        .into_iter()
+        .map(|(iteration_number, files)| IterationFileActivity {
+            iteration_number,
+            files: files.into_values().collect(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::volume::VolumeId;
+    use chrono::Utc;
+
+    fn written(volume_id: VolumeId, path: &str, iteration: Option<u8>, bytes: u64) -> StorageEvent {
+        StorageEvent::FileWritten {
+            execution_id: Some(ExecutionId::new()),
+            workflow_execution_id: None,
+            iteration_number: iteration,
+            volume_id,
+            path: path.to_string(),
+            offset: 0,
+            bytes_written: bytes,
+            duration_ms: 1,
+            written_at: Utc::now(),
+            caller_node_id: None,
+            host_node_id: None,
+        }
+    }
+
+    #[test]
+    fn groups_writes_by_iteration() {
+        let volume = VolumeId::new();
+        let events = vec![
+            written(volume, "/out.txt", Some(2), 10),
+            written(volume, "/out.txt", Some(1), 5),
+            written(volume, "/out.txt", Some(2), 7),
+            written(volume, "/legacy.txt", None, 1),
+            StorageEvent::FileClosed {
+                execution_id: None,
+                workflow_execution_id: None,
+                iteration_number: Some(1),
+                volume_id: volume,
+                path: "/out.txt".to_string(),
+                closed_at: Utc::now(),
+                caller_node_id: None,
+                host_node_id: None,
+            },
+        ];
+
+        let report = group_by_iteration(&events);
+        let numbers: Vec<_> = report.iter().map(|i| i.iteration_number).collect();
+        assert_eq!(numbers, vec![None, Some(1), Some(2)]);
+
+        let second = &report[2].files;
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].writes, 2);
+        assert_eq!(second[0].bytes_written, 17);
+        assert_eq!(report[1].files[0].bytes_written, 5);
+    }
+}
@@ -0,0 +1,425 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Execution Queue Worker
+//!
+//! Consumes the shared [`TaskQueue`] that `start_execution` writes to when
+//! `spec.execution_queue` is configured, and starts each claimed task on this
+//! daemon with its pre-assigned [`ExecutionId`].
+//!
+//! A semaphore of `max_concurrent_executions` permits bounds the work one
+//! daemon takes on: a permit is held from claim until the started execution
+//! reaches a terminal state, so a busy daemon stops claiming and leaves tasks
+//! to its peers. A cordoned daemon stops claiming altogether.
+//!
+//! A task is acked only after its execution has started. If the daemon dies
+//! in between, the redelivered task finds the execution already present and
+//! is acked without starting it again.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Queue consumer loop for distributed execution scheduling
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{watch, OwnedSemaphorePermit, Semaphore};
+use tracing::{debug, error, info, warn};
+
+use crate::application::cluster::NodeMaintenanceService;
+use crate::application::execution::ExecutionService;
+use crate::domain::node_config::ExecutionQueueConfig;
+use crate::domain::task_queue::{ClaimedTask, TaskQueue};
+
+/// How long one `wait_for_terminal` call blocks before the permit holder
+/// checks again. Executions have no upper bound on runtime.
+const TERMINAL_WAIT: Duration = Duration::from_secs(3600);
+
+pub struct ExecutionQueueWorker {
+    queue: Arc<dyn TaskQueue>,
+    executor: Arc<dyn ExecutionService>,
+    config: ExecutionQueueConfig,
+    consumer: String,
+    node_maintenance: Option<Arc<NodeMaintenanceService>>,
+    slots: Arc<Semaphore>,
+}
+
+impl ExecutionQueueWorker {
+    /// `executor` must start executions locally; it is normally the same
+    /// service whose `start_execution` feeds the queue. `consumer` names this
+    /// daemon to the queue backend and should be its stable node id.
+    pub fn new(
+        queue: Arc<dyn TaskQueue>,
+        executor: Arc<dyn ExecutionService>,
+        config: ExecutionQueueConfig,
+        consumer: impl Into<String>,
+    ) -> Self {
+        let slots = Arc::new(Semaphore::new(config.max_concurrent_executions.max(1)));
+        Self {
+            queue,
+            executor,
+            config,
+            consumer: consumer.into(),
+            node_maintenance: None,
+            slots,
+        }
+    }
+
+    /// Stop claiming while this node is cordoned.
+    pub fn with_node_maintenance(mut self, maintenance: Arc<NodeMaintenanceService>) -> Self {
+        self.node_maintenance = Some(maintenance);
+        self
+    }
+
+    pub async fn run(&self, mut shutdown: watch::Receiver<bool>) {
+        info!(
+            backend = self.queue.backend(),
+            consumer = %self.consumer,
+            max_concurrent = self.config.max_concurrent_executions,
+            "Execution queue worker started"
+        );
+        loop {
+            let permit = tokio::select! {
+                permit = self.slots.clone().acquire_owned() => {
+                    permit.expect("execution queue semaphore is never closed")
+                }
+                _ = shutdown.changed() => break,
+            };
+
+            let claimed = if self
+                .node_maintenance
+                .as_ref()
+                .is_some_and(|m| m.is_cordoned())
+            {
+                false
+            } else {
+                match self.poll_once(permit).await {
+                    Ok(claimed) => claimed,
+                    Err(e) => {
+                        warn!(error = %e, "Execution queue poll failed");
+                        false
+                    }
+                }
+            };
+
+            if !claimed {
+                tokio::select! {
+                    _ = tokio::time::sleep(self.config.poll_interval()) => {}
+                    _ = shutdown.changed() => break,
+                }
+            }
+        }
+        info!("Execution queue worker shutting down");
+    }
+
+    /// Claim and start at most one task. Returns whether a task was claimed.
+    pub(crate) async fn poll_once(&self, permit: OwnedSemaphorePermit) -> anyhow::Result<bool> {
+        let Some(claim) = self.queue.claim(&self.consumer).await? else {
+            return Ok(false);
+        };
+        self.start(claim, permit).await?;
+        Ok(true)
+    }
+
+    async fn start(&self, claim: ClaimedTask, permit: OwnedSemaphorePermit) -> anyhow::Result<()> {
+        let task = &claim.task;
+        let backend = self.queue.backend();
+
+        if self
+            .executor
+            .get_execution_unscoped(task.execution_id)
+            .await
+            .is_ok()
+        {
+            debug!(
+                execution_id = %task.execution_id,
+                attempts = claim.attempts,
+                "Queued execution already started; acknowledging redelivery"
+            );
+            return self.queue.ack(&claim).await;
+        }
+
+        let started = self
+            .executor
+            .start_execution_with_id(
+                task.execution_id,
+                task.agent_id,
+                task.input.clone(),
+                task.security_context_name.clone(),
+                task.identity.as_ref(),
+            )
+            .await;
+
+        match started {
+            Ok(execution_id) => {
+                self.queue.ack(&claim).await?;
+                metrics::counter!("aegis_execution_queue_started_total", "backend" => backend)
+                    .increment(1);
+                info!(
+                    execution_id = %execution_id,
+                    tenant_id = %task.tenant_id,
+                    attempts = claim.attempts,
+                    "Started queued execution"
+                );
+
+                let executor = self.executor.clone();
+                let tenant_id = task.tenant_id.clone();
+                tokio::spawn(async move {
+                    let _permit = permit;
+                    loop {
+                        match executor
+                            .wait_for_terminal(&tenant_id, execution_id, TERMINAL_WAIT)
+                            .await
+                        {
+                            Ok(execution) if !execution.status.is_terminal() => continue,
+                            _ => break,
+                        }
+                    }
+                });
+                Ok(())
+            }
+            Err(e) if claim.attempts >= self.config.max_attempts => {
+                error!(
+                    execution_id = %task.execution_id,
+                    tenant_id = %task.tenant_id,
+                    attempts = claim.attempts,
+                    error = %e,
+                    "Dropping queued execution after repeated start failures"
+                );
+                metrics::counter!("aegis_execution_queue_dropped_total", "backend" => backend)
+                    .increment(1);
+                self.queue.ack(&claim).await
+            }
+            Err(e) => {
+                warn!(
+                    execution_id = %task.execution_id,
+                    attempts = claim.attempts,
+                    error = %e,
+                    "Queued execution failed to start; will retry"
+                );
+                self.queue.release(&claim, self.config.retry_delay()).await
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::events::ExecutionEvent;
+    use crate::domain::execution::{Execution, ExecutionId, ExecutionInput};
+    use crate::domain::iam::UserIdentity;
+    use crate::domain::shared_kernel::AgentId;
+    use crate::domain::task_queue::ExecutionTask;
+    use crate::domain::tenant::TenantId;
+    use crate::infrastructure::event_bus::DomainEvent;
+    use crate::infrastructure::task_queue::InMemoryTaskQueue;
+    use anyhow::Result;
+    use async_trait::async_trait;
+    use futures::Stream;
+    use std::collections::HashMap;
+    use std::pin::Pin;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    fn input() -> ExecutionInput {
+        ExecutionInput {
+            intent: Some("hello".to_string()),
+            input: serde_json::json!({}),
+            workspace_volume_id: None,
+            workspace_volume_mount_path: None,
+            workspace_remote_path: None,
+            workflow_execution_id: None,
+            attachments: Vec::new(),
+        }
+    }
+
+    /// Starts executions as already completed after `failures` failed starts.
+    #[derive(Default)]
+    struct FlakyExecutionService {
+        failures: AtomicU32,
+        started: Mutex<HashMap<ExecutionId, Execution>>,
+    }
+
+    impl FlakyExecutionService {
+        fn failing(failures: u32) -> Self {
+            Self {
+                failures: AtomicU32::new(failures),
+                ..Self::default()
+            }
+        }
+
+        fn started(&self) -> usize {
+            self.started.lock().unwrap().len()
+        }
+    }
+
+    #[async_trait]
+    impl ExecutionService for FlakyExecutionService {
+        async fn start_execution(
+            &self,
+            _agent_id: AgentId,
+            _input: ExecutionInput,
+            _security_context_name: String,
+            _identity: Option<&UserIdentity>,
+        ) -> Result<ExecutionId> {
+            anyhow::bail!("start_execution not used in queue worker tests")
+        }
+
+        async fn start_execution_with_id(
+            &self,
+            execution_id: ExecutionId,
+            agent_id: AgentId,
+            input: ExecutionInput,
+            security_context_name: String,
+            _identity: Option<&UserIdentity>,
+        ) -> Result<ExecutionId> {
+            if self
+                .failures
+                .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+                .is_ok()
+            {
+                anyhow::bail!("runtime unavailable");
+            }
+            let mut execution =
+                Execution::new_with_id(execution_id, agent_id, input, 1, security_context_name);
+            execution.start();
+            execution.complete();
+            self.started.lock().unwrap().insert(execution_id, execution);
+            Ok(execution_id)
+        }
+
+        async fn start_child_execution(
+            &self,
+            _agent_id: AgentId,
+            _input: ExecutionInput,
+            _parent_execution_id: ExecutionId,
+        ) -> Result<ExecutionId> {
+            anyhow::bail!("start_child_execution not used in queue worker tests")
+        }
+
+        async fn get_execution_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            id: ExecutionId,
+        ) -> Result<Execution> {
+            self.get_execution_unscoped(id).await
+        }
+
+        async fn get_execution_unscoped(&self, id: ExecutionId) -> Result<Execution> {
+            self.started
+                .lock()
+                .unwrap()
+                .get(&id)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("Execution not found"))
+        }
+
+        async fn get_iterations_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _exec_id: ExecutionId,
+        ) -> Result<Vec<crate::domain::execution::Iteration>> {
+            anyhow::bail!("get_iterations_for_tenant not used in queue worker tests")
+        }
+
+        async fn cancel_execution_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _id: ExecutionId,
+        ) -> Result<()> {
+            anyhow::bail!("cancel_execution_for_tenant not used in queue worker tests")
+        }
+
+        async fn stream_execution(
+            &self,
+            _id: ExecutionId,
+        ) -> Result<Pin<Box<dyn Stream<Item = Result<ExecutionEvent>> + Send>>> {
+            anyhow::bail!("stream_execution not used in queue worker tests")
+        }
+
+        async fn stream_agent_events(
+            &self,
+            _id: AgentId,
+        ) -> Result<Pin<Box<dyn Stream<Item = Result<DomainEvent>> + Send>>> {
+            anyhow::bail!("stream_agent_events not used in queue worker tests")
+        }
+
+        async fn list_executions_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _agent_id: Option<AgentId>,
+            _workflow_id: Option<crate::domain::workflow::WorkflowId>,
+            _limit: usize,
+        ) -> Result<Vec<Execution>> {
+            anyhow::bail!("list_executions_for_tenant not used in queue worker tests")
+        }
+
+        async fn delete_execution_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _id: ExecutionId,
+        ) -> Result<()> {
+            anyhow::bail!("delete_execution_for_tenant not used in queue worker tests")
+        }
+
+        async fn record_llm_interaction(
+            &self,
+            _execution_id: ExecutionId,
+            _iteration: u8,
+            _interaction: crate::domain::execution::LlmInteraction,
+        ) -> Result<()> {
+            anyhow::bail!("record_llm_interaction not used in queue worker tests")
+        }
+
+        async fn store_iteration_trajectory(
+            &self,
+            _execution_id: ExecutionId,
+            _iteration: u8,
+            _trajectory: Vec<crate::domain::execution::TrajectoryStep>,
+        ) -> Result<()> {
+            anyhow::bail!("store_iteration_trajectory not used in queue worker tests")
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_failed_starts_and_acks_redeliveries_without_restarting() {
+        let queue = Arc::new(InMemoryTaskQueue::new(Duration::from_secs(60)));
+        let executor = Arc::new(FlakyExecutionService::failing(1));
+        let config = ExecutionQueueConfig {
+            retry_delay_seconds: 0,
+            ..ExecutionQueueConfig::default()
+        };
+        let worker = ExecutionQueueWorker::new(queue.clone(), executor.clone(), config, "node-a");
+        let permit = || worker.slots.clone().try_acquire_owned().unwrap();
+
+        let task = ExecutionTask {
+            execution_id: ExecutionId::new(),
+            tenant_id: TenantId::consumer(),
+            agent_id: AgentId::new(),
+            input: input(),
+            security_context_name: "default".to_string(),
+            identity: None,
+            enqueued_at: chrono::Utc::now(),
+        };
+        queue.enqueue(&task).await.unwrap();
+
+        // First start fails and the task is released for another attempt.
+        assert!(worker.poll_once(permit()).await.unwrap());
+        assert_eq!(executor.started(), 0);
+
+        assert!(worker.poll_once(permit()).await.unwrap());
+        assert_eq!(executor.started(), 1);
+        assert!(executor
+            .get_execution_unscoped(task.execution_id)
+            .await
+            .is_ok());
+        assert!(!worker.poll_once(permit()).await.unwrap());
+
+        // A duplicate delivery of a started execution is acked, not restarted.
+        queue.enqueue(&task).await.unwrap();
+        assert!(worker.poll_once(permit()).await.unwrap());
+        assert_eq!(executor.started(), 1);
+        assert!(!worker.poll_once(permit()).await.unwrap());
+    }
+}
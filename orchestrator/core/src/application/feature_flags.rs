@@ -0,0 +1,300 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Feature Flags
+//!
+//! Lets risky subsystems ship dark and be switched on per tenant. A flag's
+//! value for a tenant is the first of:
+//!
+//! 1. the runtime override's value for that tenant
+//! 2. the runtime override's global value
+//! 3. `spec.feature_flags.<name>.tenants.<tenant>` from the node config
+//! 4. `spec.feature_flags.<name>.enabled`
+//! 5. the built-in default from [`KNOWN_FLAGS`] (`false` for unknown flags)
+//!
+//! Overrides are set by operators over `/v1/admin/feature-flags` and kept in
+//! memory until restart. Services take the [`FeatureFlagService`] through a
+//! `with_feature_flags` builder and ask [`FeatureFlagService::is_enabled`] at
+//! the decision point, so a flipped flag applies to the next request.
+//!
+//! Every flag's global value is exported as the
+//! `aegis_feature_flag_enabled{flag}` gauge.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Static and runtime feature flag evaluation
+
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::node_config::{is_valid_feature_flag_name, FeatureFlagConfig};
+use crate::domain::tenant::TenantId;
+
+/// Flags consulted in this codebase.
+pub mod flags {
+    /// Per-request model routing (`spec.llm_selection.routing`). Turn off
+    /// for a tenant to pin its agents to their declared model alias.
+    pub const MODEL_ROUTING: &str = "model_routing";
+    /// Lets a tenant's executions request network flow logging
+    /// (`network_flow_capture` in the execution payload).
+    pub const NETWORK_FLOW_CAPTURE: &str = "network_flow_capture";
+    /// Audited tenants: every agent manifest update over the API must say
+    /// why (`change_reason`).
+    pub const MANIFEST_CHANGE_REASON: &str = "manifest_change_reason";
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct FeatureFlagDefinition {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub default: bool,
+}
+
+/// Flags with a built-in default, listed even when nothing configures them.
+pub const KNOWN_FLAGS: &[FeatureFlagDefinition] = &[
+    FeatureFlagDefinition {
+        name: flags::MODEL_ROUTING,
+        description: "Per-request model routing by task classification",
+        default: true,
+    },
+    FeatureFlagDefinition {
+        name: flags::NETWORK_FLOW_CAPTURE,
+        description: "Per-execution network flow logging on request",
+        default: false,
+    },
+    FeatureFlagDefinition {
+        name: flags::MANIFEST_CHANGE_REASON,
+        description: "Require a change reason on agent manifest updates",
+        default: false,
+    },
+];
+
+#[derive(Debug, thiserror::Error)]
+pub enum FeatureFlagError {
+    #[error("invalid feature flag name '{0}' (use lowercase letters, digits, '_', '.', '-')")]
+    InvalidName(String),
+    #[error("override for '{0}' sets neither `enabled` nor any tenant")]
+    EmptyOverride(String),
+}
+
+/// Runtime value for a flag, replacing the configured one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlagOverride {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+    /// Tenant slug to value.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tenants: BTreeMap<String, bool>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StoredOverride {
+    #[serde(flatten)]
+    pub value: FeatureFlagOverride,
+    pub updated_by: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A flag as reported by the admin API and diagnostics.
+#[derive(Debug, Clone, Serialize)]
+pub struct FeatureFlagState {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Value for tenants without a targeted value.
+    pub enabled: bool,
+    /// Value for the tenant the state was requested for.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled_for_tenant: Option<bool>,
+    pub default: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configured: Option<FeatureFlagConfig>,
+    #[serde(rename = "override", skip_serializing_if = "Option::is_none")]
+    pub runtime_override: Option<StoredOverride>,
+}
+
+pub struct FeatureFlagService {
+    configured: BTreeMap<String, FeatureFlagConfig>,
+    overrides: RwLock<BTreeMap<String, StoredOverride>>,
+}
+
+impl FeatureFlagService {
+    pub fn new(configured: BTreeMap<String, FeatureFlagConfig>) -> Self {
+        let service = Self {
+            configured,
+            overrides: RwLock::new(BTreeMap::new()),
+        };
+        service.record_metrics();
+        service
+    }
+
+    /// Value of `flag` for `tenant_id`, or its global value when `None`.
+    pub fn is_enabled(&self, flag: &str, tenant_id: Option<&TenantId>) -> bool {
+        let overrides = self.overrides.read().unwrap_or_else(|e| e.into_inner());
+        resolve(
+            flag,
+            tenant_id.map(TenantId::as_str),
+            overrides.get(flag).map(|o| &o.value),
+            self.configured.get(flag),
+        )
+    }
+
+    pub fn set_override(
+        &self,
+        flag: &str,
+        value: FeatureFlagOverride,
+        updated_by: &str,
+    ) -> Result<StoredOverride, FeatureFlagError> {
+        if !is_valid_feature_flag_name(flag) {
+            return Err(FeatureFlagError::InvalidName(flag.to_string()));
+        }
+        if value.enabled.is_none() && value.tenants.is_empty() {
+            return Err(FeatureFlagError::EmptyOverride(flag.to_string()));
+        }
+        let stored = StoredOverride {
+            value,
+            updated_by: updated_by.to_string(),
+            updated_at: Utc::now(),
+        };
+        self.overrides
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(flag.to_string(), stored.clone());
+        tracing::info!(flag, updated_by, "Feature flag override set");
+        self.record_metrics();
+        Ok(stored)
+    }
+
+    /// Drop the runtime override; the configured value applies again.
+    pub fn clear_override(&self, flag: &str) -> bool {
+        let removed = self
+            .overrides
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(flag)
+            .is_some();
+        if removed {
+            tracing::info!(flag, "Feature flag override cleared");
+            self.record_metrics();
+        }
+        removed
+    }
+
+    /// Every known, configured or overridden flag, by name.
+    pub fn states(&self, tenant_id: Option<&TenantId>) -> Vec<FeatureFlagState> {
+        let overrides = self.overrides.read().unwrap_or_else(|e| e.into_inner());
+        let mut names: Vec<&str> = KNOWN_FLAGS.iter().map(|d| d.name).collect();
+        names.extend(self.configured.keys().map(String::as_str));
+        names.extend(overrides.keys().map(String::as_str));
+        names.sort_unstable();
+        names.dedup();
+
+        names
+            .into_iter()
+            .map(|name| {
+                let runtime_override = overrides.get(name);
+                let configured = self.configured.get(name);
+                let known = known(name);
+                FeatureFlagState {
+                    name: name.to_string(),
+                    description: configured
+                        .and_then(|c| c.description.clone())
+                        .or_else(|| known.map(|d| d.description.to_string())),
+                    enabled: resolve(name, None, runtime_override.map(|o| &o.value), configured),
+                    enabled_for_tenant: tenant_id.map(|tenant| {
+                        resolve(
+                            name,
+                            Some(tenant.as_str()),
+                            runtime_override.map(|o| &o.value),
+                            configured,
+                        )
+                    }),
+                    default: known.is_some_and(|d| d.default),
+                    configured: configured.cloned(),
+                    runtime_override: runtime_override.cloned(),
+                }
+            })
+            .collect()
+    }
+
+    /// Global value of every flag, for health and diagnostics output.
+    pub fn summary(&self) -> BTreeMap<String, bool> {
+        self.states(None)
+            .into_iter()
+            .map(|state| (state.name, state.enabled))
+            .collect()
+    }
+
+    fn record_metrics(&self) {
+        for (name, enabled) in self.summary() {
+            let value = if enabled { 1.0 } else { 0.0 };
+            metrics::gauge!("aegis_feature_flag_enabled", "flag" => name).set(value);
+        }
+    }
+}
+
+fn known(flag: &str) -> Option<&'static FeatureFlagDefinition> {
+    KNOWN_FLAGS.iter().find(|d| d.name == flag)
+}
+
+fn resolve(
+    flag: &str,
+    tenant: Option<&str>,
+    runtime_override: Option<&FeatureFlagOverride>,
+    configured: Option<&FeatureFlagConfig>,
+) -> bool {
+    let targeted = |tenants: &BTreeMap<String, bool>| tenant.and_then(|t| tenants.get(t).copied());
+    runtime_override
+        .and_then(|o| targeted(&o.tenants).or(o.enabled))
+        .or_else(|| configured.map(|c| targeted(&c.tenants).unwrap_or(c.enabled)))
+        .unwrap_or_else(|| known(flag).is_some_and(|d| d.default))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_and_tenant_targeting_take_precedence() {
+        let acme = TenantId::from_realm_slug("acme").unwrap();
+        let other = TenantId::from_realm_slug("other").unwrap();
+        let service = FeatureFlagService::new(BTreeMap::from([(
+            "virtio_fs".to_string(),
+            FeatureFlagConfig {
+                enabled: false,
+                tenants: BTreeMap::from([("acme".to_string(), true)]),
+                description: None,
+            },
+        )]));
+
+        assert!(service.is_enabled("virtio_fs", Some(&acme)));
+        assert!(!service.is_enabled("virtio_fs", Some(&other)));
+        assert!(service.is_enabled(flags::MODEL_ROUTING, Some(&other)));
+        assert!(!service.is_enabled("unknown", None));
+
+        // A global override beats the configured tenant value...
+        service
+            .set_override(
+                "virtio_fs",
+                FeatureFlagOverride {
+                    enabled: Some(false),
+                    tenants: BTreeMap::from([("other".to_string(), true)]),
+                },
+                "ops",
+            )
+            .unwrap();
+        assert!(!service.is_enabled("virtio_fs", Some(&acme)));
+        // ...and an override tenant value beats the override's global one.
+        assert!(service.is_enabled("virtio_fs", Some(&other)));
+
+        assert!(service.clear_override("virtio_fs"));
+        assert!(service.is_enabled("virtio_fs", Some(&acme)));
+        assert!(matches!(
+            service.set_override("Bad Name", FeatureFlagOverride::default(), "ops"),
+            Err(FeatureFlagError::InvalidName(_))
+        ));
+    }
+}
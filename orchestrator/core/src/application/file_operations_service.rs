@@ -8,11 +8,14 @@
 
 use std::sync::Arc;
 
+use bytes::Bytes;
 use chrono::{DateTime, TimeZone, Utc};
+use futures::stream::{self, BoxStream, StreamExt};
 
+use crate::domain::execution::{AttachmentRef, OUTPUT_ATTACHMENT_DIR};
 use crate::domain::fsal::{AegisFSAL, FsalError};
 use crate::domain::path_sanitizer::PathSanitizer;
-use crate::domain::storage::{FileType, OpenMode, StorageError};
+use crate::domain::storage::{FileType, OpenMode, StorageError, StorageProvider};
 use crate::domain::tenant::TenantId;
 use crate::domain::volume::VolumeId;
 
@@ -34,6 +37,38 @@ pub struct FileContent {
     pub content_type: String,
 }
 
+/// Size of each ranged read issued by a [`FileStream`].
+///
+/// Bounds per-download memory: a 500 MB artifact is served as 1 MiB reads
+/// rather than materialised in full.
+pub const FILE_STREAM_CHUNK_BYTES: usize = 1024 * 1024;
+
+/// Largest file the volume browser returns inline
+/// (`GET /v1/volumes/:id/files/content`). Bigger files go through
+/// `/files/download`.
+pub const MAX_BROWSE_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Bytes read from the start of a file to sniff its MIME type when listing
+/// output attachments.
+pub const MIME_SNIFF_BYTES: usize = 8 * 1024;
+
+/// A file served as a stream of [`FILE_STREAM_CHUNK_BYTES`]-sized chunks.
+pub struct FileStream {
+    /// File size reported by the storage provider at open time.
+    pub size: u64,
+    pub content_type: String,
+    pub chunks: BoxStream<'static, Result<Bytes, FileOperationsError>>,
+}
+
+impl std::fmt::Debug for FileStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FileStream")
+            .field("size", &self.size)
+            .field("content_type", &self.content_type)
+            .finish_non_exhaustive()
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct FileAttributes {
     pub name: String,
@@ -89,6 +124,7 @@ impl From<FsalError> for FileOperationsError {
             }
             FsalError::Storage(StorageError::FileNotFound(p)) => FileOperationsError::NotFound(p),
             FsalError::Storage(StorageError::NotFound(p)) => FileOperationsError::NotFound(p),
+            FsalError::FileTooLarge { .. } => FileOperationsError::FileTooLarge,
             _ => FileOperationsError::Fsal(e.to_string()),
         }
     }
@@ -160,6 +196,56 @@ impl FileOperationsService {
         Ok(result)
     }
 
+    /// Read-only listing for the volume browser. Unlike
+    /// [`list_directory`](Self::list_directory) this also covers the tenant's
+    /// execution and workflow workspace volumes, and the FSAL audit-logs
+    /// every listing.
+    pub async fn browse_directory(
+        &self,
+        volume_id: &VolumeId,
+        tenant_id: &TenantId,
+        owner: &str,
+        path: &str,
+    ) -> Result<Vec<DirEntry>, FileOperationsError> {
+        let volume = self
+            .fsal
+            .authorize_browse(tenant_id, owner, volume_id)
+            .await?;
+        let entries = self.fsal.browse_readdir(&volume, path).await?;
+
+        Ok(entries
+            .into_iter()
+            .map(|(entry, attrs)| DirEntry {
+                name: entry.name,
+                is_dir: entry.file_type == FileType::Directory,
+                size_bytes: attrs.as_ref().map(|a| a.size).unwrap_or(0),
+                modified_at: attrs.and_then(|a| Utc.timestamp_opt(a.mtime, 0).single()),
+            })
+            .collect())
+    }
+
+    /// Read a whole file for the volume browser, refusing files over
+    /// `max_bytes`. Same authorization and auditing as
+    /// [`browse_directory`](Self::browse_directory).
+    pub async fn browse_file(
+        &self,
+        volume_id: &VolumeId,
+        tenant_id: &TenantId,
+        owner: &str,
+        path: &str,
+        max_bytes: u64,
+    ) -> Result<FileContent, FileOperationsError> {
+        let volume = self
+            .fsal
+            .authorize_browse(tenant_id, owner, volume_id)
+            .await?;
+        let data = self.fsal.browse_read(&volume, path, max_bytes).await?;
+        Ok(FileContent {
+            data: data.to_vec(),
+            content_type: guess_content_type(path),
+        })
+    }
+
     pub async fn read_file(
         &self,
         volume_id: &VolumeId,
@@ -204,6 +290,32 @@ impl FileOperationsService {
         Ok(FileContent { data, content_type })
     }
 
+    /// Streaming variant of [`read_file`](Self::read_file) for downloads.
+    ///
+    /// Authorization and path resolution are identical; the content is read
+    /// lazily in [`FILE_STREAM_CHUNK_BYTES`] ranges as the stream is polled.
+    pub async fn read_file_stream(
+        &self,
+        volume_id: &VolumeId,
+        tenant_id: &TenantId,
+        owner: &str,
+        path: &str,
+    ) -> Result<FileStream, FileOperationsError> {
+        let volume = self
+            .fsal
+            .authorize_for_user(tenant_id, owner, volume_id)
+            .await?;
+        let full_path = self.sanitize_and_resolve(path, &volume)?;
+
+        stream_file(
+            self.fsal.storage_provider().clone(),
+            full_path,
+            guess_content_type(path),
+            FILE_STREAM_CHUNK_BYTES,
+        )
+        .await
+    }
+
     pub async fn write_file(
         &self,
         volume_id: &VolumeId,
@@ -498,30 +610,9 @@ impl FileOperationsService {
         tenant_id: &crate::domain::tenant::TenantId,
         path: &str,
     ) -> Result<FileContent, FileOperationsError> {
-        use crate::domain::volume::VolumeOwnership;
-
-        let ownership = VolumeOwnership::execution(execution_id);
-        let volumes = self
-            .fsal
-            .volume_repository()
-            .find_by_ownership(&ownership)
-            .await
-            .map_err(|e| FileOperationsError::Repository(e.to_string()))?;
-
-        let volume = volumes.into_iter().next().ok_or_else(|| {
-            FileOperationsError::NotFound(format!(
-                "no workspace volume for execution {}",
-                execution_id.0
-            ))
-        })?;
-
-        // Tenant isolation check
-        if &volume.tenant_id != tenant_id {
-            return Err(FileOperationsError::Unauthorized);
-        }
-
-        let sanitized = self.sanitize(path)?;
-        let full_path = routed_path(&volume, &sanitized);
+        let full_path = self
+            .resolve_execution_path(execution_id, tenant_id, path)
+            .await?;
 
         let handle = self
             .fsal
@@ -553,12 +644,210 @@ impl FileOperationsService {
         let content_type = guess_content_type(path);
         Ok(FileContent { data, content_type })
     }
+
+    /// Streaming variant of [`read_file_for_execution`](Self::read_file_for_execution).
+    pub async fn read_file_for_execution_stream(
+        &self,
+        execution_id: crate::domain::execution::ExecutionId,
+        tenant_id: &crate::domain::tenant::TenantId,
+        path: &str,
+    ) -> Result<FileStream, FileOperationsError> {
+        let full_path = self
+            .resolve_execution_path(execution_id, tenant_id, path)
+            .await?;
+
+        stream_file(
+            self.fsal.storage_provider().clone(),
+            full_path,
+            guess_content_type(path),
+            FILE_STREAM_CHUNK_BYTES,
+        )
+        .await
+    }
+
+    /// Output attachments of an execution: every file under
+    /// [`OUTPUT_ATTACHMENT_DIR`] in its workspace volume, recursively, sorted
+    /// by path. Paths are relative to the workspace root, so each file can
+    /// be fetched with [`read_file_for_execution_stream`](Self::read_file_for_execution_stream).
+    /// MIME types are sniffed from the first [`MIME_SNIFF_BYTES`] of each
+    /// file; no digest is computed. A missing directory yields an empty list.
+    pub async fn list_execution_outputs(
+        &self,
+        execution_id: crate::domain::execution::ExecutionId,
+        tenant_id: &crate::domain::tenant::TenantId,
+    ) -> Result<Vec<AttachmentRef>, FileOperationsError> {
+        let volume = self
+            .resolve_execution_volume(execution_id, tenant_id)
+            .await?;
+        let storage = self.fsal.storage_provider();
+
+        let mut outputs = Vec::new();
+        let mut pending = vec![OUTPUT_ATTACHMENT_DIR.to_string()];
+        while let Some(dir) = pending.pop() {
+            let full_dir = routed_path(&volume, &self.sanitize(&dir)?);
+            let entries = match storage.readdir(&full_dir).await {
+                Ok(entries) => entries,
+                Err(StorageError::FileNotFound(_) | StorageError::NotFound(_)) => continue,
+                Err(e) => return Err(FileOperationsError::Fsal(e.to_string())),
+            };
+            for entry in entries {
+                if entry.name == "." || entry.name == ".." {
+                    continue;
+                }
+                let path = format!("{dir}/{}", entry.name);
+                match entry.file_type {
+                    FileType::Directory => pending.push(path),
+                    FileType::File => {
+                        let full_path = routed_path(&volume, &self.sanitize(&path)?);
+                        let (size, head) = read_head(storage.as_ref(), &full_path).await?;
+                        outputs.push(AttachmentRef {
+                            volume_id: volume.id,
+                            mime_type: infer::get(&head)
+                                .map(|kind| kind.mime_type().to_string())
+                                .unwrap_or_else(|| guess_content_type(&path)),
+                            name: entry.name,
+                            path,
+                            size,
+                            sha256: None,
+                        });
+                    }
+                    FileType::Symlink => {}
+                }
+            }
+        }
+        outputs.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(outputs)
+    }
+
+    /// Resolve `path` inside an execution's workspace volume, enforcing tenant
+    /// isolation.
+    async fn resolve_execution_path(
+        &self,
+        execution_id: crate::domain::execution::ExecutionId,
+        tenant_id: &crate::domain::tenant::TenantId,
+        path: &str,
+    ) -> Result<String, FileOperationsError> {
+        let volume = self
+            .resolve_execution_volume(execution_id, tenant_id)
+            .await?;
+        let sanitized = self.sanitize(path)?;
+        Ok(routed_path(&volume, &sanitized))
+    }
+
+    /// The workspace volume of an execution, enforcing tenant isolation.
+    async fn resolve_execution_volume(
+        &self,
+        execution_id: crate::domain::execution::ExecutionId,
+        tenant_id: &crate::domain::tenant::TenantId,
+    ) -> Result<crate::domain::volume::Volume, FileOperationsError> {
+        use crate::domain::volume::VolumeOwnership;
+
+        let ownership = VolumeOwnership::execution(execution_id);
+        let volumes = self
+            .fsal
+            .volume_repository()
+            .find_by_ownership(&ownership)
+            .await
+            .map_err(|e| FileOperationsError::Repository(e.to_string()))?;
+
+        let volume = volumes.into_iter().next().ok_or_else(|| {
+            FileOperationsError::NotFound(format!(
+                "no workspace volume for execution {}",
+                execution_id.0
+            ))
+        })?;
+
+        // Tenant isolation check
+        if &volume.tenant_id != tenant_id {
+            return Err(FileOperationsError::Unauthorized);
+        }
+
+        Ok(volume)
+    }
 }
 
 // ============================================================================
 // Helpers
 // ============================================================================
 
+/// Open `full_path` and return a stream of `chunk_size` ranged reads.
+///
+/// Only one chunk is held in memory at a time. The storage handle is closed
+/// once the final chunk has been read; handles of every current provider are
+/// path-encoded, so a stream dropped mid-download leaks nothing server-side.
+async fn stream_file(
+    storage: Arc<dyn StorageProvider>,
+    full_path: String,
+    content_type: String,
+    chunk_size: usize,
+) -> Result<FileStream, FileOperationsError> {
+    let handle = storage
+        .open_file(&full_path, OpenMode::ReadOnly)
+        .await
+        .map_err(|e| match e {
+            StorageError::FileNotFound(p) => FileOperationsError::NotFound(p),
+            StorageError::NotFound(p) => FileOperationsError::NotFound(p),
+            other => FileOperationsError::Fsal(other.to_string()),
+        })?;
+    let size = storage
+        .stat(&full_path)
+        .await
+        .map_err(|e| FileOperationsError::Fsal(e.to_string()))?
+        .size;
+
+    let chunks = stream::try_unfold(0u64, move |offset| {
+        let storage = storage.clone();
+        let handle = handle.clone();
+        async move {
+            if offset >= size {
+                let _ = storage.close_file(&handle).await;
+                return Ok(None);
+            }
+            let length = (size - offset).min(chunk_size as u64) as usize;
+            let chunk = storage
+                .read_at_bytes(&handle, offset, length)
+                .await
+                .map_err(|e| FileOperationsError::Fsal(e.to_string()))?;
+            if chunk.is_empty() {
+                // File shrank since `stat`; end the stream rather than spin.
+                let _ = storage.close_file(&handle).await;
+                return Ok(None);
+            }
+            let next = offset + chunk.len() as u64;
+            Ok(Some((chunk, next)))
+        }
+    })
+    .boxed();
+
+    Ok(FileStream {
+        size,
+        content_type,
+        chunks,
+    })
+}
+
+/// Size of a file and its first [`MIME_SNIFF_BYTES`].
+async fn read_head(
+    storage: &dyn StorageProvider,
+    full_path: &str,
+) -> Result<(u64, Vec<u8>), FileOperationsError> {
+    let size = storage
+        .stat(full_path)
+        .await
+        .map_err(|e| FileOperationsError::Fsal(e.to_string()))?
+        .size;
+    let handle = storage
+        .open_file(full_path, OpenMode::ReadOnly)
+        .await
+        .map_err(|e| FileOperationsError::Fsal(e.to_string()))?;
+    let head = storage
+        .read_at(&handle, 0, size.min(MIME_SNIFF_BYTES as u64) as usize)
+        .await
+        .map_err(|e| FileOperationsError::Fsal(e.to_string()));
+    let _ = storage.close_file(&handle).await;
+    Ok((size, head?))
+}
+
 fn routed_path(volume: &crate::domain::volume::Volume, path: &str) -> String {
     match &volume.backend {
         crate::domain::volume::VolumeBackend::SeaweedFS { remote_path, .. } => {
@@ -611,7 +900,8 @@ fn guess_content_type(path: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use crate::domain::path_sanitizer::PathSanitizer;
+    use super::*;
+    use crate::infrastructure::storage::LocalHostStorageProvider;
 
     #[test]
     fn path_sanitizer_rejects_traversal() {
@@ -620,4 +910,38 @@ mod tests {
         assert!(s.canonicalize("/etc/passwd", Some("/workspace")).is_err());
         assert!(s.canonicalize("foo/../../bar", Some("/")).is_err());
     }
+
+    #[tokio::test]
+    async fn stream_file_reads_in_bounded_chunks() {
+        let dir = tempfile::tempdir().unwrap();
+        let content: Vec<u8> = (0..2_500u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(dir.path().join("artifact.bin"), &content).unwrap();
+        let storage = Arc::new(LocalHostStorageProvider::new(dir.path()).unwrap());
+
+        let file = stream_file(
+            storage,
+            "/artifact.bin".to_string(),
+            "application/octet-stream".to_string(),
+            1_000,
+        )
+        .await
+        .unwrap();
+        assert_eq!(file.size, 2_500);
+
+        let chunks: Vec<Bytes> = file.chunks.map(|c| c.unwrap()).collect().await;
+        let sizes: Vec<usize> = chunks.iter().map(Bytes::len).collect();
+        assert_eq!(sizes, vec![1_000, 1_000, 500]);
+        assert_eq!(chunks.concat(), content);
+    }
+
+    #[tokio::test]
+    async fn stream_file_maps_missing_file_to_not_found() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = Arc::new(LocalHostStorageProvider::new(dir.path()).unwrap());
+
+        let err = stream_file(storage, "/missing".to_string(), String::new(), 1_000)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, FileOperationsError::NotFound(_)), "{err:?}");
+    }
 }
@@ -0,0 +1,301 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Message Ingestion Service (BC-8)
+//!
+//! Starts an execution for every [`InboundMessage`] delivered by an
+//! ingestion adapter (see [`crate::infrastructure::ingestion`]) and sends the
+//! execution's final output back as the reply.
+//!
+//! For each message the service:
+//!
+//! 1. writes its attachments to a fresh ephemeral volume in the route's
+//!    tenant through the [`AttachmentStore`];
+//! 2. starts the route's agent with [`InboundMessage::to_execution_input`];
+//! 3. when the message has a [`ReplyTarget`] and the route a
+//!    [`ReplyEndpoint`], waits for the execution to finish and delivers the
+//!    reply through the [`OutboundWebhookDispatcher`], so replies are
+//!    signed, retried and appear in the delivery log like any other webhook.
+//!
+//! Pending replies live in memory; executions still running when the daemon
+//! stops are not answered.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Inbound email/chat message → execution → reply
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::application::attachment_store::{AttachmentStore, AttachmentUpload};
+use crate::application::execution::ExecutionService;
+use crate::domain::agent::AgentId;
+use crate::domain::execution::{AttachmentRef, ExecutionId, ExecutionStatus};
+use crate::domain::ingestion::{InboundMessage, ReplyTarget};
+use crate::domain::node_config::{IngestionConfig, IngestionTargetConfig};
+use crate::domain::outbound_webhook::RetryPolicy;
+use crate::domain::shared_kernel::TenantId;
+use crate::infrastructure::outbound_webhook::{OutboundWebhook, OutboundWebhookDispatcher};
+
+/// Owner recorded on attachment volumes.
+pub const INGESTION_VOLUME_OWNER: &str = "aegis-ingestion";
+
+#[derive(Debug, Error)]
+pub enum IngestionError {
+    #[error("invalid ingestion target: {0}")]
+    InvalidTarget(String),
+
+    #[error("failed to store attachments: {0}")]
+    Attachments(String),
+
+    #[error("failed to start execution: {0}")]
+    StartFailed(String),
+}
+
+/// HTTP endpoint replies are delivered to.
+#[derive(Debug, Clone)]
+pub struct ReplyEndpoint {
+    pub url: String,
+    pub headers: HashMap<String, String>,
+}
+
+/// Where messages from one source run and where their replies go.
+#[derive(Debug, Clone)]
+pub struct IngestionRoute {
+    pub tenant_id: TenantId,
+    pub agent_id: AgentId,
+    pub security_context: String,
+    /// `None` disables replies for the source.
+    pub reply: Option<ReplyEndpoint>,
+}
+
+impl IngestionRoute {
+    pub fn from_config(
+        target: &IngestionTargetConfig,
+        reply: Option<ReplyEndpoint>,
+    ) -> Result<Self, IngestionError> {
+        let tenant_id = TenantId::new(target.tenant_id.clone())
+            .map_err(|e| IngestionError::InvalidTarget(e.to_string()))?;
+        let agent_id = AgentId::from_string(&target.agent_id)
+            .map_err(|e| IngestionError::InvalidTarget(format!("agent_id: {e}")))?;
+        Ok(Self {
+            tenant_id,
+            agent_id,
+            security_context: target.security_context.clone(),
+            reply,
+        })
+    }
+}
+
+pub struct IngestionService {
+    execution_service: Arc<dyn ExecutionService>,
+    attachment_store: Arc<AttachmentStore>,
+    dispatcher: Arc<OutboundWebhookDispatcher>,
+    max_attachment_bytes: u64,
+    reply_timeout: Duration,
+}
+
+impl IngestionService {
+    pub fn new(
+        execution_service: Arc<dyn ExecutionService>,
+        attachment_store: Arc<AttachmentStore>,
+        dispatcher: Arc<OutboundWebhookDispatcher>,
+        config: &IngestionConfig,
+    ) -> Self {
+        Self {
+            execution_service,
+            attachment_store,
+            dispatcher,
+            max_attachment_bytes: config.max_attachment_bytes,
+            reply_timeout: Duration::from_secs(config.reply_timeout_seconds),
+        }
+    }
+
+    /// Start an execution for `message` on `route`. The reply, if any, is
+    /// sent in the background once the execution finishes.
+    pub async fn ingest(
+        &self,
+        route: &IngestionRoute,
+        message: InboundMessage,
+    ) -> Result<ExecutionId, IngestionError> {
+        let channel = message.channel.as_str();
+        let attachments = self.store_attachments(&route.tenant_id, &message).await?;
+        let input = message.to_execution_input(&route.tenant_id, attachments);
+        let execution_id = self
+            .execution_service
+            .start_execution(route.agent_id, input, route.security_context.clone(), None)
+            .await
+            .map_err(|e| IngestionError::StartFailed(e.to_string()))?;
+
+        metrics::counter!(
+            "aegis_ingestion_messages_total",
+            "channel" => channel,
+            "source" => message.source.clone()
+        )
+        .increment(1);
+        info!(
+            %execution_id,
+            channel,
+            source = %message.source,
+            external_id = %message.external_id,
+            "Started execution for inbound message"
+        );
+
+        if let (Some(target), Some(endpoint)) = (message.reply_to, route.reply.clone()) {
+            let execution_service = self.execution_service.clone();
+            let dispatcher = self.dispatcher.clone();
+            let tenant_id = route.tenant_id.clone();
+            let timeout = self.reply_timeout;
+            tokio::spawn(async move {
+                send_reply(
+                    execution_service,
+                    dispatcher,
+                    tenant_id,
+                    execution_id,
+                    timeout,
+                    target,
+                    endpoint,
+                )
+                .await;
+            });
+        }
+        Ok(execution_id)
+    }
+
+    async fn store_attachments(
+        &self,
+        tenant_id: &TenantId,
+        message: &InboundMessage,
+    ) -> Result<Vec<AttachmentRef>, IngestionError> {
+        let uploads: Vec<_> = message
+            .attachments
+            .iter()
+            .filter(|attachment| {
+                let fits = attachment.data.len() as u64 <= self.max_attachment_bytes;
+                if !fits {
+                    warn!(
+                        filename = %attachment.filename,
+                        size = attachment.data.len(),
+                        external_id = %message.external_id,
+                        "Dropping inbound attachment over the size limit"
+                    );
+                }
+                fits
+            })
+            .map(|attachment| AttachmentUpload {
+                filename: &attachment.filename,
+                declared_mime_type: attachment.declared_mime_type.as_deref(),
+                data: &attachment.data,
+            })
+            .collect();
+        self.attachment_store
+            .store(
+                tenant_id,
+                INGESTION_VOLUME_OWNER,
+                &format!("ingestion-{}", message.channel.as_str()),
+                &uploads,
+                self.max_attachment_bytes,
+            )
+            .await
+            .map_err(|e| IngestionError::Attachments(e.to_string()))
+    }
+}
+
+async fn send_reply(
+    execution_service: Arc<dyn ExecutionService>,
+    dispatcher: Arc<OutboundWebhookDispatcher>,
+    tenant_id: TenantId,
+    execution_id: ExecutionId,
+    timeout: Duration,
+    target: ReplyTarget,
+    endpoint: ReplyEndpoint,
+) {
+    let execution = match execution_service
+        .wait_for_terminal(&tenant_id, execution_id, timeout)
+        .await
+    {
+        Ok(execution) => execution,
+        Err(e) => {
+            warn!(%execution_id, error = %e, "Could not wait for ingested execution; no reply sent");
+            return;
+        }
+    };
+    let output = match execution.status {
+        ExecutionStatus::Completed => execution
+            .iterations
+            .last()
+            .and_then(|iteration| iteration.output.clone()),
+        _ => None,
+    };
+    let Some(text) = output.filter(|text| !text.trim().is_empty()) else {
+        warn!(
+            %execution_id,
+            status = ?execution.status,
+            "Ingested execution produced no output; no reply sent"
+        );
+        return;
+    };
+
+    let webhook = OutboundWebhook {
+        tenant_id,
+        source: "ingestion".to_string(),
+        url: endpoint.url,
+        method: "POST".to_string(),
+        headers: endpoint.headers,
+        content_type: "application/json".to_string(),
+        body: reply_body(&target, &text, execution_id).to_string(),
+        timeout: None,
+        retry: RetryPolicy::default(),
+    };
+    match dispatcher.deliver(&webhook).await {
+        // Slack's Web API answers 200 with `ok: false` on failure.
+        Ok(receipt)
+            if serde_json::from_str::<serde_json::Value>(&receipt.response_body)
+                .is_ok_and(|body| body["ok"] == false) =>
+        {
+            warn!(%execution_id, response = %receipt.response_body, "Reply rejected by recipient");
+        }
+        Ok(receipt) => {
+            info!(%execution_id, delivery_id = %receipt.delivery_id, "Reply delivered");
+        }
+        Err(e) => warn!(%execution_id, error = %e, "Reply delivery failed"),
+    }
+}
+
+/// Request body for a reply: a Slack `chat.postMessage` call, or the JSON
+/// document documented on `spec.ingestion.imap[].reply_url`.
+pub fn reply_body(
+    target: &ReplyTarget,
+    text: &str,
+    execution_id: ExecutionId,
+) -> serde_json::Value {
+    match target {
+        ReplyTarget::Email {
+            to,
+            subject,
+            in_reply_to,
+        } => {
+            let subject = if subject.to_ascii_lowercase().starts_with("re:") {
+                subject.clone()
+            } else {
+                format!("Re: {subject}")
+            };
+            serde_json::json!({
+                "to": to,
+                "subject": subject,
+                "in_reply_to": in_reply_to,
+                "text": text,
+                "execution_id": execution_id.to_string(),
+            })
+        }
+        ReplyTarget::Slack { channel, thread_ts } => serde_json::json!({
+            "channel": channel,
+            "thread_ts": thread_ts,
+            "text": text,
+        }),
+    }
+}
@@ -12,6 +12,8 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 
 use crate::application::execution::ExecutionService;
+use crate::application::feature_flags::{flags, FeatureFlagService};
+use crate::application::model_router::{ModelRouter, RoutingScope};
 use crate::application::tool_invocation_service::ToolInvocationService;
 use crate::domain::agent::AgentId;
 use crate::domain::dispatch::{
@@ -20,6 +22,7 @@ use crate::domain::dispatch::{
 use crate::domain::execution::{ExecutionId, TrajectoryStep};
 use crate::domain::iam::UserIdentity;
 use crate::domain::llm::{ChatMessage, GenerationOptions, ToolSchema};
+use crate::domain::model_routing::ClassificationRequest;
 use crate::domain::tenant::TenantId;
 use crate::infrastructure::llm::registry::{ApiKeySource, ProviderRegistry};
 
@@ -59,7 +62,10 @@ the most specific tool available.";
 
 #[derive(Debug, Clone)]
 pub enum LlmOutput {
-    FinalText(String),
+    FinalText {
+        text: String,
+        usage: crate::domain::llm::TokenUsage,
+    },
     ToolCalls(Vec<ToolCall>),
 }
 
@@ -93,6 +99,9 @@ pub struct InnerLoopService {
     rate_limit_enforcer: Option<Arc<dyn crate::domain::rate_limit::RateLimitEnforcer>>,
     /// Optional rate limit policy resolver (ADR-072).
     rate_limit_resolver: Option<Arc<dyn crate::domain::rate_limit::RateLimitPolicyResolver>>,
+    /// Optional per-request model routing (`spec.llm_selection.routing`).
+    model_router: Option<Arc<ModelRouter>>,
+    feature_flags: Option<Arc<FeatureFlagService>>,
 }
 
 impl InnerLoopService {
@@ -108,6 +117,8 @@ impl InnerLoopService {
             active_executions: RwLock::new(HashMap::new()),
             rate_limit_enforcer: None,
             rate_limit_resolver: None,
+            model_router: None,
+            feature_flags: None,
         }
     }
 
@@ -122,6 +133,19 @@ impl InnerLoopService {
         self
     }
 
+    /// Route LLM calls through `router` instead of always using the agent's
+    /// model alias.
+    pub fn with_model_router(mut self, router: Arc<ModelRouter>) -> Self {
+        self.model_router = Some(router);
+        self
+    }
+
+    /// Consult `flags` for per-tenant kill switches (`model_routing`).
+    pub fn with_feature_flags(mut self, flags: Arc<FeatureFlagService>) -> Self {
+        self.feature_flags = Some(flags);
+        self
+    }
+
     pub async fn handle_agent_message(
         &self,
         message: AgentMessage,
@@ -143,9 +167,14 @@ impl InnerLoopService {
                 execution_id,
                 iteration_number,
                 prompt,
+                encrypted_prompt,
                 messages,
                 model_alias,
             } => {
+                // The dispatch gateway decrypts with the execution's payload key.
+                if encrypted_prompt.is_some() {
+                    anyhow::bail!("Encrypted prompt reached the inner loop without decryption");
+                }
                 let parsed_agent_id = AgentId::from_string(&agent_id)?;
                 let mut conversation = messages.clone();
                 if conversation.is_empty() {
@@ -322,9 +351,13 @@ impl InnerLoopService {
                 })
                 .collect();
 
+            let model_alias = self
+                .route_model_alias(&ctx, execution_id_str, tool_schemas.len())
+                .await;
+
             let llm_output = self
                 .call_llm(
-                    &ctx.model_alias,
+                    &model_alias,
                     &ctx.conversation,
                     &tool_schemas,
                     ctx.user_identity.as_ref(),
@@ -333,7 +366,7 @@ impl InnerLoopService {
                 .await?;
 
             match llm_output {
-                LlmOutput::FinalText(text) => {
+                LlmOutput::FinalText { text, usage } => {
                     tracing::debug!(
                         execution_id = %execution_id_str,
                         iterations = ctx.iterations,
@@ -352,6 +385,8 @@ impl InnerLoopService {
                         tool_calls_executed: ctx.iterations as u32,
                         conversation: ctx.conversation.clone(),
                         trajectory: ctx.trajectory.clone(),
+                        input_tokens: usage.prompt_tokens,
+                        output_tokens: usage.completion_tokens,
                     };
 
                     let execution_id = ExecutionId(uuid::Uuid::parse_str(execution_id_str)?);
@@ -594,6 +629,47 @@ impl InnerLoopService {
         }
     }
 
+    /// Alias for the next LLM call: the agent's alias, unless the model router
+    /// reroutes it.
+    async fn route_model_alias(
+        &self,
+        ctx: &ExecutionContext,
+        execution_id_str: &str,
+        tool_count: usize,
+    ) -> String {
+        let (Some(router), Ok(execution_uuid)) =
+            (&self.model_router, uuid::Uuid::parse_str(execution_id_str))
+        else {
+            return ctx.model_alias.clone();
+        };
+        if self
+            .feature_flags
+            .as_ref()
+            .is_some_and(|f| !f.is_enabled(flags::MODEL_ROUTING, Some(&ctx.tenant_id)))
+        {
+            return ctx.model_alias.clone();
+        }
+        let task = ctx
+            .conversation
+            .iter()
+            .find(|m| m.role == "user")
+            .map(|m| m.content.as_str())
+            .unwrap_or_default();
+        let request = ClassificationRequest {
+            task,
+            conversation_chars: ctx.conversation.iter().map(|m| m.content.len()).sum(),
+            message_count: ctx.conversation.len(),
+            tool_count,
+            iteration_number: ctx.iteration_number,
+        };
+        let scope = RoutingScope {
+            execution_id: ExecutionId(execution_uuid),
+            agent_id: ctx.agent_id,
+            iteration_number: ctx.iteration_number,
+        };
+        router.route(&ctx.model_alias, &request, scope).await
+    }
+
     async fn call_llm(
         &self,
         model_alias: &str,
@@ -655,8 +731,10 @@ impl InnerLoopService {
 
         // BYOK exemption (ADR-072): users who bring their own API key consume their
         // own provider quota, so platform LlmCall/LlmToken rate limits are skipped.
-        let is_byok =
-            self.provider_registry.key_source_for_alias(model_alias) == ApiKeySource::User;
+        let is_byok = self
+            .provider_registry
+            .key_source_for_tenant_alias(tenant_id, model_alias)
+            == ApiKeySource::User;
 
         // Rate limit check: LlmCall (ADR-072)
         // When real UserIdentity is available, enforce per-user quotas.
@@ -749,7 +827,7 @@ impl InnerLoopService {
 
         let llm_result = self
             .provider_registry
-            .generate_chat(model_alias, &chat_messages, &schemas, &options)
+            .generate_chat_for_tenant(tenant_id, model_alias, &chat_messages, &schemas, &options)
             .await;
 
         let llm_elapsed_ms = llm_started_at.elapsed().as_millis() as u64;
@@ -854,7 +932,10 @@ impl InnerLoopService {
                     }
                 }
 
-                Ok(LlmOutput::FinalText(r.text))
+                Ok(LlmOutput::FinalText {
+                    text: r.text,
+                    usage: r.usage,
+                })
             }
             Ok(crate::domain::llm::ChatResponse::ToolCalls(calls)) => {
                 let tool_calls = calls
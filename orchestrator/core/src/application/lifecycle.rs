@@ -22,10 +22,15 @@
 //! - Publish lifecycle state changes explicitly instead of relying on implicit side effects.
 
 use crate::application::agent::AgentLifecycleService;
+use crate::application::feature_flags::{flags, FeatureFlagService};
 use crate::application::tenant_quota::TenantQuotaService;
 use crate::domain::agent::{Agent, AgentId, AgentManifest, AgentScope};
+use crate::domain::deploy_gate::{DeployGateReport, DeployGates, DeployTarget};
 use crate::domain::events::AgentLifecycleEvent;
 use crate::domain::iam::{IdentityKind, UserIdentity};
+use crate::domain::manifest_change::{
+    apply_merge_patch, diff_manifest_json, AgentManifestChange, ManifestChangeError, ManifestUpdate,
+};
 use crate::domain::repository::AgentRepository;
 use crate::domain::security_context::repository::SecurityContextRepository;
 use crate::domain::tenant::TenantId;
@@ -34,6 +39,7 @@ use anyhow::Result;
 use async_trait::async_trait;
 use chrono::Utc;
 use std::sync::Arc;
+use uuid::Uuid;
 
 pub struct StandardAgentLifecycleService {
     repository: Arc<dyn AgentRepository>,
@@ -41,6 +47,10 @@ pub struct StandardAgentLifecycleService {
     security_context_repo: Arc<dyn SecurityContextRepository>,
     /// Optional quota enforcement service (wired at composition root, ADR-056).
     quota_service: Option<Arc<TenantQuotaService>>,
+    /// Manifest checks run on deploy and update (`spec.deploy_gates`).
+    deploy_gates: DeployGates,
+    /// Decides which tenants must give a reason for manifest updates.
+    feature_flags: Option<Arc<FeatureFlagService>>,
 }
 
 impl StandardAgentLifecycleService {
@@ -54,6 +64,8 @@ impl StandardAgentLifecycleService {
             event_bus,
             security_context_repo,
             quota_service: None,
+            deploy_gates: DeployGates::default(),
+            feature_flags: None,
         }
     }
 
@@ -63,6 +75,145 @@ impl StandardAgentLifecycleService {
         self
     }
 
+    /// Wire the deploy gates run before a manifest is stored.
+    pub fn with_deploy_gates(mut self, deploy_gates: DeployGates) -> Self {
+        self.deploy_gates = deploy_gates;
+        self
+    }
+
+    /// Wire feature flags (`manifest_change_reason`).
+    pub fn with_feature_flags(mut self, feature_flags: Arc<FeatureFlagService>) -> Self {
+        self.feature_flags = Some(feature_flags);
+        self
+    }
+
+    /// Run the deploy gates without deploying. Deploys and updates run them
+    /// again and fail on any rejection.
+    pub fn check_deploy_gates(
+        &self,
+        tenant_id: &TenantId,
+        manifest: &AgentManifest,
+        caller_identity: Option<&UserIdentity>,
+    ) -> DeployGateReport {
+        self.deploy_gates.evaluate(
+            manifest,
+            &DeployTarget {
+                tenant_id,
+                caller: caller_identity,
+            },
+        )
+    }
+
+    fn enforce_deploy_gates(
+        &self,
+        tenant_id: &TenantId,
+        manifest: &AgentManifest,
+        caller_identity: Option<&UserIdentity>,
+    ) -> Result<()> {
+        let report = self.check_deploy_gates(tenant_id, manifest, caller_identity);
+        for warning in report.warnings() {
+            tracing::warn!(
+                agent = %manifest.metadata.name,
+                tenant_id = %tenant_id,
+                gate = %warning.gate,
+                "Deploy gate warning: {}",
+                warning.message
+            );
+        }
+        if report.is_rejected() {
+            anyhow::bail!(report.rejection_message());
+        }
+        Ok(())
+    }
+
+    /// Apply `update` to an agent's manifest and record the fields it
+    /// changed. Tenants with the `manifest_change_reason` flag must give a
+    /// `reason`.
+    pub async fn change_agent_manifest_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: AgentId,
+        update: ManifestUpdate,
+        reason: Option<String>,
+        caller_identity: Option<&UserIdentity>,
+    ) -> Result<AgentManifestChange> {
+        let reason = reason
+            .map(|reason| reason.trim().to_string())
+            .filter(|reason| !reason.is_empty());
+        let reason_required = self
+            .feature_flags
+            .as_ref()
+            .is_some_and(|f| f.is_enabled(flags::MANIFEST_CHANGE_REASON, Some(tenant_id)));
+        if reason_required && reason.is_none() {
+            return Err(ManifestChangeError::ReasonRequired.into());
+        }
+        self.apply_manifest_update(tenant_id, id, update, reason, caller_identity)
+            .await
+    }
+
+    /// An agent's manifest change history, newest first.
+    pub async fn list_manifest_changes_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: AgentId,
+    ) -> Result<Vec<AgentManifestChange>> {
+        self.repository
+            .list_manifest_changes_for_tenant(tenant_id, id)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to list manifest changes: {e}"))
+    }
+
+    async fn apply_manifest_update(
+        &self,
+        tenant_id: &TenantId,
+        id: AgentId,
+        update: ManifestUpdate,
+        reason: Option<String>,
+        caller_identity: Option<&UserIdentity>,
+    ) -> Result<AgentManifestChange> {
+        let mut agent = self.get_agent_for_tenant(tenant_id, id).await?;
+        let before = serde_json::to_value(&agent.manifest)?;
+        let manifest = match update {
+            ManifestUpdate::Replace(manifest) => *manifest,
+            ManifestUpdate::MergePatch(patch) => {
+                let mut patched = before.clone();
+                apply_merge_patch(&mut patched, &patch);
+                let manifest: AgentManifest = serde_json::from_value(patched)
+                    .map_err(|e| ManifestChangeError::InvalidManifest(e.to_string()))?;
+                manifest
+                    .validate()
+                    .map_err(ManifestChangeError::InvalidManifest)?;
+                manifest
+            }
+        };
+        self.enforce_deploy_gates(tenant_id, &manifest, caller_identity)?;
+
+        let change = AgentManifestChange {
+            id: Uuid::new_v4(),
+            agent_id: id,
+            old_version: agent.manifest.metadata.version.clone(),
+            new_version: manifest.metadata.version.clone(),
+            changes: diff_manifest_json(&before, &serde_json::to_value(&manifest)?),
+            reason,
+            changed_by: caller_identity.map(|identity| identity.sub.clone()),
+            changed_at: Utc::now(),
+        };
+        agent.update_manifest(manifest);
+        self.repository.save_for_tenant(tenant_id, &agent).await?;
+        self.repository
+            .record_manifest_change(tenant_id, &change)
+            .await?;
+        self.event_bus
+            .publish_agent_event(AgentLifecycleEvent::AgentUpdated {
+                agent_id: id,
+                tenant_id: tenant_id.clone(),
+                old_version: change.old_version.clone(),
+                new_version: change.new_version.clone(),
+                updated_at: change.changed_at,
+            });
+        Ok(change)
+    }
+
     /// Cross-tenant agent list (ADR-097). Operator-only — callers MUST gate
     /// on [`crate::domain::iam::IdentityKind::Operator`]. Each returned
     /// [`Agent`] carries its own `tenant_id`; surface it in projections.
@@ -97,6 +248,7 @@ impl AgentLifecycleService for StandardAgentLifecycleService {
     ) -> Result<AgentId> {
         // Validate manifest before deploying
         manifest.validate().map_err(|e| anyhow::anyhow!(e))?;
+        self.enforce_deploy_gates(tenant_id, &manifest, caller_identity)?;
 
         // Quota check: ensure the tenant has not exceeded its max_agents limit (ADR-056).
         if let Some(quota_svc) = &self.quota_service {
@@ -235,19 +387,14 @@ impl AgentLifecycleService for StandardAgentLifecycleService {
         id: AgentId,
         manifest: AgentManifest,
     ) -> Result<()> {
-        let mut agent = self.get_agent_for_tenant(tenant_id, id).await?;
-        let old_version = agent.manifest.metadata.version.clone();
-        let new_version = manifest.metadata.version.clone();
-        agent.update_manifest(manifest);
-        self.repository.save_for_tenant(tenant_id, &agent).await?;
-        self.event_bus
-            .publish_agent_event(AgentLifecycleEvent::AgentUpdated {
-                agent_id: id,
-                tenant_id: tenant_id.clone(),
-                old_version,
-                new_version,
-                updated_at: Utc::now(),
-            });
+        self.apply_manifest_update(
+            tenant_id,
+            id,
+            ManifestUpdate::Replace(Box::new(manifest)),
+            None,
+            None,
+        )
+        .await?;
         Ok(())
     }
 
@@ -370,6 +517,11 @@ mod tests {
                     version: Some("3.11".to_string()),
                     image: None,
                     image_pull_policy: ImagePullPolicy::IfNotPresent,
+                    base_image: None,
+                    packages: Vec::new(),
+                    env: Vec::new(),
+                    sidecars: Vec::new(),
+                    platform: None,
                     isolation: "inherit".to_string(),
                     model: "default".to_string(),
                     temperature: None,
@@ -390,6 +542,8 @@ mod tests {
                 input_schema: None,
                 security_context: None,
                 output_handler: None,
+                concurrency: None,
+                healthcheck: None,
             },
         }
     }
@@ -529,4 +683,65 @@ mod tests {
             other => panic!("expected AgentRemoved, got {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn manifest_patch_records_changes_and_requires_reason_when_flagged() {
+        let alice =
+            TenantId::for_consumer_user("alice").expect("valid per-user tenant id for alice");
+        let flags = FeatureFlagService::new(
+            [(
+                flags::MANIFEST_CHANGE_REASON.to_string(),
+                crate::domain::node_config::FeatureFlagConfig {
+                    enabled: false,
+                    tenants: [(alice.as_str().to_string(), true)].into(),
+                    description: None,
+                },
+            )]
+            .into(),
+        );
+        let (svc, _bus) = make_service();
+        let svc = svc.with_feature_flags(Arc::new(flags));
+        let id = svc
+            .deploy_agent_for_tenant(
+                &alice,
+                manifest("alpha", "1.0.0"),
+                false,
+                AgentScope::Tenant,
+                None,
+            )
+            .await
+            .expect("deploy ok");
+        let patch = ManifestUpdate::MergePatch(serde_json::json!({
+            "metadata": {"version": "1.1.0", "description": "patched"}
+        }));
+
+        let err = svc
+            .change_agent_manifest_for_tenant(&alice, id, patch.clone(), Some("  ".into()), None)
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ManifestChangeError>(),
+            Some(ManifestChangeError::ReasonRequired)
+        ));
+
+        let change = svc
+            .change_agent_manifest_for_tenant(&alice, id, patch, Some("release".into()), None)
+            .await
+            .expect("patch ok");
+        let paths: Vec<_> = change.changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, ["metadata.description", "metadata.version"]);
+        let agent = svc.get_agent_for_tenant(&alice, id).await.unwrap();
+        assert_eq!(agent.manifest.metadata.version, "1.1.0");
+        assert_eq!(
+            agent.manifest.spec.runtime.language.as_deref(),
+            Some("python")
+        );
+
+        let history = svc
+            .list_manifest_changes_for_tenant(&alice, id)
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].reason.as_deref(), Some("release"));
+    }
 }
@@ -0,0 +1,473 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Lock Service
+//!
+//! Tenant-scoped exclusive locks on named resources, shared by swarm resource
+//! locks (BC-6) and manifest concurrency groups
+//! ([`crate::application::concurrency_group`]).
+//!
+//! Callers namespace their resource names (`swarm-resource/…`,
+//! `concurrency-group/…`) so the two users never contend with each other.
+//! Locks may carry a TTL; expired locks are reclaimed lazily on the next
+//! acquisition of the same resource or by [`LockService::purge_expired`].
+//!
+//! Contended acquisitions can join a FIFO wait queue via
+//! [`LockService::acquire_or_enqueue`]. On release the lock is handed directly
+//! to the next live waiter, so a queued caller never races a newcomer.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Implements internal responsibilities for lock service
+
+use crate::domain::execution::ExecutionId;
+use crate::domain::shared_kernel::AgentId;
+use crate::domain::tenant::TenantId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::sync::oneshot;
+use uuid::Uuid;
+
+/// Opaque handle identifying one granted lock.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LockToken(pub String);
+
+/// The execution a lock is held on behalf of.
+///
+/// `agent_id` is set for agent executions and `None` for workflow executions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LockHolder {
+    pub execution_id: ExecutionId,
+    pub agent_id: Option<AgentId>,
+}
+
+impl LockHolder {
+    pub fn agent(execution_id: ExecutionId, agent_id: AgentId) -> Self {
+        Self {
+            execution_id,
+            agent_id: Some(agent_id),
+        }
+    }
+
+    pub fn workflow(execution_id: ExecutionId) -> Self {
+        Self {
+            execution_id,
+            agent_id: None,
+        }
+    }
+}
+
+/// Snapshot of a granted lock.
+#[derive(Debug, Clone)]
+pub struct HeldLock {
+    pub token: LockToken,
+    pub tenant_id: TenantId,
+    pub resource: String,
+    pub holder: LockHolder,
+    pub acquired_at: DateTime<Utc>,
+    /// `None` for locks held until explicitly released.
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum LockError {
+    #[error("resource lock already held: {resource}")]
+    Held {
+        resource: String,
+        holder: LockHolder,
+    },
+    #[error("unknown lock token")]
+    UnknownToken,
+    #[error("lock wait abandoned: {resource}")]
+    WaitAbandoned { resource: String },
+}
+
+/// Outcome of [`LockService::acquire_or_enqueue`].
+pub enum LockAcquisition {
+    Acquired(HeldLock),
+    Queued {
+        /// Holder of the lock at the time of queueing.
+        current: LockHolder,
+        waiter: LockWaiter,
+    },
+}
+
+/// A queued acquisition. Dropping the waiter leaves the queue.
+pub struct LockWaiter {
+    resource: String,
+    receiver: oneshot::Receiver<HeldLock>,
+}
+
+impl LockWaiter {
+    /// Wait until the lock is handed to this waiter.
+    ///
+    /// Fails with [`LockError::WaitAbandoned`] when the waiter is removed from
+    /// the queue, e.g. by [`LockService::release_execution`] for its holder.
+    pub async fn acquired(self) -> Result<HeldLock, LockError> {
+        self.receiver.await.map_err(|_| LockError::WaitAbandoned {
+            resource: self.resource,
+        })
+    }
+}
+
+#[async_trait]
+pub trait LockService: Send + Sync {
+    /// Acquire `resource` for `holder`, failing with [`LockError::Held`] if
+    /// another holder has it.
+    async fn try_acquire(
+        &self,
+        tenant_id: &TenantId,
+        resource: &str,
+        holder: LockHolder,
+        ttl: Option<Duration>,
+    ) -> Result<HeldLock, LockError>;
+
+    /// Acquire `resource` now, or join its FIFO wait queue.
+    async fn acquire_or_enqueue(
+        &self,
+        tenant_id: &TenantId,
+        resource: &str,
+        holder: LockHolder,
+        ttl: Option<Duration>,
+    ) -> LockAcquisition;
+
+    /// Release a lock by token and hand it to the next waiter, if any.
+    async fn release(&self, tenant_id: &TenantId, token: &LockToken) -> Result<(), LockError>;
+
+    /// Release every lock held by `execution_id` and drop its queued waiters.
+    /// Returns the number of locks released.
+    async fn release_execution(&self, execution_id: ExecutionId) -> usize;
+
+    /// All live locks owned by `tenant_id`.
+    async fn locks_for_tenant(&self, tenant_id: &TenantId) -> Vec<HeldLock>;
+
+    /// Reclaim every expired lock, returning the reclaimed locks.
+    async fn purge_expired(&self) -> Vec<HeldLock>;
+}
+
+type LockKey = (TenantId, String);
+
+struct QueuedWaiter {
+    holder: LockHolder,
+    ttl: Option<Duration>,
+    sender: oneshot::Sender<HeldLock>,
+}
+
+#[derive(Default)]
+struct LockState {
+    locks: HashMap<LockKey, HeldLock>,
+    tokens: HashMap<LockToken, LockKey>,
+    waiters: HashMap<LockKey, VecDeque<QueuedWaiter>>,
+}
+
+impl LockState {
+    fn grant(&mut self, key: &LockKey, holder: LockHolder, ttl: Option<Duration>) -> HeldLock {
+        let now = Utc::now();
+        let lock = HeldLock {
+            token: LockToken(Uuid::new_v4().to_string()),
+            tenant_id: key.0.clone(),
+            resource: key.1.clone(),
+            holder,
+            acquired_at: now,
+            expires_at: ttl.map(|ttl| {
+                now + chrono::Duration::from_std(ttl).unwrap_or(chrono::Duration::minutes(5))
+            }),
+        };
+        self.tokens.insert(lock.token.clone(), key.clone());
+        self.locks.insert(key.clone(), lock.clone());
+        lock
+    }
+
+    fn remove(&mut self, key: &LockKey) -> Option<HeldLock> {
+        let lock = self.locks.remove(key)?;
+        self.tokens.remove(&lock.token);
+        Some(lock)
+    }
+
+    /// Hand a free resource to the first waiter whose receiver is still alive.
+    fn grant_next(&mut self, key: &LockKey) {
+        while let Some(waiter) = self.waiters.get_mut(key).and_then(VecDeque::pop_front) {
+            let lock = self.grant(key, waiter.holder, waiter.ttl);
+            if waiter.sender.send(lock).is_ok() {
+                break;
+            }
+            self.remove(key);
+        }
+        if self.waiters.get(key).is_some_and(VecDeque::is_empty) {
+            self.waiters.remove(key);
+        }
+    }
+
+    fn expire_if_due(&mut self, key: &LockKey, now: DateTime<Utc>) -> Option<HeldLock> {
+        let expired = self
+            .locks
+            .get(key)
+            .and_then(|lock| lock.expires_at)
+            .is_some_and(|expires_at| expires_at <= now);
+        if !expired {
+            return None;
+        }
+        let lock = self.remove(key);
+        self.grant_next(key);
+        lock
+    }
+}
+
+/// Single-node in-memory [`LockService`].
+///
+/// Locks do not survive a daemon restart; every waiter and holder is lost with
+/// the process, matching the lifetime of the executions that hold them.
+#[derive(Default)]
+pub struct InMemoryLockService {
+    state: Mutex<LockState>,
+}
+
+impl InMemoryLockService {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn key(tenant_id: &TenantId, resource: &str) -> LockKey {
+        (tenant_id.clone(), resource.to_string())
+    }
+}
+
+#[async_trait]
+impl LockService for InMemoryLockService {
+    async fn try_acquire(
+        &self,
+        tenant_id: &TenantId,
+        resource: &str,
+        holder: LockHolder,
+        ttl: Option<Duration>,
+    ) -> Result<HeldLock, LockError> {
+        let key = Self::key(tenant_id, resource);
+        let mut state = self.state.lock().expect("lock service state poisoned");
+        state.expire_if_due(&key, Utc::now());
+        if let Some(current) = state.locks.get(&key) {
+            return Err(LockError::Held {
+                resource: resource.to_string(),
+                holder: current.holder,
+            });
+        }
+        Ok(state.grant(&key, holder, ttl))
+    }
+
+    async fn acquire_or_enqueue(
+        &self,
+        tenant_id: &TenantId,
+        resource: &str,
+        holder: LockHolder,
+        ttl: Option<Duration>,
+    ) -> LockAcquisition {
+        let key = Self::key(tenant_id, resource);
+        let mut state = self.state.lock().expect("lock service state poisoned");
+        state.expire_if_due(&key, Utc::now());
+        let Some(current) = state.locks.get(&key).map(|lock| lock.holder) else {
+            return LockAcquisition::Acquired(state.grant(&key, holder, ttl));
+        };
+        let (sender, receiver) = oneshot::channel();
+        state
+            .waiters
+            .entry(key)
+            .or_default()
+            .push_back(QueuedWaiter {
+                holder,
+                ttl,
+                sender,
+            });
+        LockAcquisition::Queued {
+            current,
+            waiter: LockWaiter {
+                resource: resource.to_string(),
+                receiver,
+            },
+        }
+    }
+
+    async fn release(&self, tenant_id: &TenantId, token: &LockToken) -> Result<(), LockError> {
+        let mut state = self.state.lock().expect("lock service state poisoned");
+        // A token issued to tenant-A cannot be released by tenant-B even if
+        // tenant-B learned the token string.
+        let key = match state.tokens.get(token) {
+            Some(key) if &key.0 == tenant_id => key.clone(),
+            _ => return Err(LockError::UnknownToken),
+        };
+        state.remove(&key);
+        state.grant_next(&key);
+        Ok(())
+    }
+
+    async fn release_execution(&self, execution_id: ExecutionId) -> usize {
+        let mut state = self.state.lock().expect("lock service state poisoned");
+        for queue in state.waiters.values_mut() {
+            queue.retain(|waiter| waiter.holder.execution_id != execution_id);
+        }
+        state.waiters.retain(|_, queue| !queue.is_empty());
+
+        let held: Vec<LockKey> = state
+            .locks
+            .iter()
+            .filter(|(_, lock)| lock.holder.execution_id == execution_id)
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in &held {
+            state.remove(key);
+            state.grant_next(key);
+        }
+        held.len()
+    }
+
+    async fn locks_for_tenant(&self, tenant_id: &TenantId) -> Vec<HeldLock> {
+        let now = Utc::now();
+        let state = self.state.lock().expect("lock service state poisoned");
+        state
+            .locks
+            .values()
+            .filter(|lock| &lock.tenant_id == tenant_id)
+            .filter(|lock| lock.expires_at.is_none_or(|expires_at| expires_at > now))
+            .cloned()
+            .collect()
+    }
+
+    async fn purge_expired(&self) -> Vec<HeldLock> {
+        let now = Utc::now();
+        let mut state = self.state.lock().expect("lock service state poisoned");
+        let keys: Vec<LockKey> = state.locks.keys().cloned().collect();
+        keys.iter()
+            .filter_map(|key| state.expire_if_due(key, now))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tenant() -> TenantId {
+        TenantId::new("tenant-a").unwrap()
+    }
+
+    fn holder() -> LockHolder {
+        LockHolder::workflow(ExecutionId::new())
+    }
+
+    #[tokio::test]
+    async fn try_acquire_contends_and_release_frees() {
+        let locks = InMemoryLockService::new();
+        let first = holder();
+        let lock = locks
+            .try_acquire(&tenant(), "res", first, None)
+            .await
+            .unwrap();
+
+        match locks.try_acquire(&tenant(), "res", holder(), None).await {
+            Err(LockError::Held { holder, .. }) => assert_eq!(holder, first),
+            other => panic!("expected contention, got {other:?}"),
+        }
+
+        // Same resource name in another tenant is independent.
+        let other_tenant = TenantId::new("tenant-b").unwrap();
+        assert!(locks
+            .try_acquire(&other_tenant, "res", holder(), None)
+            .await
+            .is_ok());
+
+        assert!(matches!(
+            locks.release(&other_tenant, &lock.token).await,
+            Err(LockError::UnknownToken)
+        ));
+        locks.release(&tenant(), &lock.token).await.unwrap();
+        assert!(locks
+            .try_acquire(&tenant(), "res", holder(), None)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn release_hands_lock_to_waiters_in_fifo_order() {
+        let locks = InMemoryLockService::new();
+        let first = locks
+            .try_acquire(&tenant(), "res", holder(), None)
+            .await
+            .unwrap();
+
+        let (second, third) = (holder(), holder());
+        let LockAcquisition::Queued { waiter: w2, .. } = locks
+            .acquire_or_enqueue(&tenant(), "res", second, None)
+            .await
+        else {
+            panic!("expected queued");
+        };
+        let LockAcquisition::Queued { waiter: w3, .. } = locks
+            .acquire_or_enqueue(&tenant(), "res", third, None)
+            .await
+        else {
+            panic!("expected queued");
+        };
+
+        locks.release(&tenant(), &first.token).await.unwrap();
+        let granted = w2.acquired().await.unwrap();
+        assert_eq!(granted.holder, second);
+
+        assert_eq!(locks.release_execution(second.execution_id).await, 1);
+        assert_eq!(w3.acquired().await.unwrap().holder, third);
+    }
+
+    #[tokio::test]
+    async fn dropped_waiters_are_skipped_and_released_executions_leave_the_queue() {
+        let locks = InMemoryLockService::new();
+        let first = locks
+            .try_acquire(&tenant(), "res", holder(), None)
+            .await
+            .unwrap();
+
+        let abandoned = holder();
+        let LockAcquisition::Queued {
+            waiter: dropped, ..
+        } = locks
+            .acquire_or_enqueue(&tenant(), "res", abandoned, None)
+            .await
+        else {
+            panic!("expected queued");
+        };
+        drop(dropped);
+
+        let removed = holder();
+        let LockAcquisition::Queued {
+            waiter: removed_waiter,
+            ..
+        } = locks
+            .acquire_or_enqueue(&tenant(), "res", removed, None)
+            .await
+        else {
+            panic!("expected queued");
+        };
+        locks.release_execution(removed.execution_id).await;
+        assert!(matches!(
+            removed_waiter.acquired().await,
+            Err(LockError::WaitAbandoned { .. })
+        ));
+
+        locks.release(&tenant(), &first.token).await.unwrap();
+        assert!(locks.locks_for_tenant(&tenant()).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn expired_locks_are_reclaimed() {
+        let locks = InMemoryLockService::new();
+        locks
+            .try_acquire(&tenant(), "res", holder(), Some(Duration::ZERO))
+            .await
+            .unwrap();
+        assert!(locks.locks_for_tenant(&tenant()).await.is_empty());
+        assert_eq!(locks.purge_expired().await.len(), 1);
+        assert!(locks
+            .try_acquire(&tenant(), "res", holder(), None)
+            .await
+            .is_ok());
+    }
+}
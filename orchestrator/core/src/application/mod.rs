@@ -13,8 +13,21 @@
 //! |---|---|---|
 //! | [`agent`] | BC-1 Agent Lifecycle | `AgentLifecycleService` trait |
 //! | [`lifecycle`] | BC-1 Agent Lifecycle | `StandardAgentLifecycleService` implementation |
+//! | [`agent_healthcheck`] | BC-1 Agent Lifecycle | `AgentHealthCheckService` — runs `spec.healthcheck` smoke tests (`agent deploy --verify`) |
+//! | [`agent_shell`] | BC-2 Execution | `AgentShell` — runs a manifest in-process for `aegis agent shell`; records sessions as replayable fixtures |
 //! | [`execution`] | BC-2 Execution | `ExecutionService` trait, `StandardExecutionService` impl |
+//! | [`execution_completion`] | BC-2 Execution | `ExecutionCompletionWatcher` — wakes completion waiters on terminal events |
+//! | [`execution_queue`] | BC-2 Execution | `ExecutionQueueWorker` — claims executions from the shared `TaskQueue` and starts them on this daemon |
+//! | [`attachment_store`] | BC-2 Execution | `AttachmentStore` — writes dispatch-time file uploads to an attachment volume and returns `AttachmentRef`s |
+//! | [`bulk_execution`] | BC-2 Execution | `BulkExecutionService` — cancel or requeue every execution matching a filter, in background batches |
+//! | [`execution_explain`] | BC-2 Execution | Merges an execution's persisted events into one annotated timeline for triage |
+//! | [`lock_service`] | Cross-cutting | `LockService` — tenant-scoped resource locks with FIFO wait queues (swarm locks, concurrency groups) |
+//! | [`concurrency_group`] | BC-2/BC-3 Execution & Workflow | `ConcurrencyGroupService` — enforces manifest `spec.concurrency` groups |
+//! | [`runtime_env`] | BC-2 Execution | `RuntimeEnvRenderer` — renders `spec.runtime.env` templates and `secretRef`s |
+//! | [`feature_flags`] | Cross-cutting | `FeatureFlagService` — static and runtime per-tenant feature flags |
+//! | [`output_moderation`] | BC-2 Execution | `OutputModerationService` — `spec.moderation` checks before output handlers deliver, human override for flagged outputs |
 //! | [`policy`] | BC-4 Security Policy | Policy validation use-cases |
+//! | [`policy_simulation`] | BC-12 SEAL | `PolicySimulationService` — dry-run a tool call against a security context and rate limits |
 //! | [`attestation_service`] | BC-12 SEAL | Orchestrates SEAL attestation flow (ADR-035) |
 //! | [`credential_service`] | BC-11 Secrets & Identity | `CredentialManagementService` — user credential binding lifecycle (ADR-078) |
 //! | [`tool_catalog`] | BC-14 SEAL Tooling Gateway | `StandardToolCatalog` — enriched tool discovery with source/category/tag classification |
@@ -23,13 +36,17 @@
 //! | [`register_workflow`] | BC-3 Workflow | `RegisterWorkflowUseCase` — parse + persist workflow manifests |
 //! | [`start_workflow_execution`] | BC-3 Workflow | `StartWorkflowExecutionUseCase` — submit workflow to Temporal |
 //! | [`complete_workflow_execution`] | BC-3 Workflow | `CompleteWorkflowExecutionUseCase` — handle Temporal completion |
+//! | [`workflow_watchdog`] | BC-3 Workflow | `WorkflowWatchdog` — time-in-state tracking, `WorkflowStateStuck` events, auto-fail/retry of stuck states |
 //! | [`temporal_mapper`] | BC-3 Workflow | Maps AEGIS workflow types to/from Temporal gRPC proto types |
 //! | [`volume_manager`] | BC-7 Storage Gateway | `VolumeService` trait, volume lifecycle management |
 //! | [`nfs_gateway`] | BC-7 Storage Gateway | `NfsGatewayService` — manages the user-space NFS server lifecycle (ADR-036) |
 //! | [`storage_event_persister`] | BC-7 Storage Gateway | Subscribes to `StorageEvent`s and persists them for audit trail |
+//! | [`execution_file_activity`] | BC-7 Storage Gateway | Groups an execution's `StorageEvent`s by iteration for the execution report |
 //! | [`inner_loop_service`] | BC-2 Execution | Inner loop gateway: LLM ↔ tool call cycle (ADR-038) |
+//! | [`model_router`] | BC-2 Execution | `ModelRouter` — routes inner-loop LLM calls to model aliases by task classification |
 //! | [`repository_factory`] | Cross-cutting | Builds concrete repository implementations from config |
 //! | [`stimulus`] | BC-8 Stimulus-Response | `StimulusService` — hybrid routing pipeline, webhook ingestion (ADR-021) |
+//! | [`ingestion`] | BC-8 Stimulus-Response | `IngestionService` — email/chat messages start executions, replies via outbound webhooks |
 //!
 //! ## Removed Modules
 //!
@@ -38,11 +55,16 @@
 //! drives all FSM execution; Rust interacts with it exclusively via gRPC (`TemporalClient`).
 
 pub mod agent;
+pub mod agent_healthcheck;
 pub mod agent_scope;
+pub mod agent_shell;
+pub mod attachment_store;
 pub mod attestation_service;
 pub mod billing_service;
+pub mod bulk_execution;
 pub mod canvas_service;
 pub mod cluster;
+pub mod concurrency_group;
 pub mod correlated_activity_stream;
 pub mod credential_service;
 pub mod discovery_service;
@@ -50,6 +72,7 @@ pub mod edge;
 pub mod effective_tier_service;
 pub mod execution;
 pub mod lifecycle;
+pub mod lock_service;
 pub mod schema_registry;
 pub mod scope_requester;
 pub mod tool_catalog;
@@ -58,20 +81,30 @@ pub mod tools;
 pub mod validation_service;
 
 pub mod output_handler_service;
+pub mod output_moderation;
 pub mod policy;
+pub mod policy_simulation;
 pub mod ports;
 // pub mod workflow_engine; Removed during Temporal integration
 pub mod complete_workflow_execution;
+pub mod execution_completion;
 pub mod execution_event_persister;
+pub mod execution_explain;
+pub mod execution_file_activity;
+pub mod execution_queue;
+pub mod feature_flags;
 pub mod file_operations_service;
 pub mod git_clone_executor;
 pub mod git_repo_service;
 pub mod git_ssh_key;
+pub mod ingestion;
 pub mod inner_loop_service;
+pub mod model_router;
 pub mod nfs_gateway;
 pub mod register_workflow;
 pub mod repository_factory;
 pub mod run_container_step;
+pub mod runtime_env;
 pub mod script_service;
 pub mod start_workflow_execution;
 pub mod stimulus;
@@ -84,7 +117,9 @@ pub mod tenant_provisioning;
 pub mod tenant_quota;
 pub mod user_volume_service;
 pub mod volume_manager;
+pub mod volume_search_service;
 pub mod workflow_scope;
+pub mod workflow_watchdog;
 
 // Re-export use cases for convenience
 pub use complete_workflow_execution::{
@@ -0,0 +1,114 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Model Router
+//!
+//! Routing layer in front of [`ProviderRegistry`]: classifies each inner-loop
+//! generation request with a [`TaskClassifier`] and maps the labels to a model
+//! alias through the `spec.llm_selection.routing` rules
+//! ([`ModelRoutingConfig`]).
+//!
+//! Only requests for an alias listed in `applies_to` are rerouted; an agent
+//! pinned to another alias always gets it. Every routed request publishes
+//! [`ExecutionEvent::ModelRouted`] for audit, including requests where no rule
+//! matched.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Per-request model alias selection by task classification
+
+use crate::domain::agent::AgentId;
+use crate::domain::events::ExecutionEvent;
+use crate::domain::execution::ExecutionId;
+use crate::domain::model_routing::{ClassificationRequest, TaskClassifier};
+use crate::domain::node_config::ModelRoutingConfig;
+use crate::infrastructure::event_bus::EventBus;
+use crate::infrastructure::llm::registry::ProviderRegistry;
+use chrono::Utc;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Execution the routed request belongs to.
+#[derive(Debug, Clone, Copy)]
+pub struct RoutingScope {
+    pub execution_id: ExecutionId,
+    pub agent_id: AgentId,
+    pub iteration_number: u8,
+}
+
+/// Chooses the model alias for inner-loop LLM calls.
+pub struct ModelRouter {
+    config: ModelRoutingConfig,
+    classifier: Arc<dyn TaskClassifier>,
+    available_aliases: HashSet<String>,
+    event_bus: Arc<EventBus>,
+}
+
+impl ModelRouter {
+    /// Create a router. Rules targeting an alias the registry could not build
+    /// are skipped at routing time (the requested alias is kept).
+    pub fn new(
+        config: ModelRoutingConfig,
+        classifier: Arc<dyn TaskClassifier>,
+        registry: &ProviderRegistry,
+        event_bus: Arc<EventBus>,
+    ) -> Self {
+        Self {
+            config,
+            classifier,
+            available_aliases: registry.available_aliases().into_iter().collect(),
+            event_bus,
+        }
+    }
+
+    /// Alias to call for a request that asked for `requested_alias`.
+    pub async fn route(
+        &self,
+        requested_alias: &str,
+        request: &ClassificationRequest<'_>,
+        scope: RoutingScope,
+    ) -> String {
+        if !self.config.applies_to_alias(requested_alias) {
+            return requested_alias.to_string();
+        }
+
+        let classification = self.classifier.classify(request).await;
+        let rule = self.config.select_rule(&classification).filter(|rule| {
+            let available = self.available_aliases.contains(&rule.model);
+            if !available {
+                warn!(
+                    rule = %rule.name,
+                    model_alias = %rule.model,
+                    "Routing rule targets an unavailable model alias; keeping requested alias"
+                );
+            }
+            available
+        });
+        let routed_alias = rule
+            .map_or(requested_alias, |r| r.model.as_str())
+            .to_string();
+
+        info!(
+            execution_id = %scope.execution_id,
+            requested_alias = %requested_alias,
+            routed_alias = %routed_alias,
+            complexity = ?classification.complexity,
+            domain = ?classification.domain,
+            rule = rule.map(|r| r.name.as_str()).unwrap_or("none"),
+            "model routing decision"
+        );
+        self.event_bus
+            .publish_execution_event(ExecutionEvent::ModelRouted {
+                execution_id: scope.execution_id,
+                agent_id: scope.agent_id,
+                iteration_number: scope.iteration_number,
+                requested_alias: requested_alias.to_string(),
+                routed_alias: routed_alias.clone(),
+                classification,
+                rule: rule.map(|r| r.name.clone()),
+                timestamp: Utc::now(),
+            });
+        routed_alias
+    }
+}
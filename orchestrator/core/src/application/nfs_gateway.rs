@@ -10,6 +10,10 @@
 //! - Routes by export path `/{tenant_id}/{volume_id}`
 //! - Always-on service (starts with orchestrator)
 //! - Uses AegisFSAL for authorization and audit
+//! - Restarted by the daemon's watchdog when the server task stops; file
+//!   handles do not survive a restart, so every restart is published as a
+//!   [`StorageTransportInterruption`] for the supervisor to fail iterations
+//!   running on the now-stale mounts
 //!
 //! # Architecture
 //!
@@ -25,6 +29,7 @@ use crate::domain::{
     },
     repository::VolumeRepository,
     storage::StorageProvider,
+    supervisor::{IterationTracker, StorageTransportInterruption, StorageTransportMonitor},
     volume::{Volume, VolumeId},
 };
 use crate::infrastructure::nfs::server::{NfsServer, NfsServerError, NfsVolumeContext};
@@ -34,9 +39,16 @@ use parking_lot::{Mutex, RwLock};
 use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 use thiserror::Error;
+use tokio::sync::watch;
 use tracing::debug;
 
+/// Bind attempts made by [`NfsGatewayService::restart_server`]; the aborted
+/// listener can hold the port for a moment.
+const RESTART_BIND_ATTEMPTS: u32 = 5;
+const RESTART_BIND_BACKOFF: Duration = Duration::from_millis(500);
+
 /// NFS Gateway service errors
 #[derive(Debug, Error)]
 pub enum NfsGatewayError {
@@ -76,17 +88,21 @@ pub struct VolumeRegistration {
 
 /// Volume context registry for NFS export path routing
 ///
-/// Maps VolumeId to execution context (execution_id, UID/GID, policy).
+/// Maps VolumeId to execution context (execution_id, UID/GID, policy), and
+/// tracks the supervisor iteration each execution is on so FSAL storage
+/// events can be attributed to it.
 /// Thread-safe with RwLock for concurrent access from NFS operations.
 #[derive(Clone)]
 pub struct NfsVolumeRegistry {
     contexts: Arc<RwLock<HashMap<VolumeId, NfsVolumeContext>>>,
+    iterations: Arc<RwLock<HashMap<ExecutionId, u8>>>,
 }
 
 impl NfsVolumeRegistry {
     pub fn new() -> Self {
         Self {
             contexts: Arc::new(RwLock::new(HashMap::new())),
+            iterations: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 
@@ -213,6 +229,20 @@ impl VolumeContextLookup for NfsVolumeRegistry {
         self.lookup(volume_id)
             .and_then(|ctx| ctx.workflow_execution_id)
     }
+
+    fn lookup_iteration_number(&self, execution_id: ExecutionId) -> Option<u8> {
+        self.iterations.read().get(&execution_id).copied()
+    }
+}
+
+impl IterationTracker for NfsVolumeRegistry {
+    fn iteration_started(&self, execution_id: ExecutionId, iteration: u8) {
+        self.iterations.write().insert(execution_id, iteration);
+    }
+
+    fn execution_finished(&self, execution_id: ExecutionId) {
+        self.iterations.write().remove(&execution_id);
+    }
 }
 
 /// NFS Gateway application service
@@ -231,6 +261,9 @@ pub struct NfsGatewayService {
 
     /// Whether server is running (wrapped in Mutex for interior mutability)
     is_running: Arc<Mutex<bool>>,
+
+    /// Latest restart, published to running iterations.
+    interruptions: watch::Sender<Option<StorageTransportInterruption>>,
 }
 
 impl NfsGatewayService {
@@ -284,6 +317,7 @@ impl NfsGatewayService {
             volume_registry,
             borrowed_volumes,
             is_running: Arc::new(Mutex::new(false)),
+            interruptions: watch::channel(None).0,
         }
     }
 
@@ -377,6 +411,60 @@ impl NfsGatewayService {
         Ok(())
     }
 
+    /// Restart the server and publish a [`StorageTransportInterruption`].
+    ///
+    /// The restarted server hands out new file handles, so mounts made
+    /// before the restart are stale and the iterations using them are
+    /// failed by the supervisor. Returns the new restart generation.
+    ///
+    /// # Errors
+    /// - `BindFailed` if the port cannot be bound again
+    pub async fn restart_server(&self, reason: &str) -> Result<u64, NfsGatewayError> {
+        tracing::warn!(reason, "Restarting NFS server gateway");
+        self.nfs_server.stop().await?;
+        *self.is_running.lock() = false;
+
+        let mut attempt = 1;
+        loop {
+            match self.nfs_server.start().await {
+                Ok(()) => break,
+                Err(e) if attempt < RESTART_BIND_ATTEMPTS => {
+                    debug!(attempt, error = %e, "NFS server restart failed; retrying");
+                    attempt += 1;
+                    tokio::time::sleep(RESTART_BIND_BACKOFF).await;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+        *self.is_running.lock() = true;
+
+        let generation = self
+            .interruptions
+            .borrow()
+            .as_ref()
+            .map_or(1, |previous| previous.generation + 1);
+        self.interruptions
+            .send_replace(Some(StorageTransportInterruption {
+                transport: "nfs".to_string(),
+                generation,
+                reason: reason.to_string(),
+            }));
+        metrics::counter!("aegis_nfs_gateway_restarts_total").increment(1);
+        tracing::info!(generation, "NFS server gateway restarted");
+
+        Ok(generation)
+    }
+
+    /// Restart the server if its task has stopped on its own. Returns the
+    /// new restart generation when a restart happened; a server stopped via
+    /// [`Self::stop_server`] is left alone.
+    pub async fn ensure_running(&self) -> Result<Option<u64>, NfsGatewayError> {
+        match self.health_check().await {
+            Ok(()) | Err(NfsGatewayError::NotRunning) => Ok(None),
+            Err(e) => self.restart_server(&e.to_string()).await.map(Some),
+        }
+    }
+
     /// Get the FSAL instance (for testing/introspection)
     pub fn fsal(&self) -> &Arc<AegisFSAL> {
         self.nfs_server.fsal()
@@ -410,6 +498,12 @@ impl NfsGatewayService {
     }
 }
 
+impl StorageTransportMonitor for NfsGatewayService {
+    fn subscribe(&self) -> watch::Receiver<Option<StorageTransportInterruption>> {
+        self.interruptions.subscribe()
+    }
+}
+
 /// Event publisher adapter for EventBus
 ///
 /// Adapts the domain EventPublisher trait to the infrastructure EventBus.
@@ -447,4 +541,20 @@ mod tests {
         let service_name = "nfs_gateway_creation";
         assert_eq!(service_name, "nfs_gateway_creation");
     }
+
+    #[test]
+    fn registry_tracks_current_iteration_per_execution() {
+        use super::*;
+
+        let registry = NfsVolumeRegistry::new();
+        let execution_id = ExecutionId::new();
+        assert_eq!(registry.lookup_iteration_number(execution_id), None);
+
+        registry.iteration_started(execution_id, 1);
+        registry.iteration_started(execution_id, 2);
+        assert_eq!(registry.lookup_iteration_number(execution_id), Some(2));
+
+        registry.execution_finished(execution_id);
+        assert_eq!(registry.lookup_iteration_number(execution_id), None);
+    }
 }
@@ -11,7 +11,7 @@
 //! | Variant | Status |
 //! |---------|--------|
 //! | `Agent` | Implemented — spawns child execution and polls to completion |
-//! | `Webhook` | Implemented — HTTP POST via `reqwest` |
+//! | `Webhook` | Implemented — signed, retried delivery via [`OutboundWebhookDispatcher`] |
 //! | `Container` | ADR-103 phase 2 — returns [`OutputHandlerError::NotYetImplemented`] |
 //! | `McpTool` | ADR-103 phase 2 — returns [`OutputHandlerError::NotYetImplemented`] |
 //!
@@ -28,12 +28,16 @@
 
 use crate::application::agent::AgentLifecycleService;
 use crate::application::execution::ExecutionService;
+use crate::application::output_moderation::OutputModerationService;
 use crate::domain::events::ExecutionEvent;
 use crate::domain::execution::{ExecutionId, ExecutionInput, ExecutionStatus};
+use crate::domain::outbound_webhook::RetryPolicy;
 use crate::domain::output_handler::OutputHandlerConfig;
 use crate::domain::shared_kernel::TenantId;
+use crate::infrastructure::outbound_webhook::{OutboundWebhook, OutboundWebhookDispatcher};
 use anyhow::anyhow;
 use async_trait::async_trait;
+use chrono::Utc;
 use std::sync::Arc;
 use std::time::Duration;
 use thiserror::Error;
@@ -60,6 +64,12 @@ pub enum OutputHandlerError {
     /// `INTERNAL` / HTTP `500`.
     #[error("output handler failed: {0}")]
     Failed(String),
+
+    /// `spec.moderation` withheld the output, so the handler never ran.
+    /// Reported like [`OutputHandlerError::Failed`] at the daemon boundary;
+    /// the `OutputModerated` event carries the findings.
+    #[error("output withheld by moderation: {0}")]
+    Withheld(String),
 }
 
 impl From<anyhow::Error> for OutputHandlerError {
@@ -102,6 +112,8 @@ pub struct StandardOutputHandlerService {
     execution_service: Arc<dyn ExecutionService>,
     agent_lifecycle_service: Arc<dyn AgentLifecycleService>,
     event_bus: Arc<crate::infrastructure::event_bus::EventBus>,
+    webhook_dispatcher: Arc<OutboundWebhookDispatcher>,
+    moderation: Option<Arc<OutputModerationService>>,
 }
 
 impl StandardOutputHandlerService {
@@ -114,8 +126,23 @@ impl StandardOutputHandlerService {
             execution_service,
             agent_lifecycle_service,
             event_bus,
+            webhook_dispatcher: Arc::new(OutboundWebhookDispatcher::unlogged()),
+            moderation: None,
         }
     }
+
+    /// Deliver `Webhook` handlers through `dispatcher`, which carries the
+    /// daemon's signing secrets and delivery-attempt log.
+    pub fn with_webhook_dispatcher(mut self, dispatcher: Arc<OutboundWebhookDispatcher>) -> Self {
+        self.webhook_dispatcher = dispatcher;
+        self
+    }
+
+    /// Check every output with `spec.moderation` before it is delivered.
+    pub fn with_moderation(mut self, moderation: Arc<OutputModerationService>) -> Self {
+        self.moderation = Some(moderation);
+        self
+    }
 }
 
 #[async_trait]
@@ -142,6 +169,34 @@ impl OutputHandlerService for StandardOutputHandlerService {
                 handler_type: handler_type.to_string(),
             });
 
+        if let Some(moderation) = &self.moderation {
+            let review = moderation
+                .review(tenant_id, correlation_id, handler_type, final_output)
+                .await;
+            if let Some(action) = review.verdict.action() {
+                self.event_bus
+                    .publish_execution_event(ExecutionEvent::OutputModerated {
+                        execution_id: correlation_id,
+                        handler_type: handler_type.to_string(),
+                        action,
+                        findings: review.verdict.findings.clone(),
+                        delivered: review.delivered,
+                        reviewed_by: review.reviewed_by.clone(),
+                        moderated_at: Utc::now(),
+                    });
+            }
+            if !review.delivered {
+                let error = OutputHandlerError::Withheld(review.verdict.summary());
+                self.event_bus
+                    .publish_execution_event(ExecutionEvent::OutputHandlerFailed {
+                        execution_id: correlation_id,
+                        handler_type: handler_type.to_string(),
+                        error: error.to_string(),
+                    });
+                return Err(error);
+            }
+        }
+
         let result: Result<Option<String>, OutputHandlerError> = match config {
             OutputHandlerConfig::Agent {
                 agent_id,
@@ -182,15 +237,20 @@ impl OutputHandlerService for StandardOutputHandlerService {
                 headers,
                 body_template,
                 timeout_seconds,
-                retry: _,
+                retry,
                 ..
             } => invoke_webhook_handler(
+                &self.webhook_dispatcher,
+                tenant_id,
                 url,
                 method,
                 headers,
                 body_template.as_deref(),
                 final_output,
                 *timeout_seconds,
+                retry
+                    .as_ref()
+                    .map_or_else(RetryPolicy::none, RetryPolicy::from_config),
             )
             .await
             .map_err(OutputHandlerError::from),
@@ -301,46 +361,39 @@ async fn invoke_agent_handler(
             .await?
     };
 
-    // Poll for completion with configurable timeout (default: 300 seconds).
+    // Wait for completion with configurable timeout (default: 300 seconds).
     let timeout_secs = timeout_seconds.unwrap_or(300);
-    let poll_interval_ms = 500u64;
-    let max_attempts = (timeout_secs * 1000) / poll_interval_ms;
-
-    for _ in 0..max_attempts {
-        let exec = execution_service
-            .get_execution_for_tenant(tenant_id, child_exec_id)
-            .await?;
-        match exec.status {
-            ExecutionStatus::Completed => {
-                let output = exec.iterations().last().and_then(|it| it.output.clone());
-                return Ok(output);
-            }
-            ExecutionStatus::Failed | ExecutionStatus::Cancelled => {
-                return Err(anyhow!(
-                    "Output handler agent execution {} failed or was cancelled",
-                    child_exec_id
-                ));
-            }
-            _ => {
-                tokio::time::sleep(Duration::from_millis(poll_interval_ms)).await;
-            }
+    let exec = execution_service
+        .wait_for_terminal(tenant_id, child_exec_id, Duration::from_secs(timeout_secs))
+        .await?;
+    match exec.status {
+        ExecutionStatus::Completed => {
+            let output = exec.iterations().last().and_then(|it| it.output.clone());
+            Ok(output)
         }
+        ExecutionStatus::Failed | ExecutionStatus::Cancelled => Err(anyhow!(
+            "Output handler agent execution {} failed or was cancelled",
+            child_exec_id
+        )),
+        _ => Err(anyhow!(
+            "Output handler agent execution timed out after {} seconds",
+            timeout_secs
+        )),
     }
-
-    Err(anyhow!(
-        "Output handler agent execution timed out after {} seconds",
-        timeout_secs
-    ))
 }
 
 /// POST final output to a webhook URL using `reqwest`.
+#[allow(clippy::too_many_arguments)]
 async fn invoke_webhook_handler(
+    dispatcher: &OutboundWebhookDispatcher,
+    tenant_id: &TenantId,
     url: &str,
     method: &str,
     headers: &std::collections::HashMap<String, String>,
     body_template: Option<&str>,
     final_output: &str,
     timeout_seconds: Option<u64>,
+    retry: RetryPolicy,
 ) -> anyhow::Result<Option<String>> {
     // Render the body template if provided.
     let body = if let Some(template) = body_template {
@@ -357,49 +410,26 @@ async fn invoke_webhook_handler(
         final_output.to_string()
     };
 
-    let timeout = Duration::from_secs(timeout_seconds.unwrap_or(30));
-
-    let client = reqwest::Client::builder()
-        .timeout(timeout)
-        .build()
-        .map_err(|e| anyhow!("Failed to build HTTP client: {}", e))?;
-
-    let mut request_builder = match method.to_uppercase().as_str() {
-        "POST" => client.post(url),
-        "PUT" => client.put(url),
-        "PATCH" => client.patch(url),
-        other => {
-            return Err(anyhow!(
-                "Unsupported webhook HTTP method '{}': expected POST, PUT, or PATCH",
-                other
-            ));
-        }
+    let webhook = OutboundWebhook {
+        tenant_id: tenant_id.clone(),
+        source: "output_handler".to_string(),
+        url: url.to_string(),
+        method: method.to_string(),
+        headers: headers.clone(),
+        content_type: "text/plain".to_string(),
+        body,
+        timeout: Some(Duration::from_secs(timeout_seconds.unwrap_or(30))),
+        retry,
     };
-
-    for (key, value) in headers {
-        request_builder = request_builder.header(key.as_str(), value.as_str());
-    }
-
-    let response = request_builder
-        .header("Content-Type", "text/plain")
-        .body(body)
-        .send()
+    let receipt = dispatcher
+        .deliver(&webhook)
         .await
         .map_err(|e| anyhow!("Webhook delivery failed: {}", e))?;
 
-    if !response.status().is_success() {
-        return Err(anyhow!(
-            "Webhook returned non-success status: {}",
-            response.status()
-        ));
-    }
-
-    let response_body = response.text().await.unwrap_or_default();
-
-    Ok(if response_body.is_empty() {
+    Ok(if receipt.response_body.is_empty() {
         None
     } else {
-        Some(response_body)
+        Some(receipt.response_body)
     })
 }
 
@@ -448,6 +478,11 @@ mod tests {
                     version: Some("3.11".to_string()),
                     image: None,
                     image_pull_policy: ImagePullPolicy::IfNotPresent,
+                    base_image: None,
+                    packages: Vec::new(),
+                    env: Vec::new(),
+                    sidecars: Vec::new(),
+                    platform: None,
                     isolation: "inherit".to_string(),
                     model: "default".to_string(),
                     temperature: None,
@@ -468,6 +503,8 @@ mod tests {
                 input_schema: None,
                 security_context: None,
                 output_handler: None,
+                concurrency: None,
+                healthcheck: None,
             },
         };
         let now = Utc::now();
@@ -0,0 +1,252 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Output Moderation Service
+//!
+//! Runs the `spec.moderation` providers over a final output and decides
+//! whether its output handler may deliver it. Called by
+//! [`crate::application::output_handler_service::StandardOutputHandlerService`]
+//! before every handler invocation, so both agent executions and workflow
+//! states are covered.
+//!
+//! A `flag` verdict files a request with the [`HumanInputService`]; the
+//! output is delivered only if a human approves it before
+//! `approval_timeout_seconds`. Without an approval service attached, flagged
+//! outputs are withheld.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Pre-delivery moderation and the human override path
+
+use std::sync::Arc;
+
+use tracing::{info, warn};
+
+use crate::domain::execution::ExecutionId;
+use crate::domain::moderation::{
+    ModerationAction, ModerationFinding, ModerationProvider, ModerationVerdict,
+};
+use crate::domain::node_config::ModerationConfig;
+use crate::domain::tenant::TenantId;
+use crate::infrastructure::human_input_service::{HumanInputService, HumanInputStatus};
+
+/// Longest output excerpt shown to the approver.
+const APPROVAL_EXCERPT_CHARS: usize = 2000;
+
+/// Outcome of moderating one output.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ModerationReview {
+    pub verdict: ModerationVerdict,
+    pub delivered: bool,
+    /// Approver of a flagged output that was released.
+    pub reviewed_by: Option<String>,
+}
+
+pub struct OutputModerationService {
+    providers: Vec<Arc<dyn ModerationProvider>>,
+    on_provider_error: ModerationAction,
+    approval_timeout_seconds: u64,
+    approvals: Option<Arc<HumanInputService>>,
+}
+
+impl OutputModerationService {
+    pub fn new(providers: Vec<Arc<dyn ModerationProvider>>, config: &ModerationConfig) -> Self {
+        Self {
+            providers,
+            on_provider_error: config.on_provider_error,
+            approval_timeout_seconds: config.approval_timeout_seconds,
+            approvals: None,
+        }
+    }
+
+    /// Route flagged outputs to `/v1/human-approvals`.
+    pub fn with_approvals(mut self, approvals: Arc<HumanInputService>) -> Self {
+        self.approvals = Some(approvals);
+        self
+    }
+
+    /// Check `output` and, for a flagged output, wait for a human decision.
+    pub async fn review(
+        &self,
+        tenant_id: &TenantId,
+        execution_id: ExecutionId,
+        handler_type: &str,
+        output: &str,
+    ) -> ModerationReview {
+        let mut findings = Vec::new();
+        for provider in &self.providers {
+            match provider.check(output).await {
+                Ok(found) => findings.extend(found),
+                Err(e) => {
+                    warn!(
+                        provider = provider.name(),
+                        execution_id = %execution_id,
+                        error = %e,
+                        "Moderation provider failed"
+                    );
+                    findings.push(ModerationFinding {
+                        provider: provider.name().to_string(),
+                        category: "provider_error".to_string(),
+                        action: self.on_provider_error,
+                        score: None,
+                        detail: Some(e.to_string()),
+                    });
+                }
+            }
+        }
+        let verdict = ModerationVerdict::new(findings);
+
+        let (delivered, reviewed_by) = match verdict.action() {
+            None | Some(ModerationAction::Annotate) => (true, None),
+            Some(ModerationAction::Block) => (false, None),
+            Some(ModerationAction::Flag) => {
+                self.request_override(tenant_id, execution_id, handler_type, output, &verdict)
+                    .await
+            }
+        };
+        if let Some(action) = verdict.action() {
+            metrics::counter!(
+                "aegis_output_moderation_total",
+                "action" => action.as_str(),
+                "delivered" => if delivered { "true" } else { "false" }
+            )
+            .increment(1);
+        }
+
+        ModerationReview {
+            verdict,
+            delivered,
+            reviewed_by,
+        }
+    }
+
+    async fn request_override(
+        &self,
+        tenant_id: &TenantId,
+        execution_id: ExecutionId,
+        handler_type: &str,
+        output: &str,
+        verdict: &ModerationVerdict,
+    ) -> (bool, Option<String>) {
+        let Some(approvals) = &self.approvals else {
+            warn!(
+                execution_id = %execution_id,
+                "Output flagged by moderation but no approval service is attached; withholding"
+            );
+            return (false, None);
+        };
+
+        let mut excerpt: String = output.chars().take(APPROVAL_EXCERPT_CHARS).collect();
+        if excerpt.len() < output.len() {
+            excerpt.push_str("\n[...]");
+        }
+        let prompt = format!(
+            "Deliver moderated output of execution {execution_id} to its {handler_type} output handler?\n\n\
+             Findings: {}\n\nOutput:\n{excerpt}",
+            verdict.summary()
+        );
+
+        info!(
+            execution_id = %execution_id,
+            timeout_seconds = self.approval_timeout_seconds,
+            "Output flagged by moderation; waiting for approval"
+        );
+        match approvals
+            .request_input(
+                tenant_id.clone(),
+                execution_id,
+                prompt,
+                self.approval_timeout_seconds,
+            )
+            .await
+        {
+            Ok(HumanInputStatus::Approved { approved_by, .. }) => (
+                true,
+                Some(approved_by.unwrap_or_else(|| "anonymous".to_string())),
+            ),
+            Ok(_) | Err(_) => (false, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::moderation::PatternModerationProvider;
+
+    fn provider(
+        name: &str,
+        action: ModerationAction,
+        pattern: &str,
+    ) -> Arc<dyn ModerationProvider> {
+        Arc::new(
+            PatternModerationProvider::new(
+                name.to_string(),
+                action,
+                "compliance".to_string(),
+                &[pattern.to_string()],
+            )
+            .unwrap(),
+        )
+    }
+
+    fn config() -> ModerationConfig {
+        ModerationConfig {
+            providers: Vec::new(),
+            on_provider_error: ModerationAction::Flag,
+            approval_timeout_seconds: 30,
+        }
+    }
+
+    #[tokio::test]
+    async fn flagged_output_is_delivered_only_after_approval_and_blocks_are_final() {
+        let approvals = Arc::new(HumanInputService::new());
+        let service = OutputModerationService::new(
+            vec![
+                provider("notes", ModerationAction::Annotate, "(?i)draft"),
+                provider("secrets", ModerationAction::Flag, "(?i)confidential"),
+                provider("embargo", ModerationAction::Block, "(?i)embargoed"),
+            ],
+            &config(),
+        )
+        .with_approvals(approvals.clone());
+        let tenant = TenantId::consumer();
+
+        let annotated = service
+            .review(&tenant, ExecutionId::new(), "webhook", "Draft summary")
+            .await;
+        assert!(annotated.delivered);
+        assert_eq!(annotated.verdict.action(), Some(ModerationAction::Annotate));
+
+        let approver = approvals.clone();
+        tokio::spawn(async move {
+            loop {
+                if let Some(request) = approver.list_pending_requests().await.pop() {
+                    approver
+                        .submit_approval(request.id, None, Some("alice".to_string()))
+                        .await
+                        .unwrap();
+                    break;
+                }
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        });
+        let flagged = service
+            .review(&tenant, ExecutionId::new(), "webhook", "Confidential draft")
+            .await;
+        assert!(flagged.delivered);
+        assert_eq!(flagged.reviewed_by.as_deref(), Some("alice"));
+        assert_eq!(flagged.verdict.findings.len(), 2);
+
+        let blocked = service
+            .review(
+                &tenant,
+                ExecutionId::new(),
+                "webhook",
+                "Embargoed, confidential",
+            )
+            .await;
+        assert!(!blocked.delivered);
+        assert!(approvals.list_pending_requests().await.is_empty());
+    }
+}
@@ -0,0 +1,338 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Policy Simulation
+//!
+//! Answers "would this tool call be allowed?" for a named security context
+//! without executing anything: the capability trace from
+//! [`SecurityContext::trace`], the caller's current rate-limit headroom and
+//! the combined decision. Rate-limit counters are read, never incremented.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Backs `POST /v1/security-contexts/{name}/simulate`
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::domain::iam::{IdentityKind, UserIdentity, ZaruTier};
+use crate::domain::rate_limit::{
+    RateLimitBucket, RateLimitEnforcer, RateLimitPolicyResolver, RateLimitResourceType,
+    RateLimitScope,
+};
+use crate::domain::security_context::{
+    EvaluationContext, PolicyTrace, SecurityContext, SecurityContextRepository,
+};
+use crate::domain::tenant::TenantId;
+
+#[derive(Debug, thiserror::Error)]
+pub enum PolicySimulationError {
+    #[error("security context '{0}' not found")]
+    NotFound(String),
+    #[error("security context lookup failed: {0}")]
+    Repository(String),
+    #[error("rate limit lookup failed: {0}")]
+    RateLimit(String),
+}
+
+/// A hypothetical tool call.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SimulatedToolCall {
+    pub tool: String,
+    #[serde(default)]
+    pub args: Value,
+    /// Agent making the call; its rate-limit bucket is used when `user_id`
+    /// is not given.
+    #[serde(default)]
+    pub agent_id: Option<Uuid>,
+    /// User whose rate-limit bucket is checked. Defaults to the agent, then
+    /// to the caller.
+    #[serde(default)]
+    pub user_id: Option<String>,
+    /// Evaluation time for time-window conditions. Defaults to now.
+    #[serde(default)]
+    pub at: Option<DateTime<Utc>>,
+    /// Calls already granted in the execution, keyed by capability
+    /// `tool_pattern`, for invocation-limit conditions.
+    #[serde(default)]
+    pub prior_invocations: HashMap<String, u32>,
+}
+
+/// Remaining headroom in the caller's rate-limit buckets.
+#[derive(Debug, Clone, Serialize)]
+pub struct RateLimitState {
+    pub user_id: String,
+    pub remaining: HashMap<RateLimitBucket, u64>,
+    /// A bucket with nothing left; the call would be throttled.
+    pub exhausted_bucket: Option<RateLimitBucket>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PolicySimulation {
+    pub security_context: String,
+    pub tool: String,
+    pub evaluated_at: DateTime<Utc>,
+    pub trace: PolicyTrace,
+    /// `None` when rate limiting is not configured on this node.
+    pub rate_limit: Option<RateLimitState>,
+    /// The security context allows the call and no rate-limit bucket is
+    /// exhausted.
+    pub allowed: bool,
+}
+
+pub struct PolicySimulationService {
+    security_contexts: Arc<dyn SecurityContextRepository>,
+    rate_limit_enforcer: Option<Arc<dyn RateLimitEnforcer>>,
+    rate_limit_resolver: Option<Arc<dyn RateLimitPolicyResolver>>,
+}
+
+impl PolicySimulationService {
+    pub fn new(security_contexts: Arc<dyn SecurityContextRepository>) -> Self {
+        Self {
+            security_contexts,
+            rate_limit_enforcer: None,
+            rate_limit_resolver: None,
+        }
+    }
+
+    pub fn with_rate_limits(
+        mut self,
+        enforcer: Arc<dyn RateLimitEnforcer>,
+        resolver: Arc<dyn RateLimitPolicyResolver>,
+    ) -> Self {
+        self.rate_limit_enforcer = Some(enforcer);
+        self.rate_limit_resolver = Some(resolver);
+        self
+    }
+
+    pub async fn simulate(
+        &self,
+        tenant_id: &TenantId,
+        caller: Option<&UserIdentity>,
+        context_name: &str,
+        call: &SimulatedToolCall,
+    ) -> Result<PolicySimulation, PolicySimulationError> {
+        let context = self.load(context_name).await?;
+        let evaluated_at = call.at.unwrap_or_else(Utc::now);
+        let evaluation = EvaluationContext::at(evaluated_at)
+            .with_prior_invocations(call.prior_invocations.clone());
+        let trace = context.trace(&call.tool, &call.args, &evaluation);
+
+        let rate_limit = self.rate_limit_state(tenant_id, caller, call).await?;
+        let throttled = rate_limit
+            .as_ref()
+            .is_some_and(|state| state.exhausted_bucket.is_some());
+
+        Ok(PolicySimulation {
+            security_context: context.name,
+            tool: call.tool.clone(),
+            evaluated_at,
+            allowed: trace.allowed && !throttled,
+            trace,
+            rate_limit,
+        })
+    }
+
+    async fn load(&self, name: &str) -> Result<SecurityContext, PolicySimulationError> {
+        self.security_contexts
+            .find_by_name(name)
+            .await
+            .map_err(|e| PolicySimulationError::Repository(e.to_string()))?
+            .ok_or_else(|| PolicySimulationError::NotFound(name.to_string()))
+    }
+
+    /// Mirrors the SEAL middleware's bucket selection: the tool-call policy
+    /// for the user, scoped to the tenant.
+    async fn rate_limit_state(
+        &self,
+        tenant_id: &TenantId,
+        caller: Option<&UserIdentity>,
+        call: &SimulatedToolCall,
+    ) -> Result<Option<RateLimitState>, PolicySimulationError> {
+        let (Some(enforcer), Some(resolver)) =
+            (&self.rate_limit_enforcer, &self.rate_limit_resolver)
+        else {
+            return Ok(None);
+        };
+        let Some(user_id) = call
+            .user_id
+            .clone()
+            .or_else(|| call.agent_id.map(|id| id.to_string()))
+            .or_else(|| caller.map(|identity| identity.sub.clone()))
+        else {
+            return Ok(None);
+        };
+
+        // Simulating for someone else: resolve their policy as the
+        // middleware would for a session without a full identity.
+        let identity = match caller {
+            Some(identity) if identity.sub == user_id => identity.clone(),
+            _ => UserIdentity {
+                sub: user_id.clone(),
+                realm_slug: tenant_id.as_str().to_string(),
+                email: None,
+                name: None,
+                identity_kind: IdentityKind::ConsumerUser {
+                    zaru_tier: ZaruTier::Free,
+                    tenant_id: tenant_id.clone(),
+                },
+            },
+        };
+        let resource_type = RateLimitResourceType::SealToolCall {
+            tool_pattern: call.tool.clone(),
+        };
+        let policy = resolver
+            .resolve_policy(&identity, tenant_id, &resource_type)
+            .await
+            .map_err(|e| PolicySimulationError::RateLimit(e.to_string()))?;
+        let scope = RateLimitScope::User {
+            tenant_id: tenant_id.clone(),
+            user_id: user_id.clone(),
+        };
+        let remaining = enforcer
+            .remaining(&scope, &policy)
+            .await
+            .map_err(|e| PolicySimulationError::RateLimit(e.to_string()))?;
+        let exhausted_bucket = remaining
+            .iter()
+            .filter(|(_, left)| **left == 0)
+            .map(|(bucket, _)| *bucket)
+            .min_by_key(|bucket| bucket.window_seconds());
+
+        Ok(Some(RateLimitState {
+            user_id,
+            remaining,
+            exhausted_bucket,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::rate_limit::{RateLimitDecision, RateLimitError, RateLimitPolicy};
+    use crate::domain::security_context::{Capability, SecurityContextMetadata};
+    use crate::infrastructure::security_context::InMemorySecurityContextRepository;
+    use serde_json::json;
+
+    struct ExhaustedHourly;
+
+    #[async_trait::async_trait]
+    impl RateLimitEnforcer for ExhaustedHourly {
+        async fn check_and_increment(
+            &self,
+            _scope: &RateLimitScope,
+            _policy: &RateLimitPolicy,
+            _cost: u64,
+        ) -> Result<RateLimitDecision, RateLimitError> {
+            panic!("simulation must not increment counters")
+        }
+
+        async fn remaining(
+            &self,
+            _scope: &RateLimitScope,
+            _policy: &RateLimitPolicy,
+        ) -> Result<HashMap<RateLimitBucket, u64>, RateLimitError> {
+            Ok(HashMap::from([
+                (RateLimitBucket::PerMinute, 3),
+                (RateLimitBucket::Hourly, 0),
+            ]))
+        }
+    }
+
+    struct EmptyPolicy;
+
+    #[async_trait::async_trait]
+    impl RateLimitPolicyResolver for EmptyPolicy {
+        async fn resolve_policy(
+            &self,
+            _identity: &UserIdentity,
+            _tenant_id: &TenantId,
+            resource_type: &RateLimitResourceType,
+        ) -> Result<RateLimitPolicy, RateLimitError> {
+            Ok(RateLimitPolicy {
+                resource_type: resource_type.clone(),
+                windows: HashMap::new(),
+            })
+        }
+    }
+
+    async fn repository() -> Arc<dyn SecurityContextRepository> {
+        let repository = InMemorySecurityContextRepository::new();
+        let now = Utc::now();
+        repository
+            .save(SecurityContext {
+                name: "research".to_string(),
+                description: String::new(),
+                capabilities: vec![Capability {
+                    tool_pattern: "web.fetch".to_string(),
+                    path_allowlist: None,
+                    command_allowlist: None,
+                    subcommand_allowlist: None,
+                    domain_allowlist: Some(vec!["example.com".to_string()]),
+                    max_response_size: None,
+                    rate_limit: None,
+                    max_concurrent: None,
+                    conditions: None,
+                }],
+                deny_list: Vec::new(),
+                metadata: SecurityContextMetadata {
+                    created_at: now,
+                    updated_at: now,
+                    version: 1,
+                },
+            })
+            .await
+            .unwrap();
+        Arc::new(repository)
+    }
+
+    fn call(tool: &str) -> SimulatedToolCall {
+        SimulatedToolCall {
+            tool: tool.to_string(),
+            args: json!({"url": "https://example.com/a"}),
+            agent_id: Some(Uuid::new_v4()),
+            user_id: None,
+            at: None,
+            prior_invocations: HashMap::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn exhausted_bucket_overrides_allowed_capability() {
+        let tenant = TenantId::consumer();
+        let service = PolicySimulationService::new(repository().await);
+
+        let allowed = service
+            .simulate(&tenant, None, "research", &call("web.fetch"))
+            .await
+            .unwrap();
+        assert!(allowed.allowed);
+        assert_eq!(allowed.trace.matched_capability, Some(0));
+        assert!(allowed.rate_limit.is_none());
+
+        let service = service.with_rate_limits(Arc::new(ExhaustedHourly), Arc::new(EmptyPolicy));
+        let throttled = service
+            .simulate(&tenant, None, "research", &call("web.fetch"))
+            .await
+            .unwrap();
+        assert!(throttled.trace.allowed);
+        assert!(!throttled.allowed);
+        assert_eq!(
+            throttled.rate_limit.unwrap().exhausted_bucket,
+            Some(RateLimitBucket::Hourly)
+        );
+
+        assert!(matches!(
+            service
+                .simulate(&tenant, None, "missing", &call("web.fetch"))
+                .await,
+            Err(PolicySimulationError::NotFound(_))
+        ));
+    }
+}
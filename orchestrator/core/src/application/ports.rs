@@ -134,7 +134,8 @@ pub trait ContainerVerificationPort: Send + Sync {
     async fn verify_container_running(&self, container_id: &str) -> anyhow::Result<()>;
 }
 
-/// Port for workflow execution control operations (cancel, signal, remove).
+/// Port for workflow execution control operations (cancel, signal, remove,
+/// terminate, retry a state).
 ///
 /// Infrastructure adapters implement this to interact with the workflow engine
 /// (e.g. Temporal) for lifecycle management of running workflow executions.
@@ -165,6 +166,23 @@ pub trait WorkflowExecutionControlPort: Send + Sync {
         tenant_id: &crate::domain::tenant::TenantId,
         execution_id: crate::domain::execution::ExecutionId,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Stop the execution immediately; unlike cancel, the worker runs no
+    /// cleanup.
+    async fn terminate_workflow_execution(
+        &self,
+        tenant_id: &crate::domain::tenant::TenantId,
+        execution_id: crate::domain::execution::ExecutionId,
+        reason: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
+
+    /// Ask the worker to abandon and re-run the execution's current state.
+    async fn retry_workflow_state(
+        &self,
+        tenant_id: &crate::domain::tenant::TenantId,
+        execution_id: crate::domain::execution::ExecutionId,
+        state_name: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>>;
 }
 
 /// Port for retrieving agent-level activity log snapshots.
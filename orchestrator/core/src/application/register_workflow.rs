@@ -16,7 +16,8 @@
 //!
 //! 1. Accept YAML manifest as input
 //! 2. Parse via WorkflowParser → get Workflow domain aggregate
-//! 3. Validate via Workflow invariants (no gaps, all transitions valid)
+//! 3. Validate via Workflow invariants (no gaps, all transitions valid) and
+//!    compile its templates into the WorkflowTemplateEngine cache
 //! 4. Map via TemporalWorkflowMapper → TemporalWorkflowDefinition (JSON)
 //! 5. Register with Temporal via TemporalClient (HTTP POST to TypeScript worker)
 //! 6. Persist workflow to WorkflowRepository (PostgreSQL)
@@ -44,10 +45,11 @@ use crate::domain::tenant::TenantId;
 use crate::domain::workflow::WorkflowScope;
 use crate::infrastructure::event_bus::EventBus;
 use crate::infrastructure::workflow_parser::WorkflowParser;
+use crate::infrastructure::workflow_template_engine::WorkflowTemplateEngine;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use std::sync::Arc;
-use tracing::info;
+use tracing::{info, warn};
 
 /// Registered workflow response
 #[derive(Debug, Clone, serde::Serialize)]
@@ -57,6 +59,10 @@ pub struct RegisteredWorkflow {
     pub version: String,
     pub status: String,
     pub registered_at: chrono::DateTime<chrono::Utc>,
+    /// Non-fatal problems found during registration, such as templates that
+    /// reference undeclared blackboard variables.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
 }
 
 /// Register Workflow Use Case
@@ -110,6 +116,8 @@ pub struct StandardRegisterWorkflowUseCase {
     /// Agent lifecycle service used to resolve agent name references to UUIDs at
     /// workflow deploy time, ensuring execution-time failures are surfaced early.
     agent_service: Arc<dyn AgentLifecycleService>,
+    /// Holds each registered workflow version's compiled templates.
+    template_engine: Arc<WorkflowTemplateEngine>,
 }
 
 impl StandardRegisterWorkflowUseCase {
@@ -124,8 +132,15 @@ impl StandardRegisterWorkflowUseCase {
             workflow_engine,
             event_bus,
             agent_service,
+            template_engine: Arc::new(WorkflowTemplateEngine::new()),
         }
     }
+
+    /// Share a template engine with the components that render workflow templates.
+    pub fn with_template_engine(mut self, template_engine: Arc<WorkflowTemplateEngine>) -> Self {
+        self.template_engine = template_engine;
+        self
+    }
 }
 
 #[async_trait]
@@ -150,6 +165,10 @@ impl RegisterWorkflowUseCase for StandardRegisterWorkflowUseCase {
             }
         }
 
+        if let Some(concurrency) = &workflow.spec.concurrency {
+            concurrency.validate().map_err(|e| anyhow::anyhow!(e))?;
+        }
+
         // Validate per-state max_state_visits ceiling
         for (state_name, state) in &workflow.spec.states {
             if let Some(max_sv) = state.max_state_visits {
@@ -223,6 +242,36 @@ impl RegisterWorkflowUseCase for StandardRegisterWorkflowUseCase {
 
         let workflow_id = workflow.id.to_string();
 
+        // Step 1c: Compile every template once for this version. Syntax errors
+        // fail the deploy instead of the first execution that reaches the state.
+        self.template_engine
+            .register(&workflow)
+            .with_context(|| format!("Workflow '{workflow_name}' contains an invalid template"))?;
+
+        // Step 1d: Templates referencing undeclared blackboard variables still
+        // register, but fail when rendered — surface them to the deployer now.
+        let warnings: Vec<String> = WorkflowTemplateEngine::undeclared_references(&workflow)
+            .into_iter()
+            .map(|(template, key)| {
+                format!("Template '{template}' references undeclared blackboard variable '{key}'")
+            })
+            .collect();
+        for warning in &warnings {
+            warn!(workflow = %workflow_name, "{warning}");
+        }
+
+        // Step 1e: Handoffs may only be read by the states that consume them,
+        // so the wiring validated by the domain is the wiring templates use.
+        if let Some((template, reference)) =
+            WorkflowTemplateEngine::unconsumed_handoff_references(&workflow)
+                .into_iter()
+                .next()
+        {
+            anyhow::bail!(
+                "Workflow '{workflow_name}': template '{template}' references handoff '{reference}' that its state does not consume"
+            );
+        }
+
         // Step 2: Map to Temporal definition via anti-corruption layer
         let mut temporal_definition =
             crate::application::temporal_mapper::TemporalWorkflowMapper::to_temporal_definition(
@@ -297,6 +346,7 @@ impl RegisterWorkflowUseCase for StandardRegisterWorkflowUseCase {
             version: workflow_version,
             status: "registered".to_string(),
             registered_at: chrono::Utc::now(),
+            warnings,
         })
     }
 }
@@ -904,6 +954,83 @@ spec:
         );
     }
 
+    #[tokio::test]
+    async fn register_workflow_rejects_invalid_template_before_engine_registration() {
+        let engine = Arc::new(RecordingEngine::new());
+        let service = StandardRegisterWorkflowUseCase::new(
+            Arc::new(InMemoryWorkflowRepository::new()),
+            Arc::new(tokio::sync::RwLock::new(Some(
+                engine.clone() as Arc<dyn WorkflowEnginePort>
+            ))),
+            Arc::new(EventBus::new(8)),
+            test_agent_service(),
+        );
+        let yaml = VALID_WORKFLOW_YAML.replace("{{input}}", "{{#if input}}unclosed");
+
+        let err = service.register_workflow(&yaml, false).await.unwrap_err();
+        assert!(
+            format!("{err:#}").contains("START.input"),
+            "error should name the broken template, got: {err:#}"
+        );
+        assert!(engine.registered.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn register_workflow_compiles_templates_into_shared_engine() {
+        let repo = Arc::new(InMemoryWorkflowRepository::new());
+        let template_engine = Arc::new(WorkflowTemplateEngine::new());
+        let service = StandardRegisterWorkflowUseCase::new(
+            repo.clone(),
+            Arc::new(tokio::sync::RwLock::new(Some(
+                Arc::new(RecordingEngine::new()) as Arc<dyn WorkflowEnginePort>,
+            ))),
+            Arc::new(EventBus::new(8)),
+            test_agent_service(),
+        )
+        .with_template_engine(template_engine.clone());
+        let yaml = VALID_WORKFLOW_YAML.replace("{{input}}", "{{truncate input 3}}");
+
+        service.register_workflow(&yaml, false).await.unwrap();
+
+        let workflow = repo
+            .find_by_name_for_tenant(&TenantId::consumer(), "registration-test-workflow")
+            .await
+            .unwrap()
+            .unwrap();
+        let rendered = template_engine
+            .render(&workflow, "START.input", &json!({ "input": "abcdef" }))
+            .unwrap();
+        assert_eq!(rendered, "abc");
+    }
+
+    #[tokio::test]
+    async fn register_workflow_warns_about_undeclared_blackboard_references() {
+        let service = StandardRegisterWorkflowUseCase::new(
+            Arc::new(InMemoryWorkflowRepository::new()),
+            Arc::new(tokio::sync::RwLock::new(Some(
+                Arc::new(RecordingEngine::new()) as Arc<dyn WorkflowEnginePort>,
+            ))),
+            Arc::new(EventBus::new(8)),
+            test_agent_service(),
+        );
+        let yaml = VALID_WORKFLOW_YAML
+            .replace("{{input}}", "{{blackboard.ticket}} {{blackboard.tciket}}")
+            .replace(
+                "  initial_state: START\n",
+                "  initial_state: START\n  variables:\n    ticket:\n      type: string\n",
+            );
+
+        let registered = service.register_workflow(&yaml, false).await.unwrap();
+
+        assert_eq!(
+            registered.warnings,
+            vec![
+                "Template 'START.input' references undeclared blackboard variable 'tciket'"
+                    .to_string()
+            ]
+        );
+    }
+
     /// Workflow YAML that references an unknown judge; should fail before Temporal registration.
     const WORKFLOW_YAML_UNKNOWN_JUDGE: &str = r#"
 apiVersion: 100monkeys.ai/v1
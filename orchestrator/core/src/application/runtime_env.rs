@@ -0,0 +1,210 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Execution Environment Rendering
+//!
+//! Renders the `spec.runtime.env` entries ([`EnvVar`]) of an agent manifest for
+//! one execution, just before the container is spawned:
+//!
+//! - `value` entries are Handlebars templates over the execution input
+//!   (`{{input.KEY}}`, `{{intent}}`, `{{execution_id}}`). Rendering is strict, so
+//!   a reference to a missing input field fails the execution instead of
+//!   silently injecting an empty string. Nothing is HTML-escaped.
+//! - `secretRef` entries are read through the [`SecretsManager`] from the
+//!   owning tenant's namespace ([`SecretPath::for_tenant`]), so one tenant's
+//!   manifest cannot reference another tenant's secrets.
+//!
+//! Every resolved secret value is registered with the returned
+//! [`SecretMasker`], which the execution service applies to console output,
+//! iteration output and errors before they are published or persisted.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Per-execution env var templating and secret resolution
+
+use crate::domain::agent::{AgentId, EnvVar};
+use crate::domain::execution::{ExecutionId, ExecutionInput};
+use crate::domain::secrets::{AccessContext, SecretMasker, SecretPath};
+use crate::domain::shared_kernel::TenantId;
+use crate::infrastructure::secrets_manager::SecretsManager;
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Environment produced for one execution.
+#[derive(Debug, Default)]
+pub struct RenderedEnv {
+    /// Variables to merge into the container environment.
+    pub vars: HashMap<String, String>,
+    /// Masker holding every secret value placed in `vars`.
+    pub masker: SecretMasker,
+}
+
+/// Identity of the execution the environment is rendered for.
+pub struct EnvRenderScope<'a> {
+    pub execution_id: ExecutionId,
+    pub agent_id: AgentId,
+    pub tenant_id: &'a TenantId,
+    pub input: &'a ExecutionInput,
+}
+
+/// Renders `spec.runtime.env` for executions.
+pub struct RuntimeEnvRenderer {
+    secrets_manager: Option<Arc<SecretsManager>>,
+}
+
+impl RuntimeEnvRenderer {
+    /// Create a renderer. Without a secrets manager, `secretRef` entries fail.
+    pub fn new(secrets_manager: Option<Arc<SecretsManager>>) -> Self {
+        Self { secrets_manager }
+    }
+
+    /// Render `vars` for the execution described by `scope`.
+    pub async fn render(&self, vars: &[EnvVar], scope: &EnvRenderScope<'_>) -> Result<RenderedEnv> {
+        let mut rendered = RenderedEnv::default();
+        if vars.is_empty() {
+            return Ok(rendered);
+        }
+
+        let context = serde_json::json!({
+            "input": scope.input.input,
+            "intent": scope.input.intent,
+            "execution_id": scope.execution_id.0.to_string(),
+        });
+        let mut hb = handlebars::Handlebars::new();
+        hb.set_strict_mode(true);
+        hb.register_escape_fn(handlebars::no_escape);
+
+        for var in vars {
+            let value = match (&var.value, &var.secret_ref) {
+                (Some(template), _) if template.contains("{{") => hb
+                    .render_template(template, &context)
+                    .with_context(|| format!("failed to render spec.runtime.env '{}'", var.name))?,
+                (Some(literal), _) => literal.clone(),
+                (None, Some(secret_ref)) => {
+                    let (engine, path) = secret_ref.engine_and_path().ok_or_else(|| {
+                        anyhow!("spec.runtime.env '{}' has a malformed secretRef", var.name)
+                    })?;
+                    let secrets_manager = self.secrets_manager.as_ref().ok_or_else(|| {
+                        anyhow!(
+                            "spec.runtime.env '{}' uses secretRef but no secrets backend is configured",
+                            var.name
+                        )
+                    })?;
+                    let mount = SecretPath::for_tenant(scope.tenant_id.clone(), engine, path)
+                        .effective_mount();
+                    let ctx = AccessContext::for_execution(
+                        "orchestrator",
+                        scope.execution_id,
+                        scope.agent_id,
+                    );
+                    let secret = secrets_manager
+                        .read_secret_field(&mount, path, &secret_ref.key, &ctx)
+                        .await
+                        .map_err(|e| {
+                            anyhow!(
+                                "failed to resolve secretRef for spec.runtime.env '{}': {e}",
+                                var.name
+                            )
+                        })?;
+                    let value = secret.expose().to_string();
+                    rendered.masker.add(secret);
+                    value
+                }
+                (None, None) => {
+                    return Err(anyhow!(
+                        "spec.runtime.env '{}' must set either value or secretRef",
+                        var.name
+                    ))
+                }
+            };
+            rendered.vars.insert(var.name.clone(), value);
+        }
+        Ok(rendered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::agent::EnvSecretRef;
+    use crate::domain::secrets::SensitiveString;
+    use crate::infrastructure::event_bus::EventBus;
+    use crate::infrastructure::secrets_manager::TestSecretStore;
+
+    fn value(name: &str, value: &str) -> EnvVar {
+        EnvVar {
+            name: name.to_string(),
+            value: Some(value.to_string()),
+            secret_ref: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn renders_templates_and_masks_secrets() {
+        let manager = Arc::new(SecretsManager::from_store(
+            Arc::new(TestSecretStore::new()),
+            Arc::new(EventBus::new(16)),
+        ));
+        manager
+            .write_secret(
+                "tenant-acme/kv",
+                "github",
+                HashMap::from([("token".to_string(), SensitiveString::new("ghp_secret"))]),
+                &AccessContext::system("test"),
+            )
+            .await
+            .unwrap();
+        let renderer = RuntimeEnvRenderer::new(Some(manager));
+        let tenant = TenantId::from_realm_slug("acme").unwrap();
+        let input = ExecutionInput {
+            intent: Some("triage".to_string()),
+            input: serde_json::json!({ "repo": "acme/api" }),
+            workspace_volume_id: None,
+            workspace_volume_mount_path: None,
+            workspace_remote_path: None,
+            workflow_execution_id: None,
+            attachments: Vec::new(),
+        };
+        let scope = EnvRenderScope {
+            execution_id: ExecutionId::new(),
+            agent_id: AgentId::new(),
+            tenant_id: &tenant,
+            input: &input,
+        };
+        let secret = |path: &str| EnvVar {
+            name: "GITHUB_TOKEN".to_string(),
+            value: None,
+            secret_ref: Some(EnvSecretRef {
+                path: path.to_string(),
+                key: "token".to_string(),
+            }),
+        };
+        let vars = vec![
+            value("TARGET_REPO", "{{input.repo}}"),
+            value("MODE", "{{intent}}-<raw>"),
+            secret("kv/github"),
+        ];
+
+        let rendered = renderer.render(&vars, &scope).await.unwrap();
+        assert_eq!(rendered.vars["TARGET_REPO"], "acme/api");
+        assert_eq!(rendered.vars["MODE"], "triage-<raw>");
+        assert_eq!(rendered.vars["GITHUB_TOKEN"], "ghp_secret");
+        assert_eq!(rendered.masker.mask("echo ghp_secret"), "echo [REDACTED]");
+
+        // Strict rendering and tenant scoping both fail closed.
+        assert!(renderer
+            .render(&[value("X", "{{input.missing}}")], &scope)
+            .await
+            .is_err());
+        let other = TenantId::from_realm_slug("globex").unwrap();
+        let other_scope = EnvRenderScope {
+            tenant_id: &other,
+            ..scope
+        };
+        assert!(renderer
+            .render(&[secret("kv/github")], &other_scope)
+            .await
+            .is_err());
+    }
+}
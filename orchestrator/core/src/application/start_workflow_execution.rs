@@ -17,12 +17,16 @@
 //! - **Layer:** Application Layer
 //! - **Purpose:** Implements internal responsibilities for start workflow execution
 
+use crate::application::concurrency_group::{
+    AdmissionGuard, ConcurrencyAdmission, ConcurrencyGroupService,
+};
+use crate::application::lock_service::{LockHolder, LockWaiter};
 use crate::application::ports::WorkflowEnginePort;
-use crate::domain::execution::ExecutionId;
+use crate::domain::execution::{ExecutionId, ExecutionStatus};
 use crate::domain::iam::UserIdentity;
 use crate::domain::repository::{WorkflowExecutionRepository, WorkflowRepository};
 use crate::domain::tenant::TenantId;
-use crate::domain::workflow::{WorkflowExecution, WorkflowId};
+use crate::domain::workflow::{Workflow, WorkflowExecution, WorkflowId};
 use crate::infrastructure::event_bus::EventBus;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -137,6 +141,167 @@ pub struct StandardStartWorkflowExecutionUseCase {
     rate_limit_enforcer: Option<Arc<dyn crate::domain::rate_limit::RateLimitEnforcer>>,
     /// Optional rate limit policy resolver (ADR-072).
     rate_limit_resolver: Option<Arc<dyn crate::domain::rate_limit::RateLimitPolicyResolver>>,
+    /// Optional enforcement of manifest `spec.concurrency` groups.
+    concurrency_groups: Option<Arc<ConcurrencyGroupService>>,
+}
+
+/// Hands a persisted workflow execution to the workflow engine. Split out of
+/// the use case so executions queued behind a concurrency group can be
+/// launched from a background task once the group is free.
+#[derive(Clone)]
+struct WorkflowLauncher {
+    workflow_engine: Arc<tokio::sync::RwLock<Option<Arc<dyn WorkflowEnginePort>>>>,
+    execution_repository: Arc<dyn WorkflowExecutionRepository>,
+    event_bus: Arc<EventBus>,
+}
+
+impl WorkflowLauncher {
+    /// Start the execution in Temporal, persist the run linkage and publish
+    /// `WorkflowExecutionStarted`. Returns the Temporal run id.
+    async fn launch(
+        &self,
+        tenant_id: &TenantId,
+        workflow: &Workflow,
+        execution_id: ExecutionId,
+        request: &StartWorkflowExecutionRequest,
+        blackboard: Option<HashMap<String, serde_json::Value>>,
+    ) -> Result<String> {
+        // Step 5: Start execution in Temporal via gRPC
+        let engine = {
+            let lock = self.workflow_engine.read().await;
+            lock.clone()
+                .ok_or_else(|| anyhow::anyhow!("Workflow engine not connected yet"))?
+        };
+
+        let workflow_id = workflow.id.to_string();
+        let temporal_workflow_id = execution_id.0.to_string();
+
+        let temporal_run_id = engine
+            .start_workflow(crate::application::ports::StartWorkflowParams {
+                workflow_id: &workflow_id,
+                execution_id,
+                tenant_id: tenant_id.as_str(),
+                input: match &request.input {
+                    serde_json::Value::Object(map) => {
+                        map.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
+                    }
+                    _ => {
+                        // Wrap non-object inputs
+                        let mut map = HashMap::new();
+                        map.insert("input".to_string(), request.input.clone());
+                        map
+                    }
+                },
+                blackboard,
+                security_context_name: request.security_context_name.clone(),
+                intent: request.intent.clone(),
+            })
+            .await
+            .context("Failed to start workflow execution in Temporal")?;
+
+        self.execution_repository
+            .update_temporal_linkage_for_tenant(
+                tenant_id,
+                execution_id,
+                &temporal_workflow_id,
+                &temporal_run_id,
+            )
+            .await
+            .map_err(|error| {
+                error!(
+                    tenant_id = %tenant_id.as_str(),
+                    execution_id = %execution_id.0,
+                    workflow_id = %workflow.id.0,
+                    temporal_workflow_id = %temporal_workflow_id,
+                    temporal_run_id = %temporal_run_id,
+                    error = %error,
+                    "Failed to persist Temporal linkage for workflow execution"
+                );
+                anyhow::Error::new(error)
+            })
+            .context("Failed to persist Temporal linkage for workflow execution")?;
+
+        // Step 7: Publish domain event
+        self.event_bus.publish_workflow_event(
+            crate::domain::events::WorkflowEvent::WorkflowExecutionStarted {
+                execution_id,
+                workflow_id: workflow.id,
+                started_at: Utc::now(),
+            },
+        );
+
+        // Step 8: Record Prometheus metrics (ADR-058, BC-3)
+        metrics::counter!("aegis_workflow_executions_total", "status" => "started").increment(1);
+        metrics::gauge!("aegis_workflow_executions_active").increment(1.0);
+
+        Ok(temporal_run_id)
+    }
+
+    /// Wait for the concurrency group, then launch. Runs detached from the
+    /// request that queued the execution.
+    #[allow(clippy::too_many_arguments)]
+    async fn launch_when_admitted(
+        self,
+        waiter: LockWaiter,
+        guard: Option<AdmissionGuard>,
+        tenant_id: TenantId,
+        workflow: Workflow,
+        execution_id: ExecutionId,
+        request: StartWorkflowExecutionRequest,
+        blackboard: Option<HashMap<String, serde_json::Value>>,
+    ) {
+        if waiter.acquired().await.is_err() {
+            return;
+        }
+        // Skip executions cancelled or removed while they were queued.
+        let mut execution = match self
+            .execution_repository
+            .find_by_id_for_tenant(&tenant_id, execution_id)
+            .await
+        {
+            Ok(Some(execution)) if !execution.status.is_terminal() => execution,
+            _ => return,
+        };
+        execution.status = ExecutionStatus::Running;
+        let launched = match self
+            .execution_repository
+            .save_for_tenant(&tenant_id, &execution)
+            .await
+        {
+            Ok(()) => {
+                self.launch(&tenant_id, &workflow, execution_id, &request, blackboard)
+                    .await
+            }
+            Err(e) => Err(anyhow::Error::new(e)),
+        };
+        match launched {
+            Ok(_) => {
+                if let Some(guard) = guard {
+                    guard.disarm();
+                }
+            }
+            Err(e) => {
+                error!(
+                    tenant_id = %tenant_id.as_str(),
+                    execution_id = %execution_id.0,
+                    error = %e,
+                    "Failed to launch queued workflow execution"
+                );
+                execution.status = ExecutionStatus::Failed;
+                let _ = self
+                    .execution_repository
+                    .save_for_tenant(&tenant_id, &execution)
+                    .await;
+                self.event_bus.publish_workflow_event(
+                    crate::domain::events::WorkflowEvent::WorkflowExecutionFailed {
+                        execution_id,
+                        reason: format!("{e:#}"),
+                        failed_at: Utc::now(),
+                    },
+                );
+            }
+        }
+    }
 }
 
 impl StandardStartWorkflowExecutionUseCase {
@@ -162,6 +327,7 @@ impl StandardStartWorkflowExecutionUseCase {
             event_bus,
             rate_limit_enforcer: None,
             rate_limit_resolver: None,
+            concurrency_groups: None,
         }
     }
 
@@ -176,6 +342,20 @@ impl StandardStartWorkflowExecutionUseCase {
         self
     }
 
+    /// Enforce `spec.concurrency` groups declared by workflow manifests.
+    pub fn with_concurrency_groups(mut self, service: Arc<ConcurrencyGroupService>) -> Self {
+        self.concurrency_groups = Some(service);
+        self
+    }
+
+    fn launcher(&self) -> WorkflowLauncher {
+        WorkflowLauncher {
+            workflow_engine: self.workflow_engine.clone(),
+            execution_repository: self.execution_repository.clone(),
+            event_bus: self.event_bus.clone(),
+        }
+    }
+
     fn normalize_blackboard(
         blackboard: Option<serde_json::Value>,
     ) -> Result<Option<HashMap<String, serde_json::Value>>> {
@@ -369,12 +549,41 @@ impl StartWorkflowExecutionUseCase for StandardStartWorkflowExecutionUseCase {
         let mut workflow_execution =
             WorkflowExecution::new(&workflow, execution_id, request.input.clone());
 
-        // Step 3: Merge initial blackboard if provided
+        // Step 3: Merge initial blackboard if provided. Typed workflows check
+        // every seeded key against `spec.variables` and start with declared
+        // defaults for anything not seeded.
         if let Some(blackboard_map) = normalized_blackboard.clone() {
             for (key, value) in blackboard_map {
+                workflow.spec.check_blackboard_write(&key, &value)?;
                 workflow_execution.blackboard.set(key, value);
             }
         }
+        let normalized_blackboard = if workflow.spec.variables.is_empty() {
+            normalized_blackboard
+        } else {
+            workflow
+                .spec
+                .apply_variable_defaults(&mut workflow_execution.blackboard);
+            Some(workflow_execution.blackboard.data().clone())
+        };
+
+        // Step 3.5: Concurrency group admission. `reject` fails before anything
+        // is persisted; queued executions are persisted as pending and launched
+        // by a background task once the group is free.
+        let mut queued_lock = None;
+        let mut admission_guard = None;
+        if let (Some(groups), Some(concurrency)) =
+            (&self.concurrency_groups, &workflow.spec.concurrency)
+        {
+            if let ConcurrencyAdmission::Queued(waiter) = groups
+                .admit(tenant_id, concurrency, LockHolder::workflow(execution_id))
+                .await?
+            {
+                workflow_execution.status = ExecutionStatus::Pending;
+                queued_lock = Some(waiter);
+            }
+            admission_guard = Some(groups.admission_guard(execution_id));
+        }
 
         // Step 4: Persist execution to repository (establishes idempotency key)
         self.execution_repository
@@ -392,73 +601,41 @@ impl StartWorkflowExecutionUseCase for StandardStartWorkflowExecutionUseCase {
             })
             .context("Failed to persist workflow execution to repository")?;
 
-        // Step 5: Start execution in Temporal via gRPC
-        let engine = {
-            let lock = self.workflow_engine.read().await;
-            lock.clone()
-                .ok_or_else(|| anyhow::anyhow!("Workflow engine not connected yet"))?
-        };
-
         let workflow_id = workflow.id.to_string();
-        let temporal_workflow_id = execution_id.0.to_string();
 
-        let temporal_run_id = engine
-            .start_workflow(crate::application::ports::StartWorkflowParams {
-                workflow_id: &workflow_id,
+        if let Some(waiter) = queued_lock {
+            tokio::spawn(self.launcher().launch_when_admitted(
+                waiter,
+                admission_guard,
+                tenant_id.clone(),
+                workflow,
                 execution_id,
-                tenant_id: tenant_id.as_str(),
-                input: match &request.input {
-                    serde_json::Value::Object(map) => {
-                        map.iter().map(|(k, v)| (k.clone(), v.clone())).collect()
-                    }
-                    _ => {
-                        // Wrap non-object inputs
-                        let mut map = HashMap::new();
-                        map.insert("input".to_string(), request.input.clone());
-                        map
-                    }
-                },
-                blackboard: normalized_blackboard,
-                security_context_name: request.security_context_name.clone(),
-                intent: request.intent.clone(),
-            })
-            .await
-            .context("Failed to start workflow execution in Temporal")?;
+                request,
+                normalized_blackboard,
+            ));
+            return Ok(StartedWorkflowExecution {
+                execution_id: execution_id.0.to_string(),
+                workflow_id,
+                temporal_run_id: String::new(),
+                status: "queued".to_string(),
+                started_at: Utc::now(),
+            });
+        }
 
-        self.execution_repository
-            .update_temporal_linkage_for_tenant(
+        // Steps 5–8: start in Temporal, persist linkage, publish, record metrics.
+        let temporal_run_id = self
+            .launcher()
+            .launch(
                 tenant_id,
+                &workflow,
                 execution_id,
-                &temporal_workflow_id,
-                &temporal_run_id,
+                &request,
+                normalized_blackboard,
             )
-            .await
-            .map_err(|error| {
-                error!(
-                    tenant_id = %tenant_id.as_str(),
-                    execution_id = %execution_id.0,
-                    workflow_id = %workflow.id.0,
-                    temporal_workflow_id = %temporal_workflow_id,
-                    temporal_run_id = %temporal_run_id,
-                    error = %error,
-                    "Failed to persist Temporal linkage for workflow execution"
-                );
-                anyhow::Error::new(error)
-            })
-            .context("Failed to persist Temporal linkage for workflow execution")?;
-
-        // Step 7: Publish domain event
-        self.event_bus.publish_workflow_event(
-            crate::domain::events::WorkflowEvent::WorkflowExecutionStarted {
-                execution_id,
-                workflow_id: workflow.id,
-                started_at: Utc::now(),
-            },
-        );
-
-        // Step 8: Record Prometheus metrics (ADR-058, BC-3)
-        metrics::counter!("aegis_workflow_executions_total", "status" => "started").increment(1);
-        metrics::gauge!("aegis_workflow_executions_active").increment(1.0);
+            .await?;
+        if let Some(guard) = admission_guard {
+            guard.disarm();
+        }
 
         Ok(StartedWorkflowExecution {
             execution_id: execution_id.0.to_string(),
@@ -476,8 +653,8 @@ mod tests {
     use crate::domain::events::WorkflowEvent;
     use crate::domain::repository::{RepositoryError, WorkflowExecutionRepository};
     use crate::domain::workflow::{
-        StateKind, StateName, TransitionCondition, TransitionRule, Workflow, WorkflowId,
-        WorkflowMetadata, WorkflowSpec, WorkflowState,
+        StateKind, StateName, TransitionCondition, TransitionRule, VariableType, Workflow,
+        WorkflowId, WorkflowMetadata, WorkflowSpec, WorkflowState, WorkflowVariable,
     };
     use crate::infrastructure::event_bus::DomainEvent;
     use crate::infrastructure::repositories::{
@@ -597,6 +774,10 @@ mod tests {
             Ok(self.executions.lock().unwrap().values().cloned().collect())
         }
 
+        async fn find_active_all(&self) -> Result<Vec<WorkflowExecution>, RepositoryError> {
+            Ok(self.executions.lock().unwrap().values().cloned().collect())
+        }
+
         async fn find_by_workflow_for_tenant(
             &self,
             _tenant_id: &TenantId,
@@ -657,6 +838,33 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn append_transition(
+            &self,
+            _execution_id: ExecutionId,
+            _record: &crate::domain::workflow::WorkflowTransitionRecord,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn close_transition(
+            &self,
+            _execution_id: ExecutionId,
+            _state_name: &str,
+            _exited_at: chrono::DateTime<chrono::Utc>,
+            _output_summary: Option<String>,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_transitions_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _id: ExecutionId,
+        ) -> Result<Vec<crate::domain::workflow::WorkflowTransitionRecord>, RepositoryError>
+        {
+            Ok(vec![])
+        }
+
         async fn count_by_workflow_for_tenant(
             &self,
             _tenant_id: &TenantId,
@@ -730,9 +938,12 @@ mod tests {
             WorkflowSpec {
                 initial_state: StateName::new("START").unwrap(),
                 context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
                 states,
                 storage: Default::default(),
                 max_total_transitions: None,
+                concurrency: None,
             },
         )
         .unwrap()
@@ -931,6 +1142,66 @@ mod tests {
         assert!(err.to_string().contains("reserved key 'input'"));
     }
 
+    #[tokio::test]
+    async fn start_execution_checks_typed_variables_and_seeds_defaults() {
+        let untyped = build_test_workflow("typed-blackboard");
+        let mut spec = untyped.spec.clone();
+        spec.variables.insert(
+            "threshold".to_string(),
+            WorkflowVariable {
+                var_type: VariableType::Number,
+                default: Some(json!(0.5)),
+                description: None,
+            },
+        );
+        let workflow = Workflow::new(untyped.metadata.clone(), spec).unwrap();
+        let workflow_repo = Arc::new(InMemoryWorkflowRepository::new());
+        workflow_repo
+            .save_for_tenant(&TenantId::consumer(), &workflow)
+            .await
+            .unwrap();
+        let engine = Arc::new(RecordingWorkflowEngine::new("temporal-run-typed"));
+
+        let service = StandardStartWorkflowExecutionUseCase::new(
+            workflow_repo,
+            Arc::new(InMemoryWorkflowExecutionRepository::new()),
+            Arc::new(tokio::sync::RwLock::new(Some(engine.clone()))),
+            Arc::new(EventBus::new(8)),
+        );
+        let request = |blackboard| StartWorkflowExecutionRequest {
+            workflow_id: workflow.metadata.name.clone(),
+            input: json!({}),
+            blackboard,
+            version: None,
+            tenant_id: Some(TenantId::consumer()),
+            security_context_name: None,
+            intent: None,
+        };
+
+        let err = service
+            .start_execution(request(Some(json!({ "threshold": "high" }))))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("expects a value of type number"));
+
+        let err = service
+            .start_execution(request(Some(json!({ "thresold": 0.9 }))))
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("thresold"));
+
+        service.start_execution(request(None)).await.unwrap();
+        let calls = engine.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(
+            calls[0]
+                .blackboard
+                .as_ref()
+                .and_then(|blackboard| blackboard.get("threshold")),
+            Some(&json!(0.5))
+        );
+    }
+
     #[tokio::test]
     async fn start_execution_resolves_workflow_by_uuid_identifier() {
         let workflow = build_test_workflow("uuid-resolve-workflow");
@@ -1195,9 +1466,12 @@ mod tests {
             WorkflowSpec {
                 initial_state: StateName::new("START").unwrap(),
                 context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
                 states,
                 storage: Default::default(),
                 max_total_transitions: None,
+                concurrency: None,
             },
         )
         .unwrap()
@@ -500,50 +500,43 @@ impl StandardStimulusService {
         })
     }
 
-    /// Poll the execution until it reaches a terminal state, then return the final output.
+    /// Wait for the execution to reach a terminal state, then return the final output.
     /// Respects `self.classification_timeout`.
     async fn await_execution_output(
         &self,
         tenant_id: &crate::domain::shared_kernel::TenantId,
         exec_id: crate::domain::execution::ExecutionId,
     ) -> anyhow::Result<String> {
-        let deadline = tokio::time::Instant::now() + self.classification_timeout;
-        let poll_interval = Duration::from_millis(250);
-
-        loop {
-            if tokio::time::Instant::now() > deadline {
+        let execution = self
+            .execution_service
+            .wait_for_terminal(tenant_id, exec_id, self.classification_timeout)
+            .await?;
+
+        match execution.status {
+            ExecutionStatus::Completed => {
+                // Return the output of the last successful iteration
+                if let Some(last_iter) = execution.iterations.last() {
+                    if let Some(output) = &last_iter.output {
+                        return Ok(output.clone());
+                    }
+                }
+                anyhow::bail!("RouterAgent execution completed with no output");
+            }
+            ExecutionStatus::Failed => {
+                anyhow::bail!(
+                    "RouterAgent execution failed: {}",
+                    execution.error.as_deref().unwrap_or("unknown error")
+                );
+            }
+            ExecutionStatus::Cancelled => {
+                anyhow::bail!("RouterAgent execution was cancelled");
+            }
+            _ => {
                 anyhow::bail!(
                     "RouterAgent classification timed out after {:?}",
                     self.classification_timeout
                 );
             }
-
-            let execution = self
-                .execution_service
-                .get_execution_for_tenant(tenant_id, exec_id)
-                .await?;
-
-            match execution.status {
-                ExecutionStatus::Completed => {
-                    // Return the output of the last successful iteration
-                    if let Some(last_iter) = execution.iterations.last() {
-                        if let Some(output) = &last_iter.output {
-                            return Ok(output.clone());
-                        }
-                    }
-                    anyhow::bail!("RouterAgent execution completed with no output");
-                }
-                ExecutionStatus::Failed => {
-                    anyhow::bail!(
-                        "RouterAgent execution failed: {}",
-                        execution.error.as_deref().unwrap_or("unknown error")
-                    );
-                }
-                _ => {
-                    // Still running — wait and retry
-                    tokio::time::sleep(poll_interval).await;
-                }
-            }
         }
     }
 }
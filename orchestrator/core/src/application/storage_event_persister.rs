@@ -20,7 +20,7 @@
 //! - **Purpose:** Implements internal responsibilities for storage event persister
 
 use crate::domain::repository::StorageEventRepository;
-use crate::infrastructure::event_bus::{DomainEvent, EventBus};
+use crate::infrastructure::event_bus::{DomainEvent, EventBus, SubscriptionOptions};
 use std::sync::Arc;
 use tokio::task::JoinHandle;
 use tracing::{debug, error, info, warn};
@@ -57,7 +57,9 @@ impl StorageEventPersister {
         info!("Starting storage event persister background task");
 
         tokio::spawn(async move {
-            let mut receiver = self.event_bus.subscribe();
+            let mut receiver = self
+                .event_bus
+                .subscribe_with(SubscriptionOptions::critical("storage_event_persister"));
             let mut events_processed = 0u64;
             let mut errors_encountered = 0u64;
 
@@ -171,6 +173,7 @@ mod tests {
         let event = StorageEvent::FileOpened {
             execution_id: Some(ExecutionId::new()),
             workflow_execution_id: None,
+            iteration_number: None,
             volume_id: VolumeId::new(),
             path: "/workspace/test.txt".to_string(),
             open_mode: "read".to_string(),
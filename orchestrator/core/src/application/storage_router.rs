@@ -19,6 +19,7 @@ use crate::domain::storage::{
 };
 use crate::infrastructure::storage::{LocalHostStorageProvider, SealStorageProvider};
 use async_trait::async_trait;
+use bytes::Bytes;
 use std::sync::Arc;
 
 pub struct StorageRouter {
@@ -102,6 +103,19 @@ impl StorageProvider for StorageRouter {
             .await
     }
 
+    async fn read_at_bytes(
+        &self,
+        handle: &FileHandle,
+        offset: u64,
+        length: usize,
+    ) -> Result<Bytes, StorageError> {
+        let path = String::from_utf8(handle.0.clone())
+            .map_err(|_| StorageError::InvalidPath("Invalid handle".into()))?;
+        self.provider_for_path(&path)?
+            .read_at_bytes(handle, offset, length)
+            .await
+    }
+
     async fn write_at(
         &self,
         handle: &FileHandle,
@@ -29,7 +29,7 @@
 
 use crate::domain::tenant::TenantId;
 use crate::domain::workflow::*;
-use anyhow::{Context, Result};
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
@@ -72,6 +72,11 @@ pub struct TemporalWorkflowDefinition {
     /// Optional Handlebars template mapping blackboard values to output fields.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_template: Option<serde_json::Value>,
+
+    /// Typed handoff declarations per state. The worker exposes captured
+    /// handoffs to templates as `handoff.<STATE>.<output>`.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub handoffs: HashMap<String, crate::domain::workflow::StateHandoffs>,
 }
 
 /// Individual state in Temporal workflow
@@ -178,6 +183,28 @@ pub struct TemporalWorkflowState {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub subworkflow_input: Option<String>,
 
+    // ── ForEach-specific (fan-out/fan-in) ───────────────────────────
+    // `agent` and `input` carry the per-item agent and input template.
+    /// Expression resolving to the collection to iterate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreach_source: Option<String>,
+
+    /// Blackboard variable each item is bound to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreach_item_var: Option<String>,
+
+    /// Maximum number of per-item activities in flight
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreach_max_concurrency: Option<u32>,
+
+    /// Result folding: "list" | "flatten" | "discard"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreach_collect: Option<String>,
+
+    /// State outcome: "all_succeed" | "any_succeed" | "best_effort"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub foreach_completion: Option<String>,
+
     // Output handler (ADR-103)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub output_handler: Option<serde_json::Value>,
@@ -231,6 +258,7 @@ pub struct TemporalTransitionRule {
     pub exit_code: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub value: Option<String>,
+    /// Handlebars expression for `custom`; plugin digest for `plugin`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub expression: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -292,6 +320,12 @@ impl TemporalWorkflowMapper {
             max_total_transitions: workflow.spec.max_total_transitions,
             output_schema: workflow.metadata.output_schema.clone(),
             output_template: workflow.metadata.output_template.clone(),
+            handoffs: workflow
+                .spec
+                .handoffs
+                .iter()
+                .map(|(state, handoffs)| (state.as_str().to_string(), handoffs.clone()))
+                .collect(),
         })
     }
 
@@ -370,6 +404,11 @@ impl TemporalWorkflowMapper {
                     subworkflow_mode: None,
                     subworkflow_result_key: None,
                     subworkflow_input: None,
+                    foreach_source: None,
+                    foreach_item_var: None,
+                    foreach_max_concurrency: None,
+                    foreach_collect: None,
+                    foreach_completion: None,
                     output_handler: output_handler
                         .as_ref()
                         .map(|h| serde_json::to_value(h).unwrap_or(serde_json::Value::Null)),
@@ -420,6 +459,11 @@ impl TemporalWorkflowMapper {
                 subworkflow_mode: None,
                 subworkflow_result_key: None,
                 subworkflow_input: None,
+                foreach_source: None,
+                foreach_item_var: None,
+                foreach_max_concurrency: None,
+                foreach_collect: None,
+                foreach_completion: None,
                 output_handler: None,
                 max_state_visits: state.max_state_visits,
                 transitions,
@@ -466,6 +510,11 @@ impl TemporalWorkflowMapper {
                 subworkflow_mode: None,
                 subworkflow_result_key: None,
                 subworkflow_input: None,
+                foreach_source: None,
+                foreach_item_var: None,
+                foreach_max_concurrency: None,
+                foreach_collect: None,
+                foreach_completion: None,
                 output_handler: None,
                 max_state_visits: state.max_state_visits,
                 transitions,
@@ -546,6 +595,11 @@ impl TemporalWorkflowMapper {
                     subworkflow_mode: None,
                     subworkflow_result_key: None,
                     subworkflow_input: None,
+                    foreach_source: None,
+                    foreach_item_var: None,
+                    foreach_max_concurrency: None,
+                    foreach_collect: None,
+                    foreach_completion: None,
                     output_handler: output_handler
                         .as_ref()
                         .map(|h| serde_json::to_value(h).unwrap_or(serde_json::Value::Null)),
@@ -623,6 +677,11 @@ impl TemporalWorkflowMapper {
                     subworkflow_mode: None,
                     subworkflow_result_key: None,
                     subworkflow_input: None,
+                    foreach_source: None,
+                    foreach_item_var: None,
+                    foreach_max_concurrency: None,
+                    foreach_collect: None,
+                    foreach_completion: None,
                     output_handler: output_handler
                         .as_ref()
                         .map(|h| serde_json::to_value(h).unwrap_or(serde_json::Value::Null)),
@@ -632,12 +691,7 @@ impl TemporalWorkflowMapper {
             }
 
             StateKind::ParallelContainerRun { steps, completion } => {
-                let completion_str = match completion {
-                    ParallelCompletionStrategy::AllSucceed => "all_succeed",
-                    ParallelCompletionStrategy::AnySucceed => "any_succeed",
-                    ParallelCompletionStrategy::BestEffort => "best_effort",
-                }
-                .to_string();
+                let completion_str = Self::map_completion_strategy(*completion);
 
                 Ok(TemporalWorkflowState {
                     kind: "ParallelContainerRun".to_string(),
@@ -679,6 +733,77 @@ impl TemporalWorkflowMapper {
                     subworkflow_mode: None,
                     subworkflow_result_key: None,
                     subworkflow_input: None,
+                    foreach_source: None,
+                    foreach_item_var: None,
+                    foreach_max_concurrency: None,
+                    foreach_collect: None,
+                    foreach_completion: None,
+                    output_handler: None,
+                    max_state_visits: state.max_state_visits,
+                    transitions,
+                })
+            }
+
+            StateKind::ForEach {
+                source,
+                item_var,
+                agent,
+                input,
+                max_concurrency,
+                collect,
+                completion,
+            } => {
+                let collect_str = match collect {
+                    ForEachCollectStrategy::List => "list",
+                    ForEachCollectStrategy::Flatten => "flatten",
+                    ForEachCollectStrategy::Discard => "discard",
+                };
+
+                Ok(TemporalWorkflowState {
+                    kind: "ForEach".to_string(),
+                    agent: Some(agent.clone()),
+                    input: Some(input.clone()),
+                    intent: None,
+                    isolation: None,
+                    timeout,
+                    judges: None,
+                    max_iterations: None,
+                    pre_execution_validator: None,
+                    command: None,
+                    env: None,
+                    workdir: None,
+                    prompt: None,
+                    default_response: None,
+                    agents: None,
+                    consensus: None,
+                    judges_for_parallel: None,
+                    container_run_name: None,
+                    container_run_image: None,
+                    container_run_image_pull_policy: None,
+                    container_run_command: None,
+                    container_run_env: None,
+                    container_run_workdir: None,
+                    container_run_volumes: None,
+                    container_run_resources: None,
+                    container_run_registry_credentials: None,
+                    container_run_retry: None,
+                    container_run_shell: None,
+                    container_run_read_only_root_filesystem: None,
+                    container_run_run_as_user: None,
+                    container_run_network_mode: None,
+                    parallel_container_steps: None,
+                    parallel_container_completion: None,
+                    subworkflow_id: None,
+                    subworkflow_mode: None,
+                    subworkflow_result_key: None,
+                    subworkflow_input: None,
+                    foreach_source: Some(source.clone()),
+                    foreach_item_var: Some(item_var.clone()),
+                    foreach_max_concurrency: Some(
+                        max_concurrency.unwrap_or(MAX_FOREACH_CONCURRENCY),
+                    ),
+                    foreach_collect: Some(collect_str.to_string()),
+                    foreach_completion: Some(Self::map_completion_strategy(*completion)),
                     output_handler: None,
                     max_state_visits: state.max_state_visits,
                     transitions,
@@ -740,6 +865,11 @@ impl TemporalWorkflowMapper {
                     subworkflow_mode: Some(mode_str.to_string()),
                     subworkflow_result_key: result_key.clone(),
                     subworkflow_input: input.clone(),
+                    foreach_source: None,
+                    foreach_item_var: None,
+                    foreach_max_concurrency: None,
+                    foreach_collect: None,
+                    foreach_completion: None,
                     // Output handler (ADR-103): Subworkflow states do not carry an output_handler
                     output_handler: None,
                     // Transitions
@@ -900,6 +1030,16 @@ impl TemporalWorkflowMapper {
                 None,
                 Some(expr.clone()),
             ),
+            // The worker calls back into the orchestrator to run the plugin.
+            TransitionCondition::Plugin { plugin } => (
+                "plugin".to_string(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(plugin.to_string()),
+            ),
         };
 
         Ok(TemporalTransitionRule {
@@ -925,6 +1065,15 @@ impl TemporalWorkflowMapper {
         }
     }
 
+    /// Map ParallelCompletionStrategy to string
+    fn map_completion_strategy(strategy: ParallelCompletionStrategy) -> String {
+        match strategy {
+            ParallelCompletionStrategy::AllSucceed => "all_succeed".to_string(),
+            ParallelCompletionStrategy::AnySucceed => "any_succeed".to_string(),
+            ParallelCompletionStrategy::BestEffort => "best_effort".to_string(),
+        }
+    }
+
     /// Map ConsensusStrategy to string
     fn map_consensus_strategy(strategy: ConsensusStrategy) -> String {
         match strategy {
@@ -937,83 +1086,11 @@ impl TemporalWorkflowMapper {
 
     /// Validate Handlebars templates in workflow
     ///
-    /// This ensures all templates can be parsed before sending to TypeScript worker
+    /// This ensures all templates (including custom transition expressions)
+    /// compile with the workflow helpers before sending to TypeScript worker
     pub fn validate_templates(workflow: &Workflow) -> Result<()> {
-        let handlebars = handlebars::Handlebars::new();
-
-        for (state_name, state) in &workflow.spec.states {
-            match &state.kind {
-                StateKind::Agent { input, .. } => {
-                    handlebars
-                        .render_template(input, &serde_json::json!({}))
-                        .with_context(|| {
-                            format!("Invalid template in state {state_name}: {input}")
-                        })?;
-                }
-                StateKind::System { env, .. } => {
-                    for (key, value) in env {
-                        handlebars
-                            .render_template(value, &serde_json::json!({}))
-                            .with_context(|| {
-                                format!("Invalid template in state {state_name} env {key}: {value}")
-                            })?;
-                    }
-                }
-                StateKind::ParallelAgents { agents, .. } => {
-                    for agent in agents {
-                        handlebars
-                            .render_template(&agent.input, &serde_json::json!({}))
-                            .with_context(|| {
-                                format!(
-                                    "Invalid template in parallel agent {}: {}",
-                                    agent.agent, agent.input
-                                )
-                            })?;
-                    }
-                }
-                StateKind::ContainerRun { env, .. } => {
-                    for (key, value) in env {
-                        handlebars
-                            .render_template(value, &serde_json::json!({}))
-                            .with_context(|| {
-                                format!(
-                                    "Invalid template in ContainerRun state {state_name} env {key}: {value}"
-                                )
-                            })?;
-                    }
-                }
-                StateKind::ParallelContainerRun { steps, .. } => {
-                    for step in steps {
-                        for (key, value) in &step.env {
-                            handlebars
-                                .render_template(value, &serde_json::json!({}))
-                                .with_context(|| {
-                                    format!(
-                                        "Invalid template in ParallelContainerRun state {} step {} env {}: {}",
-                                        state_name, step.name, key, value
-                                    )
-                                })?;
-                        }
-                    }
-                }
-                StateKind::Human { .. } => {
-                    // Human states have no Handlebars templates to validate.
-                }
-                StateKind::Subworkflow { input, .. } => {
-                    if let Some(tmpl) = input {
-                        handlebars
-                            .render_template(tmpl, &serde_json::json!({}))
-                            .with_context(|| {
-                                format!(
-                                    "Invalid template in Subworkflow state {state_name} input: {tmpl}"
-                                )
-                            })?;
-                    }
-                }
-            }
-        }
-
-        Ok(())
+        crate::infrastructure::workflow_template_engine::WorkflowTemplateEngine::compile(workflow)
+            .map(|_| ())
     }
 }
 
@@ -1078,9 +1155,12 @@ mod tests {
             WorkflowSpec {
                 initial_state: StateName::new("START").unwrap(),
                 context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
                 states,
                 storage: Default::default(),
                 max_total_transitions: None,
+                concurrency: None,
             },
         )
         .unwrap();
@@ -1097,6 +1177,64 @@ mod tests {
         assert_eq!(temporal_def.states.get("END").unwrap().kind, "System");
     }
 
+    #[test]
+    fn test_map_foreach_state() {
+        let mut states = HashMap::new();
+        states.insert(
+            StateName::new("REVIEW").unwrap(),
+            WorkflowState {
+                kind: StateKind::ForEach {
+                    source: "{{TRIAGE.output.files}}".to_string(),
+                    item_var: "file".to_string(),
+                    agent: "reviewer".to_string(),
+                    input: "Review {{file}}".to_string(),
+                    max_concurrency: None,
+                    collect: ForEachCollectStrategy::Flatten,
+                    completion: ParallelCompletionStrategy::BestEffort,
+                },
+                transitions: vec![],
+                timeout: None,
+                max_state_visits: None,
+            },
+        );
+
+        let workflow = Workflow::new(
+            WorkflowMetadata {
+                name: "foreach-workflow".to_string(),
+                version: None,
+                description: None,
+                labels: HashMap::new(),
+                annotations: HashMap::new(),
+                input_schema: None,
+                output_schema: None,
+                output_template: None,
+            },
+            WorkflowSpec {
+                initial_state: StateName::new("REVIEW").unwrap(),
+                context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
+                states,
+                storage: Default::default(),
+                max_total_transitions: None,
+                concurrency: None,
+            },
+        )
+        .unwrap();
+
+        TemporalWorkflowMapper::validate_templates(&workflow).unwrap();
+        let temporal_def =
+            TemporalWorkflowMapper::to_temporal_definition(&workflow, &TenantId::consumer())
+                .unwrap();
+        let state = temporal_def.states.get("REVIEW").unwrap();
+        assert_eq!(state.kind, "ForEach");
+        assert_eq!(state.agent.as_deref(), Some("reviewer"));
+        assert_eq!(state.foreach_item_var.as_deref(), Some("file"));
+        assert_eq!(state.foreach_max_concurrency, Some(MAX_FOREACH_CONCURRENCY));
+        assert_eq!(state.foreach_collect.as_deref(), Some("flatten"));
+        assert_eq!(state.foreach_completion.as_deref(), Some("best_effort"));
+    }
+
     #[test]
     fn test_container_run_image_pull_policy_maps_to_snake_case() {
         let mut states = HashMap::new();
@@ -1140,9 +1278,12 @@ mod tests {
             WorkflowSpec {
                 initial_state: StateName::new("BUILD").unwrap(),
                 context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
                 states,
                 storage: Default::default(),
                 max_total_transitions: None,
+                concurrency: None,
             },
         )
         .unwrap();
@@ -1207,9 +1348,12 @@ mod tests {
             WorkflowSpec {
                 initial_state: StateName::new("START").unwrap(),
                 context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
                 states,
                 storage: Default::default(),
                 max_total_transitions: None,
+                concurrency: None,
             },
         )
         .unwrap();
@@ -1258,9 +1402,12 @@ mod tests {
             WorkflowSpec {
                 initial_state: StateName::new("START").unwrap(),
                 context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
                 states,
                 storage: Default::default(),
                 max_total_transitions: None,
+                concurrency: None,
             },
         )
         .unwrap();
@@ -2,33 +2,43 @@
 // SPDX-License-Identifier: AGPL-3.0
 //! # TenantQuotaService (ADR-056 §Quota Enforcement)
 //!
-//! Enforces per-tenant resource quotas for agents and concurrent executions.
+//! Enforces per-tenant resource quotas: agents, concurrent executions,
+//! allocated volume bytes and the monthly LLM token budget.
 //!
 //! ## Design
 //!
-//! Two check methods are provided — one per quota dimension — so that callers
-//! can fail fast with a precise error before creating any domain resources.
+//! One check method is provided per quota dimension so that callers can fail
+//! fast with a precise error before creating any domain resources.
 //!
-//! Both methods publish [`TenantEvent::TenantQuotaExceeded`] on the event bus
-//! before returning the error so that observability and alerting pipelines
-//! receive real-time quota signals.
+//! Every check publishes [`TenantEvent::TenantQuotaExceeded`] on the event
+//! bus before returning the error so that observability and alerting
+//! pipelines receive real-time quota signals.
 //!
 //! ## Single source of truth
 //!
 //! Tier is read from `tenant_subscriptions.tier` via `BillingRepository` and
 //! quotas are computed dynamically via [`TenantQuotas::for_tier`]. There is no
-//! duplicate tier/quota state on the `tenants` row.
+//! duplicate tier/quota state on the `tenants` row. Platform admins can
+//! override individual limits per tenant through `/v1/quotas`; overrides live
+//! in the [`QuotaRepository`] and are merged by [`EffectiveQuotas::resolve`].
+//!
+//! [`TenantQuotas::for_tier`]: crate::domain::tenancy::TenantQuotas::for_tier
 
 use std::sync::Arc;
 
 use chrono::Utc;
 
 use crate::domain::events::TenantEvent;
+use crate::domain::quota::{
+    month_start, EffectiveQuotas, QuotaDefinition, QuotaLimits, QuotaRepository, QuotaUsage,
+};
 use crate::domain::repository::{
-    AgentRepository, ExecutionRepository, RepositoryError, TenantRepository,
+    AgentRepository, ExecutionRepository, RepositoryError, TenantRepository, VolumeRepository,
 };
-use crate::domain::tenancy::{TenantQuotaKind, TenantQuotas, TenantTier};
+use crate::domain::tenancy::{TenantQuotaKind, TenantTier};
 use crate::domain::tenant::TenantId;
+use crate::domain::token_usage::{TokenUsageRepository, UsageGroupBy, UsageQuery};
+use crate::domain::volume::VolumeStatus;
 use crate::infrastructure::event_bus::EventBus;
 use crate::infrastructure::repositories::BillingRepository;
 
@@ -78,6 +88,12 @@ pub struct TenantQuotaService {
     agent_repo: Arc<dyn AgentRepository>,
     execution_repo: Arc<dyn ExecutionRepository>,
     event_bus: Arc<EventBus>,
+    /// Per-tenant overrides; tier defaults apply to every tenant without one.
+    quota_repo: Option<Arc<dyn QuotaRepository>>,
+    /// Source of allocated volume bytes. Volume usage reads as 0 without it.
+    volume_repo: Option<Arc<dyn VolumeRepository>>,
+    /// Source of monthly token usage. Token budgets are not enforced without it.
+    token_usage_repo: Option<Arc<dyn TokenUsageRepository>>,
 }
 
 impl TenantQuotaService {
@@ -94,9 +110,30 @@ impl TenantQuotaService {
             agent_repo,
             execution_repo,
             event_bus,
+            quota_repo: None,
+            volume_repo: None,
+            token_usage_repo: None,
         }
     }
 
+    /// Store and apply per-tenant overrides from `repo`.
+    pub fn with_quota_repository(mut self, repo: Arc<dyn QuotaRepository>) -> Self {
+        self.quota_repo = Some(repo);
+        self
+    }
+
+    /// Count allocated volume bytes from `repo`.
+    pub fn with_volume_repository(mut self, repo: Arc<dyn VolumeRepository>) -> Self {
+        self.volume_repo = Some(repo);
+        self
+    }
+
+    /// Count monthly token usage from `repo`.
+    pub fn with_token_usage_repository(mut self, repo: Arc<dyn TokenUsageRepository>) -> Self {
+        self.token_usage_repo = Some(repo);
+        self
+    }
+
     /// Resolve the tenant's tier from its subscription row. When no
     /// subscription exists or it is not Active/Trialing, fall back to Free.
     pub(crate) async fn resolve_tier(
@@ -106,78 +143,259 @@ impl TenantQuotaService {
         resolve_tier_from_subscription(self.billing_repo.as_ref(), tenant_id).await
     }
 
-    /// Check whether deploying a new agent would exceed the tenant's `max_agents` quota.
-    ///
-    /// Fetches the current active agent count and compares it against the tenant's quota
-    /// computed from `tenant_subscriptions.tier`. Publishes
-    /// [`TenantEvent::TenantQuotaExceeded`] and returns `Err(QuotaError::Exceeded)`
-    /// when the limit is reached or exceeded.
-    pub async fn check_agent_quota(&self, tenant_id: &TenantId) -> Result<(), QuotaError> {
-        // Probe tenant existence so callers get a precise NotFound error.
+    /// Probe tenant existence so callers get a precise NotFound error.
+    async fn ensure_tenant(&self, tenant_id: &TenantId) -> Result<(), QuotaError> {
         if self.tenant_repo.find_by_slug(tenant_id).await?.is_none() {
             return Err(QuotaError::TenantNotFound(tenant_id.as_str().to_string()));
         }
+        Ok(())
+    }
+
+    /// Stored overrides for `tenant_id`, if any.
+    pub async fn definition(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Option<QuotaDefinition>, QuotaError> {
+        match &self.quota_repo {
+            Some(repo) => Ok(repo.get(tenant_id).await?),
+            None => Ok(None),
+        }
+    }
 
+    /// Every stored override, ordered by tenant.
+    pub async fn list_definitions(&self) -> Result<Vec<QuotaDefinition>, QuotaError> {
+        match &self.quota_repo {
+            Some(repo) => Ok(repo.list().await?),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Limits in force for `tenant_id`: its tier defaults with any stored
+    /// overrides applied.
+    pub async fn effective_quotas(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<EffectiveQuotas, QuotaError> {
+        self.ensure_tenant(tenant_id).await?;
         let tier = self.resolve_tier(tenant_id).await?;
-        let quotas = TenantQuotas::for_tier(&tier);
-        let limit = quotas.max_agents as u64;
+        let overrides = self
+            .definition(tenant_id)
+            .await?
+            .map(|definition| definition.limits)
+            .unwrap_or_default();
+        Ok(EffectiveQuotas::resolve(&tier, &overrides))
+    }
 
-        let current = self.agent_repo.count_active(tenant_id).await?;
+    /// Current consumption of every quota dimension.
+    pub async fn usage(&self, tenant_id: &TenantId) -> Result<QuotaUsage, QuotaError> {
+        Ok(QuotaUsage {
+            agents: self.agent_repo.count_active(tenant_id).await?,
+            concurrent_executions: self.execution_repo.count_running(tenant_id).await?,
+            volume_bytes: self.allocated_volume_bytes(tenant_id).await?,
+            tokens_this_month: self.tokens_this_month(tenant_id).await?,
+        })
+    }
 
-        if current >= limit {
-            self.event_bus
-                .publish_tenant_event(TenantEvent::TenantQuotaExceeded {
-                    tenant_slug: tenant_id.as_str().to_string(),
-                    quota_kind: TenantQuotaKind::TotalAgents,
-                    current_value: current,
-                    limit,
-                    exceeded_at: Utc::now(),
-                });
-            return Err(QuotaError::Exceeded {
-                kind: TenantQuotaKind::TotalAgents,
-                current,
-                limit,
-            });
+    /// Replace the overrides for `tenant_id`. Publishes
+    /// [`TenantEvent::TenantQuotaUpdated`] for every limit whose effective
+    /// value changes.
+    pub async fn set_definition(
+        &self,
+        tenant_id: &TenantId,
+        limits: QuotaLimits,
+        updated_by: &str,
+    ) -> Result<QuotaDefinition, QuotaError> {
+        let repo = self.require_quota_repo()?;
+        let before = self.effective_quotas(tenant_id).await?;
+        let definition = QuotaDefinition {
+            tenant_id: tenant_id.clone(),
+            limits,
+            updated_by: updated_by.to_string(),
+            updated_at: Utc::now(),
+        };
+        repo.upsert(&definition).await?;
+        let after = self.effective_quotas(tenant_id).await?;
+        self.publish_updates(tenant_id, &before, &after, updated_by);
+        Ok(definition)
+    }
+
+    /// Drop the overrides for `tenant_id`, reverting it to its tier defaults.
+    /// Returns whether overrides existed.
+    pub async fn clear_definition(
+        &self,
+        tenant_id: &TenantId,
+        updated_by: &str,
+    ) -> Result<bool, QuotaError> {
+        let repo = self.require_quota_repo()?;
+        let before = self.effective_quotas(tenant_id).await?;
+        if !repo.delete(tenant_id).await? {
+            return Ok(false);
         }
+        let after = self.effective_quotas(tenant_id).await?;
+        self.publish_updates(tenant_id, &before, &after, updated_by);
+        Ok(true)
+    }
 
+    /// Check whether deploying a new agent would exceed the tenant's `max_agents` quota.
+    ///
+    /// Fetches the current active agent count and compares it against the
+    /// tenant's effective quota. Publishes [`TenantEvent::TenantQuotaExceeded`]
+    /// and returns `Err(QuotaError::Exceeded)` when the limit is reached or
+    /// exceeded.
+    pub async fn check_agent_quota(&self, tenant_id: &TenantId) -> Result<(), QuotaError> {
+        let limit = self.effective_quotas(tenant_id).await?.max_agents;
+        let current = self.agent_repo.count_active(tenant_id).await?;
+        if current >= limit {
+            return Err(self.exceeded(tenant_id, TenantQuotaKind::TotalAgents, current, limit));
+        }
         Ok(())
     }
 
     /// Check whether starting a new execution would exceed the tenant's
     /// `max_concurrent_executions` quota.
     ///
-    /// Counts running and pending executions and compares against the limit
-    /// computed from `tenant_subscriptions.tier`. Publishes
-    /// [`TenantEvent::TenantQuotaExceeded`] and returns `Err(QuotaError::Exceeded)`
-    /// when the limit is reached or exceeded.
+    /// Counts running and pending executions and compares against the
+    /// tenant's effective quota. Publishes [`TenantEvent::TenantQuotaExceeded`]
+    /// and returns `Err(QuotaError::Exceeded)` when the limit is reached or
+    /// exceeded.
     pub async fn check_execution_quota(&self, tenant_id: &TenantId) -> Result<(), QuotaError> {
-        if self.tenant_repo.find_by_slug(tenant_id).await?.is_none() {
-            return Err(QuotaError::TenantNotFound(tenant_id.as_str().to_string()));
+        let limit = self
+            .effective_quotas(tenant_id)
+            .await?
+            .max_concurrent_executions;
+        let current = self.execution_repo.count_running(tenant_id).await?;
+        if current >= limit {
+            return Err(self.exceeded(
+                tenant_id,
+                TenantQuotaKind::ConcurrentExecutions,
+                current,
+                limit,
+            ));
         }
+        Ok(())
+    }
 
-        let tier = self.resolve_tier(tenant_id).await?;
-        let quotas = TenantQuotas::for_tier(&tier);
-        let limit = quotas.max_concurrent_executions as u64;
-
-        let current = self.execution_repo.count_running(tenant_id).await?;
+    /// Check whether allocating `additional_bytes` more volume storage would
+    /// exceed the tenant's `max_volume_bytes` quota.
+    pub async fn check_volume_quota(
+        &self,
+        tenant_id: &TenantId,
+        additional_bytes: u64,
+    ) -> Result<(), QuotaError> {
+        let limit = self.effective_quotas(tenant_id).await?.max_volume_bytes;
+        let requested = self
+            .allocated_volume_bytes(tenant_id)
+            .await?
+            .saturating_add(additional_bytes);
+        if requested > limit {
+            return Err(self.exceeded(tenant_id, TenantQuotaKind::VolumeBytes, requested, limit));
+        }
+        Ok(())
+    }
 
+    /// Check whether the tenant has exhausted its monthly token budget.
+    /// Passes when no budget is set or token usage is not recorded.
+    pub async fn check_token_budget(&self, tenant_id: &TenantId) -> Result<(), QuotaError> {
+        let Some(limit) = self.effective_quotas(tenant_id).await?.monthly_token_budget else {
+            return Ok(());
+        };
+        let Some(current) = self.tokens_this_month(tenant_id).await? else {
+            return Ok(());
+        };
         if current >= limit {
-            self.event_bus
-                .publish_tenant_event(TenantEvent::TenantQuotaExceeded {
-                    tenant_slug: tenant_id.as_str().to_string(),
-                    quota_kind: TenantQuotaKind::ConcurrentExecutions,
-                    current_value: current,
-                    limit,
-                    exceeded_at: Utc::now(),
-                });
-            return Err(QuotaError::Exceeded {
-                kind: TenantQuotaKind::ConcurrentExecutions,
-                current,
+            return Err(self.exceeded(tenant_id, TenantQuotaKind::MonthlyTokens, current, limit));
+        }
+        Ok(())
+    }
+
+    /// Record a rejection and build its error.
+    fn exceeded(
+        &self,
+        tenant_id: &TenantId,
+        kind: TenantQuotaKind,
+        current: u64,
+        limit: u64,
+    ) -> QuotaError {
+        metrics::counter!("aegis_tenant_quota_exceeded_total", "kind" => kind.as_str())
+            .increment(1);
+        self.event_bus
+            .publish_tenant_event(TenantEvent::TenantQuotaExceeded {
+                tenant_slug: tenant_id.as_str().to_string(),
+                quota_kind: kind.clone(),
+                current_value: current,
                 limit,
+                exceeded_at: Utc::now(),
             });
+        QuotaError::Exceeded {
+            kind,
+            current,
+            limit,
         }
+    }
 
-        Ok(())
+    fn publish_updates(
+        &self,
+        tenant_id: &TenantId,
+        before: &EffectiveQuotas,
+        after: &EffectiveQuotas,
+        updated_by: &str,
+    ) {
+        for ((kind, old_limit), (_, new_limit)) in before.limits().into_iter().zip(after.limits()) {
+            if old_limit != new_limit {
+                self.event_bus
+                    .publish_tenant_event(TenantEvent::TenantQuotaUpdated {
+                        tenant_id: tenant_id.clone(),
+                        quota_kind: kind,
+                        old_limit,
+                        new_limit,
+                        updated_at: Utc::now(),
+                        updated_by: updated_by.to_string(),
+                    });
+            }
+        }
+    }
+
+    fn require_quota_repo(&self) -> Result<&Arc<dyn QuotaRepository>, QuotaError> {
+        self.quota_repo.as_ref().ok_or_else(|| {
+            QuotaError::Repository("quota overrides require a quota repository".to_string())
+        })
+    }
+
+    /// Sum of `size_limit_bytes` over the tenant's non-deleted volumes.
+    async fn allocated_volume_bytes(&self, tenant_id: &TenantId) -> Result<u64, QuotaError> {
+        let Some(repo) = &self.volume_repo else {
+            return Ok(0);
+        };
+        Ok(repo
+            .find_by_tenant(tenant_id.clone())
+            .await?
+            .iter()
+            .filter(|volume| !matches!(volume.status, VolumeStatus::Deleted))
+            .map(|volume| volume.size_limit_bytes)
+            .sum())
+    }
+
+    async fn tokens_this_month(&self, tenant_id: &TenantId) -> Result<Option<u64>, QuotaError> {
+        let Some(repo) = &self.token_usage_repo else {
+            return Ok(None);
+        };
+        let now = Utc::now();
+        let rollup = repo
+            .rollup(&UsageQuery {
+                tenant_id: tenant_id.clone(),
+                group_by: UsageGroupBy::Tenant,
+                from: month_start(now),
+                to: now,
+                agent_id: None,
+                execution_id: None,
+            })
+            .await?;
+        Ok(Some(
+            rollup
+                .iter()
+                .map(|row| row.input_tokens + row.output_tokens)
+                .sum(),
+        ))
     }
 }
 
@@ -197,7 +415,7 @@ mod tests {
 
     use crate::domain::billing::{SubscriptionStatus, TenantSubscription};
     use crate::domain::repository::RepositoryError;
-    use crate::domain::tenancy::{Tenant, TenantStatus};
+    use crate::domain::tenancy::{Tenant, TenantQuotas, TenantStatus};
 
     // ── In-memory fakes ─────────────────────────────────────────────────────
 
@@ -384,6 +602,78 @@ mod tests {
         assert_eq!(TenantQuotas::for_tier(&tier).max_agents, 5);
     }
 
+    /// Stored overrides replace individual tier defaults, and the monthly
+    /// token budget is checked against this month's recorded usage.
+    #[tokio::test]
+    async fn overrides_apply_and_token_budget_is_enforced() {
+        use crate::domain::agent::AgentId;
+        use crate::domain::execution::ExecutionId;
+        use crate::domain::token_usage::TokenUsageRecord;
+        use crate::infrastructure::repositories::{
+            InMemoryAgentRepository, InMemoryExecutionRepository, InMemoryQuotaRepository,
+            InMemoryTokenUsageRepository,
+        };
+
+        let slug = TenantId::for_consumer_user("44444444-2222-3333-4444-555555555555").unwrap();
+        let token_usage = Arc::new(InMemoryTokenUsageRepository::new());
+        let service = TenantQuotaService::new(
+            Arc::new(FakeTenantRepo::with_tenant(mk_tenant(&slug))),
+            Arc::new(FakeBillingRepo::default()),
+            Arc::new(InMemoryAgentRepository::new()),
+            Arc::new(InMemoryExecutionRepository::new()),
+            Arc::new(EventBus::new(16)),
+        )
+        .with_quota_repository(Arc::new(InMemoryQuotaRepository::new()))
+        .with_token_usage_repository(token_usage.clone());
+
+        service.check_token_budget(&slug).await.unwrap();
+        service
+            .set_definition(
+                &slug,
+                QuotaLimits {
+                    max_agents: Some(1),
+                    monthly_token_budget: Some(100),
+                    ..Default::default()
+                },
+                "admin",
+            )
+            .await
+            .unwrap();
+        let quotas = service.effective_quotas(&slug).await.unwrap();
+        assert_eq!(quotas.max_agents, 1);
+        assert_eq!(quotas.max_concurrent_executions, 2);
+
+        token_usage
+            .record(&TokenUsageRecord {
+                execution_id: ExecutionId::new(),
+                tenant_id: slug.clone(),
+                agent_id: AgentId::new(),
+                iteration_number: 1,
+                provider: "openai".to_string(),
+                model: "gpt-4o".to_string(),
+                input_tokens: 80,
+                output_tokens: 30,
+                recorded_at: Utc::now(),
+            })
+            .await
+            .unwrap();
+        assert!(matches!(
+            service.check_token_budget(&slug).await,
+            Err(QuotaError::Exceeded {
+                kind: TenantQuotaKind::MonthlyTokens,
+                current: 110,
+                limit: 100,
+            })
+        ));
+        assert_eq!(
+            service.usage(&slug).await.unwrap().tokens_this_month,
+            Some(110)
+        );
+
+        assert!(service.clear_definition(&slug, "admin").await.unwrap());
+        service.check_token_budget(&slug).await.unwrap();
+    }
+
     /// End-to-end `TenantQuotaService::check_agent_quota` path asserts that
     /// the service resolves the tier via `BillingRepository` rather than
     /// reading a `tier` column on the tenants row. Requires AgentRepository
@@ -415,6 +415,12 @@ impl ToolInvocationService {
                     "Exec timeout ceiling exceeded: requested={requested_secs}s, ceiling={ceiling_secs}s"
                 ),
             ),
+            PolicyViolation::ArgumentNotAllowed { .. }
+            | PolicyViolation::OutsideTimeWindow { .. }
+            | PolicyViolation::InvocationLimitExceeded { .. } => (
+                ViolationType::CapabilityConditionNotMet,
+                violation.to_string(),
+            ),
         }
     }
 }
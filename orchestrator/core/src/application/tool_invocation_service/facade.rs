@@ -1,5 +1,7 @@
 use super::*;
 use crate::domain::iam::TenantScope;
+use crate::domain::security_context::EvaluationContext;
+use crate::infrastructure::seal::stream::SealResponseFrame;
 
 #[allow(clippy::too_many_arguments)]
 impl ToolInvocationService {
@@ -103,9 +105,35 @@ impl ToolInvocationService {
             edge_resolver: None,
             edge_fleet_dispatcher: None,
             edge_fleet_cancel: None,
+            capability_invocations: DashMap::new(),
+            result_cache: None,
         }
     }
 
+    /// Calls already granted to `execution_id`, per capability `tool_pattern`.
+    fn capability_invocations_for(
+        &self,
+        execution_id: crate::domain::execution::ExecutionId,
+    ) -> HashMap<String, u32> {
+        self.capability_invocations
+            .get(&execution_id)
+            .map(|counts| counts.clone())
+            .unwrap_or_default()
+    }
+
+    fn record_capability_invocation(
+        &self,
+        execution_id: crate::domain::execution::ExecutionId,
+        tool_pattern: &str,
+    ) {
+        *self
+            .capability_invocations
+            .entry(execution_id)
+            .or_default()
+            .entry(tool_pattern.to_string())
+            .or_insert(0) += 1;
+    }
+
     /// ADR-117: enable the four-step edge dispatch pre-routing hook.
     pub fn with_edge_router(
         mut self,
@@ -245,6 +273,12 @@ impl ToolInvocationService {
         self
     }
 
+    /// Attach a `ToolResultCache` to reuse results of repeated identical calls.
+    pub fn with_result_cache(mut self, cache: Arc<ToolResultCache>) -> Self {
+        self.result_cache = Some(cache);
+        self
+    }
+
     /// Return the `max_concurrent` limit for `cmd.run` from the first matching
     /// `Capability` in the named `SecurityContext`, if any.
     pub async fn get_cmd_run_max_concurrent(
@@ -364,6 +398,40 @@ impl ToolInvocationService {
         }
     }
 
+    /// [`Self::invoke_tool`], returning the result as signed response frames
+    /// of at most `max_chunk_bytes` each for `/v1/seal/invoke/stream`.
+    ///
+    /// Fails before the tool runs when the node has no stream signer or the
+    /// tool is not flagged `streaming` in the node configuration.
+    pub async fn invoke_tool_streaming(
+        &self,
+        envelope: &(impl EnvelopeVerifier + Send + Sync),
+        max_chunk_bytes: usize,
+    ) -> Result<Vec<SealResponseFrame>, SealSessionError> {
+        let signer = self.seal_middleware.stream_signer().ok_or_else(|| {
+            SealSessionError::ConfigurationError(
+                "streamed SEAL responses are not enabled on this node".to_string(),
+            )
+        })?;
+        let tool_name = envelope
+            .extract_tool_name()
+            .ok_or(SealSessionError::MalformedPayload(
+                "missing tool name".to_string(),
+            ))?;
+        if !self.tool_router.is_streaming_tool(&tool_name).await {
+            return Err(SealSessionError::ConfigurationError(format!(
+                "tool '{tool_name}' is not enabled for streamed responses"
+            )));
+        }
+        let result = self.invoke_tool(envelope).await?;
+        Ok(signer.frame(&result.to_string(), max_chunk_bytes))
+    }
+
+    /// Base64 key agents use to verify streamed response frames, if enabled.
+    pub fn response_signing_key(&self) -> Option<String> {
+        self.seal_middleware.response_signing_key()
+    }
+
     /// Internal orchestrator-driven tool invocation (Gateway pattern).
     /// Resolves the SecurityContext from the execution's `security_context_name`
     /// (ADR-083), then delegates to `dispatch_tool_core`.
@@ -518,37 +586,46 @@ impl ToolInvocationService {
         }
 
         // Enforce SecurityContext constraints (e.g. subcommand_allowlist for cmd.run)
-        if let Err(violation) = security_context.evaluate(&tool_name, &args) {
-            let (violation_type, details) = Self::map_policy_violation(&violation);
-            self.event_bus
-                .publish_mcp_event(MCPToolEvent::PolicyViolation {
+        // and capability conditions, including per-execution invocation limits.
+        let context = EvaluationContext::at(Utc::now())
+            .with_prior_invocations(self.capability_invocations_for(execution_id));
+        let capability = match security_context.evaluate_in(&tool_name, &args, &context) {
+            Ok(capability) => capability,
+            Err(violation) => {
+                let (violation_type, details) = Self::map_policy_violation(&violation);
+                self.event_bus
+                    .publish_mcp_event(MCPToolEvent::PolicyViolation {
+                        execution_id,
+                        agent_id: *agent_id,
+                        tool_name: tool_name.clone(),
+                        violation_type,
+                        details: details.clone(),
+                        blocked_at: Utc::now(),
+                    });
+                // Record the blocked tool name on the iteration so validators can
+                // surface policy violations to the judge agent (ADR-049).
+                if let Err(e) = self
+                    .execution_service
+                    .store_policy_violation(execution_id, tool_name.clone())
+                    .await
+                {
+                    tracing::warn!(
+                        execution_id = %execution_id,
+                        error = %e,
+                        "Failed to record policy violation on iteration"
+                    );
+                }
+                self.publish_invocation_failed(
+                    invocation_id,
                     execution_id,
-                    agent_id: *agent_id,
-                    tool_name: tool_name.clone(),
-                    violation_type,
-                    details: details.clone(),
-                    blocked_at: Utc::now(),
-                });
-            // Record the blocked tool name on the iteration so validators can
-            // surface policy violations to the judge agent (ADR-049).
-            if let Err(e) = self
-                .execution_service
-                .store_policy_violation(execution_id, tool_name.clone())
-                .await
-            {
-                tracing::warn!(
-                    execution_id = %execution_id,
-                    error = %e,
-                    "Failed to record policy violation on iteration"
+                    *agent_id,
+                    format!("Policy violation: {details}"),
                 );
+                return Err(SealSessionError::PolicyViolation(violation));
             }
-            self.publish_invocation_failed(
-                invocation_id,
-                execution_id,
-                *agent_id,
-                format!("Policy violation: {details}"),
-            );
-            return Err(SealSessionError::PolicyViolation(violation));
+        };
+        if capability.limits_invocations() {
+            self.record_capability_invocation(execution_id, &capability.tool_pattern);
         }
 
         // --- Inner-Loop Semantic Pre-Execution Validation (ADR-049) ---
@@ -670,15 +747,75 @@ impl ToolInvocationService {
                                     ))
                                 })?;
 
-                            let poll_interval_ms = JUDGE_POLL_INTERVAL_MS;
-                            let timeout_ms = timeout_seconds.saturating_mul(1000);
-                            let max_attempts = timeout_ms
-                                .saturating_add(poll_interval_ms.saturating_sub(1))
-                                / poll_interval_ms;
-                            let mut attempts = 0;
+                            let exec = self
+                                .execution_service
+                                .wait_for_terminal(
+                                    tenant_id,
+                                    exec_id,
+                                    std::time::Duration::from_secs(*timeout_seconds),
+                                )
+                                .await
+                                .map_err(|e| {
+                                    SealSessionError::InternalError(format!(
+                                        "Failed to get judge execution {exec_id}: {e}"
+                                    ))
+                                })?;
 
-                            loop {
-                                if attempts >= max_attempts {
+                            match exec.status {
+                                crate::domain::execution::ExecutionStatus::Completed => {
+                                    let last_iter = exec.iterations().last().ok_or_else(|| {
+                                        SealSessionError::InternalError(
+                                            "Judge completed but has no iterations".to_string(),
+                                        )
+                                    })?;
+                                    let output_str =
+                                        last_iter.output.as_ref().ok_or_else(|| {
+                                            SealSessionError::InternalError(
+                                                "Judge completed but has no output".to_string(),
+                                            )
+                                        })?;
+
+                                    let json_str = extract_json_from_text(output_str)
+                                        .unwrap_or_else(|| output_str.clone());
+                                    let result: crate::domain::validation::GradientResult =
+                                        serde_json::from_str(&json_str).map_err(|e| {
+                                            SealSessionError::InternalError(format!(
+                                                "Failed to parse judge output: {e}"
+                                            ))
+                                        })?;
+
+                                    if !(result.score >= *min_score
+                                        && result.confidence >= *min_confidence)
+                                    {
+                                        self.publish_invocation_failed(
+                                            invocation_id,
+                                            execution_id,
+                                            *agent_id,
+                                            format!(
+                                            "Inner-loop tool execution rejected by semantic judge \
+                                             (Score: {:.2}, criteria_min: {:.2}). Reasoning: {}",
+                                            result.score, min_score, result.reasoning
+                                        ),
+                                        );
+                                        return Err(SealSessionError::InternalError(format!(
+                                            "Inner-loop tool execution rejected by semantic judge \
+                                             (Score: {:.2}, criteria_min: {:.2}). Reasoning: {}",
+                                            result.score, min_score, result.reasoning,
+                                        )));
+                                    }
+                                }
+                                crate::domain::execution::ExecutionStatus::Failed
+                                | crate::domain::execution::ExecutionStatus::Cancelled => {
+                                    self.publish_invocation_failed(
+                                    invocation_id,
+                                    execution_id,
+                                    *agent_id,
+                                    "Inner-loop semantic judge execution failed or was cancelled"
+                                        .to_string(),
+                                );
+                                    return Err(SealSessionError::InternalError("Inner-loop semantic judge execution failed or was cancelled".to_string()));
+                                }
+                                _ => {
                                     self.publish_invocation_failed(
                                     invocation_id,
                                     execution_id,
@@ -691,82 +828,6 @@ impl ToolInvocationService {
                                         "Inner-loop semantic judge '{judge_agent}' timed out after {timeout_seconds} seconds."
                                     )));
                                 }
-
-                                let exec = self
-                                    .execution_service
-                                    .get_execution_for_tenant(tenant_id, exec_id)
-                                    .await
-                                    .map_err(|e| {
-                                        SealSessionError::InternalError(format!(
-                                            "Failed to get judge execution {exec_id}: {e}"
-                                        ))
-                                    })?;
-
-                                match exec.status {
-                                    crate::domain::execution::ExecutionStatus::Completed => {
-                                        let last_iter =
-                                            exec.iterations().last().ok_or_else(|| {
-                                                SealSessionError::InternalError(
-                                                    "Judge completed but has no iterations"
-                                                        .to_string(),
-                                                )
-                                            })?;
-                                        let output_str =
-                                            last_iter.output.as_ref().ok_or_else(|| {
-                                                SealSessionError::InternalError(
-                                                    "Judge completed but has no output".to_string(),
-                                                )
-                                            })?;
-
-                                        let json_str = extract_json_from_text(output_str)
-                                            .unwrap_or_else(|| output_str.clone());
-                                        let result: crate::domain::validation::GradientResult =
-                                            serde_json::from_str(&json_str).map_err(|e| {
-                                                SealSessionError::InternalError(format!(
-                                                    "Failed to parse judge output: {e}"
-                                                ))
-                                            })?;
-
-                                        if !(result.score >= *min_score
-                                            && result.confidence >= *min_confidence)
-                                        {
-                                            self.publish_invocation_failed(
-                                            invocation_id,
-                                            execution_id,
-                                            *agent_id,
-                                            format!(
-                                                "Inner-loop tool execution rejected by semantic judge \
-                                                 (Score: {:.2}, criteria_min: {:.2}). Reasoning: {}",
-                                                result.score, min_score, result.reasoning
-                                            ),
-                                        );
-                                            return Err(SealSessionError::InternalError(format!(
-                                                "Inner-loop tool execution rejected by semantic judge \
-                                                 (Score: {:.2}, criteria_min: {:.2}). Reasoning: {}",
-                                                result.score, min_score, result.reasoning,
-                                            )));
-                                        }
-                                        break;
-                                    }
-                                    crate::domain::execution::ExecutionStatus::Failed
-                                    | crate::domain::execution::ExecutionStatus::Cancelled => {
-                                        self.publish_invocation_failed(
-                                        invocation_id,
-                                        execution_id,
-                                        *agent_id,
-                                        "Inner-loop semantic judge execution failed or was cancelled"
-                                            .to_string(),
-                                    );
-                                        return Err(SealSessionError::InternalError("Inner-loop semantic judge execution failed or was cancelled".to_string()));
-                                    }
-                                    _ => {
-                                        tokio::time::sleep(std::time::Duration::from_millis(
-                                            poll_interval_ms,
-                                        ))
-                                        .await;
-                                        attempts += 1;
-                                    }
-                                }
                             }
                         }
                     }
@@ -775,6 +836,67 @@ impl ToolInvocationService {
         } // end if let Some(ref agent)
           // --- End Pre-Execution Validation ---
 
+        // Tool result cache (`spec.tool_cache`): identical calls under an
+        // identical policy reuse the earlier result until its TTL expires.
+        let cache_key = self
+            .result_cache
+            .as_ref()
+            .and_then(|cache| cache.key_for(tenant_id, &tool_name, &args, security_context));
+        if let (Some(cache), Some(key)) = (&self.result_cache, &cache_key) {
+            if let Some(value) = cache.get(key) {
+                tracing::debug!(tool_name = %tool_name, "Serving tool result from cache");
+                self.publish_invocation_completed(
+                    invocation_id,
+                    execution_id,
+                    *agent_id,
+                    &value,
+                    started_at,
+                );
+                return Ok(ToolInvocationResult::Direct(value));
+            }
+        }
+
+        let result = self
+            .route_tool_call(
+                invocation_id,
+                started_at,
+                agent_id,
+                execution_id,
+                tenant_scope,
+                security_context,
+                tool_name,
+                args,
+                iteration_number,
+                tool_audit_history,
+                caller_identity,
+            )
+            .await;
+        if let (Some(cache), Some(key), Ok(ToolInvocationResult::Direct(value))) =
+            (&self.result_cache, cache_key, &result)
+        {
+            cache.insert(key, value);
+        }
+        result
+    }
+
+    /// Run a tool call that has passed policy and judge checks through the
+    /// edge / aegis.* / builtin / MCP / SEAL gateway chain.
+    async fn route_tool_call(
+        &self,
+        invocation_id: ToolInvocationId,
+        started_at: Instant,
+        agent_id: &AgentId,
+        execution_id: crate::domain::execution::ExecutionId,
+        tenant_scope: &TenantScope,
+        security_context: &crate::domain::security_context::SecurityContext,
+        tool_name: String,
+        mut args: Value,
+        iteration_number: u8,
+        tool_audit_history: Vec<TrajectoryStep>,
+        caller_identity: Option<&crate::domain::iam::UserIdentity>,
+    ) -> Result<ToolInvocationResult, SealSessionError> {
+        let tenant_id = &tenant_scope.authenticated_tenant;
+
         // Helper closure to publish invocation events based on tool result.
         let publish_result = |result: &Result<ToolInvocationResult, SealSessionError>| match result
         {
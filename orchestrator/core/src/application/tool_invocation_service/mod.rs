@@ -9,6 +9,7 @@ mod discovery;
 mod execute;
 mod facade;
 mod gateway;
+mod result_cache;
 mod runtime;
 mod storage;
 mod summary;
@@ -18,9 +19,13 @@ mod tasks;
 mod tests;
 mod workflows;
 
+pub use result_cache::ToolResultCache;
+
 use anyhow::Result;
 use chrono::Utc;
+use dashmap::DashMap;
 use serde_json::Value;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::Instant;
@@ -65,7 +70,6 @@ use crate::infrastructure::seal_gateway_proto::{
 use crate::infrastructure::tool_router::ToolRouter;
 use crate::infrastructure::workflow_parser::WorkflowParser;
 
-const JUDGE_POLL_INTERVAL_MS: u64 = 500;
 const COMPACT_JSON_INLINE_LIMIT: usize = 256;
 const COMPACT_STRING_PREVIEW_LIMIT: usize = 96;
 const COMPACT_ERROR_PREVIEW_LIMIT: usize = 3;
@@ -144,4 +148,10 @@ pub struct ToolInvocationService {
         Option<Arc<crate::application::edge::fleet::dispatcher::FleetDispatcher>>,
     /// ADR-117: cancel handle for fleet operations.
     edge_fleet_cancel: Option<Arc<crate::application::edge::fleet::CancelFleetService>>,
+    /// Calls granted per execution by capabilities that set
+    /// `max_invocations_per_execution`, keyed by capability `tool_pattern`.
+    /// Only limited capabilities are recorded.
+    capability_invocations: DashMap<crate::domain::execution::ExecutionId, HashMap<String, u32>>,
+    /// Optional result cache for repeated identical tool calls (`spec.tool_cache`).
+    result_cache: Option<Arc<ToolResultCache>>,
 }
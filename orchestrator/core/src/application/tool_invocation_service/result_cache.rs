@@ -0,0 +1,234 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Tool result cache (`spec.tool_cache`).
+//!
+//! Agents often repeat an identical call across iterations (fetching the same
+//! doc, listing the same tickets). When a tool has a TTL configured, its
+//! successful result is reused for identical calls until the TTL expires.
+//!
+//! Entries are keyed by tenant, tool name, normalized arguments and a hash of
+//! the caller's SecurityContext, so a result is only served to callers whose
+//! policy is identical to the one that produced it. The lookup happens after
+//! policy evaluation and the inner-loop judge, so a hit never bypasses either.
+//! Builtins marked `no_cache` in the tool registry are never cached.
+
+use std::time::Instant;
+
+use dashmap::DashMap;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::domain::node_config::ToolCacheConfig;
+use crate::domain::security_context::SecurityContext;
+use crate::domain::tenant::TenantId;
+use crate::infrastructure::tool_router::ToolRouter;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ToolCacheKey {
+    tenant_id: String,
+    tool_name: String,
+    arguments: String,
+    security_context: String,
+}
+
+struct CachedResult {
+    value: Value,
+    expires_at: Instant,
+}
+
+pub struct ToolResultCache {
+    config: ToolCacheConfig,
+    entries: DashMap<ToolCacheKey, CachedResult>,
+}
+
+impl ToolResultCache {
+    pub fn new(config: ToolCacheConfig) -> Self {
+        Self {
+            config,
+            entries: DashMap::new(),
+        }
+    }
+
+    /// Cache key for a call, or `None` when results of `tool_name` must not
+    /// be cached.
+    pub fn key_for(
+        &self,
+        tenant_id: &TenantId,
+        tool_name: &str,
+        args: &Value,
+        security_context: &SecurityContext,
+    ) -> Option<ToolCacheKey> {
+        if !self.config.enabled
+            || ToolRouter::is_no_cache_builtin(tool_name)
+            || self.config.ttl_for(tool_name).is_none()
+        {
+            return None;
+        }
+        Some(ToolCacheKey {
+            tenant_id: tenant_id.as_str().to_string(),
+            tool_name: tool_name.to_string(),
+            arguments: normalize_arguments(args).to_string(),
+            security_context: security_context_hash(security_context),
+        })
+    }
+
+    pub fn get(&self, key: &ToolCacheKey) -> Option<Value> {
+        let entry = self.entries.get(key)?;
+        if entry.expires_at > Instant::now() {
+            return Some(entry.value.clone());
+        }
+        drop(entry);
+        self.entries.remove(key);
+        None
+    }
+
+    /// Store a successful result. Error payloads are not cached.
+    pub fn insert(&self, key: ToolCacheKey, value: &Value) {
+        let Some(ttl) = self.config.ttl_for(&key.tool_name) else {
+            return;
+        };
+        if is_error_result(value) {
+            return;
+        }
+        if self.entries.len() >= self.config.max_entries && !self.entries.contains_key(&key) {
+            self.make_room();
+        }
+        self.entries.insert(
+            key,
+            CachedResult {
+                value: value.clone(),
+                expires_at: Instant::now() + ttl,
+            },
+        );
+    }
+
+    /// Drop expired entries; if the cache is still full, drop the entry
+    /// closest to expiry.
+    fn make_room(&self) {
+        let now = Instant::now();
+        self.entries.retain(|_, entry| entry.expires_at > now);
+        if self.entries.len() < self.config.max_entries {
+            return;
+        }
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|entry| entry.expires_at)
+            .map(|entry| entry.key().clone());
+        if let Some(key) = oldest {
+            self.entries.remove(&key);
+        }
+    }
+}
+
+/// Arguments with object keys sorted, so calls that differ only in key order
+/// share an entry.
+fn normalize_arguments(args: &Value) -> Value {
+    match args {
+        Value::Object(map) => {
+            let mut keys: Vec<&String> = map.keys().collect();
+            keys.sort();
+            Value::Object(
+                keys.into_iter()
+                    .map(|key| (key.clone(), normalize_arguments(&map[key])))
+                    .collect(),
+            )
+        }
+        Value::Array(items) => Value::Array(items.iter().map(normalize_arguments).collect()),
+        other => other.clone(),
+    }
+}
+
+fn security_context_hash(security_context: &SecurityContext) -> String {
+    let serialized = serde_json::to_vec(security_context).unwrap_or_default();
+    hex::encode(Sha256::digest(serialized))
+}
+
+fn is_error_result(value: &Value) -> bool {
+    value.get("error").is_some()
+        || matches!(
+            value.get("status").and_then(Value::as_str),
+            Some("error" | "failed")
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::node_config::ToolCacheRule;
+    use crate::domain::security_context::SecurityContextMetadata;
+    use std::collections::HashMap;
+
+    fn cache() -> ToolResultCache {
+        ToolResultCache::new(ToolCacheConfig {
+            enabled: true,
+            default_ttl_seconds: 0,
+            max_entries: 2,
+            tools: HashMap::from([
+                (
+                    "docs.fetch".to_string(),
+                    ToolCacheRule {
+                        ttl_seconds: Some(60),
+                        no_cache: false,
+                    },
+                ),
+                (
+                    "fs.read".to_string(),
+                    ToolCacheRule {
+                        ttl_seconds: Some(60),
+                        no_cache: false,
+                    },
+                ),
+            ]),
+        })
+    }
+
+    #[test]
+    fn caches_configured_tools_per_policy() {
+        let cache = cache();
+        let tenant = TenantId::new("acme").unwrap();
+        let context = SecurityContext {
+            name: "research".to_string(),
+            description: String::new(),
+            capabilities: Vec::new(),
+            deny_list: Vec::new(),
+            metadata: SecurityContextMetadata {
+                created_at: chrono::Utc::now(),
+                updated_at: chrono::Utc::now(),
+                version: 1,
+            },
+        };
+        let args = serde_json::json!({"url": "https://docs.example", "format": "md"});
+        let reordered = serde_json::json!({"format": "md", "url": "https://docs.example"});
+
+        let key = cache
+            .key_for(&tenant, "docs.fetch", &args, &context)
+            .unwrap();
+        cache.insert(key, &serde_json::json!({"content": "hello"}));
+        let same = cache
+            .key_for(&tenant, "docs.fetch", &reordered, &context)
+            .unwrap();
+        assert_eq!(
+            cache.get(&same),
+            Some(serde_json::json!({"content": "hello"}))
+        );
+
+        // A different policy gets its own entry.
+        let mut narrower = context.clone();
+        narrower.deny_list.push("cmd.run".to_string());
+        let other = cache
+            .key_for(&tenant, "docs.fetch", &args, &narrower)
+            .unwrap();
+        assert_eq!(cache.get(&other), None);
+
+        // Unlisted tools and no_cache builtins are never keyed.
+        assert!(cache
+            .key_for(&tenant, "docs.search", &args, &context)
+            .is_none());
+        assert!(cache.key_for(&tenant, "fs.read", &args, &context).is_none());
+
+        // Error payloads are not stored.
+        cache.insert(other.clone(), &serde_json::json!({"status": "error"}));
+        assert_eq!(cache.get(&other), None);
+    }
+}
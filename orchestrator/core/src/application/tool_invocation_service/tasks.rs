@@ -166,8 +166,8 @@ impl ToolInvocationService {
         }
     }
 
-    /// Blocking poll tool — waits for an execution to reach a terminal state.
-    /// Polls every `poll_interval_seconds` (default 10s) up to `timeout_seconds` (default 300s).
+    /// Blocking wait tool — waits for an execution to reach a terminal state.
+    /// Waits for the execution's terminal event up to `timeout_seconds` (default 600s).
     /// Returns the final execution status, output, and error (if any).
     pub(super) async fn invoke_aegis_task_wait_tool(
         &self,
@@ -193,69 +193,50 @@ impl ToolInvocationService {
                 .map_err(|e| SealSessionError::InvalidArguments(format!("Invalid UUID: {e}")))?,
         );
 
-        let poll_interval = args
-            .get("poll_interval_seconds")
-            .and_then(|v| v.as_u64())
-            .unwrap_or(10);
+        // `poll_interval_seconds` is still accepted for compatibility but is
+        // ignored: the wait wakes on the execution's terminal event.
         let timeout = args
             .get("timeout_seconds")
             .and_then(|v| v.as_u64())
             .unwrap_or(600);
 
-        let poll_duration = std::time::Duration::from_secs(poll_interval);
-        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(timeout);
+        match self
+            .execution_service
+            .wait_for_terminal(&tenant_id, exec_id, std::time::Duration::from_secs(timeout))
+            .await
+        {
+            Ok(exec) => {
+                let status_str = format!("{:?}", exec.status).to_lowercase();
 
-        loop {
-            match self
-                .execution_service
-                .get_execution_for_tenant(&tenant_id, exec_id)
-                .await
-            {
-                Ok(exec) => {
-                    let status_str = format!("{:?}", exec.status).to_lowercase();
-                    let is_terminal = matches!(
-                        exec.status,
-                        crate::domain::execution::ExecutionStatus::Completed
-                            | crate::domain::execution::ExecutionStatus::Failed
-                            | crate::domain::execution::ExecutionStatus::Cancelled
-                    );
-
-                    if is_terminal {
-                        let last_iter = exec.iterations().last();
-                        return Ok(ToolInvocationResult::Direct(serde_json::json!({
-                            "tool": "aegis.task.wait",
-                            "execution_id": exec_id_str,
-                            "agent_id": exec.agent_id.0.to_string(),
-                            "status": status_str,
-                            "started_at": exec.started_at,
-                            "ended_at": exec.ended_at,
-                            "iteration_count": exec.iterations().len(),
-                            "last_output": last_iter.and_then(|i| i.output.as_ref()),
-                            "last_error": last_iter.and_then(|i| i.error.as_ref().map(|e| format!("{e:?}")))
-                        })));
-                    }
-
-                    if std::time::Instant::now() >= deadline {
-                        return Ok(ToolInvocationResult::Direct(serde_json::json!({
-                            "tool": "aegis.task.wait",
-                            "execution_id": exec_id_str,
-                            "status": status_str,
-                            "timed_out": true,
-                            "message": format!("Execution still {} after {}s timeout", status_str, timeout),
-                            "iteration_count": exec.iterations().len()
-                        })));
-                    }
-
-                    tokio::time::sleep(poll_duration).await;
-                }
-                Err(e) => {
+                if exec.status.is_terminal() {
+                    let last_iter = exec.iterations().last();
                     return Ok(ToolInvocationResult::Direct(serde_json::json!({
                         "tool": "aegis.task.wait",
                         "execution_id": exec_id_str,
-                        "error": format!("Failed to get execution: {e}")
+                        "agent_id": exec.agent_id.0.to_string(),
+                        "status": status_str,
+                        "started_at": exec.started_at,
+                        "ended_at": exec.ended_at,
+                        "iteration_count": exec.iterations().len(),
+                        "last_output": last_iter.and_then(|i| i.output.as_ref()),
+                        "last_error": last_iter.and_then(|i| i.error.as_ref().map(|e| format!("{e:?}")))
                     })));
                 }
+
+                Ok(ToolInvocationResult::Direct(serde_json::json!({
+                    "tool": "aegis.task.wait",
+                    "execution_id": exec_id_str,
+                    "status": status_str,
+                    "timed_out": true,
+                    "message": format!("Execution still {} after {}s timeout", status_str, timeout),
+                    "iteration_count": exec.iterations().len()
+                })))
             }
+            Err(e) => Ok(ToolInvocationResult::Direct(serde_json::json!({
+                "tool": "aegis.task.wait",
+                "execution_id": exec_id_str,
+                "error": format!("Failed to get execution: {e}")
+            }))),
         }
     }
 
@@ -634,6 +634,15 @@ impl WorkflowExecutionRepository for StubWorkflowExecutionRepository {
         Ok(vec![])
     }
 
+    async fn find_active_all(
+        &self,
+    ) -> Result<
+        Vec<crate::domain::workflow::WorkflowExecution>,
+        crate::domain::repository::RepositoryError,
+    > {
+        Ok(vec![])
+    }
+
     async fn find_by_workflow_for_tenant(
         &self,
         _tenant_id: &TenantId,
@@ -726,6 +735,35 @@ impl WorkflowExecutionRepository for StubWorkflowExecutionRepository {
             .unwrap_or_default();
         Ok(events.into_iter().skip(offset).take(limit).collect())
     }
+
+    async fn append_transition(
+        &self,
+        _execution_id: ExecutionId,
+        _record: &crate::domain::workflow::WorkflowTransitionRecord,
+    ) -> Result<(), crate::domain::repository::RepositoryError> {
+        Ok(())
+    }
+
+    async fn close_transition(
+        &self,
+        _execution_id: ExecutionId,
+        _state_name: &str,
+        _exited_at: chrono::DateTime<chrono::Utc>,
+        _output_summary: Option<String>,
+    ) -> Result<(), crate::domain::repository::RepositoryError> {
+        Ok(())
+    }
+
+    async fn find_transitions_for_tenant(
+        &self,
+        _tenant_id: &TenantId,
+        _id: ExecutionId,
+    ) -> Result<
+        Vec<crate::domain::workflow::WorkflowTransitionRecord>,
+        crate::domain::repository::RepositoryError,
+    > {
+        Ok(vec![])
+    }
 }
 
 #[derive(Default)]
@@ -1432,6 +1470,7 @@ async fn workflow_run_tool_forwards_blackboard() {
             max_response_size: None,
             rate_limit: None,
             max_concurrent: None,
+            conditions: None,
         }],
         deny_list: vec![],
         metadata: crate::domain::security_context::SecurityContextMetadata {
@@ -1761,6 +1800,7 @@ async fn test_invoke_tool_execution_modes() {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             },
             Capability {
                 tool_pattern: "test_tool_remote".to_string(),
@@ -1771,6 +1811,7 @@ async fn test_invoke_tool_execution_modes() {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             },
         ],
         deny_list: vec![],
@@ -1822,6 +1863,7 @@ async fn test_invoke_tool_execution_modes() {
         args: vec![],
         capabilities: vec!["test_tool".to_string()],
         skip_judge_tools: std::collections::HashSet::new(),
+        streaming_tools: std::collections::HashSet::new(),
         status: ToolServerStatus::Running,
         process_id: None,
         health_check_interval: std::time::Duration::from_secs(30),
@@ -1855,6 +1897,7 @@ async fn test_invoke_tool_execution_modes() {
         args: vec![],
         capabilities: vec!["test_tool_remote".to_string()],
         skip_judge_tools: std::collections::HashSet::new(),
+        streaming_tools: std::collections::HashSet::new(),
         status: ToolServerStatus::Running,
         process_id: None,
         health_check_interval: std::time::Duration::from_secs(30),
@@ -1924,6 +1967,7 @@ async fn get_available_tools_returns_builtin_dispatcher_metadata() {
             capabilities: vec![CapabilityConfig {
                 name: "fs.read".to_string(),
                 skip_judge: true,
+                streaming: false,
             }],
             api_key: None,
         }],
@@ -1972,6 +2016,7 @@ async fn get_available_tools_for_context_filters_disallowed_tools() {
                 capabilities: vec![CapabilityConfig {
                     name: "fs.read".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -1982,6 +2027,7 @@ async fn get_available_tools_for_context_filters_disallowed_tools() {
                 capabilities: vec![CapabilityConfig {
                     name: "cmd.run".to_string(),
                     skip_judge: false,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2003,6 +2049,7 @@ async fn get_available_tools_for_context_filters_disallowed_tools() {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             }],
             deny_list: vec!["cmd.run".to_string()],
             metadata: crate::domain::security_context::SecurityContextMetadata {
@@ -2053,6 +2100,7 @@ async fn get_available_tools_for_context_hides_destructive_workflow_tools_for_lo
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.workflow.status".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2063,6 +2111,7 @@ async fn get_available_tools_for_context_hides_destructive_workflow_tools_for_lo
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.workflow.delete".to_string(),
                     skip_judge: false,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2084,6 +2133,7 @@ async fn get_available_tools_for_context_hides_destructive_workflow_tools_for_lo
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             }],
             deny_list: vec!["aegis.workflow.delete".to_string()],
             metadata: crate::domain::security_context::SecurityContextMetadata {
@@ -2141,6 +2191,7 @@ async fn invoke_tool_internal_blocks_destructive_workflow_tools_for_low_trust_ti
             max_response_size: None,
             rate_limit: None,
             max_concurrent: None,
+            conditions: None,
         }],
         deny_list: vec!["aegis.workflow.delete".to_string()],
         metadata: crate::domain::security_context::SecurityContextMetadata {
@@ -2229,6 +2280,7 @@ async fn get_available_tools_for_agent_filters_to_declared_manifest_tools() {
                 capabilities: vec![CapabilityConfig {
                     name: "fs.read".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2239,6 +2291,7 @@ async fn get_available_tools_for_agent_filters_to_declared_manifest_tools() {
                 capabilities: vec![CapabilityConfig {
                     name: "cmd.run".to_string(),
                     skip_judge: false,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2620,6 +2673,7 @@ async fn workflow_run_with_version_passes_version_through() {
             max_response_size: None,
             rate_limit: None,
             max_concurrent: None,
+            conditions: None,
         }],
         deny_list: vec![],
         metadata: crate::domain::security_context::SecurityContextMetadata {
@@ -3076,6 +3130,7 @@ async fn tool_invocation_propagates_initiating_user_sub_to_child_execution() {
             max_response_size: None,
             rate_limit: None,
             max_concurrent: None,
+            conditions: None,
         }],
         deny_list: vec![],
         metadata: crate::domain::security_context::SecurityContextMetadata {
@@ -3179,6 +3234,7 @@ async fn list_tools_does_not_duplicate_when_dispatchers_present() {
         capabilities: vec![CapabilityConfig {
             name: "aegis.workflow.wait".to_string(),
             skip_judge: true,
+            streaming: false,
         }],
         api_key: None,
     }];
@@ -3359,6 +3415,7 @@ mod gateway_timeout_regression {
                 capabilities: vec![CapabilityConfig {
                     name: "fs.read".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             }],
@@ -64,6 +64,7 @@ use crate::application::agent::AgentLifecycleService;
 use crate::application::execution::ExecutionService;
 use crate::domain::agent::{AgentId, ValidatorSpec};
 use crate::domain::execution::{ExecutionId, ExecutionInput, ExecutionStatus};
+use crate::domain::plugin::{PluginDigest, PluginHost, PluginValidationInput};
 use crate::domain::shared_kernel::TenantId;
 use crate::domain::validation::{
     extract_json_from_text, GradientResult, GradientValidator, MultiJudgeConsensus,
@@ -900,6 +901,59 @@ impl GradientValidator for MultiJudgeAgentValidator {
     }
 }
 
+// ── Plugin validator ──────────────────────────────────────────────────────────
+
+/// Scores an iteration with a WASM plugin's `validate` export (`type: plugin`).
+///
+/// Fails the pipeline when the node has no plugin host, so a manifest that
+/// asks for a plugin is never silently left unvalidated.
+pub struct PluginGradientValidator {
+    plugin: PluginDigest,
+    host: Option<Arc<dyn PluginHost>>,
+}
+
+impl PluginGradientValidator {
+    pub fn new(plugin: PluginDigest, host: Option<Arc<dyn PluginHost>>) -> Self {
+        Self { plugin, host }
+    }
+}
+
+#[async_trait::async_trait]
+impl GradientValidator for PluginGradientValidator {
+    async fn validate(&self, ctx: &ValidationContext) -> Result<GradientResult> {
+        let host = self.host.as_ref().ok_or_else(|| {
+            anyhow!(
+                "Validator plugin {} requires spec.plugins on this node",
+                self.plugin
+            )
+        })?;
+        let verdict = host
+            .validate(
+                &self.plugin,
+                &PluginValidationInput {
+                    task: ctx.task.clone(),
+                    output: ctx.output.clone(),
+                    exit_code: ctx.exit_code,
+                    stderr: ctx.stderr.clone(),
+                },
+            )
+            .await?;
+
+        let mut metadata = std::collections::HashMap::new();
+        metadata.insert(
+            "plugin".to_string(),
+            serde_json::json!(self.plugin.as_str()),
+        );
+        Ok(GradientResult {
+            score: verdict.score,
+            confidence: verdict.confidence,
+            reasoning: verdict.reasoning,
+            signals: vec![],
+            metadata,
+        })
+    }
+}
+
 // ── Pipeline factory ──────────────────────────────────────────────────────────
 
 /// Build a [`ValidationPipeline`] from an agent manifest's `validation` list
@@ -908,57 +962,28 @@ impl GradientValidator for MultiJudgeAgentValidator {
 /// Each spec produces one [`ValidatorEntry`] with its own `min_score` /
 /// `min_confidence`.  `Semantic` and `MultiJudge` entries spawn judge agents as
 /// child executions (ADR-016); no LLM is called directly from the orchestrator host.
+/// `Plugin` entries run on `plugin_host`.
 pub fn build_validation_pipeline(
     validators: &[ValidatorSpec],
     agent_lifecycle_service: Arc<dyn AgentLifecycleService>,
     execution_service: Arc<dyn ExecutionService>,
     event_bus: Arc<crate::infrastructure::event_bus::EventBus>,
+    plugin_host: Option<Arc<dyn PluginHost>>,
     parent_execution_id: ExecutionId,
     tenant_id: TenantId,
 ) -> ValidationPipeline {
     let mut entries: Vec<ValidatorEntry> = Vec::new();
 
     for spec in validators {
+        if let Some(entry) = local_validator_entry(spec) {
+            entries.push(entry);
+            continue;
+        }
         match spec {
-            ValidatorSpec::ExitCode {
-                expected: _,
-                min_score,
-            } => {
-                entries.push(ValidatorEntry {
-                    kind: ValidatorKind::System,
-                    validator: Box::new(SystemGradientValidator::new(true, false)),
-                    min_score: *min_score,
-                    min_confidence: 0.0,
-                });
-            }
-            ValidatorSpec::JsonSchema { schema, min_score } => {
-                entries.push(ValidatorEntry {
-                    kind: ValidatorKind::Output,
-                    validator: Box::new(OutputGradientValidator::new(
-                        "json".to_string(),
-                        Some(schema.clone()),
-                        None,
-                    )),
-                    min_score: *min_score,
-                    min_confidence: 0.0,
-                });
-            }
-            ValidatorSpec::Regex {
-                pattern,
-                target,
-                min_score,
-            } => {
-                entries.push(ValidatorEntry {
-                    kind: ValidatorKind::Output,
-                    validator: Box::new(OutputGradientValidator::new(
-                        target.clone(),
-                        None,
-                        Some(pattern.clone()),
-                    )),
-                    min_score: *min_score,
-                    min_confidence: 0.0,
-                });
-            }
+            // Built by `local_validator_entry` above.
+            ValidatorSpec::ExitCode { .. }
+            | ValidatorSpec::JsonSchema { .. }
+            | ValidatorSpec::Regex { .. } => {}
             ValidatorSpec::Semantic {
                 judge_agent,
                 criteria,
@@ -1022,12 +1047,87 @@ pub fn build_validation_pipeline(
                     min_confidence: *min_confidence,
                 });
             }
+            ValidatorSpec::Plugin {
+                plugin,
+                min_score,
+                min_confidence,
+            } => {
+                entries.push(ValidatorEntry {
+                    kind: ValidatorKind::Output,
+                    validator: Box::new(PluginGradientValidator::new(
+                        plugin.clone(),
+                        plugin_host.clone(),
+                    )),
+                    min_score: *min_score,
+                    min_confidence: *min_confidence,
+                });
+            }
         }
     }
 
     ValidationPipeline::new(entries)
 }
 
+/// Build a [`ValidationPipeline`] from the validators that run in-process
+/// (`exit_code`, `json_schema`, `regex`), for callers without an orchestrator
+/// to spawn judge agents on. Returns the pipeline and the `Semantic` /
+/// `MultiJudge` / `Plugin` specs that were left out.
+pub fn build_local_validation_pipeline(
+    validators: &[ValidatorSpec],
+) -> (ValidationPipeline, Vec<&ValidatorSpec>) {
+    let mut entries = Vec::new();
+    let mut skipped = Vec::new();
+    for spec in validators {
+        match local_validator_entry(spec) {
+            Some(entry) => entries.push(entry),
+            None => skipped.push(spec),
+        }
+    }
+    (ValidationPipeline::new(entries), skipped)
+}
+
+/// The [`ValidatorEntry`] for a spec that needs no judge agent.
+fn local_validator_entry(spec: &ValidatorSpec) -> Option<ValidatorEntry> {
+    match spec {
+        ValidatorSpec::ExitCode {
+            expected: _,
+            min_score,
+        } => Some(ValidatorEntry {
+            kind: ValidatorKind::System,
+            validator: Box::new(SystemGradientValidator::new(true, false)),
+            min_score: *min_score,
+            min_confidence: 0.0,
+        }),
+        ValidatorSpec::JsonSchema { schema, min_score } => Some(ValidatorEntry {
+            kind: ValidatorKind::Output,
+            validator: Box::new(OutputGradientValidator::new(
+                "json".to_string(),
+                Some(schema.clone()),
+                None,
+            )),
+            min_score: *min_score,
+            min_confidence: 0.0,
+        }),
+        ValidatorSpec::Regex {
+            pattern,
+            target,
+            min_score,
+        } => Some(ValidatorEntry {
+            kind: ValidatorKind::Output,
+            validator: Box::new(OutputGradientValidator::new(
+                target.clone(),
+                None,
+                Some(pattern.clone()),
+            )),
+            min_score: *min_score,
+            min_confidence: 0.0,
+        }),
+        ValidatorSpec::Semantic { .. }
+        | ValidatorSpec::MultiJudge { .. }
+        | ValidatorSpec::Plugin { .. } => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::calculate_max_attempts;
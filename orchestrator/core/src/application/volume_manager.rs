@@ -28,6 +28,7 @@
 //! See ADR-032 (Unified Storage via SeaweedFS), ADR-036 (NFS Gateway),
 //! AGENTS.md §BC-7 Storage Gateway.
 
+use crate::application::tenant_quota::TenantQuotaService;
 use crate::domain::agent::VolumeSpec;
 use crate::domain::events::VolumeEvent;
 use crate::domain::execution::ExecutionId;
@@ -43,6 +44,7 @@ use crate::infrastructure::event_bus::EventBus;
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use chrono::Utc;
+use futures::stream::{self, StreamExt};
 use std::path::PathBuf;
 use std::sync::Arc;
 use tracing::{debug, error, info, warn};
@@ -156,7 +158,11 @@ pub trait VolumeService: Send + Sync {
     /// Provision volumes declared in an agent manifest for a new execution.
     ///
     /// Parses the `spec.volumes` field, creates each volume according to its
-    /// spec, and returns the full `Volume` list with resolved host paths.
+    /// spec, and returns the full `Volume` list (in spec order) with resolved
+    /// host paths. Volumes are created concurrently with bounded parallelism.
+    ///
+    /// Creation is all-or-nothing: if any spec fails, volumes already created
+    /// for the call are rolled back and the error names every failed spec.
     ///
     /// # Arguments
     ///
@@ -195,12 +201,18 @@ pub trait VolumeService: Send + Sync {
 // Standard Implementation
 // ============================================================================
 
+/// Upper bound on volumes provisioned at once for a single execution, so a
+/// manifest with many volumes cannot flood the storage backend.
+const MAX_CONCURRENT_VOLUME_CREATIONS: usize = 4;
+
 pub struct StandardVolumeService {
     repository: Arc<dyn VolumeRepository>,
     storage_provider: Arc<dyn StorageProvider>,
     event_bus: Arc<EventBus>,
     filer_endpoint: FilerEndpoint,
     storage_mode: String,
+    /// Optional quota enforcement service (ADR-056). Checks allocated volume bytes.
+    quota_service: Option<Arc<TenantQuotaService>>,
 }
 
 impl StandardVolumeService {
@@ -219,8 +231,189 @@ impl StandardVolumeService {
             event_bus,
             filer_endpoint,
             storage_mode: storage_mode.into(),
+            quota_service: None,
         })
     }
+
+    /// Wire the quota enforcement service.
+    pub fn with_quota_service(mut self, quota_service: Arc<TenantQuotaService>) -> Self {
+        self.quota_service = Some(quota_service);
+        self
+    }
+
+    /// Create the volume for one manifest `VolumeSpec`.
+    ///
+    /// Returns the volume and whether its storage was provisioned here, as
+    /// opposed to pointing at pre-existing external storage (`opendal`,
+    /// `hostPath`, `seal`) that rollback must leave alone.
+    async fn create_volume_for_spec(
+        &self,
+        execution_id: ExecutionId,
+        tenant_id: &TenantId,
+        spec: &VolumeSpec,
+        storage_mode: &str,
+    ) -> Result<(Volume, bool)> {
+        // Parse size limit from string (e.g., "1Gi", "500Mi")
+        let size_limit_bytes = parse_size_string(&spec.size_limit).context(format!(
+            "Invalid size_limit '{}' in volume spec '{}'",
+            spec.size_limit, spec.name
+        ))?;
+
+        // Parse storage class
+        let storage_class = match spec.storage_class.as_str() {
+            "ephemeral" => {
+                let ttl_hours = spec.ttl_hours.unwrap_or(24) as i64;
+                StorageClass::ephemeral_hours(ttl_hours)
+            }
+            "persistent" => StorageClass::persistent(),
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Invalid storage_class '{}' in volume spec '{}'. Expected 'ephemeral' or 'persistent'",
+                    other,
+                    spec.name
+                ));
+            }
+        };
+
+        // Create volume ownership tied to execution
+        let ownership = VolumeOwnership::execution(execution_id);
+
+        // Attempt to create volume
+        let (volume_id, owns_storage) = match spec.volume_type.as_str() {
+            "seaweedfs" | "local_host" | "opendal_memory" => {
+                // Handle SeaweedFS / Local / Default standard workflow.
+                match self
+                    .create_volume(
+                        spec.name.clone(),
+                        tenant_id.clone(),
+                        storage_class.clone(),
+                        size_limit_bytes / (1024 * 1024), // Convert bytes to MB
+                        ownership.clone(),
+                    )
+                    .await
+                {
+                    Ok(id) => (id, true),
+                    Err(e) => {
+                        return Err(anyhow::anyhow!(
+                            "Volume creation failed for '{}' (storage_mode='{}'): {}",
+                            spec.name,
+                            storage_mode,
+                            e
+                        ));
+                    }
+                }
+            }
+            "opendal" | "hostPath" | "seal" => {
+                // Custom non-standard volume types.
+                // Construct backend directly and persist without the standard SeaweedFS routine.
+                let backend = match spec.volume_type.as_str() {
+                    "opendal" => VolumeBackend::OpenDal {
+                        provider: spec.provider.clone().unwrap_or_else(|| "s3".to_string()),
+                        config: spec.config.clone(),
+                        cache_path: None,
+                    },
+                    "hostPath" => VolumeBackend::HostPath {
+                        path: PathBuf::from(
+                            spec.config
+                                .as_ref()
+                                .and_then(|c| c.get("path"))
+                                .and_then(|v| v.as_str())
+                                .map(std::borrow::ToOwned::to_owned)
+                                .unwrap_or_else(|| {
+                                    std::env::temp_dir()
+                                        .join("aegis")
+                                        .to_string_lossy()
+                                        .into_owned()
+                                }),
+                        ),
+                    },
+                    "seal" => VolumeBackend::Seal {
+                        node_id: spec
+                            .config
+                            .as_ref()
+                            .and_then(|c| c.get("node_id"))
+                            .and_then(|v| v.as_str())
+                            .unwrap_or("unknown")
+                            .to_string(),
+                        remote_volume_id: spec
+                            .config
+                            .as_ref()
+                            .and_then(|c| c.get("volume_id"))
+                            .and_then(|v| v.as_str())
+                            .and_then(|v| VolumeId::from_string(v).ok())
+                            .unwrap_or_else(VolumeId::new),
+                    },
+                    _ => {
+                        return Err(anyhow::anyhow!(
+                            "Invalid custom volume type '{}'",
+                            spec.volume_type
+                        ));
+                    }
+                };
+
+                let volume_id = VolumeId::new();
+                let volume = Volume {
+                    id: volume_id,
+                    name: spec.name.clone(),
+                    tenant_id: tenant_id.clone(),
+                    storage_class: storage_class.clone(),
+                    backend,
+                    size_limit_bytes,
+                    status: crate::domain::volume::VolumeStatus::Available,
+                    ownership: ownership.clone(),
+                    created_at: Utc::now(),
+                    attached_at: None,
+                    detached_at: None,
+                    expires_at: storage_class.calculate_expiry(Utc::now()),
+                    host_node_id: None,
+                };
+
+                self.repository
+                    .save(&volume)
+                    .await
+                    .context("Failed to save custom volume to repository")?;
+
+                (volume_id, false)
+            }
+            other => {
+                return Err(anyhow::anyhow!(
+                    "Invalid volume_type '{other}'. Expected 'seaweedfs', 'opendal', 'hostPath', 'seal'"
+                ));
+            }
+        };
+
+        // Fetch created volume
+        let volume = self.get_volume(volume_id).await?;
+
+        info!(
+            "Volume '{}' created successfully (id: {}, size: {} bytes)",
+            spec.name, volume_id, size_limit_bytes
+        );
+
+        Ok((volume, owns_storage))
+    }
+
+    /// Undo a volume created by a `create_volumes_for_execution` call that
+    /// failed as a whole. Failures are logged; the provisioning error wins.
+    async fn roll_back_volume(&self, mut volume: Volume, owns_storage: bool) {
+        let result = if owns_storage {
+            self.delete_volume(volume.id).await
+        } else {
+            // Only the record is ours; never delete a user's external storage.
+            match volume.mark_deleting().and_then(|()| volume.mark_deleted()) {
+                Ok(()) => self
+                    .repository
+                    .save(&volume)
+                    .await
+                    .map_err(anyhow::Error::new),
+                Err(e) => Err(e.into()),
+            }
+        };
+        match result {
+            Ok(()) => debug!("Rolled back volume '{}' ({})", volume.name, volume.id),
+            Err(e) => warn!("Failed to roll back volume {}: {:#}", volume.id, e),
+        }
+    }
 }
 
 #[async_trait]
@@ -238,6 +431,14 @@ impl VolumeService for StandardVolumeService {
             name, tenant_id, storage_class, size_limit_mb
         );
 
+        // Quota check: the new allocation must fit the tenant's volume bytes (ADR-056).
+        if let Some(quota_svc) = &self.quota_service {
+            quota_svc
+                .check_volume_quota(&tenant_id, size_limit_mb * 1024 * 1024)
+                .await
+                .map_err(|e| anyhow::anyhow!("quota_exceeded:{}", e))?;
+        }
+
         // Construct storage-relative path: /aegis/volumes/{tenant_id}/{volume_id}
         let volume_id = VolumeId::new();
         let remote_path = format!("/aegis/volumes/{tenant_id}/{volume_id}");
@@ -623,148 +824,46 @@ impl VolumeService for StandardVolumeService {
             storage_mode
         );
 
-        let mut volumes = Vec::new();
-
-        for spec in volume_specs {
-            // Parse size limit from string (e.g., "1Gi", "500Mi")
-            let size_limit_bytes = parse_size_string(&spec.size_limit).context(format!(
-                "Invalid size_limit '{}' in volume spec '{}'",
-                spec.size_limit, spec.name
-            ))?;
-
-            // Parse storage class
-            let storage_class = match spec.storage_class.as_str() {
-                "ephemeral" => {
-                    let ttl_hours = spec.ttl_hours.unwrap_or(24) as i64;
-                    StorageClass::ephemeral_hours(ttl_hours)
-                }
-                "persistent" => StorageClass::persistent(),
-                other => {
-                    return Err(anyhow::anyhow!(
-                        "Invalid storage_class '{}' in volume spec '{}'. Expected 'ephemeral' or 'persistent'",
-                        other,
-                        spec.name
-                    ));
-                }
-            };
-
-            // Create volume ownership tied to execution
-            let ownership = VolumeOwnership::execution(execution_id);
-
-            // Attempt to create volume
-            let volume_id = match spec.volume_type.as_str() {
-                "seaweedfs" | "local_host" | "opendal_memory" => {
-                    // Handle SeaweedFS / Local / Default standard workflow.
-                    match self
-                        .create_volume(
-                            spec.name.clone(),
-                            tenant_id.clone(),
-                            storage_class.clone(),
-                            size_limit_bytes / (1024 * 1024), // Convert bytes to MB
-                            ownership.clone(),
-                        )
-                        .await
-                    {
-                        Ok(id) => id,
-                        Err(e) => {
-                            return Err(anyhow::anyhow!(
-                                "Volume creation failed for '{}' (storage_mode='{}'): {}",
-                                spec.name,
-                                storage_mode,
-                                e
-                            ));
-                        }
-                    }
-                }
-                "opendal" | "hostPath" | "seal" => {
-                    // Custom non-standard volume types.
-                    // Construct backend directly and persist without the standard SeaweedFS routine.
-                    let backend = match spec.volume_type.as_str() {
-                        "opendal" => VolumeBackend::OpenDal {
-                            provider: spec.provider.clone().unwrap_or_else(|| "s3".to_string()),
-                            config: spec.config.clone(),
-                            cache_path: None,
-                        },
-                        "hostPath" => VolumeBackend::HostPath {
-                            path: PathBuf::from(
-                                spec.config
-                                    .as_ref()
-                                    .and_then(|c| c.get("path"))
-                                    .and_then(|v| v.as_str())
-                                    .map(std::borrow::ToOwned::to_owned)
-                                    .unwrap_or_else(|| {
-                                        std::env::temp_dir()
-                                            .join("aegis")
-                                            .to_string_lossy()
-                                            .into_owned()
-                                    }),
-                            ),
-                        },
-                        "seal" => VolumeBackend::Seal {
-                            node_id: spec
-                                .config
-                                .as_ref()
-                                .and_then(|c| c.get("node_id"))
-                                .and_then(|v| v.as_str())
-                                .unwrap_or("unknown")
-                                .to_string(),
-                            remote_volume_id: spec
-                                .config
-                                .as_ref()
-                                .and_then(|c| c.get("volume_id"))
-                                .and_then(|v| v.as_str())
-                                .and_then(|v| VolumeId::from_string(v).ok())
-                                .unwrap_or_else(VolumeId::new),
-                        },
-                        _ => {
-                            return Err(anyhow::anyhow!(
-                                "Invalid custom volume type '{}'",
-                                spec.volume_type
-                            ));
-                        }
-                    };
-
-                    let volume_id = VolumeId::new();
-                    let volume = Volume {
-                        id: volume_id,
-                        name: spec.name.clone(),
-                        tenant_id: tenant_id.clone(),
-                        storage_class: storage_class.clone(),
-                        backend,
-                        size_limit_bytes,
-                        status: crate::domain::volume::VolumeStatus::Available,
-                        ownership: ownership.clone(),
-                        created_at: Utc::now(),
-                        attached_at: None,
-                        detached_at: None,
-                        expires_at: storage_class.calculate_expiry(Utc::now()),
-                        host_node_id: None,
-                    };
-
-                    self.repository
-                        .save(&volume)
-                        .await
-                        .context("Failed to save custom volume to repository")?;
-
-                    volume_id
-                }
-                other => {
-                    return Err(anyhow::anyhow!(
-                        "Invalid volume_type '{other}'. Expected 'seaweedfs', 'opendal', 'hostPath', 'seal'"
-                    ));
-                }
-            };
+        // `buffered` keeps results in spec order, which mount order relies on.
+        let results: Vec<Result<(Volume, bool)>> = stream::iter(volume_specs)
+            .map(|spec| self.create_volume_for_spec(execution_id, &tenant_id, spec, storage_mode))
+            .buffered(MAX_CONCURRENT_VOLUME_CREATIONS)
+            .collect()
+            .await;
 
-            // Fetch created volume
-            let volume = self.get_volume(volume_id).await?;
-            volumes.push(volume);
+        let mut created = Vec::with_capacity(results.len());
+        let mut failures = Vec::new();
+        for (spec, result) in volume_specs.iter().zip(results) {
+            match result {
+                Ok(entry) => created.push(entry),
+                Err(e) => failures.push(format!("'{}': {e:#}", spec.name)),
+            }
+        }
 
-            info!(
-                "Volume '{}' created successfully (id: {}, size: {} bytes)",
-                spec.name, volume_id, size_limit_bytes
+        if !failures.is_empty() {
+            warn!(
+                "Provisioning failed for {} of {} volumes for execution {}; rolling back {} created volume(s)",
+                failures.len(),
+                volume_specs.len(),
+                execution_id,
+                created.len()
             );
+            stream::iter(created)
+                .for_each_concurrent(MAX_CONCURRENT_VOLUME_CREATIONS, |(volume, owns_storage)| {
+                    self.roll_back_volume(volume, owns_storage)
+                })
+                .await;
+            return Err(anyhow::anyhow!(
+                "Failed to create {} of {} volumes for execution {}: {}",
+                failures.len(),
+                volume_specs.len(),
+                execution_id,
+                failures.join("; ")
+            ));
         }
 
+        let volumes: Vec<Volume> = created.into_iter().map(|(volume, _)| volume).collect();
+
         info!(
             "Successfully created {} volumes for execution {}",
             volumes.len(),
@@ -1322,6 +1421,84 @@ mod tests {
         );
     }
 
+    fn volume_spec(name: &str, size_limit: &str) -> VolumeSpec {
+        VolumeSpec {
+            name: name.to_string(),
+            storage_class: "ephemeral".to_string(),
+            volume_type: "seaweedfs".to_string(),
+            provider: None,
+            config: None,
+            mount_path: format!("/mnt/{name}"),
+            access_mode: "read-write".to_string(),
+            size_limit: size_limit.to_string(),
+            ttl_hours: Some(1),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_volumes_for_execution_preserves_spec_order() {
+        let (service, _repository, storage_provider) = create_test_service();
+        let specs: Vec<VolumeSpec> = (0..6)
+            .map(|i| volume_spec(&format!("vol-{i}"), "10Mi"))
+            .collect();
+
+        let volumes = service
+            .create_volumes_for_execution(
+                ExecutionId::new(),
+                TenantId::default(),
+                &specs,
+                "seaweedfs",
+            )
+            .await
+            .expect("Failed to create volumes");
+
+        let names: Vec<&str> = volumes.iter().map(|v| v.name.as_str()).collect();
+        assert_eq!(
+            names,
+            ["vol-0", "vol-1", "vol-2", "vol-3", "vol-4", "vol-5"]
+        );
+        assert_eq!(storage_provider.directories.lock().await.len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_create_volumes_for_execution_rolls_back_on_failure() {
+        let (service, repository, storage_provider) = create_test_service();
+        let specs = vec![
+            volume_spec("good-a", "10Mi"),
+            volume_spec("bad-size", "ten"),
+            volume_spec("good-b", "10Mi"),
+            VolumeSpec {
+                storage_class: "forever".to_string(),
+                ..volume_spec("bad-class", "10Mi")
+            },
+        ];
+
+        let err = service
+            .create_volumes_for_execution(
+                ExecutionId::new(),
+                TenantId::default(),
+                &specs,
+                "seaweedfs",
+            )
+            .await
+            .expect_err("provisioning should fail")
+            .to_string();
+
+        assert!(err.contains("2 of 4"), "unexpected error: {err}");
+        assert!(err.contains("'bad-size'"), "unexpected error: {err}");
+        assert!(err.contains("'bad-class'"), "unexpected error: {err}");
+
+        assert!(
+            storage_provider.directories.lock().await.is_empty(),
+            "created volume directories should be removed"
+        );
+        let volumes = repository.volumes.lock().await;
+        assert_eq!(volumes.len(), 2);
+        assert!(volumes
+            .values()
+            .all(|v| v.status == crate::domain::volume::VolumeStatus::Deleted));
+    }
+
     #[tokio::test]
     async fn test_create_volume_surfaces_repository_failure_details() {
         let repository = Arc::new(FailingVolumeRepository);
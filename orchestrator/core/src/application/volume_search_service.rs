@@ -0,0 +1,427 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Volume Search Service
+//!
+//! Answers "which volume has the file the agent claimed to write?" without
+//! mounting anything. Backs `GET /v1/volumes/search`, `POST /v1/volumes/grep`
+//! and `aegis volume search`.
+//!
+//! - **Filename search** runs against an in-memory index of every file on the
+//!   tenant's volumes. The index is built per tenant on first use and rebuilt
+//!   once it is older than [`DEFAULT_INDEX_TTL`] (or on request).
+//! - **Grep** scans indexed files for a pattern, bounded by
+//!   [`GrepLimits`]: per-file size, total bytes read, match count and
+//!   wall-clock time. Whatever was found before a limit hit is returned with
+//!   `truncated: true`.
+//!
+//! Both only surface volumes the caller may browse
+//! ([`AegisFSAL::browse_permitted`]); every file grep reads goes through
+//! [`AegisFSAL::browse_read`] and is audit-logged as `FileRead`.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use serde::Serialize;
+
+use crate::domain::fsal::{AegisFSAL, FsalError};
+use crate::domain::tenant::TenantId;
+use crate::domain::volume::{Volume, VolumeId, VolumeStatus};
+
+/// How long a tenant's filename index is served before it is rebuilt.
+pub const DEFAULT_INDEX_TTL: Duration = Duration::from_secs(300);
+
+/// Files indexed per volume; larger volumes are indexed partially.
+pub const MAX_INDEXED_FILES_PER_VOLUME: usize = 10_000;
+
+/// Upper bound on filename search results.
+pub const MAX_NAME_RESULTS: usize = 500;
+
+/// Longest line returned in a grep match, in characters.
+const MAX_MATCH_LINE_CHARS: usize = 512;
+
+/// Compiled size limit for grep patterns.
+const MAX_PATTERN_BYTES: usize = 1024 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum VolumeSearchError {
+    #[error("invalid pattern: {0}")]
+    InvalidPattern(String),
+    #[error("volume not found: {0}")]
+    VolumeNotFound(VolumeId),
+    #[error("unauthorized")]
+    Unauthorized,
+    #[error("repository error: {0}")]
+    Repository(String),
+}
+
+impl From<FsalError> for VolumeSearchError {
+    fn from(e: FsalError) -> Self {
+        match e {
+            FsalError::VolumeNotFound(id) | FsalError::VolumeNotAttached(id) => {
+                VolumeSearchError::VolumeNotFound(id)
+            }
+            FsalError::UnauthorizedAccess { .. } => VolumeSearchError::Unauthorized,
+            other => VolumeSearchError::Repository(other.to_string()),
+        }
+    }
+}
+
+/// Server-side bounds on a single grep. Callers may lower `max_matches`
+/// but never raise any limit.
+#[derive(Debug, Clone, Copy)]
+pub struct GrepLimits {
+    /// Files larger than this are skipped.
+    pub max_file_bytes: u64,
+    /// Stop once this many bytes have been read across all files.
+    pub max_total_bytes: u64,
+    pub max_matches: usize,
+    pub timeout: Duration,
+}
+
+impl Default for GrepLimits {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: 1024 * 1024,
+            max_total_bytes: 64 * 1024 * 1024,
+            max_matches: 200,
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct GrepRequest {
+    pub pattern: String,
+    /// Treat `pattern` as a regular expression instead of a literal.
+    pub regex: bool,
+    pub case_insensitive: bool,
+    /// Restrict the scan to one volume.
+    pub volume_id: Option<VolumeId>,
+    /// Only scan files whose path starts with this prefix.
+    pub path_prefix: Option<String>,
+    pub max_matches: Option<usize>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FileMatch {
+    pub volume_id: VolumeId,
+    pub volume_name: String,
+    pub path: String,
+    pub size_bytes: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NameSearchResult {
+    pub matches: Vec<FileMatch>,
+    pub truncated: bool,
+    pub indexed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GrepMatch {
+    pub volume_id: VolumeId,
+    pub volume_name: String,
+    pub path: String,
+    pub line_number: usize,
+    pub line: String,
+}
+
+/// The limit that ended a grep early.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrepStop {
+    MaxMatches,
+    MaxBytes,
+    Timeout,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GrepResult {
+    pub matches: Vec<GrepMatch>,
+    pub files_scanned: usize,
+    pub bytes_scanned: u64,
+    pub truncated: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stopped_by: Option<GrepStop>,
+}
+
+struct IndexedVolume {
+    volume: Volume,
+    files: Vec<(String, u64)>,
+}
+
+struct TenantIndex {
+    built_at: Instant,
+    indexed_at: DateTime<Utc>,
+    volumes: Arc<Vec<IndexedVolume>>,
+}
+
+pub struct VolumeSearchService {
+    fsal: Arc<AegisFSAL>,
+    index: DashMap<TenantId, TenantIndex>,
+    index_ttl: Duration,
+    limits: GrepLimits,
+}
+
+impl VolumeSearchService {
+    pub fn new(fsal: Arc<AegisFSAL>) -> Self {
+        Self {
+            fsal,
+            index: DashMap::new(),
+            index_ttl: DEFAULT_INDEX_TTL,
+            limits: GrepLimits::default(),
+        }
+    }
+
+    pub fn with_index_ttl(mut self, index_ttl: Duration) -> Self {
+        self.index_ttl = index_ttl;
+        self
+    }
+
+    pub fn with_grep_limits(mut self, limits: GrepLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Case-insensitive substring search over file paths on every volume
+    /// the caller may browse. `refresh` rebuilds the tenant index first.
+    pub async fn search_names(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &str,
+        query: &str,
+        limit: usize,
+        refresh: bool,
+    ) -> Result<NameSearchResult, VolumeSearchError> {
+        let (volumes, indexed_at) = self.tenant_index(tenant_id, refresh).await?;
+        let needle = query.to_lowercase();
+        let limit = limit.clamp(1, MAX_NAME_RESULTS);
+
+        let mut matches = Vec::new();
+        let mut truncated = false;
+        'volumes: for indexed in visible(&volumes, tenant_id, user_id) {
+            for (path, size) in &indexed.files {
+                if !path.to_lowercase().contains(&needle) {
+                    continue;
+                }
+                if matches.len() >= limit {
+                    truncated = true;
+                    break 'volumes;
+                }
+                matches.push(FileMatch {
+                    volume_id: indexed.volume.id,
+                    volume_name: indexed.volume.name.clone(),
+                    path: path.clone(),
+                    size_bytes: *size,
+                });
+            }
+        }
+
+        Ok(NameSearchResult {
+            matches,
+            truncated,
+            indexed_at,
+        })
+    }
+
+    /// Bounded grep over the indexed files of the caller's browsable
+    /// volumes. Files written since the index was built are not scanned
+    /// until the next rebuild.
+    pub async fn grep(
+        &self,
+        tenant_id: &TenantId,
+        user_id: &str,
+        request: &GrepRequest,
+    ) -> Result<GrepResult, VolumeSearchError> {
+        if request.pattern.is_empty() {
+            return Err(VolumeSearchError::InvalidPattern(
+                "pattern must not be empty".to_string(),
+            ));
+        }
+        let pattern = if request.regex {
+            request.pattern.clone()
+        } else {
+            regex::escape(&request.pattern)
+        };
+        let matcher = regex::RegexBuilder::new(&pattern)
+            .case_insensitive(request.case_insensitive)
+            .size_limit(MAX_PATTERN_BYTES)
+            .build()
+            .map_err(|e| VolumeSearchError::InvalidPattern(e.to_string()))?;
+
+        let max_matches = request
+            .max_matches
+            .unwrap_or(self.limits.max_matches)
+            .clamp(1, self.limits.max_matches);
+        let deadline = Instant::now() + self.limits.timeout;
+
+        if let Some(volume_id) = &request.volume_id {
+            // Surfaces not-found / unauthorized for an explicit volume and
+            // audit-logs denied attempts.
+            self.fsal
+                .authorize_browse(tenant_id, user_id, volume_id)
+                .await?;
+        }
+
+        let (volumes, _) = self.tenant_index(tenant_id, false).await?;
+        let mut result = GrepResult {
+            matches: Vec::new(),
+            files_scanned: 0,
+            bytes_scanned: 0,
+            truncated: false,
+            stopped_by: None,
+        };
+
+        'volumes: for indexed in visible(&volumes, tenant_id, user_id) {
+            if request.volume_id.is_some_and(|id| id != indexed.volume.id) {
+                continue;
+            }
+            for (path, size) in &indexed.files {
+                if request
+                    .path_prefix
+                    .as_deref()
+                    .is_some_and(|prefix| !path.starts_with(prefix))
+                    || *size > self.limits.max_file_bytes
+                {
+                    continue;
+                }
+                if Instant::now() >= deadline {
+                    result.stopped_by = Some(GrepStop::Timeout);
+                    break 'volumes;
+                }
+                if result.bytes_scanned + size > self.limits.max_total_bytes {
+                    result.stopped_by = Some(GrepStop::MaxBytes);
+                    break 'volumes;
+                }
+
+                let data = match self
+                    .fsal
+                    .browse_read(&indexed.volume, path, self.limits.max_file_bytes)
+                    .await
+                {
+                    Ok(data) => data,
+                    Err(e) => {
+                        tracing::debug!(
+                            volume_id = %indexed.volume.id,
+                            path = %path,
+                            error = %e,
+                            "Skipping unreadable file during volume grep"
+                        );
+                        continue;
+                    }
+                };
+                result.files_scanned += 1;
+                result.bytes_scanned += data.len() as u64;
+                if is_binary(&data) {
+                    continue;
+                }
+
+                let text = String::from_utf8_lossy(&data);
+                for (index, line) in text.lines().enumerate() {
+                    if !matcher.is_match(line) {
+                        continue;
+                    }
+                    if result.matches.len() >= max_matches {
+                        result.stopped_by = Some(GrepStop::MaxMatches);
+                        break 'volumes;
+                    }
+                    result.matches.push(GrepMatch {
+                        volume_id: indexed.volume.id,
+                        volume_name: indexed.volume.name.clone(),
+                        path: path.clone(),
+                        line_number: index + 1,
+                        line: line.chars().take(MAX_MATCH_LINE_CHARS).collect(),
+                    });
+                }
+            }
+        }
+
+        result.truncated = result.stopped_by.is_some();
+        Ok(result)
+    }
+
+    /// The tenant's index, rebuilt when missing, stale or `refresh` is set.
+    async fn tenant_index(
+        &self,
+        tenant_id: &TenantId,
+        refresh: bool,
+    ) -> Result<(Arc<Vec<IndexedVolume>>, DateTime<Utc>), VolumeSearchError> {
+        if !refresh {
+            if let Some(index) = self.index.get(tenant_id) {
+                if index.built_at.elapsed() < self.index_ttl {
+                    return Ok((index.volumes.clone(), index.indexed_at));
+                }
+            }
+        }
+
+        let volumes = self
+            .fsal
+            .volume_repository()
+            .find_by_tenant(tenant_id.clone())
+            .await
+            .map_err(|e| VolumeSearchError::Repository(e.to_string()))?;
+
+        let mut indexed = Vec::with_capacity(volumes.len());
+        for volume in volumes {
+            if !matches!(
+                volume.status,
+                VolumeStatus::Available | VolumeStatus::Attached
+            ) {
+                continue;
+            }
+            match self
+                .fsal
+                .browse_walk(&volume, MAX_INDEXED_FILES_PER_VOLUME)
+                .await
+            {
+                Ok(files) => indexed.push(IndexedVolume { volume, files }),
+                Err(e) => tracing::warn!(
+                    volume_id = %volume.id,
+                    error = %e,
+                    "Failed to index volume for search"
+                ),
+            }
+        }
+
+        let volumes = Arc::new(indexed);
+        let indexed_at = Utc::now();
+        self.index.insert(
+            tenant_id.clone(),
+            TenantIndex {
+                built_at: Instant::now(),
+                indexed_at,
+                volumes: volumes.clone(),
+            },
+        );
+        Ok((volumes, indexed_at))
+    }
+}
+
+fn visible<'a>(
+    volumes: &'a [IndexedVolume],
+    tenant_id: &'a TenantId,
+    user_id: &'a str,
+) -> impl Iterator<Item = &'a IndexedVolume> {
+    volumes
+        .iter()
+        .filter(move |v| AegisFSAL::browse_permitted(&v.volume, tenant_id, user_id))
+}
+
+/// Treat a file as binary if its first 8 KiB contain a NUL byte.
+fn is_binary(data: &[u8]) -> bool {
+    data.iter().take(8192).any(|b| *b == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn is_binary_detects_nul_in_prefix() {
+        assert!(!is_binary(b"fn main() {}\n"));
+        assert!(is_binary(b"\x7fELF\x02\x01\x01\x00"));
+        assert!(!is_binary(b""));
+    }
+}
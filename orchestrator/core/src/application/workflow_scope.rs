@@ -242,9 +242,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: start_name,
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
 
         let mut workflow =
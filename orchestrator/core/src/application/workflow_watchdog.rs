@@ -0,0 +1,511 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Workflow Watchdog (BC-3)
+//!
+//! Tracks how long each running workflow execution has been in its current
+//! state and flags executions that overstay it. The expected time in a state
+//! is the state's `timeout`, else `spec.workflow_watchdog.default_max_state_seconds`;
+//! `Human` states without a `timeout` are never stuck (see
+//! [`WorkflowState::stuck_threshold`](crate::domain::workflow::WorkflowState::stuck_threshold)).
+//!
+//! Each [`WorkflowWatchdog::sweep`]:
+//!
+//! 1. sets the `aegis_workflow_executions_running` and
+//!    `aegis_workflow_executions_stuck` gauges;
+//! 2. publishes [`WorkflowEvent::WorkflowStateStuck`] once per stuck state
+//!    visit and counts it in `aegis_workflow_state_stuck_total{action}`;
+//! 3. applies `on_stuck`: `fail` terminates the Temporal workflow and marks
+//!    the execution failed; `retry` sends the `retryState` signal, again each
+//!    time another expected period passes, and fails the execution once
+//!    `max_retries` retries of that state have not helped.
+//!
+//! Without a [`WorkflowExecutionControlPort`] every action degrades to
+//! `notify`. [`WorkflowWatchdog::find_stuck`] backs
+//! `GET /v1/workflows/executions?stuck=true` whether or not the periodic
+//! sweep runs.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Application Layer
+//! - **Purpose:** Time-in-state tracking and stuck execution handling
+
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use tracing::warn;
+
+use crate::application::complete_workflow_execution::{
+    CompleteWorkflowExecutionRequest, CompleteWorkflowExecutionUseCase, CompletionStatus,
+    StandardCompleteWorkflowExecutionUseCase,
+};
+use crate::application::ports::WorkflowExecutionControlPort;
+use crate::domain::events::WorkflowEvent;
+use crate::domain::execution::ExecutionId;
+use crate::domain::node_config::WorkflowWatchdogConfig;
+use crate::domain::repository::{RepositoryError, WorkflowExecutionRepository, WorkflowRepository};
+use crate::domain::tenant::TenantId;
+use crate::domain::workflow::{
+    StateName, StuckStateAction, Workflow, WorkflowExecution, WorkflowId,
+};
+use crate::infrastructure::event_bus::EventBus;
+
+/// A running execution that has overstayed its current state.
+#[derive(Debug, Clone)]
+pub struct StuckExecution {
+    pub execution: WorkflowExecution,
+    pub state_name: String,
+    pub entered_at: DateTime<Utc>,
+    pub expected: Duration,
+}
+
+impl StuckExecution {
+    /// Time spent in the state as of `now`.
+    pub fn stuck_for(&self, now: DateTime<Utc>) -> Duration {
+        (now - self.entered_at).to_std().unwrap_or_default()
+    }
+}
+
+/// Last action taken for an execution, kept while it stays in the state.
+struct HandledState {
+    state_name: String,
+    entered_at: DateTime<Utc>,
+    acted_at: DateTime<Utc>,
+    retries: u32,
+}
+
+pub struct WorkflowWatchdog {
+    execution_repository: Arc<dyn WorkflowExecutionRepository>,
+    workflow_repository: Arc<dyn WorkflowRepository>,
+    event_bus: Arc<EventBus>,
+    workflow_control: Option<Arc<dyn WorkflowExecutionControlPort>>,
+    config: WorkflowWatchdogConfig,
+    handled: Mutex<HashMap<ExecutionId, HandledState>>,
+}
+
+impl WorkflowWatchdog {
+    pub fn new(
+        execution_repository: Arc<dyn WorkflowExecutionRepository>,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+        event_bus: Arc<EventBus>,
+        config: WorkflowWatchdogConfig,
+    ) -> Self {
+        Self {
+            execution_repository,
+            workflow_repository,
+            event_bus,
+            workflow_control: None,
+            config,
+            handled: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Attach the port used to terminate executions and retry states.
+    pub fn with_workflow_control(mut self, port: Arc<dyn WorkflowExecutionControlPort>) -> Self {
+        self.workflow_control = Some(port);
+        self
+    }
+
+    pub fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.config.check_interval_seconds)
+    }
+
+    /// Running executions stuck as of `now` in one tenant, or in every
+    /// tenant when `tenant_id` is `None`. Newest first.
+    pub async fn find_stuck(
+        &self,
+        tenant_id: Option<&TenantId>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<StuckExecution>, RepositoryError> {
+        let running = match tenant_id {
+            Some(tenant_id) => {
+                self.execution_repository
+                    .find_active_for_tenant(tenant_id)
+                    .await?
+            }
+            None => self.execution_repository.find_active_all().await?,
+        };
+        self.stuck_among(running, now).await
+    }
+
+    /// Check every running execution once. Returns how many are stuck.
+    pub async fn sweep(&self) -> Result<usize, RepositoryError> {
+        self.sweep_at(Utc::now()).await
+    }
+
+    async fn sweep_at(&self, now: DateTime<Utc>) -> Result<usize, RepositoryError> {
+        let running = self.execution_repository.find_active_all().await?;
+        metrics::gauge!("aegis_workflow_executions_running").set(running.len() as f64);
+        let running_ids: HashSet<ExecutionId> = running.iter().map(|e| e.id).collect();
+
+        let stuck = self.stuck_among(running, now).await?;
+        metrics::gauge!("aegis_workflow_executions_stuck").set(stuck.len() as f64);
+
+        self.handled
+            .lock()
+            .expect("watchdog state poisoned")
+            .retain(|id, _| running_ids.contains(id));
+        for execution in &stuck {
+            if let Some(action) = self.next_action(execution, now) {
+                self.act(execution, action, now).await;
+            }
+        }
+        Ok(stuck.len())
+    }
+
+    async fn stuck_among(
+        &self,
+        running: Vec<WorkflowExecution>,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<StuckExecution>, RepositoryError> {
+        let default = Duration::from_secs(self.config.default_max_state_seconds);
+        let mut workflows: HashMap<WorkflowId, Option<Workflow>> = HashMap::new();
+        let mut stuck = Vec::new();
+
+        for execution in running {
+            let transitions = self
+                .execution_repository
+                .find_transitions_for_tenant(&execution.tenant_id, execution.id)
+                .await?;
+            let active = execution.active_state(&transitions);
+
+            let workflow = match workflows.entry(execution.workflow_id) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => entry.insert(
+                    self.workflow_repository
+                        .find_by_id_visible(&execution.tenant_id, execution.workflow_id)
+                        .await?,
+                ),
+            };
+            // A state missing from the definition still gets the default.
+            let state = workflow
+                .as_ref()
+                .zip(StateName::new(active.state_name.as_str()).ok())
+                .and_then(|(workflow, name)| workflow.get_state(&name));
+            let expected = match state {
+                Some(state) => state.stuck_threshold(default),
+                None => Some(default),
+            };
+            let Some(expected) = expected else {
+                continue;
+            };
+
+            if (now - active.entered_at)
+                .to_std()
+                .is_ok_and(|age| age > expected)
+            {
+                stuck.push(StuckExecution {
+                    execution,
+                    state_name: active.state_name,
+                    entered_at: active.entered_at,
+                    expected,
+                });
+            }
+        }
+        Ok(stuck)
+    }
+
+    /// What to do about `stuck` now, or `None` when it has been handled.
+    fn next_action(&self, stuck: &StuckExecution, now: DateTime<Utc>) -> Option<StuckStateAction> {
+        let configured = match self.workflow_control {
+            Some(_) => self.config.on_stuck,
+            None => StuckStateAction::Notify,
+        };
+        let mut handled = self.handled.lock().expect("watchdog state poisoned");
+        let previous = handled
+            .get(&stuck.execution.id)
+            .filter(|previous| previous.state_name == stuck.state_name);
+
+        if let Some(previous) = previous {
+            let same_visit = previous.entered_at == stuck.entered_at;
+            let retry_due = configured == StuckStateAction::Retry
+                && (now - previous.acted_at)
+                    .to_std()
+                    .is_ok_and(|waited| waited > stuck.expected);
+            if same_visit && !retry_due {
+                return None;
+            }
+        }
+
+        let retries = previous.map_or(0, |previous| previous.retries);
+        let action = match configured {
+            StuckStateAction::Retry if retries >= self.config.max_retries => StuckStateAction::Fail,
+            action => action,
+        };
+        handled.insert(
+            stuck.execution.id,
+            HandledState {
+                state_name: stuck.state_name.clone(),
+                entered_at: stuck.entered_at,
+                acted_at: now,
+                retries: retries + u32::from(action == StuckStateAction::Retry),
+            },
+        );
+        Some(action)
+    }
+
+    async fn act(&self, stuck: &StuckExecution, action: StuckStateAction, now: DateTime<Utc>) {
+        let execution = &stuck.execution;
+        warn!(
+            execution_id = %execution.id,
+            state = %stuck.state_name,
+            stuck_for_seconds = stuck.stuck_for(now).as_secs(),
+            expected_seconds = stuck.expected.as_secs(),
+            action = action.as_str(),
+            "Workflow execution stuck in state"
+        );
+        metrics::counter!("aegis_workflow_state_stuck_total", "action" => action.as_str())
+            .increment(1);
+        self.event_bus
+            .publish_workflow_event(WorkflowEvent::WorkflowStateStuck {
+                execution_id: execution.id,
+                state_name: stuck.state_name.clone(),
+                entered_at: stuck.entered_at,
+                expected_seconds: stuck.expected.as_secs(),
+                action,
+                detected_at: now,
+            });
+
+        let Some(port) = &self.workflow_control else {
+            return;
+        };
+        let result = match action {
+            StuckStateAction::Notify => Ok(()),
+            StuckStateAction::Retry => port
+                .retry_workflow_state(&execution.tenant_id, execution.id, &stuck.state_name)
+                .await
+                .map_err(|e| e.to_string()),
+            StuckStateAction::Fail => self.fail(port.as_ref(), stuck).await,
+        };
+        if let Err(e) = result {
+            warn!(
+                execution_id = %execution.id,
+                action = action.as_str(),
+                error = %e,
+                "Workflow watchdog action failed"
+            );
+        }
+    }
+
+    async fn fail(
+        &self,
+        port: &dyn WorkflowExecutionControlPort,
+        stuck: &StuckExecution,
+    ) -> Result<(), String> {
+        let execution = &stuck.execution;
+        let reason = format!(
+            "workflow watchdog: state '{}' exceeded {}s",
+            stuck.state_name,
+            stuck.expected.as_secs()
+        );
+        port.terminate_workflow_execution(&execution.tenant_id, execution.id, &reason)
+            .await
+            .map_err(|e| e.to_string())?;
+        StandardCompleteWorkflowExecutionUseCase::new(
+            self.execution_repository.clone(),
+            self.event_bus.clone(),
+        )
+        .complete_execution_for_tenant(
+            &execution.tenant_id,
+            CompleteWorkflowExecutionRequest {
+                execution_id: execution.id.to_string(),
+                status: CompletionStatus::Failed,
+                final_blackboard: None,
+                final_output: None,
+                error_reason: Some(reason),
+                artifacts: None,
+                final_state: Some(stuck.state_name.clone()),
+            },
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::execution::ExecutionStatus;
+    use crate::domain::workflow::{StateKind, WorkflowMetadata, WorkflowSpec, WorkflowState};
+    use crate::infrastructure::event_bus::DomainEvent;
+    use crate::infrastructure::repositories::{
+        InMemoryWorkflowExecutionRepository, InMemoryWorkflowRepository,
+    };
+    use async_trait::async_trait;
+
+    #[derive(Default)]
+    struct RecordingControl {
+        calls: Mutex<Vec<String>>,
+    }
+
+    #[async_trait]
+    impl WorkflowExecutionControlPort for RecordingControl {
+        async fn cancel_workflow_execution(
+            &self,
+            _tenant_id: &TenantId,
+            _execution_id: ExecutionId,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn signal_workflow_execution(
+            &self,
+            _tenant_id: &TenantId,
+            _execution_id: ExecutionId,
+            _response: &str,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn remove_workflow_execution(
+            &self,
+            _tenant_id: &TenantId,
+            _execution_id: ExecutionId,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Ok(())
+        }
+
+        async fn terminate_workflow_execution(
+            &self,
+            _tenant_id: &TenantId,
+            _execution_id: ExecutionId,
+            _reason: &str,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.calls.lock().unwrap().push("terminate".to_string());
+            Ok(())
+        }
+
+        async fn retry_workflow_state(
+            &self,
+            _tenant_id: &TenantId,
+            _execution_id: ExecutionId,
+            state_name: &str,
+        ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("retry {state_name}"));
+            Ok(())
+        }
+    }
+
+    fn workflow() -> Workflow {
+        let mut states = HashMap::new();
+        states.insert(
+            StateName::new("BUILD").unwrap(),
+            WorkflowState {
+                kind: StateKind::System {
+                    command: "make".to_string(),
+                    env: HashMap::new(),
+                    workdir: None,
+                },
+                transitions: vec![],
+                timeout: Some(Duration::from_secs(60)),
+                max_state_visits: None,
+            },
+        );
+        Workflow::new(
+            WorkflowMetadata {
+                name: "build".to_string(),
+                version: None,
+                description: None,
+                labels: HashMap::new(),
+                annotations: HashMap::new(),
+                input_schema: None,
+                output_schema: None,
+                output_template: None,
+            },
+            WorkflowSpec {
+                initial_state: StateName::new("BUILD").unwrap(),
+                context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
+                states,
+                storage: Default::default(),
+                max_total_transitions: None,
+                concurrency: None,
+            },
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn retries_stuck_state_then_fails_execution() {
+        let tenant = TenantId::new("tenant-a").unwrap();
+        let workflow = workflow();
+        let workflows = Arc::new(InMemoryWorkflowRepository::new());
+        workflows.save_for_tenant(&tenant, &workflow).await.unwrap();
+        let executions = Arc::new(InMemoryWorkflowExecutionRepository::new());
+        let mut execution =
+            WorkflowExecution::new(&workflow, ExecutionId::new(), serde_json::json!({}));
+        execution.tenant_id = tenant.clone();
+        executions
+            .save_for_tenant(&tenant, &execution)
+            .await
+            .unwrap();
+
+        let event_bus = Arc::new(EventBus::new(16));
+        let mut events = event_bus.subscribe();
+        let control = Arc::new(RecordingControl::default());
+        let watchdog = WorkflowWatchdog::new(
+            executions.clone(),
+            workflows,
+            event_bus,
+            WorkflowWatchdogConfig {
+                on_stuck: StuckStateAction::Retry,
+                max_retries: 1,
+                ..Default::default()
+            },
+        )
+        .with_workflow_control(control.clone());
+
+        let entered = execution.last_transition_at;
+        assert_eq!(watchdog.sweep_at(entered).await.unwrap(), 0);
+
+        let first = entered + chrono::Duration::seconds(90);
+        assert_eq!(watchdog.sweep_at(first).await.unwrap(), 1);
+        match events.try_recv().unwrap() {
+            DomainEvent::Workflow(WorkflowEvent::WorkflowStateStuck {
+                state_name,
+                expected_seconds,
+                action,
+                ..
+            }) => {
+                assert_eq!(state_name, "BUILD");
+                assert_eq!(expected_seconds, 60);
+                assert_eq!(action, StuckStateAction::Retry);
+            }
+            other => panic!("unexpected event {other:?}"),
+        }
+
+        // Already retried within the last expected period.
+        watchdog
+            .sweep_at(first + chrono::Duration::seconds(30))
+            .await
+            .unwrap();
+        assert_eq!(*control.calls.lock().unwrap(), vec!["retry BUILD"]);
+
+        watchdog
+            .sweep_at(first + chrono::Duration::seconds(90))
+            .await
+            .unwrap();
+        assert_eq!(
+            *control.calls.lock().unwrap(),
+            vec!["retry BUILD", "terminate"]
+        );
+        let failed = executions
+            .find_by_id_for_tenant(&tenant, execution.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(failed.status, ExecutionStatus::Failed);
+        assert!(watchdog
+            .find_stuck(Some(&tenant), Utc::now())
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
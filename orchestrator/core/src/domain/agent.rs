@@ -222,6 +222,15 @@ pub struct AgentSpec {
         skip_serializing_if = "Option::is_none"
     )]
     pub output_handler: Option<crate::domain::output_handler::OutputHandlerConfig>,
+
+    /// Optional concurrency group: executions of agents or workflows sharing the
+    /// group within a tenant never run at the same time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<crate::domain::concurrency::ConcurrencySpec>,
+
+    /// Optional smoke test run after deploy by `aegis agent deploy --verify`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub healthcheck: Option<AgentHealthCheck>,
 }
 
 /// Runtime configuration
@@ -269,6 +278,39 @@ pub struct RuntimeConfig {
     /// Higher values (0.5-0.7) for creative agents (generators).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub temperature: Option<f32>,
+
+    /// Optional base image for a built runtime (fully-qualified: registry/repo:tag).
+    /// Only meaningful together with `packages`; defaults to the StandardRuntime
+    /// image resolved from `language` + `version`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_image: Option<String>,
+
+    /// Language packages baked into a per-agent-version runtime image at deploy
+    /// time (e.g. `["requests==2.32.3", "pydantic"]` for Python).
+    /// When non-empty the orchestrator generates a Dockerfile from the base image,
+    /// builds it via the Docker API, and runs executions on the built tag.
+    /// Requires a StandardRuntime (`language` + `version`).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub packages: Vec<String>,
+
+    /// Environment variables rendered per execution and injected into the
+    /// container. Each entry is a literal/templated `value` or a `secretRef`
+    /// resolved from the tenant's secrets namespace. Applied after `spec.env`,
+    /// so entries here win on name collisions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub env: Vec<EnvVar>,
+
+    /// Helper containers started alongside the agent container (e.g. a headless
+    /// browser). Sidecars join the agent's network namespace, so the agent
+    /// reaches them on `localhost`, and are removed together with it.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sidecars: Vec<SidecarSpec>,
+
+    /// Container platform the agent must run on (`os/arch[/variant]`, e.g.
+    /// `linux/arm64`). Executions are placed on a container endpoint serving
+    /// it (`spec.runtime.endpoints` in node config); unset runs anywhere.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub platform: Option<String>,
 }
 
 impl RuntimeConfig {
@@ -312,9 +354,71 @@ impl RuntimeConfig {
             }
         }
 
+        // Built runtimes layer packages on top of a StandardRuntime base
+        if self.base_image.is_some() && self.packages.is_empty() {
+            return Err("base_image requires packages to be specified".to_string());
+        }
+        if !self.packages.is_empty() {
+            if has_custom {
+                return Err(
+                    "packages cannot be combined with a custom image; bake them into the image instead"
+                        .to_string(),
+                );
+            }
+            if let Some(base) = &self.base_image {
+                if !base.contains('/') {
+                    return Err(
+                        "base_image must be fully-qualified: registry/repo:tag (e.g., docker.io/library/python:3.11-slim)"
+                            .to_string(),
+                    );
+                }
+            }
+            for package in &self.packages {
+                crate::domain::runtime_image::validate_package_spec(package)?;
+            }
+        }
+
+        let mut env_names = std::collections::HashSet::new();
+        for var in &self.env {
+            var.validate()?;
+            if !env_names.insert(var.name.as_str()) {
+                return Err(format!("env '{}' is declared more than once", var.name));
+            }
+        }
+
+        if self.sidecars.len() > MAX_SIDECARS {
+            return Err(format!(
+                "at most {MAX_SIDECARS} sidecars are supported, got {}",
+                self.sidecars.len()
+            ));
+        }
+        let mut sidecar_names = std::collections::HashSet::new();
+        for sidecar in &self.sidecars {
+            sidecar.validate()?;
+            if !sidecar_names.insert(sidecar.name.as_str()) {
+                return Err(format!(
+                    "sidecar '{}' is declared more than once",
+                    sidecar.name
+                ));
+            }
+        }
+
+        if let Some(platform) = &self.platform {
+            if !crate::domain::runtime::is_valid_platform(platform) {
+                return Err(format!(
+                    "platform '{platform}' must be os/arch[/variant] (e.g. linux/arm64)"
+                ));
+            }
+        }
+
         Ok(())
     }
 
+    /// Whether executions of this agent run on an orchestrator-built image.
+    pub fn requires_image_build(&self) -> bool {
+        !self.packages.is_empty()
+    }
+
     /// Determine runtime type (standard or custom)
     pub fn runtime_type(&self) -> RuntimeType {
         if self.image.is_some() {
@@ -325,6 +429,212 @@ impl RuntimeConfig {
     }
 }
 
+/// One `spec.runtime.env` entry.
+///
+/// ```yaml
+/// env:
+///   - name: TARGET_REPO
+///     value: "{{input.repo}}"
+///   - name: GITHUB_TOKEN
+///     secretRef:
+///       path: kv/github
+///       key: token
+/// ```
+///
+/// `value` is a Handlebars template rendered against the execution input
+/// (`{{input}}`, `{{input.KEY}}`, `{{intent}}`, `{{execution_id}}`); a value
+/// without expressions is used verbatim. Values resolved from `secretRef` are
+/// masked in execution events and logs.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct EnvVar {
+    /// Variable name (`[A-Za-z_][A-Za-z0-9_]*`).
+    pub name: String,
+
+    /// Literal or templated value. Mutually exclusive with `secret_ref`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+
+    /// Secret to read at execution start. Mutually exclusive with `value`.
+    #[serde(
+        default,
+        rename = "secretRef",
+        alias = "secret_ref",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub secret_ref: Option<EnvSecretRef>,
+}
+
+impl EnvVar {
+    /// Validate the name and that exactly one value source is set.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut chars = self.name.chars();
+        let valid_name = chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_name {
+            return Err(format!(
+                "env name '{}' must match [A-Za-z_][A-Za-z0-9_]*",
+                self.name
+            ));
+        }
+        if !crate::domain::env_guard::is_env_var_allowed(&self.name) {
+            return Err(format!(
+                "env name '{}' is reserved for the orchestrator",
+                self.name
+            ));
+        }
+        match (&self.value, &self.secret_ref) {
+            (Some(_), Some(_)) => Err(format!(
+                "env '{}' cannot set both value and secretRef",
+                self.name
+            )),
+            (None, None) => Err(format!(
+                "env '{}' must set either value or secretRef",
+                self.name
+            )),
+            (None, Some(secret)) => secret.engine_and_path().map(|_| ()).ok_or_else(|| {
+                format!(
+                    "env '{}' secretRef.path must be 'engine/path' and key must not be empty",
+                    self.name
+                )
+            }),
+            (Some(_), None) => Ok(()),
+        }
+    }
+}
+
+/// Reference to a single field of a KV secret in the owning tenant's namespace.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct EnvSecretRef {
+    /// `engine/path` of the secret, e.g. `kv/github`.
+    pub path: String,
+
+    /// Field within the secret.
+    pub key: String,
+}
+
+impl EnvSecretRef {
+    /// Split `path` into `(engine, path)`, or `None` when malformed or `key` is empty.
+    pub fn engine_and_path(&self) -> Option<(&str, &str)> {
+        if self.key.is_empty() {
+            return None;
+        }
+        self.path
+            .split_once('/')
+            .filter(|(engine, path)| !engine.is_empty() && !path.is_empty())
+    }
+}
+
+/// Maximum number of `spec.runtime.sidecars` per agent.
+pub const MAX_SIDECARS: usize = 4;
+
+/// One `spec.runtime.sidecars` entry.
+///
+/// ```yaml
+/// sidecars:
+///   - name: browser
+///     image: ghcr.io/browserless/chromium:v2.18.0
+///     env:
+///       CONCURRENT: "1"
+///     resources:
+///       cpu: 500
+///       memory: 1Gi
+/// ```
+///
+/// A sidecar shares the execution's lifecycle: it starts after the agent
+/// container, before the first iteration runs, and is removed when the agent
+/// container is terminated. Its stdout/stderr is published as console output
+/// labelled `sidecar:<name>`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct SidecarSpec {
+    /// DNS-label name, unique within the agent.
+    pub name: String,
+
+    /// Fully-qualified image reference (registry/repo:tag).
+    pub image: String,
+
+    /// Image pull policy. Default: `IfNotPresent`.
+    #[serde(default = "default_image_pull_policy")]
+    pub image_pull_policy: ImagePullPolicy,
+
+    /// Overrides the image entrypoint when non-empty.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub command: Vec<String>,
+
+    /// Literal environment variables for the sidecar only.
+    #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
+    pub env: std::collections::HashMap<String, String>,
+
+    /// Resource limits for the sidecar container, independent of the agent's.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resources: Option<SidecarResources>,
+}
+
+impl SidecarSpec {
+    /// Validate the name, image reference and resource strings.
+    pub fn validate(&self) -> Result<(), String> {
+        let valid_name = !self.name.is_empty()
+            && self.name.len() <= 63
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+            && !self.name.starts_with('-')
+            && !self.name.ends_with('-');
+        if !valid_name {
+            return Err(format!(
+                "sidecar name '{}' must be a lowercase DNS label",
+                self.name
+            ));
+        }
+        if !self.image.contains('/') {
+            return Err(format!(
+                "sidecar '{}' image must be fully-qualified: registry/repo:tag",
+                self.name
+            ));
+        }
+        for key in self.env.keys() {
+            if !crate::domain::env_guard::is_env_var_allowed(key) {
+                return Err(format!(
+                    "sidecar '{}' env '{key}' is reserved for the orchestrator",
+                    self.name
+                ));
+            }
+        }
+        if let Some(memory) = self.resources.as_ref().and_then(|r| r.memory.as_deref()) {
+            if ResourceLimits::parse_size_to_bytes(memory).is_none() {
+                return Err(format!(
+                    "sidecar '{}' memory '{memory}' is not a valid size (e.g. 512Mi, 1Gi)",
+                    self.name
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Resource limits for one sidecar container.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct SidecarResources {
+    /// CPU quota in millicores (1000 = 1 CPU core). Unlimited when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu: Option<u32>,
+
+    /// Memory limit ("512Mi", "1Gi"). Unlimited when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<String>,
+}
+
+impl SidecarResources {
+    /// Memory limit in bytes.
+    pub fn memory_bytes(&self) -> Option<u64> {
+        self.memory
+            .as_deref()
+            .and_then(ResourceLimits::parse_size_to_bytes)
+    }
+}
+
 /// Runtime type discriminator
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum RuntimeType {
@@ -434,6 +744,18 @@ pub struct ExecutionStrategy {
     pub tool_validation: Option<ValidationConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub delivery: Option<DeliveryConfig>,
+    /// Human approval gate. `per_iteration` holds every iteration until an
+    /// operator approves it via `/v1/human-approvals`; see [`ApprovalMode`].
+    #[serde(default, skip_serializing_if = "ApprovalMode::is_off")]
+    pub approval_mode: ApprovalMode,
+    /// How long an iteration waits for a decision. Defaults to
+    /// [`DEFAULT_APPROVAL_TIMEOUT_SECONDS`]. The wait counts toward the
+    /// execution's overall timeout.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_timeout_seconds: Option<u64>,
+    /// What to do when nobody decides in time. Defaults to `reject`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub approval_on_timeout: Option<ApprovalTimeoutAction>,
 }
 
 impl Default for ExecutionStrategy {
@@ -446,6 +768,9 @@ impl Default for ExecutionStrategy {
             validation: None,
             tool_validation: None,
             delivery: None,
+            approval_mode: ApprovalMode::Off,
+            approval_timeout_seconds: None,
+            approval_on_timeout: None,
         }
     }
 }
@@ -458,6 +783,49 @@ pub enum ExecutionMode {
     Iterative,
 }
 
+/// Default wait for a per-iteration approval decision.
+pub const DEFAULT_APPROVAL_TIMEOUT_SECONDS: u64 = 3600;
+
+/// Human approval gate for high-risk agents (`spec.execution.approval_mode`).
+///
+/// With `per_iteration` the supervisor assembles each iteration's prompt,
+/// files it with the human input service and only spawns the container once
+/// an operator approves. A rejection fails the execution.
+///
+/// # YAML example
+/// ```yaml
+/// spec:
+///   execution:
+///     mode: iterative
+///     approval_mode: per_iteration
+///     approval_timeout_seconds: 900
+///     approval_on_timeout: reject
+/// ```
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalMode {
+    #[default]
+    Off,
+    PerIteration,
+}
+
+impl ApprovalMode {
+    pub fn is_off(&self) -> bool {
+        matches!(self, Self::Off)
+    }
+}
+
+/// Outcome applied when an iteration approval request times out.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ApprovalTimeoutAction {
+    /// Fail the execution.
+    #[default]
+    Reject,
+    /// Run the iteration anyway.
+    Approve,
+}
+
 /// Specification for a single validation step in the ordered pipeline.
 ///
 /// Used in `spec.execution.validation` as an ordered list. Each entry is evaluated
@@ -481,6 +849,8 @@ pub enum ExecutionMode {
 ///         judge_agent: output-judge
 ///         criteria: "Output must be idiomatic Rust with no unsafe blocks"
 ///         min_score: 0.8
+///       - type: plugin
+///         plugin: "sha256:9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08"
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -551,6 +921,18 @@ pub enum ValidatorSpec {
         #[serde(default = "default_validation_timeout")]
         timeout_seconds: u64,
     },
+    /// Scores the output with the `validate` export of a WASM plugin
+    /// (`spec.plugins`), referenced by digest.
+    Plugin {
+        /// `sha256:<hex>` digest returned when the plugin was uploaded.
+        #[schemars(with = "String")]
+        plugin: crate::domain::plugin::PluginDigest,
+        #[serde(default = "default_min_score_full")]
+        min_score: f64,
+        /// Minimum confidence required; scores below this threshold are treated as fails.
+        #[serde(default)]
+        min_confidence: f64,
+    },
 }
 
 /// Ordered list of validation steps executed after each iteration.
@@ -795,6 +1177,36 @@ pub struct AdvancedConfig {
     /// The script must be present inside the custom container image.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub bootstrap_path: Option<String>,
+    /// Keep the auto-provisioned `/workspace` volume after the execution ends
+    /// (debugging). It then expires with the node's ephemeral TTL.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub keep_workspace: bool,
+    /// How much console output and LLM traffic this agent's executions
+    /// publish on the event bus (and so persist). Default: `full`.
+    #[serde(default, skip_serializing_if = "EventVerbosity::is_full")]
+    pub event_verbosity: EventVerbosity,
+}
+
+/// Event volume for an execution (`spec.advanced.event_verbosity`), applied
+/// when events are published. Lifecycle, validation and failure events are
+/// always published.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EventVerbosity {
+    /// Every console batch and LLM interaction, verbatim.
+    #[default]
+    Full,
+    /// Console batches cut down to their first and last lines; LLM prompts
+    /// and responses truncated.
+    Summarized,
+    /// stderr console output only; no LLM interactions or routing decisions.
+    ErrorsOnly,
+}
+
+impl EventVerbosity {
+    pub fn is_full(&self) -> bool {
+        *self == Self::Full
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
@@ -804,6 +1216,129 @@ pub struct HealthCheckConfig {
     pub timeout_seconds: u64,
 }
 
+/// Post-deploy smoke test (`spec.healthcheck`).
+///
+/// `POST /v1/agents/{id}/verify` runs the agent once with the canned input and
+/// checks the finished execution against `expect`. The agent's own
+/// `spec.execution.validation` still applies, so `expect.status: completed`
+/// means the run passed validation.
+///
+/// # YAML example
+/// ```yaml
+/// spec:
+///   healthcheck:
+///     input: "Summarise: the quick brown fox jumps over the lazy dog"
+///     expect:
+///       status: completed
+///       output_contains: "fox"
+///     timeout_seconds: 120
+///     on_failure: degrade
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, JsonSchema)]
+pub struct AgentHealthCheck {
+    /// Intent for the smoke execution.
+    pub input: String,
+    /// Optional structured input, passed like `aegis task execute --input`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_data: Option<serde_json::Value>,
+    #[serde(default)]
+    pub expect: HealthCheckExpectation,
+    #[serde(default = "default_healthcheck_timeout")]
+    pub timeout_seconds: u64,
+    #[serde(default)]
+    pub on_failure: HealthCheckFailureAction,
+}
+
+/// Expected outcome of a health check execution.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default, JsonSchema)]
+pub struct HealthCheckExpectation {
+    #[serde(default)]
+    pub status: HealthCheckStatus,
+    /// Substring the final output must contain.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_contains: Option<String>,
+    /// Regex the final output must match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_matches: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckStatus {
+    #[default]
+    Completed,
+    /// For agents whose validation is expected to reject the canned input.
+    Failed,
+}
+
+/// What a failed health check does to the agent.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthCheckFailureAction {
+    /// Report the failure so `aegis agent deploy --verify` exits non-zero.
+    #[default]
+    Fail,
+    /// Mark the agent [`AgentStatus::Degraded`]; the deploy still succeeds.
+    Degrade,
+}
+
+impl AgentHealthCheck {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.input.trim().is_empty() {
+            return Err("spec.healthcheck.input must not be empty".to_string());
+        }
+        if self.timeout_seconds == 0 {
+            return Err("spec.healthcheck.timeout_seconds must be positive".to_string());
+        }
+        if let Some(pattern) = &self.expect.output_matches {
+            regex::Regex::new(pattern)
+                .map_err(|e| format!("spec.healthcheck.expect.output_matches: {e}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl HealthCheckExpectation {
+    /// Check a finished execution. `output` is the last iteration's output.
+    pub fn evaluate(
+        &self,
+        status: &crate::domain::execution::ExecutionStatus,
+        output: Option<&str>,
+    ) -> Result<(), String> {
+        use crate::domain::execution::ExecutionStatus;
+
+        let status_ok = match self.status {
+            HealthCheckStatus::Completed => *status == ExecutionStatus::Completed,
+            HealthCheckStatus::Failed => *status == ExecutionStatus::Failed,
+        };
+        if !status_ok {
+            return Err(format!(
+                "expected execution status {:?}, got {status:?}",
+                self.status
+            ));
+        }
+
+        let output = output.unwrap_or_default();
+        if let Some(needle) = &self.output_contains {
+            if !output.contains(needle.as_str()) {
+                return Err(format!("output does not contain '{needle}'"));
+            }
+        }
+        if let Some(pattern) = &self.output_matches {
+            let regex = regex::Regex::new(pattern)
+                .map_err(|e| format!("invalid output_matches regex: {e}"))?;
+            if !regex.is_match(output) {
+                return Err(format!("output does not match /{pattern}/"));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn default_healthcheck_timeout() -> u64 {
+    300
+}
+
 // MetadataConfig removed - now handled by ManifestMetadata in K8s format
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -812,6 +1347,8 @@ pub enum AgentStatus {
     Paused,
     Archived,
     Failed,
+    /// Deployed and runnable, but its last `spec.healthcheck` run failed.
+    Degraded,
 }
 
 // Defaults
@@ -909,6 +1446,12 @@ impl Agent {
         self.status = AgentStatus::Archived;
         self.updated_at = Utc::now();
     }
+
+    /// Record a failed `spec.healthcheck` run with `on_failure: degrade`.
+    pub fn degrade(&mut self) {
+        self.status = AgentStatus::Degraded;
+        self.updated_at = Utc::now();
+    }
 }
 
 impl AgentManifest {
@@ -951,6 +1494,14 @@ impl AgentManifest {
 
         // Execution strategy is optional and validated by its own invariants.
 
+        if let Some(concurrency) = &self.spec.concurrency {
+            concurrency.validate()?;
+        }
+
+        if let Some(healthcheck) = &self.spec.healthcheck {
+            healthcheck.validate()?;
+        }
+
         // spec.task is required — an agent without a task block has no instruction and cannot run
         match &self.spec.task {
             None => {
@@ -1010,6 +1561,11 @@ mod tests {
                     version: Some("3.11".to_string()),
                     image: None,
                     image_pull_policy: ImagePullPolicy::IfNotPresent,
+                    base_image: None,
+                    packages: Vec::new(),
+                    env: Vec::new(),
+                    sidecars: Vec::new(),
+                    platform: None,
                     isolation: "inherit".to_string(),
                     model: "default".to_string(),
                     temperature: None,
@@ -1030,6 +1586,8 @@ mod tests {
                 input_schema: None,
                 security_context: None,
                 output_handler: None,
+                concurrency: None,
+                healthcheck: None,
             },
         }
     }
@@ -1213,6 +1771,32 @@ mod tests {
         assert!(limits.timeout.is_none());
     }
 
+    #[test]
+    fn test_healthcheck_expectation_evaluate() {
+        use crate::domain::execution::ExecutionStatus;
+
+        let expect = HealthCheckExpectation {
+            status: HealthCheckStatus::Completed,
+            output_contains: Some("fox".to_string()),
+            output_matches: Some(r"^\w+ fox$".to_string()),
+        };
+        assert!(expect
+            .evaluate(&ExecutionStatus::Completed, Some("brown fox"))
+            .is_ok());
+        assert!(expect
+            .evaluate(&ExecutionStatus::Failed, Some("brown fox"))
+            .is_err());
+        assert!(expect
+            .evaluate(&ExecutionStatus::Completed, Some("brown dog"))
+            .is_err());
+        assert!(expect
+            .evaluate(&ExecutionStatus::Completed, Some("the brown fox"))
+            .is_err());
+        assert!(HealthCheckExpectation::default()
+            .evaluate(&ExecutionStatus::Completed, None)
+            .is_ok());
+    }
+
     #[test]
     fn test_tool_validation_parsing() {
         let yaml = r#"
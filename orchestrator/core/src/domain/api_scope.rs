@@ -31,6 +31,8 @@ pub enum ApiScope {
     ExecutionLogs,
     ExecutionCancel,
     ExecutionRemove,
+    ExecutionLabel,
+    ExecutionGuide,
     // swarm
     SwarmRead,
     SwarmList,
@@ -107,6 +109,8 @@ impl ApiScope {
             Self::ExecutionLogs => "execution:logs",
             Self::ExecutionCancel => "execution:cancel",
             Self::ExecutionRemove => "execution:remove",
+            Self::ExecutionLabel => "execution:label",
+            Self::ExecutionGuide => "execution:guide",
             Self::SwarmRead => "swarm:read",
             Self::SwarmList => "swarm:list",
             Self::SwarmCancel => "swarm:cancel",
@@ -174,6 +178,8 @@ impl ApiScope {
             "execution:logs" => Some(Self::ExecutionLogs),
             "execution:cancel" => Some(Self::ExecutionCancel),
             "execution:remove" => Some(Self::ExecutionRemove),
+            "execution:label" => Some(Self::ExecutionLabel),
+            "execution:guide" => Some(Self::ExecutionGuide),
             "swarm:read" => Some(Self::SwarmRead),
             "swarm:list" => Some(Self::SwarmList),
             "swarm:cancel" => Some(Self::SwarmCancel),
@@ -244,6 +250,8 @@ impl ApiScope {
             Self::WorkflowCancel,
             Self::ExecutionStream,
             Self::ExecutionCancel,
+            Self::ExecutionLabel,
+            Self::ExecutionGuide,
             Self::SwarmCancel,
             Self::ApprovalRead,
             Self::ApprovalList,
@@ -301,6 +309,8 @@ impl ApiScope {
             Self::ExecutionLogs,
             Self::ExecutionCancel,
             Self::ExecutionRemove,
+            Self::ExecutionLabel,
+            Self::ExecutionGuide,
             Self::SwarmRead,
             Self::SwarmList,
             Self::SwarmCancel,
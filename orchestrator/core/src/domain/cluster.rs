@@ -10,6 +10,7 @@
 //! - A `NodeCluster` must have a unique `controller_node_id`.
 //! - `NodePeer` identifiers must be unique within a cluster.
 //! - Status transitions for peers: `Active → Draining → Deregistered` or `Active → Unhealthy`.
+//!   A cordoned node returns `Draining → Active` when uncordoned.
 //!
 //! See ADR-059 (Multi-Node Deployment).
 
@@ -504,6 +505,8 @@ pub trait NodeClusterRepository: Send + Sync {
     async fn mark_unhealthy(&self, node_id: &NodeId) -> anyhow::Result<()>;
     /// Transition a node to Draining status without marking it unhealthy.
     async fn start_drain(&self, node_id: &NodeId) -> anyhow::Result<()>;
+    /// Return a Draining node to Active (`aegis daemon uncordon`).
+    async fn end_drain(&self, node_id: &NodeId) -> anyhow::Result<()>;
     async fn deregister(&self, node_id: &NodeId, reason: &str) -> anyhow::Result<()>;
     async fn get_config_version(&self, node_id: &NodeId) -> anyhow::Result<Option<String>>;
     async fn record_config_version(&self, node_id: &NodeId, hash: &str) -> anyhow::Result<()>;
@@ -583,6 +586,51 @@ pub trait NodeChallengeRepository: Send + Sync {
     async fn delete_challenge(&self, challenge_id: &Uuid) -> anyhow::Result<()>;
 }
 
+// ──────────────────────────────────────────────────────────────────────────────
+// Daemon Failover Lease (spec.high_availability)
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// The lease that makes one daemon of an active/standby pair active.
+///
+/// `epoch` increases every time the lease changes hands and acts as the
+/// fencing token: renewals and releases only succeed for the current
+/// `(holder, epoch)`, so a daemon that lost the lease cannot extend it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DaemonLease {
+    pub name: String,
+    pub holder: String,
+    pub epoch: i64,
+    pub acquired_at: DateTime<Utc>,
+    pub renewed_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Lease storage for daemon failover. Expiry is judged by the store's
+/// clock, never the caller's, so clock skew between the pair cannot let
+/// both believe they hold the lease.
+#[async_trait::async_trait]
+pub trait DaemonLeaseRepository: Send + Sync {
+    /// Take the lease if it is free, expired or already held by `holder`.
+    /// Returns `None` while another holder's lease is still valid.
+    async fn try_acquire(
+        &self,
+        name: &str,
+        holder: &str,
+        ttl: std::time::Duration,
+    ) -> anyhow::Result<Option<DaemonLease>>;
+    /// Extend the lease. `false` means it has passed to another holder.
+    async fn renew(
+        &self,
+        name: &str,
+        holder: &str,
+        epoch: i64,
+        ttl: std::time::Duration,
+    ) -> anyhow::Result<bool>;
+    /// Expire the lease immediately so the standby need not wait out the TTL.
+    async fn release(&self, name: &str, holder: &str, epoch: i64) -> anyhow::Result<()>;
+    async fn current(&self, name: &str) -> anyhow::Result<Option<DaemonLease>>;
+}
+
 // ──────────────────────────────────────────────────────────────────────────────
 // Cluster Enrolment Tokens (security audit 002 §4.9)
 // ──────────────────────────────────────────────────────────────────────────────
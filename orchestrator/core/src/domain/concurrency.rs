@@ -0,0 +1,121 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Concurrency Groups
+//!
+//! Defines [`ConcurrencySpec`], the `spec.concurrency` block shared by agent and
+//! workflow manifests. Executions that declare the same `group` within a tenant
+//! are mutually exclusive: at most one of them runs at a time. The
+//! [`ConcurrencyPolicy`] decides what happens to a new execution that arrives
+//! while the group is held.
+//!
+//! Agents and workflows share a single group namespace per tenant, so an agent
+//! and a workflow that both declare `group: deploy-prod` exclude each other.
+//! Enforcement lives in
+//! [`crate::application::concurrency_group::ConcurrencyGroupService`].
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Manifest value objects for execution mutual exclusion
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of a concurrency group name.
+pub const MAX_CONCURRENCY_GROUP_LEN: usize = 128;
+
+/// `spec.concurrency` manifest block.
+///
+/// ```yaml
+/// spec:
+///   concurrency:
+///     group: deploy-prod
+///     policy: reject
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ConcurrencySpec {
+    /// Tenant-scoped group name. Executions sharing a group never overlap.
+    pub group: String,
+
+    /// What to do when the group is already held. Defaults to `queue`.
+    #[serde(default)]
+    pub policy: ConcurrencyPolicy,
+}
+
+/// Behaviour of a new execution whose concurrency group is already held.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ConcurrencyPolicy {
+    /// Cancel the execution currently holding the group, then run.
+    CancelInProgress,
+    /// Wait in FIFO order until the group is released.
+    #[default]
+    Queue,
+    /// Fail the new execution immediately.
+    Reject,
+}
+
+impl ConcurrencyPolicy {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CancelInProgress => "cancel_in_progress",
+            Self::Queue => "queue",
+            Self::Reject => "reject",
+        }
+    }
+}
+
+impl ConcurrencySpec {
+    /// Group names are 1–128 characters of ASCII alphanumerics, `-`, `_`, `.` and `/`.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.group.is_empty() {
+            return Err("spec.concurrency.group cannot be empty".to_string());
+        }
+        if self.group.len() > MAX_CONCURRENCY_GROUP_LEN {
+            return Err(format!(
+                "spec.concurrency.group must be at most {MAX_CONCURRENCY_GROUP_LEN} characters"
+            ));
+        }
+        if let Some(ch) = self
+            .group
+            .chars()
+            .find(|c| !c.is_ascii_alphanumeric() && !matches!(c, '-' | '_' | '.' | '/'))
+        {
+            return Err(format!(
+                "Invalid spec.concurrency.group '{}': character '{ch}' is not allowed",
+                self.group
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn policy_defaults_to_queue() {
+        let spec: ConcurrencySpec = serde_yaml::from_str("group: deploy-prod").unwrap();
+        assert_eq!(spec.policy, ConcurrencyPolicy::Queue);
+
+        let spec: ConcurrencySpec =
+            serde_yaml::from_str("group: deploy-prod\npolicy: cancel_in_progress").unwrap();
+        assert_eq!(spec.policy, ConcurrencyPolicy::CancelInProgress);
+    }
+
+    #[test]
+    fn validate_rejects_empty_and_invalid_groups() {
+        let spec = |group: &str| ConcurrencySpec {
+            group: group.to_string(),
+            policy: ConcurrencyPolicy::Reject,
+        };
+        assert!(spec("deploy-prod").validate().is_ok());
+        assert!(spec("team/release_v1.2").validate().is_ok());
+        assert!(spec("").validate().is_err());
+        assert!(spec("deploy prod").validate().is_err());
+        assert!(spec(&"g".repeat(MAX_CONCURRENCY_GROUP_LEN + 1))
+            .validate()
+            .is_err());
+    }
+}
@@ -56,6 +56,14 @@ fn default_cwd() -> String {
     "/workspace".to_string()
 }
 
+/// A dispatch payload encrypted under the per-execution key negotiated during
+/// SEAL attestation (AES-256-GCM, base64 fields).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptedPayload {
+    pub nonce: String,
+    pub ciphertext: String,
+}
+
 /// The outer message envelope for Agent -> Orchestrator messages.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "type", rename_all = "snake_case")]
@@ -64,7 +72,11 @@ pub enum AgentMessage {
         agent_id: String,
         execution_id: String,
         iteration_number: u8,
+        /// Empty when the container sends `encrypted_prompt` instead.
+        #[serde(default)]
         prompt: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        encrypted_prompt: Option<EncryptedPayload>,
         #[serde(default)]
         messages: Vec<ConversationMessage>,
         #[serde(default = "default_model_alias")]
@@ -100,6 +112,12 @@ pub enum OrchestratorMessage {
         /// (e.g. validation pipeline) can use it without a DB fetch.
         #[serde(default)]
         trajectory: Vec<crate::domain::execution::TrajectoryStep>,
+        /// Prompt tokens reported by the provider for the final LLM call.
+        #[serde(default)]
+        input_tokens: u32,
+        /// Completion tokens reported by the provider for the final LLM call.
+        #[serde(default)]
+        output_tokens: u32,
     },
     Dispatch {
         dispatch_id: DispatchId,
@@ -0,0 +1,346 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Error Code Taxonomy
+//!
+//! Machine-readable `AEG-xxxx` codes carried by every REST problem response
+//! and gRPC status, so clients branch on a code instead of parsing messages.
+//!
+//! | Range | Category |
+//! |---|---|
+//! | `AEG-1xxx` | Malformed or invalid requests |
+//! | `AEG-2xxx` | Authentication and authorization |
+//! | `AEG-3xxx` | Resource state (not found, conflicts) |
+//! | `AEG-4xxx` | Rate limits and quotas |
+//! | `AEG-5xxx` | Server-side and dependency failures |
+//!
+//! ## Stability
+//!
+//! - A published code is never reassigned to a different meaning or removed;
+//!   retired codes stay reserved.
+//! - New codes may be added in any release, so clients must treat unknown
+//!   codes by their category (the leading digit).
+//! - Messages (`detail`) are for humans and may change at any time.
+//!
+//! Transport mappings (HTTP status, gRPC code) live in
+//! [`crate::presentation::problem`].
+
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A stable error code. See the module docs for the stability contract.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ErrorCode {
+    /// Malformed request: bad JSON, bad path or query parameters.
+    InvalidRequest,
+    /// Well-formed request that fails semantic validation (e.g. a manifest).
+    ValidationFailed,
+    /// `Aegis-Api-Version` names a contract this server does not serve.
+    UnsupportedApiVersion,
+    /// Request body or uploaded file exceeds the allowed size.
+    PayloadTooLarge,
+    /// Missing or invalid credentials.
+    Unauthenticated,
+    /// Authenticated, but the token lacks the required scope.
+    InsufficientScope,
+    /// Authenticated, but not allowed to act on this resource or tenant.
+    Forbidden,
+    /// The tenant or team is suspended.
+    TenantSuspended,
+    /// The feature is not available on the caller's tier or edition.
+    FeatureUnavailable,
+    /// The resource does not exist or is not visible to the caller.
+    NotFound,
+    /// The request conflicts with the resource's current state.
+    Conflict,
+    /// A resource with the same identity already exists.
+    AlreadyExists,
+    /// Too many requests; retry later.
+    RateLimited,
+    /// A tenant, volume or tier quota is exhausted.
+    QuotaExceeded,
+    /// Unexpected server-side failure.
+    Internal,
+    /// Persistence or storage backend failure.
+    StorageFailure,
+    /// A required subsystem is not configured or unavailable.
+    ServiceUnavailable,
+    /// An upstream provider (LLM, Git host, payment provider) failed.
+    UpstreamFailure,
+    /// The operation did not complete in time.
+    Timeout,
+    /// The endpoint or operation is not implemented by this server.
+    NotImplemented,
+}
+
+impl ErrorCode {
+    /// Every code, for documentation and uniqueness checks.
+    pub const ALL: &'static [ErrorCode] = &[
+        ErrorCode::InvalidRequest,
+        ErrorCode::ValidationFailed,
+        ErrorCode::UnsupportedApiVersion,
+        ErrorCode::PayloadTooLarge,
+        ErrorCode::Unauthenticated,
+        ErrorCode::InsufficientScope,
+        ErrorCode::Forbidden,
+        ErrorCode::TenantSuspended,
+        ErrorCode::FeatureUnavailable,
+        ErrorCode::NotFound,
+        ErrorCode::Conflict,
+        ErrorCode::AlreadyExists,
+        ErrorCode::RateLimited,
+        ErrorCode::QuotaExceeded,
+        ErrorCode::Internal,
+        ErrorCode::StorageFailure,
+        ErrorCode::ServiceUnavailable,
+        ErrorCode::UpstreamFailure,
+        ErrorCode::Timeout,
+        ErrorCode::NotImplemented,
+    ];
+
+    /// The stable wire code, e.g. `AEG-3000`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ErrorCode::InvalidRequest => "AEG-1000",
+            ErrorCode::ValidationFailed => "AEG-1001",
+            ErrorCode::UnsupportedApiVersion => "AEG-1002",
+            ErrorCode::PayloadTooLarge => "AEG-1003",
+            ErrorCode::Unauthenticated => "AEG-2000",
+            ErrorCode::InsufficientScope => "AEG-2001",
+            ErrorCode::Forbidden => "AEG-2002",
+            ErrorCode::TenantSuspended => "AEG-2003",
+            ErrorCode::FeatureUnavailable => "AEG-2004",
+            ErrorCode::NotFound => "AEG-3000",
+            ErrorCode::Conflict => "AEG-3001",
+            ErrorCode::AlreadyExists => "AEG-3002",
+            ErrorCode::RateLimited => "AEG-4000",
+            ErrorCode::QuotaExceeded => "AEG-4001",
+            ErrorCode::Internal => "AEG-5000",
+            ErrorCode::StorageFailure => "AEG-5001",
+            ErrorCode::ServiceUnavailable => "AEG-5002",
+            ErrorCode::UpstreamFailure => "AEG-5003",
+            ErrorCode::Timeout => "AEG-5004",
+            ErrorCode::NotImplemented => "AEG-5005",
+        }
+    }
+
+    /// Short, stable human-readable summary of the code.
+    pub const fn title(self) -> &'static str {
+        match self {
+            ErrorCode::InvalidRequest => "Invalid request",
+            ErrorCode::ValidationFailed => "Validation failed",
+            ErrorCode::UnsupportedApiVersion => "Unsupported API version",
+            ErrorCode::PayloadTooLarge => "Payload too large",
+            ErrorCode::Unauthenticated => "Authentication required",
+            ErrorCode::InsufficientScope => "Insufficient scope",
+            ErrorCode::Forbidden => "Forbidden",
+            ErrorCode::TenantSuspended => "Tenant suspended",
+            ErrorCode::FeatureUnavailable => "Feature unavailable",
+            ErrorCode::NotFound => "Not found",
+            ErrorCode::Conflict => "Conflict",
+            ErrorCode::AlreadyExists => "Already exists",
+            ErrorCode::RateLimited => "Rate limited",
+            ErrorCode::QuotaExceeded => "Quota exceeded",
+            ErrorCode::Internal => "Internal error",
+            ErrorCode::StorageFailure => "Storage failure",
+            ErrorCode::ServiceUnavailable => "Service unavailable",
+            ErrorCode::UpstreamFailure => "Upstream failure",
+            ErrorCode::Timeout => "Timeout",
+            ErrorCode::NotImplemented => "Not implemented",
+        }
+    }
+
+    /// Whether retrying the same request later may succeed.
+    pub const fn is_retryable(self) -> bool {
+        matches!(
+            self,
+            ErrorCode::RateLimited
+                | ErrorCode::ServiceUnavailable
+                | ErrorCode::UpstreamFailure
+                | ErrorCode::Timeout
+        )
+    }
+
+    /// Map a legacy snake_case `error` slug used by older handlers to a code.
+    pub fn from_legacy_slug(slug: &str) -> Option<Self> {
+        Some(match slug {
+            "bad_request"
+            | "invalid_body"
+            | "invalid_path"
+            | "invalid_tier"
+            | "invalid_role"
+            | "invalid_tenant_slug"
+            | "invalid_team_slug"
+            | "invalid_team_id"
+            | "invalid_invitation_id"
+            | "missing_email" => ErrorCode::InvalidRequest,
+            "validation_failed" => ErrorCode::ValidationFailed,
+            "unsupported_api_version" => ErrorCode::UnsupportedApiVersion,
+            "file_too_large" => ErrorCode::PayloadTooLarge,
+            "unauthorized" | "unauthenticated" => ErrorCode::Unauthenticated,
+            "insufficient_scope" => ErrorCode::InsufficientScope,
+            "forbidden"
+            | "operator_required"
+            | "owner_only"
+            | "not_a_member"
+            | "team_context_required" => ErrorCode::Forbidden,
+            "colony_suspended" => ErrorCode::TenantSuspended,
+            "enterprise_only" => ErrorCode::FeatureUnavailable,
+            "not_found" | "team_not_found" => ErrorCode::NotFound,
+            "conflict" => ErrorCode::Conflict,
+            "already_exists" => ErrorCode::AlreadyExists,
+            "rate_limited" => ErrorCode::RateLimited,
+            "quota_exceeded" => ErrorCode::QuotaExceeded,
+            "internal" | "internal_error" | "other" => ErrorCode::Internal,
+            "storage_error" => ErrorCode::StorageFailure,
+            "teams_not_configured"
+            | "cortex_not_configured"
+            | "billing_not_configured"
+            | "stimulus_service_unavailable"
+            | "usage_unavailable" => ErrorCode::ServiceUnavailable,
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for ErrorCode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ErrorCode::ALL
+            .iter()
+            .copied()
+            .find(|code| code.as_str() == s)
+            .ok_or_else(|| format!("unknown error code: {s}"))
+    }
+}
+
+impl Serialize for ErrorCode {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ErrorCode {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Errors that classify themselves into the [`ErrorCode`] taxonomy.
+pub trait CodedError: std::error::Error {
+    fn error_code(&self) -> ErrorCode;
+}
+
+impl CodedError for super::repository::RepositoryError {
+    fn error_code(&self) -> ErrorCode {
+        use super::repository::RepositoryError;
+        match self {
+            RepositoryError::NotFound(_) => ErrorCode::NotFound,
+            RepositoryError::Database(_) => ErrorCode::StorageFailure,
+            RepositoryError::Serialization(_) | RepositoryError::Unknown(_) => ErrorCode::Internal,
+        }
+    }
+}
+
+impl CodedError for super::fsal::FsalError {
+    fn error_code(&self) -> ErrorCode {
+        use super::fsal::FsalError;
+        match self {
+            FsalError::UnauthorizedAccess { .. } | FsalError::PolicyViolation(_) => {
+                ErrorCode::Forbidden
+            }
+            FsalError::VolumeNotFound(_) => ErrorCode::NotFound,
+            FsalError::VolumeNotAttached(_) => ErrorCode::Conflict,
+            FsalError::PathSanitization(_)
+            | FsalError::InvalidFileHandle
+            | FsalError::HandleDeserialization(_) => ErrorCode::InvalidRequest,
+            FsalError::QuotaExceeded { .. } => ErrorCode::QuotaExceeded,
+            FsalError::FileTooLarge { .. } => ErrorCode::PayloadTooLarge,
+            FsalError::Storage(e) => e.error_code(),
+        }
+    }
+}
+
+impl CodedError for super::storage::StorageError {
+    fn error_code(&self) -> ErrorCode {
+        use super::storage::StorageError;
+        match self {
+            StorageError::NotFound(_) | StorageError::FileNotFound(_) => ErrorCode::NotFound,
+            StorageError::AlreadyExists(_) => ErrorCode::AlreadyExists,
+            StorageError::QuotaExceeded { .. } => ErrorCode::QuotaExceeded,
+            StorageError::PermissionDenied(_) => ErrorCode::Forbidden,
+            StorageError::InvalidPath(_) => ErrorCode::InvalidRequest,
+            StorageError::Timeout => ErrorCode::Timeout,
+            StorageError::Network(_) | StorageError::Unavailable(_) => {
+                ErrorCode::ServiceUnavailable
+            }
+            StorageError::IoError(_)
+            | StorageError::Serialization(_)
+            | StorageError::Corrupted(_)
+            | StorageError::Unknown(_) => ErrorCode::StorageFailure,
+        }
+    }
+}
+
+impl CodedError for super::rate_limit::RateLimitError {
+    fn error_code(&self) -> ErrorCode {
+        use super::rate_limit::RateLimitError;
+        match self {
+            RateLimitError::PolicyResolutionFailed(_) => ErrorCode::Internal,
+            RateLimitError::EnforcementFailed(_) | RateLimitError::StorageError(_) => {
+                ErrorCode::ServiceUnavailable
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn codes_are_unique_and_round_trip() {
+        let mut seen = HashSet::new();
+        for code in ErrorCode::ALL {
+            assert!(seen.insert(code.as_str()), "duplicate {code}");
+            assert_eq!(code.as_str().parse::<ErrorCode>(), Ok(*code));
+            let json = serde_json::to_string(code).unwrap();
+            assert_eq!(serde_json::from_str::<ErrorCode>(&json).unwrap(), *code);
+        }
+        assert!("AEG-9999".parse::<ErrorCode>().is_err());
+    }
+
+    #[test]
+    fn codes_match_documented_categories() {
+        for code in ErrorCode::ALL {
+            let digits = code.as_str().strip_prefix("AEG-").unwrap();
+            assert_eq!(digits.len(), 4, "{code}");
+            assert!(matches!(&digits[..1], "1" | "2" | "3" | "4" | "5"));
+        }
+        assert!(ErrorCode::RateLimited.is_retryable());
+        assert!(!ErrorCode::NotFound.is_retryable());
+    }
+
+    #[test]
+    fn legacy_slugs_map_to_codes() {
+        assert_eq!(
+            ErrorCode::from_legacy_slug("insufficient_scope"),
+            Some(ErrorCode::InsufficientScope)
+        );
+        assert_eq!(
+            ErrorCode::from_legacy_slug("cortex_not_configured"),
+            Some(ErrorCode::ServiceUnavailable)
+        );
+        assert_eq!(ErrorCode::from_legacy_slug("Agent abc not found"), None);
+    }
+}
@@ -34,7 +34,7 @@
 //! The EventBus is currently **in-memory only** (tokio broadcast channel). Persistent
 //! event replay and external consumers (Kafka, NATS) are planned for Phase 2 per ADR-030.
 
-use crate::domain::agent::{AgentManifest, AgentScope};
+use crate::domain::agent::{AgentManifest, AgentScope, EventVerbosity};
 use crate::domain::credential::{
     CredentialBindingId, CredentialGrantId, CredentialProvider, CredentialType, GrantTarget,
 };
@@ -56,6 +56,7 @@ use crate::domain::secrets::AccessContext;
 use crate::domain::shared_kernel::{
     AgentId, DispatchId, ExecutionId, ImagePullPolicy, NodeId, VolumeId,
 };
+use crate::domain::storage::StorageErrorKind;
 use crate::domain::tenancy::TenantQuotaKind;
 use crate::domain::tenant::TenantId;
 use crate::domain::volume::StorageClass;
@@ -130,6 +131,14 @@ pub enum ImageManagementEvent {
         reason: String,
         failed_at: DateTime<Utc>,
     },
+    /// Raised when signature verification rejects an image before container
+    /// creation. The execution is failed; the reason is preserved for audit.
+    ImageVerificationFailed {
+        execution_id: ExecutionId,
+        image: String,
+        reason: String,
+        failed_at: DateTime<Utc>,
+    },
 }
 
 /// File-level audit events published by the NFS Server Gateway FSAL (ADR-036).
@@ -152,6 +161,11 @@ pub enum StorageEvent {
         /// Workflow execution that performed this operation (ContainerStep FUSE path).
         /// Mutually exclusive with `execution_id` — exactly one must be `Some`.
         workflow_execution_id: Option<uuid::Uuid>,
+        /// Supervisor iteration that was running when the operation happened
+        /// (`None` for workflow steps, user browsing and events recorded before
+        /// iteration tracking).
+        #[serde(default)]
+        iteration_number: Option<u8>,
         volume_id: VolumeId,
         path: String,
         open_mode: String, // "read", "write", "read-write", "create"
@@ -164,6 +178,8 @@ pub enum StorageEvent {
     FileRead {
         execution_id: Option<ExecutionId>,
         workflow_execution_id: Option<uuid::Uuid>,
+        #[serde(default)]
+        iteration_number: Option<u8>,
         volume_id: VolumeId,
         path: String,
         offset: u64,
@@ -178,6 +194,8 @@ pub enum StorageEvent {
     FileWritten {
         execution_id: Option<ExecutionId>,
         workflow_execution_id: Option<uuid::Uuid>,
+        #[serde(default)]
+        iteration_number: Option<u8>,
         volume_id: VolumeId,
         path: String,
         offset: u64,
@@ -192,6 +210,8 @@ pub enum StorageEvent {
     FileClosed {
         execution_id: Option<ExecutionId>,
         workflow_execution_id: Option<uuid::Uuid>,
+        #[serde(default)]
+        iteration_number: Option<u8>,
         volume_id: VolumeId,
         path: String,
         closed_at: DateTime<Utc>,
@@ -203,6 +223,8 @@ pub enum StorageEvent {
     DirectoryListed {
         execution_id: Option<ExecutionId>,
         workflow_execution_id: Option<uuid::Uuid>,
+        #[serde(default)]
+        iteration_number: Option<u8>,
         volume_id: VolumeId,
         path: String,
         entry_count: usize,
@@ -215,6 +237,8 @@ pub enum StorageEvent {
     FileCreated {
         execution_id: Option<ExecutionId>,
         workflow_execution_id: Option<uuid::Uuid>,
+        #[serde(default)]
+        iteration_number: Option<u8>,
         volume_id: VolumeId,
         path: String,
         created_at: DateTime<Utc>,
@@ -226,6 +250,8 @@ pub enum StorageEvent {
     FileDeleted {
         execution_id: Option<ExecutionId>,
         workflow_execution_id: Option<uuid::Uuid>,
+        #[serde(default)]
+        iteration_number: Option<u8>,
         volume_id: VolumeId,
         path: String,
         deleted_at: DateTime<Utc>,
@@ -243,6 +269,8 @@ pub enum StorageEvent {
     FilesystemPolicyViolation {
         execution_id: Option<ExecutionId>,
         workflow_execution_id: Option<uuid::Uuid>,
+        #[serde(default)]
+        iteration_number: Option<u8>,
         volume_id: VolumeId,
         operation: String, // "read", "write", "delete"
         path: String,
@@ -275,6 +303,118 @@ pub enum StorageEvent {
         /// Node that executed the storage operation (`None` for local operations).
         host_node_id: Option<NodeId>,
     },
+    /// The storage backend failed an operation (see
+    /// [`StorageErrorKind::is_backend_fault`]). Policy, quota and ownership
+    /// denials are recorded by their own variants instead.
+    OperationFailed {
+        execution_id: Option<ExecutionId>,
+        workflow_execution_id: Option<uuid::Uuid>,
+        #[serde(default)]
+        iteration_number: Option<u8>,
+        volume_id: VolumeId,
+        operation: String, // "read", "write", "list", "create", "delete", "rename"
+        path: String,
+        cause: StorageErrorKind,
+        error: String,
+        failed_at: DateTime<Utc>,
+        /// Node that initiated the cross-node RPC (`None` for local operations).
+        caller_node_id: Option<NodeId>,
+        /// Node that executed the storage operation (`None` for local operations).
+        host_node_id: Option<NodeId>,
+    },
+}
+
+impl StorageEvent {
+    /// Agent execution that performed the operation.
+    pub fn execution_id(&self) -> Option<ExecutionId> {
+        match self {
+            StorageEvent::FileOpened { execution_id, .. }
+            | StorageEvent::FileRead { execution_id, .. }
+            | StorageEvent::FileWritten { execution_id, .. }
+            | StorageEvent::FileClosed { execution_id, .. }
+            | StorageEvent::DirectoryListed { execution_id, .. }
+            | StorageEvent::FileCreated { execution_id, .. }
+            | StorageEvent::FileDeleted { execution_id, .. }
+            | StorageEvent::PathTraversalBlocked { execution_id, .. }
+            | StorageEvent::FilesystemPolicyViolation { execution_id, .. }
+            | StorageEvent::QuotaExceeded { execution_id, .. }
+            | StorageEvent::UnauthorizedVolumeAccess { execution_id, .. }
+            | StorageEvent::OperationFailed { execution_id, .. } => *execution_id,
+        }
+    }
+
+    /// Supervisor iteration the operation is attributed to.
+    pub fn iteration_number(&self) -> Option<u8> {
+        match self {
+            StorageEvent::FileOpened {
+                iteration_number, ..
+            }
+            | StorageEvent::FileRead {
+                iteration_number, ..
+            }
+            | StorageEvent::FileWritten {
+                iteration_number, ..
+            }
+            | StorageEvent::FileClosed {
+                iteration_number, ..
+            }
+            | StorageEvent::DirectoryListed {
+                iteration_number, ..
+            }
+            | StorageEvent::FileCreated {
+                iteration_number, ..
+            }
+            | StorageEvent::FileDeleted {
+                iteration_number, ..
+            }
+            | StorageEvent::FilesystemPolicyViolation {
+                iteration_number, ..
+            }
+            | StorageEvent::OperationFailed {
+                iteration_number, ..
+            } => *iteration_number,
+            StorageEvent::PathTraversalBlocked { .. }
+            | StorageEvent::QuotaExceeded { .. }
+            | StorageEvent::UnauthorizedVolumeAccess { .. } => None,
+        }
+    }
+
+    /// Attribute the operation to `iteration`. Variants that do not carry an
+    /// iteration are left unchanged.
+    pub fn set_iteration_number(&mut self, iteration: Option<u8>) {
+        match self {
+            StorageEvent::FileOpened {
+                iteration_number, ..
+            }
+            | StorageEvent::FileRead {
+                iteration_number, ..
+            }
+            | StorageEvent::FileWritten {
+                iteration_number, ..
+            }
+            | StorageEvent::FileClosed {
+                iteration_number, ..
+            }
+            | StorageEvent::DirectoryListed {
+                iteration_number, ..
+            }
+            | StorageEvent::FileCreated {
+                iteration_number, ..
+            }
+            | StorageEvent::FileDeleted {
+                iteration_number, ..
+            }
+            | StorageEvent::FilesystemPolicyViolation {
+                iteration_number, ..
+            }
+            | StorageEvent::OperationFailed {
+                iteration_number, ..
+            } => *iteration_number = iteration,
+            StorageEvent::PathTraversalBlocked { .. }
+            | StorageEvent::QuotaExceeded { .. }
+            | StorageEvent::UnauthorizedVolumeAccess { .. } => {}
+        }
+    }
 }
 
 /// Agent manifest lifecycle events (BC-1 Agent Lifecycle Context).
@@ -337,8 +477,10 @@ pub enum AgentLifecycleEvent {
 /// ExecutionStarted
 ///   └─ IterationStarted (N=1)
 ///       ├─ ConsoleOutput* (streaming)
-///       ├─ LlmInteraction (one per LLM call)
+///       ├─ ModelRouted? / LlmInteraction (one per LLM call)
+///       ├─ OperatorGuidanceAdded* (applied to iteration N+1)
 ///       ├─ InstanceSpawned / InstanceTerminated
+///       ├─ StorageTransportInterrupted? (iteration fails, mounts were stale)
 ///       └─ IterationCompleted | IterationFailed
 ///           └─ RefinementApplied? → IterationStarted (N+1)
 /// ExecutionCompleted | ExecutionFailed | ExecutionCancelled
@@ -423,7 +565,7 @@ pub enum ExecutionEvent {
         execution_id: ExecutionId,
         agent_id: AgentId,
         iteration_number: u8,
-        stream: String, // "stdout" or "stderr"
+        stream: String, // "stdout", "stderr" or "sidecar:<name>"
         content: String,
         timestamp: DateTime<Utc>,
     },
@@ -439,6 +581,31 @@ pub enum ExecutionEvent {
         response: String,
         timestamp: DateTime<Utc>,
     },
+    /// The model router picked the alias for one inner-loop LLM call
+    /// (`spec.llm_selection.routing`). Published for every routed request, also
+    /// when no rule matched and the requested alias was kept (`rule = None`).
+    ModelRouted {
+        execution_id: ExecutionId,
+        agent_id: AgentId,
+        iteration_number: u8,
+        requested_alias: String,
+        routed_alias: String,
+        classification: crate::domain::model_routing::TaskClassification,
+        rule: Option<String>,
+        timestamp: DateTime<Utc>,
+    },
+    /// An operator queued guidance for the execution
+    /// (`POST /v1/executions/{id}/guidance`). It is appended to the prompt of
+    /// `iteration_number`, the next iteration the supervisor starts.
+    /// `submitted_by` attributes the intervention in the audit trail.
+    OperatorGuidanceAdded {
+        execution_id: ExecutionId,
+        agent_id: AgentId,
+        iteration_number: u8,
+        guidance: String,
+        submitted_by: String,
+        timestamp: DateTime<Utc>,
+    },
     /// An LLM call failed after retry/fallback policy was applied.
     ///
     /// Emitted by the dispatch gateway whenever the inner loop surfaces an
@@ -474,6 +641,20 @@ pub enum ExecutionEvent {
         instance_id: InstanceId,
         terminated_at: DateTime<Utc>,
     },
+    /// The storage transport behind the iteration's volume mounts restarted
+    /// while the iteration ran. Its instance was terminated and the
+    /// iteration failed, so the next one starts on fresh mounts.
+    StorageTransportInterrupted {
+        execution_id: ExecutionId,
+        agent_id: AgentId,
+        iteration_number: u8,
+        /// Transport that restarted, e.g. `nfs`.
+        transport: String,
+        /// Restart counter of the transport.
+        generation: u64,
+        reason: String,
+        interrupted_at: DateTime<Utc>,
+    },
     /// A child execution was spawned by this execution (swarm coordination, ADR-039).
     /// `execution_id` is the parent; `child_execution_id` is the spawned child.
     ChildExecutionSpawned {
@@ -516,6 +697,112 @@ pub enum ExecutionEvent {
         handler_type: String,
         error: String,
     },
+
+    /// `spec.moderation` providers reported findings on an output before its
+    /// handler ran. `delivered` is false when the output was withheld;
+    /// `reviewed_by` is the approver who released a flagged output.
+    OutputModerated {
+        execution_id: ExecutionId,
+        handler_type: String,
+        action: crate::domain::moderation::ModerationAction,
+        findings: Vec<crate::domain::moderation::ModerationFinding>,
+        delivered: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        reviewed_by: Option<String>,
+        moderated_at: DateTime<Utc>,
+    },
+}
+
+impl ExecutionEvent {
+    /// Lines kept from each end of a console batch at
+    /// [`EventVerbosity::Summarized`].
+    pub const SUMMARY_EDGE_LINES: usize = 10;
+    /// Characters kept of an LLM prompt or response at
+    /// [`EventVerbosity::Summarized`].
+    pub const SUMMARY_TEXT_CHARS: usize = 2000;
+
+    /// The event as published at `verbosity`, or `None` when it is dropped.
+    pub fn at_verbosity(self, verbosity: EventVerbosity) -> Option<Self> {
+        match (verbosity, self) {
+            (EventVerbosity::Full, event) => Some(event),
+            (EventVerbosity::ErrorsOnly, ExecutionEvent::ConsoleOutput { ref stream, .. })
+                if stream != "stderr" =>
+            {
+                None
+            }
+            (
+                EventVerbosity::ErrorsOnly,
+                ExecutionEvent::LlmInteraction { .. } | ExecutionEvent::ModelRouted { .. },
+            ) => None,
+            (
+                EventVerbosity::Summarized,
+                ExecutionEvent::ConsoleOutput {
+                    execution_id,
+                    agent_id,
+                    iteration_number,
+                    stream,
+                    content,
+                    timestamp,
+                },
+            ) => Some(ExecutionEvent::ConsoleOutput {
+                execution_id,
+                agent_id,
+                iteration_number,
+                stream,
+                content: summarize_lines(&content, Self::SUMMARY_EDGE_LINES),
+                timestamp,
+            }),
+            (
+                EventVerbosity::Summarized,
+                ExecutionEvent::LlmInteraction {
+                    execution_id,
+                    agent_id,
+                    iteration_number,
+                    provider,
+                    model,
+                    input_tokens,
+                    output_tokens,
+                    prompt,
+                    response,
+                    timestamp,
+                },
+            ) => Some(ExecutionEvent::LlmInteraction {
+                execution_id,
+                agent_id,
+                iteration_number,
+                provider,
+                model,
+                input_tokens,
+                output_tokens,
+                prompt: truncate_chars(prompt, Self::SUMMARY_TEXT_CHARS),
+                response: truncate_chars(response, Self::SUMMARY_TEXT_CHARS),
+                timestamp,
+            }),
+            (_, event) => Some(event),
+        }
+    }
+}
+
+/// The first and last `edge` lines of `content`, with a marker for the lines
+/// in between.
+fn summarize_lines(content: &str, edge: usize) -> String {
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.len() <= edge * 2 {
+        return content.to_string();
+    }
+    format!(
+        "{}\n... {} lines omitted ...\n{}",
+        lines[..edge].join("\n"),
+        lines.len() - edge * 2,
+        lines[lines.len() - edge..].join("\n")
+    )
+}
+
+fn truncate_chars(text: String, max: usize) -> String {
+    match text.char_indices().nth(max) {
+        Some((cut, _)) => format!("{}... [truncated]", &text[..cut]),
+        None => text,
+    }
 }
 
 /// Structured classification of an LLM upstream failure.
@@ -583,6 +870,19 @@ pub enum WorkflowEvent {
         output: serde_json::Value,
         exited_at: DateTime<Utc>,
     },
+    /// A running execution has stayed in `state_name` longer than expected.
+    /// Published by the workflow watchdog once per state visit.
+    WorkflowStateStuck {
+        execution_id: ExecutionId,
+        state_name: String,
+        entered_at: DateTime<Utc>,
+        /// Threshold that was exceeded: the state's `timeout`, else
+        /// `spec.workflow_watchdog.default_max_state_seconds`.
+        expected_seconds: u64,
+        /// What the watchdog did about it.
+        action: crate::domain::workflow::StuckStateAction,
+        detected_at: DateTime<Utc>,
+    },
     WorkflowIterationStarted {
         execution_id: ExecutionId,
         iteration_number: u8,
@@ -859,6 +1159,9 @@ pub enum ViolationType {
     MissingRequiredArgument,
     /// The tool call exceeded the per-invocation timeout declared in the `Capability`.
     TimeoutExceeded,
+    /// A matching `Capability` denied the call on one of its conditions
+    /// (argument matcher, time window, or per-execution invocation limit).
+    CapabilityConditionNotMet,
 }
 
 /// MCP Tool server lifecycle and invocation audit events (BC-12 SEAL / Tool Routing, ADR-033).
@@ -1828,4 +2131,46 @@ mod tests {
         let json = serde_json::to_string(&event).unwrap();
         assert!(json.contains("AgentPaused"));
     }
+
+    // ── Event verbosity ───────────────────────────────────────────────────────
+
+    #[test]
+    fn test_execution_event_verbosity_summarizes_and_drops() {
+        let console = |stream: &str, content: String| ExecutionEvent::ConsoleOutput {
+            execution_id: ExecutionId::new(),
+            agent_id: AgentId::new(),
+            iteration_number: 1,
+            stream: stream.to_string(),
+            content,
+            timestamp: Utc::now(),
+        };
+        let batch = (1..=50)
+            .map(|i| format!("line {i}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let Some(ExecutionEvent::ConsoleOutput { content, .. }) =
+            console("stdout", batch.clone()).at_verbosity(EventVerbosity::Summarized)
+        else {
+            panic!("summarized console output should be published");
+        };
+        assert!(content.starts_with("line 1\n"));
+        assert!(content.contains("... 30 lines omitted ..."));
+        assert!(content.ends_with("line 50"));
+
+        assert!(console("stdout", batch.clone())
+            .at_verbosity(EventVerbosity::ErrorsOnly)
+            .is_none());
+        assert!(console("stderr", batch)
+            .at_verbosity(EventVerbosity::ErrorsOnly)
+            .is_some());
+        let failed = ExecutionEvent::ExecutionFailed {
+            execution_id: ExecutionId::new(),
+            agent_id: AgentId::new(),
+            reason: "boom".to_string(),
+            total_iterations: 1,
+            failed_at: Utc::now(),
+        };
+        assert!(failed.at_verbosity(EventVerbosity::ErrorsOnly).is_some());
+    }
 }
@@ -32,6 +32,7 @@ use crate::domain::agent::AgentId;
 use crate::domain::tenant::TenantId;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use thiserror::Error;
 
 // ============================================================================
@@ -180,6 +181,60 @@ pub struct Execution {
     /// user-scoped rate limiting (ADR-072) when the agent runtime calls back in.
     #[serde(default)]
     pub initiating_user_sub: Option<String>,
+
+    /// Content-addressed ID (`sha256:...`) of the orchestrator-built runtime
+    /// image this execution ran on, when the agent declares `spec.runtime.packages`.
+    /// Recorded for provenance; `None` for registry-pulled images.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub runtime_image_digest: Option<String>,
+
+    /// Free-form key/value tags (`release-check`, `customer=acme`) set at
+    /// submit time or via `PATCH /v1/executions/:id`. Children inherit
+    /// their parent's labels. See [`validate_labels`].
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub labels: BTreeMap<String, String>,
+}
+
+/// Maximum number of labels on one execution.
+pub const MAX_EXECUTION_LABELS: usize = 32;
+/// Maximum length of a label key.
+pub const MAX_LABEL_KEY_LEN: usize = 63;
+/// Maximum length of a label value.
+pub const MAX_LABEL_VALUE_LEN: usize = 256;
+
+/// Check execution labels: at most [`MAX_EXECUTION_LABELS`] entries; keys
+/// start with an ASCII letter or digit and contain only letters, digits and
+/// `-_.:/`; values are free text without control characters. Values may be
+/// empty, so a bare tag is `release-check=""`.
+pub fn validate_labels(labels: &BTreeMap<String, String>) -> Result<(), ExecutionError> {
+    if labels.len() > MAX_EXECUTION_LABELS {
+        return Err(ExecutionError::InvalidLabels(format!(
+            "at most {MAX_EXECUTION_LABELS} labels are allowed, got {}",
+            labels.len()
+        )));
+    }
+    for (key, value) in labels {
+        let well_formed = key
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphanumeric())
+            && key
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | ':' | '/'));
+        if !well_formed || key.len() > MAX_LABEL_KEY_LEN {
+            return Err(ExecutionError::InvalidLabels(format!(
+                "invalid label key '{key}': expected 1-{MAX_LABEL_KEY_LEN} characters of \
+                 [A-Za-z0-9-_.:/] starting with a letter or digit"
+            )));
+        }
+        if value.len() > MAX_LABEL_VALUE_LEN || value.chars().any(char::is_control) {
+            return Err(ExecutionError::InvalidLabels(format!(
+                "invalid value for label '{key}': at most {MAX_LABEL_VALUE_LEN} bytes \
+                 without control characters"
+            )));
+        }
+    }
+    Ok(())
 }
 
 fn default_container_uid() -> u32 {
@@ -230,6 +285,13 @@ pub struct ExecutionInput {
     pub attachments: Vec<AttachmentRef>,
 }
 
+/// Directory inside an attachment volume that holds uploaded files.
+pub const ATTACHMENT_DIR: &str = "attachments";
+
+/// Workspace directory whose files are returned as the execution's output
+/// attachments. Agents write deliverables to `/workspace/outputs/`.
+pub const OUTPUT_ATTACHMENT_DIR: &str = "outputs";
+
 /// Structured reference to a file attached at dispatch time (ADR-113).
 ///
 /// Mirrors the `aegis_runtime.AttachmentRef` proto message and is carried on
@@ -253,6 +315,30 @@ pub struct AttachmentRef {
     pub sha256: Option<String>,
 }
 
+impl AttachmentRef {
+    /// Path for the uploaded file at `index` inside its attachment volume.
+    /// The index prefix keeps two files with the same name apart; the
+    /// filename is reduced to a single safe path component.
+    pub fn storage_path(index: usize, filename: &str) -> String {
+        let name: String = filename
+            .rsplit(['/', '\\'])
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let name = name.trim_start_matches('.');
+        let name = if name.is_empty() { "attachment" } else { name };
+        format!("{ATTACHMENT_DIR}/{index:02}-{name}")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ExecutionStatus {
     Pending,
@@ -262,6 +348,16 @@ pub enum ExecutionStatus {
     Cancelled,
 }
 
+impl ExecutionStatus {
+    /// `true` once the execution can no longer make progress.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            ExecutionStatus::Completed | ExecutionStatus::Failed | ExecutionStatus::Cancelled
+        )
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Iteration {
     pub number: u8,
@@ -301,6 +397,12 @@ pub struct LlmInteraction {
     pub prompt: String,
     pub response: String,
     pub timestamp: DateTime<Utc>,
+    /// Prompt tokens reported by the provider; `None` when not reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_tokens: Option<u32>,
+    /// Completion tokens reported by the provider; `None` when not reported.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_tokens: Option<u32>,
 }
 
 use crate::domain::validation::ValidationResults;
@@ -355,6 +457,8 @@ pub enum ExecutionError {
         requested_agent_tenant: String,
         caller_tenant: String,
     },
+    #[error("Invalid execution labels: {0}")]
+    InvalidLabels(String),
 }
 
 impl Execution {
@@ -398,6 +502,8 @@ impl Execution {
             hierarchy: ExecutionHierarchy::root(id),
             security_context_name,
             initiating_user_sub: None,
+            runtime_image_digest: None,
+            labels: BTreeMap::new(),
         }
     }
 
@@ -428,6 +534,8 @@ impl Execution {
             hierarchy,
             security_context_name: parent.security_context_name.clone(),
             initiating_user_sub: parent.initiating_user_sub.clone(),
+            runtime_image_digest: None,
+            labels: parent.labels.clone(),
         })
     }
 
@@ -834,6 +942,8 @@ mod tests {
             prompt: "write hello world".to_string(),
             response: "print('hello')".to_string(),
             timestamp: chrono::Utc::now(),
+            input_tokens: None,
+            output_tokens: None,
         };
         exec.add_llm_interaction(1, interaction).unwrap();
         assert_eq!(exec.iterations()[0].llm_interactions.len(), 1);
@@ -855,6 +965,8 @@ mod tests {
             prompt: "prompt".to_string(),
             response: "response".to_string(),
             timestamp: chrono::Utc::now(),
+            input_tokens: None,
+            output_tokens: None,
         };
         let err = exec.add_llm_interaction(99, interaction).unwrap_err();
         assert!(matches!(err, ExecutionError::IterationNotFound(99)));
@@ -924,4 +1036,39 @@ mod tests {
         assert!(info.ended_at.is_some());
         assert!(info.error.is_none());
     }
+
+    // ── Labels ────────────────────────────────────────────────────────────────
+
+    #[test]
+    fn test_validate_labels() {
+        let labels = |pairs: &[(&str, &str)]| -> BTreeMap<String, String> {
+            pairs
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        };
+        assert!(validate_labels(&labels(&[("customer:acme", ""), ("team", "infra")])).is_ok());
+        assert!(validate_labels(&labels(&[("-leading", "x")])).is_err());
+        assert!(validate_labels(&labels(&[("has space", "x")])).is_err());
+        assert!(validate_labels(&labels(&[("ok", "line\nbreak")])).is_err());
+        let too_many: BTreeMap<String, String> = (0..=MAX_EXECUTION_LABELS)
+            .map(|i| (format!("k{i}"), String::new()))
+            .collect();
+        assert!(validate_labels(&too_many).is_err());
+    }
+
+    #[test]
+    fn test_child_inherits_labels() {
+        let mut parent = Execution::new(
+            AgentId::new(),
+            make_input("parent"),
+            3,
+            "aegis-system-operator".to_string(),
+        );
+        parent
+            .labels
+            .insert("release-check".to_string(), String::new());
+        let child = Execution::new_child(AgentId::new(), make_input("child"), 3, &parent).unwrap();
+        assert_eq!(child.labels, parent.labels);
+    }
 }
@@ -0,0 +1,618 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Execution Filter Expressions
+//!
+//! A small filter grammar for listing executions without a dedicated query
+//! parameter per field, e.g. `status=failed AND agent=foo AND started>-24h`.
+//! Used by `GET /v1/executions?filter=...` and `aegis task list --filter`.
+//!
+//! ```text
+//! query     := condition ( "AND" condition )*
+//! condition := field op value
+//! field     := "status" | "agent" | "started" | "ended" | "label" | "label." key
+//! op        := "=" | "!=" | ">" | ">=" | "<" | "<="
+//! value     := bare-word | "double quoted" | 'single quoted'
+//! ```
+//!
+//! `status` and `agent` accept `=` / `!=` only. `agent` takes an agent UUID
+//! or name; names are resolved to ids by the caller before querying
+//! ([`ExecutionQuery::unresolved_agent_names`]). `started` and `ended` take
+//! an RFC 3339 timestamp, a `YYYY-MM-DD` date (UTC midnight), or an offset
+//! relative to now such as `-30m`, `-24h`, `-7d` or `-2w`. `label.KEY=VALUE`
+//! matches an execution label (`!=` also matches executions without it);
+//! `label=KEY` / `label!=KEY` test whether the key is present at all.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Parse filter expressions into typed predicates that
+//!   repositories translate to their native query language
+
+use crate::domain::agent::AgentId;
+use crate::domain::execution::{Execution, ExecutionStatus};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::fmt;
+use thiserror::Error;
+
+/// Comparison operator of a single condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareOp {
+    Eq,
+    Ne,
+    Gt,
+    Ge,
+    Lt,
+    Le,
+}
+
+impl CompareOp {
+    /// SQL spelling of the operator.
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            CompareOp::Eq => "=",
+            CompareOp::Ne => "<>",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+        }
+    }
+
+    fn compare<T: PartialOrd>(&self, left: &T, right: &T) -> bool {
+        match self {
+            CompareOp::Eq => left == right,
+            CompareOp::Ne => left != right,
+            CompareOp::Gt => left > right,
+            CompareOp::Ge => left >= right,
+            CompareOp::Lt => left < right,
+            CompareOp::Le => left <= right,
+        }
+    }
+}
+
+impl fmt::Display for CompareOp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            CompareOp::Eq => "=",
+            CompareOp::Ne => "!=",
+            CompareOp::Gt => ">",
+            CompareOp::Ge => ">=",
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+        };
+        f.write_str(symbol)
+    }
+}
+
+/// The agent an `agent=` condition refers to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AgentSelector {
+    Id(AgentId),
+    /// Not yet resolved to an id; never matches anything.
+    Name(String),
+}
+
+/// One parsed condition. All predicates of a query are AND-ed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutionPredicate {
+    Status {
+        op: CompareOp,
+        status: ExecutionStatus,
+    },
+    Agent {
+        op: CompareOp,
+        agent: AgentSelector,
+    },
+    Started {
+        op: CompareOp,
+        at: DateTime<Utc>,
+    },
+    /// Executions that have not ended never match.
+    Ended {
+        op: CompareOp,
+        at: DateTime<Utc>,
+    },
+    /// `value: None` tests for the presence of `key`.
+    Label {
+        op: CompareOp,
+        key: String,
+        value: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ExecutionQueryError {
+    #[error("filter expression is empty")]
+    Empty,
+
+    #[error("expected {expected} but the filter ended")]
+    UnexpectedEnd { expected: &'static str },
+
+    #[error("expected {expected} but found '{found}'")]
+    Unexpected {
+        expected: &'static str,
+        found: String,
+    },
+
+    #[error(
+        "unknown filter field '{0}' (expected status, agent, started, ended, label or label.KEY)"
+    )]
+    UnknownField(String),
+
+    #[error("operator '{op}' is not supported for field '{field}'")]
+    UnsupportedOperator { field: &'static str, op: CompareOp },
+
+    #[error("invalid value '{value}' for field '{field}': {reason}")]
+    InvalidValue {
+        field: &'static str,
+        value: String,
+        reason: String,
+    },
+
+    #[error("unterminated quoted value")]
+    UnterminatedQuote,
+}
+
+/// A parsed filter expression.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ExecutionQuery {
+    pub predicates: Vec<ExecutionPredicate>,
+}
+
+impl ExecutionQuery {
+    /// Parse `input`, resolving relative timestamps against `now`.
+    pub fn parse(input: &str, now: DateTime<Utc>) -> Result<Self, ExecutionQueryError> {
+        let mut tokens = tokenize(input)?.into_iter().peekable();
+        if tokens.peek().is_none() {
+            return Err(ExecutionQueryError::Empty);
+        }
+
+        let mut predicates = Vec::new();
+        loop {
+            let field = match tokens.next() {
+                Some(Token::Word(word)) => word,
+                Some(other) => {
+                    return Err(ExecutionQueryError::Unexpected {
+                        expected: "a field name",
+                        found: other.to_string(),
+                    })
+                }
+                None => {
+                    return Err(ExecutionQueryError::UnexpectedEnd {
+                        expected: "a field name",
+                    })
+                }
+            };
+            let op = match tokens.next() {
+                Some(Token::Op(op)) => op,
+                Some(other) => {
+                    return Err(ExecutionQueryError::Unexpected {
+                        expected: "an operator",
+                        found: other.to_string(),
+                    })
+                }
+                None => {
+                    return Err(ExecutionQueryError::UnexpectedEnd {
+                        expected: "an operator",
+                    })
+                }
+            };
+            let value = match tokens.next() {
+                Some(Token::Word(value)) | Some(Token::Quoted(value)) => value,
+                Some(other) => {
+                    return Err(ExecutionQueryError::Unexpected {
+                        expected: "a value",
+                        found: other.to_string(),
+                    })
+                }
+                None => {
+                    return Err(ExecutionQueryError::UnexpectedEnd {
+                        expected: "a value",
+                    })
+                }
+            };
+            predicates.push(parse_predicate(&field, op, &value, now)?);
+
+            match tokens.next() {
+                None => break,
+                Some(Token::Word(word)) if word.eq_ignore_ascii_case("and") => {}
+                Some(other) => {
+                    return Err(ExecutionQueryError::Unexpected {
+                        expected: "AND",
+                        found: other.to_string(),
+                    })
+                }
+            }
+        }
+
+        Ok(Self { predicates })
+    }
+
+    /// Agent names that still need resolving to ids.
+    pub fn unresolved_agent_names(&self) -> Vec<String> {
+        self.predicates
+            .iter()
+            .filter_map(|predicate| match predicate {
+                ExecutionPredicate::Agent {
+                    agent: AgentSelector::Name(name),
+                    ..
+                } => Some(name.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Replace every `agent=<name>` selector for `name` with `id`.
+    pub fn resolve_agent_name(&mut self, name: &str, id: AgentId) {
+        for predicate in &mut self.predicates {
+            if let ExecutionPredicate::Agent { agent, .. } = predicate {
+                if matches!(agent, AgentSelector::Name(n) if n == name) {
+                    *agent = AgentSelector::Id(id);
+                }
+            }
+        }
+    }
+
+    /// In-memory evaluation, for repositories without a query language.
+    pub fn matches(&self, execution: &Execution) -> bool {
+        self.predicates.iter().all(|predicate| match predicate {
+            ExecutionPredicate::Status { op, status } => match op {
+                CompareOp::Ne => execution.status != *status,
+                _ => execution.status == *status,
+            },
+            ExecutionPredicate::Agent { op, agent } => match agent {
+                AgentSelector::Id(id) => match op {
+                    CompareOp::Ne => execution.agent_id != *id,
+                    _ => execution.agent_id == *id,
+                },
+                AgentSelector::Name(_) => false,
+            },
+            ExecutionPredicate::Started { op, at } => op.compare(&execution.started_at, at),
+            ExecutionPredicate::Ended { op, at } => execution
+                .ended_at
+                .is_some_and(|ended_at| op.compare(&ended_at, at)),
+            ExecutionPredicate::Label { op, key, value } => {
+                let found = match value {
+                    Some(value) => execution.labels.get(key) == Some(value),
+                    None => execution.labels.contains_key(key),
+                };
+                found != (*op == CompareOp::Ne)
+            }
+        })
+    }
+}
+
+fn parse_predicate(
+    field: &str,
+    op: CompareOp,
+    value: &str,
+    now: DateTime<Utc>,
+) -> Result<ExecutionPredicate, ExecutionQueryError> {
+    match field.to_ascii_lowercase().as_str() {
+        "status" => {
+            require_equality("status", op)?;
+            let status = match value.to_ascii_lowercase().as_str() {
+                "pending" => ExecutionStatus::Pending,
+                "running" => ExecutionStatus::Running,
+                "completed" => ExecutionStatus::Completed,
+                "failed" => ExecutionStatus::Failed,
+                "cancelled" => ExecutionStatus::Cancelled,
+                _ => {
+                    return Err(ExecutionQueryError::InvalidValue {
+                        field: "status",
+                        value: value.to_string(),
+                        reason: "expected pending, running, completed, failed or cancelled"
+                            .to_string(),
+                    })
+                }
+            };
+            Ok(ExecutionPredicate::Status { op, status })
+        }
+        "agent" => {
+            require_equality("agent", op)?;
+            let agent = match uuid::Uuid::parse_str(value) {
+                Ok(id) => AgentSelector::Id(AgentId(id)),
+                Err(_) => AgentSelector::Name(value.to_string()),
+            };
+            Ok(ExecutionPredicate::Agent { op, agent })
+        }
+        "started" => Ok(ExecutionPredicate::Started {
+            op,
+            at: parse_timestamp("started", value, now)?,
+        }),
+        "ended" => Ok(ExecutionPredicate::Ended {
+            op,
+            at: parse_timestamp("ended", value, now)?,
+        }),
+        "label" => {
+            require_equality("label", op)?;
+            Ok(ExecutionPredicate::Label {
+                op,
+                key: value.to_string(),
+                value: None,
+            })
+        }
+        _ => match field.strip_prefix("label.").filter(|key| !key.is_empty()) {
+            Some(key) => {
+                require_equality("label", op)?;
+                Ok(ExecutionPredicate::Label {
+                    op,
+                    key: key.to_string(),
+                    value: Some(value.to_string()),
+                })
+            }
+            None => Err(ExecutionQueryError::UnknownField(field.to_string())),
+        },
+    }
+}
+
+fn require_equality(field: &'static str, op: CompareOp) -> Result<(), ExecutionQueryError> {
+    match op {
+        CompareOp::Eq | CompareOp::Ne => Ok(()),
+        _ => Err(ExecutionQueryError::UnsupportedOperator { field, op }),
+    }
+}
+
+fn parse_timestamp(
+    field: &'static str,
+    value: &str,
+    now: DateTime<Utc>,
+) -> Result<DateTime<Utc>, ExecutionQueryError> {
+    let invalid = |reason: &str| ExecutionQueryError::InvalidValue {
+        field,
+        value: value.to_string(),
+        reason: reason.to_string(),
+    };
+
+    if let Some(offset) = value.strip_prefix('-') {
+        let unit_at = offset
+            .find(|c: char| !c.is_ascii_digit())
+            .ok_or_else(|| invalid("relative offsets need a unit (s, m, h, d or w)"))?;
+        let (amount, unit) = offset.split_at(unit_at);
+        let amount: i64 = amount
+            .parse()
+            .map_err(|_| invalid("expected a number before the unit"))?;
+        let duration = match unit {
+            "s" => Duration::try_seconds(amount),
+            "m" => Duration::try_minutes(amount),
+            "h" => Duration::try_hours(amount),
+            "d" => Duration::try_days(amount),
+            "w" => Duration::try_weeks(amount),
+            _ => return Err(invalid("unknown unit (expected s, m, h, d or w)")),
+        }
+        .ok_or_else(|| invalid("offset out of range"))?;
+        return now
+            .checked_sub_signed(duration)
+            .ok_or_else(|| invalid("offset out of range"));
+    }
+
+    if let Ok(at) = DateTime::parse_from_rfc3339(value) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        if let Some(midnight) = date.and_hms_opt(0, 0, 0) {
+            return Ok(midnight.and_utc());
+        }
+    }
+    Err(invalid(
+        "expected an RFC 3339 timestamp, a YYYY-MM-DD date or a relative offset like -24h",
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Word(String),
+    Quoted(String),
+    Op(CompareOp),
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Token::Word(word) => f.write_str(word),
+            Token::Quoted(value) => write!(f, "\"{value}\""),
+            Token::Op(op) => write!(f, "{op}"),
+        }
+    }
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ExecutionQueryError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.chars().peekable();
+
+    while let Some(&c) = chars.peek() {
+        match c {
+            c if c.is_whitespace() => {
+                chars.next();
+            }
+            '"' | '\'' => {
+                chars.next();
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some(ch) if ch == c => break,
+                        Some(ch) => value.push(ch),
+                        None => return Err(ExecutionQueryError::UnterminatedQuote),
+                    }
+                }
+                tokens.push(Token::Quoted(value));
+            }
+            '=' | '!' | '<' | '>' => {
+                chars.next();
+                let followed_by_eq = chars.peek() == Some(&'=');
+                let op = match (c, followed_by_eq) {
+                    ('=', _) => CompareOp::Eq,
+                    ('!', true) => CompareOp::Ne,
+                    ('<', true) => CompareOp::Le,
+                    ('>', true) => CompareOp::Ge,
+                    ('<', false) => CompareOp::Lt,
+                    ('>', false) => CompareOp::Gt,
+                    _ => {
+                        return Err(ExecutionQueryError::Unexpected {
+                            expected: "'!='",
+                            found: "!".to_string(),
+                        })
+                    }
+                };
+                if followed_by_eq && c != '=' {
+                    chars.next();
+                }
+                tokens.push(Token::Op(op));
+            }
+            _ => {
+                let mut word = String::new();
+                while let Some(&ch) = chars.peek() {
+                    if ch.is_whitespace() || matches!(ch, '=' | '!' | '<' | '>' | '"' | '\'') {
+                        break;
+                    }
+                    word.push(ch);
+                    chars.next();
+                }
+                tokens.push(Token::Word(word));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 10, 14, 12, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn parses_conjunction_with_relative_time() {
+        let query =
+            ExecutionQuery::parse("status=failed AND agent=foo AND started>-24h", now()).unwrap();
+        assert_eq!(
+            query.predicates,
+            vec![
+                ExecutionPredicate::Status {
+                    op: CompareOp::Eq,
+                    status: ExecutionStatus::Failed,
+                },
+                ExecutionPredicate::Agent {
+                    op: CompareOp::Eq,
+                    agent: AgentSelector::Name("foo".to_string()),
+                },
+                ExecutionPredicate::Started {
+                    op: CompareOp::Gt,
+                    at: Utc.with_ymd_and_hms(2026, 10, 13, 12, 0, 0).unwrap(),
+                },
+            ]
+        );
+        assert_eq!(query.unresolved_agent_names(), vec!["foo".to_string()]);
+    }
+
+    #[test]
+    fn parses_spaced_operators_quotes_and_absolute_times() {
+        let query = ExecutionQuery::parse(
+            "agent != 'my agent' and ended <= 2026-10-01 AND started >= \"2026-09-30T08:00:00+02:00\"",
+            now(),
+        )
+        .unwrap();
+        assert_eq!(
+            query.predicates,
+            vec![
+                ExecutionPredicate::Agent {
+                    op: CompareOp::Ne,
+                    agent: AgentSelector::Name("my agent".to_string()),
+                },
+                ExecutionPredicate::Ended {
+                    op: CompareOp::Le,
+                    at: Utc.with_ymd_and_hms(2026, 10, 1, 0, 0, 0).unwrap(),
+                },
+                ExecutionPredicate::Started {
+                    op: CompareOp::Ge,
+                    at: Utc.with_ymd_and_hms(2026, 9, 30, 6, 0, 0).unwrap(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_expressions() {
+        let parse = |input: &str| ExecutionQuery::parse(input, now()).unwrap_err();
+        assert_eq!(parse("  "), ExecutionQueryError::Empty);
+        assert_eq!(
+            parse("owner=bob"),
+            ExecutionQueryError::UnknownField("owner".to_string())
+        );
+        assert_eq!(
+            parse("status>failed"),
+            ExecutionQueryError::UnsupportedOperator {
+                field: "status",
+                op: CompareOp::Gt,
+            }
+        );
+        assert!(matches!(
+            parse("status=done"),
+            ExecutionQueryError::InvalidValue {
+                field: "status",
+                ..
+            }
+        ));
+        assert!(matches!(
+            parse("started>-24x"),
+            ExecutionQueryError::InvalidValue {
+                field: "started",
+                ..
+            }
+        ));
+        assert_eq!(
+            parse("status=failed agent=foo"),
+            ExecutionQueryError::Unexpected {
+                expected: "AND",
+                found: "agent".to_string(),
+            }
+        );
+        assert_eq!(
+            parse("status="),
+            ExecutionQueryError::UnexpectedEnd {
+                expected: "a value"
+            }
+        );
+        assert_eq!(parse("agent='foo"), ExecutionQueryError::UnterminatedQuote);
+    }
+
+    #[test]
+    fn parses_label_predicates() {
+        let query =
+            ExecutionQuery::parse("label.customer=acme AND label!=release-check", now()).unwrap();
+        assert_eq!(
+            query.predicates,
+            vec![
+                ExecutionPredicate::Label {
+                    op: CompareOp::Eq,
+                    key: "customer".to_string(),
+                    value: Some("acme".to_string()),
+                },
+                ExecutionPredicate::Label {
+                    op: CompareOp::Ne,
+                    key: "release-check".to_string(),
+                    value: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn resolved_agent_selectors_replace_names() {
+        let mut query = ExecutionQuery::parse("agent=foo", now()).unwrap();
+        let id = AgentId(uuid::Uuid::new_v4());
+        query.resolve_agent_name("foo", id);
+        assert!(query.unresolved_agent_names().is_empty());
+        assert_eq!(
+            query.predicates,
+            vec![ExecutionPredicate::Agent {
+                op: CompareOp::Eq,
+                agent: AgentSelector::Id(id),
+            }]
+        );
+    }
+}
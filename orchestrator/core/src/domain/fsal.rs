@@ -28,7 +28,10 @@ use crate::domain::{
     path_sanitizer::{PathSanitizer, PathSanitizerError},
     policy::FilesystemPolicy,
     repository::VolumeRepository,
-    storage::{DirEntry, FileAttributes, FileHandle, OpenMode, StorageError, StorageProvider},
+    storage::{
+        DirEntry, FileAttributes, FileHandle, FileType, OpenMode, StorageError, StorageErrorKind,
+        StorageProvider,
+    },
     volume::{Volume, VolumeId, VolumeStatus},
 };
 
@@ -65,6 +68,7 @@ impl From<FilesystemPolicy> for FsalAccessPolicy {
     }
 }
 use async_trait::async_trait;
+use bytes::Bytes;
 use chrono::Utc;
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
@@ -111,6 +115,35 @@ pub enum FsalError {
         requested_bytes: u64,
         available_bytes: u64,
     },
+
+    #[error("File is {size} bytes, over the {limit} byte limit for this read")]
+    FileTooLarge { size: u64, limit: u64 },
+}
+
+impl FsalError {
+    /// Machine-readable cause, shared with [`StorageError::kind`].
+    pub fn kind(&self) -> StorageErrorKind {
+        match self {
+            FsalError::UnauthorizedAccess { .. } | FsalError::PolicyViolation(_) => {
+                StorageErrorKind::Policy
+            }
+            FsalError::PathSanitization(e) => match e {
+                PathSanitizerError::PathTraversal(_) | PathSanitizerError::OutsideBoundary(_) => {
+                    StorageErrorKind::Policy
+                }
+                PathSanitizerError::InvalidPath(_) | PathSanitizerError::PathTooLong(_) => {
+                    StorageErrorKind::InvalidRequest
+                }
+            },
+            FsalError::VolumeNotFound(_) => StorageErrorKind::NotFound,
+            FsalError::VolumeNotAttached(_) => StorageErrorKind::BackendUnavailable,
+            FsalError::InvalidFileHandle
+            | FsalError::HandleDeserialization(_)
+            | FsalError::FileTooLarge { .. } => StorageErrorKind::InvalidRequest,
+            FsalError::QuotaExceeded { .. } => StorageErrorKind::Quota,
+            FsalError::Storage(e) => e.kind(),
+        }
+    }
 }
 
 /// Compact execution context for NFSv3 file handles.
@@ -276,11 +309,12 @@ pub struct CreateFsalFileRequest<'a> {
     pub workflow_execution_id: Option<uuid::Uuid>,
 }
 
-/// Provenance context for a filesystem policy violation event.
+/// Provenance context for a policy violation or failed operation event.
 ///
-/// Groups the metadata required by [`AegisFSAL::emit_policy_violation`] to
-/// keep that private helper within Clippy's function-argument-count limit.
-struct PolicyViolationContext {
+/// Groups the metadata required by [`AegisFSAL::emit_policy_violation`] and
+/// [`AegisFSAL::check_backend`] to keep those private helpers within Clippy's
+/// function-argument-count limit.
+struct StorageOperationContext {
     execution_id: Option<ExecutionId>,
     workflow_execution_id: Option<uuid::Uuid>,
     volume_id: VolumeId,
@@ -289,6 +323,44 @@ struct PolicyViolationContext {
     host_node_id: Option<NodeId>,
 }
 
+impl StorageOperationContext {
+    /// Context for an operation by `execution_id`, or by the workflow
+    /// execution when one is given.
+    fn new(
+        execution_id: ExecutionId,
+        workflow_execution_id: Option<uuid::Uuid>,
+        volume_id: VolumeId,
+        operation: &'static str,
+        caller_node_id: Option<NodeId>,
+        host_node_id: Option<NodeId>,
+    ) -> Self {
+        Self {
+            execution_id: if workflow_execution_id.is_some() {
+                None
+            } else {
+                Some(execution_id)
+            },
+            workflow_execution_id,
+            volume_id,
+            operation,
+            caller_node_id,
+            host_node_id,
+        }
+    }
+
+    /// Context for a local operation through `handle`.
+    fn for_handle(handle: &AegisFileHandle, operation: &'static str) -> Self {
+        Self {
+            execution_id: handle.execution_id().copied(),
+            workflow_execution_id: handle.workflow_execution_id(),
+            volume_id: handle.volume_id,
+            operation,
+            caller_node_id: None,
+            host_node_id: None,
+        }
+    }
+}
+
 /// AegisFSAL - File System Abstraction Layer
 ///
 /// Domain entity that enforces security policies and provides audit trail
@@ -325,6 +397,12 @@ pub trait EventPublisher: Send + Sync {
 pub trait VolumeContextLookup: Send + Sync {
     /// Returns the `workflow_execution_id` registered for the given volume, if any.
     fn lookup_workflow_execution_id(&self, volume_id: VolumeId) -> Option<uuid::Uuid>;
+
+    /// Returns the supervisor iteration currently running for `execution_id`,
+    /// used to attribute storage events to the iteration that caused them.
+    fn lookup_iteration_number(&self, _execution_id: ExecutionId) -> Option<u8> {
+        None
+    }
 }
 
 /// Borrowed read-only access to an existing volume, exposed under a distinct alias volume ID.
@@ -358,6 +436,17 @@ impl AegisFSAL {
         self
     }
 
+    /// Publish `event`, attributing it to the supervisor iteration currently
+    /// running for its execution.
+    async fn publish_event(&self, mut event: StorageEvent) {
+        if let (Some(lookup), Some(execution_id)) =
+            (&self.volume_context_lookup, event.execution_id())
+        {
+            event.set_iteration_number(lookup.lookup_iteration_number(execution_id));
+        }
+        self.event_publisher.publish_storage_event(event).await;
+    }
+
     /// Expose storage provider for direct use by application services (e.g. FileOperationsService)
     pub fn storage_provider(&self) -> &Arc<dyn crate::domain::storage::StorageProvider> {
         &self.storage_provider
@@ -450,16 +539,15 @@ impl AegisFSAL {
                 .map(|eid| borrowed.execution_id == eid)
                 .unwrap_or(false);
             if !is_owner {
-                self.event_publisher
-                    .publish_storage_event(StorageEvent::UnauthorizedVolumeAccess {
-                        execution_id,
-                        workflow_execution_id,
-                        volume_id,
-                        attempted_at: Utc::now(),
-                        caller_node_id: None,
-                        host_node_id: None,
-                    })
-                    .await;
+                self.publish_event(StorageEvent::UnauthorizedVolumeAccess {
+                    execution_id,
+                    workflow_execution_id,
+                    volume_id,
+                    attempted_at: Utc::now(),
+                    caller_node_id: None,
+                    host_node_id: None,
+                })
+                .await;
 
                 return Err(FsalError::UnauthorizedAccess {
                     execution_id: execution_id.unwrap_or_default(),
@@ -523,16 +611,15 @@ impl AegisFSAL {
         };
 
         if !is_owner {
-            self.event_publisher
-                .publish_storage_event(StorageEvent::UnauthorizedVolumeAccess {
-                    execution_id,
-                    workflow_execution_id,
-                    volume_id,
-                    attempted_at: Utc::now(),
-                    caller_node_id: None,
-                    host_node_id: None,
-                })
-                .await;
+            self.publish_event(StorageEvent::UnauthorizedVolumeAccess {
+                execution_id,
+                workflow_execution_id,
+                volume_id,
+                attempted_at: Utc::now(),
+                caller_node_id: None,
+                host_node_id: None,
+            })
+            .await;
 
             return Err(FsalError::UnauthorizedAccess {
                 execution_id: execution_id.unwrap_or_default(),
@@ -591,6 +678,197 @@ impl AegisFSAL {
         Ok(volume)
     }
 
+    /// Whether `user_id` in `tenant_id` may browse `volume`: their own
+    /// persistent volumes and any execution or workflow workspace in the
+    /// tenant. Does not check volume status or publish events; see
+    /// [`Self::authorize_browse`].
+    pub fn browse_permitted(
+        volume: &Volume,
+        tenant_id: &crate::domain::tenant::TenantId,
+        user_id: &str,
+    ) -> bool {
+        &volume.tenant_id == tenant_id
+            && match &volume.ownership {
+                crate::domain::volume::VolumeOwnership::Persistent { owner } => owner == user_id,
+                crate::domain::volume::VolumeOwnership::Execution { .. }
+                | crate::domain::volume::VolumeOwnership::WorkflowExecution { .. } => true,
+            }
+    }
+
+    /// Authorize read-only browsing of a volume through the REST API
+    /// (`/v1/volumes/:id/files`).
+    ///
+    /// Callers may browse their own persistent volumes, and the execution and
+    /// workflow workspace volumes of their tenant so agent output can be
+    /// inspected without mounting NFS. Denials publish
+    /// `UnauthorizedVolumeAccess`.
+    pub async fn authorize_browse(
+        &self,
+        tenant_id: &crate::domain::tenant::TenantId,
+        user_id: &str,
+        volume_id: &VolumeId,
+    ) -> Result<Volume, FsalError> {
+        let volume = self
+            .volume_repository
+            .find_by_id(*volume_id)
+            .await
+            .map_err(|_| FsalError::VolumeNotFound(*volume_id))?
+            .ok_or(FsalError::VolumeNotFound(*volume_id))?;
+
+        if !Self::browse_permitted(&volume, tenant_id, user_id) {
+            let (execution_id, workflow_execution_id) = browse_event_context(&volume);
+            self.publish_event(StorageEvent::UnauthorizedVolumeAccess {
+                execution_id,
+                workflow_execution_id,
+                volume_id: *volume_id,
+                attempted_at: Utc::now(),
+                caller_node_id: None,
+                host_node_id: None,
+            })
+            .await;
+            return Err(FsalError::UnauthorizedAccess {
+                execution_id: execution_id.unwrap_or_default(),
+                volume_id: *volume_id,
+            });
+        }
+
+        match &volume.status {
+            VolumeStatus::Available | VolumeStatus::Attached => Ok(volume),
+            _ => Err(FsalError::VolumeNotAttached(*volume_id)),
+        }
+    }
+
+    /// List `path` on a volume returned by [`Self::authorize_browse`].
+    /// Publishes `DirectoryListed`.
+    pub async fn browse_readdir(
+        &self,
+        volume: &Volume,
+        path: &str,
+    ) -> Result<Vec<(DirEntry, Option<FileAttributes>)>, FsalError> {
+        let canonical = self.path_sanitizer.canonicalize(path, Some("/"))?;
+        let path_str = canonical.to_string_lossy().replace('\\', "/");
+        let full_path = self.routed_storage_path(volume, &path_str);
+
+        let entries = self.storage_provider.readdir(&full_path).await?;
+        let mut listed = Vec::with_capacity(entries.len());
+        for entry in entries {
+            let child = format!("{}/{}", full_path.trim_end_matches('/'), entry.name);
+            let attrs = self.storage_provider.stat(&child).await.ok();
+            listed.push((entry, attrs));
+        }
+
+        let (execution_id, workflow_execution_id) = browse_event_context(volume);
+        self.event_publisher
+            .publish_storage_event(StorageEvent::DirectoryListed {
+                execution_id,
+                workflow_execution_id,
+                iteration_number: None,
+                volume_id: volume.id,
+                path: path_str,
+                entry_count: listed.len(),
+                listed_at: Utc::now(),
+                caller_node_id: None,
+                host_node_id: None,
+            })
+            .await;
+
+        Ok(listed)
+    }
+
+    /// Read `path` on a volume returned by [`Self::authorize_browse`].
+    /// Files larger than `max_bytes` are rejected with
+    /// [`FsalError::FileTooLarge`] before any data is read. Publishes
+    /// `FileRead`.
+    pub async fn browse_read(
+        &self,
+        volume: &Volume,
+        path: &str,
+        max_bytes: u64,
+    ) -> Result<Bytes, FsalError> {
+        let start = std::time::Instant::now();
+        let canonical = self.path_sanitizer.canonicalize(path, Some("/"))?;
+        let path_str = canonical.to_string_lossy().replace('\\', "/");
+        let full_path = self.routed_storage_path(volume, &path_str);
+
+        let attrs = self.storage_provider.stat(&full_path).await?;
+        if attrs.size > max_bytes {
+            return Err(FsalError::FileTooLarge {
+                size: attrs.size,
+                limit: max_bytes,
+            });
+        }
+
+        let handle = self
+            .storage_provider
+            .open_file(&full_path, OpenMode::ReadOnly)
+            .await?;
+        let data = self
+            .storage_provider
+            .read_at_bytes(&handle, 0, attrs.size as usize)
+            .await;
+        let _ = self.storage_provider.close_file(&handle).await;
+        let data = data?;
+
+        let (execution_id, workflow_execution_id) = browse_event_context(volume);
+        self.event_publisher
+            .publish_storage_event(StorageEvent::FileRead {
+                execution_id,
+                workflow_execution_id,
+                iteration_number: None,
+                volume_id: volume.id,
+                path: path_str,
+                offset: 0,
+                bytes_read: data.len() as u64,
+                duration_ms: start.elapsed().as_millis() as u64,
+                read_at: Utc::now(),
+                caller_node_id: None,
+                host_node_id: None,
+            })
+            .await;
+
+        Ok(data)
+    }
+
+    /// List every regular file on `volume` as `(path, size)`, depth-first,
+    /// stopping after `max_files`. Symlinks are not followed. Used to build
+    /// the filename search index; no per-directory events are published.
+    pub async fn browse_walk(
+        &self,
+        volume: &Volume,
+        max_files: usize,
+    ) -> Result<Vec<(String, u64)>, FsalError> {
+        let mut files = Vec::new();
+        let mut pending = vec!["/".to_string()];
+        while let Some(dir) = pending.pop() {
+            let full_path = self.routed_storage_path(volume, &dir);
+            let entries = self.storage_provider.readdir(&full_path).await?;
+            for entry in entries {
+                if entry.name == "." || entry.name == ".." {
+                    continue;
+                }
+                let path = format!("{}/{}", dir.trim_end_matches('/'), entry.name);
+                match entry.file_type {
+                    FileType::Directory => pending.push(path),
+                    FileType::File => {
+                        if files.len() >= max_files {
+                            return Ok(files);
+                        }
+                        let child = format!("{}/{}", full_path.trim_end_matches('/'), entry.name);
+                        let size = self
+                            .storage_provider
+                            .stat(&child)
+                            .await
+                            .map(|attrs| attrs.size)
+                            .unwrap_or(0);
+                        files.push((path, size));
+                    }
+                    FileType::Symlink => {}
+                }
+            }
+        }
+        Ok(files)
+    }
+
     /// Enforce filesystem policy for read operation
     fn enforce_read_policy(&self, policy: &FsalAccessPolicy, path: &str) -> Result<(), FsalError> {
         // Check if path matches any read allowlist pattern
@@ -656,23 +934,54 @@ impl AegisFSAL {
     /// for misconfigured or malicious access attempts.
     async fn emit_policy_violation(
         &self,
-        ctx: PolicyViolationContext,
+        ctx: StorageOperationContext,
         path: &str,
         error: &FsalError,
     ) {
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FilesystemPolicyViolation {
-                execution_id: ctx.execution_id,
-                workflow_execution_id: ctx.workflow_execution_id,
-                volume_id: ctx.volume_id,
-                operation: ctx.operation.to_string(),
-                path: path.to_string(),
-                policy_rule: error.to_string(),
-                violated_at: Utc::now(),
-                caller_node_id: ctx.caller_node_id,
-                host_node_id: ctx.host_node_id,
-            })
-            .await;
+        self.publish_event(StorageEvent::FilesystemPolicyViolation {
+            execution_id: ctx.execution_id,
+            workflow_execution_id: ctx.workflow_execution_id,
+            iteration_number: None,
+            volume_id: ctx.volume_id,
+            operation: ctx.operation.to_string(),
+            path: path.to_string(),
+            policy_rule: error.to_string(),
+            violated_at: Utc::now(),
+            caller_node_id: ctx.caller_node_id,
+            host_node_id: ctx.host_node_id,
+        })
+        .await;
+    }
+
+    /// Convert a storage backend result, recording backend faults (see
+    /// [`StorageErrorKind::is_backend_fault`]) as an `OperationFailed`
+    /// storage event so the cause survives past the transport's error code.
+    async fn check_backend<T>(
+        &self,
+        ctx: StorageOperationContext,
+        path: &str,
+        result: Result<T, StorageError>,
+    ) -> Result<T, FsalError> {
+        if let Err(e) = &result {
+            let cause = e.kind();
+            if cause.is_backend_fault() {
+                self.publish_event(StorageEvent::OperationFailed {
+                    execution_id: ctx.execution_id,
+                    workflow_execution_id: ctx.workflow_execution_id,
+                    iteration_number: None,
+                    volume_id: ctx.volume_id,
+                    operation: ctx.operation.to_string(),
+                    path: path.to_string(),
+                    cause,
+                    error: e.to_string(),
+                    failed_at: Utc::now(),
+                    caller_node_id: ctx.caller_node_id,
+                    host_node_id: ctx.host_node_id,
+                })
+                .await;
+            }
+        }
+        result.map_err(FsalError::Storage)
     }
 
     /// Lookup a file/directory (NFS LOOKUP operation)
@@ -721,6 +1030,24 @@ impl AegisFSAL {
         offset: u64,
         length: usize,
     ) -> Result<Vec<u8>, FsalError> {
+        self.read_bytes(handle, path, policy, offset, length)
+            .await
+            .map(Vec::from)
+    }
+
+    /// Read from file at offset, returning the storage provider's buffer as-is
+    ///
+    /// Transports that can consume [`Bytes`] directly should prefer this over
+    /// [`read`](Self::read): the data is never copied between the storage
+    /// provider and the caller.
+    pub async fn read_bytes(
+        &self,
+        handle: &AegisFileHandle,
+        path: &str,
+        policy: &FsalAccessPolicy,
+        offset: u64,
+        length: usize,
+    ) -> Result<Bytes, FsalError> {
         let start = std::time::Instant::now();
 
         // 1. Authorize
@@ -732,14 +1059,7 @@ impl AegisFSAL {
         let path_str = path_string.as_str();
         if let Err(e) = self.enforce_read_policy(policy, path_str) {
             self.emit_policy_violation(
-                PolicyViolationContext {
-                    execution_id: handle.execution_id().copied(),
-                    workflow_execution_id: handle.workflow_execution_id(),
-                    volume_id: handle.volume_id,
-                    operation: "read",
-                    caller_node_id: None,
-                    host_node_id: None,
-                },
+                StorageOperationContext::for_handle(handle, "read"),
                 path_str,
                 &e,
             )
@@ -749,32 +1069,43 @@ impl AegisFSAL {
         let full_path = self.routed_storage_path(&volume, path_str);
 
         // 3. Read via storage provider
-        let storage_handle = self
-            .storage_provider
-            .open_file(&full_path, OpenMode::ReadOnly)
-            .await?;
+        let read = async {
+            let storage_handle = self
+                .storage_provider
+                .open_file(&full_path, OpenMode::ReadOnly)
+                .await?;
+            let data = self
+                .storage_provider
+                .read_at_bytes(&storage_handle, offset, length)
+                .await?;
+            let _ = self.storage_provider.close_file(&storage_handle).await;
+            Ok::<_, StorageError>(data)
+        }
+        .await;
         let data = self
-            .storage_provider
-            .read_at(&storage_handle, offset, length)
+            .check_backend(
+                StorageOperationContext::for_handle(handle, "read"),
+                path_str,
+                read,
+            )
             .await?;
-        let _ = self.storage_provider.close_file(&storage_handle).await;
 
         // 4. Publish event
         let duration_ms = start.elapsed().as_millis() as u64;
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileRead {
-                execution_id: handle.execution_id().copied(),
-                workflow_execution_id: handle.workflow_execution_id(),
-                volume_id: handle.volume_id,
-                path: path_str.to_string(),
-                offset,
-                bytes_read: data.len() as u64,
-                duration_ms,
-                read_at: Utc::now(),
-                caller_node_id: None,
-                host_node_id: None,
-            })
-            .await;
+        self.publish_event(StorageEvent::FileRead {
+            execution_id: handle.execution_id().copied(),
+            workflow_execution_id: handle.workflow_execution_id(),
+            iteration_number: None,
+            volume_id: handle.volume_id,
+            path: path_str.to_string(),
+            offset,
+            bytes_read: data.len() as u64,
+            duration_ms,
+            read_at: Utc::now(),
+            caller_node_id: None,
+            host_node_id: None,
+        })
+        .await;
 
         Ok(data)
     }
@@ -799,14 +1130,7 @@ impl AegisFSAL {
         let path_str = path_string.as_str();
         if let Err(e) = self.enforce_write_policy(policy, path_str) {
             self.emit_policy_violation(
-                PolicyViolationContext {
-                    execution_id: handle.execution_id().copied(),
-                    workflow_execution_id: handle.workflow_execution_id(),
-                    volume_id: handle.volume_id,
-                    operation: "write",
-                    caller_node_id: None,
-                    host_node_id: None,
-                },
+                StorageOperationContext::for_handle(handle, "write"),
                 path_str,
                 &e,
             )
@@ -818,7 +1142,13 @@ impl AegisFSAL {
 
         // 3. Proactive quota enforcement (ADR-036)
         // Check if write would exceed volume quota before attempting write
-        let current_usage = self.storage_provider.get_usage(&usage_path).await?;
+        let current_usage = self
+            .check_backend(
+                StorageOperationContext::for_handle(handle, "write"),
+                path_str,
+                self.storage_provider.get_usage(&usage_path).await,
+            )
+            .await?;
         let requested_bytes = data.len() as u64;
         let projected_usage = current_usage.saturating_add(requested_bytes);
 
@@ -826,18 +1156,17 @@ impl AegisFSAL {
             let available_bytes = volume.size_limit_bytes.saturating_sub(current_usage);
 
             // Publish quota exceeded event
-            self.event_publisher
-                .publish_storage_event(StorageEvent::QuotaExceeded {
-                    execution_id: handle.execution_id().copied(),
-                    workflow_execution_id: handle.workflow_execution_id(),
-                    volume_id: handle.volume_id,
-                    requested_bytes,
-                    available_bytes,
-                    exceeded_at: Utc::now(),
-                    caller_node_id: None,
-                    host_node_id: None,
-                })
-                .await;
+            self.publish_event(StorageEvent::QuotaExceeded {
+                execution_id: handle.execution_id().copied(),
+                workflow_execution_id: handle.workflow_execution_id(),
+                volume_id: handle.volume_id,
+                requested_bytes,
+                available_bytes,
+                exceeded_at: Utc::now(),
+                caller_node_id: None,
+                host_node_id: None,
+            })
+            .await;
 
             return Err(FsalError::QuotaExceeded {
                 requested_bytes,
@@ -846,32 +1175,43 @@ impl AegisFSAL {
         }
 
         // 4. Write via storage provider
-        let storage_handle = self
-            .storage_provider
-            .open_file(&full_path, OpenMode::WriteOnly)
-            .await?;
+        let write = async {
+            let storage_handle = self
+                .storage_provider
+                .open_file(&full_path, OpenMode::WriteOnly)
+                .await?;
+            let bytes_written = self
+                .storage_provider
+                .write_at(&storage_handle, offset, data)
+                .await?;
+            let _ = self.storage_provider.close_file(&storage_handle).await;
+            Ok::<_, StorageError>(bytes_written)
+        }
+        .await;
         let bytes_written = self
-            .storage_provider
-            .write_at(&storage_handle, offset, data)
+            .check_backend(
+                StorageOperationContext::for_handle(handle, "write"),
+                path_str,
+                write,
+            )
             .await?;
-        let _ = self.storage_provider.close_file(&storage_handle).await;
 
         // 5. Publish event
         let duration_ms = start.elapsed().as_millis() as u64;
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileWritten {
-                execution_id: handle.execution_id().copied(),
-                workflow_execution_id: handle.workflow_execution_id(),
-                volume_id: handle.volume_id,
-                path: path_str.to_string(),
-                offset,
-                bytes_written: bytes_written as u64,
-                duration_ms,
-                written_at: Utc::now(),
-                caller_node_id: None,
-                host_node_id: None,
-            })
-            .await;
+        self.publish_event(StorageEvent::FileWritten {
+            execution_id: handle.execution_id().copied(),
+            workflow_execution_id: handle.workflow_execution_id(),
+            iteration_number: None,
+            volume_id: handle.volume_id,
+            path: path_str.to_string(),
+            offset,
+            bytes_written: bytes_written as u64,
+            duration_ms,
+            written_at: Utc::now(),
+            caller_node_id: None,
+            host_node_id: None,
+        })
+        .await;
 
         Ok(bytes_written)
     }
@@ -905,18 +1245,14 @@ impl AegisFSAL {
         // 3. Enforce write policy
         if let Err(e) = self.enforce_write_policy(policy, path_str) {
             self.emit_policy_violation(
-                PolicyViolationContext {
-                    execution_id: if workflow_execution_id.is_some() {
-                        None
-                    } else {
-                        Some(execution_id)
-                    },
+                StorageOperationContext::new(
+                    execution_id,
                     workflow_execution_id,
                     volume_id,
-                    operation: "write",
+                    "write",
                     caller_node_id,
                     host_node_id,
-                },
+                ),
                 path_str,
                 &e,
             )
@@ -928,19 +1264,33 @@ impl AegisFSAL {
         let full_path = self.routed_storage_path(&volume, path_str);
 
         // 5. Ensure parent directory exists (SeaweedFS does not auto-create parent dirs)
-        if let Some(parent) = std::path::Path::new(&full_path).parent() {
-            let parent_str = parent.to_string_lossy();
-            if !parent_str.is_empty() && parent_str != "/" {
-                self.storage_provider
-                    .create_directory(&parent_str)
-                    .await
-                    .map_err(FsalError::Storage)?;
+        let create = async {
+            if let Some(parent) = std::path::Path::new(&full_path).parent() {
+                let parent_str = parent.to_string_lossy();
+                if !parent_str.is_empty() && parent_str != "/" {
+                    self.storage_provider.create_directory(&parent_str).await?;
+                }
             }
-        }
 
-        // 6. Create file via storage provider (using default mode 0o644)
-        let handle = self.storage_provider.create_file(&full_path, 0o644).await?;
-        let _ = self.storage_provider.close_file(&handle).await; // Close immediately
+            // 6. Create file via storage provider (using default mode 0o644)
+            let handle = self.storage_provider.create_file(&full_path, 0o644).await?;
+            let _ = self.storage_provider.close_file(&handle).await; // Close immediately
+            Ok::<_, StorageError>(())
+        }
+        .await;
+        self.check_backend(
+            StorageOperationContext::new(
+                execution_id,
+                workflow_execution_id,
+                volume_id,
+                "create",
+                caller_node_id,
+                host_node_id,
+            ),
+            path_str,
+            create,
+        )
+        .await?;
 
         // 7. Create Aegis file handle
         let aegis_handle = AegisFileHandle::new(execution_id, volume_id, path_str);
@@ -948,21 +1298,21 @@ impl AegisFSAL {
 
         // 8. Publish event only when the caller confirms the overall write will not follow
         if emit_event {
-            self.event_publisher
-                .publish_storage_event(StorageEvent::FileCreated {
-                    execution_id: if workflow_execution_id.is_some() {
-                        None
-                    } else {
-                        Some(execution_id)
-                    },
-                    workflow_execution_id,
-                    volume_id,
-                    path: path_str.to_string(),
-                    created_at: Utc::now(),
-                    caller_node_id,
-                    host_node_id,
-                })
-                .await;
+            self.publish_event(StorageEvent::FileCreated {
+                execution_id: if workflow_execution_id.is_some() {
+                    None
+                } else {
+                    Some(execution_id)
+                },
+                workflow_execution_id,
+                iteration_number: None,
+                volume_id,
+                path: path_str.to_string(),
+                created_at: Utc::now(),
+                caller_node_id,
+                host_node_id,
+            })
+            .await;
         }
 
         Ok(aegis_handle)
@@ -1024,18 +1374,14 @@ impl AegisFSAL {
         // 3. Enforce read policy
         if let Err(e) = self.enforce_read_policy(policy, path_str) {
             self.emit_policy_violation(
-                PolicyViolationContext {
-                    execution_id: if workflow_execution_id.is_some() {
-                        None
-                    } else {
-                        Some(execution_id)
-                    },
+                StorageOperationContext::new(
+                    execution_id,
                     workflow_execution_id,
                     volume_id,
-                    operation: "read",
+                    "read",
                     caller_node_id,
                     host_node_id,
-                },
+                ),
                 path_str,
                 &e,
             )
@@ -1047,25 +1393,38 @@ impl AegisFSAL {
         let full_path = self.routed_storage_path(&volume, path_str);
 
         // 5. List directory via storage provider
-        let entries = self.storage_provider.readdir(&full_path).await?;
-
-        // 6. Publish event
-        self.event_publisher
-            .publish_storage_event(StorageEvent::DirectoryListed {
-                execution_id: if workflow_execution_id.is_some() {
-                    None
-                } else {
-                    Some(execution_id)
-                },
-                workflow_execution_id,
-                volume_id,
-                path: path_str.to_string(),
-                entry_count: entries.len(),
-                listed_at: Utc::now(),
-                caller_node_id,
-                host_node_id,
-            })
-            .await;
+        let entries = self
+            .check_backend(
+                StorageOperationContext::new(
+                    execution_id,
+                    workflow_execution_id,
+                    volume_id,
+                    "list",
+                    caller_node_id,
+                    host_node_id,
+                ),
+                path_str,
+                self.storage_provider.readdir(&full_path).await,
+            )
+            .await?;
+
+        // 6. Publish event
+        self.publish_event(StorageEvent::DirectoryListed {
+            execution_id: if workflow_execution_id.is_some() {
+                None
+            } else {
+                Some(execution_id)
+            },
+            workflow_execution_id,
+            iteration_number: None,
+            volume_id,
+            path: path_str.to_string(),
+            entry_count: entries.len(),
+            listed_at: Utc::now(),
+            caller_node_id,
+            host_node_id,
+        })
+        .await;
 
         Ok(entries)
     }
@@ -1094,18 +1453,14 @@ impl AegisFSAL {
         // 3. Enforce write policy (directory creation is a write operation)
         if let Err(e) = self.enforce_write_policy(policy, path_str) {
             self.emit_policy_violation(
-                PolicyViolationContext {
-                    execution_id: if workflow_execution_id.is_some() {
-                        None
-                    } else {
-                        Some(execution_id)
-                    },
+                StorageOperationContext::new(
+                    execution_id,
                     workflow_execution_id,
                     volume_id,
-                    operation: "write",
+                    "write",
                     caller_node_id,
                     host_node_id,
-                },
+                ),
                 path_str,
                 &e,
             )
@@ -1117,24 +1472,36 @@ impl AegisFSAL {
         let full_path = self.routed_storage_path(&volume, path_str);
 
         // 5. Create directory via storage provider
-        self.storage_provider.create_directory(&full_path).await?;
-
-        // 6. Publish event
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileCreated {
-                execution_id: if workflow_execution_id.is_some() {
-                    None
-                } else {
-                    Some(execution_id)
-                },
+        self.check_backend(
+            StorageOperationContext::new(
+                execution_id,
                 workflow_execution_id,
                 volume_id,
-                path: path_str.to_string(),
-                created_at: Utc::now(),
+                "create",
                 caller_node_id,
                 host_node_id,
-            })
-            .await;
+            ),
+            path_str,
+            self.storage_provider.create_directory(&full_path).await,
+        )
+        .await?;
+
+        // 6. Publish event
+        self.publish_event(StorageEvent::FileCreated {
+            execution_id: if workflow_execution_id.is_some() {
+                None
+            } else {
+                Some(execution_id)
+            },
+            workflow_execution_id,
+            iteration_number: None,
+            volume_id,
+            path: path_str.to_string(),
+            created_at: Utc::now(),
+            caller_node_id,
+            host_node_id,
+        })
+        .await;
 
         Ok(())
     }
@@ -1163,18 +1530,14 @@ impl AegisFSAL {
         // 3. Enforce write policy
         if let Err(e) = self.enforce_write_policy(policy, path_str) {
             self.emit_policy_violation(
-                PolicyViolationContext {
-                    execution_id: if workflow_execution_id.is_some() {
-                        None
-                    } else {
-                        Some(execution_id)
-                    },
+                StorageOperationContext::new(
+                    execution_id,
                     workflow_execution_id,
                     volume_id,
-                    operation: "delete",
+                    "delete",
                     caller_node_id,
                     host_node_id,
-                },
+                ),
                 path_str,
                 &e,
             )
@@ -1186,24 +1549,36 @@ impl AegisFSAL {
         let full_path = self.routed_storage_path(&volume, path_str);
 
         // 5. Delete file via storage provider
-        self.storage_provider.delete_file(&full_path).await?;
-
-        // 6. Publish event
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileDeleted {
-                execution_id: if workflow_execution_id.is_some() {
-                    None
-                } else {
-                    Some(execution_id)
-                },
+        self.check_backend(
+            StorageOperationContext::new(
+                execution_id,
                 workflow_execution_id,
                 volume_id,
-                path: path_str.to_string(),
-                deleted_at: Utc::now(),
+                "delete",
                 caller_node_id,
                 host_node_id,
-            })
-            .await;
+            ),
+            path_str,
+            self.storage_provider.delete_file(&full_path).await,
+        )
+        .await?;
+
+        // 6. Publish event
+        self.publish_event(StorageEvent::FileDeleted {
+            execution_id: if workflow_execution_id.is_some() {
+                None
+            } else {
+                Some(execution_id)
+            },
+            workflow_execution_id,
+            iteration_number: None,
+            volume_id,
+            path: path_str.to_string(),
+            deleted_at: Utc::now(),
+            caller_node_id,
+            host_node_id,
+        })
+        .await;
 
         Ok(())
     }
@@ -1232,18 +1607,14 @@ impl AegisFSAL {
         // 3. Enforce write policy
         if let Err(e) = self.enforce_write_policy(policy, path_str) {
             self.emit_policy_violation(
-                PolicyViolationContext {
-                    execution_id: if workflow_execution_id.is_some() {
-                        None
-                    } else {
-                        Some(execution_id)
-                    },
+                StorageOperationContext::new(
+                    execution_id,
                     workflow_execution_id,
                     volume_id,
-                    operation: "delete",
+                    "delete",
                     caller_node_id,
                     host_node_id,
-                },
+                ),
                 path_str,
                 &e,
             )
@@ -1255,24 +1626,36 @@ impl AegisFSAL {
         let full_path = self.routed_storage_path(&volume, path_str);
 
         // 5. Delete directory via storage provider
-        self.storage_provider.delete_directory(&full_path).await?;
-
-        // 6. Publish event
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileDeleted {
-                execution_id: if workflow_execution_id.is_some() {
-                    None
-                } else {
-                    Some(execution_id)
-                },
+        self.check_backend(
+            StorageOperationContext::new(
+                execution_id,
                 workflow_execution_id,
                 volume_id,
-                path: path_str.to_string(),
-                deleted_at: Utc::now(),
+                "delete",
                 caller_node_id,
                 host_node_id,
-            })
-            .await;
+            ),
+            path_str,
+            self.storage_provider.delete_directory(&full_path).await,
+        )
+        .await?;
+
+        // 6. Publish event
+        self.publish_event(StorageEvent::FileDeleted {
+            execution_id: if workflow_execution_id.is_some() {
+                None
+            } else {
+                Some(execution_id)
+            },
+            workflow_execution_id,
+            iteration_number: None,
+            volume_id,
+            path: path_str.to_string(),
+            deleted_at: Utc::now(),
+            caller_node_id,
+            host_node_id,
+        })
+        .await;
 
         Ok(())
     }
@@ -1304,18 +1687,14 @@ impl AegisFSAL {
         // 3. Enforce write policy for both paths
         if let Err(e) = self.enforce_write_policy(policy, from_str) {
             self.emit_policy_violation(
-                PolicyViolationContext {
-                    execution_id: if workflow_execution_id.is_some() {
-                        None
-                    } else {
-                        Some(execution_id)
-                    },
+                StorageOperationContext::new(
+                    execution_id,
                     workflow_execution_id,
                     volume_id,
-                    operation: "write",
+                    "write",
                     caller_node_id,
                     host_node_id,
-                },
+                ),
                 from_str,
                 &e,
             )
@@ -1324,18 +1703,14 @@ impl AegisFSAL {
         }
         if let Err(e) = self.enforce_write_policy(policy, to_str) {
             self.emit_policy_violation(
-                PolicyViolationContext {
-                    execution_id: if workflow_execution_id.is_some() {
-                        None
-                    } else {
-                        Some(execution_id)
-                    },
+                StorageOperationContext::new(
+                    execution_id,
                     workflow_execution_id,
                     volume_id,
-                    operation: "write",
+                    "write",
                     caller_node_id,
                     host_node_id,
-                },
+                ),
                 to_str,
                 &e,
             )
@@ -1348,24 +1723,36 @@ impl AegisFSAL {
         let to_full = self.routed_storage_path(&volume, to_str);
 
         // 5. Rename via storage provider
-        self.storage_provider.rename(&from_full, &to_full).await?;
-
-        // 6. Publish event (reuse FileCreated for rename target)
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileCreated {
-                execution_id: if workflow_execution_id.is_some() {
-                    None
-                } else {
-                    Some(execution_id)
-                },
+        self.check_backend(
+            StorageOperationContext::new(
+                execution_id,
                 workflow_execution_id,
                 volume_id,
-                path: to_str.to_string(),
-                created_at: Utc::now(),
+                "rename",
                 caller_node_id,
                 host_node_id,
-            })
-            .await;
+            ),
+            from_str,
+            self.storage_provider.rename(&from_full, &to_full).await,
+        )
+        .await?;
+
+        // 6. Publish event (reuse FileCreated for rename target)
+        self.publish_event(StorageEvent::FileCreated {
+            execution_id: if workflow_execution_id.is_some() {
+                None
+            } else {
+                Some(execution_id)
+            },
+            workflow_execution_id,
+            iteration_number: None,
+            volume_id,
+            path: to_str.to_string(),
+            created_at: Utc::now(),
+            caller_node_id,
+            host_node_id,
+        })
+        .await;
 
         Ok(())
     }
@@ -1395,18 +1782,18 @@ impl AegisFSAL {
         let handle = self.storage_provider.open_file(&storage_path, mode).await?;
 
         // 4. Emit event
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileOpened {
-                execution_id: Some(execution_id),
-                workflow_execution_id: None,
-                volume_id,
-                path: path_string,
-                open_mode: format!("{mode:?}"),
-                opened_at: Utc::now(),
-                caller_node_id,
-                host_node_id,
-            })
-            .await;
+        self.publish_event(StorageEvent::FileOpened {
+            execution_id: Some(execution_id),
+            workflow_execution_id: None,
+            iteration_number: None,
+            volume_id,
+            path: path_string,
+            open_mode: format!("{mode:?}"),
+            opened_at: Utc::now(),
+            caller_node_id,
+            host_node_id,
+        })
+        .await;
 
         Ok(handle)
     }
@@ -1438,20 +1825,20 @@ impl AegisFSAL {
             .await?;
 
         let duration_ms = start.elapsed().as_millis() as u64;
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileRead {
-                execution_id: Some(execution_id),
-                workflow_execution_id: None,
-                volume_id,
-                path: path.to_string(),
-                offset,
-                bytes_read: data.len() as u64,
-                duration_ms,
-                read_at: Utc::now(),
-                caller_node_id,
-                host_node_id,
-            })
-            .await;
+        self.publish_event(StorageEvent::FileRead {
+            execution_id: Some(execution_id),
+            workflow_execution_id: None,
+            iteration_number: None,
+            volume_id,
+            path: path.to_string(),
+            offset,
+            bytes_read: data.len() as u64,
+            duration_ms,
+            read_at: Utc::now(),
+            caller_node_id,
+            host_node_id,
+        })
+        .await;
 
         Ok(data)
     }
@@ -1486,18 +1873,17 @@ impl AegisFSAL {
 
         if projected_usage > volume.size_limit_bytes {
             let available_bytes = volume.size_limit_bytes.saturating_sub(current_usage);
-            self.event_publisher
-                .publish_storage_event(StorageEvent::QuotaExceeded {
-                    execution_id: Some(execution_id),
-                    workflow_execution_id: None,
-                    volume_id,
-                    requested_bytes,
-                    available_bytes,
-                    exceeded_at: Utc::now(),
-                    caller_node_id,
-                    host_node_id,
-                })
-                .await;
+            self.publish_event(StorageEvent::QuotaExceeded {
+                execution_id: Some(execution_id),
+                workflow_execution_id: None,
+                volume_id,
+                requested_bytes,
+                available_bytes,
+                exceeded_at: Utc::now(),
+                caller_node_id,
+                host_node_id,
+            })
+            .await;
             return Err(FsalError::QuotaExceeded {
                 requested_bytes,
                 available_bytes,
@@ -1507,20 +1893,20 @@ impl AegisFSAL {
         let bytes_written = self.storage_provider.write_at(handle, offset, data).await?;
 
         let duration_ms = start.elapsed().as_millis() as u64;
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileWritten {
-                execution_id: Some(execution_id),
-                workflow_execution_id: None,
-                volume_id,
-                path: path.to_string(),
-                offset,
-                bytes_written: bytes_written as u64,
-                duration_ms,
-                written_at: Utc::now(),
-                caller_node_id,
-                host_node_id,
-            })
-            .await;
+        self.publish_event(StorageEvent::FileWritten {
+            execution_id: Some(execution_id),
+            workflow_execution_id: None,
+            iteration_number: None,
+            volume_id,
+            path: path.to_string(),
+            offset,
+            bytes_written: bytes_written as u64,
+            duration_ms,
+            written_at: Utc::now(),
+            caller_node_id,
+            host_node_id,
+        })
+        .await;
 
         Ok(bytes_written)
     }
@@ -1538,22 +1924,35 @@ impl AegisFSAL {
     ) -> Result<(), FsalError> {
         self.storage_provider.close_file(handle).await?;
 
-        self.event_publisher
-            .publish_storage_event(StorageEvent::FileClosed {
-                execution_id: Some(execution_id),
-                workflow_execution_id: None,
-                volume_id,
-                path: path.to_string(),
-                closed_at: Utc::now(),
-                caller_node_id,
-                host_node_id,
-            })
-            .await;
+        self.publish_event(StorageEvent::FileClosed {
+            execution_id: Some(execution_id),
+            workflow_execution_id: None,
+            iteration_number: None,
+            volume_id,
+            path: path.to_string(),
+            closed_at: Utc::now(),
+            caller_node_id,
+            host_node_id,
+        })
+        .await;
 
         Ok(())
     }
 }
 
+/// Execution context recorded on storage events for a browsed volume.
+fn browse_event_context(volume: &Volume) -> (Option<ExecutionId>, Option<uuid::Uuid>) {
+    match &volume.ownership {
+        crate::domain::volume::VolumeOwnership::Execution { execution_id } => {
+            (Some(*execution_id), None)
+        }
+        crate::domain::volume::VolumeOwnership::WorkflowExecution {
+            workflow_execution_id,
+        } => (None, Some(*workflow_execution_id)),
+        crate::domain::volume::VolumeOwnership::Persistent { .. } => (None, None),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1745,6 +2144,35 @@ mod tests {
         )
     }
 
+    #[tokio::test]
+    async fn authorize_browse_allows_own_volumes_and_tenant_workspaces() {
+        let own = make_available_persistent_volume("alice");
+        let tenant = own.tenant_id.clone();
+        let fsal = make_fsal_with_volume(&own).await;
+        assert!(fsal
+            .authorize_browse(&tenant, "alice", &own.id)
+            .await
+            .is_ok());
+        assert!(fsal
+            .authorize_browse(&tenant, "bob", &own.id)
+            .await
+            .is_err());
+
+        let mut workspace = make_available_persistent_volume("unused");
+        workspace.ownership = crate::domain::volume::VolumeOwnership::execution(ExecutionId::new());
+        let fsal = make_fsal_with_volume(&workspace).await;
+        assert!(fsal
+            .authorize_browse(&tenant, "bob", &workspace.id)
+            .await
+            .is_ok());
+        let other_tenant = crate::domain::tenant::TenantId::new("other-tenant").unwrap();
+        assert!(matches!(
+            fsal.authorize_browse(&other_tenant, "bob", &workspace.id)
+                .await,
+            Err(FsalError::UnauthorizedAccess { .. })
+        ));
+    }
+
     #[tokio::test]
     async fn authorize_for_user_correct_owner_passes() {
         let vol = make_available_persistent_volume("alice");
@@ -2036,4 +2464,138 @@ mod tests {
             result
         );
     }
+
+    /// Backend whose listings fail as unavailable and whose deletes find
+    /// nothing to delete.
+    struct UnavailableStorage;
+
+    #[async_trait::async_trait]
+    impl StorageProvider for UnavailableStorage {
+        async fn create_directory(&self, _: &str) -> Result<(), StorageError> {
+            Ok(())
+        }
+        async fn delete_directory(&self, _: &str) -> Result<(), StorageError> {
+            Ok(())
+        }
+        async fn set_quota(&self, _: &str, _: u64) -> Result<(), StorageError> {
+            Ok(())
+        }
+        async fn get_usage(&self, _: &str) -> Result<u64, StorageError> {
+            Ok(0)
+        }
+        async fn health_check(&self) -> Result<(), StorageError> {
+            Ok(())
+        }
+        async fn open_file(&self, _: &str, _: OpenMode) -> Result<FileHandle, StorageError> {
+            Ok(FileHandle(vec![]))
+        }
+        async fn read_at(&self, _: &FileHandle, _: u64, _: usize) -> Result<Vec<u8>, StorageError> {
+            Ok(vec![])
+        }
+        async fn write_at(
+            &self,
+            _: &FileHandle,
+            _: u64,
+            data: &[u8],
+        ) -> Result<usize, StorageError> {
+            Ok(data.len())
+        }
+        async fn close_file(&self, _: &FileHandle) -> Result<(), StorageError> {
+            Ok(())
+        }
+        async fn stat(&self, path: &str) -> Result<FileAttributes, StorageError> {
+            Err(StorageError::FileNotFound(path.to_string()))
+        }
+        async fn readdir(&self, _: &str) -> Result<Vec<SDirEntry>, StorageError> {
+            Err(StorageError::Unavailable("filer is down".to_string()))
+        }
+        async fn create_file(&self, _: &str, _: u32) -> Result<FileHandle, StorageError> {
+            Ok(FileHandle(vec![]))
+        }
+        async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+            Err(StorageError::FileNotFound(path.to_string()))
+        }
+        async fn rename(&self, _: &str, _: &str) -> Result<(), StorageError> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct RecordingPublisher {
+        events: parking_lot::Mutex<Vec<StorageEvent>>,
+    }
+
+    #[async_trait::async_trait]
+    impl EventPublisher for RecordingPublisher {
+        async fn publish_storage_event(&self, event: StorageEvent) {
+            self.events.lock().push(event);
+        }
+    }
+
+    #[tokio::test]
+    async fn backend_faults_are_recorded_with_their_cause() {
+        use parking_lot::RwLock;
+        use std::collections::HashMap;
+
+        let execution_id = ExecutionId::new();
+        let mut vol = make_available_persistent_volume("unused");
+        vol.ownership = crate::domain::volume::VolumeOwnership::execution(execution_id);
+        let repo = Arc::new(InMemoryVolumeRepository::new());
+        repo.save(&vol).await.unwrap();
+        let publisher = Arc::new(RecordingPublisher::default());
+        let fsal = AegisFSAL::new(
+            Arc::new(UnavailableStorage),
+            repo as Arc<dyn crate::domain::repository::VolumeRepository>,
+            Arc::new(RwLock::new(HashMap::new())),
+            publisher.clone(),
+        );
+        let policy = FsalAccessPolicy::default();
+
+        let err = fsal
+            .readdir(
+                execution_id,
+                vol.id,
+                "/workspace/src",
+                &policy,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), StorageErrorKind::BackendUnavailable);
+
+        // A missing file is an ordinary outcome, not a backend fault.
+        let err = fsal
+            .delete_file(
+                execution_id,
+                vol.id,
+                "/workspace/a.txt",
+                &policy,
+                None,
+                None,
+                None,
+            )
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind(), StorageErrorKind::NotFound);
+
+        let events = publisher.events.lock();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            StorageEvent::OperationFailed {
+                execution_id: recorded,
+                operation,
+                path,
+                cause,
+                ..
+            } => {
+                assert_eq!(*recorded, Some(execution_id));
+                assert_eq!(operation, "list");
+                assert_eq!(path, "/workspace/src");
+                assert_eq!(*cause, StorageErrorKind::BackendUnavailable);
+            }
+            other => panic!("expected OperationFailed, got {other:?}"),
+        }
+    }
 }
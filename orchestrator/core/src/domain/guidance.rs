@@ -0,0 +1,84 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Operator Guidance — BC-2 Execution
+//!
+//! Instructions an operator injects into a running execution via
+//! `POST /v1/executions/{id}/guidance` (or `aegis task guide`). Guidance is
+//! queued per execution and appended to the prompt of the next iteration the
+//! [`crate::domain::supervisor::Supervisor`] starts; the iteration already in
+//! flight is not interrupted.
+//!
+//! Each submission is also published as
+//! [`crate::domain::events::ExecutionEvent::OperatorGuidanceAdded`], which the
+//! execution event persister records as the audit trail.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::execution::ExecutionId;
+
+/// Upper bound on a single guidance submission, in characters.
+pub const MAX_GUIDANCE_CHARS: usize = 4000;
+
+/// One piece of operator guidance awaiting the next iteration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OperatorGuidance {
+    pub guidance: String,
+    /// Identity subject of the operator, or `"anonymous"` when auth is disabled.
+    pub submitted_by: String,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// Per-execution queue of pending guidance, shared between the API handler
+/// that enqueues and the supervisor that drains before each iteration.
+pub trait GuidanceQueue: Send + Sync {
+    /// Queue guidance for the next iteration of `execution_id`.
+    fn enqueue(&self, execution_id: ExecutionId, guidance: OperatorGuidance);
+
+    /// Remove and return everything queued for `execution_id`, oldest first.
+    fn drain(&self, execution_id: ExecutionId) -> Vec<OperatorGuidance>;
+}
+
+/// Append drained guidance to an iteration prompt. Returns the prompt
+/// unchanged when nothing was queued.
+pub fn apply_guidance(prompt: &str, guidance: &[OperatorGuidance]) -> String {
+    if guidance.is_empty() {
+        return prompt.to_string();
+    }
+    let mut guided = prompt.trim_end().to_string();
+    guided.push_str("\n\n## Operator guidance\n");
+    guided.push_str("An operator watching this execution added the following instructions. ");
+    guided.push_str("Follow them for this attempt.\n");
+    for item in guidance {
+        guided.push_str("\n- ");
+        guided.push_str(item.guidance.trim());
+    }
+    guided
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guidance(text: &str) -> OperatorGuidance {
+        OperatorGuidance {
+            guidance: text.to_string(),
+            submitted_by: "operator".to_string(),
+            submitted_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn apply_guidance_appends_items_in_order() {
+        let prompt = apply_guidance(
+            "Fix the failing test\n",
+            &[
+                guidance("Check the fixture path"),
+                guidance("  Do not touch CI  "),
+            ],
+        );
+        assert!(prompt.starts_with("Fix the failing test\n\n## Operator guidance\n"));
+        assert!(prompt.ends_with("\n- Check the fixture path\n- Do not touch CI"));
+        assert_eq!(apply_guidance("unchanged", &[]), "unchanged");
+    }
+}
@@ -0,0 +1,191 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Message Ingestion Domain Types (BC-8)
+//!
+//! Email and chat messages that start agent executions. Each adapter (IMAP
+//! poller, Slack Events API receiver) turns what it receives into an
+//! [`InboundMessage`]; from there every channel is handled the same way:
+//! attachments are written to a volume, the message becomes an
+//! [`ExecutionInput`], and the execution's final output is sent back to the
+//! [`ReplyTarget`] the message came from.
+//!
+//! The execution payload has the shape:
+//!
+//! ```json
+//! {
+//!   "tenant_id": "acme",
+//!   "labels": { "ingestion.channel": "email", "ingestion.source": "support" },
+//!   "message": {
+//!     "channel": "email",
+//!     "source": "support",
+//!     "sender": "jane@example.com",
+//!     "subject": "Printer on fire",
+//!     "body": "...",
+//!     "thread_id": "<abc@example.com>",
+//!     "received_at": "2026-10-15T09:30:00Z"
+//!   }
+//! }
+//! ```
+//!
+//! Attachments are passed as [`ExecutionInput::attachments`], not inlined.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::domain::execution::{AttachmentRef, ExecutionInput};
+use crate::domain::shared_kernel::TenantId;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum IngestionChannel {
+    Email,
+    Slack,
+}
+
+impl IngestionChannel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IngestionChannel::Email => "email",
+            IngestionChannel::Slack => "slack",
+        }
+    }
+}
+
+/// Where the reply to a message goes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "channel", rename_all = "snake_case")]
+pub enum ReplyTarget {
+    Email {
+        to: String,
+        subject: String,
+        /// `Message-ID` of the inbound email, for `In-Reply-To`/`References`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        in_reply_to: Option<String>,
+    },
+    Slack {
+        channel: String,
+        /// Timestamp of the thread root; replies are posted in the thread.
+        thread_ts: String,
+    },
+}
+
+/// A file received with a message, before it is written to a volume.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboundAttachment {
+    pub filename: String,
+    /// MIME type declared by the sender. The stored attachment's type is
+    /// sniffed from the content instead.
+    pub declared_mime_type: Option<String>,
+    pub data: Vec<u8>,
+}
+
+/// A message normalized from any ingestion channel.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InboundMessage {
+    pub channel: IngestionChannel,
+    /// Name of the configured source the message arrived on.
+    pub source: String,
+    /// Channel-specific unique id (IMAP `Message-ID`, Slack `event_id`).
+    pub external_id: String,
+    pub sender: String,
+    pub subject: Option<String>,
+    pub body: String,
+    /// Conversation the message belongs to, when the channel has threads.
+    pub thread_id: Option<String>,
+    pub received_at: DateTime<Utc>,
+    pub attachments: Vec<InboundAttachment>,
+    pub reply_to: Option<ReplyTarget>,
+}
+
+impl InboundMessage {
+    /// Labels attached to executions started from this message.
+    pub fn labels(&self) -> BTreeMap<String, String> {
+        BTreeMap::from([
+            (
+                "ingestion.channel".to_string(),
+                self.channel.as_str().to_string(),
+            ),
+            ("ingestion.source".to_string(), self.source.clone()),
+        ])
+    }
+
+    /// Execution input for this message. `attachments` are the stored
+    /// copies of [`InboundMessage::attachments`].
+    pub fn to_execution_input(
+        &self,
+        tenant_id: &TenantId,
+        attachments: Vec<AttachmentRef>,
+    ) -> ExecutionInput {
+        ExecutionInput {
+            intent: Some(match &self.subject {
+                Some(subject) if !subject.trim().is_empty() => subject.clone(),
+                _ => format!("Respond to {} message", self.channel.as_str()),
+            }),
+            input: json!({
+                "tenant_id": tenant_id.as_str(),
+                "labels": self.labels(),
+                "message": {
+                    "channel": self.channel,
+                    "source": self.source,
+                    "sender": self.sender,
+                    "subject": self.subject,
+                    "body": self.body,
+                    "thread_id": self.thread_id,
+                    "received_at": self.received_at,
+                },
+            }),
+            workspace_volume_id: None,
+            workspace_volume_mount_path: None,
+            workspace_remote_path: None,
+            workflow_execution_id: None,
+            attachments,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::shared_kernel::VolumeId;
+
+    #[test]
+    fn normalizes_email_into_execution_input() {
+        let tenant = TenantId::from_realm_slug("acme").unwrap();
+        let volume_id = VolumeId::new();
+        let message = InboundMessage {
+            channel: IngestionChannel::Email,
+            source: "support".to_string(),
+            external_id: "<abc@example.com>".to_string(),
+            sender: "jane@example.com".to_string(),
+            subject: Some("Printer on fire".to_string()),
+            body: "Please help".to_string(),
+            thread_id: Some("<abc@example.com>".to_string()),
+            received_at: Utc::now(),
+            attachments: Vec::new(),
+            reply_to: None,
+        };
+        let attachment = AttachmentRef {
+            volume_id,
+            path: AttachmentRef::storage_path(0, "../../etc/pass wd"),
+            name: "pass wd".to_string(),
+            mime_type: "text/plain".to_string(),
+            size: 3,
+            sha256: None,
+        };
+        assert_eq!(attachment.path, "attachments/00-pass_wd");
+        assert_eq!(
+            AttachmentRef::storage_path(3, ".."),
+            "attachments/03-attachment"
+        );
+
+        let input = message.to_execution_input(&tenant, vec![attachment]);
+        assert_eq!(input.intent.as_deref(), Some("Printer on fire"));
+        assert_eq!(input.input["tenant_id"], "acme");
+        assert_eq!(input.input["labels"]["ingestion.channel"], "email");
+        assert_eq!(input.input["message"]["sender"], "jane@example.com");
+        assert_eq!(input.attachments.len(), 1);
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Agent Manifest Change Audit
+//!
+//! `PATCH /v1/agents/{id}` accepts either a whole manifest or an RFC 7386
+//! JSON merge patch against the stored one. Every update records an
+//! [`AgentManifestChange`] listing the fields it changed, so
+//! `GET /v1/agents/{id}/changes` answers "who changed what, and why"
+//! without diffing version snapshots by hand.
+//!
+//! Tenants with the `manifest_change_reason` feature flag must give a reason
+//! for every update.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Merge patch application and structured manifest diffs
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+use crate::domain::agent::{AgentId, AgentManifest};
+
+/// The requested update to an agent's manifest.
+#[derive(Debug, Clone)]
+pub enum ManifestUpdate {
+    /// Replace the manifest.
+    Replace(Box<AgentManifest>),
+    /// RFC 7386 merge patch applied to the stored manifest's JSON form.
+    MergePatch(Value),
+}
+
+/// One manifest field an update changed. `before` is `None` for added
+/// fields, `after` is `None` for removed ones.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestFieldChange {
+    /// Dotted field path, e.g. `spec.runtime.model`.
+    pub path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<Value>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<Value>,
+}
+
+/// An audited manifest update.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentManifestChange {
+    pub id: Uuid,
+    pub agent_id: AgentId,
+    pub old_version: String,
+    pub new_version: String,
+    pub changes: Vec<ManifestFieldChange>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Subject of the caller that made the change.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changed_by: Option<String>,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ManifestChangeError {
+    #[error("This tenant requires a change_reason for agent manifest updates")]
+    ReasonRequired,
+    #[error("Patched manifest is invalid: {0}")]
+    InvalidManifest(String),
+}
+
+/// Apply an RFC 7386 merge patch to `target`: objects merge recursively,
+/// `null` removes a field, and anything else replaces the target value.
+pub fn apply_merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Map::new());
+    }
+    let Some(target) = target.as_object_mut() else {
+        return;
+    };
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            apply_merge_patch(target.entry(key.clone()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+/// Fields that differ between two manifests' JSON forms, ordered by path.
+/// Objects are compared field by field; arrays and scalars as a whole.
+pub fn diff_manifest_json(before: &Value, after: &Value) -> Vec<ManifestFieldChange> {
+    let mut changes = Vec::new();
+    diff_into(String::new(), Some(before), Some(after), &mut changes);
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    changes
+}
+
+fn diff_into(
+    path: String,
+    before: Option<&Value>,
+    after: Option<&Value>,
+    changes: &mut Vec<ManifestFieldChange>,
+) {
+    match (before, after) {
+        (Some(Value::Object(before)), Some(Value::Object(after))) => {
+            for key in before
+                .keys()
+                .chain(after.keys().filter(|k| !before.contains_key(*k)))
+            {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                diff_into(child, before.get(key), after.get(key), changes);
+            }
+        }
+        (before, after) if before != after => changes.push(ManifestFieldChange {
+            path,
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn merge_patch_then_diff_reports_changed_fields() {
+        let before = json!({
+            "metadata": {"name": "a", "version": "1.0.0", "labels": {"team": "x"}},
+            "spec": {"runtime": {"model": "fast"}, "tools": ["fs"]}
+        });
+        let mut after = before.clone();
+        apply_merge_patch(
+            &mut after,
+            &json!({
+                "metadata": {"version": "1.1.0", "labels": null},
+                "spec": {"runtime": {"model": "smart"}, "tools": ["fs", "web"]}
+            }),
+        );
+
+        assert_eq!(after["metadata"]["name"], "a");
+        assert!(after["metadata"].get("labels").is_none());
+        let paths: Vec<_> = diff_manifest_json(&before, &after)
+            .into_iter()
+            .map(|c| (c.path, c.before.is_some(), c.after.is_some()))
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ("metadata.labels".to_string(), true, false),
+                ("metadata.version".to_string(), true, true),
+                ("spec.runtime.model".to_string(), true, true),
+                ("spec.tools".to_string(), true, true),
+            ]
+        );
+    }
+}
@@ -209,6 +209,11 @@ pub struct ToolServer {
     #[serde(default)]
     pub skip_judge_tools: std::collections::HashSet<String>,
 
+    /// Set of tool names whose results may be returned as streamed SEAL response
+    /// frames. Derived from `CapabilityConfig.streaming` entries in the node configuration.
+    #[serde(default)]
+    pub streaming_tools: std::collections::HashSet<String>,
+
     // Lifecycle
     pub status: ToolServerStatus,
     pub process_id: Option<u32>,
@@ -256,6 +261,13 @@ impl ToolServer {
             .map(|c| c.name.clone())
             .collect();
 
+        let streaming_tools: std::collections::HashSet<String> = config
+            .capabilities
+            .iter()
+            .filter(|c| c.streaming)
+            .map(|c| c.name.clone())
+            .collect();
+
         Self {
             id: ToolServerId::new(),
             name: config.name.clone(),
@@ -264,6 +276,7 @@ impl ToolServer {
             args: config.args.clone(),
             capabilities,
             skip_judge_tools,
+            streaming_tools,
             status: ToolServerStatus::Stopped,
             process_id: None,
             health_check_interval: Duration::from_secs(config.health_check.interval_seconds),
@@ -284,6 +297,12 @@ impl ToolServer {
         self.skip_judge_tools.contains(tool_name)
     }
 
+    /// Returns `true` if the operator has enabled streamed responses for this tool
+    /// (see `CapabilityConfig.streaming` in node config).
+    pub fn is_streaming(&self, tool_name: &str) -> bool {
+        self.streaming_tools.contains(tool_name)
+    }
+
     pub fn start(&mut self) -> Result<MCPToolEvent, DomainError> {
         if self.status != ToolServerStatus::Stopped {
             return Err(DomainError::InvalidStateTransition {
@@ -781,6 +800,7 @@ mod tests {
             args: vec![],
             capabilities: vec!["test.*".to_string()],
             skip_judge_tools: std::collections::HashSet::new(),
+            streaming_tools: std::collections::HashSet::new(),
             status: ToolServerStatus::Stopped,
             process_id: None,
             health_check_interval: Duration::from_secs(30),
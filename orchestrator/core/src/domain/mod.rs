@@ -12,9 +12,15 @@
 //! | Module | Bounded Context | Description |
 //! |---|---|---|
 //! | [`agent`] | BC-1 Agent Lifecycle | `Agent` aggregate, `AgentManifest`, `AgentId` |
+//! | [`deploy_gate`] | BC-1 Agent Lifecycle | `DeployGate` trait, manifest secret scan and admin-defined deploy rules |
+//! | [`manifest_change`] | BC-1 Agent Lifecycle | `AgentManifestChange`, JSON merge patch and field-level manifest diffs for the update audit trail |
 //! | [`execution`] | BC-2 Execution | `Execution` aggregate, `Iteration`, 100monkeys loop types |
+//! | [`concurrency`] | BC-2/BC-3 Execution & Workflow | `ConcurrencySpec`, `ConcurrencyPolicy` — manifest `spec.concurrency` groups |
+//! | [`execution_query`] | BC-2 Execution | `ExecutionQuery` filter expressions (`status=failed AND started>-24h`) |
 //! | [`supervisor`] | BC-2 Execution | `Supervisor` domain service driving the iteration loop (ADR-005) |
+//! | [`guidance`] | BC-2 Execution | `OperatorGuidance`, `GuidanceQueue` — operator instructions injected into the next iteration |
 //! | [`runtime`] | BC-2 Execution | `AgentRuntime` trait, `RuntimeConfig`, `InstanceId` |
+//! | [`runtime_image`] | BC-2 Execution | `RuntimeImageSpec` — Dockerfile + deterministic tag for `spec.runtime.packages` |
 //! | [`runtime_registry`] | BC-2 Execution | `StandardRuntimeRegistry` — certified language+version → image mapping (ADR-043) |
 //! | [`policy`] | BC-4 Security Policy | `SecurityPolicy`, `NetworkPolicy`, `FilesystemPolicy`, `ResourceLimits` |
 //! | [`security_context`] | BC-4/BC-12 SEAL | `SecurityContext` aggregate, `Capability` value object |
@@ -23,6 +29,7 @@
 //! | [`workflow`] | BC-3 Workflow | `Workflow` FSM aggregate, `WorkflowState`, `Blackboard` (ADR-015) |
 //! | [`workflow_registry`] | BC-8 Stimulus-Response | `WorkflowRegistry` aggregate root — routing table + RouterAgent ref (ADR-021) |
 //! | [`stimulus`] | BC-8 Stimulus-Response | `Stimulus`, `StimulusId`, `StimulusSource`, `RoutingDecision` value objects (ADR-021) |
+//! | [`ingestion`] | BC-8 Stimulus-Response | `InboundMessage`, `ReplyTarget` — email/chat messages normalized into execution input |
 //! | [`volume`] | BC-7 Storage Gateway | `Volume` aggregate, `StorageClass`, `VolumeMount` (ADR-032) |
 //! | [`storage`] | BC-7 Storage Gateway | `StorageProvider` trait, `OpenMode`, ACL for SeaweedFS |
 //! | [`fsal`] | BC-7 Storage Gateway | `AegisFSAL` transport-agnostic security boundary (ADR-036) |
@@ -32,10 +39,20 @@
 //! | [`shared_kernel`] | Shared Kernel | Cross-context identity types — DDD Shared Kernel pattern |
 //! | [`discovery`] | BC-1/BC-3 Agent & Workflow Discovery | `DiscoveryQuery`, `DiscoveryResult`, `DiscoveryResponse` value objects (ADR-075) |
 //! | [`env_guard`] | Cross-cutting | Environment variable isolation guard for execution contexts |
+//! | [`error_code`] | Cross-cutting | `ErrorCode` (`AEG-xxxx`) taxonomy and `CodedError` trait |
 //! | [`events`] | Cross-cutting | All domain events — single catalog used by the event bus (ADR-030) |
 //! | [`repository`] | Cross-cutting | Repository traits for all aggregate roots |
 //! | [`validation`] | BC-2 Execution | `ValidationConfig`, gradient validation types (ADR-017) |
 //! | [`llm`] | Cross-cutting | `LLMProvider` trait, LLM request/response value objects |
+//! | [`model_routing`] | Cross-cutting | `TaskClassification`, `TaskClassifier` trait for per-request model routing |
+//! | [`quota`] | Cross-cutting | `QuotaDefinition` per-tenant overrides, `EffectiveQuotas`, `QuotaRepository` trait (ADR-056) |
+//! | [`moderation`] | BC-2 Execution | `ModerationVerdict`, `ModerationProvider` trait — checks final outputs before output handlers deliver them |
+//! | [`plugin`] | BC-2/BC-3 Execution & Workflow | `PluginDigest`, `PluginHost` trait — content-addressed WASM validators and transition conditions |
+//! | [`network_flow`] | BC-2 Execution | `NetworkFlow` connection records, `NetworkFlowSummary`, `NetworkFlowRepository` trait |
+//! | [`task_queue`] | BC-2 Execution | `ExecutionTask`, `TaskQueue` trait — shared queue of executions waiting for a worker daemon |
+//! | [`token_usage`] | BC-2 Execution | `TokenUsageRecord`, usage rollups, `TokenUsageRepository` trait |
+//! | [`usage_report`] | Cross-cutting | `MonthlyUsageSummary` per-tenant rollups, CSV export, `UsageReportRepository` trait |
+//! | [`outbound_webhook`] | Cross-cutting | Outbound delivery attempt log, `RetryPolicy`, payload signing, `WebhookDeliveryRepository` trait |
 //! | [`node_config`] | Infrastructure config | `NodeConfigManifest` parsed from `aegis-config.yaml` |
 //! | [`cluster`] | BC-7 Infrastructure & Hosting | `NodeCluster` aggregate, `NodePeer`, `NodeRouter` (ADR-059) |
 //! | [`canvas`] | BC-7 Storage Gateway | `CanvasSession` aggregate, `WorkspaceMode`, `CanvasTierLimits` (ADR-106) |
@@ -52,27 +69,41 @@ pub mod api_scope;
 pub mod billing;
 pub mod canvas;
 pub mod cluster;
+pub mod concurrency;
 pub mod credential;
+pub mod deploy_gate;
 pub mod discovery;
 pub mod dispatch;
 pub mod edge;
 pub mod edge_fleet;
 pub mod env_guard;
+pub mod error_code;
 pub mod events;
 pub mod execution;
+pub mod execution_query;
 pub mod fsal;
 pub mod git_repo;
 pub mod git_repo_tier_limits;
+pub mod guidance;
 pub mod iam;
+pub mod ingestion;
 pub mod llm;
+pub mod manifest_change;
 pub mod mcp;
+pub mod model_routing;
+pub mod moderation;
+pub mod network_flow;
 pub mod node_config;
+pub mod outbound_webhook;
 pub mod output_handler;
 pub mod path_sanitizer;
+pub mod plugin;
 pub mod policy;
+pub mod quota;
 pub mod rate_limit;
 pub mod repository;
 pub mod runtime;
+pub mod runtime_image;
 pub mod runtime_registry;
 pub mod script;
 pub mod script_tier_limits;
@@ -84,9 +115,12 @@ pub mod shared_kernel;
 pub mod stimulus;
 pub mod storage;
 pub mod supervisor;
+pub mod task_queue;
 pub mod team;
 pub mod tenancy;
 pub mod tenant;
+pub mod token_usage;
+pub mod usage_report;
 pub mod validation;
 pub mod volume;
 pub mod workflow;
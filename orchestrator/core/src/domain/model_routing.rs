@@ -0,0 +1,201 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Model Routing
+//!
+//! Task classification for heterogeneous model routing. Every inner-loop
+//! generation request is labelled with a [`TaskClassification`] (complexity and
+//! domain); `spec.llm_selection.routing` rules
+//! ([`crate::domain::node_config::ModelRoutingConfig`]) then map the labels to a
+//! model alias, so simple iterations run on cheap models and hard ones on
+//! strong models.
+//!
+//! [`HeuristicTaskClassifier`] is the built-in classifier. It inspects only
+//! request shape (conversation size, tool count, refinement iteration) and
+//! task keywords, so it adds no LLM call to the hot path. A model-backed
+//! classifier can be plugged in through [`TaskClassifier`].
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Classification value objects and the classifier interface
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// How demanding a generation request is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskComplexity {
+    Simple,
+    Moderate,
+    Complex,
+}
+
+/// Subject area of a generation request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TaskDomain {
+    General,
+    Code,
+    Analysis,
+    Creative,
+}
+
+/// Labels attached to one generation request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TaskClassification {
+    pub complexity: TaskComplexity,
+    pub domain: TaskDomain,
+}
+
+/// What a classifier sees of a generation request.
+#[derive(Debug, Clone, Copy)]
+pub struct ClassificationRequest<'a> {
+    /// The task statement: the first user message of the conversation.
+    pub task: &'a str,
+    /// Total characters across the conversation so far.
+    pub conversation_chars: usize,
+    /// Number of messages in the conversation so far.
+    pub message_count: usize,
+    /// Number of tools offered to the model.
+    pub tool_count: usize,
+    /// 100monkeys iteration (1 = first attempt; higher = refinement after a
+    /// failed validation).
+    pub iteration_number: u8,
+}
+
+/// Labels generation requests for routing.
+#[async_trait]
+pub trait TaskClassifier: Send + Sync {
+    async fn classify(&self, request: &ClassificationRequest<'_>) -> TaskClassification;
+}
+
+/// Keyword/shape heuristics; see the module docs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicTaskClassifier;
+
+const CODE_KEYWORDS: &[&str] = &[
+    "```",
+    "code",
+    "function",
+    "compile",
+    "bug",
+    "stack trace",
+    "refactor",
+    "unit test",
+    "script",
+    "repository",
+];
+const ANALYSIS_KEYWORDS: &[&str] = &[
+    "analy", "summar", "compare", "report", "evaluate", "research", "dataset",
+];
+const CREATIVE_KEYWORDS: &[&str] = &["story", "poem", "slogan", "creative", "brainstorm"];
+
+impl HeuristicTaskClassifier {
+    /// Classify synchronously; the [`TaskClassifier`] impl delegates here.
+    pub fn classify_now(request: &ClassificationRequest<'_>) -> TaskClassification {
+        let mut score = 0u8;
+        if request.iteration_number > 1 {
+            score += 2;
+        }
+        score += match request.conversation_chars {
+            0..=6_000 => 0,
+            6_001..=24_000 => 1,
+            _ => 2,
+        };
+        if request.tool_count > 10 {
+            score += 1;
+        }
+        if request.message_count > 20 {
+            score += 1;
+        }
+        let complexity = match score {
+            0 => TaskComplexity::Simple,
+            1 | 2 => TaskComplexity::Moderate,
+            _ => TaskComplexity::Complex,
+        };
+
+        let task = request.task.to_lowercase();
+        let mentions = |keywords: &[&str]| keywords.iter().any(|k| task.contains(k));
+        let domain = if mentions(CODE_KEYWORDS) {
+            TaskDomain::Code
+        } else if mentions(ANALYSIS_KEYWORDS) {
+            TaskDomain::Analysis
+        } else if mentions(CREATIVE_KEYWORDS) {
+            TaskDomain::Creative
+        } else {
+            TaskDomain::General
+        };
+
+        TaskClassification { complexity, domain }
+    }
+}
+
+#[async_trait]
+impl TaskClassifier for HeuristicTaskClassifier {
+    async fn classify(&self, request: &ClassificationRequest<'_>) -> TaskClassification {
+        Self::classify_now(request)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::node_config::{ModelRoutingConfig, ModelRoutingRule};
+
+    fn request(task: &str) -> ClassificationRequest<'_> {
+        ClassificationRequest {
+            task,
+            conversation_chars: 400,
+            message_count: 2,
+            tool_count: 3,
+            iteration_number: 1,
+        }
+    }
+
+    #[test]
+    fn heuristics_label_complexity_and_domain() {
+        let simple =
+            HeuristicTaskClassifier::classify_now(&request("What is the capital of France?"));
+        assert_eq!(simple.complexity, TaskComplexity::Simple);
+        assert_eq!(simple.domain, TaskDomain::General);
+
+        let refinement = ClassificationRequest {
+            iteration_number: 3,
+            conversation_chars: 30_000,
+            ..request("Fix the failing unit test in src/lib.rs")
+        };
+        let hard = HeuristicTaskClassifier::classify_now(&refinement);
+        assert_eq!(hard.complexity, TaskComplexity::Complex);
+        assert_eq!(hard.domain, TaskDomain::Code);
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let rule = |name: &str, complexity, domain, model: &str| ModelRoutingRule {
+            name: name.to_string(),
+            complexity,
+            domain,
+            model: model.to_string(),
+        };
+        let config = ModelRoutingConfig {
+            enabled: true,
+            applies_to: vec!["default".to_string()],
+            rules: vec![
+                rule("simple", Some(TaskComplexity::Simple), None, "fast"),
+                rule("code", None, Some(TaskDomain::Code), "smart"),
+            ],
+        };
+        let classify = |complexity, domain| TaskClassification { complexity, domain };
+
+        let simple_code = classify(TaskComplexity::Simple, TaskDomain::Code);
+        assert_eq!(config.select_rule(&simple_code).unwrap().model, "fast");
+        let complex_code = classify(TaskComplexity::Complex, TaskDomain::Code);
+        assert_eq!(config.select_rule(&complex_code).unwrap().model, "smart");
+        let complex_general = classify(TaskComplexity::Complex, TaskDomain::General);
+        assert!(config.select_rule(&complex_general).is_none());
+
+        assert!(config.applies_to_alias("default"));
+        assert!(!config.applies_to_alias("smart"));
+    }
+}
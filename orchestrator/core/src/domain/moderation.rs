@@ -0,0 +1,133 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Output Moderation
+//!
+//! With `spec.moderation` configured, a final output is checked by every
+//! configured [`ModerationProvider`] before an output handler delivers it.
+//! Each provider reports [`ModerationFinding`]s carrying the
+//! [`ModerationAction`] the operator assigned to that provider; the strongest
+//! action across all findings decides the outcome:
+//!
+//! | Action | Delivery |
+//! |--------|----------|
+//! | `annotate` | Delivered; findings recorded on the execution |
+//! | `flag` | Held for a human approval; delivered only if approved |
+//! | `block` | Never delivered |
+//!
+//! The outcome is recorded as an `OutputModerated` execution event.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Moderation findings, verdict and the provider interface
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+/// What a finding does to delivery, weakest first.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum ModerationAction {
+    Annotate,
+    #[default]
+    Flag,
+    Block,
+}
+
+impl ModerationAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModerationAction::Annotate => "annotate",
+            ModerationAction::Flag => "flag",
+            ModerationAction::Block => "block",
+        }
+    }
+}
+
+/// One problem a provider found in an output. `detail` describes the match
+/// without repeating it, so findings never copy PII into the event log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModerationFinding {
+    /// Configured provider name.
+    pub provider: String,
+    /// Provider-defined category such as `email`, `toxicity` or `compliance`.
+    pub category: String,
+    pub action: ModerationAction,
+    /// Classifier score, for providers that produce one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+}
+
+/// Combined result of every provider for one output.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModerationVerdict {
+    pub findings: Vec<ModerationFinding>,
+}
+
+impl ModerationVerdict {
+    pub fn new(findings: Vec<ModerationFinding>) -> Self {
+        Self { findings }
+    }
+
+    /// Strongest action across all findings; `None` for a clean output.
+    pub fn action(&self) -> Option<ModerationAction> {
+        self.findings.iter().map(|f| f.action).max()
+    }
+
+    /// One line per finding, for approval prompts and error messages.
+    pub fn summary(&self) -> String {
+        self.findings
+            .iter()
+            .map(|f| match &f.detail {
+                Some(detail) => format!("{}/{}: {detail}", f.provider, f.category),
+                None => format!("{}/{}", f.provider, f.category),
+            })
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+/// A toxicity, PII or compliance check run on final outputs.
+#[async_trait]
+pub trait ModerationProvider: Send + Sync {
+    /// Configured provider name, used in findings and logs.
+    fn name(&self) -> &str;
+
+    /// Check `output`. An error means the provider could not decide; the
+    /// caller applies `spec.moderation.on_provider_error`.
+    async fn check(&self, output: &str) -> anyhow::Result<Vec<ModerationFinding>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn finding(action: ModerationAction) -> ModerationFinding {
+        ModerationFinding {
+            provider: "pii".to_string(),
+            category: "email".to_string(),
+            action,
+            score: None,
+            detail: Some("1 match".to_string()),
+        }
+    }
+
+    #[test]
+    fn verdict_action_is_the_strongest_finding() {
+        assert_eq!(ModerationVerdict::default().action(), None);
+        let verdict = ModerationVerdict::new(vec![
+            finding(ModerationAction::Annotate),
+            finding(ModerationAction::Block),
+            finding(ModerationAction::Flag),
+        ]);
+        assert_eq!(verdict.action(), Some(ModerationAction::Block));
+        assert_eq!(
+            verdict.summary(),
+            "pii/email: 1 match; pii/email: 1 match; pii/email: 1 match"
+        );
+    }
+}
@@ -0,0 +1,240 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Execution Network Flow Log
+//!
+//! When an execution opts into network flow capture, the runtime records who
+//! the agent container talked to: one [`NetworkFlow`] per connection
+//! (protocol plus source and destination address and port) with the TLS SNI
+//! or DNS name seen for it. Payloads are never captured.
+//!
+//! Flows are stored per execution through [`NetworkFlowRepository`] and
+//! condensed into a [`NetworkFlowSummary`] for the execution report.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Flow records, summary and the repository interface
+
+use std::collections::BTreeMap;
+use std::net::SocketAddr;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::execution::ExecutionId;
+use crate::domain::repository::RepositoryError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FlowProtocol {
+    Tcp,
+    Udp,
+}
+
+impl FlowProtocol {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FlowProtocol::Tcp => "tcp",
+            FlowProtocol::Udp => "udp",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "tcp" => Some(FlowProtocol::Tcp),
+            "udp" => Some(FlowProtocol::Udp),
+            _ => None,
+        }
+    }
+}
+
+/// One connection opened from inside the execution's network namespace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NetworkFlow {
+    pub protocol: FlowProtocol,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    /// TLS Server Name Indication from the ClientHello, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub server_name: Option<String>,
+    /// Name looked up when the flow is a DNS query.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dns_query: Option<String>,
+    pub first_seen: DateTime<Utc>,
+    /// Iteration during which the flow was first seen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iteration_number: Option<u8>,
+}
+
+/// Collapse packets of the same connection into one flow. A TCP SYN and the
+/// later TLS ClientHello on that connection become a single flow carrying the
+/// SNI; distinct DNS queries over the same socket stay separate. The earliest
+/// `first_seen` wins and input order is otherwise preserved.
+pub fn merge_flows(flows: Vec<NetworkFlow>) -> Vec<NetworkFlow> {
+    let mut merged: Vec<NetworkFlow> = Vec::with_capacity(flows.len());
+    let mut index: BTreeMap<(FlowProtocol, SocketAddr, SocketAddr, Option<String>), usize> =
+        BTreeMap::new();
+    for flow in flows {
+        let key = (
+            flow.protocol,
+            flow.source,
+            flow.destination,
+            flow.dns_query.clone(),
+        );
+        match index.get(&key) {
+            Some(&i) => {
+                let existing = &mut merged[i];
+                if existing.server_name.is_none() {
+                    existing.server_name = flow.server_name;
+                }
+                if flow.first_seen < existing.first_seen {
+                    existing.first_seen = flow.first_seen;
+                }
+            }
+            None => {
+                index.insert(key, merged.len());
+                merged.push(flow);
+            }
+        }
+    }
+    merged
+}
+
+/// Flows to one remote endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FlowDestination {
+    pub protocol: FlowProtocol,
+    pub address: String,
+    pub port: u16,
+    /// Distinct SNI names seen towards this endpoint.
+    pub server_names: Vec<String>,
+    pub flows: u64,
+    pub first_seen: DateTime<Utc>,
+}
+
+/// Execution report view of an execution's flows.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct NetworkFlowSummary {
+    pub total_flows: u64,
+    /// Ordered by first contact.
+    pub destinations: Vec<FlowDestination>,
+    /// Distinct names resolved through DNS, in lookup order.
+    pub dns_queries: Vec<String>,
+}
+
+impl NetworkFlowSummary {
+    pub fn summarize(flows: &[NetworkFlow]) -> Self {
+        let mut destinations: BTreeMap<(FlowProtocol, SocketAddr), FlowDestination> =
+            BTreeMap::new();
+        let mut dns_queries: Vec<String> = Vec::new();
+        for flow in flows {
+            if let Some(name) = &flow.dns_query {
+                if !dns_queries.contains(name) {
+                    dns_queries.push(name.clone());
+                }
+            }
+            let entry = destinations
+                .entry((flow.protocol, flow.destination))
+                .or_insert_with(|| FlowDestination {
+                    protocol: flow.protocol,
+                    address: flow.destination.ip().to_string(),
+                    port: flow.destination.port(),
+                    server_names: Vec::new(),
+                    flows: 0,
+                    first_seen: flow.first_seen,
+                });
+            entry.flows += 1;
+            entry.first_seen = entry.first_seen.min(flow.first_seen);
+            if let Some(name) = &flow.server_name {
+                if !entry.server_names.contains(name) {
+                    entry.server_names.push(name.clone());
+                }
+            }
+        }
+        let mut destinations: Vec<FlowDestination> = destinations.into_values().collect();
+        destinations.sort_by_key(|d| d.first_seen);
+        Self {
+            total_flows: flows.len() as u64,
+            destinations,
+            dns_queries,
+        }
+    }
+}
+
+#[async_trait]
+pub trait NetworkFlowRepository: Send + Sync {
+    /// Append flows recorded for `execution_id`.
+    async fn append(
+        &self,
+        execution_id: ExecutionId,
+        flows: &[NetworkFlow],
+    ) -> Result<(), RepositoryError>;
+
+    /// Flows of one execution, oldest first.
+    async fn find_by_execution(
+        &self,
+        execution_id: ExecutionId,
+        limit: Option<usize>,
+    ) -> Result<Vec<NetworkFlow>, RepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn flow(
+        protocol: FlowProtocol,
+        destination: &str,
+        server_name: Option<&str>,
+        dns_query: Option<&str>,
+        second: u32,
+    ) -> NetworkFlow {
+        NetworkFlow {
+            protocol,
+            source: "172.17.0.2:40000".parse().unwrap(),
+            destination: destination.parse().unwrap(),
+            server_name: server_name.map(str::to_string),
+            dns_query: dns_query.map(str::to_string),
+            first_seen: format!("2026-03-17T15:04:{second:02}Z").parse().unwrap(),
+            iteration_number: Some(1),
+        }
+    }
+
+    #[test]
+    fn merge_attaches_sni_to_syn_and_summary_groups_by_destination() {
+        let flows = merge_flows(vec![
+            flow(
+                FlowProtocol::Udp,
+                "10.0.0.53:53",
+                None,
+                Some("api.example.com"),
+                1,
+            ),
+            flow(FlowProtocol::Udp, "10.0.0.53:53", None, Some("pypi.org"), 2),
+            flow(FlowProtocol::Tcp, "93.184.216.34:443", None, None, 3),
+            flow(
+                FlowProtocol::Tcp,
+                "93.184.216.34:443",
+                Some("api.example.com"),
+                None,
+                4,
+            ),
+        ]);
+        assert_eq!(flows.len(), 3);
+        assert_eq!(flows[2].server_name.as_deref(), Some("api.example.com"));
+        assert_eq!(flows[2].first_seen.timestamp() % 60, 3);
+
+        let summary = NetworkFlowSummary::summarize(&flows);
+        assert_eq!(summary.total_flows, 3);
+        assert_eq!(summary.dns_queries, vec!["api.example.com", "pypi.org"]);
+        assert_eq!(summary.destinations.len(), 2);
+        assert_eq!(summary.destinations[0].address, "10.0.0.53");
+        assert_eq!(summary.destinations[0].flows, 2);
+        assert_eq!(summary.destinations[1].port, 443);
+        assert_eq!(
+            summary.destinations[1].server_names,
+            vec!["api.example.com"]
+        );
+    }
+}
@@ -29,11 +29,12 @@
 // - Network and observability settings
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::path::{Path, PathBuf};
 use tracing::warn;
 
 use crate::domain::cluster::MergedConfig;
+use crate::domain::shared_kernel::ImagePullPolicy;
 
 /// Top-level Kubernetes-style node configuration manifest
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,10 +101,66 @@ pub struct RegistryCredentials {
     /// Username for HTTP Basic authentication with the Docker registry API.
     pub username: String,
     /// Password or personal access token.
-    /// Supports `env:VAR_NAME` syntax for environment variable substitution.
+    ///
+    /// Supports `env:VAR_NAME` (environment variable) and
+    /// `secret:engine/path#field` (secrets provider, ADR-034) references.
+    /// Without `#field` the secret's `password` field is used. Plaintext
+    /// values are accepted but discouraged.
     pub password: String,
 }
 
+impl RegistryCredentials {
+    /// Secrets-provider reference (`engine/path` or `engine/path#field`) when
+    /// the password uses the `secret:` syntax.
+    pub fn secret_reference(&self) -> Option<&str> {
+        self.password.strip_prefix("secret:")
+    }
+
+    /// `true` when the password is stored inline rather than referenced.
+    pub fn is_plaintext(&self) -> bool {
+        !self.password.starts_with("env:") && self.secret_reference().is_none()
+    }
+
+    /// Structural validation applied by [`NodeConfigManifest::validate`] and
+    /// `aegis config registry add`.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.registry.is_empty() {
+            return Err("registry cannot be empty".to_string());
+        }
+        if self.registry.contains("://") {
+            return Err(format!(
+                "registry '{}' must be a host prefix without a URL scheme (e.g. 'ghcr.io')",
+                self.registry
+            ));
+        }
+        if self.username.is_empty() {
+            return Err(format!(
+                "registry '{}' has an empty username",
+                self.registry
+            ));
+        }
+        if self.password.is_empty() || self.password == "env:" {
+            return Err(format!(
+                "registry '{}' has an empty password",
+                self.registry
+            ));
+        }
+        if let Some(reference) = self.secret_reference() {
+            let path = reference
+                .split_once('#')
+                .map_or(reference, |(path, _)| path);
+            if !path.contains('/') || path.ends_with('/') || reference.ends_with('#') {
+                return Err(format!(
+                    "registry '{}' password '{}' must use 'secret:engine/path' or \
+                     'secret:engine/path#field'",
+                    self.registry, self.password
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Node configuration specification (content under spec:)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeConfigSpec {
@@ -124,6 +181,10 @@ pub struct NodeConfigSpec {
     #[serde(default)]
     pub llm_selection: LLMSelection,
 
+    /// Per-tenant LLM provider overrides layered over `llm_providers`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tenant_llm: Vec<TenantLLMConfig>,
+
     /// Runtime configuration
     #[serde(default)]
     pub runtime: RuntimeConfig,
@@ -226,6 +287,67 @@ pub struct NodeConfigSpec {
     /// Zaru consumer product configuration.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub zaru: Option<ZaruConfig>,
+
+    /// Container image signature verification (cosign) applied before agent
+    /// containers are created. Disabled when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_verification: Option<ImageVerificationConfig>,
+
+    /// Result cache for identical tool calls repeated across iterations.
+    /// Disabled when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tool_cache: Option<ToolCacheConfig>,
+
+    /// Active/standby failover through a lease in the shared database.
+    /// Disabled when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub high_availability: Option<HighAvailabilityConfig>,
+
+    /// Checks run on agent manifests at deploy time. When absent, manifests
+    /// are scanned for secrets and findings are only logged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deploy_gates: Option<DeployGatesConfig>,
+
+    /// Static feature flag values, keyed by flag name. Operators can
+    /// override them at runtime; see [`FeatureFlagConfig`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub feature_flags: Option<BTreeMap<String, FeatureFlagConfig>>,
+
+    /// Email and chat sources that start executions from inbound messages.
+    /// Disabled when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ingestion: Option<IngestionConfig>,
+
+    /// Stuck-state detection for running workflow executions. Disabled when
+    /// absent; `GET /v1/workflows/executions?stuck=true` then uses the
+    /// defaults of [`WorkflowWatchdogConfig`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub workflow_watchdog: Option<WorkflowWatchdogConfig>,
+
+    /// Per-tenant quota enforcement. Limits are not enforced when absent;
+    /// `/v1/quotas` still reports and stores them.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quotas: Option<QuotasConfig>,
+
+    /// Capture image for per-execution network flow logging. Executions
+    /// cannot request flow capture when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub network_flow_capture: Option<NetworkFlowCaptureConfig>,
+
+    /// Shared queue that executions are started from. Executions start
+    /// in-process on the daemon that accepted them when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub execution_queue: Option<ExecutionQueueConfig>,
+
+    /// Checks final outputs before output handlers deliver them. Outputs
+    /// are delivered unchecked when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<ModerationConfig>,
+
+    /// WASM plugin host for `type: plugin` validators and `condition: plugin`
+    /// transitions. Manifests that reference a plugin fail when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub plugins: Option<PluginsConfig>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -376,6 +498,131 @@ pub struct LLMSelection {
     /// timeout after Ns")`. Default: 30s.
     #[serde(default = "default_llm_overall_timeout_secs")]
     pub llm_overall_timeout_secs: u64,
+
+    /// Per-request model routing by task classification. Disabled when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub routing: Option<ModelRoutingConfig>,
+}
+
+/// Routes inner-loop generation requests to a model alias chosen from the
+/// request's [`crate::domain::model_routing::TaskClassification`].
+///
+/// ```yaml
+/// llm_selection:
+///   routing:
+///     enabled: true
+///     applies_to: [default]
+///     rules:
+///       - name: simple-to-fast
+///         complexity: simple
+///         model: fast
+///       - name: hard-code-to-smart
+///         complexity: complex
+///         domain: code
+///         model: smart
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelRoutingConfig {
+    /// Master switch. Default: true when the section is present.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Requested aliases eligible for rerouting. Executions pinned to any
+    /// other alias always use it. Default: `["default"]`.
+    #[serde(default = "default_routing_applies_to")]
+    pub applies_to: Vec<String>,
+
+    /// Ordered rules; the first match wins. No match keeps the requested alias.
+    #[serde(default)]
+    pub rules: Vec<ModelRoutingRule>,
+}
+
+/// One routing rule. Unset criteria match any value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModelRoutingRule {
+    /// Rule name, reported in routing audit events.
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complexity: Option<crate::domain::model_routing::TaskComplexity>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<crate::domain::model_routing::TaskDomain>,
+
+    /// Model alias to use when the rule matches.
+    pub model: String,
+}
+
+impl ModelRoutingRule {
+    /// Whether this rule matches `classification`.
+    pub fn matches(
+        &self,
+        classification: &crate::domain::model_routing::TaskClassification,
+    ) -> bool {
+        self.complexity
+            .is_none_or(|c| c == classification.complexity)
+            && self.domain.is_none_or(|d| d == classification.domain)
+    }
+}
+
+impl ModelRoutingConfig {
+    /// Whether requests for `alias` are subject to routing.
+    pub fn applies_to_alias(&self, alias: &str) -> bool {
+        self.enabled && self.applies_to.iter().any(|a| a == alias)
+    }
+
+    /// First rule matching `classification`.
+    pub fn select_rule(
+        &self,
+        classification: &crate::domain::model_routing::TaskClassification,
+    ) -> Option<&ModelRoutingRule> {
+        self.rules.iter().find(|rule| rule.matches(classification))
+    }
+}
+
+fn default_routing_applies_to() -> Vec<String> {
+    vec!["default".to_string()]
+}
+
+/// LLM configuration for one tenant, layered over the node's `llm_providers`.
+///
+/// ```yaml
+/// tenant_llm:
+///   - tenant_id: acme
+///     allowed_aliases: [default, fast]   # empty = every alias
+///     llm_providers:                     # override node aliases of the same name
+///       - name: acme-openai
+///         type: openai
+///         endpoint: ""
+///         api_key: secret:kv/tenants/acme/llm#openai_api_key
+///         models:
+///           - alias: default
+///             model: gpt-4o
+///             capabilities: [chat]
+///             context_window: 128000
+///     rate_limit:
+///       calls: 120
+///       per_seconds: 60
+/// ```
+///
+/// Tenant `api_key` values additionally accept `secret:engine/path[#field]`,
+/// read through the secrets provider at startup (field defaults to `api_key`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TenantLLMConfig {
+    /// Tenant slug the overrides apply to.
+    pub tenant_id: String,
+
+    /// Model aliases the tenant may request. Empty allows every alias.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_aliases: Vec<String>,
+
+    /// Providers whose aliases replace the node defaults for this tenant.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub llm_providers: Vec<LLMProviderConfig>,
+
+    /// Cap on LLM calls for this tenant across all aliases.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitDefinition>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -463,6 +710,260 @@ pub struct RuntimeConfig {
     /// Default: None (FUSE transport disabled; falls back to in-process daemon)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub fuse_daemon_endpoint: Option<String>,
+
+    /// Node-local garbage collection of exited containers and built images.
+    #[serde(default)]
+    pub gc: RuntimeGcConfig,
+
+    /// Additional (typically remote) container engines executions can be placed
+    /// on, e.g. arm64 build hosts. An agent declaring `spec.runtime.platform`
+    /// runs on an engine serving that platform; the local engine counts as
+    /// one candidate.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub endpoints: Vec<ContainerEndpointConfig>,
+
+    /// Run agent executions as Kubernetes Pods instead of local containers.
+    /// When set, the Kubernetes runtime replaces the container engines above
+    /// for agent executions.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kubernetes: Option<KubernetesRuntimeConfig>,
+
+    /// Run agent executions as Nomad batch jobs instead of local containers.
+    /// Mutually exclusive with `kubernetes`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nomad: Option<NomadRuntimeConfig>,
+}
+
+/// Nomad cluster agent jobs are submitted to (`spec.runtime.nomad`).
+///
+/// Each execution registers a parameterized batch job using the Docker task
+/// driver; each iteration dispatches it once.
+///
+/// ```yaml
+/// runtime:
+///   nomad:
+///     address: "https://nomad.service.consul:4646"
+///     token: "env:NOMAD_TOKEN"
+///     namespace: aegis
+///     datacenters: ["dc1"]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NomadRuntimeConfig {
+    /// Nomad HTTP API address. Supports env:VAR_NAME syntax.
+    /// Default: "http://127.0.0.1:4646".
+    #[serde(default = "default_nomad_address")]
+    pub address: String,
+
+    /// ACL token sent as `X-Nomad-Token`. Supports env:VAR_NAME syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token: Option<String>,
+
+    /// Nomad namespace. Default: the token's default namespace.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub namespace: Option<String>,
+
+    /// Nomad region. Default: the agent's region.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+
+    /// Datacenters jobs may be placed in. Default: `["*"]`.
+    #[serde(default = "default_nomad_datacenters")]
+    pub datacenters: Vec<String>,
+
+    /// PEM CA bundle for an HTTPS `address`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<String>,
+
+    /// MHz Nomad reserves per CPU core, used to convert
+    /// `resources.cpu` millicores into Nomad's `cpu` (MHz). Default: 1000.
+    #[serde(default = "default_nomad_cpu_mhz_per_core")]
+    pub cpu_mhz_per_core: u32,
+}
+
+fn default_nomad_address() -> String {
+    "http://127.0.0.1:4646".to_string()
+}
+
+fn default_nomad_datacenters() -> Vec<String> {
+    vec!["*".to_string()]
+}
+
+fn default_nomad_cpu_mhz_per_core() -> u32 {
+    1000
+}
+
+/// Kubernetes cluster agent Pods are created in (`spec.runtime.kubernetes`).
+///
+/// Every field is optional when the orchestrator itself runs in-cluster: the
+/// API server, service account token and CA are then taken from the Pod.
+///
+/// ```yaml
+/// runtime:
+///   kubernetes:
+///     namespace: aegis-agents
+///     service_account: aegis-agent
+///     image_pull_secrets: ["registry-creds"]
+///     orchestrator_namespace: aegis-system
+///     orchestrator_selector:
+///       app.kubernetes.io/name: aegis-orchestrator
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KubernetesRuntimeConfig {
+    /// API server URL. Default: in-cluster
+    /// `https://$KUBERNETES_SERVICE_HOST:$KUBERNETES_SERVICE_PORT`.
+    /// Supports env:VAR_NAME syntax.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_server: Option<String>,
+
+    /// Namespace agent Pods and their NetworkPolicies are created in.
+    /// Default: "aegis-agents".
+    #[serde(default = "default_kubernetes_namespace")]
+    pub namespace: String,
+
+    /// File holding the bearer token. Re-read on every request so rotated
+    /// service account tokens are picked up.
+    /// Default: the in-cluster service account token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_file: Option<String>,
+
+    /// PEM CA bundle for the API server.
+    /// Default: the in-cluster service account CA.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ca_cert: Option<String>,
+
+    /// Service account agent Pods run as. Default: the namespace default.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service_account: Option<String>,
+
+    /// Secrets used to pull agent and sidecar images.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub image_pull_secrets: Vec<String>,
+
+    /// Node labels agent Pods must be scheduled on.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub node_selector: HashMap<String, String>,
+
+    /// Create a NetworkPolicy per Pod from the manifest's
+    /// `spec.security.network`. Default: `true`.
+    #[serde(default = "default_true")]
+    pub network_policies: bool,
+
+    /// Labels of the orchestrator Pods. Agent Pods keep egress to them (LLM
+    /// proxy and tool calls) under every network policy.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub orchestrator_selector: HashMap<String, String>,
+
+    /// Namespace of the orchestrator Pods. Default: `namespace`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub orchestrator_namespace: Option<String>,
+}
+
+fn default_kubernetes_namespace() -> String {
+    "aegis-agents".to_string()
+}
+
+/// A container engine reachable over a socket or TCP (`spec.runtime.endpoints[]`).
+///
+/// ```yaml
+/// runtime:
+///   endpoints:
+///     - name: arm-builder-1
+///       host: "tcp://10.0.4.21:2376"
+///       platforms: ["linux/arm64"]
+///       tls:
+///         ca_cert: /etc/aegis/docker/ca.pem
+///         client_cert: /etc/aegis/docker/cert.pem
+///         client_key: /etc/aegis/docker/key.pem
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContainerEndpointConfig {
+    /// Unique name used in logs and placement decisions.
+    pub name: String,
+
+    /// Engine address: `tcp://host:port`, `unix:///path/docker.sock` or a bare
+    /// socket path. Supports env:VAR_NAME syntax.
+    pub host: String,
+
+    /// Platforms (`os/arch`, e.g. `linux/arm64`) served by this engine. When
+    /// empty, the platform reported by the engine's `/version` is used.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub platforms: Vec<String>,
+
+    /// Mutual TLS material for `tcp://` hosts. Without it the connection is
+    /// plain HTTP, which should only be used on trusted networks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tls: Option<ContainerEndpointTlsConfig>,
+}
+
+/// PEM file paths for a TLS-protected Docker endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ContainerEndpointTlsConfig {
+    pub ca_cert: String,
+    pub client_cert: String,
+    pub client_key: String,
+}
+
+/// Retention policy for node-local runtime artifacts (`spec.runtime.gc`).
+///
+/// Only objects labelled `aegis.managed=true` are considered, so images and
+/// containers created outside AEGIS are never touched. Also runnable on demand
+/// with `aegis daemon gc`.
+///
+/// ```yaml
+/// runtime:
+///   gc:
+///     interval_seconds: 3600
+///     keep_images_per_agent: 3
+///     exited_container_max_age_seconds: 86400
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuntimeGcConfig {
+    /// Run the scheduled collector in the daemon. Default: `true`.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Seconds between scheduled runs. Default: 3600.
+    #[serde(default = "default_gc_interval_seconds")]
+    pub interval_seconds: u64,
+
+    /// Built runtime images (`aegis-runtime/*`) retained per agent, newest
+    /// first. Default: 3.
+    #[serde(default = "default_gc_keep_images_per_agent")]
+    pub keep_images_per_agent: usize,
+
+    /// Exited managed containers are removed once they have been stopped for
+    /// this many seconds. This includes containers kept by
+    /// `keep_container_on_failure`. Default: 86400 (24h).
+    #[serde(default = "default_gc_exited_container_max_age_seconds")]
+    pub exited_container_max_age_seconds: u64,
+
+    /// Also prune dangling (untagged) images. Default: `true`.
+    #[serde(default = "default_true")]
+    pub prune_dangling_images: bool,
+}
+
+fn default_gc_interval_seconds() -> u64 {
+    3600
+}
+
+fn default_gc_keep_images_per_agent() -> usize {
+    3
+}
+
+fn default_gc_exited_container_max_age_seconds() -> u64 {
+    86_400
+}
+
+impl Default for RuntimeGcConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            interval_seconds: default_gc_interval_seconds(),
+            keep_images_per_agent: default_gc_keep_images_per_agent(),
+            exited_container_max_age_seconds: default_gc_exited_container_max_age_seconds(),
+            prune_dangling_images: true,
+        }
+    }
 }
 
 fn default_runtime_registry_path() -> String {
@@ -487,6 +988,10 @@ impl Default for RuntimeConfig {
             runtime_registry_path: default_runtime_registry_path(),
             fuse_mount_prefix: None,
             fuse_daemon_endpoint: None,
+            gc: RuntimeGcConfig::default(),
+            endpoints: Vec::new(),
+            kubernetes: None,
+            nomad: None,
         }
     }
 }
@@ -754,6 +1259,10 @@ pub struct StorageConfig {
     /// OpenDAL configuration (used if backend: "opendal")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub opendal: Option<OpenDalConfig>,
+
+    /// Scratch workspace provisioned for executions whose manifest declares no volumes.
+    #[serde(default)]
+    pub default_workspace: DefaultWorkspaceConfig,
 }
 
 impl Default for StorageConfig {
@@ -764,6 +1273,44 @@ impl Default for StorageConfig {
             seaweedfs: None,
             local_host: Some(LocalHostStorageConfig::default()),
             opendal: None,
+            default_workspace: DefaultWorkspaceConfig::default(),
+        }
+    }
+}
+
+/// Default per-execution workspace (`spec.storage.default_workspace`).
+///
+/// When an agent manifest declares no `spec.volumes` (and no workflow supplies
+/// a workspace), the execution gets an ephemeral volume mounted read-write at
+/// `/workspace`. It is deleted when the execution ends unless the manifest sets
+/// `spec.advanced.keep_workspace: true`, in which case it expires after
+/// `ttl_hours` like any other ephemeral volume.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DefaultWorkspaceConfig {
+    /// Provision the default workspace. Default: `true`.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Size limit, in the manifest `size_limit` format (e.g. `"1Gi"`, `"500Mi"`).
+    /// Default: `"1Gi"`.
+    #[serde(default = "default_workspace_size_limit")]
+    pub size_limit: String,
+
+    /// TTL for retained (`keep_workspace`) or leaked workspaces. Default: 24.
+    #[serde(default = "default_ttl_hours")]
+    pub ttl_hours: u32,
+}
+
+fn default_workspace_size_limit() -> String {
+    "1Gi".to_string()
+}
+
+impl Default for DefaultWorkspaceConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            size_limit: default_workspace_size_limit(),
+            ttl_hours: default_ttl_hours(),
         }
     }
 }
@@ -807,6 +1354,10 @@ pub struct SeaweedFSConfig {
     /// Default: "us-east-1"
     #[serde(default = "default_s3_region")]
     pub s3_region: String,
+
+    /// Coalescing of small writes into fewer filer uploads
+    #[serde(default)]
+    pub write_behind: SeaweedFSWriteBehindConfig,
 }
 
 impl Default for SeaweedFSConfig {
@@ -820,6 +1371,46 @@ impl Default for SeaweedFSConfig {
             gc_interval_minutes: default_gc_interval_minutes(),
             s3_endpoint: None,
             s3_region: default_s3_region(),
+            write_behind: SeaweedFSWriteBehindConfig::default(),
+        }
+    }
+}
+
+/// Write-behind buffering for the SeaweedFS adapter (`spec.storage.seaweedfs.write_behind`).
+///
+/// Small writes to a file are held in memory and uploaded together once the
+/// file has `flush_bytes` pending or its oldest pending write is
+/// `flush_interval_ms` old. Closing a file always flushes it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeaweedFSWriteBehindConfig {
+    /// Buffer writes instead of uploading each one. Default: `true`.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Pending bytes per file that trigger a flush. Default: 1048576 (1 MiB).
+    #[serde(default = "default_write_behind_flush_bytes")]
+    pub flush_bytes: usize,
+
+    /// Maximum age of a pending write before it is flushed (milliseconds).
+    /// Default: 500
+    #[serde(default = "default_write_behind_flush_interval_ms")]
+    pub flush_interval_ms: u64,
+}
+
+fn default_write_behind_flush_bytes() -> usize {
+    1024 * 1024
+}
+
+fn default_write_behind_flush_interval_ms() -> u64 {
+    500
+}
+
+impl Default for SeaweedFSWriteBehindConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            flush_bytes: default_write_behind_flush_bytes(),
+            flush_interval_ms: default_write_behind_flush_interval_ms(),
         }
     }
 }
@@ -881,6 +1472,13 @@ pub struct CapabilityConfig {
     /// node configuration, not an agent-level privilege. Agents cannot influence this flag.
     #[serde(default)]
     pub skip_judge: bool,
+
+    /// When `true`, agents may call this tool through `POST /v1/seal/invoke/stream`
+    /// and receive the result as signed, sequenced SEAL response frames instead of a
+    /// single JSON body. Intended for tools with large outputs (log tails, file reads).
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub streaming: bool,
 }
 
 /// Built-in tools configured directly inside the Orchestrator via Dispatch Protocol (ADR-040)
@@ -1013,40 +1611,142 @@ pub struct DatabaseConfig {
     /// Connection timeout in seconds.
     #[serde(default = "default_db_connect_timeout_seconds")]
     pub connect_timeout_seconds: u64,
+
+    /// How the daemon treats pending schema migrations at startup.
+    #[serde(default)]
+    pub migrations: MigrationsConfig,
+
+    /// Optional streaming replica for eventually-consistent reads such as
+    /// execution listings and storage event queries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub read_replica: Option<ReadReplicaConfig>,
 }
 
-/// Temporal workflow engine configuration (ADR-022).
-///
-/// Configures the connection to the Temporal server used for durable workflow
-/// execution. If this section is omitted, the orchestrator uses default values.
+/// Read replica connection (`spec.database.read_replica`).
 ///
-/// All string fields support the `env:VAR_NAME` credential resolution pattern.
+/// Listing and event queries go to the replica while its replay lag stays
+/// within `max_lag_seconds`; otherwise, and for status-critical reads, they
+/// go to the primary.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct TemporalConfig {
-    /// Temporal server address (host:port).
-    /// Example: `"temporal:7233"` (Docker), `"localhost:7233"` (local dev)
-    #[serde(default = "default_temporal_address")]
-    pub address: String,
+pub struct ReadReplicaConfig {
+    /// Replica connection URL. Supports `env:VAR_NAME`.
+    pub url: String,
 
-    /// HTTP endpoint of the Temporal worker service.
-    /// Used for workflow activity callbacks.
-    /// Example: `"http://temporal-worker:3000"`
-    #[serde(default = "default_temporal_worker_http_endpoint")]
-    pub worker_http_endpoint: String,
+    /// Maximum connection pool size.
+    #[serde(default = "default_db_max_connections")]
+    pub max_connections: u32,
 
-    /// Shared HMAC secret for authenticating Temporal event callbacks.
-    /// Supports `env:VAR_NAME` and `secret:` credential resolution patterns.
-    /// If omitted, the `/v1/temporal-events` endpoint is unauthenticated (warns at startup).
-    #[serde(skip_serializing_if = "Option::is_none")]
-    pub worker_secret: Option<String>,
+    /// Largest replay lag at which reads still go to the replica.
+    #[serde(default = "default_replica_max_lag_seconds")]
+    pub max_lag_seconds: u64,
 
-    /// Temporal namespace.
-    #[serde(default = "default_temporal_namespace")]
-    pub namespace: String,
+    /// How often the replay lag is measured.
+    #[serde(default = "default_replica_lag_check_interval_seconds")]
+    pub lag_check_interval_seconds: u64,
+}
 
-    /// Temporal task queue.
-    #[serde(default = "default_temporal_task_queue")]
-    pub task_queue: String,
+/// Startup migration policy (`spec.database.migrations`).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MigrationsConfig {
+    /// `apply` (default), `check` or `skip`. `aegis --daemon --migrate=<mode>`
+    /// overrides it.
+    #[serde(default)]
+    pub mode: MigrateMode,
+
+    /// `pg_dump` run before pending migrations are applied. Omit to migrate
+    /// without a backup.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub backup: Option<MigrationBackupConfig>,
+}
+
+/// What the daemon does with pending migrations at startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MigrateMode {
+    /// Refuse to start while migrations are pending; never modify the schema.
+    Check,
+    /// Apply pending migrations, after the backup hook if one is configured.
+    #[default]
+    Apply,
+    /// Start without inspecting or applying migrations.
+    Skip,
+}
+
+impl std::fmt::Display for MigrateMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            MigrateMode::Check => "check",
+            MigrateMode::Apply => "apply",
+            MigrateMode::Skip => "skip",
+        })
+    }
+}
+
+impl std::str::FromStr for MigrateMode {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "check" => Ok(MigrateMode::Check),
+            "apply" => Ok(MigrateMode::Apply),
+            "skip" => Ok(MigrateMode::Skip),
+            other => Err(format!(
+                "invalid migrate mode '{other}': expected check, apply or skip"
+            )),
+        }
+    }
+}
+
+/// Pre-migration `pg_dump` hook (`spec.database.migrations.backup`).
+///
+/// Writes `<output_dir>/aegis-<UTC timestamp>-pre-<version>.dump` in
+/// `pg_dump`'s custom format. A failed dump aborts the migration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MigrationBackupConfig {
+    /// Directory the dump is written to; created if missing.
+    pub output_dir: PathBuf,
+
+    /// `pg_dump` executable.
+    #[serde(default = "default_pg_dump_path")]
+    pub pg_dump_path: String,
+
+    /// Extra arguments passed to `pg_dump`, e.g. `["--exclude-table-data=llm_token_usage"]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extra_args: Vec<String>,
+}
+
+/// Temporal workflow engine configuration (ADR-022).
+///
+/// Configures the connection to the Temporal server used for durable workflow
+/// execution. If this section is omitted, the orchestrator uses default values.
+///
+/// All string fields support the `env:VAR_NAME` credential resolution pattern.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TemporalConfig {
+    /// Temporal server address (host:port).
+    /// Example: `"temporal:7233"` (Docker), `"localhost:7233"` (local dev)
+    #[serde(default = "default_temporal_address")]
+    pub address: String,
+
+    /// HTTP endpoint of the Temporal worker service.
+    /// Used for workflow activity callbacks.
+    /// Example: `"http://temporal-worker:3000"`
+    #[serde(default = "default_temporal_worker_http_endpoint")]
+    pub worker_http_endpoint: String,
+
+    /// Shared HMAC secret for authenticating Temporal event callbacks.
+    /// Supports `env:VAR_NAME` and `secret:` credential resolution patterns.
+    /// If omitted, the `/v1/temporal-events` endpoint is unauthenticated (warns at startup).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worker_secret: Option<String>,
+
+    /// Temporal namespace.
+    #[serde(default = "default_temporal_namespace")]
+    pub namespace: String,
+
+    /// Temporal task queue.
+    #[serde(default = "default_temporal_task_queue")]
+    pub task_queue: String,
 
     /// Maximum number of connection retries when establishing the Temporal client.
     /// If omitted, a default of 30 retries is used.
@@ -1427,130 +2127,978 @@ pub struct CapabilityDefinition {
     /// Max response size in bytes.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_response_size: Option<u64>,
+    /// Argument matchers, time windows and per-execution call limits.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<crate::domain::security_context::CapabilityConditions>,
+}
+
+/// YAML-serializable rate limit for a capability definition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimitDefinition {
+    pub calls: u32,
+    pub per_seconds: u32,
+}
+
+/// OIDC IAM configuration (ADR-041 §Node Configuration).
+///
+/// Defines the trusted identity realms, JWKS cache TTL, and custom claim names.
+/// When this section is present in `aegis-config.yaml`, all HTTP and gRPC
+/// auth middleware is enabled. When absent, auth is disabled (local dev mode).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IamConfig {
+    /// All known realms — determines which JWKS endpoints to trust and cache.
+    /// The platform validates JWTs against the realm matching the JWT's "iss" claim.
+    pub realms: Vec<IamRealmConfig>,
+
+    /// JWKS cache TTL in seconds — keys refreshed this often to support key rotation.
+    /// Default: 300 (5 minutes).
+    #[serde(default = "default_jwks_cache_ttl")]
+    pub jwks_cache_ttl_seconds: u64,
+
+    /// Custom claim names for OIDC attribute mappers.
+    #[serde(default)]
+    pub claims: IamClaimsConfig,
+
+    /// Keycloak Admin REST API credentials for tenant provisioning (ADR-097).
+    /// If omitted, automatic tenant provisioning on user signup is disabled.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keycloak_admin: Option<KeycloakAdminConfig>,
+}
+
+/// Individual realm configuration entry within `spec.iam.realms`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IamRealmConfig {
+    /// Realm identifier: "aegis-system", "zaru-consumer", or "tenant-{slug}"
+    pub slug: String,
+    /// Full issuer URL: `https://auth.example.com/realms/{slug}`
+    pub issuer_url: String,
+    /// JWKS endpoint: {issuer_url}/protocol/openid-connect/certs
+    pub jwks_uri: String,
+    /// Expected "aud" claim value for tokens from this realm
+    pub audience: String,
+    /// Realm classification: "system", "consumer", or "tenant"
+    pub kind: String,
+}
+
+/// Custom claim names for OIDC attribute mappers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IamClaimsConfig {
+    /// Custom claim mapper name for ZaruTier. Default: "zaru_tier"
+    #[serde(default = "default_zaru_tier_claim")]
+    pub zaru_tier: String,
+    /// Role attribute name in aegis-system realm. Default: "aegis_role"
+    #[serde(default = "default_aegis_role_claim")]
+    pub aegis_role: String,
+    /// Custom claim mapper name for per-user tenant ID (ADR-097). Default: "tenant_id"
+    #[serde(default = "default_tenant_id_claim")]
+    pub tenant_id: String,
+}
+
+/// Keycloak Admin REST API credentials (ADR-097).
+///
+/// Used by [`crate::application::tenant_provisioning::TenantProvisioningService`]
+/// to stamp `tenant_id` user attributes on newly registered consumer users.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KeycloakAdminConfig {
+    /// Keycloak base URL (e.g. `https://auth.aegis.local`). Supports `env:VAR` syntax.
+    pub host: String,
+    /// Admin username for the `master` realm. Supports `env:VAR` syntax.
+    pub admin_username: String,
+    /// Admin password for the `master` realm. Supports `env:VAR` syntax.
+    pub admin_password: String,
+}
+
+impl Default for IamClaimsConfig {
+    fn default() -> Self {
+        Self {
+            zaru_tier: default_zaru_tier_claim(),
+            aegis_role: default_aegis_role_claim(),
+            tenant_id: default_tenant_id_claim(),
+        }
+    }
+}
+
+fn default_jwks_cache_ttl() -> u64 {
+    300
+}
+
+fn default_zaru_tier_claim() -> String {
+    "zaru_tier".to_string()
+}
+
+fn default_aegis_role_claim() -> String {
+    "aegis_role".to_string()
+}
+
+fn default_tenant_id_claim() -> String {
+    "tenant_id".to_string()
+}
+
+/// gRPC authentication configuration (ADR-041 §gRPC Authentication Amendment).
+///
+/// When enabled, a `OIDCAuthInterceptor` is installed on the gRPC server
+/// that validates Bearer JWTs on every call except exempted methods.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrpcAuthConfig {
+    /// Whether gRPC JWT auth is enabled.
+    #[serde(default)]
+    pub enabled: bool,
+    /// Methods exempt from token auth (e.g. inner loop bootstrap channel).
+    #[serde(default)]
+    pub exempt_methods: Vec<String>,
+}
+
+/// Standalone SEAL tooling gateway endpoint configuration (ADR-053).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SealGatewayConfig {
+    /// gRPC endpoint URL of the gateway invocation service.
+    /// Example: "http://aegis-seal-gateway:50055"
+    pub url: String,
+}
+
+/// Supply-chain policy: only run agent images signed by a trusted key.
+///
+/// ```yaml
+/// image_verification:
+///   enabled: true
+///   trust_roots:
+///     - name: acme-release
+///       public_key: /etc/aegis/cosign/acme.pub   # or env:ACME_COSIGN_PUB
+///       registries: ["ghcr.io/acme"]
+///       tenants: ["acme"]                        # empty = every tenant
+/// ```
+///
+/// Verification shells out to the `cosign` CLI (`cosign verify --key ...`).
+/// An image passes when any applicable trust root verifies it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageVerificationConfig {
+    /// Master switch; `false` keeps the config in place without enforcing it.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Path to the `cosign` binary. Defaults to `cosign` on `PATH`.
+    #[serde(default = "default_cosign_path")]
+    pub cosign_path: String,
+
+    /// Wall-clock budget for a single `cosign verify` invocation.
+    #[serde(default = "default_image_verification_timeout_secs")]
+    pub timeout_seconds: u64,
+
+    /// When `true` (default), images with no applicable trust root are
+    /// rejected. When `false` they run unverified.
+    #[serde(default = "default_true")]
+    pub require_trust_root: bool,
+
+    /// Public keys images may be signed with, scoped by registry and tenant.
+    #[serde(default)]
+    pub trust_roots: Vec<ImageTrustRoot>,
+}
+
+/// One cosign public key and the images/tenants it applies to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageTrustRoot {
+    /// Operator-facing identifier used in logs and audit events.
+    pub name: String,
+
+    /// Cosign public key: a file path, or `env:VAR_NAME` holding the PEM.
+    pub public_key: String,
+
+    /// Image reference prefixes this key covers (e.g. `"ghcr.io/acme"`).
+    /// Empty matches every image.
+    #[serde(default)]
+    pub registries: Vec<String>,
+
+    /// Tenant slugs this key is trusted for. Empty means all tenants.
+    #[serde(default)]
+    pub tenants: Vec<String>,
+}
+
+impl ImageTrustRoot {
+    /// Whether this trust root applies to `image` run by `tenant`.
+    pub fn applies_to(&self, image: &str, tenant: &str) -> bool {
+        let registry_match =
+            self.registries.is_empty() || self.registries.iter().any(|r| image.starts_with(r));
+        let tenant_match = self.tenants.is_empty() || self.tenants.iter().any(|t| t == tenant);
+        registry_match && tenant_match
+    }
+}
+
+impl ImageVerificationConfig {
+    /// Trust roots applicable to `image` for `tenant`, in config order.
+    pub fn trust_roots_for(&self, image: &str, tenant: &str) -> Vec<&ImageTrustRoot> {
+        self.trust_roots
+            .iter()
+            .filter(|root| root.applies_to(image, tenant))
+            .collect()
+    }
+}
+
+/// Result cache for tool calls.
+///
+/// ```yaml
+/// tool_cache:
+///   enabled: true
+///   default_ttl_seconds: 0        # 0 = only tools listed below are cached
+///   max_entries: 1024
+///   tools:
+///     web.fetch: { ttl_seconds: 300 }
+///     docs.search: { ttl_seconds: 60 }
+///     tickets.update: { no_cache: true }
+/// ```
+///
+/// Only successful results are cached. Builtin tools that mutate state or
+/// read the execution workspace are never cached, whatever this says.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCacheConfig {
+    /// Master switch; `false` keeps the config in place without caching.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// TTL for tools without an entry in `tools`.
+    #[serde(default)]
+    pub default_ttl_seconds: u64,
+
+    /// Upper bound on cached results across all tools.
+    #[serde(default = "default_tool_cache_max_entries")]
+    pub max_entries: usize,
+
+    /// Per-tool overrides, keyed by tool name.
+    #[serde(default)]
+    pub tools: HashMap<String, ToolCacheRule>,
+}
+
+/// Cache behavior for one tool.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ToolCacheRule {
+    /// Overrides `default_ttl_seconds` for this tool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl_seconds: Option<u64>,
+
+    /// Never cache this tool. Set this for MCP tools that mutate state.
+    #[serde(default)]
+    pub no_cache: bool,
+}
+
+impl ToolCacheConfig {
+    /// How long a result of `tool_name` may be reused, or `None` when it must
+    /// not be cached.
+    pub fn ttl_for(&self, tool_name: &str) -> Option<std::time::Duration> {
+        let rule = self.tools.get(tool_name);
+        if rule.is_some_and(|rule| rule.no_cache) {
+            return None;
+        }
+        let seconds = rule
+            .and_then(|rule| rule.ttl_seconds)
+            .unwrap_or(self.default_ttl_seconds);
+        (seconds > 0).then(|| std::time::Duration::from_secs(seconds))
+    }
+}
+
+fn default_tool_cache_max_entries() -> usize {
+    1024
+}
+
+/// Warm-standby failover for a pair of daemons sharing one database.
+///
+/// ```yaml
+/// high_availability:
+///   enabled: true
+///   lease_name: aegis-primary     # identical on both daemons of the pair
+///   lease_ttl_seconds: 15
+///   renew_interval_seconds: 5
+/// ```
+///
+/// The daemon holding the lease is active: it binds NFS, HTTP and gRPC and
+/// renews the lease every `renew_interval_seconds`. The other daemon waits
+/// as a standby, connected to the database, and takes over once the lease
+/// has gone `lease_ttl_seconds` without a renewal. An active that cannot
+/// renew in time shuts down rather than risk running alongside the new one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HighAvailabilityConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Name of the lease row both daemons contend for.
+    #[serde(default = "default_ha_lease_name")]
+    pub lease_name: String,
+
+    /// How long a lease stays valid without renewal; the failover delay.
+    #[serde(default = "default_ha_lease_ttl_seconds")]
+    pub lease_ttl_seconds: u64,
+
+    /// How often the active renews, and the standby retries, the lease.
+    #[serde(default = "default_ha_renew_interval_seconds")]
+    pub renew_interval_seconds: u64,
+}
+
+impl HighAvailabilityConfig {
+    pub fn lease_ttl(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.lease_ttl_seconds)
+    }
+
+    pub fn renew_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.renew_interval_seconds)
+    }
+}
+
+/// Deploy-time checks on agent manifests.
+///
+/// ```yaml
+/// deploy_gates:
+///   secret_scan: reject            # off | warn (default) | reject
+///   rules:
+///     - name: no-egress-on-free
+///       description: Network egress must be disabled for free-tier tenants
+///       action: reject             # warn | reject (default)
+///       match:
+///         tiers: [free]            # caller's Zaru tier; empty = any
+///         tenants: []              # tenant slugs; empty = any
+///       require:
+///         network_modes: [none]
+/// ```
+///
+/// The secret scan looks for known credential formats, high-entropy tokens
+/// and literal values under secret-sounding names (`*_TOKEN`, `password`)
+/// anywhere in the manifest, including inline context. Use `secretRef` in
+/// `spec.runtime.env` instead.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeployGatesConfig {
+    #[serde(default)]
+    pub secret_scan: SecretScanMode,
+
+    /// Custom checks, evaluated in order; every failing rule is reported.
+    #[serde(default)]
+    pub rules: Vec<DeployGateRule>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SecretScanMode {
+    Off,
+    #[default]
+    Warn,
+    Reject,
+}
+
+/// What a failing deploy gate does to the deploy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeployGateAction {
+    Warn,
+    #[default]
+    Reject,
+}
+
+/// One admin-defined deploy check: when a deploy matches `match`, the
+/// manifest must satisfy every entry in `require`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeployGateRule {
+    pub name: String,
+
+    /// Shown to the deployer when the rule fails.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub action: DeployGateAction,
+
+    #[serde(default, rename = "match")]
+    pub selector: DeployGateSelector,
+
+    #[serde(default)]
+    pub require: DeployGateRequirements,
+}
+
+/// Which deploys a rule applies to. Empty lists match everything.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeployGateSelector {
+    /// Tenant slugs.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tenants: Vec<String>,
+
+    /// Zaru tiers of the deploying user (`free`, `pro`, `business`,
+    /// `enterprise`). Deploys by operators and service accounts never match
+    /// a tier selector.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tiers: Vec<String>,
+}
+
+/// Constraints a matching manifest must meet. Unset fields are not checked.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct DeployGateRequirements {
+    /// Allowed `spec.security.network.mode` values. A manifest without a
+    /// security section is checked as mode `allow`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub network_modes: Vec<String>,
+
+    /// `spec.security_context` must be set.
+    #[serde(default)]
+    pub security_context_required: bool,
+
+    /// Tool patterns that may not appear in `spec.tools`. A trailing `*`
+    /// matches any suffix.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub denied_tools: Vec<String>,
+
+    /// Prefixes a custom `spec.runtime.image` must start with.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub image_prefixes: Vec<String>,
+}
+
+/// Static value of one feature flag.
+///
+/// ```yaml
+/// feature_flags:
+///   model_routing:
+///     enabled: true
+///     tenants:                     # per-tenant values win over `enabled`
+///       acme: false
+///   virtio_fs:
+///     description: Firecracker virtio-fs transport
+/// ```
+///
+/// Operators override flags at runtime with `PUT /v1/admin/feature-flags/{name}`;
+/// overrides win over this config and last until the daemon restarts.
+/// Flags absent from both fall back to their built-in default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeatureFlagConfig {
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Tenant slug to value.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tenants: BTreeMap<String, bool>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// Feature flag names are lowercase ASCII letters, digits, `_`, `.` and `-`.
+pub fn is_valid_feature_flag_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= 64
+        && name.bytes().all(|b| {
+            b.is_ascii_lowercase() || b.is_ascii_digit() || matches!(b, b'_' | b'.' | b'-')
+        })
+}
+
+/// Inbound message sources (`spec.ingestion`).
+///
+/// ```yaml
+/// ingestion:
+///   imap:
+///     - name: support
+///       host: imap.example.com
+///       username: support@example.com
+///       password: env:SUPPORT_IMAP_PASSWORD
+///       tenant_id: acme
+///       agent_id: 6f1c1e9e-8d0b-4c55-9a53-2f0f3c1b9d11
+///       security_context: tenant-acme-support
+///       reply_url: https://mail-relay.example.com/send
+///   slack:
+///     name: helpdesk
+///     signing_secret: env:SLACK_SIGNING_SECRET
+///     bot_token: env:SLACK_BOT_TOKEN
+///     tenant_id: acme
+///     agent_id: 6f1c1e9e-8d0b-4c55-9a53-2f0f3c1b9d11
+///     security_context: tenant-acme-support
+///     channels: [C0123456789]
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IngestionConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub imap: Vec<ImapIngestionConfig>,
+
+    /// Slack Events API receiver at `POST /v1/webhooks/slack/events`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slack: Option<SlackIngestionConfig>,
+
+    /// Attachments larger than this are dropped from the message.
+    #[serde(default = "default_ingestion_max_attachment_bytes")]
+    pub max_attachment_bytes: u64,
+
+    /// How long to wait for an execution before giving up on its reply.
+    #[serde(default = "default_ingestion_reply_timeout_seconds")]
+    pub reply_timeout_seconds: u64,
+}
+
+fn default_ingestion_max_attachment_bytes() -> u64 {
+    25 * 1024 * 1024
+}
+
+fn default_ingestion_reply_timeout_seconds() -> u64 {
+    3600
+}
+
+/// Stuck-state watchdog for workflow executions (`spec.workflow_watchdog`).
+///
+/// An execution is stuck once it has been in one state longer than that
+/// state's `timeout`, or `default_max_state_seconds` when the state sets
+/// none. `Human` states without a `timeout` are never stuck.
+///
+/// ```yaml
+/// workflow_watchdog:
+///   check_interval_seconds: 60
+///   default_max_state_seconds: 3600
+///   on_stuck: retry
+///   max_retries: 2
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowWatchdogConfig {
+    /// Run the periodic check in the daemon. Default: `true`.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// Seconds between checks. Default: 60.
+    #[serde(default = "default_watchdog_check_interval_seconds")]
+    pub check_interval_seconds: u64,
+
+    /// Expected time in a state that sets no `timeout`. Default: 3600.
+    #[serde(default = "default_watchdog_max_state_seconds")]
+    pub default_max_state_seconds: u64,
+
+    /// `notify` (default) only publishes `WorkflowStateStuck`; `fail`
+    /// terminates the execution; `retry` asks the worker to re-run the state.
+    #[serde(default)]
+    pub on_stuck: crate::domain::workflow::StuckStateAction,
+
+    /// Retries per execution and state before `retry` falls back to `fail`.
+    /// Default: 1.
+    #[serde(default = "default_watchdog_max_retries")]
+    pub max_retries: u32,
+}
+
+fn default_watchdog_check_interval_seconds() -> u64 {
+    60
+}
+
+fn default_watchdog_max_state_seconds() -> u64 {
+    3600
+}
+
+fn default_watchdog_max_retries() -> u32 {
+    1
+}
+
+impl Default for WorkflowWatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            check_interval_seconds: default_watchdog_check_interval_seconds(),
+            default_max_state_seconds: default_watchdog_max_state_seconds(),
+            on_stuck: Default::default(),
+            max_retries: default_watchdog_max_retries(),
+        }
+    }
+}
+
+/// Tenant quota enforcement (`spec.quotas`).
+///
+/// Limits come from each tenant's tier, overridden per tenant through
+/// `PUT /v1/quotas/{tenant_id}`. When enabled, agent deploys, execution
+/// starts and volume creation are rejected once a limit is reached.
+///
+/// ```yaml
+/// quotas:
+///   enabled: true
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuotasConfig {
+    /// Enforce quotas. Default: `true`.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+impl Default for QuotasConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+/// Per-execution network flow logging (`spec.network_flow_capture`).
+///
+/// Executions request capture with `"network_flow_capture": true` in their
+/// payload; the tenant must have the `network_flow_capture` feature flag
+/// enabled. Only the Docker/Podman runtime supports capture.
+///
+/// ```yaml
+/// network_flow_capture:
+///   image: "nicolaka/netshoot:v0.13"
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkFlowCaptureConfig {
+    /// Image providing `tshark`. Default: `nicolaka/netshoot:v0.13`.
+    #[serde(default = "default_network_flow_capture_image")]
+    pub image: String,
+    /// Pull policy for `image`. Default: `IfNotPresent`.
+    #[serde(default)]
+    pub image_pull_policy: ImagePullPolicy,
+}
+
+fn default_network_flow_capture_image() -> String {
+    "nicolaka/netshoot:v0.13".to_string()
+}
+
+impl Default for NetworkFlowCaptureConfig {
+    fn default() -> Self {
+        Self {
+            image: default_network_flow_capture_image(),
+            image_pull_policy: ImagePullPolicy::default(),
+        }
+    }
+}
+
+/// Shared execution queue (`spec.execution_queue`).
+///
+/// `start_execution` enqueues the execution and returns its ID; a worker on
+/// any daemon sharing the queue claims and starts it. Lets several daemons
+/// split the execution load. An execution is not visible through the API
+/// until a worker has started it.
+///
+/// ```yaml
+/// execution_queue:
+///   backend: redis                 # postgres (default) | redis
+///   redis_url: "env:REDIS_URL"     # required for redis
+///   max_concurrent_executions: 16  # per daemon
+///   consume: true                  # false: enqueue only, never start
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionQueueConfig {
+    #[serde(default)]
+    pub backend: ExecutionQueueBackend,
+
+    /// Redis connection URL; `env:` references are resolved. Required for
+    /// the `redis` backend.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub redis_url: Option<String>,
+
+    /// Stream key used by the `redis` backend.
+    #[serde(default = "default_execution_queue_redis_stream")]
+    pub redis_stream: String,
+
+    /// Whether this daemon runs a worker. A daemon that only accepts API
+    /// traffic can set `false` and leave execution to the others.
+    #[serde(default = "default_true")]
+    pub consume: bool,
+
+    /// Executions this daemon's worker keeps running at once.
+    #[serde(default = "default_execution_queue_max_concurrent")]
+    pub max_concurrent_executions: usize,
+
+    /// How often an idle worker polls the queue.
+    #[serde(default = "default_execution_queue_poll_interval_ms")]
+    pub poll_interval_ms: u64,
+
+    /// How long a claimed task stays hidden from other workers before it is
+    /// handed out again.
+    #[serde(default = "default_execution_queue_visibility_timeout_seconds")]
+    pub visibility_timeout_seconds: u64,
+
+    /// Deliveries of a task that fails to start before it is dropped.
+    #[serde(default = "default_execution_queue_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Delay before a task that failed to start is retried.
+    #[serde(default = "default_execution_queue_retry_delay_seconds")]
+    pub retry_delay_seconds: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExecutionQueueBackend {
+    /// `execution_task_queue` table in `spec.database`.
+    #[default]
+    Postgres,
+    /// Redis Streams; requires the `redis-task-queue` build feature.
+    Redis,
+}
+
+impl ExecutionQueueConfig {
+    pub fn poll_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_millis(self.poll_interval_ms)
+    }
+
+    pub fn visibility_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.visibility_timeout_seconds)
+    }
+
+    pub fn retry_delay(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.retry_delay_seconds)
+    }
+}
+
+impl Default for ExecutionQueueConfig {
+    fn default() -> Self {
+        Self {
+            backend: ExecutionQueueBackend::default(),
+            redis_url: None,
+            redis_stream: default_execution_queue_redis_stream(),
+            consume: true,
+            max_concurrent_executions: default_execution_queue_max_concurrent(),
+            poll_interval_ms: default_execution_queue_poll_interval_ms(),
+            visibility_timeout_seconds: default_execution_queue_visibility_timeout_seconds(),
+            max_attempts: default_execution_queue_max_attempts(),
+            retry_delay_seconds: default_execution_queue_retry_delay_seconds(),
+        }
+    }
+}
+
+fn default_execution_queue_redis_stream() -> String {
+    "aegis:execution-tasks".to_string()
+}
+
+fn default_execution_queue_max_concurrent() -> usize {
+    16
+}
+
+fn default_execution_queue_poll_interval_ms() -> u64 {
+    500
+}
+
+fn default_execution_queue_visibility_timeout_seconds() -> u64 {
+    60
+}
+
+fn default_execution_queue_max_attempts() -> u32 {
+    10
+}
+
+fn default_execution_queue_retry_delay_seconds() -> u64 {
+    5
+}
+
+/// Output moderation (`spec.moderation`).
+///
+/// Every provider checks each final output before its output handler runs.
+/// A provider that finds something contributes its `action`; the strongest
+/// one wins. `flag`ged outputs wait in `/v1/human-approvals` and are
+/// delivered only if approved; `block`ed outputs are never delivered. A
+/// withheld output fails the handler, so a `required` handler fails the
+/// execution.
+///
+/// ```yaml
+/// moderation:
+///   providers:
+///     - name: pii
+///       type: pii
+///       action: flag
+///     - name: compliance
+///       type: pattern
+///       category: compliance
+///       patterns: ["(?i)internal use only"]
+///       action: block
+///     - name: toxicity
+///       type: http
+///       url: "https://moderation.internal/v1/check"
+///       api_key: "env:MODERATION_API_KEY"
+///       action: flag
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationConfig {
+    #[serde(default)]
+    pub providers: Vec<ModerationProviderConfig>,
+
+    /// Action taken when a provider errors instead of answering.
+    #[serde(default)]
+    pub on_provider_error: crate::domain::moderation::ModerationAction,
+
+    /// How long a flagged output waits for a human before it is withheld.
+    #[serde(default = "default_moderation_approval_timeout_seconds")]
+    pub approval_timeout_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationProviderConfig {
+    /// Recorded on each finding.
+    pub name: String,
+
+    /// Action for outputs this provider reports findings on.
+    #[serde(default)]
+    pub action: crate::domain::moderation::ModerationAction,
+
+    #[serde(flatten)]
+    pub kind: ModerationProviderKind,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ModerationProviderKind {
+    /// Built-in PII detectors. All of them run when `detectors` is empty.
+    Pii {
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        detectors: Vec<PiiDetector>,
+    },
+    /// Regular expressions; any match is a finding in `category`.
+    Pattern {
+        patterns: Vec<String>,
+        #[serde(default = "default_moderation_pattern_category")]
+        category: String,
+    },
+    /// External classifier. Receives `POST {"text": ...}` and answers
+    /// `{"findings": [{"category": ..., "score": ..., "detail": ...}]}`;
+    /// findings scoring below `threshold` are ignored.
+    Http {
+        url: String,
+        /// Sent as a bearer token; supports `env:VAR_NAME`.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        api_key: Option<String>,
+        #[serde(default = "default_moderation_http_timeout_seconds")]
+        timeout_seconds: u64,
+        #[serde(default = "default_moderation_http_threshold")]
+        threshold: f64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiDetector {
+    Email,
+    Phone,
+    /// Digit runs that pass the Luhn check.
+    CreditCard,
+    /// US Social Security numbers (`123-45-6789`).
+    Ssn,
+}
+
+fn default_moderation_approval_timeout_seconds() -> u64 {
+    3600
+}
+
+fn default_moderation_pattern_category() -> String {
+    "compliance".to_string()
+}
+
+fn default_moderation_http_timeout_seconds() -> u64 {
+    10
 }
 
-/// YAML-serializable rate limit for a capability definition.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RateLimitDefinition {
-    pub calls: u32,
-    pub per_seconds: u32,
+fn default_moderation_http_threshold() -> f64 {
+    0.5
 }
 
-/// OIDC IAM configuration (ADR-041 §Node Configuration).
+/// WASM plugins (`spec.plugins`), run in-process by builds with the
+/// `wasm-plugins` feature.
 ///
-/// Defines the trusted identity realms, JWKS cache TTL, and custom claim names.
-/// When this section is present in `aegis-config.yaml`, all HTTP and gRPC
-/// auth middleware is enabled. When absent, auth is disabled (local dev mode).
+/// Components are stored in `directory` under their SHA-256 digest and
+/// referenced from manifests as `sha256:<hex>`. Every call gets a fresh
+/// instance limited to `fuel` units of work and `max_memory_mb` of linear
+/// memory.
+///
+/// ```yaml
+/// plugins:
+///   directory: /var/lib/aegis/plugins
+///   fuel: 100000000
+///   max_memory_mb: 64
+/// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IamConfig {
-    /// All known realms — determines which JWKS endpoints to trust and cache.
-    /// The platform validates JWTs against the realm matching the JWT's "iss" claim.
-    pub realms: Vec<IamRealmConfig>,
+pub struct PluginsConfig {
+    #[serde(default = "default_plugins_directory")]
+    pub directory: String,
 
-    /// JWKS cache TTL in seconds — keys refreshed this often to support key rotation.
-    /// Default: 300 (5 minutes).
-    #[serde(default = "default_jwks_cache_ttl")]
-    pub jwks_cache_ttl_seconds: u64,
+    #[serde(default = "default_plugin_fuel")]
+    pub fuel: u64,
 
-    /// Custom claim names for OIDC attribute mappers.
-    #[serde(default)]
-    pub claims: IamClaimsConfig,
+    #[serde(default = "default_plugin_max_memory_mb")]
+    pub max_memory_mb: u64,
+}
 
-    /// Keycloak Admin REST API credentials for tenant provisioning (ADR-097).
-    /// If omitted, automatic tenant provisioning on user signup is disabled.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub keycloak_admin: Option<KeycloakAdminConfig>,
+fn default_plugins_directory() -> String {
+    "/var/lib/aegis/plugins".to_string()
 }
 
-/// Individual realm configuration entry within `spec.iam.realms`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IamRealmConfig {
-    /// Realm identifier: "aegis-system", "zaru-consumer", or "tenant-{slug}"
-    pub slug: String,
-    /// Full issuer URL: `https://auth.example.com/realms/{slug}`
-    pub issuer_url: String,
-    /// JWKS endpoint: {issuer_url}/protocol/openid-connect/certs
-    pub jwks_uri: String,
-    /// Expected "aud" claim value for tokens from this realm
-    pub audience: String,
-    /// Realm classification: "system", "consumer", or "tenant"
-    pub kind: String,
+fn default_plugin_fuel() -> u64 {
+    100_000_000
 }
 
-/// Custom claim names for OIDC attribute mappers.
+fn default_plugin_max_memory_mb() -> u64 {
+    64
+}
+
+/// Where ingested messages run. Message content comes from outside the
+/// tenant, so the security context is required rather than defaulted.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct IamClaimsConfig {
-    /// Custom claim mapper name for ZaruTier. Default: "zaru_tier"
-    #[serde(default = "default_zaru_tier_claim")]
-    pub zaru_tier: String,
-    /// Role attribute name in aegis-system realm. Default: "aegis_role"
-    #[serde(default = "default_aegis_role_claim")]
-    pub aegis_role: String,
-    /// Custom claim mapper name for per-user tenant ID (ADR-097). Default: "tenant_id"
-    #[serde(default = "default_tenant_id_claim")]
+pub struct IngestionTargetConfig {
     pub tenant_id: String,
+    pub agent_id: String,
+    pub security_context: String,
 }
 
-/// Keycloak Admin REST API credentials (ADR-097).
-///
-/// Used by [`crate::application::tenant_provisioning::TenantProvisioningService`]
-/// to stamp `tenant_id` user attributes on newly registered consumer users.
+/// A mailbox polled over IMAP (implicit TLS). Unseen messages are ingested
+/// and then flagged `\Seen`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct KeycloakAdminConfig {
-    /// Keycloak base URL (e.g. `https://auth.aegis.local`). Supports `env:VAR` syntax.
+pub struct ImapIngestionConfig {
+    /// Source name, recorded in the `ingestion.source` execution label.
+    pub name: String,
     pub host: String,
-    /// Admin username for the `master` realm. Supports `env:VAR` syntax.
-    pub admin_username: String,
-    /// Admin password for the `master` realm. Supports `env:VAR` syntax.
-    pub admin_password: String,
+    #[serde(default = "default_imap_port")]
+    pub port: u16,
+    pub username: String,
+    /// Supports `env:VAR_NAME`.
+    pub password: String,
+    #[serde(default = "default_imap_mailbox")]
+    pub mailbox: String,
+    #[serde(default = "default_imap_poll_interval_seconds")]
+    pub poll_interval_seconds: u64,
+    #[serde(flatten)]
+    pub target: IngestionTargetConfig,
+    /// Endpoint that sends reply emails. Receives `{to, subject,
+    /// in_reply_to, text, execution_id}` through the outbound webhook
+    /// dispatcher. No replies are sent when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reply_url: Option<String>,
 }
 
-impl Default for IamClaimsConfig {
-    fn default() -> Self {
-        Self {
-            zaru_tier: default_zaru_tier_claim(),
-            aegis_role: default_aegis_role_claim(),
-            tenant_id: default_tenant_id_claim(),
-        }
-    }
+fn default_imap_port() -> u16 {
+    993
 }
 
-fn default_jwks_cache_ttl() -> u64 {
-    300
+fn default_imap_mailbox() -> String {
+    "INBOX".to_string()
 }
 
-fn default_zaru_tier_claim() -> String {
-    "zaru_tier".to_string()
+fn default_imap_poll_interval_seconds() -> u64 {
+    60
 }
 
-fn default_aegis_role_claim() -> String {
-    "aegis_role".to_string()
+/// A Slack app whose Events API subscription points at this node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SlackIngestionConfig {
+    #[serde(default = "default_slack_source_name")]
+    pub name: String,
+    /// Verifies `X-Slack-Signature`. Supports `env:VAR_NAME`.
+    pub signing_secret: String,
+    /// Downloads files and posts replies. Supports `env:VAR_NAME`.
+    pub bot_token: String,
+    #[serde(flatten)]
+    pub target: IngestionTargetConfig,
+    /// Channel IDs to accept messages from. Empty accepts every channel the
+    /// app is in.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channels: Vec<String>,
 }
 
-fn default_tenant_id_claim() -> String {
-    "tenant_id".to_string()
+fn default_slack_source_name() -> String {
+    "slack".to_string()
 }
 
-/// gRPC authentication configuration (ADR-041 §gRPC Authentication Amendment).
-///
-/// When enabled, a `OIDCAuthInterceptor` is installed on the gRPC server
-/// that validates Bearer JWTs on every call except exempted methods.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct GrpcAuthConfig {
-    /// Whether gRPC JWT auth is enabled.
-    #[serde(default)]
-    pub enabled: bool,
-    /// Methods exempt from token auth (e.g. inner loop bootstrap channel).
-    #[serde(default)]
-    pub exempt_methods: Vec<String>,
+fn default_ha_lease_name() -> String {
+    "aegis-primary".to_string()
 }
 
-/// Standalone SEAL tooling gateway endpoint configuration (ADR-053).
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SealGatewayConfig {
-    /// gRPC endpoint URL of the gateway invocation service.
-    /// Example: "http://aegis-seal-gateway:50055"
-    pub url: String,
+fn default_ha_lease_ttl_seconds() -> u64 {
+    15
+}
+
+fn default_ha_renew_interval_seconds() -> u64 {
+    5
+}
+
+fn default_cosign_path() -> String {
+    "cosign".to_string()
+}
+
+fn default_image_verification_timeout_secs() -> u64 {
+    60
 }
 
 /// Configuration for the Zaru consumer product service.
@@ -1745,6 +3293,15 @@ fn default_db_max_connections() -> u32 {
 fn default_db_connect_timeout_seconds() -> u64 {
     5
 }
+fn default_replica_max_lag_seconds() -> u64 {
+    5
+}
+fn default_replica_lag_check_interval_seconds() -> u64 {
+    5
+}
+fn default_pg_dump_path() -> String {
+    "pg_dump".to_string()
+}
 fn default_temporal_address() -> String {
     "temporal:7233".to_string()
 }
@@ -1780,6 +3337,7 @@ impl Default for LLMSelection {
             max_retries: 3,
             retry_delay_ms: 1000,
             llm_overall_timeout_secs: 30,
+            routing: None,
         }
     }
 }
@@ -1796,6 +3354,7 @@ impl Default for NodeConfigSpec {
             },
             llm_providers: vec![],
             llm_selection: LLMSelection::default(),
+            tenant_llm: vec![],
             runtime: RuntimeConfig::default(),
             network: None,
             observability: None,
@@ -1819,6 +3378,18 @@ impl Default for NodeConfigSpec {
             max_execution_list_limit: None,
             billing: None,
             zaru: None,
+            image_verification: None,
+            tool_cache: None,
+            high_availability: None,
+            deploy_gates: None,
+            feature_flags: None,
+            ingestion: None,
+            workflow_watchdog: None,
+            quotas: None,
+            network_flow_capture: None,
+            execution_queue: None,
+            moderation: None,
+            plugins: None,
         }
     }
 }
@@ -2250,6 +3821,31 @@ impl NodeConfigManifest {
             }
         }
 
+        if self.spec.runtime.kubernetes.is_some() && self.spec.runtime.nomad.is_some() {
+            anyhow::bail!(
+                "spec.runtime.kubernetes and spec.runtime.nomad are mutually exclusive; \
+                 configure one cluster runtime"
+            );
+        }
+
+        if let Some(routing) = &self.spec.llm_selection.routing {
+            for rule in &routing.rules {
+                let alias_known = self
+                    .spec
+                    .llm_providers
+                    .iter()
+                    .flat_map(|p| &p.models)
+                    .any(|m| m.alias == rule.model);
+                if !alias_known {
+                    anyhow::bail!(
+                        "Routing rule '{}' targets unknown model alias '{}'",
+                        rule.name,
+                        rule.model
+                    );
+                }
+            }
+        }
+
         if let Some(cluster) = &self.spec.cluster {
             cluster.validate_roles()?;
         }
@@ -2306,6 +3902,169 @@ impl NodeConfigManifest {
             }
         }
 
+        if self.spec.runtime.gc.enabled && self.spec.runtime.gc.interval_seconds == 0 {
+            anyhow::bail!("spec.runtime.gc.interval_seconds must be greater than 0");
+        }
+
+        // Registry credentials: one entry per host prefix, references well-formed.
+        let mut registries = std::collections::HashSet::new();
+        for cred in &self.spec.registry_credentials {
+            cred.validate()
+                .map_err(|e| anyhow::anyhow!("spec.registry_credentials: {e}"))?;
+            if !registries.insert(cred.registry.as_str()) {
+                anyhow::bail!(
+                    "spec.registry_credentials: duplicate entry for registry '{}'",
+                    cred.registry
+                );
+            }
+        }
+
+        let mut llm_tenants = std::collections::HashSet::new();
+        for tenant in &self.spec.tenant_llm {
+            crate::domain::tenant::TenantId::new(tenant.tenant_id.as_str()).map_err(|e| {
+                anyhow::anyhow!(
+                    "spec.tenant_llm: invalid tenant_id '{}': {e}",
+                    tenant.tenant_id
+                )
+            })?;
+            if !llm_tenants.insert(tenant.tenant_id.as_str()) {
+                anyhow::bail!(
+                    "spec.tenant_llm: duplicate entry for tenant '{}'",
+                    tenant.tenant_id
+                );
+            }
+            if tenant
+                .rate_limit
+                .as_ref()
+                .is_some_and(|limit| limit.calls == 0 || limit.per_seconds == 0)
+            {
+                anyhow::bail!(
+                    "spec.tenant_llm tenant '{}': rate_limit calls and per_seconds must be positive",
+                    tenant.tenant_id
+                );
+            }
+        }
+
+        // Image verification trust roots must be usable before the first spawn.
+        if let Some(verification) = &self.spec.image_verification {
+            for root in &verification.trust_roots {
+                if root.name.is_empty() {
+                    anyhow::bail!("spec.image_verification.trust_roots[].name cannot be empty");
+                }
+                if root.public_key.is_empty() {
+                    anyhow::bail!(
+                        "spec.image_verification trust root '{}' has an empty public_key",
+                        root.name
+                    );
+                }
+            }
+        }
+
+        if let Some(cache) = &self.spec.tool_cache {
+            if cache.enabled && cache.max_entries == 0 {
+                anyhow::bail!("spec.tool_cache.max_entries must be positive");
+            }
+            for (tool_name, rule) in &cache.tools {
+                if rule.no_cache && rule.ttl_seconds.is_some() {
+                    anyhow::bail!(
+                        "spec.tool_cache.tools.{tool_name}: ttl_seconds cannot be combined with no_cache"
+                    );
+                }
+            }
+        }
+
+        if let Some(ha) = self.spec.high_availability.as_ref().filter(|ha| ha.enabled) {
+            if self.spec.database.is_none() {
+                anyhow::bail!("spec.high_availability requires spec.database");
+            }
+            if ha.lease_name.trim().is_empty() {
+                anyhow::bail!("spec.high_availability.lease_name cannot be empty");
+            }
+            if ha.renew_interval_seconds == 0 || ha.renew_interval_seconds >= ha.lease_ttl_seconds {
+                anyhow::bail!(
+                    "spec.high_availability.renew_interval_seconds must be positive and shorter than lease_ttl_seconds"
+                );
+            }
+        }
+
+        if let Some(gates) = &self.spec.deploy_gates {
+            for rule in &gates.rules {
+                if rule.name.trim().is_empty() {
+                    anyhow::bail!("spec.deploy_gates.rules[].name cannot be empty");
+                }
+                if let Some(tier) = rule
+                    .selector
+                    .tiers
+                    .iter()
+                    .find(|tier| crate::domain::iam::ZaruTier::from_claim(tier).is_none())
+                {
+                    anyhow::bail!(
+                        "spec.deploy_gates rule '{}' has unknown tier '{tier}'",
+                        rule.name
+                    );
+                }
+            }
+        }
+
+        if let Some(name) = self
+            .spec
+            .feature_flags
+            .iter()
+            .flat_map(|flags| flags.keys())
+            .find(|name| !is_valid_feature_flag_name(name))
+        {
+            anyhow::bail!(
+                "spec.feature_flags has invalid flag name '{name}' (use lowercase letters, digits, '_', '.', '-')"
+            );
+        }
+
+        if let Some(ingestion) = &self.spec.ingestion {
+            let targets = ingestion
+                .imap
+                .iter()
+                .map(|imap| (imap.name.as_str(), &imap.target))
+                .chain(
+                    ingestion
+                        .slack
+                        .iter()
+                        .map(|slack| (slack.name.as_str(), &slack.target)),
+                );
+            for (name, target) in targets {
+                if crate::domain::tenant::TenantId::new(target.tenant_id.clone()).is_err() {
+                    anyhow::bail!(
+                        "spec.ingestion source '{name}' has invalid tenant_id '{}'",
+                        target.tenant_id
+                    );
+                }
+                if uuid::Uuid::parse_str(&target.agent_id).is_err() {
+                    anyhow::bail!(
+                        "spec.ingestion source '{name}' agent_id must be a UUID, got '{}'",
+                        target.agent_id
+                    );
+                }
+            }
+            let mut names = std::collections::HashSet::new();
+            for imap in &ingestion.imap {
+                if !names.insert(imap.name.as_str()) {
+                    anyhow::bail!("spec.ingestion.imap has duplicate source '{}'", imap.name);
+                }
+                if imap.poll_interval_seconds == 0 {
+                    anyhow::bail!(
+                        "spec.ingestion.imap '{}' poll_interval_seconds must be positive",
+                        imap.name
+                    );
+                }
+            }
+        }
+
+        if let Some(watchdog) = &self.spec.workflow_watchdog {
+            if watchdog.check_interval_seconds == 0 || watchdog.default_max_state_seconds == 0 {
+                anyhow::bail!(
+                    "spec.workflow_watchdog check_interval_seconds and default_max_state_seconds must be positive"
+                );
+            }
+        }
+
         if self.is_production() {
             if self.spec.database.is_none() {
                 anyhow::bail!("Production nodes must configure spec.database");
@@ -2455,6 +4214,7 @@ mod tests {
                     }],
                 }],
                 llm_selection: LLMSelection::default(),
+                tenant_llm: vec![],
                 runtime: RuntimeConfig::default(),
                 network: None,
                 observability: None,
@@ -2478,6 +4238,18 @@ mod tests {
                 max_execution_list_limit: None,
                 billing: None,
                 zaru: None,
+                image_verification: None,
+                tool_cache: None,
+                high_availability: None,
+                deploy_gates: None,
+                feature_flags: None,
+                ingestion: None,
+                workflow_watchdog: None,
+                quotas: None,
+                network_flow_capture: None,
+                execution_queue: None,
+                moderation: None,
+                plugins: None,
             },
         };
 
@@ -2578,6 +4350,8 @@ mod tests {
             url: "postgresql://localhost/aegis".to_string(),
             max_connections: 5,
             connect_timeout_seconds: 10,
+            migrations: MigrationsConfig::default(),
+            read_replica: None,
         });
         manifest.spec.cluster = Some(ClusterConfig {
             enabled: true,
@@ -2665,6 +4439,30 @@ mod tests {
         assert_eq!(manifest.spec.node.id, original_id);
     }
 
+    #[test]
+    fn database_migrations_default_to_apply_without_backup() {
+        let db: DatabaseConfig = serde_yaml::from_str("url: env:AEGIS_DATABASE_URL\n").unwrap();
+        assert_eq!(db.migrations.mode, MigrateMode::Apply);
+        assert!(db.migrations.backup.is_none());
+
+        let db: DatabaseConfig = serde_yaml::from_str(
+            "url: env:AEGIS_DATABASE_URL\nmigrations:\n  mode: check\n  backup:\n    output_dir: /var/backups/aegis\n",
+        )
+        .unwrap();
+        assert_eq!(db.migrations.mode, MigrateMode::Check);
+        let backup = db.migrations.backup.unwrap();
+        assert_eq!(backup.pg_dump_path, "pg_dump");
+        assert_eq!(backup.output_dir, PathBuf::from("/var/backups/aegis"));
+    }
+
+    #[test]
+    fn migrate_mode_parses_cli_values() {
+        assert_eq!("skip".parse::<MigrateMode>(), Ok(MigrateMode::Skip));
+        assert_eq!("CHECK".parse::<MigrateMode>(), Ok(MigrateMode::Check));
+        assert!("later".parse::<MigrateMode>().is_err());
+        assert_eq!(MigrateMode::Apply.to_string(), "apply");
+    }
+
     // ── ADR-117: NodeRole::Edge / RelayCoordinator + EdgeConfig validator ──
 
     #[test]
@@ -2944,6 +4742,18 @@ path: "/metrics"
             .expect("loopback bind must validate without TLS");
     }
 
+    #[test]
+    fn validate_rejects_kubernetes_and_nomad_together() {
+        let mut manifest = manifest_with_network("127.0.0.1", None);
+        manifest.spec.runtime.kubernetes = Some(serde_json::from_str("{}").unwrap());
+        manifest.validate().expect("kubernetes alone is valid");
+        manifest.spec.runtime.nomad = Some(serde_json::from_str("{}").unwrap());
+        let err = manifest
+            .validate()
+            .expect_err("two cluster runtimes must fail");
+        assert!(format!("{err}").contains("mutually exclusive"));
+    }
+
     #[test]
     fn validate_accepts_external_bind_with_tls() {
         let tls = TlsConfig {
@@ -3104,4 +4914,138 @@ grpc_port: 50051
             .validate()
             .expect("clustered external bind WITH mTLS must validate");
     }
+
+    #[test]
+    fn image_trust_roots_scope_by_registry_and_tenant() {
+        let yaml = r#"
+enabled: true
+trust_roots:
+  - name: acme
+    public_key: env:ACME_COSIGN_PUB
+    registries: ["ghcr.io/acme"]
+    tenants: ["acme"]
+  - name: platform
+    public_key: /etc/aegis/cosign/platform.pub
+"#;
+        let config: ImageVerificationConfig = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.cosign_path, "cosign");
+        assert!(config.require_trust_root);
+
+        let names = |image: &str, tenant: &str| -> Vec<String> {
+            config
+                .trust_roots_for(image, tenant)
+                .into_iter()
+                .map(|r| r.name.clone())
+                .collect()
+        };
+        assert_eq!(names("ghcr.io/acme/agent:v1", "acme"), ["acme", "platform"]);
+        assert_eq!(names("ghcr.io/acme/agent:v1", "globex"), ["platform"]);
+        assert_eq!(names("docker.io/library/python:3.11", "acme"), ["platform"]);
+    }
+
+    #[test]
+    fn validate_rejects_trust_root_without_public_key() {
+        let mut manifest = NodeConfigManifest::default();
+        manifest.spec.image_verification = Some(ImageVerificationConfig {
+            enabled: true,
+            cosign_path: default_cosign_path(),
+            timeout_seconds: default_image_verification_timeout_secs(),
+            require_trust_root: true,
+            trust_roots: vec![ImageTrustRoot {
+                name: "acme".to_string(),
+                public_key: String::new(),
+                registries: vec![],
+                tenants: vec![],
+            }],
+        });
+        let err = manifest.validate().unwrap_err().to_string();
+        assert!(err.contains("empty public_key"), "unexpected error: {err}");
+    }
+
+    fn registry_cred(registry: &str, password: &str) -> RegistryCredentials {
+        RegistryCredentials {
+            registry: registry.to_string(),
+            username: "robot".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    #[test]
+    fn registry_credentials_validate_password_references() {
+        assert!(registry_cred("ghcr.io", "env:GHCR_TOKEN")
+            .validate()
+            .is_ok());
+        assert!(registry_cred("ghcr.io", "secret:kv/registries/ghcr")
+            .validate()
+            .is_ok());
+        assert!(registry_cred("ghcr.io", "secret:kv/registries/ghcr#token")
+            .validate()
+            .is_ok());
+        assert!(registry_cred("ghcr.io", "secret:ghcr").validate().is_err());
+        assert!(registry_cred("https://ghcr.io", "env:T")
+            .validate()
+            .is_err());
+        assert!(registry_cred("ghcr.io", "").validate().is_err());
+        assert!(registry_cred("ghcr.io", "hunter2").is_plaintext());
+        assert!(!registry_cred("ghcr.io", "env:T").is_plaintext());
+    }
+
+    #[test]
+    fn validate_rejects_duplicate_registry_credentials() {
+        let mut manifest = NodeConfigManifest::default();
+        manifest.spec.registry_credentials = vec![
+            registry_cred("ghcr.io", "env:A"),
+            registry_cred("ghcr.io", "env:B"),
+        ];
+        let err = manifest.validate().unwrap_err().to_string();
+        assert!(err.contains("duplicate entry"), "unexpected error: {err}");
+    }
+
+    #[test]
+    fn validate_rejects_duplicate_tenant_llm_entries() {
+        let tenant = |id: &str| TenantLLMConfig {
+            tenant_id: id.to_string(),
+            allowed_aliases: vec![],
+            llm_providers: vec![],
+            rate_limit: None,
+        };
+        let mut manifest = NodeConfigManifest::default();
+        manifest.spec.tenant_llm = vec![tenant("acme"), tenant("globex")];
+        assert!(manifest.validate().is_ok());
+
+        manifest.spec.tenant_llm.push(tenant("acme"));
+        let err = manifest.validate().unwrap_err().to_string();
+        assert!(err.contains("duplicate entry"), "unexpected error: {err}");
+
+        manifest.spec.tenant_llm = vec![tenant("Not A Slug")];
+        assert!(manifest.validate().is_err());
+    }
+
+    #[test]
+    fn storage_yaml_without_default_workspace_enables_it() {
+        let cfg: StorageConfig = serde_yaml::from_str("backend: seaweedfs\n").expect("yaml parses");
+        assert!(cfg.default_workspace.enabled);
+        assert_eq!(cfg.default_workspace.size_limit, "1Gi");
+
+        let cfg: StorageConfig =
+            serde_yaml::from_str("backend: seaweedfs\ndefault_workspace:\n  enabled: false\n")
+                .expect("yaml parses");
+        assert!(!cfg.default_workspace.enabled);
+        assert_eq!(cfg.default_workspace.ttl_hours, default_ttl_hours());
+    }
+
+    #[test]
+    fn seaweedfs_yaml_without_write_behind_enables_it() {
+        let cfg: SeaweedFSConfig =
+            serde_yaml::from_str("filer_url: http://filer:8888\n").expect("yaml parses");
+        assert!(cfg.write_behind.enabled);
+        assert_eq!(cfg.write_behind.flush_bytes, 1024 * 1024);
+
+        let cfg: SeaweedFSConfig = serde_yaml::from_str(
+            "filer_url: http://filer:8888\nwrite_behind:\n  flush_interval_ms: 50\n",
+        )
+        .expect("yaml parses");
+        assert!(cfg.write_behind.enabled);
+        assert_eq!(cfg.write_behind.flush_interval_ms, 50);
+    }
 }
@@ -0,0 +1,311 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Outbound Webhooks
+//!
+//! Shared model for every call the orchestrator makes to a user-provided URL
+//! (output handler delivery, alerts, triggers). The dispatcher in
+//! [`crate::infrastructure::outbound_webhook`] signs each request, retries
+//! transient failures with exponential backoff, records every attempt and
+//! dead-letters deliveries that exhaust their retries.
+//!
+//! ## Signing
+//!
+//! Each request carries:
+//!
+//! | Header | Value |
+//! |---|---|
+//! | `X-Aegis-Webhook-Id` | Delivery UUID, stable across retries (receivers dedupe on it) |
+//! | `X-Aegis-Timestamp` | Unix seconds at which this attempt was signed |
+//! | `X-Aegis-Signature` | `sha256=<hex>` per active secret, comma-separated |
+//!
+//! The signed content is `"{timestamp}.{body}"` and the MAC is HMAC-SHA256.
+//! While a secret is being rotated both the current and previous secrets are
+//! active, so receivers accept the request if any listed signature verifies
+//! with a secret they know.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Delivery attempt log, retry policy, payload signing and the
+//!   repository/secret-provider interfaces
+
+use crate::domain::repository::RepositoryError;
+use crate::domain::shared_kernel::TenantId;
+use crate::domain::workflow::RetryConfig;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::time::Duration;
+use uuid::Uuid;
+
+/// Delivery UUID header, identical on every attempt of one delivery.
+pub const WEBHOOK_ID_HEADER: &str = "X-Aegis-Webhook-Id";
+/// Unix-seconds timestamp covered by the signature.
+pub const WEBHOOK_TIMESTAMP_HEADER: &str = "X-Aegis-Timestamp";
+/// `sha256=<hex>` signatures, one per active secret.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Aegis-Signature";
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// `X-Aegis-Signature` value for `body` signed at `timestamp` with each of
+/// `secrets`, in order. `None` when no secret is configured.
+pub fn sign_payload(secrets: &[Vec<u8>], timestamp: i64, body: &[u8]) -> Option<String> {
+    if secrets.is_empty() {
+        return None;
+    }
+    let signatures: Vec<String> = secrets
+        .iter()
+        .map(|secret| {
+            let mut mac =
+                HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length");
+            mac.update(timestamp.to_string().as_bytes());
+            mac.update(b".");
+            mac.update(body);
+            format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+        })
+        .collect();
+    Some(signatures.join(", "))
+}
+
+/// Exponential backoff between delivery attempts.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts, including the first. Always at least 1.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub multiplier: f64,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(300),
+            multiplier: 2.0,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A single attempt with no retries.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Policy for a manifest `retry:` block. `max_attempts` there counts
+    /// retries after the first attempt; an unparseable `backoff` keeps the
+    /// default initial backoff.
+    pub fn from_config(config: &RetryConfig) -> Self {
+        let default = Self::default();
+        let initial_backoff = config
+            .backoff
+            .as_deref()
+            .and_then(|backoff| humantime_serde::re::humantime::parse_duration(backoff).ok())
+            .unwrap_or(default.initial_backoff);
+        Self {
+            max_attempts: config.max_attempts.saturating_add(1),
+            initial_backoff,
+            max_backoff: default.max_backoff.max(initial_backoff),
+            multiplier: default.multiplier,
+        }
+    }
+
+    /// Delay before attempt `attempt + 1`, given that `attempt` (1-based)
+    /// just failed.
+    pub fn backoff_for(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(32) as i32;
+        let delay = self.initial_backoff.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::from_secs_f64(delay.min(self.max_backoff.as_secs_f64()))
+    }
+}
+
+/// Whether an HTTP status is worth retrying: timeouts, rate limiting and
+/// server errors. Other 4xx responses are final.
+pub fn is_retryable_status(status: u16) -> bool {
+    matches!(status, 408 | 425 | 429) || (500..600).contains(&status)
+}
+
+/// Result of one delivery attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeliveryOutcome {
+    /// 2xx response; the delivery is complete.
+    Delivered,
+    /// Transient failure; another attempt follows.
+    Retrying,
+    /// Final failure; the delivery was dead-lettered.
+    DeadLettered,
+}
+
+impl DeliveryOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DeliveryOutcome::Delivered => "delivered",
+            DeliveryOutcome::Retrying => "retrying",
+            DeliveryOutcome::DeadLettered => "dead_lettered",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "delivered" => Some(DeliveryOutcome::Delivered),
+            "retrying" => Some(DeliveryOutcome::Retrying),
+            "dead_lettered" => Some(DeliveryOutcome::DeadLettered),
+            _ => None,
+        }
+    }
+}
+
+/// One HTTP attempt of an outbound delivery.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeliveryAttempt {
+    pub delivery_id: Uuid,
+    pub tenant_id: TenantId,
+    /// Destination URL.
+    pub destination: String,
+    /// Subsystem that sent the delivery, e.g. `output_handler`.
+    pub source: String,
+    /// 1-based attempt number.
+    pub attempt: u32,
+    pub status_code: Option<u16>,
+    pub error: Option<String>,
+    pub outcome: DeliveryOutcome,
+    pub duration_ms: u64,
+    pub attempted_at: DateTime<Utc>,
+}
+
+/// A delivery that exhausted its retries or was rejected outright. Request
+/// headers are not kept since they may carry credentials.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DeadLetter {
+    pub delivery_id: Uuid,
+    pub tenant_id: TenantId,
+    pub destination: String,
+    pub source: String,
+    pub method: String,
+    pub content_type: String,
+    pub body: String,
+    pub attempts: u32,
+    pub last_error: String,
+    pub dead_lettered_at: DateTime<Utc>,
+}
+
+/// Persistence for the delivery-attempt log and dead letters.
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    async fn record_attempt(&self, attempt: &DeliveryAttempt) -> Result<(), RepositoryError>;
+
+    async fn dead_letter(&self, dead_letter: &DeadLetter) -> Result<(), RepositoryError>;
+
+    /// Most recent attempts of `tenant_id`, newest first, optionally limited
+    /// to one destination URL.
+    async fn list_attempts(
+        &self,
+        tenant_id: &TenantId,
+        destination: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<DeliveryAttempt>, RepositoryError>;
+
+    /// Most recent dead letters of `tenant_id`, newest first, optionally
+    /// limited to one destination URL.
+    async fn list_dead_letters(
+        &self,
+        tenant_id: &TenantId,
+        destination: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<DeadLetter>, RepositoryError>;
+}
+
+/// Signing secrets for outbound deliveries.
+#[async_trait]
+pub trait OutboundWebhookSecretProvider: Send + Sync {
+    /// Active secrets for deliveries of `tenant_id` to `destination_host`,
+    /// current secret first. Empty when deliveries go unsigned.
+    async fn signing_secrets(&self, tenant_id: &TenantId, destination_host: &str) -> Vec<Vec<u8>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_covers_timestamp_and_body() {
+        let secrets = vec![b"current".to_vec()];
+        let signature = sign_payload(&secrets, 1_700_000_000, b"{\"ok\":true}").unwrap();
+
+        let mut mac = HmacSha256::new_from_slice(b"current").unwrap();
+        mac.update(b"1700000000.{\"ok\":true}");
+        let expected = format!("sha256={}", hex::encode(mac.finalize().into_bytes()));
+        assert_eq!(signature, expected);
+
+        assert_ne!(
+            sign_payload(&secrets, 1_700_000_001, b"{\"ok\":true}").unwrap(),
+            signature
+        );
+    }
+
+    #[test]
+    fn rotation_signs_with_every_active_secret() {
+        let secrets = vec![b"current".to_vec(), b"previous".to_vec()];
+        let signature = sign_payload(&secrets, 1, b"body").unwrap();
+        let parts: Vec<&str> = signature.split(", ").collect();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(
+            parts[0],
+            sign_payload(&secrets[..1], 1, b"body").unwrap().as_str()
+        );
+        assert!(sign_payload(&[], 1, b"body").is_none());
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_the_cap() {
+        let policy = RetryPolicy {
+            max_attempts: 10,
+            initial_backoff: Duration::from_secs(2),
+            max_backoff: Duration::from_secs(10),
+            multiplier: 2.0,
+        };
+        assert_eq!(policy.backoff_for(1), Duration::from_secs(2));
+        assert_eq!(policy.backoff_for(2), Duration::from_secs(4));
+        assert_eq!(policy.backoff_for(3), Duration::from_secs(8));
+        assert_eq!(policy.backoff_for(4), Duration::from_secs(10));
+        assert_eq!(policy.backoff_for(u32::MAX), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn manifest_retry_counts_retries_after_the_first_attempt() {
+        let policy = RetryPolicy::from_config(&RetryConfig {
+            max_attempts: 3,
+            backoff: Some("5s".to_string()),
+        });
+        assert_eq!(policy.max_attempts, 4);
+        assert_eq!(policy.initial_backoff, Duration::from_secs(5));
+
+        let policy = RetryPolicy::from_config(&RetryConfig {
+            max_attempts: 0,
+            backoff: Some("soon".to_string()),
+        });
+        assert_eq!(policy.max_attempts, 1);
+        assert_eq!(
+            policy.initial_backoff,
+            RetryPolicy::default().initial_backoff
+        );
+    }
+
+    #[test]
+    fn only_transient_statuses_are_retried() {
+        for status in [408, 425, 429, 500, 502, 503, 504] {
+            assert!(is_retryable_status(status), "{status}");
+        }
+        for status in [200, 301, 400, 401, 403, 404, 410, 422] {
+            assert!(!is_retryable_status(status), "{status}");
+        }
+    }
+}
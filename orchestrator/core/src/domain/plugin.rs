@@ -0,0 +1,173 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # WASM Plugins
+//!
+//! Custom validators and transition conditions that operators ship as
+//! WebAssembly components instead of asking for them to be built in. A
+//! plugin implements the `aegis:plugin/plugin` world (`wit/plugin.wit`):
+//!
+//! | Export | Used by | Input |
+//! |--------|---------|-------|
+//! | `validate` | `type: plugin` entries in an agent's `spec.execution.validation` | [`PluginValidationInput`] as JSON |
+//! | `evaluate` | `condition: plugin` workflow transitions | The transition context as JSON |
+//!
+//! Plugins are content-addressed: manifests reference them by the
+//! [`PluginDigest`] of the uploaded component, so a manifest always runs the
+//! exact bytes it was written against. They run inside the orchestrator with
+//! no imports (no filesystem, network or clock) and within the fuel and
+//! memory limits of `spec.plugins`.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Plugin references, verdicts and the host interface
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// `sha256:<64 lowercase hex>` digest of a plugin component.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct PluginDigest(String);
+
+impl PluginDigest {
+    const PREFIX: &'static str = "sha256:";
+
+    /// Digest of a component's bytes.
+    pub fn of(component: &[u8]) -> Self {
+        Self(format!(
+            "{}{}",
+            Self::PREFIX,
+            hex::encode(Sha256::digest(component))
+        ))
+    }
+
+    pub fn parse(value: &str) -> Result<Self, PluginError> {
+        let hex = value
+            .strip_prefix(Self::PREFIX)
+            .ok_or_else(|| PluginError::InvalidDigest(value.to_string()))?;
+        if hex.len() != 64 || !hex.chars().all(|c| matches!(c, '0'..='9' | 'a'..='f')) {
+            return Err(PluginError::InvalidDigest(value.to_string()));
+        }
+        Ok(Self(value.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The hex part, used as the file name in the plugin directory.
+    pub fn hex(&self) -> &str {
+        &self.0[Self::PREFIX.len()..]
+    }
+}
+
+impl std::fmt::Display for PluginDigest {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl TryFrom<String> for PluginDigest {
+    type Error = PluginError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::parse(&value)
+    }
+}
+
+impl From<PluginDigest> for String {
+    fn from(digest: PluginDigest) -> Self {
+        digest.0
+    }
+}
+
+/// What a `validate` plugin sees of an iteration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginValidationInput {
+    pub task: String,
+    pub output: String,
+    pub exit_code: i64,
+    pub stderr: String,
+}
+
+/// A `validate` plugin's judgement, on the gradient validation scale.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PluginVerdict {
+    pub score: f64,
+    pub confidence: f64,
+    pub reasoning: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum PluginError {
+    #[error("Invalid plugin digest '{0}': expected sha256:<64 hex characters>")]
+    InvalidDigest(String),
+
+    #[error("Plugin {0} is not installed")]
+    NotFound(PluginDigest),
+
+    #[error("Plugin is not a valid component for the aegis:plugin world: {0}")]
+    InvalidComponent(String),
+
+    #[error("Plugin {plugin} exhausted its fuel limit")]
+    FuelExhausted { plugin: PluginDigest },
+
+    #[error("Plugin {plugin} failed: {reason}")]
+    Failed {
+        plugin: PluginDigest,
+        reason: String,
+    },
+
+    #[error("Plugin storage error: {0}")]
+    Storage(String),
+}
+
+/// Installs and runs plugins.
+#[async_trait]
+pub trait PluginHost: Send + Sync {
+    /// Check that `component` implements the plugin world and store it under
+    /// its digest. Installing the same bytes again is a no-op.
+    async fn install(&self, component: &[u8]) -> Result<PluginDigest, PluginError>;
+
+    /// Score an iteration with the plugin's `validate` export.
+    async fn validate(
+        &self,
+        plugin: &PluginDigest,
+        input: &PluginValidationInput,
+    ) -> Result<PluginVerdict, PluginError>;
+
+    /// Decide a transition with the plugin's `evaluate` export.
+    async fn evaluate(
+        &self,
+        plugin: &PluginDigest,
+        context: &serde_json::Value,
+    ) -> Result<bool, PluginError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digest_round_trips_and_rejects_malformed_values() {
+        let digest = PluginDigest::of(b"\0asm");
+        assert!(digest.as_str().starts_with("sha256:"));
+        assert_eq!(digest.hex().len(), 64);
+        assert_eq!(PluginDigest::parse(digest.as_str()).unwrap(), digest);
+
+        for bad in [
+            "",
+            "sha256:",
+            "md5:00",
+            &digest.as_str().to_uppercase(),
+            &format!("{digest}0"),
+        ] {
+            assert!(
+                PluginDigest::parse(bad).is_err(),
+                "{bad} should be rejected"
+            );
+        }
+    }
+}
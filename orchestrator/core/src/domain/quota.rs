@@ -0,0 +1,167 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Tenant Quota Definitions (ADR-056 §Quota Enforcement)
+//!
+//! A tenant's limits start from its tier ([`TenantQuotas::for_tier`]). A
+//! [`QuotaDefinition`] stored by a platform admin through `/v1/quotas`
+//! overrides any of them for that tenant; [`EffectiveQuotas`] is the merged
+//! result that `TenantQuotaService` enforces and [`QuotaUsage`] holds the
+//! matching counters.
+//!
+//! The monthly token budget has no tier default and only applies once set.
+//! Months are calendar months in UTC.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Quota overrides, effective limits and the repository interface
+
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::repository::RepositoryError;
+use crate::domain::shared_kernel::TenantId;
+use crate::domain::tenancy::{TenantQuotaKind, TenantQuotas, TenantTier};
+
+const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+
+/// Per-tenant overrides. `None` keeps the tier default.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaLimits {
+    #[serde(default)]
+    pub max_agents: Option<u64>,
+    #[serde(default)]
+    pub max_concurrent_executions: Option<u64>,
+    /// Sum of `size_limit_bytes` over the tenant's volumes.
+    #[serde(default)]
+    pub max_volume_bytes: Option<u64>,
+    /// Input plus output tokens per calendar month (UTC).
+    #[serde(default)]
+    pub monthly_token_budget: Option<u64>,
+}
+
+/// Quota overrides stored for one tenant.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuotaDefinition {
+    pub tenant_id: TenantId,
+    #[serde(flatten)]
+    pub limits: QuotaLimits,
+    pub updated_by: String,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Limits in force for a tenant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct EffectiveQuotas {
+    pub max_agents: u64,
+    pub max_concurrent_executions: u64,
+    pub max_volume_bytes: u64,
+    /// `None` when no budget is set.
+    pub monthly_token_budget: Option<u64>,
+}
+
+impl EffectiveQuotas {
+    /// Tier defaults for `tier` with `overrides` applied.
+    pub fn resolve(tier: &TenantTier, overrides: &QuotaLimits) -> Self {
+        let defaults = TenantQuotas::for_tier(tier);
+        Self {
+            max_agents: overrides
+                .max_agents
+                .unwrap_or(u64::from(defaults.max_agents)),
+            max_concurrent_executions: overrides
+                .max_concurrent_executions
+                .unwrap_or(u64::from(defaults.max_concurrent_executions)),
+            // Saturates to u64::MAX for the System tier's f64::MAX.
+            max_volume_bytes: overrides
+                .max_volume_bytes
+                .unwrap_or((defaults.max_storage_gb * GIB) as u64),
+            monthly_token_budget: overrides.monthly_token_budget,
+        }
+    }
+
+    /// Every enforced limit by kind; an unset token budget reads as `u64::MAX`.
+    pub fn limits(&self) -> [(TenantQuotaKind, u64); 4] {
+        [
+            (TenantQuotaKind::TotalAgents, self.max_agents),
+            (
+                TenantQuotaKind::ConcurrentExecutions,
+                self.max_concurrent_executions,
+            ),
+            (TenantQuotaKind::VolumeBytes, self.max_volume_bytes),
+            (
+                TenantQuotaKind::MonthlyTokens,
+                self.monthly_token_budget.unwrap_or(u64::MAX),
+            ),
+        ]
+    }
+}
+
+/// Current consumption against [`EffectiveQuotas`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct QuotaUsage {
+    pub agents: u64,
+    pub concurrent_executions: u64,
+    pub volume_bytes: u64,
+    /// `None` when token accounting is unavailable.
+    pub tokens_this_month: Option<u64>,
+}
+
+/// Start of the calendar month (UTC) containing `now`.
+pub fn month_start(now: DateTime<Utc>) -> DateTime<Utc> {
+    now.date_naive()
+        .with_day(1)
+        .expect("day 1 exists in every month")
+        .and_time(NaiveTime::MIN)
+        .and_utc()
+}
+
+#[async_trait]
+pub trait QuotaRepository: Send + Sync {
+    async fn get(&self, tenant_id: &TenantId) -> Result<Option<QuotaDefinition>, RepositoryError>;
+
+    /// All stored definitions, ordered by tenant.
+    async fn list(&self) -> Result<Vec<QuotaDefinition>, RepositoryError>;
+
+    /// Create or replace the definition for `definition.tenant_id`.
+    async fn upsert(&self, definition: &QuotaDefinition) -> Result<(), RepositoryError>;
+
+    /// Remove the definition; returns whether one existed.
+    async fn delete(&self, tenant_id: &TenantId) -> Result<bool, RepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_replace_tier_defaults_field_by_field() {
+        let overrides = QuotaLimits {
+            max_agents: Some(12),
+            monthly_token_budget: Some(1_000_000),
+            ..Default::default()
+        };
+        let quotas = EffectiveQuotas::resolve(&TenantTier::Free, &overrides);
+
+        assert_eq!(quotas.max_agents, 12);
+        assert_eq!(quotas.max_concurrent_executions, 2);
+        assert_eq!(quotas.max_volume_bytes, 1024 * 1024 * 1024);
+        assert_eq!(quotas.monthly_token_budget, Some(1_000_000));
+
+        let system = EffectiveQuotas::resolve(&TenantTier::System, &QuotaLimits::default());
+        assert_eq!(system.max_volume_bytes, u64::MAX);
+        assert_eq!(
+            system.limits()[3],
+            (TenantQuotaKind::MonthlyTokens, u64::MAX)
+        );
+    }
+
+    #[test]
+    fn month_start_is_first_midnight_of_the_month() {
+        let now: DateTime<Utc> = "2026-03-17T15:04:05Z".parse().unwrap();
+        assert_eq!(
+            month_start(now),
+            "2026-03-01T00:00:00Z".parse::<DateTime<Utc>>().unwrap()
+        );
+    }
+}
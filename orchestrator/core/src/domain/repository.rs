@@ -39,11 +39,13 @@
 
 use crate::domain::agent::{Agent, AgentId, AgentScope};
 use crate::domain::execution::{Execution, ExecutionId};
+use crate::domain::execution_query::ExecutionQuery;
 use crate::domain::tenancy::Tenant;
 use crate::domain::tenant::TenantId;
 use crate::domain::volume::{Volume, VolumeId, VolumeOwnership};
 use crate::domain::workflow::{Workflow, WorkflowId, WorkflowScope};
 use async_trait::async_trait;
+use std::collections::BTreeMap;
 
 /// Storage backend enum for pluggable persistence
 #[derive(Debug, Clone)]
@@ -148,10 +150,35 @@ pub trait AgentRepository: Send + Sync {
 
     /// Count agents with `status = 'active'` for a tenant (used by quota enforcement).
     async fn count_active(&self, tenant_id: &TenantId) -> Result<u64, RepositoryError>;
+
+    /// Append an audited manifest update to the agent's change history.
+    ///
+    /// The default implementation discards it.
+    async fn record_manifest_change(
+        &self,
+        _tenant_id: &TenantId,
+        _change: &crate::domain::manifest_change::AgentManifestChange,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    /// An agent's manifest change history within a tenant scope, newest
+    /// first. The default implementation never retains changes.
+    async fn list_manifest_changes_for_tenant(
+        &self,
+        _tenant_id: &TenantId,
+        _agent_id: AgentId,
+    ) -> Result<Vec<crate::domain::manifest_change::AgentManifestChange>, RepositoryError> {
+        Ok(vec![])
+    }
 }
 
 /// Repository interface for Execution aggregates
 /// One repository per aggregate root (Execution Context)
+///
+/// Every listing is ordered newest first by `started_at`, with ties broken by
+/// descending execution id so pagination is stable. Implementations are
+/// checked against `infrastructure::repositories::contract`.
 #[async_trait]
 pub trait ExecutionRepository: Send + Sync {
     async fn save_for_tenant(
@@ -186,6 +213,29 @@ pub trait ExecutionRepository: Send + Sync {
         limit: usize,
     ) -> Result<Vec<Execution>, RepositoryError>;
 
+    /// List a tenant's executions matching a filter expression, newest first.
+    ///
+    /// Agent names in `query` must already be resolved to ids; unresolved
+    /// name selectors match nothing.
+    async fn find_by_query_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        query: &ExecutionQuery,
+        limit: usize,
+    ) -> Result<Vec<Execution>, RepositoryError>;
+
+    /// Replace an execution's labels.
+    ///
+    /// `save_for_tenant` writes labels only when the execution is first
+    /// inserted, so label edits made while the execution is running survive
+    /// the supervisor persisting its own copy of the aggregate.
+    async fn update_labels_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<(), RepositoryError>;
+
     /// List recent executions across every tenant, newest first.
     ///
     /// Used by `list_executions_handler` and `dashboard_summary_handler`
@@ -223,6 +273,11 @@ pub trait ExecutionRepository: Send + Sync {
 
     /// Count executions with `status IN ('running', 'pending')` for a tenant (quota enforcement).
     async fn count_running(&self, tenant_id: &TenantId) -> Result<u64, RepositoryError>;
+
+    /// List pending and running executions across every tenant, oldest
+    /// first. Used when a standby daemon takes over to reconcile executions
+    /// the failed active was driving.
+    async fn find_unfinished_all(&self, limit: usize) -> Result<Vec<Execution>, RepositoryError>;
 }
 
 /// Repository interface for Workflow aggregates
@@ -351,6 +406,9 @@ pub trait WorkflowRepository: Send + Sync {
 
 /// Repository interface for WorkflowExecution aggregates
 /// One repository per aggregate root (Workflow Execution Context)
+///
+/// Listings follow the same ordering as [`ExecutionRepository`]: newest
+/// `started_at` first, ties broken by descending execution id.
 #[async_trait]
 pub trait WorkflowExecutionRepository: Send + Sync {
     async fn save_for_tenant(
@@ -365,11 +423,20 @@ pub trait WorkflowExecutionRepository: Send + Sync {
         id: ExecutionId,
     ) -> Result<Option<crate::domain::workflow::WorkflowExecution>, RepositoryError>;
 
+    /// A tenant's workflow executions with status `Running`. Pending and
+    /// terminal executions are excluded.
     async fn find_active_for_tenant(
         &self,
         tenant_id: &TenantId,
     ) -> Result<Vec<crate::domain::workflow::WorkflowExecution>, RepositoryError>;
 
+    /// Workflow executions with status `Running` across every tenant, used by
+    /// the stuck-state watchdog. Each returned execution carries its own
+    /// `tenant_id`.
+    async fn find_active_all(
+        &self,
+    ) -> Result<Vec<crate::domain::workflow::WorkflowExecution>, RepositoryError>;
+
     async fn find_by_workflow_for_tenant(
         &self,
         tenant_id: &TenantId,
@@ -434,6 +501,86 @@ pub trait WorkflowExecutionRepository: Send + Sync {
         offset: usize,
     ) -> Result<Vec<crate::domain::workflow::WorkflowExecutionEventRecord>, RepositoryError>;
 
+    /// Record entry into an FSM state in the compact transition history.
+    ///
+    /// Idempotent on `(execution_id, record.sequence)` so re-delivered Temporal
+    /// callbacks do not duplicate entries.
+    async fn append_transition(
+        &self,
+        execution_id: ExecutionId,
+        record: &crate::domain::workflow::WorkflowTransitionRecord,
+    ) -> Result<(), RepositoryError>;
+
+    /// Close the most recent open transition for `state_name` with its exit
+    /// time and output summary. A no-op if no open entry exists.
+    async fn close_transition(
+        &self,
+        execution_id: ExecutionId,
+        state_name: &str,
+        exited_at: chrono::DateTime<chrono::Utc>,
+        output_summary: Option<String>,
+    ) -> Result<(), RepositoryError>;
+
+    /// Retrieve the transition history for a workflow execution within a
+    /// tenant scope, in sequence order.
+    ///
+    /// Served from the repository alone so it remains available after
+    /// Temporal's history retention expires.
+    async fn find_transitions_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+    ) -> Result<Vec<crate::domain::workflow::WorkflowTransitionRecord>, RepositoryError>;
+
+    /// Attach the blackboard snapshot taken when the transition with
+    /// `sequence` was entered. A no-op if that transition was not recorded.
+    ///
+    /// The default implementation discards the snapshot.
+    async fn record_transition_blackboard(
+        &self,
+        _execution_id: ExecutionId,
+        _sequence: i64,
+        _blackboard: &serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    /// Blackboard snapshot stored for one transition within a tenant scope.
+    ///
+    /// Returns `None` if the transition does not exist or carries no
+    /// snapshot. The default implementation never retains snapshots.
+    async fn find_transition_blackboard_for_tenant(
+        &self,
+        _tenant_id: &TenantId,
+        _id: ExecutionId,
+        _sequence: i64,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        Ok(None)
+    }
+
+    /// Store the handoffs a state produced when it exited, replacing any it
+    /// produced on an earlier visit.
+    ///
+    /// The default implementation discards them.
+    async fn record_handoffs(
+        &self,
+        _execution_id: ExecutionId,
+        _handoffs: &[crate::domain::workflow::WorkflowHandoff],
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    /// Handoffs produced so far by an execution within a tenant scope,
+    /// ordered by state and output name. The default implementation never
+    /// retains handoffs.
+    async fn find_handoffs_for_tenant(
+        &self,
+        _tenant_id: &TenantId,
+        _id: ExecutionId,
+    ) -> Result<Vec<crate::domain::workflow::WorkflowHandoff>, RepositoryError> {
+        Ok(vec![])
+    }
+
     /// Resolve the owning tenant for a workflow execution by its ID.
     ///
     /// Returns `None` if the execution does not exist. Used by the Temporal event listener
@@ -24,7 +24,7 @@
 //!
 //! See Also: ADR-027 (Docker Runtime), ADR-036 (NFS Server Gateway)
 
-use crate::domain::shared_kernel::{ExecutionId, ImagePullPolicy};
+use crate::domain::shared_kernel::{ExecutionId, ImagePullPolicy, TenantId};
 // Conformist: BC-2 (Execution) conforms to BC-7 (Storage Gateway) volume model.
 // The infrastructure layer (ContainerRuntime) accesses VolumeMount fields including
 // AccessMode enum variants, so a full ACL wrapper is not cost-effective here.
@@ -101,6 +101,88 @@ pub struct RuntimeConfig {
     /// that image pull telemetry can be correlated back to the specific execution
     /// (ADR-045).
     pub execution_id: ExecutionId,
+    /// Tenant that owns the execution. Selects the per-tenant image trust roots
+    /// applied before container creation.
+    #[serde(default)]
+    pub tenant_id: TenantId,
+    /// Helper containers started next to the agent container, sharing its
+    /// network namespace and lifecycle (from `spec.runtime.sidecars`).
+    #[serde(default)]
+    pub sidecars: Vec<SidecarConfig>,
+    /// Required container platform (`os/arch[/variant]`) from
+    /// `spec.runtime.platform`; selects the container endpoint.
+    #[serde(default)]
+    pub platform: Option<String>,
+    /// Egress policy from `spec.security.network`. Enforced by runtimes that
+    /// can express it per instance (the Kubernetes runtime translates it into
+    /// a `NetworkPolicy`); `None` leaves the runtime's default networking.
+    #[serde(default)]
+    pub network_policy: Option<crate::domain::agent::NetworkPolicy>,
+    /// Record the instance's network flows (connection tuples plus SNI and
+    /// DNS names, never payloads). `None` disables capture.
+    #[serde(default)]
+    pub network_flow_capture: Option<NetworkFlowCapture>,
+}
+
+/// Spawn-time configuration of the network flow capture helper.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkFlowCapture {
+    /// Image providing `tshark`.
+    pub image: String,
+    /// Image pull policy for `image`.
+    pub image_pull_policy: ImagePullPolicy,
+}
+
+/// Spawn-time configuration of one sidecar container.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SidecarConfig {
+    /// Name from the manifest; labels the container and its console output.
+    pub name: String,
+    /// Fully-qualified image reference.
+    pub image: String,
+    /// Image pull policy for `image`.
+    pub image_pull_policy: ImagePullPolicy,
+    /// Entrypoint override; the image default is used when empty.
+    pub command: Vec<String>,
+    /// Environment variables for the sidecar only.
+    pub env: HashMap<String, String>,
+    /// CPU and memory limits. `disk_bytes` and `timeout_seconds` are unused:
+    /// sidecars live exactly as long as the agent container.
+    pub resources: ResourceLimits,
+}
+
+impl SidecarConfig {
+    /// Build the spawn config for a manifest sidecar.
+    pub fn from_spec(spec: &crate::domain::agent::SidecarSpec) -> Self {
+        let resources = spec.resources.clone().unwrap_or_default();
+        Self {
+            name: spec.name.clone(),
+            image: spec.image.clone(),
+            image_pull_policy: spec.image_pull_policy,
+            command: spec.command.clone(),
+            env: spec.env.clone(),
+            resources: ResourceLimits {
+                cpu_millis: resources.cpu,
+                memory_bytes: resources.memory_bytes(),
+                disk_bytes: None,
+                timeout_seconds: None,
+            },
+        }
+    }
+
+    /// Console stream label for this sidecar's output (`sidecar:<name>`).
+    pub fn console_stream(&self) -> String {
+        format!("sidecar:{}", self.name)
+    }
+}
+
+/// A batch of output captured from one sidecar since the previous drain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SidecarOutput {
+    /// Console stream label (see [`SidecarConfig::console_stream`]).
+    pub stream: String,
+    /// Interleaved stdout/stderr lines.
+    pub content: String,
 }
 
 fn default_container_uid() -> u32 {
@@ -175,6 +257,54 @@ impl RuntimeConfig {
             ))),
         }
     }
+
+    /// DNS name of this execution's instance on the runtime network:
+    /// `exec-<execution id>.aegis.internal`.
+    pub fn service_host(&self) -> String {
+        format!("exec-{}.{EXECUTION_DNS_ZONE}", self.execution_id.0)
+    }
+
+    /// Every DNS name the runtime registers for this instance: the
+    /// [`service_host`](Self::service_host), then `<sidecar>.<service host>`
+    /// per sidecar. Sidecars share the agent's network namespace, so all of
+    /// them resolve to the same address.
+    pub fn service_dns_names(&self) -> Vec<String> {
+        let host = self.service_host();
+        std::iter::once(host.clone())
+            .chain(
+                self.sidecars
+                    .iter()
+                    .map(|sidecar| format!("{}.{host}", sidecar.name)),
+            )
+            .collect()
+    }
+}
+
+/// Zone of the per-execution DNS names registered by runtimes that support
+/// them (see [`RuntimeConfig::service_dns_names`]).
+pub const EXECUTION_DNS_ZONE: &str = "aegis.internal";
+
+/// Whether `platform` is a container platform string: `os/arch[/variant]`
+/// (e.g. `linux/amd64`, `linux/arm64/v8`).
+pub fn is_valid_platform(platform: &str) -> bool {
+    let parts: Vec<&str> = platform.split('/').collect();
+    (2..=3).contains(&parts.len())
+        && parts.iter().all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        })
+}
+
+/// Whether an engine serving `served` can run a container requiring
+/// `requested`. A request without a variant (`linux/arm64`) accepts any
+/// variant of that architecture (`linux/arm64/v8`).
+pub fn platform_satisfies(served: &str, requested: &str) -> bool {
+    served == requested
+        || served
+            .strip_prefix(requested)
+            .is_some_and(|rest| rest.starts_with('/'))
 }
 
 /// Opaque identifier for a running runtime instance (container / MicroVM).
@@ -303,6 +433,26 @@ pub enum RuntimeError {
         /// timeout reported by the orchestrator-side health check.
         reason: String,
     },
+    /// Image signature verification rejected the image before container creation.
+    /// No container is created; the execution is failed with this reason.
+    #[error("Image verification failed for '{image}': {reason}")]
+    ImageVerificationFailed {
+        /// Image reference that failed verification.
+        image: String,
+        /// Why verification failed (no trust root, bad signature, verifier error).
+        reason: String,
+    },
+    /// The storage transport serving the instance's volume mounts restarted
+    /// while the task ran, leaving the mounts stale. The Supervisor terminates
+    /// the instance and moves on to the next iteration, whose fresh instance
+    /// mounts against the restarted transport.
+    #[error("Storage transport '{transport}' was interrupted: {reason}")]
+    StorageTransportInterrupted {
+        /// Transport that restarted, e.g. `nfs`.
+        transport: String,
+        /// Why the transport restarted.
+        reason: String,
+    },
     /// The execution was cancelled via [`crate::application::execution::ExecutionService::cancel_execution_for_tenant`].
     /// The Supervisor observed the cancellation token and terminated the running instance.
     #[error("Execution was cancelled")]
@@ -418,6 +568,25 @@ pub trait AgentRuntime: Send + Sync {
     ///
     /// Returns [`RuntimeError::InstanceNotFound`] if `id` is unknown.
     async fn status(&self, id: &InstanceId) -> Result<InstanceStatus, RuntimeError>;
+
+    /// Return sidecar output produced since the previous call for instance `id`.
+    ///
+    /// Called by the Supervisor after every iteration. Runtimes without sidecar
+    /// support return nothing.
+    async fn drain_sidecar_output(&self, _id: &InstanceId) -> Vec<SidecarOutput> {
+        Vec::new()
+    }
+
+    /// Return network flows recorded since the previous call for instance `id`.
+    ///
+    /// Only populated when the instance was spawned with
+    /// [`RuntimeConfig::network_flow_capture`]; other runtimes return nothing.
+    async fn drain_network_flows(
+        &self,
+        _id: &InstanceId,
+    ) -> Vec<crate::domain::network_flow::NetworkFlow> {
+        Vec::new()
+    }
 }
 
 // ============================================================================
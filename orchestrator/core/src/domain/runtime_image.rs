@@ -0,0 +1,295 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Built Runtime Images
+//!
+//! Pure value objects for agents whose manifest declares `spec.runtime.packages`.
+//! Instead of installing dependencies inside every container at execution time,
+//! the orchestrator renders a Dockerfile from the StandardRuntime base (or an
+//! explicit `spec.runtime.base_image`), builds it once per agent version, and
+//! runs executions on the resulting tag.
+//!
+//! ## Determinism
+//!
+//! The image tag embeds a content hash of the rendered Dockerfile, so:
+//! - Redeploying an unchanged manifest reuses the existing image (no rebuild)
+//! - Changing a package pin or the base image always yields a new tag
+//! - Two agent versions never share a tag by accident
+//!
+//! Packages are installed with exec-form `RUN ["tool", "install", ...]`
+//! so no shell ever interprets manifest-supplied strings.
+//!
+//! See Also: ADR-043 (AEGIS Agent Runtimes), ADR-045 (Container Registry & Image Management)
+
+use crate::domain::agent::RuntimeConfig;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+/// Local repository prefix for orchestrator-built runtime images.
+pub const BUILT_IMAGE_REPOSITORY_PREFIX: &str = "aegis-runtime";
+
+/// Maximum length of a single `spec.runtime.packages` entry.
+pub const MAX_PACKAGE_SPEC_LEN: usize = 256;
+
+/// Number of hex characters of the Dockerfile hash embedded in the image tag.
+const TAG_HASH_LEN: usize = 12;
+
+/// Image label carrying the owning agent name.
+pub const AEGIS_AGENT_LABEL: &str = "aegis.agent";
+/// Image label carrying the owning agent's manifest version.
+pub const AEGIS_AGENT_VERSION_LABEL: &str = "aegis.agent_version";
+/// Image label carrying the full Dockerfile content hash.
+pub const AEGIS_RUNTIME_HASH_LABEL: &str = "aegis.runtime_hash";
+
+/// Validate one package specifier from `spec.runtime.packages`.
+///
+/// Specifiers are passed verbatim as argv entries to the package manager, so
+/// anything that could be read as a flag or smuggle extra arguments is rejected.
+pub fn validate_package_spec(package: &str) -> Result<(), String> {
+    if package.is_empty() {
+        return Err("packages entries must not be empty".to_string());
+    }
+    if package.len() > MAX_PACKAGE_SPEC_LEN {
+        return Err(format!(
+            "package '{}…' exceeds {MAX_PACKAGE_SPEC_LEN} characters",
+            &package[..package
+                .char_indices()
+                .nth(32)
+                .map_or(package.len(), |(i, _)| i)]
+        ));
+    }
+    if package.starts_with('-') {
+        return Err(format!(
+            "package '{package}' must not start with '-' (flags are not allowed)"
+        ));
+    }
+    if package.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(format!(
+            "package '{package}' must not contain whitespace or control characters"
+        ));
+    }
+    Ok(())
+}
+
+/// Package manager used to install `spec.runtime.packages` for a language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PackageManager {
+    /// `pip install --no-cache-dir ...` (python)
+    Pip,
+    /// `npm install --global ...` (javascript, typescript)
+    Npm,
+    /// `cargo install --locked ...` (rust)
+    Cargo,
+    /// `go install ...` (go; entries must be `module/path@version`)
+    Go,
+}
+
+impl PackageManager {
+    /// Resolve the package manager for a StandardRuntime language.
+    pub fn for_language(language: &str) -> Option<Self> {
+        match language {
+            "python" => Some(Self::Pip),
+            "javascript" | "typescript" => Some(Self::Npm),
+            "rust" => Some(Self::Cargo),
+            "go" => Some(Self::Go),
+            _ => None,
+        }
+    }
+
+    /// Exec-form argv installing `packages`.
+    pub fn install_command(&self, packages: &[String]) -> Vec<String> {
+        let prefix: &[&str] = match self {
+            Self::Pip => &["pip", "install", "--no-cache-dir"],
+            Self::Npm => &["npm", "install", "--global", "--no-fund", "--no-audit"],
+            Self::Cargo => &["cargo", "install", "--locked"],
+            Self::Go => &["go", "install"],
+        };
+        prefix
+            .iter()
+            .map(|s| s.to_string())
+            .chain(packages.iter().cloned())
+            .collect()
+    }
+}
+
+/// Everything needed to build the runtime image for one agent version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RuntimeImageSpec {
+    pub agent_name: String,
+    pub agent_version: String,
+    pub base_image: String,
+    pub package_manager: PackageManager,
+    pub packages: Vec<String>,
+}
+
+impl RuntimeImageSpec {
+    /// Derive the build spec from a validated manifest runtime section.
+    ///
+    /// `resolved_base` is the StandardRuntime image for `language` + `version`;
+    /// it is used unless the manifest sets `base_image`.
+    pub fn from_runtime(
+        agent_name: &str,
+        agent_version: &str,
+        runtime: &RuntimeConfig,
+        resolved_base: &str,
+    ) -> Result<Self, String> {
+        let language = runtime
+            .language
+            .as_deref()
+            .ok_or_else(|| "packages require a StandardRuntime language".to_string())?;
+        let package_manager = PackageManager::for_language(language)
+            .ok_or_else(|| format!("packages are not supported for language '{language}'"))?;
+        for package in &runtime.packages {
+            validate_package_spec(package)?;
+        }
+        Ok(Self {
+            agent_name: agent_name.to_string(),
+            agent_version: agent_version.to_string(),
+            base_image: runtime
+                .base_image
+                .clone()
+                .unwrap_or_else(|| resolved_base.to_string()),
+            package_manager,
+            packages: runtime.packages.clone(),
+        })
+    }
+
+    /// Render the Dockerfile for this spec.
+    pub fn render_dockerfile(&self) -> String {
+        // serde_json string encoding matches Docker's exec-form JSON array syntax.
+        let install = serde_json::to_string(&self.package_manager.install_command(&self.packages))
+            .unwrap_or_else(|_| "[]".to_string());
+        format!(
+            "# Generated by AEGIS from spec.runtime.packages. Do not edit.\n\
+             FROM {}\n\
+             RUN {install}\n",
+            self.base_image
+        )
+    }
+
+    /// Hex SHA-256 of the rendered Dockerfile.
+    pub fn content_hash(&self) -> String {
+        hex::encode(Sha256::digest(self.render_dockerfile().as_bytes()))
+    }
+
+    /// Deterministic local tag: `aegis-runtime/<agent>:<version>-<hash12>`.
+    pub fn image_tag(&self) -> String {
+        let hash = self.content_hash();
+        let version = sanitize_tag(&self.agent_version);
+        format!(
+            "{BUILT_IMAGE_REPOSITORY_PREFIX}/{}:{}-{}",
+            sanitize_repository(&self.agent_name),
+            // 128 is Docker's tag length limit.
+            &version[..version.len().min(128 - TAG_HASH_LEN - 1)],
+            &hash[..TAG_HASH_LEN]
+        )
+    }
+
+    /// Labels stamped on the built image for provenance and cleanup.
+    pub fn labels(&self) -> HashMap<String, String> {
+        HashMap::from([
+            ("aegis.managed".to_string(), "true".to_string()),
+            (AEGIS_AGENT_LABEL.to_string(), self.agent_name.clone()),
+            (
+                AEGIS_AGENT_VERSION_LABEL.to_string(),
+                self.agent_version.clone(),
+            ),
+            (AEGIS_RUNTIME_HASH_LABEL.to_string(), self.content_hash()),
+        ])
+    }
+}
+
+/// Result of a successful runtime image build.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuiltRuntimeImage {
+    /// Local tag executions run on.
+    pub tag: String,
+    /// Content-addressed image ID (`sha256:...`) recorded on each execution.
+    pub digest: String,
+    /// `true` when an image with this tag already existed and no build ran.
+    pub reused: bool,
+}
+
+fn sanitize_repository(name: &str) -> String {
+    let sanitized: String = name
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '-',
+        })
+        .collect();
+    let trimmed = sanitized.trim_matches(|c: char| !c.is_ascii_alphanumeric());
+    if trimmed.is_empty() {
+        "agent".to_string()
+    } else {
+        trimmed.to_string()
+    }
+}
+
+fn sanitize_tag(version: &str) -> String {
+    let sanitized: String = version
+        .chars()
+        .map(|c| match c {
+            'A'..='Z' | 'a'..='z' | '0'..='9' | '-' | '_' | '.' => c,
+            _ => '_',
+        })
+        .collect();
+    if sanitized.is_empty() || sanitized.starts_with(['.', '-']) {
+        format!("v{sanitized}")
+    } else {
+        sanitized
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(packages: &[&str]) -> RuntimeImageSpec {
+        RuntimeImageSpec {
+            agent_name: "Data Cleaner".to_string(),
+            agent_version: "1.2.0+build.7".to_string(),
+            base_image: "python:3.11-slim".to_string(),
+            package_manager: PackageManager::Pip,
+            packages: packages.iter().map(|p| p.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn dockerfile_uses_exec_form_install() {
+        let dockerfile = spec(&["requests==2.32.3", "pydantic"]).render_dockerfile();
+        assert!(dockerfile.contains("FROM python:3.11-slim\n"));
+        assert!(dockerfile
+            .contains(r#"RUN ["pip","install","--no-cache-dir","requests==2.32.3","pydantic"]"#));
+    }
+
+    #[test]
+    fn image_tag_is_deterministic_and_content_addressed() {
+        let a = spec(&["requests==2.32.3"]);
+        let b = spec(&["requests==2.32.4"]);
+        assert_eq!(a.image_tag(), a.clone().image_tag());
+        assert_ne!(a.image_tag(), b.image_tag());
+
+        let tag = a.image_tag();
+        assert!(tag.starts_with("aegis-runtime/data-cleaner:1.2.0_build.7-"));
+        assert_eq!(tag.rsplit('-').next().unwrap().len(), TAG_HASH_LEN);
+    }
+
+    #[test]
+    fn package_specs_reject_flags_and_whitespace() {
+        assert!(validate_package_spec("numpy>=1.26").is_ok());
+        assert!(validate_package_spec("--index-url=http://evil").is_err());
+        assert!(validate_package_spec("numpy pandas").is_err());
+        assert!(validate_package_spec("").is_err());
+        assert!(validate_package_spec(&"a".repeat(MAX_PACKAGE_SPEC_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn package_manager_resolution() {
+        assert_eq!(
+            PackageManager::for_language("typescript"),
+            Some(PackageManager::Npm)
+        );
+        assert_eq!(PackageManager::for_language("cobol"), None);
+    }
+}
@@ -10,6 +10,7 @@
 //! | Type | Role |
 //! |------|------|
 //! | [`SensitiveString`] | Credential wrapper that redacts itself in `Debug`/`Display` |
+//! | [`SecretMasker`] | Replaces known secret values inside free text (events, logs) |
 //! | [`SecretPath`] | Namespace-aware structured path value object |
 //! | [`AccessContext`] | Audit metadata for every secret access operation |
 //! | [`DomainDynamicSecret`] | Short-lived credential entity with TTL lifecycle methods |
@@ -72,6 +73,61 @@ impl std::fmt::Display for SensitiveString {
     }
 }
 
+// ---------------------------------------------------------------------------
+// SecretMasker — redaction of injected secret values in free text
+// ---------------------------------------------------------------------------
+
+/// Placeholder substituted for secret values by [`SecretMasker`].
+pub const MASKED_SECRET: &str = "[REDACTED]";
+
+/// Replaces occurrences of known secret values in free text.
+///
+/// Built from the secrets injected into an execution (e.g. `secretRef`
+/// environment variables) and applied to console output, iteration output and
+/// error messages before they reach events, persistence or logs. Values
+/// shorter than [`SecretMasker::MIN_SECRET_LEN`] are ignored: masking them
+/// would mangle ordinary text without meaningfully protecting anything.
+#[derive(Clone, Default)]
+pub struct SecretMasker {
+    secrets: Vec<SensitiveString>,
+}
+
+impl SecretMasker {
+    /// Shortest value that is masked.
+    pub const MIN_SECRET_LEN: usize = 4;
+
+    /// Register a secret value to be masked.
+    pub fn add(&mut self, secret: SensitiveString) {
+        if secret.expose().len() < Self::MIN_SECRET_LEN || self.secrets.contains(&secret) {
+            return;
+        }
+        self.secrets.push(secret);
+        // Longest first so a secret containing another is replaced whole.
+        self.secrets
+            .sort_by_key(|s| std::cmp::Reverse(s.expose().len()));
+    }
+
+    /// `true` when no secrets are registered.
+    pub fn is_empty(&self) -> bool {
+        self.secrets.is_empty()
+    }
+
+    /// Return `text` with every registered secret replaced by [`MASKED_SECRET`].
+    pub fn mask(&self, text: &str) -> String {
+        self.secrets.iter().fold(text.to_string(), |acc, secret| {
+            acc.replace(secret.expose(), MASKED_SECRET)
+        })
+    }
+}
+
+impl std::fmt::Debug for SecretMasker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SecretMasker")
+            .field("secrets", &self.secrets.len())
+            .finish()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // SecretPath — namespace-aware path value object (ADR-034 §SecretPath)
 // ---------------------------------------------------------------------------
@@ -396,6 +452,21 @@ mod tests {
         assert_ne!(a, c);
     }
 
+    // ── SecretMasker ─────────────────────────────────────────────────────────
+
+    #[test]
+    fn secret_masker_replaces_longest_match_first() {
+        let mut masker = SecretMasker::default();
+        masker.add(SensitiveString::new("ghp_abc"));
+        masker.add(SensitiveString::new("ghp_abcdef"));
+        masker.add(SensitiveString::new("xy"));
+        assert_eq!(
+            masker.mask("token=ghp_abcdef other=ghp_abc xy"),
+            "token=[REDACTED] other=[REDACTED] xy"
+        );
+        assert!(SecretMasker::default().is_empty());
+    }
+
     // ── SecretPath ───────────────────────────────────────────────────────────
 
     #[test]
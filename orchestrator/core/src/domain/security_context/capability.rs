@@ -21,8 +21,18 @@
 //! 2. Path allowlist (for `fs.*` / `filesystem.*` tools)
 //! 3. Command allowlist (for `cmd.run`)
 //! 4. Domain allowlist (for `web.*` / `web-search.*` tools)
+//!
+//! ## Conditions
+//!
+//! A capability may also carry [`CapabilityConditions`] that depend on more
+//! than the tool name: argument matchers (e.g. `http.fetch` only to hosts
+//! matching `*.internal.corp`), UTC time windows, and a cap on how many calls
+//! the capability grants per execution. They are checked by
+//! [`Capability::check_conditions`] against an [`EvaluationContext`] once the
+//! constraints above have passed.
 
 use super::PolicyViolation;
+use chrono::{DateTime, Datelike, NaiveTime, Utc, Weekday};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
@@ -71,6 +81,124 @@ pub struct Capability {
     /// capability. `None` means no limit enforced at the capability layer.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub max_concurrent: Option<u32>,
+    /// Optional runtime conditions evaluated after the constraints above.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<CapabilityConditions>,
+}
+
+/// Conditional constraints on a [`Capability`]. All configured conditions
+/// must hold for the capability to grant a call.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CapabilityConditions {
+    /// Every matcher must accept the call's arguments.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<ArgumentMatcher>,
+    /// Windows in which the capability is usable. Empty means at any time.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub time_windows: Vec<TimeWindow>,
+    /// Maximum number of calls this capability grants within one execution.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_invocations_per_execution: Option<u32>,
+}
+
+/// Restricts one tool argument to values matching a set of glob patterns.
+///
+/// Patterns support `*` as a wildcard for any run of characters. A call that
+/// omits the argument, or passes an object or array for it, is denied.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArgumentMatcher {
+    /// Top-level argument name, or a JSON pointer (`"/request/url"`) for
+    /// nested arguments.
+    pub argument: String,
+    /// Which part of the value the patterns are matched against.
+    #[serde(default)]
+    pub match_on: ArgumentMatchTarget,
+    /// The value must match at least one of these patterns.
+    pub patterns: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArgumentMatchTarget {
+    /// The argument value itself (strings as-is, numbers and booleans as JSON).
+    #[default]
+    Value,
+    /// The host of a URL-valued argument, matched case-insensitively.
+    Host,
+}
+
+/// A daily window, in UTC, during which a capability may be used.
+///
+/// `end` is exclusive. A window whose `end` is earlier than its `start` runs
+/// past midnight; `days` then refers to the day the window opens on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimeWindow {
+    /// Days the window opens on. Empty means every day.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub days: Vec<Weekday>,
+    pub start: NaiveTime,
+    pub end: NaiveTime,
+}
+
+impl TimeWindow {
+    /// Whether `at` falls inside this window.
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        let time = at.time();
+        let day = at.weekday();
+        if self.start <= self.end {
+            time >= self.start && time < self.end && self.opens_on(day)
+        } else if time >= self.start {
+            self.opens_on(day)
+        } else if time < self.end {
+            self.opens_on(day.pred())
+        } else {
+            false
+        }
+    }
+
+    fn opens_on(&self, day: Weekday) -> bool {
+        self.days.is_empty() || self.days.contains(&day)
+    }
+}
+
+impl std::fmt::Display for TimeWindow {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.days.is_empty() {
+            let days: Vec<String> = self.days.iter().map(|d| d.to_string()).collect();
+            write!(f, "{} ", days.join(","))?;
+        }
+        write!(
+            f,
+            "{}-{} UTC",
+            self.start.format("%H:%M"),
+            self.end.format("%H:%M")
+        )
+    }
+}
+
+/// Runtime state that [`CapabilityConditions`] are evaluated against.
+#[derive(Debug, Clone)]
+pub struct EvaluationContext {
+    /// Evaluation time, compared against time windows.
+    pub now: DateTime<Utc>,
+    /// Calls already granted in the current execution, keyed by the granting
+    /// capability's `tool_pattern`.
+    pub prior_invocations: HashMap<String, u32>,
+}
+
+impl EvaluationContext {
+    /// Context at `now` with no recorded invocations.
+    pub fn at(now: DateTime<Utc>) -> Self {
+        Self {
+            now,
+            prior_invocations: HashMap::new(),
+        }
+    }
+
+    pub fn with_prior_invocations(mut self, prior_invocations: HashMap<String, u32>) -> Self {
+        self.prior_invocations = prior_invocations;
+        self
+    }
 }
 
 impl Capability {
@@ -183,6 +311,135 @@ impl Capability {
         Ok(())
     }
 
+    /// Evaluate this capability's [`CapabilityConditions`] for a call that
+    /// [`Capability::allows`] has already accepted. A capability without
+    /// conditions always passes.
+    ///
+    /// # Errors
+    ///
+    /// - `ArgumentNotAllowed` — an argument is missing or matches none of its patterns
+    /// - `OutsideTimeWindow` — `context.now` is outside every configured window
+    /// - `InvocationLimitExceeded` — the per-execution call budget is spent
+    pub fn check_conditions(
+        &self,
+        tool_name: &str,
+        args: &Value,
+        context: &EvaluationContext,
+    ) -> Result<(), PolicyViolation> {
+        let Some(conditions) = &self.conditions else {
+            return Ok(());
+        };
+
+        for matcher in &conditions.arguments {
+            let value = Self::argument_value(args, &matcher.argument);
+            let candidate = match (matcher.match_on, value.as_deref()) {
+                (ArgumentMatchTarget::Value, Some(v)) => Some(v.to_string()),
+                (ArgumentMatchTarget::Host, Some(v)) => url::Url::parse(v)
+                    .ok()
+                    .and_then(|u| u.host_str().map(|h| h.to_ascii_lowercase())),
+                (_, None) => None,
+            };
+            let matched = candidate.as_deref().is_some_and(|candidate| {
+                matcher
+                    .patterns
+                    .iter()
+                    .any(|pattern| match matcher.match_on {
+                        ArgumentMatchTarget::Value => Self::glob_matches(pattern, candidate),
+                        ArgumentMatchTarget::Host => {
+                            Self::glob_matches(&pattern.to_ascii_lowercase(), candidate)
+                        }
+                    })
+            });
+            if !matched {
+                return Err(PolicyViolation::ArgumentNotAllowed {
+                    tool_name: tool_name.to_string(),
+                    argument: matcher.argument.clone(),
+                    value,
+                    allowed_patterns: matcher.patterns.clone(),
+                });
+            }
+        }
+
+        if !conditions.time_windows.is_empty()
+            && !conditions
+                .time_windows
+                .iter()
+                .any(|window| window.contains(context.now))
+        {
+            return Err(PolicyViolation::OutsideTimeWindow {
+                tool_name: tool_name.to_string(),
+                evaluated_at: context.now,
+                allowed_windows: conditions
+                    .time_windows
+                    .iter()
+                    .map(|w| w.to_string())
+                    .collect(),
+            });
+        }
+
+        if let Some(limit) = conditions.max_invocations_per_execution {
+            let used = context
+                .prior_invocations
+                .get(&self.tool_pattern)
+                .copied()
+                .unwrap_or(0);
+            if used >= limit {
+                return Err(PolicyViolation::InvocationLimitExceeded {
+                    tool_name: tool_name.to_string(),
+                    tool_pattern: self.tool_pattern.clone(),
+                    limit,
+                    used,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Whether calls granted by this capability count towards a
+    /// per-execution invocation limit.
+    pub fn limits_invocations(&self) -> bool {
+        self.conditions
+            .as_ref()
+            .is_some_and(|c| c.max_invocations_per_execution.is_some())
+    }
+
+    /// Resolve `argument` (a top-level key or JSON pointer) to a matchable
+    /// string. Objects and arrays yield `None`.
+    fn argument_value(args: &Value, argument: &str) -> Option<String> {
+        let value = if argument.starts_with('/') {
+            args.pointer(argument)
+        } else {
+            args.get(argument)
+        }?;
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::Number(_) | Value::Bool(_) => Some(value.to_string()),
+            _ => None,
+        }
+    }
+
+    /// `*`-only glob match over the whole of `value`.
+    fn glob_matches(pattern: &str, value: &str) -> bool {
+        let mut parts = pattern.split('*');
+        let first = parts.next().unwrap_or("");
+        let Some(mut rest) = value.strip_prefix(first) else {
+            return false;
+        };
+        let parts: Vec<&str> = parts.collect();
+        let Some((last, middle)) = parts.split_last() else {
+            // No wildcard: exact match.
+            return rest.is_empty();
+        };
+        for part in middle {
+            match rest.find(part) {
+                Some(idx) => rest = &rest[idx + part.len()..],
+                None => return false,
+            }
+        }
+        rest.ends_with(last)
+    }
+
     /// Match `domain` against an allowlist entry `allowed` using DNS label
     /// boundaries. The match succeeds only if `domain` is exactly `allowed`
     /// or a subdomain of it (i.e. `domain == allowed` or
@@ -262,6 +519,7 @@ mod tests {
             max_response_size: None,
             rate_limit: None,
             max_concurrent: None,
+            conditions: None,
         };
 
         // Allowed: cargo build
@@ -305,6 +563,7 @@ mod tests {
             max_response_size: None,
             rate_limit: None,
             max_concurrent: None,
+            conditions: None,
         };
 
         // Relative path resolves under allowed directory
@@ -332,6 +591,7 @@ mod tests {
             max_response_size: None,
             rate_limit: None,
             max_concurrent: None,
+            conditions: None,
         };
         assert!(cap_empty
             .allows("fs.read", &json!({"path": "solution.py"}))
@@ -376,6 +636,7 @@ mod tests {
             max_response_size: None,
             rate_limit: None,
             max_concurrent: None,
+            conditions: None,
         };
         assert!(cap
             .allows("web.fetch", &json!({"url": "https://example.com/"}))
@@ -388,4 +649,99 @@ mod tests {
             Err(PolicyViolation::DomainNotAllowed { .. })
         ));
     }
+
+    #[test]
+    fn test_conditions_argument_host_time_window_and_invocation_limit() {
+        let cap = Capability {
+            tool_pattern: "http.fetch".to_string(),
+            path_allowlist: None,
+            command_allowlist: None,
+            subcommand_allowlist: None,
+            domain_allowlist: None,
+            max_response_size: None,
+            rate_limit: None,
+            max_concurrent: None,
+            conditions: Some(CapabilityConditions {
+                arguments: vec![ArgumentMatcher {
+                    argument: "url".to_string(),
+                    match_on: ArgumentMatchTarget::Host,
+                    patterns: vec!["*.internal.corp".to_string()],
+                }],
+                // Overnight window opening on Friday.
+                time_windows: vec![TimeWindow {
+                    days: vec![Weekday::Fri],
+                    start: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+                    end: NaiveTime::from_hms_opt(2, 0, 0).unwrap(),
+                }],
+                max_invocations_per_execution: Some(2),
+            }),
+        };
+        // 2026-10-16 is a Friday.
+        let friday_night = "2026-10-16T23:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let saturday_early = "2026-10-17T01:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let saturday_night = "2026-10-17T23:30:00Z".parse::<DateTime<Utc>>().unwrap();
+        let internal = json!({"url": "https://api.internal.corp/v1"});
+
+        let context = EvaluationContext::at(friday_night);
+        assert!(cap
+            .check_conditions("http.fetch", &internal, &context)
+            .is_ok());
+        assert!(cap
+            .check_conditions(
+                "http.fetch",
+                &internal,
+                &EvaluationContext::at(saturday_early)
+            )
+            .is_ok());
+
+        assert!(matches!(
+            cap.check_conditions(
+                "http.fetch",
+                &json!({"url": "https://internal.corp.evil.io/"}),
+                &context
+            ),
+            Err(PolicyViolation::ArgumentNotAllowed { value: Some(_), .. })
+        ));
+        assert!(matches!(
+            cap.check_conditions("http.fetch", &json!({}), &context),
+            Err(PolicyViolation::ArgumentNotAllowed { value: None, .. })
+        ));
+        assert!(matches!(
+            cap.check_conditions(
+                "http.fetch",
+                &internal,
+                &EvaluationContext::at(saturday_night)
+            ),
+            Err(PolicyViolation::OutsideTimeWindow { .. })
+        ));
+
+        let spent = context.with_prior_invocations(HashMap::from([("http.fetch".to_string(), 2)]));
+        assert!(matches!(
+            cap.check_conditions("http.fetch", &internal, &spent),
+            Err(PolicyViolation::InvocationLimitExceeded {
+                limit: 2,
+                used: 2,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(Capability::glob_matches(
+            "*.internal.corp",
+            "api.internal.corp"
+        ));
+        assert!(!Capability::glob_matches(
+            "*.internal.corp",
+            "internal.corp"
+        ));
+        assert!(Capability::glob_matches(
+            "https://*/v1/*",
+            "https://a.b/v1/items"
+        ));
+        assert!(Capability::glob_matches("exact", "exact"));
+        assert!(!Capability::glob_matches("exact", "exactly"));
+        assert!(!Capability::glob_matches("a*a", "a"));
+    }
 }
@@ -7,7 +7,7 @@
 //!
 //! | Module | Contents |
 //! |--------|----------|
-//! | [`capability`] | `Capability` value object |
+//! | [`capability`] | `Capability` value object, `CapabilityConditions` |
 //! | [`security_context`] | `SecurityContext` aggregate root, `SecurityContextMetadata` |
 //! | [`repository`] | `SecurityContextRepository` persistence trait |
 //!
@@ -25,10 +25,14 @@ pub mod repository;
 #[allow(clippy::module_inception)]
 pub mod security_context;
 
-pub use capability::Capability;
+pub use capability::{
+    ArgumentMatchTarget, ArgumentMatcher, Capability, CapabilityConditions, EvaluationContext,
+    TimeWindow,
+};
 pub use repository::SecurityContextRepository;
 pub use security_context::{
-    validate_context_ownership, PolicyViolation, SecurityContext, SecurityContextMetadata,
+    validate_context_ownership, CapabilityTrace, PolicyTrace, PolicyViolation, SecurityContext,
+    SecurityContextMetadata,
 };
 
 pub use crate::domain::rate_limit::{
@@ -32,7 +32,7 @@ use serde_json::Value;
 use std::path::PathBuf;
 use std::time::Duration;
 
-use super::capability::Capability;
+use super::capability::{Capability, EvaluationContext};
 use crate::domain::iam::RealmKind;
 use crate::domain::tenant::TenantId;
 
@@ -94,6 +94,25 @@ pub enum PolicyViolation {
         requested_secs: u32,
         ceiling_secs: u32,
     },
+    /// An argument failed a capability's argument matcher. `value` is `None`
+    /// when the argument was missing or not a scalar.
+    ArgumentNotAllowed {
+        tool_name: String,
+        argument: String,
+        value: Option<String>,
+        allowed_patterns: Vec<String>,
+    },
+    OutsideTimeWindow {
+        tool_name: String,
+        evaluated_at: DateTime<Utc>,
+        allowed_windows: Vec<String>,
+    },
+    InvocationLimitExceeded {
+        tool_name: String,
+        tool_pattern: String,
+        limit: u32,
+        used: u32,
+    },
 }
 
 impl std::fmt::Display for PolicyViolation {
@@ -173,10 +192,72 @@ impl std::fmt::Display for PolicyViolation {
                 f,
                 "exec timeout ceiling exceeded: requested={requested_secs}s, ceiling={ceiling_secs}s"
             ),
+            PolicyViolation::ArgumentNotAllowed {
+                tool_name,
+                argument,
+                value,
+                allowed_patterns,
+            } => match value {
+                Some(value) => write!(
+                    f,
+                    "argument '{argument}' of tool '{tool_name}' has value '{value}' matching none of [{}]",
+                    allowed_patterns.join(", ")
+                ),
+                None => write!(
+                    f,
+                    "argument '{argument}' of tool '{tool_name}' is missing or not a scalar; required to match one of [{}]",
+                    allowed_patterns.join(", ")
+                ),
+            },
+            PolicyViolation::OutsideTimeWindow {
+                tool_name,
+                evaluated_at,
+                allowed_windows,
+            } => write!(
+                f,
+                "tool '{tool_name}' is not permitted at {}; allowed windows: [{}]",
+                evaluated_at.format("%a %H:%M UTC"),
+                allowed_windows.join(", ")
+            ),
+            PolicyViolation::InvocationLimitExceeded {
+                tool_name,
+                tool_pattern,
+                limit,
+                used,
+            } => write!(
+                f,
+                "tool '{tool_name}' exceeded the per-execution limit of {limit} calls for capability '{tool_pattern}' (used={used})"
+            ),
         }
     }
 }
 
+/// Step-by-step account of a policy decision, from [`SecurityContext::trace`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PolicyTrace {
+    /// The tool is on the deny list; no capability was consulted.
+    pub denied_by_deny_list: bool,
+    /// Every capability in evaluation order.
+    pub capabilities: Vec<CapabilityTrace>,
+    /// Index of the capability that granted the call.
+    pub matched_capability: Option<usize>,
+    pub allowed: bool,
+    /// Why the call was denied, exactly as enforcement would report it.
+    pub violation: Option<PolicyViolation>,
+}
+
+/// One capability's verdict within a [`PolicyTrace`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CapabilityTrace {
+    pub index: usize,
+    pub tool_pattern: String,
+    /// `tool_pattern` matches the tool name.
+    pub tool_matched: bool,
+    /// Constraint or condition that rejected the call. `None` when the tool
+    /// did not match or the capability would grant the call.
+    pub violation: Option<PolicyViolation>,
+}
+
 /// Audit metadata for a [`SecurityContext`] record.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SecurityContextMetadata {
@@ -231,12 +312,18 @@ impl SecurityContext {
     /// 2. Linear capability scan (first accepting capability returns `Ok(())`)
     /// 3. Default-deny (no capability matched → `ToolNotAllowed`)
     ///
+    /// Capability conditions are evaluated at the current time with no
+    /// invocation history, so `max_invocations_per_execution` is only enforced
+    /// by callers that track it through [`SecurityContext::evaluate_in`].
+    ///
     /// # Errors
     ///
     /// Returns a [`PolicyViolation`] describing *why* the call was denied:
     /// - `ToolExplicitlyDenied` — `tool_name` is in `deny_list`
     /// - `ToolNotAllowed` — no capability matches `tool_name`
     /// - `PathOutsideBoundary` / `DomainNotAllowed` — capability constraint violation
+    /// - `ArgumentNotAllowed` / `OutsideTimeWindow` / `InvocationLimitExceeded` —
+    ///   a capability accepted the call but one of its conditions did not hold
     ///
     /// # Security
     ///
@@ -244,6 +331,23 @@ impl SecurityContext {
     /// on **every** MCP tool invocation. It must be O(n) in capabilities and
     /// must **not** panic or silently permit on unexpected input.
     pub fn evaluate(&self, tool_name: &str, args: &Value) -> Result<(), PolicyViolation> {
+        self.evaluate_in(tool_name, args, &EvaluationContext::at(Utc::now()))
+            .map(|_| ())
+    }
+
+    /// [`SecurityContext::evaluate`] against an explicit [`EvaluationContext`].
+    /// On success returns the capability that granted the call, so callers
+    /// can record the invocation against its limit.
+    ///
+    /// When no capability grants the call but at least one failed only on its
+    /// conditions, the first such condition violation is returned instead of
+    /// `ToolNotAllowed` so the denial reason reaches the audit trail.
+    pub fn evaluate_in(
+        &self,
+        tool_name: &str,
+        args: &Value,
+        context: &EvaluationContext,
+    ) -> Result<&Capability, PolicyViolation> {
         // 1. Check deny list first (explicit denies take precedence)
         if self.deny_list.contains(&tool_name.to_string()) {
             return Err(PolicyViolation::ToolExplicitlyDenied {
@@ -252,12 +356,21 @@ impl SecurityContext {
         }
 
         // 2. Check if any capability allows this call
+        let mut condition_violation = None;
         for capability in &self.capabilities {
-            match capability.allows(tool_name, args) {
-                Ok(()) => return Ok(()),
-                Err(_) => continue, // Try next capability
+            if capability.allows(tool_name, args).is_err() {
+                continue; // Try next capability
+            }
+            match capability.check_conditions(tool_name, args, context) {
+                Ok(()) => return Ok(capability),
+                Err(violation) => {
+                    condition_violation.get_or_insert(violation);
+                }
             }
         }
+        if let Some(violation) = condition_violation {
+            return Err(violation);
+        }
 
         // 3. No capability matched — deny by default
         Err(PolicyViolation::ToolNotAllowed {
@@ -270,6 +383,52 @@ impl SecurityContext {
         })
     }
 
+    /// Explain how [`SecurityContext::evaluate_in`] decides a call: the deny
+    /// list check, every capability's verdict and the final decision. Used by
+    /// policy simulation; nothing is recorded against invocation limits.
+    pub fn trace(&self, tool_name: &str, args: &Value, context: &EvaluationContext) -> PolicyTrace {
+        let capabilities = self
+            .capabilities
+            .iter()
+            .enumerate()
+            .map(|(index, capability)| {
+                let tool_matched = capability.matches_tool_name(tool_name);
+                let violation = if tool_matched {
+                    capability
+                        .allows(tool_name, args)
+                        .and_then(|()| capability.check_conditions(tool_name, args, context))
+                        .err()
+                } else {
+                    None
+                };
+                CapabilityTrace {
+                    index,
+                    tool_pattern: capability.tool_pattern.clone(),
+                    tool_matched,
+                    violation,
+                }
+            })
+            .collect();
+
+        let (matched_capability, violation) = match self.evaluate_in(tool_name, args, context) {
+            Ok(granted) => (
+                self.capabilities
+                    .iter()
+                    .position(|capability| std::ptr::eq(capability, granted)),
+                None,
+            ),
+            Err(violation) => (None, Some(violation)),
+        };
+
+        PolicyTrace {
+            denied_by_deny_list: self.deny_list.iter().any(|denied| denied == tool_name),
+            capabilities,
+            matched_capability,
+            allowed: violation.is_none(),
+            violation,
+        }
+    }
+
     /// Validate that the given principal is allowed to use this SecurityContext (ADR-056).
     ///
     /// SecurityContext names must follow tenant-namespaced conventions:
@@ -390,6 +549,7 @@ mod tests {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             }],
             deny_list: vec![],
             metadata: test_metadata(),
@@ -425,6 +585,7 @@ mod tests {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             }],
             // Although fs.* is allowed, fs.delete is explicitly denied
             deny_list: vec!["fs.delete".to_string()],
@@ -544,6 +705,7 @@ mod tests {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             }],
             deny_list: vec!["fs.delete".to_string()],
             metadata: test_metadata(),
@@ -553,4 +715,100 @@ mod tests {
         assert!(!ctx.permits_tool_name("fs.delete"));
         assert!(!ctx.permits_tool_name("cmd.run"));
     }
+
+    #[test]
+    fn test_evaluate_in_reports_condition_violation_and_granting_capability() {
+        use crate::domain::security_context::capability::{ArgumentMatcher, CapabilityConditions};
+        use std::collections::HashMap;
+
+        let ctx = SecurityContext {
+            name: "test-ctx".to_string(),
+            description: "Testing context".to_string(),
+            capabilities: vec![Capability {
+                tool_pattern: "cmd.run".to_string(),
+                path_allowlist: None,
+                command_allowlist: None,
+                subcommand_allowlist: None,
+                domain_allowlist: None,
+                max_response_size: None,
+                rate_limit: None,
+                max_concurrent: None,
+                conditions: Some(CapabilityConditions {
+                    arguments: vec![ArgumentMatcher {
+                        argument: "command".to_string(),
+                        match_on: Default::default(),
+                        patterns: vec!["cargo *".to_string()],
+                    }],
+                    time_windows: vec![],
+                    max_invocations_per_execution: Some(1),
+                }),
+            }],
+            deny_list: vec![],
+            metadata: test_metadata(),
+        };
+        let context = EvaluationContext::at(Utc::now());
+
+        let granted = ctx
+            .evaluate_in("cmd.run", &json!({"command": "cargo test"}), &context)
+            .unwrap();
+        assert!(granted.limits_invocations());
+
+        // The capability matched the tool, so its condition is the denial reason.
+        assert!(matches!(
+            ctx.evaluate("cmd.run", &json!({"command": "rm -rf /"})),
+            Err(PolicyViolation::ArgumentNotAllowed { .. })
+        ));
+
+        let spent = context.with_prior_invocations(HashMap::from([("cmd.run".to_string(), 1)]));
+        assert!(matches!(
+            ctx.evaluate_in("cmd.run", &json!({"command": "cargo test"}), &spent),
+            Err(PolicyViolation::InvocationLimitExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn test_trace_explains_each_capability() {
+        let capability = |tool_pattern: &str, paths: &[&str]| Capability {
+            tool_pattern: tool_pattern.to_string(),
+            path_allowlist: Some(paths.iter().map(PathBuf::from).collect()),
+            command_allowlist: None,
+            subcommand_allowlist: None,
+            domain_allowlist: None,
+            max_response_size: None,
+            rate_limit: None,
+            max_concurrent: None,
+            conditions: None,
+        };
+        let ctx = SecurityContext {
+            name: "test-ctx".to_string(),
+            description: "Testing context".to_string(),
+            capabilities: vec![
+                capability("fs.*", &["/workspace/src"]),
+                capability("web.fetch", &[]),
+                capability("fs.read", &["/workspace"]),
+            ],
+            deny_list: vec!["fs.delete".to_string()],
+            metadata: test_metadata(),
+        };
+        let context = EvaluationContext::at(Utc::now());
+
+        let trace = ctx.trace("fs.read", &json!({"path": "/workspace/notes.md"}), &context);
+        assert!(trace.allowed);
+        assert_eq!(trace.matched_capability, Some(2));
+        assert!(matches!(
+            trace.capabilities[0].violation,
+            Some(PolicyViolation::PathOutsideBoundary { .. })
+        ));
+        assert!(!trace.capabilities[1].tool_matched);
+
+        let denied = ctx.trace("fs.delete", &json!({"path": "/workspace/src"}), &context);
+        assert!(denied.denied_by_deny_list);
+        assert!(!denied.allowed);
+        assert_eq!(
+            denied.violation,
+            Some(PolicyViolation::ToolExplicitlyDenied {
+                tool_name: "fs.delete".to_string()
+            })
+        );
+    }
 }
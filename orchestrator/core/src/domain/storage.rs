@@ -16,6 +16,7 @@
 //! - **Purpose:** Implements internal responsibilities for storage
 
 use async_trait::async_trait;
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -193,6 +194,21 @@ pub trait StorageProvider: Send + Sync {
         length: usize,
     ) -> Result<Vec<u8>, StorageError>;
 
+    /// Read data from file at specific offset as a reference-counted buffer
+    ///
+    /// Same contract as [`read_at`](Self::read_at). Hot read paths (NFS READ,
+    /// streamed downloads) call this so backends whose client already yields
+    /// `Bytes` can hand the buffer through without copying it into a `Vec`.
+    /// The default wraps `read_at`, which moves the `Vec` without copying.
+    async fn read_at_bytes(
+        &self,
+        handle: &FileHandle,
+        offset: u64,
+        length: usize,
+    ) -> Result<Bytes, StorageError> {
+        self.read_at(handle, offset, length).await.map(Bytes::from)
+    }
+
     /// Write data to file at specific offset
     ///
     /// # Arguments
@@ -313,10 +329,93 @@ pub enum StorageError {
     #[error("Serialization error: {0}")]
     Serialization(String),
 
+    /// The backend returned data that fails an integrity or decoding check.
+    #[error("Data corrupted: {0}")]
+    Corrupted(String),
+
     #[error("Unknown storage error: {0}")]
     Unknown(String),
 }
 
+/// Machine-readable cause of a failed storage operation.
+///
+/// Every [`StorageError`] (and [`crate::domain::fsal::FsalError`]) reduces to
+/// one of these, so transports and audit events can act on the cause without
+/// parsing error strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StorageErrorKind {
+    /// The path or volume does not exist.
+    NotFound,
+    /// The path already exists.
+    AlreadyExists,
+    /// A volume or backend quota would be exceeded.
+    Quota,
+    /// Denied by filesystem policy, volume ownership or backend permissions.
+    Policy,
+    /// The request is malformed: bad path, bad handle or over a size limit.
+    InvalidRequest,
+    /// The backend could not be reached or did not answer in time.
+    BackendUnavailable,
+    /// The backend returned data that fails an integrity or decoding check.
+    Corrupted,
+    /// Any other backend failure.
+    Io,
+}
+
+impl StorageErrorKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StorageErrorKind::NotFound => "not_found",
+            StorageErrorKind::AlreadyExists => "already_exists",
+            StorageErrorKind::Quota => "quota",
+            StorageErrorKind::Policy => "policy",
+            StorageErrorKind::InvalidRequest => "invalid_request",
+            StorageErrorKind::BackendUnavailable => "backend_unavailable",
+            StorageErrorKind::Corrupted => "corrupted",
+            StorageErrorKind::Io => "io",
+        }
+    }
+
+    /// Whether the cause lies with the storage backend rather than the
+    /// request. Only these failures are recorded as
+    /// [`crate::domain::events::StorageEvent::OperationFailed`]; the rest are
+    /// ordinary filesystem outcomes or have their own events.
+    pub fn is_backend_fault(&self) -> bool {
+        matches!(
+            self,
+            StorageErrorKind::BackendUnavailable
+                | StorageErrorKind::Corrupted
+                | StorageErrorKind::Io
+        )
+    }
+}
+
+impl std::fmt::Display for StorageErrorKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl StorageError {
+    pub fn kind(&self) -> StorageErrorKind {
+        match self {
+            StorageError::NotFound(_) | StorageError::FileNotFound(_) => StorageErrorKind::NotFound,
+            StorageError::AlreadyExists(_) => StorageErrorKind::AlreadyExists,
+            StorageError::QuotaExceeded { .. } => StorageErrorKind::Quota,
+            StorageError::PermissionDenied(_) => StorageErrorKind::Policy,
+            StorageError::InvalidPath(_) => StorageErrorKind::InvalidRequest,
+            StorageError::Network(_) | StorageError::Timeout | StorageError::Unavailable(_) => {
+                StorageErrorKind::BackendUnavailable
+            }
+            StorageError::Serialization(_) | StorageError::Corrupted(_) => {
+                StorageErrorKind::Corrupted
+            }
+            StorageError::IoError(_) | StorageError::Unknown(_) => StorageErrorKind::Io,
+        }
+    }
+}
+
 impl From<reqwest::Error> for StorageError {
     fn from(err: reqwest::Error) -> Self {
         if err.is_timeout() {
@@ -337,7 +436,23 @@ impl From<serde_json::Error> for StorageError {
 
 impl From<std::io::Error> for StorageError {
     fn from(err: std::io::Error) -> Self {
-        StorageError::IoError(err.to_string())
+        use std::io::ErrorKind;
+        let message = err.to_string();
+        match err.kind() {
+            ErrorKind::NotFound => StorageError::FileNotFound(message),
+            ErrorKind::AlreadyExists => StorageError::AlreadyExists(message),
+            ErrorKind::PermissionDenied => StorageError::PermissionDenied(message),
+            ErrorKind::InvalidInput | ErrorKind::IsADirectory | ErrorKind::NotADirectory => {
+                StorageError::InvalidPath(message)
+            }
+            ErrorKind::TimedOut => StorageError::Timeout,
+            ErrorKind::ConnectionRefused
+            | ErrorKind::ConnectionReset
+            | ErrorKind::ConnectionAborted
+            | ErrorKind::NotConnected => StorageError::Network(message),
+            ErrorKind::InvalidData | ErrorKind::UnexpectedEof => StorageError::Corrupted(message),
+            _ => StorageError::IoError(message),
+        }
     }
 }
 
@@ -518,4 +633,30 @@ mod tests {
         let result = provider.create_directory("/test/path").await;
         assert!(matches!(result, Err(StorageError::AlreadyExists(_))));
     }
+
+    #[test]
+    fn io_errors_keep_their_cause() {
+        use std::io::{Error, ErrorKind};
+
+        let kind = |k: ErrorKind| StorageError::from(Error::new(k, "boom")).kind();
+        assert_eq!(kind(ErrorKind::NotFound), StorageErrorKind::NotFound);
+        assert_eq!(kind(ErrorKind::PermissionDenied), StorageErrorKind::Policy);
+        assert_eq!(
+            kind(ErrorKind::IsADirectory),
+            StorageErrorKind::InvalidRequest
+        );
+        assert_eq!(
+            kind(ErrorKind::TimedOut),
+            StorageErrorKind::BackendUnavailable
+        );
+        assert_eq!(kind(ErrorKind::InvalidData), StorageErrorKind::Corrupted);
+        assert_eq!(kind(ErrorKind::Other), StorageErrorKind::Io);
+
+        assert!(StorageErrorKind::Corrupted.is_backend_fault());
+        assert!(!StorageErrorKind::NotFound.is_backend_fault());
+        assert_eq!(
+            serde_json::to_value(StorageErrorKind::BackendUnavailable).unwrap(),
+            "backend_unavailable"
+        );
+    }
 }
@@ -33,13 +33,17 @@
 // See: adrs/005-iterative-execution-strategy.md
 // ============================================================================
 
+use crate::domain::agent::{ApprovalMode, ApprovalTimeoutAction, DEFAULT_APPROVAL_TIMEOUT_SECONDS};
 use crate::domain::execution::{ExecutionId, ExecutionInput, TrajectoryStep};
+use crate::domain::guidance::{apply_guidance, GuidanceQueue, OperatorGuidance};
+use crate::domain::network_flow::NetworkFlow;
 use crate::domain::repository::ExecutionRepository;
 use crate::domain::runtime::{AgentRuntime, InstanceId, RuntimeConfig, RuntimeError, TaskInput};
+use crate::domain::tenant::TenantId;
 use crate::domain::validation::{ValidationContext, ValidationPipeline, ValidationResults};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
 use tokio_util::sync::CancellationToken;
 use tracing::{info, warn};
 
@@ -129,6 +133,91 @@ pub trait SupervisorObserver: Send + Sync {
         results: &ValidationResults,
         passed: bool,
     );
+
+    /// Called with the network flows an iteration's instance opened, when the
+    /// execution was spawned with network flow capture.
+    async fn on_network_flows(&self, _iteration: u8, _flows: &[NetworkFlow]) {}
+
+    /// Called when the storage transport restarted under a running
+    /// iteration. The iteration's instance is terminated and the iteration
+    /// fails with [`RuntimeError::StorageTransportInterrupted`].
+    async fn on_storage_transport_interrupted(
+        &self,
+        _iteration: u8,
+        _interruption: &StorageTransportInterruption,
+    ) {
+    }
+}
+
+/// Decision returned by an [`IterationApprovalGate`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum IterationApproval {
+    Approved {
+        feedback: Option<String>,
+        approved_by: Option<String>,
+    },
+    Rejected {
+        reason: String,
+    },
+    TimedOut,
+}
+
+/// Human approval consulted before each iteration runs when
+/// `spec.execution.approval_mode` is `per_iteration`. Implemented by the
+/// `HumanInputService`, so pending iterations show up under
+/// `/v1/human-approvals`.
+#[async_trait]
+pub trait IterationApprovalGate: Send + Sync {
+    async fn request_iteration_approval(
+        &self,
+        tenant_id: TenantId,
+        execution_id: ExecutionId,
+        prompt: String,
+        timeout_seconds: u64,
+    ) -> IterationApproval;
+}
+
+/// Records the iteration each execution is on, so file operations performed
+/// by the agent container can be attributed to the iteration that caused
+/// them. Implemented by the NFS volume registry, which the FSAL consults when
+/// publishing storage events.
+pub trait IterationTracker: Send + Sync {
+    fn iteration_started(&self, execution_id: ExecutionId, iteration: u8);
+    fn execution_finished(&self, execution_id: ExecutionId);
+}
+
+/// A restart of the storage transport that serves agent volume mounts.
+/// Mounts made before the restart are stale: the restarted server no longer
+/// recognises their file handles, so I/O on them hangs or fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageTransportInterruption {
+    /// Transport that restarted, e.g. `nfs`.
+    pub transport: String,
+    /// Incremented on every restart.
+    pub generation: u64,
+    pub reason: String,
+}
+
+/// Publishes [`StorageTransportInterruption`]s. Implemented by the NFS
+/// gateway, whose watchdog restarts the server when its task stops.
+pub trait StorageTransportMonitor: Send + Sync {
+    /// Receiver that changes on every restart after this call.
+    fn subscribe(&self) -> watch::Receiver<Option<StorageTransportInterruption>>;
+}
+
+/// Resolves with the next interruption published on `receiver`; pends
+/// forever without a monitor.
+async fn next_interruption(
+    receiver: &mut Option<watch::Receiver<Option<StorageTransportInterruption>>>,
+) -> StorageTransportInterruption {
+    if let Some(receiver) = receiver {
+        while receiver.changed().await.is_ok() {
+            if let Some(interruption) = receiver.borrow_and_update().clone() {
+                return interruption;
+            }
+        }
+    }
+    std::future::pending().await
 }
 
 pub struct Supervisor {
@@ -138,6 +227,17 @@ pub struct Supervisor {
     /// `ValidationContext::tool_trajectory` from the persisted trajectory rather than
     /// leaving it empty.
     execution_repository: Option<Arc<dyn ExecutionRepository>>,
+    /// Optional operator guidance queue, drained before each iteration so that
+    /// guidance submitted mid-run is appended to the next iteration's prompt.
+    guidance_queue: Option<Arc<dyn GuidanceQueue>>,
+    /// Human approval gate for `approval_mode: per_iteration` agents. When the
+    /// mode is set but no gate is attached, iterations fail closed.
+    approval_gate: Option<Arc<dyn IterationApprovalGate>>,
+    /// Told when each iteration starts and when the loop ends.
+    iteration_tracker: Option<Arc<dyn IterationTracker>>,
+    /// Storage transport whose restarts fail the running iteration at once
+    /// instead of leaving it to hang on stale mounts until its timeout.
+    storage_transport: Option<Arc<dyn StorageTransportMonitor>>,
 }
 
 impl Supervisor {
@@ -145,6 +245,10 @@ impl Supervisor {
         Self {
             runtime,
             execution_repository: None,
+            guidance_queue: None,
+            approval_gate: None,
+            iteration_tracker: None,
+            storage_transport: None,
         }
     }
 
@@ -155,6 +259,30 @@ impl Supervisor {
         self
     }
 
+    /// Attach the operator guidance queue (`POST /v1/executions/{id}/guidance`).
+    pub fn with_guidance_queue(mut self, queue: Arc<dyn GuidanceQueue>) -> Self {
+        self.guidance_queue = Some(queue);
+        self
+    }
+
+    /// Attach the human approval gate used by `approval_mode: per_iteration`.
+    pub fn with_approval_gate(mut self, gate: Arc<dyn IterationApprovalGate>) -> Self {
+        self.approval_gate = Some(gate);
+        self
+    }
+
+    /// Attach the tracker that attributes storage events to iterations.
+    pub fn with_iteration_tracker(mut self, tracker: Arc<dyn IterationTracker>) -> Self {
+        self.iteration_tracker = Some(tracker);
+        self
+    }
+
+    /// Attach the monitor of the storage transport behind volume mounts.
+    pub fn with_storage_transport(mut self, monitor: Arc<dyn StorageTransportMonitor>) -> Self {
+        self.storage_transport = Some(monitor);
+        self
+    }
+
     /// Run the 100monkeys loop with fresh instances per iteration
     ///
     /// This method spawns a NEW runtime instance for each iteration attempt,
@@ -182,6 +310,14 @@ impl Supervisor {
     /// `tokio::select!` during execution. When cancelled, the current container
     /// is terminated and [`RuntimeError::Cancelled`] is returned.
     ///
+    /// ## Storage Transport Interruptions
+    ///
+    /// With a [`StorageTransportMonitor`] attached, a transport restart during
+    /// an iteration terminates its instance (even with
+    /// `keep_container_on_failure`, since its mounts are stale) and fails the
+    /// iteration with [`RuntimeError::StorageTransportInterrupted`]. The next
+    /// iteration spawns a fresh instance that mounts the restarted transport.
+    ///
     /// # Arguments
     /// * `runtime_config` - Configuration for spawning runtime instances
     /// * `input` - Execution input with intent/payload
@@ -221,9 +357,10 @@ impl Supervisor {
         );
 
         let current_instance = Arc::new(Mutex::new(None));
+        let execution_id = runtime_config.execution_id;
 
         // Wrap the entire loop in an overall deadline
-        match tokio::time::timeout(
+        let result = match tokio::time::timeout(
             overall_timeout,
             self.run_loop_inner(
                 runtime_config,
@@ -256,6 +393,85 @@ impl Supervisor {
                 }
                 Err(RuntimeError::TimedOut(overall_timeout_secs))
             }
+        };
+
+        if let Some(tracker) = &self.iteration_tracker {
+            tracker.execution_finished(execution_id);
+        }
+        result
+    }
+
+    /// Hold an iteration until a human approves it (`approval_mode:
+    /// per_iteration`). Approver feedback comes back as guidance for the
+    /// iteration prompt; a rejection, or a timeout under the default
+    /// `approval_on_timeout: reject`, fails the execution.
+    async fn await_iteration_approval(
+        &self,
+        runtime_config: &RuntimeConfig,
+        iteration: u32,
+        max_retries: u32,
+        prompt: &str,
+        previous_attempt: Option<&serde_json::Value>,
+        cancellation_token: &CancellationToken,
+    ) -> Result<Option<OperatorGuidance>, RuntimeError> {
+        let Some(gate) = &self.approval_gate else {
+            return Err(RuntimeError::ExecutionFailed(
+                "approval_mode is per_iteration but no approval gate is configured".to_string(),
+            ));
+        };
+        let strategy = &runtime_config.execution;
+        let timeout_seconds = strategy
+            .approval_timeout_seconds
+            .unwrap_or(DEFAULT_APPROVAL_TIMEOUT_SECONDS);
+
+        let mut request = format!(
+            "Approve iteration {iteration}/{max_retries} of execution {}?\n\nPrompt:\n{prompt}",
+            runtime_config.execution_id
+        );
+        if let Some(previous) = previous_attempt {
+            request.push_str(&format!("\n\nPrevious attempt:\n{previous}"));
+        }
+
+        info!(iteration, timeout_seconds, "Waiting for iteration approval");
+        let decision = tokio::select! {
+            decision = gate.request_iteration_approval(
+                runtime_config.tenant_id.clone(),
+                runtime_config.execution_id,
+                request,
+                timeout_seconds,
+            ) => decision,
+            _ = cancellation_token.cancelled() => return Err(RuntimeError::Cancelled),
+        };
+
+        match decision {
+            IterationApproval::Approved {
+                feedback,
+                approved_by,
+            } => {
+                info!(iteration, approved_by = ?approved_by, "Iteration approved");
+                Ok(feedback
+                    .filter(|feedback| !feedback.trim().is_empty())
+                    .map(|guidance| OperatorGuidance {
+                        guidance,
+                        submitted_by: approved_by.unwrap_or_else(|| "anonymous".to_string()),
+                        submitted_at: chrono::Utc::now(),
+                    }))
+            }
+            IterationApproval::Rejected { reason } => Err(RuntimeError::ExecutionFailed(format!(
+                "Iteration {iteration} rejected by approver: {reason}"
+            ))),
+            IterationApproval::TimedOut => match strategy.approval_on_timeout.unwrap_or_default() {
+                ApprovalTimeoutAction::Approve => {
+                    warn!(
+                        iteration,
+                        timeout_seconds, "Iteration approval timed out; proceeding as configured"
+                    );
+                    Ok(None)
+                }
+                ApprovalTimeoutAction::Reject => Err(RuntimeError::ExecutionFailed(format!(
+                    "Iteration {iteration} approval timed out after {timeout_seconds}s"
+                ))),
+            },
         }
     }
 
@@ -289,6 +505,10 @@ impl Supervisor {
         // Track iteration history for context in subsequent attempts
         let mut iteration_history: Vec<serde_json::Value> = Vec::new();
 
+        // Operator guidance waiting for an iteration that actually runs; kept
+        // across spawn failures so nothing submitted is dropped.
+        let mut pending_guidance: Vec<OperatorGuidance> = Vec::new();
+
         while attempts < max_retries {
             // Check cancellation before each iteration
             if cancellation_token.is_cancelled() {
@@ -301,6 +521,38 @@ impl Supervisor {
             observer
                 .on_iteration_start(attempts as u8, &original_intent)
                 .await;
+            if let Some(tracker) = &self.iteration_tracker {
+                tracker.iteration_started(runtime_config.execution_id, attempts as u8);
+            }
+
+            if let Some(queue) = &self.guidance_queue {
+                pending_guidance.extend(queue.drain(runtime_config.execution_id));
+            }
+
+            // Approval gate sits between assembling the iteration and running it,
+            // so no container is held while a human decides.
+            if runtime_config.execution.approval_mode == ApprovalMode::PerIteration {
+                let prompt = apply_guidance(&original_intent, &pending_guidance);
+                match self
+                    .await_iteration_approval(
+                        &runtime_config,
+                        attempts,
+                        max_retries,
+                        &prompt,
+                        iteration_history.last(),
+                        &cancellation_token,
+                    )
+                    .await
+                {
+                    Ok(feedback) => pending_guidance.extend(feedback),
+                    Err(e) => {
+                        observer
+                            .on_iteration_fail(attempts as u8, &e.to_string())
+                            .await;
+                        return Err(e);
+                    }
+                }
+            }
 
             // SPAWN FRESH INSTANCE for this iteration
             info!("Spawning fresh runtime instance for iteration {}", attempts);
@@ -322,6 +574,10 @@ impl Supervisor {
             // Save keep_container flag before moving config
             let keep_on_failure = current_config.keep_container_on_failure;
 
+            // Subscribe before spawning: a restart while the instance mounts
+            // its volumes leaves those mounts stale as well.
+            let mut storage_transport = self.storage_transport.as_ref().map(|m| m.subscribe());
+
             let instance_id = match self.runtime.spawn(current_config).await {
                 Ok(id) => {
                     *current_instance.lock().await = Some(id.clone());
@@ -349,10 +605,19 @@ impl Supervisor {
             let mut container_guard =
                 ContainerGuard::new(self.runtime.clone(), instance_id.clone());
 
+            if !pending_guidance.is_empty() {
+                info!(
+                    iteration = attempts,
+                    count = pending_guidance.len(),
+                    "Applying operator guidance to iteration prompt"
+                );
+            }
+
             let task_input = TaskInput {
-                prompt: original_intent.clone(),
+                prompt: apply_guidance(&original_intent, &pending_guidance),
                 context: execution_context.clone(),
             };
+            pending_guidance.clear();
 
             // Execute task with per-iteration timeout and cancellation support
             let execution_result = tokio::select! {
@@ -380,11 +645,42 @@ impl Supervisor {
                     }
                     return Err(RuntimeError::Cancelled);
                 }
+                interruption = next_interruption(&mut storage_transport) => {
+                    warn!(
+                        iteration = attempts,
+                        transport = %interruption.transport,
+                        generation = interruption.generation,
+                        reason = %interruption.reason,
+                        "Storage transport restarted during iteration; mounts are stale"
+                    );
+                    observer
+                        .on_storage_transport_interrupted(attempts as u8, &interruption)
+                        .await;
+                    Err(RuntimeError::StorageTransportInterrupted {
+                        transport: interruption.transport,
+                        reason: interruption.reason,
+                    })
+                }
             };
 
-            // Terminate the instance after execution (unless keep_on_failure is set)
+            // Collect sidecar output before terminate() removes the sidecars.
+            for chunk in self.runtime.drain_sidecar_output(&instance_id).await {
+                observer
+                    .on_console_output(attempts as u8, &chunk.stream, &chunk.content)
+                    .await;
+            }
+            let flows = self.runtime.drain_network_flows(&instance_id).await;
+            if !flows.is_empty() {
+                observer.on_network_flows(attempts as u8, &flows).await;
+            }
+
+            // Terminate the instance after execution (unless keep_on_failure is set).
+            // Instances with stale mounts are never worth keeping.
             let should_terminate = if keep_on_failure {
-                execution_result.is_ok()
+                matches!(
+                    execution_result,
+                    Ok(_) | Err(RuntimeError::StorageTransportInterrupted { .. })
+                )
             } else {
                 true
             };
@@ -790,12 +1086,20 @@ mod tests {
                 validation: None,
                 tool_validation: None,
                 delivery: None,
+                approval_mode: crate::domain::agent::ApprovalMode::Off,
+                approval_timeout_seconds: None,
+                approval_on_timeout: None,
             },
             volumes: Vec::new(),
             keep_container_on_failure: false,
             image: "python:3.12".to_string(),
             bootstrap_path: None,
             execution_id: crate::domain::execution::ExecutionId::new(),
+            tenant_id: crate::domain::tenant::TenantId::default(),
+            sidecars: Vec::new(),
+            platform: None,
+            network_policy: None,
+            network_flow_capture: None,
         }
     }
 
@@ -933,6 +1237,145 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_supervisor_appends_queued_guidance_to_next_prompt() {
+        use crate::domain::guidance::OperatorGuidance;
+        use crate::infrastructure::guidance_queue::InMemoryGuidanceQueue;
+
+        let runtime = Arc::new(
+            TestRuntime::new()
+                .with_spawn_success(1)
+                .with_execute_success(vec!["Success output".to_string()]),
+        );
+        let queue = Arc::new(InMemoryGuidanceQueue::new());
+        let config = create_test_config();
+        queue.enqueue(
+            config.execution_id,
+            OperatorGuidance {
+                guidance: "Use the staging database".to_string(),
+                submitted_by: "alice".to_string(),
+                submitted_at: chrono::Utc::now(),
+            },
+        );
+        let supervisor = Supervisor::new(runtime.clone()).with_guidance_queue(queue.clone());
+
+        let result = supervisor
+            .run_loop(
+                config.clone(),
+                create_test_input(),
+                1,
+                Arc::new(TestObserver::default()),
+                CancellationToken::new(),
+                None,
+            )
+            .await;
+
+        assert!(result.is_ok());
+        let prompt = &runtime.execute_inputs.lock().await[0].prompt;
+        assert!(prompt.starts_with("Test task"));
+        assert!(prompt.ends_with("- Use the staging database"));
+        assert!(queue.drain(config.execution_id).is_empty());
+    }
+
+    struct FixedApprovalGate(IterationApproval);
+
+    #[async_trait]
+    impl IterationApprovalGate for FixedApprovalGate {
+        async fn request_iteration_approval(
+            &self,
+            _tenant_id: TenantId,
+            _execution_id: ExecutionId,
+            _prompt: String,
+            _timeout_seconds: u64,
+        ) -> IterationApproval {
+            self.0.clone()
+        }
+    }
+
+    fn per_iteration_approval_config() -> RuntimeConfig {
+        let mut config = create_test_config();
+        config.execution.approval_mode = ApprovalMode::PerIteration;
+        config
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_rejected_iteration_never_spawns() {
+        let runtime = Arc::new(TestRuntime::new());
+        let supervisor = Supervisor::new(runtime.clone()).with_approval_gate(Arc::new(
+            FixedApprovalGate(IterationApproval::Rejected {
+                reason: "too risky".to_string(),
+            }),
+        ));
+        let observer = Arc::new(TestObserver::default());
+
+        let result = supervisor
+            .run_loop(
+                per_iteration_approval_config(),
+                create_test_input(),
+                3,
+                observer.clone(),
+                CancellationToken::new(),
+                None,
+            )
+            .await;
+
+        assert!(
+            matches!(result, Err(RuntimeError::ExecutionFailed(msg)) if msg.contains("too risky"))
+        );
+        assert!(runtime.execute_inputs.lock().await.is_empty());
+        assert_eq!(*observer.iteration_fails.lock().await, vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_approver_feedback_reaches_prompt() {
+        let runtime = Arc::new(
+            TestRuntime::new()
+                .with_spawn_success(1)
+                .with_execute_success(vec!["Success output".to_string()]),
+        );
+        let supervisor = Supervisor::new(runtime.clone()).with_approval_gate(Arc::new(
+            FixedApprovalGate(IterationApproval::Approved {
+                feedback: Some("Only touch the docs folder".to_string()),
+                approved_by: Some("bob".to_string()),
+            }),
+        ));
+
+        let result = supervisor
+            .run_loop(
+                per_iteration_approval_config(),
+                create_test_input(),
+                1,
+                Arc::new(TestObserver::default()),
+                CancellationToken::new(),
+                None,
+            )
+            .await;
+
+        assert!(result.is_ok());
+        let prompt = &runtime.execute_inputs.lock().await[0].prompt;
+        assert!(prompt.ends_with("- Only touch the docs folder"));
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_per_iteration_without_gate_fails_closed() {
+        let runtime = Arc::new(TestRuntime::new());
+        let supervisor = Supervisor::new(runtime.clone());
+
+        let result = supervisor
+            .run_loop(
+                per_iteration_approval_config(),
+                create_test_input(),
+                1,
+                Arc::new(TestObserver::default()),
+                CancellationToken::new(),
+                None,
+            )
+            .await;
+
+        assert!(matches!(result, Err(RuntimeError::ExecutionFailed(_))));
+        assert!(runtime.execute_inputs.lock().await.is_empty());
+    }
+
     #[tokio::test]
     async fn test_supervisor_max_retries_exceeded() {
         let runtime = Arc::new(TestRuntime::new());
@@ -1086,6 +1529,60 @@ mod tests {
         assert_eq!(terminate_calls.len(), 1);
     }
 
+    struct TestTransport(watch::Sender<Option<StorageTransportInterruption>>);
+
+    impl StorageTransportMonitor for TestTransport {
+        fn subscribe(&self) -> watch::Receiver<Option<StorageTransportInterruption>> {
+            self.0.subscribe()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_supervisor_retries_iteration_after_storage_transport_restart() {
+        let runtime = Arc::new(
+            TestRuntime::new()
+                .with_spawn_success(2)
+                .with_execute_success(vec!["Output".to_string()])
+                .with_execute_delay(Duration::from_millis(300)),
+        );
+        let transport = Arc::new(TestTransport(watch::channel(None).0));
+
+        let supervisor = Supervisor::new(runtime.clone()).with_storage_transport(transport.clone());
+        let observer = Arc::new(TestObserver::default());
+
+        // Restart the transport while the first iteration is running.
+        let restart = transport.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            restart.0.send_replace(Some(StorageTransportInterruption {
+                transport: "nfs".to_string(),
+                generation: 1,
+                reason: "server task stopped".to_string(),
+            }));
+        });
+
+        // Stale instances are terminated even when failed ones would be kept.
+        let mut config = create_test_config();
+        config.keep_container_on_failure = true;
+
+        let result = supervisor
+            .run_loop(
+                config,
+                create_test_input(),
+                3,
+                observer.clone(),
+                CancellationToken::new(),
+                None,
+            )
+            .await;
+
+        assert_eq!(result.unwrap(), "Output");
+        assert_eq!(*observer.iteration_fails.lock().await, vec![1]);
+        assert_eq!(*observer.iteration_completes.lock().await, vec![2]);
+        let terminate_calls = runtime.terminate_calls.lock().await;
+        assert_eq!(terminate_calls[0].as_str(), "instance-0");
+    }
+
     #[tokio::test]
     async fn test_supervisor_uses_default_timeout_when_none() {
         // Verify that when timeout_seconds is None, the supervisor still proceeds
@@ -0,0 +1,71 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Execution Task Queue
+//!
+//! With `spec.execution_queue` configured, `start_execution` does not start
+//! the execution in-process. It enqueues an [`ExecutionTask`] on a
+//! [`TaskQueue`] shared by every daemon, and each daemon's
+//! `ExecutionQueueWorker` claims tasks and starts them locally.
+//!
+//! Delivery is at-least-once. A claim stays invisible to other consumers for
+//! the queue's visibility timeout; a consumer that dies before
+//! [`TaskQueue::ack`] leaves the task to be claimed again once it expires.
+//! Workers start tasks with their pre-assigned [`ExecutionId`], so a
+//! redelivered task cannot start a second execution.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Queued execution payload and the queue backend interface
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::domain::execution::{ExecutionId, ExecutionInput};
+use crate::domain::iam::UserIdentity;
+use crate::domain::shared_kernel::AgentId;
+use crate::domain::tenant::TenantId;
+
+/// Everything a worker needs to start a queued execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionTask {
+    /// Assigned at enqueue time and returned to the submitter.
+    pub execution_id: ExecutionId,
+    pub tenant_id: TenantId,
+    pub agent_id: AgentId,
+    pub input: ExecutionInput,
+    pub security_context_name: String,
+    /// Caller identity at submit time, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identity: Option<UserIdentity>,
+    pub enqueued_at: DateTime<Utc>,
+}
+
+/// A task held by one consumer until it is acked or released.
+#[derive(Debug, Clone)]
+pub struct ClaimedTask {
+    pub task: ExecutionTask,
+    /// Backend handle identifying this claim.
+    pub receipt: String,
+    /// Deliveries so far, this one included.
+    pub attempts: u32,
+}
+
+#[async_trait]
+pub trait TaskQueue: Send + Sync {
+    /// Backend name for logs and metrics (`postgres`, `redis`, `memory`).
+    fn backend(&self) -> &'static str;
+
+    async fn enqueue(&self, task: &ExecutionTask) -> anyhow::Result<()>;
+
+    /// Claim the oldest visible task for `consumer`, if any. Never blocks
+    /// waiting for work.
+    async fn claim(&self, consumer: &str) -> anyhow::Result<Option<ClaimedTask>>;
+
+    /// Remove a claimed task from the queue for good.
+    async fn ack(&self, claim: &ClaimedTask) -> anyhow::Result<()>;
+
+    /// Give a claimed task back, to become claimable again after `delay`.
+    async fn release(&self, claim: &ClaimedTask, delay: std::time::Duration) -> anyhow::Result<()>;
+}
@@ -136,6 +136,22 @@ pub enum TenantQuotaKind {
     ConcurrentExecutions,
     TotalAgents,
     StorageGb,
+    /// Allocated volume bytes (sum of `size_limit_bytes`).
+    VolumeBytes,
+    /// LLM tokens consumed in the current calendar month.
+    MonthlyTokens,
+}
+
+impl TenantQuotaKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TenantQuotaKind::ConcurrentExecutions => "concurrent_executions",
+            TenantQuotaKind::TotalAgents => "total_agents",
+            TenantQuotaKind::StorageGb => "storage_gb",
+            TenantQuotaKind::VolumeBytes => "volume_bytes",
+            TenantQuotaKind::MonthlyTokens => "monthly_tokens",
+        }
+    }
 }
 
 /// Per-tenant resource quotas
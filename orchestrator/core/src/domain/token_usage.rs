@@ -0,0 +1,206 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Token Usage Accounting
+//!
+//! One [`TokenUsageRecord`] is written per recorded [`LlmInteraction`] that
+//! carries provider-reported token counts. Records are rolled up on demand by
+//! execution, agent, day or tenant ([`UsageGroupBy`]) for `/v1/usage`, and a
+//! nightly job folds each finished UTC day into a per-tenant/agent/model
+//! summary table so dashboards never scan the raw rows.
+//!
+//! [`LlmInteraction`]: crate::domain::execution::LlmInteraction
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Token usage records, rollup queries and the repository interface
+
+use crate::domain::agent::AgentId;
+use crate::domain::execution::ExecutionId;
+use crate::domain::repository::RepositoryError;
+use crate::domain::shared_kernel::TenantId;
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Token usage of one LLM call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenUsageRecord {
+    pub execution_id: ExecutionId,
+    pub tenant_id: TenantId,
+    pub agent_id: AgentId,
+    pub iteration_number: u8,
+    pub provider: String,
+    pub model: String,
+    pub input_tokens: u32,
+    pub output_tokens: u32,
+    pub recorded_at: DateTime<Utc>,
+}
+
+/// Dimension a usage rollup is grouped by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UsageGroupBy {
+    Execution,
+    Agent,
+    #[default]
+    Day,
+    Tenant,
+}
+
+/// Filter for a usage rollup. `from` is inclusive, `to` exclusive.
+#[derive(Debug, Clone)]
+pub struct UsageQuery {
+    pub tenant_id: TenantId,
+    pub group_by: UsageGroupBy,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub agent_id: Option<AgentId>,
+    pub execution_id: Option<ExecutionId>,
+}
+
+impl UsageQuery {
+    /// Whether `record` falls inside this query.
+    pub fn matches(&self, record: &TokenUsageRecord) -> bool {
+        record.tenant_id == self.tenant_id
+            && record.recorded_at >= self.from
+            && record.recorded_at < self.to
+            && self.agent_id.is_none_or(|id| id == record.agent_id)
+            && self.execution_id.is_none_or(|id| id == record.execution_id)
+    }
+}
+
+/// Summed usage for one group key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct UsageRollup {
+    /// Execution ID, agent ID, `YYYY-MM-DD` or tenant ID, per [`UsageGroupBy`].
+    pub key: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    /// Number of LLM calls summed into this row.
+    pub calls: u64,
+}
+
+/// One row of the nightly per-day summary.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DailyUsageSummary {
+    pub day: NaiveDate,
+    pub tenant_id: String,
+    pub agent_id: AgentId,
+    pub model: String,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub calls: u64,
+}
+
+/// Group key of `record` under `group_by`.
+pub fn group_key(record: &TokenUsageRecord, group_by: UsageGroupBy) -> String {
+    match group_by {
+        UsageGroupBy::Execution => record.execution_id.to_string(),
+        UsageGroupBy::Agent => record.agent_id.0.to_string(),
+        UsageGroupBy::Day => record.recorded_at.date_naive().to_string(),
+        UsageGroupBy::Tenant => record.tenant_id.as_str().to_string(),
+    }
+}
+
+/// Roll `records` up by `group_by`, ordered by key.
+pub fn rollup<'a>(
+    records: impl IntoIterator<Item = &'a TokenUsageRecord>,
+    group_by: UsageGroupBy,
+) -> Vec<UsageRollup> {
+    let mut groups: BTreeMap<String, UsageRollup> = BTreeMap::new();
+    for record in records {
+        let key = group_key(record, group_by);
+        let row = groups.entry(key.clone()).or_insert_with(|| UsageRollup {
+            key,
+            input_tokens: 0,
+            output_tokens: 0,
+            calls: 0,
+        });
+        row.input_tokens += u64::from(record.input_tokens);
+        row.output_tokens += u64::from(record.output_tokens);
+        row.calls += 1;
+    }
+    groups.into_values().collect()
+}
+
+/// Persistence for token usage records and their daily summaries.
+#[async_trait]
+pub trait TokenUsageRepository: Send + Sync {
+    /// Append one usage record.
+    async fn record(&self, record: &TokenUsageRecord) -> Result<(), RepositoryError>;
+
+    /// Roll raw records up per `query`, ordered by key.
+    async fn rollup(&self, query: &UsageQuery) -> Result<Vec<UsageRollup>, RepositoryError>;
+
+    /// Recompute the summary rows for `day` (UTC) from the raw records.
+    /// Idempotent; returns the number of summary rows written.
+    async fn aggregate_day(&self, day: NaiveDate) -> Result<u64, RepositoryError>;
+
+    /// Summary rows for `tenant_id` with `from <= day <= to`.
+    async fn daily_summary(
+        &self,
+        tenant_id: &TenantId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyUsageSummary>, RepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(agent_id: AgentId, day: u32, input: u32, output: u32) -> TokenUsageRecord {
+        TokenUsageRecord {
+            execution_id: ExecutionId::new(),
+            tenant_id: TenantId::consumer(),
+            agent_id,
+            iteration_number: 1,
+            provider: "openai".to_string(),
+            model: "gpt-4o".to_string(),
+            input_tokens: input,
+            output_tokens: output,
+            recorded_at: format!("2026-03-{day:02}T12:00:00Z").parse().unwrap(),
+        }
+    }
+
+    #[test]
+    fn rollup_sums_per_group_key() {
+        let alpha = AgentId::new();
+        let beta = AgentId::new();
+        let records = vec![
+            record(alpha, 1, 100, 10),
+            record(alpha, 2, 50, 5),
+            record(beta, 2, 7, 3),
+        ];
+
+        let by_day = rollup(&records, UsageGroupBy::Day);
+        assert_eq!(by_day.len(), 2);
+        assert_eq!(by_day[0].key, "2026-03-01");
+        assert_eq!(
+            (
+                by_day[1].input_tokens,
+                by_day[1].output_tokens,
+                by_day[1].calls
+            ),
+            (57, 8, 2)
+        );
+
+        let by_tenant = rollup(&records, UsageGroupBy::Tenant);
+        assert_eq!(by_tenant.len(), 1);
+        assert_eq!(by_tenant[0].input_tokens, 157);
+
+        let query = UsageQuery {
+            tenant_id: TenantId::consumer(),
+            group_by: UsageGroupBy::Agent,
+            from: "2026-03-02T00:00:00Z".parse().unwrap(),
+            to: "2026-03-03T00:00:00Z".parse().unwrap(),
+            agent_id: Some(alpha),
+            execution_id: None,
+        };
+        let matched: Vec<_> = records.iter().filter(|r| query.matches(r)).collect();
+        assert_eq!(matched.len(), 1);
+        assert_eq!(matched[0].input_tokens, 50);
+    }
+}
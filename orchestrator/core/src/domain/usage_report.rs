@@ -0,0 +1,137 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Monthly Usage Reports
+//!
+//! Prometheus only keeps a few weeks of samples, so an hourly job folds the
+//! durable records into one [`MonthlyUsageSummary`] row per tenant and UTC
+//! month, served by `/v1/reports/usage` as JSON or CSV:
+//!
+//! | Column | Source |
+//! |--------|--------|
+//! | `executions` | Executions started in the month |
+//! | `tool_calls` | Trajectory steps of those executions |
+//! | `input_tokens` / `output_tokens` / `llm_calls` | `llm_token_usage` records of the month |
+//! | `storage_bytes` | Peak allocated volume bytes seen by the job during the month |
+//!
+//! The current month is month-to-date and refreshed every hour; a month is
+//! final after its first aggregation once it has ended.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Domain Layer
+//! - **Purpose:** Monthly per-tenant summaries and the repository interface
+
+use crate::domain::repository::RepositoryError;
+use crate::domain::shared_kernel::TenantId;
+use async_trait::async_trait;
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use serde::Serialize;
+
+/// Usage of one tenant in one UTC month.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MonthlyUsageSummary {
+    /// First day of the month.
+    pub month: NaiveDate,
+    pub tenant_id: String,
+    pub executions: u64,
+    pub tool_calls: u64,
+    pub input_tokens: u64,
+    pub output_tokens: u64,
+    pub llm_calls: u64,
+    pub storage_bytes: u64,
+    pub aggregated_at: DateTime<Utc>,
+}
+
+/// Parse `YYYY-MM` into the first day of that month.
+pub fn parse_month(month: &str) -> Option<NaiveDate> {
+    let (year, month) = month.split_once('-')?;
+    NaiveDate::from_ymd_opt(year.parse().ok()?, month.parse().ok()?, 1)
+}
+
+/// First day of the month containing `date`.
+pub fn month_of(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).expect("day 1 exists in every month")
+}
+
+/// `[start, end)` of the month starting on `month`.
+pub fn month_bounds(month: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>) {
+    let start = month_of(month);
+    let end = start + Months::new(1);
+    (
+        start.and_time(chrono::NaiveTime::MIN).and_utc(),
+        end.and_time(chrono::NaiveTime::MIN).and_utc(),
+    )
+}
+
+/// Render `rows` as CSV with a header line.
+pub fn to_csv(rows: &[MonthlyUsageSummary]) -> String {
+    let mut csv = String::from(
+        "month,tenant_id,executions,tool_calls,input_tokens,output_tokens,llm_calls,storage_bytes,aggregated_at\n",
+    );
+    for row in rows {
+        // Tenant IDs are slugs, so no field needs quoting.
+        csv.push_str(&format!(
+            "{},{},{},{},{},{},{},{},{}\n",
+            row.month.format("%Y-%m"),
+            row.tenant_id,
+            row.executions,
+            row.tool_calls,
+            row.input_tokens,
+            row.output_tokens,
+            row.llm_calls,
+            row.storage_bytes,
+            row.aggregated_at.to_rfc3339(),
+        ));
+    }
+    csv
+}
+
+/// Persistence for the monthly summaries.
+#[async_trait]
+pub trait UsageReportRepository: Send + Sync {
+    /// Recompute every tenant's row for the month starting on `month` from
+    /// the raw records. Idempotent; returns the number of rows written.
+    async fn aggregate_month(&self, month: NaiveDate) -> Result<u64, RepositoryError>;
+
+    /// Rows for the month starting on `month`, for one tenant or all of them.
+    async fn monthly_summary(
+        &self,
+        month: NaiveDate,
+        tenant_id: Option<&TenantId>,
+    ) -> Result<Vec<MonthlyUsageSummary>, RepositoryError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn months_parse_bound_and_render() {
+        let month = parse_month("2026-12").unwrap();
+        assert_eq!(month, NaiveDate::from_ymd_opt(2026, 12, 1).unwrap());
+        assert!(parse_month("2026-13").is_none());
+        assert!(parse_month("december").is_none());
+
+        let (start, end) = month_bounds(month);
+        assert_eq!(start.to_rfc3339(), "2026-12-01T00:00:00+00:00");
+        assert_eq!(end.to_rfc3339(), "2027-01-01T00:00:00+00:00");
+
+        let csv = to_csv(&[MonthlyUsageSummary {
+            month,
+            tenant_id: "acme".to_string(),
+            executions: 12,
+            tool_calls: 40,
+            input_tokens: 1000,
+            output_tokens: 250,
+            llm_calls: 30,
+            storage_bytes: 1 << 30,
+            aggregated_at: end,
+        }]);
+        let lines: Vec<_> = csv.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[1],
+            "2026-12,acme,12,40,1000,250,30,1073741824,2027-01-01T00:00:00+00:00"
+        );
+    }
+}
@@ -34,6 +34,7 @@ pub use crate::domain::shared_kernel::WorkflowId;
 
 use crate::domain::agent::ImagePullPolicy;
 use crate::domain::execution::{ExecutionId, ExecutionStatus};
+use crate::domain::plugin::PluginDigest;
 use crate::domain::tenant::TenantId;
 use chrono::{DateTime, Utc};
 use schemars::JsonSchema;
@@ -293,6 +294,41 @@ impl Workflow {
                         }
                     }
                 }
+                StateKind::ForEach {
+                    source,
+                    item_var,
+                    agent,
+                    max_concurrency,
+                    ..
+                } => {
+                    let invalid = |detail: String| WorkflowError::InvalidForEachState {
+                        state: state_name.clone(),
+                        detail,
+                    };
+                    if source.trim().is_empty() {
+                        return Err(invalid("ForEach.source cannot be empty".to_string()));
+                    }
+                    if agent.trim().is_empty() {
+                        return Err(invalid("ForEach.agent cannot be empty".to_string()));
+                    }
+                    if !is_identifier(item_var) {
+                        return Err(invalid(format!(
+                            "ForEach.item_var '{item_var}' must be a simple identifier"
+                        )));
+                    }
+                    if RESERVED_FOREACH_VARS.contains(&item_var.as_str()) {
+                        return Err(invalid(format!(
+                            "ForEach.item_var '{item_var}' shadows a reserved blackboard key"
+                        )));
+                    }
+                    if let Some(limit) = max_concurrency {
+                        if *limit == 0 || *limit > MAX_FOREACH_CONCURRENCY {
+                            return Err(invalid(format!(
+                                "ForEach.max_concurrency must be between 1 and {MAX_FOREACH_CONCURRENCY}, got {limit}"
+                            )));
+                        }
+                    }
+                }
                 StateKind::Subworkflow {
                     workflow_id,
                     mode,
@@ -331,6 +367,95 @@ impl Workflow {
             }
         }
 
+        // Validate: Declared variables have usable names and well-typed defaults,
+        // and every blackboard key a Subworkflow writes is declared.
+        for (key, variable) in &spec.variables {
+            let invalid = |detail: String| WorkflowError::InvalidVariable {
+                key: key.clone(),
+                detail,
+            };
+            if !is_identifier(key) {
+                return Err(invalid("name must be a simple identifier".to_string()));
+            }
+            if RESERVED_VARIABLE_NAMES.contains(&key.as_str()) {
+                return Err(invalid("name is a reserved blackboard key".to_string()));
+            }
+            if let Some(default) = &variable.default {
+                variable
+                    .check(key, default)
+                    .map_err(|e| invalid(format!("default does not match: {e}")))?;
+            }
+        }
+        if !spec.variables.is_empty() {
+            for (state_name, state) in &spec.states {
+                if let StateKind::Subworkflow {
+                    result_key: Some(result_key),
+                    ..
+                } = &state.kind
+                {
+                    if !spec.variables.contains_key(result_key) {
+                        return Err(WorkflowError::InvalidSubworkflowState {
+                            state: state_name.clone(),
+                            detail: format!(
+                                "result_key '{result_key}' is not declared in spec.variables"
+                            ),
+                        });
+                    }
+                }
+            }
+        }
+
+        // Validate: Handoff outputs are well-formed, and every consumed handoff
+        // is declared by another state that can run before the consumer.
+        for (state_name, handoffs) in &spec.handoffs {
+            let invalid = |detail: String| WorkflowError::InvalidHandoff {
+                state: state_name.clone(),
+                detail,
+            };
+            if !spec.states.contains_key(state_name) {
+                return Err(invalid("state is not defined in spec.states".to_string()));
+            }
+            for (name, output) in &handoffs.outputs {
+                if !is_identifier(name) {
+                    return Err(invalid(format!(
+                        "output '{name}' must be a simple identifier"
+                    )));
+                }
+                if let Some(schema) = &output.schema {
+                    jsonschema::validator_for(schema).map_err(|e| {
+                        invalid(format!("output '{name}' has an invalid schema: {e}"))
+                    })?;
+                }
+            }
+            for reference in &handoffs.consumes {
+                let (producer, output) = parse_handoff_ref(reference).ok_or_else(|| {
+                    invalid(format!(
+                        "consumed handoff '{reference}' must have the form STATE.output"
+                    ))
+                })?;
+                let producer = StateName::new(producer)?;
+                if &producer == state_name {
+                    return Err(invalid(format!(
+                        "state cannot consume its own output '{reference}'"
+                    )));
+                }
+                let declared = spec
+                    .handoffs
+                    .get(&producer)
+                    .is_some_and(|h| h.outputs.contains_key(output));
+                if !declared {
+                    return Err(invalid(format!(
+                        "consumed handoff '{reference}' is not declared in the outputs of state '{producer}'"
+                    )));
+                }
+                if !spec.reachable(&producer, state_name) {
+                    return Err(invalid(format!(
+                        "consumed handoff '{reference}' is produced by a state that never runs before this one"
+                    )));
+                }
+            }
+        }
+
         // Validate: All ContainerVolumeMount names resolve to declared spec.storage.shared_volumes
         // (only enforced when spec.storage.shared_volumes is non-empty — opt-in per ADR-050)
         if !spec.storage.shared_volumes.is_empty() {
@@ -654,6 +779,19 @@ pub struct WorkflowSpec {
     #[serde(default)]
     pub context: HashMap<String, serde_json::Value>,
 
+    /// Typed blackboard variables (`spec.variables`).
+    ///
+    /// Once any variable is declared, blackboard writes must use a declared
+    /// key and match its type, and templates may only reference declared keys
+    /// as `blackboard.<key>`. Workflows without declarations stay untyped.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub variables: HashMap<String, WorkflowVariable>,
+
+    /// Typed handoffs per state, declared in the manifest as `outputs:` and
+    /// `consumes:` on each state. States without declarations are absent.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub handoffs: HashMap<StateName, StateHandoffs>,
+
     /// State machine definition (state_name -> state)
     pub states: HashMap<StateName, WorkflowState>,
 
@@ -668,12 +806,121 @@ pub struct WorkflowSpec {
     /// Default: 50. Ceiling: 100.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub max_total_transitions: Option<u32>,
+
+    /// Optional concurrency group shared with agent manifests
+    /// (`spec.concurrency`). Enforced when the execution is started.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<crate::domain::concurrency::ConcurrencySpec>,
 }
 
 fn is_default_storage(s: &WorkflowStorageSpec) -> bool {
     s.workspace.is_none() && s.shared_volumes.is_empty()
 }
 
+impl WorkflowSpec {
+    /// Check a blackboard write against `spec.variables`.
+    ///
+    /// Workflows that declare no variables accept any key and value.
+    pub fn check_blackboard_write(
+        &self,
+        key: &str,
+        value: &serde_json::Value,
+    ) -> Result<(), WorkflowError> {
+        if self.variables.is_empty() {
+            return Ok(());
+        }
+        let variable = self
+            .variables
+            .get(key)
+            .ok_or_else(|| WorkflowError::UndeclaredVariable(key.to_string()))?;
+        variable.check(key, value)
+    }
+
+    /// Extract the handoffs `state_name` declares from the output it exited
+    /// with. The output must be a JSON object (or a string holding one) with a
+    /// matching value for every declared output; otherwise nothing is handed off.
+    pub fn extract_handoffs(
+        &self,
+        state_name: &StateName,
+        output: &serde_json::Value,
+    ) -> Result<HashMap<String, serde_json::Value>, WorkflowError> {
+        let Some(declared) = self
+            .handoffs
+            .get(state_name)
+            .filter(|h| !h.outputs.is_empty())
+        else {
+            return Ok(HashMap::new());
+        };
+        let parsed;
+        let object = match output {
+            serde_json::Value::Object(map) => map,
+            serde_json::Value::String(text) => {
+                parsed = serde_json::from_str::<serde_json::Value>(text).ok();
+                match &parsed {
+                    Some(serde_json::Value::Object(map)) => map,
+                    _ => {
+                        return Err(WorkflowError::HandoffMismatch {
+                            handoff: state_name.to_string(),
+                            detail: "state output is not a JSON object".to_string(),
+                        })
+                    }
+                }
+            }
+            other => {
+                return Err(WorkflowError::HandoffMismatch {
+                    handoff: state_name.to_string(),
+                    detail: format!("state output is {}, not an object", json_type_name(other)),
+                })
+            }
+        };
+
+        let mut handoffs = HashMap::with_capacity(declared.outputs.len());
+        for (name, declaration) in &declared.outputs {
+            let handoff = format!("{state_name}.{name}");
+            let value = object
+                .get(name)
+                .ok_or_else(|| WorkflowError::HandoffMismatch {
+                    handoff: handoff.clone(),
+                    detail: "missing from state output".to_string(),
+                })?;
+            declaration.check(&handoff, value)?;
+            handoffs.insert(name.clone(), value.clone());
+        }
+        Ok(handoffs)
+    }
+
+    /// Whether `to` can be entered after `from` by following transitions.
+    fn reachable(&self, from: &StateName, to: &StateName) -> bool {
+        let mut seen = std::collections::HashSet::new();
+        let mut pending = vec![from];
+        while let Some(state) = pending.pop() {
+            let Some(state) = self.states.get(state) else {
+                continue;
+            };
+            for transition in &state.transitions {
+                if &transition.target == to {
+                    return true;
+                }
+                if seen.insert(&transition.target) {
+                    pending.push(&transition.target);
+                }
+            }
+        }
+        false
+    }
+
+    /// Set every declared default whose key is not already on `blackboard`.
+    pub fn apply_variable_defaults(&self, blackboard: &mut Blackboard) {
+        for (key, variable) in &self.variables {
+            if let Some(default) = &variable.default {
+                if !blackboard.contains_key(key) {
+                    blackboard.set(key.clone(), default.clone());
+                }
+            }
+        }
+    }
+}
+
 /// Individual state in the workflow FSM
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WorkflowState {
@@ -694,6 +941,20 @@ pub struct WorkflowState {
     pub max_state_visits: Option<u32>,
 }
 
+impl WorkflowState {
+    /// How long an execution may stay in this state before the workflow
+    /// watchdog reports it stuck: the state's `timeout`, else `default`.
+    /// Human states wait on people and have no expectation unless they set a
+    /// `timeout`.
+    pub fn stuck_threshold(&self, default: Duration) -> Option<Duration> {
+        match (&self.kind, self.timeout) {
+            (_, Some(timeout)) => Some(timeout),
+            (StateKind::Human { .. }, None) => None,
+            (_, None) => Some(default),
+        }
+    }
+}
+
 // ============================================================================
 // Value Objects: State Kinds
 // ============================================================================
@@ -765,6 +1026,54 @@ pub enum ParallelCompletionStrategy {
     BestEffort,
 }
 
+/// How a `ForEach` state folds its per-item results back onto the blackboard
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ForEachCollectStrategy {
+    /// Ordered array of per-item outputs, index-aligned with the source collection
+    #[default]
+    List,
+    /// Per-item outputs that are arrays are concatenated into a single array
+    Flatten,
+    /// Per-item outputs are dropped; only success/failure counts are recorded
+    Discard,
+}
+
+/// Default blackboard variable each `ForEach` item is bound to.
+pub const DEFAULT_FOREACH_ITEM_VAR: &str = "item";
+
+/// Upper bound on concurrent fan-out activities for a single `ForEach` state.
+pub const MAX_FOREACH_CONCURRENCY: u32 = 64;
+
+/// Blackboard roots that a `ForEach` item variable must not shadow.
+const RESERVED_FOREACH_VARS: &[&str] = &["workflow", "blackboard", "state", "input", "index"];
+
+/// Blackboard keys the worker sets itself, which `spec.variables` must not declare.
+const RESERVED_VARIABLE_NAMES: &[&str] = &[
+    "instruction",
+    "input",
+    "iteration_number",
+    "previous_error",
+    "context",
+    "workflow",
+];
+
+/// `[A-Za-z_][A-Za-z0-9_]*`
+fn is_identifier(name: &str) -> bool {
+    name.chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn default_foreach_item_var() -> String {
+    DEFAULT_FOREACH_ITEM_VAR.to_string()
+}
+
+fn default_foreach_completion() -> ParallelCompletionStrategy {
+    ParallelCompletionStrategy::AllSucceed
+}
+
 /// Execution mode for a subworkflow invocation (ADR-065)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
@@ -937,6 +1246,40 @@ pub enum StateKind {
         completion: ParallelCompletionStrategy,
     },
 
+    /// Run an agent once per item of a blackboard collection (fan-out/fan-in)
+    ///
+    /// `source` is a Handlebars expression that must resolve to a JSON array.
+    /// Each item is bound to `item_var` (and its position to `index`) when
+    /// rendering `input`. Items run as parallel activities, at most
+    /// `max_concurrency` at a time, and results are folded onto
+    /// `STATE_NAME.output` according to `collect`.
+    ForEach {
+        /// Expression resolving to the collection to iterate, e.g. `{{triage.output.files}}`
+        source: String,
+
+        /// Variable name each item is bound to inside `input` (default: `item`)
+        #[serde(default = "default_foreach_item_var")]
+        item_var: String,
+
+        /// Agent identifier (name or ID) executed for each item
+        agent: String,
+
+        /// Per-item input template (Handlebars syntax)
+        input: String,
+
+        /// Maximum number of items in flight at once (default: all, capped at 64)
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        max_concurrency: Option<u32>,
+
+        /// How per-item outputs are folded back onto the blackboard
+        #[serde(default)]
+        collect: ForEachCollectStrategy,
+
+        /// How per-item outcomes determine the state outcome (default: all_succeed)
+        #[serde(default = "default_foreach_completion")]
+        completion: ParallelCompletionStrategy,
+    },
+
     /// Invoke another workflow as a child execution (ADR-065)
     ///
     /// In `Blocking` mode the parent waits for the child to complete and writes
@@ -1199,6 +1542,9 @@ pub enum TransitionCondition {
 
     /// Custom boolean expression (Handlebars)
     Custom { expression: String },
+
+    /// Decided by a WASM plugin's `evaluate` export
+    Plugin { plugin: PluginDigest },
 }
 
 // ============================================================================
@@ -1288,6 +1634,203 @@ impl Blackboard {
     }
 }
 
+/// Value type of a declared blackboard variable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum VariableType {
+    String,
+    Number,
+    Integer,
+    Boolean,
+    Object,
+    Array,
+    /// Any JSON value, including `null`.
+    Any,
+}
+
+impl VariableType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VariableType::String => "string",
+            VariableType::Number => "number",
+            VariableType::Integer => "integer",
+            VariableType::Boolean => "boolean",
+            VariableType::Object => "object",
+            VariableType::Array => "array",
+            VariableType::Any => "any",
+        }
+    }
+
+    /// Whether `value` is of this type. `null` is only accepted by `Any`.
+    pub fn accepts(&self, value: &serde_json::Value) -> bool {
+        match self {
+            VariableType::String => value.is_string(),
+            VariableType::Number => value.is_number(),
+            VariableType::Integer => value.is_i64() || value.is_u64(),
+            VariableType::Boolean => value.is_boolean(),
+            VariableType::Object => value.is_object(),
+            VariableType::Array => value.is_array(),
+            VariableType::Any => true,
+        }
+    }
+}
+
+impl std::fmt::Display for VariableType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// JSON type name of `value`, as reported in type mismatch errors.
+fn json_type_name(value: &serde_json::Value) -> &'static str {
+    match value {
+        serde_json::Value::Null => "null",
+        serde_json::Value::Bool(_) => "boolean",
+        serde_json::Value::Number(n) if n.is_f64() => "number",
+        serde_json::Value::Number(_) => "integer",
+        serde_json::Value::String(_) => "string",
+        serde_json::Value::Array(_) => "array",
+        serde_json::Value::Object(_) => "object",
+    }
+}
+
+/// A blackboard variable declared under `spec.variables`.
+///
+/// ```yaml
+/// spec:
+///   variables:
+///     threshold:
+///       type: number
+///       default: 0.8
+///     reviewers:
+///       type: array
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WorkflowVariable {
+    #[serde(rename = "type")]
+    pub var_type: VariableType,
+
+    /// Seeded onto the blackboard at start unless the caller supplies a value.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<serde_json::Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl WorkflowVariable {
+    /// Check that `value` may be written to the variable declared as `key`.
+    pub fn check(&self, key: &str, value: &serde_json::Value) -> Result<(), WorkflowError> {
+        if self.var_type.accepts(value) {
+            Ok(())
+        } else {
+            Err(WorkflowError::VariableTypeMismatch {
+                key: key.to_string(),
+                expected: self.var_type,
+                found: json_type_name(value),
+            })
+        }
+    }
+}
+
+/// A typed output a state hands to downstream states, declared under the
+/// state's `outputs:`.
+///
+/// ```yaml
+/// states:
+///   RESEARCH:
+///     kind: Agent
+///     agent: researcher
+///     outputs:
+///       findings:
+///         type: object
+///         schema: { required: [sources] }
+///     transitions: [{ condition: always, target: WRITE }]
+///   WRITE:
+///     kind: Agent
+///     agent: writer
+///     consumes: [RESEARCH.findings]
+///     input: "Write up {{handoff.RESEARCH.findings}}"
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HandoffOutput {
+    #[serde(rename = "type", default = "default_handoff_type")]
+    pub var_type: VariableType,
+
+    /// JSON Schema the value must also satisfy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<serde_json::Value>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+fn default_handoff_type() -> VariableType {
+    VariableType::Any
+}
+
+impl HandoffOutput {
+    /// Check that `value` satisfies this declaration, reported as `handoff`.
+    pub fn check(&self, handoff: &str, value: &serde_json::Value) -> Result<(), WorkflowError> {
+        let mismatch = |detail: String| WorkflowError::HandoffMismatch {
+            handoff: handoff.to_string(),
+            detail,
+        };
+        if !self.var_type.accepts(value) {
+            return Err(mismatch(format!(
+                "expected a value of type {}, got {}",
+                self.var_type,
+                json_type_name(value)
+            )));
+        }
+        if let Some(schema) = &self.schema {
+            let validator = jsonschema::validator_for(schema)
+                .map_err(|e| mismatch(format!("schema is invalid: {e}")))?;
+            let errors: Vec<String> = validator
+                .iter_errors(value)
+                .map(|e| e.to_string())
+                .collect();
+            if !errors.is_empty() {
+                return Err(mismatch(errors.join("; ")));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Handoff declarations of a single state: the outputs it produces and the
+/// `STATE.output` references it consumes.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct StateHandoffs {
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub outputs: HashMap<String, HandoffOutput>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consumes: Vec<String>,
+}
+
+impl StateHandoffs {
+    pub fn is_empty(&self) -> bool {
+        self.outputs.is_empty() && self.consumes.is_empty()
+    }
+}
+
+/// Split a `STATE.output` handoff reference. State names may contain dots,
+/// so the output name is everything after the last one.
+pub fn parse_handoff_ref(reference: &str) -> Option<(&str, &str)> {
+    let (state, output) = reference.rsplit_once('.')?;
+    (!state.is_empty() && is_identifier(output)).then_some((state, output))
+}
+
+/// A handoff value captured when its producing state exited.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkflowHandoff {
+    pub state_name: String,
+    pub output_name: String,
+    pub value: serde_json::Value,
+    pub produced_at: DateTime<Utc>,
+}
+
 // ============================================================================
 // Entities: Workflow Execution State
 // ============================================================================
@@ -1371,6 +1914,57 @@ impl WorkflowExecution {
     pub fn get_state_output(&self, state: &StateName) -> Option<&serde_json::Value> {
         self.state_outputs.get(state)
     }
+
+    /// The state this execution is in and when it was entered.
+    ///
+    /// `current_state` is only rewritten on completion, so the newest open
+    /// entry of the transition history wins; without one the persisted
+    /// `current_state` and `last_transition_at` are used.
+    pub fn active_state(&self, transitions: &[WorkflowTransitionRecord]) -> ActiveState {
+        transitions
+            .iter()
+            .filter(|record| record.exited_at.is_none())
+            .max_by_key(|record| record.sequence)
+            .map(|record| ActiveState {
+                state_name: record.state_name.clone(),
+                entered_at: record.entered_at,
+            })
+            .unwrap_or_else(|| ActiveState {
+                state_name: self.current_state.as_str().to_string(),
+                entered_at: self.last_transition_at,
+            })
+    }
+}
+
+/// See [`WorkflowExecution::active_state`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ActiveState {
+    pub state_name: String,
+    pub entered_at: DateTime<Utc>,
+}
+
+/// What the workflow watchdog does with an execution that has overstayed a
+/// state (`spec.workflow_watchdog.on_stuck`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StuckStateAction {
+    /// Publish `WorkflowStateStuck` and leave the execution running.
+    #[default]
+    Notify,
+    /// Terminate the Temporal workflow and mark the execution failed.
+    Fail,
+    /// Ask the worker to re-run the state.
+    Retry,
+}
+
+impl StuckStateAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Notify => "notify",
+            Self::Fail => "fail",
+            Self::Retry => "retry",
+        }
+    }
 }
 
 // ============================================================================
@@ -1396,6 +1990,110 @@ pub struct WorkflowExecutionEventRecord {
     pub recorded_at: chrono::DateTime<chrono::Utc>,
 }
 
+/// Upper bound on the characters kept from a state's output in
+/// [`WorkflowTransitionRecord::output_summary`].
+pub const MAX_TRANSITION_OUTPUT_SUMMARY_CHARS: usize = 512;
+
+/// One visited FSM state in a workflow execution's compact transition history.
+///
+/// Written by the `TemporalEventListener` as `WorkflowStateEntered` /
+/// `WorkflowStateExited` callbacks arrive, so the audit trail survives after
+/// Temporal's own history retention expires.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct WorkflowTransitionRecord {
+    /// Temporal sequence number of the `WorkflowStateEntered` event. Orders
+    /// the history and makes re-delivered callbacks idempotent.
+    pub sequence: i64,
+    /// State that was entered.
+    pub state_name: String,
+    /// Transition condition reported by the worker for the edge that led here
+    /// (e.g. `on_success`, `score_above`). `None` for the initial state.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub triggered_by: Option<String>,
+    pub entered_at: DateTime<Utc>,
+    /// Set once the state exits; `None` while the state is active.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exited_at: Option<DateTime<Utc>>,
+    /// Truncated rendering of the state output (see [`summarize_state_output`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output_summary: Option<String>,
+}
+
+/// Render a state output for [`WorkflowTransitionRecord::output_summary`].
+///
+/// Strings are kept verbatim and other values are serialised as compact JSON;
+/// either is cut to [`MAX_TRANSITION_OUTPUT_SUMMARY_CHARS`] characters with a
+/// trailing ellipsis. `null` yields `None`.
+pub fn summarize_state_output(output: &serde_json::Value) -> Option<String> {
+    let rendered = match output {
+        serde_json::Value::Null => return None,
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    };
+    if rendered.chars().count() <= MAX_TRANSITION_OUTPUT_SUMMARY_CHARS {
+        return Some(rendered);
+    }
+    let mut truncated: String = rendered
+        .chars()
+        .take(MAX_TRANSITION_OUTPUT_SUMMARY_CHARS)
+        .collect();
+    truncated.push('…');
+    Some(truncated)
+}
+
+/// A blackboard value that differs between two snapshots.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BlackboardChange {
+    /// Dot-separated key path; empty when the snapshots are not both objects.
+    pub path: String,
+    /// `None` when the key was added.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before: Option<serde_json::Value>,
+    /// `None` when the key was removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after: Option<serde_json::Value>,
+}
+
+/// Key-level differences between two blackboard snapshots, ordered by path.
+///
+/// Nested objects are compared key by key; any other value, arrays included,
+/// is reported as a whole when it changes.
+pub fn diff_blackboards(
+    before: &serde_json::Value,
+    after: &serde_json::Value,
+) -> Vec<BlackboardChange> {
+    let mut changes = Vec::new();
+    diff_blackboard_values(String::new(), Some(before), Some(after), &mut changes);
+    changes
+}
+
+fn diff_blackboard_values(
+    path: String,
+    before: Option<&serde_json::Value>,
+    after: Option<&serde_json::Value>,
+    changes: &mut Vec<BlackboardChange>,
+) {
+    match (before, after) {
+        (Some(serde_json::Value::Object(before)), Some(serde_json::Value::Object(after))) => {
+            let keys: BTreeSet<&String> = before.keys().chain(after.keys()).collect();
+            for key in keys {
+                let child = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                diff_blackboard_values(child, before.get(key), after.get(key), changes);
+            }
+        }
+        (before, after) if before != after => changes.push(BlackboardChange {
+            path,
+            before: before.cloned(),
+            after: after.cloned(),
+        }),
+        _ => {}
+    }
+}
+
 // ============================================================================
 // Domain Errors
 // ============================================================================
@@ -1452,8 +2150,30 @@ pub enum WorkflowError {
     #[error("Invalid subworkflow state configuration in state '{state}': {detail}")]
     InvalidSubworkflowState { state: StateName, detail: String },
 
+    #[error("Invalid ForEach state configuration in state '{state}': {detail}")]
+    InvalidForEachState { state: StateName, detail: String },
+
     #[error("Invalid workflow scope: {0}")]
     InvalidScope(String),
+
+    #[error("Invalid variable '{key}' in spec.variables: {detail}")]
+    InvalidVariable { key: String, detail: String },
+
+    #[error("Blackboard key '{0}' is not declared in spec.variables")]
+    UndeclaredVariable(String),
+
+    #[error("Blackboard variable '{key}' expects a value of type {expected}, got {found}")]
+    VariableTypeMismatch {
+        key: String,
+        expected: VariableType,
+        found: &'static str,
+    },
+
+    #[error("Invalid handoff declaration in state '{state}': {detail}")]
+    InvalidHandoff { state: StateName, detail: String },
+
+    #[error("Handoff '{handoff}' does not match its declaration: {detail}")]
+    HandoffMismatch { handoff: String, detail: String },
 }
 
 // ============================================================================
@@ -1669,9 +2389,12 @@ mod tests {
             spec: WorkflowSpec {
                 initial_state: StateName::new("START").unwrap(),
                 context: std::collections::HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
                 states,
                 storage: Default::default(),
                 max_total_transitions: None,
+                concurrency: None,
             },
             created_at: Utc::now(),
             updated_at: None,
@@ -1704,9 +2427,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states: HashMap::new(),
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
 
         let result = Workflow::new(metadata, spec);
@@ -1744,9 +2470,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
 
         let result = Workflow::new(metadata, spec);
@@ -1797,9 +2526,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("BUILD").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
 
         let result = Workflow::new(metadata, spec);
@@ -1860,9 +2592,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("TEST").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
 
         let result = Workflow::new(metadata, spec);
@@ -1917,9 +2652,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("BUILD").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
 
         let result = Workflow::new(metadata, spec);
@@ -1959,9 +2697,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("TRIGGER").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
         let result = Workflow::new(metadata, spec);
         assert!(result.is_err());
@@ -2002,9 +2743,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("TRIGGER").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
         let result = Workflow::new(metadata, spec);
         assert!(result.is_err());
@@ -2045,9 +2789,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("TRIGGER").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
         let result = Workflow::new(metadata, spec);
         assert!(result.is_ok());
@@ -2083,9 +2830,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("TRIGGER").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
         let result = Workflow::new(metadata, spec);
         assert!(result.is_ok());
@@ -2126,9 +2876,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
 
         let workflow = Workflow::new(metadata, spec).expect("valid workflow");
@@ -2175,9 +2928,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
 
         let workflow = Workflow::new(metadata, spec).expect("valid workflow");
@@ -2226,4 +2982,192 @@ mod tests {
             "last_output must be null when final_output is None"
         );
     }
+
+    fn foreach_workflow(
+        item_var: &str,
+        max_concurrency: Option<u32>,
+    ) -> Result<Workflow, WorkflowError> {
+        let metadata = WorkflowMetadata {
+            name: "test-foreach".to_string(),
+            version: Some("1.0.0".to_string()),
+            description: None,
+            labels: HashMap::new(),
+            annotations: HashMap::new(),
+            input_schema: None,
+            output_schema: None,
+            output_template: None,
+        };
+        let mut states = HashMap::new();
+        states.insert(
+            StateName::new("REVIEW_EACH").unwrap(),
+            WorkflowState {
+                kind: StateKind::ForEach {
+                    source: "{{triage.output.files}}".to_string(),
+                    item_var: item_var.to_string(),
+                    agent: "reviewer".to_string(),
+                    input: "Review {{file}}".to_string(),
+                    max_concurrency,
+                    collect: ForEachCollectStrategy::List,
+                    completion: ParallelCompletionStrategy::AllSucceed,
+                },
+                transitions: vec![],
+                timeout: None,
+                max_state_visits: None,
+            },
+        );
+        let spec = WorkflowSpec {
+            initial_state: StateName::new("REVIEW_EACH").unwrap(),
+            context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
+            states,
+            storage: Default::default(),
+            max_total_transitions: None,
+            concurrency: None,
+        };
+        Workflow::new(metadata, spec)
+    }
+
+    #[test]
+    fn test_foreach_accepts_valid_configuration() {
+        assert!(foreach_workflow("file", Some(4)).is_ok());
+    }
+
+    #[test]
+    fn test_foreach_rejects_reserved_item_var() {
+        let err = foreach_workflow("workflow", None).unwrap_err();
+        assert!(matches!(err, WorkflowError::InvalidForEachState { .. }));
+        assert!(err.to_string().contains("reserved"));
+    }
+
+    #[test]
+    fn test_foreach_rejects_out_of_range_concurrency() {
+        assert!(foreach_workflow("file", Some(0)).is_err());
+        assert!(foreach_workflow("file", Some(MAX_FOREACH_CONCURRENCY + 1)).is_err());
+    }
+
+    #[test]
+    fn summarize_state_output_truncates_long_values() {
+        assert_eq!(summarize_state_output(&serde_json::Value::Null), None);
+        assert_eq!(
+            summarize_state_output(&serde_json::json!("done")),
+            Some("done".to_string())
+        );
+        assert_eq!(
+            summarize_state_output(&serde_json::json!({"score": 0.9})),
+            Some(r#"{"score":0.9}"#.to_string())
+        );
+
+        let long = "x".repeat(MAX_TRANSITION_OUTPUT_SUMMARY_CHARS + 10);
+        let summary = summarize_state_output(&serde_json::json!(long)).unwrap();
+        assert_eq!(
+            summary.chars().count(),
+            MAX_TRANSITION_OUTPUT_SUMMARY_CHARS + 1
+        );
+        assert!(summary.ends_with('…'));
+    }
+
+    #[test]
+    fn active_state_prefers_open_transition_and_human_states_have_no_threshold() {
+        let system = WorkflowState {
+            kind: StateKind::System {
+                command: "echo".to_string(),
+                env: HashMap::new(),
+                workdir: None,
+            },
+            transitions: vec![],
+            timeout: None,
+            max_state_visits: None,
+        };
+        let human = WorkflowState {
+            kind: StateKind::Human {
+                prompt: "approve?".to_string(),
+                default_response: None,
+            },
+            ..system.clone()
+        };
+        let default = Duration::from_secs(600);
+        assert_eq!(system.stuck_threshold(default), Some(default));
+        assert_eq!(human.stuck_threshold(default), None);
+        let timed_human = WorkflowState {
+            timeout: Some(Duration::from_secs(60)),
+            ..human
+        };
+        assert_eq!(
+            timed_human.stuck_threshold(default),
+            Some(Duration::from_secs(60))
+        );
+
+        let mut states = HashMap::new();
+        states.insert(StateName::new("START").unwrap(), system);
+        let workflow = Workflow::new(
+            WorkflowMetadata {
+                name: "watchdog-test".to_string(),
+                version: None,
+                description: None,
+                labels: HashMap::new(),
+                annotations: HashMap::new(),
+                input_schema: None,
+                output_schema: None,
+                output_template: None,
+            },
+            WorkflowSpec {
+                initial_state: StateName::new("START").unwrap(),
+                context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
+                states,
+                storage: Default::default(),
+                max_total_transitions: None,
+                concurrency: None,
+            },
+        )
+        .expect("valid workflow");
+        let execution =
+            WorkflowExecution::new(&workflow, ExecutionId::new(), serde_json::json!({}));
+        assert_eq!(
+            execution.active_state(&[]),
+            ActiveState {
+                state_name: "START".to_string(),
+                entered_at: execution.last_transition_at,
+            }
+        );
+
+        let entered_at = execution.started_at + chrono::Duration::seconds(5);
+        let record = |sequence, state_name: &str, exited| WorkflowTransitionRecord {
+            sequence,
+            state_name: state_name.to_string(),
+            triggered_by: None,
+            entered_at,
+            exited_at: exited,
+            output_summary: None,
+        };
+        let history = [
+            record(1, "START", Some(entered_at)),
+            record(2, "REVIEW", None),
+        ];
+        assert_eq!(execution.active_state(&history).state_name, "REVIEW");
+    }
+
+    #[test]
+    fn diff_blackboards_reports_nested_key_changes_in_path_order() {
+        let before = serde_json::json!({
+            "draft": "v1",
+            "review": {"score": 0.4, "notes": ["typo"]},
+            "stale": true,
+        });
+        let after = serde_json::json!({
+            "draft": "v1",
+            "review": {"score": 0.9, "notes": ["typo"], "approved": true},
+        });
+        let changes = diff_blackboards(&before, &after);
+
+        let paths: Vec<&str> = changes.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, ["review.approved", "review.score", "stale"]);
+        assert_eq!(changes[0].before, None);
+        assert_eq!(changes[0].after, Some(serde_json::json!(true)));
+        assert_eq!(changes[1].before, Some(serde_json::json!(0.4)));
+        assert_eq!(changes[2].after, None);
+        assert!(diff_blackboards(&after, &after).is_empty());
+    }
 }
@@ -30,10 +30,109 @@
 //!     mode: "iterative"
 //!     max_iterations: 10
 //! ```
+//!
+//! # Base Manifests
+//!
+//! A manifest file may declare `extends:` to inherit from a base manifest,
+//! either another file (resolved relative to the extending file) or a deployed
+//! agent (`agent:<name>`). Bases may themselves extend further files. The
+//! effective manifest is the base deep-merged with the extending manifest:
+//!
+//! - mappings merge key by key, recursively;
+//! - scalars and sequences in the extending manifest replace the base value;
+//! - an explicit `null` removes the inherited key;
+//! - `metadata.name` is never inherited.
+//!
+//! Bases only need to be valid once merged; validation runs on the result.
+//! Deployed agents are stored fully merged, so an `agent:` base ends the chain.
 
 use crate::domain::agent::*;
-use anyhow::{anyhow, Context, Result};
-use std::path::Path;
+use anyhow::{anyhow, bail, Context, Result};
+use serde_yaml::Value;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+
+/// Top-level manifest key naming the base manifest.
+pub const EXTENDS_KEY: &str = "extends";
+
+/// `extends:` prefix referring to a deployed agent instead of a file.
+pub const AGENT_REFERENCE_PREFIX: &str = "agent:";
+
+/// Longest `extends` chain followed before giving up.
+const MAX_EXTENDS_DEPTH: usize = 16;
+
+// ============================================================================
+// Inheritance
+// ============================================================================
+
+/// The file layers of an `extends` chain, loaded but not yet merged.
+#[derive(Debug, Clone)]
+pub struct ManifestChain {
+    /// Manifest documents with `extends` removed, most derived first.
+    pub layers: Vec<Value>,
+
+    /// Name of the deployed agent the last file layer extends, if any.
+    pub agent_base: Option<String>,
+}
+
+impl ManifestChain {
+    /// Merge every layer onto `agent_base` (the deployed base manifest, when
+    /// the chain ends in an `agent:` reference) and return the effective
+    /// manifest document.
+    pub fn merge(self, agent_base: Option<Value>) -> Value {
+        let mut layers = self.layers.into_iter().rev();
+        let mut merged = match agent_base {
+            Some(base) => base,
+            None => layers.next().unwrap_or(Value::Null),
+        };
+        for layer in layers {
+            remove_metadata_name(&mut merged);
+            merged = merge_values(merged, layer);
+        }
+        merged
+    }
+}
+
+/// Deep-merge `overlay` onto `base` using the base-manifest override rules.
+pub fn merge_values(base: Value, overlay: Value) -> Value {
+    match (base, overlay) {
+        (Value::Mapping(mut base), Value::Mapping(overlay)) => {
+            for (key, value) in overlay {
+                if value.is_null() {
+                    base.remove(&key);
+                    continue;
+                }
+                let merged = match base.remove(&key) {
+                    Some(existing) => merge_values(existing, value),
+                    None => value,
+                };
+                base.insert(key, merged);
+            }
+            Value::Mapping(base)
+        }
+        (_, overlay) => overlay,
+    }
+}
+
+fn remove_metadata_name(manifest: &mut Value) {
+    if let Some(metadata) = manifest.get_mut("metadata").and_then(Value::as_mapping_mut) {
+        metadata.remove("name");
+    }
+}
+
+/// Remove and return the `extends` reference of a manifest document.
+fn take_extends(document: &mut Value) -> Result<Option<String>> {
+    let Some(mapping) = document.as_mapping_mut() else {
+        return Ok(None);
+    };
+    match mapping.remove(EXTENDS_KEY) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(reference)) if !reference.trim().is_empty() => {
+            Ok(Some(reference.trim().to_string()))
+        }
+        Some(_) => bail!("'{EXTENDS_KEY}' must be a file path or '{AGENT_REFERENCE_PREFIX}<name>'"),
+    }
+}
 
 // ============================================================================
 // Parser API
@@ -42,10 +141,22 @@ use std::path::Path;
 pub struct AgentManifestParser;
 
 impl AgentManifestParser {
-    /// Parse agent manifest from YAML string
+    /// Parse agent manifest from YAML string.
+    ///
+    /// `extends` needs a file to resolve against; use [`Self::parse_file`].
     pub fn parse_yaml(yaml: &str) -> Result<AgentManifest> {
-        let mut manifest: AgentManifest =
+        let document: Value =
             serde_yaml::from_str(yaml).context("Failed to parse YAML manifest")?;
+        if document.get(EXTENDS_KEY).is_some() {
+            bail!("Manifests using '{EXTENDS_KEY}' must be loaded from a file");
+        }
+        Self::from_value(document)
+    }
+
+    /// Build and validate an agent manifest from an already merged document.
+    pub fn from_value(document: Value) -> Result<AgentManifest> {
+        let mut manifest: AgentManifest =
+            serde_yaml::from_value(document).context("Failed to parse YAML manifest")?;
 
         // Filter out empty tool names produced by YAML tool objects with missing name fields
         manifest.spec.tools.retain(|t| !t.is_empty());
@@ -58,12 +169,67 @@ impl AgentManifestParser {
         Ok(manifest)
     }
 
-    /// Parse agent manifest from YAML file
+    /// Parse agent manifest from YAML file, resolving file `extends` chains.
+    ///
+    /// Fails if the chain ends in a deployed agent; resolve those with
+    /// [`Self::load_chain`] and [`ManifestChain::merge`].
     pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<AgentManifest> {
-        let yaml = std::fs::read_to_string(path.as_ref())
-            .with_context(|| format!("Failed to read manifest file: {:?}", path.as_ref()))?;
+        let chain = Self::load_chain(path)?;
+        if let Some(name) = &chain.agent_base {
+            bail!("Manifest extends deployed agent '{name}', which requires the orchestrator");
+        }
+        Self::from_value(chain.merge(None))
+    }
 
-        Self::parse_yaml(&yaml)
+    /// Read a manifest file and every file it transitively `extends`.
+    pub fn load_chain<P: AsRef<Path>>(path: P) -> Result<ManifestChain> {
+        let mut layers = Vec::new();
+        let mut visited = HashSet::new();
+        let mut next = Some(path.as_ref().to_path_buf());
+
+        while let Some(path) = next.take() {
+            let canonical = path
+                .canonicalize()
+                .with_context(|| format!("Failed to read manifest file: {path:?}"))?;
+            if !visited.insert(canonical.clone()) {
+                bail!("Manifest '{EXTENDS_KEY}' chain has a cycle at {path:?}");
+            }
+            if visited.len() > MAX_EXTENDS_DEPTH {
+                bail!("Manifest '{EXTENDS_KEY}' chain is deeper than {MAX_EXTENDS_DEPTH} levels");
+            }
+
+            let yaml = std::fs::read_to_string(&canonical)
+                .with_context(|| format!("Failed to read manifest file: {path:?}"))?;
+            let mut document: Value = serde_yaml::from_str(&yaml)
+                .with_context(|| format!("Failed to parse YAML manifest: {path:?}"))?;
+            let reference = take_extends(&mut document)
+                .with_context(|| format!("Invalid manifest: {path:?}"))?;
+            layers.push(document);
+
+            match reference {
+                None => {}
+                Some(reference) => match reference.strip_prefix(AGENT_REFERENCE_PREFIX) {
+                    Some(name) => {
+                        return Ok(ManifestChain {
+                            layers,
+                            agent_base: Some(name.trim().to_string()),
+                        });
+                    }
+                    None => {
+                        let dir = canonical
+                            .parent()
+                            .map(Path::to_path_buf)
+                            .unwrap_or_default();
+                        next = Some(resolve_relative(&dir, &reference));
+                    }
+                },
+            }
+        }
+
+        Ok(ManifestChain {
+            layers,
+            agent_base: None,
+        })
     }
 
     /// Serialize agent manifest to YAML string
@@ -72,6 +238,15 @@ impl AgentManifestParser {
     }
 }
 
+fn resolve_relative(dir: &Path, reference: &str) -> PathBuf {
+    let path = Path::new(reference);
+    if path.is_absolute() {
+        path.to_path_buf()
+    } else {
+        dir.join(path)
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -303,6 +478,11 @@ spec:
                     version: Some("3.11".to_string()),
                     image: None,
                     image_pull_policy: ImagePullPolicy::IfNotPresent,
+                    base_image: None,
+                    packages: Vec::new(),
+                    env: Vec::new(),
+                    sidecars: Vec::new(),
+                    platform: None,
                     isolation: "docker".to_string(),
                     model: "default".to_string(),
                     temperature: None,
@@ -323,6 +503,8 @@ spec:
                 input_schema: None,
                 security_context: None,
                 output_handler: None,
+                concurrency: None,
+                healthcheck: None,
             },
         };
 
@@ -336,4 +518,120 @@ spec:
         assert_eq!(parsed.metadata.name, manifest.metadata.name);
         assert_eq!(parsed.spec.runtime.language, manifest.spec.runtime.language);
     }
+
+    const BASE_MANIFEST: &str = r#"
+apiVersion: 100monkeys.ai/v1
+kind: Agent
+metadata:
+  name: base-agent
+  version: "1.0.0"
+  labels:
+    team: platform
+    tier: base
+spec:
+  runtime:
+    language: python
+    version: "3.11"
+  task:
+    instruction: "Base instruction"
+  tools:
+    - "mcp:fs"
+    - "mcp:git"
+  env:
+    LOG_LEVEL: info
+    DEBUG: "false"
+"#;
+
+    fn write(dir: &Path, name: &str, contents: &str) -> PathBuf {
+        let path = dir.join(name);
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_extends_deep_merges_base_manifest() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("bases")).unwrap();
+        write(&dir.path().join("bases"), "base.yaml", BASE_MANIFEST);
+        let child = write(
+            dir.path(),
+            "child.yaml",
+            r#"
+extends: bases/base.yaml
+metadata:
+  name: reviewer
+  labels:
+    tier: null
+    role: review
+spec:
+  task:
+    instruction: "Review pull requests"
+  tools:
+    - "mcp:git"
+  env:
+    DEBUG: null
+"#,
+        );
+
+        let manifest = AgentManifestParser::parse_file(&child).unwrap();
+        assert_eq!(manifest.metadata.name, "reviewer");
+        assert_eq!(manifest.metadata.version, "1.0.0");
+        assert_eq!(manifest.metadata.labels.get("team").unwrap(), "platform");
+        assert_eq!(manifest.metadata.labels.get("role").unwrap(), "review");
+        assert!(!manifest.metadata.labels.contains_key("tier"));
+        assert_eq!(manifest.spec.runtime.language, Some("python".to_string()));
+        assert_eq!(
+            manifest.spec.task.unwrap().instruction.as_deref(),
+            Some("Review pull requests")
+        );
+        assert_eq!(manifest.spec.tools, vec!["mcp:git".to_string()]);
+        assert_eq!(manifest.spec.env.get("LOG_LEVEL").unwrap(), "info");
+        assert!(!manifest.spec.env.contains_key("DEBUG"));
+    }
+
+    #[test]
+    fn test_extends_requires_own_name() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "base.yaml", BASE_MANIFEST);
+        let child = write(dir.path(), "child.yaml", "extends: base.yaml\n");
+
+        let err = AgentManifestParser::parse_file(&child).unwrap_err();
+        assert!(format!("{err:#}").contains("name"), "{err:#}");
+    }
+
+    #[test]
+    fn test_extends_rejects_cycles() {
+        let dir = tempfile::tempdir().unwrap();
+        write(dir.path(), "a.yaml", "extends: b.yaml\n");
+        let a = dir.path().join("a.yaml");
+        write(dir.path(), "b.yaml", "extends: a.yaml\n");
+
+        let err = AgentManifestParser::parse_file(&a).unwrap_err();
+        assert!(err.to_string().contains("cycle"), "{err:#}");
+    }
+
+    #[test]
+    fn test_extends_deployed_agent() {
+        let dir = tempfile::tempdir().unwrap();
+        let child = write(
+            dir.path(),
+            "child.yaml",
+            "extends: agent:base-agent\nmetadata:\n  name: reviewer\n",
+        );
+
+        assert!(AgentManifestParser::parse_file(&child).is_err());
+
+        let chain = AgentManifestParser::load_chain(&child).unwrap();
+        assert_eq!(chain.agent_base.as_deref(), Some("base-agent"));
+        let base = serde_yaml::from_str(BASE_MANIFEST).unwrap();
+        let manifest = AgentManifestParser::from_value(chain.merge(Some(base))).unwrap();
+        assert_eq!(manifest.metadata.name, "reviewer");
+        assert_eq!(manifest.spec.tools.len(), 2);
+    }
+
+    #[test]
+    fn test_parse_yaml_rejects_extends() {
+        let err = AgentManifestParser::parse_yaml("extends: base.yaml\n").unwrap_err();
+        assert!(err.to_string().contains("loaded from a file"));
+    }
 }
@@ -219,11 +219,13 @@ pub fn domain_to_proto(event: DomainEvent) -> Option<ExecutionEvent> {
 
         DomainEvent::InstanceSpawned { .. }
         | DomainEvent::InstanceTerminated { .. }
+        | DomainEvent::StorageTransportInterrupted { .. }
         | DomainEvent::ChildExecutionSpawned { .. }
         | DomainEvent::ChildExecutionCompleted { .. }
         | DomainEvent::Validation(_)
         | DomainEvent::OutputHandlerStarted { .. }
         | DomainEvent::OutputHandlerCompleted { .. }
-        | DomainEvent::OutputHandlerFailed { .. } => None,
+        | DomainEvent::OutputHandlerFailed { .. }
+        | DomainEvent::OutputModerated { .. } => None,
     }
 }
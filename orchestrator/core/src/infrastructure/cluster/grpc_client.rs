@@ -160,10 +160,17 @@ impl NodeClusterClient {
         &mut self,
         cpu_utilization: f64,
         active_executions: u32,
+        draining: bool,
     ) -> Result<Vec<NodeCommand>> {
+        // A cordoned node reports Draining so the controller stops routing to it.
+        let status = if draining {
+            NodeStatus::Draining
+        } else {
+            NodeStatus::Active
+        };
         let inner = NodeHeartbeatInner {
             node_id: self.node_id.0.to_string(),
-            status: NodeStatus::Active.into(),
+            status: status.into(),
             active_executions,
             available_memory_gb: 0,
             cpu_utilization_percent: cpu_utilization as f32,
@@ -0,0 +1,121 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # PostgreSQL Daemon Lease Repository
+//!
+//! `DaemonLeaseRepository` backed by the `daemon_leases` table (migration
+//! 040). Every expiry comparison uses `NOW()` on the database so both
+//! daemons of a pair judge the lease by the same clock.
+
+use crate::domain::cluster::{DaemonLease, DaemonLeaseRepository};
+use async_trait::async_trait;
+use sqlx::postgres::{PgPool, PgRow};
+use sqlx::Row;
+use std::time::Duration;
+
+pub struct PgDaemonLeaseRepository {
+    pool: PgPool,
+}
+
+impl PgDaemonLeaseRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DaemonLeaseRepository for PgDaemonLeaseRepository {
+    async fn try_acquire(
+        &self,
+        name: &str,
+        holder: &str,
+        ttl: Duration,
+    ) -> anyhow::Result<Option<DaemonLease>> {
+        // The conditional upsert is atomic: of two daemons racing for an
+        // expired lease, only one sees its row returned.
+        let row = sqlx::query(
+            r#"
+            INSERT INTO daemon_leases (name, holder, epoch, acquired_at, renewed_at, expires_at)
+            VALUES ($1, $2, 1, NOW(), NOW(), NOW() + $3 * INTERVAL '1 millisecond')
+            ON CONFLICT (name) DO UPDATE SET
+                holder = EXCLUDED.holder,
+                epoch = daemon_leases.epoch + 1,
+                acquired_at = NOW(),
+                renewed_at = NOW(),
+                expires_at = EXCLUDED.expires_at
+            WHERE daemon_leases.expires_at <= NOW()
+               OR daemon_leases.holder = EXCLUDED.holder
+            RETURNING name, holder, epoch, acquired_at, renewed_at, expires_at
+            "#,
+        )
+        .bind(name)
+        .bind(holder)
+        .bind(ttl.as_millis() as i64)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.as_ref().map(row_to_lease))
+    }
+
+    async fn renew(
+        &self,
+        name: &str,
+        holder: &str,
+        epoch: i64,
+        ttl: Duration,
+    ) -> anyhow::Result<bool> {
+        let result = sqlx::query(
+            r#"
+            UPDATE daemon_leases
+            SET renewed_at = NOW(), expires_at = NOW() + $4 * INTERVAL '1 millisecond'
+            WHERE name = $1 AND holder = $2 AND epoch = $3
+            "#,
+        )
+        .bind(name)
+        .bind(holder)
+        .bind(epoch)
+        .bind(ttl.as_millis() as i64)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() == 1)
+    }
+
+    async fn release(&self, name: &str, holder: &str, epoch: i64) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE daemon_leases
+            SET expires_at = NOW()
+            WHERE name = $1 AND holder = $2 AND epoch = $3
+            "#,
+        )
+        .bind(name)
+        .bind(holder)
+        .bind(epoch)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn current(&self, name: &str) -> anyhow::Result<Option<DaemonLease>> {
+        let row = sqlx::query(
+            r#"
+            SELECT name, holder, epoch, acquired_at, renewed_at, expires_at
+            FROM daemon_leases
+            WHERE name = $1
+            "#,
+        )
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(row.as_ref().map(row_to_lease))
+    }
+}
+
+fn row_to_lease(row: &PgRow) -> DaemonLease {
+    DaemonLease {
+        name: row.get("name"),
+        holder: row.get("holder"),
+        epoch: row.get("epoch"),
+        acquired_at: row.get("acquired_at"),
+        renewed_at: row.get("renewed_at"),
+        expires_at: row.get("expires_at"),
+    }
+}
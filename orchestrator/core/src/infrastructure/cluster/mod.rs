@@ -7,6 +7,7 @@ pub mod enrolment_token_repo;
 pub mod event_mapper;
 pub mod grpc_client;
 pub mod grpc_server;
+pub mod lease_repo;
 pub mod node_registry_repo;
 pub mod postgres_repo;
 pub mod round_robin_router;
@@ -16,6 +17,7 @@ pub use config_repo::PgConfigLayerRepository;
 pub use enrolment_token_repo::PgClusterEnrolmentTokenRepository;
 pub use grpc_client::NodeClusterClient;
 pub use grpc_server::NodeClusterServiceHandler;
+pub use lease_repo::PgDaemonLeaseRepository;
 pub use node_registry_repo::PgNodeRegistryRepository;
 pub use postgres_repo::{
     PgNodeChallengeRepository, PgNodeClusterRepository, PgStimulusIdempotencyRepository,
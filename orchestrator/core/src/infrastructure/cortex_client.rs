@@ -23,6 +23,18 @@
 //! When `api_key` is set, every outbound RPC includes an `Authorization: Bearer <key>`
 //! metadata header. When absent (self-hosted or dev mode), requests are unauthenticated.
 //!
+//! ## Metrics
+//!
+//! | Metric | Labels | Meaning |
+//! |---|---|---|
+//! | `aegis_cortex_requests_total` | `rpc`, `result` | Every Cortex RPC, `ok` or `error` |
+//! | `aegis_cortex_pattern_queries_total` | `result` | Successful pattern searches, `hit` or `miss` |
+//! | `aegis_cortex_patterns_returned_total` | | Patterns returned by pattern searches |
+//!
+//! `AegisRuntimeService` adds `aegis_cortex_patterns_injected_total` for
+//! patterns handed to agents. Reinforcement and pruning happen inside Cortex
+//! and are reported by that service.
+//!
 //! # Architecture
 //!
 //! - **Layer:** Infrastructure Layer
@@ -81,14 +93,35 @@ impl CortexGrpcClient {
         req
     }
 
+    /// Count one `rpc` call in `aegis_cortex_requests_total`.
+    fn record<T>(rpc: &'static str, result: &Result<T, Status>) {
+        let result = if result.is_ok() { "ok" } else { "error" };
+        metrics::counter!("aegis_cortex_requests_total", "rpc" => rpc, "result" => result)
+            .increment(1);
+    }
+
     /// Forward a `QueryPatterns` RPC to the Cortex service.
     pub async fn query_patterns(
         &self,
         request: QueryPatternsRequest,
     ) -> Result<QueryPatternsResponse, Status> {
         let mut client = self.client.clone();
-        let response = client.query_patterns(self.authed_request(request)).await?;
-        Ok(response.into_inner())
+        let result = client
+            .query_patterns(self.authed_request(request))
+            .await
+            .map(|r| r.into_inner());
+        Self::record("query_patterns", &result);
+        if let Ok(response) = &result {
+            let hit = if response.patterns.is_empty() {
+                "miss"
+            } else {
+                "hit"
+            };
+            metrics::counter!("aegis_cortex_pattern_queries_total", "result" => hit).increment(1);
+            metrics::counter!("aegis_cortex_patterns_returned_total")
+                .increment(response.patterns.len() as u64);
+        }
+        result
     }
 
     /// Forward a `StorePattern` RPC to the Cortex service.
@@ -97,8 +130,12 @@ impl CortexGrpcClient {
         request: StorePatternRequest,
     ) -> Result<StorePatternResponse, Status> {
         let mut client = self.client.clone();
-        let response = client.store_pattern(self.authed_request(request)).await?;
-        Ok(response.into_inner())
+        let result = client
+            .store_pattern(self.authed_request(request))
+            .await
+            .map(|r| r.into_inner());
+        Self::record("store_pattern", &result);
+        result
     }
 
     /// Forward a `StoreTrajectoryPattern` RPC to the Cortex service (ADR-049).
@@ -107,10 +144,12 @@ impl CortexGrpcClient {
         request: StoreTrajectoryPatternRequest,
     ) -> Result<StoreTrajectoryPatternResponse, Status> {
         let mut client = self.client.clone();
-        let response = client
+        let result = client
             .store_trajectory_pattern(self.authed_request(request))
-            .await?;
-        Ok(response.into_inner())
+            .await
+            .map(|r| r.into_inner());
+        Self::record("store_trajectory_pattern", &result);
+        result
     }
 
     /// Index (upsert) an agent in the Cortex discovery index.
@@ -119,10 +158,12 @@ impl CortexGrpcClient {
         request: IndexAgentRequest,
     ) -> Result<IndexAgentResponse, Status> {
         let mut client = self.client.clone();
-        client
+        let result = client
             .index_agent(self.authed_request(request))
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        Self::record("index_agent", &result);
+        result
     }
 
     /// Index (upsert) a workflow in the Cortex discovery index.
@@ -131,10 +172,12 @@ impl CortexGrpcClient {
         request: IndexWorkflowRequest,
     ) -> Result<IndexWorkflowResponse, Status> {
         let mut client = self.client.clone();
-        client
+        let result = client
             .index_workflow(self.authed_request(request))
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        Self::record("index_workflow", &result);
+        result
     }
 
     /// Remove an agent from the Cortex discovery index.
@@ -143,10 +186,12 @@ impl CortexGrpcClient {
         request: RemoveDiscoveryAgentRequest,
     ) -> Result<RemoveDiscoveryAgentResponse, Status> {
         let mut client = self.client.clone();
-        client
+        let result = client
             .remove_agent(self.authed_request(request))
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        Self::record("remove_discovery_agent", &result);
+        result
     }
 
     /// Remove a workflow from the Cortex discovery index.
@@ -155,10 +200,12 @@ impl CortexGrpcClient {
         request: RemoveDiscoveryWorkflowRequest,
     ) -> Result<RemoveDiscoveryWorkflowResponse, Status> {
         let mut client = self.client.clone();
-        client
+        let result = client
             .remove_workflow(self.authed_request(request))
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        Self::record("remove_discovery_workflow", &result);
+        result
     }
 
     /// Search for agents in the Cortex discovery index.
@@ -167,10 +214,12 @@ impl CortexGrpcClient {
         request: DiscoverAgentsRequest,
     ) -> Result<DiscoverAgentsResponse, Status> {
         let mut client = self.client.clone();
-        client
+        let result = client
             .discover_agents(self.authed_request(request))
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        Self::record("discover_agents", &result);
+        result
     }
 
     /// Search for workflows in the Cortex discovery index.
@@ -179,10 +228,12 @@ impl CortexGrpcClient {
         request: DiscoverWorkflowsRequest,
     ) -> Result<DiscoverWorkflowsResponse, Status> {
         let mut client = self.client.clone();
-        client
+        let result = client
             .discover_workflows(self.authed_request(request))
             .await
-            .map(|r| r.into_inner())
+            .map(|r| r.into_inner());
+        Self::record("discover_workflows", &result);
+        result
     }
 }
 
@@ -8,10 +8,19 @@
 //! PostgreSQL connection pool wrapper used by repository implementations when
 //! persistent storage is configured.
 //!
+//! [`DatabasePools`] adds an optional read replica. Repositories ask it for a
+//! pool per query with a [`ReadConsistency`]: writes and status-critical
+//! reads use the primary, listing and event queries may use the replica while
+//! its measured replay lag is within bounds.
+//!
 //! See ADR-025 (PostgreSQL Schema Design).
 
 use anyhow::Result;
 use sqlx::postgres::{PgPool, PgPoolOptions};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{debug, warn};
 
 #[derive(Clone)]
 pub struct Database {
@@ -32,3 +41,180 @@ impl Database {
         &self.pool
     }
 }
+
+/// Consistency a read needs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadConsistency {
+    /// Must observe every committed write, e.g. status checks that drive
+    /// cancellation or concurrency limits. Always served by the primary.
+    Strong,
+    /// May lag the primary by up to the replica's `max_lag`, e.g. listings.
+    Eventual,
+}
+
+/// Lag value meaning "not measured yet, or the last measurement failed".
+const LAG_UNKNOWN: u64 = u64::MAX;
+
+/// Primary pool plus an optional read replica.
+#[derive(Clone)]
+pub struct DatabasePools {
+    primary: PgPool,
+    replica: Option<ReadReplica>,
+}
+
+#[derive(Clone)]
+struct ReadReplica {
+    pool: PgPool,
+    max_lag: Duration,
+    /// Last measured replay lag in milliseconds, or [`LAG_UNKNOWN`].
+    lag_ms: Arc<AtomicU64>,
+}
+
+impl DatabasePools {
+    /// Every query goes to `primary`.
+    pub fn primary_only(primary: PgPool) -> Self {
+        Self {
+            primary,
+            replica: None,
+        }
+    }
+
+    /// Route eventually-consistent reads to `replica` while its lag is at
+    /// most `max_lag`. Until the first lag measurement
+    /// ([`Self::refresh_replica_lag`]) every read goes to the primary.
+    pub fn with_replica(primary: PgPool, replica: PgPool, max_lag: Duration) -> Self {
+        Self {
+            primary,
+            replica: Some(ReadReplica {
+                pool: replica,
+                max_lag,
+                lag_ms: Arc::new(AtomicU64::new(LAG_UNKNOWN)),
+            }),
+        }
+    }
+
+    /// Pool for writes and [`ReadConsistency::Strong`] reads.
+    pub fn primary(&self) -> &PgPool {
+        &self.primary
+    }
+
+    /// Pool for a read needing `consistency`.
+    pub fn read(&self, consistency: ReadConsistency) -> &PgPool {
+        match &self.replica {
+            Some(replica) if consistency == ReadConsistency::Eventual && replica.is_fresh() => {
+                &replica.pool
+            }
+            _ => &self.primary,
+        }
+    }
+
+    /// Last measured replica lag; `None` without a replica or measurement.
+    pub fn replica_lag(&self) -> Option<Duration> {
+        let replica = self.replica.as_ref()?;
+        match replica.lag_ms.load(Ordering::Relaxed) {
+            LAG_UNKNOWN => None,
+            ms => Some(Duration::from_millis(ms)),
+        }
+    }
+
+    /// Measure the replica's replay lag. A failed measurement routes reads
+    /// back to the primary until the next successful one.
+    pub async fn refresh_replica_lag(&self) {
+        let Some(replica) = &self.replica else { return };
+        // A replica that has replayed everything it received is current even
+        // when the primary has been idle since the last replayed commit.
+        let measured: Result<Option<f64>, sqlx::Error> = sqlx::query_scalar(
+            r#"
+            SELECT CASE
+                WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0
+                ELSE EXTRACT(EPOCH FROM (now() - pg_last_xact_replay_timestamp()))
+            END::float8
+            "#,
+        )
+        .fetch_one(&replica.pool)
+        .await;
+        let lag_ms = match measured {
+            Ok(Some(seconds)) => (seconds.max(0.0) * 1000.0) as u64,
+            Ok(None) => {
+                warn!("Read replica reports no replay position; routing reads to primary");
+                LAG_UNKNOWN
+            }
+            Err(e) => {
+                warn!(error = %e, "Read replica lag check failed; routing reads to primary");
+                LAG_UNKNOWN
+            }
+        };
+        debug!(lag_ms, "Measured read replica lag");
+        replica.lag_ms.store(lag_ms, Ordering::Relaxed);
+    }
+
+    /// Re-measure the replica lag every `interval`. `None` without a replica.
+    pub fn spawn_lag_monitor(&self, interval: Duration) -> Option<tokio::task::JoinHandle<()>> {
+        self.replica.as_ref()?;
+        let pools = self.clone();
+        Some(tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                ticker.tick().await;
+                pools.refresh_replica_lag().await;
+            }
+        }))
+    }
+}
+
+impl ReadReplica {
+    fn is_fresh(&self) -> bool {
+        match self.lag_ms.load(Ordering::Relaxed) {
+            LAG_UNKNOWN => false,
+            ms => Duration::from_millis(ms) <= self.max_lag,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lazy_pool(db: &str) -> PgPool {
+        PgPoolOptions::new()
+            .connect_lazy(&format!("postgres://aegis@localhost/{db}"))
+            .unwrap()
+    }
+
+    fn routes_to_replica(pools: &DatabasePools, consistency: ReadConsistency) -> bool {
+        let replica = &pools.replica.as_ref().unwrap().pool;
+        std::ptr::eq(pools.read(consistency), replica)
+    }
+
+    #[tokio::test]
+    async fn reads_use_replica_only_while_lag_is_within_bound() {
+        let pools = DatabasePools::with_replica(
+            lazy_pool("primary"),
+            lazy_pool("replica"),
+            Duration::from_secs(5),
+        );
+        let lag = &pools.replica.as_ref().unwrap().lag_ms;
+
+        // Unmeasured: primary.
+        assert!(!routes_to_replica(&pools, ReadConsistency::Eventual));
+        assert_eq!(pools.replica_lag(), None);
+
+        lag.store(1_200, Ordering::Relaxed);
+        assert!(routes_to_replica(&pools, ReadConsistency::Eventual));
+        assert!(!routes_to_replica(&pools, ReadConsistency::Strong));
+        assert_eq!(pools.replica_lag(), Some(Duration::from_millis(1_200)));
+
+        lag.store(5_001, Ordering::Relaxed);
+        assert!(!routes_to_replica(&pools, ReadConsistency::Eventual));
+    }
+
+    #[tokio::test]
+    async fn primary_only_serves_every_read() {
+        let pools = DatabasePools::primary_only(lazy_pool("primary"));
+        assert!(std::ptr::eq(
+            pools.read(ReadConsistency::Eventual),
+            pools.primary()
+        ));
+        assert!(pools.spawn_lag_monitor(Duration::from_secs(1)).is_none());
+    }
+}
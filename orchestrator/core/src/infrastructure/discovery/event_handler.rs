@@ -848,6 +848,11 @@ mod tests {
                     version: Some("3.11".to_string()),
                     image: None,
                     image_pull_policy: ImagePullPolicy::IfNotPresent,
+                    base_image: None,
+                    packages: Vec::new(),
+                    env: Vec::new(),
+                    sidecars: Vec::new(),
+                    platform: None,
                     isolation: "inherit".to_string(),
                     model: "default".to_string(),
                     temperature: None,
@@ -868,6 +873,8 @@ mod tests {
                 input_schema: None,
                 security_context: None,
                 output_handler: None,
+                concurrency: None,
+                healthcheck: None,
             },
         }
     }
@@ -909,9 +916,12 @@ mod tests {
         let spec = WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         };
 
         let mut wf = Workflow::new(metadata, spec).expect("valid workflow");
@@ -2,9 +2,9 @@
 // SPDX-License-Identifier: AGPL-3.0
 //! # Domain Event Bus (ADR-030)
 //!
-//! In-memory pub/sub event bus based on `tokio::sync::broadcast`. Every domain
-//! aggregate publishes [`DomainEvent`]s here; infrastructure adapters (CLI
-//! streamer, SSE endpoint, Cortex indexer) subscribe independently.
+//! In-memory pub/sub event bus with a bounded queue per subscriber. Every
+//! domain aggregate publishes [`DomainEvent`]s here; infrastructure adapters
+//! (CLI streamer, SSE endpoint, Cortex indexer) subscribe independently.
 //!
 //! ## Event Flow
 //!
@@ -12,12 +12,22 @@
 //! Execution aggregate
 //!   │  publish_execution_event(IterationCompleted { .. })
 //!   ▼
-//! EventBus  (broadcast channel, capacity 1000)
+//! EventBus  (one bounded queue per subscriber, default capacity 1000)
 //!   ├── CLI subscriber  →  prints progress to terminal
 //!   ├── SSE subscriber  →  streams to Zaru client WebSocket
-//!   └── Cortex subscriber → indexes RefinementApplied patterns
+//!   ├── Cortex subscriber → indexes RefinementApplied patterns
+//!   └── Persisters (OverflowPolicy::Block) → audit-trail tables
 //! ```
 //!
+//! ## Backpressure
+//!
+//! A slow subscriber only ever fills its own queue. What happens next is the
+//! subscriber's [`OverflowPolicy`]: drop the oldest event and report
+//! [`EventBusError::Lagged`], disconnect the subscriber, or — for
+//! audit-critical persisters — stall the publisher until space frees up.
+//! Queue depth, drops, disconnects and publisher stalls are exported as
+//! `aegis_event_bus_*` metrics labelled by the static subscriber name.
+//!
 //! ## Bounded Context Coverage
 //!
 //! The [`DomainEvent`] enum wraps events from **all** bounded contexts so a
@@ -45,6 +55,7 @@
 //! - Keep publish/subscribe semantics in-memory and explicit for the current runtime model.
 //! - Preserve typed domain events; do not smuggle transport-specific payloads through the bus.
 //! - Fail predictably on lag or delivery issues so subscribers can make their own recovery choices.
+//! - Subscribers that cannot tolerate gaps must opt into [`OverflowPolicy::Block`]; never widen the default.
 //!
 //! ## Phase Notes
 //!
@@ -55,13 +66,13 @@
 
 // Event Bus Implementation - Pub/Sub for Domain Events
 //
-// Provides in-memory event streaming using tokio broadcast channels.
+// Provides in-memory event streaming using per-subscriber bounded queues.
 // Enables real-time event streaming to CLI, SSE endpoints, and observers.
 //
 // For MVP: In-memory only (events lost on restart)
 // Phase 2: Add persistent event store for replay capability
 
-use crate::domain::agent::AgentId;
+use crate::domain::agent::{AgentId, EventVerbosity};
 use crate::domain::cluster::ClusterEvent;
 use crate::domain::events::{
     AgentLifecycleEvent, CanvasEvent, ContainerRunEvent, CredentialEvent, DriftEvent,
@@ -71,9 +82,12 @@ use crate::domain::events::{
 };
 use crate::domain::execution::ExecutionId;
 use chrono::{DateTime, Utc};
+use parking_lot::{Condvar, Mutex, MutexGuard, RwLock};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
-use tokio::sync::broadcast;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
 use tracing::warn;
 
 fn domain_event_type(event: &DomainEvent) -> &'static str {
@@ -179,8 +193,11 @@ impl DomainEvent {
                 | ExecutionEvent::ConsoleOutput { execution_id, .. }
                 | ExecutionEvent::LlmInteraction { execution_id, .. }
                 | ExecutionEvent::LlmCallFailed { execution_id, .. }
+                | ExecutionEvent::ModelRouted { execution_id, .. }
+                | ExecutionEvent::OperatorGuidanceAdded { execution_id, .. }
                 | ExecutionEvent::InstanceSpawned { execution_id, .. }
                 | ExecutionEvent::InstanceTerminated { execution_id, .. }
+                | ExecutionEvent::StorageTransportInterrupted { execution_id, .. }
                 | ExecutionEvent::ChildExecutionSpawned { execution_id, .. }
                 | ExecutionEvent::ChildExecutionCompleted { execution_id, .. } => *execution_id,
                 ExecutionEvent::Validation(validation) => match validation {
@@ -189,12 +206,14 @@ impl DomainEvent {
                 },
                 ExecutionEvent::OutputHandlerStarted { execution_id, .. }
                 | ExecutionEvent::OutputHandlerCompleted { execution_id, .. }
-                | ExecutionEvent::OutputHandlerFailed { execution_id, .. } => *execution_id,
+                | ExecutionEvent::OutputHandlerFailed { execution_id, .. }
+                | ExecutionEvent::OutputModerated { execution_id, .. } => *execution_id,
             }),
             DomainEvent::Workflow(event) => Some(match event {
                 WorkflowEvent::WorkflowExecutionStarted { execution_id, .. }
                 | WorkflowEvent::WorkflowStateEntered { execution_id, .. }
                 | WorkflowEvent::WorkflowStateExited { execution_id, .. }
+                | WorkflowEvent::WorkflowStateStuck { execution_id, .. }
                 | WorkflowEvent::WorkflowIterationStarted { execution_id, .. }
                 | WorkflowEvent::WorkflowIterationCompleted { execution_id, .. }
                 | WorkflowEvent::WorkflowIterationFailed { execution_id, .. }
@@ -250,7 +269,8 @@ impl DomainEvent {
                 | StorageEvent::PathTraversalBlocked { execution_id, .. }
                 | StorageEvent::FilesystemPolicyViolation { execution_id, .. }
                 | StorageEvent::QuotaExceeded { execution_id, .. }
-                | StorageEvent::UnauthorizedVolumeAccess { execution_id, .. } => *execution_id,
+                | StorageEvent::UnauthorizedVolumeAccess { execution_id, .. }
+                | StorageEvent::OperationFailed { execution_id, .. } => *execution_id,
             },
             DomainEvent::MCP(event) => match event {
                 MCPToolEvent::InvocationRequested { execution_id, .. }
@@ -273,7 +293,10 @@ impl DomainEvent {
             DomainEvent::ImageManagement(event) => Some(match event {
                 ImageManagementEvent::ImagePullStarted { execution_id, .. }
                 | ImageManagementEvent::ImagePullCompleted { execution_id, .. }
-                | ImageManagementEvent::ImagePullFailed { execution_id, .. } => *execution_id,
+                | ImageManagementEvent::ImagePullFailed { execution_id, .. }
+                | ImageManagementEvent::ImageVerificationFailed { execution_id, .. } => {
+                    *execution_id
+                }
             }),
             DomainEvent::Secrets(event) => match event {
                 SecretEvent::SecretRetrieved { access_context, .. }
@@ -345,8 +368,11 @@ impl DomainEvent {
                 | ExecutionEvent::ConsoleOutput { agent_id, .. }
                 | ExecutionEvent::LlmInteraction { agent_id, .. }
                 | ExecutionEvent::LlmCallFailed { agent_id, .. }
+                | ExecutionEvent::ModelRouted { agent_id, .. }
+                | ExecutionEvent::OperatorGuidanceAdded { agent_id, .. }
                 | ExecutionEvent::InstanceSpawned { agent_id, .. }
                 | ExecutionEvent::InstanceTerminated { agent_id, .. }
+                | ExecutionEvent::StorageTransportInterrupted { agent_id, .. }
                 | ExecutionEvent::ChildExecutionSpawned { agent_id, .. }
                 | ExecutionEvent::ChildExecutionCompleted { agent_id, .. } => Some(*agent_id),
                 ExecutionEvent::Validation(validation) => Some(match validation {
@@ -355,7 +381,8 @@ impl DomainEvent {
                 }),
                 ExecutionEvent::OutputHandlerStarted { .. }
                 | ExecutionEvent::OutputHandlerCompleted { .. }
-                | ExecutionEvent::OutputHandlerFailed { .. } => None,
+                | ExecutionEvent::OutputHandlerFailed { .. }
+                | ExecutionEvent::OutputModerated { .. } => None,
             },
             DomainEvent::Workflow(_)
             | DomainEvent::Volume(_)
@@ -436,8 +463,13 @@ impl DomainEvent {
                 ExecutionEvent::ConsoleOutput { timestamp, .. } => *timestamp,
                 ExecutionEvent::LlmInteraction { timestamp, .. } => *timestamp,
                 ExecutionEvent::LlmCallFailed { timestamp, .. } => *timestamp,
+                ExecutionEvent::ModelRouted { timestamp, .. } => *timestamp,
+                ExecutionEvent::OperatorGuidanceAdded { timestamp, .. } => *timestamp,
                 ExecutionEvent::InstanceSpawned { spawned_at, .. } => *spawned_at,
                 ExecutionEvent::InstanceTerminated { terminated_at, .. } => *terminated_at,
+                ExecutionEvent::StorageTransportInterrupted { interrupted_at, .. } => {
+                    *interrupted_at
+                }
                 ExecutionEvent::ChildExecutionSpawned { spawned_at, .. } => *spawned_at,
                 ExecutionEvent::ChildExecutionCompleted { completed_at, .. } => *completed_at,
                 ExecutionEvent::Validation(validation) => match validation {
@@ -449,12 +481,14 @@ impl DomainEvent {
                 ExecutionEvent::OutputHandlerStarted { .. }
                 | ExecutionEvent::OutputHandlerCompleted { .. }
                 | ExecutionEvent::OutputHandlerFailed { .. } => Utc::now(),
+                ExecutionEvent::OutputModerated { moderated_at, .. } => *moderated_at,
             },
             DomainEvent::Workflow(event) => match event {
                 WorkflowEvent::WorkflowRegistered { registered_at, .. } => *registered_at,
                 WorkflowEvent::WorkflowExecutionStarted { started_at, .. } => *started_at,
                 WorkflowEvent::WorkflowStateEntered { entered_at, .. } => *entered_at,
                 WorkflowEvent::WorkflowStateExited { exited_at, .. } => *exited_at,
+                WorkflowEvent::WorkflowStateStuck { detected_at, .. } => *detected_at,
                 WorkflowEvent::WorkflowIterationStarted { started_at, .. } => *started_at,
                 WorkflowEvent::WorkflowIterationCompleted { completed_at, .. } => *completed_at,
                 WorkflowEvent::WorkflowIterationFailed { failed_at, .. } => *failed_at,
@@ -506,6 +540,7 @@ impl DomainEvent {
                 StorageEvent::FilesystemPolicyViolation { violated_at, .. } => *violated_at,
                 StorageEvent::QuotaExceeded { exceeded_at, .. } => *exceeded_at,
                 StorageEvent::UnauthorizedVolumeAccess { attempted_at, .. } => *attempted_at,
+                StorageEvent::OperationFailed { failed_at, .. } => *failed_at,
             },
             DomainEvent::MCP(event) => match event {
                 MCPToolEvent::ServerRegistered { registered_at, .. } => *registered_at,
@@ -536,7 +571,8 @@ impl DomainEvent {
             DomainEvent::ImageManagement(event) => match event {
                 ImageManagementEvent::ImagePullStarted { started_at, .. } => *started_at,
                 ImageManagementEvent::ImagePullCompleted { completed_at, .. } => *completed_at,
-                ImageManagementEvent::ImagePullFailed { failed_at, .. } => *failed_at,
+                ImageManagementEvent::ImagePullFailed { failed_at, .. }
+                | ImageManagementEvent::ImageVerificationFailed { failed_at, .. } => *failed_at,
             },
             DomainEvent::Iam(event) => match event {
                 IamEvent::UserAuthenticated {
@@ -688,8 +724,13 @@ impl DomainEvent {
                 ExecutionEvent::ConsoleOutput { .. } => "console_output",
                 ExecutionEvent::LlmInteraction { .. } => "llm_interaction",
                 ExecutionEvent::LlmCallFailed { .. } => "llm_call_failed",
+                ExecutionEvent::ModelRouted { .. } => "model_routed",
+                ExecutionEvent::OperatorGuidanceAdded { .. } => "operator_guidance_added",
                 ExecutionEvent::InstanceSpawned { .. } => "instance_spawned",
                 ExecutionEvent::InstanceTerminated { .. } => "instance_terminated",
+                ExecutionEvent::StorageTransportInterrupted { .. } => {
+                    "storage_transport_interrupted"
+                }
                 ExecutionEvent::ChildExecutionSpawned { .. } => "child_execution_spawned",
                 ExecutionEvent::ChildExecutionCompleted { .. } => "child_execution_completed",
                 ExecutionEvent::Validation(validation) => match validation {
@@ -701,12 +742,14 @@ impl DomainEvent {
                 ExecutionEvent::OutputHandlerStarted { .. } => "output_handler_started",
                 ExecutionEvent::OutputHandlerCompleted { .. } => "output_handler_completed",
                 ExecutionEvent::OutputHandlerFailed { .. } => "output_handler_failed",
+                ExecutionEvent::OutputModerated { .. } => "output_moderated",
             },
             DomainEvent::Workflow(event) => match event {
                 WorkflowEvent::WorkflowRegistered { .. } => "workflow_registered",
                 WorkflowEvent::WorkflowExecutionStarted { .. } => "workflow_execution_started",
                 WorkflowEvent::WorkflowStateEntered { .. } => "workflow_state_entered",
                 WorkflowEvent::WorkflowStateExited { .. } => "workflow_state_exited",
+                WorkflowEvent::WorkflowStateStuck { .. } => "workflow_state_stuck",
                 WorkflowEvent::WorkflowIterationStarted { .. } => "workflow_iteration_started",
                 WorkflowEvent::WorkflowIterationCompleted { .. } => "workflow_iteration_completed",
                 WorkflowEvent::WorkflowIterationFailed { .. } => "workflow_iteration_failed",
@@ -762,6 +805,7 @@ impl DomainEvent {
                 StorageEvent::FilesystemPolicyViolation { .. } => "filesystem_policy_violation",
                 StorageEvent::QuotaExceeded { .. } => "quota_exceeded",
                 StorageEvent::UnauthorizedVolumeAccess { .. } => "unauthorized_volume_access",
+                StorageEvent::OperationFailed { .. } => "storage_operation_failed",
             },
             DomainEvent::MCP(event) => match event {
                 MCPToolEvent::ServerRegistered { .. } => "tool_server_registered",
@@ -791,6 +835,7 @@ impl DomainEvent {
                 ImageManagementEvent::ImagePullStarted { .. } => "image_pull_started",
                 ImageManagementEvent::ImagePullCompleted { .. } => "image_pull_completed",
                 ImageManagementEvent::ImagePullFailed { .. } => "image_pull_failed",
+                ImageManagementEvent::ImageVerificationFailed { .. } => "image_verification_failed",
             },
             DomainEvent::Iam(event) => match event {
                 IamEvent::UserAuthenticated { .. } => "user_authenticated",
@@ -970,11 +1015,20 @@ impl DomainEvent {
                 | ExecutionEvent::LlmCallFailed {
                     iteration_number, ..
                 }
+                | ExecutionEvent::ModelRouted {
+                    iteration_number, ..
+                }
+                | ExecutionEvent::OperatorGuidanceAdded {
+                    iteration_number, ..
+                }
                 | ExecutionEvent::InstanceSpawned {
                     iteration_number, ..
                 }
                 | ExecutionEvent::InstanceTerminated {
                     iteration_number, ..
+                }
+                | ExecutionEvent::StorageTransportInterrupted {
+                    iteration_number, ..
                 } => Some(*iteration_number),
                 ExecutionEvent::Validation(validation) => match validation {
                     ValidationEvent::GradientValidationPerformed {
@@ -991,7 +1045,8 @@ impl DomainEvent {
                 | ExecutionEvent::ChildExecutionCompleted { .. }
                 | ExecutionEvent::OutputHandlerStarted { .. }
                 | ExecutionEvent::OutputHandlerCompleted { .. }
-                | ExecutionEvent::OutputHandlerFailed { .. } => None,
+                | ExecutionEvent::OutputHandlerFailed { .. }
+                | ExecutionEvent::OutputModerated { .. } => None,
             },
             DomainEvent::Workflow(WorkflowEvent::WorkflowIterationStarted {
                 iteration_number,
@@ -1023,17 +1078,21 @@ impl DomainEvent {
                 | ExecutionEvent::IterationCompleted { .. }
                 | ExecutionEvent::IterationFailed { .. }
                 | ExecutionEvent::RefinementApplied { .. }
+                | ExecutionEvent::OperatorGuidanceAdded { .. }
                 | ExecutionEvent::Validation(_) => "iteration",
                 ExecutionEvent::ConsoleOutput { .. } => "console",
                 ExecutionEvent::LlmInteraction { .. } => "llm",
                 ExecutionEvent::LlmCallFailed { .. } => "llm",
+                ExecutionEvent::ModelRouted { .. } => "llm",
                 ExecutionEvent::InstanceSpawned { .. }
                 | ExecutionEvent::InstanceTerminated { .. } => "runtime",
+                ExecutionEvent::StorageTransportInterrupted { .. } => "storage",
                 ExecutionEvent::ChildExecutionSpawned { .. }
                 | ExecutionEvent::ChildExecutionCompleted { .. } => "execution",
                 ExecutionEvent::OutputHandlerStarted { .. }
                 | ExecutionEvent::OutputHandlerCompleted { .. }
-                | ExecutionEvent::OutputHandlerFailed { .. } => "output_handler",
+                | ExecutionEvent::OutputHandlerFailed { .. }
+                | ExecutionEvent::OutputModerated { .. } => "output_handler",
             }),
             DomainEvent::Workflow(_) => Some("workflow"),
             DomainEvent::Learning(_) => Some("learning"),
@@ -1059,20 +1118,359 @@ impl DomainEvent {
     }
 }
 
+/// What the bus does when a subscriber's bounded queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OverflowPolicy {
+    /// Evict the oldest buffered event and report the gap to the subscriber
+    /// as [`EventBusError::Lagged`] on its next receive (default; matches the
+    /// historical broadcast-channel semantics).
+    #[default]
+    DropOldest,
+    /// Drop the subscriber entirely. Its receiver yields
+    /// [`EventBusError::Disconnected`] once and then [`EventBusError::Closed`].
+    Disconnect,
+    /// Stall the publisher until the subscriber frees capacity. Reserved for
+    /// audit-critical consumers (event persisters) that must never miss an
+    /// event. When the publisher cannot block (current-thread runtime) or the
+    /// block timeout elapses, the event is enqueued past capacity rather than
+    /// dropped.
+    Block,
+}
+
+impl OverflowPolicy {
+    fn as_str(&self) -> &'static str {
+        match self {
+            OverflowPolicy::DropOldest => "drop_oldest",
+            OverflowPolicy::Disconnect => "disconnect",
+            OverflowPolicy::Block => "block",
+        }
+    }
+}
+
+/// Per-subscriber queue configuration passed to [`EventBus::subscribe_with`].
+#[derive(Debug, Clone)]
+pub struct SubscriptionOptions {
+    /// Stable subscriber name used as the `subscriber` metric label. Must be
+    /// a static identifier — never a tenant or execution ID.
+    pub name: &'static str,
+    /// Queue capacity. `None` uses the bus default.
+    pub capacity: Option<usize>,
+    pub overflow: OverflowPolicy,
+    /// Upper bound on how long a [`OverflowPolicy::Block`] subscriber may stall
+    /// a single publish.
+    pub block_timeout: Duration,
+}
+
+impl SubscriptionOptions {
+    /// Best-effort subscriber with the default drop-oldest policy.
+    pub fn named(name: &'static str) -> Self {
+        Self {
+            name,
+            capacity: None,
+            overflow: OverflowPolicy::DropOldest,
+            block_timeout: DEFAULT_BLOCK_TIMEOUT,
+        }
+    }
+
+    /// Audit-critical subscriber that back-pressures publishers instead of
+    /// losing events.
+    pub fn critical(name: &'static str) -> Self {
+        Self {
+            overflow: OverflowPolicy::Block,
+            ..Self::named(name)
+        }
+    }
+
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    pub fn with_overflow(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    pub fn with_block_timeout(mut self, timeout: Duration) -> Self {
+        self.block_timeout = timeout;
+        self
+    }
+}
+
+impl Default for SubscriptionOptions {
+    fn default() -> Self {
+        Self::named(ANONYMOUS_SUBSCRIBER)
+    }
+}
+
+const ANONYMOUS_SUBSCRIBER: &str = "anonymous";
+const DEFAULT_BLOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Default)]
+struct QueueState {
+    buffer: VecDeque<DomainEvent>,
+    /// Events evicted since the subscriber last observed a `Lagged` error.
+    lagged: u64,
+    closed: bool,
+    disconnected: bool,
+    disconnect_reported: bool,
+}
+
+/// A single subscriber's bounded queue. The bus holds a `Weak` reference so a
+/// dropped receiver unsubscribes itself without explicit bookkeeping.
+struct SubscriberQueue {
+    name: &'static str,
+    capacity: usize,
+    overflow: OverflowPolicy,
+    block_timeout: Duration,
+    state: Mutex<QueueState>,
+    /// Wakes the (single) consumer when an event arrives or the queue closes.
+    ready: Notify,
+    /// Wakes a blocked publisher when the consumer frees capacity.
+    space: Condvar,
+}
+
+impl SubscriberQueue {
+    fn new(options: SubscriptionOptions, default_capacity: usize) -> Self {
+        Self {
+            name: options.name,
+            capacity: options.capacity.unwrap_or(default_capacity).max(1),
+            overflow: options.overflow,
+            block_timeout: options.block_timeout,
+            state: Mutex::new(QueueState::default()),
+            ready: Notify::new(),
+            space: Condvar::new(),
+        }
+    }
+
+    fn is_disconnected(&self) -> bool {
+        self.state.lock().disconnected
+    }
+
+    /// Enqueue an event according to the subscriber's overflow policy.
+    fn push(&self, event: DomainEvent) {
+        let mut state = self.state.lock();
+        if state.closed || state.disconnected {
+            return;
+        }
+
+        if state.buffer.len() >= self.capacity {
+            match self.overflow {
+                OverflowPolicy::DropOldest => {
+                    state.buffer.pop_front();
+                    state.lagged += 1;
+                    self.record_dropped();
+                }
+                OverflowPolicy::Disconnect => {
+                    let pending = state.buffer.len() as u64;
+                    state.buffer.clear();
+                    state.lagged += pending + 1;
+                    state.disconnected = true;
+                    drop(state);
+                    warn!(
+                        subscriber = self.name,
+                        capacity = self.capacity,
+                        "Event bus subscriber overflowed its queue and was disconnected"
+                    );
+                    metrics::counter!(
+                        "aegis_event_bus_subscriber_disconnected_total",
+                        "subscriber" => self.name
+                    )
+                    .increment(1);
+                    self.ready.notify_one();
+                    return;
+                }
+                OverflowPolicy::Block => {
+                    self.wait_for_space(&mut state);
+                }
+            }
+        }
+
+        state.buffer.push_back(event);
+        metrics::gauge!("aegis_event_bus_subscriber_queue_depth", "subscriber" => self.name)
+            .set(state.buffer.len() as f64);
+        drop(state);
+        self.ready.notify_one();
+    }
+
+    /// Stall the publisher until capacity frees up or the block timeout
+    /// elapses. Never drops: on timeout the caller enqueues past capacity.
+    fn wait_for_space(&self, state: &mut MutexGuard<'_, QueueState>) {
+        metrics::counter!("aegis_event_bus_publisher_blocked_total", "subscriber" => self.name)
+            .increment(1);
+
+        let deadline = Instant::now() + self.block_timeout;
+        match publisher_block_mode() {
+            BlockMode::InPlace => {
+                tokio::task::block_in_place(|| self.wait_until(state, deadline));
+            }
+            BlockMode::Direct => self.wait_until(state, deadline),
+            BlockMode::Unavailable => {}
+        }
+
+        if state.buffer.len() >= self.capacity && !state.closed {
+            warn!(
+                subscriber = self.name,
+                depth = state.buffer.len(),
+                "Critical event bus subscriber is full; enqueueing past capacity"
+            );
+            metrics::counter!("aegis_event_bus_overflow_total", "subscriber" => self.name)
+                .increment(1);
+        }
+    }
+
+    fn wait_until(&self, state: &mut MutexGuard<'_, QueueState>, deadline: Instant) {
+        while state.buffer.len() >= self.capacity && !state.closed {
+            if self.space.wait_until(state, deadline).timed_out() {
+                break;
+            }
+        }
+    }
+
+    fn record_dropped(&self) {
+        metrics::counter!(
+            "aegis_event_bus_dropped_total",
+            "subscriber" => self.name,
+            "policy" => self.overflow.as_str()
+        )
+        .increment(1);
+    }
+
+    fn close(&self) {
+        self.state.lock().closed = true;
+        self.space.notify_all();
+        self.ready.notify_one();
+    }
+
+    /// Pop the next event, or report lag / closure. Returns `None` when the
+    /// queue is open but empty.
+    fn poll_next(&self) -> Option<Result<DomainEvent, EventBusError>> {
+        let mut state = self.state.lock();
+
+        if state.disconnected {
+            if state.disconnect_reported {
+                return Some(Err(EventBusError::Closed));
+            }
+            state.disconnect_reported = true;
+            let lost = std::mem::take(&mut state.lagged);
+            return Some(Err(EventBusError::Disconnected(lost)));
+        }
+
+        if state.lagged > 0 {
+            let n = std::mem::take(&mut state.lagged);
+            drop(state);
+            warn!(
+                subscriber = self.name,
+                "Event receiver lagged by {} events", n
+            );
+            metrics::gauge!("aegis_event_bus_subscriber_lag", "subscriber" => self.name)
+                .set(n as f64);
+            return Some(Err(EventBusError::Lagged(n)));
+        }
+
+        if let Some(event) = state.buffer.pop_front() {
+            metrics::gauge!("aegis_event_bus_subscriber_queue_depth", "subscriber" => self.name)
+                .set(state.buffer.len() as f64);
+            drop(state);
+            self.space.notify_one();
+            return Some(Ok(event));
+        }
+
+        if state.closed {
+            return Some(Err(EventBusError::Closed));
+        }
+        None
+    }
+
+    async fn recv(&self) -> Result<DomainEvent, EventBusError> {
+        loop {
+            if let Some(result) = self.poll_next() {
+                return result;
+            }
+            // `notify_one` stores a permit when nobody is waiting, so a publish
+            // racing between `poll_next` and this await is never lost.
+            self.ready.notified().await;
+        }
+    }
+
+    fn try_recv(&self) -> Result<DomainEvent, EventBusError> {
+        self.poll_next().unwrap_or(Err(EventBusError::Empty))
+    }
+}
+
+enum BlockMode {
+    /// Multi-threaded tokio runtime: hand the worker off via `block_in_place`.
+    InPlace,
+    /// Plain OS thread outside any runtime.
+    Direct,
+    /// Current-thread runtime: blocking would deadlock the consumer.
+    Unavailable,
+}
+
+fn publisher_block_mode() -> BlockMode {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => match handle.runtime_flavor() {
+            tokio::runtime::RuntimeFlavor::MultiThread => BlockMode::InPlace,
+            _ => BlockMode::Unavailable,
+        },
+        Err(_) => BlockMode::Direct,
+    }
+}
+
+/// Receiving half of a subscription. Dropping it unsubscribes.
+struct QueueReceiver {
+    queue: Arc<SubscriberQueue>,
+}
+
+impl QueueReceiver {
+    async fn recv(&mut self) -> Result<DomainEvent, EventBusError> {
+        self.queue.recv().await
+    }
+
+    fn try_recv(&mut self) -> Result<DomainEvent, EventBusError> {
+        self.queue.try_recv()
+    }
+}
+
+struct BusInner {
+    subscribers: RwLock<Vec<Weak<SubscriberQueue>>>,
+    default_capacity: usize,
+    /// Running executions whose agent asked for less than
+    /// [`EventVerbosity::Full`]; dropped when the execution ends.
+    verbosity: RwLock<HashMap<ExecutionId, EventVerbosity>>,
+}
+
+impl Drop for BusInner {
+    /// The last `EventBus` handle going away closes every live subscription,
+    /// mirroring a broadcast channel whose senders have all dropped.
+    fn drop(&mut self) {
+        for queue in self.subscribers.get_mut().iter().filter_map(Weak::upgrade) {
+            queue.close();
+        }
+    }
+}
+
 /// Event bus for publishing and subscribing to domain events
+///
+/// Every subscriber owns a bounded queue with its own [`OverflowPolicy`], so a
+/// slow SSE client can never cause the audit persisters to miss events.
 #[derive(Clone)]
 pub struct EventBus {
-    sender: Arc<broadcast::Sender<DomainEvent>>,
+    inner: Arc<BusInner>,
 }
 
 impl EventBus {
     /// Create a new event bus with specified channel capacity
-    /// Capacity determines how many events can be buffered before dropping old ones
+    /// Capacity is the default per-subscriber queue size; subscribers may
+    /// override it via [`SubscriptionOptions::with_capacity`].
     /// Default: 1000 events
     pub fn new(capacity: usize) -> Self {
-        let (sender, _) = broadcast::channel(capacity);
         Self {
-            sender: Arc::new(sender),
+            inner: Arc::new(BusInner {
+                subscribers: RwLock::new(Vec::new()),
+                default_capacity: capacity.max(1),
+                verbosity: RwLock::new(HashMap::new()),
+            }),
         }
     }
 
@@ -1086,11 +1484,48 @@ impl EventBus {
         self.publish(DomainEvent::AgentLifecycle(event));
     }
 
-    /// Publish an execution event
+    /// Publish an execution event, at the verbosity registered for its
+    /// execution with [`Self::set_execution_verbosity`].
     pub fn publish_execution_event(&self, event: ExecutionEvent) {
+        let verbosity = match &event {
+            ExecutionEvent::ConsoleOutput { execution_id, .. }
+            | ExecutionEvent::LlmInteraction { execution_id, .. }
+            | ExecutionEvent::ModelRouted { execution_id, .. } => {
+                self.inner.verbosity.read().get(execution_id).copied()
+            }
+            ExecutionEvent::ExecutionCompleted { execution_id, .. }
+            | ExecutionEvent::ExecutionFailed { execution_id, .. }
+            | ExecutionEvent::ExecutionCancelled { execution_id, .. }
+            | ExecutionEvent::ExecutionTimedOut { execution_id, .. } => {
+                self.inner.verbosity.write().remove(execution_id);
+                None
+            }
+            _ => None,
+        };
+        let event = match verbosity {
+            Some(verbosity) => match event.at_verbosity(verbosity) {
+                Some(event) => event,
+                None => {
+                    metrics::counter!("aegis_event_bus_suppressed_total").increment(1);
+                    return;
+                }
+            },
+            None => event,
+        };
         self.publish(DomainEvent::Execution(event));
     }
 
+    /// Apply `verbosity` to the console output and LLM events of
+    /// `execution_id` until it completes, fails, is cancelled or times out.
+    pub fn set_execution_verbosity(&self, execution_id: ExecutionId, verbosity: EventVerbosity) {
+        let mut registry = self.inner.verbosity.write();
+        if verbosity.is_full() {
+            registry.remove(&execution_id);
+        } else {
+            registry.insert(execution_id, verbosity);
+        }
+    }
+
     /// Publish a workflow event
     pub fn publish_workflow_event(&self, event: WorkflowEvent) {
         // ADR-087 §Observability: record agent cache hit when the pipeline
@@ -1214,20 +1649,65 @@ impl EventBus {
         let event_type = domain_event_type(&event);
         metrics::counter!("aegis_event_bus_published_total", "event_type" => event_type)
             .increment(1);
-        if self.sender.send(event).is_err() {
+
+        // Snapshot live queues so a blocking subscriber never holds the
+        // registry lock against concurrent subscribe/publish calls.
+        let (queues, stale) = {
+            let subscribers = self.inner.subscribers.read();
+            let queues: Vec<Arc<SubscriberQueue>> = subscribers
+                .iter()
+                .filter_map(Weak::upgrade)
+                .filter(|q| !q.is_disconnected())
+                .collect();
+            let stale = queues.len() != subscribers.len();
+            (queues, stale)
+        };
+        if stale {
+            self.prune();
+        }
+
+        let Some((last, rest)) = queues.split_last() else {
             metrics::counter!(
                 "aegis_event_bus_delivery_failures_total",
                 "reason" => "no_receivers"
             )
             .increment(1);
+            return;
+        };
+        for queue in rest {
+            queue.push(event.clone());
         }
+        last.push(event);
+    }
+
+    fn prune(&self) {
+        self.inner
+            .subscribers
+            .write()
+            .retain(|w| w.upgrade().is_some_and(|q| !q.is_disconnected()));
+    }
+
+    fn register(&self, options: SubscriptionOptions) -> QueueReceiver {
+        let queue = Arc::new(SubscriberQueue::new(options, self.inner.default_capacity));
+        let mut subscribers = self.inner.subscribers.write();
+        subscribers.retain(|w| w.strong_count() > 0);
+        subscribers.push(Arc::downgrade(&queue));
+        QueueReceiver { queue }
     }
 
     /// Subscribe to all domain events
     /// Returns a receiver that can be used to listen for events
     pub fn subscribe(&self) -> EventReceiver {
-        let receiver = self.sender.subscribe();
-        EventReceiver { receiver }
+        self.subscribe_with(SubscriptionOptions::default())
+    }
+
+    /// Subscribe to all domain events with an explicit queue capacity and
+    /// overflow policy. Persisters and other audit-critical consumers should
+    /// use [`SubscriptionOptions::critical`].
+    pub fn subscribe_with(&self, options: SubscriptionOptions) -> EventReceiver {
+        EventReceiver {
+            receiver: self.register(options),
+        }
     }
 
     /// Subscribe and filter for specific execution ID
@@ -1236,9 +1716,8 @@ impl EventBus {
         &self,
         execution_id: crate::domain::execution::ExecutionId,
     ) -> ExecutionEventReceiver {
-        let receiver = self.sender.subscribe();
         ExecutionEventReceiver {
-            receiver,
+            receiver: self.register(SubscriptionOptions::named("execution_stream")),
             execution_id,
         }
     }
@@ -1249,7 +1728,7 @@ impl EventBus {
         execution_id: crate::domain::execution::ExecutionId,
     ) -> ExecutionDomainEventReceiver {
         ExecutionDomainEventReceiver {
-            receiver: self.sender.subscribe(),
+            receiver: self.register(SubscriptionOptions::named("execution_domain_stream")),
             execution_id,
         }
     }
@@ -1262,78 +1741,64 @@ impl EventBus {
         id: crate::domain::execution::ExecutionId,
     ) -> WorkflowEventReceiver {
         WorkflowEventReceiver {
-            receiver: self.sender.subscribe(),
+            receiver: self.register(SubscriptionOptions::named("workflow_stream")),
             execution_id: id,
         }
     }
 
     /// Subscribe and filter for specific agent ID
     pub fn subscribe_agent(&self, agent_id: crate::domain::agent::AgentId) -> AgentEventReceiver {
-        let receiver = self.sender.subscribe();
-        AgentEventReceiver { receiver, agent_id }
+        AgentEventReceiver {
+            receiver: self.register(SubscriptionOptions::named("agent_stream")),
+            agent_id,
+        }
     }
 
     /// Get the number of active subscribers
     pub fn subscriber_count(&self) -> usize {
-        self.sender.receiver_count()
+        self.inner
+            .subscribers
+            .read()
+            .iter()
+            .filter_map(Weak::upgrade)
+            .filter(|q| !q.is_disconnected())
+            .count()
     }
 }
 
 /// Receiver for all domain events
 pub struct EventReceiver {
-    receiver: broadcast::Receiver<DomainEvent>,
+    receiver: QueueReceiver,
 }
 
 impl EventReceiver {
     /// Receive the next event (blocks until event is available)
     pub async fn recv(&mut self) -> Result<DomainEvent, EventBusError> {
-        self.receiver.recv().await.map_err(|e| match e {
-            broadcast::error::RecvError::Closed => EventBusError::Closed,
-            broadcast::error::RecvError::Lagged(n) => {
-                warn!("Event receiver lagged by {} events", n);
-                metrics::gauge!("aegis_event_bus_subscriber_lag").set(n as f64);
-                EventBusError::Lagged(n)
-            }
-        })
+        self.receiver.recv().await
     }
 
     /// Try to receive an event without blocking
     pub fn try_recv(&mut self) -> Result<DomainEvent, EventBusError> {
-        self.receiver.try_recv().map_err(|e| match e {
-            broadcast::error::TryRecvError::Empty => EventBusError::Empty,
-            broadcast::error::TryRecvError::Closed => EventBusError::Closed,
-            broadcast::error::TryRecvError::Lagged(n) => {
-                warn!("Event receiver lagged by {} events", n);
-                metrics::gauge!("aegis_event_bus_subscriber_lag").set(n as f64);
-                EventBusError::Lagged(n)
-            }
-        })
+        self.receiver.try_recv()
     }
 }
 
 /// Receiver for execution-specific events (filtered)
 pub struct ExecutionEventReceiver {
-    receiver: broadcast::Receiver<DomainEvent>,
+    receiver: QueueReceiver,
     execution_id: crate::domain::execution::ExecutionId,
 }
 
 /// Receiver for all domain events correlated to a specific execution.
 pub struct ExecutionDomainEventReceiver {
-    receiver: broadcast::Receiver<DomainEvent>,
+    receiver: QueueReceiver,
     execution_id: crate::domain::execution::ExecutionId,
 }
 
 impl ExecutionDomainEventReceiver {
     pub async fn recv(&mut self) -> Result<DomainEvent, EventBusError> {
         loop {
-            let event = self.receiver.recv().await.map_err(|e| match e {
-                broadcast::error::RecvError::Closed => EventBusError::Closed,
-                broadcast::error::RecvError::Lagged(n) => {
-                    warn!("Event receiver lagged by {} events", n);
-                    metrics::gauge!("aegis_event_bus_subscriber_lag").set(n as f64);
-                    EventBusError::Lagged(n)
-                }
-            })?;
+            let event = self.receiver.recv().await?;
 
             if event.execution_id() == Some(self.execution_id) {
                 return Ok(event);
@@ -1347,14 +1812,7 @@ impl ExecutionEventReceiver {
     /// Filters out events from other executions
     pub async fn recv(&mut self) -> Result<ExecutionEvent, EventBusError> {
         loop {
-            let event = self.receiver.recv().await.map_err(|e| match e {
-                broadcast::error::RecvError::Closed => EventBusError::Closed,
-                broadcast::error::RecvError::Lagged(n) => {
-                    warn!("Event receiver lagged by {} events", n);
-                    metrics::gauge!("aegis_event_bus_subscriber_lag").set(n as f64);
-                    EventBusError::Lagged(n)
-                }
-            })?;
+            let event = self.receiver.recv().await?;
 
             // Filter for execution events matching our ID
             if let DomainEvent::Execution(exec_event) = event {
@@ -1401,12 +1859,19 @@ impl ExecutionEventReceiver {
             ExecutionEvent::LlmCallFailed { execution_id, .. } => {
                 execution_id == &self.execution_id
             }
+            ExecutionEvent::ModelRouted { execution_id, .. } => execution_id == &self.execution_id,
+            ExecutionEvent::OperatorGuidanceAdded { execution_id, .. } => {
+                execution_id == &self.execution_id
+            }
             ExecutionEvent::InstanceSpawned { execution_id, .. } => {
                 execution_id == &self.execution_id
             }
             ExecutionEvent::InstanceTerminated { execution_id, .. } => {
                 execution_id == &self.execution_id
             }
+            ExecutionEvent::StorageTransportInterrupted { execution_id, .. } => {
+                execution_id == &self.execution_id
+            }
             ExecutionEvent::ExecutionTimedOut { execution_id, .. } => {
                 execution_id == &self.execution_id
             }
@@ -1426,7 +1891,8 @@ impl ExecutionEventReceiver {
             },
             ExecutionEvent::OutputHandlerStarted { execution_id, .. }
             | ExecutionEvent::OutputHandlerCompleted { execution_id, .. }
-            | ExecutionEvent::OutputHandlerFailed { execution_id, .. } => {
+            | ExecutionEvent::OutputHandlerFailed { execution_id, .. }
+            | ExecutionEvent::OutputModerated { execution_id, .. } => {
                 execution_id == &self.execution_id
             }
         }
@@ -1435,7 +1901,7 @@ impl ExecutionEventReceiver {
 
 /// Filtered receiver for workflow-execution–scoped domain events.
 pub struct WorkflowEventReceiver {
-    receiver: broadcast::Receiver<DomainEvent>,
+    receiver: QueueReceiver,
     execution_id: crate::domain::execution::ExecutionId,
 }
 
@@ -1451,14 +1917,11 @@ impl WorkflowEventReceiver {
                     }
                 }
                 Ok(_) => continue,
-                Err(broadcast::error::RecvError::Lagged(n)) => {
+                Err(EventBusError::Lagged(n)) => {
                     warn!("WorkflowEventReceiver lagged by {} events", n);
-                    metrics::gauge!("aegis_event_bus_subscriber_lag").set(n as f64);
                     continue;
                 }
-                Err(broadcast::error::RecvError::Closed) => {
-                    return Err(EventBusError::Closed);
-                }
+                Err(e) => return Err(e),
             }
         }
     }
@@ -1469,6 +1932,7 @@ impl WorkflowEventReceiver {
             WorkflowEvent::WorkflowExecutionStarted { execution_id, .. }
             | WorkflowEvent::WorkflowStateEntered { execution_id, .. }
             | WorkflowEvent::WorkflowStateExited { execution_id, .. }
+            | WorkflowEvent::WorkflowStateStuck { execution_id, .. }
             | WorkflowEvent::WorkflowIterationStarted { execution_id, .. }
             | WorkflowEvent::WorkflowIterationCompleted { execution_id, .. }
             | WorkflowEvent::WorkflowIterationFailed { execution_id, .. }
@@ -1484,7 +1948,7 @@ impl WorkflowEventReceiver {
 
 /// Receiver for agent-specific events (filtered)
 pub struct AgentEventReceiver {
-    receiver: broadcast::Receiver<DomainEvent>,
+    receiver: QueueReceiver,
     agent_id: crate::domain::agent::AgentId,
 }
 
@@ -1492,14 +1956,7 @@ impl AgentEventReceiver {
     /// Receive the next event for the specified agent ID
     pub async fn recv(&mut self) -> Result<DomainEvent, EventBusError> {
         loop {
-            let event = self.receiver.recv().await.map_err(|e| match e {
-                broadcast::error::RecvError::Closed => EventBusError::Closed,
-                broadcast::error::RecvError::Lagged(n) => {
-                    warn!("Event receiver lagged by {} events", n);
-                    metrics::gauge!("aegis_event_bus_subscriber_lag").set(n as f64);
-                    EventBusError::Lagged(n)
-                }
-            })?;
+            let event = self.receiver.recv().await?;
 
             if self.matches_agent(&event) {
                 return Ok(event);
@@ -1532,8 +1989,15 @@ impl AgentEventReceiver {
                 ExecutionEvent::ConsoleOutput { agent_id, .. } => agent_id == &self.agent_id,
                 ExecutionEvent::LlmInteraction { agent_id, .. } => agent_id == &self.agent_id,
                 ExecutionEvent::LlmCallFailed { agent_id, .. } => agent_id == &self.agent_id,
+                ExecutionEvent::ModelRouted { agent_id, .. } => agent_id == &self.agent_id,
+                ExecutionEvent::OperatorGuidanceAdded { agent_id, .. } => {
+                    agent_id == &self.agent_id
+                }
                 ExecutionEvent::InstanceSpawned { agent_id, .. } => agent_id == &self.agent_id,
                 ExecutionEvent::InstanceTerminated { agent_id, .. } => agent_id == &self.agent_id,
+                ExecutionEvent::StorageTransportInterrupted { agent_id, .. } => {
+                    agent_id == &self.agent_id
+                }
                 ExecutionEvent::ExecutionTimedOut { agent_id, .. } => agent_id == &self.agent_id,
                 ExecutionEvent::ChildExecutionSpawned { agent_id, .. } => {
                     agent_id == &self.agent_id
@@ -1551,7 +2015,8 @@ impl AgentEventReceiver {
                 },
                 ExecutionEvent::OutputHandlerStarted { .. }
                 | ExecutionEvent::OutputHandlerCompleted { .. }
-                | ExecutionEvent::OutputHandlerFailed { .. } => false, // not agent-scoped
+                | ExecutionEvent::OutputHandlerFailed { .. }
+                | ExecutionEvent::OutputModerated { .. } => false, // not agent-scoped
             },
             DomainEvent::Learning(e) => match e {
                 LearningEvent::PatternDiscovered { agent_id, .. } => agent_id == &self.agent_id,
@@ -1625,6 +2090,9 @@ pub enum EventBusError {
 
     #[error("Receiver lagged by {0} events (events were dropped)")]
     Lagged(u64),
+
+    #[error("Receiver overflowed its queue and was disconnected ({0} events lost)")]
+    Disconnected(u64),
 }
 
 impl Default for EventBus {
@@ -1663,6 +2131,11 @@ mod tests {
                         version: Some("3.11".to_string()),
                         image: None,
                         image_pull_policy: crate::domain::agent::ImagePullPolicy::IfNotPresent,
+                        base_image: None,
+                        packages: Vec::new(),
+                        env: Vec::new(),
+                        sidecars: Vec::new(),
+                        platform: None,
                         isolation: "docker".to_string(),
                         model: "default".to_string(),
                         temperature: None,
@@ -1679,6 +2152,8 @@ mod tests {
                     input_schema: None,
                     security_context: None,
                     output_handler: None,
+                    concurrency: None,
+                    healthcheck: None,
                 },
             },
             deployed_at: Utc::now(),
@@ -1759,6 +2234,127 @@ mod tests {
         let _ = receiver2.recv().await.unwrap();
     }
 
+    fn paused_event() -> AgentLifecycleEvent {
+        AgentLifecycleEvent::AgentPaused {
+            agent_id: crate::domain::agent::AgentId::new(),
+            paused_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drop_oldest_reports_lag_then_resumes() {
+        let event_bus = EventBus::new(2);
+        let mut receiver = event_bus.subscribe();
+
+        for _ in 0..5 {
+            event_bus.publish_agent_event(paused_event());
+        }
+
+        assert!(matches!(receiver.try_recv(), Err(EventBusError::Lagged(3))));
+        assert!(receiver.try_recv().is_ok());
+        assert!(receiver.try_recv().is_ok());
+        assert!(matches!(receiver.try_recv(), Err(EventBusError::Empty)));
+    }
+
+    #[tokio::test]
+    async fn test_slow_subscriber_does_not_affect_others() {
+        let event_bus = EventBus::new(8);
+        let mut slow =
+            event_bus.subscribe_with(SubscriptionOptions::named("slow").with_capacity(1));
+        let mut fast = event_bus.subscribe();
+
+        for _ in 0..4 {
+            event_bus.publish_agent_event(paused_event());
+        }
+
+        for _ in 0..4 {
+            assert!(fast.try_recv().is_ok());
+        }
+        assert!(matches!(slow.try_recv(), Err(EventBusError::Lagged(3))));
+    }
+
+    #[tokio::test]
+    async fn test_disconnect_policy_drops_subscriber() {
+        let event_bus = EventBus::new(8);
+        let mut receiver = event_bus.subscribe_with(
+            SubscriptionOptions::named("disconnect")
+                .with_capacity(2)
+                .with_overflow(OverflowPolicy::Disconnect),
+        );
+        assert_eq!(event_bus.subscriber_count(), 1);
+
+        for _ in 0..3 {
+            event_bus.publish_agent_event(paused_event());
+        }
+
+        assert_eq!(event_bus.subscriber_count(), 0);
+        assert!(matches!(
+            receiver.recv().await,
+            Err(EventBusError::Disconnected(3))
+        ));
+        assert!(matches!(receiver.recv().await, Err(EventBusError::Closed)));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_block_policy_never_drops_events() {
+        let event_bus = EventBus::new(8);
+        let mut receiver = event_bus.subscribe_with(
+            SubscriptionOptions::critical("persister")
+                .with_capacity(2)
+                .with_block_timeout(Duration::from_secs(10)),
+        );
+
+        let publisher = {
+            let event_bus = event_bus.clone();
+            tokio::spawn(async move {
+                for _ in 0..50 {
+                    event_bus.publish_agent_event(paused_event());
+                }
+            })
+        };
+
+        for _ in 0..50 {
+            assert!(receiver.recv().await.is_ok());
+        }
+        publisher.await.unwrap();
+        assert!(matches!(receiver.try_recv(), Err(EventBusError::Empty)));
+    }
+
+    #[tokio::test]
+    async fn test_block_policy_overflows_instead_of_deadlocking_current_thread() {
+        let event_bus = EventBus::new(8);
+        let mut receiver =
+            event_bus.subscribe_with(SubscriptionOptions::critical("persister").with_capacity(1));
+
+        for _ in 0..3 {
+            event_bus.publish_agent_event(paused_event());
+        }
+
+        for _ in 0..3 {
+            assert!(receiver.try_recv().is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dropping_bus_closes_receivers() {
+        let event_bus = EventBus::new(8);
+        let mut receiver = event_bus.subscribe();
+        event_bus.publish_agent_event(paused_event());
+        drop(event_bus);
+
+        assert!(receiver.recv().await.is_ok());
+        assert!(matches!(receiver.recv().await, Err(EventBusError::Closed)));
+    }
+
+    #[tokio::test]
+    async fn test_dropped_receiver_unsubscribes() {
+        let event_bus = EventBus::new(8);
+        let receiver = event_bus.subscribe();
+        assert_eq!(event_bus.subscriber_count(), 1);
+        drop(receiver);
+        assert_eq!(event_bus.subscriber_count(), 0);
+    }
+
     #[tokio::test]
     async fn test_execution_domain_receiver_includes_storage_events() {
         let event_bus = EventBus::new(10);
@@ -1768,6 +2364,7 @@ mod tests {
         event_bus.publish_storage_event(StorageEvent::FileOpened {
             execution_id: Some(execution_id),
             workflow_execution_id: None,
+            iteration_number: None,
             volume_id: crate::domain::volume::VolumeId::new(),
             path: "/workspace/src/main.rs".to_string(),
             open_mode: "read".to_string(),
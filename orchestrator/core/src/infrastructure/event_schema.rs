@@ -0,0 +1,494 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Versioned Event Envelopes and Event Schema Registry (ADR-030)
+//!
+//! Transports do not put a bare [`DomainEvent`] on the wire. They wrap it in an
+//! [`EventEnvelope`] that names the event with a qualified `event_type`
+//! (`"{category}.{variant}"`, e.g. `execution.iteration_completed`) and carries
+//! the `schema_version` of that event type's payload.
+//!
+//! [`event_schemas`] describes every event type the bus can carry. It is
+//! generated from the domain enums by probing their `Deserialize` impls, so a
+//! new variant is listed without registering it here. Payload fields are
+//! listed for externally tagged enums; the internally tagged `TeamEvent` only
+//! exposes its variant names this way.
+//!
+//! ## Versioning
+//!
+//! Every event type starts at [`INITIAL_SCHEMA_VERSION`]. Adding a payload
+//! field is compatible. Removing or renaming one is not: add the event type
+//! to `SCHEMA_VERSION_BUMPS` with its new version and update the snapshot in
+//! `tests/event_schema_compat_tests.rs`, which fails on any unversioned
+//! breaking change.
+
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use serde::de::value::{StrDeserializer, U32Deserializer};
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess,
+    VariantAccess, Visitor,
+};
+use serde::{forward_to_deserialize_any, Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::domain::cluster::ClusterEvent;
+use crate::domain::events::{
+    AgentLifecycleEvent, CanvasEvent, ContainerRunEvent, CredentialEvent, DriftEvent,
+    ExecutionEvent, GitRepoEvent, IamEvent, ImageManagementEvent, LearningEvent, MCPToolEvent,
+    PolicyEvent, RateLimitEvent, ScriptEvent, SealEvent, SecretEvent, StimulusEvent, StorageEvent,
+    SwarmEvent, TeamEvent, TenantEvent, ValidationEvent, VolumeEvent, WorkflowEvent,
+};
+use crate::infrastructure::event_bus::DomainEvent;
+
+/// Payload version every event type starts at.
+pub const INITIAL_SCHEMA_VERSION: u32 = 1;
+
+/// Event types whose payload had a breaking change, with their current version.
+const SCHEMA_VERSION_BUMPS: &[(&str, u32)] = &[];
+
+/// Categories whose event enum is internally tagged, with the tag field.
+const INTERNALLY_TAGGED: &[(&str, &str)] = &[("team", "kind")];
+
+/// Current payload version of `event_type`.
+pub fn schema_version(event_type: &str) -> u32 {
+    SCHEMA_VERSION_BUMPS
+        .iter()
+        .find(|(bumped, _)| *bumped == event_type)
+        .map_or(INITIAL_SCHEMA_VERSION, |(_, version)| *version)
+}
+
+/// Wire form of a [`DomainEvent`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventEnvelope {
+    pub schema_version: u32,
+    /// `"{category}.{variant}"` in snake case.
+    pub event_type: String,
+    pub category: String,
+    pub occurred_at: DateTime<Utc>,
+    /// The variant's fields, without any enum tagging.
+    pub payload: Value,
+}
+
+impl EventEnvelope {
+    pub fn new(event: &DomainEvent) -> Self {
+        let category = event.category();
+        let (variant, payload) =
+            split_variant(category, serde_json::to_value(event).unwrap_or(Value::Null));
+        let event_type = qualified_event_type(category, &variant);
+        Self {
+            schema_version: schema_version(&event_type),
+            event_type,
+            category: category.to_string(),
+            occurred_at: event.timestamp(),
+            payload,
+        }
+    }
+}
+
+/// One entry of the event schema registry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventSchema {
+    pub event_type: String,
+    pub category: &'static str,
+    /// Variant name as serialized by the domain enum.
+    pub variant: &'static str,
+    pub schema_version: u32,
+    /// Payload field names in declaration order; `None` when the enum's
+    /// representation does not expose them.
+    pub fields: Option<Vec<&'static str>>,
+}
+
+/// Every event type the bus can carry, ordered by category then declaration.
+pub fn event_schemas() -> Vec<EventSchema> {
+    let mut schemas = Vec::new();
+    for &(category, variants_of) in EVENT_FAMILIES {
+        for variant in variants_of(internal_tag(category)) {
+            let expanded = match (variant.newtype, nested_variants(category, variant.name)) {
+                (true, Some(nested)) => nested,
+                _ => vec![variant],
+            };
+            for variant in expanded {
+                let event_type = qualified_event_type(category, variant.name);
+                schemas.push(EventSchema {
+                    schema_version: schema_version(&event_type),
+                    event_type,
+                    category,
+                    variant: variant.name,
+                    fields: variant.fields,
+                });
+            }
+        }
+    }
+    schemas
+}
+
+type EventFamily = (&'static str, fn(Option<&'static str>) -> Vec<VariantShape>);
+
+/// Inner event enum of every [`DomainEvent`] variant, keyed by category.
+const EVENT_FAMILIES: &[EventFamily] = &[
+    ("agent_lifecycle", variants_of::<AgentLifecycleEvent>),
+    ("execution", variants_of::<ExecutionEvent>),
+    ("workflow", variants_of::<WorkflowEvent>),
+    ("learning", variants_of::<LearningEvent>),
+    ("policy", variants_of::<PolicyEvent>),
+    ("volume", variants_of::<VolumeEvent>),
+    ("storage", variants_of::<StorageEvent>),
+    ("mcp", variants_of::<MCPToolEvent>),
+    ("seal", variants_of::<SealEvent>),
+    ("stimulus", variants_of::<StimulusEvent>),
+    ("image_management", variants_of::<ImageManagementEvent>),
+    ("iam", variants_of::<IamEvent>),
+    ("secrets", variants_of::<SecretEvent>),
+    ("container_run", variants_of::<ContainerRunEvent>),
+    ("tenant", variants_of::<TenantEvent>),
+    ("cluster", variants_of::<ClusterEvent>),
+    ("rate_limit", variants_of::<RateLimitEvent>),
+    ("swarm", variants_of::<SwarmEvent>),
+    ("credential", variants_of::<CredentialEvent>),
+    ("git_repo", variants_of::<GitRepoEvent>),
+    ("canvas", variants_of::<CanvasEvent>),
+    ("script", variants_of::<ScriptEvent>),
+    ("team", variants_of::<TeamEvent>),
+    ("drift", variants_of::<DriftEvent>),
+];
+
+/// Variants of event enums nested in a newtype variant; they are listed (and
+/// enveloped) in place of the wrapper.
+fn nested_variants(category: &str, variant: &str) -> Option<Vec<VariantShape>> {
+    match (category, variant) {
+        ("execution", "Validation") => Some(variants_of::<ValidationEvent>(None)),
+        _ => None,
+    }
+}
+
+fn internal_tag(category: &str) -> Option<&'static str> {
+    INTERNALLY_TAGGED
+        .iter()
+        .find(|(tagged, _)| *tagged == category)
+        .map(|(_, tag)| *tag)
+}
+
+fn qualified_event_type(category: &str, variant: &str) -> String {
+    format!("{category}.{}", snake_case(variant))
+}
+
+/// `IterationCompleted` → `iteration_completed`, `MCPServerStarted` →
+/// `mcp_server_started`; snake-case input is returned unchanged.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+    for (i, c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let prev = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+            if prev.is_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_uppercase() && next_is_lower)
+            {
+                out.push('_');
+            }
+        }
+        out.extend(c.to_lowercase());
+    }
+    out
+}
+
+/// Split serialized `DomainEvent` JSON into the innermost variant name and
+/// its fields.
+fn split_variant(category: &str, value: Value) -> (String, Value) {
+    let Value::Object(mut map) = value else {
+        return (String::new(), Value::Null);
+    };
+    map.remove("type");
+
+    if let Some(tag) = internal_tag(category) {
+        let variant = match map.remove(tag) {
+            Some(Value::String(variant)) => variant,
+            _ => String::new(),
+        };
+        return (variant, Value::Object(map));
+    }
+
+    // Externally tagged: `{"Variant": fields}`, once more per newtype variant.
+    let mut variant = String::new();
+    let mut payload = Value::Object(map);
+    while let Some((name, inner)) = take_variant(&mut payload) {
+        variant = name;
+        payload = inner;
+    }
+    if payload.is_null() {
+        payload = Value::Object(Map::new());
+    }
+    (variant, payload)
+}
+
+fn take_variant(value: &mut Value) -> Option<(String, Value)> {
+    let Value::Object(map) = value else {
+        return None;
+    };
+    if map.len() != 1 {
+        return None;
+    }
+    let name = map
+        .keys()
+        .next()
+        .filter(|k| k.starts_with(char::is_uppercase))?
+        .clone();
+    let inner = map.remove(&name)?;
+    Some((name, inner))
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Deserialize introspection
+// ──────────────────────────────────────────────────────────────────────────────
+
+struct VariantShape {
+    name: &'static str,
+    fields: Option<Vec<&'static str>>,
+    newtype: bool,
+}
+
+/// Variant names, and where the representation allows it their fields, of
+/// the event enum `T`; `tag` is its tag field if internally tagged.
+///
+/// Panics if `T` is not an enum with a derived `Deserialize`; the registry
+/// tests cover every family.
+fn variants_of<T: DeserializeOwned>(tag: Option<&'static str>) -> Vec<VariantShape> {
+    let names = match T::deserialize(VariantNames { tag }) {
+        Err(Probe::Variants(names)) => names,
+        _ => panic!("cannot list variants of {}", std::any::type_name::<T>()),
+    };
+    names
+        .iter()
+        .zip(0u32..)
+        .map(|(name, index)| {
+            let shape = match tag {
+                Some(_) => Err(Probe::Unsupported("internally tagged".to_string())),
+                None => T::deserialize(VariantShapeProbe { index }).map(|_| ()),
+            };
+            VariantShape {
+                name: *name,
+                fields: match shape {
+                    Err(Probe::Struct(fields)) => Some(fields.to_vec()),
+                    Err(Probe::Unit) => Some(Vec::new()),
+                    _ => None,
+                },
+                newtype: matches!(shape, Err(Probe::Newtype)),
+            }
+        })
+        .collect()
+}
+
+/// Outcome of probing a derived `Deserialize` impl, reported through its error
+/// channel.
+#[derive(Debug)]
+enum Probe {
+    Variants(&'static [&'static str]),
+    Struct(&'static [&'static str]),
+    Newtype,
+    Unit,
+    Unsupported(String),
+}
+
+impl fmt::Display for Probe {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Probe::Unsupported(message) => f.write_str(message),
+            other => write!(f, "{other:?}"),
+        }
+    }
+}
+
+impl std::error::Error for Probe {}
+
+impl de::Error for Probe {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Probe::Unsupported(msg.to_string())
+    }
+
+    fn unknown_variant(_variant: &str, expected: &'static [&'static str]) -> Self {
+        Probe::Variants(expected)
+    }
+}
+
+/// Yields the variant list: externally tagged enums hand it to
+/// `deserialize_enum`; internally tagged ones report it when their tag holds
+/// an unknown variant.
+struct VariantNames {
+    tag: Option<&'static str>,
+}
+
+impl<'de> Deserializer<'de> for VariantNames {
+    type Error = Probe;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Probe> {
+        match self.tag {
+            Some(tag) => visitor.visit_map(UnknownTag { tag, sent: false }),
+            None => Err(Probe::Unsupported("not an enum".to_string())),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Probe> {
+        Err(Probe::Variants(variants))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+/// `{ <tag>: "\0" }`
+struct UnknownTag {
+    tag: &'static str,
+    sent: bool,
+}
+
+impl<'de> MapAccess<'de> for UnknownTag {
+    type Error = Probe;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Probe> {
+        if self.sent {
+            return Ok(None);
+        }
+        self.sent = true;
+        let key: StrDeserializer<'_, Probe> = self.tag.into_deserializer();
+        seed.deserialize(key).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Probe> {
+        let value: StrDeserializer<'_, Probe> = "\0".into_deserializer();
+        seed.deserialize(value)
+    }
+}
+
+/// Selects variant `index` of an externally tagged enum and reports its shape.
+struct VariantShapeProbe {
+    index: u32,
+}
+
+impl<'de> Deserializer<'de> for VariantShapeProbe {
+    type Error = Probe;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _visitor: V) -> Result<V::Value, Probe> {
+        Err(Probe::Unsupported("not an enum".to_string()))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Probe> {
+        visitor.visit_enum(self)
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+impl<'de> EnumAccess<'de> for VariantShapeProbe {
+    type Error = Probe;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Self), Probe> {
+        let index: U32Deserializer<Probe> = self.index.into_deserializer();
+        Ok((seed.deserialize(index)?, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for VariantShapeProbe {
+    type Error = Probe;
+
+    fn unit_variant(self) -> Result<(), Probe> {
+        Err(Probe::Unit)
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(self, _seed: T) -> Result<T::Value, Probe> {
+        Err(Probe::Newtype)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, _visitor: V) -> Result<V::Value, Probe> {
+        Err(Probe::Unsupported("tuple variant".to_string()))
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        _visitor: V,
+    ) -> Result<V::Value, Probe> {
+        Err(Probe::Struct(fields))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::execution::ExecutionId;
+    use crate::domain::shared_kernel::AgentId;
+
+    #[test]
+    fn envelope_unwraps_nested_variants() {
+        let execution_id = ExecutionId::new();
+        let event = DomainEvent::Execution(ExecutionEvent::Validation(
+            ValidationEvent::GradientValidationPerformed {
+                execution_id,
+                agent_id: AgentId::new(),
+                iteration_number: 2,
+                score: 0.9,
+                confidence: 0.8,
+                validated_at: Utc::now(),
+            },
+        ));
+
+        let envelope = EventEnvelope::new(&event);
+        assert_eq!(
+            envelope.event_type,
+            "execution.gradient_validation_performed"
+        );
+        assert_eq!(envelope.category, "execution");
+        assert_eq!(envelope.schema_version, INITIAL_SCHEMA_VERSION);
+        assert_eq!(envelope.occurred_at, event.timestamp());
+        assert_eq!(
+            envelope.payload["execution_id"],
+            serde_json::to_value(execution_id).unwrap()
+        );
+        assert_eq!(envelope.payload["iteration_number"], 2);
+        assert!(envelope.payload.get("type").is_none());
+    }
+
+    #[test]
+    fn registry_covers_every_domain_event_category() {
+        let schemas = event_schemas();
+
+        let category_count = match DomainEvent::deserialize(VariantNames { tag: Some("type") }) {
+            Err(Probe::Variants(names)) => names.len(),
+            _ => panic!("DomainEvent variants not introspectable"),
+        };
+        assert_eq!(EVENT_FAMILIES.len(), category_count);
+
+        let find = |event_type: &str| schemas.iter().find(|s| s.event_type == event_type);
+        let started = find("execution.execution_started").expect("execution_started listed");
+        assert_eq!(
+            started.fields.as_deref(),
+            Some(&["execution_id", "agent_id", "started_at"][..])
+        );
+        assert!(find("execution.validation").is_none());
+        assert!(find("mcp.server_started").is_some());
+        assert_eq!(find("team.invitation_sent").unwrap().fields, None);
+        assert_eq!(snake_case("MCPServerStarted"), "mcp_server_started");
+    }
+}
@@ -0,0 +1,520 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Fault Injection
+//!
+//! Runtime-configurable chaos layer for integration and resilience tests.
+//! Compiled only with the `fault-injection` cargo feature; release builds
+//! never contain it.
+//!
+//! A [`FaultInjector`] holds an ordered list of [`FaultRule`]s. Each
+//! instrumented call site asks the injector whether a rule matches its
+//! `(target, operation)` pair; the first matching rule may add latency,
+//! return an error, or both.
+//!
+//! | Target | Instrumented by | Operations |
+//! |--------|-----------------|------------|
+//! | `llm` | [`FaultInjectingLLMProvider`] (wrapped by `ProviderRegistry::with_fault_injector`) | `generate`, `generate_chat`, `health_check` |
+//! | `storage` | [`FaultInjectingStorageProvider`] | every [`StorageProvider`] method name, e.g. `read_at`, `write_at`, `stat` |
+//! | `temporal` | `TemporalClient::with_fault_injector` | `start_workflow`, `get_workflow_history`, `signal_workflow`, `query_workflow`, `register_workflow` |
+//!
+//! Rules are managed over HTTP at `/v1/admin/faults` by the daemon.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Latency and error injection into LLM, storage and Temporal adapters
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::domain::llm::{
+    ChatMessage, ChatResponse, GenerationOptions, GenerationResponse, LLMError, LLMProvider,
+    ToolSchema,
+};
+use crate::domain::storage::{
+    DirEntry, FileAttributes, FileHandle, OpenMode, StorageError, StorageProvider,
+};
+
+/// Adapter family a fault rule applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultTarget {
+    Llm,
+    Storage,
+    Temporal,
+}
+
+impl FaultTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Llm => "llm",
+            Self::Storage => "storage",
+            Self::Temporal => "temporal",
+        }
+    }
+}
+
+/// Error surfaced by a matching rule, mapped onto each adapter's native error type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FaultErrorKind {
+    Timeout,
+    Unavailable,
+    Network,
+    RateLimit,
+}
+
+/// Client-supplied definition of a fault rule.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FaultRuleSpec {
+    pub target: FaultTarget,
+    /// Operation name to match. `None` matches every operation of `target`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub operation: Option<String>,
+    /// Delay added before the call proceeds (or fails).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub latency_ms: Option<u64>,
+    /// Error returned instead of calling the real adapter.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<FaultErrorKind>,
+    /// Chance in `[0.0, 1.0]` that a matching call is affected. Defaults to 1.0.
+    #[serde(default = "default_probability")]
+    pub probability: f64,
+    /// Stop firing after this many hits. `None` fires until removed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_hits: Option<u64>,
+}
+
+fn default_probability() -> f64 {
+    1.0
+}
+
+impl FaultRuleSpec {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.latency_ms.is_none() && self.error.is_none() {
+            return Err("fault rule must set latency_ms, error, or both".to_string());
+        }
+        if !(0.0..=1.0).contains(&self.probability) {
+            return Err("probability must be between 0.0 and 1.0".to_string());
+        }
+        if self.operation.as_deref() == Some("") {
+            return Err("operation cannot be empty; omit it to match every operation".to_string());
+        }
+        Ok(())
+    }
+
+    fn matches(&self, target: FaultTarget, operation: &str) -> bool {
+        self.target == target && self.operation.as_deref().is_none_or(|op| op == operation)
+    }
+}
+
+/// An installed fault rule.
+#[derive(Debug, Clone, Serialize)]
+pub struct FaultRule {
+    pub id: Uuid,
+    #[serde(flatten)]
+    pub spec: FaultRuleSpec,
+    /// Number of calls this rule has affected so far.
+    pub hits: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FaultRule {
+    fn exhausted(&self) -> bool {
+        self.spec.max_hits.is_some_and(|max| self.hits >= max)
+    }
+}
+
+/// Error produced by a firing rule.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("injected {kind:?} fault on {}.{operation}", target.as_str())]
+pub struct InjectedFault {
+    pub target: FaultTarget,
+    pub operation: String,
+    pub kind: FaultErrorKind,
+}
+
+impl From<InjectedFault> for LLMError {
+    fn from(fault: InjectedFault) -> Self {
+        match fault.kind {
+            FaultErrorKind::RateLimit => LLMError::RateLimit,
+            FaultErrorKind::Unavailable => LLMError::ServiceUnavailable(fault.to_string()),
+            FaultErrorKind::Timeout | FaultErrorKind::Network => {
+                LLMError::Network(fault.to_string())
+            }
+        }
+    }
+}
+
+impl From<InjectedFault> for StorageError {
+    fn from(fault: InjectedFault) -> Self {
+        match fault.kind {
+            FaultErrorKind::Timeout => StorageError::Timeout,
+            FaultErrorKind::Network => StorageError::Network(fault.to_string()),
+            FaultErrorKind::Unavailable | FaultErrorKind::RateLimit => {
+                StorageError::Unavailable(fault.to_string())
+            }
+        }
+    }
+}
+
+/// Shared registry of fault rules consulted by instrumented adapters.
+#[derive(Default)]
+pub struct FaultInjector {
+    rules: Mutex<Vec<FaultRule>>,
+}
+
+impl FaultInjector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Install a rule. Rules are evaluated in insertion order.
+    pub fn add(&self, spec: FaultRuleSpec) -> Result<FaultRule, String> {
+        spec.validate()?;
+        let rule = FaultRule {
+            id: Uuid::new_v4(),
+            spec,
+            hits: 0,
+            created_at: Utc::now(),
+        };
+        self.rules
+            .lock()
+            .expect("fault rules poisoned")
+            .push(rule.clone());
+        Ok(rule)
+    }
+
+    pub fn list(&self) -> Vec<FaultRule> {
+        self.rules.lock().expect("fault rules poisoned").clone()
+    }
+
+    /// Remove a rule, returning whether it existed.
+    pub fn remove(&self, id: Uuid) -> bool {
+        let mut rules = self.rules.lock().expect("fault rules poisoned");
+        let before = rules.len();
+        rules.retain(|rule| rule.id != id);
+        rules.len() != before
+    }
+
+    /// Remove every rule, returning how many were removed.
+    pub fn clear(&self) -> usize {
+        let mut rules = self.rules.lock().expect("fault rules poisoned");
+        std::mem::take(&mut *rules).len()
+    }
+
+    /// Apply the first live rule matching `(target, operation)`: sleep for its
+    /// latency, then return its error if it has one.
+    pub async fn inject(&self, target: FaultTarget, operation: &str) -> Result<(), InjectedFault> {
+        let fired = {
+            let mut rules = self.rules.lock().expect("fault rules poisoned");
+            rules
+                .iter_mut()
+                .find(|rule| !rule.exhausted() && rule.spec.matches(target, operation))
+                .and_then(|rule| {
+                    if !roll(rule.spec.probability) {
+                        return None;
+                    }
+                    rule.hits += 1;
+                    Some((rule.spec.latency_ms, rule.spec.error))
+                })
+        };
+        let Some((latency_ms, error)) = fired else {
+            return Ok(());
+        };
+
+        metrics::counter!(
+            "aegis_fault_injections_total",
+            "target" => target.as_str(),
+            "operation" => operation.to_string()
+        )
+        .increment(1);
+        tracing::warn!(
+            target = target.as_str(),
+            operation,
+            ?latency_ms,
+            ?error,
+            "Injecting fault"
+        );
+
+        if let Some(ms) = latency_ms {
+            tokio::time::sleep(Duration::from_millis(ms)).await;
+        }
+        match error {
+            Some(kind) => Err(InjectedFault {
+                target,
+                operation: operation.to_string(),
+                kind,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+fn roll(probability: f64) -> bool {
+    if probability >= 1.0 {
+        return true;
+    }
+    (OsRng.next_u32() as f64 / u32::MAX as f64) < probability
+}
+
+/// [`LLMProvider`] decorator consulting a [`FaultInjector`] before each call.
+pub struct FaultInjectingLLMProvider {
+    inner: Arc<dyn LLMProvider>,
+    faults: Arc<FaultInjector>,
+}
+
+impl FaultInjectingLLMProvider {
+    pub fn new(inner: Arc<dyn LLMProvider>, faults: Arc<FaultInjector>) -> Self {
+        Self { inner, faults }
+    }
+}
+
+#[async_trait]
+impl LLMProvider for FaultInjectingLLMProvider {
+    async fn generate(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+    ) -> Result<GenerationResponse, LLMError> {
+        self.faults.inject(FaultTarget::Llm, "generate").await?;
+        self.inner.generate(prompt, options).await
+    }
+
+    async fn generate_chat(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[ToolSchema],
+        options: &GenerationOptions,
+    ) -> Result<ChatResponse, LLMError> {
+        self.faults
+            .inject(FaultTarget::Llm, "generate_chat")
+            .await?;
+        self.inner.generate_chat(messages, tools, options).await
+    }
+
+    async fn health_check(&self) -> Result<(), LLMError> {
+        self.faults.inject(FaultTarget::Llm, "health_check").await?;
+        self.inner.health_check().await
+    }
+}
+
+/// [`StorageProvider`] decorator consulting a [`FaultInjector`] before each call.
+pub struct FaultInjectingStorageProvider {
+    inner: Arc<dyn StorageProvider>,
+    faults: Arc<FaultInjector>,
+}
+
+impl FaultInjectingStorageProvider {
+    pub fn new(inner: Arc<dyn StorageProvider>, faults: Arc<FaultInjector>) -> Self {
+        Self { inner, faults }
+    }
+
+    async fn inject(&self, operation: &str) -> Result<(), StorageError> {
+        self.faults
+            .inject(FaultTarget::Storage, operation)
+            .await
+            .map_err(StorageError::from)
+    }
+}
+
+#[async_trait]
+impl StorageProvider for FaultInjectingStorageProvider {
+    async fn create_directory(&self, path: &str) -> Result<(), StorageError> {
+        self.inject("create_directory").await?;
+        self.inner.create_directory(path).await
+    }
+
+    async fn delete_directory(&self, path: &str) -> Result<(), StorageError> {
+        self.inject("delete_directory").await?;
+        self.inner.delete_directory(path).await
+    }
+
+    async fn set_quota(&self, path: &str, bytes: u64) -> Result<(), StorageError> {
+        self.inject("set_quota").await?;
+        self.inner.set_quota(path, bytes).await
+    }
+
+    async fn get_usage(&self, path: &str) -> Result<u64, StorageError> {
+        self.inject("get_usage").await?;
+        self.inner.get_usage(path).await
+    }
+
+    async fn health_check(&self) -> Result<(), StorageError> {
+        self.inject("health_check").await?;
+        self.inner.health_check().await
+    }
+
+    async fn list_directories(&self, path: &str) -> Result<Vec<String>, StorageError> {
+        self.inject("list_directories").await?;
+        self.inner.list_directories(path).await
+    }
+
+    async fn open_file(&self, path: &str, mode: OpenMode) -> Result<FileHandle, StorageError> {
+        self.inject("open_file").await?;
+        self.inner.open_file(path, mode).await
+    }
+
+    async fn read_at(
+        &self,
+        handle: &FileHandle,
+        offset: u64,
+        length: usize,
+    ) -> Result<Vec<u8>, StorageError> {
+        self.inject("read_at").await?;
+        self.inner.read_at(handle, offset, length).await
+    }
+
+    async fn read_at_bytes(
+        &self,
+        handle: &FileHandle,
+        offset: u64,
+        length: usize,
+    ) -> Result<Bytes, StorageError> {
+        // Shares the `read_at` operation name so existing rules cover both paths.
+        self.inject("read_at").await?;
+        self.inner.read_at_bytes(handle, offset, length).await
+    }
+
+    async fn write_at(
+        &self,
+        handle: &FileHandle,
+        offset: u64,
+        data: &[u8],
+    ) -> Result<usize, StorageError> {
+        self.inject("write_at").await?;
+        self.inner.write_at(handle, offset, data).await
+    }
+
+    async fn close_file(&self, handle: &FileHandle) -> Result<(), StorageError> {
+        self.inject("close_file").await?;
+        self.inner.close_file(handle).await
+    }
+
+    async fn stat(&self, path: &str) -> Result<FileAttributes, StorageError> {
+        self.inject("stat").await?;
+        self.inner.stat(path).await
+    }
+
+    async fn readdir(&self, path: &str) -> Result<Vec<DirEntry>, StorageError> {
+        self.inject("readdir").await?;
+        self.inner.readdir(path).await
+    }
+
+    async fn create_file(&self, path: &str, mode: u32) -> Result<FileHandle, StorageError> {
+        self.inject("create_file").await?;
+        self.inner.create_file(path, mode).await
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<(), StorageError> {
+        self.inject("delete_file").await?;
+        self.inner.delete_file(path).await
+    }
+
+    async fn rename(&self, from: &str, to: &str) -> Result<(), StorageError> {
+        self.inject("rename").await?;
+        self.inner.rename(from, to).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(target: FaultTarget, operation: Option<&str>) -> FaultRuleSpec {
+        FaultRuleSpec {
+            target,
+            operation: operation.map(str::to_string),
+            latency_ms: None,
+            error: Some(FaultErrorKind::Unavailable),
+            probability: 1.0,
+            max_hits: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn matching_rule_fires_and_others_pass_through() {
+        let faults = FaultInjector::new();
+        faults
+            .add(spec(FaultTarget::Storage, Some("read_at")))
+            .unwrap();
+
+        let err = faults
+            .inject(FaultTarget::Storage, "read_at")
+            .await
+            .unwrap_err();
+        assert_eq!(err.kind, FaultErrorKind::Unavailable);
+        assert!(faults.inject(FaultTarget::Storage, "stat").await.is_ok());
+        assert!(faults.inject(FaultTarget::Llm, "read_at").await.is_ok());
+        assert_eq!(faults.list()[0].hits, 1);
+    }
+
+    #[tokio::test]
+    async fn max_hits_exhausts_rule() {
+        let faults = FaultInjector::new();
+        faults
+            .add(FaultRuleSpec {
+                max_hits: Some(2),
+                ..spec(FaultTarget::Temporal, None)
+            })
+            .unwrap();
+
+        assert!(faults.inject(FaultTarget::Temporal, "start").await.is_err());
+        assert!(faults
+            .inject(FaultTarget::Temporal, "signal")
+            .await
+            .is_err());
+        assert!(faults.inject(FaultTarget::Temporal, "start").await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn remove_and_clear_rules() {
+        let faults = FaultInjector::new();
+        let rule = faults.add(spec(FaultTarget::Llm, None)).unwrap();
+        faults.add(spec(FaultTarget::Storage, None)).unwrap();
+
+        assert!(faults.remove(rule.id));
+        assert!(!faults.remove(rule.id));
+        assert!(faults.inject(FaultTarget::Llm, "generate").await.is_ok());
+        assert_eq!(faults.clear(), 1);
+        assert!(faults.list().is_empty());
+    }
+
+    #[test]
+    fn validate_rejects_noop_and_bad_probability() {
+        let noop = FaultRuleSpec {
+            error: None,
+            ..spec(FaultTarget::Llm, None)
+        };
+        assert!(noop.validate().is_err());
+        let bad = FaultRuleSpec {
+            probability: 1.5,
+            ..spec(FaultTarget::Llm, None)
+        };
+        assert!(bad.validate().is_err());
+    }
+
+    #[test]
+    fn injected_errors_map_to_adapter_errors() {
+        let fault = |kind| InjectedFault {
+            target: FaultTarget::Storage,
+            operation: "read_at".to_string(),
+            kind,
+        };
+        assert!(matches!(
+            StorageError::from(fault(FaultErrorKind::Timeout)),
+            StorageError::Timeout
+        ));
+        assert!(matches!(
+            LLMError::from(fault(FaultErrorKind::RateLimit)),
+            LLMError::RateLimit
+        ));
+    }
+}
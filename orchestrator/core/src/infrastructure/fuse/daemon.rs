@@ -1322,16 +1322,27 @@ impl Filesystem for FuseFsal {
 /// Map FSAL errors to fuser `Errno` values for FUSE responses.
 fn fsal_error_to_errno(e: &crate::domain::fsal::FsalError) -> Errno {
     use crate::domain::fsal::FsalError;
+    use crate::domain::storage::StorageErrorKind;
     match e {
         FsalError::UnauthorizedAccess { .. } => Errno::EACCES,
         FsalError::VolumeNotFound(_) => Errno::ENOENT,
         FsalError::VolumeNotAttached(_) => Errno::ENOENT,
         FsalError::PathSanitization(_) => Errno::EINVAL,
-        FsalError::Storage(_) => Errno::EIO,
+        FsalError::Storage(e) => match e.kind() {
+            StorageErrorKind::NotFound => Errno::ENOENT,
+            StorageErrorKind::Policy => Errno::EACCES,
+            StorageErrorKind::Quota => Errno::ENOSPC,
+            StorageErrorKind::InvalidRequest => Errno::EINVAL,
+            StorageErrorKind::AlreadyExists
+            | StorageErrorKind::BackendUnavailable
+            | StorageErrorKind::Corrupted
+            | StorageErrorKind::Io => Errno::EIO,
+        },
         FsalError::PolicyViolation(_) => Errno::EACCES,
         FsalError::InvalidFileHandle => Errno::ESTALE,
         FsalError::HandleDeserialization(_) => Errno::EIO,
         FsalError::QuotaExceeded { .. } => Errno::ENOSPC,
+        FsalError::FileTooLarge { .. } => Errno::EIO,
     }
 }
 
@@ -25,6 +25,7 @@ use crate::infrastructure::aegis_runtime_proto::{
     FsalCreateFileRequest as ProtoCreateFile, FsalGetattrRequest, FsalLookupRequest,
     FsalMutateRequest, FsalReadRequest, FsalReaddirRequest, FsalRenameRequest, FsalWriteRequest,
 };
+use crate::infrastructure::storage::status_to_storage_err;
 
 use super::fsal_backend::FsalBackend;
 
@@ -82,16 +83,15 @@ fn parse_file_type(s: &str) -> FileType {
     }
 }
 
-/// Map a gRPC status to an FsalError.
+/// Map a gRPC status to an FsalError, keeping the cause the orchestrator
+/// reported.
 fn grpc_to_fsal_error(status: tonic::Status) -> FsalError {
-    FsalError::Storage(StorageError::IoError(status.message().to_string()))
+    FsalError::Storage(status_to_storage_err(status))
 }
 
 /// Map a call timeout to an FsalError.
 fn timeout_to_fsal_error(_: tokio::time::error::Elapsed) -> FsalError {
-    FsalError::Storage(StorageError::IoError(
-        "FSAL gRPC call timed out after 30s".to_string(),
-    ))
+    FsalError::Storage(StorageError::Timeout)
 }
 
 /// Convert an optional workflow execution UUID to the proto string field.
@@ -0,0 +1,34 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! In-memory [`GuidanceQueue`] shared by the daemon's guidance endpoint and
+//! the supervisor. Pending guidance is node-local and not persisted; the
+//! `OperatorGuidanceAdded` event is the durable record.
+
+use dashmap::DashMap;
+
+use crate::domain::execution::ExecutionId;
+use crate::domain::guidance::{GuidanceQueue, OperatorGuidance};
+
+#[derive(Default)]
+pub struct InMemoryGuidanceQueue {
+    pending: DashMap<ExecutionId, Vec<OperatorGuidance>>,
+}
+
+impl InMemoryGuidanceQueue {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl GuidanceQueue for InMemoryGuidanceQueue {
+    fn enqueue(&self, execution_id: ExecutionId, guidance: OperatorGuidance) {
+        self.pending.entry(execution_id).or_default().push(guidance);
+    }
+
+    fn drain(&self, execution_id: ExecutionId) -> Vec<OperatorGuidance> {
+        self.pending
+            .remove(&execution_id)
+            .map(|(_, items)| items)
+            .unwrap_or_default()
+    }
+}
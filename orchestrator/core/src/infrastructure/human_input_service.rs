@@ -10,6 +10,7 @@
 //! - **Purpose:** Implements internal responsibilities for human input service
 
 use anyhow::Result;
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -18,6 +19,7 @@ use tracing::{debug, info, warn};
 use uuid::Uuid;
 
 use crate::domain::execution::ExecutionId;
+use crate::domain::supervisor::{IterationApproval, IterationApprovalGate};
 use crate::domain::tenant::TenantId;
 
 /// Status of a human input request
@@ -349,6 +351,37 @@ impl Default for HumanInputService {
     }
 }
 
+/// Per-iteration approval (`approval_mode: per_iteration`) rides on the same
+/// pending-request list as workflow approval gates.
+#[async_trait]
+impl IterationApprovalGate for HumanInputService {
+    async fn request_iteration_approval(
+        &self,
+        tenant_id: TenantId,
+        execution_id: ExecutionId,
+        prompt: String,
+        timeout_seconds: u64,
+    ) -> IterationApproval {
+        match self
+            .request_input(tenant_id, execution_id, prompt, timeout_seconds)
+            .await
+        {
+            Ok(HumanInputStatus::Approved {
+                feedback,
+                approved_by,
+                ..
+            }) => IterationApproval::Approved {
+                feedback,
+                approved_by,
+            },
+            Ok(HumanInputStatus::Rejected { reason, .. }) => IterationApproval::Rejected { reason },
+            Ok(HumanInputStatus::Pending) | Ok(HumanInputStatus::TimedOut { .. }) | Err(_) => {
+                IterationApproval::TimedOut
+            }
+        }
+    }
+}
+
 /// Information about a pending request (for serialization/API).
 ///
 /// `tenant_id` is surfaced so operator-cross-tenant projections can label
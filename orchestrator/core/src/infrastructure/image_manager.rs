@@ -10,11 +10,12 @@
 //!
 //! | Phase | Credentials | Implementation |
 //! |-------|-------------|----------------|
-//! | 1 (current) | Node-config static | [`NodeConfigCredentialResolver`] — matches registry prefix from `aegis-config.yaml` |
-//! | 2 (future)  | OpenBao dynamic | `OpenBaoCredentialResolver` — wires vault path per registry |
+//! | 1 | Node-config static | [`NodeConfigCredentialResolver`] — matches registry prefix from `aegis-config.yaml` |
+//! | 2 (current) | Secrets provider | [`SecretsCredentialResolver`] — resolves `secret:engine/path#field` passwords via [`SecretsManager`] |
 //!
 //! The `credential_resolver` field on [`StandardDockerImageManager`] is the integration
-//! point; swapping the resolver for Phase 2 requires no structural changes to `ContainerRuntime`.
+//! point; the daemon wires [`SecretsCredentialResolver`] into every pull path
+//! (agent containers, runtime image builds, workflow container steps).
 //!
 //! See ADR-045 (Container Registry & Image Management), ADR-034 (OpenBao Secrets).
 
@@ -22,6 +23,8 @@ use crate::domain::agent::ImagePullPolicy;
 use crate::domain::events::PullSource;
 use crate::domain::node_config::RegistryCredentials;
 use crate::domain::runtime::RuntimeError;
+use crate::domain::secrets::AccessContext;
+use crate::infrastructure::secrets_manager::SecretsManager;
 use async_trait::async_trait;
 use bollard::auth::DockerCredentials;
 use bollard::query_parameters::CreateImageOptions;
@@ -30,7 +33,7 @@ use futures::StreamExt;
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::time::timeout;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 /// Total wall-clock budget for `ensure_image`, including registry inspection
 /// and full pull stream consumption. Tunable via env var (see below) for tests.
@@ -72,9 +75,9 @@ where
 
 /// Resolves registry credentials for a given image reference.
 ///
-/// Phase 1 implementation: [`NodeConfigCredentialResolver`] (static node-config).
-/// Phase 2 will provide an `OpenBaoCredentialResolver` that fetches short-lived
-/// tokens from OpenBao (ADR-034).
+/// Implementations: [`NodeConfigCredentialResolver`] (static node-config) and
+/// [`SecretsCredentialResolver`] (node-config entries whose passwords live in
+/// the secrets provider, ADR-034).
 #[async_trait]
 pub trait CredentialResolver: Send + Sync {
     /// Return credentials for the registry that hosts `image`, or `None` for
@@ -113,6 +116,78 @@ impl CredentialResolver for NodeConfigCredentialResolver {
     }
 }
 
+/// Default secret field read when a `secret:engine/path` password has no `#field`.
+pub const DEFAULT_REGISTRY_SECRET_FIELD: &str = "password";
+
+/// Credential resolver that matches node-config entries like
+/// [`NodeConfigCredentialResolver`] and resolves `secret:engine/path#field`
+/// passwords through the [`SecretsManager`].
+///
+/// Secret reads go through the manager's TTL cache, so rotated registry tokens
+/// are picked up without a daemon restart. `env:` and plaintext passwords are
+/// returned unchanged for the pull path to resolve as before. A secret that
+/// cannot be read is logged and treated as "no credentials", so the pull fails
+/// with the registry's own authentication error.
+pub struct SecretsCredentialResolver {
+    inner: NodeConfigCredentialResolver,
+    secrets_manager: Arc<SecretsManager>,
+}
+
+impl SecretsCredentialResolver {
+    /// Construct a resolver from the node-config credential list.
+    pub fn new(
+        credentials: Vec<RegistryCredentials>,
+        secrets_manager: Arc<SecretsManager>,
+    ) -> Self {
+        Self {
+            inner: NodeConfigCredentialResolver::new(credentials),
+            secrets_manager,
+        }
+    }
+}
+
+#[async_trait]
+impl CredentialResolver for SecretsCredentialResolver {
+    async fn resolve(&self, image: &str) -> Option<RegistryCredentials> {
+        let mut cred = self.inner.resolve(image).await?;
+        let Some(reference) = cred.secret_reference() else {
+            return Some(cred);
+        };
+
+        let (kv_path, field) = reference
+            .split_once('#')
+            .unwrap_or((reference, DEFAULT_REGISTRY_SECRET_FIELD));
+        let Some((engine, path)) = kv_path.split_once('/') else {
+            warn!(
+                registry = %cred.registry,
+                "registry password secret reference must be 'secret:engine/path[#field]'"
+            );
+            return None;
+        };
+
+        let ctx = AccessContext::system("orchestrator");
+        match self
+            .secrets_manager
+            .read_secret_field(engine, path, field, &ctx)
+            .await
+        {
+            Ok(secret) => {
+                cred.password = secret.expose().to_string();
+                Some(cred)
+            }
+            Err(e) => {
+                warn!(
+                    registry = %cred.registry,
+                    secret = %kv_path,
+                    error = %e,
+                    "failed to resolve registry password from secrets provider; pulling anonymously"
+                );
+                None
+            }
+        }
+    }
+}
+
 // ── DockerImageManager ────────────────────────────────────────────────────────
 
 /// Manages Docker image availability: pull-policy enforcement and cache checks.
@@ -311,8 +386,63 @@ impl DockerImageManager for StandardDockerImageManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::secrets::SensitiveString;
+    use crate::infrastructure::event_bus::EventBus;
+    use crate::infrastructure::secrets_manager::TestSecretStore;
+    use std::collections::HashMap;
     use std::future::pending;
 
+    fn registry_cred(registry: &str, password: &str) -> RegistryCredentials {
+        RegistryCredentials {
+            registry: registry.to_string(),
+            username: "robot".to_string(),
+            password: password.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn secrets_resolver_reads_password_from_secret_store() {
+        let manager = Arc::new(SecretsManager::from_store(
+            Arc::new(TestSecretStore::new()),
+            Arc::new(EventBus::new(16)),
+        ));
+        let ctx = AccessContext::system("test");
+        manager
+            .write_secret(
+                "kv",
+                "registries/ghcr",
+                HashMap::from([
+                    ("password".to_string(), SensitiveString::new("s3cret")),
+                    ("token".to_string(), SensitiveString::new("tok")),
+                ]),
+                &ctx,
+            )
+            .await
+            .unwrap();
+
+        let resolver = SecretsCredentialResolver::new(
+            vec![
+                registry_cred("ghcr.io/acme", "secret:kv/registries/ghcr#token"),
+                registry_cred("ghcr.io", "secret:kv/registries/ghcr"),
+                registry_cred("registry.example.com", "env:REGISTRY_TOKEN"),
+                registry_cred("quay.io", "secret:kv/registries/missing"),
+            ],
+            manager,
+        );
+
+        let acme = resolver.resolve("ghcr.io/acme/agent:v1").await.unwrap();
+        assert_eq!(acme.password, "tok");
+        let other = resolver.resolve("ghcr.io/other/agent:v1").await.unwrap();
+        assert_eq!(other.password, "s3cret");
+        let env = resolver
+            .resolve("registry.example.com/agent:v1")
+            .await
+            .unwrap();
+        assert_eq!(env.password, "env:REGISTRY_TOKEN");
+        assert!(resolver.resolve("quay.io/acme/agent:v1").await.is_none());
+        assert!(resolver.resolve("docker.io/library/python").await.is_none());
+    }
+
     #[tokio::test]
     async fn run_pull_with_timeout_returns_ok_when_future_completes() {
         let result: Result<PullSource, RuntimeError> =
@@ -0,0 +1,262 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Image Signature Verifier
+//!
+//! Supply-chain gate run by [`crate::infrastructure::runtime::ContainerRuntime`]
+//! after the image is available locally and before any container is created.
+//! Trust roots come from `spec.image_verification` in `aegis-config.yaml`
+//! ([`ImageVerificationConfig`]) and are scoped per registry prefix and tenant.
+//!
+//! [`CosignImageVerifier`] shells out to the `cosign` CLI. Registry access for
+//! signature lookup uses cosign's own credential chain (`~/.docker/config.json`
+//! of the daemon user), not the node-config `registry_credentials`.
+//!
+//! Orchestrator-built runtime images (`aegis-runtime/*`, see
+//! [`crate::domain::runtime_image`]) exist only in the local daemon, carry no
+//! signature, and are exempt.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Refuse unsigned or untrusted agent images before spawn
+
+use crate::domain::node_config::{ImageTrustRoot, ImageVerificationConfig};
+use crate::domain::runtime::RuntimeError;
+use crate::domain::runtime_image::BUILT_IMAGE_REPOSITORY_PREFIX;
+use crate::domain::tenant::TenantId;
+use async_trait::async_trait;
+use std::process::Stdio;
+use std::time::Duration;
+use tokio::process::Command;
+use tokio::time::timeout;
+use tracing::{info, warn};
+
+/// Outcome of a verification attempt that did not reject the image.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImageVerification {
+    /// A trust root's key verified the image signature.
+    Verified { trust_root: String },
+    /// No verification was required for this image.
+    Skipped { reason: &'static str },
+}
+
+/// Verifies container image signatures against configured trust roots.
+#[async_trait]
+pub trait ImageSignatureVerifier: Send + Sync {
+    /// Verify `image` for a container owned by `tenant_id`.
+    ///
+    /// Returns [`RuntimeError::ImageVerificationFailed`] when the image must
+    /// not run.
+    async fn verify(
+        &self,
+        image: &str,
+        tenant_id: &TenantId,
+    ) -> Result<ImageVerification, RuntimeError>;
+}
+
+/// [`ImageSignatureVerifier`] backed by `cosign verify --key`.
+pub struct CosignImageVerifier {
+    config: ImageVerificationConfig,
+}
+
+impl CosignImageVerifier {
+    /// Create a verifier enforcing `config`.
+    pub fn new(config: ImageVerificationConfig) -> Self {
+        Self { config }
+    }
+
+    async fn verify_with_root(&self, image: &str, root: &ImageTrustRoot) -> Result<(), String> {
+        let mut command = Command::new(&self.config.cosign_path);
+        command
+            .args(cosign_verify_args(root, image))
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+
+        let output = match timeout(
+            Duration::from_secs(self.config.timeout_seconds),
+            command.output(),
+        )
+        .await
+        {
+            Ok(Ok(output)) => output,
+            Ok(Err(e)) => return Err(format!("failed to run '{}': {e}", self.config.cosign_path)),
+            Err(_) => {
+                return Err(format!(
+                    "cosign timed out after {} seconds",
+                    self.config.timeout_seconds
+                ))
+            }
+        };
+
+        if output.status.success() {
+            Ok(())
+        } else {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            let last_line = stderr
+                .lines()
+                .rev()
+                .find(|line| !line.trim().is_empty())
+                .unwrap_or("no signature matched");
+            Err(last_line.trim().to_string())
+        }
+    }
+}
+
+#[async_trait]
+impl ImageSignatureVerifier for CosignImageVerifier {
+    async fn verify(
+        &self,
+        image: &str,
+        tenant_id: &TenantId,
+    ) -> Result<ImageVerification, RuntimeError> {
+        if !self.config.enabled {
+            return Ok(ImageVerification::Skipped { reason: "disabled" });
+        }
+        if image.starts_with(&format!("{BUILT_IMAGE_REPOSITORY_PREFIX}/")) {
+            return Ok(ImageVerification::Skipped {
+                reason: "orchestrator-built runtime image",
+            });
+        }
+
+        let roots = self.config.trust_roots_for(image, tenant_id.as_str());
+        if roots.is_empty() {
+            if self.config.require_trust_root {
+                return Err(RuntimeError::ImageVerificationFailed {
+                    image: image.to_string(),
+                    reason: format!("no trust root configured for tenant '{tenant_id}'"),
+                });
+            }
+            return Ok(ImageVerification::Skipped {
+                reason: "no applicable trust root",
+            });
+        }
+
+        let mut failures = Vec::with_capacity(roots.len());
+        for root in roots {
+            match self.verify_with_root(image, root).await {
+                Ok(()) => {
+                    info!(image = %image, trust_root = %root.name, "image signature verified");
+                    return Ok(ImageVerification::Verified {
+                        trust_root: root.name.clone(),
+                    });
+                }
+                Err(reason) => {
+                    warn!(
+                        image = %image,
+                        trust_root = %root.name,
+                        reason = %reason,
+                        "image signature not verified by trust root"
+                    );
+                    failures.push(format!("{}: {reason}", root.name));
+                }
+            }
+        }
+
+        Err(RuntimeError::ImageVerificationFailed {
+            image: image.to_string(),
+            reason: format!("no trusted signature ({})", failures.join("; ")),
+        })
+    }
+}
+
+/// Argument list for `cosign verify`. `env:VAR` keys map to cosign's native
+/// `env://VAR` key reference so the PEM never touches disk or argv.
+fn cosign_verify_args(root: &ImageTrustRoot, image: &str) -> Vec<String> {
+    let key = match root.public_key.strip_prefix("env:") {
+        Some(var) => format!("env://{var}"),
+        None => root.public_key.clone(),
+    };
+    vec![
+        "verify".to_string(),
+        "--key".to_string(),
+        key,
+        "--output".to_string(),
+        "text".to_string(),
+        "--".to_string(),
+        image.to_string(),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn root(name: &str, registries: &[&str]) -> ImageTrustRoot {
+        ImageTrustRoot {
+            name: name.to_string(),
+            public_key: "env:COSIGN_PUB".to_string(),
+            registries: registries.iter().map(|r| r.to_string()).collect(),
+            tenants: vec![],
+        }
+    }
+
+    fn verifier(cosign_path: &str, trust_roots: Vec<ImageTrustRoot>) -> CosignImageVerifier {
+        CosignImageVerifier::new(ImageVerificationConfig {
+            enabled: true,
+            cosign_path: cosign_path.to_string(),
+            timeout_seconds: 5,
+            require_trust_root: true,
+            trust_roots,
+        })
+    }
+
+    #[test]
+    fn env_keys_use_cosign_env_reference() {
+        let args = cosign_verify_args(&root("acme", &[]), "ghcr.io/acme/agent:v1");
+        assert_eq!(
+            args,
+            [
+                "verify",
+                "--key",
+                "env://COSIGN_PUB",
+                "--output",
+                "text",
+                "--",
+                "ghcr.io/acme/agent:v1"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn image_without_trust_root_is_rejected() {
+        let v = verifier("true", vec![root("acme", &["ghcr.io/acme"])]);
+        let err = v
+            .verify("docker.io/library/python:3.11", &TenantId::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, RuntimeError::ImageVerificationFailed { .. }));
+    }
+
+    #[tokio::test]
+    async fn built_runtime_images_are_exempt() {
+        let v = verifier("false", vec![]);
+        let outcome = v
+            .verify("aegis-runtime/agent:1.0.0-abc", &TenantId::default())
+            .await
+            .unwrap();
+        assert!(matches!(outcome, ImageVerification::Skipped { .. }));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn cosign_exit_status_decides_outcome() {
+        let image = "ghcr.io/acme/agent:v1";
+        let ok = verifier("true", vec![root("acme", &["ghcr.io/acme"])]);
+        assert_eq!(
+            ok.verify(image, &TenantId::default()).await.unwrap(),
+            ImageVerification::Verified {
+                trust_root: "acme".to_string()
+            }
+        );
+
+        let rejected = verifier("false", vec![root("acme", &["ghcr.io/acme"])]);
+        match rejected.verify(image, &TenantId::default()).await {
+            Err(RuntimeError::ImageVerificationFailed { reason, .. }) => {
+                assert!(reason.contains("acme"), "unexpected reason: {reason}")
+            }
+            other => panic!("expected ImageVerificationFailed, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,247 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # IMAP Poller
+//!
+//! Polls one mailbox (`spec.ingestion.imap[]`) over IMAP with implicit TLS.
+//! Each poll fetches the `UNSEEN` messages without marking them read, hands
+//! each to the [`IngestionService`] and flags it `\Seen` only once its
+//! execution has started, so a message that fails to ingest is retried on
+//! the next poll.
+//!
+//! Replies go to the sender (or `Reply-To`) through the source's
+//! `reply_url`; the poller itself never sends mail.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use futures::TryStreamExt;
+use mail_parser::{MessageParser, MimeHeaders};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls;
+use tracing::{debug, info, warn};
+
+use crate::application::ingestion::{IngestionRoute, IngestionService, ReplyEndpoint};
+use crate::domain::ingestion::{InboundAttachment, InboundMessage, IngestionChannel, ReplyTarget};
+use crate::domain::node_config::{resolve_env_value, ImapIngestionConfig};
+
+/// Connect and login must finish within this long.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(30);
+
+pub struct ImapPoller {
+    config: ImapIngestionConfig,
+    password: String,
+    route: IngestionRoute,
+    service: Arc<IngestionService>,
+    tls: tokio_rustls::TlsConnector,
+}
+
+impl ImapPoller {
+    pub fn new(
+        config: &ImapIngestionConfig,
+        service: Arc<IngestionService>,
+    ) -> anyhow::Result<Self> {
+        let password = resolve_env_value(&config.password)?;
+        let reply = config.reply_url.clone().map(|url| ReplyEndpoint {
+            url,
+            headers: HashMap::new(),
+        });
+        let route = IngestionRoute::from_config(&config.target, reply)?;
+
+        let mut roots = rustls::RootCertStore::empty();
+        roots.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+        let tls_config = rustls::ClientConfig::builder_with_provider(Arc::new(
+            rustls::crypto::aws_lc_rs::default_provider(),
+        ))
+        .with_safe_default_protocol_versions()?
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+
+        Ok(Self {
+            config: config.clone(),
+            password,
+            route,
+            service,
+            tls: tokio_rustls::TlsConnector::from(Arc::new(tls_config)),
+        })
+    }
+
+    /// Poll until the task is dropped.
+    pub async fn run(self) {
+        info!(
+            source = %self.config.name,
+            host = %self.config.host,
+            mailbox = %self.config.mailbox,
+            "IMAP ingestion poller started"
+        );
+        let mut interval =
+            tokio::time::interval(Duration::from_secs(self.config.poll_interval_seconds));
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            interval.tick().await;
+            match self.poll_once().await {
+                Ok(0) => {}
+                Ok(count) => debug!(source = %self.config.name, count, "Ingested emails"),
+                Err(e) => warn!(source = %self.config.name, error = %e, "IMAP poll failed"),
+            }
+        }
+    }
+
+    /// Ingest every unseen message once. Returns how many were ingested.
+    pub async fn poll_once(&self) -> anyhow::Result<usize> {
+        let host = self.config.host.clone();
+        let connect = async {
+            let tcp = TcpStream::connect((host.as_str(), self.config.port)).await?;
+            let server_name = rustls::pki_types::ServerName::try_from(host.clone())?;
+            let stream = self.tls.connect(server_name, tcp).await?;
+            let mut client = async_imap::Client::new(stream);
+            let _greeting = client.read_response().await;
+            client
+                .login(&self.config.username, &self.password)
+                .await
+                .map_err(|(e, _client)| anyhow::Error::from(e))
+        };
+        let mut session = tokio::time::timeout(CONNECT_TIMEOUT, connect)
+            .await
+            .context("IMAP connect timed out")??;
+        session.select(&self.config.mailbox).await?;
+
+        let mut uids: Vec<u32> = session.uid_search("UNSEEN").await?.into_iter().collect();
+        uids.sort_unstable();
+
+        let mut ingested = 0;
+        for uid in uids {
+            let fetches: Vec<_> = session
+                .uid_fetch(uid.to_string(), "BODY.PEEK[]")
+                .await?
+                .try_collect()
+                .await?;
+            let Some(raw) = fetches.iter().find_map(|fetch| fetch.body()) else {
+                continue;
+            };
+            let Some(message) = parse_email(&self.config.name, raw) else {
+                warn!(source = %self.config.name, uid, "Skipping unparseable email");
+                continue;
+            };
+            match self.service.ingest(&self.route, message).await {
+                Ok(_) => {
+                    let _: Vec<_> = session
+                        .uid_store(uid.to_string(), "+FLAGS (\\Seen)")
+                        .await?
+                        .try_collect()
+                        .await?;
+                    ingested += 1;
+                }
+                Err(e) => {
+                    warn!(source = %self.config.name, uid, error = %e, "Email ingestion failed")
+                }
+            }
+        }
+        session.logout().await?;
+        Ok(ingested)
+    }
+}
+
+/// Normalize a raw RFC 5322 message. `None` when it cannot be parsed or
+/// has no sender.
+pub fn parse_email(source: &str, raw: &[u8]) -> Option<InboundMessage> {
+    let message = MessageParser::default().parse(raw)?;
+    let sender = message
+        .from()
+        .and_then(|from| from.first())
+        .and_then(|addr| addr.address())?
+        .to_string();
+    let reply_address = message
+        .reply_to()
+        .and_then(|reply_to| reply_to.first())
+        .and_then(|addr| addr.address())
+        .map_or_else(|| sender.clone(), str::to_string);
+    let message_id = message.message_id().map(|id| format!("<{id}>"));
+    let subject = message.subject().map(str::to_string);
+
+    let attachments = message
+        .attachments()
+        .map(|part| InboundAttachment {
+            filename: part.attachment_name().unwrap_or("attachment").to_string(),
+            declared_mime_type: part.content_type().map(|ct| match ct.subtype() {
+                Some(subtype) => format!("{}/{subtype}", ct.ctype()),
+                None => ct.ctype().to_string(),
+            }),
+            data: part.contents().to_vec(),
+        })
+        .collect();
+
+    Some(InboundMessage {
+        channel: IngestionChannel::Email,
+        source: source.to_string(),
+        external_id: message_id
+            .clone()
+            .unwrap_or_else(|| format!("<{}@aegis-ingestion>", uuid::Uuid::new_v4())),
+        sender,
+        subject: subject.clone(),
+        body: message
+            .body_text(0)
+            .map(|body| body.into_owned())
+            .unwrap_or_default(),
+        thread_id: message_id.clone(),
+        received_at: message
+            .date()
+            .and_then(|date| DateTime::<Utc>::from_timestamp(date.to_timestamp(), 0))
+            .unwrap_or_else(Utc::now),
+        attachments,
+        reply_to: Some(ReplyTarget::Email {
+            to: reply_address,
+            subject: subject.unwrap_or_default(),
+            in_reply_to: message_id,
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_multipart_email_with_attachment() {
+        let raw = concat!(
+            "From: Jane Doe <jane@example.com>\r\n",
+            "Reply-To: support-replies@example.com\r\n",
+            "Subject: Printer on fire\r\n",
+            "Message-ID: <abc123@example.com>\r\n",
+            "Date: Thu, 15 Oct 2026 09:30:00 +0000\r\n",
+            "MIME-Version: 1.0\r\n",
+            "Content-Type: multipart/mixed; boundary=\"b1\"\r\n",
+            "\r\n",
+            "--b1\r\n",
+            "Content-Type: text/plain; charset=utf-8\r\n",
+            "\r\n",
+            "It is still burning.\r\n",
+            "--b1\r\n",
+            "Content-Type: text/plain\r\n",
+            "Content-Disposition: attachment; filename=\"log.txt\"\r\n",
+            "Content-Transfer-Encoding: base64\r\n",
+            "\r\n",
+            "c21va2U=\r\n",
+            "--b1--\r\n",
+        );
+
+        let message = parse_email("support", raw.as_bytes()).unwrap();
+        assert_eq!(message.sender, "jane@example.com");
+        assert_eq!(message.subject.as_deref(), Some("Printer on fire"));
+        assert_eq!(message.body.trim(), "It is still burning.");
+        assert_eq!(message.external_id, "<abc123@example.com>");
+        assert_eq!(message.attachments.len(), 1);
+        assert_eq!(message.attachments[0].filename, "log.txt");
+        assert_eq!(message.attachments[0].data, b"smoke");
+        assert_eq!(
+            message.reply_to,
+            Some(ReplyTarget::Email {
+                to: "support-replies@example.com".to_string(),
+                subject: "Printer on fire".to_string(),
+                in_reply_to: Some("<abc123@example.com>".to_string()),
+            })
+        );
+    }
+}
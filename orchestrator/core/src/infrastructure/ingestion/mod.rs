@@ -0,0 +1,18 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Message Ingestion Adapters (BC-8)
+//!
+//! Channel adapters that turn inbound email and chat messages into
+//! [`crate::domain::ingestion::InboundMessage`]s and hand them to the
+//! [`crate::application::ingestion::IngestionService`].
+//!
+//! | Adapter | Transport | Config |
+//! |---------|-----------|--------|
+//! | [`ImapPoller`] | Polls a mailbox over IMAP with implicit TLS | `spec.ingestion.imap[]` |
+//! | [`SlackIngestionAdapter`] | Slack Events API, `POST /v1/webhooks/slack/events` | `spec.ingestion.slack` |
+
+pub mod imap;
+pub mod slack;
+
+pub use imap::ImapPoller;
+pub use slack::SlackIngestionAdapter;
@@ -0,0 +1,344 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Slack Events API Receiver
+//!
+//! Backs `POST /v1/webhooks/slack/events` (`spec.ingestion.slack`). Requests
+//! are authenticated with Slack's signing secret: `X-Slack-Signature` is
+//! `v0=<hex>`, an HMAC-SHA256 over `"v0:{X-Slack-Request-Timestamp}:{body}"`,
+//! and the timestamp must be within five minutes of now.
+//!
+//! `message` and `app_mention` events from people (not bots) in an allowed
+//! channel become executions. Slack expects an answer within three seconds,
+//! so the adapter acknowledges at once and downloads files and starts the
+//! execution in the background. Slack redelivers unacknowledged events and
+//! sends both `message` and `app_mention` for a mention; the adapter keeps
+//! recently seen `channel:ts` pairs and ingests each message once.
+//!
+//! The reply is posted to the message's thread with `chat.postMessage`.
+
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use lru::LruCache;
+use serde::Deserialize;
+use sha2::Sha256;
+use subtle::ConstantTimeEq;
+use thiserror::Error;
+use tracing::warn;
+
+use crate::application::ingestion::{IngestionRoute, IngestionService, ReplyEndpoint};
+use crate::domain::ingestion::{InboundAttachment, InboundMessage, IngestionChannel, ReplyTarget};
+use crate::domain::node_config::{resolve_env_value, SlackIngestionConfig};
+
+pub const SLACK_SIGNATURE_HEADER: &str = "X-Slack-Signature";
+pub const SLACK_TIMESTAMP_HEADER: &str = "X-Slack-Request-Timestamp";
+
+/// Largest accepted difference between the request timestamp and now.
+pub const SLACK_SIGNATURE_TOLERANCE_SECS: i64 = 300;
+
+const POST_MESSAGE_URL: &str = "https://slack.com/api/chat.postMessage";
+const FILE_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+const SEEN_MESSAGES: usize = 1024;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SlackSignatureError {
+    #[error("missing or malformed Slack signature headers")]
+    Malformed,
+    #[error("Slack request timestamp outside the allowed window")]
+    OutsideTolerance,
+    #[error("Slack signature mismatch")]
+    Mismatch,
+}
+
+/// Check a request against the app's signing secret.
+pub fn verify_slack_signature(
+    secret: &[u8],
+    timestamp: &str,
+    signature: &str,
+    body: &[u8],
+    now_unix: i64,
+) -> Result<(), SlackSignatureError> {
+    let ts: i64 = timestamp
+        .trim()
+        .parse()
+        .map_err(|_| SlackSignatureError::Malformed)?;
+    if (now_unix - ts).abs() > SLACK_SIGNATURE_TOLERANCE_SECS {
+        return Err(SlackSignatureError::OutsideTolerance);
+    }
+    let provided = signature
+        .strip_prefix("v0=")
+        .and_then(|hex_sig| hex::decode(hex_sig).ok())
+        .ok_or(SlackSignatureError::Malformed)?;
+
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(format!("v0:{ts}:").as_bytes());
+    mac.update(body);
+    let expected = mac.finalize().into_bytes();
+    if expected.as_slice().ct_eq(&provided).into() {
+        Ok(())
+    } else {
+        Err(SlackSignatureError::Mismatch)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum SlackEnvelope {
+    UrlVerification {
+        challenge: String,
+    },
+    EventCallback {
+        event_id: String,
+        event: SlackEvent,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackEvent {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    subtype: Option<String>,
+    #[serde(default)]
+    bot_id: Option<String>,
+    #[serde(default)]
+    channel: Option<String>,
+    #[serde(default)]
+    user: Option<String>,
+    #[serde(default)]
+    text: String,
+    #[serde(default)]
+    ts: Option<String>,
+    #[serde(default)]
+    thread_ts: Option<String>,
+    #[serde(default)]
+    files: Vec<SlackFile>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SlackFile {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    mimetype: Option<String>,
+    #[serde(default)]
+    size: Option<u64>,
+    #[serde(default)]
+    url_private_download: Option<String>,
+}
+
+/// What the HTTP handler should answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum SlackEventOutcome {
+    /// `url_verification` handshake; echo the challenge.
+    Challenge(String),
+    /// Ingestion started in the background.
+    Accepted,
+    /// Not a message we act on (bot message, other channel, duplicate, ...).
+    Ignored,
+}
+
+pub struct SlackIngestionAdapter {
+    config: SlackIngestionConfig,
+    signing_secret: String,
+    bot_token: String,
+    route: IngestionRoute,
+    service: Arc<IngestionService>,
+    max_attachment_bytes: u64,
+    client: reqwest::Client,
+    seen: Mutex<LruCache<String, ()>>,
+}
+
+impl SlackIngestionAdapter {
+    pub fn new(
+        config: &SlackIngestionConfig,
+        max_attachment_bytes: u64,
+        service: Arc<IngestionService>,
+    ) -> anyhow::Result<Self> {
+        let signing_secret = resolve_env_value(&config.signing_secret)?;
+        let bot_token = resolve_env_value(&config.bot_token)?;
+        let reply = ReplyEndpoint {
+            url: POST_MESSAGE_URL.to_string(),
+            headers: HashMap::from([("Authorization".to_string(), format!("Bearer {bot_token}"))]),
+        };
+        let route = IngestionRoute::from_config(&config.target, Some(reply))?;
+        Ok(Self {
+            config: config.clone(),
+            signing_secret,
+            bot_token,
+            route,
+            service,
+            max_attachment_bytes,
+            client: reqwest::Client::builder()
+                .timeout(FILE_DOWNLOAD_TIMEOUT)
+                .build()?,
+            seen: Mutex::new(LruCache::new(
+                NonZeroUsize::new(SEEN_MESSAGES).expect("non-zero capacity"),
+            )),
+        })
+    }
+
+    pub fn verify(
+        &self,
+        timestamp: &str,
+        signature: &str,
+        body: &[u8],
+    ) -> Result<(), SlackSignatureError> {
+        verify_slack_signature(
+            self.signing_secret.as_bytes(),
+            timestamp,
+            signature,
+            body,
+            Utc::now().timestamp(),
+        )
+    }
+
+    /// Handle a verified request body.
+    pub fn handle(self: &Arc<Self>, body: &[u8]) -> Result<SlackEventOutcome, serde_json::Error> {
+        let (event_id, event) = match serde_json::from_slice::<SlackEnvelope>(body)? {
+            SlackEnvelope::UrlVerification { challenge } => {
+                return Ok(SlackEventOutcome::Challenge(challenge))
+            }
+            SlackEnvelope::EventCallback { event_id, event } => (event_id, event),
+            SlackEnvelope::Other => return Ok(SlackEventOutcome::Ignored),
+        };
+        let (Some(channel), Some(user), Some(ts)) =
+            (event.channel.clone(), event.user.clone(), event.ts.clone())
+        else {
+            return Ok(SlackEventOutcome::Ignored);
+        };
+        let from_person =
+            event.bot_id.is_none() && matches!(event.subtype.as_deref(), None | Some("file_share"));
+        let allowed_channel =
+            self.config.channels.is_empty() || self.config.channels.contains(&channel);
+        if !matches!(event.kind.as_str(), "message" | "app_mention")
+            || !from_person
+            || !allowed_channel
+            || !self.first_sighting(format!("{channel}:{ts}"))
+        {
+            return Ok(SlackEventOutcome::Ignored);
+        }
+
+        let adapter = Arc::clone(self);
+        tokio::spawn(async move {
+            let thread_ts = event.thread_ts.clone().unwrap_or_else(|| ts.clone());
+            let attachments = adapter.download_files(&event.files).await;
+            let message = InboundMessage {
+                channel: IngestionChannel::Slack,
+                source: adapter.config.name.clone(),
+                external_id: event_id,
+                sender: user,
+                subject: None,
+                body: event.text,
+                thread_id: Some(thread_ts.clone()),
+                received_at: parse_slack_ts(&ts).unwrap_or_else(Utc::now),
+                attachments,
+                reply_to: Some(ReplyTarget::Slack { channel, thread_ts }),
+            };
+            if let Err(e) = adapter.service.ingest(&adapter.route, message).await {
+                warn!(source = %adapter.config.name, error = %e, "Slack message ingestion failed");
+            }
+        });
+        Ok(SlackEventOutcome::Accepted)
+    }
+
+    fn first_sighting(&self, key: String) -> bool {
+        self.seen
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .put(key, ())
+            .is_none()
+    }
+
+    async fn download_files(&self, files: &[SlackFile]) -> Vec<InboundAttachment> {
+        let mut attachments = Vec::new();
+        for file in files {
+            let Some(url) = &file.url_private_download else {
+                continue;
+            };
+            let name = file
+                .name
+                .clone()
+                .unwrap_or_else(|| "attachment".to_string());
+            if file
+                .size
+                .is_some_and(|size| size > self.max_attachment_bytes)
+            {
+                warn!(file = %name, "Skipping Slack file over the size limit");
+                continue;
+            }
+            let response = self
+                .client
+                .get(url)
+                .bearer_auth(&self.bot_token)
+                .send()
+                .await
+                .and_then(reqwest::Response::error_for_status);
+            match response {
+                Ok(response) => match response.bytes().await {
+                    Ok(data) => attachments.push(InboundAttachment {
+                        filename: name,
+                        declared_mime_type: file.mimetype.clone(),
+                        data: data.to_vec(),
+                    }),
+                    Err(e) => warn!(file = %name, error = %e, "Slack file download failed"),
+                },
+                Err(e) => warn!(file = %name, error = %e, "Slack file download failed"),
+            }
+        }
+        attachments
+    }
+}
+
+/// Slack timestamps are `"<unix seconds>.<sequence>"`.
+fn parse_slack_ts(ts: &str) -> Option<DateTime<Utc>> {
+    let seconds = ts.split('.').next()?.parse().ok()?;
+    DateTime::from_timestamp(seconds, 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(secret: &[u8], ts: i64, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret).unwrap();
+        mac.update(format!("v0:{ts}:").as_bytes());
+        mac.update(body);
+        format!("v0={}", hex::encode(mac.finalize().into_bytes()))
+    }
+
+    #[test]
+    fn verifies_signature_and_timestamp_window() {
+        let secret = b"8f742231b10e8888abcd99yyyzzz85a5";
+        let body = br#"{"type":"url_verification","challenge":"abc"}"#;
+        let now = 1_790_000_000;
+        let signature = sign(secret, now, body);
+
+        assert_eq!(
+            verify_slack_signature(secret, &now.to_string(), &signature, body, now + 10),
+            Ok(())
+        );
+        assert_eq!(
+            verify_slack_signature(secret, &now.to_string(), &signature, b"{}", now),
+            Err(SlackSignatureError::Mismatch)
+        );
+        assert_eq!(
+            verify_slack_signature(secret, &now.to_string(), &signature, body, now + 301),
+            Err(SlackSignatureError::OutsideTolerance)
+        );
+        assert_eq!(
+            verify_slack_signature(secret, "soon", &signature, body, now),
+            Err(SlackSignatureError::Malformed)
+        );
+        assert!(matches!(
+            serde_json::from_slice::<SlackEnvelope>(body).unwrap(),
+            SlackEnvelope::UrlVerification { challenge } if challenge == "abc"
+        ));
+    }
+}
@@ -15,6 +15,7 @@
 //! | [`gemini`] | Google Gemini `gemini-*` | Native Gemini API |
 //! | [`ollama`] | Ollama local models | Dev/offline use |
 //! | [`registry`] | `ProviderRegistry` | Selects provider by manifest `spec.runtime.model` |
+//! | [`replay`] | `RecordingLLMProvider` / `ReplayLLMProvider` | Capture and deterministically replay LLM calls |
 //!
 //! See ADR-009 (LLM Provider Strategy).
 
@@ -28,5 +29,6 @@ pub mod gemini;
 pub mod ollama;
 pub mod openai;
 pub mod registry;
+pub mod replay;
 
 pub use registry::ProviderRegistry;
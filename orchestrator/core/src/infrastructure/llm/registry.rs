@@ -5,6 +5,11 @@
 //! `ProviderRegistry` resolves model aliases (e.g. `"default"`, `"fast"`,
 //! `"smart"`) to real `LLMProvider` adapters at runtime based on node config.
 //! Includes retry-with-exponential-backoff and one-level fallback.
+//!
+//! Tenants listed under `spec.tenant_llm` get their own layer over the node
+//! defaults: a model alias allowlist, providers (with their own API keys)
+//! replacing node aliases of the same name, and a call-rate cap. Callers that
+//! know the execution's tenant use [`ProviderRegistry::generate_chat_for_tenant`].
 
 use crate::domain::llm::{
     ChatMessage, ChatResponse, GenerationOptions, GenerationResponse, LLMError, LLMProvider,
@@ -12,10 +17,16 @@ use crate::domain::llm::{
 };
 use crate::domain::node_config::{
     resolve_env_value, LLMProviderConfig, LLMSelectionStrategy, NodeConfigManifest,
+    RateLimitDefinition, TenantLLMConfig,
 };
+use crate::domain::secrets::AccessContext;
+use crate::domain::tenant::TenantId;
+use crate::infrastructure::secrets_manager::SecretsManager;
+use anyhow::Context;
 use async_trait::async_trait;
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use tracing::{debug, info, warn};
 
 use super::anthropic::AnthropicAdapter;
@@ -46,6 +57,74 @@ const MAX_BACKOFF_EXPONENT: u32 = 16;
 /// Matches the value produced by `retry_delay_ms = 1000` and `MAX_BACKOFF_EXPONENT = 16`.
 const MAX_BACKOFF_MS: u64 = 65_536_000; // 2^16 * 1000 ms
 
+/// Secret field read when a tenant `api_key` reference omits `#field`.
+pub const DEFAULT_TENANT_API_KEY_FIELD: &str = "api_key";
+
+/// A model alias served by a tenant's own provider.
+struct TenantAlias {
+    model: String,
+    provider: Arc<dyn LLMProvider>,
+    max_output_tokens: Option<u32>,
+    temperature: Option<f32>,
+}
+
+/// Tenant-scoped layer consulted before the node-level alias map.
+struct TenantProviders {
+    /// alias → tenant override; aliases not listed resolve to the node default.
+    aliases: HashMap<String, TenantAlias>,
+    /// `None` allows every alias.
+    allowed_aliases: Option<HashSet<String>>,
+    limiter: Option<CallLimiter>,
+}
+
+impl TenantProviders {
+    fn allows(&self, alias: &str) -> bool {
+        self.allowed_aliases
+            .as_ref()
+            .is_none_or(|allowed| allowed.contains(alias))
+    }
+}
+
+/// Fixed-window call counter enforcing a tenant's `rate_limit`.
+struct CallLimiter {
+    calls: u32,
+    window: Duration,
+    /// (window start, calls made in the window)
+    state: Mutex<(Instant, u32)>,
+}
+
+impl CallLimiter {
+    fn new(limit: &RateLimitDefinition) -> Self {
+        Self {
+            calls: limit.calls,
+            window: Duration::from_secs(u64::from(limit.per_seconds)),
+            state: Mutex::new((Instant::now(), 0)),
+        }
+    }
+
+    /// Count one call; `false` when the current window is exhausted.
+    fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().unwrap_or_else(|e| e.into_inner());
+        let now = Instant::now();
+        if now.duration_since(state.0) >= self.window {
+            *state = (now, 0);
+        }
+        if state.1 >= self.calls {
+            return false;
+        }
+        state.1 += 1;
+        true
+    }
+}
+
+/// The adapter a chat call runs against, plus the fallback it may use.
+struct ChatTarget<'a> {
+    alias: &'a str,
+    model_name: &'a str,
+    provider: &'a Arc<dyn LLMProvider>,
+    fallback: Option<&'a (String, Arc<dyn LLMProvider>)>,
+}
+
 /// Registry for managing LLM providers and resolving model aliases.
 ///
 /// Each entry in `alias_map` is an `Arc<dyn LLMProvider>` that was constructed at
@@ -75,6 +154,8 @@ pub struct ProviderRegistry {
     /// `generate`. On elapsed: return `LLMError::Network("upstream timeout
     /// after Ns")`. Sourced from `LLMSelection::llm_overall_timeout_secs`.
     llm_overall_timeout_secs: u64,
+    /// Tenant layers from `spec.tenant_llm`; see [`Self::with_tenant_configs`].
+    tenants: HashMap<TenantId, TenantProviders>,
 }
 
 /// Returns true for `LLMError` variants that are deterministic upstream
@@ -256,9 +337,111 @@ impl ProviderRegistry {
             max_retries: config.spec.llm_selection.max_retries,
             retry_delay_ms: config.spec.llm_selection.retry_delay_ms,
             llm_overall_timeout_secs: config.spec.llm_selection.llm_overall_timeout_secs,
+            tenants: HashMap::new(),
         })
     }
 
+    /// Layer tenant-scoped provider configs (`spec.tenant_llm`) over the node
+    /// defaults.
+    ///
+    /// Within a tenant the first definition of an alias wins; the node
+    /// selection strategy does not apply. `secret:` API keys are read through
+    /// `secrets` here, so a key that cannot be resolved fails startup rather
+    /// than silently routing the tenant to node credentials.
+    pub async fn with_tenant_configs(
+        mut self,
+        tenants: &[TenantLLMConfig],
+        secrets: &SecretsManager,
+    ) -> anyhow::Result<Self> {
+        for tenant_config in tenants {
+            let tenant_id = TenantId::new(tenant_config.tenant_id.as_str()).map_err(|e| {
+                anyhow::anyhow!(
+                    "Invalid tenant_llm tenant_id '{}': {e}",
+                    tenant_config.tenant_id
+                )
+            })?;
+
+            let mut aliases = HashMap::new();
+            for provider_config in tenant_config.llm_providers.iter().filter(|p| p.enabled) {
+                let api_key = Self::resolve_tenant_api_key(&provider_config.api_key, secrets)
+                    .await
+                    .with_context(|| {
+                        format!(
+                            "Failed to resolve API key for provider '{}' of tenant '{tenant_id}'",
+                            provider_config.name
+                        )
+                    })?;
+                for model_config in &provider_config.models {
+                    if aliases.contains_key(&model_config.alias) {
+                        continue;
+                    }
+                    let provider = Self::create_adapter_with_key(
+                        provider_config,
+                        &model_config.model,
+                        api_key.clone(),
+                    )?;
+                    info!(
+                        "Tenant '{}': alias '{}' -> {} ({})",
+                        tenant_id, model_config.alias, model_config.model, provider_config.name
+                    );
+                    aliases.insert(
+                        model_config.alias.clone(),
+                        TenantAlias {
+                            model: model_config.model.clone(),
+                            provider,
+                            max_output_tokens: model_config.max_output_tokens,
+                            temperature: model_config.temperature,
+                        },
+                    );
+                }
+            }
+
+            let allowed_aliases = (!tenant_config.allowed_aliases.is_empty())
+                .then(|| tenant_config.allowed_aliases.iter().cloned().collect());
+            self.tenants.insert(
+                tenant_id,
+                TenantProviders {
+                    aliases,
+                    allowed_aliases,
+                    limiter: tenant_config.rate_limit.as_ref().map(CallLimiter::new),
+                },
+            );
+        }
+        Ok(self)
+    }
+
+    /// Wrap every adapter (alias, health-check and fallback) so it consults
+    /// `faults` (target `llm`) before each call.
+    #[cfg(feature = "fault-injection")]
+    pub fn with_fault_injector(
+        mut self,
+        faults: Arc<crate::infrastructure::fault_injection::FaultInjector>,
+    ) -> Self {
+        use crate::infrastructure::fault_injection::FaultInjectingLLMProvider;
+
+        let wrap = |adapter: &Arc<dyn LLMProvider>| -> Arc<dyn LLMProvider> {
+            Arc::new(FaultInjectingLLMProvider::new(
+                adapter.clone(),
+                faults.clone(),
+            ))
+        };
+        for (_, adapter) in self.alias_map.values_mut() {
+            *adapter = wrap(adapter);
+        }
+        for adapter in self.providers.values_mut() {
+            *adapter = wrap(adapter);
+        }
+        if let Some((_, adapter)) = self.fallback_provider.as_mut() {
+            *adapter = wrap(adapter);
+        }
+        for tenant in self.tenants.values_mut() {
+            for tenant_alias in tenant.aliases.values_mut() {
+                tenant_alias.provider = wrap(&tenant_alias.provider);
+            }
+        }
+        self
+    }
+
     /// Create an adapter for the given provider config initialized with a specific model name.
     ///
     /// Called twice per (provider, model) pair:
@@ -269,7 +452,14 @@ impl ProviderRegistry {
         model: &str,
     ) -> anyhow::Result<Arc<dyn LLMProvider>> {
         let api_key = Self::resolve_api_key(&config.api_key)?;
+        Self::create_adapter_with_key(config, model, api_key)
+    }
 
+    fn create_adapter_with_key(
+        config: &LLMProviderConfig,
+        model: &str,
+        api_key: String,
+    ) -> anyhow::Result<Arc<dyn LLMProvider>> {
         let endpoint = if config.endpoint.is_empty() {
             match config.provider_type.as_str() {
                 "openai" | "openai-compatible" => "https://api.openai.com/v1",
@@ -307,6 +497,27 @@ impl ProviderRegistry {
         }
     }
 
+    /// Resolve a tenant API key: `secret:engine/path[#field]` is read through
+    /// the secrets provider, anything else goes through [`Self::resolve_api_key`].
+    async fn resolve_tenant_api_key(
+        key: &Option<String>,
+        secrets: &SecretsManager,
+    ) -> anyhow::Result<String> {
+        let Some(reference) = key.as_deref().and_then(|k| k.strip_prefix("secret:")) else {
+            return Self::resolve_api_key(key);
+        };
+        let (kv_path, field) = reference
+            .split_once('#')
+            .unwrap_or((reference, DEFAULT_TENANT_API_KEY_FIELD));
+        let (engine, path) = kv_path.split_once('/').ok_or_else(|| {
+            anyhow::anyhow!("API key secret reference must be 'secret:engine/path[#field]'")
+        })?;
+        let secret = secrets
+            .read_secret_field(engine, path, field, &AccessContext::system("orchestrator"))
+            .await?;
+        Ok(secret.expose().to_string())
+    }
+
     /// Apply per-alias `max_output_tokens` override to a copy of the given options.
     /// If the alias has a configured override, `max_tokens` is replaced; otherwise
     /// the original options are returned unchanged.
@@ -338,9 +549,88 @@ impl ProviderRegistry {
             .get(alias)
             .ok_or_else(|| LLMError::ModelNotFound(format!("Model alias '{alias}' not found")))?;
 
-        info!("LLM inference: alias='{}', model='{}'", alias, model_name);
-
+        let target = ChatTarget {
+            alias,
+            model_name,
+            provider,
+            fallback: self.fallback_provider.as_ref(),
+        };
         let effective_options = self.apply_alias_options(alias, options);
+        self.chat_with_retries(target, messages, tools, &effective_options, options)
+            .await
+    }
+
+    /// Generate a chat response for `alias` on behalf of `tenant_id`.
+    ///
+    /// Tenants without a `spec.tenant_llm` entry behave exactly like
+    /// [`Self::generate_chat`]. Otherwise the alias must be on the tenant's
+    /// allowlist, the call counts against the tenant's rate limit, and a
+    /// tenant override of the alias is used in place of the node adapter.
+    /// Tenant overrides never fall back to the node fallback provider, which
+    /// would bill the tenant's traffic to platform credentials.
+    pub async fn generate_chat_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        alias: &str,
+        messages: &[ChatMessage],
+        tools: &[ToolSchema],
+        options: &GenerationOptions,
+    ) -> Result<ChatResponse, LLMError> {
+        let Some(tenant) = self.tenants.get(tenant_id) else {
+            return self.generate_chat(alias, messages, tools, options).await;
+        };
+
+        if !tenant.allows(alias) {
+            return Err(LLMError::InvalidInput(format!(
+                "Model alias '{alias}' is not allowed for tenant '{tenant_id}'"
+            )));
+        }
+        if let Some(limiter) = &tenant.limiter {
+            if !limiter.try_acquire() {
+                warn!("Tenant '{}' exceeded its LLM call rate limit", tenant_id);
+                return Err(LLMError::RateLimit);
+            }
+        }
+
+        let Some(tenant_alias) = tenant.aliases.get(alias) else {
+            return self.generate_chat(alias, messages, tools, options).await;
+        };
+        let target = ChatTarget {
+            alias,
+            model_name: &tenant_alias.model,
+            provider: &tenant_alias.provider,
+            fallback: None,
+        };
+        let mut effective_options = options.clone();
+        if let Some(max_tokens) = tenant_alias.max_output_tokens {
+            effective_options.max_tokens = Some(max_tokens);
+        }
+        if let Some(temp) = tenant_alias.temperature {
+            effective_options.temperature = Some(temp);
+        }
+        self.chat_with_retries(target, messages, tools, &effective_options, options)
+            .await
+    }
+
+    /// Retry loop shared by [`Self::generate_chat`] and
+    /// [`Self::generate_chat_for_tenant`]. The fallback is called with the
+    /// caller's `options`, not the alias overrides.
+    async fn chat_with_retries(
+        &self,
+        target: ChatTarget<'_>,
+        messages: &[ChatMessage],
+        tools: &[ToolSchema],
+        effective_options: &GenerationOptions,
+        options: &GenerationOptions,
+    ) -> Result<ChatResponse, LLMError> {
+        let ChatTarget {
+            alias,
+            model_name,
+            provider,
+            fallback,
+        } = target;
+
+        info!("LLM inference: alias='{}', model='{}'", alias, model_name);
 
         let overall_budget = tokio::time::Duration::from_secs(self.llm_overall_timeout_secs.max(1));
 
@@ -349,7 +639,7 @@ impl ProviderRegistry {
 
             for attempt in 0..self.max_retries {
                 match provider
-                    .generate_chat(messages, tools, &effective_options)
+                    .generate_chat(messages, tools, effective_options)
                     .await
                 {
                     Ok(response) => {
@@ -385,7 +675,7 @@ impl ProviderRegistry {
                         last_error = Some(e);
 
                         if attempt == self.max_retries - 1 {
-                            if let Some((fallback_model, fallback)) = &self.fallback_provider {
+                            if let Some((fallback_model, fallback)) = fallback {
                                 info!("Trying fallback provider (model='{}')", fallback_model);
                                 match fallback.generate_chat(messages, tools, options).await {
                                     Ok(r) => return Ok(r),
@@ -536,6 +826,17 @@ impl ProviderRegistry {
             _ => ApiKeySource::Platform,
         }
     }
+
+    /// [`Self::key_source_for_alias`] for calls made through
+    /// [`Self::generate_chat_for_tenant`]. Aliases served by the tenant's own
+    /// providers run on the tenant's keys and quota, so they count as
+    /// `User`; the tenant's configured `rate_limit` applies instead.
+    pub fn key_source_for_tenant_alias(&self, tenant_id: &TenantId, alias: &str) -> ApiKeySource {
+        match self.tenants.get(tenant_id) {
+            Some(tenant) if tenant.aliases.contains_key(alias) => ApiKeySource::User,
+            _ => self.key_source_for_alias(alias),
+        }
+    }
 }
 
 // Implement domain LLMProvider trait for infrastructure ProviderRegistry
@@ -570,6 +871,53 @@ impl LLMProvider for ProviderRegistry {
     }
 }
 
+/// [`LLMProvider`] bound to one model alias of a [`ProviderRegistry`], with
+/// the registry's retries and fallback. The registry's own `LLMProvider`
+/// impl always uses `"default"`; use this when a manifest's
+/// `spec.runtime.model` should decide.
+pub struct AliasedProvider {
+    registry: Arc<ProviderRegistry>,
+    alias: String,
+}
+
+impl AliasedProvider {
+    pub fn new(registry: Arc<ProviderRegistry>, alias: impl Into<String>) -> Self {
+        Self {
+            registry,
+            alias: alias.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl LLMProvider for AliasedProvider {
+    async fn generate(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+    ) -> Result<GenerationResponse, LLMError> {
+        self.registry.generate(&self.alias, prompt, options).await
+    }
+
+    async fn generate_chat(
+        &self,
+        messages: &[ChatMessage],
+        tools: &[ToolSchema],
+        options: &GenerationOptions,
+    ) -> Result<ChatResponse, LLMError> {
+        self.registry
+            .generate_chat(&self.alias, messages, tools, options)
+            .await
+    }
+
+    async fn health_check(&self) -> Result<(), LLMError> {
+        let (_, provider) = self.registry.alias_map.get(&self.alias).ok_or_else(|| {
+            LLMError::ModelNotFound(format!("Model alias '{}' not found", self.alias))
+        })?;
+        provider.health_check().await
+    }
+}
+
 impl ProviderRegistry {
     /// Test-only constructor that wires concrete primary + optional fallback
     /// adapters without going through `from_config`. Used to inject mock
@@ -601,6 +949,7 @@ impl ProviderRegistry {
             max_retries,
             retry_delay_ms,
             llm_overall_timeout_secs,
+            tenants: HashMap::new(),
         }
     }
 }
@@ -990,6 +1339,175 @@ mod tests {
         );
     }
 
+    fn tenant_layer(
+        aliases: Vec<(&str, Arc<dyn LLMProvider>)>,
+        allowed_aliases: Option<Vec<&str>>,
+        rate_limit: Option<RateLimitDefinition>,
+    ) -> TenantProviders {
+        TenantProviders {
+            aliases: aliases
+                .into_iter()
+                .map(|(alias, provider)| {
+                    (
+                        alias.to_string(),
+                        TenantAlias {
+                            model: "tenant-model".to_string(),
+                            provider,
+                            max_output_tokens: None,
+                            temperature: None,
+                        },
+                    )
+                })
+                .collect(),
+            allowed_aliases: allowed_aliases
+                .map(|allowed| allowed.into_iter().map(str::to_string).collect()),
+            limiter: rate_limit.as_ref().map(CallLimiter::new),
+        }
+    }
+
+    #[tokio::test]
+    async fn tenant_override_replaces_node_alias_without_fallback() {
+        let node = MockProvider::panicking();
+        let node_fallback = MockProvider::panicking();
+        let tenant_provider = MockProvider::with_responses(vec![Ok(ok_response())]);
+        let tenant_adapter: Arc<dyn LLMProvider> = tenant_provider.clone();
+        let mut registry = make_registry(node.clone(), Some(node_fallback.clone()), 30);
+        let acme = TenantId::new("acme").unwrap();
+        registry.tenants.insert(
+            acme.clone(),
+            tenant_layer(vec![("default", tenant_adapter)], None, None),
+        );
+
+        let result = registry
+            .generate_chat_for_tenant(&acme, "default", &[], &[], &GenerationOptions::default())
+            .await;
+        assert!(result.is_ok());
+        assert_eq!(tenant_provider.call_count(), 1);
+        assert_eq!(
+            registry.key_source_for_tenant_alias(&acme, "default"),
+            ApiKeySource::User
+        );
+        assert_eq!(
+            registry.key_source_for_tenant_alias(&TenantId::consumer(), "default"),
+            ApiKeySource::Platform
+        );
+
+        // Tenant providers never fall back to the node fallback.
+        let failing: Arc<dyn LLMProvider> = MockProvider::with_responses(vec![
+            Err(LLMError::Provider("boom".into())),
+            Err(LLMError::Provider("boom".into())),
+            Err(LLMError::Provider("boom".into())),
+        ]);
+        registry.tenants.insert(
+            acme.clone(),
+            tenant_layer(vec![("default", failing)], None, None),
+        );
+        let result = registry
+            .generate_chat_for_tenant(&acme, "default", &[], &[], &GenerationOptions::default())
+            .await;
+        assert!(matches!(result, Err(LLMError::Provider(_))));
+    }
+
+    #[tokio::test]
+    async fn tenant_allowlist_and_rate_limit_are_enforced() {
+        let node = MockProvider::new();
+        *node.default_response.lock().unwrap() = Some(Ok(ok_response()));
+        let node = Arc::new(node);
+        let mut registry = make_registry(node.clone(), None, 30);
+        let acme = TenantId::new("acme").unwrap();
+        registry.tenants.insert(
+            acme.clone(),
+            tenant_layer(
+                vec![],
+                Some(vec!["default"]),
+                Some(RateLimitDefinition {
+                    calls: 2,
+                    per_seconds: 3600,
+                }),
+            ),
+        );
+        let opts = GenerationOptions::default();
+
+        let denied = registry
+            .generate_chat_for_tenant(&acme, "smart", &[], &[], &opts)
+            .await;
+        assert!(matches!(denied, Err(LLMError::InvalidInput(_))));
+
+        // Aliases the tenant does not override resolve to the node default.
+        for _ in 0..2 {
+            assert!(registry
+                .generate_chat_for_tenant(&acme, "default", &[], &[], &opts)
+                .await
+                .is_ok());
+        }
+        let limited = registry
+            .generate_chat_for_tenant(&acme, "default", &[], &[], &opts)
+            .await;
+        assert!(matches!(limited, Err(LLMError::RateLimit)));
+        assert_eq!(node.call_count(), 2);
+
+        // Other tenants are unaffected.
+        assert!(registry
+            .generate_chat_for_tenant(&TenantId::consumer(), "default", &[], &[], &opts)
+            .await
+            .is_ok());
+    }
+
+    #[tokio::test]
+    async fn tenant_configs_resolve_secret_api_keys() {
+        use crate::domain::secrets::SensitiveString;
+        use crate::infrastructure::event_bus::EventBus;
+        use crate::infrastructure::secrets_manager::TestSecretStore;
+
+        let secrets = SecretsManager::from_store(
+            Arc::new(TestSecretStore::new()),
+            Arc::new(EventBus::new(16)),
+        );
+        secrets
+            .write_secret(
+                "kv",
+                "tenants/acme/llm",
+                HashMap::from([("api_key".to_string(), SensitiveString::new("sk-acme"))]),
+                &AccessContext::system("test"),
+            )
+            .await
+            .unwrap();
+
+        let tenant = |api_key: &str| TenantLLMConfig {
+            tenant_id: "acme".to_string(),
+            allowed_aliases: vec![],
+            llm_providers: vec![LLMProviderConfig {
+                name: "acme-openai".to_string(),
+                provider_type: "openai".to_string(),
+                endpoint: String::new(),
+                api_key: Some(api_key.to_string()),
+                enabled: true,
+                models: vec![ModelConfig {
+                    alias: "default".to_string(),
+                    model: "gpt-4o".to_string(),
+                    capabilities: vec!["chat".to_string()],
+                    context_window: 128_000,
+                    cost_per_1k_tokens: 0.0,
+                    max_output_tokens: None,
+                    temperature: None,
+                }],
+            }],
+            rate_limit: None,
+        };
+
+        let registry = make_registry(MockProvider::panicking(), None, 30)
+            .with_tenant_configs(&[tenant("secret:kv/tenants/acme/llm")], &secrets)
+            .await
+            .unwrap();
+        let acme = TenantId::new("acme").unwrap();
+        assert_eq!(registry.tenants[&acme].aliases["default"].model, "gpt-4o");
+
+        let missing = make_registry(MockProvider::panicking(), None, 30)
+            .with_tenant_configs(&[tenant("secret:kv/tenants/globex/llm")], &secrets)
+            .await;
+        assert!(missing.is_err());
+    }
+
     #[test]
     fn test_registry_creation() {
         let config = NodeConfigManifest {
@@ -1025,6 +1543,7 @@ mod tests {
                     }],
                 }],
                 llm_selection: LLMSelection::default(),
+                tenant_llm: vec![],
                 runtime: crate::domain::node_config::RuntimeConfig::default(),
                 network: None,
                 observability: None,
@@ -1048,6 +1567,18 @@ mod tests {
                 max_execution_list_limit: None,
                 billing: None,
                 zaru: None,
+                image_verification: None,
+                tool_cache: None,
+                high_availability: None,
+                deploy_gates: None,
+                feature_flags: None,
+                ingestion: None,
+                workflow_watchdog: None,
+                quotas: None,
+                network_flow_capture: None,
+                execution_queue: None,
+                moderation: None,
+                plugins: None,
             },
         };
 
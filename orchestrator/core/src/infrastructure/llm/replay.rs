@@ -0,0 +1,195 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Record / Replay LLM Providers
+//!
+//! [`RecordingLLMProvider`] wraps a real provider and keeps every
+//! prompt/response pair as an [`LlmExchange`]. [`ReplayLLMProvider`] answers
+//! from a saved list of exchanges without touching the network, so a session
+//! captured once (e.g. by `aegis agent shell`) can be re-run deterministically
+//! as a regression fixture.
+//!
+//! Replay matches prompts exactly. Exchanges with the same prompt are served
+//! in recorded order; a prompt with no recorded answer left fails with
+//! `LLMError::Provider`, which is how a changed prompt template shows up.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::llm::{
+    FinishReason, GenerationOptions, GenerationResponse, LLMError, LLMProvider, TokenUsage,
+};
+
+/// One single-turn LLM call.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LlmExchange {
+    pub prompt: String,
+    pub response: String,
+}
+
+/// [`LLMProvider`] that forwards to `inner` and records each successful call.
+pub struct RecordingLLMProvider {
+    inner: Arc<dyn LLMProvider>,
+    exchanges: Mutex<Vec<LlmExchange>>,
+}
+
+impl RecordingLLMProvider {
+    pub fn new(inner: Arc<dyn LLMProvider>) -> Self {
+        Self {
+            inner,
+            exchanges: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Every exchange recorded so far, in call order.
+    pub fn exchanges(&self) -> Vec<LlmExchange> {
+        self.exchanges.lock().expect("exchanges poisoned").clone()
+    }
+}
+
+#[async_trait]
+impl LLMProvider for RecordingLLMProvider {
+    async fn generate(
+        &self,
+        prompt: &str,
+        options: &GenerationOptions,
+    ) -> Result<GenerationResponse, LLMError> {
+        let response = self.inner.generate(prompt, options).await?;
+        self.exchanges
+            .lock()
+            .expect("exchanges poisoned")
+            .push(LlmExchange {
+                prompt: prompt.to_string(),
+                response: response.text.clone(),
+            });
+        Ok(response)
+    }
+
+    async fn health_check(&self) -> Result<(), LLMError> {
+        self.inner.health_check().await
+    }
+}
+
+/// [`LLMProvider`] that answers from recorded [`LlmExchange`]s.
+pub struct ReplayLLMProvider {
+    responses: Mutex<HashMap<String, VecDeque<String>>>,
+}
+
+impl ReplayLLMProvider {
+    pub fn new(exchanges: impl IntoIterator<Item = LlmExchange>) -> Self {
+        let mut responses: HashMap<String, VecDeque<String>> = HashMap::new();
+        for exchange in exchanges {
+            responses
+                .entry(exchange.prompt)
+                .or_default()
+                .push_back(exchange.response);
+        }
+        Self {
+            responses: Mutex::new(responses),
+        }
+    }
+
+    /// Recorded responses not yet served.
+    pub fn remaining(&self) -> usize {
+        self.responses
+            .lock()
+            .expect("responses poisoned")
+            .values()
+            .map(VecDeque::len)
+            .sum()
+    }
+}
+
+#[async_trait]
+impl LLMProvider for ReplayLLMProvider {
+    async fn generate(
+        &self,
+        prompt: &str,
+        _options: &GenerationOptions,
+    ) -> Result<GenerationResponse, LLMError> {
+        let text = self
+            .responses
+            .lock()
+            .expect("responses poisoned")
+            .get_mut(prompt)
+            .and_then(VecDeque::pop_front)
+            .ok_or_else(|| {
+                LLMError::Provider(format!(
+                    "no recorded response for prompt ({} chars): {}",
+                    prompt.len(),
+                    prompt.chars().take(120).collect::<String>()
+                ))
+            })?;
+        Ok(GenerationResponse {
+            usage: TokenUsage {
+                prompt_tokens: 0,
+                completion_tokens: 0,
+                total_tokens: 0,
+            },
+            text,
+            provider: "replay".to_string(),
+            model: "recorded".to_string(),
+            finish_reason: FinishReason::Stop,
+        })
+    }
+
+    async fn health_check(&self) -> Result<(), LLMError> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn replays_recorded_exchanges_in_order() {
+        let replay = ReplayLLMProvider::new([
+            LlmExchange {
+                prompt: "p".to_string(),
+                response: "first".to_string(),
+            },
+            LlmExchange {
+                prompt: "p".to_string(),
+                response: "second".to_string(),
+            },
+        ]);
+        let options = GenerationOptions::default();
+
+        assert_eq!(replay.generate("p", &options).await.unwrap().text, "first");
+        assert_eq!(replay.generate("p", &options).await.unwrap().text, "second");
+        assert!(matches!(
+            replay.generate("p", &options).await,
+            Err(LLMError::Provider(_))
+        ));
+        assert!(replay.generate("other", &options).await.is_err());
+        assert_eq!(replay.remaining(), 0);
+    }
+
+    #[tokio::test]
+    async fn records_what_it_forwards() {
+        let inner = Arc::new(ReplayLLMProvider::new([LlmExchange {
+            prompt: "hello".to_string(),
+            response: "world".to_string(),
+        }]));
+        let recorder = RecordingLLMProvider::new(inner);
+
+        recorder
+            .generate("hello", &GenerationOptions::default())
+            .await
+            .unwrap();
+        assert!(recorder
+            .generate("missing", &GenerationOptions::default())
+            .await
+            .is_err());
+        assert_eq!(
+            recorder.exchanges(),
+            vec![LlmExchange {
+                prompt: "hello".to_string(),
+                response: "world".to_string(),
+            }]
+        );
+    }
+}
@@ -12,21 +12,38 @@
 //! | [`repositories`] | `AgentRepository`, `ExecutionRepository`, `VolumeRepository` impls | ADR-025 |
 //! | [`runtime`] | Docker runtime adapter implementing `AgentRuntime` trait | ADR-027 |
 //! | [`image_manager`] | `DockerImageManager` trait + `StandardDockerImageManager`, `CredentialResolver` | ADR-045 |
+//! | [`image_verifier`] | `ImageSignatureVerifier` trait + `CosignImageVerifier` (per-tenant trust roots) | ADR-045 |
+//! | [`runtime_gc`] | `RuntimeGarbageCollector`: retention-based cleanup of exited containers and built images | ADR-045 |
+//! | [`runtime_image_builder`] | `RuntimeImageBuilder` trait + `DockerRuntimeImageBuilder` for `spec.runtime.packages` | ADR-043/045 |
+//! | [`runtime_kubernetes`] | `K8sRuntime`: agent iterations as Kubernetes Pods with NetworkPolicy | ADR-027 |
+//! | [`runtime_nomad`] | `NomadRuntime`: agent iterations as dispatches of a parameterized Nomad batch job | ADR-027 |
+//! | [`runtime_placement`] | `PlacementRuntime`: platform-aware placement across local and remote container engines | ADR-027 |
+//! | [`moderation`] | `ModerationProvider`s for `spec.moderation`: built-in PII detectors, regex patterns, external HTTP classifier | — |
+//! | [`network_flow_capture`] | tshark capture sidecar command and flow log parsing for `network_flow_capture` executions | ADR-027 |
 //! | [`nfs`] | NFS Server Gateway: `AegisFSAL`, `NfsServer`, `AegisFileHandle` | ADR-036 |
 //! | [`fuse`] | FUSE FSAL Transport: `FuseFsalDaemon`, bind-mount volume access | ADR-107 |
 //! | [`seal`] | SEAL: attestation, envelope, middleware, policy engine, signature | ADR-035 |
 //! | [`event_bus`] | In-memory pub/sub `EventBus` + `DomainEvent` unified enum | ADR-030 |
+//! | [`event_schema`] | Versioned `EventEnvelope` wire form + event schema registry | ADR-030 |
+//! | `fault_injection` | `FaultInjector` + LLM/storage decorators for chaos testing (`fault-injection` feature) | — |
 //! | [`llm`] | LLM provider adapters (OpenAI, Anthropic, Ollama) anti-corruption layer | ADR-009 |
 //! | [`storage`] | `SeaweedFSAdapter` implementing `StorageProvider` | ADR-032 |
 //! | [`security_context`] | `InMemorySecurityContextRepository` | ADR-035 |
 //! | [`tool_router`] | `ToolRouter` MCP proxy + `InMemorySealSessionRepository` | ADR-033 |
 //! | [`secrets_manager`] | `OpenBaoSecretStore`, `SecretsManager`, `MockSecretStore` | ADR-034 |
+//! | [`outbound_webhook`] | `OutboundWebhookDispatcher`: signed, retried, logged calls to user-provided URLs | — |
+//! | [`ingestion`] | `ImapPoller`, `SlackIngestionAdapter`: inbound email/chat messages for `IngestionService` | — |
 //! | [`db`] | SQLx PostgreSQL connection pool | ADR-025 |
 //! | [`workflow_parser`] | YAML → `Workflow` aggregate deserializer | ADR-015/031 |
+//! | [`workflow_template_engine`] | Compiled per-version workflow templates + Handlebars helpers | ADR-031 |
+//! | [`workflow_import`] | LangGraph / CrewAI definitions → `Workflow` + `AgentManifest` drafts with a warnings report | — |
 //! | [`agent_manifest_parser`] | YAML → `AgentManifest` deserializer | — |
 //! | [`prompt_template_engine`] | Handlebars template expansion for agent prompts | ADR-031 |
 //! | [`context_loader`] | Loads `spec.context` items into agent prompts | — |
 //! | [`temporal_client`] | Temporal.io workflow client (deferred) | ADR-022 |
+//! | [`guidance_queue`] | `InMemoryGuidanceQueue`: pending operator guidance per execution | — |
+//! | [`task_queue`] | `TaskQueue` backends: Postgres `SKIP LOCKED`, Redis Streams (`redis-task-queue` feature), in-memory | — |
+//! | [`wasm_plugin_host`] | `WasmPluginHost`: wasmtime component host for `spec.plugins` (`wasm-plugins` feature) | — |
 //! | [`human_input_service`] | Suspends execution pending human response | ADR-015 |
 
 //! | [`aegis_runtime_proto`] | Generated `aegis.runtime.v1` types shared by server | ADR-042 |
@@ -50,30 +67,49 @@ pub mod discovery;
 pub mod docker;
 pub mod edge;
 pub mod event_bus;
+pub mod event_schema;
+#[cfg(feature = "fault-injection")]
+pub mod fault_injection;
 pub mod fuse;
+pub mod guidance_queue;
 pub mod human_input_service;
 pub mod iam;
 pub mod image_manager;
+pub mod image_verifier;
+pub mod ingestion;
 pub mod llm;
 pub mod log_sanitizer;
+pub mod moderation;
+pub mod network_flow_capture;
 pub mod nfs;
+pub mod outbound_webhook;
 pub mod prompt_template_engine;
 pub mod rate_limit;
 pub mod repositories;
 pub mod runtime;
+pub mod runtime_gc;
+pub mod runtime_image_builder;
+pub mod runtime_kubernetes;
+pub mod runtime_nomad;
+pub mod runtime_placement;
 pub mod seal;
 pub mod seal_gateway_proto;
 pub mod secrets_manager;
 pub mod security_context;
 pub mod sensor;
 pub mod storage;
+pub mod task_queue;
 pub mod telemetry;
 pub mod temporal_client;
 pub mod temporal_event_listener;
 pub mod temporal_proto;
 pub mod tool_router;
+#[cfg(feature = "wasm-plugins")]
+pub mod wasm_plugin_host;
 pub mod web_tools;
+pub mod workflow_import;
 pub mod workflow_parser;
+pub mod workflow_template_engine;
 
 pub use cortex_client::CortexGrpcClient;
 pub use human_input_service::{HumanInputService, HumanInputStatus, PendingRequestInfo};
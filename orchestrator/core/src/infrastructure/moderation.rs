@@ -0,0 +1,342 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Output Moderation Providers
+//!
+//! [`ModerationProvider`] implementations selected by the `type` of each
+//! `spec.moderation.providers` entry:
+//!
+//! | Type | Provider | Checks |
+//! |------|----------|--------|
+//! | `pii` | [`PiiModerationProvider`] | Emails, phone numbers, Luhn-valid card numbers, SSNs |
+//! | `pattern` | [`PatternModerationProvider`] | Operator-supplied regular expressions |
+//! | `http` | [`HttpModerationProvider`] | External classifier (toxicity and similar) |
+//!
+//! Findings report how many matches a detector saw, never the matched text.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Context;
+use async_trait::async_trait;
+use regex::Regex;
+use serde::Deserialize;
+
+use crate::domain::moderation::{ModerationAction, ModerationFinding, ModerationProvider};
+use crate::domain::node_config::{
+    resolve_env_value, ModerationConfig, ModerationProviderKind, PiiDetector,
+};
+
+/// Build the providers listed in `config`, in order.
+pub fn build_moderation_providers(
+    config: &ModerationConfig,
+) -> anyhow::Result<Vec<Arc<dyn ModerationProvider>>> {
+    config
+        .providers
+        .iter()
+        .map(|provider| {
+            let name = provider.name.clone();
+            let built: Arc<dyn ModerationProvider> = match &provider.kind {
+                ModerationProviderKind::Pii { detectors } => {
+                    Arc::new(PiiModerationProvider::new(name, provider.action, detectors))
+                }
+                ModerationProviderKind::Pattern { patterns, category } => {
+                    Arc::new(PatternModerationProvider::new(
+                        name,
+                        provider.action,
+                        category.clone(),
+                        patterns,
+                    )?)
+                }
+                ModerationProviderKind::Http {
+                    url,
+                    api_key,
+                    timeout_seconds,
+                    threshold,
+                } => Arc::new(HttpModerationProvider::new(
+                    name,
+                    provider.action,
+                    url.clone(),
+                    api_key.as_deref().map(resolve_env_value).transpose()?,
+                    Duration::from_secs(*timeout_seconds),
+                    *threshold,
+                )?),
+            };
+            Ok(built)
+        })
+        .collect()
+}
+
+fn count_finding(
+    provider: &str,
+    action: ModerationAction,
+    category: &str,
+    matches: usize,
+) -> ModerationFinding {
+    let noun = if matches == 1 { "match" } else { "matches" };
+    ModerationFinding {
+        provider: provider.to_string(),
+        category: category.to_string(),
+        action,
+        score: None,
+        detail: Some(format!("{matches} {noun}")),
+    }
+}
+
+pub struct PiiModerationProvider {
+    name: String,
+    action: ModerationAction,
+    detectors: Vec<(PiiDetector, Regex)>,
+}
+
+impl PiiModerationProvider {
+    /// Runs every detector when `detectors` is empty.
+    pub fn new(name: String, action: ModerationAction, detectors: &[PiiDetector]) -> Self {
+        let selected = if detectors.is_empty() {
+            vec![
+                PiiDetector::Email,
+                PiiDetector::Phone,
+                PiiDetector::CreditCard,
+                PiiDetector::Ssn,
+            ]
+        } else {
+            detectors.to_vec()
+        };
+        let detectors = selected
+            .into_iter()
+            .map(|detector| {
+                let pattern = match detector {
+                    PiiDetector::Email => r"(?i)\b[a-z0-9._%+-]+@[a-z0-9.-]+\.[a-z]{2,}\b",
+                    PiiDetector::Phone => {
+                        r"(?:\+\d{1,3}[\s.-]?)?\(?\b\d{3}\)?[\s.-]\d{3}[\s.-]\d{4}\b"
+                    }
+                    PiiDetector::CreditCard => r"\b\d(?:[ -]?\d){12,18}\b",
+                    PiiDetector::Ssn => r"\b\d{3}-\d{2}-\d{4}\b",
+                };
+                (
+                    detector,
+                    Regex::new(pattern).expect("built-in PII pattern is valid"),
+                )
+            })
+            .collect();
+        Self {
+            name,
+            action,
+            detectors,
+        }
+    }
+}
+
+fn category(detector: PiiDetector) -> &'static str {
+    match detector {
+        PiiDetector::Email => "email",
+        PiiDetector::Phone => "phone",
+        PiiDetector::CreditCard => "credit_card",
+        PiiDetector::Ssn => "ssn",
+    }
+}
+
+fn luhn_valid(candidate: &str) -> bool {
+    let digits: Vec<u32> = candidate.chars().filter_map(|c| c.to_digit(10)).collect();
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| match (i % 2 == 1, d * 2) {
+            (true, doubled) if doubled > 9 => doubled - 9,
+            (true, doubled) => doubled,
+            (false, _) => d,
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+#[async_trait]
+impl ModerationProvider for PiiModerationProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn check(&self, output: &str) -> anyhow::Result<Vec<ModerationFinding>> {
+        Ok(self
+            .detectors
+            .iter()
+            .filter_map(|(detector, regex)| {
+                let matches = regex
+                    .find_iter(output)
+                    .filter(|m| *detector != PiiDetector::CreditCard || luhn_valid(m.as_str()))
+                    .count();
+                (matches > 0)
+                    .then(|| count_finding(&self.name, self.action, category(*detector), matches))
+            })
+            .collect())
+    }
+}
+
+pub struct PatternModerationProvider {
+    name: String,
+    action: ModerationAction,
+    category: String,
+    patterns: Vec<Regex>,
+}
+
+impl PatternModerationProvider {
+    pub fn new(
+        name: String,
+        action: ModerationAction,
+        category: String,
+        patterns: &[String],
+    ) -> anyhow::Result<Self> {
+        let patterns = patterns
+            .iter()
+            .map(|p| {
+                Regex::new(p).with_context(|| format!("moderation provider '{name}': bad pattern"))
+            })
+            .collect::<anyhow::Result<_>>()?;
+        Ok(Self {
+            name,
+            action,
+            category,
+            patterns,
+        })
+    }
+}
+
+#[async_trait]
+impl ModerationProvider for PatternModerationProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn check(&self, output: &str) -> anyhow::Result<Vec<ModerationFinding>> {
+        let matches: usize = self
+            .patterns
+            .iter()
+            .map(|p| p.find_iter(output).count())
+            .sum();
+        Ok(if matches > 0 {
+            vec![count_finding(
+                &self.name,
+                self.action,
+                &self.category,
+                matches,
+            )]
+        } else {
+            Vec::new()
+        })
+    }
+}
+
+pub struct HttpModerationProvider {
+    name: String,
+    action: ModerationAction,
+    url: String,
+    api_key: Option<String>,
+    threshold: f64,
+    client: reqwest::Client,
+}
+
+#[derive(Deserialize)]
+struct HttpModerationResponse {
+    #[serde(default)]
+    findings: Vec<HttpModerationFinding>,
+}
+
+#[derive(Deserialize)]
+struct HttpModerationFinding {
+    category: String,
+    #[serde(default)]
+    score: Option<f64>,
+    #[serde(default)]
+    detail: Option<String>,
+}
+
+impl HttpModerationProvider {
+    pub fn new(
+        name: String,
+        action: ModerationAction,
+        url: String,
+        api_key: Option<String>,
+        timeout: Duration,
+        threshold: f64,
+    ) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder().timeout(timeout).build()?;
+        Ok(Self {
+            name,
+            action,
+            url,
+            api_key,
+            threshold,
+            client,
+        })
+    }
+}
+
+#[async_trait]
+impl ModerationProvider for HttpModerationProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn check(&self, output: &str) -> anyhow::Result<Vec<ModerationFinding>> {
+        let mut request = self
+            .client
+            .post(&self.url)
+            .json(&serde_json::json!({ "text": output }));
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+        let response: HttpModerationResponse = request
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await
+            .context("unexpected moderation response body")?;
+        Ok(response
+            .findings
+            .into_iter()
+            .filter(|f| f.score.is_none_or(|score| score >= self.threshold))
+            .map(|f| ModerationFinding {
+                provider: self.name.clone(),
+                category: f.category,
+                action: self.action,
+                score: f.score,
+                detail: f.detail,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn pii_provider_counts_matches_without_echoing_them() {
+        let provider = PiiModerationProvider::new("pii".to_string(), ModerationAction::Flag, &[]);
+        let findings = provider
+            .check(
+                "Mail jane@example.com or bob@example.org, card 4111 1111 1111 1111, \
+                 order 1234 5678 9012 3456, SSN 123-45-6789.",
+            )
+            .await
+            .unwrap();
+        let summary: Vec<_> = findings
+            .iter()
+            .map(|f| (f.category.as_str(), f.detail.as_deref().unwrap()))
+            .collect();
+        // The order number fails the Luhn check.
+        assert_eq!(
+            summary,
+            vec![
+                ("email", "2 matches"),
+                ("credit_card", "1 match"),
+                ("ssn", "1 match"),
+            ]
+        );
+        assert!(findings.iter().all(|f| f.action == ModerationAction::Flag));
+
+        let clean = provider.check("Nothing to see here.").await.unwrap();
+        assert!(clean.is_empty());
+    }
+}
@@ -0,0 +1,167 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Network Flow Capture
+//!
+//! Flow logging for executions spawned with
+//! [`RuntimeConfig::network_flow_capture`](crate::domain::runtime::RuntimeConfig::network_flow_capture).
+//! [`ContainerRuntime`](crate::infrastructure::runtime::ContainerRuntime)
+//! starts [`capture_sidecar`] in the agent's network namespace. It runs
+//! `tshark` with a display filter that keeps only connection openings (TCP
+//! SYN), TLS ClientHellos and DNS queries, and prints one tab-separated line of
+//! header fields per packet. No payload bytes leave tshark.
+//!
+//! [`parse_flows`] turns the sidecar's stdout back into [`NetworkFlow`]s.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** tshark invocation and output parsing for flow logs
+
+use std::net::{IpAddr, SocketAddr};
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::network_flow::{merge_flows, FlowProtocol, NetworkFlow};
+use crate::domain::runtime::{NetworkFlowCapture, ResourceLimits, SidecarConfig};
+
+/// Sidecar name of the capture container; also its console stream suffix.
+pub const CAPTURE_SIDECAR_NAME: &str = "aegis-network-flows";
+
+/// Linux capabilities the capture container needs to open a raw socket.
+pub const CAPTURE_CAPABILITIES: &[&str] = &["NET_RAW", "NET_ADMIN"];
+
+/// Fields printed per packet, in output column order.
+const FIELDS: &[&str] = &[
+    "frame.time_epoch",
+    "ip.src",
+    "ipv6.src",
+    "ip.dst",
+    "ipv6.dst",
+    "tcp.srcport",
+    "udp.srcport",
+    "tcp.dstport",
+    "udp.dstport",
+    "tls.handshake.extensions_server_name",
+    "dns.qry.name",
+];
+
+/// Connection openings, TLS ClientHellos and DNS queries only.
+const DISPLAY_FILTER: &str = "(tcp.flags.syn == 1 && tcp.flags.ack == 0) \
+     || tls.handshake.type == 1 \
+     || (dns && dns.flags.response == 0)";
+
+/// Loopback traffic inside the namespace is not a flow worth reporting.
+const CAPTURE_FILTER: &str = "not net 127.0.0.0/8 and not host ::1";
+
+/// Sidecar spawn config running the capture with `capture.image`.
+pub fn capture_sidecar(capture: &NetworkFlowCapture) -> SidecarConfig {
+    let mut command: Vec<String> = [
+        "tshark",
+        "-i",
+        "any",
+        "-l",
+        "-n",
+        "-f",
+        CAPTURE_FILTER,
+        "-Y",
+        DISPLAY_FILTER,
+        "-T",
+        "fields",
+        "-E",
+        "separator=/t",
+        "-E",
+        "occurrence=f",
+    ]
+    .iter()
+    .map(|arg| arg.to_string())
+    .collect();
+    for field in FIELDS {
+        command.push("-e".to_string());
+        command.push(field.to_string());
+    }
+    SidecarConfig {
+        name: CAPTURE_SIDECAR_NAME.to_string(),
+        image: capture.image.clone(),
+        image_pull_policy: capture.image_pull_policy,
+        command,
+        env: Default::default(),
+        resources: ResourceLimits {
+            cpu_millis: Some(250),
+            memory_bytes: Some(128 * 1024 * 1024),
+            disk_bytes: None,
+            timeout_seconds: None,
+        },
+    }
+}
+
+/// Parse captured stdout into merged flows. Lines that are not tshark field
+/// output (banners, truncated writes) are skipped.
+pub fn parse_flows(output: &str) -> Vec<NetworkFlow> {
+    merge_flows(output.lines().filter_map(parse_flow_line).collect())
+}
+
+fn parse_flow_line(line: &str) -> Option<NetworkFlow> {
+    let columns: Vec<&str> = line.trim_end_matches('\r').split('\t').collect();
+    if columns.len() != FIELDS.len() {
+        return None;
+    }
+    let field = |i: usize| Some(columns[i]).filter(|value| !value.is_empty());
+
+    let first_seen = parse_epoch(columns[0])?;
+    let source_ip: IpAddr = field(1).or(field(2))?.parse().ok()?;
+    let destination_ip: IpAddr = field(3).or(field(4))?.parse().ok()?;
+    let (protocol, ports) = match (field(5), field(7)) {
+        (Some(source), Some(destination)) => (FlowProtocol::Tcp, (source, destination)),
+        _ => (FlowProtocol::Udp, (field(6)?, field(8)?)),
+    };
+    Some(NetworkFlow {
+        protocol,
+        source: SocketAddr::new(source_ip, ports.0.parse().ok()?),
+        destination: SocketAddr::new(destination_ip, ports.1.parse().ok()?),
+        server_name: field(9).map(str::to_string),
+        dns_query: field(10).map(str::to_string),
+        first_seen,
+        iteration_number: None,
+    })
+}
+
+fn parse_epoch(value: &str) -> Option<DateTime<Utc>> {
+    let (seconds, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let nanos = format!("{fraction:0<9}");
+    DateTime::from_timestamp(seconds.parse().ok()?, nanos.get(..9)?.parse().ok()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_syn_client_hello_and_dns_lines_into_flows() {
+        let output = "Capturing on 'any'\n\
+            1710687845.100000000\t172.17.0.2\t\t10.0.0.53\t\t\t40001\t\t53\t\tapi.example.com\n\
+            1710687845.200000000\t172.17.0.2\t\t93.184.216.34\t\t40002\t\t443\t\t\t\n\
+            1710687845.250000000\t172.17.0.2\t\t93.184.216.34\t\t40002\t\t443\t\tapi.example.com\t\n\
+            1710687846.5\t\tfd00::2\t\tfd00::1\t40003\t\t8080\t\t\t\n";
+        let flows = parse_flows(output);
+
+        assert_eq!(flows.len(), 3);
+        assert_eq!(flows[0].protocol, FlowProtocol::Udp);
+        assert_eq!(flows[0].dns_query.as_deref(), Some("api.example.com"));
+        assert_eq!(flows[1].destination, "93.184.216.34:443".parse().unwrap());
+        assert_eq!(flows[1].server_name.as_deref(), Some("api.example.com"));
+        assert_eq!(flows[1].first_seen.timestamp_subsec_millis(), 200);
+        assert_eq!(flows[2].destination, "[fd00::1]:8080".parse().unwrap());
+        assert_eq!(flows[2].first_seen.timestamp_subsec_millis(), 500);
+    }
+
+    #[test]
+    fn capture_command_selects_every_parsed_field() {
+        let sidecar = capture_sidecar(&NetworkFlowCapture {
+            image: "nicolaka/netshoot:latest".to_string(),
+            image_pull_policy: Default::default(),
+        });
+        assert_eq!(sidecar.command[0], "tshark");
+        let selected = sidecar.command.iter().filter(|arg| *arg == "-e").count();
+        assert_eq!(selected, FIELDS.len());
+    }
+}
@@ -0,0 +1,110 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! NFS FileHandle Table (ADR-036)
+//!
+//! Maps NFSv3 `fileid3` values to [`AegisFileHandle`]s and their volume-relative
+//! paths. Every LOOKUP, GETATTR, READ and WRITE resolves through this table, so
+//! it is exposed publicly for the `nfs_handle_table` benchmark.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Implements internal responsibilities for handle_table
+
+use crate::domain::fsal::AegisFileHandle;
+use nfsserve::nfs::fileid3;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tracing::debug;
+
+/// FileHandle mapping table for NFS protocol
+///
+/// Maintains bidirectional mapping between NFSv3's fileid3 (u64) and AegisFileHandle.
+/// Thread-safe with RwLock for concurrent NFS operations.
+pub struct FileHandleTable {
+    /// Counter for generating unique fileid3 values
+    next_fileid: AtomicU64,
+    /// Forward mapping: fileid3 -> (AegisFileHandle, path)
+    forward: RwLock<HashMap<fileid3, (AegisFileHandle, String)>>,
+    /// Reverse mapping: path_hash -> fileid3 (for consistent lookup)
+    reverse: RwLock<HashMap<u64, fileid3>>,
+}
+
+impl FileHandleTable {
+    /// Create new handle table
+    pub fn new() -> Self {
+        Self {
+            next_fileid: AtomicU64::new(2), // Start at 2 (1 is reserved for root)
+            forward: RwLock::new(HashMap::new()),
+            reverse: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Register a new handle and return its fileid3
+    pub fn register(&self, handle: AegisFileHandle, path: String) -> fileid3 {
+        // Check if handle already registered
+        let reverse = self.reverse.read();
+        if let Some(&existing_id) = reverse.get(&handle.path_hash) {
+            return existing_id;
+        }
+        drop(reverse);
+
+        // Generate new fileid
+        let fileid = self.next_fileid.fetch_add(1, Ordering::SeqCst);
+
+        // Store bidirectional mapping
+        self.forward
+            .write()
+            .insert(fileid, (handle.clone(), path.clone()));
+        self.reverse.write().insert(handle.path_hash, fileid);
+
+        debug!("Registered file handle: fileid={}, path={}", fileid, path);
+        fileid
+    }
+
+    /// Lookup handle by fileid3
+    pub fn lookup(&self, id: fileid3) -> Option<(AegisFileHandle, String)> {
+        self.forward.read().get(&id).cloned()
+    }
+
+    /// Get fileid from path hash (if previously registered)
+    pub fn get_fileid_by_hash(&self, path_hash: u64) -> Option<fileid3> {
+        self.reverse.read().get(&path_hash).copied()
+    }
+}
+
+impl Default for FileHandleTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{execution::ExecutionId, volume::VolumeId};
+
+    #[test]
+    fn register_is_idempotent_per_path_and_starts_after_root() {
+        let table = FileHandleTable::new();
+        let execution_id = ExecutionId::new();
+        let volume_id = VolumeId::new();
+        let handle = AegisFileHandle::new(execution_id, volume_id, "/a.txt");
+        let path_hash = handle.path_hash;
+
+        let first = table.register(handle.clone(), "/a.txt".to_string());
+        let again = table.register(handle, "/a.txt".to_string());
+        let other = table.register(
+            AegisFileHandle::new(execution_id, volume_id, "/b.txt"),
+            "/b.txt".to_string(),
+        );
+
+        assert_eq!(first, 2);
+        assert_eq!(again, first);
+        assert_eq!(other, 3);
+        assert_eq!(table.get_fileid_by_hash(path_hash), Some(first));
+        assert_eq!(table.lookup(first).unwrap().1, "/a.txt");
+        assert!(table.lookup(99).is_none());
+    }
+}
@@ -45,7 +45,9 @@
 //! - **Purpose:** Implements internal responsibilities for mod
 
 pub mod file_handle;
+pub mod handle_table;
 pub mod server;
 
 pub use file_handle::{decode_file_handle, encode_file_handle};
+pub use handle_table::FileHandleTable;
 pub use server::NfsServer;
@@ -11,7 +11,7 @@
 //! - **AegisFsalAdapter**: Implements `nfsserve::NFSFileSystem` trait
 //!   - Maps NFSv3 RPC operations (LOOKUP, GETATTR, READ, WRITE, READDIR) to AegisFSAL domain methods
 //!   - Handles fileid3 ↔ AegisFileHandle encoding/decoding (max 64 bytes for NFSv3)
-//!   - Translates NFS errors (nfsstat3) to FSAL errors
+//!   - Translates FSAL errors to NFS status codes (`fsal_error_to_nfsstat`)
 //! - **NfsServer**: Manages server lifecycle and TCP listener
 //!   - Spawns tokio task running `nfsserve::tcp::NFSTcp`
 //!   - Provides graceful shutdown with task abort + timeout
@@ -49,15 +49,21 @@
 //! - **Purpose:** Implements internal responsibilities for server
 
 use crate::domain::execution::ExecutionId;
-use crate::domain::fsal::{AegisFSAL, AegisFileHandle, FsalAccessPolicy, RenameFsalRequest};
+use crate::domain::fsal::{
+    AegisFSAL, AegisFileHandle, FsalAccessPolicy, FsalError, RenameFsalRequest,
+};
+use crate::domain::path_sanitizer::PathSanitizerError;
+use crate::domain::storage::StorageError;
 use crate::domain::volume::VolumeId;
-use nfsserve::nfs::{fattr3, fileid3, filename3, ftype3, nfspath3, nfsstring, nfstime3, specdata3};
+use crate::infrastructure::nfs::handle_table::FileHandleTable;
+use nfsserve::nfs::{
+    fattr3, fileid3, filename3, ftype3, nfspath3, nfsstat3, nfsstring, nfstime3, specdata3,
+};
 use nfsserve::tcp::{NFSTcp, NFSTcpListener};
 use nfsserve::vfs::{self, NFSFileSystem};
 use parking_lot::{Mutex, RwLock};
 use std::collections::HashMap;
 use std::path::PathBuf;
-use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use thiserror::Error;
 use tokio::task::AbortHandle;
@@ -105,62 +111,6 @@ pub enum NfsServerError {
     InvalidHandle(fileid3),
 }
 
-/// FileHandle mapping table for NFS protocol
-///
-/// Maintains bidirectional mapping between NFSv3's fileid3 (u64) and AegisFileHandle.
-/// Thread-safe with RwLock for concurrent NFS operations.
-struct FileHandleTable {
-    /// Counter for generating unique fileid3 values
-    next_fileid: AtomicU64,
-    /// Forward mapping: fileid3 -> (AegisFileHandle, path)
-    forward: RwLock<HashMap<fileid3, (AegisFileHandle, String)>>,
-    /// Reverse mapping: path_hash -> fileid3 (for consistent lookup)
-    reverse: RwLock<HashMap<u64, fileid3>>,
-}
-
-impl FileHandleTable {
-    /// Create new handle table
-    fn new() -> Self {
-        Self {
-            next_fileid: AtomicU64::new(2), // Start at 2 (1 is reserved for root)
-            forward: RwLock::new(HashMap::new()),
-            reverse: RwLock::new(HashMap::new()),
-        }
-    }
-
-    /// Register a new handle and return its fileid3
-    fn register(&self, handle: AegisFileHandle, path: String) -> fileid3 {
-        // Check if handle already registered
-        let reverse = self.reverse.read();
-        if let Some(&existing_id) = reverse.get(&handle.path_hash) {
-            return existing_id;
-        }
-        drop(reverse);
-
-        // Generate new fileid
-        let fileid = self.next_fileid.fetch_add(1, Ordering::SeqCst);
-
-        // Store bidirectional mapping
-        self.forward
-            .write()
-            .insert(fileid, (handle.clone(), path.clone()));
-        self.reverse.write().insert(handle.path_hash, fileid);
-
-        debug!("Registered file handle: fileid={}, path={}", fileid, path);
-        fileid
-    }
-
-    /// Lookup handle by fileid3
-    fn lookup(&self, id: fileid3) -> Option<(AegisFileHandle, String)> {
-        self.forward.read().get(&id).cloned()
-    }
-
-    /// Get fileid from path hash (if previously registered)
-    fn get_fileid_by_hash(&self, path_hash: u64) -> Option<fileid3> {
-        self.reverse.read().get(&path_hash).copied()
-    }
-}
-
 /// NFS File System Adapter for AegisFSAL
 ///
 /// Maps NFSv3 protocol operations to AegisFSAL domain methods.
@@ -446,7 +396,7 @@ impl NFSFileSystem for AegisFsalAdapter {
                 .await
                 .map_err(|e| {
                     warn!("FSAL lookup failed: {}", e);
-                    nfsserve::nfs::nfsstat3::NFS3ERR_NOENT
+                    fsal_error_to_nfsstat(&e)
                 })?;
 
             // Build child path relative to volume root
@@ -615,7 +565,7 @@ impl NFSFileSystem for AegisFsalAdapter {
                 .await
                 .map_err(|e| {
                     warn!("FSAL getattr failed: {}", e);
-                    nfsserve::nfs::nfsstat3::NFS3ERR_NOENT
+                    fsal_error_to_nfsstat(&e)
                 })?;
 
             let mut nfs_attrs = self.convert_attrs(attrs, handle.volume_id);
@@ -653,10 +603,13 @@ impl NFSFileSystem for AegisFsalAdapter {
 
         match self
             .fsal
-            .read(&handle, &path, &context.policy, offset, count as usize)
+            .read_bytes(&handle, &path, &context.policy, offset, count as usize)
             .await
         {
             Ok(data) => {
+                // nfsserve owns the reply buffer as a `Vec`. Converting a uniquely
+                // owned `Bytes` reuses its allocation; only shared buffers copy.
+                let data = Vec::from(data);
                 metrics::counter!("aegis_nfs_operations_total", "operation" => "read", "result" => "success").increment(1);
                 metrics::histogram!("aegis_nfs_operation_duration_seconds", "operation" => "read")
                     .record(start.elapsed().as_secs_f64());
@@ -668,7 +621,7 @@ impl NFSFileSystem for AegisFsalAdapter {
                 metrics::counter!("aegis_nfs_operations_total", "operation" => "read", "result" => "error").increment(1);
                 AegisFsalAdapter::record_fsal_security_metrics(&e);
                 error!("FSAL read failed: {}", e);
-                Err(nfsserve::nfs::nfsstat3::NFS3ERR_IO)
+                Err(fsal_error_to_nfsstat(&e))
             }
         }
     }
@@ -697,25 +650,7 @@ impl NFSFileSystem for AegisFsalAdapter {
                 metrics::counter!("aegis_nfs_operations_total", "operation" => "write", "result" => "error").increment(1);
                 AegisFsalAdapter::record_fsal_security_metrics(&e);
                 error!("FSAL write failed: {}", e);
-                // Map FSAL errors to appropriate NFS status codes
-                match e {
-                    crate::domain::fsal::FsalError::QuotaExceeded { .. } => {
-                        // Quota exceeded - no space left on device (ADR-036)
-                        nfsserve::nfs::nfsstat3::NFS3ERR_NOSPC
-                    }
-                    crate::domain::fsal::FsalError::PolicyViolation(_) => {
-                        // Policy violation - permission denied
-                        nfsserve::nfs::nfsstat3::NFS3ERR_ACCES
-                    }
-                    crate::domain::fsal::FsalError::UnauthorizedAccess { .. } => {
-                        // Unauthorized access - permission denied
-                        nfsserve::nfs::nfsstat3::NFS3ERR_ACCES
-                    }
-                    _ => {
-                        // Generic I/O error for other failures
-                        nfsserve::nfs::nfsstat3::NFS3ERR_IO
-                    }
-                }
+                fsal_error_to_nfsstat(&e)
             })?;
 
         metrics::counter!("aegis_nfs_operations_total", "operation" => "write", "result" => "success").increment(1);
@@ -763,7 +698,7 @@ impl NFSFileSystem for AegisFsalAdapter {
                 metrics::counter!("aegis_nfs_operations_total", "operation" => "readdir", "result" => "error").increment(1);
                 AegisFsalAdapter::record_fsal_security_metrics(&e);
                 error!("FSAL readdir failed: {}", e);
-                nfsserve::nfs::nfsstat3::NFS3ERR_IO
+                fsal_error_to_nfsstat(&e)
             })?;
 
         metrics::counter!("aegis_nfs_operations_total", "operation" => "readdir", "result" => "success").increment(1);
@@ -790,10 +725,7 @@ impl NFSFileSystem for AegisFsalAdapter {
             let name: nfsstring = nfsstring::from(entry.name.as_bytes());
 
             // Get attributes for this entry (required, not optional)
-            let attr = self
-                .getattr(fileid)
-                .await
-                .map_err(|_| nfsserve::nfs::nfsstat3::NFS3ERR_IO)?;
+            let attr = self.getattr(fileid).await?;
 
             nfs_entries.push(vfs::DirEntry { fileid, name, attr });
         }
@@ -849,7 +781,7 @@ impl NFSFileSystem for AegisFsalAdapter {
                 metrics::counter!("aegis_nfs_operations_total", "operation" => "create", "result" => "error").increment(1);
                 AegisFsalAdapter::record_fsal_security_metrics(&e);
                 error!("FSAL create failed: {}", e);
-                nfsserve::nfs::nfsstat3::NFS3ERR_IO
+                fsal_error_to_nfsstat(&e)
             })?;
 
         metrics::counter!("aegis_nfs_operations_total", "operation" => "create", "result" => "success").increment(1);
@@ -922,7 +854,7 @@ impl NFSFileSystem for AegisFsalAdapter {
                 metrics::counter!("aegis_nfs_operations_total", "operation" => "create", "result" => "error").increment(1);
                 AegisFsalAdapter::record_fsal_security_metrics(&e);
                 error!("FSAL mkdir failed: {}", e);
-                nfsserve::nfs::nfsstat3::NFS3ERR_IO
+                fsal_error_to_nfsstat(&e)
             })?;
 
         metrics::counter!("aegis_nfs_operations_total", "operation" => "create", "result" => "success").increment(1);
@@ -1002,7 +934,7 @@ impl NFSFileSystem for AegisFsalAdapter {
                         AegisFsalAdapter::record_fsal_security_metrics(&file_err);
                         AegisFsalAdapter::record_fsal_security_metrics(&e);
                         error!("FSAL remove failed: {}", e);
-                        nfsserve::nfs::nfsstat3::NFS3ERR_IO
+                        fsal_error_to_nfsstat(&e)
                     })
             }
         };
@@ -1074,7 +1006,7 @@ impl NFSFileSystem for AegisFsalAdapter {
             .await
             .map_err(|e| {
                 error!("FSAL rename failed: {}", e);
-                nfsserve::nfs::nfsstat3::NFS3ERR_IO
+                fsal_error_to_nfsstat(&e)
             })
     }
 
@@ -1116,6 +1048,52 @@ impl NFSFileSystem for AegisFsalAdapter {
     }
 }
 
+/// NFSv3 status for an FSAL error.
+///
+/// Every [`FsalError`] and [`StorageError`] variant is matched explicitly, so
+/// a new variant cannot fall through to `NFS3ERR_IO` unnoticed. The cause is
+/// recorded separately as a storage event; clients only see the status.
+///
+/// An unreachable or slow backend maps to `NFS3ERR_IO` rather than
+/// `NFS3ERR_JUKEBOX`: Linux clients retry JUKEBOX indefinitely, even on the
+/// `soft` mounts agent containers use, which would hang the agent instead of
+/// failing its tool call.
+fn fsal_error_to_nfsstat(e: &FsalError) -> nfsstat3 {
+    match e {
+        FsalError::UnauthorizedAccess { .. } | FsalError::PolicyViolation(_) => {
+            nfsstat3::NFS3ERR_ACCES
+        }
+        FsalError::PathSanitization(e) => match e {
+            PathSanitizerError::PathTraversal(_) | PathSanitizerError::OutsideBoundary(_) => {
+                nfsstat3::NFS3ERR_ACCES
+            }
+            PathSanitizerError::InvalidPath(_) => nfsstat3::NFS3ERR_INVAL,
+            PathSanitizerError::PathTooLong(_) => nfsstat3::NFS3ERR_NAMETOOLONG,
+        },
+        FsalError::VolumeNotFound(_) | FsalError::VolumeNotAttached(_) => nfsstat3::NFS3ERR_STALE,
+        FsalError::InvalidFileHandle | FsalError::HandleDeserialization(_) => {
+            nfsstat3::NFS3ERR_BADHANDLE
+        }
+        // No space left on device (ADR-036).
+        FsalError::QuotaExceeded { .. } => nfsstat3::NFS3ERR_NOSPC,
+        FsalError::FileTooLarge { .. } => nfsstat3::NFS3ERR_FBIG,
+        FsalError::Storage(e) => match e {
+            StorageError::NotFound(_) | StorageError::FileNotFound(_) => nfsstat3::NFS3ERR_NOENT,
+            StorageError::AlreadyExists(_) => nfsstat3::NFS3ERR_EXIST,
+            StorageError::QuotaExceeded { .. } => nfsstat3::NFS3ERR_NOSPC,
+            StorageError::PermissionDenied(_) => nfsstat3::NFS3ERR_ACCES,
+            StorageError::InvalidPath(_) => nfsstat3::NFS3ERR_INVAL,
+            StorageError::Network(_)
+            | StorageError::Timeout
+            | StorageError::Unavailable(_)
+            | StorageError::Serialization(_)
+            | StorageError::Corrupted(_)
+            | StorageError::IoError(_)
+            | StorageError::Unknown(_) => nfsstat3::NFS3ERR_IO,
+        },
+    }
+}
+
 /// NFS Server
 ///
 /// Manages NFSv3 TCP server lifecycle.
@@ -1215,11 +1193,111 @@ impl NfsServer {
 
 #[cfg(test)]
 mod tests {
-    // Future tests will use specific imports as needed
+    use super::*;
 
     #[test]
     fn test_nfs_server_creation() {
         let module_marker = "nfs_server_creation";
         assert_eq!(module_marker, "nfs_server_creation");
     }
+
+    #[test]
+    fn every_fsal_error_maps_to_its_nfs_status() {
+        let s = String::new;
+        let volume_id = VolumeId::new();
+        let cases: Vec<(FsalError, nfsstat3)> = vec![
+            (
+                FsalError::UnauthorizedAccess {
+                    execution_id: ExecutionId::new(),
+                    volume_id,
+                },
+                nfsstat3::NFS3ERR_ACCES,
+            ),
+            (FsalError::PolicyViolation(s()), nfsstat3::NFS3ERR_ACCES),
+            (
+                PathSanitizerError::PathTraversal(s()).into(),
+                nfsstat3::NFS3ERR_ACCES,
+            ),
+            (
+                PathSanitizerError::OutsideBoundary(s()).into(),
+                nfsstat3::NFS3ERR_ACCES,
+            ),
+            (
+                PathSanitizerError::InvalidPath(s()).into(),
+                nfsstat3::NFS3ERR_INVAL,
+            ),
+            (
+                PathSanitizerError::PathTooLong(s()).into(),
+                nfsstat3::NFS3ERR_NAMETOOLONG,
+            ),
+            (
+                FsalError::VolumeNotFound(volume_id),
+                nfsstat3::NFS3ERR_STALE,
+            ),
+            (
+                FsalError::VolumeNotAttached(volume_id),
+                nfsstat3::NFS3ERR_STALE,
+            ),
+            (FsalError::InvalidFileHandle, nfsstat3::NFS3ERR_BADHANDLE),
+            (
+                FsalError::HandleDeserialization(s()),
+                nfsstat3::NFS3ERR_BADHANDLE,
+            ),
+            (
+                FsalError::QuotaExceeded {
+                    requested_bytes: 2,
+                    available_bytes: 1,
+                },
+                nfsstat3::NFS3ERR_NOSPC,
+            ),
+            (
+                FsalError::FileTooLarge { size: 2, limit: 1 },
+                nfsstat3::NFS3ERR_FBIG,
+            ),
+            (StorageError::NotFound(s()).into(), nfsstat3::NFS3ERR_NOENT),
+            (
+                StorageError::FileNotFound(s()).into(),
+                nfsstat3::NFS3ERR_NOENT,
+            ),
+            (
+                StorageError::AlreadyExists(s()).into(),
+                nfsstat3::NFS3ERR_EXIST,
+            ),
+            (
+                StorageError::QuotaExceeded {
+                    path: s(),
+                    limit_bytes: 1,
+                    actual_bytes: 2,
+                }
+                .into(),
+                nfsstat3::NFS3ERR_NOSPC,
+            ),
+            (
+                StorageError::PermissionDenied(s()).into(),
+                nfsstat3::NFS3ERR_ACCES,
+            ),
+            (
+                StorageError::InvalidPath(s()).into(),
+                nfsstat3::NFS3ERR_INVAL,
+            ),
+            (StorageError::Network(s()).into(), nfsstat3::NFS3ERR_IO),
+            (StorageError::Timeout.into(), nfsstat3::NFS3ERR_IO),
+            (StorageError::Unavailable(s()).into(), nfsstat3::NFS3ERR_IO),
+            (
+                StorageError::Serialization(s()).into(),
+                nfsstat3::NFS3ERR_IO,
+            ),
+            (StorageError::Corrupted(s()).into(), nfsstat3::NFS3ERR_IO),
+            (StorageError::IoError(s()).into(), nfsstat3::NFS3ERR_IO),
+            (StorageError::Unknown(s()).into(), nfsstat3::NFS3ERR_IO),
+        ];
+
+        for (error, expected) in cases {
+            assert_eq!(
+                fsal_error_to_nfsstat(&error) as u32,
+                expected as u32,
+                "{error:?}"
+            );
+        }
+    }
 }
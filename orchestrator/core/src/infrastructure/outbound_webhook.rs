@@ -0,0 +1,519 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Outbound Webhook Dispatcher
+//!
+//! [`OutboundWebhookDispatcher`] is the single HTTP path for calls to
+//! user-provided URLs. Subsystems build an [`OutboundWebhook`] and call
+//! [`OutboundWebhookDispatcher::deliver`]; the dispatcher signs the body
+//! (see [`crate::domain::outbound_webhook`]), retries network errors and
+//! retryable statuses per the request's [`RetryPolicy`], logs every attempt
+//! to the [`WebhookDeliveryRepository`] and dead-letters deliveries that do
+//! not succeed.
+//!
+//! Phase 1: [`EnvOutboundWebhookSecretProvider`] reads signing secrets from
+//! `AEGIS_OUTBOUND_WEBHOOK_SECRET_<TENANT>_<HOST>` and, during rotation,
+//! `AEGIS_OUTBOUND_WEBHOOK_SECRET_<TENANT>_<HOST>_PREVIOUS`.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Signed, retried, logged HTTP delivery to user-provided URLs
+
+use crate::domain::outbound_webhook::{
+    is_retryable_status, sign_payload, DeadLetter, DeliveryAttempt, DeliveryOutcome,
+    OutboundWebhookSecretProvider, RetryPolicy, WebhookDeliveryRepository, WEBHOOK_ID_HEADER,
+    WEBHOOK_SIGNATURE_HEADER, WEBHOOK_TIMESTAMP_HEADER,
+};
+use crate::domain::shared_kernel::TenantId;
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+/// Per-attempt timeout when the request sets none.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Longest response body kept in an attempt's error message.
+const MAX_ERROR_BODY_CHARS: usize = 512;
+
+/// One delivery to a user-provided URL.
+#[derive(Debug, Clone)]
+pub struct OutboundWebhook {
+    pub tenant_id: TenantId,
+    /// Subsystem sending the delivery, recorded in the attempt log.
+    pub source: String,
+    pub url: String,
+    /// `POST`, `PUT` or `PATCH`.
+    pub method: String,
+    pub headers: HashMap<String, String>,
+    pub content_type: String,
+    pub body: String,
+    pub timeout: Option<Duration>,
+    pub retry: RetryPolicy,
+}
+
+/// A successful delivery.
+#[derive(Debug, Clone)]
+pub struct DeliveryReceipt {
+    pub delivery_id: Uuid,
+    pub attempts: u32,
+    pub status_code: u16,
+    pub response_body: String,
+}
+
+#[derive(Debug, Error)]
+pub enum OutboundWebhookError {
+    #[error("invalid webhook request: {0}")]
+    InvalidRequest(String),
+
+    /// Every attempt failed; the delivery was dead-lettered.
+    #[error("webhook delivery {delivery_id} failed after {attempts} attempt(s): {last_error}")]
+    DeadLettered {
+        delivery_id: Uuid,
+        attempts: u32,
+        last_error: String,
+    },
+}
+
+/// Signs, sends, retries and logs outbound webhook deliveries.
+pub struct OutboundWebhookDispatcher {
+    client: reqwest::Client,
+    secrets: Arc<dyn OutboundWebhookSecretProvider>,
+    log: Option<Arc<dyn WebhookDeliveryRepository>>,
+}
+
+impl OutboundWebhookDispatcher {
+    pub fn new(
+        secrets: Arc<dyn OutboundWebhookSecretProvider>,
+        log: Option<Arc<dyn WebhookDeliveryRepository>>,
+    ) -> Self {
+        Self {
+            client: reqwest::Client::new(),
+            secrets,
+            log,
+        }
+    }
+
+    /// Dispatcher with environment-variable secrets and no attempt log.
+    pub fn unlogged() -> Self {
+        Self::new(Arc::new(EnvOutboundWebhookSecretProvider), None)
+    }
+
+    /// Deliver `webhook`, retrying per its policy.
+    pub async fn deliver(
+        &self,
+        webhook: &OutboundWebhook,
+    ) -> Result<DeliveryReceipt, OutboundWebhookError> {
+        let method = match webhook.method.to_uppercase().as_str() {
+            "POST" => reqwest::Method::POST,
+            "PUT" => reqwest::Method::PUT,
+            "PATCH" => reqwest::Method::PATCH,
+            other => {
+                return Err(OutboundWebhookError::InvalidRequest(format!(
+                    "unsupported HTTP method '{other}': expected POST, PUT, or PATCH"
+                )));
+            }
+        };
+        let url = reqwest::Url::parse(&webhook.url)
+            .map_err(|e| OutboundWebhookError::InvalidRequest(format!("invalid URL: {e}")))?;
+        let host = url.host_str().unwrap_or_default().to_string();
+        let secrets = self
+            .secrets
+            .signing_secrets(&webhook.tenant_id, &host)
+            .await;
+
+        let delivery_id = Uuid::new_v4();
+        let max_attempts = webhook.retry.max_attempts.max(1);
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let started = Instant::now();
+            let result = self
+                .send_once(webhook, method.clone(), url.clone(), delivery_id, &secrets)
+                .await;
+            let duration_ms = started.elapsed().as_millis() as u64;
+
+            let (status_code, error, retryable, retry_after) = match result {
+                Ok(response) if (200..300).contains(&response.status) => {
+                    self.record(
+                        webhook,
+                        delivery_id,
+                        attempt,
+                        Some(response.status),
+                        None,
+                        duration_ms,
+                    )
+                    .await;
+                    return Ok(DeliveryReceipt {
+                        delivery_id,
+                        attempts: attempt,
+                        status_code: response.status,
+                        response_body: response.body,
+                    });
+                }
+                Ok(response) => (
+                    Some(response.status),
+                    format!("HTTP {}: {}", response.status, truncate(&response.body)),
+                    is_retryable_status(response.status),
+                    response.retry_after,
+                ),
+                Err(e) => (None, format!("request failed: {e}"), true, None),
+            };
+
+            let final_attempt = !retryable || attempt >= max_attempts;
+            self.record(
+                webhook,
+                delivery_id,
+                attempt,
+                status_code,
+                Some((error.clone(), final_attempt)),
+                duration_ms,
+            )
+            .await;
+
+            if final_attempt {
+                self.dead_letter(webhook, delivery_id, attempt, &error)
+                    .await;
+                return Err(OutboundWebhookError::DeadLettered {
+                    delivery_id,
+                    attempts: attempt,
+                    last_error: error,
+                });
+            }
+
+            let backoff = webhook.retry.backoff_for(attempt);
+            let delay = retry_after.map_or(backoff, |hint| {
+                backoff.max(hint).min(webhook.retry.max_backoff)
+            });
+            debug!(
+                %delivery_id,
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                "Retrying outbound webhook delivery"
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    async fn send_once(
+        &self,
+        webhook: &OutboundWebhook,
+        method: reqwest::Method,
+        url: reqwest::Url,
+        delivery_id: Uuid,
+        secrets: &[Vec<u8>],
+    ) -> Result<AttemptResponse, reqwest::Error> {
+        let timestamp = Utc::now().timestamp();
+        let mut request = self
+            .client
+            .request(method, url)
+            .timeout(webhook.timeout.unwrap_or(DEFAULT_TIMEOUT));
+        for (key, value) in &webhook.headers {
+            request = request.header(key.as_str(), value.as_str());
+        }
+        request = request
+            .header("Content-Type", webhook.content_type.as_str())
+            .header(WEBHOOK_ID_HEADER, delivery_id.to_string())
+            .header(WEBHOOK_TIMESTAMP_HEADER, timestamp.to_string());
+        if let Some(signature) = sign_payload(secrets, timestamp, webhook.body.as_bytes()) {
+            request = request.header(WEBHOOK_SIGNATURE_HEADER, signature);
+        }
+
+        let response = request.body(webhook.body.clone()).send().await?;
+        let status = response.status().as_u16();
+        // Only the delay-seconds form of `Retry-After` is honoured.
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let body = response.text().await.unwrap_or_default();
+        Ok(AttemptResponse {
+            status,
+            body,
+            retry_after,
+        })
+    }
+
+    async fn record(
+        &self,
+        webhook: &OutboundWebhook,
+        delivery_id: Uuid,
+        attempt: u32,
+        status_code: Option<u16>,
+        failure: Option<(String, bool)>,
+        duration_ms: u64,
+    ) {
+        let Some(log) = &self.log else { return };
+        let (error, outcome) = match failure {
+            None => (None, DeliveryOutcome::Delivered),
+            Some((error, true)) => (Some(error), DeliveryOutcome::DeadLettered),
+            Some((error, false)) => (Some(error), DeliveryOutcome::Retrying),
+        };
+        let record = DeliveryAttempt {
+            delivery_id,
+            tenant_id: webhook.tenant_id.clone(),
+            destination: webhook.url.clone(),
+            source: webhook.source.clone(),
+            attempt,
+            status_code,
+            error,
+            outcome,
+            duration_ms,
+            attempted_at: Utc::now(),
+        };
+        if let Err(e) = log.record_attempt(&record).await {
+            warn!(%delivery_id, error = %e, "Failed to record outbound webhook attempt");
+        }
+    }
+
+    async fn dead_letter(
+        &self,
+        webhook: &OutboundWebhook,
+        delivery_id: Uuid,
+        attempts: u32,
+        last_error: &str,
+    ) {
+        warn!(
+            %delivery_id,
+            destination = %webhook.url,
+            attempts,
+            error = last_error,
+            "Outbound webhook delivery dead-lettered"
+        );
+        let Some(log) = &self.log else { return };
+        let dead_letter = DeadLetter {
+            delivery_id,
+            tenant_id: webhook.tenant_id.clone(),
+            destination: webhook.url.clone(),
+            source: webhook.source.clone(),
+            method: webhook.method.to_uppercase(),
+            content_type: webhook.content_type.clone(),
+            body: webhook.body.clone(),
+            attempts,
+            last_error: last_error.to_string(),
+            dead_lettered_at: Utc::now(),
+        };
+        if let Err(e) = log.dead_letter(&dead_letter).await {
+            warn!(%delivery_id, error = %e, "Failed to store outbound webhook dead letter");
+        }
+    }
+}
+
+/// HTTP response to one attempt.
+struct AttemptResponse {
+    status: u16,
+    body: String,
+    retry_after: Option<Duration>,
+}
+
+fn truncate(body: &str) -> String {
+    match body.char_indices().nth(MAX_ERROR_BODY_CHARS) {
+        Some((idx, _)) => format!("{}…", &body[..idx]),
+        None => body.to_string(),
+    }
+}
+
+/// Phase 1: reads `AEGIS_OUTBOUND_WEBHOOK_SECRET_{UPPER_TENANT}_{UPPER_HOST}`
+/// and its `_PREVIOUS` companion.
+///
+/// `(tenant=u-abc123, host=hooks.example.com)` →
+/// `AEGIS_OUTBOUND_WEBHOOK_SECRET_U_ABC123_HOOKS_EXAMPLE_COM`.
+///
+/// To rotate, move the current value to `_PREVIOUS`, set the new secret and
+/// drop `_PREVIOUS` once the receiver only accepts the new one.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnvOutboundWebhookSecretProvider;
+
+fn env_token(s: &str) -> String {
+    s.to_uppercase().replace(['-', '.', ':'], "_")
+}
+
+#[async_trait]
+impl OutboundWebhookSecretProvider for EnvOutboundWebhookSecretProvider {
+    async fn signing_secrets(&self, tenant_id: &TenantId, destination_host: &str) -> Vec<Vec<u8>> {
+        let env_key = format!(
+            "AEGIS_OUTBOUND_WEBHOOK_SECRET_{}_{}",
+            env_token(tenant_id.as_str()),
+            env_token(destination_host)
+        );
+        [env_key.clone(), format!("{env_key}_PREVIOUS")]
+            .iter()
+            .filter_map(|key| std::env::var(key).ok())
+            .filter(|secret| !secret.is_empty())
+            .map(String::into_bytes)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::repositories::InMemoryWebhookDeliveryRepository;
+
+    struct FixedSecrets(Vec<Vec<u8>>);
+
+    #[async_trait]
+    impl OutboundWebhookSecretProvider for FixedSecrets {
+        async fn signing_secrets(&self, _: &TenantId, _: &str) -> Vec<Vec<u8>> {
+            self.0.clone()
+        }
+    }
+
+    fn webhook(url: String, max_attempts: u32) -> OutboundWebhook {
+        OutboundWebhook {
+            tenant_id: TenantId::from_realm_slug("u-tenant").unwrap(),
+            source: "test".to_string(),
+            url,
+            method: "POST".to_string(),
+            headers: HashMap::new(),
+            content_type: "application/json".to_string(),
+            body: "{\"ok\":true}".to_string(),
+            timeout: Some(Duration::from_secs(5)),
+            retry: RetryPolicy {
+                max_attempts,
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(5),
+                multiplier: 2.0,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn retries_server_errors_then_dead_letters() {
+        let mut server = mockito::Server::new_async().await;
+        let failing = server
+            .mock("POST", "/hook")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+        let repo = Arc::new(InMemoryWebhookDeliveryRepository::new());
+        let dispatcher = OutboundWebhookDispatcher::new(
+            Arc::new(FixedSecrets(Vec::new())),
+            Some(repo.clone() as Arc<dyn WebhookDeliveryRepository>),
+        );
+        let request = webhook(format!("{}/hook", server.url()), 3);
+
+        let result = dispatcher.deliver(&request).await;
+        assert!(matches!(
+            result,
+            Err(OutboundWebhookError::DeadLettered { attempts: 3, .. })
+        ));
+        failing.assert_async().await;
+
+        let attempts = repo
+            .list_attempts(&request.tenant_id, Some(&request.url), 10)
+            .await
+            .unwrap();
+        let outcomes: Vec<_> = attempts.iter().map(|a| a.outcome).collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                DeliveryOutcome::DeadLettered,
+                DeliveryOutcome::Retrying,
+                DeliveryOutcome::Retrying
+            ]
+        );
+        assert!(attempts.iter().all(|a| a.status_code == Some(503)));
+        let dead = repo
+            .list_dead_letters(&request.tenant_id, None, 10)
+            .await
+            .unwrap();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].attempts, 3);
+        assert_eq!(dead[0].body, request.body);
+    }
+
+    #[tokio::test]
+    async fn signed_delivery_succeeds_on_first_attempt() {
+        let mut server = mockito::Server::new_async().await;
+        let accepted = server
+            .mock("POST", "/hook")
+            .match_header(
+                WEBHOOK_SIGNATURE_HEADER,
+                mockito::Matcher::Regex("^sha256=[0-9a-f]{64}$".to_string()),
+            )
+            .match_header(WEBHOOK_ID_HEADER, mockito::Matcher::Any)
+            .with_status(200)
+            .with_body("accepted")
+            .create_async()
+            .await;
+        let repo = Arc::new(InMemoryWebhookDeliveryRepository::new());
+        let dispatcher = OutboundWebhookDispatcher::new(
+            Arc::new(FixedSecrets(vec![b"s3cret".to_vec()])),
+            Some(repo.clone() as Arc<dyn WebhookDeliveryRepository>),
+        );
+        let request = webhook(format!("{}/hook", server.url()), 3);
+
+        let receipt = dispatcher.deliver(&request).await.unwrap();
+        assert_eq!(receipt.attempts, 1);
+        assert_eq!(receipt.status_code, 200);
+        assert_eq!(receipt.response_body, "accepted");
+        accepted.assert_async().await;
+
+        let attempts = repo
+            .list_attempts(&request.tenant_id, None, 10)
+            .await
+            .unwrap();
+        assert_eq!(attempts.len(), 1);
+        assert_eq!(attempts[0].outcome, DeliveryOutcome::Delivered);
+        assert_eq!(attempts[0].delivery_id, receipt.delivery_id);
+    }
+
+    #[tokio::test]
+    async fn client_errors_are_not_retried() {
+        let mut server = mockito::Server::new_async().await;
+        let rejected = server
+            .mock("POST", "/hook")
+            .with_status(422)
+            .expect(1)
+            .create_async()
+            .await;
+        let dispatcher = OutboundWebhookDispatcher::new(Arc::new(FixedSecrets(Vec::new())), None);
+
+        let result = dispatcher
+            .deliver(&webhook(format!("{}/hook", server.url()), 5))
+            .await;
+        assert!(matches!(
+            result,
+            Err(OutboundWebhookError::DeadLettered { attempts: 1, .. })
+        ));
+        rejected.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn unsupported_method_is_rejected_before_sending() {
+        let dispatcher = OutboundWebhookDispatcher::new(Arc::new(FixedSecrets(Vec::new())), None);
+        let mut request = webhook("http://127.0.0.1:9/hook".to_string(), 1);
+        request.method = "DELETE".to_string();
+        assert!(matches!(
+            dispatcher.deliver(&request).await,
+            Err(OutboundWebhookError::InvalidRequest(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn env_provider_returns_current_then_previous_secret() {
+        std::env::set_var(
+            "AEGIS_OUTBOUND_WEBHOOK_SECRET_U_ROTATE_HOOKS_EXAMPLE_COM",
+            "new",
+        );
+        std::env::set_var(
+            "AEGIS_OUTBOUND_WEBHOOK_SECRET_U_ROTATE_HOOKS_EXAMPLE_COM_PREVIOUS",
+            "old",
+        );
+        let tenant = TenantId::from_realm_slug("u-rotate").unwrap();
+        let secrets = EnvOutboundWebhookSecretProvider
+            .signing_secrets(&tenant, "hooks.example.com")
+            .await;
+        assert_eq!(secrets, vec![b"new".to_vec(), b"old".to_vec()]);
+        std::env::remove_var("AEGIS_OUTBOUND_WEBHOOK_SECRET_U_ROTATE_HOOKS_EXAMPLE_COM");
+        std::env::remove_var("AEGIS_OUTBOUND_WEBHOOK_SECRET_U_ROTATE_HOOKS_EXAMPLE_COM_PREVIOUS");
+    }
+}
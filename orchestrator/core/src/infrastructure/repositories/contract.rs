@@ -0,0 +1,549 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Repository Contract Suite
+//!
+//! Behavioural checks shared by every [`ExecutionRepository`] and
+//! [`WorkflowExecutionRepository`] implementation, so the in-memory
+//! repositories used by tests order and filter exactly like Postgres does.
+//! Compiled for in-crate tests and with the `repository-contract` cargo
+//! feature.
+//!
+//! The suite pins down the guarantees callers rely on:
+//!
+//! - listings are newest `started_at` first, ties broken by descending id;
+//! - `limit` / `offset` pagination is stable when timestamps collide;
+//! - every read is scoped to the requested tenant;
+//! - `find_active_for_tenant` returns `Running` workflow executions only;
+//! - `count_running` counts `Pending` and `Running` executions;
+//! - labels are written on first insert and afterwards only through
+//!   `update_labels_for_tenant`;
+//! - filter expressions apply every predicate, and unresolved agent names
+//!   match nothing.
+//!
+//! Each check creates its own random tenants, so the suite can run against a
+//! shared database. Cross-tenant listings (`list_recent_all_paginated`,
+//! `list_paginated_all`) are not covered for that reason. Fixture timestamps
+//! are whole seconds since Postgres stores microseconds only.
+//!
+//! ## Third-party backends
+//!
+//! Enable the `repository-contract` feature and run the suite from a test:
+//!
+//! ```ignore
+//! use aegis_orchestrator_core::infrastructure::repositories::contract;
+//!
+//! #[tokio::test]
+//! async fn my_backend_honours_the_execution_contract() {
+//!     let repo = MyExecutionRepository::connect(&test_url()).await;
+//!     contract::execution_repository_contract(&repo).await;
+//! }
+//! ```
+//!
+//! Checks panic with a description of the violated guarantee.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Test support (exercises infrastructure against domain traits)
+//! - **Purpose:** Keep every repository backend behaviourally identical
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+use crate::domain::agent::AgentId;
+use crate::domain::execution::{Execution, ExecutionId, ExecutionInput, ExecutionStatus};
+use crate::domain::execution_query::ExecutionQuery;
+use crate::domain::repository::{ExecutionRepository, WorkflowExecutionRepository};
+use crate::domain::tenant::TenantId;
+use crate::domain::workflow::{Blackboard, StateName, WorkflowExecution, WorkflowId};
+
+/// Runs every [`ExecutionRepository`] check against `repo`.
+pub async fn execution_repository_contract(repo: &dyn ExecutionRepository) {
+    executions_are_listed_newest_first_with_id_tiebreak(repo).await;
+    execution_reads_are_tenant_scoped(repo).await;
+    execution_labels_are_written_on_first_insert_only(repo).await;
+    count_running_counts_pending_and_running(repo).await;
+    unfinished_executions_are_listed_across_tenants(repo).await;
+    execution_query_applies_every_predicate(repo).await;
+}
+
+/// Runs every [`WorkflowExecutionRepository`] check against `repo`.
+pub async fn workflow_execution_repository_contract(repo: &dyn WorkflowExecutionRepository) {
+    active_workflow_executions_are_running_only(repo).await;
+    workflow_execution_pagination_is_stable(repo).await;
+    workflow_execution_reads_are_tenant_scoped(repo).await;
+}
+
+/// `find_recent_for_tenant` and `find_by_agent_for_tenant` order by
+/// `started_at` descending, then id descending, and honour `limit`.
+pub async fn executions_are_listed_newest_first_with_id_tiebreak(repo: &dyn ExecutionRepository) {
+    let tenant = contract_tenant();
+    let agent_id = AgentId::new();
+    let older = execution(&tenant, agent_id, at(0), ExecutionStatus::Completed);
+    let tied_a = execution(&tenant, agent_id, at(60), ExecutionStatus::Running);
+    let tied_b = execution(&tenant, agent_id, at(60), ExecutionStatus::Pending);
+    for e in [&older, &tied_a, &tied_b] {
+        save_execution(repo, &tenant, e).await;
+    }
+
+    let mut tied = [tied_a.id, tied_b.id];
+    tied.sort_by_key(|id| std::cmp::Reverse(id.0));
+    let expected = vec![tied[0], tied[1], older.id];
+
+    let recent = repo
+        .find_recent_for_tenant(&tenant, 10)
+        .await
+        .expect("find_recent_for_tenant");
+    assert_eq!(
+        ids(&recent),
+        expected,
+        "find_recent_for_tenant must order by started_at DESC, id DESC"
+    );
+
+    let by_agent = repo
+        .find_by_agent_for_tenant(&tenant, agent_id, 10)
+        .await
+        .expect("find_by_agent_for_tenant");
+    assert_eq!(
+        ids(&by_agent),
+        expected,
+        "find_by_agent_for_tenant must order by started_at DESC, id DESC"
+    );
+
+    let limited = repo
+        .find_recent_for_tenant(&tenant, 2)
+        .await
+        .expect("find_recent_for_tenant");
+    assert_eq!(
+        ids(&limited),
+        expected[..2].to_vec(),
+        "find_recent_for_tenant must keep the newest `limit` executions"
+    );
+}
+
+/// Executions saved for one tenant are invisible to every read of another.
+pub async fn execution_reads_are_tenant_scoped(repo: &dyn ExecutionRepository) {
+    let owner = contract_tenant();
+    let other = contract_tenant();
+    let agent_id = AgentId::new();
+    let e = execution(&owner, agent_id, at(0), ExecutionStatus::Running);
+    save_execution(repo, &owner, &e).await;
+
+    assert!(
+        repo.find_by_id_for_tenant(&other, e.id)
+            .await
+            .expect("find_by_id_for_tenant")
+            .is_none(),
+        "find_by_id_for_tenant must not cross tenants"
+    );
+    assert!(
+        repo.find_recent_for_tenant(&other, 10)
+            .await
+            .expect("find_recent_for_tenant")
+            .is_empty(),
+        "find_recent_for_tenant must not cross tenants"
+    );
+    assert!(
+        repo.find_by_agent_for_tenant(&other, agent_id, 10)
+            .await
+            .expect("find_by_agent_for_tenant")
+            .is_empty(),
+        "find_by_agent_for_tenant must not cross tenants"
+    );
+    assert_eq!(
+        repo.count_by_agent_for_tenant(&other, agent_id)
+            .await
+            .expect("count_by_agent_for_tenant"),
+        0,
+        "count_by_agent_for_tenant must not cross tenants"
+    );
+    assert_eq!(
+        repo.count_running(&other).await.expect("count_running"),
+        0,
+        "count_running must not cross tenants"
+    );
+
+    repo.delete_for_tenant(&other, e.id).await.ok();
+    assert!(
+        repo.find_by_id_for_tenant(&owner, e.id)
+            .await
+            .expect("find_by_id_for_tenant")
+            .is_some(),
+        "delete_for_tenant must not delete another tenant's execution"
+    );
+
+    let unscoped = repo
+        .find_by_id_unscoped(e.id)
+        .await
+        .expect("find_by_id_unscoped")
+        .expect("find_by_id_unscoped must find the execution");
+    assert_eq!(
+        unscoped.tenant_id, owner,
+        "find_by_id_unscoped must return the owning tenant"
+    );
+}
+
+/// Re-saving an execution keeps the labels it was inserted with;
+/// `update_labels_for_tenant` replaces them.
+pub async fn execution_labels_are_written_on_first_insert_only(repo: &dyn ExecutionRepository) {
+    let tenant = contract_tenant();
+    let mut e = execution(&tenant, AgentId::new(), at(0), ExecutionStatus::Running);
+    e.labels.insert("team".to_string(), "search".to_string());
+    save_execution(repo, &tenant, &e).await;
+
+    e.labels.insert("team".to_string(), "stale".to_string());
+    e.status = ExecutionStatus::Completed;
+    save_execution(repo, &tenant, &e).await;
+
+    let stored = find_execution(repo, &tenant, e.id).await;
+    assert_eq!(
+        stored.status,
+        ExecutionStatus::Completed,
+        "save_for_tenant must update an existing execution"
+    );
+    assert_eq!(
+        stored.labels.get("team").map(String::as_str),
+        Some("search"),
+        "save_for_tenant must not overwrite labels of an existing execution"
+    );
+
+    let labels = [("team".to_string(), "billing".to_string())].into();
+    repo.update_labels_for_tenant(&tenant, e.id, &labels)
+        .await
+        .expect("update_labels_for_tenant");
+    assert_eq!(
+        find_execution(repo, &tenant, e.id).await.labels,
+        labels,
+        "update_labels_for_tenant must replace the labels"
+    );
+
+    assert!(
+        repo.update_labels_for_tenant(&tenant, ExecutionId::new(), &labels)
+            .await
+            .is_err(),
+        "update_labels_for_tenant must fail for an unknown execution"
+    );
+}
+
+/// `count_running` counts `Pending` and `Running` executions only.
+pub async fn count_running_counts_pending_and_running(repo: &dyn ExecutionRepository) {
+    let tenant = contract_tenant();
+    let agent_id = AgentId::new();
+    for status in [
+        ExecutionStatus::Pending,
+        ExecutionStatus::Running,
+        ExecutionStatus::Completed,
+        ExecutionStatus::Failed,
+        ExecutionStatus::Cancelled,
+    ] {
+        save_execution(repo, &tenant, &execution(&tenant, agent_id, at(0), status)).await;
+    }
+
+    assert_eq!(
+        repo.count_running(&tenant).await.expect("count_running"),
+        2,
+        "count_running must count pending and running executions"
+    );
+    assert_eq!(
+        repo.count_by_agent_for_tenant(&tenant, agent_id)
+            .await
+            .expect("count_by_agent_for_tenant"),
+        5,
+        "count_by_agent_for_tenant must count every status"
+    );
+}
+
+/// `find_unfinished_all` returns pending and running executions of every
+/// tenant, each carrying its own `tenant_id`.
+pub async fn unfinished_executions_are_listed_across_tenants(repo: &dyn ExecutionRepository) {
+    let first = contract_tenant();
+    let second = contract_tenant();
+    let pending = execution(&first, AgentId::new(), at(0), ExecutionStatus::Pending);
+    let running = execution(&second, AgentId::new(), at(1), ExecutionStatus::Running);
+    let completed = execution(&first, AgentId::new(), at(2), ExecutionStatus::Completed);
+    for e in [&pending, &running, &completed] {
+        save_execution(repo, &e.tenant_id, e).await;
+    }
+
+    // Other checks may share the store, so only look at our own rows.
+    let unfinished = repo
+        .find_unfinished_all(10_000)
+        .await
+        .expect("find_unfinished_all");
+    let find = |id: ExecutionId| unfinished.iter().find(|e| e.id == id);
+    assert_eq!(
+        find(pending.id).map(|e| &e.tenant_id),
+        Some(&first),
+        "pending execution must be listed with its tenant"
+    );
+    assert_eq!(
+        find(running.id).map(|e| &e.tenant_id),
+        Some(&second),
+        "running execution must be listed with its tenant"
+    );
+    assert!(
+        find(completed.id).is_none(),
+        "terminal executions must not be listed"
+    );
+}
+
+/// `find_by_query_for_tenant` AND-s every predicate, orders like
+/// `find_recent_for_tenant` and never matches unresolved agent names.
+pub async fn execution_query_applies_every_predicate(repo: &dyn ExecutionRepository) {
+    let tenant = contract_tenant();
+    let agent_id = AgentId::new();
+    let failed_old = execution(&tenant, agent_id, at(0), ExecutionStatus::Failed);
+    let failed_new = execution(&tenant, agent_id, at(120), ExecutionStatus::Failed);
+    let completed = execution(&tenant, agent_id, at(120), ExecutionStatus::Completed);
+    let other_agent = execution(&tenant, AgentId::new(), at(120), ExecutionStatus::Failed);
+    for e in [&failed_old, &failed_new, &completed, &other_agent] {
+        save_execution(repo, &tenant, e).await;
+    }
+
+    let query = parse_query(&format!("status=failed AND agent={}", agent_id.0));
+    let matched = repo
+        .find_by_query_for_tenant(&tenant, &query, 10)
+        .await
+        .expect("find_by_query_for_tenant");
+    assert_eq!(
+        ids(&matched),
+        vec![failed_new.id, failed_old.id],
+        "find_by_query_for_tenant must apply every predicate, newest first"
+    );
+
+    let query = parse_query(&format!(
+        "status=failed AND started>=\"{}\"",
+        at(60).to_rfc3339()
+    ));
+    let mut expected = vec![failed_new.id, other_agent.id];
+    expected.sort_by_key(|id| std::cmp::Reverse(id.0));
+    assert_eq!(
+        ids(&repo
+            .find_by_query_for_tenant(&tenant, &query, 10)
+            .await
+            .expect("find_by_query_for_tenant")),
+        expected,
+        "find_by_query_for_tenant must break started_at ties by id DESC"
+    );
+
+    let query = parse_query("agent=some-agent-name");
+    assert!(
+        repo.find_by_query_for_tenant(&tenant, &query, 10)
+            .await
+            .expect("find_by_query_for_tenant")
+            .is_empty(),
+        "unresolved agent names must match nothing"
+    );
+}
+
+/// `find_active_for_tenant` returns `Running` workflow executions only,
+/// newest first.
+pub async fn active_workflow_executions_are_running_only(repo: &dyn WorkflowExecutionRepository) {
+    let tenant = contract_tenant();
+    let workflow_id = WorkflowId::new();
+    let running_old = workflow_execution(&tenant, workflow_id, at(0), ExecutionStatus::Running);
+    let running_new = workflow_execution(&tenant, workflow_id, at(60), ExecutionStatus::Running);
+    let pending = workflow_execution(&tenant, workflow_id, at(60), ExecutionStatus::Pending);
+    let completed = workflow_execution(&tenant, workflow_id, at(60), ExecutionStatus::Completed);
+    for e in [&running_old, &running_new, &pending, &completed] {
+        save_workflow_execution(repo, &tenant, e).await;
+    }
+
+    let active = repo
+        .find_active_for_tenant(&tenant)
+        .await
+        .expect("find_active_for_tenant");
+    assert_eq!(
+        workflow_ids(&active),
+        vec![running_new.id, running_old.id],
+        "find_active_for_tenant must return running executions only, newest first"
+    );
+}
+
+/// Paging through `list_paginated_for_tenant` and
+/// `find_by_workflow_for_tenant` visits every execution exactly once even
+/// when `started_at` collides.
+pub async fn workflow_execution_pagination_is_stable(repo: &dyn WorkflowExecutionRepository) {
+    let tenant = contract_tenant();
+    let workflow_id = WorkflowId::new();
+    let mut saved = Vec::new();
+    for _ in 0..5 {
+        let e = workflow_execution(&tenant, workflow_id, at(0), ExecutionStatus::Running);
+        save_workflow_execution(repo, &tenant, &e).await;
+        saved.push(e.id);
+    }
+    let other_workflow =
+        workflow_execution(&tenant, WorkflowId::new(), at(0), ExecutionStatus::Running);
+    save_workflow_execution(repo, &tenant, &other_workflow).await;
+
+    saved.sort_by_key(|id| std::cmp::Reverse(id.0));
+    let mut paged = Vec::new();
+    for offset in (0..5).step_by(2) {
+        let page = repo
+            .find_by_workflow_for_tenant(&tenant, workflow_id, 2, offset)
+            .await
+            .expect("find_by_workflow_for_tenant");
+        paged.extend(workflow_ids(&page));
+    }
+    assert_eq!(
+        paged, saved,
+        "find_by_workflow_for_tenant pages must follow started_at DESC, id DESC"
+    );
+    assert_eq!(
+        repo.count_by_workflow_for_tenant(&tenant, workflow_id)
+            .await
+            .expect("count_by_workflow_for_tenant"),
+        5,
+        "count_by_workflow_for_tenant must count one workflow only"
+    );
+
+    let first_page = repo
+        .list_paginated_for_tenant(&tenant, 3, 0)
+        .await
+        .expect("list_paginated_for_tenant");
+    let second_page = repo
+        .list_paginated_for_tenant(&tenant, 3, 3)
+        .await
+        .expect("list_paginated_for_tenant");
+    let mut listed = workflow_ids(&first_page);
+    listed.extend(workflow_ids(&second_page));
+    let mut expected = saved.clone();
+    expected.push(other_workflow.id);
+    expected.sort_by_key(|id| std::cmp::Reverse(id.0));
+    assert_eq!(
+        listed, expected,
+        "list_paginated_for_tenant pages must follow started_at DESC, id DESC"
+    );
+}
+
+/// Workflow executions saved for one tenant are invisible to another.
+pub async fn workflow_execution_reads_are_tenant_scoped(repo: &dyn WorkflowExecutionRepository) {
+    let owner = contract_tenant();
+    let other = contract_tenant();
+    let workflow_id = WorkflowId::new();
+    let e = workflow_execution(&owner, workflow_id, at(0), ExecutionStatus::Running);
+    save_workflow_execution(repo, &owner, &e).await;
+
+    assert!(
+        repo.find_by_id_for_tenant(&other, e.id)
+            .await
+            .expect("find_by_id_for_tenant")
+            .is_none(),
+        "find_by_id_for_tenant must not cross tenants"
+    );
+    assert!(
+        repo.find_active_for_tenant(&other)
+            .await
+            .expect("find_active_for_tenant")
+            .is_empty(),
+        "find_active_for_tenant must not cross tenants"
+    );
+    assert!(
+        repo.find_by_workflow_for_tenant(&other, workflow_id, 10, 0)
+            .await
+            .expect("find_by_workflow_for_tenant")
+            .is_empty(),
+        "find_by_workflow_for_tenant must not cross tenants"
+    );
+    assert_eq!(
+        repo.find_tenant_id_by_execution(e.id)
+            .await
+            .expect("find_tenant_id_by_execution"),
+        Some(owner),
+        "find_tenant_id_by_execution must return the owning tenant"
+    );
+}
+
+fn contract_tenant() -> TenantId {
+    TenantId::from_realm_slug(format!("contract-{}", uuid::Uuid::new_v4().simple()))
+        .expect("contract tenant slug is valid")
+}
+
+/// Fixture timestamp `seconds` after a fixed epoch, at whole-second precision.
+fn at(seconds: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap() + Duration::seconds(seconds)
+}
+
+fn execution(
+    tenant_id: &TenantId,
+    agent_id: AgentId,
+    started_at: DateTime<Utc>,
+    status: ExecutionStatus,
+) -> Execution {
+    let mut execution = Execution::new(
+        agent_id,
+        ExecutionInput {
+            intent: Some("contract".to_string()),
+            input: serde_json::json!({}),
+            workspace_volume_id: None,
+            workspace_volume_mount_path: None,
+            workspace_remote_path: None,
+            workflow_execution_id: None,
+            attachments: Vec::new(),
+        },
+        5,
+        "aegis-system-operator".to_string(),
+    );
+    execution.tenant_id = tenant_id.clone();
+    execution.started_at = started_at;
+    execution.status = status;
+    execution
+}
+
+fn workflow_execution(
+    tenant_id: &TenantId,
+    workflow_id: WorkflowId,
+    started_at: DateTime<Utc>,
+    status: ExecutionStatus,
+) -> WorkflowExecution {
+    WorkflowExecution {
+        id: ExecutionId::new(),
+        workflow_id,
+        tenant_id: tenant_id.clone(),
+        status,
+        current_state: StateName::new("START").expect("valid state name"),
+        blackboard: Blackboard::new(),
+        input: serde_json::json!({}),
+        state_outputs: Default::default(),
+        final_output: None,
+        started_at,
+        last_transition_at: started_at,
+    }
+}
+
+async fn save_execution(repo: &dyn ExecutionRepository, tenant_id: &TenantId, e: &Execution) {
+    repo.save_for_tenant(tenant_id, e)
+        .await
+        .expect("save_for_tenant");
+}
+
+async fn find_execution(
+    repo: &dyn ExecutionRepository,
+    tenant_id: &TenantId,
+    id: ExecutionId,
+) -> Execution {
+    repo.find_by_id_for_tenant(tenant_id, id)
+        .await
+        .expect("find_by_id_for_tenant")
+        .expect("saved execution must be found")
+}
+
+async fn save_workflow_execution(
+    repo: &dyn WorkflowExecutionRepository,
+    tenant_id: &TenantId,
+    e: &WorkflowExecution,
+) {
+    repo.save_for_tenant(tenant_id, e)
+        .await
+        .expect("save_for_tenant");
+}
+
+fn parse_query(expression: &str) -> ExecutionQuery {
+    ExecutionQuery::parse(expression, Utc::now()).expect("contract query parses")
+}
+
+fn ids(executions: &[Execution]) -> Vec<ExecutionId> {
+    executions.iter().map(|e| e.id).collect()
+}
+
+fn workflow_ids(executions: &[WorkflowExecution]) -> Vec<ExecutionId> {
+    executions.iter().map(|e| e.id).collect()
+}
@@ -47,7 +47,16 @@
 //! 2. **Transactional Consistency**: Operations are atomic where possible
 //! 3. **Error Mapping**: Infrastructure errors mapped to domain RepositoryError
 //! 4. **Connection Pooling**: Efficient database connection management
+//!
+//! # Contract Suite
+//!
+//! [`contract`] holds the behavioural checks (ordering, pagination, tenant
+//! scoping, filter semantics) every backend must pass. The in-memory
+//! repositories run it in this module's tests; other backends enable the
+//! `repository-contract` feature.
 
+#[cfg(any(test, feature = "repository-contract"))]
+pub mod contract;
 pub mod postgres_agent;
 pub mod postgres_api_key;
 pub mod postgres_billing;
@@ -56,24 +65,35 @@ pub mod postgres_canvas;
 pub mod postgres_credential;
 pub mod postgres_execution;
 pub mod postgres_git_repo;
+pub mod postgres_network_flow;
+pub mod postgres_quota;
 pub mod postgres_realm;
 pub mod postgres_script;
 pub mod postgres_storage_event;
 pub mod postgres_team;
 pub mod postgres_tenant;
+pub mod postgres_token_usage;
+pub mod postgres_usage_report;
 pub mod postgres_volume;
+pub mod postgres_webhook_delivery;
 pub use postgres_api_key::PostgresApiKeyRepository;
 pub use postgres_canvas::PostgresCanvasSessionRepository;
 pub use postgres_credential::PostgresCredentialBindingRepository;
 pub use postgres_git_repo::PostgresGitRepoBindingRepository;
+pub use postgres_network_flow::PostgresNetworkFlowRepository;
+pub use postgres_quota::PostgresQuotaRepository;
 pub use postgres_realm::PostgresRealmRepository;
 pub use postgres_script::PostgresScriptRepository;
 pub use postgres_team::{PgMembershipRepository, PgTeamInvitationRepository, PgTeamRepository};
+pub use postgres_token_usage::PostgresTokenUsageRepository;
+pub use postgres_usage_report::PostgresUsageReportRepository;
+pub use postgres_webhook_delivery::PostgresWebhookDeliveryRepository;
 pub mod postgres_workflow;
 pub mod postgres_workflow_execution;
 
 use crate::domain::agent::{Agent, AgentId, AgentScope};
 use crate::domain::execution::{Execution, ExecutionId};
+use crate::domain::manifest_change::AgentManifestChange;
 use crate::domain::repository::{
     AgentRepository, ExecutionRepository, RepositoryError, StorageEventRepository,
     WorkflowRepository,
@@ -82,18 +102,20 @@ use crate::domain::tenant::TenantId;
 use crate::domain::workflow::{Workflow, WorkflowId, WorkflowScope};
 use async_trait::async_trait;
 use std::cmp::Reverse;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 
 #[derive(Clone)]
 pub struct InMemoryAgentRepository {
     agents: Arc<RwLock<HashMap<TenantId, HashMap<AgentId, Agent>>>>,
+    manifest_changes: Arc<RwLock<Vec<(TenantId, AgentManifestChange)>>>,
 }
 
 impl InMemoryAgentRepository {
     pub fn new() -> Self {
         Self {
             agents: Arc::new(RwLock::new(HashMap::new())),
+            manifest_changes: Arc::new(RwLock::new(Vec::new())),
         }
     }
 }
@@ -311,12 +333,46 @@ impl AgentRepository for InMemoryAgentRepository {
             .map(|tenant_agents| {
                 tenant_agents
                     .values()
-                    .filter(|a| matches!(a.status, crate::domain::agent::AgentStatus::Active))
+                    .filter(|a| {
+                        matches!(
+                            a.status,
+                            crate::domain::agent::AgentStatus::Active
+                                | crate::domain::agent::AgentStatus::Degraded
+                        )
+                    })
                     .count() as u64
             })
             .unwrap_or(0);
         Ok(count)
     }
+
+    async fn record_manifest_change(
+        &self,
+        tenant_id: &TenantId,
+        change: &AgentManifestChange,
+    ) -> Result<(), RepositoryError> {
+        self.manifest_changes
+            .write()
+            .unwrap()
+            .push((tenant_id.clone(), change.clone()));
+        Ok(())
+    }
+
+    async fn list_manifest_changes_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        agent_id: AgentId,
+    ) -> Result<Vec<AgentManifestChange>, RepositoryError> {
+        Ok(self
+            .manifest_changes
+            .read()
+            .unwrap()
+            .iter()
+            .rev()
+            .filter(|(tid, change)| tid == tenant_id && change.agent_id == agent_id)
+            .map(|(_, change)| change.clone())
+            .collect())
+    }
 }
 
 // AgentLifecycleService implementation for in-memory use
@@ -511,13 +567,37 @@ impl ExecutionRepository for InMemoryExecutionRepository {
         execution: &Execution,
     ) -> Result<(), RepositoryError> {
         let mut executions = self.executions.write().unwrap();
-        executions
-            .entry(tenant_id.clone())
-            .or_default()
-            .insert(execution.id, execution.clone());
+        let tenant_execs = executions.entry(tenant_id.clone()).or_default();
+        // Mirror Postgres: labels are only written on first insert.
+        let mut execution = execution.clone();
+        if let Some(existing) = tenant_execs.get(&execution.id) {
+            execution.labels = existing.labels.clone();
+        }
+        tenant_execs.insert(execution.id, execution);
         Ok(())
     }
 
+    async fn update_labels_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+        labels: &std::collections::BTreeMap<String, String>,
+    ) -> Result<(), RepositoryError> {
+        let mut executions = self.executions.write().unwrap();
+        match executions
+            .get_mut(tenant_id)
+            .and_then(|tenant_execs| tenant_execs.get_mut(&id))
+        {
+            Some(execution) => {
+                execution.labels = labels.clone();
+                Ok(())
+            }
+            None => Err(RepositoryError::NotFound(format!(
+                "Execution {id} not found"
+            ))),
+        }
+    }
+
     async fn find_by_id_for_tenant(
         &self,
         tenant_id: &TenantId,
@@ -544,7 +624,7 @@ impl ExecutionRepository for InMemoryExecutionRepository {
             .filter(|e| e.agent_id == agent_id)
             .cloned()
             .collect();
-        results.sort_by_key(|e| Reverse(e.started_at));
+        results.sort_by_key(|e| Reverse((e.started_at, e.id.0)));
         Ok(results.into_iter().take(limit).collect())
     }
 
@@ -568,11 +648,29 @@ impl ExecutionRepository for InMemoryExecutionRepository {
             .get(tenant_id)
             .map(|tenant_execs| tenant_execs.values().cloned().collect())
             .unwrap_or_default();
-        // Sort by started_at desc
-        execution_list.sort_by_key(|e| Reverse(e.started_at));
+        // Newest first; ties broken by id, matching Postgres.
+        execution_list.sort_by_key(|e| Reverse((e.started_at, e.id.0)));
         Ok(execution_list.into_iter().take(limit).collect())
     }
 
+    async fn find_by_query_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        query: &crate::domain::execution_query::ExecutionQuery,
+        limit: usize,
+    ) -> Result<Vec<Execution>, RepositoryError> {
+        let executions = self.executions.read().unwrap();
+        let mut results: Vec<Execution> = executions
+            .get(tenant_id)
+            .into_iter()
+            .flat_map(|tenant_execs| tenant_execs.values())
+            .filter(|e| query.matches(e))
+            .cloned()
+            .collect();
+        results.sort_by_key(|e| Reverse((e.started_at, e.id.0)));
+        Ok(results.into_iter().take(limit).collect())
+    }
+
     async fn list_recent_all_paginated(
         &self,
         limit: usize,
@@ -583,7 +681,7 @@ impl ExecutionRepository for InMemoryExecutionRepository {
             .values()
             .flat_map(|tenant_execs| tenant_execs.values().cloned())
             .collect();
-        all.sort_by_key(|e| Reverse(e.started_at));
+        all.sort_by_key(|e| Reverse((e.started_at, e.id.0)));
         Ok(all.into_iter().skip(offset).take(limit).collect())
     }
 
@@ -652,6 +750,24 @@ impl ExecutionRepository for InMemoryExecutionRepository {
             .unwrap_or(0);
         Ok(count)
     }
+
+    async fn find_unfinished_all(&self, limit: usize) -> Result<Vec<Execution>, RepositoryError> {
+        let executions = self.executions.read().unwrap();
+        let mut unfinished: Vec<Execution> = executions
+            .values()
+            .flat_map(|tenant_execs| tenant_execs.values())
+            .filter(|e| {
+                matches!(
+                    e.status,
+                    crate::domain::execution::ExecutionStatus::Running
+                        | crate::domain::execution::ExecutionStatus::Pending
+                )
+            })
+            .cloned()
+            .collect();
+        unfinished.sort_by_key(|e| (e.started_at, e.id.0));
+        Ok(unfinished.into_iter().take(limit).collect())
+    }
 }
 
 #[derive(Clone)]
@@ -974,12 +1090,26 @@ pub struct InMemoryWorkflowExecutionRepository {
             >,
         >,
     >,
+    transitions:
+        Arc<RwLock<HashMap<ExecutionId, Vec<crate::domain::workflow::WorkflowTransitionRecord>>>>,
+    transition_blackboards: Arc<RwLock<HashMap<(ExecutionId, i64), serde_json::Value>>>,
+    handoffs: Arc<
+        RwLock<
+            HashMap<
+                ExecutionId,
+                BTreeMap<(String, String), crate::domain::workflow::WorkflowHandoff>,
+            >,
+        >,
+    >,
 }
 
 impl InMemoryWorkflowExecutionRepository {
     pub fn new() -> Self {
         Self {
             executions: Arc::new(RwLock::new(HashMap::new())),
+            transitions: Arc::new(RwLock::new(HashMap::new())),
+            transition_blackboards: Arc::new(RwLock::new(HashMap::new())),
+            handoffs: Arc::new(RwLock::new(HashMap::new())),
         }
     }
 }
@@ -1034,13 +1164,29 @@ impl crate::domain::repository::WorkflowExecutionRepository
         tenant_id: &TenantId,
     ) -> Result<Vec<crate::domain::workflow::WorkflowExecution>, RepositoryError> {
         let executions = self.executions.read().unwrap();
-        Ok(executions
+        let mut active: Vec<_> = executions
             .get(tenant_id)
             .into_iter()
             .flat_map(|tenant_execs| tenant_execs.values())
             .filter(|e| e.status == crate::domain::execution::ExecutionStatus::Running)
             .cloned()
-            .collect())
+            .collect();
+        active.sort_by_key(|e| Reverse((e.started_at, e.id.0)));
+        Ok(active)
+    }
+
+    async fn find_active_all(
+        &self,
+    ) -> Result<Vec<crate::domain::workflow::WorkflowExecution>, RepositoryError> {
+        let executions = self.executions.read().unwrap();
+        let mut active: Vec<_> = executions
+            .values()
+            .flat_map(|tenant_execs| tenant_execs.values())
+            .filter(|e| e.status == crate::domain::execution::ExecutionStatus::Running)
+            .cloned()
+            .collect();
+        active.sort_by_key(|e| Reverse((e.started_at, e.id.0)));
+        Ok(active)
     }
 
     async fn find_by_workflow_for_tenant(
@@ -1061,7 +1207,7 @@ impl crate::domain::repository::WorkflowExecutionRepository
                     .collect()
             })
             .unwrap_or_default();
-        list.sort_by_key(|e| Reverse(e.started_at));
+        list.sort_by_key(|e| Reverse((e.started_at, e.id.0)));
         Ok(list.into_iter().skip(offset).take(limit).collect())
     }
 
@@ -1096,6 +1242,146 @@ impl crate::domain::repository::WorkflowExecutionRepository
         Ok(vec![])
     }
 
+    async fn append_transition(
+        &self,
+        execution_id: ExecutionId,
+        record: &crate::domain::workflow::WorkflowTransitionRecord,
+    ) -> Result<(), RepositoryError> {
+        let mut transitions = self.transitions.write().unwrap();
+        let history = transitions.entry(execution_id).or_default();
+        if history.iter().all(|r| r.sequence != record.sequence) {
+            history.push(record.clone());
+            history.sort_by_key(|r| r.sequence);
+        }
+        Ok(())
+    }
+
+    async fn close_transition(
+        &self,
+        execution_id: ExecutionId,
+        state_name: &str,
+        exited_at: chrono::DateTime<chrono::Utc>,
+        output_summary: Option<String>,
+    ) -> Result<(), RepositoryError> {
+        let mut transitions = self.transitions.write().unwrap();
+        if let Some(open) = transitions.get_mut(&execution_id).and_then(|history| {
+            history
+                .iter_mut()
+                .rev()
+                .find(|r| r.state_name == state_name && r.exited_at.is_none())
+        }) {
+            open.exited_at = Some(exited_at);
+            open.output_summary = output_summary;
+        }
+        Ok(())
+    }
+
+    async fn find_transitions_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+    ) -> Result<Vec<crate::domain::workflow::WorkflowTransitionRecord>, RepositoryError> {
+        let owned = self
+            .executions
+            .read()
+            .unwrap()
+            .get(tenant_id)
+            .is_some_and(|tenant_execs| tenant_execs.contains_key(&id));
+        if !owned {
+            return Ok(vec![]);
+        }
+        Ok(self
+            .transitions
+            .read()
+            .unwrap()
+            .get(&id)
+            .cloned()
+            .unwrap_or_default())
+    }
+
+    async fn record_transition_blackboard(
+        &self,
+        execution_id: ExecutionId,
+        sequence: i64,
+        blackboard: &serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        let recorded = self
+            .transitions
+            .read()
+            .unwrap()
+            .get(&execution_id)
+            .is_some_and(|history| history.iter().any(|r| r.sequence == sequence));
+        if recorded {
+            self.transition_blackboards
+                .write()
+                .unwrap()
+                .insert((execution_id, sequence), blackboard.clone());
+        }
+        Ok(())
+    }
+
+    async fn find_transition_blackboard_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+        sequence: i64,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        let owned = self
+            .executions
+            .read()
+            .unwrap()
+            .get(tenant_id)
+            .is_some_and(|tenant_execs| tenant_execs.contains_key(&id));
+        if !owned {
+            return Ok(None);
+        }
+        Ok(self
+            .transition_blackboards
+            .read()
+            .unwrap()
+            .get(&(id, sequence))
+            .cloned())
+    }
+
+    async fn record_handoffs(
+        &self,
+        execution_id: ExecutionId,
+        handoffs: &[crate::domain::workflow::WorkflowHandoff],
+    ) -> Result<(), RepositoryError> {
+        let mut stored = self.handoffs.write().unwrap();
+        let execution = stored.entry(execution_id).or_default();
+        for handoff in handoffs {
+            execution.insert(
+                (handoff.state_name.clone(), handoff.output_name.clone()),
+                handoff.clone(),
+            );
+        }
+        Ok(())
+    }
+
+    async fn find_handoffs_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+    ) -> Result<Vec<crate::domain::workflow::WorkflowHandoff>, RepositoryError> {
+        let owned = self
+            .executions
+            .read()
+            .unwrap()
+            .get(tenant_id)
+            .is_some_and(|tenant_execs| tenant_execs.contains_key(&id));
+        if !owned {
+            return Ok(vec![]);
+        }
+        Ok(self
+            .handoffs
+            .read()
+            .unwrap()
+            .get(&id)
+            .map(|handoffs| handoffs.values().cloned().collect())
+            .unwrap_or_default())
+    }
+
     async fn count_by_workflow_for_tenant(
         &self,
         tenant_id: &TenantId,
@@ -1125,7 +1411,7 @@ impl crate::domain::repository::WorkflowExecutionRepository
             .get(tenant_id)
             .map(|tenant_execs| tenant_execs.values().cloned().collect())
             .unwrap_or_default();
-        list.sort_by_key(|e| Reverse(e.started_at));
+        list.sort_by_key(|e| Reverse((e.started_at, e.id.0)));
         Ok(list.into_iter().skip(offset).take(limit).collect())
     }
 
@@ -1139,7 +1425,7 @@ impl crate::domain::repository::WorkflowExecutionRepository
             .values()
             .flat_map(|tenant_execs| tenant_execs.values().cloned())
             .collect();
-        list.sort_by_key(|e| Reverse(e.started_at));
+        list.sort_by_key(|e| Reverse((e.started_at, e.id.0)));
         Ok(list.into_iter().skip(offset).take(limit).collect())
     }
 }
@@ -1222,6 +1508,9 @@ impl StorageEventRepository for InMemoryStorageEventRepository {
                     StorageEvent::UnauthorizedVolumeAccess {
                         execution_id: eid, ..
                     } => *eid == Some(execution_id),
+                    StorageEvent::OperationFailed {
+                        execution_id: eid, ..
+                    } => *eid == Some(execution_id),
                 }
             })
             .cloned()
@@ -1260,6 +1549,7 @@ impl StorageEventRepository for InMemoryStorageEventRepository {
                     StorageEvent::UnauthorizedVolumeAccess { volume_id: vid, .. } => {
                         *vid == volume_id
                     }
+                    StorageEvent::OperationFailed { volume_id: vid, .. } => *vid == volume_id,
                     // PathTraversalBlocked doesn't have volume_id
                     StorageEvent::PathTraversalBlocked { .. } => false,
                 }
@@ -1467,6 +1757,269 @@ impl crate::domain::repository::VolumeRepository for InMemoryVolumeRepository {
     }
 }
 
+// ============================================================================
+// In-Memory TokenUsageRepository (for testing)
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct InMemoryTokenUsageRepository {
+    records: Arc<RwLock<Vec<crate::domain::token_usage::TokenUsageRecord>>>,
+    daily: Arc<RwLock<Vec<crate::domain::token_usage::DailyUsageSummary>>>,
+}
+
+impl InMemoryTokenUsageRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl crate::domain::token_usage::TokenUsageRepository for InMemoryTokenUsageRepository {
+    async fn record(
+        &self,
+        record: &crate::domain::token_usage::TokenUsageRecord,
+    ) -> Result<(), RepositoryError> {
+        self.records.write().unwrap().push(record.clone());
+        Ok(())
+    }
+
+    async fn rollup(
+        &self,
+        query: &crate::domain::token_usage::UsageQuery,
+    ) -> Result<Vec<crate::domain::token_usage::UsageRollup>, RepositoryError> {
+        let records = self.records.read().unwrap();
+        Ok(crate::domain::token_usage::rollup(
+            records.iter().filter(|r| query.matches(r)),
+            query.group_by,
+        ))
+    }
+
+    async fn aggregate_day(&self, day: chrono::NaiveDate) -> Result<u64, RepositoryError> {
+        use crate::domain::token_usage::DailyUsageSummary;
+        let mut groups: std::collections::BTreeMap<
+            (String, uuid::Uuid, String),
+            DailyUsageSummary,
+        > = std::collections::BTreeMap::new();
+        for record in self
+            .records
+            .read()
+            .unwrap()
+            .iter()
+            .filter(|r| r.recorded_at.date_naive() == day)
+        {
+            let key = (
+                record.tenant_id.as_str().to_string(),
+                record.agent_id.0,
+                record.model.clone(),
+            );
+            let row = groups.entry(key).or_insert_with(|| DailyUsageSummary {
+                day,
+                tenant_id: record.tenant_id.as_str().to_string(),
+                agent_id: record.agent_id,
+                model: record.model.clone(),
+                input_tokens: 0,
+                output_tokens: 0,
+                calls: 0,
+            });
+            row.input_tokens += u64::from(record.input_tokens);
+            row.output_tokens += u64::from(record.output_tokens);
+            row.calls += 1;
+        }
+        let mut daily = self.daily.write().unwrap();
+        daily.retain(|row| row.day != day);
+        let written = groups.len() as u64;
+        daily.extend(groups.into_values());
+        Ok(written)
+    }
+
+    async fn daily_summary(
+        &self,
+        tenant_id: &TenantId,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<crate::domain::token_usage::DailyUsageSummary>, RepositoryError> {
+        let daily = self.daily.read().unwrap();
+        let mut rows: Vec<_> = daily
+            .iter()
+            .filter(|row| row.tenant_id == tenant_id.as_str() && row.day >= from && row.day <= to)
+            .cloned()
+            .collect();
+        rows.sort_by(|a, b| a.day.cmp(&b.day).then_with(|| a.model.cmp(&b.model)));
+        Ok(rows)
+    }
+}
+
+// ============================================================================
+// In-Memory QuotaRepository (for testing)
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct InMemoryQuotaRepository {
+    definitions: Arc<RwLock<HashMap<TenantId, crate::domain::quota::QuotaDefinition>>>,
+}
+
+impl InMemoryQuotaRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl crate::domain::quota::QuotaRepository for InMemoryQuotaRepository {
+    async fn get(
+        &self,
+        tenant_id: &TenantId,
+    ) -> Result<Option<crate::domain::quota::QuotaDefinition>, RepositoryError> {
+        Ok(self.definitions.read().unwrap().get(tenant_id).cloned())
+    }
+
+    async fn list(&self) -> Result<Vec<crate::domain::quota::QuotaDefinition>, RepositoryError> {
+        let mut definitions: Vec<_> = self.definitions.read().unwrap().values().cloned().collect();
+        definitions.sort_by(|a, b| a.tenant_id.as_str().cmp(b.tenant_id.as_str()));
+        Ok(definitions)
+    }
+
+    async fn upsert(
+        &self,
+        definition: &crate::domain::quota::QuotaDefinition,
+    ) -> Result<(), RepositoryError> {
+        self.definitions
+            .write()
+            .unwrap()
+            .insert(definition.tenant_id.clone(), definition.clone());
+        Ok(())
+    }
+
+    async fn delete(&self, tenant_id: &TenantId) -> Result<bool, RepositoryError> {
+        Ok(self
+            .definitions
+            .write()
+            .unwrap()
+            .remove(tenant_id)
+            .is_some())
+    }
+}
+
+// ============================================================================
+// In-Memory NetworkFlowRepository (for testing)
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct InMemoryNetworkFlowRepository {
+    flows: Arc<RwLock<HashMap<ExecutionId, Vec<crate::domain::network_flow::NetworkFlow>>>>,
+}
+
+impl InMemoryNetworkFlowRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl crate::domain::network_flow::NetworkFlowRepository for InMemoryNetworkFlowRepository {
+    async fn append(
+        &self,
+        execution_id: ExecutionId,
+        flows: &[crate::domain::network_flow::NetworkFlow],
+    ) -> Result<(), RepositoryError> {
+        self.flows
+            .write()
+            .unwrap()
+            .entry(execution_id)
+            .or_default()
+            .extend_from_slice(flows);
+        Ok(())
+    }
+
+    async fn find_by_execution(
+        &self,
+        execution_id: ExecutionId,
+        limit: Option<usize>,
+    ) -> Result<Vec<crate::domain::network_flow::NetworkFlow>, RepositoryError> {
+        let mut flows = self
+            .flows
+            .read()
+            .unwrap()
+            .get(&execution_id)
+            .cloned()
+            .unwrap_or_default();
+        flows.sort_by_key(|flow| flow.first_seen);
+        flows.truncate(limit.unwrap_or(usize::MAX));
+        Ok(flows)
+    }
+}
+
+// ============================================================================
+// In-Memory WebhookDeliveryRepository (for testing)
+// ============================================================================
+
+#[derive(Clone, Default)]
+pub struct InMemoryWebhookDeliveryRepository {
+    attempts: Arc<RwLock<Vec<crate::domain::outbound_webhook::DeliveryAttempt>>>,
+    dead_letters: Arc<RwLock<Vec<crate::domain::outbound_webhook::DeadLetter>>>,
+}
+
+impl InMemoryWebhookDeliveryRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl crate::domain::outbound_webhook::WebhookDeliveryRepository
+    for InMemoryWebhookDeliveryRepository
+{
+    async fn record_attempt(
+        &self,
+        attempt: &crate::domain::outbound_webhook::DeliveryAttempt,
+    ) -> Result<(), RepositoryError> {
+        self.attempts.write().unwrap().push(attempt.clone());
+        Ok(())
+    }
+
+    async fn dead_letter(
+        &self,
+        dead_letter: &crate::domain::outbound_webhook::DeadLetter,
+    ) -> Result<(), RepositoryError> {
+        self.dead_letters.write().unwrap().push(dead_letter.clone());
+        Ok(())
+    }
+
+    async fn list_attempts(
+        &self,
+        tenant_id: &TenantId,
+        destination: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<crate::domain::outbound_webhook::DeliveryAttempt>, RepositoryError> {
+        let attempts = self.attempts.read().unwrap();
+        Ok(attempts
+            .iter()
+            .rev()
+            .filter(|a| &a.tenant_id == tenant_id)
+            .filter(|a| destination.is_none_or(|d| a.destination == d))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
+    async fn list_dead_letters(
+        &self,
+        tenant_id: &TenantId,
+        destination: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<crate::domain::outbound_webhook::DeadLetter>, RepositoryError> {
+        let dead_letters = self.dead_letters.read().unwrap();
+        Ok(dead_letters
+            .iter()
+            .rev()
+            .filter(|d| &d.tenant_id == tenant_id)
+            .filter(|d| destination.is_none_or(|url| d.destination == url))
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1477,6 +2030,19 @@ mod tests {
     // imported directly.
     use crate::domain::repository::WorkflowExecutionRepository;
 
+    #[tokio::test]
+    async fn in_memory_execution_repository_meets_contract() {
+        contract::execution_repository_contract(&InMemoryExecutionRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_workflow_execution_repository_meets_contract() {
+        contract::workflow_execution_repository_contract(
+            &InMemoryWorkflowExecutionRepository::new(),
+        )
+        .await;
+    }
+
     #[tokio::test]
     async fn test_in_memory_agent_repository_basic() {
         let repo = InMemoryAgentRepository::new();
@@ -1599,6 +2165,33 @@ mod tests {
         assert_ne!(page1[0].id, page2[0].id);
     }
 
+    #[tokio::test]
+    async fn find_by_query_for_tenant_applies_filter_expression() {
+        use crate::domain::execution::ExecutionStatus;
+        use crate::domain::execution_query::ExecutionQuery;
+
+        let repo = InMemoryExecutionRepository::new();
+        let t = TenantId::new("t-a".to_string()).unwrap();
+        let other = TenantId::new("t-b".to_string()).unwrap();
+        let mut old_failed = make_execution(&t, 1000);
+        old_failed.status = ExecutionStatus::Failed;
+        let mut new_failed = make_execution(&t, 2000);
+        new_failed.status = ExecutionStatus::Failed;
+        let new_running = make_execution(&t, 2001);
+        let mut foreign = make_execution(&other, 2000);
+        foreign.status = ExecutionStatus::Failed;
+        for e in [&old_failed, &new_failed, &new_running] {
+            repo.save_for_tenant(&t, e).await.unwrap();
+        }
+        repo.save_for_tenant(&other, &foreign).await.unwrap();
+
+        let now = chrono::DateTime::from_timestamp(2500, 0).unwrap();
+        let query = ExecutionQuery::parse("status=failed AND started>-20m", now).unwrap();
+        let rows = repo.find_by_query_for_tenant(&t, &query, 10).await.unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].id, new_failed.id);
+    }
+
     fn make_workflow_exec(
         tenant: &TenantId,
         started_secs: i64,
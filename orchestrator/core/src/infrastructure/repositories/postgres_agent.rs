@@ -11,6 +11,7 @@
 //! See ADR-025 (PostgreSQL Schema Design and Migration Strategy).
 
 use crate::domain::agent::{Agent, AgentId, AgentManifest, AgentScope, AgentStatus};
+use crate::domain::manifest_change::AgentManifestChange;
 use crate::domain::repository::{AgentRepository, RepositoryError};
 use crate::domain::tenant::TenantId;
 use anyhow::Result;
@@ -45,6 +46,7 @@ impl AgentRepository for PostgresAgentRepository {
             AgentStatus::Active => "active",
             AgentStatus::Paused => "paused",
             AgentStatus::Archived => "archived",
+            AgentStatus::Degraded => "degraded",
             AgentStatus::Failed => "active", // Table stores active/paused/archived states only.
         };
 
@@ -149,6 +151,7 @@ impl AgentRepository for PostgresAgentRepository {
                 "active" => AgentStatus::Active,
                 "paused" => AgentStatus::Paused,
                 "archived" => AgentStatus::Archived,
+                "degraded" => AgentStatus::Degraded,
                 _ => AgentStatus::Active,
             };
 
@@ -210,6 +213,7 @@ impl AgentRepository for PostgresAgentRepository {
                 "active" => AgentStatus::Active,
                 "paused" => AgentStatus::Paused,
                 "archived" => AgentStatus::Archived,
+                "degraded" => AgentStatus::Degraded,
                 _ => AgentStatus::Active,
             };
 
@@ -271,6 +275,7 @@ impl AgentRepository for PostgresAgentRepository {
                 "active" => AgentStatus::Active,
                 "paused" => AgentStatus::Paused,
                 "archived" => AgentStatus::Archived,
+                "degraded" => AgentStatus::Degraded,
                 _ => AgentStatus::Active,
             };
 
@@ -330,6 +335,7 @@ impl AgentRepository for PostgresAgentRepository {
                 "active" => AgentStatus::Active,
                 "paused" => AgentStatus::Paused,
                 "archived" => AgentStatus::Archived,
+                "degraded" => AgentStatus::Degraded,
                 _ => AgentStatus::Active,
             };
 
@@ -387,6 +393,7 @@ impl AgentRepository for PostgresAgentRepository {
                 "active" => AgentStatus::Active,
                 "paused" => AgentStatus::Paused,
                 "archived" => AgentStatus::Archived,
+                "degraded" => AgentStatus::Degraded,
                 _ => AgentStatus::Active,
             };
 
@@ -498,6 +505,7 @@ impl AgentRepository for PostgresAgentRepository {
                 "active" => AgentStatus::Active,
                 "paused" => AgentStatus::Paused,
                 "archived" => AgentStatus::Archived,
+                "degraded" => AgentStatus::Degraded,
                 _ => AgentStatus::Active,
             };
 
@@ -565,7 +573,7 @@ impl AgentRepository for PostgresAgentRepository {
         let row = sqlx::query(
             r#"
             SELECT COUNT(*) AS cnt FROM agents
-            WHERE tenant_id = $1 AND status = 'active'
+            WHERE tenant_id = $1 AND status IN ('active', 'degraded')
             "#,
         )
         .bind(tenant_id.as_str())
@@ -577,6 +585,75 @@ impl AgentRepository for PostgresAgentRepository {
         Ok(count.max(0) as u64)
     }
 
+    async fn record_manifest_change(
+        &self,
+        tenant_id: &TenantId,
+        change: &AgentManifestChange,
+    ) -> Result<(), RepositoryError> {
+        let changes = serde_json::to_value(&change.changes)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO agent_manifest_changes (
+                id, agent_id, tenant_id, old_version, new_version,
+                changes, reason, changed_by, changed_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(change.id)
+        .bind(change.agent_id.0)
+        .bind(tenant_id.as_str())
+        .bind(&change.old_version)
+        .bind(&change.new_version)
+        .bind(changes)
+        .bind(change.reason.as_deref())
+        .bind(change.changed_by.as_deref())
+        .bind(change.changed_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("Failed to record manifest change: {e}")))?;
+
+        Ok(())
+    }
+
+    async fn list_manifest_changes_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        agent_id: AgentId,
+    ) -> Result<Vec<AgentManifestChange>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT id, old_version, new_version, changes, reason, changed_by, changed_at
+            FROM agent_manifest_changes
+            WHERE tenant_id = $1 AND agent_id = $2
+            ORDER BY changed_at DESC
+            "#,
+        )
+        .bind(tenant_id.as_str())
+        .bind(agent_id.0)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("Failed to query manifest changes: {e}")))?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok(AgentManifestChange {
+                    id: row.get("id"),
+                    agent_id,
+                    old_version: row.get("old_version"),
+                    new_version: row.get("new_version"),
+                    changes: serde_json::from_value(row.get("changes"))
+                        .map_err(|e| RepositoryError::Serialization(e.to_string()))?,
+                    reason: row.get("reason"),
+                    changed_by: row.get("changed_by"),
+                    changed_at: row.get("changed_at"),
+                })
+            })
+            .collect()
+    }
+
     async fn resolve_by_name(
         &self,
         tenant_id: &TenantId,
@@ -615,6 +692,7 @@ impl AgentRepository for PostgresAgentRepository {
                 "active" => AgentStatus::Active,
                 "paused" => AgentStatus::Paused,
                 "archived" => AgentStatus::Archived,
+                "degraded" => AgentStatus::Degraded,
                 _ => AgentStatus::Active,
             };
 
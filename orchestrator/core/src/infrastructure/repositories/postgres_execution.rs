@@ -46,23 +46,135 @@ use crate::domain::agent::AgentId;
 use crate::domain::execution::{
     Execution, ExecutionHierarchy, ExecutionId, ExecutionInput, ExecutionStatus, Iteration,
 };
+use crate::domain::execution_query::{
+    AgentSelector, CompareOp, ExecutionPredicate, ExecutionQuery,
+};
 use crate::domain::repository::{ExecutionRepository, RepositoryError};
 use crate::domain::tenant::TenantId;
+use crate::infrastructure::db::{DatabasePools, ReadConsistency};
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::postgres::PgPool;
-use sqlx::Row;
+use sqlx::postgres::{PgPool, PgRow};
+use sqlx::{Postgres, QueryBuilder, Row};
+use std::collections::BTreeMap;
 
 pub struct PostgresExecutionRepository {
-    pool: PgPool,
+    pools: DatabasePools,
 }
 
 impl PostgresExecutionRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self::with_pools(DatabasePools::primary_only(pool))
+    }
+
+    /// Listing and count queries may be served by the read replica in
+    /// `pools`; lookups by ID and `count_running` always read the primary
+    /// since cancellation and concurrency limits act on their result.
+    pub fn with_pools(pools: DatabasePools) -> Self {
+        Self { pools }
     }
 }
 
+/// Database spelling of an [`ExecutionStatus`].
+fn status_as_str(status: &ExecutionStatus) -> &'static str {
+    match status {
+        ExecutionStatus::Pending => "pending",
+        ExecutionStatus::Running => "running",
+        ExecutionStatus::Completed => "completed",
+        ExecutionStatus::Failed => "failed",
+        ExecutionStatus::Cancelled => "cancelled",
+    }
+}
+
+/// Map a row selected with the standard execution column list.
+fn execution_from_row(row: &PgRow, tenant_id: &TenantId) -> Result<Execution, RepositoryError> {
+    let id: uuid::Uuid = row.get("id");
+    let agent_id: uuid::Uuid = row.get("agent_id");
+    let status_str: String = row.get("status");
+    let input_val: serde_json::Value = row.get("input");
+    let iterations_val: serde_json::Value = row.get("iterations");
+    let max_iterations: i32 = row.get("max_iterations");
+    let container_uid: i32 = row.get("container_uid");
+    let container_gid: i32 = row.get("container_gid");
+    let started_at: chrono::DateTime<chrono::Utc> = row.get("started_at");
+    let completed_at: Option<chrono::DateTime<chrono::Utc>> = row.get("completed_at");
+    let error_message: Option<String> = row.get("error_message");
+    let parent_execution_id: Option<uuid::Uuid> = row.get("parent_execution_id");
+    let security_context_name: String = row.get("security_context_name");
+    let initiating_user_sub: Option<String> = row.get("initiating_user_sub");
+    let runtime_image_digest: Option<String> = row.get("runtime_image_digest");
+    let labels_val: serde_json::Value = row.get("labels");
+    let labels: BTreeMap<String, String> = serde_json::from_value(labels_val).map_err(|e| {
+        RepositoryError::Serialization(format!("Failed to deserialize labels: {e}"))
+    })?;
+
+    let status = match status_str.as_str() {
+        "pending" => Ok(ExecutionStatus::Pending),
+        "running" => Ok(ExecutionStatus::Running),
+        "completed" => Ok(ExecutionStatus::Completed),
+        "failed" => Ok(ExecutionStatus::Failed),
+        "cancelled" => Ok(ExecutionStatus::Cancelled),
+        other => Err(RepositoryError::Serialization(format!(
+            "Unknown execution status value from database: '{other}'"
+        ))),
+    }?;
+
+    let input: ExecutionInput = serde_json::from_value(input_val).map_err(|e| {
+        RepositoryError::Serialization(format!("Failed to deserialize execution input: {e}"))
+    })?;
+    let iterations: Vec<Iteration> = serde_json::from_value(iterations_val).map_err(|e| {
+        RepositoryError::Serialization(format!("Failed to deserialize iterations: {e}"))
+    })?;
+
+    let max_iterations_u8 = u8::try_from(max_iterations).map_err(|_| {
+        RepositoryError::Serialization(format!(
+            "Invalid max_iterations value {max_iterations}: expected 0-255"
+        ))
+    })?;
+
+    let hierarchy = match parent_execution_id {
+        Some(parent_id) => ExecutionHierarchy {
+            parent_execution_id: Some(ExecutionId(parent_id)),
+            depth: 1,
+            path: vec![ExecutionId(id)],
+            swarm_id: None,
+        },
+        None => ExecutionHierarchy::root(ExecutionId(id)),
+    };
+
+    let container_uid_u32 = u32::try_from(container_uid).map_err(|_| {
+        RepositoryError::Serialization(format!(
+            "Invalid container_uid value (expected non-negative i32): {container_uid}"
+        ))
+    })?;
+
+    let container_gid_u32 = u32::try_from(container_gid).map_err(|_| {
+        RepositoryError::Serialization(format!(
+            "Invalid container_gid value (expected non-negative i32): {container_gid}"
+        ))
+    })?;
+
+    Ok(Execution {
+        id: ExecutionId(id),
+        agent_id: AgentId(agent_id),
+        tenant_id: tenant_id.clone(),
+        status,
+        iterations,
+        max_iterations: max_iterations_u8,
+        container_uid: container_uid_u32,
+        container_gid: container_gid_u32,
+        input,
+        started_at,
+        ended_at: completed_at,
+        error: error_message,
+        hierarchy,
+        security_context_name,
+        initiating_user_sub,
+        runtime_image_digest,
+        labels,
+    })
+}
+
 #[async_trait]
 impl ExecutionRepository for PostgresExecutionRepository {
     async fn save_for_tenant(
@@ -76,6 +188,9 @@ impl ExecutionRepository for PostgresExecutionRepository {
         let input_json = serde_json::to_value(&execution.input)
             .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
 
+        let labels_json = serde_json::to_value(&execution.labels)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+
         // Extract final output and error from the execution state or last iteration
         let final_output = execution.iterations().last().and_then(|i| i.output.clone());
 
@@ -103,9 +218,9 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 current_iteration, max_iterations, final_output, error_message,
                 container_uid, container_gid,
                 started_at, completed_at, parent_execution_id,
-                security_context_name, initiating_user_sub
+                security_context_name, initiating_user_sub, runtime_image_digest, labels
             )
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19)
             ON CONFLICT (id) DO UPDATE SET
                 tenant_id = EXCLUDED.tenant_id,
                 status = EXCLUDED.status,
@@ -118,7 +233,11 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 completed_at = EXCLUDED.completed_at,
                 parent_execution_id = EXCLUDED.parent_execution_id,
                 security_context_name = EXCLUDED.security_context_name,
-                initiating_user_sub = EXCLUDED.initiating_user_sub
+                initiating_user_sub = EXCLUDED.initiating_user_sub,
+                runtime_image_digest = EXCLUDED.runtime_image_digest
+            -- labels are only written on insert; later edits go through
+            -- update_labels_for_tenant so a stale in-flight copy of the
+            -- aggregate cannot revert them.
             "#,
         )
         .bind(execution.id.0)
@@ -138,7 +257,9 @@ impl ExecutionRepository for PostgresExecutionRepository {
         .bind(parent_execution_id)
         .bind(&execution.security_context_name)
         .bind(&execution.initiating_user_sub)
-        .execute(&self.pool)
+        .bind(&execution.runtime_image_digest)
+        .bind(labels_json)
+        .execute(self.pools.primary())
         .await
         .map_err(|e| RepositoryError::Database(format!("Failed to save execution: {e}")))?;
 
@@ -162,14 +283,14 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 id, agent_id, input, status, iterations, max_iterations,
                 container_uid, container_gid,
                 started_at, completed_at, error_message,
-                parent_execution_id, security_context_name, initiating_user_sub
+                parent_execution_id, security_context_name, initiating_user_sub, runtime_image_digest, labels
             FROM executions
             WHERE tenant_id = $1 AND id = $2
             "#,
         )
         .bind(tenant_id.as_str())
         .bind(id.0)
-        .fetch_optional(&self.pool)
+        .fetch_optional(self.pools.primary())
         .await
         .map_err(|e| {
             tracing::error!(
@@ -206,6 +327,12 @@ impl ExecutionRepository for PostgresExecutionRepository {
             let parent_execution_id: Option<uuid::Uuid> = row.get("parent_execution_id");
             let security_context_name: String = row.get("security_context_name");
             let initiating_user_sub: Option<String> = row.get("initiating_user_sub");
+            let runtime_image_digest: Option<String> = row.get("runtime_image_digest");
+            let labels_val: serde_json::Value = row.get("labels");
+            let labels: BTreeMap<String, String> =
+                serde_json::from_value(labels_val).map_err(|e| {
+                    RepositoryError::Serialization(format!("Failed to deserialize labels: {e}"))
+                })?;
 
             let status = match status_str.as_str() {
                 "pending" => Ok(ExecutionStatus::Pending),
@@ -283,6 +410,8 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 hierarchy,
                 security_context_name,
                 initiating_user_sub,
+                runtime_image_digest,
+                labels,
             }))
         } else {
             Ok(None)
@@ -301,17 +430,17 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 id, agent_id, input, status, iterations, max_iterations,
                 container_uid, container_gid,
                 started_at, completed_at, error_message, parent_execution_id,
-                security_context_name, initiating_user_sub
+                security_context_name, initiating_user_sub, runtime_image_digest, labels
             FROM executions
             WHERE tenant_id = $1 AND agent_id = $2
-            ORDER BY started_at DESC
+            ORDER BY started_at DESC, id DESC
             LIMIT $3
             "#,
         )
         .bind(tenant_id.as_str())
         .bind(agent_id.0)
         .bind(limit as i64)
-        .fetch_all(&self.pool)
+        .fetch_all(self.pools.read(ReadConsistency::Eventual))
         .await
         .map_err(|e| RepositoryError::Database(e.to_string()))?;
 
@@ -332,6 +461,12 @@ impl ExecutionRepository for PostgresExecutionRepository {
             let parent_execution_id: Option<uuid::Uuid> = row.get("parent_execution_id");
             let security_context_name: String = row.get("security_context_name");
             let initiating_user_sub: Option<String> = row.get("initiating_user_sub");
+            let runtime_image_digest: Option<String> = row.get("runtime_image_digest");
+            let labels_val: serde_json::Value = row.get("labels");
+            let labels: BTreeMap<String, String> =
+                serde_json::from_value(labels_val).map_err(|e| {
+                    RepositoryError::Serialization(format!("Failed to deserialize labels: {e}"))
+                })?;
 
             let status = match status_str.as_str() {
                 "pending" => Ok(ExecutionStatus::Pending),
@@ -398,6 +533,8 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 hierarchy,
                 security_context_name,
                 initiating_user_sub,
+                runtime_image_digest,
+                labels,
             });
         }
 
@@ -416,18 +553,18 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 e.id, e.agent_id, e.input, e.status, e.iterations, e.max_iterations,
                 e.container_uid, e.container_gid,
                 e.started_at, e.completed_at, e.error_message, e.parent_execution_id,
-                e.security_context_name, e.initiating_user_sub
+                e.security_context_name, e.initiating_user_sub, e.runtime_image_digest, e.labels
             FROM executions e
             INNER JOIN workflow_executions we ON e.workflow_execution_id = we.id
             WHERE e.tenant_id = $1 AND we.workflow_id = $2
-            ORDER BY e.started_at DESC
+            ORDER BY e.started_at DESC, e.id DESC
             LIMIT $3
             "#,
         )
         .bind(tenant_id.as_str())
         .bind(workflow_id.0)
         .bind(limit as i64)
-        .fetch_all(&self.pool)
+        .fetch_all(self.pools.read(ReadConsistency::Eventual))
         .await
         .map_err(|e| RepositoryError::Database(e.to_string()))?;
 
@@ -447,6 +584,12 @@ impl ExecutionRepository for PostgresExecutionRepository {
             let parent_execution_id: Option<uuid::Uuid> = row.get("parent_execution_id");
             let security_context_name: String = row.get("security_context_name");
             let initiating_user_sub: Option<String> = row.get("initiating_user_sub");
+            let runtime_image_digest: Option<String> = row.get("runtime_image_digest");
+            let labels_val: serde_json::Value = row.get("labels");
+            let labels: BTreeMap<String, String> =
+                serde_json::from_value(labels_val).map_err(|e| {
+                    RepositoryError::Serialization(format!("Failed to deserialize labels: {e}"))
+                })?;
 
             let status = match status_str.as_str() {
                 "pending" => Ok(ExecutionStatus::Pending),
@@ -513,6 +656,8 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 hierarchy,
                 security_context_name,
                 initiating_user_sub,
+                runtime_image_digest,
+                labels,
             });
         }
 
@@ -530,16 +675,16 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 id, agent_id, input, status, iterations, max_iterations,
                 container_uid, container_gid,
                 started_at, completed_at, error_message, parent_execution_id,
-                security_context_name, initiating_user_sub
+                security_context_name, initiating_user_sub, runtime_image_digest, labels
             FROM executions
             WHERE tenant_id = $1
-            ORDER BY started_at DESC
+            ORDER BY started_at DESC, id DESC
             LIMIT $2
             "#,
         )
         .bind(tenant_id.as_str())
         .bind(limit as i64)
-        .fetch_all(&self.pool)
+        .fetch_all(self.pools.read(ReadConsistency::Eventual))
         .await
         .map_err(|e| RepositoryError::Database(e.to_string()))?;
 
@@ -559,6 +704,12 @@ impl ExecutionRepository for PostgresExecutionRepository {
             let parent_execution_id: Option<uuid::Uuid> = row.get("parent_execution_id");
             let security_context_name: String = row.get("security_context_name");
             let initiating_user_sub: Option<String> = row.get("initiating_user_sub");
+            let runtime_image_digest: Option<String> = row.get("runtime_image_digest");
+            let labels_val: serde_json::Value = row.get("labels");
+            let labels: BTreeMap<String, String> =
+                serde_json::from_value(labels_val).map_err(|e| {
+                    RepositoryError::Serialization(format!("Failed to deserialize labels: {e}"))
+                })?;
 
             let status = match status_str.as_str() {
                 "pending" => Ok(ExecutionStatus::Pending),
@@ -625,11 +776,127 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 hierarchy,
                 security_context_name,
                 initiating_user_sub,
+                runtime_image_digest,
+                labels,
             });
         }
         Ok(executions)
     }
 
+    async fn find_by_query_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        query: &ExecutionQuery,
+        limit: usize,
+    ) -> Result<Vec<Execution>, RepositoryError> {
+        // Every predicate maps onto an indexed column (see migrations 036/037);
+        // values are always bound, never interpolated.
+        let mut builder = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT
+                id, agent_id, input, status, iterations, max_iterations,
+                container_uid, container_gid,
+                started_at, completed_at, error_message, parent_execution_id,
+                security_context_name, initiating_user_sub, runtime_image_digest, labels
+            FROM executions
+            WHERE tenant_id = "#,
+        );
+        builder.push_bind(tenant_id.as_str());
+
+        for predicate in &query.predicates {
+            match predicate {
+                ExecutionPredicate::Status { op, status } => {
+                    builder
+                        .push(format!(" AND status {} ", op.as_sql()))
+                        .push_bind(status_as_str(status));
+                }
+                ExecutionPredicate::Agent {
+                    op,
+                    agent: AgentSelector::Id(agent_id),
+                } => {
+                    builder
+                        .push(format!(" AND agent_id {} ", op.as_sql()))
+                        .push_bind(agent_id.0);
+                }
+                ExecutionPredicate::Agent {
+                    agent: AgentSelector::Name(_),
+                    ..
+                } => {
+                    builder.push(" AND FALSE");
+                }
+                ExecutionPredicate::Started { op, at } => {
+                    builder
+                        .push(format!(" AND started_at {} ", op.as_sql()))
+                        .push_bind(*at);
+                }
+                ExecutionPredicate::Ended { op, at } => {
+                    builder
+                        .push(format!(" AND completed_at {} ", op.as_sql()))
+                        .push_bind(*at);
+                }
+                // `@>` and `?` are served by the GIN index on labels.
+                ExecutionPredicate::Label { op, key, value } => {
+                    builder.push(if *op == CompareOp::Ne {
+                        " AND NOT ("
+                    } else {
+                        " AND ("
+                    });
+                    match value {
+                        Some(value) => {
+                            builder
+                                .push("labels @> jsonb_build_object(")
+                                .push_bind(key.clone())
+                                .push("::text, ")
+                                .push_bind(value.clone())
+                                .push("::text))");
+                        }
+                        None => {
+                            builder.push("labels ? ").push_bind(key.clone()).push(")");
+                        }
+                    }
+                }
+            }
+        }
+
+        builder
+            .push(" ORDER BY started_at DESC, id DESC LIMIT ")
+            .push_bind(limit as i64);
+
+        let rows = builder
+            .build()
+            .fetch_all(self.pools.read(ReadConsistency::Eventual))
+            .await
+            .map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        rows.iter()
+            .map(|row| execution_from_row(row, tenant_id))
+            .collect()
+    }
+
+    async fn update_labels_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+        labels: &BTreeMap<String, String>,
+    ) -> Result<(), RepositoryError> {
+        let labels_json = serde_json::to_value(labels)
+            .map_err(|e| RepositoryError::Serialization(e.to_string()))?;
+        let result =
+            sqlx::query("UPDATE executions SET labels = $3 WHERE tenant_id = $1 AND id = $2")
+                .bind(tenant_id.as_str())
+                .bind(id.0)
+                .bind(labels_json)
+                .execute(self.pools.primary())
+                .await
+                .map_err(|e| RepositoryError::Database(e.to_string()))?;
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound(format!(
+                "Execution {id} not found"
+            )));
+        }
+        Ok(())
+    }
+
     async fn list_recent_all_paginated(
         &self,
         limit: usize,
@@ -641,15 +908,15 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 id, tenant_id, agent_id, input, status, iterations, max_iterations,
                 container_uid, container_gid,
                 started_at, completed_at, error_message, parent_execution_id,
-                security_context_name, initiating_user_sub
+                security_context_name, initiating_user_sub, runtime_image_digest, labels
             FROM executions
-            ORDER BY started_at DESC
+            ORDER BY started_at DESC, id DESC
             LIMIT $1 OFFSET $2
             "#,
         )
         .bind(limit as i64)
         .bind(offset as i64)
-        .fetch_all(&self.pool)
+        .fetch_all(self.pools.read(ReadConsistency::Eventual))
         .await
         .map_err(|e| RepositoryError::Database(e.to_string()))?;
 
@@ -670,6 +937,12 @@ impl ExecutionRepository for PostgresExecutionRepository {
             let parent_execution_id: Option<uuid::Uuid> = row.get("parent_execution_id");
             let security_context_name: String = row.get("security_context_name");
             let initiating_user_sub: Option<String> = row.get("initiating_user_sub");
+            let runtime_image_digest: Option<String> = row.get("runtime_image_digest");
+            let labels_val: serde_json::Value = row.get("labels");
+            let labels: BTreeMap<String, String> =
+                serde_json::from_value(labels_val).map_err(|e| {
+                    RepositoryError::Serialization(format!("Failed to deserialize labels: {e}"))
+                })?;
 
             let tenant_id = TenantId::from_string(&tenant_id_str).map_err(|e| {
                 RepositoryError::Serialization(format!("Invalid tenant_id in database: {e}"))
@@ -740,6 +1013,8 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 hierarchy,
                 security_context_name,
                 initiating_user_sub,
+                runtime_image_digest,
+                labels,
             });
         }
         Ok(executions)
@@ -753,7 +1028,7 @@ impl ExecutionRepository for PostgresExecutionRepository {
         sqlx::query("DELETE FROM executions WHERE tenant_id = $1 AND id = $2")
             .bind(tenant_id.as_str())
             .bind(id.0)
-            .execute(&self.pool)
+            .execute(self.pools.primary())
             .await
             .map_err(|e| RepositoryError::Database(e.to_string()))?;
         Ok(())
@@ -769,7 +1044,7 @@ impl ExecutionRepository for PostgresExecutionRepository {
         )
         .bind(tenant_id.as_str())
         .bind(agent_id.0)
-        .fetch_one(&self.pool)
+        .fetch_one(self.pools.read(ReadConsistency::Eventual))
         .await
         .map_err(|e| RepositoryError::Database(e.to_string()))?;
         Ok(count)
@@ -790,13 +1065,13 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 id, tenant_id, agent_id, input, status, iterations, max_iterations,
                 container_uid, container_gid,
                 started_at, completed_at, error_message,
-                parent_execution_id, security_context_name, initiating_user_sub
+                parent_execution_id, security_context_name, initiating_user_sub, runtime_image_digest, labels
             FROM executions
             WHERE id = $1
             "#,
         )
         .bind(id.0)
-        .fetch_optional(&self.pool)
+        .fetch_optional(self.pools.primary())
         .await
         .map_err(|e| RepositoryError::Database(e.to_string()))?;
 
@@ -816,6 +1091,12 @@ impl ExecutionRepository for PostgresExecutionRepository {
             let parent_execution_id: Option<uuid::Uuid> = row.get("parent_execution_id");
             let security_context_name: String = row.get("security_context_name");
             let initiating_user_sub: Option<String> = row.get("initiating_user_sub");
+            let runtime_image_digest: Option<String> = row.get("runtime_image_digest");
+            let labels_val: serde_json::Value = row.get("labels");
+            let labels: BTreeMap<String, String> =
+                serde_json::from_value(labels_val).map_err(|e| {
+                    RepositoryError::Serialization(format!("Failed to deserialize labels: {e}"))
+                })?;
 
             let tenant_id = TenantId::from_string(&tenant_id_str).map_err(|e| {
                 RepositoryError::Serialization(format!("Invalid tenant_id in database: {e}"))
@@ -885,6 +1166,8 @@ impl ExecutionRepository for PostgresExecutionRepository {
                 hierarchy,
                 security_context_name,
                 initiating_user_sub,
+                runtime_image_digest,
+                labels,
             }))
         } else {
             Ok(None)
@@ -899,9 +1182,39 @@ impl ExecutionRepository for PostgresExecutionRepository {
             "#,
         )
         .bind(tenant_id.as_str())
-        .fetch_one(&self.pool)
+        .fetch_one(self.pools.primary())
         .await
         .map_err(|e| RepositoryError::Database(e.to_string()))?;
         Ok(count.max(0) as u64)
     }
+
+    async fn find_unfinished_all(&self, limit: usize) -> Result<Vec<Execution>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, tenant_id, agent_id, input, status, iterations, max_iterations,
+                container_uid, container_gid,
+                started_at, completed_at, error_message, parent_execution_id,
+                security_context_name, initiating_user_sub, runtime_image_digest, labels
+            FROM executions
+            WHERE status IN ('running', 'pending')
+            ORDER BY started_at ASC, id ASC
+            LIMIT $1
+            "#,
+        )
+        .bind(limit as i64)
+        .fetch_all(self.pools.primary())
+        .await
+        .map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        rows.iter()
+            .map(|row| {
+                let tenant_id_str: String = row.get("tenant_id");
+                let tenant_id = TenantId::from_string(&tenant_id_str).map_err(|e| {
+                    RepositoryError::Serialization(format!("Invalid tenant_id in database: {e}"))
+                })?;
+                execution_from_row(row, &tenant_id)
+            })
+            .collect()
+    }
 }
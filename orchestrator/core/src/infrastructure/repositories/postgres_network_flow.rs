@@ -0,0 +1,119 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # PostgreSQL Network Flow Repository
+//!
+//! Production implementation of [`NetworkFlowRepository`] backed by the
+//! `execution_network_flows` table introduced in migration
+//! `043_execution_network_flows.sql`.
+
+use std::net::{IpAddr, SocketAddr};
+
+use async_trait::async_trait;
+use sqlx::postgres::{PgPool, PgRow};
+use sqlx::{Postgres, QueryBuilder, Row};
+
+use crate::domain::execution::ExecutionId;
+use crate::domain::network_flow::{FlowProtocol, NetworkFlow, NetworkFlowRepository};
+use crate::domain::repository::RepositoryError;
+
+pub struct PostgresNetworkFlowRepository {
+    pool: PgPool,
+}
+
+impl PostgresNetworkFlowRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn socket_addr(row: &PgRow, address: &str, port: &str) -> Result<SocketAddr, RepositoryError> {
+    let ip: String = row.get(address);
+    let ip: IpAddr = ip.parse().map_err(|e| {
+        RepositoryError::Serialization(format!("execution_network_flows.{address}: {e}"))
+    })?;
+    let port: i32 = row.get(port);
+    let port = u16::try_from(port).map_err(|e| {
+        RepositoryError::Serialization(format!("execution_network_flows.{address} port: {e}"))
+    })?;
+    Ok(SocketAddr::new(ip, port))
+}
+
+fn flow_from_row(row: &PgRow) -> Result<NetworkFlow, RepositoryError> {
+    let protocol: String = row.get("protocol");
+    Ok(NetworkFlow {
+        protocol: FlowProtocol::parse(&protocol).ok_or_else(|| {
+            RepositoryError::Serialization(format!(
+                "execution_network_flows.protocol: unknown '{protocol}'"
+            ))
+        })?,
+        source: socket_addr(row, "source_address", "source_port")?,
+        destination: socket_addr(row, "destination_address", "destination_port")?,
+        server_name: row.get("server_name"),
+        dns_query: row.get("dns_query"),
+        first_seen: row.get("first_seen"),
+        iteration_number: row
+            .get::<Option<i16>, _>("iteration_number")
+            .and_then(|n| u8::try_from(n).ok()),
+    })
+}
+
+#[async_trait]
+impl NetworkFlowRepository for PostgresNetworkFlowRepository {
+    async fn append(
+        &self,
+        execution_id: ExecutionId,
+        flows: &[NetworkFlow],
+    ) -> Result<(), RepositoryError> {
+        if flows.is_empty() {
+            return Ok(());
+        }
+        let mut builder = QueryBuilder::<Postgres>::new(
+            r#"
+            INSERT INTO execution_network_flows (
+                execution_id, iteration_number, protocol,
+                source_address, source_port, destination_address, destination_port,
+                server_name, dns_query, first_seen
+            )
+            "#,
+        );
+        builder.push_values(flows, |mut row, flow| {
+            row.push_bind(execution_id.0)
+                .push_bind(flow.iteration_number.map(i16::from))
+                .push_bind(flow.protocol.as_str())
+                .push_bind(flow.source.ip().to_string())
+                .push_bind(i32::from(flow.source.port()))
+                .push_bind(flow.destination.ip().to_string())
+                .push_bind(i32::from(flow.destination.port()))
+                .push_bind(flow.server_name.clone())
+                .push_bind(flow.dns_query.clone())
+                .push_bind(flow.first_seen);
+        });
+        builder.build().execute(&self.pool).await.map_err(|e| {
+            RepositoryError::Database(format!("insert execution_network_flows: {e}"))
+        })?;
+        Ok(())
+    }
+
+    async fn find_by_execution(
+        &self,
+        execution_id: ExecutionId,
+        limit: Option<usize>,
+    ) -> Result<Vec<NetworkFlow>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT iteration_number, protocol, source_address, source_port,
+                   destination_address, destination_port, server_name, dns_query, first_seen
+            FROM execution_network_flows
+            WHERE execution_id = $1
+            ORDER BY first_seen, id
+            LIMIT $2
+            "#,
+        )
+        .bind(execution_id.0)
+        .bind(limit.map(|l| i64::try_from(l).unwrap_or(i64::MAX)))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("read execution_network_flows: {e}")))?;
+        rows.iter().map(flow_from_row).collect()
+    }
+}
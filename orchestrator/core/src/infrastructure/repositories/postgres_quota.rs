@@ -0,0 +1,123 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # PostgreSQL Quota Repository
+//!
+//! Production implementation of [`QuotaRepository`] backed by the
+//! `tenant_quotas` table introduced in migration `042_tenant_quotas.sql`.
+
+use async_trait::async_trait;
+use sqlx::postgres::{PgPool, PgRow};
+use sqlx::Row;
+
+use crate::domain::quota::{QuotaDefinition, QuotaLimits, QuotaRepository};
+use crate::domain::repository::RepositoryError;
+use crate::domain::tenant::TenantId;
+
+pub struct PostgresQuotaRepository {
+    pool: PgPool,
+}
+
+impl PostgresQuotaRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn limit_from_db(value: Option<i64>) -> Option<u64> {
+    value.map(|v| u64::try_from(v).unwrap_or_default())
+}
+
+/// Limits above `i64::MAX` are stored as `i64::MAX`.
+fn limit_to_db(value: Option<u64>) -> Option<i64> {
+    value.map(|v| i64::try_from(v).unwrap_or(i64::MAX))
+}
+
+fn definition_from_row(row: &PgRow) -> Result<QuotaDefinition, RepositoryError> {
+    let tenant_id: String = row.get("tenant_id");
+    Ok(QuotaDefinition {
+        tenant_id: TenantId::from_string(&tenant_id)
+            .map_err(|e| RepositoryError::Serialization(format!("tenant_quotas.tenant_id: {e}")))?,
+        limits: QuotaLimits {
+            max_agents: limit_from_db(row.get("max_agents")),
+            max_concurrent_executions: limit_from_db(row.get("max_concurrent_executions")),
+            max_volume_bytes: limit_from_db(row.get("max_volume_bytes")),
+            monthly_token_budget: limit_from_db(row.get("monthly_token_budget")),
+        },
+        updated_by: row.get("updated_by"),
+        updated_at: row.get("updated_at"),
+    })
+}
+
+#[async_trait]
+impl QuotaRepository for PostgresQuotaRepository {
+    async fn get(&self, tenant_id: &TenantId) -> Result<Option<QuotaDefinition>, RepositoryError> {
+        let row = sqlx::query(
+            r#"
+            SELECT tenant_id, max_agents, max_concurrent_executions, max_volume_bytes,
+                   monthly_token_budget, updated_by, updated_at
+            FROM tenant_quotas
+            WHERE tenant_id = $1
+            "#,
+        )
+        .bind(tenant_id.as_str())
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("read tenant_quotas: {e}")))?;
+        row.as_ref().map(definition_from_row).transpose()
+    }
+
+    async fn list(&self) -> Result<Vec<QuotaDefinition>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT tenant_id, max_agents, max_concurrent_executions, max_volume_bytes,
+                   monthly_token_budget, updated_by, updated_at
+            FROM tenant_quotas
+            ORDER BY tenant_id
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("list tenant_quotas: {e}")))?;
+        rows.iter().map(definition_from_row).collect()
+    }
+
+    async fn upsert(&self, definition: &QuotaDefinition) -> Result<(), RepositoryError> {
+        let limits = &definition.limits;
+        sqlx::query(
+            r#"
+            INSERT INTO tenant_quotas (
+                tenant_id, max_agents, max_concurrent_executions, max_volume_bytes,
+                monthly_token_budget, updated_by, updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (tenant_id) DO UPDATE SET
+                max_agents = EXCLUDED.max_agents,
+                max_concurrent_executions = EXCLUDED.max_concurrent_executions,
+                max_volume_bytes = EXCLUDED.max_volume_bytes,
+                monthly_token_budget = EXCLUDED.monthly_token_budget,
+                updated_by = EXCLUDED.updated_by,
+                updated_at = EXCLUDED.updated_at
+            "#,
+        )
+        .bind(definition.tenant_id.as_str())
+        .bind(limit_to_db(limits.max_agents))
+        .bind(limit_to_db(limits.max_concurrent_executions))
+        .bind(limit_to_db(limits.max_volume_bytes))
+        .bind(limit_to_db(limits.monthly_token_budget))
+        .bind(&definition.updated_by)
+        .bind(definition.updated_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("upsert tenant_quotas: {e}")))?;
+        Ok(())
+    }
+
+    async fn delete(&self, tenant_id: &TenantId) -> Result<bool, RepositoryError> {
+        let result = sqlx::query("DELETE FROM tenant_quotas WHERE tenant_id = $1")
+            .bind(tenant_id.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Database(format!("delete tenant_quotas: {e}")))?;
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -13,7 +13,9 @@
 use crate::domain::events::StorageEvent;
 use crate::domain::execution::ExecutionId;
 use crate::domain::repository::{RepositoryError, StorageEventRepository};
+use crate::domain::storage::StorageErrorKind;
 use crate::domain::volume::VolumeId;
+use crate::infrastructure::db::{DatabasePools, ReadConsistency};
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use sqlx::{PgPool, Row};
@@ -21,12 +23,17 @@ use tracing::{debug, error, warn};
 use uuid::Uuid;
 
 pub struct PostgresStorageEventRepository {
-    pool: PgPool,
+    pools: DatabasePools,
 }
 
 impl PostgresStorageEventRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self::with_pools(DatabasePools::primary_only(pool))
+    }
+
+    /// Event queries may be served by the read replica in `pools`.
+    pub fn with_pools(pools: DatabasePools) -> Self {
+        Self { pools }
     }
 
     /// Helper to deserialize database row into StorageEvent
@@ -37,6 +44,10 @@ impl PostgresStorageEventRepository {
         let execution_id: Option<Uuid> = row.try_get("execution_id").unwrap_or(None);
         let workflow_execution_id: Option<Uuid> =
             row.try_get("workflow_execution_id").unwrap_or(None);
+        let iteration_number: Option<u8> = row
+            .try_get::<Option<i16>, _>("iteration_number")
+            .unwrap_or(None)
+            .and_then(|n| u8::try_from(n).ok());
         let volume_id = VolumeId(
             row.try_get::<Uuid, _>("volume_id")
                 .map_err(|e| RepositoryError::Database(format!("Missing volume_id: {e}")))?,
@@ -74,6 +85,7 @@ impl PostgresStorageEventRepository {
                 Ok(StorageEvent::FileOpened {
                     execution_id: exec_id,
                     workflow_execution_id,
+                    iteration_number,
                     volume_id,
                     path,
                     open_mode,
@@ -95,6 +107,7 @@ impl PostgresStorageEventRepository {
                 Ok(StorageEvent::FileRead {
                     execution_id: exec_id,
                     workflow_execution_id,
+                    iteration_number,
                     volume_id,
                     path,
                     offset,
@@ -118,6 +131,7 @@ impl PostgresStorageEventRepository {
                 Ok(StorageEvent::FileWritten {
                     execution_id: exec_id,
                     workflow_execution_id,
+                    iteration_number,
                     volume_id,
                     path,
                     offset,
@@ -131,6 +145,7 @@ impl PostgresStorageEventRepository {
             "FileClosed" => Ok(StorageEvent::FileClosed {
                 execution_id: exec_id,
                 workflow_execution_id,
+                iteration_number,
                 volume_id,
                 path,
                 closed_at: parse_timestamp("timestamp"),
@@ -145,6 +160,7 @@ impl PostgresStorageEventRepository {
                 Ok(StorageEvent::DirectoryListed {
                     execution_id: exec_id,
                     workflow_execution_id,
+                    iteration_number,
                     volume_id,
                     path,
                     entry_count,
@@ -156,6 +172,7 @@ impl PostgresStorageEventRepository {
             "FileCreated" => Ok(StorageEvent::FileCreated {
                 execution_id: exec_id,
                 workflow_execution_id,
+                iteration_number,
                 volume_id,
                 path,
                 created_at: parse_timestamp("timestamp"),
@@ -165,6 +182,7 @@ impl PostgresStorageEventRepository {
             "FileDeleted" => Ok(StorageEvent::FileDeleted {
                 execution_id: exec_id,
                 workflow_execution_id,
+                iteration_number,
                 volume_id,
                 path,
                 deleted_at: parse_timestamp("timestamp"),
@@ -191,6 +209,7 @@ impl PostgresStorageEventRepository {
                 Ok(StorageEvent::FilesystemPolicyViolation {
                     execution_id: exec_id,
                     workflow_execution_id,
+                    iteration_number,
                     volume_id,
                     operation,
                     path,
@@ -228,6 +247,35 @@ impl PostgresStorageEventRepository {
                 caller_node_id: None,
                 host_node_id: None,
             }),
+            "OperationFailed" => {
+                let operation = details
+                    .get("operation")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+                let cause = details
+                    .get("cause")
+                    .and_then(|v| serde_json::from_value(v.clone()).ok())
+                    .unwrap_or(StorageErrorKind::Io);
+                let error = details
+                    .get("error")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("")
+                    .to_string();
+                Ok(StorageEvent::OperationFailed {
+                    execution_id: exec_id,
+                    workflow_execution_id,
+                    iteration_number,
+                    volume_id,
+                    operation,
+                    path,
+                    cause,
+                    error,
+                    failed_at: parse_timestamp("timestamp"),
+                    caller_node_id: None,
+                    host_node_id: None,
+                })
+            }
             _ => {
                 warn!("Unknown storage event type: {}", event_type);
                 Err(RepositoryError::Database(format!(
@@ -248,6 +296,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                 StorageEvent::FileOpened {
                     execution_id,
                     workflow_execution_id,
+                    iteration_number: _,
                     volume_id,
                     path,
                     open_mode,
@@ -270,6 +319,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                 StorageEvent::FileRead {
                     execution_id,
                     workflow_execution_id,
+                    iteration_number: _,
                     volume_id,
                     path,
                     offset,
@@ -296,6 +346,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                 StorageEvent::FileWritten {
                     execution_id,
                     workflow_execution_id,
+                    iteration_number: _,
                     volume_id,
                     path,
                     offset,
@@ -322,6 +373,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                 StorageEvent::FileClosed {
                     execution_id,
                     workflow_execution_id,
+                    iteration_number: _,
                     volume_id,
                     path,
                     closed_at,
@@ -342,6 +394,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                 StorageEvent::DirectoryListed {
                     execution_id,
                     workflow_execution_id,
+                    iteration_number: _,
                     volume_id,
                     path,
                     entry_count,
@@ -364,6 +417,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                 StorageEvent::FileCreated {
                     execution_id,
                     workflow_execution_id,
+                    iteration_number: _,
                     volume_id,
                     path,
                     created_at,
@@ -384,6 +438,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                 StorageEvent::FileDeleted {
                     execution_id,
                     workflow_execution_id,
+                    iteration_number: _,
                     volume_id,
                     path,
                     deleted_at,
@@ -419,6 +474,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                 StorageEvent::FilesystemPolicyViolation {
                     execution_id,
                     workflow_execution_id,
+                    iteration_number: _,
                     volume_id,
                     operation,
                     path,
@@ -482,6 +538,33 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                         "host_node_id": host_node_id,
                     }),
                 ),
+                StorageEvent::OperationFailed {
+                    execution_id,
+                    workflow_execution_id,
+                    iteration_number: _,
+                    volume_id,
+                    operation,
+                    path,
+                    cause,
+                    error,
+                    failed_at,
+                    caller_node_id,
+                    host_node_id,
+                } => (
+                    "OperationFailed",
+                    execution_id.map(|e| e.0),
+                    *workflow_execution_id,
+                    *volume_id,
+                    path.clone(),
+                    serde_json::json!({
+                        "operation": operation,
+                        "cause": cause,
+                        "error": error,
+                        "timestamp": failed_at.to_rfc3339(),
+                        "caller_node_id": caller_node_id,
+                        "host_node_id": host_node_id,
+                    }),
+                ),
             };
 
         // Insert into database.
@@ -489,17 +572,18 @@ impl StorageEventRepository for PostgresStorageEventRepository {
         // constraint added in migration 015). One must be non-null; the other must be null.
         sqlx::query(
             r#"
-            INSERT INTO storage_events (execution_id, workflow_execution_id, volume_id, event_type, path, operation_details)
-            VALUES ($1, $2, $3, $4, $5, $6)
+            INSERT INTO storage_events (execution_id, workflow_execution_id, iteration_number, volume_id, event_type, path, operation_details)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             "#,
         )
         .bind(execution_id)
         .bind(workflow_execution_id)
+        .bind(event.iteration_number().map(i16::from))
         .bind(volume_id.0)
         .bind(event_type)
         .bind(path)
         .bind(details)
-        .execute(&self.pool)
+        .execute(self.pools.primary())
         .await
         .map_err(|e| {
             error!("Failed to save storage event: {}", e);
@@ -522,7 +606,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
 
         let rows = sqlx::query(
             r#"
-            SELECT event_type, execution_id, workflow_execution_id, volume_id, path, operation_details, timestamp
+            SELECT event_type, execution_id, workflow_execution_id, iteration_number, volume_id, path, operation_details, timestamp
             FROM storage_events
             WHERE execution_id = $1
             ORDER BY timestamp DESC
@@ -531,7 +615,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
         )
         .bind(execution_id.0)
         .bind(limit)
-        .fetch_all(&self.pool)
+        .fetch_all(self.pools.read(ReadConsistency::Eventual))
         .await
         .map_err(|e| RepositoryError::Database(e.to_string()))?;
 
@@ -564,7 +648,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
 
         let rows = sqlx::query(
             r#"
-            SELECT event_type, execution_id, workflow_execution_id, volume_id, path, operation_details, timestamp
+            SELECT event_type, execution_id, workflow_execution_id, iteration_number, volume_id, path, operation_details, timestamp
             FROM storage_events
             WHERE volume_id = $1
             ORDER BY timestamp DESC
@@ -573,7 +657,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
         )
         .bind(volume_id.0)
         .bind(limit)
-        .fetch_all(&self.pool)
+        .fetch_all(self.pools.read(ReadConsistency::Eventual))
         .await
         .map_err(|e| RepositoryError::Database(e.to_string()))?;
 
@@ -603,7 +687,7 @@ impl StorageEventRepository for PostgresStorageEventRepository {
         let rows = if let Some(exec_id) = execution_id {
             sqlx::query(
                 r#"
-                SELECT event_type, execution_id, workflow_execution_id, volume_id, path, operation_details, timestamp
+                SELECT event_type, execution_id, workflow_execution_id, iteration_number, volume_id, path, operation_details, timestamp
                 FROM storage_events
                 WHERE execution_id = $1
                   AND event_type IN ('PathTraversalBlocked', 'FilesystemPolicyViolation', 'QuotaExceeded', 'UnauthorizedVolumeAccess')
@@ -611,19 +695,19 @@ impl StorageEventRepository for PostgresStorageEventRepository {
                 "#,
             )
             .bind(exec_id.0)
-            .fetch_all(&self.pool)
+            .fetch_all(self.pools.read(ReadConsistency::Eventual))
             .await
         } else {
             sqlx::query(
                 r#"
-                SELECT event_type, execution_id, workflow_execution_id, volume_id, path, operation_details, timestamp
+                SELECT event_type, execution_id, workflow_execution_id, iteration_number, volume_id, path, operation_details, timestamp
                 FROM storage_events
                 WHERE event_type IN ('PathTraversalBlocked', 'FilesystemPolicyViolation', 'QuotaExceeded', 'UnauthorizedVolumeAccess')
                 ORDER BY timestamp DESC
                 LIMIT 100
                 "#,
             )
-            .fetch_all(&self.pool)
+            .fetch_all(self.pools.read(ReadConsistency::Eventual))
             .await
         };
 
@@ -668,6 +752,7 @@ mod tests {
         let wf_event = StorageEvent::FileRead {
             execution_id: None,
             workflow_execution_id: Some(wf_id),
+            iteration_number: None,
             volume_id: vol_id,
             path: "/workspace/data.txt".to_string(),
             offset: 0,
@@ -694,6 +779,7 @@ mod tests {
         let agent_event = StorageEvent::FileRead {
             execution_id: Some(exec_id),
             workflow_execution_id: None,
+            iteration_number: None,
             volume_id: vol_id,
             path: "/workspace/data.txt".to_string(),
             offset: 0,
@@ -0,0 +1,179 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # PostgreSQL Token Usage Repository
+//!
+//! Production implementation of [`TokenUsageRepository`] backed by the
+//! `llm_token_usage` (raw, one row per LLM call) and `llm_token_usage_daily`
+//! (nightly summary) tables introduced in migration `035_llm_token_usage.sql`.
+
+use async_trait::async_trait;
+use chrono::{NaiveDate, NaiveTime};
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::domain::agent::AgentId;
+use crate::domain::repository::RepositoryError;
+use crate::domain::tenant::TenantId;
+use crate::domain::token_usage::{
+    DailyUsageSummary, TokenUsageRecord, TokenUsageRepository, UsageGroupBy, UsageQuery,
+    UsageRollup,
+};
+
+pub struct PostgresTokenUsageRepository {
+    pool: PgPool,
+}
+
+impl PostgresTokenUsageRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn group_key_sql(group_by: UsageGroupBy) -> &'static str {
+    match group_by {
+        UsageGroupBy::Execution => "execution_id::text",
+        UsageGroupBy::Agent => "agent_id::text",
+        UsageGroupBy::Day => "to_char(recorded_at AT TIME ZONE 'UTC', 'YYYY-MM-DD')",
+        UsageGroupBy::Tenant => "tenant_id",
+    }
+}
+
+fn as_u64(value: i64) -> u64 {
+    u64::try_from(value).unwrap_or_default()
+}
+
+#[async_trait]
+impl TokenUsageRepository for PostgresTokenUsageRepository {
+    async fn record(&self, record: &TokenUsageRecord) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO llm_token_usage (
+                execution_id, tenant_id, agent_id, iteration_number,
+                provider, model, input_tokens, output_tokens, recorded_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+        )
+        .bind(record.execution_id.0)
+        .bind(record.tenant_id.as_str())
+        .bind(record.agent_id.0)
+        .bind(i16::from(record.iteration_number))
+        .bind(&record.provider)
+        .bind(&record.model)
+        .bind(i64::from(record.input_tokens))
+        .bind(i64::from(record.output_tokens))
+        .bind(record.recorded_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("insert llm_token_usage: {e}")))?;
+        Ok(())
+    }
+
+    async fn rollup(&self, query: &UsageQuery) -> Result<Vec<UsageRollup>, RepositoryError> {
+        // The group key expression comes from a closed match, never from input.
+        let sql = format!(
+            r#"
+            SELECT {key} AS key,
+                   COALESCE(SUM(input_tokens), 0)::BIGINT AS input_tokens,
+                   COALESCE(SUM(output_tokens), 0)::BIGINT AS output_tokens,
+                   COUNT(*)::BIGINT AS calls
+            FROM llm_token_usage
+            WHERE tenant_id = $1
+              AND recorded_at >= $2
+              AND recorded_at < $3
+              AND ($4::uuid IS NULL OR agent_id = $4)
+              AND ($5::uuid IS NULL OR execution_id = $5)
+            GROUP BY 1
+            ORDER BY 1
+            "#,
+            key = group_key_sql(query.group_by)
+        );
+        let rows = sqlx::query(&sql)
+            .bind(query.tenant_id.as_str())
+            .bind(query.from)
+            .bind(query.to)
+            .bind(query.agent_id.map(|id| id.0))
+            .bind(query.execution_id.map(|id| id.0))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Database(format!("rollup llm_token_usage: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| UsageRollup {
+                key: row.get("key"),
+                input_tokens: as_u64(row.get("input_tokens")),
+                output_tokens: as_u64(row.get("output_tokens")),
+                calls: as_u64(row.get("calls")),
+            })
+            .collect())
+    }
+
+    async fn aggregate_day(&self, day: NaiveDate) -> Result<u64, RepositoryError> {
+        let start = day.and_time(NaiveTime::MIN).and_utc();
+        let end = start + chrono::Duration::days(1);
+
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM llm_token_usage_daily WHERE day = $1")
+            .bind(day)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::Database(format!("clear llm_token_usage_daily: {e}")))?;
+        let inserted = sqlx::query(
+            r#"
+            INSERT INTO llm_token_usage_daily (
+                day, tenant_id, agent_id, model, input_tokens, output_tokens, calls
+            )
+            SELECT $1, tenant_id, agent_id, model,
+                   SUM(input_tokens)::BIGINT, SUM(output_tokens)::BIGINT, COUNT(*)::BIGINT
+            FROM llm_token_usage
+            WHERE recorded_at >= $2 AND recorded_at < $3
+            GROUP BY tenant_id, agent_id, model
+            "#,
+        )
+        .bind(day)
+        .bind(start)
+        .bind(end)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("aggregate llm_token_usage: {e}")))?;
+        tx.commit().await?;
+        Ok(inserted.rows_affected())
+    }
+
+    async fn daily_summary(
+        &self,
+        tenant_id: &TenantId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyUsageSummary>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT day, tenant_id, agent_id, model, input_tokens, output_tokens, calls
+            FROM llm_token_usage_daily
+            WHERE tenant_id = $1 AND day >= $2 AND day <= $3
+            ORDER BY day, model
+            "#,
+        )
+        .bind(tenant_id.as_str())
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("read llm_token_usage_daily: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| DailyUsageSummary {
+                day: row.get("day"),
+                tenant_id: row.get("tenant_id"),
+                agent_id: AgentId(row.get::<Uuid, _>("agent_id")),
+                model: row.get("model"),
+                input_tokens: as_u64(row.get("input_tokens")),
+                output_tokens: as_u64(row.get("output_tokens")),
+                calls: as_u64(row.get("calls")),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,146 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # PostgreSQL Usage Report Repository
+//!
+//! Production implementation of [`UsageReportRepository`] backed by the
+//! `usage_monthly` table introduced in migration `046_usage_monthly.sql`.
+
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+
+use crate::domain::repository::RepositoryError;
+use crate::domain::tenant::TenantId;
+use crate::domain::usage_report::{
+    month_bounds, month_of, MonthlyUsageSummary, UsageReportRepository,
+};
+
+pub struct PostgresUsageReportRepository {
+    pool: PgPool,
+}
+
+impl PostgresUsageReportRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn as_u64(value: i64) -> u64 {
+    u64::try_from(value).unwrap_or_default()
+}
+
+#[async_trait]
+impl UsageReportRepository for PostgresUsageReportRepository {
+    async fn aggregate_month(&self, month: NaiveDate) -> Result<u64, RepositoryError> {
+        let month = month_of(month);
+        let (start, end) = month_bounds(month);
+
+        // Volumes are only sampled while the month is current, and the
+        // stored value is the peak across samples.
+        let written = sqlx::query(
+            r#"
+            WITH execution_usage AS (
+                SELECT ex.tenant_id,
+                       COUNT(*)::BIGINT AS executions,
+                       COALESCE(SUM(steps.tool_calls), 0)::BIGINT AS tool_calls
+                FROM executions ex
+                CROSS JOIN LATERAL (
+                    SELECT COALESCE(SUM(
+                        CASE WHEN jsonb_typeof(it->'trajectory') = 'array'
+                             THEN jsonb_array_length(it->'trajectory')
+                             ELSE 0
+                        END
+                    ), 0) AS tool_calls
+                    FROM jsonb_array_elements(ex.iterations) AS it
+                ) steps
+                WHERE ex.started_at >= $2 AND ex.started_at < $3
+                GROUP BY ex.tenant_id
+            ),
+            token_usage AS (
+                SELECT tenant_id,
+                       SUM(input_tokens)::BIGINT AS input_tokens,
+                       SUM(output_tokens)::BIGINT AS output_tokens,
+                       COUNT(*)::BIGINT AS llm_calls
+                FROM llm_token_usage
+                WHERE recorded_at >= $2 AND recorded_at < $3
+                GROUP BY tenant_id
+            ),
+            storage AS (
+                SELECT tenant_id, SUM(size_limit_bytes)::BIGINT AS storage_bytes
+                FROM volumes
+                WHERE NOW() < $3 AND status #>> '{}' <> 'deleted'
+                GROUP BY tenant_id
+            ),
+            tenants AS (
+                SELECT tenant_id FROM execution_usage
+                UNION SELECT tenant_id FROM token_usage
+                UNION SELECT tenant_id FROM storage
+            )
+            INSERT INTO usage_monthly (
+                month, tenant_id, executions, tool_calls, input_tokens,
+                output_tokens, llm_calls, storage_bytes, aggregated_at
+            )
+            SELECT $1, t.tenant_id,
+                   COALESCE(e.executions, 0), COALESCE(e.tool_calls, 0),
+                   COALESCE(u.input_tokens, 0), COALESCE(u.output_tokens, 0),
+                   COALESCE(u.llm_calls, 0), COALESCE(s.storage_bytes, 0), NOW()
+            FROM tenants t
+            LEFT JOIN execution_usage e USING (tenant_id)
+            LEFT JOIN token_usage u USING (tenant_id)
+            LEFT JOIN storage s USING (tenant_id)
+            ON CONFLICT (month, tenant_id) DO UPDATE SET
+                executions = EXCLUDED.executions,
+                tool_calls = EXCLUDED.tool_calls,
+                input_tokens = EXCLUDED.input_tokens,
+                output_tokens = EXCLUDED.output_tokens,
+                llm_calls = EXCLUDED.llm_calls,
+                storage_bytes = GREATEST(usage_monthly.storage_bytes, EXCLUDED.storage_bytes),
+                aggregated_at = EXCLUDED.aggregated_at
+            "#,
+        )
+        .bind(month)
+        .bind(start)
+        .bind(end)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("aggregate usage_monthly: {e}")))?;
+        Ok(written.rows_affected())
+    }
+
+    async fn monthly_summary(
+        &self,
+        month: NaiveDate,
+        tenant_id: Option<&TenantId>,
+    ) -> Result<Vec<MonthlyUsageSummary>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT month, tenant_id, executions, tool_calls, input_tokens,
+                   output_tokens, llm_calls, storage_bytes, aggregated_at
+            FROM usage_monthly
+            WHERE month = $1 AND ($2::text IS NULL OR tenant_id = $2)
+            ORDER BY tenant_id
+            "#,
+        )
+        .bind(month_of(month))
+        .bind(tenant_id.map(|id| id.as_str()))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("read usage_monthly: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| MonthlyUsageSummary {
+                month: row.get("month"),
+                tenant_id: row.get("tenant_id"),
+                executions: as_u64(row.get("executions")),
+                tool_calls: as_u64(row.get("tool_calls")),
+                input_tokens: as_u64(row.get("input_tokens")),
+                output_tokens: as_u64(row.get("output_tokens")),
+                llm_calls: as_u64(row.get("llm_calls")),
+                storage_bytes: as_u64(row.get("storage_bytes")),
+                aggregated_at: row.get("aggregated_at"),
+            })
+            .collect())
+    }
+}
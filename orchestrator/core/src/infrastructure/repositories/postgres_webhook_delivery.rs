@@ -0,0 +1,177 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # PostgreSQL Webhook Delivery Repository
+//!
+//! Production implementation of [`WebhookDeliveryRepository`] backed by the
+//! `webhook_delivery_attempts` and `webhook_dead_letters` tables introduced
+//! in migration `038_webhook_deliveries.sql`.
+
+use async_trait::async_trait;
+use sqlx::postgres::{PgPool, PgRow};
+use sqlx::Row;
+
+use crate::domain::outbound_webhook::{
+    DeadLetter, DeliveryAttempt, DeliveryOutcome, WebhookDeliveryRepository,
+};
+use crate::domain::repository::RepositoryError;
+use crate::domain::tenant::TenantId;
+
+pub struct PostgresWebhookDeliveryRepository {
+    pool: PgPool,
+}
+
+impl PostgresWebhookDeliveryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+fn as_limit(limit: usize) -> i64 {
+    i64::try_from(limit).unwrap_or(i64::MAX)
+}
+
+fn attempt_from_row(row: &PgRow, tenant_id: &TenantId) -> Result<DeliveryAttempt, RepositoryError> {
+    let outcome: String = row.get("outcome");
+    Ok(DeliveryAttempt {
+        delivery_id: row.get("delivery_id"),
+        tenant_id: tenant_id.clone(),
+        destination: row.get("destination"),
+        source: row.get("source"),
+        attempt: u32::try_from(row.get::<i32, _>("attempt")).unwrap_or_default(),
+        status_code: row
+            .get::<Option<i32>, _>("status_code")
+            .and_then(|code| u16::try_from(code).ok()),
+        error: row.get("error"),
+        outcome: DeliveryOutcome::parse(&outcome).ok_or_else(|| {
+            RepositoryError::Serialization(format!("unknown delivery outcome '{outcome}'"))
+        })?,
+        duration_ms: u64::try_from(row.get::<i64, _>("duration_ms")).unwrap_or_default(),
+        attempted_at: row.get("attempted_at"),
+    })
+}
+
+#[async_trait]
+impl WebhookDeliveryRepository for PostgresWebhookDeliveryRepository {
+    async fn record_attempt(&self, attempt: &DeliveryAttempt) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_delivery_attempts (
+                delivery_id, tenant_id, destination, source, attempt,
+                status_code, error, outcome, duration_ms, attempted_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            "#,
+        )
+        .bind(attempt.delivery_id)
+        .bind(attempt.tenant_id.as_str())
+        .bind(&attempt.destination)
+        .bind(&attempt.source)
+        .bind(i32::try_from(attempt.attempt).unwrap_or(i32::MAX))
+        .bind(attempt.status_code.map(i32::from))
+        .bind(&attempt.error)
+        .bind(attempt.outcome.as_str())
+        .bind(i64::try_from(attempt.duration_ms).unwrap_or(i64::MAX))
+        .bind(attempt.attempted_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("insert webhook_delivery_attempts: {e}")))?;
+        Ok(())
+    }
+
+    async fn dead_letter(&self, dead_letter: &DeadLetter) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO webhook_dead_letters (
+                delivery_id, tenant_id, destination, source, method,
+                content_type, body, attempts, last_error, dead_lettered_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            ON CONFLICT (delivery_id) DO NOTHING
+            "#,
+        )
+        .bind(dead_letter.delivery_id)
+        .bind(dead_letter.tenant_id.as_str())
+        .bind(&dead_letter.destination)
+        .bind(&dead_letter.source)
+        .bind(&dead_letter.method)
+        .bind(&dead_letter.content_type)
+        .bind(&dead_letter.body)
+        .bind(i32::try_from(dead_letter.attempts).unwrap_or(i32::MAX))
+        .bind(&dead_letter.last_error)
+        .bind(dead_letter.dead_lettered_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("insert webhook_dead_letters: {e}")))?;
+        Ok(())
+    }
+
+    async fn list_attempts(
+        &self,
+        tenant_id: &TenantId,
+        destination: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<DeliveryAttempt>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT delivery_id, destination, source, attempt, status_code,
+                   error, outcome, duration_ms, attempted_at
+            FROM webhook_delivery_attempts
+            WHERE tenant_id = $1
+              AND ($2::text IS NULL OR destination = $2)
+            ORDER BY attempted_at DESC, id DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(tenant_id.as_str())
+        .bind(destination)
+        .bind(as_limit(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("read webhook_delivery_attempts: {e}")))?;
+
+        rows.iter()
+            .map(|row| attempt_from_row(row, tenant_id))
+            .collect()
+    }
+
+    async fn list_dead_letters(
+        &self,
+        tenant_id: &TenantId,
+        destination: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<DeadLetter>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT delivery_id, destination, source, method, content_type,
+                   body, attempts, last_error, dead_lettered_at
+            FROM webhook_dead_letters
+            WHERE tenant_id = $1
+              AND ($2::text IS NULL OR destination = $2)
+            ORDER BY dead_lettered_at DESC
+            LIMIT $3
+            "#,
+        )
+        .bind(tenant_id.as_str())
+        .bind(destination)
+        .bind(as_limit(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("read webhook_dead_letters: {e}")))?;
+
+        Ok(rows
+            .iter()
+            .map(|row| DeadLetter {
+                delivery_id: row.get("delivery_id"),
+                tenant_id: tenant_id.clone(),
+                destination: row.get("destination"),
+                source: row.get("source"),
+                method: row.get("method"),
+                content_type: row.get("content_type"),
+                body: row.get("body"),
+                attempts: u32::try_from(row.get::<i32, _>("attempts")).unwrap_or_default(),
+                last_error: row.get("last_error"),
+                dead_lettered_at: row.get("dead_lettered_at"),
+            })
+            .collect())
+    }
+}
@@ -27,6 +27,11 @@
 //! Rust mirrors high-level progress from Temporal event callbacks:
 //! - **Current State**: Last reported active state (updated by `TemporalEventListener`)
 //! - **State History**: Ordered list of visited states (for audit/Cortex)
+//! - **Transition History**: Per-state entry/exit records with output summaries
+//!   and triggering condition in `workflow_execution_transitions`, retained
+//!   independently of Temporal history
+//! - **Transition Blackboards**: Blackboard snapshot reported on state entry,
+//!   stored on the matching transition row for step-through debugging
 //! - **Final Blackboard**: Snapshot captured from `WorkflowExecutionCompleted` (not mutated mid-run)
 //! - **Transitions**: Evaluated inside the TypeScript worker, not by this repository
 //!
@@ -55,7 +60,9 @@
 use crate::domain::execution::{ExecutionId, ExecutionStatus};
 use crate::domain::repository::{RepositoryError, WorkflowExecutionRepository};
 use crate::domain::tenant::TenantId;
-use crate::domain::workflow::{Blackboard, StateName, WorkflowExecution, WorkflowId};
+use crate::domain::workflow::{
+    Blackboard, StateName, WorkflowExecution, WorkflowId, WorkflowTransitionRecord,
+};
 use anyhow::Result;
 use async_trait::async_trait;
 use sqlx::postgres::PgPool;
@@ -329,6 +336,7 @@ impl WorkflowExecutionRepository for PostgresWorkflowExecutionRepository {
                 started_at, last_transition_at
             FROM workflow_executions
             WHERE tenant_id = $1 AND status = 'running'
+            ORDER BY started_at DESC, id DESC
             "#,
         )
         .bind(tenant_id.as_str())
@@ -388,6 +396,74 @@ impl WorkflowExecutionRepository for PostgresWorkflowExecutionRepository {
         Ok(executions)
     }
 
+    async fn find_active_all(&self) -> Result<Vec<WorkflowExecution>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT
+                id, tenant_id, workflow_id, input_params,
+                current_state, blackboard, state_outputs,
+                final_output,
+                started_at, last_transition_at
+            FROM workflow_executions
+            WHERE status = 'running'
+            ORDER BY started_at DESC, id DESC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(e.to_string()))?;
+
+        let mut executions = Vec::new();
+        for row in rows {
+            let id: uuid::Uuid = row.get("id");
+            let tenant_id_str: String = row.get("tenant_id");
+            let workflow_id: uuid::Uuid = row.get("workflow_id");
+            let input_val: serde_json::Value = row.get("input_params");
+            let current_state_str: String = row.get("current_state");
+            let blackboard_val: serde_json::Value = row.get("blackboard");
+            let state_outputs_val: serde_json::Value = row.get("state_outputs");
+            let final_output: Option<serde_json::Value> = row.get("final_output");
+            let started_at: chrono::DateTime<chrono::Utc> = row.get("started_at");
+            let last_transition_at: chrono::DateTime<chrono::Utc> = row.get("last_transition_at");
+
+            let tenant_id = TenantId::from_string(&tenant_id_str).map_err(|e| {
+                RepositoryError::Serialization(format!("Invalid tenant_id in database: {e}"))
+            })?;
+
+            let blackboard =
+                Blackboard::from_json(&blackboard_val).unwrap_or_else(|_| Blackboard::new());
+
+            let state_outputs: HashMap<StateName, serde_json::Value> = state_outputs_val
+                .as_object()
+                .map(|obj| {
+                    obj.iter()
+                        .filter_map(|(k, v)| {
+                            StateName::new(k)
+                                .ok()
+                                .map(|state_name| (state_name, v.clone()))
+                        })
+                        .collect()
+                })
+                .unwrap_or_default();
+
+            executions.push(WorkflowExecution {
+                id: ExecutionId(id),
+                workflow_id: WorkflowId(workflow_id),
+                tenant_id,
+                status: ExecutionStatus::Running,
+                current_state: StateName::new(&current_state_str)
+                    .unwrap_or_else(|_| StateName::new("start").unwrap()),
+                blackboard,
+                input: input_val,
+                state_outputs,
+                final_output,
+                started_at,
+                last_transition_at,
+            });
+        }
+        Ok(executions)
+    }
+
     async fn find_by_workflow_for_tenant(
         &self,
         tenant_id: &TenantId,
@@ -404,7 +480,7 @@ impl WorkflowExecutionRepository for PostgresWorkflowExecutionRepository {
                 started_at, last_transition_at
             FROM workflow_executions
             WHERE tenant_id = $1 AND workflow_id = $2
-            ORDER BY started_at DESC
+            ORDER BY started_at DESC, id DESC
             LIMIT $3 OFFSET $4
             "#,
         )
@@ -567,6 +643,219 @@ impl WorkflowExecutionRepository for PostgresWorkflowExecutionRepository {
         Ok(records)
     }
 
+    async fn append_transition(
+        &self,
+        execution_id: ExecutionId,
+        record: &WorkflowTransitionRecord,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            INSERT INTO workflow_execution_transitions (
+                execution_id, sequence_number, state_name, triggered_by,
+                entered_at, exited_at, output_summary
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (execution_id, sequence_number) DO NOTHING
+            "#,
+        )
+        .bind(execution_id.0)
+        .bind(record.sequence)
+        .bind(&record.state_name)
+        .bind(&record.triggered_by)
+        .bind(record.entered_at)
+        .bind(record.exited_at)
+        .bind(&record.output_summary)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            RepositoryError::Database(format!("Failed to append workflow transition: {e}"))
+        })?;
+
+        Ok(())
+    }
+
+    async fn close_transition(
+        &self,
+        execution_id: ExecutionId,
+        state_name: &str,
+        exited_at: chrono::DateTime<chrono::Utc>,
+        output_summary: Option<String>,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            UPDATE workflow_execution_transitions
+            SET exited_at = $3,
+                output_summary = $4
+            WHERE execution_id = $1
+              AND sequence_number = (
+                  SELECT MAX(sequence_number)
+                  FROM workflow_execution_transitions
+                  WHERE execution_id = $1 AND state_name = $2 AND exited_at IS NULL
+              )
+            "#,
+        )
+        .bind(execution_id.0)
+        .bind(state_name)
+        .bind(exited_at)
+        .bind(output_summary)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            RepositoryError::Database(format!("Failed to close workflow transition: {e}"))
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_transitions_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+    ) -> Result<Vec<WorkflowTransitionRecord>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT t.sequence_number, t.state_name, t.triggered_by,
+                   t.entered_at, t.exited_at, t.output_summary
+            FROM workflow_execution_transitions t
+            JOIN workflow_executions e ON e.id = t.execution_id
+            WHERE e.tenant_id = $1 AND t.execution_id = $2
+            ORDER BY t.sequence_number ASC
+            "#,
+        )
+        .bind(tenant_id.as_str())
+        .bind(id.0)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| {
+            RepositoryError::Database(format!("Failed to query workflow transitions: {e}"))
+        })?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| WorkflowTransitionRecord {
+                sequence: row.get("sequence_number"),
+                state_name: row.get("state_name"),
+                triggered_by: row.get("triggered_by"),
+                entered_at: row.get("entered_at"),
+                exited_at: row.get("exited_at"),
+                output_summary: row.get("output_summary"),
+            })
+            .collect())
+    }
+
+    async fn record_transition_blackboard(
+        &self,
+        execution_id: ExecutionId,
+        sequence: i64,
+        blackboard: &serde_json::Value,
+    ) -> Result<(), RepositoryError> {
+        sqlx::query(
+            r#"
+            UPDATE workflow_execution_transitions
+            SET blackboard = $3
+            WHERE execution_id = $1 AND sequence_number = $2
+            "#,
+        )
+        .bind(execution_id.0)
+        .bind(sequence)
+        .bind(blackboard)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| {
+            RepositoryError::Database(format!("Failed to record transition blackboard: {e}"))
+        })?;
+
+        Ok(())
+    }
+
+    async fn find_transition_blackboard_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+        sequence: i64,
+    ) -> Result<Option<serde_json::Value>, RepositoryError> {
+        let row = sqlx::query(
+            r#"
+            SELECT t.blackboard
+            FROM workflow_execution_transitions t
+            JOIN workflow_executions e ON e.id = t.execution_id
+            WHERE e.tenant_id = $1 AND t.execution_id = $2 AND t.sequence_number = $3
+            "#,
+        )
+        .bind(tenant_id.as_str())
+        .bind(id.0)
+        .bind(sequence)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| {
+            RepositoryError::Database(format!("Failed to query transition blackboard: {e}"))
+        })?;
+
+        Ok(row.and_then(|row| {
+            let blackboard: Option<serde_json::Value> = row.get("blackboard");
+            blackboard
+        }))
+    }
+
+    async fn record_handoffs(
+        &self,
+        execution_id: ExecutionId,
+        handoffs: &[crate::domain::workflow::WorkflowHandoff],
+    ) -> Result<(), RepositoryError> {
+        for handoff in handoffs {
+            sqlx::query(
+                r#"
+                INSERT INTO workflow_handoffs
+                    (execution_id, state_name, output_name, value, produced_at)
+                VALUES ($1, $2, $3, $4, $5)
+                ON CONFLICT (execution_id, state_name, output_name)
+                DO UPDATE SET value = EXCLUDED.value, produced_at = EXCLUDED.produced_at
+                "#,
+            )
+            .bind(execution_id.0)
+            .bind(&handoff.state_name)
+            .bind(&handoff.output_name)
+            .bind(&handoff.value)
+            .bind(handoff.produced_at)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::Database(format!("Failed to record handoff: {e}")))?;
+        }
+
+        Ok(())
+    }
+
+    async fn find_handoffs_for_tenant(
+        &self,
+        tenant_id: &TenantId,
+        id: ExecutionId,
+    ) -> Result<Vec<crate::domain::workflow::WorkflowHandoff>, RepositoryError> {
+        let rows = sqlx::query(
+            r#"
+            SELECT h.state_name, h.output_name, h.value, h.produced_at
+            FROM workflow_handoffs h
+            JOIN workflow_executions e ON e.id = h.execution_id
+            WHERE e.tenant_id = $1 AND h.execution_id = $2
+            ORDER BY h.state_name, h.output_name
+            "#,
+        )
+        .bind(tenant_id.as_str())
+        .bind(id.0)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::Database(format!("Failed to query handoffs: {e}")))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| crate::domain::workflow::WorkflowHandoff {
+                state_name: row.get("state_name"),
+                output_name: row.get("output_name"),
+                value: row.get("value"),
+                produced_at: row.get("produced_at"),
+            })
+            .collect())
+    }
+
     async fn count_by_workflow_for_tenant(
         &self,
         tenant_id: &TenantId,
@@ -598,7 +887,7 @@ impl WorkflowExecutionRepository for PostgresWorkflowExecutionRepository {
                 started_at, last_transition_at
             FROM workflow_executions
             WHERE tenant_id = $1
-            ORDER BY started_at DESC
+            ORDER BY started_at DESC, id DESC
             LIMIT $2 OFFSET $3
             "#,
         )
@@ -678,7 +967,7 @@ impl WorkflowExecutionRepository for PostgresWorkflowExecutionRepository {
                 final_output,
                 started_at, last_transition_at
             FROM workflow_executions
-            ORDER BY started_at DESC
+            ORDER BY started_at DESC, id DESC
             LIMIT $1 OFFSET $2
             "#,
         )
@@ -14,6 +14,7 @@
 //! - Pull or reuse agent container image
 //! - Apply `ResourceLimits` (CPU, memory) and `NetworkPolicy` (iptables allowlist)
 //! - Mount volumes via NFS (orchestrator-side NFS Server Gateway — ADR-036)
+//! - Register per-execution DNS names on user-defined networks
 //! - Stream container stdout/stderr to the execution event bus
 //! - Destroy container on execution completion or cancellation
 //!
@@ -33,22 +34,29 @@
 // ============================================================================
 
 use crate::domain::events::ImageManagementEvent;
+use crate::domain::network_flow::NetworkFlow;
 use crate::domain::runtime::{
     AgentRuntime, ContainerEngineKind, InstanceId, InstanceStatus, RuntimeConfig, RuntimeError,
-    TaskInput, TaskOutput,
+    SidecarConfig, SidecarOutput, TaskInput, TaskOutput,
 };
 use crate::infrastructure::event_bus::EventBus;
 use crate::infrastructure::image_manager::{
     CredentialResolver, DockerImageManager, StandardDockerImageManager,
 };
+use crate::infrastructure::image_verifier::ImageSignatureVerifier;
+use crate::infrastructure::network_flow_capture::{
+    capture_sidecar, parse_flows, CAPTURE_CAPABILITIES, CAPTURE_SIDECAR_NAME,
+};
 use async_trait::async_trait;
 use bollard::container::LogOutput;
 use bollard::exec::{CreateExecOptions, StartExecOptions, StartExecResults};
-use bollard::models::{ContainerCreateBody, Mount, MountTypeEnum};
+use bollard::models::{
+    ContainerCreateBody, EndpointSettings, Mount, MountTypeEnum, NetworkingConfig,
+};
 use bollard::query_parameters::{
-    CreateContainerOptions, KillContainerOptions, ListContainersOptionsBuilder, PruneImagesOptions,
-    RemoveContainerOptions, RemoveVolumeOptions, StartContainerOptions, StatsOptions,
-    StopContainerOptions, UploadToContainerOptions,
+    CreateContainerOptions, KillContainerOptions, ListContainersOptionsBuilder, LogsOptions,
+    PruneImagesOptions, RemoveContainerOptions, RemoveVolumeOptions, StartContainerOptions,
+    StatsOptions, StopContainerOptions, UploadToContainerOptions,
 };
 use bollard::Docker;
 use chrono::Utc;
@@ -80,8 +88,49 @@ pub fn connect_container_runtime(
     }
 }
 
+/// Connect to a configured container endpoint (`spec.runtime.endpoints[]`).
+///
+/// `tcp://` hosts use mutual TLS when `tls` is set and plain HTTP otherwise;
+/// `unix://` and bare paths are delegated to [`connect_container_runtime`].
+pub fn connect_container_endpoint(
+    endpoint: &crate::domain::node_config::ContainerEndpointConfig,
+) -> Result<Docker, bollard::errors::Error> {
+    let host = crate::domain::node_config::resolve_env_value(&endpoint.host)
+        .unwrap_or_else(|_| endpoint.host.clone());
+    if let Some(path) = host.strip_prefix("unix://") {
+        return connect_container_runtime(Some(path));
+    }
+    if !host.contains("://") {
+        return connect_container_runtime(Some(&host));
+    }
+    match &endpoint.tls {
+        Some(tls) => Docker::connect_with_ssl(
+            &host,
+            std::path::Path::new(&tls.client_key),
+            std::path::Path::new(&tls.client_cert),
+            std::path::Path::new(&tls.ca_cert),
+            120,
+            bollard::API_DEFAULT_VERSION,
+        ),
+        None => Docker::connect_with_http(&host, 120, bollard::API_DEFAULT_VERSION),
+    }
+}
+
+/// `os/arch` platform string from an engine `/version` report, normalising
+/// kernel-style architecture names (`x86_64`, `aarch64`) to OCI ones.
+pub fn engine_platform(os: Option<&str>, arch: Option<&str>) -> Option<String> {
+    let arch = match arch? {
+        "x86_64" => "amd64",
+        "aarch64" => "arm64",
+        other => other,
+    };
+    Some(format!("{}/{}", os.unwrap_or("linux").to_lowercase(), arch))
+}
+
 const AEGIS_CONTAINER_KIND_AGENT: &str = "agent";
 const AEGIS_CONTAINER_KIND_LABEL: &str = "aegis.container_kind";
+const AEGIS_CONTAINER_KIND_SIDECAR: &str = "sidecar";
+const AEGIS_SIDECAR_NAME_LABEL: &str = "aegis.sidecar";
 const AEGIS_EXECUTION_ID_LABEL: &str = "aegis.execution_id";
 const AEGIS_KEEP_CONTAINER_ON_FAILURE_LABEL: &str = "aegis.keep_container_on_failure";
 const AEGIS_MANAGED_LABEL: &str = "aegis.managed";
@@ -231,6 +280,8 @@ pub struct ContainerRuntime {
     image_manager: Arc<dyn DockerImageManager>,
     /// Event bus for publishing image management lifecycle events (ADR-045, ADR-030).
     event_bus: Arc<EventBus>,
+    /// Optional signature gate run after `ensure_image` and before container creation.
+    image_verifier: Option<Arc<dyn ImageSignatureVerifier>>,
     /// FUSE FSAL daemon for bind-mount-based volume access (ADR-107).
     fuse_daemon: Option<Arc<crate::infrastructure::fuse::daemon::FuseFsalDaemon>>,
     /// Host directory prefix for FUSE mountpoints (ADR-107).
@@ -257,6 +308,24 @@ pub struct ContainerRuntime {
     /// Without this, agent containers using gRPC FUSE mounts leave orphaned
     /// mountpoints that spam `DirectoryListed` events every 2 seconds.
     grpc_fuse_mounts: RwLock<HashMap<String, Vec<(String, String)>>>,
+    /// Sidecar containers keyed by the agent container ID they are attached to.
+    /// Started in `spawn()`, drained by `drain_sidecar_output()` and removed in
+    /// `terminate()` before the agent container.
+    sidecars: RwLock<HashMap<String, Vec<RunningSidecar>>>,
+    /// Platforms (`os/arch`) this engine runs containers for: the endpoint's
+    /// configured list, or the platform reported by `/version`.
+    platforms: Vec<String>,
+}
+
+/// A started sidecar container and its log cursor.
+struct RunningSidecar {
+    container_id: String,
+    stream: String,
+    /// Network flow capture rather than a manifest sidecar: its output is
+    /// returned by `drain_network_flows()` instead of as console output.
+    flow_capture: bool,
+    /// Unix timestamp passed as `since` on the next log drain.
+    logs_since: i64,
 }
 
 /// Configuration bundle for constructing a [`ContainerRuntime`].
@@ -266,6 +335,8 @@ pub struct ContainerRuntime {
 pub struct ContainerRuntimeConfig {
     pub bootstrap_script: String,
     pub socket_path: Option<String>,
+    /// Remote or additional engine to connect to instead of `socket_path`.
+    pub endpoint: Option<crate::domain::node_config::ContainerEndpointConfig>,
     pub network_mode: Option<String>,
     pub orchestrator_url: String,
     pub nfs_server_host: Option<String>,
@@ -273,6 +344,9 @@ pub struct ContainerRuntimeConfig {
     pub nfs_mountport: u16,
     pub event_bus: Arc<EventBus>,
     pub credential_resolver: Arc<dyn CredentialResolver>,
+    /// Image signature verifier built from `spec.image_verification`.
+    /// `None` runs every image without signature checks.
+    pub image_verifier: Option<Arc<dyn ImageSignatureVerifier>>,
     /// FUSE FSAL daemon for bind-mount-based volume access (ADR-107).
     /// When `Some`, the runtime uses FUSE + bind mounts instead of NFS volume
     /// driver mounts. Required for rootless container runtimes (Podman).
@@ -295,6 +369,7 @@ impl ContainerRuntime {
         let ContainerRuntimeConfig {
             bootstrap_script,
             socket_path,
+            endpoint,
             network_mode,
             orchestrator_url,
             nfs_server_host,
@@ -302,6 +377,7 @@ impl ContainerRuntime {
             nfs_mountport,
             event_bus,
             credential_resolver,
+            image_verifier,
             fuse_daemon,
             fuse_mount_prefix,
             fuse_mount_client,
@@ -333,7 +409,10 @@ impl ContainerRuntime {
 
         info!("Using bootstrap script: {}", bootstrap_path.display());
         // Connect to container runtime (Docker or Podman) — custom socket or auto-detect
-        let docker = connect_container_runtime(socket_path.as_deref())
+        let docker = match &endpoint {
+            Some(endpoint) => connect_container_endpoint(endpoint),
+            None => connect_container_runtime(socket_path.as_deref()),
+        }
             .map_err(|e| {
                 RuntimeError::SpawnFailed(format!(
                     "Failed to connect to container runtime: {e}\n\n\
@@ -357,20 +436,32 @@ impl ContainerRuntime {
         // means labels read "unknown" and the operator hints are generic. We do
         // NOT default to Docker on failure because that would re-introduce the
         // Podman-blind behavior that produced the reaper storm.
-        let engine = match docker.version().await {
+        let (engine, reported_platform) = match docker.version().await {
             Ok(version) => {
                 let payload = serde_json::to_value(&version).unwrap_or(serde_json::Value::Null);
-                ContainerEngine::detect_from_version_payload(&payload)
+                (
+                    ContainerEngine::detect_from_version_payload(&payload),
+                    engine_platform(version.os.as_deref(), version.arch.as_deref()),
+                )
             }
             Err(error) => {
                 warn!(
                     error = %error,
                     "Failed to query container engine /version; defaulting to Unknown"
                 );
-                ContainerEngine::Unknown
+                (ContainerEngine::Unknown, None)
             }
         };
-        info!(engine = ?engine, "Container engine detected");
+        let platforms = match endpoint.as_ref().map(|e| e.platforms.clone()) {
+            Some(configured) if !configured.is_empty() => configured,
+            _ => reported_platform.into_iter().collect(),
+        };
+        info!(
+            engine = ?engine,
+            endpoint = endpoint.as_ref().map(|e| e.name.as_str()).unwrap_or("local"),
+            platforms = ?platforms,
+            "Container engine detected"
+        );
 
         Ok(Self {
             docker,
@@ -384,12 +475,15 @@ impl ContainerRuntime {
             bootstrap_paths: RwLock::new(HashMap::new()),
             image_manager,
             event_bus,
+            image_verifier,
             engine,
             fuse_daemon,
             fuse_mount_prefix,
             fuse_mount_client,
             fuse_mount_handles: RwLock::new(HashMap::new()),
             grpc_fuse_mounts: RwLock::new(HashMap::new()),
+            sidecars: RwLock::new(HashMap::new()),
+            platforms,
         })
     }
 
@@ -399,6 +493,11 @@ impl ContainerRuntime {
         &self.engine
     }
 
+    /// Platforms this engine runs containers for; empty if unknown.
+    pub fn platforms(&self) -> &[String] {
+        &self.platforms
+    }
+
     /// Inspect a bollard error and, if it represents an un-killable container
     /// (HTTP 500 with "did not die within timeout" in the body), return a
     /// typed [`RuntimeError::Unkillable`] tagged with the live engine. Returns
@@ -489,17 +588,24 @@ impl ContainerRuntime {
         let mut by_id: std::collections::HashMap<String, ManagedAgentContainer> =
             std::collections::HashMap::new();
 
-        for runtime_label_value in [
+        // Sidecars carry the agent's execution label and are listed too, so a
+        // sidecar left behind by a crashed daemon is reaped with its agent.
+        let queries = [
             ContainerEngineKind::Podman.label_value(),
             ContainerEngineKind::Docker.label_value(),
-        ] {
+        ]
+        .into_iter()
+        .flat_map(|runtime| {
+            [AEGIS_CONTAINER_KIND_AGENT, AEGIS_CONTAINER_KIND_SIDECAR].map(|kind| (runtime, kind))
+        });
+        for (runtime_label_value, container_kind) in queries {
             let mut filters = std::collections::HashMap::new();
             filters.insert(
                 "label".to_string(),
                 vec![
                     format!("{AEGIS_MANAGED_LABEL}=true"),
                     format!("{AEGIS_RUNTIME_LABEL}={runtime_label_value}"),
-                    format!("{AEGIS_CONTAINER_KIND_LABEL}={AEGIS_CONTAINER_KIND_AGENT}"),
+                    format!("{AEGIS_CONTAINER_KIND_LABEL}={container_kind}"),
                 ],
             );
 
@@ -649,6 +755,213 @@ impl ContainerRuntime {
         ])
     }
 
+    /// Network config registering [`RuntimeConfig::service_dns_names`] as
+    /// aliases on `network_mode`, so other containers on that network
+    /// (swarm members included) can reach services the execution exposes.
+    /// The names go away with the container. Docker only resolves aliases on
+    /// user-defined networks, so this is `None` for the engine default and
+    /// the built-in `bridge`, `host` and `none` modes.
+    fn service_networking_config(
+        network_mode: Option<&str>,
+        config: &RuntimeConfig,
+    ) -> Option<NetworkingConfig> {
+        let network = network_mode.filter(|mode| {
+            !matches!(*mode, "" | "default" | "bridge" | "host" | "none")
+                && !mode.starts_with("container:")
+        })?;
+        Some(NetworkingConfig {
+            endpoints_config: Some(HashMap::from([(
+                network.to_string(),
+                EndpointSettings {
+                    aliases: Some(config.service_dns_names()),
+                    ..Default::default()
+                },
+            )])),
+        })
+    }
+
+    /// Container config for a sidecar joining the network namespace of
+    /// `agent_container_id`. The sidecar inherits the agent's managed labels so
+    /// the orphan reaper and GC treat both containers alike.
+    fn sidecar_container_body(
+        agent_container_id: &str,
+        sidecar: &SidecarConfig,
+        config: &RuntimeConfig,
+        engine_kind: ContainerEngineKind,
+    ) -> ContainerCreateBody {
+        let mut labels = Self::managed_container_labels(config, engine_kind);
+        labels.insert(
+            AEGIS_CONTAINER_KIND_LABEL.to_string(),
+            AEGIS_CONTAINER_KIND_SIDECAR.to_string(),
+        );
+        labels.insert(AEGIS_SIDECAR_NAME_LABEL.to_string(), sidecar.name.clone());
+
+        let cap_add = (sidecar.name == CAPTURE_SIDECAR_NAME).then(|| {
+            CAPTURE_CAPABILITIES
+                .iter()
+                .map(|cap| cap.to_string())
+                .collect()
+        });
+        let host_config = bollard::models::HostConfig {
+            network_mode: Some(format!("container:{agent_container_id}")),
+            cap_add,
+            memory: sidecar.resources.memory_bytes.map(|bytes| bytes as i64),
+            nano_cpus: sidecar
+                .resources
+                .cpu_millis
+                .map(|millis| (millis as i64) * 1_000_000_000 / 1000),
+            ..Default::default()
+        };
+
+        let mut env: Vec<String> = sidecar
+            .env
+            .iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect();
+        env.sort();
+
+        ContainerCreateBody {
+            image: Some(sidecar.image.clone()),
+            cmd: (!sidecar.command.is_empty()).then(|| sidecar.command.clone()),
+            env: Some(env),
+            labels: Some(labels),
+            host_config: Some(host_config),
+            ..Default::default()
+        }
+    }
+
+    /// Pull, verify, create and start every sidecar in `config`, plus the
+    /// network flow capture when requested, next to `agent_container_id`.
+    /// Started sidecars are registered before the next one is attempted so
+    /// that a failure part-way through is fully cleaned up by `terminate()`.
+    async fn start_sidecars(
+        &self,
+        agent_container_id: &str,
+        config: &RuntimeConfig,
+    ) -> Result<(), RuntimeError> {
+        let flow_capture = config.network_flow_capture.as_ref().map(capture_sidecar);
+        for sidecar in config.sidecars.iter().chain(flow_capture.as_ref()) {
+            self.image_manager
+                .ensure_image(&sidecar.image, sidecar.image_pull_policy, None)
+                .await?;
+            if let Some(verifier) = &self.image_verifier {
+                verifier.verify(&sidecar.image, &config.tenant_id).await?;
+            }
+
+            let body = Self::sidecar_container_body(
+                agent_container_id,
+                sidecar,
+                config,
+                self.engine.kind(),
+            );
+            let options = CreateContainerOptions {
+                name: Some(format!(
+                    "aegis-sidecar-{agent_container_id:.12}-{}",
+                    sidecar.name
+                )),
+                platform: String::new(),
+            };
+            let container_id = self
+                .docker
+                .create_container(Some(options), body)
+                .await
+                .map_err(|e| {
+                    RuntimeError::SpawnFailed(format!(
+                        "Failed to create sidecar '{}': {e}",
+                        sidecar.name
+                    ))
+                })?
+                .id;
+            self.sidecars
+                .write()
+                .await
+                .entry(agent_container_id.to_string())
+                .or_default()
+                .push(RunningSidecar {
+                    container_id: container_id.clone(),
+                    stream: sidecar.console_stream(),
+                    flow_capture: sidecar.name == CAPTURE_SIDECAR_NAME,
+                    logs_since: 0,
+                });
+            self.docker
+                .start_container(&container_id, None::<StartContainerOptions>)
+                .await
+                .map_err(|e| {
+                    RuntimeError::SpawnFailed(format!(
+                        "Failed to start sidecar '{}': {e}",
+                        sidecar.name
+                    ))
+                })?;
+            info!(
+                container_id = agent_container_id,
+                sidecar = %sidecar.name,
+                sidecar_container_id = %container_id,
+                "Started sidecar container"
+            );
+        }
+        Ok(())
+    }
+
+    /// Force-remove the sidecars attached to `agent_container_id`.
+    async fn remove_sidecars(&self, agent_container_id: &str) {
+        let Some(sidecars) = self.sidecars.write().await.remove(agent_container_id) else {
+            return;
+        };
+        for sidecar in sidecars {
+            let options = RemoveContainerOptions {
+                force: true,
+                v: true,
+                ..Default::default()
+            };
+            if let Err(e) = self
+                .docker
+                .remove_container(&sidecar.container_id, Some(options))
+                .await
+            {
+                warn!(
+                    container_id = agent_container_id,
+                    sidecar_container_id = %sidecar.container_id,
+                    error = %e,
+                    "Failed to remove sidecar container"
+                );
+            }
+        }
+    }
+
+    /// Read `sidecar`'s logs written since the previous read and advance its
+    /// cursor.
+    async fn read_sidecar_logs(
+        docker: &Docker,
+        sidecar: &mut RunningSidecar,
+        stderr: bool,
+    ) -> String {
+        let now = Utc::now().timestamp();
+        let options = LogsOptions {
+            stdout: true,
+            stderr,
+            since: sidecar.logs_since as i32,
+            until: now as i32,
+            ..Default::default()
+        };
+        let mut logs = docker.logs(&sidecar.container_id, Some(options));
+        let mut content = String::new();
+        while let Some(chunk) = logs.next().await {
+            match chunk {
+                Ok(output) => content.push_str(&String::from_utf8_lossy(&output.into_bytes())),
+                Err(e) => {
+                    debug!(
+                        sidecar_container_id = %sidecar.container_id,
+                        error = %e,
+                        "Failed to read sidecar logs"
+                    );
+                    break;
+                }
+            }
+        }
+        sidecar.logs_since = now;
+        content
+    }
+
     async fn cleanup_spawned_container(&self, container_id: &str) {
         let cleanup_id = InstanceId::new(container_id.to_string());
         if let Err(error) = AgentRuntime::terminate(self, &cleanup_id).await {
@@ -851,6 +1164,32 @@ impl AgentRuntime for ContainerRuntime {
         // pull_source carried for audit; not needed for container creation logic.
         let _ = pull_source;
 
+        // Supply-chain gate: refuse untrusted images before any container exists.
+        if let Some(verifier) = &self.image_verifier {
+            if let Err(e) = verifier.verify(&image, &config.tenant_id).await {
+                let reason = match &e {
+                    RuntimeError::ImageVerificationFailed { reason, .. } => reason.clone(),
+                    other => other.to_string(),
+                };
+                error!(
+                    target: "image_verification",
+                    image = %image,
+                    execution_id = %config.execution_id,
+                    tenant_id = %config.tenant_id,
+                    reason = %reason,
+                    "image verification failed"
+                );
+                self.event_bus
+                    .publish_image_event(ImageManagementEvent::ImageVerificationFailed {
+                        execution_id: config.execution_id,
+                        image: image.clone(),
+                        reason,
+                        failed_at: Utc::now(),
+                    });
+                return Err(e);
+            }
+        }
+
         // Build host config with resource limits
 
         let mut host_config = bollard::models::HostConfig {
@@ -1266,7 +1605,7 @@ impl AgentRuntime for ContainerRuntime {
 
         let options = CreateContainerOptions {
             name: Some(format!("aegis-agent-{}", uuid::Uuid::new_v4())),
-            platform: String::new(),
+            platform: config.platform.clone().unwrap_or_default(),
         };
 
         // Filter env vars to prevent orchestrator-internal variables from leaking
@@ -1288,6 +1627,17 @@ impl AgentRuntime for ContainerRuntime {
         // Add orchestrator URL for agent bootstrap script to call LLM proxy
         env_vars.push(format!("AEGIS_ORCHESTRATOR_URL={}", self.orchestrator_url));
 
+        let networking_config =
+            Self::service_networking_config(self.network_mode.as_deref(), &config);
+        if networking_config.is_some() {
+            env_vars.push(format!("AEGIS_SERVICE_HOST={}", config.service_host()));
+            debug!(
+                execution_id = %config.execution_id,
+                names = ?config.service_dns_names(),
+                "Registering execution DNS names"
+            );
+        }
+
         // Enable bootstrap.py verbose mode if log level is debug or trace
         if tracing::level_enabled!(tracing::Level::DEBUG) {
             env_vars.push("AEGIS_BOOTSTRAP_DEBUG=true".to_string());
@@ -1311,6 +1661,7 @@ impl AgentRuntime for ContainerRuntime {
             env: Some(env_vars),
             labels: Some(Self::managed_container_labels(&config, self.engine.kind())),
             host_config: Some(host_config),
+            networking_config,
             ..Default::default()
         };
 
@@ -1392,6 +1743,14 @@ impl AgentRuntime for ContainerRuntime {
             info!(target: "runtime_spawn", step = "copy_bootstrap_complete", container_id = %id);
         }
 
+        if !config.sidecars.is_empty() || config.network_flow_capture.is_some() {
+            info!(target: "runtime_spawn", step = "start_sidecars", container_id = %id, count = config.sidecars.len(), network_flow_capture = config.network_flow_capture.is_some());
+            if let Err(error) = self.start_sidecars(&id, &config).await {
+                self.cleanup_spawned_container(&id).await;
+                return Err(error);
+            }
+        }
+
         Ok(InstanceId::new(id))
     }
 
@@ -1570,6 +1929,9 @@ impl AgentRuntime for ContainerRuntime {
 
         self.bootstrap_paths.write().await.remove(id.as_str());
 
+        // Sidecars join the agent's network namespace, so they go first.
+        self.remove_sidecars(id.as_str()).await;
+
         // Inspect first. If the engine is already in the middle of removing the
         // container (state=removing|dead), a second remove call is what produces
         // the recurring HTTP 500 storm — return Ok and let the in-flight removal
@@ -1784,6 +2146,37 @@ impl AgentRuntime for ContainerRuntime {
             cpu_usage_percent: cpu,
         })
     }
+
+    async fn drain_sidecar_output(&self, id: &InstanceId) -> Vec<SidecarOutput> {
+        let mut sidecars = self.sidecars.write().await;
+        let Some(sidecars) = sidecars.get_mut(id.as_str()) else {
+            return Vec::new();
+        };
+        let mut drained = Vec::new();
+        for sidecar in sidecars.iter_mut().filter(|sidecar| !sidecar.flow_capture) {
+            let content = Self::read_sidecar_logs(&self.docker, sidecar, true).await;
+            if !content.is_empty() {
+                drained.push(SidecarOutput {
+                    stream: sidecar.stream.clone(),
+                    content,
+                });
+            }
+        }
+        drained
+    }
+
+    async fn drain_network_flows(&self, id: &InstanceId) -> Vec<NetworkFlow> {
+        let mut sidecars = self.sidecars.write().await;
+        let Some(sidecars) = sidecars.get_mut(id.as_str()) else {
+            return Vec::new();
+        };
+        let mut output = String::new();
+        for sidecar in sidecars.iter_mut().filter(|sidecar| sidecar.flow_capture) {
+            // tshark reports its own status on stderr; stdout is field lines only.
+            output.push_str(&Self::read_sidecar_logs(&self.docker, sidecar, false).await);
+        }
+        parse_flows(&output)
+    }
 }
 
 #[cfg(test)]
@@ -1792,10 +2185,13 @@ mod tests {
     use super::{
         ContainerEngine, ContainerRuntime, AEGIS_CONTAINER_KIND_LABEL, AEGIS_EXECUTION_ID_LABEL,
         AEGIS_KEEP_CONTAINER_ON_FAILURE_LABEL, AEGIS_MANAGED_LABEL, AEGIS_RUNTIME_LABEL,
+        AEGIS_SIDECAR_NAME_LABEL,
     };
     use crate::domain::agent::{ExecutionStrategy, ImagePullPolicy};
     use crate::domain::execution::ExecutionId;
-    use crate::domain::runtime::{ContainerEngineKind, ResourceLimits, RuntimeConfig};
+    use crate::domain::runtime::{
+        ContainerEngineKind, ResourceLimits, RuntimeConfig, SidecarConfig,
+    };
     use std::collections::HashMap;
 
     #[test]
@@ -1862,6 +2258,11 @@ mod tests {
             image: "python:3.12".to_string(),
             bootstrap_path: None,
             execution_id: ExecutionId::new(),
+            tenant_id: crate::domain::tenant::TenantId::default(),
+            sidecars: Vec::new(),
+            platform: None,
+            network_policy: None,
+            network_flow_capture: None,
         };
 
         let labels =
@@ -1883,6 +2284,135 @@ mod tests {
         );
     }
 
+    #[test]
+    fn sidecar_container_joins_agent_network_namespace() {
+        let config = RuntimeConfig {
+            language: "python".to_string(),
+            version: "3.12".to_string(),
+            isolation: "docker".to_string(),
+            env: HashMap::new(),
+            image_pull_policy: ImagePullPolicy::IfNotPresent,
+            resources: ResourceLimits {
+                cpu_millis: None,
+                memory_bytes: None,
+                disk_bytes: None,
+                timeout_seconds: None,
+            },
+            execution: ExecutionStrategy::default(),
+            volumes: Vec::new(),
+            container_uid: 1000,
+            container_gid: 1000,
+            keep_container_on_failure: false,
+            image: "python:3.12".to_string(),
+            bootstrap_path: None,
+            execution_id: ExecutionId::new(),
+            tenant_id: crate::domain::tenant::TenantId::default(),
+            sidecars: Vec::new(),
+            platform: None,
+            network_policy: None,
+            network_flow_capture: None,
+        };
+        let sidecar = SidecarConfig {
+            name: "postgres".to_string(),
+            image: "docker.io/library/postgres:16".to_string(),
+            image_pull_policy: ImagePullPolicy::IfNotPresent,
+            command: Vec::new(),
+            env: HashMap::from([("POSTGRES_PASSWORD".to_string(), "test".to_string())]),
+            resources: ResourceLimits {
+                cpu_millis: Some(500),
+                memory_bytes: Some(256 * 1024 * 1024),
+                disk_bytes: None,
+                timeout_seconds: None,
+            },
+        };
+
+        let body = ContainerRuntime::sidecar_container_body(
+            "abc123",
+            &sidecar,
+            &config,
+            ContainerEngineKind::Docker,
+        );
+
+        let host_config = body.host_config.unwrap();
+        assert_eq!(
+            host_config.network_mode.as_deref(),
+            Some("container:abc123")
+        );
+        assert_eq!(host_config.nano_cpus, Some(500_000_000));
+        assert_eq!(host_config.memory, Some(256 * 1024 * 1024));
+        assert_eq!(body.cmd, None);
+        assert_eq!(body.env, Some(vec!["POSTGRES_PASSWORD=test".to_string()]));
+        let labels = body.labels.unwrap();
+        assert_eq!(
+            labels.get(AEGIS_CONTAINER_KIND_LABEL),
+            Some(&"sidecar".to_string())
+        );
+        assert_eq!(
+            labels.get(AEGIS_EXECUTION_ID_LABEL),
+            Some(&config.execution_id.to_string())
+        );
+        assert_eq!(
+            labels.get(AEGIS_SIDECAR_NAME_LABEL),
+            Some(&"postgres".to_string())
+        );
+    }
+
+    #[test]
+    fn service_dns_names_are_aliases_on_user_defined_networks_only() {
+        let config = RuntimeConfig {
+            language: "python".to_string(),
+            version: "3.12".to_string(),
+            isolation: "docker".to_string(),
+            env: HashMap::new(),
+            image_pull_policy: ImagePullPolicy::IfNotPresent,
+            resources: ResourceLimits {
+                cpu_millis: None,
+                memory_bytes: None,
+                disk_bytes: None,
+                timeout_seconds: None,
+            },
+            execution: ExecutionStrategy::default(),
+            volumes: Vec::new(),
+            container_uid: 1000,
+            container_gid: 1000,
+            keep_container_on_failure: false,
+            image: "python:3.12".to_string(),
+            bootstrap_path: None,
+            execution_id: ExecutionId::new(),
+            tenant_id: crate::domain::tenant::TenantId::default(),
+            sidecars: vec![SidecarConfig {
+                name: "preview".to_string(),
+                image: "docker.io/library/nginx:1".to_string(),
+                image_pull_policy: ImagePullPolicy::IfNotPresent,
+                command: Vec::new(),
+                env: HashMap::new(),
+                resources: ResourceLimits {
+                    cpu_millis: None,
+                    memory_bytes: None,
+                    disk_bytes: None,
+                    timeout_seconds: None,
+                },
+            }],
+            platform: None,
+            network_policy: None,
+            network_flow_capture: None,
+        };
+
+        for mode in [None, Some("bridge"), Some("host"), Some("container:abc123")] {
+            assert!(ContainerRuntime::service_networking_config(mode, &config).is_none());
+        }
+
+        let networking =
+            ContainerRuntime::service_networking_config(Some("aegis-network"), &config).unwrap();
+        let endpoints = networking.endpoints_config.unwrap();
+        let host = format!("exec-{}.aegis.internal", config.execution_id.0);
+        assert_eq!(config.service_host(), host);
+        assert_eq!(
+            endpoints["aegis-network"].aliases,
+            Some(vec![host.clone(), format!("preview.{host}")])
+        );
+    }
+
     /// Regression: gRPC FUSE mounts in agent containers (via ContainerRuntime)
     /// must be tracked per-container and unmounted in terminate(). Before this
     /// fix, only ContainerStepRunner tracked gRPC-mounted volumes — agent
@@ -2098,6 +2628,11 @@ mod tests {
             image: "python:3.12".to_string(),
             bootstrap_path: None,
             execution_id: ExecutionId::new(),
+            tenant_id: crate::domain::tenant::TenantId::default(),
+            sidecars: Vec::new(),
+            platform: None,
+            network_policy: None,
+            network_flow_capture: None,
         }
     }
 
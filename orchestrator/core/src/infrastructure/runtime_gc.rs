@@ -0,0 +1,325 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Runtime Garbage Collector
+//!
+//! Reclaims node-local disk from past executions according to
+//! [`RuntimeGcConfig`] (`spec.runtime.gc`):
+//!
+//! 1. Exited/dead managed containers stopped longer than
+//!    `exited_container_max_age_seconds` are removed (with anonymous volumes).
+//! 2. Built runtime images (`aegis.agent` label, see
+//!    [`crate::domain::runtime_image`]) beyond the newest
+//!    `keep_images_per_agent` per agent are removed.
+//! 3. Dangling managed images are pruned.
+//!
+//! Only objects labelled `aegis.managed=true` are considered. Running
+//! containers are never touched, and image removal is never forced, so an
+//! image still referenced by a container is skipped rather than broken.
+//!
+//! Complements the orphan reaper in the daemon, which removes containers whose
+//! execution is no longer running; the collector handles what the reaper
+//! deliberately leaves behind (debug-retained containers, old image tags).
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Retention-based cleanup of node-local containers and images
+
+use crate::domain::node_config::RuntimeGcConfig;
+use crate::domain::runtime::RuntimeError;
+use crate::domain::runtime_image::AEGIS_AGENT_LABEL;
+use bollard::query_parameters::{
+    ListContainersOptionsBuilder, ListImagesOptionsBuilder, PruneImagesOptions,
+    RemoveContainerOptions, RemoveImageOptions,
+};
+use bollard::Docker;
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use std::collections::HashMap;
+use tracing::{debug, info, warn};
+
+const AEGIS_MANAGED_FILTER: &str = "aegis.managed=true";
+
+/// Outcome of one collector run.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct GcReport {
+    /// `true` when nothing was removed and the lists are candidates only.
+    pub dry_run: bool,
+    /// Container IDs removed (or that would be removed).
+    pub containers_removed: Vec<String>,
+    /// Image IDs removed (or that would be removed).
+    pub images_removed: Vec<String>,
+    /// Bytes reclaimed from removed and pruned images, as reported by the engine.
+    pub bytes_reclaimed: u64,
+    /// Objects skipped because the engine refused removal (e.g. image in use).
+    pub skipped: Vec<String>,
+}
+
+/// A built runtime image considered for per-agent retention.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GcImage {
+    pub id: String,
+    pub agent: String,
+    pub created: i64,
+    pub size: u64,
+}
+
+/// Images beyond the newest `keep` per agent, oldest last within each agent.
+pub fn surplus_images(images: Vec<GcImage>, keep: usize) -> Vec<GcImage> {
+    let mut by_agent: HashMap<String, Vec<GcImage>> = HashMap::new();
+    for image in images {
+        by_agent.entry(image.agent.clone()).or_default().push(image);
+    }
+    let mut surplus = Vec::new();
+    for (_, mut images) in by_agent {
+        images.sort_by(|a, b| b.created.cmp(&a.created).then_with(|| a.id.cmp(&b.id)));
+        surplus.extend(images.into_iter().skip(keep));
+    }
+    surplus.sort_by(|a, b| a.agent.cmp(&b.agent).then(b.created.cmp(&a.created)));
+    surplus
+}
+
+/// Whether a stopped container has exceeded `max_age`.
+///
+/// `finished_at` falls back to the creation time when the engine reports no
+/// (or the zero-value) finish timestamp.
+pub fn container_expired(
+    finished_at: Option<DateTime<Utc>>,
+    created: i64,
+    now: DateTime<Utc>,
+    max_age: Duration,
+) -> bool {
+    let stopped_at = finished_at
+        .filter(|t| t.timestamp() > 0)
+        .or_else(|| DateTime::from_timestamp(created, 0));
+    stopped_at.is_some_and(|t| now - t >= max_age)
+}
+
+/// Retention-based collector for one container engine.
+pub struct RuntimeGarbageCollector {
+    docker: Docker,
+    config: RuntimeGcConfig,
+}
+
+impl RuntimeGarbageCollector {
+    /// Create a collector enforcing `config` against `docker`.
+    pub fn new(docker: Docker, config: RuntimeGcConfig) -> Self {
+        Self { docker, config }
+    }
+
+    /// Run one collection pass. With `dry_run` nothing is removed.
+    pub async fn run(&self, dry_run: bool) -> Result<GcReport, RuntimeError> {
+        let mut report = GcReport {
+            dry_run,
+            ..Default::default()
+        };
+        self.collect_containers(dry_run, &mut report).await?;
+        self.collect_images(dry_run, &mut report).await?;
+        if self.config.prune_dangling_images && !dry_run {
+            self.prune_dangling(&mut report).await?;
+        }
+        info!(
+            dry_run,
+            containers = report.containers_removed.len(),
+            images = report.images_removed.len(),
+            bytes_reclaimed = report.bytes_reclaimed,
+            skipped = report.skipped.len(),
+            "runtime gc pass complete"
+        );
+        Ok(report)
+    }
+
+    async fn collect_containers(
+        &self,
+        dry_run: bool,
+        report: &mut GcReport,
+    ) -> Result<(), RuntimeError> {
+        let filters = HashMap::from([
+            ("label".to_string(), vec![AEGIS_MANAGED_FILTER.to_string()]),
+            (
+                "status".to_string(),
+                vec!["exited".to_string(), "dead".to_string()],
+            ),
+        ]);
+        let containers = self
+            .docker
+            .list_containers(Some(
+                ListContainersOptionsBuilder::new()
+                    .all(true)
+                    .filters(&filters)
+                    .build(),
+            ))
+            .await
+            .map_err(|e| {
+                RuntimeError::SpawnFailed(format!("GC: failed to list containers: {e}"))
+            })?;
+
+        let now = Utc::now();
+        let max_age = i64::try_from(self.config.exited_container_max_age_seconds)
+            .ok()
+            .and_then(Duration::try_seconds)
+            .unwrap_or(Duration::MAX);
+        for container in containers {
+            let Some(id) = container.id else { continue };
+            let created = container.created.unwrap_or_default();
+            // finished_at >= created, so a recent creation time rules the
+            // container out without an inspect round-trip.
+            if !container_expired(None, created, now, max_age) {
+                continue;
+            }
+            let finished_at = match self.docker.inspect_container(&id, None).await {
+                Ok(inspect) => inspect
+                    .state
+                    .and_then(|state| state.finished_at)
+                    .and_then(|raw| DateTime::parse_from_rfc3339(&raw).ok())
+                    .map(|t| t.with_timezone(&Utc)),
+                Err(e) => {
+                    debug!(container_id = %id, error = %e, "GC: inspect failed; skipping");
+                    continue;
+                }
+            };
+            if !container_expired(finished_at, created, now, max_age) {
+                continue;
+            }
+            if dry_run {
+                report.containers_removed.push(id);
+                continue;
+            }
+            let options = RemoveContainerOptions {
+                v: true,
+                ..Default::default()
+            };
+            match self.docker.remove_container(&id, Some(options)).await {
+                Ok(()) => report.containers_removed.push(id),
+                Err(e) => {
+                    warn!(container_id = %id, error = %e, "GC: failed to remove container");
+                    report.skipped.push(id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn collect_images(
+        &self,
+        dry_run: bool,
+        report: &mut GcReport,
+    ) -> Result<(), RuntimeError> {
+        let filters = HashMap::from([(
+            "label".to_string(),
+            vec![
+                AEGIS_MANAGED_FILTER.to_string(),
+                AEGIS_AGENT_LABEL.to_string(),
+            ],
+        )]);
+        let images = self
+            .docker
+            .list_images(Some(
+                ListImagesOptionsBuilder::new().filters(&filters).build(),
+            ))
+            .await
+            .map_err(|e| RuntimeError::SpawnFailed(format!("GC: failed to list images: {e}")))?;
+
+        let candidates = images
+            .into_iter()
+            .filter_map(|image| {
+                Some(GcImage {
+                    agent: image.labels.get(AEGIS_AGENT_LABEL)?.clone(),
+                    id: image.id,
+                    created: image.created,
+                    size: u64::try_from(image.size).unwrap_or_default(),
+                })
+            })
+            .collect();
+
+        for image in surplus_images(candidates, self.config.keep_images_per_agent) {
+            if dry_run {
+                report.bytes_reclaimed += image.size;
+                report.images_removed.push(image.id);
+                continue;
+            }
+            match self
+                .docker
+                .remove_image(&image.id, None::<RemoveImageOptions>, None)
+                .await
+            {
+                Ok(_) => {
+                    report.bytes_reclaimed += image.size;
+                    report.images_removed.push(image.id);
+                }
+                Err(e) => {
+                    debug!(image_id = %image.id, agent = %image.agent, error = %e, "GC: image not removed");
+                    report.skipped.push(image.id);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    async fn prune_dangling(&self, report: &mut GcReport) -> Result<(), RuntimeError> {
+        let filters = HashMap::from([
+            ("dangling".to_string(), vec!["true".to_string()]),
+            ("label".to_string(), vec![AEGIS_MANAGED_FILTER.to_string()]),
+        ]);
+        let pruned = self
+            .docker
+            .prune_images(Some(PruneImagesOptions {
+                filters: Some(filters),
+            }))
+            .await
+            .map_err(|e| RuntimeError::SpawnFailed(format!("GC: image prune failed: {e}")))?;
+        report.bytes_reclaimed += pruned
+            .space_reclaimed
+            .and_then(|bytes| u64::try_from(bytes).ok())
+            .unwrap_or_default();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(id: &str, agent: &str, created: i64) -> GcImage {
+        GcImage {
+            id: id.to_string(),
+            agent: agent.to_string(),
+            created,
+            size: 10,
+        }
+    }
+
+    #[test]
+    fn surplus_images_keeps_newest_per_agent() {
+        let surplus = surplus_images(
+            vec![
+                image("a1", "alpha", 1),
+                image("a3", "alpha", 3),
+                image("a2", "alpha", 2),
+                image("b1", "beta", 1),
+            ],
+            2,
+        );
+        let ids: Vec<_> = surplus.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, ["a1"]);
+
+        assert!(surplus_images(vec![image("a1", "alpha", 1)], 1).is_empty());
+        assert_eq!(surplus_images(vec![image("a1", "alpha", 1)], 0).len(), 1);
+    }
+
+    #[test]
+    fn container_expiry_prefers_finish_time() {
+        let now = DateTime::from_timestamp(10_000, 0).unwrap();
+        let max_age = Duration::seconds(3_600);
+        let created = 0;
+
+        assert!(container_expired(None, created, now, max_age));
+        let recently_finished = DateTime::from_timestamp(9_000, 0);
+        assert!(!container_expired(recently_finished, created, now, max_age));
+        // Docker reports "0001-01-01T00:00:00Z" for never-finished containers.
+        let zero = DateTime::parse_from_rfc3339("0001-01-01T00:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert!(container_expired(Some(zero), created, now, max_age));
+    }
+}
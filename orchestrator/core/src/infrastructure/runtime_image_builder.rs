@@ -0,0 +1,265 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Runtime Image Builder
+//!
+//! Builds the per-agent-version images described by
+//! [`crate::domain::runtime_image::RuntimeImageSpec`] through the Docker Engine
+//! `/build` API. The build context is a single generated `Dockerfile`; nothing
+//! from the host filesystem is sent to the daemon.
+//!
+//! Builds are idempotent: the tag is content-addressed, so an existing image
+//! with the same tag is reused without contacting the daemon's builder.
+//! Concurrent requests for the same tag on one node are serialized (one lock
+//! per tag, kept for the process lifetime) so that a burst of executions right
+//! after deploy triggers a single build.
+//!
+//! Base-image registry credentials come from the same [`CredentialResolver`]
+//! used for pulls (ADR-045).
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Dockerfile-from-manifest builds for `spec.runtime.packages`
+
+use crate::domain::runtime::RuntimeError;
+use crate::domain::runtime_image::{BuiltRuntimeImage, RuntimeImageSpec};
+use crate::infrastructure::image_manager::CredentialResolver;
+use async_trait::async_trait;
+use bollard::auth::DockerCredentials;
+use bollard::query_parameters::BuildImageOptionsBuilder;
+use bollard::Docker;
+use dashmap::DashMap;
+use futures::StreamExt;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::time::timeout;
+use tracing::{error, info};
+
+/// Wall-clock budget for a single runtime image build, including the base
+/// image pull and package installation.
+pub const IMAGE_BUILD_TIMEOUT_SECS: u64 = 900;
+
+/// Builds (or reuses) runtime images for agents that declare packages.
+#[async_trait]
+pub trait RuntimeImageBuilder: Send + Sync {
+    /// Ensure the image for `spec` exists locally and return its tag and digest.
+    async fn ensure_built(
+        &self,
+        spec: &RuntimeImageSpec,
+    ) -> Result<BuiltRuntimeImage, RuntimeError>;
+}
+
+/// [`RuntimeImageBuilder`] backed by the local Docker daemon.
+pub struct DockerRuntimeImageBuilder {
+    docker: Docker,
+    credential_resolver: Arc<dyn CredentialResolver>,
+    in_flight: DashMap<String, Arc<tokio::sync::Mutex<()>>>,
+}
+
+impl DockerRuntimeImageBuilder {
+    /// Create a builder backed by `docker`, resolving base-image registry
+    /// credentials through `credential_resolver`.
+    pub fn new(docker: Docker, credential_resolver: Arc<dyn CredentialResolver>) -> Self {
+        Self {
+            docker,
+            credential_resolver,
+            in_flight: DashMap::new(),
+        }
+    }
+
+    async fn existing_digest(&self, tag: &str) -> Option<String> {
+        self.docker
+            .inspect_image(tag)
+            .await
+            .ok()
+            .and_then(|image| image.id)
+    }
+
+    async fn build_credentials(
+        &self,
+        base_image: &str,
+    ) -> Option<HashMap<String, DockerCredentials>> {
+        let cred = self.credential_resolver.resolve(base_image).await?;
+        // SAFETY: resolved password is handed straight to bollard and never logged.
+        let password = if let Some(var) = cred.password.strip_prefix("env:") {
+            std::env::var(var).unwrap_or(cred.password.clone())
+        } else {
+            cred.password.clone()
+        };
+        Some(HashMap::from([(
+            cred.registry.clone(),
+            DockerCredentials {
+                username: Some(cred.username.clone()),
+                password: Some(password),
+                serveraddress: Some(cred.registry.clone()),
+                ..Default::default()
+            },
+        )]))
+    }
+
+    async fn run_build(&self, spec: &RuntimeImageSpec, tag: &str) -> Result<String, RuntimeError> {
+        let context = build_context(&spec.render_dockerfile())?;
+        let labels = spec.labels();
+        let options = BuildImageOptionsBuilder::new()
+            .dockerfile("Dockerfile")
+            .t(tag)
+            .rm(true)
+            .forcerm(true)
+            .labels(&labels)
+            .build();
+        let credentials = self.build_credentials(&spec.base_image).await;
+
+        let mut stream = self.docker.build_image(
+            options,
+            credentials,
+            Some(bollard::body_full(context.into())),
+        );
+        let mut image_id: Option<String> = None;
+        while let Some(chunk) = stream.next().await {
+            let info = chunk.map_err(|e| {
+                RuntimeError::SpawnFailed(format!("Failed to build runtime image {tag}: {e}"))
+            })?;
+            if let Some(detail) = info.error_detail {
+                return Err(RuntimeError::SpawnFailed(format!(
+                    "Runtime image build for {tag} failed: {}",
+                    detail.message.unwrap_or_default()
+                )));
+            }
+            if let Some(line) = info.stream.as_deref().map(str::trim) {
+                if !line.is_empty() {
+                    info!(target: "image_build", image = %tag, "{line}");
+                }
+            }
+            if let Some(id) = info.aux.and_then(|aux| aux.id) {
+                image_id = Some(id);
+            }
+        }
+
+        match image_id {
+            Some(id) => Ok(id),
+            None => self.existing_digest(tag).await.ok_or_else(|| {
+                RuntimeError::SpawnFailed(format!(
+                    "Runtime image build for {tag} finished but the image was not found"
+                ))
+            }),
+        }
+    }
+}
+
+#[async_trait]
+impl RuntimeImageBuilder for DockerRuntimeImageBuilder {
+    async fn ensure_built(
+        &self,
+        spec: &RuntimeImageSpec,
+    ) -> Result<BuiltRuntimeImage, RuntimeError> {
+        let tag = spec.image_tag();
+        let lock = self
+            .in_flight
+            .entry(tag.clone())
+            .or_insert_with(|| Arc::new(tokio::sync::Mutex::new(())))
+            .clone();
+        let _guard = lock.lock().await;
+
+        if let Some(digest) = self.existing_digest(&tag).await {
+            info!(image = %tag, digest = %digest, "reusing built runtime image");
+            return Ok(BuiltRuntimeImage {
+                tag,
+                digest,
+                reused: true,
+            });
+        }
+
+        let started = Instant::now();
+        info!(
+            image = %tag,
+            base_image = %spec.base_image,
+            packages = spec.packages.len(),
+            "runtime image build begin"
+        );
+        let result = match timeout(
+            Duration::from_secs(IMAGE_BUILD_TIMEOUT_SECS),
+            self.run_build(spec, &tag),
+        )
+        .await
+        {
+            Ok(inner) => inner,
+            Err(_) => Err(RuntimeError::SpawnFailed(format!(
+                "Runtime image build for {tag} timed out after {IMAGE_BUILD_TIMEOUT_SECS} seconds"
+            ))),
+        };
+
+        match result {
+            Ok(digest) => {
+                info!(
+                    image = %tag,
+                    digest = %digest,
+                    elapsed_ms = started.elapsed().as_millis() as u64,
+                    "runtime image build complete"
+                );
+                Ok(BuiltRuntimeImage {
+                    tag,
+                    digest,
+                    reused: false,
+                })
+            }
+            Err(e) => {
+                error!(
+                    image = %tag,
+                    error = %e,
+                    elapsed_ms = started.elapsed().as_millis() as u64,
+                    "runtime image build failed"
+                );
+                Err(e)
+            }
+        }
+    }
+}
+
+/// Tar a build context containing only `Dockerfile`.
+fn build_context(dockerfile: &str) -> Result<Vec<u8>, RuntimeError> {
+    let mut tar_builder = tar::Builder::new(Vec::new());
+    let mut header = tar::Header::new_gnu();
+    header
+        .set_path("Dockerfile")
+        .map_err(|e| RuntimeError::SpawnFailed(format!("Failed to set tar path: {e}")))?;
+    header.set_size(dockerfile.len() as u64);
+    header.set_mode(0o644);
+    header.set_cksum();
+    tar_builder
+        .append(&header, dockerfile.as_bytes())
+        .map_err(|e| RuntimeError::SpawnFailed(format!("Failed to create tar: {e}")))?;
+    tar_builder
+        .into_inner()
+        .map_err(|e| RuntimeError::SpawnFailed(format!("Failed to finalize tar: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn build_context_contains_only_dockerfile() {
+        let data = build_context("FROM python:3.11-slim\n").unwrap();
+        let mut archive = tar::Archive::new(data.as_slice());
+        let entries: Vec<_> = archive
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let mut entry = entry.unwrap();
+                let path = entry.path().unwrap().display().to_string();
+                let mut body = String::new();
+                entry.read_to_string(&mut body).unwrap();
+                (path, body)
+            })
+            .collect();
+        assert_eq!(
+            entries,
+            vec![(
+                "Dockerfile".to_string(),
+                "FROM python:3.11-slim\n".to_string()
+            )]
+        );
+    }
+}
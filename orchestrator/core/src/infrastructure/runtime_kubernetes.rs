@@ -0,0 +1,1210 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Kubernetes Runtime
+//!
+//! [`K8sRuntime`] implements [`AgentRuntime`] by running every iteration as a
+//! Kubernetes Pod, for clusters where the orchestrator should not drive a
+//! container engine directly. It talks to the API server over plain REST.
+//!
+//! - `spawn()` verifies the image and creates the instance's NetworkPolicy and
+//!   NFS volume claims. The Pod itself is built but not created yet, because
+//!   the bootstrap command needs the iteration prompt.
+//! - `execute()` creates the Pod with `python <bootstrap> <prompt>` as the
+//!   agent container command, follows its log to completion and reads the
+//!   container's exit code.
+//! - `terminate()` deletes the Pod, NetworkPolicy, claims and volumes.
+//!
+//! The manifest's security policy is translated as follows:
+//!
+//! - Pod and container security contexts: non-root UID/GID from the runtime
+//!   config, no privilege escalation, all capabilities dropped,
+//!   `RuntimeDefault` seccomp, and no service account token.
+//! - `spec.security.network` becomes a NetworkPolicy. It denies all ingress
+//!   and keeps egress to cluster DNS and the orchestrator. `allow` adds the
+//!   IP/CIDR allowlist entries, `deny` permits everything except the CIDR
+//!   denylist entries, and `none` adds nothing. Hostname entries cannot be
+//!   expressed in a NetworkPolicy and are logged and skipped; tool calls apply
+//!   them in the orchestrator either way.
+//!
+//! Kubernetes merges stdout and stderr into one log. Lines tagged
+//! `[BOOTSTRAP ` by `bootstrap.py` become the iteration's logs and the rest is
+//! its output, matching what the Docker runtime reports.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Kubernetes Pod adapter implementing `AgentRuntime`
+
+use crate::domain::agent::NetworkPolicy;
+use crate::domain::node_config::{resolve_env_value, KubernetesRuntimeConfig};
+use crate::domain::runtime::{
+    AgentRuntime, InstanceId, InstanceStatus, RuntimeConfig, RuntimeError, SidecarOutput,
+    TaskInput, TaskOutput,
+};
+use crate::domain::volume::AccessMode;
+use crate::infrastructure::image_verifier::ImageSignatureVerifier;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Method;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::{debug, error, info, warn};
+
+const SERVICE_ACCOUNT_TOKEN: &str = "/var/run/secrets/kubernetes.io/serviceaccount/token";
+const SERVICE_ACCOUNT_CA: &str = "/var/run/secrets/kubernetes.io/serviceaccount/ca.crt";
+const FIELD_MANAGER: &str = "aegis-orchestrator";
+const BOOTSTRAP_CONFIG_MAP: &str = "aegis-bootstrap";
+const BOOTSTRAP_KEY: &str = "bootstrap.py";
+const DEFAULT_BOOTSTRAP_PATH: &str = "/usr/local/bin/aegis-bootstrap";
+const AGENT_CONTAINER: &str = "agent";
+const INSTANCE_LABEL: &str = "aegis.instance";
+const EXECUTION_ID_LABEL: &str = "aegis.execution_id";
+const MANAGED_BY_LABEL: &str = "app.kubernetes.io/managed-by";
+/// NFS capacity is informational; the gateway enforces volume quotas.
+const NFS_VOLUME_CAPACITY: &str = "1Gi";
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Container waiting reasons after which the Pod will never start.
+const FATAL_WAITING_REASONS: &[&str] = &[
+    "ErrImagePull",
+    "ImagePullBackOff",
+    "InvalidImageName",
+    "CreateContainerConfigError",
+    "CreateContainerError",
+];
+
+/// Configuration for [`K8sRuntime::new`].
+pub struct K8sRuntimeConfig {
+    pub kubernetes: KubernetesRuntimeConfig,
+    /// Host path of `bootstrap.py`, published to agent Pods as a ConfigMap.
+    pub bootstrap_script: String,
+    pub orchestrator_url: String,
+    /// NFS Server Gateway address as reachable from cluster nodes (ADR-036).
+    pub nfs_server_host: Option<String>,
+    pub nfs_port: u16,
+    pub nfs_mountport: u16,
+    pub image_verifier: Option<Arc<dyn ImageSignatureVerifier>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+enum KubeError {
+    #[error("Kubernetes API request failed: {0}")]
+    Transport(String),
+    #[error("Kubernetes API returned {status}: {message}")]
+    Status { status: u16, message: String },
+}
+
+impl KubeError {
+    fn is_not_found(&self) -> bool {
+        matches!(self, KubeError::Status { status: 404, .. })
+    }
+}
+
+/// Minimal Kubernetes REST client: bearer token auth plus JSON bodies.
+struct KubeClient {
+    http: reqwest::Client,
+    api_server: String,
+    token_file: Option<PathBuf>,
+}
+
+impl KubeClient {
+    fn new(config: &KubernetesRuntimeConfig) -> Result<Self, RuntimeError> {
+        let api_server = match &config.api_server {
+            Some(raw) => resolve_env_value(raw)
+                .map_err(|e| RuntimeError::SpawnFailed(format!("kubernetes.api_server: {e}")))?,
+            None => in_cluster_api_server().ok_or_else(|| {
+                RuntimeError::SpawnFailed(
+                    "kubernetes.api_server is not set and KUBERNETES_SERVICE_HOST is not \
+                     available (the orchestrator is not running in-cluster)"
+                        .to_string(),
+                )
+            })?,
+        };
+
+        let ca_cert = config
+            .ca_cert
+            .clone()
+            .or_else(|| existing(SERVICE_ACCOUNT_CA));
+        let mut builder = reqwest::Client::builder();
+        if let Some(path) = ca_cert {
+            let pem = std::fs::read(&path).map_err(|e| {
+                RuntimeError::SpawnFailed(format!("Failed to read Kubernetes CA {path}: {e}"))
+            })?;
+            let cert = reqwest::Certificate::from_pem(&pem).map_err(|e| {
+                RuntimeError::SpawnFailed(format!("Invalid Kubernetes CA {path}: {e}"))
+            })?;
+            builder = builder.add_root_certificate(cert);
+        }
+        let http = builder
+            .build()
+            .map_err(|e| RuntimeError::SpawnFailed(format!("Kubernetes HTTP client: {e}")))?;
+
+        Ok(Self {
+            http,
+            api_server: api_server.trim_end_matches('/').to_string(),
+            token_file: config
+                .token_file
+                .clone()
+                .or_else(|| existing(SERVICE_ACCOUNT_TOKEN))
+                .map(PathBuf::from),
+        })
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<(&str, &Value)>,
+    ) -> Result<reqwest::Response, KubeError> {
+        let mut request = self
+            .http
+            .request(method, format!("{}{}", self.api_server, path));
+        if let Some(file) = &self.token_file {
+            let token = tokio::fs::read_to_string(file)
+                .await
+                .map_err(|e| KubeError::Transport(format!("read token {}: {e}", file.display())))?;
+            request = request.bearer_auth(token.trim());
+        }
+        if let Some((content_type, body)) = body {
+            request = request
+                .header(CONTENT_TYPE, content_type)
+                .body(body.to_string());
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| KubeError::Transport(e.to_string()))?;
+        if response.status().is_success() {
+            return Ok(response);
+        }
+        let status = response.status().as_u16();
+        let text = response.text().await.unwrap_or_default();
+        let message = serde_json::from_str::<Value>(&text)
+            .ok()
+            .and_then(|v| v["message"].as_str().map(str::to_string))
+            .unwrap_or(text);
+        Err(KubeError::Status { status, message })
+    }
+
+    async fn get(&self, path: &str) -> Result<Value, KubeError> {
+        self.request(Method::GET, path, None)
+            .await?
+            .json()
+            .await
+            .map_err(|e| KubeError::Transport(e.to_string()))
+    }
+
+    async fn create(&self, collection: &str, manifest: &Value) -> Result<(), KubeError> {
+        self.request(
+            Method::POST,
+            collection,
+            Some(("application/json", manifest)),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Server-side apply `manifest` at `path` (create or update).
+    async fn apply(&self, path: &str, manifest: &Value) -> Result<(), KubeError> {
+        self.request(
+            Method::PATCH,
+            &format!("{path}?fieldManager={FIELD_MANAGER}&force=true"),
+            Some(("application/apply-patch+yaml", manifest)),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Delete the object at `path`; a missing object is not an error.
+    async fn delete(&self, path: &str) -> Result<(), KubeError> {
+        match self
+            .request(
+                Method::DELETE,
+                &format!("{path}?gracePeriodSeconds=0"),
+                None,
+            )
+            .await
+        {
+            Err(e) if e.is_not_found() => Ok(()),
+            other => other.map(|_| ()),
+        }
+    }
+}
+
+fn existing(path: &str) -> Option<String> {
+    Path::new(path).exists().then(|| path.to_string())
+}
+
+fn in_cluster_api_server() -> Option<String> {
+    let host = std::env::var("KUBERNETES_SERVICE_HOST").ok()?;
+    let port = std::env::var("KUBERNETES_SERVICE_PORT").unwrap_or_else(|_| "443".to_string());
+    Some(if host.contains(':') {
+        format!("https://[{host}]:{port}")
+    } else {
+        format!("https://{host}:{port}")
+    })
+}
+
+/// `entry` as a CIDR (`10.0.0.0/8`; a bare IP becomes `/32` or `/128`), if it is one.
+fn as_cidr(entry: &str) -> Option<String> {
+    let (addr, prefix) = match entry.split_once('/') {
+        Some((addr, prefix)) => (addr, Some(prefix)),
+        None => (entry, None),
+    };
+    let ip: IpAddr = addr.trim().parse().ok()?;
+    let max = if ip.is_ipv4() { 32 } else { 128 };
+    let prefix = match prefix {
+        Some(p) => p.trim().parse::<u8>().ok().filter(|p| *p <= max)?,
+        None => max,
+    };
+    Some(format!("{ip}/{prefix}"))
+}
+
+/// The NetworkPolicy for instance `name`, plus the entries that had to be
+/// skipped because they are not IPs or CIDRs.
+fn network_policy_manifest(
+    name: &str,
+    settings: &KubernetesRuntimeConfig,
+    policy: &NetworkPolicy,
+) -> (Value, Vec<String>) {
+    let orchestrator_namespace = settings
+        .orchestrator_namespace
+        .as_deref()
+        .unwrap_or(&settings.namespace);
+    let mut egress = vec![
+        json!({
+            "to": [{
+                "namespaceSelector": {},
+                "podSelector": { "matchLabels": { "k8s-app": "kube-dns" } },
+            }],
+            "ports": [
+                { "protocol": "UDP", "port": 53 },
+                { "protocol": "TCP", "port": 53 },
+            ],
+        }),
+        json!({
+            "to": [{
+                "namespaceSelector": {
+                    "matchLabels": { "kubernetes.io/metadata.name": orchestrator_namespace },
+                },
+                "podSelector": { "matchLabels": settings.orchestrator_selector },
+            }],
+        }),
+    ];
+
+    let mut skipped = Vec::new();
+    let mut cidrs = |entries: &[String]| -> Vec<String> {
+        entries
+            .iter()
+            .filter_map(|entry| {
+                let cidr = as_cidr(entry);
+                if cidr.is_none() {
+                    skipped.push(entry.clone());
+                }
+                cidr
+            })
+            .collect()
+    };
+    match policy.mode.as_str() {
+        "allow" => {
+            let allowed = cidrs(&policy.allowlist);
+            if !allowed.is_empty() {
+                let peers: Vec<Value> = allowed
+                    .iter()
+                    .map(|cidr| json!({ "ipBlock": { "cidr": cidr } }))
+                    .collect();
+                egress.push(json!({ "to": peers }));
+            }
+        }
+        "deny" => {
+            let denied = cidrs(&policy.denylist);
+            let (v6, v4): (Vec<String>, Vec<String>) =
+                denied.into_iter().partition(|cidr| cidr.contains(':'));
+            egress.push(json!({
+                "to": [
+                    { "ipBlock": { "cidr": "0.0.0.0/0", "except": v4 } },
+                    { "ipBlock": { "cidr": "::/0", "except": v6 } },
+                ],
+            }));
+        }
+        _ => {}
+    }
+
+    let manifest = json!({
+        "apiVersion": "networking.k8s.io/v1",
+        "kind": "NetworkPolicy",
+        "metadata": {
+            "name": name,
+            "namespace": settings.namespace,
+            "labels": { MANAGED_BY_LABEL: "aegis" },
+        },
+        "spec": {
+            "podSelector": { "matchLabels": { INSTANCE_LABEL: name } },
+            "policyTypes": ["Ingress", "Egress"],
+            "egress": egress,
+        },
+    });
+    (manifest, skipped)
+}
+
+/// Kubernetes `resources` for `limits`; requests equal limits.
+fn resource_requirements(limits: &crate::domain::runtime::ResourceLimits) -> Value {
+    let mut quantities = serde_json::Map::new();
+    if let Some(cpu) = limits.cpu_millis {
+        quantities.insert("cpu".to_string(), json!(format!("{cpu}m")));
+    }
+    if let Some(memory) = limits.memory_bytes {
+        quantities.insert("memory".to_string(), json!(memory.to_string()));
+    }
+    if let Some(disk) = limits.disk_bytes {
+        quantities.insert("ephemeral-storage".to_string(), json!(disk.to_string()));
+    }
+    json!({ "limits": quantities.clone(), "requests": quantities })
+}
+
+fn container_security_context() -> Value {
+    json!({
+        "allowPrivilegeEscalation": false,
+        "privileged": false,
+        "capabilities": { "drop": ["ALL"] },
+    })
+}
+
+/// Name of the PersistentVolume and claim backing volume `index` of `pod`.
+fn volume_claim_name(pod: &str, index: usize) -> String {
+    format!("{pod}-vol-{index}")
+}
+
+/// The Pod for instance `name`, without the agent container command (set by
+/// `execute()`).
+fn pod_manifest(
+    name: &str,
+    settings: &KubernetesRuntimeConfig,
+    config: &RuntimeConfig,
+    env: &[(String, String)],
+) -> Value {
+    let env: Vec<Value> = env
+        .iter()
+        .map(|(name, value)| json!({ "name": name, "value": value }))
+        .collect();
+
+    let mut volumes = Vec::new();
+    let mut mounts = Vec::new();
+    if config.bootstrap_path.is_none() {
+        volumes.push(json!({
+            "name": BOOTSTRAP_CONFIG_MAP,
+            "configMap": { "name": BOOTSTRAP_CONFIG_MAP },
+        }));
+        mounts.push(json!({
+            "name": BOOTSTRAP_CONFIG_MAP,
+            "mountPath": DEFAULT_BOOTSTRAP_PATH,
+            "subPath": BOOTSTRAP_KEY,
+            "readOnly": true,
+        }));
+    }
+    for (index, volume) in config.volumes.iter().enumerate() {
+        let read_only = matches!(volume.access_mode, AccessMode::ReadOnly);
+        let volume_name = format!("vol-{index}");
+        volumes.push(json!({
+            "name": volume_name,
+            "persistentVolumeClaim": {
+                "claimName": volume_claim_name(name, index),
+                "readOnly": read_only,
+            },
+        }));
+        mounts.push(json!({
+            "name": volume_name,
+            "mountPath": volume.mount_point.display().to_string(),
+            "readOnly": read_only,
+        }));
+    }
+
+    let mut containers = vec![json!({
+        "name": AGENT_CONTAINER,
+        "image": config.image,
+        "imagePullPolicy": config.image_pull_policy.to_string(),
+        "env": env,
+        "resources": resource_requirements(&config.resources),
+        "securityContext": container_security_context(),
+        "volumeMounts": mounts,
+    })];
+    for sidecar in &config.sidecars {
+        let env: Vec<Value> = sidecar
+            .env
+            .iter()
+            .map(|(name, value)| json!({ "name": name, "value": value }))
+            .collect();
+        let mut container = json!({
+            "name": format!("sidecar-{}", sidecar.name),
+            "image": sidecar.image,
+            "imagePullPolicy": sidecar.image_pull_policy.to_string(),
+            "env": env,
+            "resources": resource_requirements(&sidecar.resources),
+            "securityContext": container_security_context(),
+        });
+        if !sidecar.command.is_empty() {
+            container["command"] = json!(sidecar.command);
+        }
+        containers.push(container);
+    }
+
+    let mut pod = json!({
+        "apiVersion": "v1",
+        "kind": "Pod",
+        "metadata": {
+            "name": name,
+            "namespace": settings.namespace,
+            "labels": {
+                MANAGED_BY_LABEL: "aegis",
+                INSTANCE_LABEL: name,
+                EXECUTION_ID_LABEL: config.execution_id.to_string(),
+            },
+        },
+        "spec": {
+            "restartPolicy": "Never",
+            "automountServiceAccountToken": false,
+            "enableServiceLinks": false,
+            "securityContext": {
+                "runAsUser": config.container_uid,
+                "runAsGroup": config.container_gid,
+                "fsGroup": config.container_gid,
+                "runAsNonRoot": config.container_uid != 0,
+                "seccompProfile": { "type": "RuntimeDefault" },
+            },
+            "containers": containers,
+            "volumes": volumes,
+        },
+    });
+    let spec = &mut pod["spec"];
+    if let Some(timeout) = config.resources.timeout_seconds {
+        spec["activeDeadlineSeconds"] = json!(timeout);
+    }
+    if let Some(account) = &settings.service_account {
+        spec["serviceAccountName"] = json!(account);
+    }
+    if !settings.image_pull_secrets.is_empty() {
+        let secrets: Vec<Value> = settings
+            .image_pull_secrets
+            .iter()
+            .map(|name| json!({ "name": name }))
+            .collect();
+        spec["imagePullSecrets"] = json!(secrets);
+    }
+    let mut node_selector = settings.node_selector.clone();
+    if let Some(platform) = &config.platform {
+        let mut parts = platform.split('/');
+        if let (Some(os), Some(arch)) = (parts.next(), parts.next()) {
+            node_selector.insert("kubernetes.io/os".to_string(), os.to_string());
+            node_selector.insert("kubernetes.io/arch".to_string(), arch.to_string());
+        }
+    }
+    if !node_selector.is_empty() {
+        spec["nodeSelector"] = json!(node_selector);
+    }
+    pod
+}
+
+/// Split a merged Pod log into the agent's output and its `[BOOTSTRAP ` lines.
+fn split_bootstrap_log(log: &str) -> (String, Vec<String>) {
+    let mut output = String::new();
+    let mut logs = Vec::new();
+    for line in log.split_inclusive('\n') {
+        if line.starts_with("[BOOTSTRAP ") {
+            logs.push(line.trim_end_matches(['\r', '\n']).to_string());
+        } else {
+            output.push_str(line);
+        }
+    }
+    (output, logs)
+}
+
+/// Status of the agent container in a Pod object.
+fn agent_container_state(pod: &Value) -> Option<&Value> {
+    pod["status"]["containerStatuses"]
+        .as_array()?
+        .iter()
+        .find(|status| status["name"] == AGENT_CONTAINER)
+        .map(|status| &status["state"])
+}
+
+struct PodInstance {
+    /// Pod manifest; created on `execute()`.
+    manifest: Value,
+    bootstrap_path: String,
+    keep_on_failure: bool,
+    network_policy: bool,
+    volume_claims: Vec<String>,
+    sidecar_containers: Vec<(String, String)>,
+    sidecar_output: Vec<SidecarOutput>,
+}
+
+/// [`AgentRuntime`] that runs each iteration as a Kubernetes Pod.
+pub struct K8sRuntime {
+    client: KubeClient,
+    settings: KubernetesRuntimeConfig,
+    orchestrator_url: String,
+    nfs_server_host: Option<String>,
+    nfs_port: u16,
+    nfs_mountport: u16,
+    image_verifier: Option<Arc<dyn ImageSignatureVerifier>>,
+    instances: RwLock<HashMap<String, PodInstance>>,
+}
+
+impl K8sRuntime {
+    /// Connect to the cluster and publish `bootstrap.py` as the
+    /// `aegis-bootstrap` ConfigMap in the agent namespace.
+    pub async fn new(config: K8sRuntimeConfig) -> Result<Self, RuntimeError> {
+        let client = KubeClient::new(&config.kubernetes)?;
+        let bootstrap = std::fs::read_to_string(&config.bootstrap_script).map_err(|e| {
+            RuntimeError::SpawnFailed(format!(
+                "Failed to read bootstrap script at {}: {e}",
+                config.bootstrap_script
+            ))
+        })?;
+        let namespace = &config.kubernetes.namespace;
+        client
+            .apply(
+                &format!("/api/v1/namespaces/{namespace}/configmaps/{BOOTSTRAP_CONFIG_MAP}"),
+                &json!({
+                    "apiVersion": "v1",
+                    "kind": "ConfigMap",
+                    "metadata": {
+                        "name": BOOTSTRAP_CONFIG_MAP,
+                        "namespace": namespace,
+                        "labels": { MANAGED_BY_LABEL: "aegis" },
+                    },
+                    "data": { BOOTSTRAP_KEY: bootstrap },
+                }),
+            )
+            .await
+            .map_err(|e| {
+                RuntimeError::SpawnFailed(format!(
+                    "Cannot publish bootstrap ConfigMap in namespace '{namespace}': {e}"
+                ))
+            })?;
+        info!(
+            api_server = %client.api_server,
+            namespace = %namespace,
+            "Kubernetes runtime connected"
+        );
+
+        Ok(Self {
+            client,
+            settings: config.kubernetes,
+            orchestrator_url: config.orchestrator_url,
+            nfs_server_host: config.nfs_server_host,
+            nfs_port: config.nfs_port,
+            nfs_mountport: config.nfs_mountport,
+            image_verifier: config.image_verifier,
+            instances: RwLock::new(HashMap::new()),
+        })
+    }
+
+    fn namespaced(&self, resource: &str) -> String {
+        format!("/api/v1/namespaces/{}/{resource}", self.settings.namespace)
+    }
+
+    fn network_policy_path(&self, name: &str) -> String {
+        format!(
+            "/apis/networking.k8s.io/v1/namespaces/{}/networkpolicies/{name}",
+            self.settings.namespace
+        )
+    }
+
+    /// Create the PersistentVolume/claim pair for each NFS volume mount.
+    async fn create_volume_claims(
+        &self,
+        name: &str,
+        config: &RuntimeConfig,
+    ) -> Result<Vec<String>, RuntimeError> {
+        let nfs_host = self.nfs_server_host.as_deref().unwrap_or("127.0.0.1");
+        let mut claims = Vec::new();
+        for (index, volume) in config.volumes.iter().enumerate() {
+            let claim = volume_claim_name(name, index);
+            let read_only = matches!(volume.access_mode, AccessMode::ReadOnly);
+            let labels = json!({ MANAGED_BY_LABEL: "aegis", INSTANCE_LABEL: name });
+            let volume_manifest = json!({
+                "apiVersion": "v1",
+                "kind": "PersistentVolume",
+                "metadata": { "name": claim, "labels": labels.clone() },
+                "spec": {
+                    "capacity": { "storage": NFS_VOLUME_CAPACITY },
+                    "accessModes": ["ReadWriteMany"],
+                    "persistentVolumeReclaimPolicy": "Retain",
+                    "storageClassName": "",
+                    "mountOptions": [
+                        "nfsvers=3",
+                        "proto=tcp",
+                        format!("port={}", self.nfs_port),
+                        format!("mountport={}", self.nfs_mountport),
+                        "soft",
+                        "timeo=10",
+                        "nolock",
+                    ],
+                    "nfs": {
+                        "server": nfs_host,
+                        "path": volume.remote_path,
+                        "readOnly": read_only,
+                    },
+                    "claimRef": { "namespace": self.settings.namespace, "name": claim },
+                },
+            });
+            let claim_manifest = json!({
+                "apiVersion": "v1",
+                "kind": "PersistentVolumeClaim",
+                "metadata": {
+                    "name": claim,
+                    "namespace": self.settings.namespace,
+                    "labels": labels,
+                },
+                "spec": {
+                    "accessModes": ["ReadWriteMany"],
+                    "storageClassName": "",
+                    "volumeName": claim,
+                    "resources": { "requests": { "storage": NFS_VOLUME_CAPACITY } },
+                },
+            });
+            // Record the claim first so a partial failure is still cleaned up.
+            claims.push(claim.clone());
+            let created = match self
+                .client
+                .apply(
+                    &format!("/api/v1/persistentvolumes/{claim}"),
+                    &volume_manifest,
+                )
+                .await
+            {
+                Ok(()) => {
+                    self.client
+                        .apply(
+                            &self.namespaced(&format!("persistentvolumeclaims/{claim}")),
+                            &claim_manifest,
+                        )
+                        .await
+                }
+                Err(e) => Err(e),
+            };
+            created.map_err(|e| {
+                RuntimeError::SpawnFailed(format!(
+                    "Failed to create volume claim for {}: {e}",
+                    volume.volume_id
+                ))
+            })?;
+        }
+        Ok(claims)
+    }
+
+    async fn delete_resources(
+        &self,
+        name: &str,
+        network_policy: bool,
+        volume_claims: &[String],
+    ) -> Result<(), KubeError> {
+        self.client
+            .delete(&self.namespaced(&format!("pods/{name}")))
+            .await?;
+        if network_policy {
+            self.client.delete(&self.network_policy_path(name)).await?;
+        }
+        for claim in volume_claims {
+            self.client
+                .delete(&self.namespaced(&format!("persistentvolumeclaims/{claim}")))
+                .await?;
+            self.client
+                .delete(&format!("/api/v1/persistentvolumes/{claim}"))
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn get_pod(&self, name: &str) -> Result<Value, RuntimeError> {
+        self.client
+            .get(&self.namespaced(&format!("pods/{name}")))
+            .await
+            .map_err(|e| match e {
+                e if e.is_not_found() => RuntimeError::InstanceNotFound(name.to_string()),
+                e => RuntimeError::ExecutionFailed(e.to_string()),
+            })
+    }
+
+    /// Wait until the agent container has started (or already finished).
+    async fn wait_for_start(&self, name: &str) -> Result<(), RuntimeError> {
+        loop {
+            let pod = self.get_pod(name).await?;
+            if let Some(state) = agent_container_state(&pod) {
+                if state.get("running").is_some() || state.get("terminated").is_some() {
+                    return Ok(());
+                }
+                let reason = state["waiting"]["reason"].as_str().unwrap_or_default();
+                if FATAL_WAITING_REASONS.contains(&reason) {
+                    return Err(RuntimeError::ExecutionFailed(format!(
+                        "Pod {name} cannot start: {reason}: {}",
+                        state["waiting"]["message"].as_str().unwrap_or_default()
+                    )));
+                }
+            }
+            if pod["status"]["phase"] == "Failed" {
+                return Err(RuntimeError::ExecutionFailed(format!(
+                    "Pod {name} failed before start: {}",
+                    pod["status"]["message"].as_str().unwrap_or_default()
+                )));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    /// Follow the agent container log until it exits, echoing each line.
+    async fn follow_log(&self, name: &str) -> Result<String, RuntimeError> {
+        let response = self
+            .client
+            .request(
+                Method::GET,
+                &self.namespaced(&format!(
+                    "pods/{name}/log?container={AGENT_CONTAINER}&follow=true"
+                )),
+                None,
+            )
+            .await
+            .map_err(|e| RuntimeError::ExecutionFailed(e.to_string()))?;
+
+        let mut log = String::new();
+        let mut pending = Vec::new();
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| RuntimeError::ExecutionFailed(e.to_string()))?;
+            pending.extend_from_slice(&chunk);
+            while let Some(end) = pending.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = pending.drain(..=end).collect();
+                let line = String::from_utf8_lossy(&line);
+                if !line.trim().is_empty() {
+                    tracing::info!(
+                        target: "child_runtime",
+                        container_id = name,
+                        stream = "log",
+                        "{}",
+                        line.trim_end()
+                    );
+                }
+                log.push_str(&line);
+            }
+        }
+        log.push_str(&String::from_utf8_lossy(&pending));
+        Ok(log)
+    }
+
+    /// Wait for the agent container's exit code.
+    async fn wait_for_exit(&self, name: &str) -> Result<i64, RuntimeError> {
+        loop {
+            let pod = self.get_pod(name).await?;
+            if let Some(code) = agent_container_state(&pod)
+                .and_then(|state| state["terminated"]["exitCode"].as_i64())
+            {
+                return Ok(code);
+            }
+            if pod["status"]["phase"] == "Failed" {
+                return Err(RuntimeError::ExecutionFailed(format!(
+                    "Pod {name} failed: {} {}",
+                    pod["status"]["reason"].as_str().unwrap_or_default(),
+                    pod["status"]["message"].as_str().unwrap_or_default()
+                )));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    async fn collect_sidecar_output(&self, name: &str) {
+        let sidecars = match self.instances.read().await.get(name) {
+            Some(instance) => instance.sidecar_containers.clone(),
+            None => return,
+        };
+        let mut output = Vec::new();
+        for (container, stream) in sidecars {
+            let path = self.namespaced(&format!("pods/{name}/log?container={container}"));
+            match self.client.request(Method::GET, &path, None).await {
+                Ok(response) => {
+                    let content = response.text().await.unwrap_or_default();
+                    if !content.is_empty() {
+                        output.push(SidecarOutput { stream, content });
+                    }
+                }
+                Err(e) => warn!(pod = name, container = %container, "Sidecar log unavailable: {e}"),
+            }
+        }
+        if let Some(instance) = self.instances.write().await.get_mut(name) {
+            instance.sidecar_output = output;
+        }
+    }
+}
+
+#[async_trait]
+impl AgentRuntime for K8sRuntime {
+    async fn spawn(&self, config: RuntimeConfig) -> Result<InstanceId, RuntimeError> {
+        config.validate_isolation()?;
+        if config.network_flow_capture.is_some() {
+            return Err(RuntimeError::SpawnFailed(
+                "network flow capture is not supported by the Kubernetes runtime".to_string(),
+            ));
+        }
+
+        // Supply-chain gate: refuse untrusted images before anything is created.
+        if let Some(verifier) = &self.image_verifier {
+            if let Err(e) = verifier.verify(&config.image, &config.tenant_id).await {
+                error!(
+                    target: "image_verification",
+                    image = %config.image,
+                    execution_id = %config.execution_id,
+                    tenant_id = %config.tenant_id,
+                    "image verification failed: {e}"
+                );
+                return Err(e);
+            }
+        }
+
+        let name = format!("aegis-agent-{}", uuid::Uuid::new_v4());
+
+        let (filtered_env, blocked_vars) = crate::domain::env_guard::filter_env_vars(&config.env);
+        if !blocked_vars.is_empty() {
+            warn!(
+                execution_id = %config.execution_id,
+                blocked = ?blocked_vars,
+                "Blocked orchestrator-internal env vars from agent pod"
+            );
+        }
+        let mut env: Vec<(String, String)> = filtered_env.into_iter().collect();
+        env.sort();
+        env.push((
+            "AEGIS_ORCHESTRATOR_URL".to_string(),
+            self.orchestrator_url.clone(),
+        ));
+        if tracing::level_enabled!(tracing::Level::DEBUG) {
+            env.push(("AEGIS_BOOTSTRAP_DEBUG".to_string(), "true".to_string()));
+        }
+
+        let network_policy = match &config.network_policy {
+            Some(policy) if self.settings.network_policies => {
+                let (manifest, skipped) = network_policy_manifest(&name, &self.settings, policy);
+                if !skipped.is_empty() {
+                    warn!(
+                        execution_id = %config.execution_id,
+                        entries = ?skipped,
+                        "Hostname network policy entries cannot be expressed as a NetworkPolicy; skipped"
+                    );
+                }
+                self.client
+                    .apply(&self.network_policy_path(&name), &manifest)
+                    .await
+                    .map_err(|e| {
+                        RuntimeError::SpawnFailed(format!("Failed to create NetworkPolicy: {e}"))
+                    })?;
+                true
+            }
+            _ => false,
+        };
+
+        let volume_claims = match self.create_volume_claims(&name, &config).await {
+            Ok(claims) => claims,
+            Err(e) => {
+                let claims: Vec<String> = (0..config.volumes.len())
+                    .map(|index| volume_claim_name(&name, index))
+                    .collect();
+                let _ = self.delete_resources(&name, network_policy, &claims).await;
+                return Err(e);
+            }
+        };
+
+        let instance = PodInstance {
+            manifest: pod_manifest(&name, &self.settings, &config, &env),
+            bootstrap_path: config
+                .bootstrap_path
+                .clone()
+                .unwrap_or_else(|| DEFAULT_BOOTSTRAP_PATH.to_string()),
+            keep_on_failure: config.keep_container_on_failure,
+            network_policy,
+            volume_claims,
+            sidecar_containers: config
+                .sidecars
+                .iter()
+                .map(|s| (format!("sidecar-{}", s.name), s.console_stream()))
+                .collect(),
+            sidecar_output: Vec::new(),
+        };
+        self.instances.write().await.insert(name.clone(), instance);
+        info!(
+            execution_id = %config.execution_id,
+            namespace = %self.settings.namespace,
+            pod = %name,
+            "Prepared agent pod"
+        );
+        Ok(InstanceId::new(name))
+    }
+
+    async fn execute(&self, id: &InstanceId, input: TaskInput) -> Result<TaskOutput, RuntimeError> {
+        let name = id.as_str();
+        let (mut manifest, bootstrap_path, keep_on_failure) = {
+            let instances = self.instances.read().await;
+            let instance = instances
+                .get(name)
+                .ok_or_else(|| RuntimeError::InstanceNotFound(name.to_string()))?;
+            (
+                instance.manifest.clone(),
+                instance.bootstrap_path.clone(),
+                instance.keep_on_failure,
+            )
+        };
+        manifest["spec"]["containers"][0]["command"] =
+            json!(["python", bootstrap_path, input.prompt]);
+
+        debug!(
+            pod = name,
+            prompt_len = input.prompt.len(),
+            "Creating agent pod"
+        );
+        self.client
+            .create(&self.namespaced("pods"), &manifest)
+            .await
+            .map_err(|e| RuntimeError::ExecutionFailed(format!("Failed to create pod: {e}")))?;
+
+        self.wait_for_start(name).await?;
+        let log = self.follow_log(name).await?;
+        let exit_code = self.wait_for_exit(name).await?;
+        self.collect_sidecar_output(name).await;
+
+        let (output, logs) = split_bootstrap_log(&log);
+        debug!(
+            pod = name,
+            exit_code = exit_code,
+            log_lines = logs.len(),
+            "Agent pod completed"
+        );
+
+        if exit_code != 0 && keep_on_failure {
+            let error_msg = if !logs.is_empty() {
+                logs.join("\n")
+            } else {
+                format!("Bootstrap script exited with code {exit_code}")
+            };
+            return Err(RuntimeError::ExecutionFailed(error_msg));
+        }
+        Ok(TaskOutput {
+            result: Value::String(output),
+            logs,
+            tool_calls: vec![],
+            exit_code,
+            trajectory: vec![],
+        })
+    }
+
+    async fn terminate(&self, id: &InstanceId) -> Result<(), RuntimeError> {
+        let name = id.as_str();
+        let (network_policy, volume_claims) = match self.instances.read().await.get(name) {
+            Some(instance) => (instance.network_policy, instance.volume_claims.clone()),
+            // Unknown instance (e.g. from before a restart): remove what may exist.
+            None => (self.settings.network_policies, Vec::new()),
+        };
+        self.delete_resources(name, network_policy, &volume_claims)
+            .await
+            .map_err(|e| RuntimeError::TerminationFailed(e.to_string()))?;
+        self.instances.write().await.remove(name);
+        info!(pod = name, "Cleaned up agent pod");
+        Ok(())
+    }
+
+    async fn status(&self, id: &InstanceId) -> Result<InstanceStatus, RuntimeError> {
+        let name = id.as_str();
+        let prepared = self.instances.read().await.contains_key(name);
+        let pod = match self.get_pod(name).await {
+            Ok(pod) => pod,
+            // Spawned but not yet executed: the Pod does not exist yet.
+            Err(RuntimeError::InstanceNotFound(_)) if prepared => {
+                json!({ "status": { "phase": "Prepared" } })
+            }
+            Err(e) => return Err(e),
+        };
+        let uptime_seconds = pod["status"]["startTime"]
+            .as_str()
+            .and_then(|t| t.parse::<DateTime<Utc>>().ok())
+            .map(|started| (Utc::now() - started).num_seconds().max(0) as u64)
+            .unwrap_or(0);
+        Ok(InstanceStatus {
+            id: id.clone(),
+            state: pod["status"]["phase"]
+                .as_str()
+                .unwrap_or("Unknown")
+                .to_lowercase(),
+            uptime_seconds,
+            memory_usage_mb: 0,
+            cpu_usage_percent: 0.0,
+        })
+    }
+
+    async fn drain_sidecar_output(&self, id: &InstanceId) -> Vec<SidecarOutput> {
+        self.instances
+            .write()
+            .await
+            .get_mut(id.as_str())
+            .map(|instance| std::mem::take(&mut instance.sidecar_output))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::runtime::ResourceLimits;
+
+    fn settings() -> KubernetesRuntimeConfig {
+        serde_json::from_value(json!({
+            "orchestrator_namespace": "aegis-system",
+            "orchestrator_selector": { "app": "aegis" },
+        }))
+        .unwrap()
+    }
+
+    fn policy(mode: &str, allow: &[&str], deny: &[&str]) -> NetworkPolicy {
+        NetworkPolicy {
+            mode: mode.to_string(),
+            allowlist: allow.iter().map(|s| s.to_string()).collect(),
+            denylist: deny.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn network_policy_keeps_dns_and_orchestrator_and_adds_cidrs() {
+        let (manifest, skipped) = network_policy_manifest(
+            "aegis-agent-1",
+            &settings(),
+            &policy("allow", &["10.0.0.0/8", "1.2.3.4", "api.github.com"], &[]),
+        );
+        assert_eq!(skipped, vec!["api.github.com".to_string()]);
+        let spec = &manifest["spec"];
+        assert_eq!(
+            spec["podSelector"]["matchLabels"][INSTANCE_LABEL],
+            "aegis-agent-1"
+        );
+        assert_eq!(spec["policyTypes"], json!(["Ingress", "Egress"]));
+        assert!(spec.get("ingress").is_none());
+        let egress = spec["egress"].as_array().unwrap();
+        assert_eq!(egress.len(), 3);
+        assert_eq!(
+            egress[1]["to"][0]["namespaceSelector"]["matchLabels"]["kubernetes.io/metadata.name"],
+            "aegis-system"
+        );
+        assert_eq!(
+            egress[1]["to"][0]["podSelector"]["matchLabels"]["app"],
+            "aegis"
+        );
+        assert_eq!(egress[2]["to"][0]["ipBlock"]["cidr"], "10.0.0.0/8");
+        assert_eq!(egress[2]["to"][1]["ipBlock"]["cidr"], "1.2.3.4/32");
+
+        let (none, _) = network_policy_manifest("p", &settings(), &policy("none", &[], &[]));
+        assert_eq!(none["spec"]["egress"].as_array().unwrap().len(), 2);
+
+        let (deny, _) = network_policy_manifest(
+            "p",
+            &settings(),
+            &policy("deny", &[], &["169.254.169.254", "fd00::/8"]),
+        );
+        let open = &deny["spec"]["egress"][2]["to"];
+        assert_eq!(open[0]["ipBlock"]["except"], json!(["169.254.169.254/32"]));
+        assert_eq!(open[1]["ipBlock"]["except"], json!(["fd00::/8"]));
+    }
+
+    #[test]
+    fn cidr_parsing_rejects_hostnames_and_bad_prefixes() {
+        assert_eq!(as_cidr("192.168.0.0/16").as_deref(), Some("192.168.0.0/16"));
+        assert_eq!(as_cidr("::1").as_deref(), Some("::1/128"));
+        assert_eq!(as_cidr("10.0.0.0/33"), None);
+        assert_eq!(as_cidr("*.example.com"), None);
+    }
+
+    #[test]
+    fn pod_manifest_applies_security_context_and_limits() {
+        let config = RuntimeConfig {
+            language: "python".to_string(),
+            version: "3.12".to_string(),
+            isolation: "docker".to_string(),
+            env: HashMap::new(),
+            image_pull_policy: crate::domain::agent::ImagePullPolicy::IfNotPresent,
+            container_uid: 1000,
+            container_gid: 1000,
+            resources: ResourceLimits {
+                cpu_millis: Some(500),
+                memory_bytes: Some(536_870_912),
+                disk_bytes: None,
+                timeout_seconds: Some(300),
+            },
+            execution: crate::domain::agent::ExecutionStrategy {
+                mode: crate::domain::agent::ExecutionMode::Iterative,
+                max_retries: 5,
+                iteration_timeout: None,
+                llm_timeout_seconds: 300,
+                validation: None,
+                tool_validation: None,
+                delivery: None,
+                approval_mode: crate::domain::agent::ApprovalMode::Off,
+                approval_timeout_seconds: None,
+                approval_on_timeout: None,
+            },
+            volumes: Vec::new(),
+            keep_container_on_failure: false,
+            image: "python:3.12".to_string(),
+            bootstrap_path: None,
+            execution_id: crate::domain::execution::ExecutionId::new(),
+            tenant_id: crate::domain::tenant::TenantId::default(),
+            sidecars: Vec::new(),
+            platform: Some("linux/arm64".to_string()),
+            network_policy: None,
+            network_flow_capture: None,
+        };
+        let pod = pod_manifest(
+            "aegis-agent-1",
+            &settings(),
+            &config,
+            &[("A".to_string(), "1".to_string())],
+        );
+
+        let spec = &pod["spec"];
+        assert_eq!(spec["restartPolicy"], "Never");
+        assert_eq!(spec["automountServiceAccountToken"], false);
+        assert_eq!(spec["activeDeadlineSeconds"], 300);
+        assert_eq!(spec["securityContext"]["runAsUser"], 1000);
+        assert_eq!(spec["securityContext"]["runAsNonRoot"], true);
+        assert_eq!(spec["nodeSelector"]["kubernetes.io/arch"], "arm64");
+
+        let agent = &spec["containers"][0];
+        assert_eq!(agent["name"], AGENT_CONTAINER);
+        assert_eq!(agent["resources"]["limits"]["cpu"], "500m");
+        assert_eq!(agent["resources"]["limits"]["memory"], "536870912");
+        assert_eq!(
+            agent["securityContext"]["capabilities"]["drop"],
+            json!(["ALL"])
+        );
+        assert_eq!(agent["securityContext"]["allowPrivilegeEscalation"], false);
+        assert_eq!(
+            agent["volumeMounts"][0]["mountPath"],
+            DEFAULT_BOOTSTRAP_PATH
+        );
+        assert_eq!(agent["env"][0], json!({ "name": "A", "value": "1" }));
+    }
+
+    #[test]
+    fn split_bootstrap_log_separates_tagged_lines() {
+        let (output, logs) = split_bootstrap_log(
+            "[BOOTSTRAP DEBUG] calling LLM\n{\"answer\": 42}\n[BOOTSTRAP INFO] done\n",
+        );
+        assert_eq!(output, "{\"answer\": 42}\n");
+        assert_eq!(
+            logs,
+            vec![
+                "[BOOTSTRAP DEBUG] calling LLM".to_string(),
+                "[BOOTSTRAP INFO] done".to_string()
+            ]
+        );
+    }
+}
@@ -0,0 +1,949 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Nomad Runtime
+//!
+//! [`NomadRuntime`] implements [`AgentRuntime`] on a HashiCorp Nomad cluster
+//! through the Nomad HTTP API, using the Docker task driver.
+//!
+//! - `spawn()` registers a parameterized batch job for the instance. The job
+//!   requires a `prompt` meta value, and its agent task runs
+//!   `python <bootstrap> ${NOMAD_META_prompt}`. `bootstrap.py` is delivered as
+//!   an embedded template into the task's `local/` directory.
+//! - `execute()` dispatches the job once, waits for the allocation to start,
+//!   follows the agent task's stdout, and reads the exit code from its
+//!   `Terminated` event.
+//! - `terminate()` purges the dispatched jobs and the parent job.
+//!
+//! `ResourceLimits` map to task resources: CPU millicores to MHz via
+//! `cpu_mhz_per_core`, memory to MB, and disk to the group's ephemeral disk.
+//! Restarts and rescheduling are disabled, so one dispatch is exactly one
+//! iteration attempt.
+//!
+//! Allocation status is reconciled into the iteration result:
+//!
+//! - A failed placement fails the iteration with Nomad's reason.
+//! - A `failed` or `lost` allocation (e.g. its node went down) fails the
+//!   iteration with the task's last event.
+//! - `status()` reports the allocation's client status.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Nomad batch-job adapter implementing `AgentRuntime`
+
+use crate::domain::node_config::{resolve_env_value, NomadRuntimeConfig};
+use crate::domain::runtime::{
+    AgentRuntime, InstanceId, InstanceStatus, ResourceLimits, RuntimeConfig, RuntimeError,
+    SidecarOutput, TaskInput, TaskOutput,
+};
+use crate::domain::volume::AccessMode;
+use crate::infrastructure::image_verifier::ImageSignatureVerifier;
+use async_trait::async_trait;
+use chrono::Utc;
+use futures::StreamExt;
+use reqwest::Method;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::RwLock;
+use tracing::{debug, error, info, warn};
+
+const AGENT_TASK: &str = "agent";
+const TASK_GROUP: &str = "agent";
+const PROMPT_META: &str = "prompt";
+/// In-task path of the bootstrap template (the task's `local/` directory).
+const BOOTSTRAP_PATH: &str = "/local/aegis-bootstrap";
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// Template delimiters that never occur in `bootstrap.py`, so Nomad copies it
+/// verbatim instead of rendering `{{ }}`.
+const TEMPLATE_LEFT_DELIM: &str = "[[aegis-no-template";
+const TEMPLATE_RIGHT_DELIM: &str = "aegis-no-template]]";
+const MIB: u64 = 1024 * 1024;
+
+/// Configuration for [`NomadRuntime::new`].
+pub struct NomadDriverConfig {
+    pub nomad: NomadRuntimeConfig,
+    /// Host path of `bootstrap.py`, embedded into every job.
+    pub bootstrap_script: String,
+    pub orchestrator_url: String,
+    /// NFS Server Gateway address as reachable from Nomad clients (ADR-036).
+    pub nfs_server_host: Option<String>,
+    pub nfs_port: u16,
+    pub nfs_mountport: u16,
+    pub image_verifier: Option<Arc<dyn ImageSignatureVerifier>>,
+}
+
+#[derive(Debug, thiserror::Error)]
+enum NomadError {
+    #[error("Nomad API request failed: {0}")]
+    Transport(String),
+    #[error("Nomad API returned {status}: {message}")]
+    Status { status: u16, message: String },
+}
+
+impl NomadError {
+    fn is_not_found(&self) -> bool {
+        matches!(self, NomadError::Status { status: 404, .. })
+    }
+}
+
+impl From<NomadError> for RuntimeError {
+    fn from(e: NomadError) -> Self {
+        RuntimeError::ExecutionFailed(e.to_string())
+    }
+}
+
+/// Minimal Nomad HTTP API client.
+struct NomadClient {
+    http: reqwest::Client,
+    address: String,
+    token: Option<String>,
+    /// `namespace`/`region` query parameters appended to every request.
+    scope: Vec<(&'static str, String)>,
+}
+
+impl NomadClient {
+    fn new(config: &NomadRuntimeConfig) -> Result<Self, RuntimeError> {
+        let address = resolve_env_value(&config.address)
+            .map_err(|e| RuntimeError::SpawnFailed(format!("nomad.address: {e}")))?;
+        let token = config
+            .token
+            .as_deref()
+            .map(resolve_env_value)
+            .transpose()
+            .map_err(|e| RuntimeError::SpawnFailed(format!("nomad.token: {e}")))?;
+
+        let mut builder = reqwest::Client::builder();
+        if let Some(path) = &config.ca_cert {
+            let pem = std::fs::read(path).map_err(|e| {
+                RuntimeError::SpawnFailed(format!("Failed to read Nomad CA {path}: {e}"))
+            })?;
+            let cert = reqwest::Certificate::from_pem(&pem)
+                .map_err(|e| RuntimeError::SpawnFailed(format!("Invalid Nomad CA {path}: {e}")))?;
+            builder = builder.add_root_certificate(cert);
+        }
+        let http = builder
+            .build()
+            .map_err(|e| RuntimeError::SpawnFailed(format!("Nomad HTTP client: {e}")))?;
+
+        let mut scope = Vec::new();
+        if let Some(namespace) = &config.namespace {
+            scope.push(("namespace", namespace.clone()));
+        }
+        if let Some(region) = &config.region {
+            scope.push(("region", region.clone()));
+        }
+        Ok(Self {
+            http,
+            address: address.trim_end_matches('/').to_string(),
+            token,
+            scope,
+        })
+    }
+
+    async fn request(
+        &self,
+        method: Method,
+        path: &str,
+        query: &[(&str, &str)],
+        body: Option<&Value>,
+    ) -> Result<reqwest::Response, NomadError> {
+        let mut request = self
+            .http
+            .request(method, format!("{}{}", self.address, path))
+            .query(&self.scope)
+            .query(query);
+        if let Some(token) = &self.token {
+            request = request.header("X-Nomad-Token", token);
+        }
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+        let response = request
+            .send()
+            .await
+            .map_err(|e| NomadError::Transport(e.to_string()))?;
+        if response.status().is_success() {
+            return Ok(response);
+        }
+        let status = response.status().as_u16();
+        let message = response.text().await.unwrap_or_default().trim().to_string();
+        Err(NomadError::Status { status, message })
+    }
+
+    async fn json(
+        &self,
+        method: Method,
+        path: &str,
+        body: Option<&Value>,
+    ) -> Result<Value, NomadError> {
+        self.request(method, path, &[], body)
+            .await?
+            .json()
+            .await
+            .map_err(|e| NomadError::Transport(e.to_string()))
+    }
+}
+
+/// Nomad `cpu` (MHz) for `cpu_millis` millicores.
+fn cpu_mhz(cpu_millis: u32, mhz_per_core: u32) -> u64 {
+    (u64::from(cpu_millis) * u64::from(mhz_per_core)).div_ceil(1000)
+}
+
+/// Whole MB covering `bytes`.
+fn megabytes(bytes: u64) -> u64 {
+    bytes.div_ceil(MIB)
+}
+
+fn task_resources(limits: &ResourceLimits, mhz_per_core: u32) -> Value {
+    let mut resources = serde_json::Map::new();
+    if let Some(cpu) = limits.cpu_millis {
+        resources.insert("CPU".to_string(), json!(cpu_mhz(cpu, mhz_per_core)));
+    }
+    if let Some(memory) = limits.memory_bytes {
+        resources.insert("MemoryMB".to_string(), json!(megabytes(memory)));
+    }
+    Value::Object(resources)
+}
+
+fn env_map(env: &[(String, String)]) -> Value {
+    json!(env.iter().cloned().collect::<HashMap<String, String>>())
+}
+
+/// Parameterized batch job for instance `job_id`.
+fn job_manifest(
+    job_id: &str,
+    settings: &NomadRuntimeConfig,
+    config: &RuntimeConfig,
+    env: &[(String, String)],
+    bootstrap: &str,
+    nfs: (&str, u16, u16),
+) -> Value {
+    let (nfs_host, nfs_port, nfs_mountport) = nfs;
+    let mounts: Vec<Value> = config
+        .volumes
+        .iter()
+        .map(|volume| {
+            json!({
+                "type": "volume",
+                "target": volume.mount_point.display().to_string(),
+                "source": format!("aegis-vol-{}", volume.volume_id),
+                "readonly": matches!(volume.access_mode, AccessMode::ReadOnly),
+                "volume_options": {
+                    "driver_config": {
+                        "name": "local",
+                        "options": {
+                            "type": "nfs",
+                            "o": format!(
+                                "addr={nfs_host},nfsvers=3,proto=tcp,port={nfs_port},mountport={nfs_mountport},soft,timeo=10,nolock"
+                            ),
+                            "device": format!(":{}", volume.remote_path),
+                        },
+                    },
+                },
+            })
+        })
+        .collect();
+
+    let bootstrap_path = config.bootstrap_path.as_deref().unwrap_or(BOOTSTRAP_PATH);
+    let mut agent = json!({
+        "Name": AGENT_TASK,
+        "Driver": "docker",
+        "User": format!("{}:{}", config.container_uid, config.container_gid),
+        "Config": {
+            "image": config.image,
+            "force_pull": matches!(
+                config.image_pull_policy,
+                crate::domain::agent::ImagePullPolicy::Always
+            ),
+            "command": "python",
+            "args": [bootstrap_path, format!("${{NOMAD_META_{PROMPT_META}}}")],
+            "cap_drop": ["all"],
+            "security_opt": ["no-new-privileges"],
+            "mount": mounts,
+        },
+        "Env": env_map(env),
+        "Resources": task_resources(&config.resources, settings.cpu_mhz_per_core),
+    });
+    if config.bootstrap_path.is_none() {
+        agent["Templates"] = json!([{
+            "EmbeddedTmpl": bootstrap,
+            "DestPath": "local/aegis-bootstrap",
+            "Perms": "0755",
+            "LeftDelim": TEMPLATE_LEFT_DELIM,
+            "RightDelim": TEMPLATE_RIGHT_DELIM,
+        }]);
+    }
+
+    let mut tasks = vec![agent];
+    for sidecar in &config.sidecars {
+        let env: Vec<(String, String)> = sidecar
+            .env
+            .iter()
+            .map(|(k, v)| (k.clone(), v.clone()))
+            .collect();
+        let mut task = json!({
+            "Name": sidecar_task_name(&sidecar.name),
+            "Driver": "docker",
+            "Lifecycle": { "Hook": "prestart", "Sidecar": true },
+            "Config": {
+                "image": sidecar.image,
+                "cap_drop": ["all"],
+                "security_opt": ["no-new-privileges"],
+            },
+            "Env": env_map(&env),
+            "Resources": task_resources(&sidecar.resources, settings.cpu_mhz_per_core),
+        });
+        if let Some((command, args)) = sidecar.command.split_first() {
+            task["Config"]["command"] = json!(command);
+            task["Config"]["args"] = json!(args);
+        }
+        tasks.push(task);
+    }
+
+    let mut group = json!({
+        "Name": TASK_GROUP,
+        "Count": 1,
+        "RestartPolicy": { "Attempts": 0, "Mode": "fail" },
+        "ReschedulePolicy": { "Attempts": 0, "Unlimited": false },
+        "Tasks": tasks,
+    });
+    if let Some(disk) = config.resources.disk_bytes {
+        group["EphemeralDisk"] = json!({ "SizeMB": megabytes(disk) });
+    }
+
+    let mut constraints = Vec::new();
+    if let Some(platform) = &config.platform {
+        let mut parts = platform.split('/');
+        if let (Some(os), Some(arch)) = (parts.next(), parts.next()) {
+            constraints.push(json!({
+                "LTarget": "${attr.kernel.name}",
+                "RTarget": os,
+                "Operand": "=",
+            }));
+            constraints.push(json!({
+                "LTarget": "${attr.cpu.arch}",
+                "RTarget": arch,
+                "Operand": "=",
+            }));
+        }
+    }
+
+    json!({
+        "ID": job_id,
+        "Name": job_id,
+        "Type": "batch",
+        "Datacenters": settings.datacenters,
+        "Namespace": settings.namespace,
+        "Region": settings.region,
+        "Meta": {
+            "aegis.managed": "true",
+            "aegis.execution_id": config.execution_id.to_string(),
+        },
+        "ParameterizedJob": {
+            "Payload": "forbidden",
+            "MetaRequired": [PROMPT_META],
+        },
+        "Constraints": constraints,
+        "TaskGroups": [group],
+    })
+}
+
+fn sidecar_task_name(name: &str) -> String {
+    format!("sidecar-{name}")
+}
+
+/// Exit code reported by a task's `Terminated` event, if it has one.
+fn terminated_exit_code(task_state: &Value) -> Option<i64> {
+    task_state["Events"]
+        .as_array()?
+        .iter()
+        .rev()
+        .find(|event| event["Type"] == "Terminated")
+        .and_then(|event| {
+            event["Details"]["exit_code"]
+                .as_str()
+                .and_then(|code| code.parse().ok())
+                .or_else(|| event["ExitCode"].as_i64())
+        })
+}
+
+/// Why an allocation ended without the agent task terminating, if it did.
+fn allocation_failure(allocation: &Value) -> Option<String> {
+    let client_status = allocation["ClientStatus"].as_str().unwrap_or_default();
+    if !matches!(client_status, "failed" | "lost") {
+        return None;
+    }
+    let last_event = allocation["TaskStates"][AGENT_TASK]["Events"]
+        .as_array()
+        .and_then(|events| events.last())
+        .and_then(|event| event["DisplayMessage"].as_str())
+        .unwrap_or("no task events");
+    Some(
+        match allocation["ClientDescription"]
+            .as_str()
+            .filter(|d| !d.is_empty())
+        {
+            Some(description) => {
+                format!("allocation {client_status}: {description} ({last_event})")
+            }
+            None => format!("allocation {client_status} ({last_event})"),
+        },
+    )
+}
+
+struct NomadInstance {
+    job_id: String,
+    /// Dispatched child job IDs, purged on terminate.
+    dispatched: Vec<String>,
+    /// Allocation of the latest dispatch.
+    allocation_id: Option<String>,
+    keep_on_failure: bool,
+    sidecar_tasks: Vec<(String, String)>,
+    sidecar_output: Vec<SidecarOutput>,
+}
+
+/// [`AgentRuntime`] that runs each iteration as a Nomad dispatch.
+pub struct NomadRuntime {
+    client: NomadClient,
+    settings: NomadRuntimeConfig,
+    bootstrap: String,
+    orchestrator_url: String,
+    nfs_server_host: Option<String>,
+    nfs_port: u16,
+    nfs_mountport: u16,
+    image_verifier: Option<Arc<dyn ImageSignatureVerifier>>,
+    instances: RwLock<HashMap<String, NomadInstance>>,
+}
+
+impl NomadRuntime {
+    /// Connect to Nomad and load the bootstrap script.
+    pub async fn new(config: NomadDriverConfig) -> Result<Self, RuntimeError> {
+        let client = NomadClient::new(&config.nomad)?;
+        let bootstrap = std::fs::read_to_string(&config.bootstrap_script).map_err(|e| {
+            RuntimeError::SpawnFailed(format!(
+                "Failed to read bootstrap script at {}: {e}",
+                config.bootstrap_script
+            ))
+        })?;
+        let leader = client
+            .json(Method::GET, "/v1/status/leader", None)
+            .await
+            .map_err(|e| {
+                RuntimeError::SpawnFailed(format!("Cannot reach Nomad at {}: {e}", client.address))
+            })?;
+        info!(address = %client.address, leader = %leader, "Nomad runtime connected");
+
+        Ok(Self {
+            client,
+            settings: config.nomad,
+            bootstrap,
+            orchestrator_url: config.orchestrator_url,
+            nfs_server_host: config.nfs_server_host,
+            nfs_port: config.nfs_port,
+            nfs_mountport: config.nfs_mountport,
+            image_verifier: config.image_verifier,
+            instances: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Wait for the allocation of dispatched job `job_id`, failing if Nomad
+    /// cannot place it.
+    async fn wait_for_allocation(
+        &self,
+        job_id: &str,
+        eval_id: &str,
+    ) -> Result<String, RuntimeError> {
+        loop {
+            let allocations = self
+                .client
+                .json(Method::GET, &format!("/v1/job/{job_id}/allocations"), None)
+                .await?;
+            if let Some(id) = allocations
+                .as_array()
+                .and_then(|list| list.first())
+                .and_then(|allocation| allocation["ID"].as_str())
+            {
+                return Ok(id.to_string());
+            }
+            let evaluation = self
+                .client
+                .json(Method::GET, &format!("/v1/evaluation/{eval_id}"), None)
+                .await?;
+            if let Some(failed) = evaluation["FailedTGAllocs"].as_object() {
+                if !failed.is_empty() {
+                    return Err(RuntimeError::ExecutionFailed(format!(
+                        "Nomad could not place job {job_id}: {}",
+                        Value::Object(failed.clone())
+                    )));
+                }
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    async fn get_allocation(&self, allocation_id: &str) -> Result<Value, RuntimeError> {
+        self.client
+            .json(
+                Method::GET,
+                &format!("/v1/allocation/{allocation_id}"),
+                None,
+            )
+            .await
+            .map_err(RuntimeError::from)
+    }
+
+    /// Wait until the agent task has started or its allocation has ended.
+    async fn wait_for_start(&self, allocation_id: &str) -> Result<(), RuntimeError> {
+        loop {
+            let allocation = self.get_allocation(allocation_id).await?;
+            if let Some(reason) = allocation_failure(&allocation) {
+                if terminated_exit_code(&allocation["TaskStates"][AGENT_TASK]).is_none() {
+                    return Err(RuntimeError::ExecutionFailed(reason));
+                }
+            }
+            let state = allocation["TaskStates"][AGENT_TASK]["State"]
+                .as_str()
+                .unwrap_or_default();
+            if matches!(state, "running" | "dead") {
+                return Ok(());
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    async fn task_log(
+        &self,
+        allocation_id: &str,
+        task: &str,
+        stream: &str,
+        follow: bool,
+    ) -> Result<reqwest::Response, RuntimeError> {
+        self.client
+            .request(
+                Method::GET,
+                &format!("/v1/client/fs/logs/{allocation_id}"),
+                &[
+                    ("task", task),
+                    ("type", stream),
+                    ("origin", "start"),
+                    ("offset", "0"),
+                    ("plain", "true"),
+                    ("follow", if follow { "true" } else { "false" }),
+                ],
+                None,
+            )
+            .await
+            .map_err(RuntimeError::from)
+    }
+
+    /// Follow the agent task's stdout until the task exits, echoing each line.
+    async fn follow_stdout(&self, allocation_id: &str) -> Result<String, RuntimeError> {
+        let response = self
+            .task_log(allocation_id, AGENT_TASK, "stdout", true)
+            .await?;
+        let mut stdout = String::new();
+        let mut pending = Vec::new();
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| RuntimeError::ExecutionFailed(e.to_string()))?;
+            pending.extend_from_slice(&chunk);
+            while let Some(end) = pending.iter().position(|b| *b == b'\n') {
+                let line: Vec<u8> = pending.drain(..=end).collect();
+                let line = String::from_utf8_lossy(&line);
+                if !line.trim().is_empty() {
+                    tracing::info!(
+                        target: "child_runtime",
+                        container_id = allocation_id,
+                        stream = "stdout",
+                        "{}",
+                        line.trim_end()
+                    );
+                }
+                stdout.push_str(&line);
+            }
+        }
+        stdout.push_str(&String::from_utf8_lossy(&pending));
+        Ok(stdout)
+    }
+
+    /// Wait for the agent task's exit code.
+    async fn wait_for_exit(&self, allocation_id: &str) -> Result<i64, RuntimeError> {
+        loop {
+            let allocation = self.get_allocation(allocation_id).await?;
+            let task_state = &allocation["TaskStates"][AGENT_TASK];
+            if task_state["State"] == "dead" {
+                if let Some(code) = terminated_exit_code(task_state) {
+                    return Ok(code);
+                }
+            }
+            if let Some(reason) = allocation_failure(&allocation) {
+                return Err(RuntimeError::ExecutionFailed(reason));
+            }
+            tokio::time::sleep(POLL_INTERVAL).await;
+        }
+    }
+
+    async fn read_log(&self, allocation_id: &str, task: &str, stream: &str) -> String {
+        match self.task_log(allocation_id, task, stream, false).await {
+            Ok(response) => response.text().await.unwrap_or_default(),
+            Err(e) => {
+                warn!(allocation_id, task, stream, "Task log unavailable: {e}");
+                String::new()
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl AgentRuntime for NomadRuntime {
+    async fn spawn(&self, config: RuntimeConfig) -> Result<InstanceId, RuntimeError> {
+        config.validate_isolation()?;
+        if config.network_flow_capture.is_some() {
+            return Err(RuntimeError::SpawnFailed(
+                "network flow capture is not supported by the Nomad runtime".to_string(),
+            ));
+        }
+
+        // Supply-chain gate: refuse untrusted images before the job exists.
+        if let Some(verifier) = &self.image_verifier {
+            if let Err(e) = verifier.verify(&config.image, &config.tenant_id).await {
+                error!(
+                    target: "image_verification",
+                    image = %config.image,
+                    execution_id = %config.execution_id,
+                    tenant_id = %config.tenant_id,
+                    "image verification failed: {e}"
+                );
+                return Err(e);
+            }
+        }
+
+        let (filtered_env, blocked_vars) = crate::domain::env_guard::filter_env_vars(&config.env);
+        if !blocked_vars.is_empty() {
+            warn!(
+                execution_id = %config.execution_id,
+                blocked = ?blocked_vars,
+                "Blocked orchestrator-internal env vars from agent job"
+            );
+        }
+        let mut env: Vec<(String, String)> = filtered_env.into_iter().collect();
+        env.push((
+            "AEGIS_ORCHESTRATOR_URL".to_string(),
+            self.orchestrator_url.clone(),
+        ));
+        if tracing::level_enabled!(tracing::Level::DEBUG) {
+            env.push(("AEGIS_BOOTSTRAP_DEBUG".to_string(), "true".to_string()));
+        }
+
+        let job_id = format!("aegis-agent-{}", uuid::Uuid::new_v4());
+        let job = job_manifest(
+            &job_id,
+            &self.settings,
+            &config,
+            &env,
+            &self.bootstrap,
+            (
+                self.nfs_server_host.as_deref().unwrap_or("127.0.0.1"),
+                self.nfs_port,
+                self.nfs_mountport,
+            ),
+        );
+        self.client
+            .json(Method::POST, "/v1/jobs", Some(&json!({ "Job": job })))
+            .await
+            .map_err(|e| RuntimeError::SpawnFailed(format!("Failed to register job: {e}")))?;
+
+        self.instances.write().await.insert(
+            job_id.clone(),
+            NomadInstance {
+                job_id: job_id.clone(),
+                dispatched: Vec::new(),
+                allocation_id: None,
+                keep_on_failure: config.keep_container_on_failure,
+                sidecar_tasks: config
+                    .sidecars
+                    .iter()
+                    .map(|s| (sidecar_task_name(&s.name), s.console_stream()))
+                    .collect(),
+                sidecar_output: Vec::new(),
+            },
+        );
+        info!(
+            execution_id = %config.execution_id,
+            job_id = %job_id,
+            "Registered agent job"
+        );
+        Ok(InstanceId::new(job_id))
+    }
+
+    async fn execute(&self, id: &InstanceId, input: TaskInput) -> Result<TaskOutput, RuntimeError> {
+        let (job_id, keep_on_failure, sidecar_tasks) = {
+            let instances = self.instances.read().await;
+            let instance = instances
+                .get(id.as_str())
+                .ok_or_else(|| RuntimeError::InstanceNotFound(id.as_str().to_string()))?;
+            (
+                instance.job_id.clone(),
+                instance.keep_on_failure,
+                instance.sidecar_tasks.clone(),
+            )
+        };
+
+        debug!(job_id = %job_id, prompt_len = input.prompt.len(), "Dispatching agent job");
+        let dispatch = self
+            .client
+            .json(
+                Method::POST,
+                &format!("/v1/job/{job_id}/dispatch"),
+                Some(&json!({ "Meta": { PROMPT_META: input.prompt } })),
+            )
+            .await
+            .map_err(|e| RuntimeError::ExecutionFailed(format!("Failed to dispatch job: {e}")))?;
+        let dispatched = dispatch["DispatchedJobID"]
+            .as_str()
+            .ok_or_else(|| {
+                RuntimeError::ExecutionFailed("dispatch response has no DispatchedJobID".into())
+            })?
+            .to_string();
+        let eval_id = dispatch["EvalID"].as_str().unwrap_or_default().to_string();
+        if let Some(instance) = self.instances.write().await.get_mut(id.as_str()) {
+            instance.dispatched.push(dispatched.clone());
+        }
+
+        let allocation_id = self.wait_for_allocation(&dispatched, &eval_id).await?;
+        if let Some(instance) = self.instances.write().await.get_mut(id.as_str()) {
+            instance.allocation_id = Some(allocation_id.clone());
+        }
+        self.wait_for_start(&allocation_id).await?;
+        let stdout = self.follow_stdout(&allocation_id).await?;
+        let exit_code = self.wait_for_exit(&allocation_id).await?;
+        let stderr = self.read_log(&allocation_id, AGENT_TASK, "stderr").await;
+
+        let mut sidecar_output = Vec::new();
+        for (task, stream) in sidecar_tasks {
+            let content = format!(
+                "{}{}",
+                self.read_log(&allocation_id, &task, "stdout").await,
+                self.read_log(&allocation_id, &task, "stderr").await
+            );
+            if !content.is_empty() {
+                sidecar_output.push(SidecarOutput { stream, content });
+            }
+        }
+        if let Some(instance) = self.instances.write().await.get_mut(id.as_str()) {
+            instance.sidecar_output = sidecar_output;
+        }
+
+        let logs: Vec<String> = stderr.lines().map(str::to_string).collect();
+        debug!(
+            job_id = %dispatched,
+            allocation_id = %allocation_id,
+            exit_code = exit_code,
+            "Agent job completed"
+        );
+        if exit_code != 0 && keep_on_failure {
+            let error_msg = if !logs.is_empty() {
+                logs.join("\n")
+            } else {
+                format!("Bootstrap script exited with code {exit_code}")
+            };
+            return Err(RuntimeError::ExecutionFailed(error_msg));
+        }
+        Ok(TaskOutput {
+            result: Value::String(stdout),
+            logs,
+            tool_calls: vec![],
+            exit_code,
+            trajectory: vec![],
+        })
+    }
+
+    async fn terminate(&self, id: &InstanceId) -> Result<(), RuntimeError> {
+        let jobs = match self.instances.read().await.get(id.as_str()) {
+            Some(instance) => {
+                let mut jobs = instance.dispatched.clone();
+                jobs.push(instance.job_id.clone());
+                jobs
+            }
+            None => vec![id.as_str().to_string()],
+        };
+        for job in jobs {
+            match self
+                .client
+                .request(
+                    Method::DELETE,
+                    &format!("/v1/job/{job}"),
+                    &[("purge", "true")],
+                    None,
+                )
+                .await
+            {
+                Ok(_) => {}
+                Err(e) if e.is_not_found() => {}
+                Err(e) => return Err(RuntimeError::TerminationFailed(e.to_string())),
+            }
+        }
+        self.instances.write().await.remove(id.as_str());
+        info!(job_id = id.as_str(), "Purged agent job");
+        Ok(())
+    }
+
+    async fn status(&self, id: &InstanceId) -> Result<InstanceStatus, RuntimeError> {
+        let allocation_id = self
+            .instances
+            .read()
+            .await
+            .get(id.as_str())
+            .ok_or_else(|| RuntimeError::InstanceNotFound(id.as_str().to_string()))?
+            .allocation_id
+            .clone();
+        let Some(allocation_id) = allocation_id else {
+            return Ok(InstanceStatus {
+                id: id.clone(),
+                state: "registered".to_string(),
+                uptime_seconds: 0,
+                memory_usage_mb: 0,
+                cpu_usage_percent: 0.0,
+            });
+        };
+        let allocation = self.get_allocation(&allocation_id).await?;
+        let started_at = allocation["TaskStates"][AGENT_TASK]["StartedAt"]
+            .as_str()
+            .and_then(|t| t.parse::<chrono::DateTime<Utc>>().ok());
+        Ok(InstanceStatus {
+            id: id.clone(),
+            state: allocation["ClientStatus"]
+                .as_str()
+                .unwrap_or("unknown")
+                .to_string(),
+            uptime_seconds: started_at
+                .map(|started| (Utc::now() - started).num_seconds().max(0) as u64)
+                .unwrap_or(0),
+            memory_usage_mb: 0,
+            cpu_usage_percent: 0.0,
+        })
+    }
+
+    async fn drain_sidecar_output(&self, id: &InstanceId) -> Vec<SidecarOutput> {
+        self.instances
+            .write()
+            .await
+            .get_mut(id.as_str())
+            .map(|instance| std::mem::take(&mut instance.sidecar_output))
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn settings() -> NomadRuntimeConfig {
+        serde_json::from_value(json!({ "namespace": "aegis", "cpu_mhz_per_core": 2400 })).unwrap()
+    }
+
+    fn config() -> RuntimeConfig {
+        RuntimeConfig {
+            language: "python".to_string(),
+            version: "3.12".to_string(),
+            isolation: "docker".to_string(),
+            env: HashMap::new(),
+            image_pull_policy: crate::domain::agent::ImagePullPolicy::IfNotPresent,
+            container_uid: 1000,
+            container_gid: 1000,
+            resources: ResourceLimits {
+                cpu_millis: Some(500),
+                memory_bytes: Some(536_870_913),
+                disk_bytes: Some(2 * 1024 * MIB),
+                timeout_seconds: None,
+            },
+            execution: crate::domain::agent::ExecutionStrategy {
+                mode: crate::domain::agent::ExecutionMode::Iterative,
+                max_retries: 5,
+                iteration_timeout: None,
+                llm_timeout_seconds: 300,
+                validation: None,
+                tool_validation: None,
+                delivery: None,
+                approval_mode: crate::domain::agent::ApprovalMode::Off,
+                approval_timeout_seconds: None,
+                approval_on_timeout: None,
+            },
+            volumes: Vec::new(),
+            keep_container_on_failure: false,
+            image: "python:3.12".to_string(),
+            bootstrap_path: None,
+            execution_id: crate::domain::execution::ExecutionId::new(),
+            tenant_id: crate::domain::tenant::TenantId::default(),
+            sidecars: Vec::new(),
+            platform: Some("linux/arm64".to_string()),
+            network_policy: None,
+            network_flow_capture: None,
+        }
+    }
+
+    #[test]
+    fn job_manifest_maps_resources_and_platform() {
+        let job = job_manifest(
+            "aegis-agent-1",
+            &settings(),
+            &config(),
+            &[],
+            "print('hi')",
+            ("10.0.0.5", 2049, 2049),
+        );
+        assert_eq!(job["Type"], "batch");
+        assert_eq!(job["Namespace"], "aegis");
+        assert_eq!(job["Datacenters"], json!(["*"]));
+        assert_eq!(job["ParameterizedJob"]["MetaRequired"], json!(["prompt"]));
+
+        let group = &job["TaskGroups"][0];
+        assert_eq!(group["RestartPolicy"]["Attempts"], 0);
+        assert_eq!(group["ReschedulePolicy"]["Attempts"], 0);
+        assert_eq!(group["EphemeralDisk"]["SizeMB"], 2048);
+
+        let agent = &group["Tasks"][0];
+        // 500 millicores at 2400 MHz per core; memory rounds up to whole MB.
+        assert_eq!(agent["Resources"]["CPU"], 1200);
+        assert_eq!(agent["Resources"]["MemoryMB"], 513);
+        assert_eq!(agent["User"], "1000:1000");
+        assert_eq!(
+            agent["Config"]["args"],
+            json!([BOOTSTRAP_PATH, "${NOMAD_META_prompt}"])
+        );
+        assert_eq!(agent["Templates"][0]["EmbeddedTmpl"], "print('hi')");
+        assert_eq!(job["Constraints"][1]["LTarget"], "${attr.cpu.arch}");
+        assert_eq!(job["Constraints"][1]["RTarget"], "arm64");
+    }
+
+    #[test]
+    fn allocation_state_reconciliation() {
+        let finished = json!({
+            "State": "dead",
+            "Events": [
+                { "Type": "Started" },
+                { "Type": "Terminated", "Details": { "exit_code": "3" }, "ExitCode": 3 },
+            ],
+        });
+        assert_eq!(terminated_exit_code(&finished), Some(3));
+        assert_eq!(terminated_exit_code(&json!({ "Events": [] })), None);
+
+        let lost = json!({
+            "ClientStatus": "lost",
+            "ClientDescription": "node is down",
+            "TaskStates": { "agent": { "Events": [
+                { "Type": "Started", "DisplayMessage": "Task started by client" },
+            ] } },
+        });
+        assert_eq!(
+            allocation_failure(&lost).as_deref(),
+            Some("allocation lost: node is down (Task started by client)")
+        );
+        assert_eq!(
+            allocation_failure(&json!({ "ClientStatus": "running" })),
+            None
+        );
+    }
+}
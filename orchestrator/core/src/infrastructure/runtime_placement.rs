@@ -0,0 +1,193 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Container Endpoint Placement
+//!
+//! [`PlacementRuntime`] puts one [`ContainerRuntime`] per container engine
+//! (the local engine first, then each `spec.runtime.endpoints[]` entry) behind
+//! the single [`AgentRuntime`] the supervisor drives.
+//!
+//! - An execution whose `spec.runtime.platform` is set is placed on an engine
+//!   serving that platform, preferring the one with the fewest live instances.
+//!   Spawn fails if no engine serves it.
+//! - An execution without a platform stays on the local engine, as it did
+//!   before endpoints existed.
+//!
+//! Every later call for an instance is routed to the engine that spawned it.
+//! Instances the router has no record of (e.g. from before a daemon restart)
+//! are routed to the local engine.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Platform-aware placement of agent containers across engines
+
+use crate::domain::network_flow::NetworkFlow;
+use crate::domain::runtime::{
+    platform_satisfies, AgentRuntime, InstanceId, InstanceStatus, RuntimeConfig, RuntimeError,
+    SidecarOutput, TaskInput, TaskOutput,
+};
+use crate::infrastructure::runtime::ContainerRuntime;
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tracing::info;
+
+/// One container engine available for placement.
+pub struct RuntimeEndpoint {
+    pub name: String,
+    pub runtime: Arc<ContainerRuntime>,
+}
+
+/// Index of the endpoint to place a container on.
+///
+/// `served[i]` lists the platforms endpoint `i` serves and `active[i]` its live
+/// instance count. Without a `platform` the first endpoint is chosen.
+pub fn select_endpoint(
+    served: &[&[String]],
+    active: &[usize],
+    platform: Option<&str>,
+) -> Option<usize> {
+    let Some(platform) = platform else {
+        return (!served.is_empty()).then_some(0);
+    };
+    served
+        .iter()
+        .enumerate()
+        .filter(|(_, platforms)| platforms.iter().any(|p| platform_satisfies(p, platform)))
+        .min_by_key(|(i, _)| active.get(*i).copied().unwrap_or_default())
+        .map(|(i, _)| i)
+}
+
+/// [`AgentRuntime`] that spreads instances over several container engines.
+pub struct PlacementRuntime {
+    endpoints: Vec<RuntimeEndpoint>,
+    /// Endpoint index per live instance ID.
+    placements: RwLock<HashMap<String, usize>>,
+}
+
+impl PlacementRuntime {
+    /// Create a router over `endpoints`; the first entry is the local engine.
+    pub fn new(endpoints: Vec<RuntimeEndpoint>) -> Self {
+        Self {
+            endpoints,
+            placements: RwLock::new(HashMap::new()),
+        }
+    }
+
+    async fn runtime_for(&self, id: &InstanceId) -> &ContainerRuntime {
+        let index = self
+            .placements
+            .read()
+            .await
+            .get(id.as_str())
+            .copied()
+            .unwrap_or(0);
+        &self.endpoints[index].runtime
+    }
+}
+
+#[async_trait]
+impl AgentRuntime for PlacementRuntime {
+    async fn spawn(&self, config: RuntimeConfig) -> Result<InstanceId, RuntimeError> {
+        let served: Vec<&[String]> = self
+            .endpoints
+            .iter()
+            .map(|e| e.runtime.platforms())
+            .collect();
+        let index = {
+            let placements = self.placements.read().await;
+            let mut active = vec![0usize; self.endpoints.len()];
+            for index in placements.values() {
+                active[*index] += 1;
+            }
+            select_endpoint(&served, &active, config.platform.as_deref())
+        }
+        .ok_or_else(|| {
+            let available: Vec<String> = self
+                .endpoints
+                .iter()
+                .map(|e| format!("{} {:?}", e.name, e.runtime.platforms()))
+                .collect();
+            RuntimeError::SpawnFailed(format!(
+                "no container endpoint serves platform '{}' (available: {})",
+                config.platform.as_deref().unwrap_or_default(),
+                available.join(", ")
+            ))
+        })?;
+
+        let endpoint = &self.endpoints[index];
+        info!(
+            execution_id = %config.execution_id,
+            endpoint = %endpoint.name,
+            platform = config.platform.as_deref().unwrap_or("any"),
+            "Placing agent container"
+        );
+        let id = endpoint.runtime.spawn(config).await?;
+        self.placements
+            .write()
+            .await
+            .insert(id.as_str().to_string(), index);
+        Ok(id)
+    }
+
+    async fn execute(&self, id: &InstanceId, input: TaskInput) -> Result<TaskOutput, RuntimeError> {
+        self.runtime_for(id).await.execute(id, input).await
+    }
+
+    async fn terminate(&self, id: &InstanceId) -> Result<(), RuntimeError> {
+        let result = self.runtime_for(id).await.terminate(id).await;
+        if result.is_ok() {
+            self.placements.write().await.remove(id.as_str());
+        }
+        result
+    }
+
+    async fn status(&self, id: &InstanceId) -> Result<InstanceStatus, RuntimeError> {
+        self.runtime_for(id).await.status(id).await
+    }
+
+    async fn drain_sidecar_output(&self, id: &InstanceId) -> Vec<SidecarOutput> {
+        self.runtime_for(id).await.drain_sidecar_output(id).await
+    }
+
+    async fn drain_network_flows(&self, id: &InstanceId) -> Vec<NetworkFlow> {
+        self.runtime_for(id).await.drain_network_flows(id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn platforms(list: &[&str]) -> Vec<String> {
+        list.iter().map(|p| p.to_string()).collect()
+    }
+
+    #[test]
+    fn select_endpoint_matches_platform_and_balances_load() {
+        let local = platforms(&["linux/amd64"]);
+        let arm_a = platforms(&["linux/arm64/v8"]);
+        let arm_b = platforms(&["linux/arm64"]);
+        let served: Vec<&[String]> = vec![&local, &arm_a, &arm_b];
+
+        assert_eq!(select_endpoint(&served, &[5, 0, 0], None), Some(0));
+        assert_eq!(
+            select_endpoint(&served, &[0, 0, 0], Some("linux/amd64")),
+            Some(0)
+        );
+        assert_eq!(
+            select_endpoint(&served, &[0, 2, 1], Some("linux/arm64")),
+            Some(2)
+        );
+        // A variant-specific request only matches engines reporting that variant.
+        assert_eq!(
+            select_endpoint(&served, &[0, 2, 1], Some("linux/arm64/v8")),
+            Some(1)
+        );
+        assert_eq!(
+            select_endpoint(&served, &[0, 0, 0], Some("windows/amd64")),
+            None
+        );
+    }
+}
@@ -76,6 +76,9 @@ pub struct AttestationRequest {
     /// Embedded as the `task_summary` JWT claim in the issued `SecurityToken`.
     /// Truncated to 256 characters if longer.
     pub task_summary: Option<String>,
+    /// Optional base64 X25519 public key offered by the agent to negotiate a
+    /// per-execution payload encryption key (see `seal::payload_crypto`).
+    pub payload_public_key: Option<String>,
 }
 
 /// The orchestrator's response to a successful attestation handshake.
@@ -90,6 +93,9 @@ pub struct AttestationResponse {
     pub expires_at: String,
     /// Optional session identifier for audit correlation.
     pub session_id: Option<String>,
+    /// The orchestrator's base64 X25519 public key when a payload encryption
+    /// key was negotiated for this execution.
+    pub payload_public_key: Option<String>,
 }
 
 /// Service responsible for completing the SEAL attestation handshake.
@@ -29,6 +29,7 @@ use crate::domain::rate_limit::{
 };
 use crate::domain::seal_session::{EnvelopeVerifier, SealSession, SealSessionError};
 use crate::infrastructure::seal::nonce_store::{NonceOutcome, NonceStore};
+use crate::infrastructure::seal::stream::SealStreamSigner;
 
 /// Orchestrator middleware that verifies and unwraps incoming SEAL envelopes.
 ///
@@ -39,6 +40,7 @@ pub struct SealMiddleware {
     rate_limit_enforcer: Option<Arc<dyn RateLimitEnforcer>>,
     rate_limit_resolver: Option<Arc<dyn RateLimitPolicyResolver>>,
     nonce_store: Option<Arc<dyn NonceStore>>,
+    stream_signer: Option<Arc<SealStreamSigner>>,
 }
 
 impl SealMiddleware {
@@ -51,6 +53,7 @@ impl SealMiddleware {
             rate_limit_enforcer: None,
             rate_limit_resolver: None,
             nonce_store: None,
+            stream_signer: None,
         }
     }
 
@@ -63,6 +66,7 @@ impl SealMiddleware {
             rate_limit_enforcer,
             rate_limit_resolver,
             nonce_store: None,
+            stream_signer: None,
         }
     }
 
@@ -77,6 +81,25 @@ impl SealMiddleware {
         self
     }
 
+    /// Attach the key that signs streamed tool responses
+    /// ([`crate::infrastructure::seal::stream`]). Without it only
+    /// single-body responses are available.
+    pub fn with_stream_signer(mut self, signer: Arc<SealStreamSigner>) -> Self {
+        self.stream_signer = Some(signer);
+        self
+    }
+
+    pub fn stream_signer(&self) -> Option<&SealStreamSigner> {
+        self.stream_signer.as_deref()
+    }
+
+    /// Base64 public key agents use to verify response frames, if streaming
+    /// is enabled.
+    pub fn response_signing_key(&self) -> Option<String> {
+        self.stream_signer()
+            .map(SealStreamSigner::public_key_base64)
+    }
+
     /// Verify the envelope against the given session and extract the inner MCP arguments.
     ///
     /// This is the **single choke-point** through which all MCP tool calls must pass.
@@ -213,6 +236,11 @@ impl SealMiddleware {
                             PolicyViolation::ExecTimeoutCeilingExceeded { .. } => {
                                 "exec_timeout_ceiling_exceeded"
                             }
+                            PolicyViolation::ArgumentNotAllowed { .. } => "argument_not_allowed",
+                            PolicyViolation::OutsideTimeWindow { .. } => "outside_time_window",
+                            PolicyViolation::InvocationLimitExceeded { .. } => {
+                                "invocation_limit_exceeded"
+                            }
                         };
                         metrics::counter!("aegis_seal_policy_violations_total", "violation_type" => violation_type).increment(1);
                     }
@@ -431,6 +459,7 @@ mod tests {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             }],
             deny_list: vec![],
             metadata: SecurityContextMetadata {
@@ -556,6 +585,7 @@ mod tests {
                 max_response_size: None,
                 rate_limit: None,
                 max_concurrent: None,
+                conditions: None,
             }],
             deny_list: vec![],
             metadata: SecurityContextMetadata {
@@ -13,8 +13,10 @@
 //! | [`attestation`] | `AttestationService` trait + request/response types for the initial handshake |
 //! | [`envelope`] | `SealEnvelope` (outer signed wrapper) and `ContextClaims` (JWT payload) |
 //! | [`middleware`] | `SealMiddleware` — session-level verify-and-unwrap per tool call |
+//! | [`payload_crypto`] | Per-execution keys encrypting dispatch prompts and final outputs |
 //! | [`policy_engine`] | `PolicyEngine` — thin shim delegating to `SecurityContext::evaluate` |
 //! | [`signature`] | Ed25519 keypair generation and signing utilities |
+//! | [`stream`] | Signed, sequence-chained frames for streamed tool responses |
 //! | [`audit`] | Emits `SealEvent` audit records to the event bus |
 //! | [`session_repository`] | In-memory `SealSessionRepository` implementation |
 //!
@@ -40,12 +42,16 @@ pub mod envelope;
 pub mod gateway_client;
 pub mod middleware;
 pub mod nonce_store;
+pub mod payload_crypto;
 pub mod policy_engine;
 pub mod session_repository;
 pub mod signature;
+pub mod stream;
 
 pub use attestation::{AttestationRequest, AttestationResponse, AttestationService};
 pub use envelope::{AudienceClaim, ContextClaims, SealEnvelope};
 pub use middleware::SealMiddleware;
 pub use nonce_store::{InMemoryNonceStore, NonceOutcome, NonceStore, SEAL_REPLAY_TTL};
+pub use payload_crypto::{PayloadCryptoError, PayloadKey, PayloadKeyRegistry};
 pub use policy_engine::PolicyEngine;
+pub use stream::{SealResponseFrame, SealStreamError, SealStreamSigner, SealStreamVerifier};
@@ -0,0 +1,301 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Execution Payload Encryption
+//!
+//! Per-execution symmetric keys protecting the prompt an agent container sends
+//! on `/v1/dispatch-gateway` and the final output returned to it, so neither
+//! crosses the container network in plaintext.
+//!
+//! ## Key Negotiation
+//!
+//! The key is negotiated during SEAL attestation:
+//!
+//! ```text
+//! Agent container
+//!   │  AttestationRequest { ..., payload_public_key: X25519(agent) }
+//!   ▼
+//! negotiate()
+//!   1. Generate an ephemeral X25519 keypair for this execution
+//!   2. ECDH(orchestrator secret, agent public) → shared secret
+//!   3. HKDF-SHA256(salt = execution_id, info = "aegis-payload-v1") → AES-256-GCM key
+//!   4. PayloadKeyRegistry.insert(execution_id, key)
+//!   ──────────────────────────────────────────────────────────────────────────
+//!   AttestationResponse { ..., payload_public_key: X25519(orchestrator) }
+//! ```
+//!
+//! Payloads are sealed with a random 96-bit nonce and the execution ID as
+//! associated data, so a ciphertext cannot be replayed into another execution.
+//! `bootstrap.py` implements the container side with the same parameters.
+
+use std::time::{Duration, Instant};
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use dashmap::DashMap;
+use hkdf::Hkdf;
+use rand_core::{OsRng, RngCore};
+use sha2::Sha256;
+use thiserror::Error;
+use x25519_dalek::{EphemeralSecret, PublicKey};
+
+use crate::domain::dispatch::EncryptedPayload;
+use crate::domain::execution::ExecutionId;
+
+/// HKDF `info` string binding derived keys to this protocol version.
+pub const PAYLOAD_KEY_INFO: &[u8] = b"aegis-payload-v1";
+
+/// Keys live as long as the SEAL session that negotiated them.
+pub const PAYLOAD_KEY_TTL: Duration = Duration::from_secs(3600);
+
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug, Error)]
+pub enum PayloadCryptoError {
+    #[error("Invalid payload public key: {0}")]
+    InvalidPublicKey(String),
+
+    #[error("Malformed encrypted payload: {0}")]
+    Malformed(String),
+
+    #[error("Payload decryption failed")]
+    DecryptionFailed,
+
+    #[error("No payload key negotiated for execution {0}")]
+    NoKey(ExecutionId),
+}
+
+/// AES-256-GCM key for one execution's payloads.
+#[derive(Clone)]
+pub struct PayloadKey {
+    key: [u8; 32],
+    execution_id: ExecutionId,
+}
+
+impl std::fmt::Debug for PayloadKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PayloadKey")
+            .field("execution_id", &self.execution_id)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PayloadKey {
+    /// Negotiate a key with the agent's X25519 public key (base64, raw 32
+    /// bytes). Returns the key and the orchestrator's public key (base64) for
+    /// the attestation response.
+    pub fn negotiate(
+        execution_id: ExecutionId,
+        agent_public_key_b64: &str,
+    ) -> Result<(Self, String), PayloadCryptoError> {
+        let bytes: [u8; 32] = STANDARD
+            .decode(agent_public_key_b64.trim())
+            .map_err(|e| PayloadCryptoError::InvalidPublicKey(e.to_string()))?
+            .try_into()
+            .map_err(|_| PayloadCryptoError::InvalidPublicKey("expected 32 bytes".to_string()))?;
+
+        let secret = EphemeralSecret::random_from_rng(OsRng);
+        let public = PublicKey::from(&secret);
+        let shared = secret.diffie_hellman(&PublicKey::from(bytes));
+        if !shared.was_contributory() {
+            return Err(PayloadCryptoError::InvalidPublicKey(
+                "low-order point".to_string(),
+            ));
+        }
+
+        Ok((
+            Self::derive(shared.as_bytes(), execution_id),
+            STANDARD.encode(public.as_bytes()),
+        ))
+    }
+
+    fn derive(shared_secret: &[u8; 32], execution_id: ExecutionId) -> Self {
+        let salt = execution_id.0.to_string();
+        let mut key = [0u8; 32];
+        Hkdf::<Sha256>::new(Some(salt.as_bytes()), shared_secret)
+            .expand(PAYLOAD_KEY_INFO, &mut key)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        Self { key, execution_id }
+    }
+
+    fn cipher(&self) -> Aes256Gcm {
+        Aes256Gcm::new_from_slice(&self.key).expect("key is 32 bytes")
+    }
+
+    fn aad(&self) -> String {
+        self.execution_id.0.to_string()
+    }
+
+    pub fn encrypt(&self, plaintext: &str) -> EncryptedPayload {
+        let mut nonce = [0u8; NONCE_LEN];
+        OsRng.fill_bytes(&mut nonce);
+        let aad = self.aad();
+        let ciphertext = self
+            .cipher()
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext.as_bytes(),
+                    aad: aad.as_bytes(),
+                },
+            )
+            .expect("AES-GCM encryption of an in-memory buffer cannot fail");
+        EncryptedPayload {
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(ciphertext),
+        }
+    }
+
+    pub fn decrypt(&self, payload: &EncryptedPayload) -> Result<String, PayloadCryptoError> {
+        let nonce = STANDARD
+            .decode(&payload.nonce)
+            .map_err(|e| PayloadCryptoError::Malformed(e.to_string()))?;
+        if nonce.len() != NONCE_LEN {
+            return Err(PayloadCryptoError::Malformed(format!(
+                "nonce must be {NONCE_LEN} bytes"
+            )));
+        }
+        let ciphertext = STANDARD
+            .decode(&payload.ciphertext)
+            .map_err(|e| PayloadCryptoError::Malformed(e.to_string()))?;
+        let aad = self.aad();
+        let plaintext = self
+            .cipher()
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: aad.as_bytes(),
+                },
+            )
+            .map_err(|_| PayloadCryptoError::DecryptionFailed)?;
+        String::from_utf8(plaintext).map_err(|e| PayloadCryptoError::Malformed(e.to_string()))
+    }
+}
+
+/// In-memory payload keys by execution, shared between attestation and the
+/// dispatch gateway. A new attestation for the same execution replaces its key.
+pub struct PayloadKeyRegistry {
+    keys: DashMap<ExecutionId, (PayloadKey, Instant)>,
+    ttl: Duration,
+}
+
+impl PayloadKeyRegistry {
+    pub fn new() -> Self {
+        Self {
+            keys: DashMap::new(),
+            ttl: PAYLOAD_KEY_TTL,
+        }
+    }
+
+    /// Negotiate and store a key for `execution_id`; returns the
+    /// orchestrator's public key for the attestation response.
+    pub fn negotiate(
+        &self,
+        execution_id: ExecutionId,
+        agent_public_key_b64: &str,
+    ) -> Result<String, PayloadCryptoError> {
+        let (key, public_key) = PayloadKey::negotiate(execution_id, agent_public_key_b64)?;
+        let now = Instant::now();
+        self.keys
+            .retain(|_, (_, inserted_at)| now.duration_since(*inserted_at) < self.ttl);
+        self.keys.insert(execution_id, (key, now));
+        Ok(public_key)
+    }
+
+    pub fn get(&self, execution_id: ExecutionId) -> Result<PayloadKey, PayloadCryptoError> {
+        self.keys
+            .get(&execution_id)
+            .filter(|entry| entry.1.elapsed() < self.ttl)
+            .map(|entry| entry.0.clone())
+            .ok_or(PayloadCryptoError::NoKey(execution_id))
+    }
+
+    pub fn remove(&self, execution_id: ExecutionId) {
+        self.keys.remove(&execution_id);
+    }
+}
+
+impl Default for PayloadKeyRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Container side of the handshake, as implemented by `bootstrap.py`.
+    fn agent_key(
+        execution_id: ExecutionId,
+        secret: EphemeralSecret,
+        orchestrator_public_b64: &str,
+    ) -> PayloadKey {
+        let bytes: [u8; 32] = STANDARD
+            .decode(orchestrator_public_b64)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        let shared = secret.diffie_hellman(&PublicKey::from(bytes));
+        PayloadKey::derive(shared.as_bytes(), execution_id)
+    }
+
+    #[test]
+    fn negotiated_keys_match_on_both_sides() {
+        let execution_id = ExecutionId::new();
+        let agent_secret = EphemeralSecret::random_from_rng(OsRng);
+        let agent_public = STANDARD.encode(PublicKey::from(&agent_secret).as_bytes());
+
+        let registry = PayloadKeyRegistry::new();
+        let orchestrator_public = registry.negotiate(execution_id, &agent_public).unwrap();
+        let agent = agent_key(execution_id, agent_secret, &orchestrator_public);
+        let orchestrator = registry.get(execution_id).unwrap();
+
+        let sealed = agent.encrypt("summarise the quarterly figures");
+        assert_eq!(
+            orchestrator.decrypt(&sealed).unwrap(),
+            "summarise the quarterly figures"
+        );
+        let reply = orchestrator.encrypt("done");
+        assert_eq!(agent.decrypt(&reply).unwrap(), "done");
+    }
+
+    #[test]
+    fn ciphertext_is_bound_to_its_execution() {
+        let shared = [7u8; 32];
+        let key = PayloadKey::derive(&shared, ExecutionId::new());
+        let other = PayloadKey {
+            execution_id: ExecutionId::new(),
+            ..key.clone()
+        };
+
+        let sealed = key.encrypt("secret");
+        assert!(matches!(
+            other.decrypt(&sealed),
+            Err(PayloadCryptoError::DecryptionFailed)
+        ));
+
+        let mut tampered = sealed.clone();
+        tampered.ciphertext = STANDARD.encode(b"not the ciphertext at all");
+        assert!(key.decrypt(&tampered).is_err());
+    }
+
+    #[test]
+    fn rejects_malformed_public_keys() {
+        let registry = PayloadKeyRegistry::new();
+        let execution_id = ExecutionId::new();
+        assert!(registry.negotiate(execution_id, "not base64!").is_err());
+        assert!(registry
+            .negotiate(execution_id, &STANDARD.encode([1u8; 16]))
+            .is_err());
+        assert!(registry
+            .negotiate(execution_id, &STANDARD.encode([0u8; 32]))
+            .is_err());
+        assert!(matches!(
+            registry.get(execution_id),
+            Err(PayloadCryptoError::NoKey(_))
+        ));
+    }
+}
@@ -0,0 +1,364 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # SEAL Streamed Responses
+//!
+//! Tool results that are too large for one response (log tails, long query
+//! results) are returned as a sequence of signed [`SealResponseFrame`]s
+//! instead of a single JSON body.
+//!
+//! ## Frame Chain
+//!
+//! ```text
+//! digest[0] = SHA-256(stream_id ":" 0 ":" chunk[0])
+//! digest[n] = SHA-256(digest[n-1] ":" n ":" chunk[n])
+//! signature = Ed25519(canonical(stream_id, sequence, chunk, is_final, digest))
+//! ```
+//!
+//! Sequence numbers start at 0 and increase by one per frame; the last frame
+//! has `is_final: true`. Because each digest covers the previous one, a
+//! dropped, reordered or replayed frame breaks the chain even if its own
+//! signature is valid. [`SealStreamVerifier`] implements the receiving side.
+//!
+//! Frames are signed with the node's response signing key
+//! ([`SealStreamSigner`]). Its public key is returned from attestation as
+//! `response_signing_key`, so the agent learns it over the channel it already
+//! trusts.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand_core::{OsRng, RngCore};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use uuid::Uuid;
+
+/// Protocol identifier carried by every frame.
+pub const SEAL_STREAM_PROTOCOL: &str = "seal-stream/v1";
+
+/// Default upper bound on the chunk carried by one frame, in bytes.
+pub const DEFAULT_STREAM_CHUNK_BYTES: usize = 64 * 1024;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SealStreamError {
+    #[error("unsupported stream protocol '{0}'")]
+    UnsupportedProtocol(String),
+
+    #[error("frame belongs to stream {actual}, expected {expected}")]
+    WrongStream { expected: Uuid, actual: Uuid },
+
+    #[error("out-of-order frame: expected sequence {expected}, got {actual}")]
+    OutOfOrder { expected: u64, actual: u64 },
+
+    #[error("frame {0} received after the final frame")]
+    AfterFinal(u64),
+
+    #[error("digest mismatch at sequence {0}")]
+    DigestMismatch(u64),
+
+    #[error("invalid signature at sequence {0}")]
+    InvalidSignature(u64),
+
+    #[error("stream ended before the final frame (received {0} frames)")]
+    Incomplete(u64),
+
+    #[error("invalid response signing key: {0}")]
+    InvalidKey(String),
+}
+
+/// One signed chunk of a streamed tool response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SealResponseFrame {
+    pub protocol: String,
+    pub stream_id: Uuid,
+    pub sequence: u64,
+    pub chunk: String,
+    pub is_final: bool,
+    /// Hex SHA-256 chain digest covering this chunk and every earlier one.
+    pub digest: String,
+    /// Base64 Ed25519 signature over the canonical frame.
+    pub signature: String,
+}
+
+/// Node-level Ed25519 key that signs response frames.
+pub struct SealStreamSigner {
+    signing_key: SigningKey,
+}
+
+impl SealStreamSigner {
+    /// Generate a fresh key. The key lives for the lifetime of the process;
+    /// agents re-learn it on every attestation.
+    pub fn generate() -> Self {
+        let mut secret = [0u8; 32];
+        OsRng.fill_bytes(&mut secret);
+        Self::from_bytes(&secret)
+    }
+
+    pub fn from_bytes(secret: &[u8; 32]) -> Self {
+        Self {
+            signing_key: SigningKey::from_bytes(secret),
+        }
+    }
+
+    /// Base64 raw 32-byte public key, as returned from attestation.
+    pub fn public_key_base64(&self) -> String {
+        STANDARD.encode(self.signing_key.verifying_key().as_bytes())
+    }
+
+    /// Split `body` into chunks of at most `max_chunk_bytes` and sign them as
+    /// one stream. An empty body still yields a single (empty) final frame.
+    pub fn frame(&self, body: &str, max_chunk_bytes: usize) -> Vec<SealResponseFrame> {
+        let chunks = chunk_text(body, max_chunk_bytes);
+        let mut writer = self.stream(Uuid::new_v4());
+        let last = chunks.len() - 1;
+        chunks
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| writer.next_frame(chunk, i == last))
+            .collect()
+    }
+
+    /// Start a stream whose frames are produced incrementally.
+    pub fn stream(&self, stream_id: Uuid) -> SealStreamWriter<'_> {
+        SealStreamWriter {
+            signer: self,
+            stream_id,
+            sequence: 0,
+            digest: stream_id.to_string(),
+        }
+    }
+}
+
+/// Produces the frames of one stream in order.
+pub struct SealStreamWriter<'a> {
+    signer: &'a SealStreamSigner,
+    stream_id: Uuid,
+    sequence: u64,
+    digest: String,
+}
+
+impl SealStreamWriter<'_> {
+    pub fn next_frame(&mut self, chunk: String, is_final: bool) -> SealResponseFrame {
+        let sequence = self.sequence;
+        let digest = chain_digest(&self.digest, sequence, &chunk);
+        let signature = self.signer.signing_key.sign(&canonical_frame(
+            self.stream_id,
+            sequence,
+            &chunk,
+            is_final,
+            &digest,
+        ));
+        self.sequence += 1;
+        self.digest = digest.clone();
+        SealResponseFrame {
+            protocol: SEAL_STREAM_PROTOCOL.to_string(),
+            stream_id: self.stream_id,
+            sequence,
+            chunk,
+            is_final,
+            digest,
+            signature: STANDARD.encode(signature.to_bytes()),
+        }
+    }
+}
+
+/// Receiving side: checks order, chain and signature of each frame and
+/// reassembles the body.
+pub struct SealStreamVerifier {
+    verifying_key: VerifyingKey,
+    stream_id: Option<Uuid>,
+    expected_sequence: u64,
+    digest: String,
+    finished: bool,
+    body: String,
+}
+
+impl SealStreamVerifier {
+    /// `response_signing_key` is the base64 key returned from attestation.
+    pub fn new(response_signing_key: &str) -> Result<Self, SealStreamError> {
+        let bytes: [u8; 32] = STANDARD
+            .decode(response_signing_key)
+            .map_err(|e| SealStreamError::InvalidKey(e.to_string()))?
+            .try_into()
+            .map_err(|_| SealStreamError::InvalidKey("must be 32 bytes".to_string()))?;
+        let verifying_key = VerifyingKey::from_bytes(&bytes)
+            .map_err(|e| SealStreamError::InvalidKey(e.to_string()))?;
+        Ok(Self {
+            verifying_key,
+            stream_id: None,
+            expected_sequence: 0,
+            digest: String::new(),
+            finished: false,
+            body: String::new(),
+        })
+    }
+
+    /// Verify the next frame and append its chunk to the body.
+    pub fn accept(&mut self, frame: &SealResponseFrame) -> Result<(), SealStreamError> {
+        if frame.protocol != SEAL_STREAM_PROTOCOL {
+            return Err(SealStreamError::UnsupportedProtocol(frame.protocol.clone()));
+        }
+        if self.finished {
+            return Err(SealStreamError::AfterFinal(frame.sequence));
+        }
+        let stream_id = *self.stream_id.get_or_insert(frame.stream_id);
+        if frame.stream_id != stream_id {
+            return Err(SealStreamError::WrongStream {
+                expected: stream_id,
+                actual: frame.stream_id,
+            });
+        }
+        if frame.sequence != self.expected_sequence {
+            return Err(SealStreamError::OutOfOrder {
+                expected: self.expected_sequence,
+                actual: frame.sequence,
+            });
+        }
+
+        let previous = if frame.sequence == 0 {
+            stream_id.to_string()
+        } else {
+            self.digest.clone()
+        };
+        if chain_digest(&previous, frame.sequence, &frame.chunk) != frame.digest {
+            return Err(SealStreamError::DigestMismatch(frame.sequence));
+        }
+
+        let signature = STANDARD
+            .decode(&frame.signature)
+            .ok()
+            .and_then(|bytes| <[u8; 64]>::try_from(bytes).ok())
+            .map(|bytes| Signature::from_bytes(&bytes))
+            .ok_or(SealStreamError::InvalidSignature(frame.sequence))?;
+        let message = canonical_frame(
+            stream_id,
+            frame.sequence,
+            &frame.chunk,
+            frame.is_final,
+            &frame.digest,
+        );
+        self.verifying_key
+            .verify(&message, &signature)
+            .map_err(|_| SealStreamError::InvalidSignature(frame.sequence))?;
+
+        self.expected_sequence += 1;
+        self.digest = frame.digest.clone();
+        self.finished = frame.is_final;
+        self.body.push_str(&frame.chunk);
+        Ok(())
+    }
+
+    /// Return the reassembled body once the final frame has been accepted.
+    pub fn finish(self) -> Result<String, SealStreamError> {
+        if self.finished {
+            Ok(self.body)
+        } else {
+            Err(SealStreamError::Incomplete(self.expected_sequence))
+        }
+    }
+}
+
+/// Split `text` into pieces of at most `max_bytes`, never inside a UTF-8
+/// character. Always returns at least one (possibly empty) piece.
+pub fn chunk_text(text: &str, max_bytes: usize) -> Vec<String> {
+    let max_bytes = max_bytes.max(4);
+    let mut chunks = Vec::new();
+    let mut rest = text;
+    while rest.len() > max_bytes {
+        let mut end = max_bytes;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        chunks.push(rest[..end].to_string());
+        rest = &rest[end..];
+    }
+    chunks.push(rest.to_string());
+    chunks
+}
+
+fn chain_digest(previous: &str, sequence: u64, chunk: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(previous.as_bytes());
+    hasher.update(format!(":{sequence}:").as_bytes());
+    hasher.update(chunk.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+fn canonical_frame(
+    stream_id: Uuid,
+    sequence: u64,
+    chunk: &str,
+    is_final: bool,
+    digest: &str,
+) -> Vec<u8> {
+    serde_json::to_vec(&serde_json::json!({
+        "protocol": SEAL_STREAM_PROTOCOL,
+        "stream_id": stream_id,
+        "sequence": sequence,
+        "chunk": chunk,
+        "is_final": is_final,
+        "digest": digest,
+    }))
+    .expect("canonical frame is always serializable")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frames_round_trip_and_detect_tampering() {
+        let signer = SealStreamSigner::from_bytes(&[9u8; 32]);
+        let body = "line one\nline two — ünïcode\nline three\n".repeat(20);
+        let frames = signer.frame(&body, 64);
+        assert!(frames.len() > 1);
+        assert!(frames.last().unwrap().is_final);
+
+        let mut verifier = SealStreamVerifier::new(&signer.public_key_base64()).unwrap();
+        for frame in &frames {
+            verifier.accept(frame).unwrap();
+        }
+        assert_eq!(verifier.finish().unwrap(), body);
+
+        // Dropping a frame breaks the sequence.
+        let mut verifier = SealStreamVerifier::new(&signer.public_key_base64()).unwrap();
+        verifier.accept(&frames[0]).unwrap();
+        assert!(matches!(
+            verifier.accept(&frames[2]),
+            Err(SealStreamError::OutOfOrder {
+                expected: 1,
+                actual: 2
+            })
+        ));
+
+        // Editing a chunk breaks the chain.
+        let mut tampered = frames[0].clone();
+        tampered.chunk.push('!');
+        let mut verifier = SealStreamVerifier::new(&signer.public_key_base64()).unwrap();
+        assert_eq!(
+            verifier.accept(&tampered),
+            Err(SealStreamError::DigestMismatch(0))
+        );
+
+        // A frame signed by another key is rejected.
+        let other = SealStreamSigner::from_bytes(&[1u8; 32]);
+        let mut verifier = SealStreamVerifier::new(&other.public_key_base64()).unwrap();
+        assert_eq!(
+            verifier.accept(&frames[0]),
+            Err(SealStreamError::InvalidSignature(0))
+        );
+
+        // Stopping early is not a complete stream.
+        let mut verifier = SealStreamVerifier::new(&signer.public_key_base64()).unwrap();
+        verifier.accept(&frames[0]).unwrap();
+        assert_eq!(verifier.finish(), Err(SealStreamError::Incomplete(1)));
+    }
+
+    #[test]
+    fn chunk_text_respects_char_boundaries() {
+        let chunks = chunk_text("ééééé", 5);
+        assert!(chunks.iter().all(|c| c.len() <= 5));
+        assert_eq!(chunks.concat(), "ééééé");
+        assert_eq!(chunk_text("", 16), vec![String::new()]);
+    }
+}
@@ -14,15 +14,17 @@ pub mod local_host_provider;
 pub mod opendal_provider;
 pub mod remote_storage_server;
 pub mod seaweedfs;
+pub mod write_behind;
 
 use crate::domain::storage::{
-    DirEntry, FileAttributes, FileHandle, FileType, OpenMode, StorageProvider,
+    DirEntry, FileAttributes, FileHandle, FileType, OpenMode, StorageError, StorageProvider,
 };
 
 pub use local_host_provider::LocalHostStorageProvider;
 pub use opendal_provider::OpenDalStorageProvider;
 pub use remote_storage_server::RemoteStorageServiceHandler;
 pub use seaweedfs::SeaweedFSAdapter;
+pub use write_behind::WriteBehindConfig;
 pub mod seal_provider;
 use opendal::Operator;
 pub use seal_provider::SealStorageProvider;
@@ -34,7 +36,11 @@ use std::sync::Arc;
 #[derive(Debug, Clone)]
 pub enum StorageBackend {
     /// SeaweedFS distributed storage (production)
-    SeaweedFS { filer_url: String },
+    SeaweedFS {
+        filer_url: String,
+        /// Coalesce small writes per file; `None` uploads every write directly
+        write_behind: Option<WriteBehindConfig>,
+    },
 
     /// Local host mount point for direct host IO (ADR-047)
     LocalHost { mount_point: String },
@@ -60,7 +66,16 @@ pub fn create_storage_provider(
     backend: StorageBackend,
 ) -> Result<Arc<dyn StorageProvider>, anyhow::Error> {
     match backend {
-        StorageBackend::SeaweedFS { filer_url } => Ok(Arc::new(SeaweedFSAdapter::new(filer_url))),
+        StorageBackend::SeaweedFS {
+            filer_url,
+            write_behind,
+        } => {
+            let adapter = SeaweedFSAdapter::new(filer_url);
+            Ok(match write_behind {
+                Some(config) => Arc::new(adapter.with_write_behind(config)),
+                None => Arc::new(adapter),
+            })
+        }
         StorageBackend::LocalHost { mount_point } => {
             let provider = LocalHostStorageProvider::new(mount_point)
                 .context("Failed to create LocalHostStorageProvider")?;
@@ -218,6 +233,29 @@ mod test_support {
     }
 }
 
+/// Convert a gRPC status from a remote FSAL or storage node back into the
+/// [`StorageError`] it was raised as, so the cause survives the hop.
+pub(crate) fn status_to_storage_err(status: tonic::Status) -> StorageError {
+    let message = status.message().to_string();
+    match status.code() {
+        tonic::Code::NotFound => StorageError::FileNotFound(message),
+        tonic::Code::PermissionDenied | tonic::Code::Unauthenticated => {
+            StorageError::PermissionDenied(message)
+        }
+        tonic::Code::AlreadyExists => StorageError::AlreadyExists(message),
+        tonic::Code::ResourceExhausted => StorageError::QuotaExceeded {
+            path: String::new(),
+            limit_bytes: 0,
+            actual_bytes: 0,
+        },
+        tonic::Code::InvalidArgument => StorageError::InvalidPath(message),
+        tonic::Code::Unavailable => StorageError::Unavailable(message),
+        tonic::Code::DeadlineExceeded => StorageError::Timeout,
+        tonic::Code::DataLoss => StorageError::Corrupted(message),
+        _ => StorageError::Unknown(message),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,6 +264,7 @@ mod tests {
     fn test_factory_seaweedfs() {
         let provider = create_storage_provider(StorageBackend::SeaweedFS {
             filer_url: "http://localhost:8888".to_string(),
+            write_behind: Some(WriteBehindConfig::default()),
         })
         .expect("SeaweedFS storage provider should be created successfully");
 
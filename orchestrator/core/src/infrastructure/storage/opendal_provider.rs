@@ -15,6 +15,7 @@ use crate::domain::storage::{
     DirEntry, FileAttributes, FileHandle, FileType, OpenMode, StorageError, StorageProvider,
 };
 use async_trait::async_trait;
+use bytes::Bytes;
 use opendal::Operator;
 
 pub struct OpenDalStorageProvider {
@@ -96,6 +97,25 @@ impl StorageProvider for OpenDalStorageProvider {
         Ok(data.to_vec())
     }
 
+    async fn read_at_bytes(
+        &self,
+        handle: &FileHandle,
+        offset: u64,
+        length: usize,
+    ) -> Result<Bytes, StorageError> {
+        let path = String::from_utf8(handle.0.clone())
+            .map_err(|_| StorageError::InvalidPath("Invalid handle".into()))?;
+
+        // `Buffer::to_bytes` is zero-copy when the backend returned one
+        // contiguous chunk.
+        let data = self
+            .operator
+            .read_with(&path)
+            .range(offset..offset + length as u64)
+            .await?;
+        Ok(data.to_bytes())
+    }
+
     async fn write_at(
         &self,
         handle: &FileHandle,
@@ -184,6 +184,7 @@ impl RemoteStorageServiceHandler {
             FsalError::InvalidFileHandle => Status::invalid_argument(e.to_string()),
             FsalError::HandleDeserialization(_) => Status::invalid_argument(e.to_string()),
             FsalError::QuotaExceeded { .. } => Status::resource_exhausted(e.to_string()),
+            FsalError::FileTooLarge { .. } => Status::failed_precondition(e.to_string()),
             FsalError::Storage(ref se) => Self::storage_err_to_status(se),
         }
     }
@@ -199,6 +200,8 @@ impl RemoteStorageServiceHandler {
             StorageError::QuotaExceeded { .. } => Status::resource_exhausted("quota exceeded"),
             StorageError::Unavailable(m) => Status::unavailable(m.clone()),
             StorageError::InvalidPath(m) => Status::invalid_argument(m.clone()),
+            StorageError::Timeout => Status::deadline_exceeded(e.to_string()),
+            StorageError::Corrupted(m) => Status::data_loss(m.clone()),
             _ => Status::internal(e.to_string()),
         }
     }
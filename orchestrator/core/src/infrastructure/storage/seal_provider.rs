@@ -30,6 +30,7 @@ use crate::infrastructure::aegis_remote_storage_proto::{
     remote_storage_service_client::RemoteStorageServiceClient, CreateFileRequest, GetUsageRequest,
     OpenFileRequest, RemoteStorageRequest, RenameRequest, SetQuotaRequest,
 };
+use crate::infrastructure::storage::status_to_storage_err;
 
 /// gRPC-backed storage provider for cross-node volume access.
 ///
@@ -184,28 +185,6 @@ impl SealStorageProvider {
         })
     }
 
-    /// Convert a tonic Status into a StorageError.
-    fn status_to_storage_err(status: tonic::Status) -> StorageError {
-        match status.code() {
-            tonic::Code::NotFound => StorageError::FileNotFound(status.message().to_string()),
-            tonic::Code::PermissionDenied => {
-                StorageError::PermissionDenied(status.message().to_string())
-            }
-            tonic::Code::AlreadyExists => StorageError::AlreadyExists(status.message().to_string()),
-            tonic::Code::ResourceExhausted => StorageError::QuotaExceeded {
-                path: String::new(),
-                limit_bytes: 0,
-                actual_bytes: 0,
-            },
-            tonic::Code::InvalidArgument => StorageError::InvalidPath(status.message().to_string()),
-            tonic::Code::Unavailable => StorageError::Unavailable(status.message().to_string()),
-            tonic::Code::Unauthenticated => {
-                StorageError::PermissionDenied(status.message().to_string())
-            }
-            _ => StorageError::Unknown(status.message().to_string()),
-        }
-    }
-
     /// Convert a domain `OpenMode` to the proto integer representation.
     fn open_mode_to_proto(mode: OpenMode) -> i32 {
         match mode {
@@ -240,7 +219,7 @@ impl StorageProvider for SealStorageProvider {
                 path: internal_path,
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         if resp.success {
@@ -262,7 +241,7 @@ impl StorageProvider for SealStorageProvider {
                 path: internal_path,
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         if resp.success {
@@ -285,7 +264,7 @@ impl StorageProvider for SealStorageProvider {
                 bytes,
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         if resp.success {
@@ -307,7 +286,7 @@ impl StorageProvider for SealStorageProvider {
                 path: internal_path,
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         Ok(resp.bytes_used)
@@ -332,7 +311,7 @@ impl StorageProvider for SealStorageProvider {
                 mode: Self::open_mode_to_proto(mode),
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         Ok(FileHandle(resp.file_handle))
@@ -396,7 +375,7 @@ impl StorageProvider for SealStorageProvider {
                 path: internal_path,
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         Ok(FileAttributes {
@@ -424,7 +403,7 @@ impl StorageProvider for SealStorageProvider {
                 path: internal_path,
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         Ok(resp
@@ -450,7 +429,7 @@ impl StorageProvider for SealStorageProvider {
                 mode,
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         Ok(FileHandle(resp.file_handle))
@@ -468,7 +447,7 @@ impl StorageProvider for SealStorageProvider {
                 path: internal_path,
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         if resp.success {
@@ -492,7 +471,7 @@ impl StorageProvider for SealStorageProvider {
                 to_path: internal_to,
             })
             .await
-            .map_err(Self::status_to_storage_err)?
+            .map_err(status_to_storage_err)?
             .into_inner();
 
         if resp.success {
@@ -0,0 +1,169 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Execution Task Queue Backends
+//!
+//! Implementations of [`TaskQueue`] selected by `spec.execution_queue.backend`:
+//!
+//! | Backend | Type | Claiming |
+//! |---------|------|----------|
+//! | `postgres` | [`PostgresTaskQueue`] | `FOR UPDATE SKIP LOCKED` on `execution_task_queue` |
+//! | `redis` | [`RedisTaskQueue`] | Redis Streams consumer group (`redis-task-queue` feature) |
+//! | — | [`InMemoryTaskQueue`] | Single process; tests only |
+
+pub mod postgres;
+#[cfg(feature = "redis-task-queue")]
+pub mod redis;
+
+pub use postgres::PostgresTaskQueue;
+#[cfg(feature = "redis-task-queue")]
+pub use redis::RedisTaskQueue;
+
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use crate::domain::task_queue::{ClaimedTask, ExecutionTask, TaskQueue};
+
+struct QueueEntry {
+    task: ExecutionTask,
+    available_at: Instant,
+    attempts: u32,
+    receipt: Option<String>,
+}
+
+/// Process-local [`TaskQueue`]; only one daemon can consume it.
+pub struct InMemoryTaskQueue {
+    visibility_timeout: Duration,
+    entries: Mutex<Vec<QueueEntry>>,
+}
+
+impl InMemoryTaskQueue {
+    pub fn new(visibility_timeout: Duration) -> Self {
+        Self {
+            visibility_timeout,
+            entries: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl TaskQueue for InMemoryTaskQueue {
+    fn backend(&self) -> &'static str {
+        "memory"
+    }
+
+    async fn enqueue(&self, task: &ExecutionTask) -> anyhow::Result<()> {
+        let mut entries = self.entries.lock().unwrap();
+        if entries
+            .iter()
+            .all(|e| e.task.execution_id != task.execution_id)
+        {
+            entries.push(QueueEntry {
+                task: task.clone(),
+                available_at: Instant::now(),
+                attempts: 0,
+                receipt: None,
+            });
+        }
+        Ok(())
+    }
+
+    async fn claim(&self, _consumer: &str) -> anyhow::Result<Option<ClaimedTask>> {
+        let now = Instant::now();
+        let mut entries = self.entries.lock().unwrap();
+        let Some(entry) = entries.iter_mut().find(|e| e.available_at <= now) else {
+            return Ok(None);
+        };
+        let receipt = uuid::Uuid::new_v4().to_string();
+        entry.available_at = now + self.visibility_timeout;
+        entry.attempts += 1;
+        entry.receipt = Some(receipt.clone());
+        Ok(Some(ClaimedTask {
+            task: entry.task.clone(),
+            receipt,
+            attempts: entry.attempts,
+        }))
+    }
+
+    async fn ack(&self, claim: &ClaimedTask) -> anyhow::Result<()> {
+        self.entries
+            .lock()
+            .unwrap()
+            .retain(|e| e.receipt.as_deref() != Some(claim.receipt.as_str()));
+        Ok(())
+    }
+
+    async fn release(&self, claim: &ClaimedTask, delay: Duration) -> anyhow::Result<()> {
+        let mut entries = self.entries.lock().unwrap();
+        if let Some(entry) = entries
+            .iter_mut()
+            .find(|e| e.receipt.as_deref() == Some(claim.receipt.as_str()))
+        {
+            entry.available_at = Instant::now() + delay;
+            entry.receipt = None;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::execution::{ExecutionId, ExecutionInput};
+    use crate::domain::shared_kernel::AgentId;
+    use crate::domain::tenant::TenantId;
+
+    fn task() -> ExecutionTask {
+        ExecutionTask {
+            execution_id: ExecutionId::new(),
+            tenant_id: TenantId::consumer(),
+            agent_id: AgentId::new(),
+            input: ExecutionInput {
+                intent: Some("hello".to_string()),
+                input: serde_json::json!({}),
+                workspace_volume_id: None,
+                workspace_volume_mount_path: None,
+                workspace_remote_path: None,
+                workflow_execution_id: None,
+                attachments: Vec::new(),
+            },
+            security_context_name: "default".to_string(),
+            identity: None,
+            enqueued_at: chrono::Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn claims_are_exclusive_until_released_or_expired() {
+        let queue = InMemoryTaskQueue::new(Duration::from_secs(60));
+        let first = task();
+        queue.enqueue(&first).await.unwrap();
+        // Re-enqueueing the same execution is a no-op.
+        queue.enqueue(&first).await.unwrap();
+        queue.enqueue(&task()).await.unwrap();
+
+        let a = queue.claim("node-a").await.unwrap().unwrap();
+        let b = queue.claim("node-b").await.unwrap().unwrap();
+        assert_eq!(a.task.execution_id, first.execution_id);
+        assert_ne!(b.task.execution_id, first.execution_id);
+        assert!(queue.claim("node-c").await.unwrap().is_none());
+
+        queue.release(&a, Duration::ZERO).await.unwrap();
+        queue.ack(&b).await.unwrap();
+        let again = queue.claim("node-c").await.unwrap().unwrap();
+        assert_eq!(again.task.execution_id, first.execution_id);
+        assert_eq!(again.attempts, 2);
+        // A stale receipt from the earlier claim no longer acks the task.
+        queue.ack(&a).await.unwrap();
+        queue.ack(&again).await.unwrap();
+        assert!(queue.claim("node-c").await.unwrap().is_none());
+
+        let expiring = InMemoryTaskQueue::new(Duration::ZERO);
+        expiring.enqueue(&task()).await.unwrap();
+        let lost = expiring.claim("node-a").await.unwrap().unwrap();
+        let redelivered = expiring.claim("node-b").await.unwrap().unwrap();
+        assert_eq!(redelivered.task.execution_id, lost.task.execution_id);
+        assert_eq!(redelivered.attempts, 2);
+    }
+}
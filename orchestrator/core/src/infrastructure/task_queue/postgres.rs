@@ -0,0 +1,122 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # PostgreSQL Execution Task Queue
+//!
+//! [`TaskQueue`] over the `execution_task_queue` table (migration 045). A
+//! claim locks the oldest visible row with `FOR UPDATE SKIP LOCKED`, so
+//! daemons polling concurrently never wait on or double-claim the same row.
+//! Visibility is judged by `NOW()` on the database, not daemon clocks.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sqlx::postgres::PgPool;
+use sqlx::Row;
+use uuid::Uuid;
+
+use crate::domain::task_queue::{ClaimedTask, ExecutionTask, TaskQueue};
+
+pub struct PostgresTaskQueue {
+    pool: PgPool,
+    visibility_timeout: Duration,
+}
+
+impl PostgresTaskQueue {
+    pub fn new(pool: PgPool, visibility_timeout: Duration) -> Self {
+        Self {
+            pool,
+            visibility_timeout,
+        }
+    }
+}
+
+#[async_trait]
+impl TaskQueue for PostgresTaskQueue {
+    fn backend(&self) -> &'static str {
+        "postgres"
+    }
+
+    async fn enqueue(&self, task: &ExecutionTask) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO execution_task_queue (execution_id, tenant_id, task, enqueued_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (execution_id) DO NOTHING
+            "#,
+        )
+        .bind(task.execution_id.0)
+        .bind(task.tenant_id.as_str())
+        .bind(serde_json::to_value(task)?)
+        .bind(task.enqueued_at)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn claim(&self, consumer: &str) -> anyhow::Result<Option<ClaimedTask>> {
+        let receipt = Uuid::new_v4();
+        let row = sqlx::query(
+            r#"
+            UPDATE execution_task_queue
+            SET claimed_by = $1,
+                claim_token = $2,
+                attempts = attempts + 1,
+                available_at = NOW() + $3 * INTERVAL '1 millisecond'
+            WHERE execution_id = (
+                SELECT execution_id
+                FROM execution_task_queue
+                WHERE available_at <= NOW()
+                ORDER BY enqueued_at
+                LIMIT 1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING task, attempts
+            "#,
+        )
+        .bind(consumer)
+        .bind(receipt)
+        .bind(self.visibility_timeout.as_millis() as i64)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let task: serde_json::Value = row.get("task");
+        let attempts: i32 = row.get("attempts");
+        Ok(Some(ClaimedTask {
+            task: serde_json::from_value(task)?,
+            receipt: receipt.to_string(),
+            attempts: attempts as u32,
+        }))
+    }
+
+    async fn ack(&self, claim: &ClaimedTask) -> anyhow::Result<()> {
+        sqlx::query(
+            "DELETE FROM execution_task_queue WHERE execution_id = $1 AND claim_token = $2",
+        )
+        .bind(claim.task.execution_id.0)
+        .bind(Uuid::parse_str(&claim.receipt)?)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    async fn release(&self, claim: &ClaimedTask, delay: Duration) -> anyhow::Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE execution_task_queue
+            SET claimed_by = NULL,
+                claim_token = NULL,
+                available_at = NOW() + $3 * INTERVAL '1 millisecond'
+            WHERE execution_id = $1 AND claim_token = $2
+            "#,
+        )
+        .bind(claim.task.execution_id.0)
+        .bind(Uuid::parse_str(&claim.receipt)?)
+        .bind(delay.as_millis() as i64)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Redis Streams Execution Task Queue
+//!
+//! [`TaskQueue`] over one Redis stream read through the consumer group
+//! [`CONSUMER_GROUP`]. Each daemon reads as its own consumer:
+//!
+//! - **claim** first takes over, with `XAUTOCLAIM`, an entry whose previous
+//!   consumer has held it longer than the visibility timeout, then falls back
+//!   to `XREADGROUP` for new entries. The pending-entry delivery count is the
+//!   attempt number.
+//! - **ack** is `XACK` plus `XDEL`, so the stream only holds unfinished work.
+//! - **release** re-`XCLAIM`s the entry with an `IDLE` time chosen so that it
+//!   becomes reclaimable after the requested delay. Delays are therefore
+//!   capped at the visibility timeout.
+
+use std::time::Duration;
+
+use anyhow::anyhow;
+use async_trait::async_trait;
+use redis::aio::ConnectionManager;
+use redis::streams::{
+    StreamAutoClaimOptions, StreamAutoClaimReply, StreamClaimOptions, StreamId,
+    StreamPendingCountReply, StreamReadOptions, StreamReadReply,
+};
+use redis::AsyncCommands;
+
+use crate::domain::task_queue::{ClaimedTask, ExecutionTask, TaskQueue};
+
+/// Consumer group shared by every daemon reading the stream.
+pub const CONSUMER_GROUP: &str = "aegis-execution-workers";
+
+/// Consumer that holds released entries until they are reclaimed.
+const RELEASED_CONSUMER: &str = "released";
+
+/// Stream entry field holding the JSON-encoded [`ExecutionTask`].
+const TASK_FIELD: &str = "task";
+
+pub struct RedisTaskQueue {
+    connection: ConnectionManager,
+    stream: String,
+    visibility_timeout: Duration,
+}
+
+impl RedisTaskQueue {
+    /// Connect to `url` and create the stream and consumer group if missing.
+    pub async fn connect(
+        url: &str,
+        stream: impl Into<String>,
+        visibility_timeout: Duration,
+    ) -> anyhow::Result<Self> {
+        let client = redis::Client::open(url)?;
+        let mut connection = ConnectionManager::new(client).await?;
+        let stream = stream.into();
+        let created: redis::RedisResult<()> = connection
+            .xgroup_create_mkstream(&stream, CONSUMER_GROUP, "0")
+            .await;
+        match created {
+            Ok(()) => {}
+            Err(e) if e.code() == Some("BUSYGROUP") => {}
+            Err(e) => return Err(e.into()),
+        }
+        Ok(Self {
+            connection,
+            stream,
+            visibility_timeout,
+        })
+    }
+
+    /// Decode a stream entry. An undecodable entry would otherwise be
+    /// redelivered forever, so it is dropped from the stream.
+    async fn decode(&self, entry: &StreamId, attempts: u32) -> anyhow::Result<ClaimedTask> {
+        let task = entry
+            .get::<String>(TASK_FIELD)
+            .ok_or_else(|| anyhow!("stream entry {} has no `{TASK_FIELD}` field", entry.id))
+            .and_then(|payload| Ok(serde_json::from_str::<ExecutionTask>(&payload)?));
+        match task {
+            Ok(task) => Ok(ClaimedTask {
+                task,
+                receipt: entry.id.clone(),
+                attempts,
+            }),
+            Err(e) => {
+                self.delete(&entry.id).await?;
+                Err(e.context(format!("dropped malformed execution task {}", entry.id)))
+            }
+        }
+    }
+
+    async fn delete(&self, id: &str) -> anyhow::Result<()> {
+        let mut connection = self.connection.clone();
+        let _: () = redis::pipe()
+            .xack(&self.stream, CONSUMER_GROUP, &[id])
+            .ignore()
+            .xdel(&self.stream, &[id])
+            .ignore()
+            .query_async(&mut connection)
+            .await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TaskQueue for RedisTaskQueue {
+    fn backend(&self) -> &'static str {
+        "redis"
+    }
+
+    async fn enqueue(&self, task: &ExecutionTask) -> anyhow::Result<()> {
+        let mut connection = self.connection.clone();
+        let payload = serde_json::to_string(task)?;
+        let _: String = connection
+            .xadd(&self.stream, "*", &[(TASK_FIELD, payload)])
+            .await?;
+        Ok(())
+    }
+
+    async fn claim(&self, consumer: &str) -> anyhow::Result<Option<ClaimedTask>> {
+        let mut connection = self.connection.clone();
+
+        let reclaimed: StreamAutoClaimReply = connection
+            .xautoclaim_options(
+                &self.stream,
+                CONSUMER_GROUP,
+                consumer,
+                self.visibility_timeout.as_millis() as usize,
+                "0-0",
+                StreamAutoClaimOptions::default().count(1),
+            )
+            .await?;
+        if let Some(entry) = reclaimed.claimed.first() {
+            let pending: StreamPendingCountReply = connection
+                .xpending_count(&self.stream, CONSUMER_GROUP, &entry.id, &entry.id, 1)
+                .await?;
+            let attempts = pending.ids.first().map_or(1, |p| p.times_delivered as u32);
+            return self.decode(entry, attempts).await.map(Some);
+        }
+
+        let reply: StreamReadReply = connection
+            .xread_options(
+                &[&self.stream],
+                &[">"],
+                &StreamReadOptions::default()
+                    .group(CONSUMER_GROUP, consumer)
+                    .count(1),
+            )
+            .await?;
+        match reply.keys.first().and_then(|key| key.ids.first()) {
+            Some(entry) => self.decode(entry, 1).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    async fn ack(&self, claim: &ClaimedTask) -> anyhow::Result<()> {
+        self.delete(&claim.receipt).await
+    }
+
+    async fn release(&self, claim: &ClaimedTask, delay: Duration) -> anyhow::Result<()> {
+        let mut connection = self.connection.clone();
+        let idle = self.visibility_timeout.saturating_sub(delay);
+        let _: redis::Value = connection
+            .xclaim_options(
+                &self.stream,
+                CONSUMER_GROUP,
+                RELEASED_CONSUMER,
+                0,
+                &[&claim.receipt],
+                StreamClaimOptions::default()
+                    .idle(idle.as_millis() as usize)
+                    .with_justid(),
+            )
+            .await?;
+        Ok(())
+    }
+}
@@ -13,6 +13,8 @@
 //! # Client Features
 //!
 //! - **Workflow Execution**: Start workflows with the Generic Interpreter pattern
+//! - **Signals & Queries**: Deliver external signals (human input, blackboard
+//!   updates) and read live FSM state from running workflows
 //! - **Connection Management**: Persistent gRPC channel with timeout handling
 //! - **JSON Payload Encoding**: Standard encoding for workflow inputs
 //! - **Namespace Isolation**: Multi-tenant workflow execution support
@@ -52,6 +54,20 @@
 //! ).await?;
 //! ```
 //!
+//! # Signals and Queries
+//!
+//! The `aegis_workflow` worker function registers a fixed set of handlers:
+//!
+//! | Name | Kind | Effect |
+//! |---|---|---|
+//! | [`HUMAN_INPUT_SIGNAL`] | Signal | Resumes a workflow paused at a `Human` state |
+//! | [`BLACKBOARD_UPDATE_SIGNAL`] | Signal | Merges a JSON object into the workflow blackboard |
+//! | [`RETRY_STATE_SIGNAL`] | Signal | Abandons the current state's activity and re-runs the state |
+//! | [`WORKFLOW_STATE_QUERY`] | Query | Returns current state, blackboard, and state outputs |
+//!
+//! Any other signal name in [`WorkflowSignal::Custom`] is forwarded verbatim so
+//! workflow authors can wait on domain-specific events (e.g. `dataArrived`).
+//!
 //! # Configuration
 //!
 //! - **Address**: Temporal server endpoint (e.g., `localhost:7233`)
@@ -72,6 +88,117 @@ use crate::infrastructure::temporal_proto::temporal::api::workflowservice::v1::w
 use crate::infrastructure::temporal_proto::temporal::api::workflowservice::v1::StartWorkflowExecutionRequest;
 use crate::infrastructure::temporal_proto::temporal::api::common::v1::{WorkflowType, Payloads, Payload};
 
+/// Signal consumed by `Human` states (`defineSignal('humanInput')` in the worker).
+pub const HUMAN_INPUT_SIGNAL: &str = "humanInput";
+
+/// Signal whose payload object is merged into the running workflow's blackboard.
+pub const BLACKBOARD_UPDATE_SIGNAL: &str = "blackboardUpdate";
+
+/// Signal with payload `{ "state": "<name>" }` asking the worker to re-run a
+/// state; ignored unless the workflow is still in that state. Sent by the
+/// workflow watchdog.
+pub const RETRY_STATE_SIGNAL: &str = "retryState";
+
+/// Query returning `{ current_state, blackboard, state_outputs }` from the worker.
+pub const WORKFLOW_STATE_QUERY: &str = "workflowState";
+
+/// Maximum length of a signal or query name accepted from external callers.
+pub const MAX_HANDLER_NAME_LEN: usize = 128;
+
+/// A signal to deliver to a running `aegis_workflow` execution.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WorkflowSignal {
+    /// Free-text response for a workflow paused at a `Human` state.
+    HumanInput(String),
+    /// Key/value pairs merged into the workflow blackboard.
+    BlackboardUpdate(serde_json::Map<String, serde_json::Value>),
+    /// Author-defined signal forwarded to the workflow unchanged.
+    Custom {
+        name: String,
+        payload: serde_json::Value,
+    },
+}
+
+impl WorkflowSignal {
+    /// Build a signal from an externally supplied name and JSON payload.
+    ///
+    /// Well-known names are mapped onto their typed variants so payload shape
+    /// errors surface here rather than inside the worker.
+    pub fn from_parts(name: &str, payload: serde_json::Value) -> Result<Self> {
+        validate_handler_name(name)?;
+        match name {
+            HUMAN_INPUT_SIGNAL => match payload {
+                serde_json::Value::String(response) => Ok(Self::HumanInput(response)),
+                other => Ok(Self::HumanInput(other.to_string())),
+            },
+            BLACKBOARD_UPDATE_SIGNAL => match payload {
+                serde_json::Value::Object(updates) if !updates.is_empty() => {
+                    Ok(Self::BlackboardUpdate(updates))
+                }
+                serde_json::Value::Object(_) => {
+                    anyhow::bail!("'{BLACKBOARD_UPDATE_SIGNAL}' payload must not be empty")
+                }
+                _ => anyhow::bail!("'{BLACKBOARD_UPDATE_SIGNAL}' payload must be a JSON object"),
+            },
+            _ => Ok(Self::Custom {
+                name: name.to_string(),
+                payload,
+            }),
+        }
+    }
+
+    /// Temporal signal name registered by the worker.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::HumanInput(_) => HUMAN_INPUT_SIGNAL,
+            Self::BlackboardUpdate(_) => BLACKBOARD_UPDATE_SIGNAL,
+            Self::Custom { name, .. } => name,
+        }
+    }
+
+    /// JSON payload sent as the single signal argument.
+    pub fn payload(&self) -> serde_json::Value {
+        match self {
+            Self::HumanInput(response) => serde_json::Value::String(response.clone()),
+            Self::BlackboardUpdate(updates) => serde_json::Value::Object(updates.clone()),
+            Self::Custom { payload, .. } => payload.clone(),
+        }
+    }
+}
+
+/// Reject empty, oversized, or non-identifier handler names before they reach
+/// Temporal; names are echoed into worker logs and history.
+pub fn validate_handler_name(name: &str) -> Result<()> {
+    if name.is_empty() || name.len() > MAX_HANDLER_NAME_LEN {
+        anyhow::bail!("handler name must be 1-{MAX_HANDLER_NAME_LEN} characters");
+    }
+    let mut chars = name.chars();
+    let first_ok = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    if !first_ok || !chars.all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.') {
+        anyhow::bail!("invalid handler name '{name}'");
+    }
+    Ok(())
+}
+
+/// Encode a single JSON value as Temporal `Payloads` with `json/plain` encoding.
+fn json_payloads(value: &serde_json::Value) -> Result<Payloads> {
+    let mut metadata = HashMap::new();
+    metadata.insert("encoding".to_string(), "json/plain".as_bytes().to_vec());
+    Ok(Payloads {
+        payloads: vec![Payload {
+            metadata,
+            data: serde_json::to_vec(value)?,
+            // Any additional fields generated from the Temporal proto definition
+            // (for example, `external_payloads` in newer API versions) are left at
+            // their default values. This matches the expected encoding for a
+            // single JSON payload.
+            ..Default::default()
+        }],
+    })
+}
+
 #[derive(Clone)]
 pub struct TemporalClient {
     client: WorkflowServiceClient<Channel>,
@@ -81,6 +208,8 @@ pub struct TemporalClient {
     /// Original Temporal server address (used for diagnostics/reconnection)
     temporal_endpoint: String,
     worker_http_endpoint: String,
+    #[cfg(feature = "fault-injection")]
+    faults: Option<std::sync::Arc<crate::infrastructure::fault_injection::FaultInjector>>,
 }
 
 impl TemporalClient {
@@ -116,14 +245,42 @@ impl TemporalClient {
             task_queue: task_queue.to_string(),
             temporal_endpoint: address.to_string(),
             worker_http_endpoint: worker_http_endpoint.to_string(),
+            #[cfg(feature = "fault-injection")]
+            faults: None,
         })
     }
 
+    /// Consult `faults` (target `temporal`) before every Temporal call.
+    #[cfg(feature = "fault-injection")]
+    pub fn with_fault_injector(
+        mut self,
+        faults: std::sync::Arc<crate::infrastructure::fault_injection::FaultInjector>,
+    ) -> Self {
+        self.faults = Some(faults);
+        self
+    }
+
+    /// No-op unless built with the `fault-injection` feature and an injector is attached.
+    async fn inject_fault(&self, operation: &str) -> Result<()> {
+        #[cfg(feature = "fault-injection")]
+        if let Some(faults) = &self.faults {
+            faults
+                .inject(
+                    crate::infrastructure::fault_injection::FaultTarget::Temporal,
+                    operation,
+                )
+                .await?;
+        }
+        let _ = operation;
+        Ok(())
+    }
+
     /// Start a workflow execution using the Generic Interpreter pattern
     pub async fn start_workflow(
         &self,
         params: crate::application::ports::StartWorkflowParams<'_>,
     ) -> Result<String> {
+        self.inject_fault("start_workflow").await?;
         let execution_workflow_id = params.execution_id.0.to_string();
 
         // Generic workflow type that the worker registers
@@ -142,27 +299,7 @@ impl TemporalClient {
             input_obj["intent"] = serde_json::Value::String(intent_val);
         }
 
-        // Serialize to JSON payload
-        let json_bytes = serde_json::to_vec(&input_obj)?;
-
-        // Metadata for JSON encoding
-        let mut metadata = HashMap::new();
-        metadata.insert("encoding".to_string(), "json/plain".as_bytes().to_vec());
-
-        let payload = Payload {
-            metadata,
-            data: json_bytes,
-            // Any additional fields generated from the Temporal proto definition
-            // (for example, `external_payloads` in newer API versions) are left at
-            // their default values via `..Default::default()`. This matches the
-            // expected encoding for a single JSON payload; see the Temporal
-            // Payloads documentation for details.
-            ..Default::default()
-        };
-
-        let payloads = Payloads {
-            payloads: vec![payload],
-        };
+        let payloads = json_payloads(&input_obj)?;
 
         let request_id = Uuid::new_v4().to_string();
 
@@ -205,6 +342,7 @@ impl TemporalClient {
         use crate::infrastructure::temporal_proto::temporal::api::common::v1::WorkflowExecution;
         use crate::infrastructure::temporal_proto::temporal::api::workflowservice::v1::GetWorkflowExecutionHistoryRequest;
 
+        self.inject_fault("get_workflow_history").await?;
         let request = GetWorkflowExecutionHistoryRequest {
             namespace: self.namespace.clone(),
             execution: Some(WorkflowExecution {
@@ -236,35 +374,30 @@ impl TemporalClient {
     /// Workflows using `StateKind::Human` call `defineSignal('humanInput')` and
     /// `await condition(...)` — they resume only when this signal arrives.
     /// The `response` string is JSON-encoded and forwarded as the signal payload.
+    pub async fn send_human_signal(&self, execution_id: &str, response: String) -> Result<()> {
+        self.signal_workflow(execution_id, &WorkflowSignal::HumanInput(response))
+            .await
+    }
+
+    /// Deliver `signal` to a running workflow execution.
     ///
     /// # gRPC Endpoint
     ///
     /// `WorkflowService.SignalWorkflowExecution` — the signal is sent directly to
     /// Temporal Server without an extra HTTP hop through the TypeScript worker.
-    pub async fn send_human_signal(&self, execution_id: &str, response: String) -> Result<()> {
+    pub async fn signal_workflow(&self, execution_id: &str, signal: &WorkflowSignal) -> Result<()> {
         use crate::infrastructure::temporal_proto::temporal::api::common::v1::WorkflowExecution;
         use crate::infrastructure::temporal_proto::temporal::api::workflowservice::v1::SignalWorkflowExecutionRequest;
 
-        let json_bytes = serde_json::to_vec(&response)?;
-        let mut metadata = HashMap::new();
-        metadata.insert("encoding".to_string(), "json/plain".as_bytes().to_vec());
-
-        let payload = Payload {
-            metadata,
-            data: json_bytes,
-            ..Default::default()
-        };
-
+        self.inject_fault("signal_workflow").await?;
         let request = SignalWorkflowExecutionRequest {
             namespace: self.namespace.clone(),
             workflow_execution: Some(WorkflowExecution {
                 workflow_id: execution_id.to_string(),
                 run_id: String::new(),
             }),
-            signal_name: "humanInput".to_string(),
-            input: Some(Payloads {
-                payloads: vec![payload],
-            }),
+            signal_name: signal.name().to_string(),
+            input: Some(json_payloads(&signal.payload())?),
             identity: "aegis-orchestrator".to_string(),
             request_id: Uuid::new_v4().to_string(),
             ..Default::default()
@@ -274,11 +407,76 @@ impl TemporalClient {
         client
             .signal_workflow_execution(request)
             .await
-            .context("Failed to send humanInput signal to workflow execution")?;
+            .context(format!(
+                "Failed to send {} signal to workflow execution",
+                signal.name()
+            ))?;
 
         Ok(())
     }
 
+    /// Run a query handler against a running (or recently closed) workflow.
+    ///
+    /// Returns the handler's JSON answer, or `Value::Null` if it answered with
+    /// no payload. Fails if Temporal rejects the query (e.g. the worker is not
+    /// polling the task queue).
+    ///
+    /// # gRPC Endpoint
+    ///
+    /// `WorkflowService.QueryWorkflow`
+    pub async fn query_workflow(
+        &self,
+        execution_id: &str,
+        query_type: &str,
+        args: Option<&serde_json::Value>,
+    ) -> Result<serde_json::Value> {
+        use crate::infrastructure::temporal_proto::temporal::api::common::v1::WorkflowExecution;
+        use crate::infrastructure::temporal_proto::temporal::api::query::v1::WorkflowQuery;
+        use crate::infrastructure::temporal_proto::temporal::api::workflowservice::v1::QueryWorkflowRequest;
+
+        validate_handler_name(query_type)?;
+        self.inject_fault("query_workflow").await?;
+
+        let request = QueryWorkflowRequest {
+            namespace: self.namespace.clone(),
+            execution: Some(WorkflowExecution {
+                workflow_id: execution_id.to_string(),
+                run_id: String::new(),
+            }),
+            query: Some(WorkflowQuery {
+                query_type: query_type.to_string(),
+                query_args: args.map(json_payloads).transpose()?,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let mut client = self.client.clone();
+        let response = client
+            .query_workflow(request)
+            .await
+            .context(format!(
+                "Failed to run {query_type} query against workflow execution"
+            ))?
+            .into_inner();
+
+        if let Some(rejected) = response.query_rejected {
+            anyhow::bail!(
+                "Query {query_type} rejected by Temporal (workflow status {})",
+                rejected.status
+            );
+        }
+
+        match response
+            .query_result
+            .and_then(|payloads| payloads.payloads.into_iter().next())
+        {
+            Some(payload) => serde_json::from_slice(&payload.data)
+                .context("Failed to decode workflow query result as JSON"),
+            None => Ok(serde_json::Value::Null),
+        }
+    }
+
     /// Register a workflow definition with the Temporal worker
     ///
     /// This calls the TypeScript worker HTTP API to register a new workflow definition.
@@ -293,6 +491,7 @@ impl TemporalClient {
         &self,
         definition: &crate::application::temporal_mapper::TemporalWorkflowDefinition,
     ) -> Result<()> {
+        self.inject_fault("register_workflow").await?;
         let url = format!("{}/register-workflow", self.worker_http_endpoint);
 
         let response = self
@@ -333,3 +532,41 @@ impl WorkflowEnginePort for TemporalClient {
         TemporalClient::start_workflow(self, params).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn well_known_signal_names_map_to_typed_variants() {
+        let human = WorkflowSignal::from_parts(HUMAN_INPUT_SIGNAL, json!("approved")).unwrap();
+        assert_eq!(human, WorkflowSignal::HumanInput("approved".to_string()));
+        assert_eq!(human.payload(), json!("approved"));
+
+        let update =
+            WorkflowSignal::from_parts(BLACKBOARD_UPDATE_SIGNAL, json!({"dataset": "s3://x"}))
+                .unwrap();
+        assert_eq!(update.name(), BLACKBOARD_UPDATE_SIGNAL);
+        assert_eq!(update.payload(), json!({"dataset": "s3://x"}));
+
+        let custom = WorkflowSignal::from_parts("dataArrived", json!({"rows": 10})).unwrap();
+        assert_eq!(custom.name(), "dataArrived");
+    }
+
+    #[test]
+    fn blackboard_update_requires_non_empty_object() {
+        assert!(WorkflowSignal::from_parts(BLACKBOARD_UPDATE_SIGNAL, json!([1, 2])).is_err());
+        assert!(WorkflowSignal::from_parts(BLACKBOARD_UPDATE_SIGNAL, json!({})).is_err());
+    }
+
+    #[test]
+    fn handler_names_are_validated() {
+        assert!(validate_handler_name("workflowState").is_ok());
+        assert!(validate_handler_name("data.arrived-v2").is_ok());
+        assert!(validate_handler_name("").is_err());
+        assert!(validate_handler_name("9lives").is_err());
+        assert!(validate_handler_name("has space").is_err());
+        assert!(validate_handler_name(&"a".repeat(MAX_HANDLER_NAME_LEN + 1)).is_err());
+    }
+}
@@ -44,6 +44,8 @@
 //!   "execution_id": "uuid",
 //!   "workflow_id": "uuid (optional)",
 //!   "state_name": "string (optional)",
+//!   "transition_condition": "string (optional, WorkflowStateEntered)",
+//!   "blackboard": "object (optional, WorkflowStateEntered)",
 //!   "output": "string (optional)",
 //!   "error": "string (optional)",
 //!   "timestamp": "RFC3339"
@@ -61,10 +63,13 @@ use crate::application::complete_workflow_execution::{
 };
 use crate::domain::events::{ContainerRunEvent, ContainerRunFailureReason, WorkflowEvent};
 use crate::domain::execution::ExecutionId;
-use crate::domain::repository::WorkflowExecutionRepository;
+use crate::domain::repository::{WorkflowExecutionRepository, WorkflowRepository};
 use crate::domain::shared_kernel::VolumeId;
 use crate::domain::tenant::TenantId;
-use crate::domain::workflow::{ExecutionLanguage, WorkflowId};
+use crate::domain::workflow::{
+    summarize_state_output, ExecutionLanguage, StateName, WorkflowHandoff, WorkflowId,
+    WorkflowTransitionRecord,
+};
 use crate::infrastructure::event_bus::EventBus;
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Utc};
@@ -225,6 +230,16 @@ pub struct TemporalEventPayload {
     #[serde(default)]
     pub failed_at_state: Option<String>,
 
+    /// Transition condition that led into this state (WorkflowStateEntered), e.g.
+    /// `on_success` or `score_above`. Recorded in the compact transition history.
+    #[serde(default)]
+    pub transition_condition: Option<String>,
+
+    /// Blackboard as it stood when the state was entered (WorkflowStateEntered).
+    /// Stored with the transition for step-through debugging.
+    #[serde(default)]
+    pub blackboard: Option<serde_json::Value>,
+
     /// Event timestamp
     pub timestamp: String,
 }
@@ -566,6 +581,7 @@ impl TemporalEventMapper {
 pub struct TemporalEventListener {
     event_bus: Arc<EventBus>,
     execution_repository: Arc<dyn WorkflowExecutionRepository>,
+    workflow_repository: Option<Arc<dyn WorkflowRepository>>,
 }
 
 impl TemporalEventListener {
@@ -576,9 +592,20 @@ impl TemporalEventListener {
         Self {
             event_bus,
             execution_repository,
+            workflow_repository: None,
         }
     }
 
+    /// Resolve workflow definitions so `WorkflowStateExited` outputs can be
+    /// captured as typed handoffs. Without it no handoffs are recorded.
+    pub fn with_workflow_repository(
+        mut self,
+        workflow_repository: Arc<dyn WorkflowRepository>,
+    ) -> Self {
+        self.workflow_repository = Some(workflow_repository);
+        self
+    }
+
     /// Persist an execution-scoped event to the repository and publish it to the event bus.
     ///
     /// This helper encapsulates the two-step pattern used for all execution events:
@@ -642,6 +669,131 @@ impl TemporalEventListener {
         Ok(())
     }
 
+    async fn record_transition(
+        &self,
+        domain_event: &WorkflowEvent,
+        payload: &TemporalEventPayload,
+    ) -> Result<()> {
+        match domain_event {
+            WorkflowEvent::WorkflowStateEntered {
+                execution_id,
+                state_name,
+                entered_at,
+            } => {
+                self.execution_repository
+                    .append_transition(
+                        *execution_id,
+                        &WorkflowTransitionRecord {
+                            sequence: payload.temporal_sequence_number,
+                            state_name: state_name.clone(),
+                            triggered_by: payload.transition_condition.clone(),
+                            entered_at: *entered_at,
+                            exited_at: None,
+                            output_summary: None,
+                        },
+                    )
+                    .await
+                    .context("Failed to record workflow state transition")?;
+                if let Some(blackboard) = &payload.blackboard {
+                    self.execution_repository
+                        .record_transition_blackboard(
+                            *execution_id,
+                            payload.temporal_sequence_number,
+                            blackboard,
+                        )
+                        .await
+                        .context("Failed to record transition blackboard")?;
+                }
+            }
+            WorkflowEvent::WorkflowStateExited {
+                execution_id,
+                state_name,
+                output,
+                exited_at,
+            } => {
+                self.execution_repository
+                    .close_transition(
+                        *execution_id,
+                        state_name,
+                        *exited_at,
+                        summarize_state_output(output),
+                    )
+                    .await
+                    .context("Failed to record workflow state exit")?;
+                self.record_handoffs(*execution_id, state_name, output, *exited_at)
+                    .await?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Capture the typed handoffs an exited state declares. Output that does
+    /// not match its declarations is logged and not handed off; the worker
+    /// has already moved on, so it must not fail the event.
+    async fn record_handoffs(
+        &self,
+        execution_id: ExecutionId,
+        state_name: &str,
+        output: &serde_json::Value,
+        exited_at: DateTime<Utc>,
+    ) -> Result<()> {
+        let Some(workflow_repository) = &self.workflow_repository else {
+            return Ok(());
+        };
+        let Some(tenant_id) = self
+            .execution_repository
+            .find_tenant_id_by_execution(execution_id)
+            .await
+            .context("Failed to resolve workflow execution tenant")?
+        else {
+            return Ok(());
+        };
+        let Some(execution) = self
+            .execution_repository
+            .find_by_id_for_tenant(&tenant_id, execution_id)
+            .await
+            .context("Failed to load workflow execution")?
+        else {
+            return Ok(());
+        };
+        let Some(workflow) = workflow_repository
+            .find_by_id_visible(&tenant_id, execution.workflow_id)
+            .await
+            .context("Failed to load workflow definition")?
+        else {
+            return Ok(());
+        };
+
+        let state_name = StateName::new(state_name)?;
+        let handoffs = match workflow.spec.extract_handoffs(&state_name, output) {
+            Ok(handoffs) if handoffs.is_empty() => return Ok(()),
+            Ok(handoffs) => handoffs,
+            Err(e) => {
+                tracing::warn!(
+                    execution_id = %execution_id.0,
+                    state = %state_name,
+                    "Discarding handoffs: {e}"
+                );
+                return Ok(());
+            }
+        };
+        let handoffs: Vec<WorkflowHandoff> = handoffs
+            .into_iter()
+            .map(|(output_name, value)| WorkflowHandoff {
+                state_name: state_name.to_string(),
+                output_name,
+                value,
+                produced_at: exited_at,
+            })
+            .collect();
+        self.execution_repository
+            .record_handoffs(execution_id, &handoffs)
+            .await
+            .context("Failed to record workflow handoffs")?;
+        Ok(())
+    }
+
     async fn reconcile_terminal_workflow_event(
         &self,
         execution_id: ExecutionId,
@@ -855,6 +1007,7 @@ impl TemporalEventListener {
             WorkflowEvent::WorkflowExecutionStarted { execution_id, .. }
             | WorkflowEvent::WorkflowStateEntered { execution_id, .. }
             | WorkflowEvent::WorkflowStateExited { execution_id, .. }
+            | WorkflowEvent::WorkflowStateStuck { execution_id, .. }
             | WorkflowEvent::WorkflowIterationStarted { execution_id, .. }
             | WorkflowEvent::WorkflowIterationCompleted { execution_id, .. }
             | WorkflowEvent::WorkflowIterationFailed { execution_id, .. }
@@ -906,7 +1059,11 @@ impl TemporalEventListener {
         )
         .await?;
 
-        // Step 3: Reconcile terminal state before publishing, otherwise publish directly.
+        // Step 3: Maintain the compact transition history, which outlives
+        // Temporal's own history retention.
+        self.record_transition(&domain_event, &payload).await?;
+
+        // Step 4: Reconcile terminal state before publishing, otherwise publish directly.
         match &domain_event {
             WorkflowEvent::WorkflowExecutionCompleted { .. }
             | WorkflowEvent::WorkflowExecutionFailed { .. }
@@ -917,7 +1074,7 @@ impl TemporalEventListener {
             _ => self.event_bus.publish_workflow_event(domain_event.clone()),
         }
 
-        // Step 4: Return execution ID for response
+        // Step 5: Return execution ID for response
         Ok(execution_id_obj.0.to_string())
     }
 }
@@ -1197,6 +1354,12 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn find_active_all(
+            &self,
+        ) -> Result<Vec<crate::domain::workflow::WorkflowExecution>, RepositoryError> {
+            Ok(vec![])
+        }
+
         async fn find_by_workflow_for_tenant(
             &self,
             _tenant_id: &crate::domain::tenant::TenantId,
@@ -1238,6 +1401,33 @@ mod tests {
             Ok(vec![])
         }
 
+        async fn append_transition(
+            &self,
+            _execution_id: ExecutionId,
+            _record: &crate::domain::workflow::WorkflowTransitionRecord,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn close_transition(
+            &self,
+            _execution_id: ExecutionId,
+            _state_name: &str,
+            _exited_at: chrono::DateTime<chrono::Utc>,
+            _output_summary: Option<String>,
+        ) -> Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_transitions_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _id: ExecutionId,
+        ) -> Result<Vec<crate::domain::workflow::WorkflowTransitionRecord>, RepositoryError>
+        {
+            Ok(vec![])
+        }
+
         async fn count_by_workflow_for_tenant(
             &self,
             _tenant_id: &crate::domain::tenant::TenantId,
@@ -1436,9 +1626,12 @@ mod tests {
             WorkflowSpec {
                 initial_state: StateName::new("START").unwrap(),
                 context: HashMap::new(),
+                variables: Default::default(),
+                handoffs: Default::default(),
                 states,
                 storage: Default::default(),
                 max_total_transitions: None,
+                concurrency: None,
             },
         )
         .unwrap()
@@ -1516,4 +1709,78 @@ mod tests {
             other => panic!("expected workflow completion event, got {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn test_state_events_record_transition_history() {
+        let tenant_id = TenantId::from_string("tenant-blue").unwrap();
+        let workflow = build_test_workflow("listener-history");
+        let execution_id = ExecutionId::new();
+        let execution = WorkflowExecution::new(&workflow, execution_id, json!({}));
+        let repo = Arc::new(InMemoryWorkflowExecutionRepository::new());
+        repo.save_for_tenant(&tenant_id, &execution).await.unwrap();
+        let listener = TemporalEventListener::new(Arc::new(EventBus::new(16)), repo.clone());
+
+        let state_event = |event_type: &str, sequence: i64, state: &str| TemporalEventPayload {
+            event_type: event_type.to_string(),
+            execution_id: execution_id.to_string(),
+            temporal_sequence_number: sequence,
+            state_name: Some(state.to_string()),
+            timestamp: "2026-02-19T12:00:00Z".to_string(),
+            ..Default::default()
+        };
+
+        listener
+            .handle_event(state_event("WorkflowStateEntered", 1, "START"))
+            .await
+            .unwrap();
+        listener
+            .handle_event(TemporalEventPayload {
+                output: Some(json!({"score": 0.92})),
+                ..state_event("WorkflowStateExited", 2, "START")
+            })
+            .await
+            .unwrap();
+        let entered_review = TemporalEventPayload {
+            transition_condition: Some("on_success".to_string()),
+            blackboard: Some(json!({"score": 0.92})),
+            ..state_event("WorkflowStateEntered", 3, "REVIEW")
+        };
+        listener.handle_event(entered_review.clone()).await.unwrap();
+        // Re-delivered callbacks must not duplicate history entries.
+        listener.handle_event(entered_review).await.unwrap();
+
+        let history = repo
+            .find_transitions_for_tenant(&tenant_id, execution_id)
+            .await
+            .unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].state_name, "START");
+        assert!(history[0].exited_at.is_some());
+        assert_eq!(
+            history[0].output_summary.as_deref(),
+            Some(r#"{"score":0.92}"#)
+        );
+        assert_eq!(history[1].state_name, "REVIEW");
+        assert_eq!(history[1].triggered_by.as_deref(), Some("on_success"));
+        assert!(history[1].exited_at.is_none());
+        assert_eq!(
+            repo.find_transition_blackboard_for_tenant(&tenant_id, execution_id, 3)
+                .await
+                .unwrap(),
+            Some(json!({"score": 0.92}))
+        );
+        assert_eq!(
+            repo.find_transition_blackboard_for_tenant(&tenant_id, execution_id, 1)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let other_tenant = TenantId::from_string("tenant-red").unwrap();
+        assert!(repo
+            .find_transitions_for_tenant(&other_tenant, execution_id)
+            .await
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -162,12 +162,17 @@ pub struct ToolRouter {
 /// out via `aegis.edge.fleet.invoke`. Currently only that tool itself; the
 /// list / cancel siblings are operator-tier coordination tools that do not
 /// fan out further.
+///
+/// `no_cache`: tools whose results must never be served from the tool result
+/// cache (`spec.tool_cache`) — anything that mutates state, and the `fs.*`
+/// tools, whose results depend on the calling execution's workspace.
 struct BuiltinToolDefinition {
     name: &'static str,
     description: &'static str,
     skip_judge: bool,
     edge_executor: bool,
     fleet_capable: bool,
+    no_cache: bool,
 }
 
 impl BuiltinToolDefinition {
@@ -178,6 +183,7 @@ impl BuiltinToolDefinition {
             skip_judge: false,
             edge_executor: false,
             fleet_capable: false,
+            no_cache: false,
         }
     }
 
@@ -196,6 +202,11 @@ impl BuiltinToolDefinition {
         self
     }
 
+    const fn no_cache(mut self) -> Self {
+        self.no_cache = true;
+        self
+    }
+
     /// Look up a definition by name, if present in the registry.
     fn lookup(name: &str) -> Option<&'static BuiltinToolDefinition> {
         BUILTIN_TOOL_DEFINITIONS.iter().find(|d| d.name == name)
@@ -206,56 +217,56 @@ impl BuiltinToolDefinition {
 /// truth — the daemon startup and every other consumer derives its data
 /// from this slice.
 const BUILTIN_TOOL_DEFINITIONS: &[BuiltinToolDefinition] = &[
-    BuiltinToolDefinition::new("cmd.run", "Executes a shell command inside the agent's ephemeral container environment. Use this to build, run, or analyze code locally."),
-    BuiltinToolDefinition::new("fs.read", "Read the contents of a file at the given POSIX path from the mounted Workspace volume.").skip_judge(),
-    BuiltinToolDefinition::new("fs.write", "Write content to a file at the given POSIX path in the Workspace volume. Automatically creates missing parent directories."),
-    BuiltinToolDefinition::new("fs.list", "List the contents of a directory in the Workspace volume.").skip_judge(),
-    BuiltinToolDefinition::new("fs.create_dir", "Creates a new directory along with any necessary parent directories."),
-    BuiltinToolDefinition::new("fs.delete", "Deletes a file or directory."),
-    BuiltinToolDefinition::new("fs.edit", "Performs an exact string replacement in a file."),
-    BuiltinToolDefinition::new("fs.multi_edit", "Performs multiple sequential string replacements in a file."),
-    BuiltinToolDefinition::new("fs.grep", "Recursively searches for a regex pattern within files in a given directory.").skip_judge(),
-    BuiltinToolDefinition::new("fs.glob", "Recursively matches files against a glob pattern.").skip_judge(),
+    BuiltinToolDefinition::new("cmd.run", "Executes a shell command inside the agent's ephemeral container environment. Use this to build, run, or analyze code locally.").no_cache(),
+    BuiltinToolDefinition::new("fs.read", "Read the contents of a file at the given POSIX path from the mounted Workspace volume.").skip_judge().no_cache(),
+    BuiltinToolDefinition::new("fs.write", "Write content to a file at the given POSIX path in the Workspace volume. Automatically creates missing parent directories.").no_cache(),
+    BuiltinToolDefinition::new("fs.list", "List the contents of a directory in the Workspace volume.").skip_judge().no_cache(),
+    BuiltinToolDefinition::new("fs.create_dir", "Creates a new directory along with any necessary parent directories.").no_cache(),
+    BuiltinToolDefinition::new("fs.delete", "Deletes a file or directory.").no_cache(),
+    BuiltinToolDefinition::new("fs.edit", "Performs an exact string replacement in a file.").no_cache(),
+    BuiltinToolDefinition::new("fs.multi_edit", "Performs multiple sequential string replacements in a file.").no_cache(),
+    BuiltinToolDefinition::new("fs.grep", "Recursively searches for a regex pattern within files in a given directory.").skip_judge().no_cache(),
+    BuiltinToolDefinition::new("fs.glob", "Recursively matches files against a glob pattern.").skip_judge().no_cache(),
     BuiltinToolDefinition::new("web.search", "Performs an internet search query.").skip_judge(),
     BuiltinToolDefinition::new("web.fetch", "Fetches content from a URL, optionally converting HTML to Markdown.").skip_judge(),
     BuiltinToolDefinition::new("aegis.schema.get", "Returns the canonical JSON Schema for a manifest kind (agent or workflow).").skip_judge(),
     BuiltinToolDefinition::new("aegis.schema.validate", "Validates a manifest YAML string against its canonical JSON Schema.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.agent.create", "Parses, validates, and deploys an Agent manifest to the registry.").skip_judge(),
+    BuiltinToolDefinition::new("aegis.agent.create", "Parses, validates, and deploys an Agent manifest to the registry.").skip_judge().no_cache(),
     BuiltinToolDefinition::new("aegis.agent.list", "Lists currently deployed agents and metadata.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.agent.update", "Updates an existing Agent manifest in the registry.").skip_judge(),
+    BuiltinToolDefinition::new("aegis.agent.update", "Updates an existing Agent manifest in the registry.").skip_judge().no_cache(),
     BuiltinToolDefinition::new("aegis.agent.export", "Exports an Agent manifest by name.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.agent.delete", "Removes a deployed agent from the registry by UUID.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.agent.generate", "Generates an Agent manifest from a natural-language intent."),
+    BuiltinToolDefinition::new("aegis.agent.delete", "Removes a deployed agent from the registry by UUID.").skip_judge().no_cache(),
+    BuiltinToolDefinition::new("aegis.agent.generate", "Generates an Agent manifest from a natural-language intent.").no_cache(),
     BuiltinToolDefinition::new("aegis.agent.logs", "Retrieve agent-level activity log snapshot.").skip_judge(),
     BuiltinToolDefinition::new("aegis.agent.search", "Semantic search over deployed agents by natural-language query.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.workflow.create", "Performs strict deterministic and semantic workflow validation, then registers on pass.").skip_judge(),
+    BuiltinToolDefinition::new("aegis.workflow.create", "Performs strict deterministic and semantic workflow validation, then registers on pass.").skip_judge().no_cache(),
     BuiltinToolDefinition::new("aegis.workflow.list", "Lists currently registered workflows and metadata.").skip_judge(),
     BuiltinToolDefinition::new("aegis.workflow.validate", "Validate a workflow manifest against the schema.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.workflow.update", "Updates an existing Workflow manifest in the registry.").skip_judge(),
+    BuiltinToolDefinition::new("aegis.workflow.update", "Updates an existing Workflow manifest in the registry.").skip_judge().no_cache(),
     BuiltinToolDefinition::new("aegis.workflow.export", "Exports a Workflow manifest by name.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.workflow.delete", "Removes a registered workflow from the registry by workflow name (not UUID)."),
-    BuiltinToolDefinition::new("aegis.workflow.run", "Executes a registered workflow by name with optional input parameters."),
-    BuiltinToolDefinition::new("aegis.workflow.generate", "Generates a Workflow manifest from a natural-language objective."),
+    BuiltinToolDefinition::new("aegis.workflow.delete", "Removes a registered workflow from the registry by workflow name (not UUID).").no_cache(),
+    BuiltinToolDefinition::new("aegis.workflow.run", "Executes a registered workflow by name with optional input parameters.").no_cache(),
+    BuiltinToolDefinition::new("aegis.workflow.generate", "Generates a Workflow manifest from a natural-language objective.").no_cache(),
     BuiltinToolDefinition::new("aegis.workflow.logs", "Returns paginated workflow execution events.").skip_judge(),
     BuiltinToolDefinition::new("aegis.workflow.wait", "Polls a workflow execution until it reaches a terminal state and returns the result.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.workflow.cancel", "Cancel a running workflow execution.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.workflow.signal", "Send human input response to a paused workflow execution.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.workflow.remove", "Remove a workflow execution record.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.workflow.promote", "Promote a workflow from user scope to tenant scope."),
-    BuiltinToolDefinition::new("aegis.workflow.demote", "Demote a workflow from tenant scope to user scope."),
+    BuiltinToolDefinition::new("aegis.workflow.cancel", "Cancel a running workflow execution.").skip_judge().no_cache(),
+    BuiltinToolDefinition::new("aegis.workflow.signal", "Send human input response to a paused workflow execution.").skip_judge().no_cache(),
+    BuiltinToolDefinition::new("aegis.workflow.remove", "Remove a workflow execution record.").skip_judge().no_cache(),
+    BuiltinToolDefinition::new("aegis.workflow.promote", "Promote a workflow from user scope to tenant scope.").no_cache(),
+    BuiltinToolDefinition::new("aegis.workflow.demote", "Demote a workflow from tenant scope to user scope.").no_cache(),
     BuiltinToolDefinition::new("aegis.workflow.executions.list", "Lists workflow executions, optionally filtered.").skip_judge(),
     BuiltinToolDefinition::new("aegis.workflow.executions.get", "Returns details of a specific workflow execution.").skip_judge(),
     BuiltinToolDefinition::new("aegis.workflow.status", "Returns current status of a workflow execution.").skip_judge(),
     BuiltinToolDefinition::new("aegis.workflow.search", "Semantic search over registered workflows.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.task.execute", "Starts a new agent execution (task) by agent UUID or name."),
+    BuiltinToolDefinition::new("aegis.task.execute", "Starts a new agent execution (task) by agent UUID or name.").no_cache(),
     BuiltinToolDefinition::new("aegis.task.status", "Returns the current status and output of an execution by UUID.").skip_judge(),
     BuiltinToolDefinition::new("aegis.task.list", "Lists recent executions, optionally filtered by agent.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.task.cancel", "Cancels an active agent execution by UUID."),
-    BuiltinToolDefinition::new("aegis.task.remove", "Removes a completed or failed execution record by UUID."),
+    BuiltinToolDefinition::new("aegis.task.cancel", "Cancels an active agent execution by UUID.").no_cache(),
+    BuiltinToolDefinition::new("aegis.task.remove", "Removes a completed or failed execution record by UUID.").no_cache(),
     BuiltinToolDefinition::new("aegis.task.logs", "Returns paginated execution events for a task by UUID.").skip_judge(),
     BuiltinToolDefinition::new("aegis.task.wait", "Polls an execution until it reaches a terminal state and returns the result.").skip_judge(),
     BuiltinToolDefinition::new("aegis.agent.wait", "Alias for aegis.task.wait. Blocks until an agent execution completes.").skip_judge(),
-    BuiltinToolDefinition::new("aegis.execute.intent", "Starts the intent-to-execution pipeline: discovers or generates an agent, writes code, executes in a container, and returns the formatted result."),
+    BuiltinToolDefinition::new("aegis.execute.intent", "Starts the intent-to-execution pipeline: discovers or generates an agent, writes code, executes in a container, and returns the formatted result.").no_cache(),
     BuiltinToolDefinition::new("aegis.execute.status", "Returns the current status of an intent-to-execution pipeline run.").skip_judge(),
     BuiltinToolDefinition::new("aegis.execute.wait", "Alias for aegis.workflow.wait. Blocks until pipeline execution completes.").skip_judge(),
     BuiltinToolDefinition::new("aegis.tools.list", "List all MCP tools available to your security context with pagination and optional source/category filtering.").skip_judge(),
@@ -266,8 +277,8 @@ const BUILTIN_TOOL_DEFINITIONS: &[BuiltinToolDefinition] = &[
     BuiltinToolDefinition::new("aegis.execution.file", "Read a file from a completed execution's workspace volume. Use this to retrieve output files after an agent or task execution finishes.").skip_judge(),
     BuiltinToolDefinition::new("aegis.attachment.read", "Read the contents of a file attached to a chat message. Returns the file content (UTF-8 text or base64-encoded bytes for binary), MIME type, size, and SHA-256 digest. Tenant-scoped and read-only.").skip_judge(),
     BuiltinToolDefinition::new("aegis.edge.fleet.list", "ADR-117: resolve an edge fleet target (selector / group / @node / all) and return the matched node ids without dispatching. Operator-tier.").skip_judge().edge_executor(),
-    BuiltinToolDefinition::new("aegis.edge.fleet.invoke", "ADR-117: dispatch a tool to a fleet of edge daemons (selector / group / @node / all). Returns the fleet_command_id; per-node progress streams via /v1/edge/fleet/invoke. Operator-tier, fleet-capable.").skip_judge().edge_executor().fleet_capable(),
-    BuiltinToolDefinition::new("aegis.edge.fleet.cancel", "ADR-117: cancel an in-flight fleet operation by fleet_command_id. Operator-tier.").skip_judge().edge_executor(),
+    BuiltinToolDefinition::new("aegis.edge.fleet.invoke", "ADR-117: dispatch a tool to a fleet of edge daemons (selector / group / @node / all). Returns the fleet_command_id; per-node progress streams via /v1/edge/fleet/invoke. Operator-tier, fleet-capable.").skip_judge().edge_executor().fleet_capable().no_cache(),
+    BuiltinToolDefinition::new("aegis.edge.fleet.cancel", "ADR-117: cancel an in-flight fleet operation by fleet_command_id. Operator-tier.").skip_judge().edge_executor().no_cache(),
 ];
 
 impl ToolRouter {
@@ -284,6 +295,7 @@ impl ToolRouter {
                 capabilities: vec![crate::domain::node_config::CapabilityConfig {
                     name: def.name.to_string(),
                     skip_judge: def.skip_judge,
+                    streaming: false,
                 }],
                 api_key: None,
             })
@@ -303,6 +315,12 @@ impl ToolRouter {
         BuiltinToolDefinition::lookup(tool_name).is_some()
     }
 
+    /// Returns `true` for builtin tools marked `no_cache` in
+    /// `BUILTIN_TOOL_DEFINITIONS`.
+    pub fn is_no_cache_builtin(tool_name: &str) -> bool {
+        BuiltinToolDefinition::lookup(tool_name).is_some_and(|def| def.no_cache)
+    }
+
     fn should_advertise_builtin_tool(tool_name: &str) -> bool {
         if tool_name.starts_with("aegis.workflow.") || tool_name.starts_with("aegis.execute.") {
             return Self::is_supported_builtin_workflow_tool(tool_name);
@@ -1413,7 +1431,7 @@ impl ToolRouter {
                 },
                 "poll_interval_seconds": {
                     "type": "integer",
-                    "description": "Seconds between status polls (default 10).",
+                    "description": "Deprecated and ignored; the wait returns as soon as the execution reaches a terminal state.",
                     "minimum": 1
                 },
                 "timeout_seconds": {
@@ -1437,7 +1455,7 @@ impl ToolRouter {
                 },
                 "poll_interval_seconds": {
                     "type": "integer",
-                    "description": "Seconds between status polls (default 10).",
+                    "description": "Deprecated and ignored; the wait returns as soon as the execution reaches a terminal state.",
                     "minimum": 1
                 },
                 "timeout_seconds": {
@@ -1757,6 +1775,24 @@ impl ToolRouter {
 
         false
     }
+
+    /// Returns `true` if the operator has enabled streamed SEAL responses for
+    /// `tool_name`. Checks builtin dispatchers first, then MCP server entries.
+    pub async fn is_streaming_tool(&self, tool_name: &str) -> bool {
+        if self
+            .builtin_dispatchers
+            .iter()
+            .flat_map(|dispatcher| &dispatcher.capabilities)
+            .any(|cap| cap.name == tool_name && cap.streaming)
+        {
+            return true;
+        }
+
+        let servers = self.servers.read().await;
+        servers
+            .values()
+            .any(|server| server.is_streaming(tool_name))
+    }
 }
 
 // =============================================================================
@@ -2120,6 +2156,7 @@ mod tests {
             args: vec![],
             capabilities: capabilities.into_iter().map(|s| s.to_string()).collect(),
             skip_judge_tools: std::collections::HashSet::new(),
+            streaming_tools: std::collections::HashSet::new(),
             status: ToolServerStatus::Running,
             process_id: None,
             health_check_interval: Duration::from_secs(60),
@@ -2269,6 +2306,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.agent.create".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2279,6 +2317,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.workflow.create".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2289,6 +2328,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.task.logs".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2299,6 +2339,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.workflow.status".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2375,6 +2416,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.workflow.cancel".to_string(),
                     skip_judge: false,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2385,6 +2427,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.workflow.signal".to_string(),
                     skip_judge: false,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2395,6 +2438,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.workflow.remove".to_string(),
                     skip_judge: false,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2405,6 +2449,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.workflow.status".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2500,6 +2545,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.task.execute".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2511,6 +2557,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.agent.generate".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
@@ -2521,6 +2568,7 @@ mod tests {
                 capabilities: vec![CapabilityConfig {
                     name: "aegis.execute.intent".to_string(),
                     skip_judge: true,
+                    streaming: false,
                 }],
                 api_key: None,
             },
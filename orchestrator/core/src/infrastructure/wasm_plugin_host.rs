@@ -0,0 +1,249 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # WASM Plugin Host
+//!
+//! [`PluginHost`] on wasmtime's component model, enabled by the
+//! `wasm-plugins` feature and configured by `spec.plugins`.
+//!
+//! - Components are checked against the `aegis:plugin/plugin` world
+//!   (`wit/plugin.wit`) on install and stored as `<directory>/<hex>.wasm`.
+//! - Compiled components are cached per digest; a component read back from
+//!   disk must still hash to its digest.
+//! - Every call gets a fresh instance with an empty linker (no WASI), the
+//!   configured fuel and a linear-memory cap. Calls run on the blocking pool.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure Layer
+//! - **Purpose:** Sandboxed execution of content-addressed plugins
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use dashmap::DashMap;
+use wasmtime::component::{Component, Linker};
+use wasmtime::{Config, Engine, Store, StoreLimits, StoreLimitsBuilder, Trap};
+
+use crate::domain::node_config::PluginsConfig;
+use crate::domain::plugin::{
+    PluginDigest, PluginError, PluginHost, PluginValidationInput, PluginVerdict,
+};
+
+mod bindings {
+    wasmtime::component::bindgen!({
+        world: "plugin",
+        path: "wit/plugin.wit",
+    });
+}
+
+struct PluginState {
+    limits: StoreLimits,
+}
+
+/// wasmtime-backed [`PluginHost`]. Cheap to share; clone the `Arc` it is
+/// handed out in.
+pub struct WasmPluginHost {
+    inner: Arc<HostInner>,
+}
+
+struct HostInner {
+    engine: Engine,
+    linker: Linker<PluginState>,
+    directory: PathBuf,
+    fuel: u64,
+    max_memory_bytes: usize,
+    components: DashMap<PluginDigest, Component>,
+}
+
+impl WasmPluginHost {
+    /// Create the host and its plugin directory.
+    pub fn new(config: &PluginsConfig) -> Result<Self, PluginError> {
+        let mut engine_config = Config::new();
+        engine_config.wasm_component_model(true).consume_fuel(true);
+        let engine = Engine::new(&engine_config)
+            .map_err(|e| PluginError::Storage(format!("Failed to create WASM engine: {e}")))?;
+        std::fs::create_dir_all(&config.directory).map_err(|e| {
+            PluginError::Storage(format!(
+                "Failed to create plugin directory {}: {e}",
+                config.directory
+            ))
+        })?;
+
+        Ok(Self {
+            inner: Arc::new(HostInner {
+                linker: Linker::new(&engine),
+                engine,
+                directory: PathBuf::from(&config.directory),
+                fuel: config.fuel,
+                max_memory_bytes: usize::try_from(config.max_memory_mb.saturating_mul(1 << 20))
+                    .unwrap_or(usize::MAX),
+                components: DashMap::new(),
+            }),
+        })
+    }
+
+    async fn blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&HostInner) -> Result<T, PluginError> + Send + 'static,
+    ) -> Result<T, PluginError> {
+        let inner = self.inner.clone();
+        tokio::task::spawn_blocking(move || f(&inner))
+            .await
+            .map_err(|e| PluginError::Storage(format!("Plugin task panicked: {e}")))?
+    }
+}
+
+impl HostInner {
+    fn path(&self, plugin: &PluginDigest) -> PathBuf {
+        self.directory.join(format!("{}.wasm", plugin.hex()))
+    }
+
+    fn store(&self) -> Result<Store<PluginState>, PluginError> {
+        let mut store = Store::new(
+            &self.engine,
+            PluginState {
+                limits: StoreLimitsBuilder::new()
+                    .memory_size(self.max_memory_bytes)
+                    .build(),
+            },
+        );
+        store.limiter(|state| &mut state.limits);
+        store
+            .set_fuel(self.fuel)
+            .map_err(|e| PluginError::Storage(format!("Failed to set plugin fuel: {e}")))?;
+        Ok(store)
+    }
+
+    /// Compile `bytes` and check that they instantiate as the plugin world.
+    fn compile(&self, bytes: &[u8]) -> Result<Component, PluginError> {
+        let component = Component::new(&self.engine, bytes)
+            .map_err(|e| PluginError::InvalidComponent(format!("{e:#}")))?;
+        bindings::Plugin::instantiate(&mut self.store()?, &component, &self.linker)
+            .map_err(|e| PluginError::InvalidComponent(format!("{e:#}")))?;
+        Ok(component)
+    }
+
+    fn install(&self, bytes: &[u8]) -> Result<PluginDigest, PluginError> {
+        let digest = PluginDigest::of(bytes);
+        if self.components.contains_key(&digest) {
+            return Ok(digest);
+        }
+        let component = self.compile(bytes)?;
+
+        // Write-then-rename so a concurrent reader never sees a partial file.
+        let path = self.path(&digest);
+        let partial = path.with_extension("wasm.partial");
+        std::fs::write(&partial, bytes)
+            .and_then(|_| std::fs::rename(&partial, &path))
+            .map_err(|e| PluginError::Storage(format!("Failed to store plugin {digest}: {e}")))?;
+        self.components.insert(digest.clone(), component);
+        Ok(digest)
+    }
+
+    /// Compiled component for `plugin`, loaded from the directory on first use.
+    fn component(&self, plugin: &PluginDigest) -> Result<Component, PluginError> {
+        if let Some(component) = self.components.get(plugin) {
+            return Ok(component.clone());
+        }
+        let path = self.path(plugin);
+        let bytes = std::fs::read(&path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => PluginError::NotFound(plugin.clone()),
+            _ => PluginError::Storage(format!("Failed to read {}: {e}", path.display())),
+        })?;
+        if PluginDigest::of(&bytes) != *plugin {
+            return Err(PluginError::Storage(format!(
+                "{} does not match its digest",
+                path.display()
+            )));
+        }
+        let component = self.compile(&bytes)?;
+        self.components.insert(plugin.clone(), component.clone());
+        Ok(component)
+    }
+
+    /// Run `call` on a fresh, limited instance of `plugin`.
+    fn call<T>(
+        &self,
+        plugin: &PluginDigest,
+        call: impl FnOnce(
+            &bindings::Plugin,
+            &mut Store<PluginState>,
+        ) -> wasmtime::Result<Result<T, String>>,
+    ) -> Result<T, PluginError> {
+        let failure = |error: wasmtime::Error| match error.downcast_ref::<Trap>() {
+            Some(Trap::OutOfFuel) => PluginError::FuelExhausted {
+                plugin: plugin.clone(),
+            },
+            _ => PluginError::Failed {
+                plugin: plugin.clone(),
+                reason: format!("{error:#}"),
+            },
+        };
+
+        let component = self.component(plugin)?;
+        let mut store = self.store()?;
+        let instance =
+            bindings::Plugin::instantiate(&mut store, &component, &self.linker).map_err(failure)?;
+        call(&instance, &mut store)
+            .map_err(failure)?
+            .map_err(|reason| PluginError::Failed {
+                plugin: plugin.clone(),
+                reason,
+            })
+    }
+}
+
+#[async_trait]
+impl PluginHost for WasmPluginHost {
+    async fn install(&self, component: &[u8]) -> Result<PluginDigest, PluginError> {
+        let bytes = component.to_vec();
+        self.blocking(move |host| host.install(&bytes)).await
+    }
+
+    async fn validate(
+        &self,
+        plugin: &PluginDigest,
+        input: &PluginValidationInput,
+    ) -> Result<PluginVerdict, PluginError> {
+        let plugin = plugin.clone();
+        let input = serde_json::to_string(input)
+            .map_err(|e| PluginError::Storage(format!("Failed to encode plugin input: {e}")))?;
+        self.blocking(move |host| {
+            let verdict = host.call(&plugin, |instance, store| {
+                instance.call_validate(store, &input)
+            })?;
+            let in_range = |value: f64| (0.0..=1.0).contains(&value);
+            if !in_range(verdict.score) || !in_range(verdict.confidence) {
+                return Err(PluginError::Failed {
+                    plugin,
+                    reason: format!(
+                        "verdict score {} / confidence {} is outside 0.0-1.0",
+                        verdict.score, verdict.confidence
+                    ),
+                });
+            }
+            Ok(PluginVerdict {
+                score: verdict.score,
+                confidence: verdict.confidence,
+                reasoning: verdict.reasoning,
+            })
+        })
+        .await
+    }
+
+    async fn evaluate(
+        &self,
+        plugin: &PluginDigest,
+        context: &serde_json::Value,
+    ) -> Result<bool, PluginError> {
+        let plugin = plugin.clone();
+        let context = context.to_string();
+        self.blocking(move |host| {
+            host.call(&plugin, |instance, store| {
+                instance.call_evaluate(store, &context)
+            })
+        })
+        .await
+    }
+}
@@ -0,0 +1,789 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # External Workflow Importers
+//!
+//! Best-effort translation of graph and crew definitions from Python agent
+//! frameworks into AEGIS manifest drafts, backing `aegis workflow import`:
+//!
+//! | Format | Source | Translation |
+//! |--------|--------|-------------|
+//! | `langgraph` | `graph.get_graph().to_json()` (`nodes` + `edges`) | One `Agent` state per node; conditional edges become `custom` transitions on `output.next` |
+//! | `crewai` | Crew JSON (`agents`, `tasks`, `process`) | One `Agent` state per task, run in order; one agent per crew member |
+//!
+//! Framework code (router functions, Python tools, LLM settings) cannot be
+//! carried over. Everything that was dropped or guessed is reported in
+//! [`ImportedDrafts::warnings`], and every draft is validated with
+//! [`WorkflowParser`] / [`AgentManifestParser`] before it is returned.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure
+//! - **Purpose:** Anti-Corruption Layer — external framework schemas → AEGIS manifests
+
+use std::collections::{BTreeMap, HashMap};
+use std::str::FromStr;
+
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::infrastructure::agent_manifest_parser::AgentManifestParser;
+use crate::infrastructure::workflow_parser::{
+    StateKindYaml, TransitionConditionYaml, TransitionRuleYaml, WorkflowManifest,
+    WorkflowMetadataYaml, WorkflowParser, WorkflowSpecYaml, WorkflowStateYaml,
+};
+
+/// LangGraph's implicit entry and exit nodes.
+const LANGGRAPH_START: &str = "__start__";
+const LANGGRAPH_END: &str = "__end__";
+
+/// Terminal state added when a node can either finish or continue.
+const END_STATE: &str = "END";
+
+/// Longest DNS-label name accepted for workflows and agents.
+const MAX_NAME_LEN: usize = 63;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImportFormat {
+    LangGraph,
+    CrewAi,
+}
+
+impl ImportFormat {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportFormat::LangGraph => "langgraph",
+            ImportFormat::CrewAi => "crewai",
+        }
+    }
+}
+
+impl FromStr for ImportFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "langgraph" => Ok(ImportFormat::LangGraph),
+            "crewai" => Ok(ImportFormat::CrewAi),
+            other => Err(format!(
+                "unknown import format '{other}' (expected langgraph or crewai)"
+            )),
+        }
+    }
+}
+
+/// Manifest drafts produced from one external definition.
+#[derive(Debug, Clone)]
+pub struct ImportedDrafts {
+    pub workflow_name: String,
+    pub workflow_yaml: String,
+    pub agents: Vec<AgentDraft>,
+    /// What could not be translated faithfully; review these before deploying.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AgentDraft {
+    pub name: String,
+    pub yaml: String,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum WorkflowImportError {
+    #[error("Invalid {format} definition: {reason}")]
+    InvalidSource {
+        format: &'static str,
+        reason: String,
+    },
+
+    #[error("Generated draft failed validation: {0}")]
+    InvalidDraft(String),
+}
+
+/// Translate `source` into a workflow named after `name` plus one agent per
+/// node or crew member.
+pub fn import_definition(
+    format: ImportFormat,
+    source: &str,
+    name: &str,
+) -> Result<ImportedDrafts, WorkflowImportError> {
+    let workflow_name = slug(name);
+    if workflow_name.is_empty() {
+        return Err(WorkflowImportError::InvalidDraft(format!(
+            "'{name}' does not yield a valid workflow name"
+        )));
+    }
+    let invalid = |e: serde_json::Error| WorkflowImportError::InvalidSource {
+        format: format.as_str(),
+        reason: e.to_string(),
+    };
+    let mut builder = DraftBuilder::new(format, workflow_name);
+    match format {
+        ImportFormat::LangGraph => {
+            builder.langgraph(serde_json::from_str(source).map_err(invalid)?)?
+        }
+        ImportFormat::CrewAi => builder.crewai(serde_json::from_str(source).map_err(invalid)?)?,
+    }
+    builder.finish()
+}
+
+// ============================================================================
+// External schemas
+// ============================================================================
+
+#[derive(Deserialize)]
+struct LangGraphDefinition {
+    nodes: Vec<LangGraphNode>,
+    edges: Vec<LangGraphEdge>,
+}
+
+#[derive(Deserialize)]
+struct LangGraphNode {
+    id: String,
+    #[serde(default)]
+    data: Value,
+}
+
+#[derive(Deserialize)]
+struct LangGraphEdge {
+    source: String,
+    target: String,
+    #[serde(default)]
+    conditional: bool,
+}
+
+#[derive(Deserialize)]
+struct CrewDefinition {
+    agents: Keyed<CrewAgent>,
+    tasks: Keyed<CrewTask>,
+    #[serde(default)]
+    process: Option<String>,
+}
+
+/// CrewAI accepts both `{name: {...}}` maps (the `agents.yaml` layout) and
+/// plain lists.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Keyed<T> {
+    Map(BTreeMap<String, T>),
+    List(Vec<T>),
+}
+
+impl<T> Keyed<T> {
+    fn into_entries(self, name_of: impl Fn(usize, &T) -> String) -> Vec<(String, T)> {
+        match self {
+            Keyed::Map(map) => map.into_iter().collect(),
+            Keyed::List(list) => list
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| (name_of(i, &item), item))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct CrewAgent {
+    #[serde(default)]
+    name: Option<String>,
+    role: String,
+    #[serde(default)]
+    goal: Option<String>,
+    #[serde(default)]
+    backstory: Option<String>,
+    #[serde(default)]
+    tools: Vec<Value>,
+    #[serde(default)]
+    allow_delegation: bool,
+}
+
+#[derive(Deserialize)]
+struct CrewTask {
+    #[serde(default)]
+    name: Option<String>,
+    description: String,
+    #[serde(default)]
+    expected_output: Option<String>,
+    #[serde(default)]
+    agent: Option<String>,
+    #[serde(default)]
+    context: Vec<String>,
+}
+
+// ============================================================================
+// Translation
+// ============================================================================
+
+struct DraftBuilder {
+    format: ImportFormat,
+    workflow_name: String,
+    initial_state: Option<String>,
+    states: HashMap<String, WorkflowStateYaml>,
+    agents: Vec<(String, Value)>,
+    warnings: Vec<String>,
+}
+
+impl DraftBuilder {
+    fn new(format: ImportFormat, workflow_name: String) -> Self {
+        Self {
+            format,
+            workflow_name,
+            initial_state: None,
+            states: HashMap::new(),
+            agents: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    fn invalid(&self, reason: impl Into<String>) -> WorkflowImportError {
+        WorkflowImportError::InvalidSource {
+            format: self.format.as_str(),
+            reason: reason.into(),
+        }
+    }
+
+    fn agent_name(&self, id: &str) -> String {
+        let name = format!("{}-{}", self.workflow_name, slug(id));
+        name[..name.len().min(MAX_NAME_LEN)]
+            .trim_end_matches('-')
+            .to_string()
+    }
+
+    fn add_state(
+        &mut self,
+        state: String,
+        kind: StateKindYaml,
+        transitions: Vec<TransitionRuleYaml>,
+    ) -> Result<(), WorkflowImportError> {
+        if self.states.contains_key(&state) {
+            return Err(self.invalid(format!("two steps map to the state name '{state}'")));
+        }
+        self.states.insert(
+            state,
+            WorkflowStateYaml {
+                kind,
+                transitions,
+                timeout: None,
+                max_state_visits: None,
+                outputs: HashMap::new(),
+                consumes: Vec::new(),
+            },
+        );
+        Ok(())
+    }
+
+    fn add_agent(&mut self, name: String, description: Option<String>, instruction: String) {
+        let mut metadata = json!({
+            "name": name,
+            "version": "1.0.0",
+            "labels": { "source": format!("{}-import", self.format.as_str()) },
+        });
+        if let Some(description) = description {
+            metadata["description"] = json!(description);
+        }
+        self.agents.push((
+            name,
+            json!({
+                "apiVersion": "100monkeys.ai/v1",
+                "kind": "Agent",
+                "metadata": metadata,
+                "spec": {
+                    "runtime": {
+                        "language": "python",
+                        "version": "3.11",
+                        "isolation": "docker",
+                        "model": "default",
+                    },
+                    "task": { "instruction": instruction },
+                    "execution": { "mode": "one-shot", "max_retries": 1 },
+                },
+            }),
+        ));
+    }
+
+    fn langgraph(&mut self, graph: LangGraphDefinition) -> Result<(), WorkflowImportError> {
+        let is_special = |id: &str| id == LANGGRAPH_START || id == LANGGRAPH_END;
+        let nodes: Vec<&LangGraphNode> =
+            graph.nodes.iter().filter(|n| !is_special(&n.id)).collect();
+        if nodes.is_empty() {
+            return Err(self.invalid("graph has no nodes besides __start__ and __end__"));
+        }
+
+        let entries: Vec<&str> = graph
+            .edges
+            .iter()
+            .filter(|e| e.source == LANGGRAPH_START && !is_special(&e.target))
+            .map(|e| e.target.as_str())
+            .collect();
+        let entry = match entries.as_slice() {
+            [] => {
+                self.warnings.push(format!(
+                    "Graph has no edge from __start__; starting at '{}'",
+                    nodes[0].id
+                ));
+                nodes[0].id.as_str()
+            }
+            [entry] => *entry,
+            [entry, ..] => {
+                self.warnings.push(format!(
+                    "Graph fans out from __start__ to {}; only '{entry}' is used as the initial state",
+                    entries.join(", ")
+                ));
+                *entry
+            }
+        };
+        self.initial_state = Some(state_name(entry));
+
+        let mut needs_end_state = false;
+        for node in nodes {
+            let state = state_name(&node.id);
+            let outgoing: Vec<&LangGraphEdge> =
+                graph.edges.iter().filter(|e| e.source == node.id).collect();
+            let predecessors: Vec<&str> = graph
+                .edges
+                .iter()
+                .filter(|e| e.target == node.id && e.source != LANGGRAPH_START)
+                .map(|e| e.source.as_str())
+                .collect();
+
+            let target_state = |target: &str| {
+                if target == LANGGRAPH_END {
+                    END_STATE.to_string()
+                } else {
+                    state_name(target)
+                }
+            };
+            let mut transitions = Vec::new();
+            let routes: Vec<String> = outgoing
+                .iter()
+                .filter(|e| e.conditional)
+                .map(|e| target_state(&e.target))
+                .collect();
+            if outgoing.iter().any(|e| e.target != LANGGRAPH_END) {
+                for route in &routes {
+                    transitions.push(TransitionRuleYaml {
+                        condition: TransitionConditionYaml::Custom {
+                            expression: format!("state_output.output.next == \"{route}\""),
+                        },
+                        target: route.clone(),
+                        feedback: None,
+                    });
+                }
+                let mut unconditional = outgoing.iter().filter(|e| !e.conditional);
+                if let Some(edge) = unconditional.next() {
+                    transitions.push(TransitionRuleYaml {
+                        condition: TransitionConditionYaml::Always,
+                        target: target_state(&edge.target),
+                        feedback: None,
+                    });
+                }
+                let dropped: Vec<&str> = unconditional.map(|e| e.target.as_str()).collect();
+                if !dropped.is_empty() {
+                    self.warnings.push(format!(
+                        "Node '{}' fans out in parallel; edges to {} were dropped",
+                        node.id,
+                        dropped.join(", ")
+                    ));
+                }
+                needs_end_state |= transitions.iter().any(|t| t.target == END_STATE);
+            }
+
+            let input = match predecessors.as_slice() {
+                _ if node.id == entry => "{{input}}".to_string(),
+                [predecessor] => format!("{{{{{}.output}}}}", state_name(predecessor)),
+                _ => {
+                    self.warnings.push(format!(
+                        "Node '{}' has {} incoming edges; its input defaults to the workflow input",
+                        node.id,
+                        predecessors.len()
+                    ));
+                    "{{input}}".to_string()
+                }
+            };
+
+            let mut instruction = ["description", "prompt", "system_prompt"]
+                .iter()
+                .find_map(|key| node.data.get(key).and_then(Value::as_str))
+                .map(str::to_string)
+                .unwrap_or_else(|| {
+                    self.warnings.push(format!(
+                        "Node '{}' has no description; its agent instruction is a placeholder",
+                        node.id
+                    ));
+                    format!(
+                        "TODO: port the logic of the LangGraph node '{}' into this instruction.",
+                        node.id
+                    )
+                });
+            if !routes.is_empty() {
+                self.warnings.push(format!(
+                    "Node '{}' routes conditionally; its agent must return a `next` field naming one of: {}",
+                    node.id,
+                    routes.join(", ")
+                ));
+                instruction.push_str(&format!(
+                    "\n\nEnd your answer with a JSON object whose `next` field is one of: {}.",
+                    routes.join(", ")
+                ));
+            }
+
+            let agent = self.agent_name(&node.id);
+            self.add_agent(
+                agent.clone(),
+                Some(format!("Imported from LangGraph node '{}'", node.id)),
+                instruction,
+            );
+            self.add_state(
+                state,
+                StateKindYaml::Agent {
+                    agent,
+                    input,
+                    intent: None,
+                    isolation: None,
+                    judges: Vec::new(),
+                    max_iterations: None,
+                    pre_execution_validator: None,
+                    output_handler: None,
+                },
+                transitions,
+            )?;
+        }
+
+        if needs_end_state {
+            self.add_state(
+                END_STATE.to_string(),
+                StateKindYaml::System {
+                    command: "echo 'Workflow complete'".to_string(),
+                    env: HashMap::new(),
+                    workdir: None,
+                },
+                Vec::new(),
+            )?;
+        }
+        Ok(())
+    }
+
+    fn crewai(&mut self, crew: CrewDefinition) -> Result<(), WorkflowImportError> {
+        if let Some(process) = crew.process.as_deref() {
+            if !process.eq_ignore_ascii_case("sequential") {
+                self.warnings.push(format!(
+                    "Crew process '{process}' is not supported; tasks run sequentially"
+                ));
+            }
+        }
+
+        let agents = crew
+            .agents
+            .into_entries(|_, agent| agent.name.clone().unwrap_or_else(|| agent.role.clone()));
+        let tasks = crew.tasks.into_entries(|i, task| {
+            task.name
+                .clone()
+                .unwrap_or_else(|| format!("task_{}", i + 1))
+        });
+        if agents.is_empty() || tasks.is_empty() {
+            return Err(self.invalid("crew needs at least one agent and one task"));
+        }
+
+        let mut agent_names = HashMap::new();
+        for (key, agent) in &agents {
+            let name = self.agent_name(key);
+            agent_names.insert(key.to_ascii_lowercase(), name.clone());
+            agent_names.insert(agent.role.to_ascii_lowercase(), name.clone());
+
+            if !agent.tools.is_empty() {
+                let tools: Vec<String> = agent
+                    .tools
+                    .iter()
+                    .map(|tool| match tool {
+                        Value::String(tool) => tool.clone(),
+                        other => other
+                            .get("name")
+                            .and_then(Value::as_str)
+                            .unwrap_or("unnamed")
+                            .to_string(),
+                    })
+                    .collect();
+                self.warnings.push(format!(
+                    "Agent '{key}' uses CrewAI tools ({}); add the matching spec.tools by hand",
+                    tools.join(", ")
+                ));
+            }
+            if agent.allow_delegation {
+                self.warnings.push(format!(
+                    "Agent '{key}' allows delegation, which has no AEGIS equivalent"
+                ));
+            }
+
+            let mut instruction = format!("You are {}.", agent.role.trim());
+            if let Some(goal) = &agent.goal {
+                instruction.push_str(&format!("\n\nGoal: {}", goal.trim()));
+            }
+            if let Some(backstory) = &agent.backstory {
+                instruction.push_str(&format!("\n\nBackstory: {}", backstory.trim()));
+            }
+            self.add_agent(name, agent.goal.clone(), instruction);
+        }
+
+        let states: Vec<String> = tasks.iter().map(|(key, _)| state_name(key)).collect();
+        let task_states: HashMap<&str, &str> = tasks
+            .iter()
+            .zip(&states)
+            .map(|((key, _), state)| (key.as_str(), state.as_str()))
+            .collect();
+        self.initial_state = Some(states[0].clone());
+
+        for (i, (key, task)) in tasks.iter().enumerate() {
+            let agent = match &task.agent {
+                Some(agent) => agent_names
+                    .get(&agent.to_ascii_lowercase())
+                    .cloned()
+                    .ok_or_else(|| {
+                        self.invalid(format!("task '{key}' names unknown agent '{agent}'"))
+                    })?,
+                None => {
+                    self.warnings.push(format!(
+                        "Task '{key}' has no agent; assigned to '{}'",
+                        agents[0].0
+                    ));
+                    self.agent_name(&agents[0].0)
+                }
+            };
+
+            let mut input = interpolate_inputs(task.description.trim());
+            if let Some(expected) = &task.expected_output {
+                input.push_str(&format!("\n\nExpected output: {}", expected.trim()));
+            }
+            let context: Vec<String> = if !task.context.is_empty() {
+                task.context
+                    .iter()
+                    .filter_map(|name| match task_states.get(name.as_str()) {
+                        Some(state) => Some(format!("{{{{{state}.output}}}}")),
+                        None => {
+                            self.warnings
+                                .push(format!("Task '{key}' uses unknown context task '{name}'"));
+                            None
+                        }
+                    })
+                    .collect()
+            } else if i == 0 {
+                vec!["{{input}}".to_string()]
+            } else {
+                vec![format!("{{{{{}.output}}}}", states[i - 1])]
+            };
+            if !context.is_empty() {
+                input.push_str(&format!("\n\nContext:\n{}", context.join("\n\n")));
+            }
+
+            let transitions = states
+                .get(i + 1)
+                .map(|next| {
+                    vec![TransitionRuleYaml {
+                        condition: TransitionConditionYaml::Always,
+                        target: next.clone(),
+                        feedback: None,
+                    }]
+                })
+                .unwrap_or_default();
+            self.add_state(
+                states[i].clone(),
+                StateKindYaml::Agent {
+                    agent,
+                    input,
+                    intent: None,
+                    isolation: None,
+                    judges: Vec::new(),
+                    max_iterations: None,
+                    pre_execution_validator: None,
+                    output_handler: None,
+                },
+                transitions,
+            )?;
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<ImportedDrafts, WorkflowImportError> {
+        let draft_error = WorkflowImportError::InvalidDraft;
+        let manifest = WorkflowManifest {
+            api_version: "100monkeys.ai/v1".to_string(),
+            kind: "Workflow".to_string(),
+            metadata: WorkflowMetadataYaml {
+                name: self.workflow_name.clone(),
+                version: Some("1.0.0".to_string()),
+                description: Some(format!("Imported from {}", self.format.as_str())),
+                labels: HashMap::from([(
+                    "source".to_string(),
+                    format!("{}-import", self.format.as_str()),
+                )]),
+                annotations: HashMap::new(),
+                input_schema: None,
+                output_schema: None,
+                output_template: None,
+            },
+            spec: WorkflowSpecYaml {
+                initial_state: self.initial_state.unwrap_or_default(),
+                context: HashMap::new(),
+                variables: HashMap::new(),
+                states: self.states,
+                storage: Default::default(),
+                max_total_transitions: None,
+                concurrency: None,
+            },
+        };
+        let workflow_yaml =
+            serde_yaml::to_string(&manifest).map_err(|e| draft_error(e.to_string()))?;
+        WorkflowParser::parse_yaml(&workflow_yaml).map_err(|e| draft_error(e.to_string()))?;
+
+        let agents = self
+            .agents
+            .into_iter()
+            .map(|(name, document)| {
+                let document =
+                    serde_yaml::to_value(&document).map_err(|e| draft_error(e.to_string()))?;
+                AgentManifestParser::from_value(document.clone())
+                    .map_err(|e| draft_error(format!("agent '{name}': {e:#}")))?;
+                let yaml =
+                    serde_yaml::to_string(&document).map_err(|e| draft_error(e.to_string()))?;
+                Ok(AgentDraft { name, yaml })
+            })
+            .collect::<Result<_, WorkflowImportError>>()?;
+
+        Ok(ImportedDrafts {
+            workflow_name: self.workflow_name,
+            workflow_yaml,
+            agents,
+            warnings: self.warnings,
+        })
+    }
+}
+
+/// `research-agent` → `RESEARCH_AGENT`
+fn state_name(id: &str) -> String {
+    id.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// `Senior Researcher` → `senior-researcher`, capped at 63 characters.
+fn slug(name: &str) -> String {
+    let mut slug = String::new();
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.truncate(MAX_NAME_LEN);
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Rewrite CrewAI `{topic}` placeholders as `{{input.topic}}`. Text that
+/// already contains Handlebars is left alone.
+fn interpolate_inputs(text: &str) -> String {
+    if text.contains("{{") {
+        return text.to_string();
+    }
+    let placeholder = Regex::new(r"\{(\w+)\}").expect("placeholder pattern is valid");
+    placeholder.replace_all(text, "{{input.$1}}").into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::workflow::TransitionCondition;
+
+    #[test]
+    fn langgraph_conditional_edges_become_custom_transitions() {
+        let graph = r#"{
+            "nodes": [
+                {"id": "__start__", "type": "schema", "data": "Input"},
+                {"id": "researcher", "type": "runnable", "data": {"description": "Research the topic."}},
+                {"id": "writer", "type": "runnable", "data": {"name": "writer"}},
+                {"id": "__end__", "type": "schema", "data": "Output"}
+            ],
+            "edges": [
+                {"source": "__start__", "target": "researcher"},
+                {"source": "researcher", "target": "writer", "conditional": true},
+                {"source": "researcher", "target": "__end__", "conditional": true},
+                {"source": "writer", "target": "__end__"}
+            ]
+        }"#;
+        let drafts = import_definition(ImportFormat::LangGraph, graph, "Research Graph").unwrap();
+        assert_eq!(drafts.workflow_name, "research-graph");
+
+        let workflow = WorkflowParser::parse_yaml(&drafts.workflow_yaml).unwrap();
+        assert_eq!(workflow.spec.initial_state.as_str(), "RESEARCHER");
+        let researcher = workflow
+            .spec
+            .states
+            .iter()
+            .find(|(name, _)| name.as_str() == "RESEARCHER")
+            .map(|(_, state)| state)
+            .unwrap();
+        let targets: Vec<_> = researcher
+            .transitions
+            .iter()
+            .map(|t| {
+                assert!(matches!(t.condition, TransitionCondition::Custom { .. }));
+                t.target.as_str()
+            })
+            .collect();
+        assert_eq!(targets, vec!["WRITER", END_STATE]);
+
+        let agents: Vec<_> = drafts.agents.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(
+            agents,
+            vec!["research-graph-researcher", "research-graph-writer"]
+        );
+        assert!(drafts
+            .warnings
+            .iter()
+            .any(|w| w.contains("'writer' has no description")));
+        assert!(drafts
+            .warnings
+            .iter()
+            .any(|w| w.contains("'researcher' routes conditionally")));
+    }
+
+    #[test]
+    fn crewai_tasks_run_in_order_with_context_and_placeholders() {
+        let crew = r#"{
+            "agents": {
+                "researcher": {"role": "Senior Researcher", "goal": "Find facts", "tools": ["SerperDevTool"]},
+                "writer": {"role": "Writer", "goal": "Write clearly"}
+            },
+            "tasks": [
+                {"name": "research", "description": "Research {topic}.", "agent": "researcher"},
+                {"name": "outline", "description": "Outline the report.", "agent": "Writer"},
+                {"name": "write", "description": "Write it.", "agent": "writer", "context": ["research", "outline"]}
+            ],
+            "process": "hierarchical"
+        }"#;
+        let drafts = import_definition(ImportFormat::CrewAi, crew, "report-crew").unwrap();
+        let manifest: WorkflowManifest = serde_yaml::from_str(&drafts.workflow_yaml).unwrap();
+
+        assert_eq!(manifest.spec.initial_state, "RESEARCH");
+        let StateKindYaml::Agent { agent, input, .. } = &manifest.spec.states["RESEARCH"].kind
+        else {
+            panic!("expected an agent state");
+        };
+        assert_eq!(agent, "report-crew-researcher");
+        assert!(input.starts_with("Research {{input.topic}}."));
+        let StateKindYaml::Agent { agent, input, .. } = &manifest.spec.states["WRITE"].kind else {
+            panic!("expected an agent state");
+        };
+        assert_eq!(agent, "report-crew-writer");
+        assert!(input.ends_with("{{RESEARCH.output}}\n\n{{OUTLINE.output}}"));
+        assert!(manifest.spec.states["WRITE"].transitions.is_empty());
+
+        assert_eq!(drafts.agents.len(), 2);
+        assert_eq!(drafts.warnings.len(), 2);
+    }
+}
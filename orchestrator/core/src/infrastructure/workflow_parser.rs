@@ -50,6 +50,7 @@
 //!       transitions: []
 //! ```
 
+use crate::domain::plugin::PluginDigest;
 use crate::domain::workflow::*;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -98,6 +99,9 @@ pub struct WorkflowSpecYaml {
     pub initial_state: String,
     #[serde(default)]
     pub context: HashMap<String, serde_json::Value>,
+    /// Typed blackboard variables (`spec.variables`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub variables: HashMap<String, WorkflowVariable>,
     pub states: HashMap<String, WorkflowStateYaml>,
     /// Workflow-level storage configuration (WORKFLOW_MANIFEST_SPEC_V1 §spec.storage)
     #[serde(default)]
@@ -106,6 +110,9 @@ pub struct WorkflowSpecYaml {
     /// Default: 50. Ceiling: 100.
     #[serde(default)]
     pub max_total_transitions: Option<u32>,
+    /// Optional concurrency group (`spec.concurrency`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub concurrency: Option<crate::domain::concurrency::ConcurrencySpec>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -121,6 +128,12 @@ pub struct WorkflowStateYaml {
     /// before the workflow terminates. Default: 5. Ceiling: 20.
     #[serde(default)]
     pub max_state_visits: Option<u32>,
+    /// Typed handoff outputs this state produces.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub outputs: HashMap<String, HandoffOutput>,
+    /// `STATE.output` handoffs this state consumes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consumes: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
@@ -209,6 +222,24 @@ pub enum StateKindYaml {
         steps: Vec<crate::domain::workflow::ContainerRunConfig>,
         completion: crate::domain::workflow::ParallelCompletionStrategy,
     },
+    /// Fan an agent out over every item of a blackboard collection
+    #[serde(rename = "ForEach")]
+    ForEach {
+        /// Expression resolving to a JSON array on the blackboard
+        source: String,
+        /// Variable each item is bound to in `input` (default: "item")
+        #[serde(default)]
+        item_var: Option<String>,
+        agent: String,
+        input: String,
+        #[serde(default)]
+        max_concurrency: Option<u32>,
+        #[serde(default)]
+        collect: crate::domain::workflow::ForEachCollectStrategy,
+        /// Defaults to all_succeed
+        #[serde(default)]
+        completion: Option<crate::domain::workflow::ParallelCompletionStrategy>,
+    },
     /// Invoke a child workflow — blocking or fire-and-forget (ADR-065)
     #[serde(rename = "Subworkflow")]
     Subworkflow {
@@ -329,6 +360,10 @@ pub enum TransitionConditionYaml {
     Custom {
         expression: String,
     },
+    /// `sha256:` digest of an installed plugin
+    Plugin {
+        plugin: String,
+    },
 }
 
 // ============================================================================
@@ -394,10 +429,19 @@ impl WorkflowParser {
 
         // Convert states
         let mut states = HashMap::new();
+        let mut handoffs = HashMap::new();
         for (state_name_str, state_yaml) in manifest.spec.states {
             let state_name = StateName::new(state_name_str)
                 .map_err(|e| WorkflowParseError::ValidationError(e.to_string()))?;
 
+            let state_handoffs = StateHandoffs {
+                outputs: state_yaml.outputs,
+                consumes: state_yaml.consumes,
+            };
+            if !state_handoffs.is_empty() {
+                handoffs.insert(state_name.clone(), state_handoffs);
+            }
+
             let kind = Self::convert_state_kind(state_yaml.kind)?;
 
             let transitions = state_yaml
@@ -424,9 +468,12 @@ impl WorkflowParser {
         let spec = WorkflowSpec {
             initial_state,
             context: manifest.spec.context,
+            variables: manifest.spec.variables,
+            handoffs,
             states,
             storage: manifest.spec.storage,
             max_total_transitions: manifest.spec.max_total_transitions,
+            concurrency: manifest.spec.concurrency,
         };
 
         // Create and validate workflow
@@ -556,6 +603,26 @@ impl WorkflowParser {
             StateKindYaml::ParallelContainerRun { steps, completion } => {
                 StateKind::ParallelContainerRun { steps, completion }
             }
+            StateKindYaml::ForEach {
+                source,
+                item_var,
+                agent,
+                input,
+                max_concurrency,
+                collect,
+                completion,
+            } => StateKind::ForEach {
+                source,
+                item_var: item_var.unwrap_or_else(|| {
+                    crate::domain::workflow::DEFAULT_FOREACH_ITEM_VAR.to_string()
+                }),
+                agent,
+                input,
+                max_concurrency,
+                collect,
+                completion: completion
+                    .unwrap_or(crate::domain::workflow::ParallelCompletionStrategy::AllSucceed),
+            },
             StateKindYaml::Subworkflow {
                 workflow_id,
                 mode,
@@ -636,6 +703,10 @@ impl WorkflowParser {
             TransitionConditionYaml::Custom { expression } => {
                 TransitionCondition::Custom { expression }
             }
+            TransitionConditionYaml::Plugin { plugin } => TransitionCondition::Plugin {
+                plugin: PluginDigest::parse(&plugin)
+                    .map_err(|e| WorkflowParseError::ValidationError(e.to_string()))?,
+            },
         })
     }
 
@@ -660,6 +731,7 @@ impl WorkflowParser {
 
         let mut states = HashMap::new();
         for (state_name, state) in &workflow.spec.states {
+            let handoffs = workflow.spec.handoffs.get(state_name);
             states.insert(
                 state_name.as_str().to_string(),
                 WorkflowStateYaml {
@@ -671,6 +743,8 @@ impl WorkflowParser {
                         .collect(),
                     timeout: state.timeout,
                     max_state_visits: state.max_state_visits,
+                    outputs: handoffs.map(|h| h.outputs.clone()).unwrap_or_default(),
+                    consumes: handoffs.map(|h| h.consumes.clone()).unwrap_or_default(),
                 },
             );
         }
@@ -678,9 +752,11 @@ impl WorkflowParser {
         let spec = WorkflowSpecYaml {
             initial_state: workflow.spec.initial_state.as_str().to_string(),
             context: workflow.spec.context.clone(),
+            variables: workflow.spec.variables.clone(),
             states,
             storage: workflow.spec.storage.clone(),
             max_total_transitions: workflow.spec.max_total_transitions,
+            concurrency: workflow.spec.concurrency.clone(),
         };
 
         WorkflowManifest {
@@ -808,6 +884,23 @@ impl WorkflowParser {
                     completion: *completion,
                 }
             }
+            StateKind::ForEach {
+                source,
+                item_var,
+                agent,
+                input,
+                max_concurrency,
+                collect,
+                completion,
+            } => StateKindYaml::ForEach {
+                source: source.clone(),
+                item_var: Some(item_var.clone()),
+                agent: agent.clone(),
+                input: input.clone(),
+                max_concurrency: *max_concurrency,
+                collect: *collect,
+                completion: Some(*completion),
+            },
             StateKind::Subworkflow {
                 workflow_id,
                 mode,
@@ -879,6 +972,9 @@ impl WorkflowParser {
             TransitionCondition::Custom { expression } => TransitionConditionYaml::Custom {
                 expression: expression.clone(),
             },
+            TransitionCondition::Plugin { plugin } => TransitionConditionYaml::Plugin {
+                plugin: plugin.to_string(),
+            },
             TransitionCondition::ScoreAndConfidenceAbove { threshold } => {
                 TransitionConditionYaml::ScoreAndConfidenceAbove {
                     threshold: *threshold,
@@ -1310,6 +1406,62 @@ spec:
         assert_eq!(reparsed.metadata.name, "parent-workflow");
     }
 
+    #[test]
+    fn test_foreach_round_trip() {
+        let yaml = r#"
+apiVersion: 100monkeys.ai/v1
+kind: Workflow
+metadata:
+  name: review-each-file
+spec:
+  initial_state: REVIEW
+  states:
+    REVIEW:
+      kind: ForEach
+      source: "{{TRIAGE.output.files}}"
+      item_var: file
+      agent: code-reviewer
+      input: "Review {{file}} ({{index}})"
+      max_concurrency: 4
+      collect: flatten
+      transitions: []
+"#;
+        let workflow = WorkflowParser::parse_yaml(yaml).expect("Should parse ForEach YAML");
+        let state = workflow.spec.states.values().next().unwrap();
+        match &state.kind {
+            StateKind::ForEach {
+                source,
+                item_var,
+                agent,
+                max_concurrency,
+                collect,
+                completion,
+                ..
+            } => {
+                assert_eq!(source, "{{TRIAGE.output.files}}");
+                assert_eq!(item_var, "file");
+                assert_eq!(agent, "code-reviewer");
+                assert_eq!(*max_concurrency, Some(4));
+                assert_eq!(
+                    *collect,
+                    crate::domain::workflow::ForEachCollectStrategy::Flatten
+                );
+                assert_eq!(
+                    *completion,
+                    crate::domain::workflow::ParallelCompletionStrategy::AllSucceed
+                );
+            }
+            other => panic!("Expected ForEach, got {other:?}"),
+        }
+
+        let yaml_out = WorkflowParser::to_yaml(&workflow).expect("Should serialize to YAML");
+        let reparsed = WorkflowParser::parse_yaml(&yaml_out).expect("Should re-parse from YAML");
+        assert!(matches!(
+            reparsed.spec.states.values().next().unwrap().kind,
+            StateKind::ForEach { .. }
+        ));
+    }
+
     // ========================================================================
     // Regression tests: output_schema and output_template parsing
     // ========================================================================
@@ -1435,4 +1587,177 @@ spec:
             "output_template must survive round-trip serialization"
         );
     }
+
+    #[test]
+    fn test_variables_round_trip() {
+        let yaml = r#"
+apiVersion: 100monkeys.ai/v1
+kind: Workflow
+metadata:
+  name: typed-blackboard
+spec:
+  initial_state: START
+  variables:
+    threshold:
+      type: number
+      default: 0.8
+    reviewers:
+      type: array
+      description: Reviewer agent names
+  states:
+    START:
+      kind: System
+      command: echo "done"
+      transitions: []
+"#;
+
+        let workflow = WorkflowParser::parse_yaml(yaml).expect("initial parse should succeed");
+        let threshold = &workflow.spec.variables["threshold"];
+        assert_eq!(threshold.var_type, VariableType::Number);
+        assert_eq!(threshold.default, Some(serde_json::json!(0.8)));
+
+        let yaml_out = WorkflowParser::to_yaml(&workflow).unwrap();
+        let reparsed = WorkflowParser::parse_yaml(&yaml_out).unwrap();
+        assert_eq!(workflow.spec.variables, reparsed.spec.variables);
+    }
+
+    #[test]
+    fn test_variable_default_must_match_type() {
+        let yaml = r#"
+apiVersion: 100monkeys.ai/v1
+kind: Workflow
+metadata:
+  name: typed-blackboard
+spec:
+  initial_state: START
+  variables:
+    threshold:
+      type: number
+      default: "high"
+  states:
+    START:
+      kind: System
+      command: echo "done"
+      transitions: []
+"#;
+
+        let err = WorkflowParser::parse_yaml(yaml).unwrap_err().to_string();
+        assert!(err.contains("threshold"), "{err}");
+        assert!(err.contains("expects a value of type number"), "{err}");
+    }
+
+    #[test]
+    fn test_handoffs_validate_wiring_and_round_trip() {
+        let yaml = r#"
+apiVersion: 100monkeys.ai/v1
+kind: Workflow
+metadata:
+  name: typed-handoffs
+spec:
+  initial_state: RESEARCH
+  states:
+    RESEARCH:
+      kind: Agent
+      agent: researcher
+      input: "{{input}}"
+      outputs:
+        findings:
+          type: object
+          schema:
+            required: [sources]
+      transitions:
+        - condition: always
+          target: WRITE
+    WRITE:
+      kind: Agent
+      agent: writer
+      input: "Write up {{handoff.RESEARCH.findings}}"
+      consumes: [RESEARCH.findings]
+      transitions: []
+"#;
+
+        let workflow = WorkflowParser::parse_yaml(yaml).expect("initial parse should succeed");
+        let research = StateName::new("RESEARCH").unwrap();
+        assert_eq!(
+            workflow.spec.handoffs[&StateName::new("WRITE").unwrap()].consumes,
+            vec!["RESEARCH.findings".to_string()]
+        );
+
+        let yaml_out = WorkflowParser::to_yaml(&workflow).unwrap();
+        let reparsed = WorkflowParser::parse_yaml(&yaml_out).unwrap();
+        assert_eq!(workflow.spec.handoffs, reparsed.spec.handoffs);
+
+        let captured = workflow
+            .spec
+            .extract_handoffs(
+                &research,
+                &serde_json::json!(r#"{"findings": {"sources": []}, "notes": "x"}"#),
+            )
+            .unwrap();
+        assert_eq!(captured["findings"], serde_json::json!({"sources": []}));
+        let err = workflow
+            .spec
+            .extract_handoffs(&research, &serde_json::json!({"findings": {}}))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("RESEARCH.findings"), "{err}");
+
+        // RESEARCH never runs after WRITE, so it cannot consume WRITE's output.
+        let backwards = yaml
+            .replace(
+                "      consumes: [RESEARCH.findings]\n",
+                "      outputs:\n        draft: {}\n",
+            )
+            .replace(
+                "      input: \"{{input}}\"\n",
+                "      input: \"{{input}}\"\n      consumes: [WRITE.draft]\n",
+            );
+        let err = WorkflowParser::parse_yaml(&backwards)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("never runs before"), "{err}");
+        assert!(err.contains("WRITE.draft"), "{err}");
+    }
+
+    #[test]
+    fn test_plugin_transition_requires_a_digest() {
+        let digest = PluginDigest::of(b"plugin");
+        let yaml = format!(
+            r#"
+apiVersion: 100monkeys.ai/v1
+kind: Workflow
+metadata:
+  name: plugin-gate
+spec:
+  initial_state: CHECK
+  states:
+    CHECK:
+      kind: Agent
+      agent: checker
+      input: "{{{{input}}}}"
+      transitions:
+        - condition: plugin
+          plugin: "{digest}"
+          target: DONE
+    DONE:
+      kind: Agent
+      agent: checker
+      input: "done"
+      transitions: []
+"#
+        );
+
+        let workflow = WorkflowParser::parse_yaml(&yaml).unwrap();
+        let reparsed =
+            WorkflowParser::parse_yaml(&WorkflowParser::to_yaml(&workflow).unwrap()).unwrap();
+        let rule = &reparsed.spec.states[&StateName::new("CHECK").unwrap()].transitions[0];
+        assert!(
+            matches!(&rule.condition, TransitionCondition::Plugin { plugin } if *plugin == digest)
+        );
+
+        let err = WorkflowParser::parse_yaml(&yaml.replace(digest.as_str(), "my-plugin"))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Invalid plugin digest"), "{err}");
+    }
 }
@@ -0,0 +1,589 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Workflow Template Engine
+//!
+//! Compiles the Handlebars templates of a workflow once per workflow version
+//! and renders them from that compiled registry, instead of re-parsing the
+//! template source on every render.
+//!
+//! # Architecture
+//!
+//! - **Layer:** Infrastructure
+//! - **Purpose:** Compile, cache and render workflow state templates
+//! - **Integration:** Workflow registration → compiled registry per (workflow id, version)
+//!
+//! # Template Names
+//!
+//! Each template is registered under a name derived from its location:
+//!
+//! | Template | Name |
+//! |----------|------|
+//! | Agent / ForEach / Subworkflow input | `{state}.input` |
+//! | ForEach source | `{state}.source` |
+//! | System / ContainerRun env value | `{state}.env.{key}` |
+//! | ParallelAgents agent input | `{state}.agents.{index}.input` |
+//! | ParallelContainerRun step env value | `{state}.steps.{step}.env.{key}` |
+//! | Custom transition expression | `{state}.transitions.{index}` |
+//!
+//! # Helpers
+//!
+//! - `{{json value}}` - Serialize any value as compact JSON
+//! - `{{truncate text 200}}` - First N characters of a value (non-strings as JSON)
+//! - `{{default value "fallback"}}` - `value` unless it is missing, null or `""`
+//! - `{{regex_match text "^ok"}}` - `true` if `text` matches the pattern
+//! - `{{now}}` - Current UTC time in RFC 3339
+//!
+//! The Temporal worker renders the same manifests, so it must register
+//! helpers with identical names and semantics.
+//!
+//! # Declared Variables
+//!
+//! When a workflow declares `spec.variables`, every `blackboard.<key>`
+//! reference must name a declared variable. Registration reports undeclared
+//! references as warnings ([`WorkflowTemplateEngine::undeclared_references`])
+//! and rendering a template that contains one fails.
+//!
+//! # Handoffs
+//!
+//! States reference typed handoffs as `handoff.<STATE>.<output>`. A state
+//! template may only reference handoffs its state lists under `consumes:`
+//! ([`WorkflowTemplateEngine::unconsumed_handoff_references`]); registration
+//! rejects workflows that break this rule.
+
+use crate::domain::workflow::{
+    parse_handoff_ref, StateKind, StateName, TransitionCondition, Workflow, WorkflowId,
+    WorkflowState,
+};
+use anyhow::{Context, Result};
+use handlebars::{handlebars_helper, Handlebars};
+use parking_lot::RwLock;
+use std::collections::{BTreeSet, HashMap};
+use std::sync::{Arc, OnceLock};
+
+handlebars_helper!(JsonHelper: |value: Json| serde_json::to_string(value).unwrap_or_default());
+
+handlebars_helper!(TruncateHelper: |value: Json, len: u64| {
+    let text = match value {
+        serde_json::Value::String(text) => text.clone(),
+        serde_json::Value::Null => String::new(),
+        other => other.to_string(),
+    };
+    text.chars().take(len as usize).collect::<String>()
+});
+
+handlebars_helper!(DefaultHelper: |value: Json, fallback: Json| {
+    if value.is_null() || value.as_str() == Some("") {
+        fallback.clone()
+    } else {
+        value.clone()
+    }
+});
+
+handlebars_helper!(RegexMatchHelper: |value: Json, pattern: str| {
+    // Missing values and invalid patterns never match.
+    value.as_str().is_some_and(|text| {
+        regex::Regex::new(pattern).is_ok_and(|re| re.is_match(text))
+    })
+});
+
+handlebars_helper!(NowHelper: |*_args| chrono::Utc::now().to_rfc3339());
+
+/// Build a registry configured like every other AEGIS Handlebars instance
+/// (lenient about missing variables, no HTML escaping) plus workflow helpers.
+pub fn workflow_handlebars() -> Handlebars<'static> {
+    let mut handlebars = Handlebars::new();
+    handlebars.set_strict_mode(false);
+    handlebars.register_escape_fn(handlebars::no_escape);
+    handlebars.register_helper("json", Box::new(JsonHelper));
+    handlebars.register_helper("truncate", Box::new(TruncateHelper));
+    handlebars.register_helper("default", Box::new(DefaultHelper));
+    handlebars.register_helper("regex_match", Box::new(RegexMatchHelper));
+    handlebars.register_helper("now", Box::new(NowHelper));
+    handlebars
+}
+
+/// Blackboard keys referenced as `blackboard.<key>` inside the `{{ }}`
+/// expressions of `template`.
+pub fn blackboard_references(template: &str) -> BTreeSet<String> {
+    static REFERENCE_RE: OnceLock<regex::Regex> = OnceLock::new();
+    let reference = REFERENCE_RE.get_or_init(|| {
+        regex::Regex::new(r"\bblackboard\.([A-Za-z_][A-Za-z0-9_]*)").expect("static regex compiles")
+    });
+
+    expressions(template)
+        .flat_map(|expression| {
+            reference
+                .captures_iter(expression)
+                .map(|reference| reference[1].to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Handoffs referenced as `handoff.<STATE>.<output>` inside the `{{ }}`
+/// expressions of `template`, as `STATE.output`.
+pub fn handoff_references(template: &str) -> BTreeSet<String> {
+    static REFERENCE_RE: OnceLock<regex::Regex> = OnceLock::new();
+    let reference = REFERENCE_RE.get_or_init(|| {
+        regex::Regex::new(r"\bhandoff\.([A-Za-z0-9_-]+)\.([A-Za-z_][A-Za-z0-9_]*)")
+            .expect("static regex compiles")
+    });
+
+    expressions(template)
+        .flat_map(|expression| {
+            reference
+                .captures_iter(expression)
+                .map(|reference| format!("{}.{}", &reference[1], &reference[2]))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// The bodies of the `{{ }}` expressions in `template`.
+fn expressions(template: &str) -> impl Iterator<Item = &str> {
+    static EXPRESSION_RE: OnceLock<regex::Regex> = OnceLock::new();
+    EXPRESSION_RE
+        .get_or_init(|| regex::Regex::new(r"(?s)\{\{(.*?)\}\}").expect("static regex compiles"))
+        .captures_iter(template)
+        .map(|captures| captures.get(1).map_or("", |m| m.as_str()))
+}
+
+/// Compiled templates for every registered workflow version.
+pub struct WorkflowTemplateEngine {
+    compiled: RwLock<HashMap<(WorkflowId, String), Arc<Handlebars<'static>>>>,
+}
+
+impl WorkflowTemplateEngine {
+    pub fn new() -> Self {
+        Self {
+            compiled: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Compile every template in `workflow` and cache the result for its
+    /// version, replacing any earlier compilation of the same version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming the first template that fails to parse.
+    pub fn register(&self, workflow: &Workflow) -> Result<Arc<Handlebars<'static>>> {
+        let compiled = Arc::new(Self::compile(workflow)?);
+        self.compiled
+            .write()
+            .insert(Self::key(workflow), compiled.clone());
+        Ok(compiled)
+    }
+
+    /// Compile every template in `workflow` without caching it.
+    pub fn compile(workflow: &Workflow) -> Result<Handlebars<'static>> {
+        let mut handlebars = workflow_handlebars();
+        for (name, template) in Self::templates(workflow) {
+            handlebars
+                .register_template_string(&name, template)
+                .with_context(|| format!("Invalid template '{name}': {template}"))?;
+        }
+        Ok(handlebars)
+    }
+
+    /// Render the template registered as `name` for `workflow`, compiling the
+    /// workflow first if this version has not been registered yet.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the template references a blackboard key that the workflow's
+    /// `spec.variables` do not declare.
+    pub fn render(
+        &self,
+        workflow: &Workflow,
+        name: &str,
+        data: &serde_json::Value,
+    ) -> Result<String> {
+        if !workflow.spec.variables.is_empty() {
+            if let Some((_, template)) = Self::templates(workflow)
+                .into_iter()
+                .find(|(template_name, _)| template_name == name)
+            {
+                if let Some(key) = blackboard_references(template)
+                    .into_iter()
+                    .find(|key| !workflow.spec.variables.contains_key(key))
+                {
+                    anyhow::bail!(
+                        "Workflow template '{name}' references undeclared blackboard variable '{key}'"
+                    );
+                }
+            }
+        }
+
+        let cached = self.compiled.read().get(&Self::key(workflow)).cloned();
+        let compiled = match cached {
+            Some(compiled) => compiled,
+            None => self.register(workflow)?,
+        };
+        compiled
+            .render(name, data)
+            .with_context(|| format!("Failed to render workflow template '{name}'"))
+    }
+
+    /// Evaluate the custom expression of transition `index` on `state`.
+    ///
+    /// The expression holds when it renders to `true` (surrounding whitespace
+    /// ignored), e.g. `{{regex_match output "^PASS"}}`.
+    pub fn evaluate_condition(
+        &self,
+        workflow: &Workflow,
+        state: &StateName,
+        index: usize,
+        data: &serde_json::Value,
+    ) -> Result<bool> {
+        let rendered = self.render(workflow, &format!("{state}.transitions.{index}"), data)?;
+        Ok(rendered.trim() == "true")
+    }
+
+    /// `(template name, key)` for every `blackboard.<key>` reference to a key
+    /// missing from `spec.variables`, including references in
+    /// `metadata.output_template`. Empty for workflows that declare no
+    /// variables.
+    pub fn undeclared_references(workflow: &Workflow) -> Vec<(String, String)> {
+        let variables = &workflow.spec.variables;
+        if variables.is_empty() {
+            return Vec::new();
+        }
+
+        let mut templates: Vec<(String, &str)> = Self::templates(workflow);
+        if let Some(output_template) = &workflow.metadata.output_template {
+            let mut strings = Vec::new();
+            collect_strings(output_template, &mut strings);
+            templates.extend(
+                strings
+                    .into_iter()
+                    .map(|template| ("output_template".to_string(), template)),
+            );
+        }
+
+        let mut undeclared: Vec<(String, String)> = templates
+            .into_iter()
+            .flat_map(|(name, template)| {
+                blackboard_references(template)
+                    .into_iter()
+                    .filter(|key| !variables.contains_key(key))
+                    .map(move |key| (name.clone(), key))
+            })
+            .collect();
+        undeclared.sort();
+        undeclared.dedup();
+        undeclared
+    }
+
+    /// `(template name, STATE.output)` for every `handoff.<STATE>.<output>`
+    /// reference the template's state does not list under `consumes:`.
+    /// `metadata.output_template` may reference any declared output.
+    pub fn unconsumed_handoff_references(workflow: &Workflow) -> Vec<(String, String)> {
+        let handoffs = &workflow.spec.handoffs;
+        let mut unconsumed = Vec::new();
+        for (state_name, state) in &workflow.spec.states {
+            let consumes = handoffs
+                .get(state_name)
+                .map(|h| h.consumes.as_slice())
+                .unwrap_or_default();
+            for (name, template) in Self::state_templates(state_name, state) {
+                unconsumed.extend(
+                    handoff_references(template)
+                        .into_iter()
+                        .filter(|reference| !consumes.contains(reference))
+                        .map(|reference| (name.clone(), reference)),
+                );
+            }
+        }
+
+        if let Some(output_template) = &workflow.metadata.output_template {
+            let mut strings = Vec::new();
+            collect_strings(output_template, &mut strings);
+            for template in strings {
+                unconsumed.extend(
+                    handoff_references(template)
+                        .into_iter()
+                        .filter(|reference| {
+                            !parse_handoff_ref(reference).is_some_and(|(state, output)| {
+                                handoffs.iter().any(|(name, h)| {
+                                    name.as_str() == state && h.outputs.contains_key(output)
+                                })
+                            })
+                        })
+                        .map(|reference| ("output_template".to_string(), reference)),
+                );
+            }
+        }
+
+        unconsumed.sort();
+        unconsumed.dedup();
+        unconsumed
+    }
+
+    fn key(workflow: &Workflow) -> (WorkflowId, String) {
+        (
+            workflow.id,
+            workflow.metadata.version.clone().unwrap_or_default(),
+        )
+    }
+
+    /// Every template in `workflow`, keyed by its registered name.
+    fn templates(workflow: &Workflow) -> Vec<(String, &str)> {
+        workflow
+            .spec
+            .states
+            .iter()
+            .flat_map(|(state_name, state)| Self::state_templates(state_name, state))
+            .collect()
+    }
+
+    /// The templates of a single state, keyed by their registered names.
+    fn state_templates<'a>(
+        state_name: &StateName,
+        state: &'a WorkflowState,
+    ) -> Vec<(String, &'a str)> {
+        let mut templates = Vec::new();
+        match &state.kind {
+            StateKind::Agent { input, .. } => {
+                templates.push((format!("{state_name}.input"), input.as_str()));
+            }
+            StateKind::System { env, .. } | StateKind::ContainerRun { env, .. } => {
+                for (key, value) in env {
+                    templates.push((format!("{state_name}.env.{key}"), value.as_str()));
+                }
+            }
+            StateKind::ParallelAgents { agents, .. } => {
+                for (index, agent) in agents.iter().enumerate() {
+                    templates.push((
+                        format!("{state_name}.agents.{index}.input"),
+                        agent.input.as_str(),
+                    ));
+                }
+            }
+            StateKind::ParallelContainerRun { steps, .. } => {
+                for step in steps {
+                    for (key, value) in &step.env {
+                        templates.push((
+                            format!("{state_name}.steps.{}.env.{key}", step.name),
+                            value.as_str(),
+                        ));
+                    }
+                }
+            }
+            StateKind::ForEach { source, input, .. } => {
+                templates.push((format!("{state_name}.source"), source.as_str()));
+                templates.push((format!("{state_name}.input"), input.as_str()));
+            }
+            StateKind::Subworkflow { input, .. } => {
+                if let Some(input) = input {
+                    templates.push((format!("{state_name}.input"), input.as_str()));
+                }
+            }
+            StateKind::Human { .. } => {}
+        }
+        for (index, rule) in state.transitions.iter().enumerate() {
+            if let TransitionCondition::Custom { expression } = &rule.condition {
+                templates.push((
+                    format!("{state_name}.transitions.{index}"),
+                    expression.as_str(),
+                ));
+            }
+        }
+        templates
+    }
+}
+
+fn collect_strings<'a>(value: &'a serde_json::Value, out: &mut Vec<&'a str>) {
+    match value {
+        serde_json::Value::String(text) => out.push(text),
+        serde_json::Value::Array(items) => items.iter().for_each(|item| collect_strings(item, out)),
+        serde_json::Value::Object(map) => map.values().for_each(|item| collect_strings(item, out)),
+        _ => {}
+    }
+}
+
+impl Default for WorkflowTemplateEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::workflow_parser::WorkflowParser;
+    use serde_json::json;
+
+    const MANIFEST: &str = r#"
+apiVersion: 100monkeys.ai/v1
+kind: Workflow
+metadata:
+  name: review
+  version: "1.0.0"
+spec:
+  initial_state: REVIEW
+  states:
+    REVIEW:
+      kind: Agent
+      agent: reviewer
+      input: "Review {{truncate blackboard.diff 5}} for {{default blackboard.owner \"nobody\"}}"
+      transitions:
+        - condition: custom
+          expression: "{{regex_match REVIEW.output \"^PASS\"}}"
+          target: DONE
+    DONE:
+      kind: System
+      command: echo
+      env:
+        PAYLOAD: "{{json blackboard.files}}"
+      transitions: []
+"#;
+
+    fn workflow() -> Workflow {
+        WorkflowParser::parse_yaml(MANIFEST).expect("manifest parses")
+    }
+
+    #[test]
+    fn renders_registered_templates_with_helpers() {
+        let engine = WorkflowTemplateEngine::new();
+        let workflow = workflow();
+        engine.register(&workflow).unwrap();
+
+        let rendered = engine
+            .render(
+                &workflow,
+                "REVIEW.input",
+                &json!({ "blackboard": { "diff": "abcdefghij" } }),
+            )
+            .unwrap();
+        assert_eq!(rendered, "Review abcde for nobody");
+
+        let rendered = engine
+            .render(
+                &workflow,
+                "DONE.env.PAYLOAD",
+                &json!({ "blackboard": { "files": ["a.rs", "b.rs"] } }),
+            )
+            .unwrap();
+        assert_eq!(rendered, r#"["a.rs","b.rs"]"#);
+    }
+
+    #[test]
+    fn render_reuses_the_compiled_version() {
+        let engine = WorkflowTemplateEngine::new();
+        let workflow = workflow();
+        let first = engine.register(&workflow).unwrap();
+
+        engine
+            .render(&workflow, "REVIEW.input", &json!({}))
+            .unwrap();
+        let cached = engine
+            .compiled
+            .read()
+            .get(&WorkflowTemplateEngine::key(&workflow))
+            .cloned()
+            .unwrap();
+        assert!(Arc::ptr_eq(&first, &cached));
+    }
+
+    #[test]
+    fn custom_condition_uses_regex_match() {
+        let engine = WorkflowTemplateEngine::new();
+        let workflow = workflow();
+        let state = StateName::new("REVIEW").unwrap();
+
+        let pass = json!({ "REVIEW": { "output": "PASS: looks good" } });
+        let fail = json!({ "REVIEW": { "output": "FAIL" } });
+        assert!(engine
+            .evaluate_condition(&workflow, &state, 0, &pass)
+            .unwrap());
+        assert!(!engine
+            .evaluate_condition(&workflow, &state, 0, &fail)
+            .unwrap());
+        assert!(!engine
+            .evaluate_condition(&workflow, &state, 0, &json!({}))
+            .unwrap());
+    }
+
+    #[test]
+    fn now_renders_rfc3339_timestamp() {
+        let rendered = workflow_handlebars()
+            .render_template("{{now}}", &json!({}))
+            .unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(&rendered).is_ok());
+    }
+
+    #[test]
+    fn compile_rejects_invalid_template() {
+        let manifest = MANIFEST.replace("{{json blackboard.files}}", "{{#if x}}unclosed");
+        let workflow = WorkflowParser::parse_yaml(&manifest).unwrap();
+        let err = WorkflowTemplateEngine::compile(&workflow).unwrap_err();
+        assert!(
+            err.to_string().contains("DONE.env.PAYLOAD"),
+            "unexpected error: {err}"
+        );
+    }
+
+    const TYPED_MANIFEST: &str = r#"
+apiVersion: 100monkeys.ai/v1
+kind: Workflow
+metadata:
+  name: typed-review
+  version: "1.0.0"
+  output_template:
+    summary: "{{blackboard.summary}}"
+spec:
+  initial_state: REVIEW
+  variables:
+    diff:
+      type: string
+    owner:
+      type: string
+      default: nobody
+  states:
+    REVIEW:
+      kind: Agent
+      agent: reviewer
+      input: "Review {{blackboard.diff}} for {{blackboard.ownr}}"
+      transitions: []
+"#;
+
+    #[test]
+    fn blackboard_references_only_come_from_expressions() {
+        let refs = blackboard_references(
+            "see blackboard.notes: {{truncate blackboard.diff 5}} {{#each blackboard.files}}{{this}}{{/each}}",
+        );
+        assert_eq!(
+            refs.into_iter().collect::<Vec<_>>(),
+            vec!["diff".to_string(), "files".to_string()]
+        );
+    }
+
+    #[test]
+    fn undeclared_references_are_reported_per_template() {
+        let workflow = WorkflowParser::parse_yaml(TYPED_MANIFEST).unwrap();
+        assert_eq!(
+            WorkflowTemplateEngine::undeclared_references(&workflow),
+            vec![
+                ("REVIEW.input".to_string(), "ownr".to_string()),
+                ("output_template".to_string(), "summary".to_string()),
+            ]
+        );
+
+        // Untyped workflows never report undeclared references.
+        assert!(WorkflowTemplateEngine::undeclared_references(&workflow()).is_empty());
+    }
+
+    #[test]
+    fn render_rejects_undeclared_references() {
+        let engine = WorkflowTemplateEngine::new();
+        let workflow = WorkflowParser::parse_yaml(TYPED_MANIFEST).unwrap();
+        let err = engine
+            .render(&workflow, "REVIEW.input", &json!({ "blackboard": {} }))
+            .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("undeclared blackboard variable 'ownr'"),
+            "unexpected error: {err}"
+        );
+    }
+}
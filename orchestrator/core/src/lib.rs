@@ -34,11 +34,28 @@
 //!
 //! See `orchestrator/core/tests/` for integration tests covering the NFS gateway,
 //! validation events, and Temporal workflow mapping.
+//! `tests/supervisor_simulation.rs` drives the supervisor loop through the
+//! `simulation` feature's in-memory harness (`cargo test --features simulation`).
+//!
+//! ## gRPC Types
+//!
+//! The AEGIS `.proto` definitions and their generated tonic/prost types live
+//! in the `aegis-orchestrator-proto` crate and are re-exported as [`proto`]
+//! (`proto::aegis::runtime::v1`, `cluster`, `cortex`, `seal_gateway`,
+//! `storage`). Services that talk to the orchestrator over gRPC should use
+//! these types instead of vendoring the `.proto` files. The crate follows
+//! semver: wire-compatible additions are minor releases, anything that
+//! renames, renumbers or removes a field or RPC is a major one. `build.rs`
+//! refuses to build against an `aegis_cortex.proto` whose `proto-version`
+//! differs from the one this crate implements.
 
 pub mod api;
 pub mod application;
 pub mod domain;
 pub mod infrastructure;
 pub mod presentation;
+#[cfg(feature = "simulation")]
+pub mod simulation;
 
+pub use aegis_orchestrator_proto as proto;
 pub use domain::*;
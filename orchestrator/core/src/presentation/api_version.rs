@@ -0,0 +1,377 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # HTTP API Versioning
+//!
+//! Contract governance for the `/v1` HTTP surface:
+//!
+//! - Clients may pin a contract with the `Aegis-Api-Version` request header
+//!   (`v1` or `1`). Unsupported versions are rejected with `400` before the
+//!   handler runs instead of failing later on a changed payload shape.
+//! - Every response carries `Aegis-Api-Version` with the served contract.
+//! - Endpoints listed in [`DEPRECATED_ENDPOINTS`] additionally carry
+//!   `Deprecation` (RFC 9745), `Sunset` (RFC 8594) and a `Link` to their
+//!   successor, so the Control Plane and SDKs see a change coming before it
+//!   lands.
+//!
+//! `GET /v1/meta/version` reports the same data, and `POST /v1/meta/handshake`
+//! lets an SDK present its version and required capability flags and learn
+//! up front whether this server can serve it (see [`handshake`]).
+
+use axum::{
+    extract::{MatchedPath, Request},
+    http::HeaderValue,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+
+use super::problem::ProblemDetails;
+use crate::domain::error_code::ErrorCode;
+
+/// Contract version served by this build.
+pub const API_VERSION: &str = "v1";
+
+/// Contract versions this build accepts in [`API_VERSION_HEADER`].
+pub const SUPPORTED_API_VERSIONS: &[&str] = &["v1"];
+
+/// Request/response header carrying the API contract version.
+pub const API_VERSION_HEADER: &str = "aegis-api-version";
+
+const DEPRECATION_HEADER: &str = "deprecation";
+const SUNSET_HEADER: &str = "sunset";
+
+/// Oldest SDK release this server accepts in a handshake.
+pub const MIN_CLIENT_VERSION: &str = "0.15.0";
+
+/// Capability flags negotiated in the SDK handshake.
+pub mod features {
+    /// `GET /v1/executions/{id}/events` server-sent event stream.
+    pub const EXECUTION_EVENT_STREAM: &str = "execution_event_stream";
+    /// `GET /v1/agents/{id}/events` server-sent event stream.
+    pub const AGENT_EVENT_STREAM: &str = "agent_event_stream";
+    /// Text generation through the dispatch gateway LLM proxy.
+    pub const LLM_TEXT_GENERATION: &str = "llm_text_generation";
+    /// `Aegis-Api-Version` negotiation and deprecation headers.
+    pub const API_VERSION_NEGOTIATION: &str = "api_version_negotiation";
+    /// Event payloads wrapped in versioned envelopes, described by
+    /// `GET /v1/schemas/events`.
+    pub const VERSIONED_EVENTS: &str = "versioned_events";
+}
+
+/// Capability flags served by this build.
+pub const SERVER_FEATURES: &[&str] = &[
+    features::EXECUTION_EVENT_STREAM,
+    features::AGENT_EVENT_STREAM,
+    features::LLM_TEXT_GENERATION,
+    features::API_VERSION_NEGOTIATION,
+    features::VERSIONED_EVENTS,
+];
+
+/// Capabilities of servers that predate the handshake endpoint.
+pub const LEGACY_SERVER_FEATURES: &[&str] = &[
+    features::EXECUTION_EVENT_STREAM,
+    features::AGENT_EVENT_STREAM,
+    features::LLM_TEXT_GENERATION,
+];
+
+/// Body of `POST /v1/meta/handshake`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandshakeRequest {
+    pub client_version: String,
+    /// Contract the client was built against; defaults to [`API_VERSION`].
+    #[serde(default)]
+    pub api_version: Option<String>,
+    /// Features the client cannot work without.
+    #[serde(default)]
+    pub required_features: Vec<String>,
+}
+
+/// Response of `POST /v1/meta/handshake`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandshakeResponse {
+    pub compatible: bool,
+    pub api_version: String,
+    pub server_version: String,
+    pub min_client_version: String,
+    /// Every feature the server supports.
+    pub features: Vec<String>,
+    /// Required features the server lacks.
+    #[serde(default)]
+    pub missing_features: Vec<String>,
+    /// Why the client is incompatible, when it is.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl HandshakeResponse {
+    pub fn supports(&self, feature: &str) -> bool {
+        self.features.iter().any(|supported| supported == feature)
+    }
+}
+
+/// Decide whether a client can be served. Incompatibility is reported in the
+/// response rather than as an HTTP error so the client can explain it.
+pub fn handshake(request: &HandshakeRequest, server_version: &str) -> HandshakeResponse {
+    let missing_features: Vec<String> = request
+        .required_features
+        .iter()
+        .filter(|feature| !SERVER_FEATURES.contains(&feature.as_str()))
+        .cloned()
+        .collect();
+
+    let reason = match negotiate(request.api_version.as_deref()) {
+        Err(requested) => Some(format!(
+            "API version '{requested}' is not supported (supported: {})",
+            SUPPORTED_API_VERSIONS.join(", ")
+        )),
+        Ok(_) if is_older(&request.client_version, MIN_CLIENT_VERSION) => Some(format!(
+            "client version {} is older than the minimum {MIN_CLIENT_VERSION}",
+            request.client_version
+        )),
+        Ok(_) if !missing_features.is_empty() => Some(format!(
+            "required features not supported: {}",
+            missing_features.join(", ")
+        )),
+        Ok(_) => None,
+    };
+
+    HandshakeResponse {
+        compatible: reason.is_none(),
+        api_version: API_VERSION.to_string(),
+        server_version: server_version.to_string(),
+        min_client_version: MIN_CLIENT_VERSION.to_string(),
+        features: SERVER_FEATURES.iter().map(|f| f.to_string()).collect(),
+        missing_features,
+        reason,
+    }
+}
+
+/// Compare `major.minor.patch`, ignoring pre-release and build suffixes.
+/// Unparseable versions are not considered older.
+fn is_older(version: &str, minimum: &str) -> bool {
+    fn release(version: &str) -> Option<(u64, u64, u64)> {
+        let core = version.trim().trim_start_matches('v');
+        let core = core.split(['-', '+']).next()?;
+        let mut parts = core.split('.').map(|part| part.parse::<u64>().ok());
+        Some((
+            parts.next()??,
+            parts.next()??,
+            parts.next().flatten().unwrap_or(0),
+        ))
+    }
+    matches!((release(version), release(minimum)), (Some(v), Some(m)) if v < m)
+}
+
+/// An endpoint slated for removal or change.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct DeprecatedEndpoint {
+    pub method: &'static str,
+    /// Route template as registered with the router.
+    pub path: &'static str,
+    /// ISO date (`YYYY-MM-DD`) the deprecation was announced.
+    pub deprecated_on: &'static str,
+    /// ISO date (`YYYY-MM-DD`) after which the endpoint may be removed.
+    pub sunset_on: &'static str,
+    /// Replacement endpoint.
+    pub successor: &'static str,
+}
+
+/// Endpoints carrying `Deprecation`/`Sunset` headers.
+pub const DEPRECATED_ENDPOINTS: &[DeprecatedEndpoint] = &[
+    DeprecatedEndpoint {
+        method: "POST",
+        path: "/v1/workflows/{name}/run",
+        deprecated_on: "2026-10-15",
+        sunset_on: "2027-04-15",
+        successor: "/v1/workflows/temporal/execute",
+    },
+    DeprecatedEndpoint {
+        method: "POST",
+        path: "/v1/workflows/temporal/register",
+        deprecated_on: "2026-10-15",
+        sunset_on: "2027-04-15",
+        successor: "/v1/workflows",
+    },
+];
+
+/// Resolve a requested contract version; `None` selects [`API_VERSION`].
+pub fn negotiate(requested: Option<&str>) -> Result<&'static str, String> {
+    let Some(requested) = requested else {
+        return Ok(API_VERSION);
+    };
+    let requested = requested.trim();
+    let normalized = requested
+        .strip_prefix('v')
+        .or_else(|| requested.strip_prefix('V'))
+        .unwrap_or(requested);
+    SUPPORTED_API_VERSIONS
+        .iter()
+        .find(|supported| supported.trim_start_matches('v') == normalized)
+        .copied()
+        .ok_or_else(|| requested.to_string())
+}
+
+/// The deprecation entry for a request, if any.
+pub fn deprecation_for(method: &str, path: &str) -> Option<&'static DeprecatedEndpoint> {
+    DEPRECATED_ENDPOINTS
+        .iter()
+        .find(|endpoint| endpoint.method.eq_ignore_ascii_case(method) && endpoint.path == path)
+}
+
+impl DeprecatedEndpoint {
+    /// `Deprecation` header value: `@<unix seconds>` (RFC 9745).
+    fn deprecation_header(&self) -> Option<String> {
+        let date = NaiveDate::parse_from_str(self.deprecated_on, "%Y-%m-%d").ok()?;
+        Some(format!(
+            "@{}",
+            date.and_time(NaiveTime::MIN).and_utc().timestamp()
+        ))
+    }
+
+    /// `Sunset` header value: an HTTP-date (RFC 8594).
+    fn sunset_header(&self) -> Option<String> {
+        let date = NaiveDate::parse_from_str(self.sunset_on, "%Y-%m-%d").ok()?;
+        Some(
+            date.and_time(NaiveTime::MIN)
+                .and_utc()
+                .format("%a, %d %b %Y %H:%M:%S GMT")
+                .to_string(),
+        )
+    }
+}
+
+/// Axum middleware enforcing version negotiation and stamping version and
+/// deprecation headers. Must be added with `Router::layer` so
+/// [`MatchedPath`] is available.
+pub async fn api_version_middleware(request: Request, next: Next) -> Response {
+    let requested = request
+        .headers()
+        .get(API_VERSION_HEADER)
+        .map(|value| value.to_str().unwrap_or_default().to_string());
+    let version = match negotiate(requested.as_deref()) {
+        Ok(version) => version,
+        Err(requested) => {
+            let mut response = ProblemDetails::new(
+                ErrorCode::UnsupportedApiVersion,
+                format!("API version '{requested}' is not supported"),
+            )
+            .with_extension("requested", requested)
+            .with_extension("supported_versions", SUPPORTED_API_VERSIONS)
+            .into_response();
+            response
+                .headers_mut()
+                .insert(API_VERSION_HEADER, HeaderValue::from_static(API_VERSION));
+            return response;
+        }
+    };
+
+    let deprecation = request
+        .extensions()
+        .get::<MatchedPath>()
+        .and_then(|path| deprecation_for(request.method().as_str(), path.as_str()));
+
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    headers.insert(API_VERSION_HEADER, HeaderValue::from_static(version));
+
+    if let Some(endpoint) = deprecation {
+        if let Some(value) = endpoint
+            .deprecation_header()
+            .and_then(|value| HeaderValue::from_str(&value).ok())
+        {
+            headers.insert(DEPRECATION_HEADER, value);
+        }
+        if let Some(value) = endpoint
+            .sunset_header()
+            .and_then(|value| HeaderValue::from_str(&value).ok())
+        {
+            headers.insert(SUNSET_HEADER, value);
+        }
+        if let Ok(value) = HeaderValue::from_str(&format!(
+            "<{}>; rel=\"successor-version\"",
+            endpoint.successor
+        )) {
+            headers.append(axum::http::header::LINK, value);
+        }
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiates_supported_versions() {
+        assert_eq!(negotiate(None), Ok("v1"));
+        assert_eq!(negotiate(Some("v1")), Ok("v1"));
+        assert_eq!(negotiate(Some(" 1 ")), Ok("v1"));
+        assert_eq!(negotiate(Some("V1")), Ok("v1"));
+        assert_eq!(negotiate(Some("v2")), Err("v2".to_string()));
+        assert_eq!(negotiate(Some("")), Err(String::new()));
+    }
+
+    fn request(client_version: &str, required: &[&str]) -> HandshakeRequest {
+        HandshakeRequest {
+            client_version: client_version.to_string(),
+            api_version: Some("v1".to_string()),
+            required_features: required.iter().map(|f| f.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn handshake_accepts_current_clients() {
+        let response = handshake(
+            &request("0.15.0-pre-alpha", &[features::EXECUTION_EVENT_STREAM]),
+            "0.15.0-pre-alpha",
+        );
+        assert!(response.compatible, "{response:?}");
+        assert!(response.missing_features.is_empty());
+        assert!(response.supports(features::API_VERSION_NEGOTIATION));
+    }
+
+    #[test]
+    fn handshake_reports_incompatibility_reasons() {
+        let response = handshake(&request("0.14.2", &[]), "0.15.0");
+        assert!(!response.compatible);
+        assert!(response.reason.unwrap().contains("older than the minimum"));
+
+        let response = handshake(&request("0.15.0", &["quantum_streaming"]), "0.15.0");
+        assert!(!response.compatible);
+        assert_eq!(response.missing_features, ["quantum_streaming"]);
+
+        let mut unsupported = request("0.15.0", &[]);
+        unsupported.api_version = Some("v2".to_string());
+        assert!(!handshake(&unsupported, "0.15.0").compatible);
+    }
+
+    #[test]
+    fn compares_release_versions() {
+        assert!(is_older("0.14.9", "0.15.0"));
+        assert!(is_older("v0.9", "0.15.0"));
+        assert!(!is_older("0.15.0-pre-alpha", "0.15.0"));
+        assert!(!is_older("1.0.0", "0.15.0"));
+        assert!(!is_older("dev", "0.15.0"));
+    }
+
+    #[test]
+    fn deprecated_endpoints_have_valid_dates() {
+        for endpoint in DEPRECATED_ENDPOINTS {
+            assert!(endpoint.deprecation_header().is_some(), "{endpoint:?}");
+            assert!(endpoint.sunset_header().is_some(), "{endpoint:?}");
+            assert!(endpoint.deprecated_on < endpoint.sunset_on, "{endpoint:?}");
+        }
+    }
+
+    #[test]
+    fn formats_deprecation_headers() {
+        let endpoint = deprecation_for("post", "/v1/workflows/{name}/run").unwrap();
+        assert_eq!(endpoint.deprecation_header().unwrap(), "@1792022400");
+        assert_eq!(
+            endpoint.sunset_header().unwrap(),
+            "Thu, 15 Apr 2027 00:00:00 GMT"
+        );
+        assert!(deprecation_for("GET", "/v1/workflows/{name}/run").is_none());
+    }
+}
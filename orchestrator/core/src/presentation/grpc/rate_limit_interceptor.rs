@@ -24,12 +24,14 @@ use tonic::metadata::MetadataMap;
 use tonic::Status;
 use tracing::warn;
 
+use crate::domain::error_code::{CodedError, ErrorCode};
 use crate::domain::iam::{IdentityKind, UserIdentity};
 use crate::domain::rate_limit::{
     RateLimitDecision, RateLimitEnforcer, RateLimitError, RateLimitPolicyResolver,
     RateLimitResourceType, RateLimitScope,
 };
 use crate::domain::tenant::TenantId;
+use crate::presentation::problem::{grpc_status, GRPC_ERROR_CODE_METADATA};
 
 /// Holds the rate limiting dependencies needed by gRPC handlers.
 ///
@@ -156,6 +158,10 @@ pub async fn check_rate_limit(
         if let Ok(v) = "0".parse() {
             metadata.insert("x-ratelimit-remaining", v);
         }
+        metadata.insert(
+            GRPC_ERROR_CODE_METADATA,
+            tonic::metadata::MetadataValue::from_static(ErrorCode::RateLimited.as_str()),
+        );
         return Err(Status::with_metadata(
             tonic::Code::ResourceExhausted,
             message,
@@ -205,16 +211,17 @@ fn allow_all_decision() -> RateLimitDecision {
 
 /// Map a domain [`RateLimitError`] to a gRPC [`Status`].
 fn rate_limit_error_to_status(err: &RateLimitError) -> Status {
-    match err {
+    let message = match err {
         RateLimitError::PolicyResolutionFailed(_) => {
-            Status::internal(format!("Rate limit configuration error: {err}"))
+            format!("Rate limit configuration error: {err}")
         }
         RateLimitError::EnforcementFailed(_) | RateLimitError::StorageError(_) => {
             // Fail open: if the enforcement backend is unavailable we let the
             // request through rather than blocking legitimate traffic.
-            Status::internal(format!("Rate limit enforcement unavailable: {err}"))
+            format!("Rate limit enforcement unavailable: {err}")
         }
-    }
+    };
+    grpc_status(err.error_code(), message)
 }
 
 #[cfg(test)]
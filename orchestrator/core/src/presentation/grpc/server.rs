@@ -771,6 +771,9 @@ impl AegisRuntime for AegisRuntimeService {
             tenant_id: crate::domain::tenant::TenantId::system(),
             realm: crate::domain::iam::RealmKind::System,
             task_summary: None,
+            // The AttestAgent proto carries no payload key; payload encryption
+            // is negotiated over HTTP attestation only.
+            payload_public_key: None,
         };
 
         match attestation_service.attest(attestation_req).await {
@@ -1402,7 +1405,9 @@ impl AegisRuntime for AegisRuntimeService {
                         success_score: p.success_score,
                         frequency: p.frequency,
                     })
-                    .collect();
+                    .collect::<Vec<_>>();
+                metrics::counter!("aegis_cortex_patterns_injected_total")
+                    .increment(patterns.len() as u64);
                 Ok(Response::new(QueryCortexPatternsResponse { patterns }))
             }
             Err(e) => Err(Status::unavailable(format!(
@@ -1976,16 +1981,7 @@ fn parse_optional_wf_id(field: &str) -> Result<Option<uuid::Uuid>, Status> {
 
 /// Convert an `FsalError` to a gRPC `Status`.
 fn fsal_error_to_status(e: crate::domain::fsal::FsalError) -> Status {
-    use crate::domain::fsal::FsalError;
-    match &e {
-        FsalError::UnauthorizedAccess { .. } => Status::permission_denied(e.to_string()),
-        FsalError::VolumeNotFound(_) | FsalError::VolumeNotAttached(_) => {
-            Status::not_found(e.to_string())
-        }
-        FsalError::PolicyViolation(_) => Status::permission_denied(e.to_string()),
-        FsalError::PathSanitization(_) => Status::invalid_argument(e.to_string()),
-        _ => Status::internal(e.to_string()),
-    }
+    crate::presentation::problem::coded_error_to_status(&e)
 }
 
 /// Convert a proto `FsalAccessPolicy` to the domain type.
@@ -2692,6 +2688,8 @@ mod tests {
             container_gid: 1000,
             security_context_name: "aegis-system-operator".to_string(),
             initiating_user_sub: None,
+            runtime_image_digest: None,
+            labels: Default::default(),
         };
         let execution_service = Arc::new(TestExecutionService {
             execution_id,
@@ -11,6 +11,7 @@
 //! The following paths are exempt from JWT validation (they use alternative
 //! auth mechanisms):
 //! - `/health` — health check
+//! - `/v1/meta/*` — API version metadata and SDK compatibility handshake
 //! - `/v1/dispatch-gateway/*` — Dispatch Protocol (container ↔ orchestrator)
 //! - `/v1/seal/attest` — SEAL attestation handshake
 //! - `/v1/seal/invoke` — SEAL tool invocation (uses SecurityToken)
@@ -84,6 +85,7 @@ const EXEMPT_PATH_PREFIXES: &[&str] = &[
     "/v1/api-keys/validate",
     "/v1/billing/prices",
     "/v1/dispatch-gateway",
+    "/v1/meta/",
     "/v1/seal/attest",
     "/v1/seal/invoke",
     "/v1/seal/tools",
@@ -182,6 +184,8 @@ mod tests {
         assert!(is_exempt("/health"));
         assert!(is_exempt("/v1/api-keys/validate"));
         assert!(is_exempt("/v1/dispatch-gateway/some-id"));
+        assert!(is_exempt("/v1/meta/version"));
+        assert!(is_exempt("/v1/meta/handshake"));
         assert!(is_exempt("/v1/seal/attest"));
         assert!(is_exempt("/v1/seal/invoke"));
         assert!(is_exempt("/v1/seal/tools"));
@@ -13,9 +13,13 @@
 //! | [`keycloak_auth`] | HTTP | IAM/OIDC JWT auth middleware for HTTP endpoints (ADR-041) |
 //! | [`tenant_middleware`] | HTTP | TenantContext extraction middleware (ADR-056) |
 //! | [`metrics_middleware`] | HTTP | Prometheus metrics tracking (ADR-058) |
+//! | [`api_version`] | HTTP | API version negotiation and deprecation headers |
+//! | [`problem`] | HTTP + gRPC | `AEG-xxxx` error codes as problem+json and gRPC status details |
 
+pub mod api_version;
 pub mod grpc;
 pub mod keycloak_auth;
 pub mod metrics_middleware;
+pub mod problem;
 pub mod tenant_middleware;
 pub mod webhook_guard;
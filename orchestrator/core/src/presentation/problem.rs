@@ -0,0 +1,332 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Problem Details and gRPC Error Mapping
+//!
+//! Renders [`ErrorCode`]s on both transports:
+//!
+//! - **REST:** RFC 9457 `application/problem+json` bodies carrying `type`,
+//!   `title`, `status`, `detail` and the `code` extension member.
+//!   [`problem_details_middleware`] upgrades error responses from handlers
+//!   that still return `{"error": "..."}` or plain text, keeping their
+//!   original fields as extension members so existing clients keep working.
+//! - **gRPC:** the code in the `aegis-error-code` metadata entry and the
+//!   problem document as JSON in the status details.
+//!
+//! Both transports derive status from the same table ([`http_status`],
+//! [`grpc_code`]), so one code always means the same thing on either.
+
+use axum::{
+    extract::Request,
+    http::{header, HeaderValue, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use crate::domain::error_code::{CodedError, ErrorCode};
+
+/// Media type of problem responses.
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// gRPC metadata key carrying the error code.
+pub const GRPC_ERROR_CODE_METADATA: &str = "aegis-error-code";
+
+/// Base of the `type` URI; the code is appended.
+const PROBLEM_TYPE_BASE: &str = "https://docs.100monkeys.ai/errors/";
+
+/// Largest error body the middleware will rewrite.
+const MAX_REWRITE_BODY_BYTES: usize = 1 << 20;
+
+/// Members owned by the problem document; legacy fields never override them.
+const RESERVED_MEMBERS: &[&str] = &["type", "title", "status", "detail", "code"];
+
+/// An RFC 9457 problem document.
+#[derive(Debug, Clone, Serialize)]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    pub title: &'static str,
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    pub code: ErrorCode,
+    #[serde(flatten)]
+    pub extensions: Map<String, Value>,
+}
+
+impl ProblemDetails {
+    pub fn new(code: ErrorCode, detail: impl Into<String>) -> Self {
+        let detail = detail.into();
+        Self {
+            type_uri: format!("{PROBLEM_TYPE_BASE}{code}"),
+            title: code.title(),
+            status: http_status(code).as_u16(),
+            detail: (!detail.is_empty()).then_some(detail),
+            code,
+            extensions: Map::new(),
+        }
+    }
+
+    pub fn from_error<E: CodedError + ?Sized>(error: &E) -> Self {
+        Self::new(error.error_code(), error.to_string())
+    }
+
+    /// Override the HTTP status, e.g. to keep an endpoint's historical status.
+    pub fn with_status(mut self, status: StatusCode) -> Self {
+        self.status = status.as_u16();
+        self
+    }
+
+    pub fn with_extension(mut self, key: impl Into<String>, value: impl Into<Value>) -> Self {
+        let key = key.into();
+        if !RESERVED_MEMBERS.contains(&key.as_str()) {
+            self.extensions.insert(key, value.into());
+        }
+        self
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let body = serde_json::to_vec(&self).unwrap_or_default();
+        let mut response = (status, body).into_response();
+        response
+            .headers_mut()
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+        response
+    }
+}
+
+/// HTTP status for a code.
+pub fn http_status(code: ErrorCode) -> StatusCode {
+    match code {
+        ErrorCode::InvalidRequest | ErrorCode::UnsupportedApiVersion => StatusCode::BAD_REQUEST,
+        ErrorCode::ValidationFailed => StatusCode::UNPROCESSABLE_ENTITY,
+        ErrorCode::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+        ErrorCode::Unauthenticated => StatusCode::UNAUTHORIZED,
+        ErrorCode::InsufficientScope
+        | ErrorCode::Forbidden
+        | ErrorCode::TenantSuspended
+        | ErrorCode::FeatureUnavailable => StatusCode::FORBIDDEN,
+        ErrorCode::NotFound => StatusCode::NOT_FOUND,
+        ErrorCode::Conflict | ErrorCode::AlreadyExists => StatusCode::CONFLICT,
+        ErrorCode::RateLimited | ErrorCode::QuotaExceeded => StatusCode::TOO_MANY_REQUESTS,
+        ErrorCode::Internal | ErrorCode::StorageFailure => StatusCode::INTERNAL_SERVER_ERROR,
+        ErrorCode::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+        ErrorCode::UpstreamFailure => StatusCode::BAD_GATEWAY,
+        ErrorCode::Timeout => StatusCode::GATEWAY_TIMEOUT,
+        ErrorCode::NotImplemented => StatusCode::NOT_IMPLEMENTED,
+    }
+}
+
+/// gRPC status code for a code.
+pub fn grpc_code(code: ErrorCode) -> tonic::Code {
+    match code {
+        ErrorCode::InvalidRequest
+        | ErrorCode::ValidationFailed
+        | ErrorCode::UnsupportedApiVersion => tonic::Code::InvalidArgument,
+        ErrorCode::PayloadTooLarge | ErrorCode::RateLimited | ErrorCode::QuotaExceeded => {
+            tonic::Code::ResourceExhausted
+        }
+        ErrorCode::Unauthenticated => tonic::Code::Unauthenticated,
+        ErrorCode::InsufficientScope | ErrorCode::Forbidden | ErrorCode::TenantSuspended => {
+            tonic::Code::PermissionDenied
+        }
+        ErrorCode::FeatureUnavailable | ErrorCode::Conflict => tonic::Code::FailedPrecondition,
+        ErrorCode::NotFound => tonic::Code::NotFound,
+        ErrorCode::AlreadyExists => tonic::Code::AlreadyExists,
+        ErrorCode::Internal | ErrorCode::StorageFailure => tonic::Code::Internal,
+        ErrorCode::ServiceUnavailable | ErrorCode::UpstreamFailure => tonic::Code::Unavailable,
+        ErrorCode::Timeout => tonic::Code::DeadlineExceeded,
+        ErrorCode::NotImplemented => tonic::Code::Unimplemented,
+    }
+}
+
+/// Fallback code for an error response that carries none.
+pub fn code_for_http_status(status: StatusCode) -> ErrorCode {
+    match status {
+        StatusCode::UNAUTHORIZED => ErrorCode::Unauthenticated,
+        StatusCode::PAYMENT_REQUIRED => ErrorCode::QuotaExceeded,
+        StatusCode::FORBIDDEN => ErrorCode::Forbidden,
+        StatusCode::NOT_FOUND => ErrorCode::NotFound,
+        StatusCode::CONFLICT => ErrorCode::Conflict,
+        StatusCode::PAYLOAD_TOO_LARGE => ErrorCode::PayloadTooLarge,
+        StatusCode::UNPROCESSABLE_ENTITY => ErrorCode::ValidationFailed,
+        StatusCode::TOO_MANY_REQUESTS => ErrorCode::RateLimited,
+        StatusCode::NOT_IMPLEMENTED => ErrorCode::NotImplemented,
+        StatusCode::BAD_GATEWAY => ErrorCode::UpstreamFailure,
+        StatusCode::SERVICE_UNAVAILABLE => ErrorCode::ServiceUnavailable,
+        StatusCode::GATEWAY_TIMEOUT => ErrorCode::Timeout,
+        status if status.is_server_error() => ErrorCode::Internal,
+        _ => ErrorCode::InvalidRequest,
+    }
+}
+
+/// A gRPC status carrying `code` in metadata and the problem in details.
+pub fn grpc_status(code: ErrorCode, message: impl Into<String>) -> tonic::Status {
+    let message = message.into();
+    let details =
+        serde_json::to_vec(&ProblemDetails::new(code, message.clone())).unwrap_or_default();
+    let mut status = tonic::Status::with_details(grpc_code(code), message, details.into());
+    status.metadata_mut().insert(
+        GRPC_ERROR_CODE_METADATA,
+        tonic::metadata::MetadataValue::from_static(code.as_str()),
+    );
+    status
+}
+
+/// [`grpc_status`] for a [`CodedError`].
+pub fn coded_error_to_status<E: CodedError + ?Sized>(error: &E) -> tonic::Status {
+    grpc_status(error.error_code(), error.to_string())
+}
+
+/// Axum middleware rewriting error responses that are not already problem
+/// documents into `application/problem+json`.
+///
+/// A JSON object body keeps its members as extensions; a legacy snake_case
+/// `error` slug selects the code (see [`ErrorCode::from_legacy_slug`]) and a
+/// free-text `error` becomes `detail`. Plain-text bodies become `detail`.
+/// Without a recognised slug the code is derived from the HTTP status, which
+/// is never changed.
+pub async fn problem_details_middleware(request: Request, next: Next) -> Response {
+    let response = next.run(request).await;
+    let status = response.status();
+    if !(status.is_client_error() || status.is_server_error()) {
+        return response;
+    }
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    if content_type.starts_with(PROBLEM_JSON) || content_type.starts_with("text/event-stream") {
+        return response;
+    }
+
+    let (parts, body) = response.into_parts();
+    let bytes = match axum::body::to_bytes(body, MAX_REWRITE_BODY_BYTES).await {
+        Ok(bytes) => bytes,
+        Err(_) => {
+            return ProblemDetails::new(code_for_http_status(status), "")
+                .with_status(status)
+                .into_response();
+        }
+    };
+
+    let mut rewritten = legacy_body_to_problem(status, &bytes).into_response();
+    // Preserve handler headers (e.g. Retry-After, WWW-Authenticate).
+    for (name, value) in parts.headers.iter() {
+        if name != header::CONTENT_TYPE && name != header::CONTENT_LENGTH {
+            rewritten.headers_mut().append(name.clone(), value.clone());
+        }
+    }
+    rewritten
+}
+
+fn legacy_body_to_problem(status: StatusCode, body: &[u8]) -> ProblemDetails {
+    let fallback = code_for_http_status(status);
+    match serde_json::from_slice::<Value>(body) {
+        Ok(Value::Object(members)) => {
+            let error = members.get("error").and_then(Value::as_str);
+            let slug_code = error
+                .or_else(|| members.get("code").and_then(Value::as_str))
+                .and_then(ErrorCode::from_legacy_slug);
+            let detail = match (slug_code, error) {
+                (Some(_), _) | (None, None) => members
+                    .get("message")
+                    .or_else(|| members.get("detail"))
+                    .and_then(Value::as_str)
+                    .unwrap_or_default()
+                    .to_string(),
+                (None, Some(error)) => error.to_string(),
+            };
+            let mut problem =
+                ProblemDetails::new(slug_code.unwrap_or(fallback), detail).with_status(status);
+            for (key, value) in members {
+                problem = problem.with_extension(key, value);
+            }
+            problem
+        }
+        _ => {
+            let text = String::from_utf8_lossy(body);
+            ProblemDetails::new(fallback, text.trim()).with_status(status)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_json(problem: &ProblemDetails) -> Value {
+        serde_json::to_value(problem).unwrap()
+    }
+
+    #[test]
+    fn problem_serializes_rfc9457_members() {
+        let problem = ProblemDetails::new(ErrorCode::NotFound, "Agent abc not found")
+            .with_extension("agent_id", "abc")
+            .with_extension("status", 200);
+        let json = to_json(&problem);
+        assert_eq!(json["type"], "https://docs.100monkeys.ai/errors/AEG-3000");
+        assert_eq!(json["title"], "Not found");
+        assert_eq!(json["status"], 404);
+        assert_eq!(json["detail"], "Agent abc not found");
+        assert_eq!(json["code"], "AEG-3000");
+        assert_eq!(json["agent_id"], "abc");
+    }
+
+    #[test]
+    fn legacy_slug_bodies_keep_their_members() {
+        let body = br#"{"error":"insufficient_scope","required":"agent:deploy"}"#;
+        let json = to_json(&legacy_body_to_problem(StatusCode::FORBIDDEN, body));
+        assert_eq!(json["code"], "AEG-2001");
+        assert_eq!(json["error"], "insufficient_scope");
+        assert_eq!(json["required"], "agent:deploy");
+        assert_eq!(json["status"], 403);
+        assert!(json.get("detail").is_none());
+    }
+
+    #[test]
+    fn free_text_and_plain_bodies_become_detail() {
+        let body = br#"{"error":"Agent abc not found"}"#;
+        let json = to_json(&legacy_body_to_problem(StatusCode::NOT_FOUND, body));
+        assert_eq!(json["code"], "AEG-3000");
+        assert_eq!(json["detail"], "Agent abc not found");
+
+        let json = to_json(&legacy_body_to_problem(
+            StatusCode::UNAUTHORIZED,
+            b"Missing Authorization header",
+        ));
+        assert_eq!(json["code"], "AEG-2000");
+        assert_eq!(json["detail"], "Missing Authorization header");
+    }
+
+    #[test]
+    fn transports_agree_on_every_code() {
+        for code in ErrorCode::ALL {
+            let status = http_status(*code);
+            let grpc = grpc_code(*code);
+            assert_eq!(
+                status.is_server_error(),
+                matches!(
+                    grpc,
+                    tonic::Code::Internal
+                        | tonic::Code::Unavailable
+                        | tonic::Code::DeadlineExceeded
+                        | tonic::Code::Unimplemented
+                ),
+                "{code}"
+            );
+        }
+        let status = grpc_status(ErrorCode::QuotaExceeded, "volume full");
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        assert_eq!(
+            status.metadata().get(GRPC_ERROR_CODE_METADATA).unwrap(),
+            "AEG-4001"
+        );
+    }
+}
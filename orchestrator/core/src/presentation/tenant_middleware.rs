@@ -80,6 +80,7 @@ pub fn derive_tenant_id(identity: &UserIdentity) -> TenantId {
 /// Paths exempt from tenant extraction (match the IAM exempt paths).
 fn is_tenant_exempt(path: &str) -> bool {
     path == "/health"
+        || path.starts_with("/v1/meta/")
         || path.starts_with("/v1/dispatch-gateway")
         || path.starts_with("/v1/seal/")
         || path.starts_with("/v1/webhooks/")
@@ -482,6 +483,8 @@ mod tests {
         assert!(is_tenant_exempt("/v1/seal/invoke"));
         assert!(is_tenant_exempt("/v1/webhooks/github"));
         assert!(is_tenant_exempt("/v1/temporal-events"));
+        assert!(is_tenant_exempt("/v1/meta/version"));
+        assert!(is_tenant_exempt("/v1/meta/handshake"));
         assert!(!is_tenant_exempt("/v1/executions"));
         assert!(!is_tenant_exempt("/v1/agents"));
     }
@@ -0,0 +1,637 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Supervisor Simulation Harness
+//!
+//! Deterministic, fully in-memory driver for the 100monkeys iteration loop
+//! ([`Supervisor::run_loop`], ADR-005). Compiled only with the `simulation`
+//! cargo feature.
+//!
+//! A [`SimulationScenario`] wires the real [`Supervisor`] to:
+//!
+//! | Double | Replaces | Behaviour |
+//! |--------|----------|-----------|
+//! | [`ScriptedLLMProvider`] | LLM adapters | Pops one [`ScriptedTurn`] per call; optional virtual latency |
+//! | [`SimulatedRuntime`] | Docker / Firecracker | Each iteration asks the LLM once; its reply is the iteration output |
+//! | [`ScriptedValidator`] | Judge agents | Returns a scripted score per iteration (ADR-017) |
+//! | [`RecordingObserver`] | `ExecutionMonitor` | Records every [`SimEvent`] the supervisor emits |
+//!
+//! Time is virtual: run scenarios with [`run_virtual`] (or under
+//! `#[tokio::test(start_paused = true)]`) and every timeout, latency and
+//! deadline resolves instantly while still being observed in order. Nothing
+//! touches the network, the filesystem or a database, so CI can run
+//! thousands of executions per second.
+//!
+//! ```ignore
+//! let outcome = run_virtual(
+//!     SimulationScenario::new("write a haiku")
+//!         .turn(ScriptedTurn::reply("draft"))
+//!         .turn(ScriptedTurn::reply("final").after(Duration::from_secs(30)))
+//!         .validate_with([0.2, 0.9], 0.8)
+//!         .run(),
+//! );
+//! assert_eq!(outcome.iterations, 2);
+//! assert_eq!(outcome.validation_outcomes(), vec![false, true]);
+//! ```
+//!
+//! # Architecture
+//!
+//! - **Layer:** Test support (spans domain doubles; not part of the runtime layers)
+//! - **Purpose:** Fast, deterministic end-to-end tests of the supervisor loop
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
+
+use crate::domain::agent::{ExecutionMode, ExecutionStrategy, ImagePullPolicy};
+use crate::domain::execution::{ExecutionId, ExecutionInput};
+use crate::domain::llm::{
+    FinishReason, GenerationOptions, GenerationResponse, LLMError, LLMProvider, TokenUsage,
+};
+use crate::domain::runtime::{
+    AgentRuntime, InstanceId, InstanceStatus, ResourceLimits, RuntimeConfig, RuntimeError,
+    TaskInput, TaskOutput,
+};
+use crate::domain::supervisor::{Supervisor, SupervisorObserver};
+use crate::domain::tenant::TenantId;
+use crate::domain::validation::{
+    GradientResult, GradientValidator, ValidationContext, ValidationPipeline, ValidationResults,
+    ValidatorEntry, ValidatorKind,
+};
+
+/// Run `future` on a fresh current-thread runtime whose clock starts paused.
+///
+/// Timers auto-advance whenever the runtime is idle, so simulated latency and
+/// timeouts cost no wall-clock time.
+pub fn run_virtual<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_time()
+        .start_paused(true)
+        .build()
+        .expect("failed to build simulation runtime")
+        .block_on(future)
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Scripted LLM
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// One scripted LLM call.
+#[derive(Debug, Clone)]
+pub struct ScriptedTurn {
+    latency: Duration,
+    reply: Result<String, String>,
+}
+
+impl ScriptedTurn {
+    /// The LLM answers with `text`.
+    pub fn reply(text: impl Into<String>) -> Self {
+        Self {
+            latency: Duration::ZERO,
+            reply: Ok(text.into()),
+        }
+    }
+
+    /// The LLM call fails with a provider error.
+    pub fn fail(message: impl Into<String>) -> Self {
+        Self {
+            latency: Duration::ZERO,
+            reply: Err(message.into()),
+        }
+    }
+
+    /// Delay the answer by `latency` of virtual time.
+    pub fn after(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+}
+
+/// [`LLMProvider`] that replays a fixed script, one [`ScriptedTurn`] per call.
+///
+/// Calls past the end of the script fail with `LLMError::Provider`.
+#[derive(Default)]
+pub struct ScriptedLLMProvider {
+    turns: Mutex<VecDeque<ScriptedTurn>>,
+    prompts: Mutex<Vec<String>>,
+}
+
+impl ScriptedLLMProvider {
+    pub fn new(turns: impl IntoIterator<Item = ScriptedTurn>) -> Self {
+        Self {
+            turns: Mutex::new(turns.into_iter().collect()),
+            prompts: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Every prompt received so far, in call order.
+    pub fn prompts(&self) -> Vec<String> {
+        self.prompts.lock().expect("prompts poisoned").clone()
+    }
+
+    /// Turns not yet consumed.
+    pub fn remaining(&self) -> usize {
+        self.turns.lock().expect("turns poisoned").len()
+    }
+}
+
+#[async_trait]
+impl LLMProvider for ScriptedLLMProvider {
+    async fn generate(
+        &self,
+        prompt: &str,
+        _options: &GenerationOptions,
+    ) -> Result<GenerationResponse, LLMError> {
+        self.prompts
+            .lock()
+            .expect("prompts poisoned")
+            .push(prompt.to_string());
+        let turn = self
+            .turns
+            .lock()
+            .expect("turns poisoned")
+            .pop_front()
+            .ok_or_else(|| LLMError::Provider("simulation script exhausted".to_string()))?;
+        if !turn.latency.is_zero() {
+            tokio::time::sleep(turn.latency).await;
+        }
+        let text = turn.reply.map_err(LLMError::Provider)?;
+        Ok(GenerationResponse {
+            usage: TokenUsage {
+                prompt_tokens: prompt.len() as u32,
+                completion_tokens: text.len() as u32,
+                total_tokens: (prompt.len() + text.len()) as u32,
+            },
+            text,
+            provider: "simulation".to_string(),
+            model: "scripted".to_string(),
+            finish_reason: FinishReason::Stop,
+        })
+    }
+
+    async fn health_check(&self) -> Result<(), LLMError> {
+        Ok(())
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Simulated runtime
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// [`AgentRuntime`] whose "container" makes a single LLM call per iteration.
+///
+/// The LLM reply becomes the iteration's stdout with exit code 0; an LLM error
+/// becomes `RuntimeError::ExecutionFailed`. Instances are tracked so tests can
+/// assert that none leak.
+pub struct SimulatedRuntime {
+    llm: Arc<dyn LLMProvider>,
+    next_instance: AtomicU64,
+    live: Mutex<HashSet<InstanceId>>,
+    spawned_env: Mutex<Vec<HashMap<String, String>>>,
+}
+
+impl SimulatedRuntime {
+    pub fn new(llm: Arc<dyn LLMProvider>) -> Self {
+        Self {
+            llm,
+            next_instance: AtomicU64::new(0),
+            live: Mutex::new(HashSet::new()),
+            spawned_env: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Number of instances spawned and not yet terminated.
+    pub fn live_instances(&self) -> usize {
+        self.live.lock().expect("live instances poisoned").len()
+    }
+
+    /// Environment of every spawned instance, in spawn order. Carries
+    /// `AEGIS_ITERATION` and `AEGIS_ITERATION_HISTORY` as set by the supervisor.
+    pub fn spawned_env(&self) -> Vec<HashMap<String, String>> {
+        self.spawned_env
+            .lock()
+            .expect("spawned env poisoned")
+            .clone()
+    }
+}
+
+#[async_trait]
+impl AgentRuntime for SimulatedRuntime {
+    async fn spawn(&self, config: RuntimeConfig) -> Result<InstanceId, RuntimeError> {
+        let n = self.next_instance.fetch_add(1, Ordering::Relaxed);
+        let id = InstanceId::new(format!("sim-{n}"));
+        self.spawned_env
+            .lock()
+            .expect("spawned env poisoned")
+            .push(config.env);
+        self.live
+            .lock()
+            .expect("live instances poisoned")
+            .insert(id.clone());
+        Ok(id)
+    }
+
+    async fn execute(&self, id: &InstanceId, input: TaskInput) -> Result<TaskOutput, RuntimeError> {
+        if !self
+            .live
+            .lock()
+            .expect("live instances poisoned")
+            .contains(id)
+        {
+            return Err(RuntimeError::InstanceNotFound(id.0.clone()));
+        }
+        let response = self
+            .llm
+            .generate(&input.prompt, &GenerationOptions::default())
+            .await
+            .map_err(|e| RuntimeError::ExecutionFailed(e.to_string()))?;
+        Ok(TaskOutput {
+            result: serde_json::Value::String(response.text),
+            logs: Vec::new(),
+            tool_calls: Vec::new(),
+            exit_code: 0,
+            trajectory: Vec::new(),
+        })
+    }
+
+    async fn terminate(&self, id: &InstanceId) -> Result<(), RuntimeError> {
+        self.live
+            .lock()
+            .expect("live instances poisoned")
+            .remove(id);
+        Ok(())
+    }
+
+    async fn status(&self, id: &InstanceId) -> Result<InstanceStatus, RuntimeError> {
+        let running = self
+            .live
+            .lock()
+            .expect("live instances poisoned")
+            .contains(id);
+        Ok(InstanceStatus {
+            id: id.clone(),
+            state: if running { "running" } else { "exited" }.to_string(),
+            uptime_seconds: 0,
+            memory_usage_mb: 0,
+            cpu_usage_percent: 0.0,
+        })
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Scripted validation
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// [`GradientValidator`] returning one scripted score per call (confidence 1.0).
+///
+/// Calls past the end of the script score 0.0.
+pub struct ScriptedValidator {
+    scores: Mutex<VecDeque<f64>>,
+}
+
+impl ScriptedValidator {
+    pub fn new(scores: impl IntoIterator<Item = f64>) -> Self {
+        Self {
+            scores: Mutex::new(scores.into_iter().collect()),
+        }
+    }
+
+    /// Single-entry semantic pipeline passing at `min_score`.
+    pub fn pipeline(scores: impl IntoIterator<Item = f64>, min_score: f64) -> ValidationPipeline {
+        ValidationPipeline::new(vec![ValidatorEntry {
+            kind: ValidatorKind::Semantic,
+            validator: Box::new(Self::new(scores)),
+            min_score,
+            min_confidence: 0.0,
+        }])
+    }
+}
+
+#[async_trait]
+impl GradientValidator for ScriptedValidator {
+    async fn validate(&self, _ctx: &ValidationContext) -> anyhow::Result<GradientResult> {
+        let score = self
+            .scores
+            .lock()
+            .expect("scores poisoned")
+            .pop_front()
+            .unwrap_or(0.0);
+        Ok(GradientResult {
+            score,
+            confidence: 1.0,
+            reasoning: format!("scripted score {score:.2}"),
+            signals: Vec::new(),
+            metadata: HashMap::new(),
+        })
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Event recording
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// A [`SupervisorObserver`] callback, as recorded by [`RecordingObserver`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SimEvent {
+    IterationStarted {
+        iteration: u8,
+    },
+    ConsoleOutput {
+        iteration: u8,
+        stream: String,
+        content: String,
+    },
+    IterationCompleted {
+        iteration: u8,
+        output: String,
+        exit_code: i64,
+    },
+    IterationFailed {
+        iteration: u8,
+        error: String,
+    },
+    InstanceSpawned {
+        iteration: u8,
+        instance_id: InstanceId,
+    },
+    InstanceTerminated {
+        iteration: u8,
+        instance_id: InstanceId,
+    },
+    ValidationCompleted {
+        iteration: u8,
+        passed: bool,
+        score: Option<f64>,
+    },
+}
+
+/// Observer that records every supervisor callback in order.
+#[derive(Default)]
+pub struct RecordingObserver {
+    events: Mutex<Vec<SimEvent>>,
+}
+
+impl RecordingObserver {
+    pub fn events(&self) -> Vec<SimEvent> {
+        self.events.lock().expect("events poisoned").clone()
+    }
+
+    fn record(&self, event: SimEvent) {
+        self.events.lock().expect("events poisoned").push(event);
+    }
+}
+
+#[async_trait]
+impl SupervisorObserver for RecordingObserver {
+    async fn on_iteration_start(&self, iteration: u8, _prompt: &str) {
+        self.record(SimEvent::IterationStarted { iteration });
+    }
+
+    async fn on_console_output(&self, iteration: u8, stream: &str, content: &str) {
+        self.record(SimEvent::ConsoleOutput {
+            iteration,
+            stream: stream.to_string(),
+            content: content.to_string(),
+        });
+    }
+
+    async fn on_iteration_complete(&self, iteration: u8, result: &str, exit_code: i64) {
+        self.record(SimEvent::IterationCompleted {
+            iteration,
+            output: result.to_string(),
+            exit_code,
+        });
+    }
+
+    async fn on_iteration_fail(&self, iteration: u8, error: &str) {
+        self.record(SimEvent::IterationFailed {
+            iteration,
+            error: error.to_string(),
+        });
+    }
+
+    async fn on_instance_spawned(&self, iteration: u8, instance_id: &InstanceId) {
+        self.record(SimEvent::InstanceSpawned {
+            iteration,
+            instance_id: instance_id.clone(),
+        });
+    }
+
+    async fn on_instance_terminated(&self, iteration: u8, instance_id: &InstanceId) {
+        self.record(SimEvent::InstanceTerminated {
+            iteration,
+            instance_id: instance_id.clone(),
+        });
+    }
+
+    async fn on_validation_complete(
+        &self,
+        iteration: u8,
+        results: &ValidationResults,
+        passed: bool,
+    ) {
+        self.record(SimEvent::ValidationCompleted {
+            iteration,
+            passed,
+            score: results.gradient.as_ref().map(|g| g.score),
+        });
+    }
+}
+
+// ──────────────────────────────────────────────────────────────────────────────
+// Scenario
+// ──────────────────────────────────────────────────────────────────────────────
+
+/// Builder for one simulated execution.
+pub struct SimulationScenario {
+    intent: String,
+    turns: Vec<ScriptedTurn>,
+    validation: Option<(Vec<f64>, f64)>,
+    max_iterations: u32,
+    timeout_seconds: Option<u64>,
+    iteration_timeout: Option<String>,
+    cancellation_token: CancellationToken,
+}
+
+impl SimulationScenario {
+    /// Scenario with no script, no validation and 5 iterations.
+    pub fn new(intent: impl Into<String>) -> Self {
+        Self {
+            intent: intent.into(),
+            turns: Vec::new(),
+            validation: None,
+            max_iterations: 5,
+            timeout_seconds: None,
+            iteration_timeout: None,
+            cancellation_token: CancellationToken::new(),
+        }
+    }
+
+    /// Append one LLM turn (consumed by one iteration).
+    pub fn turn(mut self, turn: ScriptedTurn) -> Self {
+        self.turns.push(turn);
+        self
+    }
+
+    pub fn turns(mut self, turns: impl IntoIterator<Item = ScriptedTurn>) -> Self {
+        self.turns.extend(turns);
+        self
+    }
+
+    /// Judge each successful iteration with the next of `scores`, passing at `min_score`.
+    pub fn validate_with(mut self, scores: impl IntoIterator<Item = f64>, min_score: f64) -> Self {
+        self.validation = Some((scores.into_iter().collect(), min_score));
+        self
+    }
+
+    pub fn max_iterations(mut self, max_iterations: u32) -> Self {
+        self.max_iterations = max_iterations;
+        self
+    }
+
+    /// Overall execution deadline (`spec.resources.timeout`).
+    pub fn timeout_seconds(mut self, seconds: u64) -> Self {
+        self.timeout_seconds = Some(seconds);
+        self
+    }
+
+    /// Per-iteration deadline (`spec.execution.iteration_timeout`, e.g. `"30s"`).
+    pub fn iteration_timeout(mut self, timeout: impl Into<String>) -> Self {
+        self.iteration_timeout = Some(timeout.into());
+        self
+    }
+
+    /// Token the caller can cancel to interrupt the run.
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.cancellation_token.clone()
+    }
+
+    /// Drive the real [`Supervisor`] through the scenario.
+    pub async fn run(self) -> SimulationOutcome {
+        let llm = Arc::new(ScriptedLLMProvider::new(self.turns));
+        let runtime = Arc::new(SimulatedRuntime::new(llm.clone()));
+        let observer = Arc::new(RecordingObserver::default());
+        let supervisor = Supervisor::new(runtime.clone());
+        let pipeline = self
+            .validation
+            .map(|(scores, min_score)| Arc::new(ScriptedValidator::pipeline(scores, min_score)));
+
+        let config = RuntimeConfig {
+            language: "python".to_string(),
+            version: "3.12".to_string(),
+            isolation: "simulation".to_string(),
+            env: HashMap::new(),
+            image_pull_policy: ImagePullPolicy::IfNotPresent,
+            container_uid: 1000,
+            container_gid: 1000,
+            resources: ResourceLimits {
+                cpu_millis: None,
+                memory_bytes: None,
+                disk_bytes: None,
+                timeout_seconds: self.timeout_seconds,
+            },
+            execution: ExecutionStrategy {
+                mode: ExecutionMode::Iterative,
+                max_retries: self.max_iterations,
+                iteration_timeout: self.iteration_timeout,
+                ..ExecutionStrategy::default()
+            },
+            volumes: Vec::new(),
+            keep_container_on_failure: false,
+            image: "simulation".to_string(),
+            bootstrap_path: None,
+            execution_id: ExecutionId::new(),
+            tenant_id: TenantId::default(),
+            sidecars: Vec::new(),
+            platform: None,
+            network_policy: None,
+            network_flow_capture: None,
+        };
+        let input = ExecutionInput {
+            intent: Some(self.intent),
+            input: serde_json::json!({}),
+            workspace_volume_id: None,
+            workspace_volume_mount_path: None,
+            workspace_remote_path: None,
+            workflow_execution_id: None,
+            attachments: Vec::new(),
+        };
+
+        let started = tokio::time::Instant::now();
+        let result = supervisor
+            .run_loop(
+                config,
+                input,
+                self.max_iterations,
+                observer.clone(),
+                self.cancellation_token,
+                pipeline,
+            )
+            .await;
+        let elapsed = started.elapsed();
+
+        let events = observer.events();
+        SimulationOutcome {
+            iterations: events
+                .iter()
+                .filter(|e| matches!(e, SimEvent::IterationStarted { .. }))
+                .count(),
+            result,
+            events,
+            prompts: llm.prompts(),
+            unused_turns: llm.remaining(),
+            leaked_instances: runtime.live_instances(),
+            spawned_env: runtime.spawned_env(),
+            elapsed,
+        }
+    }
+}
+
+/// Everything observable about one simulated execution.
+#[derive(Debug)]
+pub struct SimulationOutcome {
+    /// Final supervisor result: the accepted output, or why the loop stopped.
+    pub result: Result<String, RuntimeError>,
+    /// Number of iterations started.
+    pub iterations: usize,
+    /// Supervisor callbacks in emission order.
+    pub events: Vec<SimEvent>,
+    /// Prompts sent to the scripted LLM.
+    pub prompts: Vec<String>,
+    /// Script turns left unconsumed.
+    pub unused_turns: usize,
+    /// Instances spawned but never terminated.
+    pub leaked_instances: usize,
+    /// Environment passed to each spawned instance.
+    pub spawned_env: Vec<HashMap<String, String>>,
+    /// Virtual time the loop took.
+    pub elapsed: Duration,
+}
+
+impl SimulationOutcome {
+    /// `passed` flag of each validation, in iteration order.
+    pub fn validation_outcomes(&self) -> Vec<bool> {
+        self.events
+            .iter()
+            .filter_map(|e| match e {
+                SimEvent::ValidationCompleted { passed, .. } => Some(*passed),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Iterations that failed before producing output (spawn/LLM/timeout errors).
+    pub fn failed_iterations(&self) -> Vec<u8> {
+        self.events
+            .iter()
+            .filter_map(|e| match e {
+                SimEvent::IterationFailed { iteration, .. } => Some(*iteration),
+                _ => None,
+            })
+            .collect()
+    }
+}
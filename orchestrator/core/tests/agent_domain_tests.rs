@@ -14,10 +14,11 @@
 //! - **Purpose:** Verify domain invariants, state transitions, and value-object semantics
 
 use aegis_orchestrator_core::domain::agent::{
-    Agent, AgentManifest, AgentSpec, AgentStatus, ContextItem, DeliveryCondition, DeliveryConfig,
-    DeliveryDestination, DeliveryType, EmailConfig, ExecutionMode, ExecutionStrategy,
-    FilesystemPolicy, ManifestMetadata, NetworkPolicy, ResourceLimits, RuntimeConfig, RuntimeType,
-    ScheduleConfig, SecurityConfig, TaskConfig, ValidatorSpec, VolumeSpec, WebhookConfig,
+    Agent, AgentManifest, AgentSpec, AgentStatus, ApprovalMode, ApprovalTimeoutAction, ContextItem,
+    DeliveryCondition, DeliveryConfig, DeliveryDestination, DeliveryType, EmailConfig,
+    EnvSecretRef, EnvVar, ExecutionMode, ExecutionStrategy, FilesystemPolicy, ManifestMetadata,
+    NetworkPolicy, ResourceLimits, RuntimeConfig, RuntimeType, ScheduleConfig, SecurityConfig,
+    TaskConfig, ValidatorSpec, VolumeSpec, WebhookConfig,
 };
 use aegis_orchestrator_core::domain::shared_kernel::{AgentId, ImagePullPolicy};
 use aegis_orchestrator_core::domain::workflow::ConsensusStrategy;
@@ -45,6 +46,11 @@ fn make_standard_manifest(name: &str) -> AgentManifest {
                 version: Some("3.11".to_string()),
                 image: None,
                 image_pull_policy: ImagePullPolicy::IfNotPresent,
+                base_image: None,
+                packages: Vec::new(),
+                env: Vec::new(),
+                sidecars: Vec::new(),
+                platform: None,
                 isolation: "inherit".to_string(),
                 model: "default".to_string(),
                 temperature: None,
@@ -65,6 +71,8 @@ fn make_standard_manifest(name: &str) -> AgentManifest {
             input_schema: None,
             security_context: None,
             output_handler: None,
+            concurrency: None,
+            healthcheck: None,
         },
     }
 }
@@ -77,6 +85,11 @@ fn make_custom_runtime_manifest(name: &str, image: &str) -> AgentManifest {
         version: None,
         image: Some(image.to_string()),
         image_pull_policy: ImagePullPolicy::Always,
+        base_image: None,
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
         isolation: "docker".to_string(),
         model: "default".to_string(),
         temperature: None,
@@ -289,6 +302,11 @@ fn runtime_config_valid_standard() {
         version: Some("3.11".to_string()),
         image: None,
         image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: None,
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
         isolation: "inherit".to_string(),
         model: "default".to_string(),
         temperature: None,
@@ -304,6 +322,11 @@ fn runtime_config_valid_custom() {
         version: None,
         image: Some("ghcr.io/myorg/custom:v2".to_string()),
         image_pull_policy: ImagePullPolicy::Always,
+        base_image: None,
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
         isolation: "docker".to_string(),
         model: "default".to_string(),
         temperature: None,
@@ -312,6 +335,31 @@ fn runtime_config_valid_custom() {
     assert_eq!(rc.runtime_type(), RuntimeType::Custom);
 }
 
+#[test]
+fn runtime_config_platform_must_be_os_arch() {
+    let mut rc = RuntimeConfig {
+        language: Some("python".to_string()),
+        version: Some("3.11".to_string()),
+        image: None,
+        image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: None,
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: Some("linux/arm64".to_string()),
+        isolation: "docker".to_string(),
+        model: "default".to_string(),
+        temperature: None,
+    };
+    assert!(rc.validate().is_ok());
+    rc.platform = Some("linux/arm64/v8".to_string());
+    assert!(rc.validate().is_ok());
+    for bad in ["arm64", "linux/", "Linux/ARM64", "linux/arm64/v8/extra"] {
+        rc.platform = Some(bad.to_string());
+        assert!(rc.validate().unwrap_err().contains("platform"), "{bad}");
+    }
+}
+
 #[test]
 fn runtime_config_both_specified_is_error() {
     let rc = RuntimeConfig {
@@ -319,6 +367,11 @@ fn runtime_config_both_specified_is_error() {
         version: Some("3.11".to_string()),
         image: Some("ghcr.io/myorg/custom:v2".to_string()),
         image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: None,
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
         isolation: "inherit".to_string(),
         model: "default".to_string(),
         temperature: None,
@@ -334,6 +387,11 @@ fn runtime_config_neither_specified_is_error() {
         version: None,
         image: None,
         image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: None,
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
         isolation: "inherit".to_string(),
         model: "default".to_string(),
         temperature: None,
@@ -349,6 +407,11 @@ fn runtime_config_language_without_version_is_error() {
         version: None,
         image: None,
         image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: None,
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
         isolation: "inherit".to_string(),
         model: "default".to_string(),
         temperature: None,
@@ -364,6 +427,11 @@ fn runtime_config_version_without_language_is_error() {
         version: Some("3.11".to_string()),
         image: None,
         image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: None,
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
         isolation: "inherit".to_string(),
         model: "default".to_string(),
         temperature: None,
@@ -379,6 +447,11 @@ fn runtime_config_custom_image_must_be_fully_qualified() {
         version: None,
         image: Some("myimage:latest".to_string()), // no slash → not fully qualified
         image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: None,
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
         isolation: "inherit".to_string(),
         model: "default".to_string(),
         temperature: None,
@@ -387,6 +460,113 @@ fn runtime_config_custom_image_must_be_fully_qualified() {
     assert!(err.contains("fully-qualified"));
 }
 
+#[test]
+fn runtime_config_packages_on_standard_runtime_require_build() {
+    let rc = RuntimeConfig {
+        language: Some("python".to_string()),
+        version: Some("3.11".to_string()),
+        image: None,
+        image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: None,
+        packages: vec!["requests==2.32.3".to_string()],
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
+        isolation: "inherit".to_string(),
+        model: "default".to_string(),
+        temperature: None,
+    };
+    assert!(rc.validate().is_ok());
+    assert!(rc.requires_image_build());
+}
+
+#[test]
+fn runtime_config_packages_with_custom_image_is_error() {
+    let rc = RuntimeConfig {
+        language: None,
+        version: None,
+        image: Some("ghcr.io/myorg/custom:v2".to_string()),
+        image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: None,
+        packages: vec!["requests".to_string()],
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
+        isolation: "inherit".to_string(),
+        model: "default".to_string(),
+        temperature: None,
+    };
+    let err = rc.validate().unwrap_err();
+    assert!(err.contains("custom image"));
+}
+
+#[test]
+fn runtime_config_base_image_without_packages_is_error() {
+    let rc = RuntimeConfig {
+        language: Some("python".to_string()),
+        version: Some("3.11".to_string()),
+        image: None,
+        image_pull_policy: ImagePullPolicy::IfNotPresent,
+        base_image: Some("docker.io/library/python:3.11".to_string()),
+        packages: Vec::new(),
+        env: Vec::new(),
+        sidecars: Vec::new(),
+        platform: None,
+        isolation: "inherit".to_string(),
+        model: "default".to_string(),
+        temperature: None,
+    };
+    let err = rc.validate().unwrap_err();
+    assert!(err.contains("base_image requires packages"));
+}
+
+#[test]
+fn runtime_config_env_entries_are_validated() {
+    let yaml = r#"
+language: python
+version: "3.11"
+env:
+  - name: TARGET_REPO
+    value: "{{input.repo}}"
+  - name: GITHUB_TOKEN
+    secretRef:
+      path: kv/github
+      key: token
+"#;
+    let mut rc: RuntimeConfig = serde_yaml::from_str(yaml).unwrap();
+    assert!(rc.validate().is_ok());
+    assert_eq!(rc.env[1].secret_ref.as_ref().unwrap().key, "token");
+
+    let invalid = [
+        ("1BAD", Some("x"), None),
+        ("DATABASE_URL", Some("x"), None),
+        ("BOTH", Some("x"), Some("kv/github")),
+        ("NEITHER", None, None),
+        ("NO_ENGINE", None, Some("github")),
+    ];
+    for (name, value, secret_path) in invalid {
+        rc.env = vec![EnvVar {
+            name: name.to_string(),
+            value: value.map(str::to_string),
+            secret_ref: secret_path.map(|path| EnvSecretRef {
+                path: path.to_string(),
+                key: "token".to_string(),
+            }),
+        }];
+        assert!(rc.validate().is_err(), "{name} should be rejected");
+    }
+
+    rc.env = vec![
+        EnvVar {
+            name: "MODE".to_string(),
+            value: Some("a".to_string()),
+            secret_ref: None,
+        };
+        2
+    ];
+    assert!(rc.validate().unwrap_err().contains("more than once"));
+}
+
 // ============================================================================
 // 5. SecurityConfig Defaults
 // ============================================================================
@@ -639,6 +819,9 @@ fn execution_strategy_iterative_with_validation() {
         }]),
         tool_validation: None,
         delivery: None,
+        approval_mode: ApprovalMode::Off,
+        approval_timeout_seconds: None,
+        approval_on_timeout: None,
     };
     assert!(matches!(es.mode, ExecutionMode::Iterative));
     assert_eq!(es.max_retries, 10);
@@ -950,12 +1133,32 @@ fn execution_strategy_json_round_trip() {
         }]),
         tool_validation: None,
         delivery: None,
+        approval_mode: ApprovalMode::Off,
+        approval_timeout_seconds: None,
+        approval_on_timeout: None,
     };
     let json = serde_json::to_string(&original).expect("serialize");
     let deserialized: ExecutionStrategy = serde_json::from_str(&json).expect("deserialize");
     assert_eq!(original, deserialized);
 }
 
+#[test]
+fn execution_strategy_parses_per_iteration_approval() {
+    let es: ExecutionStrategy = serde_yaml::from_str(
+        "mode: iterative\napproval_mode: per_iteration\napproval_on_timeout: approve\n",
+    )
+    .expect("parse");
+    assert_eq!(es.approval_mode, ApprovalMode::PerIteration);
+    assert_eq!(es.approval_on_timeout, Some(ApprovalTimeoutAction::Approve));
+    assert_eq!(es.approval_timeout_seconds, None);
+
+    let default: ExecutionStrategy = serde_yaml::from_str("mode: iterative\n").expect("parse");
+    assert!(default.approval_mode.is_off());
+    assert!(!serde_yaml::to_string(&default)
+        .unwrap()
+        .contains("approval_mode"));
+}
+
 #[test]
 fn security_config_json_round_trip() {
     let original = SecurityConfig {
@@ -1000,6 +1203,7 @@ fn agent_status_json_round_trip() {
         AgentStatus::Active,
         AgentStatus::Paused,
         AgentStatus::Archived,
+        AgentStatus::Degraded,
         AgentStatus::Failed,
     ];
     for status in statuses {
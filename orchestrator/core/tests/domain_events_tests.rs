@@ -123,6 +123,7 @@ fn execution_id_returns_some_for_storage_event() {
     let event = DomainEvent::Storage(StorageEvent::FileOpened {
         execution_id: Some(eid),
         workflow_execution_id: None,
+        iteration_number: None,
         volume_id: vid,
         path: "/workspace/main.py".to_string(),
         open_mode: "read".to_string(),
@@ -664,6 +665,7 @@ fn storage_event_file_written_serde_roundtrip() {
     let event = StorageEvent::FileWritten {
         execution_id: Some(eid),
         workflow_execution_id: None,
+        iteration_number: None,
         volume_id: vid,
         path: "/workspace/output.txt".to_string(),
         offset: 0,
@@ -584,6 +584,9 @@ mod prod_handler_stubs {
         async fn start_drain(&self, _node_id: &NodeId) -> anyhow::Result<()> {
             unimplemented!("test stub")
         }
+        async fn end_drain(&self, _node_id: &NodeId) -> anyhow::Result<()> {
+            unimplemented!("test stub")
+        }
         async fn deregister(&self, _node_id: &NodeId, _reason: &str) -> anyhow::Result<()> {
             unimplemented!("test stub")
         }
@@ -0,0 +1,1567 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Event Schema Compatibility Tests
+//!
+//! Pins every event type published on the bus, its payload schema version and
+//! its payload fields. Removing an event type, or removing or renaming a field
+//! without bumping the type's version in `event_schema::SCHEMA_VERSION_BUMPS`,
+//! fails here. Adding fields is compatible; new event types and version bumps
+//! only need their `SNAPSHOT` entry added or updated.
+
+use std::collections::{BTreeSet, HashMap};
+
+use aegis_orchestrator_core::domain::events::WorkflowEvent;
+use aegis_orchestrator_core::domain::shared_kernel::ExecutionId;
+use aegis_orchestrator_core::infrastructure::event_bus::DomainEvent;
+use aegis_orchestrator_core::infrastructure::event_schema::{
+    event_schemas, EventEnvelope, EventSchema,
+};
+use chrono::Utc;
+
+/// `(event_type, schema_version, payload fields)`; `None` where the enum's
+/// representation does not expose fields.
+type PinnedSchema = (&'static str, u32, Option<&'static [&'static str]>);
+
+const SNAPSHOT: &[PinnedSchema] = &[
+    (
+        "agent_lifecycle.agent_deployed",
+        1,
+        Some(&["agent_id", "tenant_id", "manifest", "deployed_at"]),
+    ),
+    (
+        "agent_lifecycle.agent_paused",
+        1,
+        Some(&["agent_id", "paused_at"]),
+    ),
+    (
+        "agent_lifecycle.agent_resumed",
+        1,
+        Some(&["agent_id", "resumed_at"]),
+    ),
+    (
+        "agent_lifecycle.agent_updated",
+        1,
+        Some(&[
+            "agent_id",
+            "tenant_id",
+            "old_version",
+            "new_version",
+            "updated_at",
+        ]),
+    ),
+    (
+        "agent_lifecycle.agent_removed",
+        1,
+        Some(&["agent_id", "tenant_id", "removed_at"]),
+    ),
+    (
+        "agent_lifecycle.agent_failed",
+        1,
+        Some(&["agent_id", "reason", "failed_at"]),
+    ),
+    (
+        "agent_lifecycle.agent_scope_changed",
+        1,
+        Some(&[
+            "agent_id",
+            "agent_name",
+            "previous_scope",
+            "new_scope",
+            "previous_tenant_id",
+            "new_tenant_id",
+            "changed_by",
+            "changed_at",
+        ]),
+    ),
+    (
+        "execution.execution_started",
+        1,
+        Some(&["execution_id", "agent_id", "started_at"]),
+    ),
+    (
+        "execution.iteration_started",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "action",
+            "started_at",
+        ]),
+    ),
+    (
+        "execution.iteration_completed",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "output",
+            "completed_at",
+        ]),
+    ),
+    (
+        "execution.iteration_failed",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "error",
+            "failed_at",
+        ]),
+    ),
+    (
+        "execution.refinement_applied",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "code_diff",
+            "applied_at",
+            "cortex_pattern_id",
+            "cortex_pattern_category",
+            "cortex_success_score",
+            "cortex_solution_approach",
+        ]),
+    ),
+    (
+        "execution.execution_completed",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "final_output",
+            "total_iterations",
+            "completed_at",
+        ]),
+    ),
+    (
+        "execution.execution_failed",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "reason",
+            "total_iterations",
+            "failed_at",
+        ]),
+    ),
+    (
+        "execution.execution_cancelled",
+        1,
+        Some(&["execution_id", "agent_id", "reason", "cancelled_at"]),
+    ),
+    (
+        "execution.execution_timed_out",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "timeout_seconds",
+            "total_iterations",
+            "timed_out_at",
+        ]),
+    ),
+    (
+        "execution.console_output",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "stream",
+            "content",
+            "timestamp",
+        ]),
+    ),
+    (
+        "execution.llm_interaction",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "provider",
+            "model",
+            "input_tokens",
+            "output_tokens",
+            "prompt",
+            "response",
+            "timestamp",
+        ]),
+    ),
+    (
+        "execution.model_routed",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "requested_alias",
+            "routed_alias",
+            "classification",
+            "rule",
+            "timestamp",
+        ]),
+    ),
+    (
+        "execution.operator_guidance_added",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "guidance",
+            "submitted_by",
+            "timestamp",
+        ]),
+    ),
+    (
+        "execution.llm_call_failed",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "provider",
+            "model",
+            "error_class",
+            "message",
+            "attempts",
+            "elapsed_ms",
+            "fallback_attempted",
+            "timestamp",
+        ]),
+    ),
+    (
+        "execution.instance_spawned",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "instance_id",
+            "spawned_at",
+        ]),
+    ),
+    (
+        "execution.instance_terminated",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "instance_id",
+            "terminated_at",
+        ]),
+    ),
+    (
+        "execution.child_execution_spawned",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "parent_execution_id",
+            "child_execution_id",
+            "child_agent_id",
+            "spawned_at",
+        ]),
+    ),
+    (
+        "execution.child_execution_completed",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "child_execution_id",
+            "outcome",
+            "completed_at",
+        ]),
+    ),
+    (
+        "execution.gradient_validation_performed",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "iteration_number",
+            "score",
+            "confidence",
+            "validated_at",
+        ]),
+    ),
+    (
+        "execution.multi_judge_consensus",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "judge_scores",
+            "final_score",
+            "confidence",
+            "reached_at",
+        ]),
+    ),
+    (
+        "execution.output_handler_started",
+        1,
+        Some(&["execution_id", "handler_type"]),
+    ),
+    (
+        "execution.output_handler_completed",
+        1,
+        Some(&["execution_id", "handler_type", "result"]),
+    ),
+    (
+        "execution.output_handler_failed",
+        1,
+        Some(&["execution_id", "handler_type", "error"]),
+    ),
+    (
+        "workflow.workflow_registered",
+        1,
+        Some(&[
+            "workflow_id",
+            "tenant_id",
+            "name",
+            "version",
+            "scope",
+            "registered_at",
+        ]),
+    ),
+    (
+        "workflow.workflow_execution_started",
+        1,
+        Some(&["execution_id", "workflow_id", "started_at"]),
+    ),
+    (
+        "workflow.workflow_state_entered",
+        1,
+        Some(&["execution_id", "state_name", "entered_at"]),
+    ),
+    (
+        "workflow.workflow_state_exited",
+        1,
+        Some(&["execution_id", "state_name", "output", "exited_at"]),
+    ),
+    (
+        "workflow.workflow_state_stuck",
+        1,
+        Some(&[
+            "execution_id",
+            "state_name",
+            "entered_at",
+            "expected_seconds",
+            "action",
+            "detected_at",
+        ]),
+    ),
+    (
+        "workflow.workflow_iteration_started",
+        1,
+        Some(&["execution_id", "iteration_number", "started_at"]),
+    ),
+    (
+        "workflow.workflow_iteration_completed",
+        1,
+        Some(&["execution_id", "iteration_number", "output", "completed_at"]),
+    ),
+    (
+        "workflow.workflow_iteration_failed",
+        1,
+        Some(&["execution_id", "iteration_number", "error", "failed_at"]),
+    ),
+    (
+        "workflow.workflow_execution_completed",
+        1,
+        Some(&[
+            "execution_id",
+            "final_blackboard",
+            "artifacts",
+            "completed_at",
+        ]),
+    ),
+    (
+        "workflow.workflow_execution_failed",
+        1,
+        Some(&["execution_id", "reason", "failed_at"]),
+    ),
+    (
+        "workflow.workflow_execution_cancelled",
+        1,
+        Some(&["execution_id", "cancelled_at"]),
+    ),
+    (
+        "workflow.subworkflow_triggered",
+        1,
+        Some(&[
+            "parent_execution_id",
+            "child_execution_id",
+            "child_workflow_id",
+            "mode",
+            "parent_state_name",
+            "triggered_at",
+        ]),
+    ),
+    (
+        "workflow.subworkflow_completed",
+        1,
+        Some(&[
+            "parent_execution_id",
+            "child_execution_id",
+            "result_key",
+            "completed_at",
+        ]),
+    ),
+    (
+        "workflow.subworkflow_failed",
+        1,
+        Some(&[
+            "parent_execution_id",
+            "child_execution_id",
+            "reason",
+            "failed_at",
+        ]),
+    ),
+    (
+        "workflow.workflow_scope_changed",
+        1,
+        Some(&[
+            "workflow_id",
+            "workflow_name",
+            "previous_scope",
+            "new_scope",
+            "previous_tenant_id",
+            "new_tenant_id",
+            "changed_by",
+            "changed_at",
+        ]),
+    ),
+    (
+        "workflow.intent_execution_pipeline_started",
+        1,
+        Some(&[
+            "pipeline_execution_id",
+            "workflow_execution_id",
+            "intent",
+            "language",
+            "workspace_volume_id",
+            "started_at",
+        ]),
+    ),
+    (
+        "workflow.intent_execution_pipeline_completed",
+        1,
+        Some(&[
+            "pipeline_execution_id",
+            "workflow_execution_id",
+            "tenant_id",
+            "final_result",
+            "duration_ms",
+            "reused_existing_agent",
+            "agent_similarity_score",
+            "completed_at",
+        ]),
+    ),
+    (
+        "workflow.intent_execution_pipeline_failed",
+        1,
+        Some(&[
+            "pipeline_execution_id",
+            "workflow_execution_id",
+            "failed_at_state",
+            "reason",
+            "failed_at",
+        ]),
+    ),
+    (
+        "workflow.workflow_removed",
+        1,
+        Some(&["workflow_id", "tenant_id", "workflow_name", "removed_at"]),
+    ),
+    (
+        "learning.pattern_discovered",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "pattern_category",
+            "discovered_at",
+        ]),
+    ),
+    (
+        "learning.pattern_reinforced",
+        1,
+        Some(&["execution_id", "agent_id", "delta", "reinforced_at"]),
+    ),
+    (
+        "learning.pattern_decayed",
+        1,
+        Some(&["execution_id", "agent_id", "delta", "decayed_at"]),
+    ),
+    (
+        "policy.policy_violation_attempted",
+        1,
+        Some(&["agent_id", "violation_type", "details", "attempted_at"]),
+    ),
+    (
+        "policy.policy_violation_blocked",
+        1,
+        Some(&["agent_id", "violation_type", "details", "blocked_at"]),
+    ),
+    (
+        "volume.volume_created",
+        1,
+        Some(&[
+            "volume_id",
+            "execution_id",
+            "storage_class",
+            "remote_path",
+            "size_limit_bytes",
+            "created_at",
+        ]),
+    ),
+    (
+        "volume.volume_attached",
+        1,
+        Some(&[
+            "volume_id",
+            "instance_id",
+            "mount_point",
+            "access_mode",
+            "attached_at",
+        ]),
+    ),
+    (
+        "volume.volume_detached",
+        1,
+        Some(&["volume_id", "instance_id", "detached_at"]),
+    ),
+    (
+        "volume.volume_deleted",
+        1,
+        Some(&["volume_id", "deleted_at"]),
+    ),
+    (
+        "volume.volume_expired",
+        1,
+        Some(&["volume_id", "expired_at"]),
+    ),
+    (
+        "volume.volume_mount_failed",
+        1,
+        Some(&["volume_id", "instance_id", "error", "failed_at"]),
+    ),
+    (
+        "volume.volume_quota_exceeded",
+        1,
+        Some(&[
+            "volume_id",
+            "size_limit_bytes",
+            "actual_bytes",
+            "exceeded_at",
+        ]),
+    ),
+    (
+        "volume.user_volume_created",
+        1,
+        Some(&[
+            "volume_id",
+            "owner_user_id",
+            "tenant_id",
+            "label",
+            "size_limit_bytes",
+            "created_at",
+        ]),
+    ),
+    (
+        "volume.user_volume_renamed",
+        1,
+        Some(&["volume_id", "old_label", "new_label", "renamed_at"]),
+    ),
+    (
+        "volume.user_volume_deleted",
+        1,
+        Some(&["volume_id", "owner_user_id", "deleted_at"]),
+    ),
+    (
+        "volume.user_volume_quota_warning",
+        1,
+        Some(&["owner_user_id", "tenant_id", "usage_percent", "warned_at"]),
+    ),
+    (
+        "storage.file_opened",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "iteration_number",
+            "volume_id",
+            "path",
+            "open_mode",
+            "opened_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.file_read",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "iteration_number",
+            "volume_id",
+            "path",
+            "offset",
+            "bytes_read",
+            "duration_ms",
+            "read_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.file_written",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "iteration_number",
+            "volume_id",
+            "path",
+            "offset",
+            "bytes_written",
+            "duration_ms",
+            "written_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.file_closed",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "iteration_number",
+            "volume_id",
+            "path",
+            "closed_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.directory_listed",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "iteration_number",
+            "volume_id",
+            "path",
+            "entry_count",
+            "listed_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.file_created",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "iteration_number",
+            "volume_id",
+            "path",
+            "created_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.file_deleted",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "iteration_number",
+            "volume_id",
+            "path",
+            "deleted_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.path_traversal_blocked",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "attempted_path",
+            "blocked_at",
+        ]),
+    ),
+    (
+        "storage.filesystem_policy_violation",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "iteration_number",
+            "volume_id",
+            "operation",
+            "path",
+            "policy_rule",
+            "violated_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.quota_exceeded",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "volume_id",
+            "requested_bytes",
+            "available_bytes",
+            "exceeded_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.unauthorized_volume_access",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "volume_id",
+            "attempted_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "storage.operation_failed",
+        1,
+        Some(&[
+            "execution_id",
+            "workflow_execution_id",
+            "iteration_number",
+            "volume_id",
+            "operation",
+            "path",
+            "cause",
+            "error",
+            "failed_at",
+            "caller_node_id",
+            "host_node_id",
+        ]),
+    ),
+    (
+        "mcp.server_registered",
+        1,
+        Some(&["server_id", "name", "capabilities", "registered_at"]),
+    ),
+    (
+        "mcp.server_started",
+        1,
+        Some(&["server_id", "name", "process_id", "started_at"]),
+    ),
+    (
+        "mcp.server_stopped",
+        1,
+        Some(&["server_id", "name", "stopped_at"]),
+    ),
+    (
+        "mcp.server_failed",
+        1,
+        Some(&["server_id", "name", "error", "failed_at"]),
+    ),
+    (
+        "mcp.server_unhealthy",
+        1,
+        Some(&["server_id", "last_healthy"]),
+    ),
+    (
+        "mcp.invocation_requested",
+        1,
+        Some(&[
+            "invocation_id",
+            "execution_id",
+            "agent_id",
+            "tool_name",
+            "arguments",
+            "requested_at",
+        ]),
+    ),
+    (
+        "mcp.invocation_started",
+        1,
+        Some(&[
+            "invocation_id",
+            "execution_id",
+            "agent_id",
+            "server_id",
+            "tool_name",
+            "started_at",
+        ]),
+    ),
+    (
+        "mcp.invocation_completed",
+        1,
+        Some(&[
+            "invocation_id",
+            "execution_id",
+            "agent_id",
+            "result",
+            "duration_ms",
+            "completed_at",
+        ]),
+    ),
+    (
+        "mcp.invocation_failed",
+        1,
+        Some(&[
+            "invocation_id",
+            "execution_id",
+            "agent_id",
+            "error",
+            "failed_at",
+        ]),
+    ),
+    (
+        "mcp.policy_violation",
+        1,
+        Some(&[
+            "execution_id",
+            "agent_id",
+            "tool_name",
+            "violation_type",
+            "details",
+            "blocked_at",
+        ]),
+    ),
+    (
+        "seal.attestation_completed",
+        1,
+        Some(&[
+            "agent_id",
+            "execution_id",
+            "security_context_name",
+            "attested_at",
+        ]),
+    ),
+    (
+        "seal.session_created",
+        1,
+        Some(&[
+            "session_id",
+            "agent_id",
+            "execution_id",
+            "security_context_name",
+            "expires_at",
+            "created_at",
+        ]),
+    ),
+    (
+        "seal.session_revoked",
+        1,
+        Some(&["session_id", "agent_id", "reason", "revoked_at"]),
+    ),
+    (
+        "seal.policy_violation_blocked",
+        1,
+        Some(&[
+            "agent_id",
+            "execution_id",
+            "tool_name",
+            "violation_type",
+            "details",
+            "blocked_at",
+        ]),
+    ),
+    (
+        "stimulus.stimulus_received",
+        1,
+        Some(&["stimulus_id", "source", "received_at"]),
+    ),
+    (
+        "stimulus.stimulus_classified",
+        1,
+        Some(&[
+            "stimulus_id",
+            "workflow_id",
+            "confidence",
+            "routing_mode",
+            "classified_at",
+        ]),
+    ),
+    (
+        "stimulus.stimulus_rejected",
+        1,
+        Some(&["stimulus_id", "reason", "rejected_at"]),
+    ),
+    (
+        "stimulus.classification_failed",
+        1,
+        Some(&["stimulus_id", "error", "failed_at"]),
+    ),
+    (
+        "image_management.image_pull_started",
+        1,
+        Some(&["execution_id", "image", "pull_policy", "started_at"]),
+    ),
+    (
+        "image_management.image_pull_completed",
+        1,
+        Some(&[
+            "execution_id",
+            "image",
+            "source",
+            "duration_ms",
+            "completed_at",
+        ]),
+    ),
+    (
+        "image_management.image_pull_failed",
+        1,
+        Some(&["execution_id", "image", "reason", "failed_at"]),
+    ),
+    (
+        "image_management.image_verification_failed",
+        1,
+        Some(&["execution_id", "image", "reason", "failed_at"]),
+    ),
+    (
+        "iam.user_authenticated",
+        1,
+        Some(&["sub", "realm_slug", "identity_kind", "authenticated_at"]),
+    ),
+    (
+        "iam.token_validation_failed",
+        1,
+        Some(&["realm_slug", "reason", "attempted_at"]),
+    ),
+    (
+        "iam.realm_registered",
+        1,
+        Some(&["realm_slug", "realm_kind", "issuer_url", "registered_at"]),
+    ),
+    (
+        "iam.tenant_realm_provisioned",
+        1,
+        Some(&[
+            "tenant_slug",
+            "realm_id",
+            "secret_namespace",
+            "provisioned_at",
+        ]),
+    ),
+    (
+        "iam.service_account_provisioned",
+        1,
+        Some(&["client_id", "realm_slug", "grant_type", "provisioned_at"]),
+    ),
+    (
+        "iam.service_account_revoked",
+        1,
+        Some(&["client_id", "realm_slug", "reason", "revoked_at"]),
+    ),
+    (
+        "iam.jwks_cache_refreshed",
+        1,
+        Some(&["realm_slug", "key_count", "refreshed_at"]),
+    ),
+    (
+        "iam.jwks_cache_refresh_failed",
+        1,
+        Some(&["realm_slug", "reason", "failed_at"]),
+    ),
+    (
+        "secrets.secret_retrieved",
+        1,
+        Some(&["engine", "path", "access_context", "retrieved_at"]),
+    ),
+    (
+        "secrets.secret_written",
+        1,
+        Some(&["engine", "path", "access_context", "written_at"]),
+    ),
+    (
+        "secrets.dynamic_secret_generated",
+        1,
+        Some(&[
+            "engine",
+            "role",
+            "lease_id",
+            "lease_duration_secs",
+            "access_context",
+            "generated_at",
+        ]),
+    ),
+    (
+        "secrets.lease_renewed",
+        1,
+        Some(&[
+            "lease_id",
+            "new_duration_secs",
+            "access_context",
+            "renewed_at",
+        ]),
+    ),
+    (
+        "secrets.lease_revoked",
+        1,
+        Some(&["lease_id", "access_context", "revoked_at"]),
+    ),
+    (
+        "secrets.secret_access_denied",
+        1,
+        Some(&["engine", "path", "reason", "access_context", "denied_at"]),
+    ),
+    (
+        "container_run.container_run_started",
+        1,
+        Some(&[
+            "execution_id",
+            "state_name",
+            "step_name",
+            "image",
+            "command",
+            "started_at",
+        ]),
+    ),
+    (
+        "container_run.container_run_completed",
+        1,
+        Some(&[
+            "execution_id",
+            "state_name",
+            "step_name",
+            "exit_code",
+            "stdout_bytes",
+            "stderr_bytes",
+            "duration_ms",
+            "completed_at",
+        ]),
+    ),
+    (
+        "container_run.container_run_failed",
+        1,
+        Some(&[
+            "execution_id",
+            "state_name",
+            "step_name",
+            "reason",
+            "failed_at",
+        ]),
+    ),
+    (
+        "container_run.parallel_container_run_aggregated",
+        1,
+        Some(&[
+            "execution_id",
+            "state_name",
+            "total_steps",
+            "succeeded",
+            "failed",
+            "strategy",
+            "aggregated_at",
+        ]),
+    ),
+    (
+        "tenant.tenant_provisioned",
+        1,
+        Some(&[
+            "tenant_slug",
+            "tier",
+            "keycloak_realm",
+            "openbao_namespace",
+            "provisioned_at",
+        ]),
+    ),
+    (
+        "tenant.tenant_suspended",
+        1,
+        Some(&["tenant_slug", "reason", "suspended_at"]),
+    ),
+    (
+        "tenant.tenant_soft_deleted",
+        1,
+        Some(&["tenant_slug", "deleted_at"]),
+    ),
+    (
+        "tenant.tenant_hard_deleted",
+        1,
+        Some(&["tenant_id", "hard_deleted_at"]),
+    ),
+    (
+        "tenant.admin_cross_tenant_access",
+        1,
+        Some(&["admin_identity", "target_tenant_id", "accessed_at"]),
+    ),
+    (
+        "tenant.tenant_quota_exceeded",
+        1,
+        Some(&[
+            "tenant_slug",
+            "quota_kind",
+            "current_value",
+            "limit",
+            "exceeded_at",
+        ]),
+    ),
+    (
+        "tenant.tenant_quota_updated",
+        1,
+        Some(&[
+            "tenant_id",
+            "quota_kind",
+            "old_limit",
+            "new_limit",
+            "updated_at",
+            "updated_by",
+        ]),
+    ),
+    (
+        "tenant.user_tenant_provisioned",
+        1,
+        Some(&[
+            "tenant_slug",
+            "user_sub",
+            "tier",
+            "keycloak_realm",
+            "provisioned_at",
+        ]),
+    ),
+    (
+        "cluster.node_attested",
+        1,
+        Some(&["node_id", "role", "attested_at"]),
+    ),
+    (
+        "cluster.node_registered",
+        1,
+        Some(&["node_id", "capabilities", "registered_at"]),
+    ),
+    (
+        "cluster.node_deregistered",
+        1,
+        Some(&["node_id", "reason", "deregistered_at"]),
+    ),
+    (
+        "cluster.node_unhealthy",
+        1,
+        Some(&["node_id", "last_seen", "marked_at"]),
+    ),
+    (
+        "cluster.execution_forwarded",
+        1,
+        Some(&[
+            "execution_id",
+            "from_node_id",
+            "to_node_id",
+            "tenant_id",
+            "forwarded_at",
+        ]),
+    ),
+    (
+        "cluster.cluster_config_pushed",
+        1,
+        Some(&["node_id", "config_version", "pushed_at"]),
+    ),
+    (
+        "cluster.edge_enrolled",
+        1,
+        Some(&["node_id", "tenant_id", "enrolled_at"]),
+    ),
+    (
+        "cluster.edge_connected",
+        1,
+        Some(&["node_id", "tenant_id", "stream_id", "connected_at"]),
+    ),
+    (
+        "cluster.edge_disconnected",
+        1,
+        Some(&["node_id", "disconnected_at"]),
+    ),
+    (
+        "cluster.edge_command_dispatched",
+        1,
+        Some(&["node_id", "command_id", "tool_name", "dispatched_at"]),
+    ),
+    ("cluster.edge_revoked", 1, Some(&["node_id", "revoked_at"])),
+    (
+        "cluster.edge_tags_changed",
+        1,
+        Some(&["node_id", "tags", "changed_at"]),
+    ),
+    (
+        "cluster.edge_group_created",
+        1,
+        Some(&["group_id", "tenant_id", "name", "created_at"]),
+    ),
+    (
+        "cluster.edge_group_updated",
+        1,
+        Some(&["group_id", "updated_at"]),
+    ),
+    (
+        "cluster.edge_group_deleted",
+        1,
+        Some(&["group_id", "deleted_at"]),
+    ),
+    (
+        "cluster.fleet_command_started",
+        1,
+        Some(&[
+            "fleet_command_id",
+            "tenant_id",
+            "tool_name",
+            "targets",
+            "started_at",
+        ]),
+    ),
+    (
+        "cluster.fleet_command_completed",
+        1,
+        Some(&["fleet_command_id", "ok", "err", "completed_at"]),
+    ),
+    (
+        "cluster.fleet_command_cancelled",
+        1,
+        Some(&["fleet_command_id", "cancelled_at"]),
+    ),
+    (
+        "rate_limit.exceeded",
+        1,
+        Some(&[
+            "user_id",
+            "tenant_id",
+            "resource_type",
+            "bucket",
+            "limit",
+            "counter",
+            "timestamp",
+        ]),
+    ),
+    (
+        "rate_limit.warning",
+        1,
+        Some(&[
+            "user_id",
+            "tenant_id",
+            "resource_type",
+            "bucket",
+            "limit",
+            "current",
+            "threshold_percent",
+            "timestamp",
+        ]),
+    ),
+    (
+        "swarm.swarm_created",
+        1,
+        Some(&["swarm_id", "parent_execution_id", "created_at"]),
+    ),
+    (
+        "swarm.child_spawned",
+        1,
+        Some(&["swarm_id", "agent_id", "execution_id", "spawned_at"]),
+    ),
+    (
+        "swarm.swarm_dissolved",
+        1,
+        Some(&["swarm_id", "reason", "dissolved_at"]),
+    ),
+    (
+        "swarm.lock_acquired",
+        1,
+        Some(&["swarm_id", "resource_id", "holder", "execution_id"]),
+    ),
+    ("swarm.lock_released", 1, Some(&["swarm_id", "resource_id"])),
+    (
+        "swarm.message_broadcast",
+        1,
+        Some(&["swarm_id", "from", "recipient_count"]),
+    ),
+    (
+        "credential.credential_created",
+        1,
+        Some(&[
+            "binding_id",
+            "owner_user_id",
+            "tenant_id",
+            "provider",
+            "credential_type",
+        ]),
+    ),
+    (
+        "credential.credential_revoked",
+        1,
+        Some(&["binding_id", "tenant_id"]),
+    ),
+    (
+        "credential.credential_rotated",
+        1,
+        Some(&["binding_id", "tenant_id"]),
+    ),
+    (
+        "credential.credential_granted",
+        1,
+        Some(&["binding_id", "grant_id", "target", "granted_by"]),
+    ),
+    (
+        "credential.credential_grant_revoked",
+        1,
+        Some(&["binding_id", "grant_id"]),
+    ),
+    (
+        "credential.credential_accessed",
+        1,
+        Some(&["binding_id", "agent_id", "tenant_id"]),
+    ),
+    (
+        "git_repo.binding_created",
+        1,
+        Some(&["id", "repo_url", "git_ref", "volume_id", "created_at"]),
+    ),
+    (
+        "git_repo.clone_started",
+        1,
+        Some(&["id", "volume_id", "strategy", "started_at"]),
+    ),
+    (
+        "git_repo.clone_completed",
+        1,
+        Some(&["id", "commit_sha", "duration_ms", "completed_at"]),
+    ),
+    (
+        "git_repo.clone_failed",
+        1,
+        Some(&["id", "error", "failed_at"]),
+    ),
+    ("git_repo.refresh_started", 1, Some(&["id", "started_at"])),
+    (
+        "git_repo.refresh_completed",
+        1,
+        Some(&[
+            "id",
+            "old_commit_sha",
+            "new_commit_sha",
+            "duration_ms",
+            "completed_at",
+        ]),
+    ),
+    (
+        "git_repo.refresh_failed",
+        1,
+        Some(&["id", "error", "failed_at"]),
+    ),
+    (
+        "git_repo.webhook_received",
+        1,
+        Some(&["id", "source", "received_at"]),
+    ),
+    (
+        "git_repo.binding_deleted",
+        1,
+        Some(&["id", "volume_id", "deleted_at"]),
+    ),
+    (
+        "git_repo.commit_made",
+        1,
+        Some(&["id", "commit_sha", "committed_at"]),
+    ),
+    (
+        "git_repo.push_completed",
+        1,
+        Some(&["id", "remote", "ref_name", "pushed_at"]),
+    ),
+    (
+        "canvas.session_created",
+        1,
+        Some(&[
+            "session_id",
+            "conversation_id",
+            "workspace_mode",
+            "tenant_id",
+            "created_at",
+        ]),
+    ),
+    (
+        "canvas.files_written_by_agent",
+        1,
+        Some(&["session_id", "file_count", "written_at"]),
+    ),
+    (
+        "canvas.git_commit_made",
+        1,
+        Some(&["session_id", "commit_sha", "committed_at"]),
+    ),
+    (
+        "canvas.git_pushed",
+        1,
+        Some(&["session_id", "remote", "ref_name", "pushed_at"]),
+    ),
+    (
+        "canvas.session_terminated",
+        1,
+        Some(&["session_id", "terminated_at"]),
+    ),
+    (
+        "canvas.workspace_volume_orphaned",
+        1,
+        Some(&["session_id", "volume_id", "reason", "observed_at"]),
+    ),
+    (
+        "script.created",
+        1,
+        Some(&["id", "tenant_id", "name", "version", "created_at"]),
+    ),
+    (
+        "script.updated",
+        1,
+        Some(&["id", "name", "new_version", "updated_at"]),
+    ),
+    ("script.deleted", 1, Some(&["id", "deleted_at"])),
+    ("team.team_provisioned", 1, None),
+    ("team.invitation_sent", 1, None),
+    ("team.invitation_accepted", 1, None),
+    ("team.invitation_cancelled", 1, None),
+    ("team.invitation_expired", 1, None),
+    ("team.membership_revoked", 1, None),
+    ("team.membership_role_changed", 1, None),
+    ("team.seat_count_changed", 1, None),
+    ("team.status_changed", 1, None),
+    ("team.tenant_context_switched", 1, None),
+    (
+        "drift.keycloak_user_missing",
+        1,
+        Some(&["tenant_id", "user_sub", "detected_at"]),
+    ),
+    (
+        "drift.keycloak_realm_missing",
+        1,
+        Some(&["realm", "tenant_id", "detected_at"]),
+    ),
+    (
+        "drift.stripe_customer_missing",
+        1,
+        Some(&["customer_id", "tenant_id", "detected_at"]),
+    ),
+    (
+        "drift.stripe_subscription_missing",
+        1,
+        Some(&["subscription_id", "tenant_id", "detected_at"]),
+    ),
+    (
+        "drift.orphan_subscription",
+        1,
+        Some(&["tenant_id", "stripe_customer_id", "detected_at"]),
+    ),
+    (
+        "drift.duplicate_stripe_customer",
+        1,
+        Some(&["user_sub", "matches", "detected_at"]),
+    ),
+];
+
+fn current() -> HashMap<String, EventSchema> {
+    event_schemas()
+        .into_iter()
+        .map(|schema| (schema.event_type.clone(), schema))
+        .collect()
+}
+
+#[test]
+fn pinned_event_types_still_exist() {
+    let current = current();
+    let missing: Vec<_> = SNAPSHOT
+        .iter()
+        .filter(|(event_type, _, _)| !current.contains_key(*event_type))
+        .map(|(event_type, _, _)| *event_type)
+        .collect();
+    assert!(
+        missing.is_empty(),
+        "event types removed or renamed: {missing:?}"
+    );
+}
+
+#[test]
+fn payload_fields_are_not_removed_without_a_version_bump() {
+    let current = current();
+    for (event_type, version, pinned_fields) in SNAPSHOT {
+        let Some(schema) = current.get(*event_type) else {
+            continue;
+        };
+        assert_eq!(
+            schema.schema_version, *version,
+            "{event_type}: schema version changed; update its SNAPSHOT entry"
+        );
+        let (Some(pinned), Some(fields)) = (pinned_fields, &schema.fields) else {
+            continue;
+        };
+        let fields: BTreeSet<_> = fields.iter().copied().collect();
+        let removed: Vec<_> = pinned.iter().filter(|f| !fields.contains(*f)).collect();
+        assert!(
+            removed.is_empty(),
+            "{event_type} v{version}: fields {removed:?} removed or renamed; \
+             bump its schema version"
+        );
+    }
+}
+
+#[test]
+fn every_event_type_is_pinned() {
+    let pinned: BTreeSet<_> = SNAPSHOT
+        .iter()
+        .map(|(event_type, _, _)| *event_type)
+        .collect();
+    let unpinned: Vec<_> = event_schemas()
+        .into_iter()
+        .filter(|schema| !pinned.contains(schema.event_type.as_str()))
+        .map(|schema| schema.event_type)
+        .collect();
+    assert!(
+        unpinned.is_empty(),
+        "add SNAPSHOT entries for new event types: {unpinned:?}"
+    );
+}
+
+#[test]
+fn envelope_wire_shape_is_stable() {
+    let event = DomainEvent::Workflow(WorkflowEvent::WorkflowExecutionCancelled {
+        execution_id: ExecutionId::new(),
+        cancelled_at: Utc::now(),
+    });
+
+    let json = serde_json::to_value(EventEnvelope::new(&event)).unwrap();
+    let keys: BTreeSet<_> = json.as_object().unwrap().keys().cloned().collect();
+    assert_eq!(
+        keys,
+        [
+            "category",
+            "event_type",
+            "occurred_at",
+            "payload",
+            "schema_version"
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect()
+    );
+    assert_eq!(json["event_type"], "workflow.workflow_execution_cancelled");
+    assert_eq!(json["schema_version"], 1);
+    assert!(json["payload"]["execution_id"].is_string());
+    assert!(json["payload"]["cancelled_at"].is_string());
+}
@@ -78,6 +78,8 @@ fn make_llm_interaction(provider: &str, model: &str) -> LlmInteraction {
         prompt: "test prompt".to_string(),
         response: "test response".to_string(),
         timestamp: Utc::now(),
+        input_tokens: None,
+        output_tokens: None,
     }
 }
 
@@ -801,6 +803,8 @@ fn llm_interaction_preserves_all_fields() {
         prompt: "solve P=NP".to_string(),
         response: "here is a proof...".to_string(),
         timestamp: ts,
+        input_tokens: None,
+        output_tokens: None,
     };
     exec.add_llm_interaction(1, interaction).unwrap();
 
@@ -936,6 +940,8 @@ fn llm_interaction_serializes_roundtrip() {
         prompt: "hello".to_string(),
         response: "world".to_string(),
         timestamp: Utc::now(),
+        input_tokens: None,
+        output_tokens: None,
     };
     let json = serde_json::to_string(&interaction).unwrap();
     let deserialized: LlmInteraction = serde_json::from_str(&json).unwrap();
@@ -108,9 +108,12 @@ fn workflow_with_schema(name: &str, schema: serde_json::Value) -> Workflow {
         WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         },
     )
     .unwrap()
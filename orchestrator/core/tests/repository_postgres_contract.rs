@@ -0,0 +1,102 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! Runs the repository contract suite against the Postgres repositories.
+//!
+//! The in-memory repositories run the same suite in-crate, so any ordering or
+//! filter divergence between the two shows up here. Tables are created in the
+//! session's temp schema; the test is skipped when no database is configured.
+
+use aegis_orchestrator_core::infrastructure::repositories::contract;
+use aegis_orchestrator_core::infrastructure::repositories::postgres_execution::PostgresExecutionRepository;
+use aegis_orchestrator_core::infrastructure::repositories::postgres_workflow_execution::PostgresWorkflowExecutionRepository;
+use sqlx::postgres::{PgPool, PgPoolOptions};
+
+async fn connect_test_pool() -> Option<PgPool> {
+    let database_url = std::env::var("AEGIS_DATABASE_URL")
+        .or_else(|_| std::env::var("DATABASE_URL"))
+        .ok()?;
+
+    // One connection so every query sees the same temp tables.
+    PgPoolOptions::new()
+        .max_connections(1)
+        .connect(&database_url)
+        .await
+        .ok()
+}
+
+#[tokio::test]
+async fn postgres_execution_repository_meets_contract() {
+    let Some(pool) = connect_test_pool().await else {
+        eprintln!("Skipping test: AEGIS_DATABASE_URL or DATABASE_URL not set or unreachable");
+        return;
+    };
+
+    sqlx::query(
+        r#"
+        CREATE TEMP TABLE executions (
+            id UUID PRIMARY KEY,
+            tenant_id TEXT NOT NULL,
+            agent_id UUID NOT NULL,
+            workflow_execution_id UUID,
+            input JSONB NOT NULL,
+            status TEXT NOT NULL,
+            iterations JSONB NOT NULL DEFAULT '[]'::jsonb,
+            current_iteration INTEGER NOT NULL DEFAULT 0,
+            max_iterations INTEGER NOT NULL DEFAULT 10,
+            final_output TEXT,
+            error_message TEXT,
+            container_uid INTEGER NOT NULL DEFAULT 1000,
+            container_gid INTEGER NOT NULL DEFAULT 1000,
+            parent_execution_id UUID,
+            started_at TIMESTAMPTZ NOT NULL,
+            completed_at TIMESTAMPTZ,
+            security_context_name TEXT NOT NULL,
+            initiating_user_sub TEXT,
+            runtime_image_digest TEXT,
+            labels JSONB NOT NULL DEFAULT '{}'::jsonb
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create temp executions table");
+
+    let repo = PostgresExecutionRepository::new(pool);
+    contract::execution_repository_contract(&repo).await;
+}
+
+#[tokio::test]
+async fn postgres_workflow_execution_repository_meets_contract() {
+    let Some(pool) = connect_test_pool().await else {
+        eprintln!("Skipping test: AEGIS_DATABASE_URL or DATABASE_URL not set or unreachable");
+        return;
+    };
+
+    sqlx::query(
+        r#"
+        CREATE TEMP TABLE workflow_executions (
+            id UUID PRIMARY KEY,
+            tenant_id TEXT NOT NULL,
+            workflow_id UUID NOT NULL,
+            temporal_workflow_id TEXT NOT NULL,
+            temporal_run_id TEXT NOT NULL,
+            input_params JSONB NOT NULL,
+            status TEXT NOT NULL,
+            current_state TEXT NOT NULL,
+            blackboard JSONB NOT NULL,
+            state_outputs JSONB NOT NULL,
+            state_history JSONB NOT NULL,
+            final_output JSONB,
+            started_at TIMESTAMPTZ NOT NULL,
+            last_transition_at TIMESTAMPTZ NOT NULL,
+            completed_at TIMESTAMPTZ
+        )
+        "#,
+    )
+    .execute(&pool)
+    .await
+    .expect("Failed to create temp workflow_executions table");
+
+    let repo = PostgresWorkflowExecutionRepository::new(pool);
+    contract::workflow_execution_repository_contract(&repo).await;
+}
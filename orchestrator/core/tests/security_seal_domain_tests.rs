@@ -49,6 +49,7 @@ fn fs_read_capability() -> Capability {
         max_response_size: None,
         rate_limit: None,
         max_concurrent: None,
+        conditions: None,
     }
 }
 
@@ -62,6 +63,7 @@ fn fs_wildcard_capability() -> Capability {
         max_response_size: None,
         rate_limit: None,
         max_concurrent: None,
+        conditions: None,
     }
 }
 
@@ -75,6 +77,7 @@ fn wildcard_capability() -> Capability {
         max_response_size: None,
         rate_limit: None,
         max_concurrent: None,
+        conditions: None,
     }
 }
 
@@ -88,6 +91,7 @@ fn web_capability_with_domains(domains: Vec<&str>) -> Capability {
         max_response_size: None,
         rate_limit: None,
         max_concurrent: None,
+        conditions: None,
     }
 }
 
@@ -358,6 +362,7 @@ fn capability_allows_when_no_path_constraint() {
         max_response_size: None,
         rate_limit: None,
         max_concurrent: None,
+        conditions: None,
     };
     // No path_allowlist means no path restriction
     assert!(cap
@@ -380,6 +385,7 @@ fn capability_allows_whitelisted_command() {
         max_response_size: None,
         rate_limit: None,
         max_concurrent: None,
+        conditions: None,
     };
     assert!(cap
         .allows("cmd.run", &json!({"command": "cargo build --release"}))
@@ -400,6 +406,7 @@ fn capability_rejects_non_whitelisted_command() {
         max_response_size: None,
         rate_limit: None,
         max_concurrent: None,
+        conditions: None,
     };
     let err = cap
         .allows("cmd.run", &json!({"command": "rm -rf /"}))
@@ -905,6 +912,7 @@ fn capability_serialization_roundtrip() {
         max_response_size: Some(1_048_576),
         rate_limit: None,
         max_concurrent: None,
+        conditions: None,
     };
 
     let json = serde_json::to_string(&cap).expect("serialize Capability");
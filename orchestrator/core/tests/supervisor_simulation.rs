@@ -0,0 +1,190 @@
+// Copyright (c) 2026 100monkeys.ai
+// SPDX-License-Identifier: AGPL-3.0
+//! # Supervisor Loop Simulation Tests (ADR-005, ADR-017)
+//!
+//! Drives the real `Supervisor` through scripted scenarios using the
+//! `simulation` feature harness. All time is virtual, so timeouts and slow
+//! LLM replies resolve instantly. Requires `--features simulation`.
+
+use std::time::Duration;
+
+use aegis_orchestrator_core::domain::runtime::{InstanceId, RuntimeError};
+use aegis_orchestrator_core::simulation::{
+    run_virtual, ScriptedTurn, SimEvent, SimulationScenario,
+};
+
+#[tokio::test(start_paused = true)]
+async fn first_success_without_validation_emits_ordered_events() {
+    let outcome = SimulationScenario::new("say hi")
+        .turn(ScriptedTurn::reply("hi"))
+        .run()
+        .await;
+
+    assert_eq!(outcome.result.unwrap(), "hi");
+    assert_eq!(outcome.iterations, 1);
+    assert_eq!(outcome.prompts, vec!["say hi".to_string()]);
+    assert_eq!(outcome.leaked_instances, 0);
+    let instance_id = InstanceId::new("sim-0");
+    assert_eq!(
+        outcome.events,
+        vec![
+            SimEvent::IterationStarted { iteration: 1 },
+            SimEvent::InstanceSpawned {
+                iteration: 1,
+                instance_id: instance_id.clone(),
+            },
+            SimEvent::InstanceTerminated {
+                iteration: 1,
+                instance_id,
+            },
+            SimEvent::ConsoleOutput {
+                iteration: 1,
+                stream: "stdout".to_string(),
+                content: "hi".to_string(),
+            },
+            SimEvent::IterationCompleted {
+                iteration: 1,
+                output: "hi".to_string(),
+                exit_code: 0,
+            },
+        ]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn rejected_iterations_are_refined_until_validation_passes() {
+    let outcome = SimulationScenario::new("write a haiku")
+        .turns([
+            ScriptedTurn::reply("draft"),
+            ScriptedTurn::reply("better"),
+            ScriptedTurn::reply("final"),
+        ])
+        .validate_with([0.3, 0.6, 0.95], 0.8)
+        .run()
+        .await;
+
+    assert_eq!(outcome.result.unwrap(), "final");
+    assert_eq!(outcome.iterations, 3);
+    assert_eq!(outcome.validation_outcomes(), vec![false, false, true]);
+
+    let iterations: Vec<_> = outcome
+        .spawned_env
+        .iter()
+        .map(|env| env["AEGIS_ITERATION"].clone())
+        .collect();
+    assert_eq!(iterations, vec!["1", "2", "3"]);
+    assert!(!outcome.spawned_env[0].contains_key("AEGIS_ITERATION_HISTORY"));
+    assert!(outcome.spawned_env[1]["AEGIS_ITERATION_HISTORY"].contains("validation_failed"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn loop_stops_at_max_iterations() {
+    let outcome = SimulationScenario::new("task")
+        .turns([
+            ScriptedTurn::reply("a"),
+            ScriptedTurn::reply("b"),
+            ScriptedTurn::reply("c"),
+        ])
+        .validate_with([0.1, 0.2], 0.5)
+        .max_iterations(2)
+        .run()
+        .await;
+
+    assert!(matches!(
+        outcome.result,
+        Err(RuntimeError::ExecutionFailed(_))
+    ));
+    assert_eq!(outcome.iterations, 2);
+    assert_eq!(outcome.validation_outcomes(), vec![false, false]);
+    assert_eq!(outcome.unused_turns, 1);
+    assert_eq!(outcome.leaked_instances, 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn llm_failure_fails_the_iteration_and_the_loop_retries() {
+    let outcome = SimulationScenario::new("task")
+        .turns([ScriptedTurn::fail("overloaded"), ScriptedTurn::reply("ok")])
+        .run()
+        .await;
+
+    assert_eq!(outcome.result.unwrap(), "ok");
+    assert_eq!(outcome.iterations, 2);
+    assert_eq!(outcome.failed_iterations(), vec![1]);
+    assert!(outcome.spawned_env[1]["AEGIS_ITERATION_HISTORY"].contains("overloaded"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn slow_llm_reply_hits_iteration_timeout_in_virtual_time() {
+    let outcome = SimulationScenario::new("task")
+        .turns([
+            ScriptedTurn::reply("slow").after(Duration::from_secs(120)),
+            ScriptedTurn::reply("fast").after(Duration::from_secs(5)),
+        ])
+        .iteration_timeout("30s")
+        .run()
+        .await;
+
+    assert_eq!(outcome.result.unwrap(), "fast");
+    assert_eq!(outcome.failed_iterations(), vec![1]);
+    assert_eq!(outcome.elapsed, Duration::from_secs(35));
+    assert_eq!(outcome.leaked_instances, 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn overall_deadline_interrupts_the_loop() {
+    let outcome = SimulationScenario::new("task")
+        .turns(
+            (0..5).map(|i| ScriptedTurn::reply(format!("try {i}")).after(Duration::from_secs(40))),
+        )
+        .validate_with([0.0; 5], 0.5)
+        .timeout_seconds(60)
+        .run()
+        .await;
+
+    assert!(matches!(outcome.result, Err(RuntimeError::TimedOut(60))));
+    assert_eq!(outcome.iterations, 2);
+    assert_eq!(outcome.validation_outcomes(), vec![false]);
+    assert_eq!(outcome.elapsed, Duration::from_secs(60));
+    assert_eq!(outcome.leaked_instances, 0);
+}
+
+#[tokio::test(start_paused = true)]
+async fn cancellation_terminates_the_running_instance() {
+    let scenario = SimulationScenario::new("task")
+        .turn(ScriptedTurn::reply("never").after(Duration::from_secs(100)));
+    let token = scenario.cancellation_token();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        token.cancel();
+    });
+
+    let outcome = scenario.run().await;
+
+    assert!(matches!(outcome.result, Err(RuntimeError::Cancelled)));
+    assert_eq!(outcome.elapsed, Duration::from_secs(10));
+    assert_eq!(outcome.leaked_instances, 0);
+    assert!(outcome
+        .events
+        .iter()
+        .any(|e| matches!(e, SimEvent::InstanceTerminated { iteration: 1, .. })));
+}
+
+#[test]
+fn thousands_of_simulated_executions_run_in_one_virtual_runtime() {
+    run_virtual(async {
+        for i in 0..2_000 {
+            let outcome = SimulationScenario::new(format!("task {i}"))
+                .turns([
+                    ScriptedTurn::reply("draft").after(Duration::from_secs(20)),
+                    ScriptedTurn::reply("final").after(Duration::from_secs(20)),
+                ])
+                .validate_with([0.4, 0.9], 0.7)
+                .run()
+                .await;
+            assert_eq!(outcome.result.unwrap(), "final");
+            assert_eq!(outcome.iterations, 2);
+            assert_eq!(outcome.validation_outcomes(), vec![false, true]);
+            assert_eq!(outcome.leaked_instances, 0);
+        }
+    });
+}
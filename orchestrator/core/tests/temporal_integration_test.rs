@@ -405,6 +405,10 @@ impl WorkflowExecutionRepository for MockWorkflowExecRepo {
         Ok(vec![])
     }
 
+    async fn find_active_all(&self) -> Result<Vec<WorkflowExecution>, RepositoryError> {
+        Ok(vec![])
+    }
+
     async fn find_by_workflow_for_tenant(
         &self,
         _tenant_id: &TenantId,
@@ -427,6 +431,35 @@ impl WorkflowExecutionRepository for MockWorkflowExecRepo {
         Ok(vec![])
     }
 
+    async fn append_transition(
+        &self,
+        _execution_id: ExecutionId,
+        _record: &aegis_orchestrator_core::domain::workflow::WorkflowTransitionRecord,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn close_transition(
+        &self,
+        _execution_id: ExecutionId,
+        _state_name: &str,
+        _exited_at: chrono::DateTime<chrono::Utc>,
+        _output_summary: Option<String>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn find_transitions_for_tenant(
+        &self,
+        _tenant_id: &TenantId,
+        _id: ExecutionId,
+    ) -> Result<
+        Vec<aegis_orchestrator_core::domain::workflow::WorkflowTransitionRecord>,
+        RepositoryError,
+    > {
+        Ok(vec![])
+    }
+
     async fn count_by_workflow_for_tenant(
         &self,
         _tenant_id: &TenantId,
@@ -512,6 +545,10 @@ impl WorkflowExecutionRepository for FailingWorkflowExecRepo {
         Ok(vec![])
     }
 
+    async fn find_active_all(&self) -> Result<Vec<WorkflowExecution>, RepositoryError> {
+        Ok(vec![])
+    }
+
     async fn find_by_workflow_for_tenant(
         &self,
         _tenant_id: &TenantId,
@@ -534,6 +571,35 @@ impl WorkflowExecutionRepository for FailingWorkflowExecRepo {
         Ok(vec![])
     }
 
+    async fn append_transition(
+        &self,
+        _execution_id: ExecutionId,
+        _record: &aegis_orchestrator_core::domain::workflow::WorkflowTransitionRecord,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn close_transition(
+        &self,
+        _execution_id: ExecutionId,
+        _state_name: &str,
+        _exited_at: chrono::DateTime<chrono::Utc>,
+        _output_summary: Option<String>,
+    ) -> Result<(), RepositoryError> {
+        Ok(())
+    }
+
+    async fn find_transitions_for_tenant(
+        &self,
+        _tenant_id: &TenantId,
+        _id: ExecutionId,
+    ) -> Result<
+        Vec<aegis_orchestrator_core::domain::workflow::WorkflowTransitionRecord>,
+        RepositoryError,
+    > {
+        Ok(vec![])
+    }
+
     async fn count_by_workflow_for_tenant(
         &self,
         _tenant_id: &TenantId,
@@ -656,9 +722,12 @@ fn build_test_workflow(name: &str) -> Workflow {
         WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         },
     )
     .unwrap()
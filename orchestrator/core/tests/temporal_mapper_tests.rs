@@ -94,9 +94,12 @@ fn test_map_100monkeys_workflow() {
         WorkflowSpec {
             initial_state: StateName::new("GENERATE").unwrap(),
             context: HashMap::from([("task".to_string(), serde_json::json!("Write fibonacci"))]),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         },
     )
     .unwrap();
@@ -160,9 +163,12 @@ fn test_map_workflow_defaults_missing_version_to_one_zero_zero() {
         WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         },
     )
     .unwrap();
@@ -204,6 +210,8 @@ fn test_spec_storage_is_mapped_to_temporal_definition() {
         WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: WorkflowStorageSpec {
                 workspace: Some(WorkflowWorkspaceSpec {
@@ -216,6 +224,7 @@ fn test_spec_storage_is_mapped_to_temporal_definition() {
                 shared_volumes: vec![],
             },
             max_total_transitions: None,
+            concurrency: None,
         },
     )
     .unwrap();
@@ -294,9 +303,12 @@ fn test_scope_mapped_to_temporal_definition() {
         WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states: states.clone(),
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         },
     )
     .unwrap();
@@ -323,9 +335,12 @@ fn test_scope_mapped_to_temporal_definition() {
         WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         },
     )
     .unwrap();
@@ -295,6 +295,11 @@ fn stub_agent(id: AgentId) -> Agent {
                 version: Some("3.11".to_string()),
                 image: None,
                 image_pull_policy: ImagePullPolicy::IfNotPresent,
+                base_image: None,
+                packages: Vec::new(),
+                env: Vec::new(),
+                sidecars: Vec::new(),
+                platform: None,
                 isolation: "inherit".to_string(),
                 model: "judge".to_string(),
                 temperature: None,
@@ -311,6 +316,8 @@ fn stub_agent(id: AgentId) -> Agent {
             input_schema: None,
             output_handler: None,
             security_context: None,
+            concurrency: None,
+            healthcheck: None,
         },
     };
     Agent {
@@ -229,6 +229,11 @@ impl AgentLifecycleService for MockAgentLifecycleService {
                     version: Some("3.11".to_string()),
                     image: None,
                     image_pull_policy: ImagePullPolicy::IfNotPresent,
+                    base_image: None,
+                    packages: Vec::new(),
+                    env: Vec::new(),
+                    sidecars: Vec::new(),
+                    platform: None,
                     isolation: "inherit".to_string(),
                     model: "judge".to_string(),
                     temperature: None,
@@ -246,6 +251,8 @@ impl AgentLifecycleService for MockAgentLifecycleService {
                 input_schema: None,
                 output_handler: None,
                 security_context: None,
+                concurrency: None,
+                healthcheck: None,
             },
         };
         Ok(Agent {
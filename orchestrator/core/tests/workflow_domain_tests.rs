@@ -62,9 +62,12 @@ fn single_system_state_spec(state_name: &str) -> WorkflowSpec {
     WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     }
 }
 
@@ -106,9 +109,12 @@ fn two_state_spec(from: &str, to: &str) -> WorkflowSpec {
     WorkflowSpec {
         initial_state: from_sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     }
 }
 
@@ -180,9 +186,12 @@ fn workflow_rejects_empty_states() {
     let spec = WorkflowSpec {
         initial_state: StateName::new("S").unwrap(),
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states: HashMap::new(),
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad"), spec).unwrap_err();
     assert!(err.to_string().contains("at least one state"));
@@ -208,9 +217,12 @@ fn workflow_rejects_missing_initial_state() {
     let spec = WorkflowSpec {
         initial_state: StateName::new("MISSING").unwrap(),
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad"), spec).unwrap_err();
     assert!(err.to_string().contains("not found"));
@@ -240,9 +252,12 @@ fn workflow_rejects_transition_to_nonexistent_state() {
     let spec = WorkflowSpec {
         initial_state: start,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad"), spec).unwrap_err();
     assert!(err.to_string().contains("NOWHERE"));
@@ -285,9 +300,12 @@ fn workflow_allows_duplicate_state_name_inserts_overwrite_in_hashmap() {
         WorkflowSpec {
             initial_state: sn,
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         },
     );
     assert!(wf.is_ok());
@@ -528,9 +546,12 @@ fn workflow_validates_all_transition_targets() {
     let spec = WorkflowSpec {
         initial_state: start,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad-transition"), spec).unwrap_err();
     assert!(err.to_string().contains("GHOST"));
@@ -975,9 +996,12 @@ fn container_run_rejects_empty_name() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad-name"), spec).unwrap_err();
     assert!(err.to_string().contains("name cannot be empty"));
@@ -999,9 +1023,12 @@ fn container_run_rejects_empty_image() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad-image"), spec).unwrap_err();
     assert!(err.to_string().contains("image cannot be empty"));
@@ -1023,9 +1050,12 @@ fn container_run_rejects_empty_command() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad-cmd"), spec).unwrap_err();
     assert!(err.to_string().contains("at least one token"));
@@ -1056,9 +1086,12 @@ fn container_run_rejects_non_absolute_mount_path() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad-mount"), spec).unwrap_err();
     assert!(err.to_string().contains("absolute path"));
@@ -1089,9 +1122,12 @@ fn container_run_accepts_valid_mount() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     assert!(Workflow::new(minimal_metadata("good-mount"), spec).is_ok());
 }
@@ -1119,9 +1155,12 @@ fn parallel_container_run_rejects_empty_steps() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("empty-par"), spec).unwrap_err();
     assert!(err.to_string().contains("at least one step"));
@@ -1157,9 +1196,12 @@ fn parallel_container_run_rejects_duplicate_step_names() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("dup-par"), spec).unwrap_err();
     assert!(err.to_string().contains("must be unique"));
@@ -1198,9 +1240,12 @@ fn parallel_container_run_rejects_non_absolute_step_mount() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad-par-mount"), spec).unwrap_err();
     assert!(err.to_string().contains("non-absolute mount_path"));
@@ -1235,9 +1280,12 @@ fn parallel_container_run_rejects_empty_step_image() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("empty-img"), spec).unwrap_err();
     assert!(err.to_string().contains("empty image"));
@@ -1272,9 +1320,12 @@ fn parallel_container_run_rejects_empty_step_command() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("empty-cmd"), spec).unwrap_err();
     assert!(err.to_string().contains("at least one token"));
@@ -1305,9 +1356,12 @@ fn subworkflow_blocking_requires_result_key() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("sub-bad"), spec).unwrap_err();
     assert!(err.to_string().contains("result_key"));
@@ -1334,9 +1388,12 @@ fn subworkflow_fire_and_forget_forbids_result_key() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("sub-bad2"), spec).unwrap_err();
     assert!(err.to_string().contains("must not specify result_key"));
@@ -1363,9 +1420,12 @@ fn subworkflow_rejects_empty_workflow_id() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("sub-empty"), spec).unwrap_err();
     assert!(err.to_string().contains("workflow_id cannot be empty"));
@@ -1392,9 +1452,12 @@ fn subworkflow_blocking_with_result_key_accepted() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     assert!(Workflow::new(minimal_metadata("sub-ok"), spec).is_ok());
 }
@@ -1420,9 +1483,12 @@ fn subworkflow_fire_and_forget_without_result_key_accepted() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(),
         max_total_transitions: None,
+        concurrency: None,
     };
     assert!(Workflow::new(minimal_metadata("sub-ok2"), spec).is_ok());
 }
@@ -1456,6 +1522,8 @@ fn volume_mount_rejects_undeclared_volume() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: WorkflowStorageSpec {
             workspace: None,
@@ -1466,6 +1534,7 @@ fn volume_mount_rejects_undeclared_volume() {
             }],
         },
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad-vol"), spec).unwrap_err();
     assert!(err.to_string().contains("ghost-vol"));
@@ -1497,6 +1566,8 @@ fn volume_mount_accepts_declared_volume() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: WorkflowStorageSpec {
             workspace: None,
@@ -1507,6 +1578,7 @@ fn volume_mount_accepts_declared_volume() {
             }],
         },
         max_total_transitions: None,
+        concurrency: None,
     };
     assert!(Workflow::new(minimal_metadata("good-vol"), spec).is_ok());
 }
@@ -1537,9 +1609,12 @@ fn volume_mount_skips_validation_when_no_volumes_declared() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: Default::default(), // empty shared_volumes => skip resolution check
         max_total_transitions: None,
+        concurrency: None,
     };
     assert!(Workflow::new(minimal_metadata("no-vol-decl"), spec).is_ok());
 }
@@ -1577,6 +1652,8 @@ fn parallel_container_run_mount_rejects_undeclared_volume() {
     let spec = WorkflowSpec {
         initial_state: sn,
         context: HashMap::new(),
+        variables: Default::default(),
+        handoffs: Default::default(),
         states,
         storage: WorkflowStorageSpec {
             workspace: None,
@@ -1587,6 +1664,7 @@ fn parallel_container_run_mount_rejects_undeclared_volume() {
             }],
         },
         max_total_transitions: None,
+        concurrency: None,
     };
     let err = Workflow::new(minimal_metadata("bad-par-vol"), spec).unwrap_err();
     assert!(err.to_string().contains("missing-vol"));
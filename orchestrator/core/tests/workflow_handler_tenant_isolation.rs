@@ -86,9 +86,12 @@ fn build_minimal_workflow(name: &str) -> Workflow {
         WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         },
     )
     .expect("workflow")
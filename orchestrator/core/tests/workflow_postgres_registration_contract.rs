@@ -127,9 +127,12 @@ fn build_workflow_with_description(name: &str, description: Option<&str>) -> Wor
         WorkflowSpec {
             initial_state: StateName::new("START").unwrap(),
             context: HashMap::new(),
+            variables: Default::default(),
+            handoffs: Default::default(),
             states,
             storage: Default::default(),
             max_total_transitions: None,
+            concurrency: None,
         },
     )
     .unwrap()
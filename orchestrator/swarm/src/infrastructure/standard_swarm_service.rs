@@ -6,6 +6,9 @@ use crate::domain::swarm::SwarmChildSpec;
 use crate::domain::{
     CancellationReason, MessageEnvelope, ResourceLock, Swarm, SwarmId, SwarmStatus,
 };
+use aegis_orchestrator_core::application::lock_service::{
+    HeldLock, InMemoryLockService, LockError, LockHolder, LockService,
+};
 use aegis_orchestrator_core::application::ports::SwarmCancellationPort;
 use aegis_orchestrator_core::domain::repository::ExecutionRepository;
 use aegis_orchestrator_core::domain::shared_kernel::{AgentId, ExecutionId};
@@ -17,16 +20,11 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::RwLock;
-use uuid::Uuid;
 
 #[derive(Default)]
 struct SwarmState {
     swarms: HashMap<SwarmId, Swarm>,
     execution_to_swarm: HashMap<ExecutionId, SwarmId>,
-    locks: HashMap<String, ResourceLock>,
-    /// Token -> (tenant_id, resource) so `release_lock` can verify the
-    /// caller's tenant matches the lock's owning swarm before deletion.
-    tokens: HashMap<String, (TenantId, String)>,
     messages: HashMap<SwarmId, Vec<MessageEnvelope>>,
 }
 
@@ -43,6 +41,33 @@ pub struct StandardSwarmService {
     /// and will reject the call (audit 002, finding 4.33). Production
     /// wiring MUST inject the real repository.
     execution_repo: Option<Arc<dyn ExecutionRepository>>,
+    /// Resource locks live in the core lock service, shared with manifest
+    /// concurrency groups. Swarm resources are namespaced by
+    /// [`SWARM_RESOURCE_PREFIX`] and keyed by the swarm's tenant.
+    locks: Arc<dyn LockService>,
+}
+
+/// Lock-service namespace for swarm resource locks.
+const SWARM_RESOURCE_PREFIX: &str = "swarm-resource/";
+
+fn swarm_resource(resource: &str) -> String {
+    format!("{SWARM_RESOURCE_PREFIX}{resource}")
+}
+
+/// Project a lock-service lock back into the swarm domain. Returns `None`
+/// for locks outside the swarm namespace.
+fn resource_lock(lock: HeldLock) -> Option<ResourceLock> {
+    let resource_id = lock
+        .resource
+        .strip_prefix(SWARM_RESOURCE_PREFIX)?
+        .to_string();
+    Some(ResourceLock {
+        resource_id,
+        held_by: lock.holder.agent_id?,
+        execution_id: lock.holder.execution_id,
+        acquired_at: lock.acquired_at,
+        expires_at: lock.expires_at.unwrap_or(chrono::DateTime::<Utc>::MAX_UTC),
+    })
 }
 
 impl StandardSwarmService {
@@ -50,9 +75,17 @@ impl StandardSwarmService {
         Self {
             state: Arc::new(RwLock::new(SwarmState::default())),
             execution_repo: None,
+            locks: Arc::new(InMemoryLockService::new()),
         }
     }
 
+    /// Share a lock service with other lock users (concurrency groups).
+    /// Defaults to a private [`InMemoryLockService`].
+    pub fn with_lock_service(mut self, locks: Arc<dyn LockService>) -> Self {
+        self.locks = locks;
+        self
+    }
+
     /// Inject the [`ExecutionRepository`] used to verify that the
     /// `parent_execution_id` supplied to [`SwarmService::create_swarm`]
     /// belongs to the caller's tenant.
@@ -108,17 +141,14 @@ impl StandardSwarmService {
     /// Operator-only (ADR-097). Returns an empty vector if the swarm does
     /// not exist.
     pub async fn locks_for_swarm_unscoped(&self, swarm_id: SwarmId) -> Vec<ResourceLock> {
-        let state = self.state.read().await;
-        let Some(swarm) = state.swarms.get(&swarm_id) else {
-            return Vec::new();
+        let (tenant_id, member_ids) = {
+            let state = self.state.read().await;
+            let Some(swarm) = state.swarms.get(&swarm_id) else {
+                return Vec::new();
+            };
+            (swarm.tenant_id.clone(), swarm.member_ids())
         };
-        let member_ids = swarm.member_ids();
-        state
-            .locks
-            .values()
-            .filter(|lock| member_ids.contains(&lock.held_by))
-            .cloned()
-            .collect()
+        self.member_locks(&tenant_id, &member_ids).await
     }
 
     /// Tenant-scoped list of swarms.
@@ -142,37 +172,41 @@ impl StandardSwarmService {
         tenant_id: &TenantId,
         swarm_id: SwarmId,
     ) -> Vec<ResourceLock> {
-        let state = self.state.read().await;
-        let Some(swarm) = state.swarms.get(&swarm_id) else {
-            return Vec::new();
+        let member_ids = {
+            let state = self.state.read().await;
+            let Some(swarm) = state.swarms.get(&swarm_id) else {
+                return Vec::new();
+            };
+            if &swarm.tenant_id != tenant_id {
+                return Vec::new();
+            }
+            swarm.member_ids()
         };
-        if &swarm.tenant_id != tenant_id {
-            return Vec::new();
-        }
+        self.member_locks(tenant_id, &member_ids).await
+    }
 
-        let member_ids = swarm.member_ids();
-        state
-            .locks
-            .values()
+    /// Swarm resource locks in `tenant_id` held by any of `member_ids`.
+    async fn member_locks(
+        &self,
+        tenant_id: &TenantId,
+        member_ids: &[AgentId],
+    ) -> Vec<ResourceLock> {
+        self.locks
+            .locks_for_tenant(tenant_id)
+            .await
+            .into_iter()
+            .filter_map(resource_lock)
             .filter(|lock| member_ids.contains(&lock.held_by))
-            .cloned()
             .collect()
     }
 
-    fn cleanup_expired_locks(state: &mut SwarmState) {
-        let now = Utc::now();
-        let expired_resources: Vec<String> = state
-            .locks
+    async fn cleanup_expired_locks(locks: &dyn LockService) {
+        let expired_count = locks
+            .purge_expired()
+            .await
             .iter()
-            .filter_map(|(resource, lock)| (lock.expires_at <= now).then_some(resource.clone()))
-            .collect();
-        let expired_count = expired_resources.len() as u64;
-        for resource in expired_resources {
-            state.locks.remove(&resource);
-            state
-                .tokens
-                .retain(|_, (_, held_resource)| held_resource != &resource);
-        }
+            .filter(|lock| lock.resource.starts_with(SWARM_RESOURCE_PREFIX))
+            .count() as u64;
         if expired_count > 0 {
             metrics::counter!("aegis_swarm_lock_expirations_total").increment(expired_count);
         }
@@ -180,13 +214,12 @@ impl StandardSwarmService {
 
     /// Spawn a background task that garbage-collects expired locks every 30 seconds.
     pub fn start_gc_task(self: &Arc<Self>) {
-        let state = Arc::clone(&self.state);
+        let locks = Arc::clone(&self.locks);
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(30));
             loop {
                 interval.tick().await;
-                let mut guard = state.write().await;
-                Self::cleanup_expired_locks(&mut guard);
+                Self::cleanup_expired_locks(locks.as_ref()).await;
             }
         });
     }
@@ -350,52 +383,46 @@ impl SwarmService for StandardSwarmService {
         execution_id: ExecutionId,
         ttl: Duration,
     ) -> Result<LockToken> {
-        let mut state = self.state.write().await;
-        Self::cleanup_expired_locks(&mut state);
+        Self::cleanup_expired_locks(self.locks.as_ref()).await;
 
         // Validate holder is in the swarm (tenant-scoped).
-        let swarm = swarm_for_tenant(&state, tenant_id, swarm_id)?;
-        if !swarm.contains(holder) {
-            bail!("agent {holder:?} is not a member of swarm {swarm_id:?}");
+        {
+            let state = self.state.read().await;
+            let swarm = swarm_for_tenant(&state, tenant_id, swarm_id)?;
+            if !swarm.contains(holder) {
+                bail!("agent {holder:?} is not a member of swarm {swarm_id:?}");
+            }
         }
 
-        if state.locks.contains_key(resource) {
-            metrics::counter!("aegis_swarm_lock_contentions_total").increment(1);
-            bail!("resource lock already held: {resource}");
+        match self
+            .locks
+            .try_acquire(
+                tenant_id,
+                &swarm_resource(resource),
+                LockHolder::agent(execution_id, holder),
+                Some(ttl),
+            )
+            .await
+        {
+            Ok(lock) => Ok(LockToken(lock.token.0)),
+            Err(LockError::Held { .. }) => {
+                metrics::counter!("aegis_swarm_lock_contentions_total").increment(1);
+                bail!("resource lock already held: {resource}");
+            }
+            Err(e) => Err(e.into()),
         }
-
-        let token = LockToken(Uuid::new_v4().to_string());
-        let ttl_chrono =
-            chrono::Duration::from_std(ttl).unwrap_or_else(|_| chrono::Duration::minutes(5));
-        let lock = ResourceLock {
-            resource_id: resource.to_string(),
-            held_by: holder,
-            execution_id,
-            acquired_at: Utc::now(),
-            expires_at: Utc::now() + ttl_chrono,
-        };
-
-        state.locks.insert(resource.to_string(), lock);
-        state
-            .tokens
-            .insert(token.0.clone(), (tenant_id.clone(), resource.to_string()));
-        Ok(token)
     }
 
     async fn release_lock(&self, tenant_id: &TenantId, token: LockToken) -> Result<()> {
-        let mut state = self.state.write().await;
         // Tenant-scoped: a token issued to tenant-A cannot be released by
         // tenant-B even if they somehow learned the token string.
-        let entry = state.tokens.get(&token.0).cloned();
-        let Some((token_tenant, resource)) = entry else {
-            bail!("unknown swarm lock token");
-        };
-        if &token_tenant != tenant_id {
-            bail!("unknown swarm lock token");
-        }
-        state.tokens.remove(&token.0);
-        state.locks.remove(&resource);
-        Ok(())
+        self.locks
+            .release(
+                tenant_id,
+                &aegis_orchestrator_core::application::lock_service::LockToken(token.0),
+            )
+            .await
+            .map_err(|_| anyhow!("unknown swarm lock token"))
     }
 
     async fn cancel_swarm(
@@ -405,18 +432,25 @@ impl SwarmService for StandardSwarmService {
         _reason: CancellationReason,
     ) -> Result<()> {
         let mut state = self.state.write().await;
-        let member_execution_ids = {
+        let (member_execution_ids, member_agent_ids) = {
             let swarm = swarm_for_tenant_mut(&mut state, tenant_id, swarm_id)?;
             swarm.status = SwarmStatus::Dissolving;
             let exec_ids = swarm.member_execution_ids();
             let member_agent_ids = swarm.member_ids();
             swarm.dissolve();
-            // Release all locks held by member agents
-            state
-                .locks
-                .retain(|_, lock| !member_agent_ids.contains(&lock.held_by));
-            exec_ids
+            (exec_ids, member_agent_ids)
         };
+        // Release all locks held by member agents
+        for lock in self.locks.locks_for_tenant(tenant_id).await {
+            let held_by_member = lock.resource.starts_with(SWARM_RESOURCE_PREFIX)
+                && lock
+                    .holder
+                    .agent_id
+                    .is_some_and(|agent_id| member_agent_ids.contains(&agent_id));
+            if held_by_member {
+                let _ = self.locks.release(tenant_id, &lock.token).await;
+            }
+        }
         // Clean up execution_to_swarm entries
         for exec_id in &member_execution_ids {
             state.execution_to_swarm.remove(exec_id);
@@ -633,6 +667,24 @@ mod tests {
             Ok(Vec::new())
         }
 
+        async fn update_labels_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _id: ExecutionId,
+            _labels: &std::collections::BTreeMap<String, String>,
+        ) -> std::result::Result<(), RepositoryError> {
+            Ok(())
+        }
+
+        async fn find_by_query_for_tenant(
+            &self,
+            _tenant_id: &TenantId,
+            _query: &aegis_orchestrator_core::domain::execution_query::ExecutionQuery,
+            _limit: usize,
+        ) -> std::result::Result<Vec<Execution>, RepositoryError> {
+            Ok(Vec::new())
+        }
+
         async fn list_recent_all_paginated(
             &self,
             _limit: usize,
@@ -676,6 +728,13 @@ mod tests {
         ) -> std::result::Result<u64, RepositoryError> {
             Ok(0)
         }
+
+        async fn find_unfinished_all(
+            &self,
+            _limit: usize,
+        ) -> std::result::Result<Vec<Execution>, RepositoryError> {
+            Ok(Vec::new())
+        }
     }
 
     fn tenant_a() -> TenantId {
@@ -0,0 +1,182 @@
+#!/usr/bin/env python3
+"""Compare criterion results against a stored baseline and flag regressions.
+
+Reads every ``<criterion-dir>/**/new/estimates.json`` produced by
+``cargo bench``, compares each benchmark's median against ``--baseline``
+and writes:
+
+* a Markdown results table (``--summary``, e.g. ``$GITHUB_STEP_SUMMARY``),
+* one CSV row per benchmark appended to ``--history``,
+* the current medians as the next run's baseline (``--write-baseline``).
+
+Exits with status 1 when any benchmark is slower than the baseline by more
+than ``--threshold`` (a fraction, default 0.10). Benchmarks missing from the
+baseline are reported as ``new`` and never fail the run.
+"""
+from __future__ import annotations
+
+import argparse
+import csv
+import json
+import os
+import sys
+from datetime import datetime, timezone
+from pathlib import Path
+
+HISTORY_FIELDS = [
+    "timestamp",
+    "commit",
+    "benchmark",
+    "median_ns",
+    "baseline_ns",
+    "change_pct",
+    "status",
+]
+
+
+def collect(criterion_dir: Path) -> dict[str, float]:
+    results: dict[str, float] = {}
+    for estimates in sorted(criterion_dir.glob("**/new/estimates.json")):
+        meta_path = estimates.parent / "benchmark.json"
+        if not meta_path.exists():
+            continue
+        meta = json.loads(meta_path.read_text(encoding="utf-8"))
+        median = json.loads(estimates.read_text(encoding="utf-8"))["median"]
+        results[meta["full_id"]] = float(median["point_estimate"])
+    return results
+
+
+def format_ns(value: float | None) -> str:
+    if value is None:
+        return "-"
+    for unit, scale in (("s", 1e9), ("ms", 1e6), ("µs", 1e3)):
+        if value >= scale:
+            return f"{value / scale:.2f} {unit}"
+    return f"{value:.1f} ns"
+
+
+def compare(
+    current: dict[str, float], baseline: dict[str, float], threshold: float
+) -> list[dict[str, object]]:
+    rows = []
+    for name, median in sorted(current.items()):
+        previous = baseline.get(name)
+        if previous is None or previous <= 0:
+            change, status = None, "new"
+        else:
+            change = (median - previous) / previous
+            if change > threshold:
+                status = "regression"
+            elif change < -threshold:
+                status = "improvement"
+            else:
+                status = "ok"
+        rows.append(
+            {
+                "benchmark": name,
+                "median_ns": median,
+                "baseline_ns": previous,
+                "change": change,
+                "status": status,
+            }
+        )
+    return rows
+
+
+def render_markdown(rows: list[dict[str, object]], threshold: float) -> str:
+    lines = [
+        "## Benchmark results",
+        "",
+        f"Regression threshold: +{threshold:.0%} on the median.",
+        "",
+        "| Benchmark | Median | Baseline | Change | Status |",
+        "|---|---:|---:|---:|---|",
+    ]
+    for row in rows:
+        change = row["change"]
+        change_text = "-" if change is None else f"{change:+.1%}"
+        status = row["status"]
+        if status == "regression":
+            status = "**regression**"
+        lines.append(
+            f"| `{row['benchmark']}` | {format_ns(row['median_ns'])} | "
+            f"{format_ns(row['baseline_ns'])} | {change_text} | {status} |"
+        )
+    return "\n".join(lines) + "\n"
+
+
+def append_history(path: Path, rows: list[dict[str, object]], commit: str) -> None:
+    exists = path.exists()
+    path.parent.mkdir(parents=True, exist_ok=True)
+    timestamp = datetime.now(timezone.utc).strftime("%Y-%m-%dT%H:%M:%SZ")
+    with path.open("a", newline="", encoding="utf-8") as handle:
+        writer = csv.DictWriter(handle, fieldnames=HISTORY_FIELDS)
+        if not exists:
+            writer.writeheader()
+        for row in rows:
+            change = row["change"]
+            writer.writerow(
+                {
+                    "timestamp": timestamp,
+                    "commit": commit,
+                    "benchmark": row["benchmark"],
+                    "median_ns": f"{row['median_ns']:.1f}",
+                    "baseline_ns": ""
+                    if row["baseline_ns"] is None
+                    else f"{row['baseline_ns']:.1f}",
+                    "change_pct": "" if change is None else f"{change * 100:.2f}",
+                    "status": row["status"],
+                }
+            )
+
+
+def main() -> int:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument("--criterion-dir", type=Path, default=Path("target/criterion"))
+    parser.add_argument("--baseline", type=Path, help="baseline JSON from a previous run")
+    parser.add_argument("--write-baseline", type=Path, help="write current medians here")
+    parser.add_argument("--history", type=Path, help="CSV file to append results to")
+    parser.add_argument("--summary", type=Path, help="Markdown file to append the table to")
+    parser.add_argument("--threshold", type=float, default=0.10)
+    parser.add_argument("--commit", default=os.environ.get("GITHUB_SHA", "local"))
+    args = parser.parse_args()
+
+    current = collect(args.criterion_dir)
+    if not current:
+        print(f"no criterion results found under {args.criterion_dir}", file=sys.stderr)
+        return 2
+
+    baseline: dict[str, float] = {}
+    if args.baseline and args.baseline.exists():
+        baseline = json.loads(args.baseline.read_text(encoding="utf-8"))
+    else:
+        print("no baseline found; all benchmarks reported as new")
+
+    rows = compare(current, baseline, args.threshold)
+    table = render_markdown(rows, args.threshold)
+    print(table)
+
+    if args.summary:
+        with args.summary.open("a", encoding="utf-8") as handle:
+            handle.write(table)
+    if args.history:
+        append_history(args.history, rows, args.commit)
+    if args.write_baseline:
+        args.write_baseline.parent.mkdir(parents=True, exist_ok=True)
+        args.write_baseline.write_text(
+            json.dumps(current, indent=2, sort_keys=True) + "\n", encoding="utf-8"
+        )
+
+    regressions = [row["benchmark"] for row in rows if row["status"] == "regression"]
+    if regressions:
+        print(
+            f"{len(regressions)} benchmark(s) regressed beyond +{args.threshold:.0%}: "
+            + ", ".join(regressions),
+            file=sys.stderr,
+        )
+        return 1
+    return 0
+
+
+if __name__ == "__main__":
+    raise SystemExit(main())
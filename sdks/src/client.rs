@@ -10,14 +10,37 @@
 //! - **Purpose:** Implements client
 
 use anyhow::{bail, Result};
-use reqwest::Client;
+use reqwest::{Client, StatusCode};
 use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+
+use aegis_orchestrator_core::presentation::api_version::{
+    features, HandshakeRequest, HandshakeResponse, API_VERSION, LEGACY_SERVER_FEATURES,
+};
+
+/// SDK release reported in the compatibility handshake.
+pub const SDK_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// The orchestrator cannot serve this SDK, e.g. it is too old or lacks a
+/// required feature. Returned by every call once the handshake fails.
+#[derive(Debug, Clone, thiserror::Error)]
+#[error(
+    "AEGIS orchestrator {server_version} is incompatible with SDK {}: {reason}",
+    SDK_VERSION
+)]
+pub struct IncompatibleServerError {
+    pub server_version: String,
+    pub reason: String,
+    pub missing_features: Vec<String>,
+}
 
 /// Client for interacting with the AEGIS orchestrator.
 pub struct AegisClient {
     base_url: String,
     client: Client,
     api_key: Option<String>,
+    required_features: Vec<String>,
+    server: OnceCell<HandshakeResponse>,
 }
 
 impl AegisClient {
@@ -27,6 +50,8 @@ impl AegisClient {
             base_url: base_url.into(),
             client: Client::new(),
             api_key: None,
+            required_features: Vec::new(),
+            server: OnceCell::new(),
         }
     }
 
@@ -36,11 +61,73 @@ impl AegisClient {
         self
     }
 
+    /// Require server features (see [`features`]); calls fail with
+    /// [`IncompatibleServerError`] against servers lacking any of them.
+    pub fn with_required_features<I, F>(mut self, required: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: Into<String>,
+    {
+        self.required_features
+            .extend(required.into_iter().map(Into::into));
+        self
+    }
+
+    /// Negotiate compatibility with the server. Performed once, lazily, by
+    /// the first call; servers that predate the handshake are assumed to
+    /// serve the legacy feature set.
+    pub async fn handshake(&self) -> Result<&HandshakeResponse> {
+        let server = self
+            .server
+            .get_or_try_init(|| self.request_handshake())
+            .await?;
+
+        if !server.compatible {
+            return Err(IncompatibleServerError {
+                server_version: server.server_version.clone(),
+                reason: server.reason.clone().unwrap_or_default(),
+                missing_features: server.missing_features.clone(),
+            }
+            .into());
+        }
+        Ok(server)
+    }
+
+    async fn request_handshake(&self) -> Result<HandshakeResponse> {
+        let url = format!("{}/v1/meta/handshake", self.base_url);
+        let request = HandshakeRequest {
+            client_version: SDK_VERSION.to_string(),
+            api_version: Some(API_VERSION.to_string()),
+            required_features: self.required_features.clone(),
+        };
+        let response = self.client.post(&url).json(&request).send().await?;
+        match response.status() {
+            StatusCode::NOT_FOUND | StatusCode::METHOD_NOT_ALLOWED => {
+                Ok(legacy_server(&self.required_features))
+            }
+            status if status.is_success() => Ok(response.json().await?),
+            status => {
+                let body = response
+                    .text()
+                    .await
+                    .unwrap_or_else(|_| "<failed to read body>".to_string());
+                bail!("Compatibility handshake failed: HTTP {status} - {body}");
+            }
+        }
+    }
+
+    /// Whether the server supports an optional feature, for callers that
+    /// downgrade (e.g. poll instead of stream) rather than fail.
+    pub async fn supports(&self, feature: &str) -> Result<bool> {
+        Ok(self.handshake().await?.supports(feature))
+    }
+
     /// Deploy an agent to the AEGIS cloud.
     pub async fn deploy_agent(
         &self,
         manifest: &crate::AgentManifest,
     ) -> Result<DeploymentResponse> {
+        self.handshake().await?;
         let url = format!("{}/v1/agents", self.base_url);
 
         let mut req = self.client.post(&url).json(manifest);
@@ -67,6 +154,7 @@ impl AegisClient {
 
     /// Execute a task on a deployed agent.
     pub async fn execute_task(&self, agent_id: &str, input: TaskInput) -> Result<TaskOutput> {
+        self.handshake().await?;
         let url = format!("{}/v1/agents/{}/execute", self.base_url, agent_id);
 
         let mut req = self.client.post(&url).json(&input);
@@ -93,6 +181,7 @@ impl AegisClient {
 
     /// Get the status of an agent.
     pub async fn get_agent_status(&self, agent_id: &str) -> Result<AgentStatus> {
+        self.handshake().await?;
         let url = format!("{}/v1/agents/{}/status", self.base_url, agent_id);
 
         let mut req = self.client.get(&url);
@@ -119,6 +208,7 @@ impl AegisClient {
 
     /// Terminate an agent instance.
     pub async fn terminate_agent(&self, agent_id: &str) -> Result<()> {
+        self.handshake().await?;
         let url = format!("{}/v1/agents/{}", self.base_url, agent_id);
 
         let mut req = self.client.delete(&url);
@@ -145,6 +235,9 @@ impl AegisClient {
         model: Option<&str>,
         execution_id: Option<&str>,
     ) -> Result<String> {
+        if !self.supports(features::LLM_TEXT_GENERATION).await? {
+            bail!("The AEGIS orchestrator does not support text generation");
+        }
         let url = format!("{}/v1/dispatch-gateway", self.base_url);
 
         let payload = serde_json::json!({
@@ -179,6 +272,32 @@ impl AegisClient {
     }
 }
 
+/// Handshake outcome for servers without `/v1/meta/handshake`.
+fn legacy_server(required_features: &[String]) -> HandshakeResponse {
+    let missing_features: Vec<String> = required_features
+        .iter()
+        .filter(|feature| !LEGACY_SERVER_FEATURES.contains(&feature.as_str()))
+        .cloned()
+        .collect();
+    HandshakeResponse {
+        compatible: missing_features.is_empty(),
+        api_version: API_VERSION.to_string(),
+        server_version: "unknown (pre-handshake)".to_string(),
+        min_client_version: "0.0.0".to_string(),
+        features: LEGACY_SERVER_FEATURES
+            .iter()
+            .map(|feature| feature.to_string())
+            .collect(),
+        reason: (!missing_features.is_empty()).then(|| {
+            format!(
+                "server predates the compatibility handshake and lacks: {}",
+                missing_features.join(", ")
+            )
+        }),
+        missing_features,
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DeploymentResponse {
     pub agent_id: String,
@@ -205,3 +324,19 @@ pub struct AgentStatus {
     pub state: String,
     pub uptime_seconds: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_servers_serve_only_legacy_features() {
+        let server = legacy_server(&[features::EXECUTION_EVENT_STREAM.to_string()]);
+        assert!(server.compatible);
+        assert!(!server.supports(features::API_VERSION_NEGOTIATION));
+
+        let server = legacy_server(&[features::API_VERSION_NEGOTIATION.to_string()]);
+        assert!(!server.compatible);
+        assert_eq!(server.missing_features, [features::API_VERSION_NEGOTIATION]);
+    }
+}
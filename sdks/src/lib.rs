@@ -11,6 +11,7 @@
 //! |--------|----------|
 //! | [`client`] | [`AegisClient`] — HTTP/gRPC orchestrator client |
 //! | [`types`] | SDK-specific value objects (execution watchers, etc.) |
+//! | [`proto`] | Generated gRPC types, re-exported from `aegis-orchestrator-core` |
 //!
 //! ## Manifest Re-exports
 //!
@@ -33,5 +34,8 @@ pub use aegis_orchestrator_core::domain::agent::{
     RuntimeConfig, SecurityConfig, TaskConfig, ValidationConfig, ValidatorSpec,
 };
 
-pub use client::AegisClient;
+// Generated gRPC types (`aegis-orchestrator-proto`), same version as the core
+pub use aegis_orchestrator_core::proto;
+
+pub use client::{AegisClient, IncompatibleServerError};
 pub use types::*;